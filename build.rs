@@ -1,21 +1,85 @@
 // Build file to create wavetables for the various synthesizers
 
 use std::f64::consts::PI;
-use std::fmt::Display;
 use std::{fs, path::Path};
 
 /// Directory used to store wavetable files generated during compile time
 const WAVETABLES_DIRECTORY: &str = "src/osc/wave_tables/";
 
+/// Directory used to store MIDI note tables generated during compile time
+const NOTE_TABLES_DIRECTORY: &str = "src/midi/note_tables/";
+
+/// Fractional bits of the note frequency ratios
+const NOTE_RATIO_SHIFT: u32 = 24;
+
+/// Number of octave levels of the band-limited (mipmapped) wavetables
+const MIPMAP_LEVELS: usize = 10;
+
+/// Duty cycle of the pulse wavetables
+const PULSE_DUTY: f64 = 0.25;
+
+/// Frames of the morph bank as number of harmonics and gain of the even
+/// harmonics: a sine gains harmonics up to a saw, which loses its even
+/// harmonics to become a square
+const MORPH_FRAMES: [(usize, f64); 7] = [
+    (1, 1.0),
+    (4, 1.0),
+    (16, 1.0),
+    (64, 1.0),
+    (64, 2.0 / 3.0),
+    (64, 1.0 / 3.0),
+    (64, 0.0),
+];
+
 struct Wavetable<T> {
     table: Vec<T>,
     len: usize,
 }
 
+/// Stack of band-limited wavetables, one per octave. Level `l` contains the
+/// harmonics `1..=(len / 2) >> l`. Also holds the frames of the morph bank.
+struct Mipmap<T> {
+    levels: Vec<Wavetable<T>>,
+    len: usize,
+}
+
+#[derive(Clone, Copy)]
+enum Shape {
+    Saw,
+    Square,
+    Triangle,
+    Pulse,
+}
+
+impl Shape {
+    /// Returns the value of the k-th partial at phase x
+    fn partial(&self, k: usize, x: f64) -> f64 {
+        let kf = k as f64;
+        match self {
+            Shape::Saw => (kf * x).sin() / kf,
+            Shape::Square if k % 2 == 1 => (kf * x).sin() / kf,
+            Shape::Triangle if k % 2 == 1 => {
+                let sign = if (k / 2).is_multiple_of(2) {
+                    1_f64
+                } else {
+                    -1_f64
+                };
+                sign * (kf * x).sin() / (kf * kf)
+            }
+            // Difference of two saws shifted by the duty cycle
+            Shape::Pulse => ((kf * x).sin() - (kf * (x - 2_f64 * PI * PULSE_DUTY)).sin()) / kf,
+            _ => 0_f64,
+        }
+    }
+}
+
 trait MaxAmp {
     fn max_amp(&self) -> f64;
+    /// Converts a value in `-max_amp..=max_amp`, rounding for integers
     fn cast(&self, value: f64) -> Self;
     fn type_string(&self) -> String;
+    /// Formats the value as a Rust literal of its type
+    fn literal(&self) -> String;
 }
 
 impl MaxAmp for i32 {
@@ -23,11 +87,14 @@ impl MaxAmp for i32 {
         i32::MAX as f64
     }
     fn cast(&self, value: f64) -> Self {
-        value as Self
+        value.round() as Self
     }
     fn type_string(&self) -> String {
         String::from("i32")
     }
+    fn literal(&self) -> String {
+        self.to_string()
+    }
 }
 
 impl MaxAmp for i16 {
@@ -35,11 +102,14 @@ impl MaxAmp for i16 {
         i16::MAX as f64
     }
     fn cast(&self, value: f64) -> Self {
-        value as Self
+        value.round() as Self
     }
     fn type_string(&self) -> String {
         String::from("i16")
     }
+    fn literal(&self) -> String {
+        self.to_string()
+    }
 }
 
 impl MaxAmp for i8 {
@@ -47,24 +117,59 @@ impl MaxAmp for i8 {
         i8::MAX as f64
     }
     fn cast(&self, value: f64) -> Self {
-        value as Self
+        value.round() as Self
     }
     fn type_string(&self) -> String {
         String::from("i8")
     }
+    fn literal(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl MaxAmp for f64 {
+    fn max_amp(&self) -> f64 {
+        1_f64
+    }
+    fn cast(&self, value: f64) -> Self {
+        value as Self
+    }
+    fn type_string(&self) -> String {
+        String::from("f64")
+    }
+    fn literal(&self) -> String {
+        // Debug keeps the decimal point, e.g. 0.0 instead of 0
+        format!("{:?}", self)
+    }
+}
+
+impl MaxAmp for f32 {
+    fn max_amp(&self) -> f64 {
+        1_f64
+    }
+    fn cast(&self, value: f64) -> Self {
+        value as Self
+    }
+    fn type_string(&self) -> String {
+        String::from("f32")
+    }
+    fn literal(&self) -> String {
+        // Debug keeps the decimal point, e.g. 0.0 instead of 0
+        format!("{:?}", self)
+    }
 }
 
 fn generate_wavetable_sine<T: Sized + MaxAmp>(t: T, len: usize) -> Wavetable<T> {
     let mut sine_table = Wavetable::<T> {
         table: Vec::with_capacity(len),
-        len: len,
+        len,
     };
 
     for i in 0..len {
         let mut _sine: f64 = (2_f64 * PI * ((i as f64) / (len as f64))).sin();
 
         _sine *= t.max_amp();
-        sine_table.table.push(t.cast(_sine.round()));
+        sine_table.table.push(t.cast(_sine));
     }
     sine_table
 }
@@ -72,28 +177,140 @@ fn generate_wavetable_sine<T: Sized + MaxAmp>(t: T, len: usize) -> Wavetable<T>
 fn generate_wavetable_exp<T: Sized + MaxAmp>(t: T, len: usize) -> Wavetable<T> {
     let mut exp_table = Wavetable::<T> {
         table: Vec::with_capacity(len),
-        len: len,
+        len,
     };
 
     for i in 0..len {
         // let mut _exp: f64 = ((i as f64)/(len as f64)).exp();
         let mut _exp: f64 = t.max_amp().powf(1_f64 - ((i as f64) / (len as f64)));
 
-        exp_table.table.push(t.cast(_exp.round()));
+        exp_table.table.push(t.cast(_exp));
     }
     exp_table
 }
 
-fn write_table_to_file<T: Sized + MaxAmp + Display>(wavetable: Wavetable<T>, fname: &str) {
+fn generate_mipmap<T: Sized + MaxAmp>(t: T, shape: Shape, len: usize) -> Mipmap<T> {
+    // Sum up the partials of each level in double precision first
+    let mut levels: Vec<Vec<f64>> = Vec::with_capacity(MIPMAP_LEVELS);
+    for l in 0..MIPMAP_LEVELS {
+        let harmonics = ((len / 2) >> l).max(1);
+        let mut level = Vec::with_capacity(len);
+        for i in 0..len {
+            let x = 2_f64 * PI * ((i as f64) / (len as f64));
+            level.push((1..=harmonics).map(|k| shape.partial(k, x)).sum::<f64>());
+        }
+        levels.push(level);
+    }
+
+    // Normalize all levels by the same peak (incl. Gibbs overshoot) so the
+    // loudness does not jump between levels
+    let peak = levels
+        .iter()
+        .flatten()
+        .fold(0_f64, |peak, y| peak.max(y.abs()));
+
+    let mut mipmap = Mipmap::<T> {
+        levels: Vec::with_capacity(MIPMAP_LEVELS),
+        len,
+    };
+    for level in levels {
+        let mut wavetable = Wavetable::<T> {
+            table: Vec::with_capacity(len),
+            len,
+        };
+        for y in level {
+            wavetable.table.push(t.cast(y / peak * t.max_amp()));
+        }
+        mipmap.levels.push(wavetable);
+    }
+    mipmap
+}
+
+fn generate_morph_bank<T: Sized + MaxAmp>(t: T, len: usize) -> Mipmap<T> {
+    let mut bank = Mipmap::<T> {
+        levels: Vec::with_capacity(MORPH_FRAMES.len()),
+        len,
+    };
+    for (harmonics, even_gain) in MORPH_FRAMES {
+        let frame: Vec<f64> = (0..len)
+            .map(|i| {
+                let x = 2_f64 * PI * ((i as f64) / (len as f64));
+                (1..=harmonics)
+                    .map(|k| {
+                        let gain = if k % 2 == 0 { even_gain } else { 1_f64 };
+                        gain * Shape::Saw.partial(k, x)
+                    })
+                    .sum::<f64>()
+            })
+            .collect();
+
+        // Each frame is normalized on its own to keep the loudness steady
+        let peak = frame.iter().fold(0_f64, |peak, y| peak.max(y.abs()));
+        bank.levels.push(Wavetable::<T> {
+            table: frame
+                .iter()
+                .map(|y| t.cast(y / peak * t.max_amp()))
+                .collect(),
+            len,
+        });
+    }
+    bank
+}
+
+/// Float tables contain values like 1/sqrt(2) that clippy would rather see
+/// as named constants
+fn lint_attributes(type_string: &str) -> String {
+    if type_string.starts_with('f') {
+        String::from("#[allow(clippy::approx_constant)]\r\n")
+    } else {
+        String::new()
+    }
+}
+
+fn write_mipmap_to_file<T: Sized + MaxAmp>(mipmap: Mipmap<T>, fname: &str) {
+    let type_string = mipmap.levels[0].table[0].type_string();
+    let wave_string = fname.split('_').collect::<Vec<&str>>()[0].to_uppercase();
+
+    // Construct a name like SAW_I16 with type [[i16; len]; levels]
+    let mut array_string = lint_attributes(&type_string);
+    array_string.push_str("pub static ");
+    array_string.push_str(&wave_string);
+    array_string.push('_');
+    array_string.push_str(&type_string.to_uppercase());
+    array_string.push_str(": [[");
+    array_string.push_str(&type_string);
+    array_string.push_str("; ");
+    array_string.push_str(mipmap.len.to_string().as_str());
+    array_string.push_str("]; ");
+    array_string.push_str(mipmap.levels.len().to_string().as_str());
+
+    // Fill array level by level
+    array_string.push_str("] = [");
+    for level in &mipmap.levels {
+        array_string.push('[');
+        for s in &level.table {
+            array_string.push_str(s.literal().as_str());
+            array_string.push_str(", ");
+        }
+        array_string.push_str("], ");
+    }
+    array_string.push_str("];\r\n");
+
+    let dest_path = Path::new(&WAVETABLES_DIRECTORY).join(fname);
+    fs::write(dest_path, array_string).unwrap();
+}
+
+fn write_table_to_file<T: Sized + MaxAmp>(wavetable: Wavetable<T>, fname: &str) {
     let type_string = wavetable.table[0].type_string();
     let wave_string = fname.split("_").collect::<Vec<&str>>()[0].to_uppercase();
 
     // Create string
-    let mut array_string = String::from("pub static ");
+    let mut array_string = lint_attributes(&type_string);
+    array_string.push_str("pub static ");
 
     // Construct a name like SINE_i32 or EXP_i8 etc.
     array_string.push_str(&wave_string);
-    array_string.push_str("_");
+    array_string.push('_');
     array_string.push_str(&type_string.to_uppercase());
 
     // Specify array type and length
@@ -106,7 +323,7 @@ fn write_table_to_file<T: Sized + MaxAmp + Display>(wavetable: Wavetable<T>, fna
     array_string.push_str("] = [");
     for s in &wavetable.table {
         array_string.push_str("");
-        array_string.push_str(s.to_string().as_str());
+        array_string.push_str(s.literal().as_str());
         array_string.push_str(", ");
     }
     array_string.push_str("];\r\n");
@@ -115,14 +332,14 @@ fn write_table_to_file<T: Sized + MaxAmp + Display>(wavetable: Wavetable<T>, fna
     // tau is given in terms of the closest integer index
     if wave_string == "EXP" {
         let t = &wavetable.table[0];
-        let tau = t.cast(((wavetable.len as f64) / t.max_amp().ln()).round());
+        let tau = t.cast((wavetable.len as f64) / t.max_amp().ln());
         array_string.push_str("\r\n");
         array_string.push_str("pub const TAU_");
         array_string.push_str(&wave_string);
-        array_string.push_str("_");
+        array_string.push('_');
         array_string.push_str(&type_string.to_uppercase());
         array_string.push_str(": usize = ");
-        array_string.push_str(tau.to_string().as_str());
+        array_string.push_str(tau.literal().as_str());
         array_string.push_str(";\r\n");
     }
 
@@ -135,6 +352,26 @@ fn write_table_to_file<T: Sized + MaxAmp + Display>(wavetable: Wavetable<T>, fna
     fs::write(&dest_path, array_string).unwrap();
 }
 
+/// Writes the 12-TET frequency ratio of every MIDI note relative to A4 (note
+/// 69) in fixed point, so that A4 can be chosen at runtime
+fn write_note_ratio_table(fname: &str) {
+    let mut array_string = String::from("pub const NOTE_RATIO_SHIFT: u32 = ");
+    array_string.push_str(NOTE_RATIO_SHIFT.to_string().as_str());
+    array_string.push_str(";\r\n\r\n");
+
+    array_string.push_str("pub static NOTE_RATIO: [u32; 128] = [");
+    for note in 0..128 {
+        let ratio = 2_f64.powf((note as f64 - 69_f64) / 12_f64);
+        let ratio = (ratio * (1_u64 << NOTE_RATIO_SHIFT) as f64).round() as u32;
+        array_string.push_str(ratio.to_string().as_str());
+        array_string.push_str(", ");
+    }
+    array_string.push_str("];\r\n");
+
+    let dest_path = Path::new(&NOTE_TABLES_DIRECTORY).join(fname);
+    fs::write(dest_path, array_string).unwrap();
+}
+
 fn main() {
     let sine_table8 = generate_wavetable_sine(0_i8, 256);
     write_table_to_file(sine_table8, "sine_i8.rs");
@@ -145,6 +382,12 @@ fn main() {
     let sine_table32 = generate_wavetable_sine(0_i32, 1024);
     write_table_to_file(sine_table32, "sine_i32.rs");
 
+    let sine_table_f32 = generate_wavetable_sine(0_f32, 1024);
+    write_table_to_file(sine_table_f32, "sine_f32.rs");
+
+    let sine_table_f64 = generate_wavetable_sine(0_f64, 1024);
+    write_table_to_file(sine_table_f64, "sine_f64.rs");
+
     let exp_table8 = generate_wavetable_exp(0_i8, 256);
     write_table_to_file(exp_table8, "exp_i8.rs");
 
@@ -153,4 +396,30 @@ fn main() {
 
     let exp_table32 = generate_wavetable_exp(0_i32, 1024);
     write_table_to_file(exp_table32, "exp_i32.rs");
+
+    for (shape, name) in [
+        (Shape::Saw, "saw"),
+        (Shape::Square, "square"),
+        (Shape::Triangle, "triangle"),
+        (Shape::Pulse, "pulse"),
+    ] {
+        let mipmap16 = generate_mipmap(0_i16, shape, 1024);
+        write_mipmap_to_file(mipmap16, &format!("{}_i16.rs", name));
+
+        let mipmap32 = generate_mipmap(0_i32, shape, 1024);
+        write_mipmap_to_file(mipmap32, &format!("{}_i32.rs", name));
+
+        let mipmap_f32 = generate_mipmap(0_f32, shape, 1024);
+        write_mipmap_to_file(mipmap_f32, &format!("{}_f32.rs", name));
+
+        let mipmap_f64 = generate_mipmap(0_f64, shape, 1024);
+        write_mipmap_to_file(mipmap_f64, &format!("{}_f64.rs", name));
+    }
+
+    write_mipmap_to_file(generate_morph_bank(0_i16, 1024), "morph_i16.rs");
+    write_mipmap_to_file(generate_morph_bank(0_i32, 1024), "morph_i32.rs");
+    write_mipmap_to_file(generate_morph_bank(0_f32, 1024), "morph_f32.rs");
+    write_mipmap_to_file(generate_morph_bank(0_f64, 1024), "morph_f64.rs");
+
+    write_note_ratio_table("note_ratio.rs");
 }
@@ -0,0 +1,399 @@
+// Provides a DAHDSR (delay, attack, hold, decay, sustain, release) envelope
+// generator. Attack, decay and release follow LinExp curves. With zero delay
+// and hold it is a classic ADSR envelope.
+
+use crate::fixed::Q15;
+use crate::freq::MilliHz;
+use crate::linexp::{LinExp, LinExpError};
+
+/// Peak level of the envelope, 1.0 in Q15
+const PEAK: i32 = i16::MAX as i32;
+
+/// Stages of the envelope
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    Idle,
+    Delay,
+    Attack,
+    Hold,
+    Decay,
+    Sustain,
+    Release,
+}
+
+/// Envelope segment with a duration and a curve
+struct Segment {
+    /// Duration in milliseconds
+    ms: u32,
+    /// Duration in samples
+    samples: u32,
+    curve: LinExp<i32>,
+}
+
+impl Segment {
+    fn new(ms: u32) -> Self {
+        Self {
+            ms,
+            samples: 0,
+            curve: LinExp::<i32>::new(),
+        }
+    }
+
+    /// Returns the falling curve from 1.0 to 0.0 at sample `pos`, where 1.0
+    /// saturates to `Q15::MAX`
+    fn y(&self, pos: u32) -> Q15 {
+        let norm = self.curve.get_norm();
+        let x = ((pos as i64) * (norm as i64)) / (self.samples as i64);
+        Q15(self.curve.y(x as i32).min(PEAK) as i16)
+    }
+}
+
+/// DAHDSR envelope generator producing Q15 samples in `0..=i16::MAX`
+pub struct Envelope {
+    stage: Stage,
+    gate: bool,
+    retrigger: bool,
+    legato: bool,
+
+    msample_rate: u32,
+
+    /// Delay and hold durations in milliseconds and samples
+    delay_ms: u32,
+    delay: u32,
+    hold_ms: u32,
+    hold: u32,
+
+    attack: Segment,
+    decay: Segment,
+    release: Segment,
+    sustain: i32,
+
+    /// Position within the current stage in samples
+    pos: u32,
+    level: i32,
+    start_level: i32,
+}
+
+impl Envelope {
+    pub fn new() -> Self {
+        let mut envelope = Self {
+            stage: Stage::Idle,
+            gate: false,
+            retrigger: false,
+            legato: false,
+
+            msample_rate: 44100 * 1000,
+
+            delay_ms: 0,
+            delay: 0,
+            hold_ms: 0,
+            hold: 0,
+
+            attack: Segment::new(10),
+            decay: Segment::new(100),
+            release: Segment::new(200),
+            sustain: PEAK / 2,
+
+            pos: 0,
+            level: 0,
+            start_level: 0,
+        };
+        envelope.update_samples();
+        envelope
+    }
+
+    /// Creates an ADSR envelope with times in milliseconds and the sustain
+    /// level in Q15
+    pub fn adsr(attack: u32, decay: u32, sustain: i16, release: u32) -> Self {
+        Self::dahdsr(0, attack, 0, decay, sustain, release)
+    }
+
+    /// Creates a DAHDSR envelope with times in milliseconds and the sustain
+    /// level in Q15
+    pub fn dahdsr(
+        delay: u32,
+        attack: u32,
+        hold: u32,
+        decay: u32,
+        sustain: i16,
+        release: u32,
+    ) -> Self {
+        let mut envelope = Self::new();
+        envelope.delay_ms = delay;
+        envelope.attack.ms = attack;
+        envelope.hold_ms = hold;
+        envelope.decay.ms = decay;
+        envelope.release.ms = release;
+        envelope.set_sustain(sustain);
+        envelope.update_samples();
+        envelope
+    }
+
+    fn ms_to_samples(&self, ms: u32) -> u32 {
+        (((ms as u64) * (self.msample_rate as u64)) / 1_000_000) as u32
+    }
+
+    fn update_samples(&mut self) {
+        self.delay = self.ms_to_samples(self.delay_ms);
+        self.hold = self.ms_to_samples(self.hold_ms);
+        self.attack.samples = self.ms_to_samples(self.attack.ms);
+        self.decay.samples = self.ms_to_samples(self.decay.ms);
+        self.release.samples = self.ms_to_samples(self.release.ms);
+    }
+
+    fn enter(&mut self, stage: Stage) {
+        self.stage = stage;
+        self.pos = 0;
+        self.start_level = self.level;
+    }
+
+    /// Opens the gate. While the gate is already open, legato mode keeps the
+    /// envelope running instead of restarting it. Retrigger mode restarts
+    /// from zero, otherwise the attack starts from the current level.
+    pub fn gate_on(&mut self) {
+        let active = self.gate && self.stage != Stage::Idle;
+        self.gate = true;
+        if active && self.legato {
+            return;
+        }
+        if self.retrigger {
+            self.level = 0;
+        }
+        self.enter(Stage::Delay);
+    }
+
+    /// Closes the gate and enters the release stage
+    pub fn gate_off(&mut self) {
+        self.gate = false;
+        if self.stage != Stage::Idle {
+            self.enter(Stage::Release);
+        }
+    }
+
+    /// Stops the envelope immediately
+    pub fn reset(&mut self) {
+        self.gate = false;
+        self.level = 0;
+        self.enter(Stage::Idle);
+    }
+
+    /// Restart from zero instead of the current level on every gate on
+    pub fn set_retrigger(&mut self, retrigger: bool) {
+        self.retrigger = retrigger;
+    }
+
+    /// Do not restart the envelope on gate on while the gate is open
+    pub fn set_legato(&mut self, legato: bool) {
+        self.legato = legato;
+    }
+
+    pub fn set_delay(&mut self, ms: u32) {
+        self.delay_ms = ms;
+        self.update_samples();
+    }
+
+    pub fn set_attack(&mut self, ms: u32) {
+        self.attack.ms = ms;
+        self.update_samples();
+    }
+
+    pub fn set_hold(&mut self, ms: u32) {
+        self.hold_ms = ms;
+        self.update_samples();
+    }
+
+    pub fn set_decay(&mut self, ms: u32) {
+        self.decay.ms = ms;
+        self.update_samples();
+    }
+
+    /// Sets the sustain level in Q15, negative levels are clamped to zero
+    pub fn set_sustain(&mut self, sustain: i16) {
+        self.sustain = (sustain as i32).max(0);
+    }
+
+    pub fn set_release(&mut self, ms: u32) {
+        self.release.ms = ms;
+        self.update_samples();
+    }
+
+    /// Sets the curvature of the attack segment, see `LinExp::set_sigma`
+    pub fn set_attack_sigma(&mut self, sigma: i32) -> Result<(), LinExpError> {
+        self.attack.curve.set_sigma(sigma)
+    }
+
+    /// Sets the curvature of the decay segment, see `LinExp::set_sigma`
+    pub fn set_decay_sigma(&mut self, sigma: i32) -> Result<(), LinExpError> {
+        self.decay.curve.set_sigma(sigma)
+    }
+
+    /// Sets the curvature of the release segment, see `LinExp::set_sigma`
+    pub fn set_release_sigma(&mut self, sigma: i32) -> Result<(), LinExpError> {
+        self.release.curve.set_sigma(sigma)
+    }
+
+    pub fn set_msample_rate(&mut self, msample_rate: u32) {
+        self.msample_rate = msample_rate;
+        self.update_samples();
+    }
+
+    /// Sets the sample rate in any unit, e.g. `Hz(48000)`
+    pub fn set_sample_rate<R: Into<MilliHz>>(&mut self, sample_rate: R) {
+        self.set_msample_rate(sample_rate.into().0);
+    }
+
+    /// Returns whether the gate is open
+    pub fn gate(&self) -> bool {
+        self.gate
+    }
+
+    /// Returns the current stage
+    pub fn stage(&self) -> Stage {
+        self.stage
+    }
+
+    /// Returns whether the envelope is outside of the idle stage
+    pub fn is_active(&self) -> bool {
+        self.stage != Stage::Idle
+    }
+
+    /// Returns the current level in Q15
+    pub fn level(&self) -> i16 {
+        self.level as i16
+    }
+
+    /// Advances the envelope by one sample and returns the level in Q15
+    #[inline]
+    pub fn _next(&mut self) -> i16 {
+        loop {
+            match self.stage {
+                Stage::Idle => {
+                    self.level = 0;
+                }
+                Stage::Delay => {
+                    if self.pos >= self.delay {
+                        self.enter(Stage::Attack);
+                        continue;
+                    }
+                }
+                Stage::Attack => {
+                    if self.pos >= self.attack.samples {
+                        self.level = PEAK;
+                        self.enter(Stage::Hold);
+                        continue;
+                    }
+                    let rise = Q15::MAX - self.attack.y(self.pos);
+                    let start = Q15(self.start_level as i16);
+                    self.level = start.mul_add(Q15::MAX - start, rise).0 as i32;
+                }
+                Stage::Hold => {
+                    if self.pos >= self.hold {
+                        self.enter(Stage::Decay);
+                        continue;
+                    }
+                }
+                Stage::Decay => {
+                    if self.pos >= self.decay.samples {
+                        self.level = self.sustain;
+                        self.enter(Stage::Sustain);
+                        continue;
+                    }
+                    let fall = self.decay.y(self.pos);
+                    let sustain = Q15(self.sustain as i16);
+                    let start = Q15(self.start_level as i16);
+                    self.level = sustain.mul_add(start - sustain, fall).0 as i32;
+                }
+                Stage::Sustain => {
+                    self.level = self.sustain;
+                }
+                Stage::Release => {
+                    if self.pos >= self.release.samples {
+                        self.level = 0;
+                        self.enter(Stage::Idle);
+                        continue;
+                    }
+                    let fall = self.release.y(self.pos);
+                    self.level = (Q15(self.start_level as i16) * fall).0 as i32;
+                }
+            }
+            break;
+        }
+        self.pos = self.pos.saturating_add(1);
+        self.level as i16
+    }
+
+    /// Advances the envelope and multiplies the sample by its level
+    #[inline]
+    pub fn apply(&mut self, sample: i16) -> i16 {
+        (Q15(sample) * Q15(self._next())).0
+    }
+}
+
+impl Default for Envelope {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for Envelope {
+    type Item = i16;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self._next())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::freq::Hz;
+
+    #[test]
+    fn test_adsr() {
+        let mut env = Envelope::adsr(10, 10, i16::MAX / 2, 10);
+        env.set_sample_rate(Hz(1000));
+        assert_eq!(env._next(), 0);
+
+        env.gate_on();
+        let attack: Vec<i16> = env.by_ref().take(10).collect();
+        assert_eq!(attack[0], 0);
+        assert!(attack.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(env._next(), i16::MAX);
+
+        let decay: Vec<i16> = env.by_ref().take(10).collect();
+        assert!(decay.windows(2).all(|w| w[0] > w[1]));
+        assert_eq!(env.stage(), Stage::Sustain);
+        assert_eq!(env._next(), i16::MAX / 2);
+
+        env.gate_off();
+        assert_eq!(env._next(), i16::MAX / 2);
+        assert_eq!(env.by_ref().take(10).last(), Some(0));
+        assert!(!env.is_active());
+    }
+
+    #[test]
+    fn test_legato_and_retrigger() {
+        let mut env = Envelope::dahdsr(2, 5, 2, 5, i16::MAX, 5);
+        env.set_sample_rate(Hz(1000));
+        env.gate_on();
+        assert_eq!(env.by_ref().take(3).collect::<Vec<i16>>(), [0, 0, 0]);
+        env.by_ref().take(20).count();
+        assert_eq!(env.stage(), Stage::Sustain);
+
+        // Legato keeps the sustain stage
+        env.set_legato(true);
+        env.gate_on();
+        assert_eq!(env.stage(), Stage::Sustain);
+
+        // Without legato the envelope restarts from the current level
+        env.set_legato(false);
+        env.gate_on();
+        assert_eq!(env._next(), i16::MAX);
+        assert_eq!(env.stage(), Stage::Delay);
+
+        env.set_retrigger(true);
+        env.gate_on();
+        assert_eq!(env._next(), 0);
+    }
+}
@@ -0,0 +1,506 @@
+// Biquad filters with coefficient designs from Robert Bristow-Johnson's
+// "Cookbook formulae for audio EQ biquad filter coefficients". The floating
+// point filter uses the transposed direct form II, the fixed-point filter the
+// direct form I with a Q31 state.
+
+use crate::filter::Filter;
+use crate::fixed::{Q15, Q31};
+use core::f32::consts::PI;
+use core::fmt;
+
+/// Magnitude limit of fixed-point coefficients
+const COEF_MAX: f64 = (1_u64 << 16) as f64;
+
+/// Errors of the fixed-point conversion
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BiquadError {
+    /// A coefficient is not finite or exceeds the fixed-point range
+    CoefficientOutOfRange,
+}
+
+impl fmt::Display for BiquadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BiquadError::CoefficientOutOfRange => write!(f, "coefficient is out of range"),
+        }
+    }
+}
+
+impl core::error::Error for BiquadError {}
+
+/// Biquad coefficients normalized by a0
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Coefficients {
+    pub b0: f32,
+    pub b1: f32,
+    pub b2: f32,
+    pub a1: f32,
+    pub a2: f32,
+}
+
+/// Intermediate values shared by all cookbook designs
+struct Design {
+    cos_w0: f32,
+    alpha: f32,
+}
+
+impl Design {
+    fn new(sample_rate: f32, f0: f32, q: f32) -> Self {
+        let w0 = 2.0 * PI * f0 / sample_rate;
+        Self {
+            cos_w0: libm::cosf(w0),
+            alpha: libm::sinf(w0) / (2.0 * q),
+        }
+    }
+}
+
+impl Coefficients {
+    /// Identity filter
+    pub const BYPASS: Self = Self {
+        b0: 1.0,
+        b1: 0.0,
+        b2: 0.0,
+        a1: 0.0,
+        a2: 0.0,
+    };
+
+    fn normalized(b0: f32, b1: f32, b2: f32, a0: f32, a1: f32, a2: f32) -> Self {
+        Self {
+            b0: b0 / a0,
+            b1: b1 / a0,
+            b2: b2 / a0,
+            a1: a1 / a0,
+            a2: a2 / a0,
+        }
+    }
+
+    pub fn lowpass(sample_rate: f32, f0: f32, q: f32) -> Self {
+        let Design { cos_w0, alpha } = Design::new(sample_rate, f0, q);
+        let b1 = 1.0 - cos_w0;
+        Self::normalized(
+            b1 / 2.0,
+            b1,
+            b1 / 2.0,
+            1.0 + alpha,
+            -2.0 * cos_w0,
+            1.0 - alpha,
+        )
+    }
+
+    pub fn highpass(sample_rate: f32, f0: f32, q: f32) -> Self {
+        let Design { cos_w0, alpha } = Design::new(sample_rate, f0, q);
+        let b1 = 1.0 + cos_w0;
+        Self::normalized(
+            b1 / 2.0,
+            -b1,
+            b1 / 2.0,
+            1.0 + alpha,
+            -2.0 * cos_w0,
+            1.0 - alpha,
+        )
+    }
+
+    /// Bandpass with a constant peak gain of 0 dB
+    pub fn bandpass(sample_rate: f32, f0: f32, q: f32) -> Self {
+        let Design { cos_w0, alpha } = Design::new(sample_rate, f0, q);
+        Self::normalized(alpha, 0.0, -alpha, 1.0 + alpha, -2.0 * cos_w0, 1.0 - alpha)
+    }
+
+    pub fn allpass(sample_rate: f32, f0: f32, q: f32) -> Self {
+        let Design { cos_w0, alpha } = Design::new(sample_rate, f0, q);
+        Self::normalized(
+            1.0 - alpha,
+            -2.0 * cos_w0,
+            1.0 + alpha,
+            1.0 + alpha,
+            -2.0 * cos_w0,
+            1.0 - alpha,
+        )
+    }
+
+    /// Peaking EQ with the gain in dB at f0
+    pub fn peaking(sample_rate: f32, f0: f32, q: f32, gain_db: f32) -> Self {
+        let Design { cos_w0, alpha } = Design::new(sample_rate, f0, q);
+        let a = libm::powf(10.0, gain_db / 40.0);
+        Self::normalized(
+            1.0 + alpha * a,
+            -2.0 * cos_w0,
+            1.0 - alpha * a,
+            1.0 + alpha / a,
+            -2.0 * cos_w0,
+            1.0 - alpha / a,
+        )
+    }
+
+    /// Low shelf with the gain in dB below f0
+    pub fn low_shelf(sample_rate: f32, f0: f32, q: f32, gain_db: f32) -> Self {
+        let Design { cos_w0, alpha } = Design::new(sample_rate, f0, q);
+        let a = libm::powf(10.0, gain_db / 40.0);
+        let beta = 2.0 * libm::sqrtf(a) * alpha;
+        Self::normalized(
+            a * ((a + 1.0) - (a - 1.0) * cos_w0 + beta),
+            2.0 * a * ((a - 1.0) - (a + 1.0) * cos_w0),
+            a * ((a + 1.0) - (a - 1.0) * cos_w0 - beta),
+            (a + 1.0) + (a - 1.0) * cos_w0 + beta,
+            -2.0 * ((a - 1.0) + (a + 1.0) * cos_w0),
+            (a + 1.0) + (a - 1.0) * cos_w0 - beta,
+        )
+    }
+
+    /// High shelf with the gain in dB above f0
+    pub fn high_shelf(sample_rate: f32, f0: f32, q: f32, gain_db: f32) -> Self {
+        let Design { cos_w0, alpha } = Design::new(sample_rate, f0, q);
+        let a = libm::powf(10.0, gain_db / 40.0);
+        let beta = 2.0 * libm::sqrtf(a) * alpha;
+        Self::normalized(
+            a * ((a + 1.0) + (a - 1.0) * cos_w0 + beta),
+            -2.0 * a * ((a - 1.0) + (a + 1.0) * cos_w0),
+            a * ((a + 1.0) + (a - 1.0) * cos_w0 - beta),
+            (a + 1.0) - (a - 1.0) * cos_w0 + beta,
+            2.0 * ((a - 1.0) - (a + 1.0) * cos_w0),
+            (a + 1.0) - (a - 1.0) * cos_w0 - beta,
+        )
+    }
+
+    /// Returns the Q of section `section` of a Butterworth filter of order
+    /// `2 * sections`, to be used with `lowpass` or `highpass`
+    pub fn butterworth_q(sections: usize, section: usize) -> f32 {
+        let order = (2 * sections) as f32;
+        let theta = PI * (2 * section + 1) as f32 / (2.0 * order);
+        1.0 / (2.0 * libm::sinf(theta))
+    }
+
+    /// Returns the magnitude of the frequency response at `freq`
+    pub fn magnitude(&self, sample_rate: f32, freq: f32) -> f32 {
+        let w = 2.0 * PI * freq / sample_rate;
+        let (c1, s1) = (libm::cosf(w), libm::sinf(w));
+        let (c2, s2) = (libm::cosf(2.0 * w), libm::sinf(2.0 * w));
+        let num_re = self.b0 + self.b1 * c1 + self.b2 * c2;
+        let num_im = -(self.b1 * s1 + self.b2 * s2);
+        let den_re = 1.0 + self.a1 * c1 + self.a2 * c2;
+        let den_im = -(self.a1 * s1 + self.a2 * s2);
+        libm::sqrtf((num_re * num_re + num_im * num_im) / (den_re * den_re + den_im * den_im))
+    }
+}
+
+impl Default for Coefficients {
+    fn default() -> Self {
+        Self::BYPASS
+    }
+}
+
+/// Biquad coefficients in Q31 with a common exponent, i.e. each coefficient
+/// is its Q31 value times `2^shift`. The exponent is chosen such that the
+/// magnitudes sum up to less than one, so no partial sum of the filter can
+/// saturate. This holds the cookbook designs up to very high gains, e.g.
+/// shelves of +24 dB with a b0 of 30.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FixedCoefficients {
+    pub b0: Q31,
+    pub b1: Q31,
+    pub b2: Q31,
+    pub a1: Q31,
+    pub a2: Q31,
+    pub shift: u32,
+}
+
+impl FixedCoefficients {
+    /// Identity filter
+    pub const BYPASS: Self = Self {
+        b0: Q31(1 << 30),
+        b1: Q31::ZERO,
+        b2: Q31::ZERO,
+        a1: Q31::ZERO,
+        a2: Q31::ZERO,
+        shift: 1,
+    };
+}
+
+/// Fails instead of saturating if a coefficient reaches 2^16 in magnitude
+impl TryFrom<Coefficients> for FixedCoefficients {
+    type Error = BiquadError;
+
+    fn try_from(c: Coefficients) -> Result<Self, Self::Error> {
+        let coefficients = [c.b0, c.b1, c.b2, c.a1, c.a2].map(|x| x as f64);
+        // Also rejects NaN
+        if !coefficients.iter().all(|x| x.abs() < COEF_MAX) {
+            return Err(BiquadError::CoefficientOutOfRange);
+        }
+        let sum: f64 = coefficients.iter().map(|x| x.abs()).sum();
+        let mut shift = 0;
+        while sum >= (1_u64 << shift) as f64 {
+            shift += 1;
+        }
+        let scale = (1_u64 << (31 - shift)) as f64;
+        let [b0, b1, b2, a1, a2] = coefficients.map(|x| Q31(libm::round(x * scale) as i32));
+        Ok(Self {
+            b0,
+            b1,
+            b2,
+            a1,
+            a2,
+            shift,
+        })
+    }
+}
+
+impl Default for FixedCoefficients {
+    fn default() -> Self {
+        Self::BYPASS
+    }
+}
+
+/// Floating-point biquad in transposed direct form II
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Biquad {
+    coefficients: Coefficients,
+    s1: f32,
+    s2: f32,
+}
+
+impl Biquad {
+    pub fn new(coefficients: Coefficients) -> Self {
+        Self {
+            coefficients,
+            s1: 0.0,
+            s2: 0.0,
+        }
+    }
+
+    /// Sets new coefficients while keeping the state
+    pub fn set_coefficients(&mut self, coefficients: Coefficients) {
+        self.coefficients = coefficients;
+    }
+
+    #[inline]
+    pub fn process(&mut self, x: f32) -> f32 {
+        let c = &self.coefficients;
+        let y = c.b0 * x + self.s1;
+        self.s1 = c.b1 * x - c.a1 * y + self.s2;
+        self.s2 = c.b2 * x - c.a2 * y;
+        y
+    }
+
+    pub fn reset(&mut self) {
+        self.s1 = 0.0;
+        self.s2 = 0.0;
+    }
+}
+
+/// Fixed-point biquad in direct form I for Q15 samples. The output is kept
+/// in Q31 for the feedback, which avoids DC offsets for low cutoff
+/// frequencies, and saturates.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BiquadFixed {
+    coefficients: FixedCoefficients,
+    x1: Q31,
+    x2: Q31,
+    y1: Q31,
+    y2: Q31,
+}
+
+impl BiquadFixed {
+    pub fn new(coefficients: FixedCoefficients) -> Self {
+        Self {
+            coefficients,
+            x1: Q31::ZERO,
+            x2: Q31::ZERO,
+            y1: Q31::ZERO,
+            y2: Q31::ZERO,
+        }
+    }
+
+    /// Sets new coefficients while keeping the state
+    pub fn set_coefficients(&mut self, coefficients: FixedCoefficients) {
+        self.coefficients = coefficients;
+    }
+}
+
+impl Filter for BiquadFixed {
+    #[inline]
+    fn process(&mut self, x: Q15) -> Q15 {
+        let c = &self.coefficients;
+        let x = Q31::from(x);
+        // Scaled down by 2^shift
+        let acc = Q31::ZERO
+            .mul_add(c.b0, x)
+            .mul_add(c.b1, self.x1)
+            .mul_add(c.b2, self.x2)
+            .mul_add(-c.a1, self.y1)
+            .mul_add(-c.a2, self.y2);
+        let y = Q31(acc.0.saturating_mul(1 << c.shift));
+        self.x2 = self.x1;
+        self.x1 = x;
+        self.y2 = self.y1;
+        self.y1 = y;
+        Q15::from(y)
+    }
+
+    fn reset(&mut self) {
+        self.x1 = Q31::ZERO;
+        self.x2 = Q31::ZERO;
+        self.y1 = Q31::ZERO;
+        self.y2 = Q31::ZERO;
+    }
+}
+
+/// Series connection of `N` biquad sections forming a filter of order `2 * N`
+#[derive(Clone, Copy, Debug)]
+pub struct Cascade<B, const N: usize> {
+    pub sections: [B; N],
+}
+
+impl<B: Default + Copy, const N: usize> Default for Cascade<B, N> {
+    fn default() -> Self {
+        Self {
+            sections: [B::default(); N],
+        }
+    }
+}
+
+impl<const N: usize> Cascade<Biquad, N> {
+    /// Butterworth lowpass of order `2 * N`
+    pub fn butterworth_lowpass(sample_rate: f32, f0: f32) -> Self {
+        Self {
+            sections: core::array::from_fn(|i| {
+                Biquad::new(Coefficients::lowpass(
+                    sample_rate,
+                    f0,
+                    Coefficients::butterworth_q(N, i),
+                ))
+            }),
+        }
+    }
+
+    /// Butterworth highpass of order `2 * N`
+    pub fn butterworth_highpass(sample_rate: f32, f0: f32) -> Self {
+        Self {
+            sections: core::array::from_fn(|i| {
+                Biquad::new(Coefficients::highpass(
+                    sample_rate,
+                    f0,
+                    Coefficients::butterworth_q(N, i),
+                ))
+            }),
+        }
+    }
+
+    #[inline]
+    pub fn process(&mut self, x: f32) -> f32 {
+        self.sections.iter_mut().fold(x, |x, s| s.process(x))
+    }
+
+    pub fn reset(&mut self) {
+        self.sections.iter_mut().for_each(Biquad::reset);
+    }
+}
+
+impl<const N: usize> Cascade<BiquadFixed, N> {
+    /// Converts a floating-point cascade into fixed point, failing if any
+    /// coefficient is out of range
+    pub fn from_float(cascade: &Cascade<Biquad, N>) -> Result<Self, BiquadError> {
+        let mut fixed = Self::default();
+        for (section, float) in fixed.sections.iter_mut().zip(&cascade.sections) {
+            section.set_coefficients(float.coefficients.try_into()?);
+        }
+        Ok(fixed)
+    }
+}
+
+impl<const N: usize> Filter for Cascade<BiquadFixed, N> {
+    #[inline]
+    fn process(&mut self, x: Q15) -> Q15 {
+        self.sections.iter_mut().fold(x, |x, s| s.process(x))
+    }
+
+    fn reset(&mut self) {
+        self.sections.iter_mut().for_each(Filter::reset);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const FS: f32 = 48000.0;
+
+    #[test]
+    fn test_cookbook_designs() {
+        let lp = Coefficients::lowpass(FS, 1000.0, 0.707);
+        assert!((lp.magnitude(FS, 0.0) - 1.0).abs() < 1e-3);
+        assert!(lp.magnitude(FS, 10000.0) < 0.02);
+
+        let hp = Coefficients::highpass(FS, 1000.0, 0.707);
+        assert!(hp.magnitude(FS, 10.0) < 1e-3);
+        assert!((hp.magnitude(FS, 20000.0) - 1.0).abs() < 1e-2);
+
+        let bp = Coefficients::bandpass(FS, 1000.0, 2.0);
+        assert!((bp.magnitude(FS, 1000.0) - 1.0).abs() < 1e-3);
+
+        let ap = Coefficients::allpass(FS, 1000.0, 0.707);
+        for f in [10.0, 1000.0, 15000.0] {
+            assert!((ap.magnitude(FS, f) - 1.0).abs() < 1e-3);
+        }
+
+        let peak = Coefficients::peaking(FS, 1000.0, 1.0, 6.0);
+        assert!((peak.magnitude(FS, 1000.0) - 1.995).abs() < 1e-2);
+
+        let low = Coefficients::low_shelf(FS, 200.0, 0.707, -12.0);
+        assert!((low.magnitude(FS, 10.0) - 0.251).abs() < 1e-2);
+        let high = Coefficients::high_shelf(FS, 5000.0, 0.707, 12.0);
+        assert!((high.magnitude(FS, 20000.0) - 3.98).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_butterworth_cascade() {
+        let mut lp = Cascade::<Biquad, 2>::butterworth_lowpass(FS, 1000.0);
+        let gain: f32 = lp
+            .sections
+            .iter()
+            .map(|s| s.coefficients.magnitude(FS, 1000.0))
+            .product();
+        assert!((gain - core::f32::consts::FRAC_1_SQRT_2).abs() < 1e-3);
+
+        // Fixed and floating point agree on a step response
+        let mut fixed = Cascade::<BiquadFixed, 2>::from_float(&lp).unwrap();
+        for _ in 0..200 {
+            let y = lp.process(0.5);
+            let y_fixed = fixed.process(Q15(i16::MAX / 2)).to_f32();
+            assert!((y - y_fixed).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_high_gain_fixed() {
+        // A +24 dB shelf has coefficients far beyond the range of Q31
+        let shelf = Coefficients::high_shelf(FS, 1000.0, 0.707, 24.0);
+        assert!(shelf.b1 < -8.0);
+        let mut float = Biquad::new(shelf);
+        let coefficients = FixedCoefficients::try_from(shelf).unwrap();
+        assert_eq!(coefficients.shift, 6);
+        let mut fixed = BiquadFixed::new(coefficients);
+        let mut peak = 0.0_f32;
+        for i in 0..4800 {
+            let x = (1000.0 * libm::sinf(2.0 * PI * 10000.0 * i as f32 / FS)) as i16;
+            let y = float.process(x as f32 / 32768.0);
+            let y_fixed = fixed.process(Q15(x)).to_f32();
+            assert!((y - y_fixed).abs() < 1e-3);
+            peak = peak.max(y_fixed);
+        }
+        // 1000 amplified by almost 16
+        assert!(peak > 0.4);
+
+        let huge = Coefficients {
+            b0: 1e6,
+            ..Coefficients::BYPASS
+        };
+        assert_eq!(
+            FixedCoefficients::try_from(huge),
+            Err(BiquadError::CoefficientOutOfRange)
+        );
+
+        let mut bypass = BiquadFixed::default();
+        assert_eq!(bypass.process(Q15::MIN), Q15::MIN);
+        assert_eq!(bypass.process(Q15(-1234)), Q15(-1234));
+    }
+}
@@ -0,0 +1,91 @@
+// Filters operating on fixed-point samples
+
+pub mod biquad;
+pub mod svf;
+
+use crate::fixed::Q15;
+
+#[cfg(feature = "rodio")]
+use core::time::Duration;
+#[cfg(feature = "rodio")]
+use rodio::source::Source;
+
+/// Filter processing one Q15 sample at a time
+pub trait Filter {
+    /// Filters one sample
+    fn process(&mut self, x: Q15) -> Q15;
+
+    /// Clears the filter state
+    fn reset(&mut self);
+}
+
+/// Adapter that filters any `Iterator<Item = i16>` and can be played as a
+/// rodio `Source`
+#[cfg(feature = "rodio")]
+pub struct FilterSource<I, F> {
+    input: I,
+    filter: F,
+    sample_rate: u32,
+}
+
+#[cfg(feature = "rodio")]
+impl<I, F> FilterSource<I, F>
+where
+    I: Iterator<Item = i16>,
+    F: Filter,
+{
+    pub fn new(input: I, filter: F, sample_rate: u32) -> Self {
+        Self {
+            input,
+            filter,
+            sample_rate,
+        }
+    }
+
+    /// Returns the filter, e.g. to change its parameters while playing
+    pub fn filter_mut(&mut self) -> &mut F {
+        &mut self.filter
+    }
+
+    /// Returns the wrapped input and the filter
+    pub fn into_inner(self) -> (I, F) {
+        (self.input, self.filter)
+    }
+}
+
+#[cfg(feature = "rodio")]
+impl<I, F> Iterator for FilterSource<I, F>
+where
+    I: Iterator<Item = i16>,
+    F: Filter,
+{
+    type Item = i16;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.input.next().map(|x| self.filter.process(Q15(x)).0)
+    }
+}
+
+#[cfg(feature = "rodio")]
+impl<I, F> Source for FilterSource<I, F>
+where
+    I: Iterator<Item = i16>,
+    F: Filter,
+{
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
@@ -0,0 +1,235 @@
+// Chamberlin state variable filter in fixed point. Runs two iterations per
+// sample (double sampling), which keeps it stable up to a third of the sample
+// rate.
+
+use crate::filter::Filter;
+use crate::fixed::{Q15, Q31};
+use crate::freq::MilliHz;
+use crate::osc::wave_table_osc::Sample;
+use crate::osc::wave_tables::SINE_I16;
+
+/// The state is in Q31 scaled down by 2^STATE_SHIFT, which leaves headroom
+/// for resonance peaks of 48 dB and keeps 8 bits more precision than the
+/// Q15 input
+const STATE_SHIFT: u32 = 8;
+
+/// Smallest damping (halved), limits the resonance to keep the filter stable
+const DAMPING_MIN: Q31 = Q31(1 << 23);
+
+/// Filter response returned by `StateVariableFilter` as `Filter`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SvfMode {
+    #[default]
+    Lowpass,
+    Highpass,
+    Bandpass,
+    Notch,
+}
+
+/// All simultaneous outputs of the state variable filter
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SvfOutput {
+    pub lowpass: Q15,
+    pub highpass: Q15,
+    pub bandpass: Q15,
+    pub notch: Q15,
+}
+
+impl SvfOutput {
+    pub fn get(&self, mode: SvfMode) -> Q15 {
+        match mode {
+            SvfMode::Lowpass => self.lowpass,
+            SvfMode::Highpass => self.highpass,
+            SvfMode::Bandpass => self.bandpass,
+            SvfMode::Notch => self.notch,
+        }
+    }
+}
+
+/// Fixed-point Chamberlin state variable filter for Q15 samples
+pub struct StateVariableFilter {
+    mode: SvfMode,
+
+    mcutoff: u32,
+    msample_rate: u32,
+    /// Resonance in Q15
+    resonance: i16,
+
+    /// Frequency coefficient 2 * sin(pi * fc / (2 * fs)), which saturates
+    /// just below 1 at the highest cutoff
+    f: Q31,
+    /// Half the damping 1 / Q, since the damping reaches 2
+    q: Q31,
+
+    low: Q31,
+    band: Q31,
+}
+
+impl StateVariableFilter {
+    pub fn new() -> Self {
+        let mut svf = Self {
+            mode: SvfMode::Lowpass,
+
+            mcutoff: 1_000_000,
+            msample_rate: 44_100_000,
+            resonance: 0,
+
+            f: Q31::ZERO,
+            q: Q31::MAX,
+
+            low: Q31::ZERO,
+            band: Q31::ZERO,
+        };
+        svf.update_f();
+        svf
+    }
+
+    /// Computes the frequency coefficient for the doubled sample rate from the
+    /// sine wavetable. The cutoff is limited to a third of the sample rate.
+    fn update_f(&mut self) {
+        let mcutoff = (self.mcutoff as u64).min(self.msample_rate as u64 / 3);
+        // Phase of pi * fc / (2 * fs) as a fraction of a full period
+        let phi = (mcutoff << 32)
+            .checked_div(4 * self.msample_rate as u64)
+            .unwrap_or(0);
+        let pos = (SINE_I16.len() as u64) * phi;
+        let idx = (pos >> 32) as usize;
+        let frac = (pos as u32) >> 16;
+        let sine = Q31::from(Q15(i16::lerp(SINE_I16[idx], SINE_I16[idx + 1], frac)));
+        self.f = sine + sine;
+    }
+
+    fn update_q(&mut self) {
+        let resonance = Q31::from(Q15(self.resonance.max(0)));
+        self.q = (Q31::MAX - resonance).max(DAMPING_MIN);
+    }
+
+    pub fn set_mode(&mut self, mode: SvfMode) {
+        self.mode = mode;
+    }
+
+    /// Sets the cutoff frequency in millihertz
+    pub fn set_mcutoff(&mut self, mcutoff: u32) {
+        self.mcutoff = mcutoff;
+        self.update_f();
+    }
+
+    /// Sets the cutoff frequency in any unit, e.g. `Hz(1000)`
+    pub fn set_cutoff<F: Into<MilliHz>>(&mut self, cutoff: F) {
+        self.set_mcutoff(cutoff.into().0);
+    }
+
+    /// Sets the resonance in Q15, 0 is no resonance and `i16::MAX` is close
+    /// to self-oscillation
+    pub fn set_resonance(&mut self, resonance: i16) {
+        self.resonance = resonance;
+        self.update_q();
+    }
+
+    pub fn set_msample_rate(&mut self, msample_rate: u32) {
+        self.msample_rate = msample_rate;
+        self.update_f();
+    }
+
+    /// Sets the sample rate in any unit, e.g. `Hz(48000)`
+    pub fn set_sample_rate<R: Into<MilliHz>>(&mut self, sample_rate: R) {
+        self.set_msample_rate(sample_rate.into().0);
+    }
+
+    /// Filters one sample and returns all outputs
+    #[inline]
+    pub fn process_all(&mut self, x: Q15) -> SvfOutput {
+        let x = Q31((x.0 as i32) << (16 - STATE_SHIFT));
+        let mut high = Q31::ZERO;
+        for _ in 0..2 {
+            self.low = self.low.mul_add(self.f, self.band);
+            let damping = self.q * self.band;
+            high = x - self.low - damping - damping;
+            self.band = self.band.mul_add(self.f, high);
+        }
+        let out = |y: Q31| Q15::from(Q31(y.0.saturating_mul(1 << STATE_SHIFT)));
+        SvfOutput {
+            lowpass: out(self.low),
+            highpass: out(high),
+            bandpass: out(self.band),
+            notch: out(high + self.low),
+        }
+    }
+}
+
+impl Default for StateVariableFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Filter for StateVariableFilter {
+    #[inline]
+    fn process(&mut self, x: Q15) -> Q15 {
+        self.process_all(x).get(self.mode)
+    }
+
+    fn reset(&mut self) {
+        self.low = Q31::ZERO;
+        self.band = Q31::ZERO;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::freq::Hz;
+    use crate::osc::wave_table_osc::WaveTableOsc16;
+
+    /// Peak amplitude of a filtered sine after the filter settled
+    fn peak(freq: u32, mode: SvfMode) -> i16 {
+        let mut osc = WaveTableOsc16::new();
+        osc.set_wavetable(&SINE_I16);
+        osc.set_sample_rate(Hz(48000));
+        osc.set_freq(Hz(freq));
+        osc.start();
+
+        let mut svf = StateVariableFilter::new();
+        svf.set_sample_rate(Hz(48000));
+        svf.set_cutoff(Hz(1000));
+        svf.set_resonance(i16::MAX / 4);
+        svf.set_mode(mode);
+
+        osc.map(|x| svf.process(Q15(x)).0)
+            .skip(4800)
+            .take(4800)
+            .map(|y| y.saturating_abs())
+            .max()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_svf_responses() {
+        assert!(peak(100, SvfMode::Lowpass) > 30000);
+        assert!(peak(10000, SvfMode::Lowpass) < 1000);
+        assert!(peak(100, SvfMode::Highpass) < 1000);
+        assert!(peak(10000, SvfMode::Highpass) > 30000);
+        assert!(peak(1000, SvfMode::Bandpass) > 20000);
+        assert!(peak(100, SvfMode::Bandpass) < 10000);
+        assert!(peak(1000, SvfMode::Notch) < 2000);
+    }
+
+    #[test]
+    fn test_svf_stability() {
+        let mut svf = StateVariableFilter::new();
+        svf.set_sample_rate(Hz(48000));
+        svf.set_cutoff(Hz(24000));
+        svf.set_resonance(i16::MAX);
+        for i in 0..48000 {
+            svf.process(if i % 2 == 0 { Q15::MAX } else { Q15::MIN });
+        }
+        svf.process(Q15::ZERO);
+        let out = (0..1000).map(|_| svf.process(Q15::ZERO).0.saturating_abs());
+        assert!(out.last().unwrap() < 100);
+
+        // Without a sample rate, nothing passes the lowpass
+        let mut svf = StateVariableFilter::new();
+        svf.set_sample_rate(Hz(0));
+        assert!((0..100).all(|_| svf.process(Q15::MAX) == Q15::ZERO));
+    }
+}
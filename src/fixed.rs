@@ -0,0 +1,219 @@
+// Provides the fixed-point types Q15 and Q31 for values in [-1, 1). All
+// arithmetic saturates instead of wrapping and multiplications round to
+// nearest, so no operation can overflow.
+
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Signed fixed-point number with 15 fractional bits, the format of all i16
+/// samples of this crate
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Q15(pub i16);
+
+/// Signed fixed-point number with 31 fractional bits
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Q31(pub i32);
+
+/// Implements the arithmetic of the fixed-point type `$q` with the bits
+/// `$t`, using `$w` for intermediate results. `$w` holds a full product,
+/// sums of products saturate before they could overflow.
+macro_rules! impl_fixed {
+    ($q:ident, $t:ty, $w:ty, $frac:expr) => {
+        impl $q {
+            /// Number of fractional bits
+            pub const FRAC_BITS: u32 = $frac;
+            pub const ZERO: Self = Self(0);
+            /// -1
+            pub const MIN: Self = Self(<$t>::MIN);
+            /// The largest value below 1
+            pub const MAX: Self = Self(<$t>::MAX);
+
+            pub const fn from_bits(bits: $t) -> Self {
+                Self(bits)
+            }
+
+            pub const fn to_bits(self) -> $t {
+                self.0
+            }
+
+            /// Saturates a wide intermediate result
+            #[inline]
+            fn saturate(x: $w) -> Self {
+                Self(x.clamp(<$t>::MIN as $w, <$t>::MAX as $w) as $t)
+            }
+
+            #[inline]
+            pub fn saturating_add(self, rhs: Self) -> Self {
+                Self(self.0.saturating_add(rhs.0))
+            }
+
+            #[inline]
+            pub fn saturating_sub(self, rhs: Self) -> Self {
+                Self(self.0.saturating_sub(rhs.0))
+            }
+
+            /// Negates, -1 saturates to `MAX`
+            #[inline]
+            pub fn saturating_neg(self) -> Self {
+                Self(self.0.saturating_neg())
+            }
+
+            /// Multiplies and rounds to nearest, -1 * -1 saturates to `MAX`
+            #[inline]
+            pub fn saturating_mul(self, rhs: Self) -> Self {
+                let product = self.0 as $w * rhs.0 as $w;
+                Self::saturate(product.saturating_add(1 << ($frac - 1)) >> $frac)
+            }
+
+            /// Returns `self + a * b`, rounded once after the addition
+            #[inline]
+            pub fn mul_add(self, a: Self, b: Self) -> Self {
+                // A saturated sum is far out of range and still saturates
+                // correctly after the shift
+                let sum = ((self.0 as $w) << $frac).saturating_add(a.0 as $w * b.0 as $w);
+                Self::saturate(sum.saturating_add(1 << ($frac - 1)) >> $frac)
+            }
+
+            /// Converts from a float, rounding to nearest and saturating
+            /// outside of [-1, 1). NaN converts to zero.
+            pub fn from_f32(x: f32) -> Self {
+                // Float to integer casts saturate
+                Self(libm::round(x as f64 * (1_u64 << $frac) as f64) as $t)
+            }
+
+            pub fn to_f32(self) -> f32 {
+                (self.0 as f64 / (1_u64 << $frac) as f64) as f32
+            }
+        }
+
+        impl Add for $q {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                self.saturating_add(rhs)
+            }
+        }
+
+        impl Sub for $q {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                self.saturating_sub(rhs)
+            }
+        }
+
+        impl Mul for $q {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                self.saturating_mul(rhs)
+            }
+        }
+
+        impl Neg for $q {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                self.saturating_neg()
+            }
+        }
+
+        impl AddAssign for $q {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $q {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl MulAssign for $q {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        /// Reinterprets the bits, no scaling
+        impl From<$t> for $q {
+            fn from(bits: $t) -> Self {
+                Self(bits)
+            }
+        }
+
+        /// Reinterprets the bits, no scaling
+        impl From<$q> for $t {
+            fn from(x: $q) -> Self {
+                x.0
+            }
+        }
+
+        impl From<$q> for f32 {
+            fn from(x: $q) -> Self {
+                x.to_f32()
+            }
+        }
+    };
+}
+
+impl_fixed!(Q15, i16, i64, 15);
+impl_fixed!(Q31, i32, i64, 31);
+
+/// Lossless
+impl From<Q15> for Q31 {
+    fn from(x: Q15) -> Self {
+        Q31((x.0 as i32) << 16)
+    }
+}
+
+/// Rounds to nearest, saturating just below 1
+impl From<Q31> for Q15 {
+    fn from(x: Q31) -> Self {
+        Q15::saturate((x.0 as i64 + (1 << 15)) >> 16)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_saturation() {
+        assert_eq!(Q15::MAX + Q15(1), Q15::MAX);
+        assert_eq!(Q15::MIN - Q15(1), Q15::MIN);
+        assert_eq!(-Q15::MIN, Q15::MAX);
+        assert_eq!(Q15::MIN * Q15::MIN, Q15::MAX);
+        assert_eq!(Q31::MIN * Q31::MIN, Q31::MAX);
+        assert_eq!(Q15::MAX.mul_add(Q15::MAX, Q15::MAX), Q15::MAX);
+        assert_eq!(Q31::MIN.mul_add(Q31::MAX, Q31::MIN), Q31::MIN);
+        assert_eq!(Q31::MAX.mul_add(Q31::MIN, Q31::MIN), Q31::MAX);
+        assert_eq!(Q31::MIN.mul_add(Q31::MIN, Q31::MIN), Q31(0));
+        assert_eq!(Q15::from(Q31::MAX), Q15::MAX);
+        assert_eq!(Q15::from_f32(2.0), Q15::MAX);
+        assert_eq!(Q31::from_f32(-2.0), Q31::MIN);
+        assert_eq!(Q15::from_f32(f32::NAN), Q15::ZERO);
+    }
+
+    #[test]
+    fn test_rounding() {
+        let half = Q15::from_f32(0.5);
+        assert_eq!(half, Q15(1 << 14));
+        assert_eq!(half * half, Q15(1 << 13));
+        // 3/2^15 * 1/2 rounds to 2/2^15, truncation would give 1/2^15
+        assert_eq!(Q15(3) * half, Q15(2));
+        assert_eq!(Q15(-3) * half, Q15(-1));
+        assert_eq!(Q15(100).mul_add(half, Q15(3)), Q15(102));
+        assert_eq!(Q31::from(Q15(-1)), Q31(-1 << 16));
+        assert_eq!(Q15::from(Q31((1 << 16) + (1 << 15))), Q15(2));
+        assert_eq!(Q31::from_f32(0.25).to_f32(), 0.25);
+        assert_eq!(f32::from(Q15::MIN), -1.0);
+    }
+}
@@ -0,0 +1,241 @@
+// Provides frequency unit types with conversions between them. Conversions
+// via `From` round to the nearest value and saturate on overflow, the
+// `checked_*` methods return `None` instead of saturating.
+
+use crate::midi::{note_to_mfreq, A4_MFREQ};
+use core::fmt;
+
+/// Error returned when converting into a unit type fails
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FreqError {
+    /// MIDI note numbers are in `0..=127`
+    NoteOutOfRange,
+}
+
+impl fmt::Display for FreqError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FreqError::NoteOutOfRange => write!(f, "note number is out of range"),
+        }
+    }
+}
+
+impl core::error::Error for FreqError {}
+
+/// Returns 2^(x / 2^16) in Q16 for an exponent x in Q16, saturating at
+/// `u32::MAX`
+pub(crate) fn exp2_q16(x: i32) -> u32 {
+    let int = x >> 16;
+    let frac = (x & 0xffff) as u64;
+    // Polynomial fit of 2^f on [0, 1), error below 0.01 cents
+    let poly = (1 << 16)
+        + ((frac
+            * (45418 + ((frac * (15821 + ((frac * (3413 + ((frac * 884) >> 16))) >> 16))) >> 16)))
+            >> 16);
+    if int >= 16 {
+        u32::MAX
+    } else if int >= 0 {
+        (poly << int).min(u32::MAX as u64) as u32
+    } else if int > -32 {
+        (poly >> -int) as u32
+    } else {
+        0
+    }
+}
+
+/// Returns `x * num / den` rounded to nearest, or `None` if it overflows
+fn checked_scale(x: u32, num: u64, den: u64) -> Option<u32> {
+    u32::try_from((x as u64 * num + den / 2) / den).ok()
+}
+
+/// Frequency in hertz
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Hz(pub u32);
+
+/// Frequency in millihertz
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MilliHz(pub u32);
+
+/// Frequency in binary hertz, i.e. in units of 1/1024 Hz
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BinHz(pub u32);
+
+/// Interval in cents, 1200 cents are one octave
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cents(pub i32);
+
+/// MIDI note number in `0..=127`, 69 is A4
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NoteNumber(u8);
+
+impl Hz {
+    pub fn checked_to_mhz(self) -> Option<MilliHz> {
+        self.0.checked_mul(1000).map(MilliHz)
+    }
+
+    pub fn checked_to_bhz(self) -> Option<BinHz> {
+        self.0.checked_mul(1024).map(BinHz)
+    }
+}
+
+impl MilliHz {
+    pub fn checked_to_bhz(self) -> Option<BinHz> {
+        checked_scale(self.0, 1024, 1000).map(BinHz)
+    }
+
+    /// Transposes the frequency by an interval, or returns `None` if the
+    /// result overflows
+    pub fn checked_transpose(self, cents: Cents) -> Option<Self> {
+        // Octaves in Q16, larger intervals saturate in `exp2_q16` anyway
+        let octaves = ((cents.0 as i64) << 16) / 1200;
+        let ratio = exp2_q16(octaves.clamp(i32::MIN as i64, i32::MAX as i64) as i32);
+        if ratio == u32::MAX && self.0 > 0 {
+            return None;
+        }
+        u32::try_from((self.0 as u64 * ratio as u64 + (1 << 15)) >> 16)
+            .ok()
+            .map(MilliHz)
+    }
+
+    /// Transposes the frequency by an interval, saturating at `u32::MAX`
+    pub fn transpose(self, cents: Cents) -> Self {
+        self.checked_transpose(cents).unwrap_or(MilliHz(u32::MAX))
+    }
+}
+
+impl BinHz {
+    pub fn checked_to_mhz(self) -> Option<MilliHz> {
+        checked_scale(self.0, 1000, 1024).map(MilliHz)
+    }
+}
+
+impl NoteNumber {
+    /// Returns `None` if `note` is above 127
+    pub const fn new(note: u8) -> Option<Self> {
+        if note <= 127 {
+            Some(Self(note))
+        } else {
+            None
+        }
+    }
+
+    pub const fn get(self) -> u8 {
+        self.0
+    }
+
+    /// Returns the 12-TET frequency of the note for the given A4
+    pub fn to_mhz(self, a4: MilliHz) -> MilliHz {
+        MilliHz(note_to_mfreq(self.0, a4.0))
+    }
+}
+
+impl TryFrom<u8> for NoteNumber {
+    type Error = FreqError;
+
+    fn try_from(note: u8) -> Result<Self, Self::Error> {
+        Self::new(note).ok_or(FreqError::NoteOutOfRange)
+    }
+}
+
+impl From<NoteNumber> for u8 {
+    fn from(note: NoteNumber) -> Self {
+        note.0
+    }
+}
+
+impl From<Hz> for MilliHz {
+    fn from(freq: Hz) -> Self {
+        freq.checked_to_mhz().unwrap_or(MilliHz(u32::MAX))
+    }
+}
+
+impl From<BinHz> for MilliHz {
+    fn from(freq: BinHz) -> Self {
+        // Cannot overflow since 1000 < 1024
+        MilliHz(checked_scale(freq.0, 1000, 1024).unwrap_or(u32::MAX))
+    }
+}
+
+/// 12-TET with A4 at 440 Hz
+impl From<NoteNumber> for MilliHz {
+    fn from(note: NoteNumber) -> Self {
+        note.to_mhz(MilliHz(A4_MFREQ))
+    }
+}
+
+impl From<MilliHz> for Hz {
+    fn from(freq: MilliHz) -> Self {
+        Hz(checked_scale(freq.0, 1, 1000).unwrap_or(u32::MAX))
+    }
+}
+
+impl From<BinHz> for Hz {
+    fn from(freq: BinHz) -> Self {
+        Hz(checked_scale(freq.0, 1, 1024).unwrap_or(u32::MAX))
+    }
+}
+
+impl From<Hz> for BinHz {
+    fn from(freq: Hz) -> Self {
+        freq.checked_to_bhz().unwrap_or(BinHz(u32::MAX))
+    }
+}
+
+impl From<MilliHz> for BinHz {
+    fn from(freq: MilliHz) -> Self {
+        freq.checked_to_bhz().unwrap_or(BinHz(u32::MAX))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_exp2() {
+        assert_eq!(exp2_q16(0), 1 << 16);
+        assert_eq!(exp2_q16(1 << 16), 1 << 17);
+        assert_eq!(exp2_q16(-2 << 16), 1 << 14);
+        // 2^(1/12) is one semitone
+        let semitone = exp2_q16((1 << 16) / 12) as f64 / 65536.0;
+        assert!((semitone - 1.059463).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(MilliHz::from(Hz(440)), MilliHz(440_000));
+        assert_eq!(Hz::from(MilliHz(440_499)), Hz(440));
+        assert_eq!(Hz::from(MilliHz(440_500)), Hz(441));
+        assert_eq!(BinHz::from(Hz(3)), BinHz(3072));
+        assert_eq!(MilliHz::from(BinHz(1)), MilliHz(1));
+        assert_eq!(MilliHz::from(BinHz(1024)), MilliHz(1000));
+        assert_eq!(Hz::from(BinHz(1536)), Hz(2));
+
+        // Overflow saturates, checked conversions fail
+        assert_eq!(Hz(5_000_000).checked_to_mhz(), None);
+        assert_eq!(MilliHz::from(Hz(5_000_000)), MilliHz(u32::MAX));
+        assert_eq!(MilliHz(u32::MAX).checked_to_bhz(), None);
+        assert_eq!(Hz::from(MilliHz(u32::MAX)), Hz(4_294_967));
+
+        assert_eq!(
+            MilliHz::from(NoteNumber::new(69).unwrap()),
+            MilliHz(440_000)
+        );
+        assert_eq!(NoteNumber::try_from(128), Err(FreqError::NoteOutOfRange));
+        assert_eq!(
+            NoteNumber::new(81).unwrap().to_mhz(MilliHz(432_000)),
+            MilliHz(864_000)
+        );
+
+        assert_eq!(MilliHz(440_000).transpose(Cents(1200)), MilliHz(880_000));
+        assert_eq!(MilliHz(440_000).transpose(Cents(-2400)), MilliHz(110_000));
+        // The Q16 ratio resolves about 0.03 cents
+        let fifth = MilliHz(440_000).transpose(Cents(700)).0 as i64;
+        assert!((fifth - 659_255).abs() <= 10);
+        assert_eq!(MilliHz(1 << 20).checked_transpose(Cents(12 * 1200)), None);
+        assert_eq!(
+            MilliHz(1 << 20).transpose(Cents(i32::MAX)),
+            MilliHz(u32::MAX)
+        );
+    }
+}
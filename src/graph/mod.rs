@@ -0,0 +1,551 @@
+// Provides a processing graph of nodes with typed audio and control ports.
+// Nodes are processed in topological order, one block of samples at a time.
+
+pub mod nodes;
+
+#[cfg(feature = "rodio")]
+use crate::freq::Hz;
+use crate::freq::MilliHz;
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::any::Any;
+use core::fmt;
+#[cfg(feature = "rodio")]
+use core::time::Duration;
+#[cfg(feature = "rodio")]
+use rodio::source::Source;
+
+/// Maximum number of samples processed per node and call
+pub const BLOCK_LEN: usize = 64;
+
+/// Input or output port of a node. Audio ports carry Q15 samples, control
+/// ports carry one i32 parameter value per sample, e.g. a frequency in mHz.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Port {
+    Audio(usize),
+    Control(usize),
+}
+
+/// Number of ports of each kind of a node
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Ports {
+    pub audio_in: usize,
+    pub control_in: usize,
+    pub audio_out: usize,
+    pub control_out: usize,
+}
+
+/// Error returned when connecting nodes fails
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GraphError {
+    /// The node is not part of the graph
+    InvalidNode,
+    /// The node has no such port
+    InvalidPort,
+    /// Audio ports only connect to audio ports, control ports to control ports
+    KindMismatch,
+    /// The input is already connected
+    InputConnected,
+    /// The connection would create a cycle
+    Cycle,
+}
+
+impl fmt::Display for GraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GraphError::InvalidNode => write!(f, "node is not part of the graph"),
+            GraphError::InvalidPort => write!(f, "node has no such port"),
+            GraphError::KindMismatch => write!(f, "ports are of different kinds"),
+            GraphError::InputConnected => write!(f, "input is already connected"),
+            GraphError::Cycle => write!(f, "connection would create a cycle"),
+        }
+    }
+}
+
+impl core::error::Error for GraphError {}
+
+/// Input buffers of a node for one block
+pub struct Inputs<'a> {
+    audio: &'a [[i16; BLOCK_LEN]],
+    control: &'a [[i32; BLOCK_LEN]],
+    audio_sources: &'a [Option<Connection>],
+    control_sources: &'a [Option<Connection>],
+    len: usize,
+}
+
+impl Inputs<'_> {
+    /// Returns the samples of an audio input, or `None` if it is not
+    /// connected
+    pub fn audio(&self, index: usize) -> Option<&[i16]> {
+        self.audio_sources.get(index)?.as_ref()?;
+        Some(&self.audio[index][..self.len])
+    }
+
+    /// Returns the values of a control input, or `None` if it is not
+    /// connected
+    pub fn control(&self, index: usize) -> Option<&[i32]> {
+        self.control_sources.get(index)?.as_ref()?;
+        Some(&self.control[index][..self.len])
+    }
+
+    /// Returns the number of samples in the block
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// Output buffers of a node for one block
+pub struct Outputs<'a> {
+    audio: &'a mut [[i16; BLOCK_LEN]],
+    control: &'a mut [[i32; BLOCK_LEN]],
+    len: usize,
+}
+
+impl Outputs<'_> {
+    /// Returns the buffer of an audio output, panics if there is no such
+    /// output
+    pub fn audio(&mut self, index: usize) -> &mut [i16] {
+        &mut self.audio[index][..self.len]
+    }
+
+    /// Returns the buffer of a control output, panics if there is no such
+    /// output
+    pub fn control(&mut self, index: usize) -> &mut [i32] {
+        &mut self.control[index][..self.len]
+    }
+
+    /// Returns the number of samples in the block
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// Node of a graph, processing blocks of samples
+pub trait Processor: Any + Send {
+    /// Returns the number of ports, which must not change once the node is
+    /// part of a graph
+    fn ports(&self) -> Ports;
+
+    /// Processes one block. All outputs have to be written.
+    fn process(&mut self, inputs: &Inputs, outputs: &mut Outputs);
+
+    /// Sets the sample rate, which may be fractional
+    fn set_sample_rate(&mut self, _sample_rate: MilliHz) {}
+
+    /// Clears the internal state
+    fn reset(&mut self) {}
+}
+
+/// Handle of a node in a graph
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NodeId(usize);
+
+/// Output an input is connected to, as node index and output index
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Connection {
+    node: usize,
+    output: usize,
+}
+
+struct Node {
+    processor: Box<dyn Processor>,
+    audio_sources: Vec<Option<Connection>>,
+    control_sources: Vec<Option<Connection>>,
+    audio_in: Vec<[i16; BLOCK_LEN]>,
+    control_in: Vec<[i32; BLOCK_LEN]>,
+    audio_out: Vec<[i16; BLOCK_LEN]>,
+    control_out: Vec<[i32; BLOCK_LEN]>,
+}
+
+/// Graph of processors, itself a mono source of the samples of one audio
+/// output
+pub struct Graph {
+    nodes: Vec<Node>,
+    /// Node indices in processing order
+    order: Vec<usize>,
+    output: Option<Connection>,
+    sample_rate: MilliHz,
+
+    /// Position of `_next` in the last block of the output
+    pos: usize,
+}
+
+impl Graph {
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            order: Vec::new(),
+            output: None,
+            sample_rate: MilliHz(44_100_000),
+
+            pos: BLOCK_LEN,
+        }
+    }
+
+    /// Adds a node and returns its handle
+    pub fn add<P: Processor>(&mut self, mut processor: P) -> NodeId {
+        processor.set_sample_rate(self.sample_rate);
+        let ports = processor.ports();
+        self.nodes.push(Node {
+            processor: Box::new(processor),
+            audio_sources: vec![None; ports.audio_in],
+            control_sources: vec![None; ports.control_in],
+            audio_in: vec![[0; BLOCK_LEN]; ports.audio_in],
+            control_in: vec![[0; BLOCK_LEN]; ports.control_in],
+            audio_out: vec![[0; BLOCK_LEN]; ports.audio_out],
+            control_out: vec![[0; BLOCK_LEN]; ports.control_out],
+        });
+        let index = self.nodes.len() - 1;
+        self.order.push(index);
+        NodeId(index)
+    }
+
+    /// Returns a node, e.g. to change its parameters, or `None` if it is not
+    /// of type `P`
+    pub fn node<P: Processor>(&self, id: NodeId) -> Option<&P> {
+        let processor: &dyn Any = self.nodes.get(id.0)?.processor.as_ref();
+        processor.downcast_ref()
+    }
+
+    pub fn node_mut<P: Processor>(&mut self, id: NodeId) -> Option<&mut P> {
+        let processor: &mut dyn Any = self.nodes.get_mut(id.0)?.processor.as_mut();
+        processor.downcast_mut()
+    }
+
+    /// Returns the source of an input slot of a node
+    fn input_mut(
+        &mut self,
+        id: NodeId,
+        input: Port,
+    ) -> Result<&mut Option<Connection>, GraphError> {
+        let node = self.nodes.get_mut(id.0).ok_or(GraphError::InvalidNode)?;
+        match input {
+            Port::Audio(i) => node.audio_sources.get_mut(i),
+            Port::Control(i) => node.control_sources.get_mut(i),
+        }
+        .ok_or(GraphError::InvalidPort)
+    }
+
+    /// Checks that a node has an output
+    fn check_output(&self, id: NodeId, output: Port) -> Result<Connection, GraphError> {
+        let node = self.nodes.get(id.0).ok_or(GraphError::InvalidNode)?;
+        let (index, len) = match output {
+            Port::Audio(i) => (i, node.audio_out.len()),
+            Port::Control(i) => (i, node.control_out.len()),
+        };
+        if index >= len {
+            return Err(GraphError::InvalidPort);
+        }
+        Ok(Connection {
+            node: id.0,
+            output: index,
+        })
+    }
+
+    /// Connects an output of one node to an input of the same kind of
+    /// another node
+    pub fn connect(
+        &mut self,
+        from: NodeId,
+        output: Port,
+        to: NodeId,
+        input: Port,
+    ) -> Result<(), GraphError> {
+        if matches!(output, Port::Audio(_)) != matches!(input, Port::Audio(_)) {
+            return Err(GraphError::KindMismatch);
+        }
+        let source = self.check_output(from, output)?;
+        let slot = self.input_mut(to, input)?;
+        if slot.is_some() {
+            return Err(GraphError::InputConnected);
+        }
+        *slot = Some(source);
+        if !self.update_order() {
+            *self.input_mut(to, input)? = None;
+            return Err(GraphError::Cycle);
+        }
+        Ok(())
+    }
+
+    /// Disconnects an input, unconnected inputs are `None` in `Inputs`
+    pub fn disconnect(&mut self, to: NodeId, input: Port) -> Result<(), GraphError> {
+        *self.input_mut(to, input)? = None;
+        Ok(())
+    }
+
+    /// Selects the audio output the graph produces
+    pub fn set_output(&mut self, id: NodeId, output: Port) -> Result<(), GraphError> {
+        if !matches!(output, Port::Audio(_)) {
+            return Err(GraphError::KindMismatch);
+        }
+        self.output = Some(self.check_output(id, output)?);
+        Ok(())
+    }
+
+    /// Sorts the nodes topologically (Kahn's algorithm), returns `false` if
+    /// the graph has a cycle
+    fn update_order(&mut self) -> bool {
+        let sources = |node: &Node| {
+            node.audio_sources
+                .iter()
+                .chain(node.control_sources.iter())
+                .flatten()
+                .map(|source| source.node)
+                .collect::<Vec<_>>()
+        };
+        let mut pending: Vec<usize> = self.nodes.iter().map(|n| sources(n).len()).collect();
+        let mut ready: Vec<usize> = (0..self.nodes.len())
+            .filter(|&i| pending[i] == 0)
+            .rev()
+            .collect();
+        let mut order = Vec::with_capacity(self.nodes.len());
+        while let Some(i) = ready.pop() {
+            order.push(i);
+            for (j, node) in self.nodes.iter().enumerate() {
+                for _ in sources(node).into_iter().filter(|&s| s == i) {
+                    pending[j] -= 1;
+                    if pending[j] == 0 {
+                        ready.push(j);
+                    }
+                }
+            }
+        }
+        if order.len() < self.nodes.len() {
+            return false;
+        }
+        self.order = order;
+        true
+    }
+
+    /// Sets the sample rate of all nodes, including nodes added later
+    pub fn set_sample_rate<R: Into<MilliHz>>(&mut self, sample_rate: R) {
+        let sample_rate = sample_rate.into();
+        self.sample_rate = sample_rate;
+        for node in self.nodes.iter_mut() {
+            node.processor.set_sample_rate(sample_rate);
+        }
+    }
+
+    /// Resets all nodes
+    pub fn reset(&mut self) {
+        for node in self.nodes.iter_mut() {
+            node.processor.reset();
+        }
+        self.pos = BLOCK_LEN;
+    }
+
+    /// Processes all nodes for `len <= BLOCK_LEN` samples
+    fn run_block(&mut self, len: usize) {
+        for &i in self.order.iter() {
+            // Copy the outputs of earlier nodes into the inputs
+            for port in 0..self.nodes[i].audio_sources.len() {
+                if let Some(source) = self.nodes[i].audio_sources[port] {
+                    let block = self.nodes[source.node].audio_out[source.output];
+                    self.nodes[i].audio_in[port][..len].copy_from_slice(&block[..len]);
+                }
+            }
+            for port in 0..self.nodes[i].control_sources.len() {
+                if let Some(source) = self.nodes[i].control_sources[port] {
+                    let block = self.nodes[source.node].control_out[source.output];
+                    self.nodes[i].control_in[port][..len].copy_from_slice(&block[..len]);
+                }
+            }
+
+            let node = &mut self.nodes[i];
+            let inputs = Inputs {
+                audio: &node.audio_in,
+                control: &node.control_in,
+                audio_sources: &node.audio_sources,
+                control_sources: &node.control_sources,
+                len,
+            };
+            let mut outputs = Outputs {
+                audio: &mut node.audio_out,
+                control: &mut node.control_out,
+                len,
+            };
+            node.processor.process(&inputs, &mut outputs);
+        }
+    }
+
+    /// Fills `out` with samples of the output, silence without an output.
+    /// Samples of a block partly read by `_next` are returned first.
+    pub fn process(&mut self, out: &mut [i16]) {
+        let pending = (BLOCK_LEN - self.pos).min(out.len());
+        let (pending, out) = out.split_at_mut(pending);
+        for y in pending.iter_mut() {
+            *y = self._next();
+        }
+        if out.is_empty() {
+            return;
+        }
+        for chunk in out.chunks_mut(BLOCK_LEN) {
+            self.run_block(chunk.len());
+            match self.output {
+                Some(source) => chunk.copy_from_slice(
+                    &self.nodes[source.node].audio_out[source.output][..chunk.len()],
+                ),
+                None => chunk.fill(0),
+            }
+        }
+        self.pos = BLOCK_LEN;
+    }
+
+    /// Returns the next sample of the output, processing a block whenever
+    /// the last one is used up
+    #[inline]
+    pub fn _next(&mut self) -> i16 {
+        if self.pos == BLOCK_LEN {
+            self.run_block(BLOCK_LEN);
+            self.pos = 0;
+        }
+        let y = match self.output {
+            Some(source) => self.nodes[source.node].audio_out[source.output][self.pos],
+            None => 0,
+        };
+        self.pos += 1;
+        y
+    }
+}
+
+impl Default for Graph {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for Graph {
+    type Item = i16;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self._next())
+    }
+}
+
+#[cfg(feature = "rodio")]
+impl Source for Graph {
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        Hz::from(self.sample_rate).0
+    }
+
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::nodes::{Constant, Mixer};
+    use super::*;
+    use crate::freq::Hz;
+    use crate::osc::wave_table_osc::WaveTableOsc16;
+    use crate::osc::wave_tables::SINE_I16;
+
+    #[test]
+    fn test_connect() {
+        let mut graph = Graph::new();
+        let mixer = graph.add(Mixer::new(2));
+        let a = graph.add(Constant::new(1000));
+        let b = graph.add(Constant::new(2000));
+        let gain = graph.add(Constant::new(1 << 14));
+        graph.set_output(mixer, Port::Audio(0)).unwrap();
+
+        // Audio outputs only connect to audio inputs
+        assert_eq!(
+            graph.connect(a, Port::Control(0), mixer, Port::Audio(0)),
+            Err(GraphError::KindMismatch)
+        );
+        graph
+            .connect(a, Port::Audio(0), mixer, Port::Audio(0))
+            .unwrap();
+        graph
+            .connect(b, Port::Audio(0), mixer, Port::Audio(1))
+            .unwrap();
+        assert_eq!(
+            graph.connect(b, Port::Audio(0), mixer, Port::Audio(1)),
+            Err(GraphError::InputConnected)
+        );
+        assert_eq!(
+            graph.connect(a, Port::Audio(0), mixer, Port::Audio(2)),
+            Err(GraphError::InvalidPort)
+        );
+
+        // The mixer was added first but is processed last
+        assert_eq!(graph._next(), 3000);
+        graph
+            .connect(gain, Port::Control(0), mixer, Port::Control(0))
+            .unwrap();
+        // The rest of the block read by `_next` comes first, nothing is lost
+        let mut out = [0; 100];
+        graph.process(&mut out);
+        assert!(out[..BLOCK_LEN - 1].iter().all(|&y| y == 3000));
+        assert!(out[BLOCK_LEN - 1..].iter().all(|&y| y == 1500));
+
+        graph.node_mut::<Constant>(b).unwrap().set_value(-1000);
+        assert_eq!(graph._next(), 0);
+        assert!(graph.node_mut::<Mixer>(b).is_none());
+    }
+
+    #[test]
+    fn test_mixed_apis() {
+        fn sine_graph() -> Graph {
+            let mut osc = WaveTableOsc16::new();
+            osc.set_wavetable(&SINE_I16);
+            osc.set_freq(Hz(1000));
+            osc.start();
+            let mut graph = Graph::new();
+            graph.set_sample_rate(Hz(48000));
+            let osc = graph.add(osc);
+            graph.set_output(osc, Port::Audio(0)).unwrap();
+            graph
+        }
+
+        let mut reference = vec![0; 215];
+        sine_graph().process(&mut reference);
+
+        let mut graph = sine_graph();
+        let mut out = Vec::new();
+        out.extend((0..10).map(|_| graph._next()));
+        let mut block = [0; 200];
+        graph.process(&mut block);
+        out.extend(block);
+        out.extend((0..5).map(|_| graph._next()));
+        assert_eq!(out, reference);
+    }
+
+    #[test]
+    fn test_cycle() {
+        let mut graph = Graph::new();
+        let a = graph.add(Mixer::new(1));
+        let b = graph.add(Mixer::new(1));
+        graph.connect(a, Port::Audio(0), b, Port::Audio(0)).unwrap();
+        assert_eq!(
+            graph.connect(b, Port::Audio(0), a, Port::Audio(0)),
+            Err(GraphError::Cycle)
+        );
+        // The failed connection was removed again
+        graph.disconnect(a, Port::Audio(0)).unwrap();
+        assert_eq!(
+            graph.connect(a, Port::Audio(0), a, Port::Audio(0)),
+            Err(GraphError::Cycle)
+        );
+    }
+}
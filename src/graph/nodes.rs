@@ -0,0 +1,282 @@
+// Implements `Processor` for the oscillators, envelopes and filters of this
+// crate and provides the constant and mixer nodes to combine them.
+
+use crate::envelope::Envelope;
+use crate::filter::svf::StateVariableFilter;
+use crate::filter::Filter;
+use crate::fixed::Q15;
+use crate::freq::MilliHz;
+use crate::graph::{Inputs, Outputs, Ports, Processor};
+use crate::osc::wave_table_osc::WaveTableOsc16;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Constant value on audio output 0 (saturated to Q15) and control output 0
+pub struct Constant {
+    value: i32,
+}
+
+impl Constant {
+    pub fn new(value: i32) -> Self {
+        Self { value }
+    }
+
+    pub fn set_value(&mut self, value: i32) {
+        self.value = value;
+    }
+}
+
+impl Processor for Constant {
+    fn ports(&self) -> Ports {
+        Ports {
+            audio_out: 1,
+            control_out: 1,
+            ..Ports::default()
+        }
+    }
+
+    fn process(&mut self, _inputs: &Inputs, outputs: &mut Outputs) {
+        let sample = self.value.clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+        outputs.audio(0).fill(sample);
+        outputs.control(0).fill(self.value);
+    }
+}
+
+/// Sums its audio inputs with a gain each. Control input 0 scales the sum in
+/// Q15, e.g. driven by an envelope.
+pub struct Mixer {
+    /// Gains in Q15, `1 << 15` is unity
+    gains: Vec<i32>,
+}
+
+impl Mixer {
+    /// Mixer of `inputs` audio inputs at unity gain
+    pub fn new(inputs: usize) -> Self {
+        Self {
+            gains: vec![1 << 15; inputs],
+        }
+    }
+
+    /// Sets the gain of an input in Q15, where `1 << 15` is unity
+    pub fn set_gain(&mut self, input: usize, gain: i32) {
+        if let Some(g) = self.gains.get_mut(input) {
+            *g = gain;
+        }
+    }
+}
+
+impl Processor for Mixer {
+    fn ports(&self) -> Ports {
+        Ports {
+            audio_in: self.gains.len(),
+            control_in: 1,
+            audio_out: 1,
+            control_out: 0,
+        }
+    }
+
+    fn process(&mut self, inputs: &Inputs, outputs: &mut Outputs) {
+        let mut sum = [0_i64; super::BLOCK_LEN];
+        for (i, &gain) in self.gains.iter().enumerate() {
+            if let Some(x) = inputs.audio(i) {
+                for (s, &x) in sum.iter_mut().zip(x) {
+                    *s += (x as i64 * gain as i64) >> 15;
+                }
+            }
+        }
+        if let Some(level) = inputs.control(0) {
+            for (s, &level) in sum.iter_mut().zip(level) {
+                *s = (*s * level as i64) >> 15;
+            }
+        }
+        for (y, s) in outputs.audio(0).iter_mut().zip(sum) {
+            *y = s.clamp(i16::MIN as i64, i16::MAX as i64) as i16;
+        }
+    }
+}
+
+/// Audio input 0 is phase modulation (full scale is half a period), control
+/// input 0 the frequency in mHz and control input 1 exponential FM in Q16
+/// octaves. Stopped oscillators output silence.
+impl Processor for WaveTableOsc16 {
+    fn ports(&self) -> Ports {
+        Ports {
+            audio_in: 1,
+            control_in: 2,
+            audio_out: 1,
+            control_out: 0,
+        }
+    }
+
+    fn process(&mut self, inputs: &Inputs, outputs: &mut Outputs) {
+        let phase_mod = inputs.audio(0);
+        let mfreq = inputs.control(0);
+        let fm_exp = inputs.control(1);
+        if phase_mod.is_none() && mfreq.is_none() && fm_exp.is_none() {
+            self.fill(outputs.audio(0));
+            return;
+        }
+        for (i, y) in outputs.audio(0).iter_mut().enumerate() {
+            if let Some(x) = phase_mod {
+                self.set_phase_mod((x[i] as i32) << 16);
+            }
+            // Frequency changes update the mipmap level, skip unchanged ones
+            if let Some(mfreq) = mfreq {
+                let mfreq = mfreq[i].max(0) as u32;
+                if mfreq != self.freq().0 {
+                    self.set_mfreq(mfreq);
+                }
+            }
+            if let Some(octaves) = fm_exp {
+                self.set_fm_exp(octaves[i]);
+            }
+            *y = self._next().unwrap_or(0);
+        }
+    }
+
+    fn set_sample_rate(&mut self, sample_rate: MilliHz) {
+        WaveTableOsc16::set_sample_rate(self, sample_rate);
+    }
+
+    fn reset(&mut self) {
+        WaveTableOsc16::reset(self);
+    }
+}
+
+/// Control input 0 is the gate, open while positive. Control output 0 is the
+/// level in Q15 and audio output 0 audio input 0 scaled by the level.
+impl Processor for Envelope {
+    fn ports(&self) -> Ports {
+        Ports {
+            audio_in: 1,
+            control_in: 1,
+            audio_out: 1,
+            control_out: 1,
+        }
+    }
+
+    fn process(&mut self, inputs: &Inputs, outputs: &mut Outputs) {
+        let gate = inputs.control(0);
+        let mut levels = [0_i16; super::BLOCK_LEN];
+        for (i, level) in levels[..inputs.len()].iter_mut().enumerate() {
+            if let Some(gate) = gate {
+                match (gate[i] > 0, self.gate()) {
+                    (true, false) => self.gate_on(),
+                    (false, true) => self.gate_off(),
+                    _ => {}
+                }
+            }
+            *level = self._next();
+        }
+        for (y, &level) in outputs.control(0).iter_mut().zip(levels.iter()) {
+            *y = level as i32;
+        }
+        let x = inputs.audio(0);
+        for (i, y) in outputs.audio(0).iter_mut().enumerate() {
+            *y = x.map_or(0, |x| (Q15(x[i]) * Q15(levels[i])).0);
+        }
+    }
+
+    fn set_sample_rate(&mut self, sample_rate: MilliHz) {
+        Envelope::set_sample_rate(self, sample_rate);
+    }
+
+    fn reset(&mut self) {
+        Envelope::reset(self);
+    }
+}
+
+/// Audio input 0 is filtered by the selected mode. Control input 0 is the
+/// cutoff in mHz and control input 1 the resonance in Q15.
+impl Processor for StateVariableFilter {
+    fn ports(&self) -> Ports {
+        Ports {
+            audio_in: 1,
+            control_in: 2,
+            audio_out: 1,
+            control_out: 0,
+        }
+    }
+
+    fn process(&mut self, inputs: &Inputs, outputs: &mut Outputs) {
+        let x = inputs.audio(0);
+        let mcutoff = inputs.control(0);
+        let resonance = inputs.control(1);
+        for (i, y) in outputs.audio(0).iter_mut().enumerate() {
+            if let Some(mcutoff) = mcutoff {
+                self.set_mcutoff(mcutoff[i].max(0) as u32);
+            }
+            if let Some(resonance) = resonance {
+                self.set_resonance(resonance[i].clamp(i16::MIN as i32, i16::MAX as i32) as i16);
+            }
+            *y = Filter::process(self, Q15(x.map_or(0, |x| x[i]))).0;
+        }
+    }
+
+    fn set_sample_rate(&mut self, sample_rate: MilliHz) {
+        StateVariableFilter::set_sample_rate(self, sample_rate);
+    }
+
+    fn reset(&mut self) {
+        Filter::reset(self);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::freq::Hz;
+    use crate::graph::{Graph, Port};
+    use crate::osc::wave_tables::SINE_I16;
+
+    #[test]
+    fn test_synth_patch() {
+        // Oscillator -> envelope -> filter, with the envelope gated and the
+        // oscillator tuned by constants
+        let mut graph = Graph::new();
+        graph.set_sample_rate(Hz(48000));
+        let mut osc = WaveTableOsc16::new();
+        osc.set_wavetable(&SINE_I16);
+        osc.start();
+        let osc = graph.add(osc);
+        let freq = graph.add(Constant::new(1_000_000));
+        let gate = graph.add(Constant::new(1));
+        let env = graph.add(Envelope::adsr(10, 0, i16::MAX, 10));
+        let mut svf = StateVariableFilter::new();
+        svf.set_cutoff(Hz(10_000));
+        let svf = graph.add(svf);
+        graph
+            .connect(freq, Port::Control(0), osc, Port::Control(0))
+            .unwrap();
+        graph
+            .connect(osc, Port::Audio(0), env, Port::Audio(0))
+            .unwrap();
+        graph
+            .connect(gate, Port::Control(0), env, Port::Control(0))
+            .unwrap();
+        graph
+            .connect(env, Port::Audio(0), svf, Port::Audio(0))
+            .unwrap();
+        graph.set_output(svf, Port::Audio(0)).unwrap();
+
+        let mut out = vec![0; 4800];
+        graph.process(&mut out);
+        assert_eq!(
+            graph.node::<WaveTableOsc16>(osc).unwrap().freq().0,
+            1_000_000
+        );
+        assert!(out[..48].iter().all(|y| y.abs() < 10000));
+        assert!(out[2400..].iter().any(|&y| y > 20000));
+
+        // Closing the gate releases the envelope
+        graph.node_mut::<Constant>(gate).unwrap().set_value(0);
+        graph.process(&mut out);
+        assert!(out[2400..].iter().all(|&y| y.abs() < 10));
+
+        // Fractional sample rates reach the nodes unrounded
+        graph.set_sample_rate(MilliHz(44_100_500));
+        let osc = graph.node_mut::<WaveTableOsc16>(osc).unwrap();
+        osc.set_freq(MilliHz(22_050_250));
+        assert_eq!(osc.delta_phi(), 1 << 31);
+    }
+}
@@ -1,5 +1,24 @@
+// The DSP core only needs `alloc`. WAV files and rendering need the `std`
+// feature, rodio playback the `rodio` feature.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+pub mod envelope;
+pub mod filter;
+pub mod fixed;
+pub mod freq;
+pub mod graph;
 pub mod linexp;
+pub mod midi;
+pub mod modulation;
 pub mod osc;
+#[cfg(feature = "std")]
+pub mod render;
+pub mod tuning;
+pub mod voice;
+#[cfg(feature = "std")]
+pub mod wav;
 // use osc::wave_table_osc;
 // pub mod wave_table_osc;
 // pub use wave_tables;
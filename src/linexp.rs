@@ -1,74 +1,172 @@
 // Provides a struct that implements a parameterized piecewise linear
 // approximation of an exponential curve. Can be used for envelops.
 
+use crate::fixed::Q31;
+use core::fmt;
+
+/// Error returned by `LinExp::set_sigma`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LinExpError {
+    /// Sigma is outside of `0..sigma_max`
+    SigmaOutOfRange,
+}
+
+impl fmt::Display for LinExpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinExpError::SigmaOutOfRange => write!(f, "sigma is out of range"),
+        }
+    }
+}
+
+impl core::error::Error for LinExpError {}
+
 pub struct LinExp<T> {
     sigma: T,
     norm: T,
     sigma_max: T,
 }
 
-impl LinExp<i32> {
-    pub fn new() -> Self {
-        Self {
-            sigma: 0,
-            norm: (1 << 15),
-            sigma_max: (1 << 15) / 2,
-        }
-    }
-    pub fn y(&self, x: i32) -> i32 {
-        // x is normalized to [0..norm]
-        // Analytically sigma is in [-0.5..0]. Here we map this range to
-        // [0..norm/2] and hence sigma has to be normalized by -norm.
-        let norm = self.norm;
-        return match x {
-            // Negative out of bound
-            i if i < 0 => norm,
-            // A
-            // sigma/2 + 1/4
-            i if i <= norm / 4 - &self.sigma / 2 => {
-                println!("A({}): {} of {}", norm / 4 - &self.sigma / 2, i, norm);
-                // x*(4*sigma - 1)/(2*sigma + 1) + 1
-                (x * (4 * &self.sigma + norm)) / (2 * &self.sigma - norm) + norm
+/// Evaluates the falling curve at `x` in (0, 1) for the curvature `sigma`
+/// in [0, 1/2). The four linear segments run through the points (0, 1),
+/// (1/4 - sigma/2, 3/4 - sigma), (1/2 - sigma, 1/2 - sigma),
+/// (3/4 - sigma, 1/4 - sigma/2) and (1, 0).
+fn curve(x: Q31, sigma: Q31) -> Q31 {
+    const QUARTER: Q31 = Q31(1 << 29);
+    let x_a = QUARTER - Q31(sigma.0 / 2);
+    let x_b = QUARTER + QUARTER - sigma;
+    let x_c = x_b + QUARTER;
+    // End of the segment, the curve at its end and its fall from the start
+    let (x0, x1, y1, fall) = if x <= x_a {
+        (Q31::ZERO, x_a, x_c, QUARTER + sigma)
+    } else if x <= x_b {
+        (x_a, x_b, x_b, QUARTER)
+    } else if x <= x_c {
+        (x_b, x_c, x_a, x_a)
+    } else {
+        (x_c, Q31::MAX, Q31::ZERO, x_a)
+    };
+    // Remaining part of the segment, 1 at its start. The last segment ends
+    // one step early at `MAX`, which the rounding of the result hides.
+    let rest = (((x1.0 - x.0) as i64) << 31) / (x1.0 - x0.0) as i64;
+    y1.mul_add(fall, Q31(rest.min(i32::MAX as i64) as i32))
+}
+
+/// Implements `LinExp` for the sample type `$t` with a norm of `2^$bits`.
+/// The curve is evaluated in Q31, normalized by the norm.
+macro_rules! impl_linexp {
+    ($t:ty, $bits:expr) => {
+        impl LinExp<$t> {
+            /// Shift from the normalized sample type to Q31
+            const SHIFT: u32 = 31 - $bits;
+
+            pub fn new() -> Self {
+                Self {
+                    sigma: 0,
+                    norm: 1 << $bits,
+                    sigma_max: 1 << ($bits - 1),
+                }
             }
-            // B
-            // sigma + 1/2
-            i if i <= norm / 2 - &self.sigma => {
-                println!("B({}): {} of {}", norm / 2 - &self.sigma, i, norm);
-                // x*(- 1)/(2*sigma + 1) + 1 + sigma
-                (norm * x) / (2 * &self.sigma - norm) + norm - &self.sigma
+
+            /// Evaluates the curve. Does neither allocate nor block and can be
+            /// used in audio callbacks.
+            pub fn y(&self, x: $t) -> $t {
+                // x is normalized to [0..norm]
+                // Analytically sigma is in [-0.5..0]. Here we map this range to
+                // [0..norm/2] and hence sigma has to be normalized by -norm.
+                if x <= 0 {
+                    return self.norm;
+                }
+                if x >= self.norm {
+                    return 0;
+                }
+                let to_q31 = |v: $t| Q31(((v as i64) << Self::SHIFT) as i32);
+                let y = curve(to_q31(x), to_q31(self.sigma)).0 as i64;
+                // Rounds to nearest, the shift may be zero
+                ((y + ((1 << Self::SHIFT) >> 1)) >> Self::SHIFT) as $t
             }
-            // C
-            // sigma + 3/4
-            i if i <= (3 * norm) / 4 - &self.sigma => {
-                println!("C({}): {} of {}", (3 * norm) / 4 - &self.sigma, i, norm);
-                // x*(-2*sigma - 1) + (1 + sigma)*(2*sigma + 1)
-                ((x * (2 * &self.sigma - norm)) + ((norm - &self.sigma) * (norm - 2 * &self.sigma)))
-                    / norm
+
+            /// Sets the curvature, 0 is linear and values towards `sigma_max`
+            /// approach an exponential. Out of range values are rejected and
+            /// leave the curve unchanged.
+            pub fn set_sigma(&mut self, sigma: $t) -> Result<(), LinExpError> {
+                if sigma >= 0 && sigma < self.sigma_max {
+                    self.sigma = sigma;
+                    Ok(())
+                } else {
+                    Err(LinExpError::SigmaOutOfRange)
+                }
             }
-            // D
-            // 1
-            i if i <= norm => {
-                println!("D({}): {} of {}", norm, i, norm);
-                // x*(-2*sigma - 1)/(1 - 4*sigma) + (1 + 2*sigma)/(1 - 4*sigma)
-                ((x * (2 * &self.sigma - norm)) + (norm * (norm - 2 * &self.sigma)))
-                    / (norm + 4 * &self.sigma)
+
+            pub fn get_sigma(&self) -> $t {
+                self.sigma
+            }
+
+            pub fn get_sigma_max(&self) -> $t {
+                self.sigma_max
+            }
+
+            pub fn get_norm(&self) -> $t {
+                self.norm
             }
-            // Positive out of bound
-            _ => 0,
-        };
-    }
-    pub fn set_sigma(&mut self, sigma: i32) {
-        if sigma >= 0 && sigma < self.sigma_max {
-            self.sigma = sigma;
-        } else {
-            // TODO should be an error handle here if performance allows it.
-            self.sigma = 0;
         }
+
+        impl Default for LinExp<$t> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+    };
+}
+
+impl_linexp!(i16, 14);
+impl_linexp!(i32, 15);
+impl_linexp!(i64, 31);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_linexp_widths() {
+        let mut linexp16 = LinExp::<i16>::new();
+        let mut linexp32 = LinExp::<i32>::new();
+        let mut linexp64 = LinExp::<i64>::new();
+        linexp16.set_sigma(linexp16.get_sigma_max() - 1).unwrap();
+        linexp32.set_sigma(linexp32.get_sigma_max() - 1).unwrap();
+        linexp64.set_sigma(linexp64.get_sigma_max() - 1).unwrap();
+
+        // End points and monotony for the steepest curve
+        for (y0, y1) in [
+            (linexp16.y(0) as i64, linexp16.y(linexp16.get_norm()) as i64),
+            (linexp32.y(0) as i64, linexp32.y(linexp32.get_norm()) as i64),
+            (linexp64.y(0), linexp64.y(linexp64.get_norm())),
+        ] {
+            assert!(y0 > 0);
+            assert_eq!(y1, 0);
+        }
+        let norm = linexp64.get_norm();
+        let ys: Vec<i64> = (0..=16).map(|i| linexp64.y(i * norm / 16)).collect();
+        assert!(ys.windows(2).all(|w| w[0] >= w[1]));
+        let norm16 = linexp16.get_norm();
+        let ys: Vec<i16> = (0..=norm16).map(|x| linexp16.y(x)).collect();
+        assert!(ys.windows(2).all(|w| w[0] >= w[1] && w[1] >= 0));
+
+        // The curves agree across widths
+        let y16 = linexp16.y(linexp16.get_norm() / 4) as i64 * (1 << 17);
+        let y64 = linexp64.y(norm / 4);
+        assert!((y16 - y64).abs() < 1 << 20);
     }
-    pub fn get_sigma_max(&self) -> i32 {
-        self.sigma_max
-    }
-    pub fn get_norm(&self) -> i32 {
-        self.norm
+
+    #[test]
+    fn test_set_sigma() {
+        let mut linexp = LinExp::<i32>::new();
+        assert_eq!(linexp.set_sigma(100), Ok(()));
+        assert_eq!(
+            linexp.set_sigma(linexp.get_sigma_max()),
+            Err(LinExpError::SigmaOutOfRange)
+        );
+        assert_eq!(linexp.set_sigma(-1), Err(LinExpError::SigmaOutOfRange));
+        assert_eq!(linexp.get_sigma(), 100);
     }
 }
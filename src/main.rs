@@ -1,40 +1,185 @@
 use core::time::Duration;
-use rain::{linexp::LinExp, osc::wave_table_osc::WaveTableOsc16, osc::wave_tables::SINE_I16};
+use rain::{
+    freq::Hz,
+    midi::smf::{Smf, SmfPlayer},
+    osc::wave_table_osc::WaveTableOsc16,
+    osc::wave_tables::SINE_I16,
+    render::render_to_file,
+    tuning::{KeyboardMapping, Scale, Tuning},
+    wav::{WavFormat, WavSpec},
+};
+#[cfg(feature = "rodio")]
 use rodio::{source::Source, OutputStream};
+use std::process::exit;
 
-fn main() {
-    // print!("Hello World!\n");
-    // print!("{:?}\n", wavetables::SINE_I8);
+const USAGE: &str = "usage: rain [play]
+       rain render <out.wav> [--seconds <s>] [--freq <hz>] [--format <pcm16|pcm24|pcm32|float32>]
+       rain smf <in.mid> <out.wav> [--format <pcm16|pcm24|pcm32|float32>] [--scl <scale.scl>] [--kbm <mapping.kbm>]";
+
+/// Sample rate of playback and rendering
+const SAMPLE_RATE: u32 = 44100;
+
+/// Number of voices of the synth playing MIDI files
+const SMF_VOICES: usize = 16;
 
-    // let mut linexp = LinExp::new();
-    // let sigma_max = linexp.get_sigma_max();
-    // let norm = linexp.get_norm();
-    // linexp.set_sigma(sigma_max - sigma_max / 16);
-    // for x in (0..norm + 1).step_by(norm as usize / 16) {
-    //     print!("{:?}\n", linexp.y(x));
-    // }
+/// Longest release tail rendered after the last event of a MIDI file
+const SMF_TAIL: Duration = Duration::from_secs(10);
 
+fn sine(freq: u32) -> WaveTableOsc16 {
     let mut sine = WaveTableOsc16::new();
     sine.set_wavetable(&SINE_I16);
-    sine.set_freq(440);
-    sine.set_sample_rate(44100);
+    sine.set_freq(Hz(freq));
+    sine.set_sample_rate(Hz(SAMPLE_RATE));
     sine.set_repeat(true);
     sine.start();
+    sine
+}
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("error: {}\n{}", message, USAGE);
+    exit(1);
+}
+
+fn parse_format(format: &str) -> WavFormat {
+    match format {
+        "pcm16" => WavFormat::Pcm16,
+        "pcm24" => WavFormat::Pcm24,
+        "pcm32" => WavFormat::Pcm32,
+        "float32" => WavFormat::Float32,
+        _ => usage_error("invalid format"),
+    }
+}
+
+/// Returns the layout of rendered mono WAV files
+fn mono(format: WavFormat) -> WavSpec {
+    WavSpec {
+        channels: 1,
+        sample_rate: SAMPLE_RATE,
+        format,
+    }
+}
+
+/// Plays a sine through the default output device
+#[cfg(feature = "rodio")]
+fn play() {
+    let sine = sine(440);
 
     let (_stream, stream_handle) = OutputStream::try_default().unwrap();
 
     let _result = stream_handle.play_raw(sine.convert_samples());
 
     std::thread::sleep(std::time::Duration::from_secs(1));
+}
 
-    // for x in 0..110 {
-    //     let _y = osc.next();
-    //     match _y {
-    //         Some(y) => println!("{}: {}, {}\n", x, (y as f64) / (i16::MAX as f64), y),
-    //         None => {
-    //             println!("Generator stopped at {}", x);
-    //             break;
-    //         }
-    //     }
-    // }
+/// Playback needs rodio, the offline subcommands work without
+#[cfg(not(feature = "rodio"))]
+fn play() {
+    usage_error("play is not available without the rodio feature");
+}
+
+/// Renders a sine into a WAV file
+fn render(args: &[String]) {
+    let mut path = None;
+    let mut seconds = 1_f64;
+    let mut freq = 440;
+    let mut format = WavFormat::Pcm16;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .unwrap_or_else(|| usage_error(&format!("missing value for {}", arg)))
+        };
+        match arg.as_str() {
+            "--seconds" => {
+                seconds = value()
+                    .parse()
+                    .unwrap_or_else(|_| usage_error("invalid duration"))
+            }
+            "--freq" => {
+                freq = value()
+                    .parse()
+                    .unwrap_or_else(|_| usage_error("invalid frequency"))
+            }
+            "--format" => format = parse_format(value()),
+            _ if path.is_none() && !arg.starts_with("--") => path = Some(arg.clone()),
+            _ => usage_error(&format!("unexpected argument {}", arg)),
+        }
+    }
+    let path = path.unwrap_or_else(|| usage_error("missing output file"));
+    let duration =
+        Duration::try_from_secs_f64(seconds).unwrap_or_else(|_| usage_error("invalid duration"));
+
+    if let Err(e) = render_to_file(sine(freq), mono(format), duration, &path) {
+        eprintln!("error: could not write {}: {}", path, e);
+        exit(1);
+    }
+}
+
+/// Renders a Standard MIDI File into a WAV file
+fn smf(args: &[String]) {
+    let mut paths = Vec::new();
+    let mut format = WavFormat::Pcm16;
+    let mut scl = None;
+    let mut kbm = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .unwrap_or_else(|| usage_error(&format!("missing value for {}", arg)))
+        };
+        match arg.as_str() {
+            "--format" => format = parse_format(value()),
+            "--scl" => scl = Some(value().clone()),
+            "--kbm" => kbm = Some(value().clone()),
+            _ if paths.len() < 2 && !arg.starts_with("--") => paths.push(arg.clone()),
+            _ => usage_error(&format!("unexpected argument {}", arg)),
+        }
+    }
+    let [input, output] = paths.as_slice() else {
+        usage_error("missing input or output file");
+    };
+
+    let bytes = std::fs::read(input).unwrap_or_else(|e| {
+        eprintln!("error: could not read {}: {}", input, e);
+        exit(1);
+    });
+    let smf = Smf::parse(&bytes).unwrap_or_else(|e| {
+        eprintln!("error: could not parse {}: {}", input, e);
+        exit(1);
+    });
+    let mut player = SmfPlayer::<SMF_VOICES>::new(&smf, SAMPLE_RATE);
+    if scl.is_some() || kbm.is_some() {
+        let scale = scl.map_or(Ok(Scale::equal(12, 1200.0)), |path| {
+            Scale::from_file(&path).map_err(|e| (path, e))
+        });
+        let mapping = kbm.map_or(Ok(KeyboardMapping::default()), |path| {
+            KeyboardMapping::from_file(&path).map_err(|e| (path, e))
+        });
+        match scale.and_then(|scale| Ok(Tuning::from_scala(&scale, &mapping?))) {
+            Ok(tuning) => player.synth_mut().set_tuning(tuning),
+            Err((path, e)) => {
+                eprintln!("error: could not load {}: {}", path, e);
+                exit(1);
+            }
+        }
+    }
+    // Rendering stops once the last voice has been released
+    let duration = player.duration() + SMF_TAIL;
+
+    if let Err(e) = render_to_file(player, mono(format), duration, output) {
+        eprintln!("error: could not write {}: {}", output, e);
+        exit(1);
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        None | Some("play") => play(),
+        Some("render") => render(&args[1..]),
+        Some("smf") => smf(&args[1..]),
+        Some(command) => usage_error(&format!("unknown command {}", command)),
+    }
 }
@@ -0,0 +1,161 @@
+// MIDI 1.0 event model, byte stream parser, Standard MIDI File reader and
+// note frequencies
+
+pub mod note_tables;
+pub mod parser;
+pub mod smf;
+
+use note_tables::{NOTE_RATIO, NOTE_RATIO_SHIFT};
+
+/// Frequency of A4 (note 69) in millihertz used by default
+pub const A4_MFREQ: u32 = 440_000;
+
+/// Returns the 12-TET frequency of a MIDI note in millihertz for the given
+/// frequency of A4 in millihertz. Notes above 127 are clamped.
+pub fn note_to_mfreq(note: u8, a4_mfreq: u32) -> u32 {
+    let ratio = NOTE_RATIO[note.min(127) as usize] as u64;
+    let mfreq = (ratio * a4_mfreq as u64 + (1 << (NOTE_RATIO_SHIFT - 1))) >> NOTE_RATIO_SHIFT;
+    mfreq.min(u32::MAX as u64) as u32
+}
+
+/// Typed MIDI 1.0 message. Channels are zero based (0..=15).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MidiEvent {
+    /// Note off. A note on with velocity 0 is reported as note off with
+    /// velocity 0.
+    NoteOff {
+        channel: u8,
+        note: u8,
+        velocity: u8,
+    },
+    NoteOn {
+        channel: u8,
+        note: u8,
+        velocity: u8,
+    },
+    PolyPressure {
+        channel: u8,
+        note: u8,
+        pressure: u8,
+    },
+    ControlChange {
+        channel: u8,
+        controller: u8,
+        value: u8,
+    },
+    ProgramChange {
+        channel: u8,
+        program: u8,
+    },
+    ChannelPressure {
+        channel: u8,
+        pressure: u8,
+    },
+    /// Pitch bend, centered around zero in `-8192..=8191`
+    PitchBend {
+        channel: u8,
+        value: i16,
+    },
+
+    // System common messages
+    TimeCodeQuarterFrame(u8),
+    SongPosition(u16),
+    SongSelect(u8),
+    TuneRequest,
+
+    // System realtime messages
+    Clock,
+    Start,
+    Continue,
+    Stop,
+    ActiveSensing,
+    Reset,
+}
+
+impl MidiEvent {
+    /// Returns the channel of channel messages
+    pub fn channel(&self) -> Option<u8> {
+        match *self {
+            MidiEvent::NoteOff { channel, .. }
+            | MidiEvent::NoteOn { channel, .. }
+            | MidiEvent::PolyPressure { channel, .. }
+            | MidiEvent::ControlChange { channel, .. }
+            | MidiEvent::ProgramChange { channel, .. }
+            | MidiEvent::ChannelPressure { channel, .. }
+            | MidiEvent::PitchBend { channel, .. } => Some(channel),
+            _ => None,
+        }
+    }
+
+    /// Builds a channel message from its status byte and data bytes
+    pub(crate) fn from_channel_message(status: u8, data: [u8; 2]) -> Self {
+        let channel = status & 0x0f;
+        let [d0, d1] = data;
+        match status & 0xf0 {
+            0x80 => MidiEvent::NoteOff {
+                channel,
+                note: d0,
+                velocity: d1,
+            },
+            0x90 if d1 == 0 => MidiEvent::NoteOff {
+                channel,
+                note: d0,
+                velocity: 0,
+            },
+            0x90 => MidiEvent::NoteOn {
+                channel,
+                note: d0,
+                velocity: d1,
+            },
+            0xa0 => MidiEvent::PolyPressure {
+                channel,
+                note: d0,
+                pressure: d1,
+            },
+            0xb0 => MidiEvent::ControlChange {
+                channel,
+                controller: d0,
+                value: d1,
+            },
+            0xc0 => MidiEvent::ProgramChange {
+                channel,
+                program: d0,
+            },
+            0xd0 => MidiEvent::ChannelPressure {
+                channel,
+                pressure: d0,
+            },
+            _ => MidiEvent::PitchBend {
+                channel,
+                value: (((d1 as i16) << 7) | d0 as i16) - 8192,
+            },
+        }
+    }
+}
+
+/// Returns the number of data bytes following a channel or system common
+/// status byte
+pub(crate) fn data_len(status: u8) -> usize {
+    match status {
+        0xc0..=0xdf => 1,
+        0x80..=0xef => 2,
+        0xf1 | 0xf3 => 1,
+        0xf2 => 2,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_note_to_mfreq() {
+        assert_eq!(note_to_mfreq(69, A4_MFREQ), 440_000);
+        assert_eq!(note_to_mfreq(81, A4_MFREQ), 880_000);
+        assert_eq!(note_to_mfreq(60, A4_MFREQ), 261_626);
+        assert_eq!(note_to_mfreq(0, A4_MFREQ), 8_176);
+        assert_eq!(note_to_mfreq(127, A4_MFREQ), 12_543_854);
+        assert_eq!(note_to_mfreq(69, 432_000), 432_000);
+    }
+}
@@ -0,0 +1,2 @@
+// Includes all note table files
+include!("note_tables/note_ratio.rs");
@@ -0,0 +1,3 @@
+pub const NOTE_RATIO_SHIFT: u32 = 24;
+
+pub static NOTE_RATIO: [u32; 128] = [311744, 330281, 349920, 370728, 392772, 416128, 440872, 467088, 494862, 524288, 555464, 588493, 623487, 660561, 699841, 741455, 785544, 832255, 881744, 934175, 989724, 1048576, 1110928, 1176987, 1246974, 1321123, 1399681, 1482910, 1571089, 1664511, 1763488, 1868350, 1979448, 2097152, 2221855, 2353974, 2493948, 2642246, 2799362, 2965821, 3142178, 3329021, 3526975, 3736700, 3958896, 4194304, 4443710, 4707947, 4987896, 5284492, 5598724, 5931642, 6284355, 6658043, 7053950, 7473400, 7917792, 8388608, 8887421, 9415894, 9975792, 10568984, 11197448, 11863283, 12568711, 13316085, 14107901, 14946800, 15835583, 16777216, 17774841, 18831788, 19951585, 21137968, 22394897, 23726566, 25137421, 26632170, 28215802, 29893600, 31671166, 33554432, 35549682, 37663576, 39903169, 42275935, 44789793, 47453133, 50274843, 53264341, 56431603, 59787201, 63342333, 67108864, 71099365, 75327153, 79806339, 84551870, 89579586, 94906266, 100549686, 106528681, 112863206, 119574402, 126684666, 134217728, 142198729, 150654306, 159612677, 169103741, 179159172, 189812531, 201099372, 213057363, 225726413, 239148804, 253369332, 268435456, 284397459, 301308612, 319225354, 338207482, 358318345, 379625062, 402198743, 426114725, 451452825, 478297607, ];
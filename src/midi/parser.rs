@@ -0,0 +1,173 @@
+// Incremental parser turning a raw MIDI 1.0 byte stream into events. Handles
+// running status, skips system exclusive messages and passes realtime
+// messages through even if they interrupt another message.
+
+use crate::midi::{data_len, MidiEvent};
+
+/// Stateful MIDI byte stream parser
+#[derive(Clone, Debug, Default)]
+pub struct Parser {
+    /// Status of the message being received, kept for running status
+    status: Option<u8>,
+    data: [u8; 2],
+    len: usize,
+    sysex: bool,
+}
+
+impl Parser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Clears the running status and any partially received message
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Feeds one byte and returns an event once a message is complete
+    pub fn push(&mut self, byte: u8) -> Option<MidiEvent> {
+        match byte {
+            // Realtime messages may appear anywhere and leave the state alone
+            0xf8 => Some(MidiEvent::Clock),
+            0xfa => Some(MidiEvent::Start),
+            0xfb => Some(MidiEvent::Continue),
+            0xfc => Some(MidiEvent::Stop),
+            0xfe => Some(MidiEvent::ActiveSensing),
+            0xff => Some(MidiEvent::Reset),
+            0xf9 | 0xfd => None,
+            0xf0 => {
+                self.sysex = true;
+                self.status = None;
+                None
+            }
+            0xf7 => {
+                self.sysex = false;
+                None
+            }
+            // Any other status ends a system exclusive message
+            0x80..=0xf6 => {
+                self.sysex = false;
+                self.len = 0;
+                self.status = Some(byte);
+                match byte {
+                    0xf6 => {
+                        self.status = None;
+                        Some(MidiEvent::TuneRequest)
+                    }
+                    0xf4 | 0xf5 => {
+                        self.status = None;
+                        None
+                    }
+                    _ => None,
+                }
+            }
+            _ => {
+                if self.sysex {
+                    return None;
+                }
+                let status = self.status?;
+                self.data[self.len] = byte;
+                self.len += 1;
+                if self.len < data_len(status) {
+                    return None;
+                }
+                self.len = 0;
+                if status < 0xf0 {
+                    Some(MidiEvent::from_channel_message(status, self.data))
+                } else {
+                    // System common messages cancel running status
+                    self.status = None;
+                    Some(match status {
+                        0xf1 => MidiEvent::TimeCodeQuarterFrame(self.data[0]),
+                        0xf2 => MidiEvent::SongPosition(
+                            ((self.data[1] as u16) << 7) | self.data[0] as u16,
+                        ),
+                        _ => MidiEvent::SongSelect(self.data[0]),
+                    })
+                }
+            }
+        }
+    }
+
+    /// Parses a slice of bytes and returns the resulting events
+    pub fn parse<'a>(&'a mut self, bytes: &'a [u8]) -> impl Iterator<Item = MidiEvent> + 'a {
+        bytes.iter().filter_map(move |&byte| self.push(byte))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_running_status_and_realtime() {
+        let mut parser = Parser::new();
+        let bytes = [
+            0x91, 60, 100, // Note on
+            62, 0xf8, 90, // Running status interrupted by a clock
+            60, 0, // Note on with velocity 0
+            0xe0, 0x00, 0x40, // Pitch bend center
+            0xc2, 5, 6, // Program changes with running status
+        ];
+        let events: Vec<MidiEvent> = parser.parse(&bytes).collect();
+        assert_eq!(
+            events,
+            [
+                MidiEvent::NoteOn {
+                    channel: 1,
+                    note: 60,
+                    velocity: 100
+                },
+                MidiEvent::Clock,
+                MidiEvent::NoteOn {
+                    channel: 1,
+                    note: 62,
+                    velocity: 90
+                },
+                MidiEvent::NoteOff {
+                    channel: 1,
+                    note: 60,
+                    velocity: 0
+                },
+                MidiEvent::PitchBend {
+                    channel: 0,
+                    value: 0
+                },
+                MidiEvent::ProgramChange {
+                    channel: 2,
+                    program: 5
+                },
+                MidiEvent::ProgramChange {
+                    channel: 2,
+                    program: 6
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sysex_and_system_common() {
+        let mut parser = Parser::new();
+        let bytes = [
+            0xb0, 7, 100, // Control change
+            0xf0, 0x7e, 0x01, 0xfe, 0x02, 0xf7, // SysEx with active sensing
+            3,    // Data without status after SysEx is dropped
+            0xf2, 0x10, 0x01, // Song position
+            0xf6, // Tune request
+        ];
+        let events: Vec<MidiEvent> = parser.parse(&bytes).collect();
+        assert_eq!(
+            events,
+            [
+                MidiEvent::ControlChange {
+                    channel: 0,
+                    controller: 7,
+                    value: 100
+                },
+                MidiEvent::ActiveSensing,
+                MidiEvent::SongPosition(0x90),
+                MidiEvent::TuneRequest,
+            ]
+        );
+    }
+}
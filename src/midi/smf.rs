@@ -0,0 +1,503 @@
+// Reader for Standard MIDI Files (SMF) of format 0 and 1 and a player that
+// renders them sample accurately with the polyphonic synth.
+
+use crate::freq::Hz;
+use crate::midi::{data_len, MidiEvent};
+use crate::voice::VoiceAllocator;
+use alloc::vec::Vec;
+use core::fmt;
+use core::time::Duration;
+#[cfg(feature = "rodio")]
+use rodio::source::Source;
+
+/// Tempo of a file without tempo events, 120 bpm in microseconds per quarter
+const DEFAULT_TEMPO: u32 = 500_000;
+
+/// Error returned by `Smf::parse`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SmfError {
+    /// The file ends inside a chunk or an event
+    UnexpectedEof,
+    /// The file does not start with a valid `MThd` chunk
+    InvalidHeader,
+    /// Only formats 0 and 1 are supported
+    UnsupportedFormat(u16),
+    /// A track contains a data byte without running status or an invalid
+    /// status byte
+    InvalidEvent,
+}
+
+impl fmt::Display for SmfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SmfError::UnexpectedEof => write!(f, "unexpected end of file"),
+            SmfError::InvalidHeader => write!(f, "invalid header"),
+            SmfError::UnsupportedFormat(format) => write!(f, "unsupported format {}", format),
+            SmfError::InvalidEvent => write!(f, "invalid event"),
+        }
+    }
+}
+
+impl core::error::Error for SmfError {}
+
+/// Meaning of delta times
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Timing {
+    /// Ticks per quarter note, scaled by tempo events
+    Metrical(u16),
+    /// Ticks per SMPTE frame, independent of tempo. 29 frames per second
+    /// stand for 29.97 (drop frame).
+    Timecode { fps: u8, ticks_per_frame: u8 },
+}
+
+/// Event of a track
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrackEventKind {
+    Midi(MidiEvent),
+    /// Set tempo meta event in microseconds per quarter note
+    Tempo(u32),
+    EndOfTrack,
+}
+
+/// Track event at an absolute time in ticks
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TrackEvent {
+    pub tick: u64,
+    pub kind: TrackEventKind,
+}
+
+/// MIDI event at an absolute time in samples
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimedEvent {
+    pub sample: u64,
+    pub event: MidiEvent,
+}
+
+/// Cursor over the bytes of a file
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], SmfError> {
+        let end = self.pos.checked_add(len).ok_or(SmfError::UnexpectedEof)?;
+        let bytes = self
+            .bytes
+            .get(self.pos..end)
+            .ok_or(SmfError::UnexpectedEof)?;
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8, SmfError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, SmfError> {
+        Ok(u16::from_be_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32, SmfError> {
+        Ok(u32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    /// Reads a variable length quantity of at most four bytes
+    fn vlq(&mut self) -> Result<u32, SmfError> {
+        let mut value = 0;
+        for _ in 0..4 {
+            let byte = self.u8()?;
+            value = (value << 7) | (byte & 0x7f) as u32;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(SmfError::InvalidEvent)
+    }
+}
+
+/// Parsed Standard MIDI File
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Smf {
+    format: u16,
+    timing: Timing,
+    tracks: Vec<Vec<TrackEvent>>,
+}
+
+impl Smf {
+    /// Parses the bytes of a format 0 or 1 file. Unknown chunks, system
+    /// exclusive messages and meta events other than tempo and end of track
+    /// are skipped.
+    pub fn parse(bytes: &[u8]) -> Result<Self, SmfError> {
+        let mut reader = Reader::new(bytes);
+        if reader.take(4).ok() != Some(b"MThd".as_slice()) {
+            return Err(SmfError::InvalidHeader);
+        }
+        let len = reader.u32()? as usize;
+        if len < 6 {
+            return Err(SmfError::InvalidHeader);
+        }
+        let format = reader.u16()?;
+        let track_count = reader.u16()?;
+        let division = reader.u16()?;
+        reader.take(len - 6)?;
+
+        if format > 1 {
+            return Err(SmfError::UnsupportedFormat(format));
+        }
+        let timing = if division & 0x8000 != 0 {
+            let fps = ((division >> 8) as u8 as i8).unsigned_abs();
+            let ticks_per_frame = division as u8;
+            if fps == 0 || ticks_per_frame == 0 {
+                return Err(SmfError::InvalidHeader);
+            }
+            Timing::Timecode {
+                fps,
+                ticks_per_frame,
+            }
+        } else if division == 0 {
+            return Err(SmfError::InvalidHeader);
+        } else {
+            Timing::Metrical(division)
+        };
+
+        let mut tracks = Vec::with_capacity(track_count as usize);
+        while tracks.len() < track_count as usize && !reader.is_empty() {
+            let id = reader.take(4)?;
+            let len = reader.u32()? as usize;
+            let chunk = reader.take(len)?;
+            if id == b"MTrk" {
+                tracks.push(Self::parse_track(chunk)?);
+            }
+        }
+        Ok(Self {
+            format,
+            timing,
+            tracks,
+        })
+    }
+
+    fn parse_track(bytes: &[u8]) -> Result<Vec<TrackEvent>, SmfError> {
+        let mut reader = Reader::new(bytes);
+        let mut events = Vec::new();
+        let mut tick = 0;
+        let mut running_status = None;
+        while !reader.is_empty() {
+            tick += reader.vlq()? as u64;
+            let byte = reader.u8()?;
+            let kind = match byte {
+                0xff => {
+                    running_status = None;
+                    let meta = reader.u8()?;
+                    let len = reader.vlq()? as usize;
+                    let data = reader.take(len)?;
+                    match meta {
+                        0x51 if len == 3 => TrackEventKind::Tempo(
+                            ((data[0] as u32) << 16) | ((data[1] as u32) << 8) | data[2] as u32,
+                        ),
+                        0x2f => TrackEventKind::EndOfTrack,
+                        _ => continue,
+                    }
+                }
+                0xf0 | 0xf7 => {
+                    running_status = None;
+                    let len = reader.vlq()? as usize;
+                    reader.take(len)?;
+                    continue;
+                }
+                0x80..=0xef => {
+                    running_status = Some(byte);
+                    let mut data = [0; 2];
+                    for d in data.iter_mut().take(data_len(byte)) {
+                        *d = reader.u8()?;
+                    }
+                    TrackEventKind::Midi(MidiEvent::from_channel_message(byte, data))
+                }
+                0x00..=0x7f => {
+                    let status = running_status.ok_or(SmfError::InvalidEvent)?;
+                    let mut data = [byte, 0];
+                    if data_len(status) == 2 {
+                        data[1] = reader.u8()?;
+                    }
+                    TrackEventKind::Midi(MidiEvent::from_channel_message(status, data))
+                }
+                _ => return Err(SmfError::InvalidEvent),
+            };
+            events.push(TrackEvent { tick, kind });
+            if kind == TrackEventKind::EndOfTrack {
+                break;
+            }
+        }
+        Ok(events)
+    }
+
+    pub fn format(&self) -> u16 {
+        self.format
+    }
+
+    pub fn timing(&self) -> Timing {
+        self.timing
+    }
+
+    pub fn tracks(&self) -> &[Vec<TrackEvent>] {
+        &self.tracks
+    }
+
+    /// Merges all tracks and converts ticks to sample positions. Tempo events
+    /// of any track apply to all tracks. Events at the same tick keep the
+    /// order of their tracks.
+    pub fn timed_events(&self, sample_rate: u32) -> Vec<TimedEvent> {
+        let mut events: Vec<TrackEvent> = self.tracks.iter().flatten().copied().collect();
+        events.sort_by_key(|e| e.tick);
+
+        // Samples are accumulated exactly as `position / denominator`
+        let denominator = match self.timing {
+            Timing::Metrical(ticks_per_quarter) => ticks_per_quarter as u128 * 1_000_000,
+            Timing::Timecode {
+                fps,
+                ticks_per_frame,
+            } => {
+                let mfps = if fps == 29 {
+                    29_970
+                } else {
+                    fps as u128 * 1000
+                };
+                mfps * ticks_per_frame as u128 * 1000
+            }
+        };
+        let mut us_per_quarter = DEFAULT_TEMPO;
+        let mut position: u128 = 0;
+        let mut tick = 0;
+        let mut timed = Vec::with_capacity(events.len());
+        for event in events {
+            let us_per_tick = match self.timing {
+                Timing::Metrical(_) => us_per_quarter as u128,
+                Timing::Timecode { .. } => 1_000_000,
+            };
+            position += (event.tick - tick) as u128 * us_per_tick * sample_rate as u128;
+            tick = event.tick;
+            match event.kind {
+                TrackEventKind::Midi(event) => timed.push(TimedEvent {
+                    sample: (position / denominator) as u64,
+                    event,
+                }),
+                TrackEventKind::Tempo(tempo) => us_per_quarter = tempo,
+                TrackEventKind::EndOfTrack => {}
+            }
+        }
+        timed
+    }
+}
+
+/// Plays a Standard MIDI File with `N` voices. Ends once all events have
+/// been played and all voices are idle.
+pub struct SmfPlayer<const N: usize> {
+    synth: VoiceAllocator<N>,
+    events: Vec<TimedEvent>,
+    next_event: usize,
+    position: u64,
+    sample_rate: u32,
+}
+
+impl<const N: usize> SmfPlayer<N> {
+    pub fn new(smf: &Smf, sample_rate: u32) -> Self {
+        let mut synth = VoiceAllocator::new();
+        synth.set_sample_rate(Hz(sample_rate));
+        Self {
+            synth,
+            events: smf.timed_events(sample_rate),
+            next_event: 0,
+            position: 0,
+            sample_rate,
+        }
+    }
+
+    pub fn synth(&self) -> &VoiceAllocator<N> {
+        &self.synth
+    }
+
+    /// Returns the synth, e.g. to set wavetables and envelopes of its voices
+    pub fn synth_mut(&mut self) -> &mut VoiceAllocator<N> {
+        &mut self.synth
+    }
+
+    pub fn events(&self) -> &[TimedEvent] {
+        &self.events
+    }
+
+    /// Returns the time of the last event, i.e. without release tails
+    pub fn duration(&self) -> Duration {
+        let samples = self.events.last().map_or(0, |e| e.sample);
+        (samples as u128 * 1_000_000_000)
+            .checked_div(self.sample_rate as u128)
+            .map_or(Duration::ZERO, |nanos| Duration::from_nanos(nanos as u64))
+    }
+
+    /// Returns the number of samples played so far
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns whether all events have been played and all voices are idle
+    pub fn is_finished(&self) -> bool {
+        self.next_event >= self.events.len() && self.synth.active_voices() == 0
+    }
+
+    /// Plays all events due at the current position and renders the next
+    /// sample
+    #[inline]
+    pub fn _next(&mut self) -> Option<i16> {
+        if self.is_finished() {
+            return None;
+        }
+        while let Some(event) = self.events.get(self.next_event) {
+            if event.sample > self.position {
+                break;
+            }
+            self.synth.handle_event(&event.event);
+            self.next_event += 1;
+        }
+        self.position += 1;
+        Some(self.synth._next())
+    }
+}
+
+impl<const N: usize> Iterator for SmfPlayer<N> {
+    type Item = i16;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self._next()
+    }
+}
+
+#[cfg(feature = "rodio")]
+impl<const N: usize> Source for SmfPlayer<N> {
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Format 1 file with 96 ticks per quarter, a tempo track switching from
+    /// 120 to 60 bpm after one quarter and a note track using running status
+    fn file() -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(b"MThd");
+        bytes.extend_from_slice(&[0, 0, 0, 6, 0, 1, 0, 2, 0, 96]);
+
+        let tempo_track = [
+            0x00, 0xff, 0x51, 0x03, 0x07, 0xa1, 0x20, // 120 bpm
+            0x60, 0xff, 0x51, 0x03, 0x0f, 0x42, 0x40, // 60 bpm after 96 ticks
+            0x00, 0xff, 0x2f, 0x00,
+        ];
+        bytes.extend_from_slice(b"MTrk");
+        bytes.extend_from_slice(&(tempo_track.len() as u32).to_be_bytes());
+        bytes.extend_from_slice(&tempo_track);
+
+        let note_track = [
+            0x00, 0xff, 0x03, 0x01, b'x', // Track name
+            0x00, 0x90, 60, 100, // Note on
+            0x81, 0x40, 60, 0, // Note off by running status after 192 ticks
+            0x00, 0xf0, 0x02, 0x7e, 0xf7, // SysEx
+            0x30, 0x80, 64, 0, // Note off after 48 ticks
+            0x00, 0xff, 0x2f, 0x00,
+        ];
+        bytes.extend_from_slice(b"MTrk");
+        bytes.extend_from_slice(&(note_track.len() as u32).to_be_bytes());
+        bytes.extend_from_slice(&note_track);
+        bytes
+    }
+
+    #[test]
+    fn test_parse_and_timing() {
+        let smf = Smf::parse(&file()).unwrap();
+        assert_eq!(smf.format(), 1);
+        assert_eq!(smf.timing(), Timing::Metrical(96));
+        assert_eq!(smf.tracks().len(), 2);
+        assert_eq!(smf.tracks()[1][1].tick, 192);
+
+        // One quarter at 120 bpm, then one quarter and an eighth at 60 bpm
+        let events = smf.timed_events(1000);
+        assert_eq!(
+            events,
+            [
+                TimedEvent {
+                    sample: 0,
+                    event: MidiEvent::NoteOn {
+                        channel: 0,
+                        note: 60,
+                        velocity: 100
+                    }
+                },
+                TimedEvent {
+                    sample: 1500,
+                    event: MidiEvent::NoteOff {
+                        channel: 0,
+                        note: 60,
+                        velocity: 0
+                    }
+                },
+                TimedEvent {
+                    sample: 2000,
+                    event: MidiEvent::NoteOff {
+                        channel: 0,
+                        note: 64,
+                        velocity: 0
+                    }
+                },
+            ]
+        );
+
+        assert_eq!(Smf::parse(b"MThd"), Err(SmfError::UnexpectedEof));
+        assert_eq!(Smf::parse(b"RIFF"), Err(SmfError::InvalidHeader));
+        let mut bytes = file();
+        bytes[9] = 2;
+        assert_eq!(Smf::parse(&bytes), Err(SmfError::UnsupportedFormat(2)));
+    }
+
+    #[test]
+    fn test_player() {
+        let smf = Smf::parse(&file()).unwrap();
+        let mut player = SmfPlayer::<4>::new(&smf, 1000);
+        assert_eq!(player.duration(), Duration::from_secs(2));
+        for voice in player.synth_mut().voices_mut() {
+            voice.env.set_release(10);
+        }
+
+        let head: Vec<i16> = player.by_ref().take(1500).collect();
+        assert!(head.iter().any(|&y| y != 0));
+        assert_eq!(player.synth().active_voices(), 1);
+
+        // The last event comes after the note off, then the release ends
+        let rest = player.by_ref().count();
+        assert!(player.is_finished());
+        assert!((500..600).contains(&rest));
+
+        // Without a sample rate, there is no time
+        assert_eq!(SmfPlayer::<4>::new(&smf, 0).duration(), Duration::ZERO);
+    }
+}
@@ -0,0 +1,489 @@
+// Provides a modulation matrix routing sources (LFOs, envelopes, velocity and
+// MIDI CCs) with signed depths to named destinations of oscillators, filters,
+// envelopes and voices.
+
+use crate::envelope::Envelope;
+use crate::filter::svf::StateVariableFilter;
+use crate::freq::MilliHz;
+use crate::midi::MidiEvent;
+use crate::osc::lfo::Lfo;
+use crate::osc::wave_table_osc::{Sample, WaveTableOscillator};
+use crate::voice::Voice;
+use alloc::vec::Vec;
+
+/// Source of a route. Source values are Q15.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModSource {
+    /// LFO by index, as returned by `ModMatrix::add_lfo`
+    Lfo(usize),
+    /// Envelope by index, as returned by `ModMatrix::add_envelope`
+    Envelope(usize),
+    /// Velocity of the last note on
+    Velocity,
+    /// MIDI control change by controller number
+    Cc(u8),
+}
+
+/// Parameter a route modulates. The unit of base values and depths is given
+/// per destination.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Destination {
+    /// Exponential pitch offset of an oscillator in cents
+    Pitch,
+    /// Oscillator frequency in millihertz
+    Mfreq,
+    /// Oscillator phase offset, the full i32 range covers one period
+    Phase,
+    /// Morph position of an oscillator in Q16
+    Position,
+    /// Voice amplitude in Q15, `i16::MAX` is unity
+    Amplitude,
+    /// Filter cutoff in millihertz
+    Cutoff,
+    /// Filter resonance in Q15
+    Resonance,
+    /// Envelope times in milliseconds
+    Attack,
+    Decay,
+    Release,
+    /// Envelope sustain level in Q15
+    Sustain,
+    /// Envelope curve shapes, see `LinExp::set_sigma`
+    AttackSigma,
+    DecaySigma,
+    ReleaseSigma,
+}
+
+impl Destination {
+    /// Number of destinations
+    pub const COUNT: usize = 14;
+
+    /// All destinations in the order of their index
+    pub const ALL: [Destination; Self::COUNT] = [
+        Destination::Pitch,
+        Destination::Mfreq,
+        Destination::Phase,
+        Destination::Position,
+        Destination::Amplitude,
+        Destination::Cutoff,
+        Destination::Resonance,
+        Destination::Attack,
+        Destination::Decay,
+        Destination::Release,
+        Destination::Sustain,
+        Destination::AttackSigma,
+        Destination::DecaySigma,
+        Destination::ReleaseSigma,
+    ];
+}
+
+/// Something with modulatable parameters. Destinations a target does not
+/// have are ignored.
+pub trait ModTarget {
+    fn set_param(&mut self, destination: Destination, value: i32);
+}
+
+/// Connection of a source to a destination. The depth is the offset added
+/// to the destination (in its unit) at full scale of the source, i.e. at
+/// `i16::MAX` and, negated, at `i16::MIN`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Route {
+    pub source: ModSource,
+    pub destination: Destination,
+    pub depth: i32,
+}
+
+impl Route {
+    /// Returns the offset for a source value, rounded to nearest. Positive
+    /// values are scaled by 1/32767 and negative ones by 1/32768, so both
+    /// ends of the range reach the depth exactly.
+    fn offset(&self, source: i16) -> i64 {
+        let den = if source < 0 { 1 << 15 } else { i16::MAX as i64 };
+        let product = source as i64 * self.depth as i64;
+        (product + product.signum() * den / 2) / den
+    }
+}
+
+/// How often the routes are evaluated
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ModRate {
+    /// Every sample
+    #[default]
+    Audio,
+    /// Every n samples, cheaper for expensive setters such as filter cutoff
+    Control(u32),
+}
+
+/// Modulation matrix. Sources advance once per sample with `advance`, which
+/// evaluates the destinations at the selected rate. Whenever it returns
+/// true, `apply` passes the values to each target, e.g. an oscillator and a
+/// filter. `tick` does both for a single target.
+pub struct ModMatrix {
+    lfos: Vec<Lfo>,
+    /// Last output of each LFO
+    lfo_values: Vec<i16>,
+    envelopes: Vec<Envelope>,
+    velocity: i16,
+    ccs: [i16; 128],
+
+    routes: Vec<Route>,
+    bases: [Option<i32>; Destination::COUNT],
+    /// Destinations with a base value or a route as of the last evaluation
+    active: [bool; Destination::COUNT],
+    values: [i32; Destination::COUNT],
+
+    rate: ModRate,
+    countdown: u32,
+}
+
+impl ModMatrix {
+    pub fn new() -> Self {
+        Self {
+            lfos: Vec::new(),
+            lfo_values: Vec::new(),
+            envelopes: Vec::new(),
+            velocity: 0,
+            ccs: [0; 128],
+
+            routes: Vec::new(),
+            bases: [None; Destination::COUNT],
+            active: [false; Destination::COUNT],
+            values: [0; Destination::COUNT],
+
+            rate: ModRate::Audio,
+            countdown: 0,
+        }
+    }
+
+    /// Adds an LFO and returns it as a source
+    pub fn add_lfo(&mut self, lfo: Lfo) -> ModSource {
+        self.lfos.push(lfo);
+        self.lfo_values.push(0);
+        ModSource::Lfo(self.lfos.len() - 1)
+    }
+
+    /// Adds an envelope and returns it as a source. Envelopes are gated by
+    /// `handle_event`.
+    pub fn add_envelope(&mut self, envelope: Envelope) -> ModSource {
+        self.envelopes.push(envelope);
+        ModSource::Envelope(self.envelopes.len() - 1)
+    }
+
+    pub fn lfo_mut(&mut self, index: usize) -> Option<&mut Lfo> {
+        self.lfos.get_mut(index)
+    }
+
+    pub fn envelope_mut(&mut self, index: usize) -> Option<&mut Envelope> {
+        self.envelopes.get_mut(index)
+    }
+
+    /// Routes a source to a destination
+    pub fn add_route(&mut self, source: ModSource, destination: Destination, depth: i32) {
+        self.routes.push(Route {
+            source,
+            destination,
+            depth,
+        });
+    }
+
+    /// Removes all routes to a destination
+    pub fn remove_routes(&mut self, destination: Destination) {
+        self.routes.retain(|r| r.destination != destination);
+    }
+
+    pub fn routes(&self) -> &[Route] {
+        &self.routes
+    }
+
+    /// Sets the unmodulated value of a destination. Destinations without a
+    /// base value are modulated around zero.
+    pub fn set_base(&mut self, destination: Destination, value: i32) {
+        self.bases[destination as usize] = Some(value);
+    }
+
+    pub fn set_rate(&mut self, rate: ModRate) {
+        self.rate = rate;
+        self.countdown = 0;
+    }
+
+    /// Sets the sample rate of all LFOs and envelopes
+    pub fn set_sample_rate<R: Into<MilliHz>>(&mut self, sample_rate: R) {
+        let sample_rate = sample_rate.into();
+        self.lfos
+            .iter_mut()
+            .for_each(|lfo| lfo.set_sample_rate(sample_rate));
+        self.envelopes
+            .iter_mut()
+            .for_each(|env| env.set_sample_rate(sample_rate));
+    }
+
+    /// Updates velocity and CC sources and gates the envelopes
+    pub fn handle_event(&mut self, event: &MidiEvent) {
+        match *event {
+            MidiEvent::NoteOn { velocity, .. } => {
+                self.velocity = ((velocity.min(127) as i32 * i16::MAX as i32) / 127) as i16;
+                self.envelopes.iter_mut().for_each(Envelope::gate_on);
+            }
+            MidiEvent::NoteOff { .. } => {
+                self.envelopes.iter_mut().for_each(Envelope::gate_off);
+            }
+            MidiEvent::ControlChange {
+                controller, value, ..
+            } => {
+                self.set_cc(controller, value);
+            }
+            _ => {}
+        }
+    }
+
+    /// Sets a CC source from a 7 bit value
+    pub fn set_cc(&mut self, controller: u8, value: u8) {
+        if let Some(cc) = self.ccs.get_mut(controller as usize) {
+            *cc = ((value.min(127) as i32 * i16::MAX as i32) / 127) as i16;
+        }
+    }
+
+    /// Returns the current value of a source in Q15
+    pub fn source(&self, source: ModSource) -> i16 {
+        match source {
+            ModSource::Lfo(i) => self.lfo_values.get(i).copied().unwrap_or(0),
+            ModSource::Envelope(i) => self.envelopes.get(i).map_or(0, Envelope::level),
+            ModSource::Velocity => self.velocity,
+            ModSource::Cc(cc) => self.ccs.get(cc as usize).copied().unwrap_or(0),
+        }
+    }
+
+    /// Returns the value of a destination as of the last evaluation
+    pub fn value(&self, destination: Destination) -> i32 {
+        self.values[destination as usize]
+    }
+
+    /// Evaluates all destinations with a base value or a route from the
+    /// current source values, regardless of the rate
+    pub fn update(&mut self) {
+        self.active = self.bases.map(|base| base.is_some());
+        let mut sums = self.bases.map(|base| base.unwrap_or(0) as i64);
+        for route in self.routes.iter() {
+            let i = route.destination as usize;
+            self.active[i] = true;
+            sums[i] += route.offset(self.source(route.source));
+        }
+        for (value, sum) in self.values.iter_mut().zip(sums) {
+            *value = sum.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+        }
+    }
+
+    /// Advances all sources by one sample and evaluates the destinations
+    /// when due. Returns whether they were evaluated, i.e. whether the
+    /// targets should be updated with `apply`.
+    pub fn advance(&mut self) -> bool {
+        for (lfo, value) in self.lfos.iter_mut().zip(self.lfo_values.iter_mut()) {
+            *value = lfo._next();
+        }
+        self.envelopes.iter_mut().for_each(|env| {
+            env._next();
+        });
+        if self.countdown > 0 {
+            self.countdown -= 1;
+            return false;
+        }
+        self.countdown = match self.rate {
+            ModRate::Audio => 0,
+            ModRate::Control(period) => period.saturating_sub(1),
+        };
+        self.update();
+        true
+    }
+
+    /// Passes the destinations of the last evaluation to a target. The
+    /// sources do not advance, so one matrix can drive several targets.
+    pub fn apply<T: ModTarget>(&self, target: &mut T) {
+        for (i, destination) in Destination::ALL.into_iter().enumerate() {
+            if self.active[i] {
+                target.set_param(destination, self.values[i]);
+            }
+        }
+    }
+
+    /// Advances the sources and updates a single target when due, see
+    /// `advance`. Returns whether the target was updated.
+    pub fn tick<T: ModTarget>(&mut self, target: &mut T) -> bool {
+        let due = self.advance();
+        if due {
+            self.apply(target);
+        }
+        due
+    }
+}
+
+impl Default for ModMatrix {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Converts cents to octaves in Q16
+fn cents_to_octaves(cents: i32) -> i32 {
+    (((cents as i64) << 16) / 1200).clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
+
+fn to_q15(value: i32) -> i16 {
+    value.clamp(i16::MIN as i32, i16::MAX as i32) as i16
+}
+
+impl<T: Sample> ModTarget for WaveTableOscillator<T> {
+    fn set_param(&mut self, destination: Destination, value: i32) {
+        match destination {
+            Destination::Pitch => self.set_fm_exp(cents_to_octaves(value)),
+            Destination::Mfreq => self.set_mfreq(value.max(0) as u32),
+            Destination::Phase => self.set_phase_mod(value),
+            Destination::Position => self.set_position(value.max(0) as u32),
+            _ => {}
+        }
+    }
+}
+
+impl ModTarget for StateVariableFilter {
+    fn set_param(&mut self, destination: Destination, value: i32) {
+        match destination {
+            Destination::Cutoff => self.set_mcutoff(value.max(0) as u32),
+            Destination::Resonance => self.set_resonance(to_q15(value)),
+            _ => {}
+        }
+    }
+}
+
+/// Sigma values out of range are ignored and keep the previous curve
+impl ModTarget for Envelope {
+    fn set_param(&mut self, destination: Destination, value: i32) {
+        match destination {
+            Destination::Attack => self.set_attack(value.max(0) as u32),
+            Destination::Decay => self.set_decay(value.max(0) as u32),
+            Destination::Release => self.set_release(value.max(0) as u32),
+            Destination::Sustain => self.set_sustain(to_q15(value.max(0))),
+            Destination::AttackSigma => self.set_attack_sigma(value).unwrap_or(()),
+            Destination::DecaySigma => self.set_decay_sigma(value).unwrap_or(()),
+            Destination::ReleaseSigma => self.set_release_sigma(value).unwrap_or(()),
+            _ => {}
+        }
+    }
+}
+
+impl ModTarget for Voice {
+    fn set_param(&mut self, destination: Destination, value: i32) {
+        match destination {
+            Destination::Amplitude => self.set_amplitude(value),
+            Destination::Pitch
+            | Destination::Mfreq
+            | Destination::Phase
+            | Destination::Position => self.osc.set_param(destination, value),
+            _ => self.env.set_param(destination, value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::filter::Filter;
+    use crate::fixed::Q15;
+    use crate::freq::{Hz, MilliHz};
+    use crate::osc::lfo::LfoShape;
+    use crate::osc::wave_table_osc::WaveTableOsc16;
+
+    #[test]
+    fn test_routes() {
+        let mut matrix = ModMatrix::new();
+        let mut lfo = Lfo::new();
+        lfo.set_shape(LfoShape::Square);
+        lfo.set_sample_rate(Hz(4));
+        lfo.set_rate(Hz(1));
+        let lfo = matrix.add_lfo(lfo);
+
+        // Vibrato of 1200 cents and a CC controlled frequency offset
+        matrix.add_route(lfo, Destination::Pitch, 1200);
+        matrix.set_base(Destination::Mfreq, 440_000);
+        matrix.add_route(ModSource::Cc(1), Destination::Mfreq, 100_000);
+        matrix.set_cc(1, 127);
+
+        let mut osc = WaveTableOsc16::new();
+        osc.set_sample_rate(Hz(48000));
+
+        // Full scale sources add exactly the depth
+        assert!(matrix.tick(&mut osc));
+        assert_eq!(matrix.value(Destination::Mfreq), 540_000);
+        assert_eq!(matrix.value(Destination::Pitch), 1200);
+        assert_eq!(osc.freq(), MilliHz(540_000));
+        let delta_phi = osc.delta_phi() as i64;
+        assert_eq!(osc.increment(), 2 * delta_phi);
+
+        // The square LFO goes negative after half a period
+        matrix.tick(&mut osc);
+        matrix.tick(&mut osc);
+        assert_eq!(matrix.value(Destination::Pitch), -1200);
+        assert_eq!(osc.increment(), delta_phi / 2);
+
+        matrix.remove_routes(Destination::Pitch);
+        assert_eq!(matrix.routes().len(), 1);
+    }
+
+    #[test]
+    fn test_control_rate() {
+        let mut matrix = ModMatrix::new();
+        let env = matrix.add_envelope(Envelope::adsr(0, 0, i16::MAX, 0));
+        matrix.set_sample_rate(Hz(1000));
+        matrix.set_rate(ModRate::Control(4));
+        matrix.add_route(env, Destination::Amplitude, 1 << 15);
+        matrix.add_route(ModSource::Velocity, Destination::Sustain, i16::MAX as i32);
+
+        let mut voice = Voice::new();
+        let updates = (0..8).filter(|_| matrix.tick(&mut voice)).count();
+        assert_eq!(updates, 2);
+        assert_eq!(matrix.value(Destination::Amplitude), 0);
+
+        matrix.handle_event(&MidiEvent::NoteOn {
+            channel: 0,
+            note: 60,
+            velocity: 127,
+        });
+        for _ in 0..8 {
+            matrix.tick(&mut voice);
+        }
+        assert!(matrix.value(Destination::Amplitude) > 32000);
+        assert_eq!(matrix.value(Destination::Sustain), 32767);
+    }
+
+    #[test]
+    fn test_several_targets() {
+        let mut matrix = ModMatrix::new();
+        let mut lfo = Lfo::new();
+        lfo.set_shape(LfoShape::Square);
+        lfo.set_sample_rate(Hz(4));
+        lfo.set_rate(Hz(1));
+        let lfo = matrix.add_lfo(lfo);
+        matrix.set_base(Destination::Mfreq, 440_000);
+        matrix.add_route(lfo, Destination::Mfreq, 100_000);
+        matrix.set_base(Destination::Cutoff, 1_000_000);
+        matrix.add_route(lfo, Destination::Cutoff, 500_000);
+
+        // The LFO advances once per sample and drives both targets in step
+        let mut osc = WaveTableOsc16::new();
+        let mut svf = StateVariableFilter::new();
+        let mut reference = StateVariableFilter::new();
+        for (freq, cutoff) in [
+            (540, 1500),
+            (540, 1500),
+            (340, 500),
+            (340, 500),
+            (540, 1500),
+        ] {
+            assert!(matrix.advance());
+            matrix.apply(&mut osc);
+            matrix.apply(&mut svf);
+            assert_eq!(osc.freq(), MilliHz::from(Hz(freq)));
+            assert_eq!(matrix.value(Destination::Cutoff), cutoff * 1000);
+            reference.set_cutoff(Hz(cutoff as u32));
+            assert_eq!(svf.process(Q15(10000)), reference.process(Q15(10000)));
+        }
+    }
+}
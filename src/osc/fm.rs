@@ -0,0 +1,435 @@
+// FM voices built from sine wavetable oscillators. Modulators drive the phase
+// modulation input of their targets (DX7 style "FM").
+
+use crate::freq::MilliHz;
+use crate::midi::A4_MFREQ;
+use crate::osc::wave_table_osc::{Interpolation, WaveTableOsc16};
+use crate::osc::wave_tables::SINE_I16;
+#[cfg(feature = "rodio")]
+use core::time::Duration;
+#[cfg(feature = "rodio")]
+use rodio::source::Source;
+
+/// 2^32 / (2 * pi), converts radians into phase units
+const PHASE_PER_RADIAN: i64 = 683_565_276;
+
+/// Single FM operator: a sine oscillator with a frequency ratio relative to
+/// the voice frequency and an output level
+pub struct Operator {
+    osc: WaveTableOsc16,
+    /// Frequency ratio in Q16
+    ratio: u32,
+    /// Modulation index in Q12 radians if used as modulator, otherwise the
+    /// output level in Q12
+    level: u16,
+}
+
+impl Operator {
+    pub fn new() -> Self {
+        let mut osc = WaveTableOsc16::new();
+        osc.set_wavetable(&SINE_I16);
+        osc.set_interpolation(Interpolation::Linear);
+        Self {
+            osc,
+            ratio: 1 << 16,
+            level: 1 << 12,
+        }
+    }
+
+    /// Sets the frequency ratio in Q16, e.g. `2 << 16` for one octave above
+    /// the voice frequency
+    pub fn set_ratio(&mut self, ratio: u32) {
+        self.ratio = ratio;
+    }
+
+    /// Sets the level in Q12. For modulators this is the modulation index in
+    /// radians.
+    pub fn set_level(&mut self, level: u16) {
+        self.level = level;
+    }
+
+    fn set_mfreq(&mut self, mfreq: u32) {
+        self.osc
+            .set_mfreq((((mfreq as u64) * (self.ratio as u64)) >> 16) as u32);
+    }
+
+    /// Advances the operator with the given phase modulation input and
+    /// returns its raw output
+    #[inline]
+    fn next(&mut self, phase_mod: i32) -> i16 {
+        self.osc.set_phase_mod(phase_mod);
+        self.osc._next().unwrap_or(0)
+    }
+
+    /// Converts an output sample into a phase modulation input for the next
+    /// operator
+    #[inline]
+    fn modulation(&self, y: i16) -> i32 {
+        // y (Q15) * level (Q12) is the phase deviation in radians (Q27)
+        (((y as i64) * (self.level as i64) * PHASE_PER_RADIAN) >> 27) as i32
+    }
+
+    /// Scales an output sample by the operator level
+    #[inline]
+    fn output(&self, y: i16) -> i32 {
+        ((y as i32) * (self.level as i32)) >> 12
+    }
+}
+
+impl Default for Operator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Two-operator FM voice, operator 1 is the carrier and operator 2 modulates
+/// it
+pub struct FmVoice2 {
+    pub ops: [Operator; 2],
+    mfreq: u32,
+}
+
+impl FmVoice2 {
+    pub fn new() -> Self {
+        Self {
+            ops: [Operator::new(), Operator::new()],
+            mfreq: A4_MFREQ,
+        }
+    }
+
+    /// Sets the voice frequency in millihertz and retunes all operators
+    pub fn set_mfreq(&mut self, mfreq: u32) {
+        self.set_freq(MilliHz(mfreq));
+    }
+
+    /// Sets the voice frequency in any unit and retunes all operators
+    pub fn set_freq<F: Into<MilliHz>>(&mut self, freq: F) {
+        self.mfreq = freq.into().0;
+        for op in self.ops.iter_mut() {
+            op.set_mfreq(self.mfreq);
+        }
+    }
+
+    /// Sets the sample rate in any unit, e.g. `Hz(48000)`
+    pub fn set_sample_rate<R: Into<MilliHz>>(&mut self, sample_rate: R) {
+        let sample_rate = sample_rate.into();
+        for op in self.ops.iter_mut() {
+            op.osc.set_sample_rate(sample_rate);
+        }
+        self.set_mfreq(self.mfreq);
+    }
+
+    pub fn start(&mut self) {
+        for op in self.ops.iter_mut() {
+            op.osc.reset_and_start();
+        }
+    }
+
+    pub fn stop(&mut self) {
+        for op in self.ops.iter_mut() {
+            op.osc.stop_and_reset();
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.ops[0].osc.is_running()
+    }
+
+    #[inline]
+    fn _next(&mut self) -> Option<i16> {
+        if !self.is_running() {
+            return None;
+        }
+        let [carrier, modulator] = &mut self.ops;
+        let m = modulator.next(0);
+        let y = carrier.next(modulator.modulation(m));
+        Some(carrier.output(y).clamp(i16::MIN as i32, i16::MAX as i32) as i16)
+    }
+}
+
+impl Default for FmVoice2 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Routing of the four operators of a `FmVoice4`. Operator numbers start at
+/// 1, "a -> b" means a modulates b, carriers are summed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Algorithm {
+    /// 4 -> 3 -> 2 -> 1
+    #[default]
+    Stack,
+    /// (2 -> 1) + (4 -> 3)
+    TwoStacks,
+    /// 2, 3 and 4 all modulate 1
+    Branch,
+    /// 4 -> 3 -> 2 and 4 -> 1, i.e. one modulator feeding two carriers
+    /// through different paths
+    Fork,
+    /// 1 + 2 + 3 + 4, additive
+    Parallel,
+}
+
+/// Four-operator FM voice with selectable algorithm
+pub struct FmVoice4 {
+    pub ops: [Operator; 4],
+    algorithm: Algorithm,
+    mfreq: u32,
+}
+
+impl FmVoice4 {
+    pub fn new() -> Self {
+        Self {
+            ops: [
+                Operator::new(),
+                Operator::new(),
+                Operator::new(),
+                Operator::new(),
+            ],
+            algorithm: Algorithm::Stack,
+            mfreq: A4_MFREQ,
+        }
+    }
+
+    pub fn set_algorithm(&mut self, algorithm: Algorithm) {
+        self.algorithm = algorithm;
+    }
+
+    /// Sets the voice frequency in millihertz and retunes all operators
+    pub fn set_mfreq(&mut self, mfreq: u32) {
+        self.set_freq(MilliHz(mfreq));
+    }
+
+    /// Sets the voice frequency in any unit and retunes all operators
+    pub fn set_freq<F: Into<MilliHz>>(&mut self, freq: F) {
+        self.mfreq = freq.into().0;
+        for op in self.ops.iter_mut() {
+            op.set_mfreq(self.mfreq);
+        }
+    }
+
+    /// Sets the sample rate in any unit, e.g. `Hz(48000)`
+    pub fn set_sample_rate<R: Into<MilliHz>>(&mut self, sample_rate: R) {
+        let sample_rate = sample_rate.into();
+        for op in self.ops.iter_mut() {
+            op.osc.set_sample_rate(sample_rate);
+        }
+        self.set_mfreq(self.mfreq);
+    }
+
+    pub fn start(&mut self) {
+        for op in self.ops.iter_mut() {
+            op.osc.reset_and_start();
+        }
+    }
+
+    pub fn stop(&mut self) {
+        for op in self.ops.iter_mut() {
+            op.osc.stop_and_reset();
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.ops[0].osc.is_running()
+    }
+
+    #[inline]
+    fn _next(&mut self) -> Option<i16> {
+        if !self.is_running() {
+            return None;
+        }
+        let [op1, op2, op3, op4] = &mut self.ops;
+        let out = match self.algorithm {
+            Algorithm::Stack => {
+                let y4 = op4.next(0);
+                let y3 = op3.next(op4.modulation(y4));
+                let y2 = op2.next(op3.modulation(y3));
+                let y1 = op1.next(op2.modulation(y2));
+                op1.output(y1)
+            }
+            Algorithm::TwoStacks => {
+                let y2 = op2.next(0);
+                let y1 = op1.next(op2.modulation(y2));
+                let y4 = op4.next(0);
+                let y3 = op3.next(op4.modulation(y4));
+                (op1.output(y1) + op3.output(y3)) / 2
+            }
+            Algorithm::Branch => {
+                let y2 = op2.next(0);
+                let y3 = op3.next(0);
+                let y4 = op4.next(0);
+                let m = op2
+                    .modulation(y2)
+                    .wrapping_add(op3.modulation(y3))
+                    .wrapping_add(op4.modulation(y4));
+                let y1 = op1.next(m);
+                op1.output(y1)
+            }
+            Algorithm::Fork => {
+                let y4 = op4.next(0);
+                let m4 = op4.modulation(y4);
+                let y3 = op3.next(m4);
+                let y2 = op2.next(op3.modulation(y3));
+                let y1 = op1.next(m4);
+                (op1.output(y1) + op2.output(y2)) / 2
+            }
+            Algorithm::Parallel => {
+                let y: i32 = [op1, op2, op3, op4]
+                    .into_iter()
+                    .map(|op| {
+                        let y = op.next(0);
+                        op.output(y)
+                    })
+                    .sum();
+                y / 4
+            }
+        };
+        Some(out.clamp(i16::MIN as i32, i16::MAX as i32) as i16)
+    }
+}
+
+impl Default for FmVoice4 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for FmVoice2 {
+    type Item = i16;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self._next()
+    }
+}
+
+impl Iterator for FmVoice4 {
+    type Item = i16;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self._next()
+    }
+}
+
+#[cfg(feature = "rodio")]
+impl Source for FmVoice2 {
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.ops[0].osc.sample_rate()
+    }
+
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+#[cfg(feature = "rodio")]
+impl Source for FmVoice4 {
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.ops[0].osc.sample_rate()
+    }
+
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::freq::Hz;
+
+    #[test]
+    fn test_fm_voice2() {
+        let mut plain = WaveTableOsc16::new();
+        plain.set_wavetable(&SINE_I16);
+        plain.set_interpolation(Interpolation::Linear);
+        plain.set_sample_rate(Hz(48000));
+        plain.set_freq(Hz(440));
+        plain.start();
+
+        let mut voice = FmVoice2::new();
+        voice.set_sample_rate(Hz(48000));
+        voice.set_mfreq(440_000);
+        voice.ops[1].set_ratio(2 << 16);
+        voice.ops[1].set_level(0);
+        voice.start();
+
+        // Without modulation the carrier is a plain sine
+        let a: Vec<i16> = plain.by_ref().take(64).collect();
+        let b: Vec<i16> = voice.by_ref().take(64).collect();
+        assert_eq!(a, b);
+
+        // With modulation the output deviates
+        voice.ops[1].set_level(2 << 12);
+        let a: Vec<i16> = plain.take(64).collect();
+        let b: Vec<i16> = voice.take(64).collect();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_fm_voice4_algorithms() {
+        // Renders an algorithm with the given operators (0-based) silenced
+        fn render(algorithm: Algorithm, silent: &[usize]) -> Vec<i16> {
+            let mut voice = FmVoice4::new();
+            voice.set_sample_rate(Hz(48000));
+            voice.set_mfreq(220_000);
+            voice.set_algorithm(algorithm);
+            for &op in silent {
+                voice.ops[op].set_level(0);
+            }
+            voice.start();
+            voice.take(256).collect()
+        }
+
+        let algorithms = [
+            Algorithm::Stack,
+            Algorithm::TwoStacks,
+            Algorithm::Branch,
+            Algorithm::Fork,
+            Algorithm::Parallel,
+        ];
+        let outputs: Vec<Vec<i16>> = algorithms.iter().map(|&a| render(a, &[])).collect();
+        for (i, a) in outputs.iter().enumerate() {
+            assert_eq!(a.len(), 256);
+            assert!(outputs[i + 1..].iter().all(|b| a != b));
+        }
+
+        // Four unmodulated carriers in unison sum to a single sine
+        let mut sine = WaveTableOsc16::new();
+        sine.set_wavetable(&SINE_I16);
+        sine.set_interpolation(Interpolation::Linear);
+        sine.set_sample_rate(Hz(48000));
+        sine.set_freq(Hz(220));
+        sine.start();
+        assert_eq!(outputs[4], sine.take(256).collect::<Vec<_>>());
+
+        // Silent modulators contribute nothing, leaving only 2 -> 1
+        assert_eq!(
+            render(Algorithm::Stack, &[2]),
+            render(Algorithm::Branch, &[2, 3])
+        );
+
+        let mut voice = FmVoice4::new();
+        voice.start();
+        voice.stop();
+        assert_eq!(voice.next(), None);
+    }
+}
@@ -1,2 +1,6 @@
+pub mod fm;
+pub mod lfo;
+pub mod noise;
+pub mod table;
 pub mod wave_table_osc;
 pub mod wave_tables;
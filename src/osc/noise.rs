@@ -0,0 +1,416 @@
+// Provides deterministic, seedable fixed-point noise generators: white, pink
+// (Voss-McCartney), brown (leaky integrated white) and clocked random
+// sample-and-hold.
+
+use crate::freq::{Hz, MilliHz};
+#[cfg(feature = "rodio")]
+use core::time::Duration;
+#[cfg(feature = "rodio")]
+use rodio::source::Source;
+
+/// Seed used by `new()`
+pub const DEFAULT_SEED: u32 = 0x2545_f491;
+
+/// Number of octave rows of the pink noise generator
+const PINK_ROWS: usize = 16;
+
+/// Leak of the brown noise integrator as right shift, i.e. the state decays
+/// by 1/256 per sample which keeps it bounded without audible DC
+const BROWN_LEAK_SHIFT: u32 = 8;
+
+/// Xorshift32 pseudo random number generator
+#[derive(Clone, Debug)]
+pub(crate) struct Xorshift32 {
+    state: u32,
+}
+
+impl Xorshift32 {
+    /// Zero is a fixed point of xorshift and is replaced by the default seed
+    pub(crate) fn new(seed: u32) -> Self {
+        Self {
+            state: if seed == 0 { DEFAULT_SEED } else { seed },
+        }
+    }
+
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.state = x;
+        x
+    }
+
+    /// Returns a full scale i16
+    #[inline]
+    pub(crate) fn next_i16(&mut self) -> i16 {
+        (self.next_u32() >> 16) as i16
+    }
+}
+
+/// Uniform white noise in the full i16 range
+pub struct WhiteNoise {
+    seed: u32,
+    rng: Xorshift32,
+    sample_rate: u32,
+}
+
+impl WhiteNoise {
+    pub fn new() -> Self {
+        Self::with_seed(DEFAULT_SEED)
+    }
+
+    pub fn with_seed(seed: u32) -> Self {
+        Self {
+            seed,
+            rng: Xorshift32::new(seed),
+            sample_rate: 44100,
+        }
+    }
+
+    /// Sets the seed and restarts the sequence
+    pub fn set_seed(&mut self, seed: u32) {
+        self.seed = seed;
+        self.reset();
+    }
+
+    /// Restarts the sequence from the seed
+    pub fn reset(&mut self) {
+        self.rng = Xorshift32::new(self.seed);
+    }
+
+    /// Sets the sample rate in any unit, e.g. `Hz(48000)`
+    pub fn set_sample_rate<R: Into<MilliHz>>(&mut self, sample_rate: R) {
+        self.sample_rate = Hz::from(sample_rate.into()).0;
+    }
+
+    #[inline]
+    pub fn _next(&mut self) -> i16 {
+        self.rng.next_i16()
+    }
+}
+
+/// Pink (-3 dB per octave) noise after Voss-McCartney. Each of 16 rows holds
+/// a random value that is renewed every `2^row` samples, the output is the
+/// sum of all rows and a white noise sample.
+pub struct PinkNoise {
+    seed: u32,
+    rng: Xorshift32,
+    rows: [i32; PINK_ROWS],
+    sum: i32,
+    counter: u32,
+    sample_rate: u32,
+}
+
+impl PinkNoise {
+    pub fn new() -> Self {
+        Self::with_seed(DEFAULT_SEED)
+    }
+
+    pub fn with_seed(seed: u32) -> Self {
+        Self {
+            seed,
+            rng: Xorshift32::new(seed),
+            rows: [0; PINK_ROWS],
+            sum: 0,
+            counter: 0,
+            sample_rate: 44100,
+        }
+    }
+
+    /// Sets the seed and restarts the sequence
+    pub fn set_seed(&mut self, seed: u32) {
+        self.seed = seed;
+        self.reset();
+    }
+
+    /// Restarts the sequence from the seed
+    pub fn reset(&mut self) {
+        self.rng = Xorshift32::new(self.seed);
+        self.rows = [0; PINK_ROWS];
+        self.sum = 0;
+        self.counter = 0;
+    }
+
+    /// Sets the sample rate in any unit, e.g. `Hz(48000)`
+    pub fn set_sample_rate<R: Into<MilliHz>>(&mut self, sample_rate: R) {
+        self.sample_rate = Hz::from(sample_rate.into()).0;
+    }
+
+    /// Rows and white noise are scaled to 1/16 of full scale, so their sum
+    /// only rarely saturates
+    #[inline]
+    pub fn _next(&mut self) -> i16 {
+        self.counter = self.counter.wrapping_add(1);
+        let row = self.counter.trailing_zeros() as usize;
+        if row < PINK_ROWS {
+            let value = (self.rng.next_i16() >> 4) as i32;
+            self.sum += value - self.rows[row];
+            self.rows[row] = value;
+        }
+        let white = (self.rng.next_i16() >> 4) as i32;
+        (self.sum + white).clamp(i16::MIN as i32, i16::MAX as i32) as i16
+    }
+}
+
+/// Brown (-6 dB per octave) noise from leaky integrated white noise
+pub struct BrownNoise {
+    seed: u32,
+    rng: Xorshift32,
+    /// Integrator state, 32 times the output
+    state: i32,
+    sample_rate: u32,
+}
+
+impl BrownNoise {
+    pub fn new() -> Self {
+        Self::with_seed(DEFAULT_SEED)
+    }
+
+    pub fn with_seed(seed: u32) -> Self {
+        Self {
+            seed,
+            rng: Xorshift32::new(seed),
+            state: 0,
+            sample_rate: 44100,
+        }
+    }
+
+    /// Sets the seed and restarts the sequence
+    pub fn set_seed(&mut self, seed: u32) {
+        self.seed = seed;
+        self.reset();
+    }
+
+    /// Restarts the sequence from the seed
+    pub fn reset(&mut self) {
+        self.rng = Xorshift32::new(self.seed);
+        self.state = 0;
+    }
+
+    /// Sets the sample rate in any unit, e.g. `Hz(48000)`
+    pub fn set_sample_rate<R: Into<MilliHz>>(&mut self, sample_rate: R) {
+        self.sample_rate = Hz::from(sample_rate.into()).0;
+    }
+
+    #[inline]
+    pub fn _next(&mut self) -> i16 {
+        let white = self.rng.next_i16() as i32;
+        self.state += white - (self.state >> BROWN_LEAK_SHIFT);
+        // The state's standard deviation is about 11 times the white noise's,
+        // scaled down it is about a third of full scale
+        (self.state >> 5).clamp(i16::MIN as i32, i16::MAX as i32) as i16
+    }
+}
+
+/// Random values held for one clock period, e.g. as a modulation source
+pub struct SampleAndHold {
+    seed: u32,
+    rng: Xorshift32,
+    value: i16,
+
+    mrate: u32,
+    msample_rate: u32,
+    /// Clock phase, the full u32 range covers one period
+    phi: u32,
+    delta_phi: u32,
+}
+
+impl SampleAndHold {
+    pub fn new() -> Self {
+        Self::with_seed(DEFAULT_SEED)
+    }
+
+    pub fn with_seed(seed: u32) -> Self {
+        let mut sample_and_hold = Self {
+            seed,
+            rng: Xorshift32::new(seed),
+            value: 0,
+
+            mrate: 1000,
+            msample_rate: 44100 * 1000,
+            phi: 0,
+            delta_phi: 0,
+        };
+        sample_and_hold.update_delta_phi();
+        sample_and_hold.reset();
+        sample_and_hold
+    }
+
+    fn update_delta_phi(&mut self) {
+        let msample_rate = self.msample_rate as u128;
+        let delta_phi = (((self.mrate as u128) << 32) + msample_rate / 2)
+            .checked_div(msample_rate)
+            .unwrap_or(0);
+        self.delta_phi = delta_phi.min(u32::MAX as u128) as u32;
+    }
+
+    /// Sets the seed and restarts the sequence
+    pub fn set_seed(&mut self, seed: u32) {
+        self.seed = seed;
+        self.reset();
+    }
+
+    /// Restarts the sequence from the seed and the clock from zero. The
+    /// first value is sampled immediately.
+    pub fn reset(&mut self) {
+        self.rng = Xorshift32::new(self.seed);
+        self.phi = 0;
+        self.trigger();
+    }
+
+    /// Sets the clock rate in any unit. A rate of zero only changes the value
+    /// on `trigger`.
+    pub fn set_rate<F: Into<MilliHz>>(&mut self, rate: F) {
+        self.mrate = rate.into().0;
+        self.update_delta_phi();
+    }
+
+    pub fn set_msample_rate(&mut self, msample_rate: u32) {
+        self.msample_rate = msample_rate;
+        self.update_delta_phi();
+    }
+
+    /// Sets the sample rate in any unit, e.g. `Hz(48000)`
+    pub fn set_sample_rate<R: Into<MilliHz>>(&mut self, sample_rate: R) {
+        self.set_msample_rate(sample_rate.into().0);
+    }
+
+    /// Samples a new random value immediately, e.g. from an external clock
+    pub fn trigger(&mut self) {
+        self.value = self.rng.next_i16();
+    }
+
+    /// Returns the held value
+    pub fn value(&self) -> i16 {
+        self.value
+    }
+
+    /// Returns the held value and advances the clock, sampling a new value
+    /// when a period ends
+    #[inline]
+    pub fn _next(&mut self) -> i16 {
+        let value = self.value;
+        let phi = self.phi.wrapping_add(self.delta_phi);
+        if phi < self.phi {
+            self.trigger();
+        }
+        self.phi = phi;
+        value
+    }
+}
+
+/// Implements `Default`, `Iterator` and `Source` for a noise generator
+macro_rules! impl_noise {
+    ($t:ty, |$s:ident| $sample_rate:expr) => {
+        impl Default for $t {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl Iterator for $t {
+            type Item = i16;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                Some(self._next())
+            }
+        }
+
+        #[cfg(feature = "rodio")]
+        impl Source for $t {
+            fn channels(&self) -> u16 {
+                1
+            }
+
+            fn sample_rate(&self) -> u32 {
+                let $s = self;
+                $sample_rate
+            }
+
+            fn current_frame_len(&self) -> Option<usize> {
+                None
+            }
+
+            fn total_duration(&self) -> Option<Duration> {
+                None
+            }
+        }
+    };
+}
+
+impl_noise!(WhiteNoise, |noise| noise.sample_rate);
+impl_noise!(PinkNoise, |noise| noise.sample_rate);
+impl_noise!(BrownNoise, |noise| noise.sample_rate);
+impl_noise!(SampleAndHold, |noise| Hz::from(MilliHz(noise.msample_rate))
+    .0);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Returns the mean and the mean absolute difference of successive
+    /// samples, the latter being small for noise with weak high frequencies
+    fn stats<I: Iterator<Item = i16>>(noise: I, len: usize) -> (i64, i64) {
+        let samples: Vec<i64> = noise.take(len).map(|x| x as i64).collect();
+        let mean = samples.iter().sum::<i64>() / len as i64;
+        let diff = samples.windows(2).map(|w| (w[1] - w[0]).abs()).sum::<i64>() / len as i64;
+        (mean, diff)
+    }
+
+    #[test]
+    fn test_noise_colors() {
+        // Sequences are deterministic and depend on the seed
+        let a: Vec<i16> = WhiteNoise::with_seed(1).take(16).collect();
+        let mut white = WhiteNoise::with_seed(1);
+        assert_eq!(white.by_ref().take(16).collect::<Vec<_>>(), a);
+        white.set_seed(2);
+        assert_ne!(white.by_ref().take(16).collect::<Vec<_>>(), a);
+        white.set_seed(1);
+        assert_eq!(white.by_ref().take(16).collect::<Vec<_>>(), a);
+
+        let (mean, white_diff) = stats(WhiteNoise::new(), 1 << 16);
+        assert!(mean.abs() < 500);
+        let (mean, pink_diff) = stats(PinkNoise::new(), 1 << 16);
+        assert!(mean.abs() < 2000);
+        let (mean, brown_diff) = stats(BrownNoise::new(), 1 << 16);
+        assert!(mean.abs() < 4000);
+
+        // Redder noise changes less from sample to sample
+        assert!(white_diff > 2 * pink_diff);
+        assert!(pink_diff > 2 * brown_diff);
+
+        // Pink and brown noise still use a good part of the range
+        assert!(PinkNoise::new().take(1 << 16).any(|x| x.abs() > 8000));
+        assert!(BrownNoise::new().take(1 << 16).any(|x| x.abs() > 8000));
+    }
+
+    #[test]
+    fn test_sample_and_hold() {
+        let mut sample_and_hold = SampleAndHold::new();
+        sample_and_hold.set_sample_rate(Hz(100));
+        sample_and_hold.set_rate(Hz(10));
+
+        // A new value every 10 samples, starting with the first sample
+        let samples: Vec<i16> = sample_and_hold.by_ref().take(30).collect();
+        assert_ne!(samples[0], 0);
+        assert!(samples[..10].iter().all(|&x| x == samples[0]));
+        assert!(samples[10..20].iter().all(|&x| x == samples[10]));
+        assert_ne!(samples[9], samples[10]);
+
+        // Without a clock, only triggers change the value
+        sample_and_hold.set_rate(MilliHz(0));
+        let held = sample_and_hold.value();
+        assert!(sample_and_hold.by_ref().take(100).all(|x| x == held));
+        sample_and_hold.trigger();
+        assert_ne!(sample_and_hold._next(), held);
+
+        // Without a sample rate, the clock stops as well
+        sample_and_hold.set_rate(Hz(10));
+        sample_and_hold.set_sample_rate(Hz(0));
+        let held = sample_and_hold.value();
+        assert!(sample_and_hold.take(100).all(|x| x == held));
+    }
+}
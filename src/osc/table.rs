@@ -0,0 +1,235 @@
+// Provides the wavetable type of the oscillators. Tables either borrow static
+// data, e.g. the generated tables in `wave_tables`, or share data built or
+// loaded at runtime.
+
+#[cfg(feature = "std")]
+use crate::wav::{WavReader, WavSample};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::ops::{Deref, Range};
+#[cfg(feature = "std")]
+use std::fs::File;
+#[cfg(feature = "std")]
+use std::io::{self, BufReader, Read};
+#[cfg(feature = "std")]
+use std::path::Path;
+
+/// Cycle length of Serum wavetables without a `clm ` chunk
+pub const SERUM_FRAME_LEN: usize = 2048;
+
+#[derive(Debug)]
+enum Data<T: 'static> {
+    Static(&'static [T]),
+    Shared(Arc<[T]>),
+}
+
+// Derived `Clone` would require `T: Clone`
+impl<T> Clone for Data<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Data::Static(samples) => Data::Static(samples),
+            Data::Shared(samples) => Data::Shared(samples.clone()),
+        }
+    }
+}
+
+/// Wavetable of one or more single cycle frames of equal length. Cloning and
+/// slicing never copy the samples.
+#[derive(Debug)]
+pub struct Table<T: 'static> {
+    data: Data<T>,
+    range: Range<usize>,
+    frame_len: usize,
+}
+
+impl<T> Clone for Table<T> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            range: self.range.clone(),
+            frame_len: self.frame_len,
+        }
+    }
+}
+
+impl<T> Table<T> {
+    /// Borrows static samples as a single frame
+    pub const fn from_static(samples: &'static [T]) -> Self {
+        Self {
+            data: Data::Static(samples),
+            range: 0..samples.len(),
+            frame_len: samples.len(),
+        }
+    }
+
+    /// Shares samples as a single frame
+    pub fn from_shared(samples: Arc<[T]>) -> Self {
+        let len = samples.len();
+        Self {
+            data: Data::Shared(samples),
+            range: 0..len,
+            frame_len: len,
+        }
+    }
+
+    /// Splits the table into frames of `frame_len` samples. Returns `None`
+    /// if the length is not a multiple of `frame_len`.
+    pub fn with_frame_len(mut self, frame_len: usize) -> Option<Self> {
+        if frame_len == 0 || !self.len().is_multiple_of(frame_len) {
+            return None;
+        }
+        self.frame_len = frame_len;
+        Some(self)
+    }
+
+    /// Returns the number of samples per frame
+    pub fn frame_len(&self) -> usize {
+        self.frame_len
+    }
+
+    /// Returns the number of frames
+    pub fn frames(&self) -> usize {
+        self.len().checked_div(self.frame_len).unwrap_or(0)
+    }
+
+    /// Returns one frame as a table without copying
+    pub fn frame(&self, index: usize) -> Option<Self> {
+        if index >= self.frames() {
+            return None;
+        }
+        let start = self.range.start + index * self.frame_len;
+        Some(Self {
+            data: self.data.clone(),
+            range: start..start + self.frame_len,
+            frame_len: self.frame_len,
+        })
+    }
+
+    /// Returns whether the samples are static, i.e. no allocation is shared
+    pub fn is_static(&self) -> bool {
+        matches!(self.data, Data::Static(_))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: WavSample> Table<T> {
+    /// Loads the first channel of a WAV file. The frame length is taken from
+    /// the `clm ` chunk of Serum wavetables. Without one, files of several
+    /// times `SERUM_FRAME_LEN` samples are split into frames of that length
+    /// and all other files are a single frame.
+    pub fn from_wav<R: Read>(reader: R) -> io::Result<Self> {
+        let wav = WavReader::new(reader)?;
+        let table = Self::from(wav.channel(0).collect::<Vec<T>>());
+        let len = table.len();
+        let frame_len = match wav.cycle_len() {
+            Some(cycle_len) => cycle_len,
+            None if len > SERUM_FRAME_LEN && len.is_multiple_of(SERUM_FRAME_LEN) => SERUM_FRAME_LEN,
+            None => len,
+        };
+        table.with_frame_len(frame_len).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{} samples are no multiple of {}", len, frame_len),
+            )
+        })
+    }
+
+    /// Loads the first channel of a WAV file at `path`
+    pub fn from_wav_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::from_wav(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<T> Deref for Table<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        match &self.data {
+            Data::Static(samples) => &samples[self.range.clone()],
+            Data::Shared(samples) => &samples[self.range.clone()],
+        }
+    }
+}
+
+impl<T> From<&'static [T]> for Table<T> {
+    fn from(samples: &'static [T]) -> Self {
+        Self::from_static(samples)
+    }
+}
+
+impl<T, const N: usize> From<&'static [T; N]> for Table<T> {
+    fn from(samples: &'static [T; N]) -> Self {
+        Self::from_static(samples)
+    }
+}
+
+impl<T> From<Vec<T>> for Table<T> {
+    fn from(samples: Vec<T>) -> Self {
+        Self::from_shared(samples.into())
+    }
+}
+
+impl<T> From<Arc<[T]>> for Table<T> {
+    fn from(samples: Arc<[T]>) -> Self {
+        Self::from_shared(samples)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(feature = "std")]
+    use crate::wav::{WavFormat, WavSpec, WavWriter};
+    #[cfg(feature = "std")]
+    use std::io::Cursor;
+
+    #[test]
+    fn test_frames() {
+        static RAMP: [i16; 6] = [0, 1, 2, 3, 4, 5];
+        let table = Table::from(&RAMP);
+        assert!(table.is_static());
+        assert_eq!(table.frames(), 1);
+        assert!(table.clone().with_frame_len(4).is_none());
+
+        let table = table.with_frame_len(2).unwrap();
+        assert_eq!(table.frames(), 3);
+        assert_eq!(&*table.frame(1).unwrap(), &[2, 3]);
+        assert!(table.frame(3).is_none());
+
+        let shared = Table::from(vec![1_i32, 2, 3]);
+        assert!(!shared.is_static());
+        assert_eq!(&*shared, &[1, 2, 3]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_from_wav() {
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: 44100,
+            format: WavFormat::Float32,
+        };
+        let mut wav = WavWriter::new(Cursor::new(Vec::new()), spec).unwrap();
+        for i in 0..2 * SERUM_FRAME_LEN {
+            wav.write_sample(if i < SERUM_FRAME_LEN { 0.5_f32 } else { -0.5 })
+                .unwrap();
+        }
+        let bytes = wav.finalize().unwrap().into_inner();
+
+        let table = Table::<i16>::from_wav(bytes.as_slice()).unwrap();
+        assert_eq!(table.frame_len(), SERUM_FRAME_LEN);
+        assert_eq!(table.frames(), 2);
+        assert_eq!(table.frame(1).unwrap()[0], -16384);
+
+        // A single cycle of another length is one frame
+        let mut wav = WavWriter::new(Cursor::new(Vec::new()), spec).unwrap();
+        for _ in 0..3000 {
+            wav.write_sample(0.25_f32).unwrap();
+        }
+        let bytes = wav.finalize().unwrap().into_inner();
+        let table = Table::<i16>::from_wav(bytes.as_slice()).unwrap();
+        assert_eq!(table.frame_len(), 3000);
+        assert_eq!(table.frames(), 1);
+    }
+}
@@ -1,82 +1,524 @@
-/// Convenience trait to convert between different frequency units
-/// mHz is millihertz, bHz is 1/1024 Hz
-trait Frequency<T> {
-    fn to_mHz(&self) -> T;
-    fn from_mHz(&self) -> T;
-    fn to_bHz(&self) -> T;
-    fn from_bHz(&self) -> T;
+use crate::fixed::{Q15, Q31};
+use crate::freq::{exp2_q16, MilliHz};
+use crate::midi::A4_MFREQ;
+use crate::osc::table::Table;
+
+/// Sample types that can be read from a wavetable and interpolated.
+/// Fractional positions are given in Q16, i.e. `1 << 16` corresponds to one
+/// table index.
+pub trait Sample: Copy {
+    /// Silence, written by stopped oscillators
+    const ZERO: Self;
+
+    /// Linear interpolation between `x0` and `x1`
+    fn lerp(x0: Self, x1: Self, frac: u32) -> Self;
+    /// Cubic Hermite (Catmull-Rom) interpolation between `x0` and `x1`
+    fn hermite(xm1: Self, x0: Self, x1: Self, x2: Self, frac: u32) -> Self;
 }
 
-impl Frequency<u32> for u32 {
-    fn to_mHz(&self) -> u32 {
-        self * 1000
-    }
-    fn from_mHz(&self) -> u32 {
-        self / 1000
-    }
-    fn to_bHz(&self) -> u32 {
-        self * 1024
-    }
-    fn from_bHz(&self) -> u32 {
-        self / 1024
-    }
+macro_rules! impl_sample {
+    ($t:ty) => {
+        impl Sample for $t {
+            const ZERO: Self = 0;
+
+            #[inline]
+            fn lerp(x0: Self, x1: Self, frac: u32) -> Self {
+                let (x0, x1) = (x0 as i64, x1 as i64);
+                (x0 + (((x1 - x0) * frac as i64) >> 16)) as Self
+            }
+
+            #[inline]
+            fn hermite(xm1: Self, x0: Self, x1: Self, x2: Self, frac: u32) -> Self {
+                let (xm1, x0, x1, x2) = (xm1 as i64, x0 as i64, x1 as i64, x2 as i64);
+                let t = frac as i64;
+                // Coefficients are scaled by 2 to stay in integer arithmetic
+                let c1 = x1 - xm1;
+                let c2 = 2 * xm1 - 5 * x0 + 4 * x1 - x2;
+                let c3 = (x2 - xm1) + 3 * (x0 - x1);
+                let y = x0 + ((((((c3 * t) >> 16) + c2) * t >> 16) + c1) * t >> 17);
+                // The Hermite spline may overshoot the table's peak values
+                y.clamp(<$t>::MIN as i64, <$t>::MAX as i64) as Self
+            }
+        }
+    };
+}
+
+impl_sample!(i8);
+impl_sample!(i16);
+impl_sample!(i32);
+
+/// Implements `Sample` for a fixed-point type by interpolating its bits
+macro_rules! impl_sample_fixed {
+    ($q:ty, $t:ty) => {
+        impl Sample for $q {
+            const ZERO: Self = <$q>::ZERO;
+
+            #[inline]
+            fn lerp(x0: Self, x1: Self, frac: u32) -> Self {
+                Self(<$t>::lerp(x0.0, x1.0, frac))
+            }
+
+            #[inline]
+            fn hermite(xm1: Self, x0: Self, x1: Self, x2: Self, frac: u32) -> Self {
+                Self(<$t>::hermite(xm1.0, x0.0, x1.0, x2.0, frac))
+            }
+        }
+    };
+}
+
+impl_sample_fixed!(Q15, i16);
+impl_sample_fixed!(Q31, i32);
+
+/// Implements `Sample` for a float type with full scale at ±1. The fraction
+/// is converted exactly, so floats follow the same phase as the integers.
+macro_rules! impl_sample_float {
+    ($t:ty) => {
+        impl Sample for $t {
+            const ZERO: Self = 0.0;
+
+            #[inline]
+            fn lerp(x0: Self, x1: Self, frac: u32) -> Self {
+                x0 + (x1 - x0) * (frac as $t / 65536.0)
+            }
+
+            #[inline]
+            fn hermite(xm1: Self, x0: Self, x1: Self, x2: Self, frac: u32) -> Self {
+                let t = frac as $t / 65536.0;
+                let c1 = 0.5 * (x1 - xm1);
+                let c2 = xm1 - 2.5 * x0 + 2.0 * x1 - 0.5 * x2;
+                let c3 = 0.5 * (x2 - xm1) + 1.5 * (x0 - x1);
+                let y = x0 + ((c3 * t + c2) * t + c1) * t;
+                // Clamped to full scale like the integer samples
+                y.clamp(-1.0, 1.0)
+            }
+        }
+    };
+}
+
+impl_sample_float!(f32);
+impl_sample_float!(f64);
+
+/// Interpolation between adjacent wavetable samples
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Truncate the phase to the nearest lower table index
+    #[default]
+    None,
+    /// Linear interpolation between two samples
+    Linear,
+    /// Cubic Hermite interpolation over four samples
+    Cubic,
+}
+
+/// Response of an oscillator to a sync event of another oscillator
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SyncMode {
+    /// Sync events are ignored
+    #[default]
+    Off,
+    /// Hard sync, the phase restarts from zero
+    Hard,
+    /// Soft sync, the phase reverses its direction
+    Soft,
+}
+
+/// Parameter targets of `process_block`, reached at the last sample of the
+/// block and interpolated linearly from the current values. `None` keeps a
+/// parameter constant.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Ramp {
+    /// Frequency in millihertz
+    pub mfreq: Option<u32>,
+    /// Morph position in Q16
+    pub position: Option<u32>,
+    /// Phase modulation input, see `set_phase_mod`
+    pub phase_mod: Option<i32>,
 }
 
 /// Stateful wavetable signal generator
 pub struct WaveTableOscillator<T: 'static> {
     repeat: bool,
     running: bool,
+    interpolation: Interpolation,
 
     mfreq: u32,
     msample_rate: u32,
 
-    wavetable: &'static [T],
+    wavetable: Table<T>,
+    levels: usize,
+    level: usize,
+
+    /// Morph position over all frames in Q16, the selected frame and the
+    /// crossfade towards the next frame in Q16
+    position: u32,
+    frame: usize,
+    frame_frac: u32,
+
+    /// Phase accumulator, the full u32 range covers one period
+    phi: u32,
+    delta_phi: u32,
 
-    phi: i32,
-    phi_max: i32,
-    delta_phi: i32,
+    /// Modulation inputs and the resulting signed phase increment
+    phase_mod: i32,
+    fm_linear: i32,
+    fm_exp: u32,
+    increment: i64,
+
+    /// Sync state and the sub-sample position (Q16) of the last phase wrap
+    sync_mode: SyncMode,
+    reversed: bool,
+    wrap: Option<u32>,
 
     idx: usize,
     idx_max: usize,
+    frac: u32,
 }
 
-impl<T> WaveTableOscillator<T> {
+impl<T: Sample> WaveTableOscillator<T> {
+    /// Updates the table index and its fractional part (Q16) from the phase.
+    /// Scaling the phase by the table length works for any length, not only
+    /// powers of two.
     fn update_idx(&mut self) {
-        self.idx = (((self.idx_max as i32) * self.phi) / self.phi_max) as usize;
+        let phi = self.phi.wrapping_add(self.phase_mod as u32);
+        let pos = (self.idx_max as u64) * (phi as u64);
+        self.idx = (pos >> 32) as usize;
+        self.frac = (pos as u32) >> 16;
+    }
+
+    /// Reads the wavetable at the current index, crossfading between the two
+    /// frames around the morph position
+    #[inline]
+    fn read(&self) -> T {
+        let offset = self.level + self.frame;
+        let y = self.read_frame(offset);
+        if self.frame_frac == 0 {
+            y
+        } else {
+            T::lerp(y, self.read_frame(offset + 1), self.frame_frac)
+        }
+    }
+
+    /// Reads the frame (or mipmap level) at `offset` at the current index
+    /// using the selected interpolation
+    #[inline]
+    fn read_frame(&self, offset: usize) -> T {
+        let len = self.idx_max;
+        let table = &self.wavetable[offset * len..(offset + 1) * len];
+        let idx = self.idx;
+        match self.interpolation {
+            Interpolation::None => table[idx],
+            Interpolation::Linear => T::lerp(table[idx], table[(idx + 1) % len], self.frac),
+            Interpolation::Cubic => T::hermite(
+                table[(idx + len - 1) % len],
+                table[idx],
+                table[(idx + 1) % len],
+                table[(idx + 2) % len],
+                self.frac,
+            ),
+        }
     }
 
+    /// Computes the phase increment 2^32 * mfreq / msample_rate, rounded to
+    /// the nearest integer. Frequencies at or above the sample rate saturate
+    /// just below one period per sample.
     fn update_delta_phi(&mut self) {
-        self.delta_phi =
-            (((self.mfreq as i64) * (self.phi_max as i64)) / (self.msample_rate as i64)) as i32;
+        let msample_rate = self.msample_rate as u128;
+        let delta_phi = (((self.mfreq as u128) << 32) + msample_rate / 2)
+            .checked_div(msample_rate)
+            .unwrap_or(0);
+        self.delta_phi = delta_phi.min(u32::MAX as u128) as u32;
+        self.update_increment();
+    }
+
+    /// Applies linear and exponential frequency modulation to the phase
+    /// increment. The result may be negative (through-zero FM), in which case
+    /// the phase runs backwards. Like `delta_phi`, it saturates just below
+    /// one period per sample in either direction.
+    fn update_increment(&mut self) {
+        let max = u32::MAX as i64;
+        self.increment = ((((self.delta_phi as u64) * (self.fm_exp as u64)) >> 16) as i64
+            + self.fm_linear as i64)
+            .clamp(-max, max);
+        self.update_level();
+    }
+
+    /// Selects the mipmap level with the most harmonics that still lie below
+    /// the Nyquist frequency. Level `l` contains `(idx_max / 2) >> l`
+    /// harmonics.
+    fn update_level(&mut self) {
+        let mut level = 0;
+        if let Some(harmonics_max) = (1_u64 << 31).checked_div(self.increment.unsigned_abs()) {
+            let harmonics_max = harmonics_max as usize;
+            while level + 1 < self.levels && ((self.idx_max / 2) >> level) > harmonics_max {
+                level += 1;
+            }
+        }
+        self.level = level;
     }
 
     /// Increments phase accumulator and returns either the next sample or None
     /// if the generator is not running
     #[inline]
-    pub fn _next(&mut self) -> Option<T>
-    where
-        T: Copy,
-    {
-        self.phi += self.delta_phi;
-        if self.phi > self.phi_max {
-            self.phi -= self.phi_max;
-            if !self.repeat {
-                self.stop_and_reset();
-            }
+    pub fn _next(&mut self) -> Option<T> {
+        let increment = if self.reversed {
+            -self.increment
+        } else {
+            self.increment
+        };
+        let phi = self.phi.wrapping_add(increment as u32);
+        let wrapped = if increment < 0 {
+            phi > self.phi
+        } else {
+            phi < self.phi
         };
+        self.wrap = None;
+        if wrapped {
+            // Phase travelled since the wrap, relative to the increment
+            let past = if increment < 0 {
+                phi.wrapping_neg()
+            } else {
+                phi
+            };
+            let frac = ((past as u64) << 16) / increment.unsigned_abs();
+            self.wrap = Some(frac.min(1 << 16) as u32);
+        }
+        self.phi = phi;
+        if wrapped && !self.repeat {
+            self.stop_and_reset();
+        }
         if self.is_running() {
             self.update_idx();
-            let out = self.wavetable[self.idx];
-            Some(out)
+            Some(self.read())
         } else {
             None
         }
     }
 
-    /// Set the wavetable
-    pub fn set_wavetable(&mut self, wavetable: &'static [T]) {
-        self.wavetable = wavetable;
-        self.idx_max = self.wavetable.len();
+    /// Renders `out.len()` samples and returns how many were rendered before
+    /// the oscillator stopped, the rest is silence. Repeating oscillators
+    /// skip the per-sample checks of `_next`, only the last sample updates
+    /// `wrapped`.
+    pub fn fill(&mut self, out: &mut [T]) -> usize {
+        if !self.is_running() {
+            out.fill(T::ZERO);
+            return 0;
+        }
+        if !self.repeat {
+            // The oscillator may stop within the block, from then on the
+            // phase must not advance
+            let mut len = 0;
+            while let Some(y) = out.get_mut(len) {
+                match self._next() {
+                    Some(x) => *y = x,
+                    None => break,
+                }
+                len += 1;
+            }
+            out[len..].fill(T::ZERO);
+            return len;
+        }
+        let Some((last, head)) = out.split_last_mut() else {
+            return 0;
+        };
+        let increment = if self.reversed {
+            -self.increment
+        } else {
+            self.increment
+        } as u32;
+        for y in head.iter_mut() {
+            self.phi = self.phi.wrapping_add(increment);
+            self.update_idx();
+            *y = self.read();
+        }
+        *last = self._next().unwrap_or(T::ZERO);
+        out.len()
+    }
+
+    /// Renders a block like `fill` while ramping parameters towards the
+    /// targets of `ramp`, e.g. to glide or to smooth parameter changes of an
+    /// audio callback. The mipmap level is chosen for the higher frequency
+    /// of the ramp.
+    pub fn process_block(&mut self, out: &mut [T], ramp: Ramp) -> usize {
+        if ramp == Ramp::default() || out.is_empty() {
+            return self.fill(out);
+        }
+        if !self.is_running() {
+            // The phase must not advance, the parameters still jump to
+            // their targets
+            out.fill(T::ZERO);
+            if let Some(mfreq) = ramp.mfreq {
+                self.set_mfreq(mfreq);
+            }
+            if let Some(position) = ramp.position {
+                self.set_position(position);
+            }
+            if let Some(phase_mod) = ramp.phase_mod {
+                self.set_phase_mod(phase_mod);
+            }
+            return 0;
+        }
+        let n = out.len() as i64;
+        let start_increment = self.increment;
+        let start_position = self.position as i64;
+        let start_phase_mod = self.phase_mod as i64;
+        if let Some(mfreq) = ramp.mfreq {
+            self.set_mfreq(mfreq);
+        }
+        let end_increment = self.increment;
+        self.increment = if start_increment.abs() > end_increment.abs() {
+            start_increment
+        } else {
+            end_increment
+        };
+        self.update_level();
+        let end_position = ramp
+            .position
+            .map_or(start_position, |p| p.min(1 << 16) as i64);
+        // The phase offset wraps, so it takes the shorter way around
+        let delta_phase_mod = ramp
+            .phase_mod
+            .map_or(0, |p| p.wrapping_sub(self.phase_mod) as i64);
+
+        let mut len = 0;
+        while let Some(y) = out.get_mut(len) {
+            let i = len as i64 + 1;
+            self.increment = start_increment + (end_increment - start_increment) * i / n;
+            if ramp.position.is_some() {
+                self.position = (start_position + (end_position - start_position) * i / n) as u32;
+                self.update_frame();
+            }
+            self.phase_mod = (start_phase_mod + delta_phase_mod * i / n) as i32;
+            match self._next() {
+                Some(x) => *y = x,
+                // Stopped, the parameters still reach their targets
+                None => break,
+            }
+            len += 1;
+        }
+        out[len..].fill(T::ZERO);
+        self.increment = end_increment;
+        if ramp.position.is_some() {
+            self.position = end_position as u32;
+            self.update_frame();
+        }
+        if let Some(phase_mod) = ramp.phase_mod {
+            self.phase_mod = phase_mod;
+        }
+        self.update_level();
+        len
+    }
+
+    /// Returns the sub-sample position (Q16) of the phase wrap if the last
+    /// sample completed a period. The position is the fraction of the sample
+    /// period that has elapsed since the wrap.
+    pub fn wrapped(&self) -> Option<u32> {
+        self.wrap
+    }
+
+    /// Sets how the oscillator responds to sync events
+    pub fn set_sync_mode(&mut self, sync_mode: SyncMode) {
+        self.sync_mode = sync_mode;
+        self.reversed = false;
+    }
+
+    /// Sync input, to be called before `_next` with the sub-sample position
+    /// (Q16) of the master's phase wrap as returned by `wrapped`. The phase
+    /// is adjusted such that the following sample is sub-sample accurate.
+    pub fn sync(&mut self, frac: u32) {
+        let increment = if self.reversed {
+            -self.increment
+        } else {
+            self.increment
+        };
+        // Phase the oscillator would still travel before the sync event
+        let rest = (increment * ((1 << 16) - frac.min(1 << 16) as i64)) >> 16;
+        match self.sync_mode {
+            SyncMode::Off => {}
+            SyncMode::Hard => {
+                self.phi = (-rest) as u32;
+            }
+            SyncMode::Soft => {
+                self.phi = self.phi.wrapping_add((2 * rest) as u32);
+                self.reversed = !self.reversed;
+            }
+        }
+    }
+
+    /// Applies a sync event if the master's last sample wrapped. Call after
+    /// advancing the master and before advancing this oscillator.
+    pub fn sync_to<U: Sample>(&mut self, master: &WaveTableOscillator<U>) {
+        if let Some(frac) = master.wrapped() {
+            self.sync(frac);
+        }
+    }
+
+    /// Set the wavetable, either static such as `&SINE_I16` or a `Table`
+    /// built or loaded at runtime. Multi-frame tables are scanned by the
+    /// morph position.
+    pub fn set_wavetable<W: Into<Table<T>>>(&mut self, wavetable: W) {
+        self.wavetable = wavetable.into();
+        self.idx_max = self.wavetable.frame_len();
+        self.levels = 1;
+        self.level = 0;
+        self.update_frame();
+    }
+
+    /// Set a static stack of frames to morph through, such as `MORPH_I16`
+    pub fn set_frames<const N: usize>(&mut self, frames: &'static [[T; N]]) {
+        // A stack of frames has the same layout as a mipmap
+        let table = Table::from_static(frames.as_flattened()).with_frame_len(N);
+        self.set_wavetable(table.unwrap_or(Table::from_static(&[])));
+    }
+
+    /// Sets the morph position in Q16, where 0 plays the first frame and
+    /// `1 << 16` the last one. Positions in between crossfade linearly
+    /// between adjacent frames.
+    pub fn set_position(&mut self, position: u32) {
+        self.position = position.min(1 << 16);
+        self.update_frame();
+    }
+
+    /// Returns the morph position in Q16
+    pub fn position(&self) -> u32 {
+        self.position
+    }
+
+    fn update_frame(&mut self) {
+        let frames = self.wavetable.frames().max(1) as u64;
+        let pos = self.position as u64 * (frames - 1);
+        self.frame = (pos >> 16) as usize;
+        self.frame_frac = (pos & 0xffff) as u32;
+    }
+
+    /// Set a band-limited wavetable with one level per octave, such as
+    /// `SAW_I16`. The level is chosen from the frequency to avoid aliasing.
+    pub fn set_mipmap<const N: usize>(&mut self, mipmap: &'static [[T; N]]) {
+        self.wavetable = Table::from_static(mipmap.as_flattened());
+        self.idx_max = N;
+        self.levels = mipmap.len();
+        self.frame = 0;
+        self.frame_frac = 0;
+        self.update_level();
+    }
+
+    /// Returns the wavetable
+    pub fn wavetable(&self) -> &Table<T> {
+        &self.wavetable
+    }
+
+    /// Returns the currently selected mipmap level
+    pub fn level(&self) -> usize {
+        self.level
+    }
+
+    /// Set the interpolation mode used to read the wavetable
+    pub fn set_interpolation(&mut self, interpolation: Interpolation) {
+        self.interpolation = interpolation;
+    }
+
+    /// Returns the interpolation mode
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
     }
 
     /// Set repeat to true or false
@@ -97,6 +539,7 @@ impl<T> WaveTableOscillator<T> {
     /// Resets the phase accumulator
     pub fn reset(&mut self) {
         self.phi = 0;
+        self.reversed = false;
     }
 
     /// Resets the phase accumulator and set the generator into "running" mode
@@ -116,80 +559,124 @@ impl<T> WaveTableOscillator<T> {
         self.running
     }
 
-    /// Sets the frequency
+    /// Sets the frequency in millihertz
     pub fn set_mfreq(&mut self, mfreq: u32) {
-        self.mfreq = mfreq;
-        self.update_delta_phi();
+        self.set_freq(MilliHz(mfreq));
     }
 
-    pub fn set_freq(&mut self, freq: u32) {
-        self.mfreq = freq.to_mHz();
+    /// Sets the frequency in any unit, e.g. `Hz(440)` or a `NoteNumber`
+    pub fn set_freq<F: Into<MilliHz>>(&mut self, freq: F) {
+        self.mfreq = freq.into().0;
         self.update_delta_phi();
     }
 
-    pub fn set_msample_rate(&mut self, msample_rate: u32) {
-        self.msample_rate = msample_rate;
-        self.update_delta_phi();
+    /// Returns the frequency
+    pub fn freq(&self) -> MilliHz {
+        MilliHz(self.mfreq)
     }
 
-    pub fn set_sample_rate(&mut self, sample_rate: u32) {
-        self.msample_rate = sample_rate.to_mHz();
-        self.update_delta_phi();
+    /// Sets the phase increment per sample directly, where `1 << 32` is one
+    /// period. Allows tuning finer than one millihertz.
+    pub fn set_delta_phi(&mut self, delta_phi: u32) {
+        self.delta_phi = delta_phi;
+        self.update_increment();
     }
-}
 
-impl WaveTableOscillator<i32> {
-    pub fn new() -> Self {
-        Self {
-            repeat: true,
-            running: false,
+    /// Returns the phase increment per sample
+    pub fn delta_phi(&self) -> u32 {
+        self.delta_phi
+    }
 
-            mfreq: 440.to_mHz(),
-            msample_rate: 44100.to_mHz(),
+    /// Returns the phase, where the full u32 range is one period
+    pub fn phase(&self) -> u32 {
+        self.phi
+    }
 
-            wavetable: &[],
+    /// Sets the phase modulation input, an offset added to the phase when
+    /// reading the wavetable. The full i32 range covers one period.
+    #[inline]
+    pub fn set_phase_mod(&mut self, phase_mod: i32) {
+        self.phase_mod = phase_mod;
+    }
 
-            phi: 0,
-            phi_max: 1 << 16,
-            delta_phi: 0,
+    /// Sets the linear frequency modulation input, an offset added to the
+    /// phase increment per sample. Offsets larger than the phase increment
+    /// result in through-zero FM.
+    #[inline]
+    pub fn set_fm_linear(&mut self, fm_linear: i32) {
+        self.fm_linear = fm_linear;
+        self.update_increment();
+    }
 
-            idx: 0,
-            idx_max: 0,
-        }
+    /// Sets the exponential frequency modulation input in octaves (Q16),
+    /// e.g. `1 << 16` doubles the frequency
+    #[inline]
+    pub fn set_fm_exp(&mut self, octaves: i32) {
+        self.fm_exp = exp2_q16(octaves);
+        self.update_increment();
     }
-}
 
-impl Iterator for WaveTableOscillator<i32> {
-    type Item = i32;
+    /// Returns the modulated, signed phase increment per sample
+    pub fn increment(&self) -> i64 {
+        self.increment
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self._next()
+    pub fn set_msample_rate(&mut self, msample_rate: u32) {
+        self.msample_rate = msample_rate;
+        self.update_delta_phi();
+    }
+
+    /// Sets the sample rate in any unit, e.g. `Hz(48000)`
+    pub fn set_sample_rate<R: Into<MilliHz>>(&mut self, sample_rate: R) {
+        self.set_msample_rate(sample_rate.into().0);
     }
 }
 
-impl WaveTableOscillator<i16> {
+impl<T: Sample> WaveTableOscillator<T> {
     pub fn new() -> Self {
         Self {
             repeat: true,
             running: false,
+            interpolation: Interpolation::None,
+
+            mfreq: A4_MFREQ,
+            msample_rate: 44100 * 1000,
 
-            mfreq: 440.to_mHz(),
-            msample_rate: 44100.to_mHz(),
+            wavetable: Table::from_static(&[]),
+            levels: 1,
+            level: 0,
 
-            wavetable: &[],
+            position: 0,
+            frame: 0,
+            frame_frac: 0,
 
             phi: 0,
-            phi_max: 1 << 16,
             delta_phi: 0,
 
+            phase_mod: 0,
+            fm_linear: 0,
+            fm_exp: 1 << 16,
+            increment: 0,
+
+            sync_mode: SyncMode::Off,
+            reversed: false,
+            wrap: None,
+
             idx: 0,
             idx_max: 0,
+            frac: 0,
         }
     }
 }
 
-impl Iterator for WaveTableOscillator<i16> {
-    type Item = i16;
+impl<T: Sample> Default for WaveTableOscillator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Sample> Iterator for WaveTableOscillator<T> {
+    type Item = T;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
@@ -197,40 +684,69 @@ impl Iterator for WaveTableOscillator<i16> {
     }
 }
 
+#[cfg(feature = "rodio")]
+use crate::freq::Hz;
+#[cfg(feature = "rodio")]
 use core::time::Duration;
+#[cfg(feature = "rodio")]
 use rodio::source::Source;
+#[cfg(feature = "rodio")]
 impl Source for WaveTableOscillator<i16> {
     fn channels(&self) -> u16 {
-        return 1;
+        1
     }
 
     fn sample_rate(&self) -> u32 {
-        return self.msample_rate.from_mHz();
+        Hz::from(MilliHz(self.msample_rate)).0
     }
 
     fn current_frame_len(&self) -> Option<usize> {
-        return None;
+        None
     }
 
     fn total_duration(&self) -> Option<Duration> {
-        return None;
+        None
+    }
+}
+
+#[cfg(feature = "rodio")]
+impl Source for WaveTableOscillator<f32> {
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        Hz::from(MilliHz(self.msample_rate)).0
+    }
+
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
     }
 }
 
 pub type WaveTableOsc16 = WaveTableOscillator<i16>;
 pub type WaveTableOsc32 = WaveTableOscillator<i32>;
+pub type WaveTableOscF32 = WaveTableOscillator<f32>;
+pub type WaveTableOscF64 = WaveTableOscillator<f64>;
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::osc::wave_tables::SINE_I16;
+    use crate::freq::Hz;
+    use crate::osc::wave_tables::{
+        MORPH_I16, SAW_F32, SAW_F64, SAW_I16, SAW_I32, SINE_F32, SINE_F64, SINE_I16, SINE_I32,
+    };
 
     #[test]
     fn test_wave_table_oscillator() {
         let mut osc = WaveTableOscillator::<i16>::new();
         osc.set_wavetable(&SINE_I16);
-        osc.set_freq(2);
-        osc.set_sample_rate(100);
+        osc.set_freq(Hz(2));
+        osc.set_sample_rate(Hz(100));
         osc.set_repeat(false);
         osc.start();
         for x in 0..110 {
@@ -244,4 +760,355 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_interpolation() {
+        // Ramp up from 0 to 3000 and back to 0 over one cycle
+        static RAMP: [i16; 4] = [0, 1000, 2000, 3000];
+        let mut osc = WaveTableOscillator::<i16>::new();
+        osc.set_wavetable(&RAMP);
+        osc.set_freq(Hz(1));
+        osc.set_sample_rate(Hz(8));
+        osc.start();
+
+        let truncated: Vec<i16> = osc.by_ref().take(4).collect();
+        assert_eq!(truncated, [0, 1000, 1000, 2000]);
+
+        osc.reset();
+        osc.set_interpolation(Interpolation::Linear);
+        let linear: Vec<i16> = osc.by_ref().take(8).collect();
+        assert_eq!(linear, [500, 1000, 1500, 2000, 2500, 3000, 1500, 0]);
+
+        osc.reset();
+        osc.set_interpolation(Interpolation::Cubic);
+        let cubic: Vec<i16> = osc.by_ref().take(4).collect();
+        assert_eq!(cubic[1], 1000);
+        assert_eq!(cubic[3], 2000);
+    }
+
+    #[test]
+    fn test_hermite_passes_through_samples() {
+        assert_eq!(i32::hermite(-7, 100, 250, 9, 0), 100);
+        assert_eq!(i32::hermite(-7, 100, 250, 9, 1 << 16), 250);
+        assert_eq!(
+            i16::hermite(i16::MIN, i16::MAX, i16::MAX, i16::MIN, 1 << 15),
+            i16::MAX
+        );
+    }
+
+    #[test]
+    fn test_mipmap_level() {
+        let mut osc = WaveTableOscillator::<i16>::new();
+        osc.set_sample_rate(Hz(44100));
+        osc.set_freq(Hz(20));
+        osc.set_mipmap(&SAW_I16);
+        assert_eq!(osc.level(), 0);
+
+        // 512 harmonics of 100 Hz exceed Nyquist, 128 harmonics do not
+        osc.set_freq(Hz(100));
+        assert_eq!(osc.level(), 2);
+
+        // Only the fundamental is left close to Nyquist
+        osc.set_freq(Hz(15000));
+        assert_eq!(osc.level(), SAW_I16.len() - 1);
+        osc.start();
+        let peak = osc.take(100).map(|y| y.unsigned_abs()).max().unwrap();
+        assert!(peak > i16::MAX as u16 / 2);
+    }
+
+    #[test]
+    fn test_phase_accumulator() {
+        let mut osc = WaveTableOscillator::<i16>::new();
+        osc.set_sample_rate(Hz(48000));
+        osc.set_mfreq(440_001);
+        assert_eq!(osc.delta_phi(), 39370623);
+        osc.set_sample_rate(MilliHz(48_000_000));
+        assert_eq!(osc.delta_phi(), 39370623);
+
+        // The sample rate itself does not wrap around to a standstill
+        osc.set_freq(Hz(48000));
+        assert_eq!(osc.delta_phi(), u32::MAX);
+        osc.set_freq(Hz(96000));
+        assert_eq!(osc.delta_phi(), u32::MAX);
+
+        // A table length that is not a power of two
+        static TABLE: [i16; 3] = [0, 1, 2];
+        osc.set_wavetable(&TABLE);
+        osc.set_freq(Hz(1));
+        osc.set_sample_rate(Hz(6));
+        osc.start();
+        let samples: Vec<i16> = osc.by_ref().take(7).collect();
+        assert_eq!(samples, [0, 1, 1, 2, 2, 0, 0]);
+
+        // Fractional sample rates are not rounded to whole hertz
+        osc.set_sample_rate(MilliHz(44_100_500));
+        osc.set_freq(MilliHz(22_050_250));
+        assert_eq!(osc.delta_phi(), 1 << 31);
+    }
+
+    #[test]
+    fn test_modulation_inputs() {
+        static RAMP: [i16; 4] = [0, 1000, 2000, 3000];
+        let mut osc = WaveTableOscillator::<i16>::new();
+        osc.set_wavetable(&RAMP);
+        osc.set_freq(Hz(1));
+        osc.set_sample_rate(Hz(4));
+        osc.start();
+        assert_eq!(osc._next(), Some(1000));
+
+        // Quarter period phase offset
+        osc.set_phase_mod(1 << 30);
+        assert_eq!(osc._next(), Some(3000));
+        osc.set_phase_mod(0);
+
+        // Exponential FM by one octave doubles the increment
+        osc.set_fm_exp(1 << 16);
+        assert_eq!(osc.increment(), 1 << 31);
+        osc.set_fm_exp(0);
+
+        // Through-zero FM runs the phase backwards
+        osc.set_fm_linear(i32::MIN);
+        assert_eq!(osc.increment(), -(1 << 30));
+        assert_eq!(osc._next(), Some(1000));
+        assert_eq!(osc._next(), Some(0));
+        assert_eq!(osc._next(), Some(3000));
+        osc.set_fm_linear(0);
+
+        // Extreme exponential FM saturates instead of wrapping the pitch
+        osc.set_fm_exp(i32::MAX);
+        assert_eq!(osc.increment(), u32::MAX as i64);
+        let phi = osc.phase();
+        osc._next();
+        assert_eq!(osc.phase(), phi.wrapping_sub(1));
+        osc.set_sync_mode(SyncMode::Hard);
+        osc.sync(0);
+        assert_eq!(osc.phase(), 1);
+    }
+
+    #[test]
+    fn test_fill() {
+        let mut osc = WaveTableOscillator::<i16>::new();
+        osc.set_wavetable(&SINE_I16);
+        osc.set_interpolation(Interpolation::Linear);
+        osc.set_sample_rate(Hz(48000));
+        osc.set_mfreq(1_234_567);
+        osc.start();
+        let mut reference = WaveTableOscillator::<i16>::new();
+        reference.set_wavetable(&SINE_I16);
+        reference.set_interpolation(Interpolation::Linear);
+        reference.set_sample_rate(Hz(48000));
+        reference.set_mfreq(1_234_567);
+        reference.start();
+
+        let mut block = [0; 100];
+        for _ in 0..10 {
+            assert_eq!(osc.fill(&mut block), 100);
+            let expected: Vec<i16> = reference.by_ref().take(100).collect();
+            assert_eq!(block.as_slice(), expected.as_slice());
+            assert_eq!(osc.wrapped(), reference.wrapped());
+        }
+
+        // A single shot stops within the block
+        osc.set_freq(Hz(1000));
+        osc.set_repeat(false);
+        osc.reset_and_start();
+        assert_eq!(osc.fill(&mut block), 48);
+        assert!(block[48..].iter().all(|&y| y == 0));
+        let first = block;
+        // The phase stays at the start once stopped
+        assert_eq!(osc.phase(), 0);
+        assert_eq!(osc.fill(&mut block), 0);
+        assert_eq!(osc.phase(), 0);
+        osc.start();
+        assert_eq!(osc.process_block(&mut block, Ramp::default()), 48);
+        assert_eq!(block, first);
+        osc.start();
+        let ramp = Ramp {
+            phase_mod: Some(1),
+            ..Ramp::default()
+        };
+        assert_eq!(osc.process_block(&mut block, ramp), 48);
+        assert_eq!(osc.phase(), 0);
+        let ramp = Ramp {
+            mfreq: Some(2_000_000),
+            phase_mod: Some(2),
+            ..Ramp::default()
+        };
+        assert_eq!(osc.process_block(&mut block, ramp), 0);
+        assert!(block.iter().all(|&y| y == 0));
+        assert_eq!(osc.phase(), 0);
+        assert_eq!(osc.freq(), MilliHz(2_000_000));
+    }
+
+    #[test]
+    fn test_fixed_point_samples() {
+        static RAMP_Q15: [Q15; 4] = [Q15(0), Q15(1000), Q15(2000), Q15(3000)];
+        static RAMP: [i16; 4] = [0, 1000, 2000, 3000];
+        let mut q15 = WaveTableOscillator::<Q15>::new();
+        q15.set_wavetable(&RAMP_Q15);
+        let mut int = WaveTableOsc16::new();
+        int.set_wavetable(&RAMP);
+        for interpolation in [Interpolation::Linear, Interpolation::Cubic] {
+            q15.set_interpolation(interpolation);
+            int.set_interpolation(interpolation);
+            q15.set_sample_rate(Hz(100));
+            int.set_sample_rate(Hz(100));
+            q15.set_freq(Hz(7));
+            int.set_freq(Hz(7));
+            q15.reset_and_start();
+            int.reset_and_start();
+            let a: Vec<i16> = q15.by_ref().take(32).map(i16::from).collect();
+            let b: Vec<i16> = int.by_ref().take(32).collect();
+            assert_eq!(a, b);
+        }
+    }
+
+    #[test]
+    fn test_float_samples() {
+        // Renders 1 kHz at 48 kHz with every interpolation, from the sine
+        // table and from the mipmapped saw at a level above the first
+        fn render<T: Sample>(sine: &'static [T], saw: &'static [[T; 1024]]) -> Vec<T> {
+            let mut osc = WaveTableOscillator::<T>::new();
+            osc.set_sample_rate(Hz(48000));
+            let mut y = Vec::new();
+            for interpolation in [
+                Interpolation::None,
+                Interpolation::Linear,
+                Interpolation::Cubic,
+            ] {
+                osc.set_interpolation(interpolation);
+                osc.set_wavetable(sine);
+                osc.set_freq(Hz(1000));
+                osc.reset_and_start();
+                y.extend(osc.by_ref().take(100));
+                osc.set_mipmap(saw);
+                osc.set_freq(Hz(1000));
+                osc.reset_and_start();
+                y.extend(osc.by_ref().take(100));
+            }
+            y
+        }
+
+        let int: Vec<f64> = render(&SINE_I32, &SAW_I32)
+            .into_iter()
+            .map(|y| y as f64 / i32::MAX as f64)
+            .collect();
+        let f32s = render(&SINE_F32, &SAW_F32);
+        let f64s = render(&SINE_F64, &SAW_F64);
+        assert_eq!(int.len(), 600);
+        for ((a, b), c) in int.iter().zip(f32s).zip(f64s) {
+            assert!((a - b as f64).abs() < 1e-6);
+            assert!((a - c).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_ramp() {
+        static RAMP: [i16; 4] = [0, 1000, 2000, 3000];
+        let mut osc = WaveTableOscillator::<i16>::new();
+        osc.set_wavetable(&RAMP);
+        osc.set_sample_rate(Hz(1000));
+        osc.set_freq(Hz(1));
+        osc.start();
+
+        // Gliding from 1 Hz to 5 Hz over four samples, the phase travels
+        // 2 + 3 + 4 + 5 thousandths of a period
+        let mut block = [0; 4];
+        let ramp = Ramp {
+            mfreq: Some(5000),
+            ..Ramp::default()
+        };
+        assert_eq!(osc.process_block(&mut block, ramp), 4);
+        assert_eq!(osc.freq(), MilliHz(5000));
+        let period = 1_u64 << 32;
+        assert!(osc.phase().abs_diff((14 * period / 1000) as u32) < 4);
+
+        // The phase offset reaches its target at the last sample
+        osc.reset();
+        let ramp = Ramp {
+            phase_mod: Some(i32::MIN),
+            ..Ramp::default()
+        };
+        osc.process_block(&mut block, ramp);
+        assert_eq!(block[3], 2000);
+
+        // Crossing from the largest to the smallest offset is a small step
+        // around half a period, not a sweep over the whole period
+        osc.set_mfreq(0);
+        osc.reset();
+        osc.set_phase_mod(i32::MAX - 30);
+        let ramp = Ramp {
+            phase_mod: Some(i32::MIN + 30),
+            ..Ramp::default()
+        };
+        osc.process_block(&mut block, ramp);
+        assert_eq!(block, [1000, 1000, 2000, 2000]);
+    }
+
+    #[test]
+    fn test_morph() {
+        static FRAMES: [[i16; 2]; 3] = [[0, 0], [1000, 1000], [3000, 3000]];
+        let mut osc = WaveTableOscillator::<i16>::new();
+        osc.set_frames(&FRAMES);
+        osc.set_freq(Hz(1));
+        osc.start();
+        assert_eq!(osc._next(), Some(0));
+
+        // Halfway between the first and the last frame is the middle frame
+        osc.set_position(1 << 15);
+        assert_eq!(osc._next(), Some(1000));
+        osc.set_position(3 << 14);
+        assert_eq!(osc._next(), Some(2000));
+        osc.set_position(u32::MAX);
+        assert_eq!(osc.position(), 1 << 16);
+        assert_eq!(osc._next(), Some(3000));
+
+        // The demo bank starts with a sine
+        osc.set_frames(&MORPH_I16);
+        osc.set_position(0);
+        osc.reset();
+        osc.set_sample_rate(Hz(1024));
+        osc.set_freq(Hz(256));
+        assert_eq!(osc._next(), Some(MORPH_I16[0][256]));
+        assert_eq!(MORPH_I16[0][256], SINE_I16[256]);
+    }
+
+    #[test]
+    fn test_sync() {
+        let mut master = WaveTableOscillator::<i16>::new();
+        master.set_wavetable(&SINE_I16);
+        master.set_sample_rate(Hz(1000));
+        master.set_freq(Hz(300));
+        master.start();
+
+        let mut slave = WaveTableOscillator::<i16>::new();
+        slave.set_wavetable(&SINE_I16);
+        slave.set_sample_rate(Hz(1000));
+        slave.set_freq(Hz(470));
+        slave.set_sync_mode(SyncMode::Hard);
+        slave.start();
+
+        // The master wraps in its fourth sample, 2/3 of a sample before its end
+        for _ in 0..3 {
+            master._next();
+            assert_eq!(master.wrapped(), None);
+        }
+        master._next();
+        let frac = master.wrapped().unwrap();
+        assert_eq!(frac, 43690);
+        for _ in 0..3 {
+            slave._next();
+        }
+        slave.sync_to(&master);
+        slave._next();
+        let expected = (slave.increment() * frac as i64) >> 16;
+        assert!((slave.phase() as i64 - expected).abs() <= 1);
+
+        // Soft sync runs the phase backwards until the next sync event
+        slave.set_sync_mode(SyncMode::Soft);
+        let before = slave.phase();
+        slave.sync(1 << 16);
+        slave._next();
+        assert_eq!(slave.phase(), before.wrapping_sub(slave.increment() as u32));
+    }
 }
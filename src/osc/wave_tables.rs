@@ -2,6 +2,28 @@
 include!("wave_tables/sine_i8.rs");
 include!("wave_tables/sine_i16.rs");
 include!("wave_tables/sine_i32.rs");
+include!("wave_tables/sine_f32.rs");
+include!("wave_tables/sine_f64.rs");
 include!("wave_tables/exp_i8.rs");
 include!("wave_tables/exp_i16.rs");
 include!("wave_tables/exp_i32.rs");
+include!("wave_tables/saw_i16.rs");
+include!("wave_tables/saw_i32.rs");
+include!("wave_tables/saw_f32.rs");
+include!("wave_tables/saw_f64.rs");
+include!("wave_tables/square_i16.rs");
+include!("wave_tables/square_i32.rs");
+include!("wave_tables/square_f32.rs");
+include!("wave_tables/square_f64.rs");
+include!("wave_tables/triangle_i16.rs");
+include!("wave_tables/triangle_i32.rs");
+include!("wave_tables/triangle_f32.rs");
+include!("wave_tables/triangle_f64.rs");
+include!("wave_tables/pulse_i16.rs");
+include!("wave_tables/pulse_i32.rs");
+include!("wave_tables/pulse_f32.rs");
+include!("wave_tables/pulse_f64.rs");
+include!("wave_tables/morph_i16.rs");
+include!("wave_tables/morph_i32.rs");
+include!("wave_tables/morph_f32.rs");
+include!("wave_tables/morph_f64.rs");
@@ -0,0 +1,2 @@
+#[allow(clippy::approx_constant)]
+pub static MORPH_F32: [[f32; 1024]; 7] = [[0.0, 0.0061358847, 0.012271538, 0.01840673, 0.024541229, 0.030674804, 0.036807224, 0.04293826, 0.049067676, 0.055195246, 0.061320737, 0.06744392, 0.07356457, 0.07968244, 0.08579731, 0.091908954, 0.09801714, 0.10412163, 0.110222206, 0.11631863, 0.12241068, 0.1284981, 0.1345807, 0.14065824, 0.14673047, 0.15279719, 0.15885815, 0.16491312, 0.17096189, 0.17700422, 0.18303989, 0.18906866, 0.19509032, 0.20110464, 0.20711137, 0.21311031, 0.21910124, 0.22508392, 0.2310581, 0.2370236, 0.24298018, 0.24892761, 0.25486565, 0.2607941, 0.26671275, 0.27262136, 0.2785197, 0.28440753, 0.29028466, 0.2961509, 0.30200595, 0.30784965, 0.31368175, 0.31950203, 0.3253103, 0.3311063, 0.33688986, 0.34266073, 0.34841868, 0.35416353, 0.35989505, 0.36561298, 0.3713172, 0.37700742, 0.38268343, 0.38834503, 0.39399204, 0.3996242, 0.4052413, 0.41084316, 0.41642955, 0.42200026, 0.42755508, 0.43309382, 0.43861625, 0.44412214, 0.44961134, 0.45508358, 0.46053872, 0.4659765, 0.47139674, 0.47679922, 0.48218378, 0.48755017, 0.4928982, 0.49822766, 0.50353837, 0.50883013, 0.51410276, 0.519356, 0.52458966, 0.52980363, 0.53499764, 0.54017144, 0.545325, 0.55045795, 0.55557024, 0.56066155, 0.5657318, 0.57078075, 0.57580817, 0.58081394, 0.58579785, 0.5907597, 0.5956993, 0.60061646, 0.60551107, 0.6103828, 0.6152316, 0.6200572, 0.6248595, 0.62963825, 0.6343933, 0.63912445, 0.64383155, 0.6485144, 0.65317285, 0.6578067, 0.6624158, 0.66699994, 0.671559, 0.6760927, 0.680601, 0.6850837, 0.68954057, 0.69397146, 0.69837624, 0.70275474, 0.70710677, 0.7114322, 0.71573085, 0.72000253, 0.7242471, 0.72846437, 0.7326543, 0.7368166, 0.7409511, 0.74505776, 0.7491364, 0.7531868, 0.7572088, 0.7612024, 0.76516724, 0.76910335, 0.77301043, 0.7768885, 0.7807372, 0.78455657, 0.7883464, 0.79210657, 0.7958369, 0.79953724, 0.8032075, 0.8068476, 0.81045717, 0.8140363, 0.8175848, 0.8211025, 0.8245893, 0.82804507, 0.8314696, 0.8348629, 0.8382247, 0.841555, 0.8448536, 0.84812033, 0.8513552, 0.854558, 0.8577286, 0.86086696, 0.86397284, 0.86704624, 0.87008697, 0.873095, 0.8760701, 0.8790122, 0.8819213, 0.8847971, 0.88763964, 0.89044875, 0.8932243, 0.89596623, 0.8986745, 0.9013488, 0.9039893, 0.9065957, 0.909168, 0.91170603, 0.9142098, 0.9166791, 0.9191139, 0.92151403, 0.9238795, 0.9262102, 0.9285061, 0.93076694, 0.9329928, 0.9351835, 0.937339, 0.9394592, 0.94154406, 0.94359344, 0.9456073, 0.9475856, 0.94952816, 0.951435, 0.953306, 0.9551412, 0.95694035, 0.95870346, 0.9604305, 0.9621214, 0.96377605, 0.96539444, 0.96697646, 0.9685221, 0.97003126, 0.9715039, 0.97293997, 0.97433937, 0.9757021, 0.97702813, 0.9783174, 0.9795698, 0.98078525, 0.9819639, 0.9831055, 0.9842101, 0.98527765, 0.9863081, 0.9873014, 0.9882576, 0.9891765, 0.9900582, 0.99090266, 0.99170977, 0.99247956, 0.9932119, 0.993907, 0.9945646, 0.9951847, 0.9957674, 0.9963126, 0.9968203, 0.99729043, 0.99772304, 0.9981181, 0.99847555, 0.99879545, 0.99907774, 0.99932235, 0.9995294, 0.9996988, 0.9998306, 0.9999247, 0.99998116, 1.0, 0.99998116, 0.9999247, 0.9998306, 0.9996988, 0.9995294, 0.99932235, 0.99907774, 0.99879545, 0.99847555, 0.9981181, 0.99772304, 0.99729043, 0.9968203, 0.9963126, 0.9957674, 0.9951847, 0.9945646, 0.993907, 0.9932119, 0.99247956, 0.99170977, 0.99090266, 0.9900582, 0.9891765, 0.9882576, 0.9873014, 0.9863081, 0.98527765, 0.9842101, 0.9831055, 0.9819639, 0.98078525, 0.9795698, 0.9783174, 0.97702813, 0.9757021, 0.97433937, 0.97293997, 0.9715039, 0.97003126, 0.9685221, 0.96697646, 0.96539444, 0.96377605, 0.9621214, 0.9604305, 0.95870346, 0.95694035, 0.9551412, 0.953306, 0.951435, 0.94952816, 0.9475856, 0.9456073, 0.94359344, 0.94154406, 0.9394592, 0.937339, 0.9351835, 0.9329928, 0.93076694, 0.9285061, 0.9262102, 0.9238795, 0.92151403, 0.9191139, 0.9166791, 0.9142098, 0.91170603, 0.909168, 0.9065957, 0.9039893, 0.9013488, 0.8986745, 0.89596623, 0.8932243, 0.89044875, 0.88763964, 0.8847971, 0.8819213, 0.8790122, 0.8760701, 0.873095, 0.87008697, 0.86704624, 0.86397284, 0.86086696, 0.8577286, 0.854558, 0.8513552, 0.84812033, 0.8448536, 0.841555, 0.8382247, 0.8348629, 0.8314696, 0.82804507, 0.8245893, 0.8211025, 0.8175848, 0.8140363, 0.81045717, 0.8068476, 0.8032075, 0.79953724, 0.7958369, 0.79210657, 0.7883464, 0.78455657, 0.7807372, 0.7768885, 0.77301043, 0.76910335, 0.76516724, 0.7612024, 0.7572088, 0.7531868, 0.7491364, 0.74505776, 0.7409511, 0.7368166, 0.7326543, 0.72846437, 0.7242471, 0.72000253, 0.71573085, 0.7114322, 0.70710677, 0.70275474, 0.69837624, 0.69397146, 0.68954057, 0.6850837, 0.680601, 0.6760927, 0.671559, 0.66699994, 0.6624158, 0.6578067, 0.65317285, 0.6485144, 0.64383155, 0.63912445, 0.6343933, 0.62963825, 0.6248595, 0.6200572, 0.6152316, 0.6103828, 0.60551107, 0.60061646, 0.5956993, 0.5907597, 0.58579785, 0.58081394, 0.57580817, 0.57078075, 0.5657318, 0.56066155, 0.55557024, 0.55045795, 0.545325, 0.54017144, 0.53499764, 0.52980363, 0.52458966, 0.519356, 0.51410276, 0.50883013, 0.50353837, 0.49822766, 0.4928982, 0.48755017, 0.48218378, 0.47679922, 0.47139674, 0.4659765, 0.46053872, 0.45508358, 0.44961134, 0.44412214, 0.43861625, 0.43309382, 0.42755508, 0.42200026, 0.41642955, 0.41084316, 0.4052413, 0.3996242, 0.39399204, 0.38834503, 0.38268343, 0.37700742, 0.3713172, 0.36561298, 0.35989505, 0.35416353, 0.34841868, 0.34266073, 0.33688986, 0.3311063, 0.3253103, 0.31950203, 0.31368175, 0.30784965, 0.30200595, 0.2961509, 0.29028466, 0.28440753, 0.2785197, 0.27262136, 0.26671275, 0.2607941, 0.25486565, 0.24892761, 0.24298018, 0.2370236, 0.2310581, 0.22508392, 0.21910124, 0.21311031, 0.20711137, 0.20110464, 0.19509032, 0.18906866, 0.18303989, 0.17700422, 0.17096189, 0.16491312, 0.15885815, 0.15279719, 0.14673047, 0.14065824, 0.1345807, 0.1284981, 0.12241068, 0.11631863, 0.110222206, 0.10412163, 0.09801714, 0.091908954, 0.08579731, 0.07968244, 0.07356457, 0.06744392, 0.061320737, 0.055195246, 0.049067676, 0.04293826, 0.036807224, 0.030674804, 0.024541229, 0.01840673, 0.012271538, 0.0061358847, 1.2246469e-16, -0.0061358847, -0.012271538, -0.01840673, -0.024541229, -0.030674804, -0.036807224, -0.04293826, -0.049067676, -0.055195246, -0.061320737, -0.06744392, -0.07356457, -0.07968244, -0.08579731, -0.091908954, -0.09801714, -0.10412163, -0.110222206, -0.11631863, -0.12241068, -0.1284981, -0.1345807, -0.14065824, -0.14673047, -0.15279719, -0.15885815, -0.16491312, -0.17096189, -0.17700422, -0.18303989, -0.18906866, -0.19509032, -0.20110464, -0.20711137, -0.21311031, -0.21910124, -0.22508392, -0.2310581, -0.2370236, -0.24298018, -0.24892761, -0.25486565, -0.2607941, -0.26671275, -0.27262136, -0.2785197, -0.28440753, -0.29028466, -0.2961509, -0.30200595, -0.30784965, -0.31368175, -0.31950203, -0.3253103, -0.3311063, -0.33688986, -0.34266073, -0.34841868, -0.35416353, -0.35989505, -0.36561298, -0.3713172, -0.37700742, -0.38268343, -0.38834503, -0.39399204, -0.3996242, -0.4052413, -0.41084316, -0.41642955, -0.42200026, -0.42755508, -0.43309382, -0.43861625, -0.44412214, -0.44961134, -0.45508358, -0.46053872, -0.4659765, -0.47139674, -0.47679922, -0.48218378, -0.48755017, -0.4928982, -0.49822766, -0.50353837, -0.50883013, -0.51410276, -0.519356, -0.52458966, -0.52980363, -0.53499764, -0.54017144, -0.545325, -0.55045795, -0.55557024, -0.56066155, -0.5657318, -0.57078075, -0.57580817, -0.58081394, -0.58579785, -0.5907597, -0.5956993, -0.60061646, -0.60551107, -0.6103828, -0.6152316, -0.6200572, -0.6248595, -0.62963825, -0.6343933, -0.63912445, -0.64383155, -0.6485144, -0.65317285, -0.6578067, -0.6624158, -0.66699994, -0.671559, -0.6760927, -0.680601, -0.6850837, -0.68954057, -0.69397146, -0.69837624, -0.70275474, -0.70710677, -0.7114322, -0.71573085, -0.72000253, -0.7242471, -0.72846437, -0.7326543, -0.7368166, -0.7409511, -0.74505776, -0.7491364, -0.7531868, -0.7572088, -0.7612024, -0.76516724, -0.76910335, -0.77301043, -0.7768885, -0.7807372, -0.78455657, -0.7883464, -0.79210657, -0.7958369, -0.79953724, -0.8032075, -0.8068476, -0.81045717, -0.8140363, -0.8175848, -0.8211025, -0.8245893, -0.82804507, -0.8314696, -0.8348629, -0.8382247, -0.841555, -0.8448536, -0.84812033, -0.8513552, -0.854558, -0.8577286, -0.86086696, -0.86397284, -0.86704624, -0.87008697, -0.873095, -0.8760701, -0.8790122, -0.8819213, -0.8847971, -0.88763964, -0.89044875, -0.8932243, -0.89596623, -0.8986745, -0.9013488, -0.9039893, -0.9065957, -0.909168, -0.91170603, -0.9142098, -0.9166791, -0.9191139, -0.92151403, -0.9238795, -0.9262102, -0.9285061, -0.93076694, -0.9329928, -0.9351835, -0.937339, -0.9394592, -0.94154406, -0.94359344, -0.9456073, -0.9475856, -0.94952816, -0.951435, -0.953306, -0.9551412, -0.95694035, -0.95870346, -0.9604305, -0.9621214, -0.96377605, -0.96539444, -0.96697646, -0.9685221, -0.97003126, -0.9715039, -0.97293997, -0.97433937, -0.9757021, -0.97702813, -0.9783174, -0.9795698, -0.98078525, -0.9819639, -0.9831055, -0.9842101, -0.98527765, -0.9863081, -0.9873014, -0.9882576, -0.9891765, -0.9900582, -0.99090266, -0.99170977, -0.99247956, -0.9932119, -0.993907, -0.9945646, -0.9951847, -0.9957674, -0.9963126, -0.9968203, -0.99729043, -0.99772304, -0.9981181, -0.99847555, -0.99879545, -0.99907774, -0.99932235, -0.9995294, -0.9996988, -0.9998306, -0.9999247, -0.99998116, -1.0, -0.99998116, -0.9999247, -0.9998306, -0.9996988, -0.9995294, -0.99932235, -0.99907774, -0.99879545, -0.99847555, -0.9981181, -0.99772304, -0.99729043, -0.9968203, -0.9963126, -0.9957674, -0.9951847, -0.9945646, -0.993907, -0.9932119, -0.99247956, -0.99170977, -0.99090266, -0.9900582, -0.9891765, -0.9882576, -0.9873014, -0.9863081, -0.98527765, -0.9842101, -0.9831055, -0.9819639, -0.98078525, -0.9795698, -0.9783174, -0.97702813, -0.9757021, -0.97433937, -0.97293997, -0.9715039, -0.97003126, -0.9685221, -0.96697646, -0.96539444, -0.96377605, -0.9621214, -0.9604305, -0.95870346, -0.95694035, -0.9551412, -0.953306, -0.951435, -0.94952816, -0.9475856, -0.9456073, -0.94359344, -0.94154406, -0.9394592, -0.937339, -0.9351835, -0.9329928, -0.93076694, -0.9285061, -0.9262102, -0.9238795, -0.92151403, -0.9191139, -0.9166791, -0.9142098, -0.91170603, -0.909168, -0.9065957, -0.9039893, -0.9013488, -0.8986745, -0.89596623, -0.8932243, -0.89044875, -0.88763964, -0.8847971, -0.8819213, -0.8790122, -0.8760701, -0.873095, -0.87008697, -0.86704624, -0.86397284, -0.86086696, -0.8577286, -0.854558, -0.8513552, -0.84812033, -0.8448536, -0.841555, -0.8382247, -0.8348629, -0.8314696, -0.82804507, -0.8245893, -0.8211025, -0.8175848, -0.8140363, -0.81045717, -0.8068476, -0.8032075, -0.79953724, -0.7958369, -0.79210657, -0.7883464, -0.78455657, -0.7807372, -0.7768885, -0.77301043, -0.76910335, -0.76516724, -0.7612024, -0.7572088, -0.7531868, -0.7491364, -0.74505776, -0.7409511, -0.7368166, -0.7326543, -0.72846437, -0.7242471, -0.72000253, -0.71573085, -0.7114322, -0.70710677, -0.70275474, -0.69837624, -0.69397146, -0.68954057, -0.6850837, -0.680601, -0.6760927, -0.671559, -0.66699994, -0.6624158, -0.6578067, -0.65317285, -0.6485144, -0.64383155, -0.63912445, -0.6343933, -0.62963825, -0.6248595, -0.6200572, -0.6152316, -0.6103828, -0.60551107, -0.60061646, -0.5956993, -0.5907597, -0.58579785, -0.58081394, -0.57580817, -0.57078075, -0.5657318, -0.56066155, -0.55557024, -0.55045795, -0.545325, -0.54017144, -0.53499764, -0.52980363, -0.52458966, -0.519356, -0.51410276, -0.50883013, -0.50353837, -0.49822766, -0.4928982, -0.48755017, -0.48218378, -0.47679922, -0.47139674, -0.4659765, -0.46053872, -0.45508358, -0.44961134, -0.44412214, -0.43861625, -0.43309382, -0.42755508, -0.42200026, -0.41642955, -0.41084316, -0.4052413, -0.3996242, -0.39399204, -0.38834503, -0.38268343, -0.37700742, -0.3713172, -0.36561298, -0.35989505, -0.35416353, -0.34841868, -0.34266073, -0.33688986, -0.3311063, -0.3253103, -0.31950203, -0.31368175, -0.30784965, -0.30200595, -0.2961509, -0.29028466, -0.28440753, -0.2785197, -0.27262136, -0.26671275, -0.2607941, -0.25486565, -0.24892761, -0.24298018, -0.2370236, -0.2310581, -0.22508392, -0.21910124, -0.21311031, -0.20711137, -0.20110464, -0.19509032, -0.18906866, -0.18303989, -0.17700422, -0.17096189, -0.16491312, -0.15885815, -0.15279719, -0.14673047, -0.14065824, -0.1345807, -0.1284981, -0.12241068, -0.11631863, -0.110222206, -0.10412163, -0.09801714, -0.091908954, -0.08579731, -0.07968244, -0.07356457, -0.06744392, -0.061320737, -0.055195246, -0.049067676, -0.04293826, -0.036807224, -0.030674804, -0.024541229, -0.01840673, -0.012271538, -0.0061358847, ], [0.0, 0.016069701, 0.032134864, 0.048190955, 0.06423344, 0.080257796, 0.09625951, 0.11223406, 0.12817697, 0.14408375, 0.15994994, 0.17577109, 0.19154276, 0.20726055, 0.2229201, 0.23851703, 0.25404704, 0.2695058, 0.28488904, 0.3001926, 0.31541216, 0.33054367, 0.34558296, 0.36052594, 0.3753686, 0.39010692, 0.40473697, 0.41925484, 0.4336567, 0.4479387, 0.46209714, 0.4761283, 0.49002856, 0.5037944, 0.51742214, 0.53090847, 0.5442499, 0.55744314, 0.5704849, 0.583372, 0.5961013, 0.6086697, 0.6210742, 0.6333119, 0.64538, 0.6572756, 0.6689961, 0.6805388, 0.69190115, 0.7030807, 0.7140751, 0.7248819, 0.7354989, 0.74592406, 0.7561552, 0.76619035, 0.77602756, 0.7856651, 0.79510117, 0.8043341, 0.81336236, 0.8221845, 0.83079904, 0.83920467, 0.84740025, 0.8553846, 0.8631567, 0.87071556, 0.87806034, 0.88519025, 0.8921046, 0.8988028, 0.9052843, 0.91154873, 0.9175957, 0.923425, 0.9290365, 0.93443006, 0.9396058, 0.9445636, 0.949304, 0.95382696, 0.95813304, 0.96222264, 0.9660962, 0.9697545, 0.9731982, 0.97642803, 0.9794449, 0.9822498, 0.98484373, 0.98722774, 0.9894032, 0.9913712, 0.9931332, 0.99469066, 0.996045, 0.9971978, 0.9981508, 0.99890566, 0.9994643, 0.9998284, 1.0, 0.9999811, 0.99977386, 0.9993803, 0.99880266, 0.99804324, 0.9971043, 0.9959883, 0.9946977, 0.99323493, 0.99160266, 0.9898034, 0.9878398, 0.98571473, 0.98343086, 0.980991, 0.978398, 0.97565484, 0.97276443, 0.9697297, 0.9665538, 0.9632397, 0.95979065, 0.95620966, 0.9524999, 0.94866467, 0.9447071, 0.9406305, 0.9364382, 0.93213344, 0.9277196, 0.9232, 0.9185781, 0.91385716, 0.9090407, 0.90413207, 0.8991347, 0.8940521, 0.88888764, 0.88364476, 0.878327, 0.8729378, 0.8674806, 0.86195886, 0.856376, 0.85073555, 0.8450409, 0.8392955, 0.83350277, 0.82766616, 0.821789, 0.81587476, 0.8099268, 0.8039484, 0.797943, 0.7919138, 0.7858642, 0.7797973, 0.7737165, 0.767625, 0.76152587, 0.7554223, 0.74931747, 0.74321437, 0.7371161, 0.73102564, 0.724946, 0.71888006, 0.7128308, 0.70680094, 0.70079345, 0.6948109, 0.6888561, 0.6829318, 0.6770405, 0.67118484, 0.66536725, 0.6595903, 0.65385634, 0.6481678, 0.6425269, 0.636936, 0.6313972, 0.62591267, 0.62048453, 0.61511475, 0.60980535, 0.6045582, 0.5993751, 0.594258, 0.5892084, 0.58422816, 0.5793187, 0.57448167, 0.5697185, 0.5650306, 0.56041926, 0.55588585, 0.55143154, 0.54705745, 0.54276466, 0.53855425, 0.5344271, 0.5303841, 0.5264261, 0.5225538, 0.51876795, 0.51506907, 0.5114578, 0.50793463, 0.5044999, 0.50115407, 0.49789736, 0.49473003, 0.4916522, 0.488664, 0.4857655, 0.4829566, 0.48023722, 0.47760725, 0.47506645, 0.47261456, 0.47025123, 0.46797606, 0.4657886, 0.46368837, 0.46167472, 0.4597471, 0.4579048, 0.45614704, 0.4544731, 0.45288208, 0.4513731, 0.4499452, 0.4485974, 0.44732863, 0.4461378, 0.44502375, 0.44398525, 0.44302115, 0.4421301, 0.4413108, 0.44056183, 0.43988186, 0.4392694, 0.43872294, 0.438241, 0.43782198, 0.4374643, 0.43716633, 0.4369264, 0.4367428, 0.4366139, 0.43653783, 0.4365129, 0.43653724, 0.43660903, 0.43672648, 0.43688768, 0.43709075, 0.4373338, 0.43761483, 0.43793195, 0.4382832, 0.4386666, 0.4390802, 0.43952197, 0.43998992, 0.44048208, 0.44099635, 0.4415308, 0.44208336, 0.442652, 0.4432347, 0.4438295, 0.44443431, 0.44504714, 0.44566596, 0.44628876, 0.4469136, 0.44753844, 0.4481613, 0.4487802, 0.44939327, 0.4499985, 0.45059395, 0.45117778, 0.45174804, 0.4523029, 0.4528405, 0.45335904, 0.45385665, 0.4543316, 0.45478213, 0.45520648, 0.45560297, 0.45596996, 0.45630574, 0.4566087, 0.45687735, 0.45711002, 0.45730525, 0.45746157, 0.4575775, 0.45765162, 0.45768258, 0.45766905, 0.45760968, 0.45750326, 0.45734853, 0.45714432, 0.4568895, 0.45658293, 0.4562236, 0.4558105, 0.4553426, 0.45481902, 0.45423883, 0.45360127, 0.45290545, 0.4521507, 0.45133626, 0.4504615, 0.4495258, 0.44852862, 0.4474694, 0.4463477, 0.4451631, 0.44391522, 0.4426037, 0.4412283, 0.43978876, 0.4382849, 0.4367166, 0.43508372, 0.43338624, 0.43162417, 0.4297976, 0.42790654, 0.42595118, 0.42393172, 0.42184836, 0.41970143, 0.4174912, 0.41521806, 0.41288242, 0.41048476, 0.40802556, 0.4055054, 0.4029248, 0.40028447, 0.39758503, 0.39482722, 0.39201176, 0.38913944, 0.38621116, 0.3832277, 0.38019, 0.37709904, 0.37395573, 0.37076113, 0.36751628, 0.36422226, 0.36088017, 0.35749117, 0.35405645, 0.35057718, 0.34705463, 0.34349003, 0.3398847, 0.33623996, 0.3325571, 0.32883754, 0.32508266, 0.32129383, 0.31747255, 0.3136202, 0.30973828, 0.30582827, 0.3018917, 0.29793006, 0.29394493, 0.28993776, 0.28591022, 0.28186378, 0.2778001, 0.27372074, 0.26962727, 0.26552132, 0.2614045, 0.2572784, 0.25314465, 0.24900489, 0.24486068, 0.24071367, 0.23656549, 0.2324177, 0.22827196, 0.22412986, 0.21999297, 0.21586291, 0.21174124, 0.20762955, 0.20352939, 0.19944231, 0.19536984, 0.1913135, 0.18727483, 0.18325527, 0.17925633, 0.17527947, 0.1713261, 0.16739766, 0.16349554, 0.1596211, 0.15577571, 0.1519607, 0.14817736, 0.14442697, 0.14071079, 0.13703002, 0.1333859, 0.12977955, 0.12621213, 0.12268476, 0.11919849, 0.11575438, 0.11235344, 0.10899664, 0.10568492, 0.102419205, 0.09920036, 0.09602923, 0.09290662, 0.089833274, 0.08680995, 0.083837315, 0.08091604, 0.07804673, 0.075229965, 0.07246628, 0.069756165, 0.06710008, 0.06449845, 0.061951634, 0.059459984, 0.057023786, 0.054643292, 0.052318722, 0.05005024, 0.047837976, 0.045682024, 0.043582425, 0.04153919, 0.039552275, 0.03762161, 0.035747077, 0.033928514, 0.03216572, 0.030458461, 0.028806448, 0.027209364, 0.025666844, 0.024178492, 0.022743862, 0.021362478, 0.020033821, 0.018757336, 0.017532427, 0.016358463, 0.015234775, 0.01416066, 0.013135376, 0.012158148, 0.011228166, 0.010344584, 0.009506524, 0.008713076, 0.007963297, 0.0072562117, 0.0065908153, 0.005966073, 0.005380919, 0.004834262, 0.004324981, 0.0038519278, 0.0034139291, 0.0030097868, 0.0026382776, 0.0022981549, 0.0019881502, 0.0017069727, 0.0014533115, 0.0012258355, 0.0010231951, 0.0008440229, 0.0006869345, 0.00055053, 0.00043339428, 0.00033409882, 0.00025120206, 0.00018325083, 0.00012878122, 8.631966e-5, 5.438402e-5, 3.1484622e-5, 1.6125316e-5, 6.804571e-6, 2.0165298e-6, 2.520933e-7, 0.0, -2.520933e-7, -2.0165298e-6, -6.804571e-6, -1.6125316e-5, -3.1484622e-5, -5.438402e-5, -8.631966e-5, -0.00012878122, -0.00018325083, -0.00025120206, -0.00033409882, -0.00043339428, -0.00055053, -0.0006869345, -0.0008440229, -0.0010231951, -0.0012258355, -0.0014533115, -0.0017069727, -0.0019881502, -0.0022981549, -0.0026382776, -0.0030097868, -0.0034139291, -0.0038519278, -0.004324981, -0.004834262, -0.005380919, -0.005966073, -0.0065908153, -0.0072562117, -0.007963297, -0.008713076, -0.009506524, -0.010344584, -0.011228166, -0.012158148, -0.013135376, -0.01416066, -0.015234775, -0.016358463, -0.017532427, -0.018757336, -0.020033821, -0.021362478, -0.022743862, -0.024178492, -0.025666844, -0.027209364, -0.028806448, -0.030458461, -0.03216572, -0.033928514, -0.035747077, -0.03762161, -0.039552275, -0.04153919, -0.043582425, -0.045682024, -0.047837976, -0.05005024, -0.052318722, -0.054643292, -0.057023786, -0.059459984, -0.061951634, -0.06449845, -0.06710008, -0.069756165, -0.07246628, -0.075229965, -0.07804673, -0.08091604, -0.083837315, -0.08680995, -0.089833274, -0.09290662, -0.09602923, -0.09920036, -0.102419205, -0.10568492, -0.10899664, -0.11235344, -0.11575438, -0.11919849, -0.12268476, -0.12621213, -0.12977955, -0.1333859, -0.13703002, -0.14071079, -0.14442697, -0.14817736, -0.1519607, -0.15577571, -0.1596211, -0.16349554, -0.16739766, -0.1713261, -0.17527947, -0.17925633, -0.18325527, -0.18727483, -0.1913135, -0.19536984, -0.19944231, -0.20352939, -0.20762955, -0.21174124, -0.21586291, -0.21999297, -0.22412986, -0.22827196, -0.2324177, -0.23656549, -0.24071367, -0.24486068, -0.24900489, -0.25314465, -0.2572784, -0.2614045, -0.26552132, -0.26962727, -0.27372074, -0.2778001, -0.28186378, -0.28591022, -0.28993776, -0.29394493, -0.29793006, -0.3018917, -0.30582827, -0.30973828, -0.3136202, -0.31747255, -0.32129383, -0.32508266, -0.32883754, -0.3325571, -0.33623996, -0.3398847, -0.34349003, -0.34705463, -0.35057718, -0.35405645, -0.35749117, -0.36088017, -0.36422226, -0.36751628, -0.37076113, -0.37395573, -0.37709904, -0.38019, -0.3832277, -0.38621116, -0.38913944, -0.39201176, -0.39482722, -0.39758503, -0.40028447, -0.4029248, -0.4055054, -0.40802556, -0.41048476, -0.41288242, -0.41521806, -0.4174912, -0.41970143, -0.42184836, -0.42393172, -0.42595118, -0.42790654, -0.4297976, -0.43162417, -0.43338624, -0.43508372, -0.4367166, -0.4382849, -0.43978876, -0.4412283, -0.4426037, -0.44391522, -0.4451631, -0.4463477, -0.4474694, -0.44852862, -0.4495258, -0.4504615, -0.45133626, -0.4521507, -0.45290545, -0.45360127, -0.45423883, -0.45481902, -0.4553426, -0.4558105, -0.4562236, -0.45658293, -0.4568895, -0.45714432, -0.45734853, -0.45750326, -0.45760968, -0.45766905, -0.45768258, -0.45765162, -0.4575775, -0.45746157, -0.45730525, -0.45711002, -0.45687735, -0.4566087, -0.45630574, -0.45596996, -0.45560297, -0.45520648, -0.45478213, -0.4543316, -0.45385665, -0.45335904, -0.4528405, -0.4523029, -0.45174804, -0.45117778, -0.45059395, -0.4499985, -0.44939327, -0.4487802, -0.4481613, -0.44753844, -0.4469136, -0.44628876, -0.44566596, -0.44504714, -0.44443431, -0.4438295, -0.4432347, -0.442652, -0.44208336, -0.4415308, -0.44099635, -0.44048208, -0.43998992, -0.43952197, -0.4390802, -0.4386666, -0.4382832, -0.43793195, -0.43761483, -0.4373338, -0.43709075, -0.43688768, -0.43672648, -0.43660903, -0.43653724, -0.4365129, -0.43653783, -0.4366139, -0.4367428, -0.4369264, -0.43716633, -0.4374643, -0.43782198, -0.438241, -0.43872294, -0.4392694, -0.43988186, -0.44056183, -0.4413108, -0.4421301, -0.44302115, -0.44398525, -0.44502375, -0.4461378, -0.44732863, -0.4485974, -0.4499452, -0.4513731, -0.45288208, -0.4544731, -0.45614704, -0.4579048, -0.4597471, -0.46167472, -0.46368837, -0.4657886, -0.46797606, -0.47025123, -0.47261456, -0.47506645, -0.47760725, -0.48023722, -0.4829566, -0.4857655, -0.488664, -0.4916522, -0.49473003, -0.49789736, -0.50115407, -0.5044999, -0.50793463, -0.5114578, -0.51506907, -0.51876795, -0.5225538, -0.5264261, -0.5303841, -0.5344271, -0.53855425, -0.54276466, -0.54705745, -0.55143154, -0.55588585, -0.56041926, -0.5650306, -0.5697185, -0.57448167, -0.5793187, -0.58422816, -0.5892084, -0.594258, -0.5993751, -0.6045582, -0.60980535, -0.61511475, -0.62048453, -0.62591267, -0.6313972, -0.636936, -0.6425269, -0.6481678, -0.65385634, -0.6595903, -0.66536725, -0.67118484, -0.6770405, -0.6829318, -0.6888561, -0.6948109, -0.70079345, -0.70680094, -0.7128308, -0.71888006, -0.724946, -0.73102564, -0.7371161, -0.74321437, -0.74931747, -0.7554223, -0.76152587, -0.767625, -0.7737165, -0.7797973, -0.7858642, -0.7919138, -0.797943, -0.8039484, -0.8099268, -0.81587476, -0.821789, -0.82766616, -0.83350277, -0.8392955, -0.8450409, -0.85073555, -0.856376, -0.86195886, -0.8674806, -0.8729378, -0.878327, -0.88364476, -0.88888764, -0.8940521, -0.8991347, -0.90413207, -0.9090407, -0.91385716, -0.9185781, -0.9232, -0.9277196, -0.93213344, -0.9364382, -0.9406305, -0.9447071, -0.94866467, -0.9524999, -0.95620966, -0.95979065, -0.9632397, -0.9665538, -0.9697297, -0.97276443, -0.97565484, -0.978398, -0.980991, -0.98343086, -0.98571473, -0.9878398, -0.9898034, -0.99160266, -0.99323493, -0.9946977, -0.9959883, -0.9971043, -0.99804324, -0.99880266, -0.9993803, -0.99977386, -0.9999811, -1.0, -0.9998284, -0.9994643, -0.99890566, -0.9981508, -0.9971978, -0.996045, -0.99469066, -0.9931332, -0.9913712, -0.9894032, -0.98722774, -0.98484373, -0.9822498, -0.9794449, -0.97642803, -0.9731982, -0.9697545, -0.9660962, -0.96222264, -0.95813304, -0.95382696, -0.949304, -0.9445636, -0.9396058, -0.93443006, -0.9290365, -0.923425, -0.9175957, -0.91154873, -0.9052843, -0.8988028, -0.8921046, -0.88519025, -0.87806034, -0.87071556, -0.8631567, -0.8553846, -0.84740025, -0.83920467, -0.83079904, -0.8221845, -0.81336236, -0.8043341, -0.79510117, -0.7856651, -0.77602756, -0.76619035, -0.7561552, -0.74592406, -0.7354989, -0.7248819, -0.7140751, -0.7030807, -0.69190115, -0.6805388, -0.6689961, -0.6572756, -0.64538, -0.6333119, -0.6210742, -0.6086697, -0.5961013, -0.583372, -0.5704849, -0.55744314, -0.5442499, -0.53090847, -0.51742214, -0.5037944, -0.49002856, -0.4761283, -0.46209714, -0.4479387, -0.4336567, -0.41925484, -0.40473697, -0.39010692, -0.3753686, -0.36052594, -0.34558296, -0.33054367, -0.31541216, -0.3001926, -0.28488904, -0.2695058, -0.25404704, -0.23851703, -0.2229201, -0.20726055, -0.19154276, -0.17577109, -0.15994994, -0.14408375, -0.12817697, -0.11223406, -0.09625951, -0.080257796, -0.06423344, -0.048190955, -0.032134864, -0.016069701, ], [0.0, 0.055792555, 0.11138889, 0.166594, 0.22121526, 0.27506363, 0.3279548, 0.3797103, 0.43015853, 0.4791359, 0.5264877, 0.572069, 0.61574554, 0.65739447, 0.6969049, 0.7341787, 0.76913095, 0.80169, 0.8317983, 0.8594124, 0.8845027, 0.9070541, 0.92706555, 0.94454974, 0.9595331, 0.97205526, 0.98216873, 0.98993826, 0.99544007, 0.99876153, 1.0, 0.9992622, 0.99666333, 0.9923261, 0.9863797, 0.97895885, 0.9702028, 0.96025425, 0.9492583, 0.93736136, 0.92471004, 0.9114504, 0.89772654, 0.8836799, 0.86944836, 0.85516495, 0.8409576, 0.82694775, 0.81325006, 0.7999715, 0.78721106, 0.7750587, 0.7635956, 0.75289315, 0.74301314, 0.7340075, 0.7259178, 0.71877587, 0.7126033, 0.7074117, 0.7032033, 0.6999707, 0.6976975, 0.6963587, 0.69592106, 0.6963439, 0.6975794, 0.6995734, 0.70226604, 0.70559245, 0.70948356, 0.71386665, 0.71866643, 0.7238054, 0.72920513, 0.7347865, 0.7404709, 0.7461805, 0.75183946, 0.75737417, 0.7627142, 0.76779264, 0.7725468, 0.77691877, 0.78085554, 0.78430974, 0.7872399, 0.7896105, 0.7913924, 0.7925629, 0.7931059, 0.79301167, 0.7922771, 0.7909055, 0.7889063, 0.78629506, 0.7830929, 0.7793266, 0.7750279, 0.77023304, 0.76498264, 0.7593211, 0.75329584, 0.7469572, 0.7403576, 0.733551, 0.72659266, 0.71953803, 0.7124428, 0.70536196, 0.69834924, 0.6914571, 0.6847355, 0.678232, 0.6719914, 0.6660547, 0.6604595, 0.6552393, 0.6504231, 0.6460357, 0.64209694, 0.6386221, 0.6356214, 0.6331003, 0.6310591, 0.62949365, 0.62839484, 0.6277491, 0.6275385, 0.6277411, 0.628331, 0.62927896, 0.63055235, 0.63211596, 0.63393205, 0.6359609, 0.6381612, 0.6404905, 0.6429056, 0.64536315, 0.64781976, 0.65023285, 0.65256065, 0.654763, 0.6568013, 0.6586392, 0.660243, 0.66158146, 0.66262674, 0.6633541, 0.6637423, 0.66377395, 0.6634353, 0.6627165, 0.6616118, 0.66011924, 0.6582409, 0.6559828, 0.6533544, 0.65036917, 0.64704376, 0.64339817, 0.6394554, 0.63524115, 0.6307836, 0.6261131, 0.6212617, 0.61626303, 0.6111519, 0.6059636, 0.6007342, 0.5954994, 0.59029496, 0.5851557, 0.58011556, 0.57520694, 0.5704608, 0.565906, 0.56156915, 0.5574745, 0.5536436, 0.55009496, 0.54684424, 0.5439038, 0.5412829, 0.5389872, 0.5370194, 0.5353784, 0.53406024, 0.53305745, 0.5323596, 0.5319531, 0.53182167, 0.53194636, 0.5323058, 0.53287643, 0.53363276, 0.5345477, 0.5355927, 0.5367382, 0.5379539, 0.53920907, 0.54047275, 0.5417142, 0.54290324, 0.5440104, 0.54500735, 0.545867, 0.54656404, 0.5470749, 0.5473781, 0.54745454, 0.54728746, 0.5468627, 0.5461689, 0.5451976, 0.543943, 0.54240227, 0.54057574, 0.53846633, 0.53608, 0.5334254, 0.5305138, 0.5273592, 0.5239776, 0.5203875, 0.5166092, 0.51266485, 0.5085782, 0.50437397, 0.5000782, 0.49571753, 0.49131912, 0.4869103, 0.4825183, 0.47816995, 0.47389156, 0.46970844, 0.4656448, 0.4617235, 0.45796576, 0.45439097, 0.4510166, 0.44785792, 0.4449279, 0.44223714, 0.4397937, 0.43760312, 0.4356683, 0.43398952, 0.4325645, 0.43138832, 0.43045366, 0.42975068, 0.42926738, 0.42898947, 0.42890075, 0.42898315, 0.42921704, 0.42958125, 0.43005353, 0.43061063, 0.4312285, 0.43188274, 0.43254864, 0.43320155, 0.43381706, 0.43437135, 0.43484128, 0.43520477, 0.4354409, 0.43553016, 0.43545467, 0.43519834, 0.43474695, 0.4340884, 0.43321273, 0.43211225, 0.43078163, 0.42921785, 0.4274203, 0.42539078, 0.4231334, 0.4206546, 0.41796303, 0.41506946, 0.41198662, 0.40872914, 0.40531337, 0.40175718, 0.39807972, 0.39430133, 0.3904433, 0.38652754, 0.38257656, 0.378613, 0.37465957, 0.3707388, 0.36687276, 0.3630828, 0.35938945, 0.3558122, 0.35236916, 0.34907708, 0.34595108, 0.34300447, 0.34024873, 0.33769333, 0.33534563, 0.33321092, 0.33129215, 0.32959026, 0.32810378, 0.32682922, 0.3257608, 0.3248908, 0.3242094, 0.32370496, 0.32336408, 0.32317176, 0.32311153, 0.32316566, 0.3233153, 0.32354072, 0.32382149, 0.32413673, 0.32446527, 0.3247859, 0.3250775, 0.32531947, 0.32549164, 0.32557476, 0.3255505, 0.32540163, 0.32511243, 0.32466847, 0.3240571, 0.3232673, 0.32229006, 0.32111815, 0.31974646, 0.3181719, 0.3163934, 0.3144121, 0.3122311, 0.30985552, 0.30729255, 0.30455115, 0.3016421, 0.29857787, 0.2953725, 0.29204133, 0.288601, 0.28506923, 0.28146452, 0.27780613, 0.2741138, 0.2704075, 0.26670733, 0.2630333, 0.25940496, 0.25584146, 0.25236118, 0.24898157, 0.24571908, 0.24258882, 0.23960458, 0.23677859, 0.23412143, 0.23164193, 0.22934711, 0.22724204, 0.22532986, 0.22361176, 0.2220869, 0.22075254, 0.21960394, 0.2186345, 0.21783581, 0.21719778, 0.21670863, 0.21635519, 0.21612284, 0.21599585, 0.2159574, 0.21598983, 0.21607482, 0.21619353, 0.21632682, 0.21645549, 0.2165604, 0.2166227, 0.21662402, 0.21654668, 0.21637382, 0.21608959, 0.21567935, 0.21512972, 0.21442881, 0.21356632, 0.21253356, 0.21132365, 0.20993151, 0.20835392, 0.20658958, 0.20463909, 0.20250495, 0.20019151, 0.19770496, 0.19505325, 0.19224596, 0.18929428, 0.18621083, 0.18300955, 0.17970558, 0.17631502, 0.17285489, 0.16934285, 0.16579705, 0.16223598, 0.15867823, 0.15514235, 0.1516466, 0.1482088, 0.14484617, 0.14157513, 0.13841112, 0.1353685, 0.13246031, 0.12969823, 0.12709245, 0.124651514, 0.12238229, 0.12028987, 0.11837754, 0.11664675, 0.11509708, 0.113726296, 0.11253029, 0.11150322, 0.1106375, 0.10992394, 0.10935177, 0.108908825, 0.10858162, 0.10835551, 0.10821483, 0.10814309, 0.10812309, 0.10813713, 0.10816718, 0.10819509, 0.10820272, 0.108172186, 0.108086, 0.10792726, 0.107679844, 0.10732853, 0.10685917, 0.106258854, 0.10551601, 0.10462053, 0.1035639, 0.10233922, 0.10094134, 0.099366866, 0.09761422, 0.09568366, 0.093577206, 0.09129874, 0.08885385, 0.086249836, 0.08349561, 0.08060162, 0.07757972, 0.07444308, 0.07120601, 0.06788385, 0.064492784, 0.061049715, 0.05757204, 0.05407751, 0.050584033, 0.047109503, 0.0436716, 0.040287636, 0.03697434, 0.033747736, 0.030622937, 0.027614016, 0.024733856, 0.02199402, 0.019404635, 0.016974283, 0.014709922, 0.012616812, 0.010698463, 0.008956598, 0.007391139, 0.006000205, 0.0047801347, 0.0037255217, 0.0028292718, 0.0020826745, 0.0014754933, 0.0009960693, 0.00063144066, 0.00036747506, 0.00018901352, 8.002544e-5, 2.377181e-5, 2.9760279e-6, 1.6293342e-16, -2.9760279e-6, -2.377181e-5, -8.002544e-5, -0.00018901352, -0.00036747506, -0.00063144066, -0.0009960693, -0.0014754933, -0.0020826745, -0.0028292718, -0.0037255217, -0.0047801347, -0.006000205, -0.007391139, -0.008956598, -0.010698463, -0.012616812, -0.014709922, -0.016974283, -0.019404635, -0.02199402, -0.024733856, -0.027614016, -0.030622937, -0.033747736, -0.03697434, -0.040287636, -0.0436716, -0.047109503, -0.050584033, -0.05407751, -0.05757204, -0.061049715, -0.064492784, -0.06788385, -0.07120601, -0.07444308, -0.07757972, -0.08060162, -0.08349561, -0.086249836, -0.08885385, -0.09129874, -0.093577206, -0.09568366, -0.09761422, -0.099366866, -0.10094134, -0.10233922, -0.1035639, -0.10462053, -0.10551601, -0.106258854, -0.10685917, -0.10732853, -0.107679844, -0.10792726, -0.108086, -0.108172186, -0.10820272, -0.10819509, -0.10816718, -0.10813713, -0.10812309, -0.10814309, -0.10821483, -0.10835551, -0.10858162, -0.108908825, -0.10935177, -0.10992394, -0.1106375, -0.11150322, -0.11253029, -0.113726296, -0.11509708, -0.11664675, -0.11837754, -0.12028987, -0.12238229, -0.124651514, -0.12709245, -0.12969823, -0.13246031, -0.1353685, -0.13841112, -0.14157513, -0.14484617, -0.1482088, -0.1516466, -0.15514235, -0.15867823, -0.16223598, -0.16579705, -0.16934285, -0.17285489, -0.17631502, -0.17970558, -0.18300955, -0.18621083, -0.18929428, -0.19224596, -0.19505325, -0.19770496, -0.20019151, -0.20250495, -0.20463909, -0.20658958, -0.20835392, -0.20993151, -0.21132365, -0.21253356, -0.21356632, -0.21442881, -0.21512972, -0.21567935, -0.21608959, -0.21637382, -0.21654668, -0.21662402, -0.2166227, -0.2165604, -0.21645549, -0.21632682, -0.21619353, -0.21607482, -0.21598983, -0.2159574, -0.21599585, -0.21612284, -0.21635519, -0.21670863, -0.21719778, -0.21783581, -0.2186345, -0.21960394, -0.22075254, -0.2220869, -0.22361176, -0.22532986, -0.22724204, -0.22934711, -0.23164193, -0.23412143, -0.23677859, -0.23960458, -0.24258882, -0.24571908, -0.24898157, -0.25236118, -0.25584146, -0.25940496, -0.2630333, -0.26670733, -0.2704075, -0.2741138, -0.27780613, -0.28146452, -0.28506923, -0.288601, -0.29204133, -0.2953725, -0.29857787, -0.3016421, -0.30455115, -0.30729255, -0.30985552, -0.3122311, -0.3144121, -0.3163934, -0.3181719, -0.31974646, -0.32111815, -0.32229006, -0.3232673, -0.3240571, -0.32466847, -0.32511243, -0.32540163, -0.3255505, -0.32557476, -0.32549164, -0.32531947, -0.3250775, -0.3247859, -0.32446527, -0.32413673, -0.32382149, -0.32354072, -0.3233153, -0.32316566, -0.32311153, -0.32317176, -0.32336408, -0.32370496, -0.3242094, -0.3248908, -0.3257608, -0.32682922, -0.32810378, -0.32959026, -0.33129215, -0.33321092, -0.33534563, -0.33769333, -0.34024873, -0.34300447, -0.34595108, -0.34907708, -0.35236916, -0.3558122, -0.35938945, -0.3630828, -0.36687276, -0.3707388, -0.37465957, -0.378613, -0.38257656, -0.38652754, -0.3904433, -0.39430133, -0.39807972, -0.40175718, -0.40531337, -0.40872914, -0.41198662, -0.41506946, -0.41796303, -0.4206546, -0.4231334, -0.42539078, -0.4274203, -0.42921785, -0.43078163, -0.43211225, -0.43321273, -0.4340884, -0.43474695, -0.43519834, -0.43545467, -0.43553016, -0.4354409, -0.43520477, -0.43484128, -0.43437135, -0.43381706, -0.43320155, -0.43254864, -0.43188274, -0.4312285, -0.43061063, -0.43005353, -0.42958125, -0.42921704, -0.42898315, -0.42890075, -0.42898947, -0.42926738, -0.42975068, -0.43045366, -0.43138832, -0.4325645, -0.43398952, -0.4356683, -0.43760312, -0.4397937, -0.44223714, -0.4449279, -0.44785792, -0.4510166, -0.45439097, -0.45796576, -0.4617235, -0.4656448, -0.46970844, -0.47389156, -0.47816995, -0.4825183, -0.4869103, -0.49131912, -0.49571753, -0.5000782, -0.50437397, -0.5085782, -0.51266485, -0.5166092, -0.5203875, -0.5239776, -0.5273592, -0.5305138, -0.5334254, -0.53608, -0.53846633, -0.54057574, -0.54240227, -0.543943, -0.5451976, -0.5461689, -0.5468627, -0.54728746, -0.54745454, -0.5473781, -0.5470749, -0.54656404, -0.545867, -0.54500735, -0.5440104, -0.54290324, -0.5417142, -0.54047275, -0.53920907, -0.5379539, -0.5367382, -0.5355927, -0.5345477, -0.53363276, -0.53287643, -0.5323058, -0.53194636, -0.53182167, -0.5319531, -0.5323596, -0.53305745, -0.53406024, -0.5353784, -0.5370194, -0.5389872, -0.5412829, -0.5439038, -0.54684424, -0.55009496, -0.5536436, -0.5574745, -0.56156915, -0.565906, -0.5704608, -0.57520694, -0.58011556, -0.5851557, -0.59029496, -0.5954994, -0.6007342, -0.6059636, -0.6111519, -0.61626303, -0.6212617, -0.6261131, -0.6307836, -0.63524115, -0.6394554, -0.64339817, -0.64704376, -0.65036917, -0.6533544, -0.6559828, -0.6582409, -0.66011924, -0.6616118, -0.6627165, -0.6634353, -0.66377395, -0.6637423, -0.6633541, -0.66262674, -0.66158146, -0.660243, -0.6586392, -0.6568013, -0.654763, -0.65256065, -0.65023285, -0.64781976, -0.64536315, -0.6429056, -0.6404905, -0.6381612, -0.6359609, -0.63393205, -0.63211596, -0.63055235, -0.62927896, -0.628331, -0.6277411, -0.6275385, -0.6277491, -0.62839484, -0.62949365, -0.6310591, -0.6331003, -0.6356214, -0.6386221, -0.64209694, -0.6460357, -0.6504231, -0.6552393, -0.6604595, -0.6660547, -0.6719914, -0.678232, -0.6847355, -0.6914571, -0.69834924, -0.70536196, -0.7124428, -0.71953803, -0.72659266, -0.733551, -0.7403576, -0.7469572, -0.75329584, -0.7593211, -0.76498264, -0.77023304, -0.7750279, -0.7793266, -0.7830929, -0.78629506, -0.7889063, -0.7909055, -0.7922771, -0.79301167, -0.7931059, -0.7925629, -0.7913924, -0.7896105, -0.7872399, -0.78430974, -0.78085554, -0.77691877, -0.7725468, -0.76779264, -0.7627142, -0.75737417, -0.75183946, -0.7461805, -0.7404709, -0.7347865, -0.72920513, -0.7238054, -0.71866643, -0.71386665, -0.70948356, -0.70559245, -0.70226604, -0.6995734, -0.6975794, -0.6963439, -0.69592106, -0.6963587, -0.6976975, -0.6999707, -0.7032033, -0.7074117, -0.7126033, -0.71877587, -0.7259178, -0.7340075, -0.74301314, -0.75289315, -0.7635956, -0.7750587, -0.78721106, -0.7999715, -0.81325006, -0.82694775, -0.8409576, -0.85516495, -0.86944836, -0.8836799, -0.89772654, -0.9114504, -0.92471004, -0.93736136, -0.9492583, -0.96025425, -0.9702028, -0.97895885, -0.9863797, -0.9923261, -0.99666333, -0.9992622, -1.0, -0.99876153, -0.99544007, -0.98993826, -0.98216873, -0.97205526, -0.9595331, -0.94454974, -0.92706555, -0.9070541, -0.8845027, -0.8594124, -0.8317983, -0.80169, -0.76913095, -0.7341787, -0.6969049, -0.65739447, -0.61574554, -0.572069, -0.5264877, -0.4791359, -0.43015853, -0.3797103, -0.3279548, -0.27506363, -0.22121526, -0.166594, -0.11138889, -0.055792555, ], [0.0, 0.21302757, 0.41501063, 0.5959282, 0.7476936, 0.86485237, 0.94499475, 0.988841, 1.0, 0.9844364, 0.94971657, 0.9041261, 0.8557653, 0.8117246, 0.7774329, 0.7562425, 0.7492858, 0.7556041, 0.7725137, 0.7961501, 0.8221089, 0.8460978, 0.86451477, 0.874885, 0.8761085, 0.86850053, 0.8536337, 0.8340173, 0.8126691, 0.7926452, 0.7765966, 0.7664123, 0.76299447, 0.7661884, 0.77486956, 0.78716534, 0.8007729, 0.8133216, 0.8227264, 0.8274808, 0.8268495, 0.82093936, 0.81064194, 0.79746395, 0.78327423, 0.770009, 0.75938, 0.7526286, 0.7503605, 0.7524813, 0.7582392, 0.76636416, 0.7752785, 0.7833456, 0.7891181, 0.79154843, 0.7901319, 0.7849638, 0.7767032, 0.7664551, 0.7555885, 0.7455206, 0.73750186, 0.732432, 0.7307354, 0.7323115, 0.7365659, 0.7425155, 0.7489487, 0.75461555, 0.75841814, 0.75957316, 0.75772136, 0.75297076, 0.74586743, 0.737301, 0.7283605, 0.7201637, 0.7136865, 0.7096186, 0.7082652, 0.7095105, 0.7128453, 0.71745425, 0.7223467, 0.726512, 0.72907364, 0.72941965, 0.72729003, 0.72280824, 0.71645415, 0.70898306, 0.7013047, 0.69434106, 0.68888575, 0.6854855, 0.6843619, 0.6853841, 0.6880959, 0.6917921, 0.69563043, 0.6987636, 0.7004682, 0.70025307, 0.69793, 0.6936365, 0.6878092, 0.68111145, 0.67432773, 0.6682417, 0.66351634, 0.66059494, 0.6596367, 0.6604976, 0.6627576, 0.66578937, 0.668858, 0.6712349, 0.67230946, 0.67168057, 0.66921437, 0.66506076, 0.65962577, 0.6535055, 0.64739186, 0.64196503, 0.6377896, 0.6352298, 0.63439673, 0.6351351, 0.6370509, 0.6395754, 0.64205474, 0.6438512, 0.6444411, 0.6434931, 0.6409163, 0.6368711, 0.63174087, 0.62607026, 0.6204795, 0.6155682, 0.61182374, 0.609548, 0.6088134, 0.6094551, 0.6110988, 0.6132216, 0.61523324, 0.6165682, 0.616772, 0.61556923, 0.612904, 0.6089457, 0.6040601, 0.5987511, 0.5935816, 0.58908635, 0.5856902, 0.58364445, 0.58298975, 0.5835528, 0.5849751, 0.58677036, 0.5884002, 0.5893578, 0.5892453, 0.58783364, 0.5850958, 0.58120906, 0.57652503, 0.5715145, 0.566693, 0.562542, 0.55943465, 0.5575797, 0.55699134, 0.55748904, 0.55872685, 0.56024873, 0.5615595, 0.5622014, 0.561824, 0.5602371, 0.5574383, 0.55361164, 0.5490974, 0.54433835, 0.5398108, 0.53595066, 0.53308755, 0.53139424, 0.53086215, 0.5313043, 0.53238523, 0.53367454, 0.53471357, 0.5350862, 0.53448266, 0.532746, 0.52989507, 0.5261199, 0.5217512, 0.5172081, 0.5129329, 0.509323, 0.5066701, 0.5051161, 0.5046326, 0.5050267, 0.5059721, 0.5070602, 0.5078639, 0.5080032, 0.5072034, 0.5053367, 0.50244045, 0.49871013, 0.49446815, 0.4901132, 0.4860582, 0.4826668, 0.48019764, 0.47876543, 0.4783244, 0.4786765, 0.479503, 0.48041454, 0.48101148, 0.4809456, 0.47997323, 0.47799203, 0.47505584, 0.47136506, 0.46723497, 0.46304622, 0.4591859, 0.45598775, 0.45368123, 0.45235696, 0.4519536, 0.4522684, 0.4529893, 0.4537439, 0.45415702, 0.45390856, 0.45278245, 0.45069924, 0.4477274, 0.44407195, 0.44004175, 0.4360016, 0.43231535, 0.42929018, 0.42712936, 0.42590183, 0.42553222, 0.42581353, 0.42643964, 0.4270532, 0.42730102, 0.42688826, 0.42562371, 0.42344856, 0.42044464, 0.416821, 0.41288105, 0.40897506, 0.4054462, 0.40257746, 0.4005485, 0.39940852, 0.39906955, 0.39932048, 0.39986056, 0.40034613, 0.4004438, 0.39988172, 0.3984913, 0.39623252, 0.39319935, 0.38960475, 0.385747, 0.3819634, 0.37857813, 0.3758522, 0.37394366, 0.37288377, 0.37257275, 0.3727959, 0.37325725, 0.37362555, 0.37358558, 0.37288663, 0.37138072, 0.36904514, 0.36598516, 0.3624172, 0.35863504, 0.35496402, 0.35171092, 0.34911647, 0.34731892, 0.34633282, 0.34604758, 0.3462451, 0.34663376, 0.34689376, 0.34672663, 0.34590107, 0.34428835, 0.34188172, 0.33879688, 0.33525363, 0.3315415, 0.32797492, 0.3248444, 0.32237205, 0.3206775, 0.31975994, 0.31949872, 0.31967238, 0.31999338, 0.32015258, 0.319867, 0.31892353, 0.31721127, 0.31473836, 0.31163034, 0.30811015, 0.30446345, 0.30099437, 0.29797843, 0.29562023, 0.29402205, 0.29316866, 0.2929299, 0.29308122, 0.29333884, 0.2934035, 0.2930069, 0.29195276, 0.29014704, 0.28761193, 0.28448212, 0.2809836, 0.2773984, 0.2740211, 0.27111298, 0.2688622, 0.26735482, 0.26656184, 0.26634422, 0.26647452, 0.2666723, 0.26664782, 0.26614636, 0.2649877, 0.26309368, 0.26049975, 0.25734934, 0.25387132, 0.25034437, 0.24705388, 0.2442479, 0.24209893, 0.2406776, 0.23994194, 0.2397444, 0.23985468, 0.23999573, 0.23988652, 0.23928542, 0.23802745, 0.23604944, 0.23339958, 0.23022959, 0.22677109, 0.22329956, 0.22009182, 0.21738318, 0.21533127, 0.21399197, 0.21331102, 0.21313262, 0.21322381, 0.21331066, 0.21312049, 0.21242417, 0.21107122, 0.20901291, 0.20630953, 0.20312078, 0.19968095, 0.19626255, 0.1931341, 0.19051874, 0.1885599, 0.18729933, 0.18667084, 0.18651083, 0.1865837, 0.18661845, 0.18635051, 0.18556266, 0.18411838, 0.1819828, 0.1792279, 0.17602111, 0.17259929, 0.16923204, 0.16617998, 0.16365457, 0.16178548, 0.16060084, 0.16002297, 0.15988071, 0.15993589, 0.15992032, 0.15957722, 0.15870088, 0.1571683, 0.154958, 0.15215327, 0.148929, 0.1455246, 0.1422069, 0.13922888, 0.13679063, 0.13500856, 0.13389754, 0.1333688, 0.13324377, 0.13328175, 0.13321732, 0.13280122, 0.1318389, 0.13022046, 0.12793751, 0.1250843, 0.12184303, 0.11845561, 0.115186155, 0.11228023, 0.109926894, 0.10822962, 0.1071904, 0.10670957, 0.10660132, 0.10662255, 0.106510416, 0.10602303, 0.10497672, 0.103274405, 0.10092043, 0.09801982, 0.09476194, 0.09139111, 0.08816887, 0.08533352, 0.08306335, 0.08144915, 0.08048028, 0.08004639, 0.07995462, 0.07995939, 0.079800464, 0.07924311, 0.07811435, 0.07632966, 0.07390593, 0.070958756, 0.067684524, 0.06433001, 0.061154217, 0.05838831, 0.05619999, 0.054667566, 0.05376796, 0.05338033, 0.053304784, 0.053293347, 0.053088278, 0.05246191, 0.05125181, 0.049385834, 0.04689323, 0.043900065, 0.040609695, 0.037271313, 0.034141418, 0.031444173, 0.029336795, 0.027885275, 0.027054224, 0.026712375, 0.0266529, 0.026625402, 0.026374614, 0.025679851, 0.024389105, 0.02244251, 0.019881587, 0.016842786, 0.013536396, 0.010214028, 0.0071297237, 0.004500696, 0.0024737648, 0.0011026739, 0.00033979278, 4.3484466e-5, -1.1744697e-16, -4.3484466e-5, -0.00033979278, -0.0011026739, -0.0024737648, -0.004500696, -0.0071297237, -0.010214028, -0.013536396, -0.016842786, -0.019881587, -0.02244251, -0.024389105, -0.025679851, -0.026374614, -0.026625402, -0.0266529, -0.026712375, -0.027054224, -0.027885275, -0.029336795, -0.031444173, -0.034141418, -0.037271313, -0.040609695, -0.043900065, -0.04689323, -0.049385834, -0.05125181, -0.05246191, -0.053088278, -0.053293347, -0.053304784, -0.05338033, -0.05376796, -0.054667566, -0.05619999, -0.05838831, -0.061154217, -0.06433001, -0.067684524, -0.070958756, -0.07390593, -0.07632966, -0.07811435, -0.07924311, -0.079800464, -0.07995939, -0.07995462, -0.08004639, -0.08048028, -0.08144915, -0.08306335, -0.08533352, -0.08816887, -0.09139111, -0.09476194, -0.09801982, -0.10092043, -0.103274405, -0.10497672, -0.10602303, -0.106510416, -0.10662255, -0.10660132, -0.10670957, -0.1071904, -0.10822962, -0.109926894, -0.11228023, -0.115186155, -0.11845561, -0.12184303, -0.1250843, -0.12793751, -0.13022046, -0.1318389, -0.13280122, -0.13321732, -0.13328175, -0.13324377, -0.1333688, -0.13389754, -0.13500856, -0.13679063, -0.13922888, -0.1422069, -0.1455246, -0.148929, -0.15215327, -0.154958, -0.1571683, -0.15870088, -0.15957722, -0.15992032, -0.15993589, -0.15988071, -0.16002297, -0.16060084, -0.16178548, -0.16365457, -0.16617998, -0.16923204, -0.17259929, -0.17602111, -0.1792279, -0.1819828, -0.18411838, -0.18556266, -0.18635051, -0.18661845, -0.1865837, -0.18651083, -0.18667084, -0.18729933, -0.1885599, -0.19051874, -0.1931341, -0.19626255, -0.19968095, -0.20312078, -0.20630953, -0.20901291, -0.21107122, -0.21242417, -0.21312049, -0.21331066, -0.21322381, -0.21313262, -0.21331102, -0.21399197, -0.21533127, -0.21738318, -0.22009182, -0.22329956, -0.22677109, -0.23022959, -0.23339958, -0.23604944, -0.23802745, -0.23928542, -0.23988652, -0.23999573, -0.23985468, -0.2397444, -0.23994194, -0.2406776, -0.24209893, -0.2442479, -0.24705388, -0.25034437, -0.25387132, -0.25734934, -0.26049975, -0.26309368, -0.2649877, -0.26614636, -0.26664782, -0.2666723, -0.26647452, -0.26634422, -0.26656184, -0.26735482, -0.2688622, -0.27111298, -0.2740211, -0.2773984, -0.2809836, -0.28448212, -0.28761193, -0.29014704, -0.29195276, -0.2930069, -0.2934035, -0.29333884, -0.29308122, -0.2929299, -0.29316866, -0.29402205, -0.29562023, -0.29797843, -0.30099437, -0.30446345, -0.30811015, -0.31163034, -0.31473836, -0.31721127, -0.31892353, -0.319867, -0.32015258, -0.31999338, -0.31967238, -0.31949872, -0.31975994, -0.3206775, -0.32237205, -0.3248444, -0.32797492, -0.3315415, -0.33525363, -0.33879688, -0.34188172, -0.34428835, -0.34590107, -0.34672663, -0.34689376, -0.34663376, -0.3462451, -0.34604758, -0.34633282, -0.34731892, -0.34911647, -0.35171092, -0.35496402, -0.35863504, -0.3624172, -0.36598516, -0.36904514, -0.37138072, -0.37288663, -0.37358558, -0.37362555, -0.37325725, -0.3727959, -0.37257275, -0.37288377, -0.37394366, -0.3758522, -0.37857813, -0.3819634, -0.385747, -0.38960475, -0.39319935, -0.39623252, -0.3984913, -0.39988172, -0.4004438, -0.40034613, -0.39986056, -0.39932048, -0.39906955, -0.39940852, -0.4005485, -0.40257746, -0.4054462, -0.40897506, -0.41288105, -0.416821, -0.42044464, -0.42344856, -0.42562371, -0.42688826, -0.42730102, -0.4270532, -0.42643964, -0.42581353, -0.42553222, -0.42590183, -0.42712936, -0.42929018, -0.43231535, -0.4360016, -0.44004175, -0.44407195, -0.4477274, -0.45069924, -0.45278245, -0.45390856, -0.45415702, -0.4537439, -0.4529893, -0.4522684, -0.4519536, -0.45235696, -0.45368123, -0.45598775, -0.4591859, -0.46304622, -0.46723497, -0.47136506, -0.47505584, -0.47799203, -0.47997323, -0.4809456, -0.48101148, -0.48041454, -0.479503, -0.4786765, -0.4783244, -0.47876543, -0.48019764, -0.4826668, -0.4860582, -0.4901132, -0.49446815, -0.49871013, -0.50244045, -0.5053367, -0.5072034, -0.5080032, -0.5078639, -0.5070602, -0.5059721, -0.5050267, -0.5046326, -0.5051161, -0.5066701, -0.509323, -0.5129329, -0.5172081, -0.5217512, -0.5261199, -0.52989507, -0.532746, -0.53448266, -0.5350862, -0.53471357, -0.53367454, -0.53238523, -0.5313043, -0.53086215, -0.53139424, -0.53308755, -0.53595066, -0.5398108, -0.54433835, -0.5490974, -0.55361164, -0.5574383, -0.5602371, -0.561824, -0.5622014, -0.5615595, -0.56024873, -0.55872685, -0.55748904, -0.55699134, -0.5575797, -0.55943465, -0.562542, -0.566693, -0.5715145, -0.57652503, -0.58120906, -0.5850958, -0.58783364, -0.5892453, -0.5893578, -0.5884002, -0.58677036, -0.5849751, -0.5835528, -0.58298975, -0.58364445, -0.5856902, -0.58908635, -0.5935816, -0.5987511, -0.6040601, -0.6089457, -0.612904, -0.61556923, -0.616772, -0.6165682, -0.61523324, -0.6132216, -0.6110988, -0.6094551, -0.6088134, -0.609548, -0.61182374, -0.6155682, -0.6204795, -0.62607026, -0.63174087, -0.6368711, -0.6409163, -0.6434931, -0.6444411, -0.6438512, -0.64205474, -0.6395754, -0.6370509, -0.6351351, -0.63439673, -0.6352298, -0.6377896, -0.64196503, -0.64739186, -0.6535055, -0.65962577, -0.66506076, -0.66921437, -0.67168057, -0.67230946, -0.6712349, -0.668858, -0.66578937, -0.6627576, -0.6604976, -0.6596367, -0.66059494, -0.66351634, -0.6682417, -0.67432773, -0.68111145, -0.6878092, -0.6936365, -0.69793, -0.70025307, -0.7004682, -0.6987636, -0.69563043, -0.6917921, -0.6880959, -0.6853841, -0.6843619, -0.6854855, -0.68888575, -0.69434106, -0.7013047, -0.70898306, -0.71645415, -0.72280824, -0.72729003, -0.72941965, -0.72907364, -0.726512, -0.7223467, -0.71745425, -0.7128453, -0.7095105, -0.7082652, -0.7096186, -0.7136865, -0.7201637, -0.7283605, -0.737301, -0.74586743, -0.75297076, -0.75772136, -0.75957316, -0.75841814, -0.75461555, -0.7489487, -0.7425155, -0.7365659, -0.7323115, -0.7307354, -0.732432, -0.73750186, -0.7455206, -0.7555885, -0.7664551, -0.7767032, -0.7849638, -0.7901319, -0.79154843, -0.7891181, -0.7833456, -0.7752785, -0.76636416, -0.7582392, -0.7524813, -0.7503605, -0.7526286, -0.75938, -0.770009, -0.78327423, -0.79746395, -0.81064194, -0.82093936, -0.8268495, -0.8274808, -0.8227264, -0.8133216, -0.8007729, -0.78716534, -0.77486956, -0.7661884, -0.76299447, -0.7664123, -0.7765966, -0.7926452, -0.8126691, -0.8340173, -0.8536337, -0.86850053, -0.8761085, -0.874885, -0.86451477, -0.8460978, -0.8221089, -0.7961501, -0.7725137, -0.7556041, -0.7492858, -0.7562425, -0.7774329, -0.8117246, -0.8557653, -0.9041261, -0.94971657, -0.9844364, -1.0, -0.988841, -0.94499475, -0.86485237, -0.7476936, -0.5959282, -0.41501063, -0.21302757, ], [0.0, 0.21246108, 0.4139579, 0.59453917, 0.74616826, 0.863415, 0.94386536, 0.9882084, 1.0, 0.98513794, 0.95111793, 0.9061614, 0.8583194, 0.81465507, 0.7805945, 0.75951135, 0.752579, 0.758892, 0.7758242, 0.7995625, 0.8257408, 0.85008526, 0.8689905, 0.879957, 0.8818431, 0.8749119, 0.86068225, 0.841616, 0.8206976, 0.8009691, 0.7850888, 0.7749729, 0.77156657, 0.774767, 0.783502, 0.79594404, 0.8098205, 0.8227718, 0.83270293, 0.8380779, 0.8381174, 0.83287627, 0.8231945, 0.81053555, 0.7967401, 0.7837358, 0.77324665, 0.76654524, 0.7642823, 0.76641566, 0.7722446, 0.7805409, 0.7897531, 0.7982512, 0.80457366, 0.80764014, 0.80689985, 0.8023954, 0.79473567, 0.7849848, 0.7744871, 0.76465505, 0.7567552, 0.7517214, 0.75002515, 0.75161856, 0.75595737, 0.76209825, 0.7688526, 0.7749735, 0.7793455, 0.7811495, 0.77997833, 0.7758871, 0.76937205, 0.7612841, 0.75269055, 0.74470776, 0.73833114, 0.7342871, 0.7329297, 0.7341966, 0.73762786, 0.74244595, 0.74768066, 0.752321, 0.75546974, 0.7564766, 0.75503176, 0.75120515, 0.74542767, 0.7384176, 0.7310657, 0.7242956, 0.71892345, 0.7155355, 0.7144039, 0.7154518, 0.71827155, 0.722194, 0.7263948, 0.7300232, 0.732332, 0.7327891, 0.73115474, 0.7275125, 0.72225046, 0.71599674, 0.7095194, 0.7036069, 0.6989478, 0.69602734, 0.6950571, 0.69594765, 0.6983269, 0.7016019, 0.705053, 0.7079451, 0.7096408, 0.7096954, 0.707922, 0.70441556, 0.6995345, 0.69384134, 0.68801403, 0.68274075, 0.67861456, 0.67604434, 0.67519534, 0.6759673, 0.67801374, 0.6807986, 0.6836805, 0.6860124, 0.6872405, 0.68698746, 0.68510747, 0.6817054, 0.6771176, 0.6718569, 0.6665321, 0.66175395, 0.6580414, 0.65574366, 0.65498906, 0.6556684, 0.65745443, 0.65985495, 0.6622898, 0.6641809, 0.66504043, 0.6645444, 0.66258013, 0.65926087, 0.65490586, 0.649989, 0.64506453, 0.6406814, 0.63729936, 0.63521963, 0.63454074, 0.63514566, 0.6367223, 0.6388133, 0.6408877, 0.6424228, 0.6429843, 0.6422915, 0.6402589, 0.6370069, 0.63284117, 0.6282043, 0.62360615, 0.61954546, 0.61643356, 0.61453223, 0.6139153, 0.6144592, 0.6158639, 0.61770046, 0.6194781, 0.6207198, 0.6210354, 0.62018013, 0.61809105, 0.6148946, 0.6108858, 0.6064812, 0.60215396, 0.59836125, 0.59547406, 0.59372133, 0.5931561, 0.5936491, 0.59491014, 0.59653395, 0.5980633, 0.59905934, 0.59916884, 0.5981773, 0.5960408, 0.59289116, 0.58901423, 0.5848051, 0.58070606, 0.5771393, 0.5744417, 0.57281435, 0.57229275, 0.5727427, 0.5738822, 0.57532597, 0.5766449, 0.5774327, 0.5773673, 0.57626003, 0.57408327, 0.5709734, 0.5672087, 0.5631659, 0.55926126, 0.555887, 0.553351, 0.55183065, 0.5513463, 0.5517595, 0.5527952, 0.5540849, 0.55522394, 0.55583346, 0.5556184, 0.55441207, 0.55220073, 0.54912496, 0.5454565, 0.5415564, 0.5378186, 0.5346096, 0.5322125, 0.5307841, 0.53033173, 0.5307133, 0.53165966, 0.53281695, 0.53380114, 0.5342572, 0.5339132, 0.5326215, 0.5303804, 0.52733403, 0.5237486, 0.51997155, 0.51637757, 0.51331115, 0.5110338, 0.5096847, 0.50925994, 0.5096142, 0.5104835, 0.5115264, 0.51237696, 0.51270056, 0.5122453, 0.5108799, 0.50861317, 0.50559205, 0.50207824, 0.49840745, 0.49493766, 0.49199426, 0.48982027, 0.4885396, 0.48813874, 0.48846945, 0.4892722, 0.49021652, 0.4909518, 0.49116126, 0.4906101, 0.48918116, 0.48689237, 0.48389313, 0.48044094, 0.47686154, 0.47349852, 0.4706609, 0.4685757, 0.4673538, 0.4669734, 0.4672839, 0.46802968, 0.4688895, 0.46952608, 0.4696378, 0.46900463, 0.46752146, 0.46521378, 0.46223313, 0.45883352, 0.45533195, 0.45205984, 0.44931212, 0.44730237, 0.44613037, 0.44576728, 0.44606072, 0.44675827, 0.44754672, 0.44810006, 0.44812948, 0.44742727, 0.44589853, 0.443575, 0.44060996, 0.43725428, 0.43381768, 0.4306213, 0.42794836, 0.42600125, 0.42487055, 0.42452183, 0.42480117, 0.42545906, 0.42618892, 0.42667404, 0.4266362, 0.42587778, 0.42431197, 0.42197558, 0.41902316, 0.41570282, 0.41231838, 0.40918258, 0.40656927, 0.40467188, 0.40357387, 0.40323648, 0.40350476, 0.40413165, 0.40481597, 0.4052484, 0.4051588, 0.40435734, 0.40276334, 0.40041724, 0.39747426, 0.39418033, 0.39083454, 0.38774326, 0.3851735, 0.3833121, 0.38223758, 0.38190824, 0.38216874, 0.38277408, 0.38342705, 0.38382354, 0.38369915, 0.38286915, 0.38125673, 0.37890434, 0.37596732, 0.37268978, 0.36936754, 0.36630285, 0.36375844, 0.36191756, 0.36085603, 0.360531, 0.3607875, 0.36138213, 0.36202008, 0.3624002, 0.36226076, 0.36141917, 0.3597997, 0.35744515, 0.35450995, 0.3512369, 0.34792015, 0.3448605, 0.3423196, 0.3404804, 0.33941913, 0.33909395, 0.33935106, 0.33994824, 0.34059143, 0.34097943, 0.34084955, 0.34001753, 0.33840552, 0.33605385, 0.33311525, 0.3298315, 0.32649717, 0.32341477, 0.32084915, 0.31898725, 0.3179097, 0.31757838, 0.3178422, 0.31845957, 0.31913486, 0.31956306, 0.31947574, 0.3186819, 0.31709674, 0.31475496, 0.31180575, 0.3084905, 0.3051067, 0.30196315, 0.2993335, 0.29741484, 0.29629764, 0.29595187, 0.296231, 0.2968937, 0.29763964, 0.29815465, 0.29815772, 0.29744357, 0.2959138, 0.29359215, 0.29062176, 0.287244, 0.28376305, 0.28050056, 0.27774745, 0.27572015, 0.27452767, 0.2741546, 0.27446243, 0.27520958, 0.2760865, 0.27676174, 0.27693093, 0.2763628, 0.2749341, 0.27264917, 0.26964024, 0.26614925, 0.2624927, 0.2590154, 0.25603956, 0.25381643, 0.25248832, 0.252066, 0.25242555, 0.25332496, 0.2544383, 0.25540298, 0.25587273, 0.25556916, 0.25432405, 0.2521058, 0.2490266, 0.2453283, 0.24134925, 0.23747614, 0.23408824, 0.23150137, 0.2299202, 0.22940566, 0.22986281, 0.23105094, 0.23261516, 0.23413533, 0.23518574, 0.23539704, 0.23451173, 0.23242518, 0.22920565, 0.22509013, 0.2204558, 0.21577041, 0.21152797, 0.20817828, 0.20606014, 0.20534775, 0.20601806, 0.2078449, 0.21042128, 0.21320842, 0.21560559, 0.2170324, 0.21701266, 0.21524866, 0.21167526, 0.20648536, 0.20012195, 0.19323544, 0.18661009, 0.18106677, 0.17735304, 0.1760335, 0.17739365, 0.18137018, 0.1875171, 0.1950142, 0.20271891, 0.20925838, 0.213153, 0.21295986, 0.20742068, 0.19559912, 0.176992, 0.15160204, 0.11996354, 0.0831169, 0.04253385, 8.2803204e-16, -0.04253385, -0.0831169, -0.11996354, -0.15160204, -0.176992, -0.19559912, -0.20742068, -0.21295986, -0.213153, -0.20925838, -0.20271891, -0.1950142, -0.1875171, -0.18137018, -0.17739365, -0.1760335, -0.17735304, -0.18106677, -0.18661009, -0.19323544, -0.20012195, -0.20648536, -0.21167526, -0.21524866, -0.21701266, -0.2170324, -0.21560559, -0.21320842, -0.21042128, -0.2078449, -0.20601806, -0.20534775, -0.20606014, -0.20817828, -0.21152797, -0.21577041, -0.2204558, -0.22509013, -0.22920565, -0.23242518, -0.23451173, -0.23539704, -0.23518574, -0.23413533, -0.23261516, -0.23105094, -0.22986281, -0.22940566, -0.2299202, -0.23150137, -0.23408824, -0.23747614, -0.24134925, -0.2453283, -0.2490266, -0.2521058, -0.25432405, -0.25556916, -0.25587273, -0.25540298, -0.2544383, -0.25332496, -0.25242555, -0.252066, -0.25248832, -0.25381643, -0.25603956, -0.2590154, -0.2624927, -0.26614925, -0.26964024, -0.27264917, -0.2749341, -0.2763628, -0.27693093, -0.27676174, -0.2760865, -0.27520958, -0.27446243, -0.2741546, -0.27452767, -0.27572015, -0.27774745, -0.28050056, -0.28376305, -0.287244, -0.29062176, -0.29359215, -0.2959138, -0.29744357, -0.29815772, -0.29815465, -0.29763964, -0.2968937, -0.296231, -0.29595187, -0.29629764, -0.29741484, -0.2993335, -0.30196315, -0.3051067, -0.3084905, -0.31180575, -0.31475496, -0.31709674, -0.3186819, -0.31947574, -0.31956306, -0.31913486, -0.31845957, -0.3178422, -0.31757838, -0.3179097, -0.31898725, -0.32084915, -0.32341477, -0.32649717, -0.3298315, -0.33311525, -0.33605385, -0.33840552, -0.34001753, -0.34084955, -0.34097943, -0.34059143, -0.33994824, -0.33935106, -0.33909395, -0.33941913, -0.3404804, -0.3423196, -0.3448605, -0.34792015, -0.3512369, -0.35450995, -0.35744515, -0.3597997, -0.36141917, -0.36226076, -0.3624002, -0.36202008, -0.36138213, -0.3607875, -0.360531, -0.36085603, -0.36191756, -0.36375844, -0.36630285, -0.36936754, -0.37268978, -0.37596732, -0.37890434, -0.38125673, -0.38286915, -0.38369915, -0.38382354, -0.38342705, -0.38277408, -0.38216874, -0.38190824, -0.38223758, -0.3833121, -0.3851735, -0.38774326, -0.39083454, -0.39418033, -0.39747426, -0.40041724, -0.40276334, -0.40435734, -0.4051588, -0.4052484, -0.40481597, -0.40413165, -0.40350476, -0.40323648, -0.40357387, -0.40467188, -0.40656927, -0.40918258, -0.41231838, -0.41570282, -0.41902316, -0.42197558, -0.42431197, -0.42587778, -0.4266362, -0.42667404, -0.42618892, -0.42545906, -0.42480117, -0.42452183, -0.42487055, -0.42600125, -0.42794836, -0.4306213, -0.43381768, -0.43725428, -0.44060996, -0.443575, -0.44589853, -0.44742727, -0.44812948, -0.44810006, -0.44754672, -0.44675827, -0.44606072, -0.44576728, -0.44613037, -0.44730237, -0.44931212, -0.45205984, -0.45533195, -0.45883352, -0.46223313, -0.46521378, -0.46752146, -0.46900463, -0.4696378, -0.46952608, -0.4688895, -0.46802968, -0.4672839, -0.4669734, -0.4673538, -0.4685757, -0.4706609, -0.47349852, -0.47686154, -0.48044094, -0.48389313, -0.48689237, -0.48918116, -0.4906101, -0.49116126, -0.4909518, -0.49021652, -0.4892722, -0.48846945, -0.48813874, -0.4885396, -0.48982027, -0.49199426, -0.49493766, -0.49840745, -0.50207824, -0.50559205, -0.50861317, -0.5108799, -0.5122453, -0.51270056, -0.51237696, -0.5115264, -0.5104835, -0.5096142, -0.50925994, -0.5096847, -0.5110338, -0.51331115, -0.51637757, -0.51997155, -0.5237486, -0.52733403, -0.5303804, -0.5326215, -0.5339132, -0.5342572, -0.53380114, -0.53281695, -0.53165966, -0.5307133, -0.53033173, -0.5307841, -0.5322125, -0.5346096, -0.5378186, -0.5415564, -0.5454565, -0.54912496, -0.55220073, -0.55441207, -0.5556184, -0.55583346, -0.55522394, -0.5540849, -0.5527952, -0.5517595, -0.5513463, -0.55183065, -0.553351, -0.555887, -0.55926126, -0.5631659, -0.5672087, -0.5709734, -0.57408327, -0.57626003, -0.5773673, -0.5774327, -0.5766449, -0.57532597, -0.5738822, -0.5727427, -0.57229275, -0.57281435, -0.5744417, -0.5771393, -0.58070606, -0.5848051, -0.58901423, -0.59289116, -0.5960408, -0.5981773, -0.59916884, -0.59905934, -0.5980633, -0.59653395, -0.59491014, -0.5936491, -0.5931561, -0.59372133, -0.59547406, -0.59836125, -0.60215396, -0.6064812, -0.6108858, -0.6148946, -0.61809105, -0.62018013, -0.6210354, -0.6207198, -0.6194781, -0.61770046, -0.6158639, -0.6144592, -0.6139153, -0.61453223, -0.61643356, -0.61954546, -0.62360615, -0.6282043, -0.63284117, -0.6370069, -0.6402589, -0.6422915, -0.6429843, -0.6424228, -0.6408877, -0.6388133, -0.6367223, -0.63514566, -0.63454074, -0.63521963, -0.63729936, -0.6406814, -0.64506453, -0.649989, -0.65490586, -0.65926087, -0.66258013, -0.6645444, -0.66504043, -0.6641809, -0.6622898, -0.65985495, -0.65745443, -0.6556684, -0.65498906, -0.65574366, -0.6580414, -0.66175395, -0.6665321, -0.6718569, -0.6771176, -0.6817054, -0.68510747, -0.68698746, -0.6872405, -0.6860124, -0.6836805, -0.6807986, -0.67801374, -0.6759673, -0.67519534, -0.67604434, -0.67861456, -0.68274075, -0.68801403, -0.69384134, -0.6995345, -0.70441556, -0.707922, -0.7096954, -0.7096408, -0.7079451, -0.705053, -0.7016019, -0.6983269, -0.69594765, -0.6950571, -0.69602734, -0.6989478, -0.7036069, -0.7095194, -0.71599674, -0.72225046, -0.7275125, -0.73115474, -0.7327891, -0.732332, -0.7300232, -0.7263948, -0.722194, -0.71827155, -0.7154518, -0.7144039, -0.7155355, -0.71892345, -0.7242956, -0.7310657, -0.7384176, -0.74542767, -0.75120515, -0.75503176, -0.7564766, -0.75546974, -0.752321, -0.74768066, -0.74244595, -0.73762786, -0.7341966, -0.7329297, -0.7342871, -0.73833114, -0.74470776, -0.75269055, -0.7612841, -0.76937205, -0.7758871, -0.77997833, -0.7811495, -0.7793455, -0.7749735, -0.7688526, -0.76209825, -0.75595737, -0.75161856, -0.75002515, -0.7517214, -0.7567552, -0.76465505, -0.7744871, -0.7849848, -0.79473567, -0.8023954, -0.80689985, -0.80764014, -0.80457366, -0.7982512, -0.7897531, -0.7805409, -0.7722446, -0.76641566, -0.7642823, -0.76654524, -0.77324665, -0.7837358, -0.7967401, -0.81053555, -0.8231945, -0.83287627, -0.8381174, -0.8380779, -0.83270293, -0.8227718, -0.8098205, -0.79594404, -0.783502, -0.774767, -0.77156657, -0.7749729, -0.7850888, -0.8009691, -0.8206976, -0.841616, -0.86068225, -0.8749119, -0.8818431, -0.879957, -0.8689905, -0.85008526, -0.8257408, -0.7995625, -0.7758242, -0.758892, -0.752579, -0.75951135, -0.7805945, -0.81465507, -0.8583194, -0.9061614, -0.95111793, -0.98513794, -1.0, -0.9882084, -0.94386536, -0.863415, -0.74616826, -0.59453917, -0.4139579, -0.21246108, ], [0.0, 0.21161705, 0.4123894, 0.59246963, 0.74389565, 0.86127347, 0.9421827, 0.987266, 1.0, 0.9861831, 0.9532059, 0.90919375, 0.8621248, 0.8190212, 0.78530514, 0.7643817, 0.75748545, 0.7637908, 0.7807565, 0.80464673, 0.83115196, 0.8560262, 0.87565887, 0.8875138, 0.89038706, 0.8844643, 0.87118393, 0.85293734, 0.8326594, 0.8133711, 0.7977415, 0.78772753, 0.7843383, 0.7875483, 0.7963636, 0.8090235, 0.8233006, 0.8368518, 0.8475671, 0.8538667, 0.8549056, 0.8506613, 0.8418968, 0.8300111, 0.8168031, 0.80418766, 0.79390687, 0.78727984, 0.78502464, 0.7871767, 0.7931114, 0.8016629, 0.811319, 0.82045937, 0.82760113, 0.83161545, 0.8318826, 0.8283671, 0.82160264, 0.81259257, 0.8026444, 0.79316384, 0.78544104, 0.78046095, 0.77876526, 0.7803845, 0.78484905, 0.79127485, 0.7985077, 0.8053052, 0.8105255, 0.81329644, 0.8131394, 0.81003046, 0.8043919, 0.79701686, 0.78894025, 0.78127646, 0.7750495, 0.7710409, 0.76967776, 0.7709768, 0.7745518, 0.7796815, 0.7854261, 0.79077435, 0.7947977, 0.79678917, 0.7963646, 0.7935142, 0.7885958, 0.78227264, 0.775407, 0.7689254, 0.7636772, 0.76030755, 0.7591641, 0.7602501, 0.7632307, 0.76749027, 0.7722311, 0.77659744, 0.7798063, 0.78126496, 0.7806569, 0.77798486, 0.773565, 0.7679728, 0.761952, 0.7562981, 0.75173765, 0.74881864, 0.7478306, 0.74876523, 0.75132215, 0.75495964, 0.7589804, 0.76264024, 0.7652613, 0.76633435, 0.76559305, 0.76305103, 0.7589952, 0.7539383, 0.7485377, 0.7434932, 0.73944026, 0.73685455, 0.73598176, 0.73680377, 0.7390449, 0.7422176, 0.7456993, 0.74882895, 0.751008, 0.75179034, 0.75094855, 0.7485049, 0.74472517, 0.74007505, 0.73514664, 0.73056686, 0.7269018, 0.7245713, 0.7237869, 0.72452235, 0.7265204, 0.7293348, 0.7324001, 0.73511976, 0.7369563, 0.73751324, 0.7365933, 0.7342262, 0.7306617, 0.7263291, 0.7217697, 0.7175537, 0.71419257, 0.7120623, 0.71134734, 0.7120147, 0.7138213, 0.71635306, 0.7190897, 0.7214852, 0.7230508, 0.72342914, 0.7224472, 0.72014093, 0.71674746, 0.71266735, 0.70840186, 0.70447576, 0.7013571, 0.6993867, 0.6987272, 0.69934005, 0.7009934, 0.70329875, 0.7057719, 0.7079073, 0.70925534, 0.7094902, 0.7084585, 0.70620114, 0.70294535, 0.6990687, 0.6950401, 0.6913478, 0.6884248, 0.68658346, 0.68596876, 0.6865376, 0.688067, 0.69018924, 0.6924491, 0.69437397, 0.6955458, 0.6956644, 0.69459236, 0.69237465, 0.68923056, 0.68551886, 0.68168247, 0.67817986, 0.67541564, 0.67367905, 0.6731007, 0.6736338, 0.6750626, 0.67703617, 0.6791229, 0.68087685, 0.6819056, 0.6819299, 0.68082505, 0.67863953, 0.67558604, 0.6720082, 0.6683276, 0.66497886, 0.6623434, 0.6606917, 0.6601428, 0.660647, 0.66199434, 0.66384757, 0.6657943, 0.66741014, 0.66832334, 0.6682714, 0.6671402, 0.6649806, 0.66200006, 0.65853006, 0.6549748, 0.6517496, 0.6492175, 0.6476339, 0.6471086, 0.6475896, 0.64887184, 0.65062904, 0.65246415, 0.6539699, 0.65479124, 0.65467876, 0.65352654, 0.6513875, 0.6484647, 0.6450797, 0.64162326, 0.6384953, 0.6360443, 0.6345141, 0.6340073, 0.6344702, 0.6357018, 0.63738436, 0.6391329, 0.64055365, 0.64130414, 0.6411451, 0.63997686, 0.6378535, 0.6349746, 0.6316542, 0.6282724, 0.6252178, 0.6228277, 0.6213374, 0.62084436, 0.6212939, 0.6224881, 0.6241159, 0.6258012, 0.6271601, 0.6278593, 0.6276669, 0.6264871, 0.62437487, 0.62152696, 0.61825174, 0.6149219, 0.61191785, 0.6095695, 0.6081062, 0.60762244, 0.6080631, 0.60923266, 0.61082476, 0.6124694, 0.61378896, 0.61445606, 0.6142429, 0.61305606, 0.6109505, 0.60812086, 0.6048717, 0.6015713, 0.5985952, 0.5962693, 0.5948203, 0.5943413, 0.5947776, 0.5959354, 0.5975112, 0.59913796, 0.6004412, 0.6010956, 0.60087484, 0.5996856, 0.59758204, 0.5947574, 0.5915145, 0.58822, 0.58524823, 0.5829248, 0.5814765, 0.58099747, 0.58143425, 0.58259404, 0.5841741, 0.58580756, 0.587119, 0.5877817, 0.5875674, 0.5863808, 0.58427423, 0.58144015, 0.5781818, 0.57486737, 0.571874, 0.5695308, 0.5680684, 0.56758404, 0.56802654, 0.5692037, 0.57081115, 0.5724788, 0.57382625, 0.57452106, 0.57432926, 0.5731509, 0.57103556, 0.56817555, 0.5648768, 0.56151265, 0.55846757, 0.55607873, 0.55458474, 0.5540889, 0.55454344, 0.555756, 0.5574183, 0.5591527, 0.5605692, 0.5613246, 0.56117445, 0.5600111, 0.5578801, 0.5549742, 0.5516046, 0.5481545, 0.54502094, 0.5425549, 0.5410078, 0.54049295, 0.5409673, 0.54223764, 0.5439888, 0.5458309, 0.547358, 0.5482095, 0.5481253, 0.5469856, 0.5448301, 0.5418528, 0.53837365, 0.534791, 0.53152156, 0.52893746, 0.52730966, 0.5267657, 0.5272701, 0.5286276, 0.5305124, 0.53251594, 0.53420836, 0.53520346, 0.5352174, 0.534113, 0.5319211, 0.52883804, 0.5251969, 0.5214187, 0.51794904, 0.5151912, 0.51344454, 0.51285803, 0.51340634, 0.51489174, 0.51697236, 0.51921237, 0.5211469, 0.52235204, 0.5225097, 0.5214568, 0.5192117, 0.5159739, 0.5120958, 0.5080313, 0.5042685, 0.5012562, 0.49933544, 0.49868646, 0.4992993, 0.5009725, 0.503341, 0.505929, 0.5082201, 0.5097351, 0.5101049, 0.50912815, 0.5068043, 0.503337, 0.49910682, 0.49461633, 0.49041644, 0.48702395, 0.48484254, 0.4840999, 0.4848098, 0.48676607, 0.48956954, 0.49268457, 0.49551722, 0.49750403, 0.49819776, 0.49733764, 0.49489266, 0.49107158, 0.48629662, 0.48114476, 0.47626394, 0.4722769, 0.4696864, 0.46879613, 0.4696598, 0.47206628, 0.4755646, 0.47952545, 0.48323137, 0.48598278, 0.48720422, 0.48653495, 0.48389024, 0.47948268, 0.47379956, 0.4675382, 0.46150765, 0.45651013, 0.45321956, 0.45207512, 0.4532063, 0.45640144, 0.4611266, 0.46659347, 0.47186795, 0.4760052, 0.47819194, 0.47787493, 0.4748565, 0.46934086, 0.46192273, 0.45351693, 0.4452368, 0.43823668, 0.43354025, 0.43187898, 0.43356505, 0.43841925, 0.44576746, 0.45451015, 0.46325904, 0.47052547, 0.4749359, 0.47544605, 0.47152248, 0.46326336, 0.45143765, 0.43743065, 0.42309672, 0.4105325, 0.40179497, 0.39859802, 0.40202567, 0.41230056, 0.42864102, 0.44923124, 0.47131562, 0.49141386, 0.5056387, 0.51008403, 0.50124204, 0.4764027, 0.43398955, 0.37379065, 0.29705626, 0.20644782, 0.10584092, 2.2367163e-15, -0.10584092, -0.20644782, -0.29705626, -0.37379065, -0.43398955, -0.4764027, -0.50124204, -0.51008403, -0.5056387, -0.49141386, -0.47131562, -0.44923124, -0.42864102, -0.41230056, -0.40202567, -0.39859802, -0.40179497, -0.4105325, -0.42309672, -0.43743065, -0.45143765, -0.46326336, -0.47152248, -0.47544605, -0.4749359, -0.47052547, -0.46325904, -0.45451015, -0.44576746, -0.43841925, -0.43356505, -0.43187898, -0.43354025, -0.43823668, -0.4452368, -0.45351693, -0.46192273, -0.46934086, -0.4748565, -0.47787493, -0.47819194, -0.4760052, -0.47186795, -0.46659347, -0.4611266, -0.45640144, -0.4532063, -0.45207512, -0.45321956, -0.45651013, -0.46150765, -0.4675382, -0.47379956, -0.47948268, -0.48389024, -0.48653495, -0.48720422, -0.48598278, -0.48323137, -0.47952545, -0.4755646, -0.47206628, -0.4696598, -0.46879613, -0.4696864, -0.4722769, -0.47626394, -0.48114476, -0.48629662, -0.49107158, -0.49489266, -0.49733764, -0.49819776, -0.49750403, -0.49551722, -0.49268457, -0.48956954, -0.48676607, -0.4848098, -0.4840999, -0.48484254, -0.48702395, -0.49041644, -0.49461633, -0.49910682, -0.503337, -0.5068043, -0.50912815, -0.5101049, -0.5097351, -0.5082201, -0.505929, -0.503341, -0.5009725, -0.4992993, -0.49868646, -0.49933544, -0.5012562, -0.5042685, -0.5080313, -0.5120958, -0.5159739, -0.5192117, -0.5214568, -0.5225097, -0.52235204, -0.5211469, -0.51921237, -0.51697236, -0.51489174, -0.51340634, -0.51285803, -0.51344454, -0.5151912, -0.51794904, -0.5214187, -0.5251969, -0.52883804, -0.5319211, -0.534113, -0.5352174, -0.53520346, -0.53420836, -0.53251594, -0.5305124, -0.5286276, -0.5272701, -0.5267657, -0.52730966, -0.52893746, -0.53152156, -0.534791, -0.53837365, -0.5418528, -0.5448301, -0.5469856, -0.5481253, -0.5482095, -0.547358, -0.5458309, -0.5439888, -0.54223764, -0.5409673, -0.54049295, -0.5410078, -0.5425549, -0.54502094, -0.5481545, -0.5516046, -0.5549742, -0.5578801, -0.5600111, -0.56117445, -0.5613246, -0.5605692, -0.5591527, -0.5574183, -0.555756, -0.55454344, -0.5540889, -0.55458474, -0.55607873, -0.55846757, -0.56151265, -0.5648768, -0.56817555, -0.57103556, -0.5731509, -0.57432926, -0.57452106, -0.57382625, -0.5724788, -0.57081115, -0.5692037, -0.56802654, -0.56758404, -0.5680684, -0.5695308, -0.571874, -0.57486737, -0.5781818, -0.58144015, -0.58427423, -0.5863808, -0.5875674, -0.5877817, -0.587119, -0.58580756, -0.5841741, -0.58259404, -0.58143425, -0.58099747, -0.5814765, -0.5829248, -0.58524823, -0.58822, -0.5915145, -0.5947574, -0.59758204, -0.5996856, -0.60087484, -0.6010956, -0.6004412, -0.59913796, -0.5975112, -0.5959354, -0.5947776, -0.5943413, -0.5948203, -0.5962693, -0.5985952, -0.6015713, -0.6048717, -0.60812086, -0.6109505, -0.61305606, -0.6142429, -0.61445606, -0.61378896, -0.6124694, -0.61082476, -0.60923266, -0.6080631, -0.60762244, -0.6081062, -0.6095695, -0.61191785, -0.6149219, -0.61825174, -0.62152696, -0.62437487, -0.6264871, -0.6276669, -0.6278593, -0.6271601, -0.6258012, -0.6241159, -0.6224881, -0.6212939, -0.62084436, -0.6213374, -0.6228277, -0.6252178, -0.6282724, -0.6316542, -0.6349746, -0.6378535, -0.63997686, -0.6411451, -0.64130414, -0.64055365, -0.6391329, -0.63738436, -0.6357018, -0.6344702, -0.6340073, -0.6345141, -0.6360443, -0.6384953, -0.64162326, -0.6450797, -0.6484647, -0.6513875, -0.65352654, -0.65467876, -0.65479124, -0.6539699, -0.65246415, -0.65062904, -0.64887184, -0.6475896, -0.6471086, -0.6476339, -0.6492175, -0.6517496, -0.6549748, -0.65853006, -0.66200006, -0.6649806, -0.6671402, -0.6682714, -0.66832334, -0.66741014, -0.6657943, -0.66384757, -0.66199434, -0.660647, -0.6601428, -0.6606917, -0.6623434, -0.66497886, -0.6683276, -0.6720082, -0.67558604, -0.67863953, -0.68082505, -0.6819299, -0.6819056, -0.68087685, -0.6791229, -0.67703617, -0.6750626, -0.6736338, -0.6731007, -0.67367905, -0.67541564, -0.67817986, -0.68168247, -0.68551886, -0.68923056, -0.69237465, -0.69459236, -0.6956644, -0.6955458, -0.69437397, -0.6924491, -0.69018924, -0.688067, -0.6865376, -0.68596876, -0.68658346, -0.6884248, -0.6913478, -0.6950401, -0.6990687, -0.70294535, -0.70620114, -0.7084585, -0.7094902, -0.70925534, -0.7079073, -0.7057719, -0.70329875, -0.7009934, -0.69934005, -0.6987272, -0.6993867, -0.7013571, -0.70447576, -0.70840186, -0.71266735, -0.71674746, -0.72014093, -0.7224472, -0.72342914, -0.7230508, -0.7214852, -0.7190897, -0.71635306, -0.7138213, -0.7120147, -0.71134734, -0.7120623, -0.71419257, -0.7175537, -0.7217697, -0.7263291, -0.7306617, -0.7342262, -0.7365933, -0.73751324, -0.7369563, -0.73511976, -0.7324001, -0.7293348, -0.7265204, -0.72452235, -0.7237869, -0.7245713, -0.7269018, -0.73056686, -0.73514664, -0.74007505, -0.74472517, -0.7485049, -0.75094855, -0.75179034, -0.751008, -0.74882895, -0.7456993, -0.7422176, -0.7390449, -0.73680377, -0.73598176, -0.73685455, -0.73944026, -0.7434932, -0.7485377, -0.7539383, -0.7589952, -0.76305103, -0.76559305, -0.76633435, -0.7652613, -0.76264024, -0.7589804, -0.75495964, -0.75132215, -0.74876523, -0.7478306, -0.74881864, -0.75173765, -0.7562981, -0.761952, -0.7679728, -0.773565, -0.77798486, -0.7806569, -0.78126496, -0.7798063, -0.77659744, -0.7722311, -0.76749027, -0.7632307, -0.7602501, -0.7591641, -0.76030755, -0.7636772, -0.7689254, -0.775407, -0.78227264, -0.7885958, -0.7935142, -0.7963646, -0.79678917, -0.7947977, -0.79077435, -0.7854261, -0.7796815, -0.7745518, -0.7709768, -0.76967776, -0.7710409, -0.7750495, -0.78127646, -0.78894025, -0.79701686, -0.8043919, -0.81003046, -0.8131394, -0.81329644, -0.8105255, -0.8053052, -0.7985077, -0.79127485, -0.78484905, -0.7803845, -0.77876526, -0.78046095, -0.78544104, -0.79316384, -0.8026444, -0.81259257, -0.82160264, -0.8283671, -0.8318826, -0.83161545, -0.82760113, -0.82045937, -0.811319, -0.8016629, -0.7931114, -0.7871767, -0.78502464, -0.78727984, -0.79390687, -0.80418766, -0.8168031, -0.8300111, -0.8418968, -0.8506613, -0.8549056, -0.8538667, -0.8475671, -0.8368518, -0.8233006, -0.8090235, -0.7963636, -0.7875483, -0.7843383, -0.78772753, -0.7977415, -0.8133711, -0.8326594, -0.85293734, -0.87118393, -0.8844643, -0.89038706, -0.8875138, -0.87565887, -0.8560262, -0.83115196, -0.80464673, -0.7807565, -0.7637908, -0.75748545, -0.7643817, -0.78530514, -0.8190212, -0.8621248, -0.90919375, -0.9532059, -0.9861831, -1.0, -0.987266, -0.9421827, -0.86127347, -0.74389565, -0.59246963, -0.4123894, -0.21161705, ], [0.0, 0.21022536, 0.40980315, 0.5890572, 0.74014837, 0.8577423, 0.93940824, 0.98571205, 1.0, 0.9879065, 0.9566486, 0.91419375, 0.8683994, 0.8262204, 0.7930722, 0.77241224, 0.7655756, 0.7718681, 0.7888892, 0.8130299, 0.8400742, 0.8658219, 0.88665414, 0.8999739, 0.9044749, 0.90021497, 0.8884998, 0.87160474, 0.8523827, 0.8338202, 0.81860393, 0.808758, 0.8053971, 0.8086229, 0.81757057, 0.8305898, 0.84552747, 0.86006767, 0.8720759, 0.87990016, 0.882587, 0.8799863, 0.8727342, 0.86212355, 0.8498842, 0.8379098, 0.82797265, 0.8214683, 0.8192258, 0.82140875, 0.82751787, 0.8364903, 0.8468782, 0.85707736, 0.86557025, 0.87114733, 0.8730756, 0.8711908, 0.8659024, 0.85811377, 0.8490718, 0.84017074, 0.83273995, 0.8278485, 0.82615364, 0.8278154, 0.8324874, 0.83938295, 0.8474048, 0.85531783, 0.8619368, 0.8663022, 0.8678173, 0.86632806, 0.86213475, 0.8559352, 0.8487109, 0.84157306, 0.835593, 0.8316429, 0.8302701, 0.8316221, 0.83543414, 0.8410776, 0.84766304, 0.85417837, 0.85964406, 0.86325884, 0.8645166, 0.8632759, 0.85977393, 0.8545834, 0.8485197, 0.8425137, 0.83746976, 0.8341303, 0.8329672, 0.83411616, 0.83736193, 0.8421775, 0.8478087, 0.8533917, 0.85808486, 0.8611949, 0.86227894, 0.86120677, 0.85817546, 0.8536742, 0.848406, 0.8431786, 0.83878076, 0.83586407, 0.8348467, 0.835854, 0.8387039, 0.84293896, 0.8478992, 0.8528248, 0.85697174, 0.859724, 0.8606846, 0.85973257, 0.8570375, 0.8530298, 0.84833264, 0.84366536, 0.8397333, 0.837122, 0.8362101, 0.83711463, 0.8396767, 0.84348893, 0.8479596, 0.8524046, 0.8561515, 0.85864127, 0.8595112, 0.8586477, 0.85620046, 0.8525572, 0.8482823, 0.8440296, 0.84044296, 0.83805835, 0.83722484, 0.8380528, 0.84040046, 0.84389716, 0.8480022, 0.8520879, 0.8555355, 0.8578287, 0.8586306, 0.8578335, 0.85557246, 0.8522031, 0.8482458, 0.8443053, 0.84097874, 0.8387651, 0.8379906, 0.83876085, 0.84094656, 0.8442051, 0.84803385, 0.85184795, 0.8550692, 0.8572137, 0.8579642, 0.8572173, 0.8550972, 0.8519351, 0.84821814, 0.8445139, 0.8413842, 0.8392998, 0.83857, 0.83929664, 0.8413599, 0.84443825, 0.8480583, 0.8516673, 0.8547177, 0.85674995, 0.8574617, 0.8567527, 0.8547386, 0.8517325, 0.84819627, 0.84466946, 0.8416875, 0.8397001, 0.8390038, 0.8396977, 0.84166956, 0.84461355, 0.848078, 0.8515344, 0.85445786, 0.8564069, 0.85709, 0.85640895, 0.85447305, 0.8515818, 0.84817827, 0.84478146, 0.8419075, 0.8399908, 0.8393188, 0.8399891, 0.8418948, 0.84474194, 0.84809464, 0.85144174, 0.85427463, 0.8561645, 0.85682726, 0.8561659, 0.85428494, 0.8514739, 0.8481628, 0.844856, 0.84205645, 0.8401882, 0.8395329, 0.84018713, 0.84204835, 0.84483075, 0.84810925, 0.85138434, 0.854158, 0.8560095, 0.8566591, 0.85601026, 0.854164, 0.851403, 0.8481488, 0.84489703, 0.8421422, 0.84030277, 0.8396572, 0.8403022, 0.8421383, 0.8448847, 0.8481227, 0.8513593, 0.854102, 0.85593396, 0.8565771, 0.85593426, 0.854104, 0.85136545, 0.84813565, 0.8449064, 0.84216905, 0.8403402, 0.83969796, 0.8403402, 0.84216905, 0.8449064, 0.84813565, 0.85136545, 0.854104, 0.85593426, 0.8565771, 0.85593396, 0.854102, 0.8513593, 0.8481227, 0.8448847, 0.8421383, 0.8403022, 0.8396572, 0.84030277, 0.8421422, 0.84489703, 0.8481488, 0.851403, 0.854164, 0.85601026, 0.8566591, 0.8560095, 0.854158, 0.85138434, 0.84810925, 0.84483075, 0.84204835, 0.84018713, 0.8395329, 0.8401882, 0.84205645, 0.844856, 0.8481628, 0.8514739, 0.85428494, 0.8561659, 0.85682726, 0.8561645, 0.85427463, 0.85144174, 0.84809464, 0.84474194, 0.8418948, 0.8399891, 0.8393188, 0.8399908, 0.8419075, 0.84478146, 0.84817827, 0.8515818, 0.85447305, 0.85640895, 0.85709, 0.8564069, 0.85445786, 0.8515344, 0.848078, 0.84461355, 0.84166956, 0.8396977, 0.8390038, 0.8397001, 0.8416875, 0.84466946, 0.84819627, 0.8517325, 0.8547386, 0.8567527, 0.8574617, 0.85674995, 0.8547177, 0.8516673, 0.8480583, 0.84443825, 0.8413599, 0.83929664, 0.83857, 0.8392998, 0.8413842, 0.8445139, 0.84821814, 0.8519351, 0.8550972, 0.8572173, 0.8579642, 0.8572137, 0.8550692, 0.85184795, 0.84803385, 0.8442051, 0.84094656, 0.83876085, 0.8379906, 0.8387651, 0.84097874, 0.8443053, 0.8482458, 0.8522031, 0.85557246, 0.8578335, 0.8586306, 0.8578287, 0.8555355, 0.8520879, 0.8480022, 0.84389716, 0.84040046, 0.8380528, 0.83722484, 0.83805835, 0.84044296, 0.8440296, 0.8482823, 0.8525572, 0.85620046, 0.8586477, 0.8595112, 0.85864127, 0.8561515, 0.8524046, 0.8479596, 0.84348893, 0.8396767, 0.83711463, 0.8362101, 0.837122, 0.8397333, 0.84366536, 0.84833264, 0.8530298, 0.8570375, 0.85973257, 0.8606846, 0.859724, 0.85697174, 0.8528248, 0.8478992, 0.84293896, 0.8387039, 0.835854, 0.8348467, 0.83586407, 0.83878076, 0.8431786, 0.848406, 0.8536742, 0.85817546, 0.86120677, 0.86227894, 0.8611949, 0.85808486, 0.8533917, 0.8478087, 0.8421775, 0.83736193, 0.83411616, 0.8329672, 0.8341303, 0.83746976, 0.8425137, 0.8485197, 0.8545834, 0.85977393, 0.8632759, 0.8645166, 0.86325884, 0.85964406, 0.85417837, 0.84766304, 0.8410776, 0.83543414, 0.8316221, 0.8302701, 0.8316429, 0.835593, 0.84157306, 0.8487109, 0.8559352, 0.86213475, 0.86632806, 0.8678173, 0.8663022, 0.8619368, 0.85531783, 0.8474048, 0.83938295, 0.8324874, 0.8278154, 0.82615364, 0.8278485, 0.83273995, 0.84017074, 0.8490718, 0.85811377, 0.8659024, 0.8711908, 0.8730756, 0.87114733, 0.86557025, 0.85707736, 0.8468782, 0.8364903, 0.82751787, 0.82140875, 0.8192258, 0.8214683, 0.82797265, 0.8379098, 0.8498842, 0.86212355, 0.8727342, 0.8799863, 0.882587, 0.87990016, 0.8720759, 0.86006767, 0.84552747, 0.8305898, 0.81757057, 0.8086229, 0.8053971, 0.808758, 0.81860393, 0.8338202, 0.8523827, 0.87160474, 0.8884998, 0.90021497, 0.9044749, 0.8999739, 0.88665414, 0.8658219, 0.8400742, 0.8130299, 0.7888892, 0.7718681, 0.7655756, 0.77241224, 0.7930722, 0.8262204, 0.8683994, 0.91419375, 0.9566486, 0.9879065, 1.0, 0.98571205, 0.93940824, 0.8577423, 0.74014837, 0.5890572, 0.40980315, 0.21022536, 4.559438e-15, -0.21022536, -0.40980315, -0.5890572, -0.74014837, -0.8577423, -0.93940824, -0.98571205, -1.0, -0.9879065, -0.9566486, -0.91419375, -0.8683994, -0.8262204, -0.7930722, -0.77241224, -0.7655756, -0.7718681, -0.7888892, -0.8130299, -0.8400742, -0.8658219, -0.88665414, -0.8999739, -0.9044749, -0.90021497, -0.8884998, -0.87160474, -0.8523827, -0.8338202, -0.81860393, -0.808758, -0.8053971, -0.8086229, -0.81757057, -0.8305898, -0.84552747, -0.86006767, -0.8720759, -0.87990016, -0.882587, -0.8799863, -0.8727342, -0.86212355, -0.8498842, -0.8379098, -0.82797265, -0.8214683, -0.8192258, -0.82140875, -0.82751787, -0.8364903, -0.8468782, -0.85707736, -0.86557025, -0.87114733, -0.8730756, -0.8711908, -0.8659024, -0.85811377, -0.8490718, -0.84017074, -0.83273995, -0.8278485, -0.82615364, -0.8278154, -0.8324874, -0.83938295, -0.8474048, -0.85531783, -0.8619368, -0.8663022, -0.8678173, -0.86632806, -0.86213475, -0.8559352, -0.8487109, -0.84157306, -0.835593, -0.8316429, -0.8302701, -0.8316221, -0.83543414, -0.8410776, -0.84766304, -0.85417837, -0.85964406, -0.86325884, -0.8645166, -0.8632759, -0.85977393, -0.8545834, -0.8485197, -0.8425137, -0.83746976, -0.8341303, -0.8329672, -0.83411616, -0.83736193, -0.8421775, -0.8478087, -0.8533917, -0.85808486, -0.8611949, -0.86227894, -0.86120677, -0.85817546, -0.8536742, -0.848406, -0.8431786, -0.83878076, -0.83586407, -0.8348467, -0.835854, -0.8387039, -0.84293896, -0.8478992, -0.8528248, -0.85697174, -0.859724, -0.8606846, -0.85973257, -0.8570375, -0.8530298, -0.84833264, -0.84366536, -0.8397333, -0.837122, -0.8362101, -0.83711463, -0.8396767, -0.84348893, -0.8479596, -0.8524046, -0.8561515, -0.85864127, -0.8595112, -0.8586477, -0.85620046, -0.8525572, -0.8482823, -0.8440296, -0.84044296, -0.83805835, -0.83722484, -0.8380528, -0.84040046, -0.84389716, -0.8480022, -0.8520879, -0.8555355, -0.8578287, -0.8586306, -0.8578335, -0.85557246, -0.8522031, -0.8482458, -0.8443053, -0.84097874, -0.8387651, -0.8379906, -0.83876085, -0.84094656, -0.8442051, -0.84803385, -0.85184795, -0.8550692, -0.8572137, -0.8579642, -0.8572173, -0.8550972, -0.8519351, -0.84821814, -0.8445139, -0.8413842, -0.8392998, -0.83857, -0.83929664, -0.8413599, -0.84443825, -0.8480583, -0.8516673, -0.8547177, -0.85674995, -0.8574617, -0.8567527, -0.8547386, -0.8517325, -0.84819627, -0.84466946, -0.8416875, -0.8397001, -0.8390038, -0.8396977, -0.84166956, -0.84461355, -0.848078, -0.8515344, -0.85445786, -0.8564069, -0.85709, -0.85640895, -0.85447305, -0.8515818, -0.84817827, -0.84478146, -0.8419075, -0.8399908, -0.8393188, -0.8399891, -0.8418948, -0.84474194, -0.84809464, -0.85144174, -0.85427463, -0.8561645, -0.85682726, -0.8561659, -0.85428494, -0.8514739, -0.8481628, -0.844856, -0.84205645, -0.8401882, -0.8395329, -0.84018713, -0.84204835, -0.84483075, -0.84810925, -0.85138434, -0.854158, -0.8560095, -0.8566591, -0.85601026, -0.854164, -0.851403, -0.8481488, -0.84489703, -0.8421422, -0.84030277, -0.8396572, -0.8403022, -0.8421383, -0.8448847, -0.8481227, -0.8513593, -0.854102, -0.85593396, -0.8565771, -0.85593426, -0.854104, -0.85136545, -0.84813565, -0.8449064, -0.84216905, -0.8403402, -0.83969796, -0.8403402, -0.84216905, -0.8449064, -0.84813565, -0.85136545, -0.854104, -0.85593426, -0.8565771, -0.85593396, -0.854102, -0.8513593, -0.8481227, -0.8448847, -0.8421383, -0.8403022, -0.8396572, -0.84030277, -0.8421422, -0.84489703, -0.8481488, -0.851403, -0.854164, -0.85601026, -0.8566591, -0.8560095, -0.854158, -0.85138434, -0.84810925, -0.84483075, -0.84204835, -0.84018713, -0.8395329, -0.8401882, -0.84205645, -0.844856, -0.8481628, -0.8514739, -0.85428494, -0.8561659, -0.85682726, -0.8561645, -0.85427463, -0.85144174, -0.84809464, -0.84474194, -0.8418948, -0.8399891, -0.8393188, -0.8399908, -0.8419075, -0.84478146, -0.84817827, -0.8515818, -0.85447305, -0.85640895, -0.85709, -0.8564069, -0.85445786, -0.8515344, -0.848078, -0.84461355, -0.84166956, -0.8396977, -0.8390038, -0.8397001, -0.8416875, -0.84466946, -0.84819627, -0.8517325, -0.8547386, -0.8567527, -0.8574617, -0.85674995, -0.8547177, -0.8516673, -0.8480583, -0.84443825, -0.8413599, -0.83929664, -0.83857, -0.8392998, -0.8413842, -0.8445139, -0.84821814, -0.8519351, -0.8550972, -0.8572173, -0.8579642, -0.8572137, -0.8550692, -0.85184795, -0.84803385, -0.8442051, -0.84094656, -0.83876085, -0.8379906, -0.8387651, -0.84097874, -0.8443053, -0.8482458, -0.8522031, -0.85557246, -0.8578335, -0.8586306, -0.8578287, -0.8555355, -0.8520879, -0.8480022, -0.84389716, -0.84040046, -0.8380528, -0.83722484, -0.83805835, -0.84044296, -0.8440296, -0.8482823, -0.8525572, -0.85620046, -0.8586477, -0.8595112, -0.85864127, -0.8561515, -0.8524046, -0.8479596, -0.84348893, -0.8396767, -0.83711463, -0.8362101, -0.837122, -0.8397333, -0.84366536, -0.84833264, -0.8530298, -0.8570375, -0.85973257, -0.8606846, -0.859724, -0.85697174, -0.8528248, -0.8478992, -0.84293896, -0.8387039, -0.835854, -0.8348467, -0.83586407, -0.83878076, -0.8431786, -0.848406, -0.8536742, -0.85817546, -0.86120677, -0.86227894, -0.8611949, -0.85808486, -0.8533917, -0.8478087, -0.8421775, -0.83736193, -0.83411616, -0.8329672, -0.8341303, -0.83746976, -0.8425137, -0.8485197, -0.8545834, -0.85977393, -0.8632759, -0.8645166, -0.86325884, -0.85964406, -0.85417837, -0.84766304, -0.8410776, -0.83543414, -0.8316221, -0.8302701, -0.8316429, -0.835593, -0.84157306, -0.8487109, -0.8559352, -0.86213475, -0.86632806, -0.8678173, -0.8663022, -0.8619368, -0.85531783, -0.8474048, -0.83938295, -0.8324874, -0.8278154, -0.82615364, -0.8278485, -0.83273995, -0.84017074, -0.8490718, -0.85811377, -0.8659024, -0.8711908, -0.8730756, -0.87114733, -0.86557025, -0.85707736, -0.8468782, -0.8364903, -0.82751787, -0.82140875, -0.8192258, -0.8214683, -0.82797265, -0.8379098, -0.8498842, -0.86212355, -0.8727342, -0.8799863, -0.882587, -0.87990016, -0.8720759, -0.86006767, -0.84552747, -0.8305898, -0.81757057, -0.8086229, -0.8053971, -0.808758, -0.81860393, -0.8338202, -0.8523827, -0.87160474, -0.8884998, -0.90021497, -0.9044749, -0.8999739, -0.88665414, -0.8658219, -0.8400742, -0.8130299, -0.7888892, -0.7718681, -0.7655756, -0.77241224, -0.7930722, -0.8262204, -0.8683994, -0.91419375, -0.9566486, -0.9879065, -1.0, -0.98571205, -0.93940824, -0.8577423, -0.74014837, -0.5890572, -0.40980315, -0.21022536, ], ];
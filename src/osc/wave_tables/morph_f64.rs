@@ -0,0 +1,2 @@
+#[allow(clippy::approx_constant)]
+pub static MORPH_F64: [[f64; 1024]; 7] = [[0.0, 0.006135884649154475, 0.012271538285719925, 0.01840672990580482, 0.024541228522912288, 0.030674803176636626, 0.03680722294135883, 0.04293825693494082, 0.049067674327418015, 0.055195244349689934, 0.06132073630220858, 0.06744391956366405, 0.07356456359966743, 0.07968243797143013, 0.0857973123444399, 0.09190895649713272, 0.0980171403295606, 0.10412163387205459, 0.11022220729388306, 0.11631863091190475, 0.1224106751992162, 0.12849811079379317, 0.13458070850712617, 0.1406582393328492, 0.14673047445536175, 0.15279718525844344, 0.15885814333386145, 0.16491312048996992, 0.17096188876030122, 0.17700422041214875, 0.18303988795514095, 0.1890686641498062, 0.19509032201612825, 0.2011046348420919, 0.20711137619221856, 0.21311031991609136, 0.2191012401568698, 0.22508391135979283, 0.2310581082806711, 0.2370236059943672, 0.24298017990326387, 0.24892760574572015, 0.25486565960451457, 0.2607941179152755, 0.26671275747489837, 0.272621355449949, 0.27851968938505306, 0.2844075372112719, 0.29028467725446233, 0.2961508882436238, 0.3020059493192281, 0.30784964004153487, 0.3136817403988915, 0.3195020308160157, 0.3253102921622629, 0.33110630575987643, 0.33688985339222005, 0.3426607173119944, 0.34841868024943456, 0.35416352542049034, 0.3598950365349881, 0.36561299780477385, 0.37131719395183754, 0.37700741021641826, 0.3826834323650898, 0.38834504669882625, 0.3939920400610481, 0.3996241998456468, 0.40524131400498986, 0.4108431710579039, 0.41642956009763715, 0.4220002707997997, 0.4275550934302821, 0.43309381885315196, 0.43861623853852766, 0.4441221445704292, 0.44961132965460654, 0.45508358712634384, 0.46053871095824, 0.4659764957679662, 0.47139673682599764, 0.4767992300633221, 0.4821837720791227, 0.487550160148436, 0.49289819222978404, 0.49822766697278187, 0.5035383837257176, 0.508830142543107, 0.5141027441932217, 0.5193559901655896, 0.524589682678469, 0.5298036246862946, 0.5349976198870972, 0.5401714727298929, 0.5453249884220465, 0.5504579729366048, 0.5555702330196022, 0.560661576197336, 0.5657318107836131, 0.5707807458869673, 0.5758081914178453, 0.5808139580957645, 0.5857978574564389, 0.5907597018588742, 0.5956993044924334, 0.600616479383869, 0.6055110414043255, 0.6103828062763095, 0.6152315905806268, 0.6200572117632891, 0.6248594881423863, 0.629638238914927, 0.6343932841636455, 0.6391244448637757, 0.6438315428897914, 0.6485144010221124, 0.6531728429537768, 0.6578066932970786, 0.6624157775901718, 0.6669999223036375, 0.6715589548470183, 0.6760927035753159, 0.680600997795453, 0.6850836677727004, 0.6895405447370668, 0.693971460889654, 0.6983762494089729, 0.7027547444572253, 0.7071067811865475, 0.7114321957452164, 0.7157308252838186, 0.7200025079613817, 0.7242470829514669, 0.7284643904482252, 0.7326542716724128, 0.7368165688773698, 0.7409511253549591, 0.745057785441466, 0.7491363945234593, 0.7531867990436124, 0.7572088465064845, 0.7612023854842618, 0.765167265622459, 0.7691033376455796, 0.773010453362737, 0.7768884656732324, 0.7807372285720944, 0.7845565971555752, 0.7883464276266062, 0.7921065773002124, 0.7958369046088835, 0.799537269107905, 0.8032075314806448, 0.8068475535437992, 0.8104571982525948, 0.8140363297059483, 0.8175848131515837, 0.8211025149911046, 0.8245893027850253, 0.8280450452577558, 0.8314696123025452, 0.83486287498638, 0.838224705554838, 0.8415549774368983, 0.844853565249707, 0.8481203448032971, 0.8513551931052652, 0.8545579883654005, 0.8577286100002721, 0.8608669386377673, 0.8639728561215867, 0.8670462455156926, 0.8700869911087113, 0.8730949784182901, 0.8760700941954066, 0.8790122264286334, 0.8819212643483549, 0.8847970984309378, 0.8876396204028539, 0.8904487232447579, 0.8932243011955153, 0.8959662497561851, 0.8986744656939538, 0.901348847046022, 0.9039892931234433, 0.9065957045149153, 0.9091679830905223, 0.9117060320054299, 0.9142097557035307, 0.9166790599210427, 0.9191138516900578, 0.9215140393420419, 0.9238795325112867, 0.9262102421383113, 0.9285060804732155, 0.9307669610789837, 0.9329927988347388, 0.9351835099389475, 0.937339011912575, 0.9394592236021899, 0.9415440651830208, 0.9435934581619604, 0.9456073253805213, 0.9475855910177411, 0.9495281805930367, 0.9514350209690083, 0.9533060403541938, 0.9551411683057707, 0.9569403357322089, 0.9587034748958716, 0.9604305194155658, 0.9621214042690416, 0.9637760657954398, 0.9653944416976894, 0.9669764710448521, 0.9685220942744173, 0.970031253194544, 0.9715038909862518, 0.9729399522055601, 0.9743393827855759, 0.9757021300385286, 0.9770281426577544, 0.9783173707196277, 0.9795697656854405, 0.9807852804032304, 0.9819638691095552, 0.9831054874312163, 0.984210092386929, 0.9852776423889412, 0.9863080972445987, 0.9873014181578584, 0.9882575677307495, 0.989176509964781, 0.9900582102622971, 0.99090263542778, 0.9917097536690995, 0.99247953459871, 0.9932119492347945, 0.9939069700023561, 0.9945645707342554, 0.9951847266721968, 0.9957674144676598, 0.996312612182778, 0.9968202992911657, 0.9972904566786902, 0.9977230666441916, 0.9981181129001492, 0.9984755805732948, 0.9987954562051724, 0.9990777277526454, 0.9993223845883495, 0.9995294175010931, 0.9996988186962042, 0.9998305817958234, 0.9999247018391445, 0.9999811752826011, 1.0, 0.9999811752826011, 0.9999247018391445, 0.9998305817958234, 0.9996988186962042, 0.9995294175010931, 0.9993223845883495, 0.9990777277526454, 0.9987954562051724, 0.9984755805732948, 0.9981181129001492, 0.9977230666441916, 0.9972904566786902, 0.9968202992911658, 0.996312612182778, 0.9957674144676598, 0.9951847266721969, 0.9945645707342554, 0.9939069700023561, 0.9932119492347945, 0.99247953459871, 0.9917097536690995, 0.99090263542778, 0.9900582102622971, 0.989176509964781, 0.9882575677307495, 0.9873014181578584, 0.9863080972445987, 0.9852776423889412, 0.984210092386929, 0.9831054874312163, 0.9819638691095552, 0.9807852804032304, 0.9795697656854405, 0.9783173707196277, 0.9770281426577544, 0.9757021300385286, 0.9743393827855759, 0.9729399522055602, 0.9715038909862518, 0.970031253194544, 0.9685220942744174, 0.9669764710448521, 0.9653944416976894, 0.9637760657954398, 0.9621214042690416, 0.9604305194155659, 0.9587034748958716, 0.9569403357322089, 0.9551411683057707, 0.9533060403541939, 0.9514350209690083, 0.9495281805930367, 0.9475855910177412, 0.9456073253805214, 0.9435934581619604, 0.9415440651830208, 0.9394592236021899, 0.937339011912575, 0.9351835099389476, 0.9329927988347388, 0.9307669610789837, 0.9285060804732156, 0.9262102421383114, 0.9238795325112867, 0.921514039342042, 0.9191138516900578, 0.9166790599210427, 0.9142097557035307, 0.9117060320054299, 0.9091679830905225, 0.9065957045149153, 0.9039892931234434, 0.901348847046022, 0.8986744656939539, 0.8959662497561852, 0.8932243011955152, 0.890448723244758, 0.8876396204028539, 0.8847970984309379, 0.881921264348355, 0.8790122264286335, 0.8760700941954066, 0.8730949784182902, 0.8700869911087115, 0.8670462455156928, 0.8639728561215868, 0.8608669386377672, 0.8577286100002721, 0.8545579883654005, 0.8513551931052652, 0.8481203448032972, 0.8448535652497072, 0.8415549774368984, 0.8382247055548382, 0.8348628749863801, 0.8314696123025455, 0.8280450452577558, 0.8245893027850252, 0.8211025149911048, 0.8175848131515837, 0.8140363297059485, 0.8104571982525948, 0.8068475535437994, 0.8032075314806449, 0.7995372691079052, 0.7958369046088836, 0.7921065773002123, 0.7883464276266063, 0.7845565971555751, 0.7807372285720946, 0.7768884656732324, 0.7730104533627371, 0.7691033376455796, 0.7651672656224591, 0.7612023854842619, 0.7572088465064847, 0.7531867990436125, 0.7491363945234593, 0.7450577854414661, 0.740951125354959, 0.73681656887737, 0.7326542716724128, 0.7284643904482253, 0.7242470829514669, 0.7200025079613818, 0.7157308252838187, 0.7114321957452167, 0.7071067811865476, 0.7027547444572252, 0.6983762494089729, 0.693971460889654, 0.689540544737067, 0.6850836677727004, 0.6806009977954532, 0.6760927035753159, 0.6715589548470186, 0.6669999223036376, 0.662415777590172, 0.6578066932970787, 0.6531728429537766, 0.6485144010221126, 0.6438315428897914, 0.6391244448637758, 0.6343932841636455, 0.6296382389149272, 0.6248594881423863, 0.6200572117632894, 0.6152315905806269, 0.6103828062763097, 0.6055110414043257, 0.6006164793838689, 0.5956993044924335, 0.5907597018588742, 0.585797857456439, 0.5808139580957645, 0.5758081914178454, 0.5707807458869673, 0.5657318107836135, 0.5606615761973361, 0.5555702330196022, 0.5504579729366049, 0.5453249884220464, 0.540171472729893, 0.5349976198870972, 0.5298036246862948, 0.524589682678469, 0.5193559901655898, 0.5141027441932218, 0.5088301425431073, 0.5035383837257177, 0.49822766697278176, 0.49289819222978415, 0.4875501601484359, 0.4821837720791229, 0.4767992300633221, 0.47139673682599786, 0.4659764957679662, 0.4605387109582402, 0.4550835871263439, 0.4496113296546069, 0.4441221445704293, 0.43861623853852755, 0.43309381885315207, 0.42755509343028203, 0.42200027079979985, 0.41642956009763715, 0.41084317105790413, 0.4052413140049899, 0.39962419984564707, 0.39399204006104815, 0.3883450466988266, 0.3826834323650899, 0.37700741021641815, 0.3713171939518377, 0.3656129978047738, 0.35989503653498833, 0.3541635254204904, 0.3484186802494348, 0.34266071731199443, 0.33688985339222033, 0.3311063057598765, 0.32531029216226326, 0.3195020308160158, 0.3136817403988914, 0.30784964004153503, 0.30200594931922803, 0.296150888243624, 0.2902846772544624, 0.2844075372112721, 0.27851968938505317, 0.27262135544994925, 0.2667127574748985, 0.26079411791527585, 0.2548656596045147, 0.2489276057457201, 0.24298017990326407, 0.23702360599436717, 0.23105810828067133, 0.22508391135979283, 0.21910124015687005, 0.21311031991609142, 0.20711137619221884, 0.201104634842092, 0.1950903220161286, 0.18906866414980636, 0.1830398879551409, 0.17700422041214894, 0.17096188876030122, 0.16491312048997014, 0.15885814333386147, 0.15279718525844369, 0.1467304744553618, 0.14065823933284954, 0.13458070850712628, 0.12849811079379309, 0.12241067519921635, 0.11631863091190471, 0.11022220729388324, 0.10412163387205457, 0.09801714032956083, 0.09190895649713275, 0.08579731234444016, 0.0796824379714302, 0.07356456359966773, 0.06744391956366418, 0.06132073630220849, 0.055195244349690094, 0.049067674327417966, 0.04293825693494102, 0.03680722294135883, 0.030674803176636865, 0.024541228522912326, 0.0184067299058051, 0.012271538285720007, 0.006135884649154799, 1.2246467991473532e-16, -0.006135884649154554, -0.012271538285719762, -0.01840672990580486, -0.02454122852291208, -0.03067480317663662, -0.03680722294135858, -0.04293825693494078, -0.049067674327417724, -0.05519524434968985, -0.061320736302208245, -0.06744391956366393, -0.0735645635996675, -0.07968243797142995, -0.08579731234443992, -0.09190895649713252, -0.09801714032956059, -0.10412163387205432, -0.110222207293883, -0.11631863091190447, -0.1224106751992161, -0.12849811079379284, -0.13458070850712606, -0.1406582393328493, -0.14673047445536158, -0.15279718525844344, -0.15885814333386122, -0.1649131204899699, -0.17096188876030097, -0.1770042204121487, -0.18303988795514065, -0.1890686641498061, -0.19509032201612836, -0.20110463484209176, -0.2071113761922186, -0.2131103199160912, -0.2191012401568698, -0.2250839113597926, -0.23105810828067108, -0.23702360599436695, -0.24298017990326382, -0.24892760574571987, -0.25486565960451446, -0.2607941179152756, -0.26671275747489825, -0.27262135544994903, -0.2785196893850529, -0.2844075372112718, -0.2902846772544621, -0.2961508882436238, -0.3020059493192278, -0.3078496400415348, -0.3136817403988912, -0.3195020308160156, -0.325310292162263, -0.33110630575987626, -0.3368898533922201, -0.3426607173119942, -0.34841868024943456, -0.3541635254204901, -0.3598950365349881, -0.3656129978047736, -0.37131719395183743, -0.3770074102164179, -0.38268343236508967, -0.38834504669882636, -0.39399204006104793, -0.39962419984564684, -0.4052413140049897, -0.4108431710579039, -0.41642956009763693, -0.4220002707997996, -0.4275550934302818, -0.43309381885315185, -0.4386162385385273, -0.4441221445704291, -0.44961132965460665, -0.45508358712634367, -0.46053871095824006, -0.46597649576796596, -0.47139673682599764, -0.47679923006332187, -0.48218377207912266, -0.48755016014843566, -0.4928981922297839, -0.49822766697278154, -0.5035383837257175, -0.5088301425431071, -0.5141027441932216, -0.5193559901655896, -0.5245896826784687, -0.5298036246862946, -0.5349976198870969, -0.5401714727298929, -0.5453249884220461, -0.5504579729366047, -0.555570233019602, -0.5606615761973359, -0.5657318107836132, -0.5707807458869671, -0.5758081914178453, -0.5808139580957643, -0.5857978574564389, -0.5907597018588739, -0.5956993044924332, -0.6006164793838686, -0.6055110414043254, -0.6103828062763095, -0.6152315905806267, -0.6200572117632892, -0.6248594881423862, -0.629638238914927, -0.6343932841636453, -0.6391244448637757, -0.6438315428897913, -0.6485144010221123, -0.6531728429537765, -0.6578066932970785, -0.6624157775901718, -0.6669999223036374, -0.6715589548470184, -0.6760927035753158, -0.680600997795453, -0.6850836677727001, -0.6895405447370668, -0.6939714608896538, -0.6983762494089728, -0.7027547444572251, -0.7071067811865475, -0.7114321957452164, -0.7157308252838185, -0.7200025079613817, -0.7242470829514668, -0.7284643904482252, -0.7326542716724126, -0.7368165688773698, -0.7409511253549589, -0.7450577854414658, -0.749136394523459, -0.7531867990436124, -0.7572088465064842, -0.761202385484262, -0.765167265622459, -0.7691033376455795, -0.7730104533627367, -0.7768884656732326, -0.7807372285720944, -0.784556597155575, -0.7883464276266059, -0.7921065773002124, -0.7958369046088835, -0.7995372691079048, -0.803207531480645, -0.8068475535437992, -0.8104571982525947, -0.8140363297059481, -0.8175848131515838, -0.8211025149911046, -0.8245893027850251, -0.8280450452577555, -0.8314696123025452, -0.83486287498638, -0.8382247055548379, -0.8415549774368986, -0.844853565249707, -0.8481203448032971, -0.8513551931052649, -0.8545579883654006, -0.857728610000272, -0.8608669386377671, -0.8639728561215865, -0.8670462455156926, -0.8700869911087113, -0.8730949784182899, -0.8760700941954067, -0.8790122264286334, -0.8819212643483549, -0.8847970984309376, -0.887639620402854, -0.8904487232447579, -0.8932243011955152, -0.8959662497561849, -0.8986744656939538, -0.9013488470460219, -0.9039892931234431, -0.9065957045149154, -0.9091679830905224, -0.9117060320054298, -0.9142097557035305, -0.9166790599210427, -0.9191138516900577, -0.9215140393420418, -0.9238795325112865, -0.9262102421383114, -0.9285060804732155, -0.9307669610789836, -0.932992798834739, -0.9351835099389476, -0.9373390119125748, -0.9394592236021897, -0.9415440651830208, -0.9435934581619603, -0.9456073253805212, -0.9475855910177412, -0.9495281805930367, -0.9514350209690083, -0.9533060403541938, -0.9551411683057708, -0.9569403357322088, -0.9587034748958715, -0.9604305194155657, -0.9621214042690416, -0.9637760657954398, -0.9653944416976893, -0.9669764710448522, -0.9685220942744173, -0.970031253194544, -0.9715038909862517, -0.9729399522055602, -0.9743393827855759, -0.9757021300385285, -0.9770281426577543, -0.9783173707196277, -0.9795697656854405, -0.9807852804032303, -0.9819638691095554, -0.9831054874312163, -0.984210092386929, -0.9852776423889411, -0.9863080972445987, -0.9873014181578583, -0.9882575677307495, -0.9891765099647809, -0.9900582102622971, -0.99090263542778, -0.9917097536690995, -0.9924795345987101, -0.9932119492347945, -0.9939069700023561, -0.9945645707342554, -0.9951847266721969, -0.9957674144676598, -0.996312612182778, -0.9968202992911657, -0.9972904566786902, -0.9977230666441916, -0.9981181129001492, -0.9984755805732948, -0.9987954562051724, -0.9990777277526454, -0.9993223845883494, -0.9995294175010931, -0.9996988186962042, -0.9998305817958234, -0.9999247018391445, -0.9999811752826011, -1.0, -0.9999811752826011, -0.9999247018391445, -0.9998305817958234, -0.9996988186962042, -0.9995294175010931, -0.9993223845883495, -0.9990777277526454, -0.9987954562051724, -0.9984755805732948, -0.9981181129001492, -0.9977230666441916, -0.9972904566786902, -0.9968202992911657, -0.996312612182778, -0.9957674144676598, -0.9951847266721969, -0.9945645707342554, -0.9939069700023561, -0.9932119492347946, -0.9924795345987101, -0.9917097536690995, -0.99090263542778, -0.9900582102622971, -0.9891765099647809, -0.9882575677307495, -0.9873014181578584, -0.9863080972445988, -0.9852776423889412, -0.9842100923869291, -0.9831054874312164, -0.9819638691095554, -0.9807852804032304, -0.9795697656854406, -0.9783173707196278, -0.9770281426577543, -0.9757021300385286, -0.974339382785576, -0.9729399522055603, -0.9715038909862518, -0.970031253194544, -0.9685220942744174, -0.9669764710448523, -0.9653944416976894, -0.96377606579544, -0.9621214042690417, -0.9604305194155658, -0.9587034748958716, -0.9569403357322089, -0.9551411683057709, -0.9533060403541939, -0.9514350209690084, -0.9495281805930368, -0.9475855910177413, -0.9456073253805213, -0.9435934581619604, -0.9415440651830209, -0.9394592236021898, -0.937339011912575, -0.9351835099389477, -0.9329927988347391, -0.9307669610789837, -0.9285060804732156, -0.9262102421383115, -0.9238795325112866, -0.9215140393420419, -0.9191138516900579, -0.9166790599210428, -0.9142097557035306, -0.9117060320054299, -0.9091679830905225, -0.9065957045149156, -0.9039892931234433, -0.9013488470460221, -0.898674465693954, -0.895966249756185, -0.8932243011955153, -0.890448723244758, -0.8876396204028542, -0.8847970984309377, -0.881921264348355, -0.8790122264286336, -0.8760700941954069, -0.8730949784182901, -0.8700869911087115, -0.8670462455156929, -0.8639728561215866, -0.8608669386377673, -0.8577286100002722, -0.8545579883654008, -0.8513551931052651, -0.8481203448032973, -0.8448535652497072, -0.8415549774368988, -0.838224705554838, -0.8348628749863801, -0.8314696123025455, -0.8280450452577557, -0.8245893027850253, -0.8211025149911049, -0.817584813151584, -0.8140363297059483, -0.8104571982525949, -0.8068475535437994, -0.8032075314806453, -0.799537269107905, -0.7958369046088837, -0.7921065773002126, -0.7883464276266061, -0.7845565971555752, -0.7807372285720946, -0.7768884656732328, -0.7730104533627369, -0.7691033376455797, -0.7651672656224592, -0.7612023854842622, -0.7572088465064846, -0.7531867990436126, -0.7491363945234596, -0.7450577854414658, -0.7409511253549591, -0.73681656887737, -0.7326542716724131, -0.7284643904482251, -0.724247082951467, -0.7200025079613819, -0.715730825283819, -0.7114321957452164, -0.7071067811865477, -0.7027547444572256, -0.6983762494089727, -0.693971460889654, -0.6895405447370672, -0.6850836677727008, -0.680600997795453, -0.676092703575316, -0.6715589548470187, -0.666999922303638, -0.6624157775901718, -0.6578066932970789, -0.6531728429537771, -0.6485144010221123, -0.6438315428897915, -0.639124444863776, -0.6343932841636459, -0.629638238914927, -0.6248594881423865, -0.6200572117632895, -0.6152315905806274, -0.6103828062763095, -0.6055110414043257, -0.6006164793838693, -0.5956993044924332, -0.5907597018588743, -0.5857978574564391, -0.580813958095765, -0.5758081914178452, -0.5707807458869674, -0.5657318107836136, -0.5606615761973366, -0.5555702330196022, -0.550457972936605, -0.5453249884220468, -0.5401714727298927, -0.5349976198870973, -0.5298036246862949, -0.5245896826784694, -0.5193559901655895, -0.5141027441932219, -0.5088301425431074, -0.5035383837257181, -0.49822766697278187, -0.49289819222978426, -0.4875501601484364, -0.4821837720791226, -0.4767992300633222, -0.4713967368259979, -0.4659764957679667, -0.46053871095823995, -0.455083587126344, -0.449611329654607, -0.4441221445704298, -0.43861623853852766, -0.4330938188531522, -0.42755509343028253, -0.42200027079979957, -0.41642956009763726, -0.41084317105790424, -0.4052413140049904, -0.3996241998456468, -0.39399204006104827, -0.3883450466988267, -0.3826834323650904, -0.37700741021641826, -0.3713171939518378, -0.36561299780477435, -0.359895036534988, -0.3541635254204905, -0.3484186802494349, -0.34266071731199493, -0.33688985339222, -0.3311063057598766, -0.32531029216226337, -0.31950203081601547, -0.3136817403988915, -0.30784964004153514, -0.3020059493192286, -0.29615088824362373, -0.2902846772544625, -0.2844075372112722, -0.27851968938505367, -0.272621355449949, -0.2667127574748986, -0.26079411791527596, -0.2548656596045144, -0.2489276057457202, -0.24298017990326418, -0.23702360599436773, -0.231058108280671, -0.22508391135979297, -0.21910124015687016, -0.21311031991609197, -0.20711137619221853, -0.20110463484209212, -0.19509032201612872, -0.18906866414980603, -0.183039887955141, -0.17700422041214905, -0.17096188876030177, -0.1649131204899698, -0.15885814333386158, -0.1527971852584438, -0.1467304744553624, -0.1406582393328492, -0.13458070850712642, -0.12849811079379364, -0.12241067519921603, -0.11631863091190484, -0.11022220729388336, -0.10412163387205513, -0.0980171403295605, -0.09190895649713288, -0.08579731234444028, -0.07968243797143075, -0.07356456359966741, -0.06744391956366429, -0.06132073630220906, -0.055195244349689775, -0.04906767432741809, -0.04293825693494114, -0.036807222941359394, -0.030674803176636543, -0.024541228522912448, -0.018406729905805226, -0.012271538285720572, -0.006135884649154477, ], [0.0, 0.016069700385633785, 0.03213486343392391, 0.04819095382318008, 0.06423344026197048, 0.08025779750163106, 0.09625950834563243, 0.11223406565475973, 0.12817697434706296, 0.14408375339153834, 0.15994993779450495, 0.17577108057764426, 0.19154275474667562, 0.20726055524964546, 0.22292010092381404, 0.2385170364301303, 0.2540470341742902, 0.269505796213385, 0.28488905614715077, 0.30019258099284024, 0.3154121730427477, 0.3305436717034259, 0.34558295531564587, 0.3605259429541603, 0.3753685962063406, 0.39010692092877497, 0.4047369689809193, 0.41925483993491297, 0.43365668276067976, 0.4479386974854492, 0.4620971368268504, 0.47612830779874055, 0.49002857328894955, 0.503794353608135, 0.5174221280089597, 0.5309084361748192, 0.5442498796773644, 0.5574431234020805, 0.5704848969412036, 0.5833719959532717, 0.5961012834886258, 0.6086696912801994, 0.6210742209989477, 0.6333119454732914, 0.645380009871971, 0.6572756328497247, 0.668996107655222, 0.6805388032007159, 0.691901165092882, 0.70308071662435, 0.7140750597254428, 0.7248818758756698, 0.7354989269745343, 0.7459240561712442, 0.7561551886529368, 0.7661903323910461, 0.7760275788454725, 0.7856651036262297, 0.795101167112275, 0.8043341150272433, 0.8133623789718409, 0.8221844769126659, 0.8307990136272565, 0.839204681105188, 0.8474002589050609, 0.8553846144672546, 0.8631567033823359, 0.8707155696150423, 0.878060345683784, 0.8851902527956291, 0.8921046009367639, 0.8988027889184431, 0.9052843043784735, 0.9115487237382883, 0.9175957121157078, 0.9234250231934913, 0.929036499043825, 0.9344300699088995, 0.9396057539377619, 0.9445636568796559, 0.9493039717340761, 0.9538269783577917, 0.9581330430291225, 0.9622226179697615, 0.9660962408244775, 0.969754534099034, 0.9731982045567048, 0.9764280425737687, 0.9794449214544052, 0.9822497967054185, 0.984843705271256, 0.9872277647297913, 0.989403172449377, 0.9913712047076833, 0.993133215772865, 0.994690636947617, 0.9960449755766959, 0.9971978140185108, 0.9981508085813974, 0.998905688425215, 0.9994642544289212, 0.9998283780247943, 0.9999999999999999, 0.9999811292661995, 0.9997738415979361, 0.9993802783405268, 0.9988026450882254, 0.9980432103334215, 0.997104304087665, 0.9959883164753166, 0.9946976963006331, 0.9932349495891236, 0.9916026381040105, 0.9898033778386519, 0.9878398374857883, 0.9857147368844931, 0.9834308454457094, 0.9809909805572783, 0.9783980059693584, 0.9756548301611602, 0.972764404689918, 0.9697297225230335, 0.9665538163543376, 0.9632397569054127, 0.9597906512129382, 0.9562096409030165, 0.9524999004534516, 0.9486646354449497, 0.9447070808022194, 0.940630499025956, 0.9364381784166892, 0.9321334312914862, 0.9277195921944963, 0.9232000161023313, 0.9185780766252685, 0.9138571642052777, 0.9090406843118547, 0.9041320556366608, 0.8991347082879563, 0.8940520819858169, 0.888887624259119, 0.8836447886452823, 0.8783270328937404, 0.8729378171741288, 0.8674806022901504, 0.8619588479000925, 0.8563760107449521, 0.8507355428851286, 0.8450408899466283, 0.8392954893777235, 0.833502768716998, 0.8276661438737074, 0.8217890174213639, 0.815874776905459, 0.8099267931662169, 0.8039484186772651, 0.7979429859010994, 0.7919138056622078, 0.7858641655387031, 0.7797973282733077, 0.773716530204516, 0.7676249797187481, 0.7615258557242995, 0.755422306147868, 0.7493174464544301, 0.7432143581912328, 0.7371160875566278, 0.7310256439944878, 0.7249459988149048, 0.7188800838418714, 0.712830790088614, 0.7068009664612437, 0.7007934184913603, 0.6948109070982407, 0.688856147381205, 0.6829318074427614, 0.677040507243084, 0.671184817486381, 0.6653672585396797, 0.6595902993845323, 0.6538563566021416, 0.6481677933923659, 0.6425269186270565, 0.6369359859381529, 0.6313971928409445, 0.6259126798928808, 0.6204845298882966, 0.6151147670893947, 0.6098053564938037, 0.6045582031390144, 0.5993751514439647, 0.5942579845880387, 0.5892084239276989, 0.5842281284509725, 0.5793186942699745, 0.5744816541516348, 0.5697184770867681, 0.5650305678976154, 0.5604192668839427, 0.5558858495077836, 0.5514315261168693, 0.5470574417067805, 0.5427646757218274, 0.5385542418946414, 0.5344270881244401, 0.5303840963939075, 0.5264260827246057, 0.5225537971708104, 0.5187679238516472, 0.515069081021376, 0.5114578211776557, 0.507934631207592, 0.5044999325713551, 0.501154081523136, 0.4978973693691753, 0.49473002276259287, 0.491652204034715, 0.4886640115625826, 0.48576548017229554, 0.48295658157783555, 0.4802372248549861, 0.47760725694994755, 0.475066463222229, 0.47261456802137797, 0.47025123529708923, 0.4679760692422151, 0.465788614968186, 0.4636883592123251, 0.46167473107652934, 0.4597471027967669, 0.45790479054283056, 0.4561470552477623, 0.4544731034663557, 0.4528820882621191, 0.4513731101220787, 0.4499452178987698, 0.4485974097787677, 0.44732863427707936, 0.44613779125671527, 0.445023732972742, 0.4439852651401029, 0.4430211480244851, 0.44213009755549687, 0.44131078646140715, 0.44056184542469157, 0.4398818642576161, 0.43926939309707697, 0.4387229436179144, 0.4382409902638981, 0.43782197149558183, 0.4374642910542141, 0.4371663192408801, 0.43692639421005314, 0.4367428232767129, 0.43661388423619873, 0.43653782669594415, 0.43651287341824657, 0.43653722167321435, 0.43660904460103217, 0.43672649258268276, 0.4368876946182571, 0.4370907597119888, 0.4373337782631382, 0.43761482346185776, 0.43793195268916746, 0.4382832089201651, 0.43866662212960283, 0.4390802106989562, 0.439521982824118, 0.43998993792284724, 0.44048206804110956, 0.44099635925744585, 0.4415307930845081, 0.4420833478669109, 0.442652000174545, 0.4432347261905073, 0.4438295030928092, 0.44443431042902637, 0.4450471314830627, 0.44566595463320835, 0.44628877470067874, 0.4469135942878255, 0.4475384251052279, 0.4481612892868695, 0.4487802206926279, 0.44939326619730274, 0.4499984869654271, 0.4505939597111096, 0.4511777779421758, 0.4517480531878765, 0.45230291620945534, 0.4528405181928698, 0.45335903192297944, 0.4538566529385266, 0.4543316006672441, 0.454782119540446, 0.4552064800864677, 0.4556029800023325, 0.45596994520304485, 0.456305730847922, 0.45660872234338656, 0.4568773363216697, 0.45711002159487935, 0.45730526008391276, 0.4574615677217061, 0.45757749533032926, 0.45765162947145754, 0.45768259326976335, 0.45766904720879137, 0.45760968989890116, 0.4575032588168757, 0.45734853101681494, 0.4571443238119537, 0.456889495427059, 0.4565829456210849, 0.4562236162797786, 0.45581049197795315, 0.455342600511164, 0.45481901339654063, 0.45423884634254946, 0.4536012596874829, 0.45290545880648997, 0.452150694486979, 0.45133626327225584, 0.4504615077732639, 0.4495258169483292, 0.4485286263508238, 0.4474694183446815, 0.4463477222877308, 0.4451631146828143, 0.44391521929669675, 0.44260370724678105, 0.44122829705566796, 0.43978875467361944, 0.43828489346900545, 0.43671657418683446, 0.4350837048754807, 0.43338624078175825, 0.4316241842144882, 0.429797584376747, 0.4279065371669895, 0.42595118494926376, 0.423931716292757, 0.4218483656809249, 0.4197014131904792, 0.41749118414052894, 0.4152180487121831, 0.41288242153894456, 0.41048476126824684, 0.4080255700944905, 0.40550539326397006, 0.40292481855208523, 0.40028447571325415, 0.39758503590396216, 0.3948272110793969, 0.3920117533641292, 0.38913945439732667, 0.38621114465299455, 0.38322769273574986, 0.3801900046526645, 0.37709902306170534, 0.37395572649733566, 0.37076112857383914, 0.3675162771669488, 0.36422225357437465, 0.3608801716558358, 0.35749117695321386, 0.3540564457914574, 0.3505771843608804, 0.3470546277814989, 0.34349003915007936, 0.3398847085705549, 0.33623995216850544, 0.3325571110903804, 0.32883755048816793, 0.3250826584902159, 0.32129384515891835, 0.3174725414359897, 0.3136201980760522, 0.30973828456927643, 0.30582828805380596, 0.3018917122187221, 0.297930076198288, 0.2939449134582361, 0.28993777067484955, 0.2859102066076042, 0.28186379096613234, 0.27780010327227694, 0.2737207317180039, 0.2696272720199416, 0.2655213262713228, 0.2614045017920908, 0.25727840997795504, 0.25314466514915074, 0.24900488339968588, 0.2448606814478342, 0.240713675488645, 0.23656548004923073, 0.23241770684759253, 0.22827196365574337, 0.22412985316787631, 0.2199929718743327, 0.21586290894210583, 0.21174124510262773, 0.20762955154755958, 0.20352938883332325, 0.19944230579508287, 0.19536983847089553, 0.19131350903673053, 0.1872748247530537, 0.18325527692366814, 0.17925633986748218, 0.17527946990388193, 0.17132610435235915, 0.16739766054705174, 0.16349553486682528, 0.15962110178153316, 0.15577571291506198, 0.15196069612577026, 0.14817735460491346, 0.1444269659936289, 0.14071078151905383, 0.13703002515012158, 0.1333858927735829, 0.12977955139077024, 0.1262121383356228, 0.12268476051446175, 0.11919849366800418, 0.11575438165607634, 0.1123534357654785, 0.10899663404144003, 0.10568492064307662, 0.1024192052232608, 0.099200362333283, 0.09602923085268185, 0.09290661344458737, 0.08983327603691978, 0.08680994732975571, 0.08383731832916826, 0.08091604190781707, 0.07804673239255756, 0.07522996517931556, 0.07246627637545193, 0.06975616246983347, 0.0671000800307944, 0.06449844543216805, 0.061951634607537505, 0.0594599828328456, 0.05702378453747508, 0.05464329314390454, 0.05231872093601016, 0.050050238956079574, 0.0478379769305765, 0.0456820232246761, 0.04358242482557804, 0.041539187354576505, 0.039552275107854515, 0.03762161112594794, 0.0357470772918056, 0.03392851445735332, 0.03216572259845356, 0.03045846099812631, 0.028806448457888705, 0.027209363537042848, 0.02566684481972901, 0.024178491209540635, 0.022743862251479688, 0.021362478481012734, 0.02003382179997246, 0.018757335879027604, 0.017532426586431192, 0.016358462442739564, 0.015234775101169275, 0.014160659853258045, 0.013135376159462716, 0.012158148204322188, 0.01122816547579307, 0.010344583368349062, 0.009506523809421073, 0.008713075908741442, 0.007963296630135536, 0.0072562114852949685, 0.006590815249051716, 0.0059660726956494515, 0.005380919355511638, 0.004834262291974329, 0.004324980897452212, 0.00385192770848697, 0.00341392923911659, 0.0030097868319898745, 0.002638277526646432, 0.002298154944359138, 0.0019881501889374054, 0.0017069727628720012, 0.0014533114981912828, 0.0012258355013977376, 0.001023195111831609, 0.0008440228728123666, 0.0006869345148921129, 0.0005505299505507435, 0.00043339427965136346, 0.00033409880497676164, 0.00025120205714656397, 0.0001832508282240542, 0.00012878121330555856, 8.631965938086593e-5, 5.4384020757629725e-5, 3.148462032350853e-5, 1.612531593071675e-5, 6.804571174618254e-6, 2.016529839623126e-6, 2.5209327854047304e-7, 0.0, -2.5209327854047304e-7, -2.0165298396219907e-6, -6.804571174620526e-6, -1.612531593071675e-5, -3.148462032350626e-5, -5.4384020757629725e-5, -8.631965938085685e-5, -0.000128781213305554, -0.0001832508282240542, -0.00025120205714656397, -0.00033409880497675253, -0.0004333942796513816, -0.0005505299505507526, -0.0006869345148921129, -0.0008440228728123666, -0.001023195111831609, -0.0012258355013977376, -0.0014533114981912737, -0.001706972762871992, -0.001988150188937378, -0.002298154944359129, -0.00263827752664645, -0.0030097868319898563, -0.00341392923911659, -0.003851927708486952, -0.004324980897452195, -0.004834262291974293, -0.005380919355511583, -0.005966072695649415, -0.006590815249051679, -0.007256211485294932, -0.007963296630135482, -0.008713075908741406, -0.009506523809421037, -0.010344583368349062, -0.011228165475792997, -0.012158148204322169, -0.01313537615946268, -0.014160659853258008, -0.015234775101169204, -0.016358462442739526, -0.017532426586431157, -0.018757335879027552, -0.020033821799972387, -0.021362478481012734, -0.022743862251479615, -0.024178491209540468, -0.025666844819728883, -0.02720936353704283, -0.028806448457888652, -0.030458460998126255, -0.032165722598453524, -0.03392851445735321, -0.035747077291805526, -0.03762161112594786, -0.03955227510785444, -0.041539187354576415, -0.04358242482557795, -0.045682023224675974, -0.04783797693057641, -0.05005023895607952, -0.052318720936010046, -0.054643293143904446, -0.05702378453747497, -0.05945998283284551, -0.061951634607537436, -0.06449844543216793, -0.06710008003079433, -0.06975616246983335, -0.0724662763754518, -0.0752299651793154, -0.07804673239255744, -0.08091604190781693, -0.08383731832916816, -0.08680994732975558, -0.08983327603691965, -0.09290661344458724, -0.09602923085268177, -0.09920036233328287, -0.10241920522326065, -0.10568492064307647, -0.10899663404143989, -0.11235343576547836, -0.1157543816560762, -0.11919849366800404, -0.1226847605144616, -0.12621213833562267, -0.12977955139077008, -0.1333858927735828, -0.13703002515012142, -0.14071078151905367, -0.14442696599362873, -0.14817735460491335, -0.15196069612577012, -0.15577571291506184, -0.15962110178153294, -0.1634955348668251, -0.16739766054705152, -0.17132610435235904, -0.17527946990388182, -0.17925633986748196, -0.18325527692366808, -0.18727482475305346, -0.1913135090367303, -0.19536983847089537, -0.19944230579508276, -0.20352938883332303, -0.20762955154755944, -0.21174124510262748, -0.21586290894210575, -0.21999297187433253, -0.22412985316787612, -0.2282719636557433, -0.23241770684759228, -0.23656548004923056, -0.24071367548864486, -0.24486068144783413, -0.2490048833996857, -0.25314466514915057, -0.2572784099779548, -0.26140450179209057, -0.2655213262713226, -0.2696272720199414, -0.2737207317180037, -0.27780010327227667, -0.2818637909661322, -0.2859102066076041, -0.2899377706748495, -0.2939449134582359, -0.29793007619828776, -0.30189171221872174, -0.3058282880538058, -0.3097382845692763, -0.3136201980760519, -0.31747254143598946, -0.3212938451589181, -0.3250826584902157, -0.3288375504881678, -0.33255711109038044, -0.33623995216850505, -0.3398847085705549, -0.3434900391500791, -0.3470546277814988, -0.35057718436088003, -0.35405644579145784, -0.35749117695321375, -0.3608801716558355, -0.3642222535743744, -0.3675162771669488, -0.37076112857383925, -0.3739557264973354, -0.37709902306170534, -0.38019000465266417, -0.38322769273574975, -0.3862111446529943, -0.38913945439732694, -0.39201175336412913, -0.3948272110793966, -0.39758503590396194, -0.40028447571325393, -0.4029248185520853, -0.4055053932639699, -0.4080255700944905, -0.4104847612682464, -0.41288242153894444, -0.415218048712183, -0.41749118414052927, -0.4197014131904792, -0.4218483656809246, -0.42393171629275694, -0.4259511849492637, -0.4279065371669896, -0.4297975843767468, -0.43162418421448817, -0.43338624078175797, -0.43508370487548065, -0.4367165741868344, -0.4382848934690058, -0.43978875467361933, -0.44122829705566774, -0.44260370724678094, -0.44391521929669664, -0.4451631146828144, -0.4463477222877308, -0.4474694183446815, -0.4485286263508235, -0.4495258169483292, -0.4504615077732639, -0.4513362632722559, -0.452150694486979, -0.4529054588064896, -0.45360125968748294, -0.4542388463425494, -0.4548190133965407, -0.455342600511164, -0.4558104919779531, -0.45622361627977837, -0.456582945621085, -0.45688949542705914, -0.45714432381195375, -0.4573485310168149, -0.45750325881687554, -0.45760968989890105, -0.45766904720879137, -0.45768259326976357, -0.4576516294714577, -0.45757749533032926, -0.457461567721706, -0.45730526008391276, -0.45711002159487946, -0.4568773363216698, -0.45660872234338656, -0.4563057308479219, -0.45596994520304474, -0.45560298000233246, -0.4552064800864679, -0.4547821195404462, -0.45433160066724404, -0.4538566529385266, -0.4533590319229796, -0.45284051819286997, -0.45230291620945534, -0.4517480531878765, -0.45117777794217573, -0.4505939597111095, -0.449998486965427, -0.4493932661973029, -0.44878022069262785, -0.44816128928686944, -0.44753842510522773, -0.4469135942878257, -0.4462887747006788, -0.44566595463320846, -0.4450471314830627, -0.4444343104290265, -0.44382950309280933, -0.4432347261905073, -0.44265200017454504, -0.442083347866911, -0.4415307930845081, -0.44099635925744585, -0.4404820680411097, -0.4399899379228473, -0.439521982824118, -0.4390802106989563, -0.4386666221296028, -0.43828320892016503, -0.43793195268916746, -0.4376148234618578, -0.4373337782631382, -0.4370907597119888, -0.43688769461825705, -0.43672649258268276, -0.4366090446010323, -0.43653722167321435, -0.4365128734182467, -0.43653782669594415, -0.4366138842361986, -0.436742823276713, -0.43692639421005314, -0.4371663192408801, -0.43746429105421414, -0.43782197149558194, -0.43824099026389796, -0.43872294361791436, -0.43926939309707697, -0.4398818642576161, -0.44056184542469157, -0.44131078646140715, -0.44213009755549687, -0.4430211480244851, -0.4439852651401027, -0.4450237329727421, -0.44613779125671527, -0.4473286342770792, -0.4485974097787675, -0.4499452178987698, -0.4513731101220785, -0.4528820882621191, -0.4544731034663559, -0.4561470552477624, -0.45790479054283023, -0.4597471027967667, -0.46167473107652934, -0.46368835921232515, -0.46578861496818574, -0.46797606924221463, -0.47025123529708907, -0.47261456802137797, -0.47506646322222884, -0.4776072569499477, -0.4802372248549861, -0.4829565815778355, -0.48576548017229526, -0.4886640115625826, -0.4916522040347149, -0.4947300227625926, -0.497897369369175, -0.5011540815231359, -0.5044999325713548, -0.5079346312075916, -0.511457821177656, -0.515069081021376, -0.5187679238516466, -0.5225537971708101, -0.5264260827246057, -0.5303840963939076, -0.5344270881244395, -0.5385542418946407, -0.5427646757218271, -0.5470574417067802, -0.5514315261168691, -0.555885849507784, -0.5604192668839427, -0.565030567897615, -0.5697184770867676, -0.5744816541516345, -0.5793186942699745, -0.584228128450972, -0.589208423927699, -0.5942579845880386, -0.5993751514439647, -0.604558203139014, -0.6098053564938043, -0.6151147670893947, -0.620484529888296, -0.6259126798928801, -0.6313971928409446, -0.6369359859381529, -0.642526918627056, -0.648167793392366, -0.6538563566021414, -0.659590299384532, -0.6653672585396795, -0.6711848174863815, -0.6770405072430837, -0.6829318074427607, -0.6888561473812042, -0.6948109070982407, -0.7007934184913603, -0.7068009664612432, -0.7128307900886144, -0.7188800838418713, -0.7249459988149046, -0.7310256439944872, -0.737116087556628, -0.7432143581912325, -0.7493174464544295, -0.7554223061478672, -0.7615258557242995, -0.7676249797187481, -0.7737165302045153, -0.779797328273308, -0.7858641655387029, -0.7919138056622074, -0.7979429859010991, -0.8039484186772653, -0.8099267931662169, -0.8158747769054585, -0.821789017421363, -0.8276661438737078, -0.8335027687169984, -0.8392954893777231, -0.8450408899466286, -0.8507355428851284, -0.8563760107449522, -0.861958847900092, -0.8674806022901508, -0.8729378171741288, -0.8783270328937398, -0.8836447886452818, -0.8888876242591189, -0.8940520819858169, -0.8991347082879558, -0.9041320556366609, -0.9090406843118543, -0.9138571642052772, -0.9185780766252681, -0.9232000161023313, -0.9277195921944963, -0.9321334312914856, -0.9364381784166884, -0.9406304990259563, -0.9447070808022194, -0.9486646354449492, -0.9524999004534518, -0.9562096409030163, -0.9597906512129383, -0.9632397569054127, -0.9665538163543377, -0.9697297225230335, -0.9727644046899175, -0.9756548301611602, -0.9783980059693583, -0.9809909805572783, -0.9834308454457092, -0.9857147368844931, -0.9878398374857883, -0.9898033778386516, -0.9916026381040106, -0.9932349495891238, -0.9946976963006329, -0.9959883164753164, -0.9971043040876648, -0.9980432103334216, -0.9988026450882255, -0.999380278340527, -0.9997738415979361, -0.9999811292661995, -1.0, -0.9998283780247943, -0.9994642544289211, -0.9989056884252152, -0.9981508085813976, -0.9971978140185112, -0.9960449755766959, -0.994690636947617, -0.993133215772865, -0.9913712047076833, -0.9894031724493771, -0.9872277647297917, -0.9848437052712563, -0.9822497967054185, -0.9794449214544052, -0.976428042573769, -0.9731982045567051, -0.969754534099034, -0.9660962408244776, -0.9622226179697619, -0.9581330430291223, -0.953826978357792, -0.9493039717340762, -0.9445636568796564, -0.9396057539377618, -0.9344300699088998, -0.9290364990438258, -0.9234250231934918, -0.9175957121157079, -0.9115487237382887, -0.9052843043784741, -0.898802788918443, -0.892104600936764, -0.8851902527956299, -0.8780603456837848, -0.8707155696150419, -0.8631567033823361, -0.8553846144672553, -0.8474002589050619, -0.8392046811051878, -0.8307990136272569, -0.8221844769126665, -0.8133623789718408, -0.8043341150272436, -0.7951011671122752, -0.7856651036262305, -0.7760275788454721, -0.7661903323910463, -0.7561551886529375, -0.745924056171244, -0.7354989269745347, -0.7248818758756704, -0.7140750597254437, -0.7030807166243497, -0.6919011650928825, -0.6805388032007169, -0.668996107655223, -0.6572756328497243, -0.6453800098719715, -0.6333119454732925, -0.6210742209989475, -0.6086696912801993, -0.5961012834886262, -0.5833719959532727, -0.5704848969412035, -0.5574431234020809, -0.5442498796773648, -0.5309084361748204, -0.5174221280089595, -0.5037943536081355, -0.4900285732889508, -0.4761283077987404, -0.4620971368268509, -0.44793869748544973, -0.43365668276068103, -0.41925483993491275, -0.40473696898091976, -0.39010692092877614, -0.375368596206342, -0.3605259429541601, -0.3455829553156465, -0.3305436717034272, -0.3154121730427475, -0.3001925809928401, -0.2848890561471514, -0.26950579621338644, -0.2540470341742899, -0.23851703643013086, -0.2229201009238147, -0.20726055524964684, -0.19154275474667543, -0.1757710805776449, -0.15994993779450642, -0.1440837533915381, -0.12817697434706354, -0.11223406565476038, -0.09625950834563389, -0.08025779750163084, -0.06423344026197109, -0.048190953823181525, -0.03213486343392541, -0.016069700385633597, ], [0.0, 0.05579255369345594, 0.11138889060622456, 0.16659399703486158, 0.22121525665940356, 0.2750636273771393, 0.3279547921009636, 0.3797102751656828, 0.43015851625805474, 0.47913589412258734, 0.5264876926923832, 0.5720690027493124, 0.6157455527267203, 0.657394462826535, 0.6969049172263464, 0.7341787497958507, 0.7691309394205681, 0.8016900117384402, 0.8317983448258501, 0.8594123771178384, 0.884502716606601, 0.9070541511265472, 0.9270655602969701, 0.9445497304485582, 0.9595330746013314, 0.9720552602831725, 0.9821687486740327, 0.9899382492256016, 0.995440094534317, 0.9987615408321571, 0.9999999999999994, 0.9992622094982346, 0.9966633470450247, 0.9923260972507434, 0.9863796777349201, 0.9789588325071217, 0.9702028005838917, 0.9602542679388745, 0.9492583109419755, 0.9373613394356838, 0.9247100475230301, 0.9114503800030584, 0.8977265221877048, 0.8836799205707174, 0.869448341497267, 0.8551649746053095, 0.8409575873800552, 0.8269477366850438, 0.8132500426116602, 0.7999715294281037, 0.7872110378138594, 0.7750587119418332, 0.7635955643229569, 0.7528931206628903, 0.7430131463031332, 0.7340074551352576, 0.725917801192862, 0.7187758524470059, 0.7126032456630451, 0.7074117205254276, 0.7032033306076003, 0.6999707281617724, 0.6976975191328263, 0.6963586842666989, 0.6959210616902671, 0.6963438858910503, 0.6975793776242576, 0.699573378924839, 0.7022660271058043, 0.7055924613831087, 0.7094835555834622, 0.7138666702654918, 0.7186664175172889, 0.7238054316844996, 0.7292051393322657, 0.7347865218504656, 0.7404708642733427, 0.7461804840997539, 0.7518394341665269, 0.7573741739418871, 0.7627142039653692, 0.7677926585614686, 0.7725468523925086, 0.7769187768876258, 0.7808555430848554, 0.7843097679473396, 0.7872399017578395, 0.7896104947529371, 0.7913924017245653, 0.7925629238866218, 0.7931058878733833, 0.7930116622990998, 0.7922771128597145, 0.7909054974932034, 0.7889063036300592, 0.78629503005553, 0.7830929163662954, 0.7793266234324918, 0.77502786866793, 0.7702330202638588, 0.7649826548520146, 0.7593210833285965, 0.7532958497903774, 0.7469572087059102, 0.7403575855677098, 0.7335510263448475, 0.7265926410794454, 0.7195380469455018, 0.7124428160150279, 0.7053619328559516, 0.6983492669201701, 0.6914570644706648, 0.6847354645460297, 0.6782320431719715, 0.6719913897053286, 0.6660547188403485, 0.6604595214229839, 0.6552392568106625, 0.650423089086435, 0.6460356689917289, 0.642096962985543, 0.6386221303740185, 0.6356214489874735, 0.6331002894163817, 0.63105913735792, 0.6294936631746092, 0.6283948373305294, 0.6277490899523351, 0.6275385123656623, 0.6277410980859188, 0.6283310203990982, 0.6292789433560857, 0.630552362725478, 0.6321159732075221, 0.6339320580072176, 0.6359608966995084, 0.6381611871948739, 0.640490477530315, 0.6429056031690343, 0.6453631254919784, 0.6478197672054377, 0.6502328404702115, 0.6525606636783122, 0.6547629629611802, 0.6568012547070997, 0.6586392055926369, 0.6602429668910793, 0.6615814801071378, 0.662626751298674, 0.6633540917796369, 0.6637423232503638, 0.6637739457683771, 0.6634352673511057, 0.6627164943878915, 0.6616117824283749, 0.6601192473042166, 0.6582409369272324, 0.6559827644858987, 0.6533544041300748, 0.6503691505874415, 0.6470437444911653, 0.6433981655137019, 0.63945539569356, 0.6352411556077014, 0.6307836162797151, 0.6261130899209898, 0.6212617027770514, 0.6162630534927144, 0.6111518605165912, 0.6059636021371579, 0.6007341527785767, 0.5954994191848388, 0.590294980085805, 0.5851557328690703, 0.5801155506782384, 0.5752069532224701, 0.5704607944156599, 0.565905969768215, 0.5615691462322805, 0.5574745169547827, 0.5536435831244595, 0.5500949648119036, 0.5468442423985144, 0.5439038298742305, 0.5412828809581963, 0.5389872286643173, 0.5370193585983332, 0.5353784159378207, 0.5340602457147323, 0.5330574656948902, 0.5323595708333851, 0.5319530679821108, 0.531821639238496, 0.5319463320556407, 0.5323057739858905, 0.532876409704771, 0.5336327577620611, 0.5345476843334583, 0.5355926911012032, 0.5367382142764104, 0.5379539316905931, 0.5392090748295753, 0.5404727426599211, 0.5417142141062117, 0.5429032560765711, 0.5440104240032538, 0.545007351963859, 0.5458670295756847, 0.5465640630094271, 0.5470749176471161, 0.5473781401109427, 0.5474545576123132, 0.5472874528117325, 0.5468627126374112, 0.5461689497812234, 0.545197595872022, 0.5439429656154604, 0.54240229148357, 0.5405757288333245, 0.5384663316285058, 0.5360799992303542, 0.5334253950069336, 0.5305138377861445, 0.5273591674401621, 0.5239775861372941, 0.5203874770284946, 0.5166092023478486, 0.512664883097294, 0.5085781626539679, 0.504373956782266, 0.5000781926508334, 0.495717539546167, 0.4913191340386392, 0.48691030239307653, 0.4825182830243605, 0.478169951778958, 0.4738915527762562, 0.4697084374696253, 0.46564481448726225, 0.4617235126881788, 0.4579657597205795, 0.4543909781999797, 0.45101660143453237, 0.447857910417174, 0.4449278935805648, 0.44223713057365294, 0.4397937010705272, 0.43760311936552626, 0.4356682952459663, 0.43398952136795643, 0.43256448709428885, 0.43138831848890796, 0.4304536439026635, 0.4297506843324355, 0.4292673674927809, 0.42898946430833784, 0.4289007463185742, 0.4289831622861212, 0.4292170321178405, 0.4295812560455835, 0.43005353687285797, 0.4306106129755822, 0.43122849965081833, 0.43188273633766083, 0.43254863718986003, 0.43320154246057513, 0.4338170681659586, 0.43437135152585127, 0.4348412897362561, 0.43520476970879496, 0.43544088651605833, 0.43553014840753196, 0.4354546664071985, 0.43519832666943636, 0.4347469439527277, 0.43408839476897465, 0.43321272897793617, 0.43211225881915744, 0.43078162460559305, 0.4292178365414748, 0.42742029236948675, 0.42539077079654025, 0.42313340089091783, 0.42065460788387415, 0.4179630360435537, 0.41506944951599756, 0.411986612244822, 0.40872914828576706, 0.40531338402272576, 0.40175717396627003, 0.39807971197238323, 0.3943013298566473, 0.3904432854962056, 0.3865275426073802, 0.38257654445997363, 0.378612983839448, 0.37465957159492425, 0.37073880611411736, 0.3668727460460254, 0.3630827885486923, 0.3593894552732424, 0.35581218820737237, 0.35236915739257874, 0.3490770824007677, 0.3459510693089353, 0.34300446474682306, 0.34024872841362164, 0.3376933252677155, 0.3353456383901389, 0.33321090330993614, 0.3312921643601278, 0.3295902534087392, 0.3281037910825889, 0.32682921037454776, 0.3257608023000578, 0.32489078304806207, 0.3242093818573395, 0.32370494864371957, 0.323364080208722, 0.3231717636778107, 0.32311153564840595, 0.3231656553756933, 0.32331529018957295, 0.3235407112201073, 0.32382149741258837, 0.3241367457378018, 0.32446528544886644, 0.3247858942036193, 0.32507751386119565, 0.32531946377315796, 0.32549164942314707, 0.32557476432407867, 0.325550483157786, 0.32540164423786294, 0.32511241949129494, 0.3246684702869792, 0.32405708758813134, 0.3232673150692347, 0.3222900540149168, 0.3211181490061783, 0.31974645359674564, 0.31817187538698455, 0.3163934001127865, 0.3144120945798424, 0.31223108848777775, 0.309855535401454, 0.30729255333627215, 0.30455114562842805, 0.3016421029577761, 0.29857788757821213, 0.29537250098657325, 0.292041336424107, 0.28860101775307145, 0.2850692263835318, 0.28146451804060385, 0.27780613125919096, 0.2741137895707551, 0.27040749940411346, 0.2667073457591344, 0.26303328772830115, 0.2594049559361497, 0.2558414539408904, 0.25236116559623556, 0.2489815703051903, 0.24571906801206364, 0.24258881567505428, 0.23960457684064945, 0.2367785858039529, 0.2341214276873917, 0.23164193560556096, 0.22934710590802376, 0.2272420323063812, 0.22532985949887543, 0.22361175670705863, 0.22208691133669967, 0.22075254277118772, 0.2196039361022067, 0.21863449540152843, 0.21783581594135115, 0.21719777458070275, 0.2167086373539041, 0.216355183125766, 0.2161228420187663, 0.21599584717148992, 0.21595739825656188, 0.21598983507144043, 0.2160748194178569, 0.2161935234063808, 0.21632682226224662, 0.2164554896678137, 0.2165603936561762, 0.21662269106967325, 0.21662401861632305, 0.21654667859628274, 0.21637381742887615, 0.21608959518789744, 0.21567934444798934, 0.21512971685686685, 0.21442881597591862, 0.21356631507386126, 0.2125335587132545, 0.21132364713617233, 0.20993150263149507, 0.20835391725031227, 0.20658958142596498, 0.20463909324937732, 0.20250494834652868, 0.2001915105012987, 0.1977049633613894, 0.1950532437557488, 0.19224595733689204, 0.18929427743891605, 0.18621082821006557, 0.18300955323576307, 0.1797055710124458, 0.17631501876302028, 0.1728548861998788, 0.1693428409401073, 0.1657970473588597, 0.16223598072994902, 0.15867823854706384, 0.15514235094412615, 0.1516465921390063, 0.14820879481106808, 0.14484616928998603, 0.14157512938134822, 0.13841112658426127, 0.13536849436829257, 0.1324603040724472, 0.12969823386869542, 0.12709245209794004, 0.12465151613872603, 0.12238228780989832, 0.12028986613945654, 0.11837753815474455, 0.1166467481656598, 0.11509708582459938, 0.11372629305633625, 0.11253028975980975, 0.11150321799386752, 0.11063750417226256, 0.10992393861148239, 0.10935177160020165, 0.10890882499296552, 0.10858161817485222, 0.10835550709985393, 0.10821483497502331, 0.10814309304632441, 0.1081230898417951, 0.1081371271440306, 0.10816718089796361, 0.10819508521213266, 0.10820271758247975, 0.10817218345755071, 0.10808599827281606, 0.10792726510960118, 0.10767984618049868, 0.10732852640765794, 0.10685916744231004, 0.1062588505724875, 0.10551600708007511, 0.1046205347369515, 0.10356389927172431, 0.10233921979197072, 0.10094133731043115, 0.09936686569559461, 0.09761422454580122, 0.09568365366960492, 0.09357720904177623, 0.09129874029213027, 0.0888538499714327, 0.08624983502307225, 0.08349561106913828, 0.08060162029319694, 0.07757972386766267, 0.07444308002954121, 0.07120600905295371, 0.06788384649869805, 0.06449278623895228, 0.061049714857795215, 0.05757203911456129, 0.05407750822624301, 0.05058403277656236, 0.047109502092400383, 0.04367160194272896, 0.04028763441084989, 0.036974341767687484, 0.033747736132357785, 0.030622936646642965, 0.027614015812999104, 0.024733856552036314, 0.021994021426060547, 0.019404635351295215, 0.01697428298413171, 0.014709921817537662, 0.012616811864101065, 0.010698462633752431, 0.008956597938639574, 0.0073911388767598845, 0.0060002051615610525, 0.0047801347787191405, 0.0037255217655103013, 0.002829271724556357, 0.0020826745040247764, 0.0014754933024816598, 0.0009960692902461967, 0.0006314406819524575, 0.00036747504872098463, 0.00018901352428330812, 8.002543901451061e-5, 2.3771810268474593e-5, 2.9760277707333363e-6, 1.6293341490908767e-16, -2.9760277707318565e-6, -2.377181026847065e-5, -8.002543901450865e-5, -0.00018901352428331403, -0.00036747504872098463, -0.0006314406819524457, -0.0009960692902461809, -0.0014754933024816361, -0.0020826745040247526, -0.0028292717245563493, -0.0037255217655102735, -0.004780134778719049, -0.0060002051615610135, -0.0073911388767597935, -0.00895659793863953, -0.010698462633752414, -0.012616811864100911, -0.014709921817537554, -0.0169742829841315, -0.01940463535129511, -0.021994021426060537, -0.024733856552036193, -0.027614015812998844, -0.03062293664664285, -0.03374773613235776, -0.03697434176768735, -0.040287634410849905, -0.04367160194272861, -0.04710950209240022, -0.05058403277656239, -0.05407750822624286, -0.057572039114561095, -0.0610497148577951, -0.06449278623895197, -0.06788384649869794, -0.07120600905295335, -0.07444308002954111, -0.07757972386766265, -0.08060162029319662, -0.083495611069138, -0.08624983502307192, -0.08885384997143245, -0.09129874029213032, -0.09357720904177619, -0.09568365366960481, -0.09761422454580129, -0.09936686569559466, -0.10094133731043115, -0.10233921979197091, -0.10356389927172428, -0.10462053473695146, -0.10551600708007529, -0.10625885057248745, -0.10685916744231004, -0.10732852640765789, -0.10767984618049853, -0.10792726510960118, -0.10808599827281586, -0.10817218345755071, -0.1082027175824798, -0.10819508521213256, -0.10816718089796346, -0.10813712714403058, -0.10812308984179499, -0.10814309304632445, -0.10821483497502335, -0.10835550709985392, -0.1085816181748523, -0.10890882499296548, -0.10935177160020165, -0.10992393861148228, -0.11063750417226269, -0.11150321799386757, -0.11253028975980955, -0.11372629305633605, -0.11509708582459922, -0.11664674816565981, -0.11837753815474468, -0.12028986613945651, -0.12238228780989845, -0.12465151613872591, -0.12709245209793985, -0.1296982338686956, -0.13246030407244724, -0.13536849436829274, -0.13841112658426127, -0.14157512938134784, -0.14484616928998612, -0.1482087948110681, -0.1516465921390059, -0.15514235094412565, -0.15867823854706353, -0.16223598072994858, -0.16579704735885972, -0.16934284094010707, -0.17285488619987818, -0.17631501876302022, -0.17970557101244578, -0.18300955323576298, -0.18621082821006588, -0.18929427743891566, -0.1922459573368924, -0.19505324375574887, -0.1977049633613891, -0.20019151050129894, -0.20250494834652866, -0.20463909324937707, -0.20658958142596506, -0.20835391725031213, -0.20993150263149501, -0.2113236471361723, -0.21253355871325444, -0.2135663150738609, -0.2144288159759185, -0.21512971685686658, -0.21567934444798942, -0.21608959518789705, -0.21637381742887574, -0.21654667859628302, -0.21662401861632347, -0.21662269106967316, -0.21656039365617627, -0.21645548966781336, -0.21632682226224678, -0.21619352340638107, -0.2160748194178568, -0.21598983507144057, -0.2159573982565618, -0.2159958471714901, -0.21612284201876616, -0.21635518312576607, -0.21670863735390403, -0.2171977745807028, -0.21783581594135118, -0.21863449540152827, -0.21960393610220688, -0.22075254277118767, -0.22208691133669967, -0.22361175670705866, -0.2253298594988754, -0.22724203230638101, -0.2293471059080236, -0.2316419356055609, -0.23412142768739155, -0.23677858580395328, -0.23960457684064923, -0.24258881567505397, -0.2457190680120634, -0.24898157030519033, -0.2523611655962356, -0.25584145394089, -0.2594049559361497, -0.26303328772830087, -0.26670734575913413, -0.270407499404113, -0.2741137895707562, -0.27780613125919096, -0.2814645180406035, -0.2850692263835316, -0.2886010177530712, -0.29204133642410757, -0.2953725009865733, -0.29857788757821174, -0.3016421029577751, -0.3045511456284279, -0.3072925533362723, -0.3098555354014548, -0.3122310884877777, -0.31441209457984176, -0.31639340011278655, -0.3181718753869846, -0.3197464535967461, -0.3211181490061786, -0.32229005401491634, -0.32326731506923406, -0.3240570875881311, -0.3246684702869795, -0.32511241949129566, -0.3254016442378629, -0.3255504831577854, -0.32557476432407917, -0.3254916494231469, -0.3253194637731585, -0.32507751386119565, -0.32478589420361953, -0.3244652854488659, -0.3241367457378019, -0.3238214974125885, -0.3235407112201077, -0.3233152901895729, -0.32316565537569336, -0.3231115356484063, -0.3231717636778102, -0.3233640802087226, -0.32370494864371985, -0.3242093818573393, -0.3248907830480614, -0.3257608023000583, -0.3268292103745479, -0.3281037910825889, -0.32959025340873943, -0.3312921643601274, -0.33321090330993614, -0.3353456383901391, -0.33769332526771556, -0.34024872841362164, -0.3430044647468226, -0.3459510693089354, -0.34907708240076735, -0.35236915739257807, -0.3558121882073728, -0.35938945527324195, -0.36308278854869186, -0.3668727460460252, -0.3707388061141172, -0.37465957159492413, -0.3786129838394473, -0.38257654445997435, -0.3865275426073804, -0.39044328549620555, -0.3943013298566468, -0.3980797119723832, -0.4017571739662702, -0.4053133840227253, -0.4087291482857671, -0.4119866122448219, -0.41506944951599717, -0.41796303604355384, -0.4206546078838736, -0.42313340089091805, -0.42539077079654036, -0.4274202923694864, -0.4292178365414749, -0.4307816246055928, -0.43211225881915744, -0.4332127289779363, -0.4340883947689756, -0.434746943952727, -0.4351983266694358, -0.43545466640719926, -0.4355301484075326, -0.4354408865160576, -0.43520476970879496, -0.43484128973625624, -0.4343713515258513, -0.4338170681659586, -0.43320154246057496, -0.43254863718986025, -0.4318827363376608, -0.4312284996508184, -0.4306106129755822, -0.4300535368728576, -0.42958125604558345, -0.42921703211784096, -0.4289831622861212, -0.4289007463185743, -0.4289894643083376, -0.4292673674927806, -0.42975068433243613, -0.4304536439026635, -0.4313883184889079, -0.4325644870942888, -0.4339895213679562, -0.4356682952459653, -0.4376031193655263, -0.4397937010705275, -0.4422371305736527, -0.44492789358056445, -0.4478579104171741, -0.4510166014345324, -0.45439097819997976, -0.45796575972057896, -0.4617235126881791, -0.46564481448726186, -0.46970843746962515, -0.4738915527762563, -0.4781699517789578, -0.48251828302436023, -0.4869103023930764, -0.4913191340386393, -0.4957175395461662, -0.5000781926508334, -0.504373956782266, -0.5085781626539673, -0.5126648830972939, -0.5166092023478486, -0.5203874770284942, -0.523977586137294, -0.5273591674401615, -0.5305138377861445, -0.5334253950069336, -0.5360799992303543, -0.5384663316285057, -0.5405757288333236, -0.5424022914835707, -0.5439429656154605, -0.5451975958720217, -0.5461689497812235, -0.5468627126374106, -0.5472874528117331, -0.5474545576123134, -0.5473781401109431, -0.5470749176471165, -0.5465640630094262, -0.5458670295756853, -0.5450073519638585, -0.5440104240032545, -0.5429032560765709, -0.5417142141062113, -0.5404727426599204, -0.5392090748295743, -0.5379539316905937, -0.5367382142764103, -0.5355926911012032, -0.5345476843334582, -0.5336327577620606, -0.5328764097047716, -0.532305773985891, -0.5319463320556411, -0.531821639238496, -0.5319530679821104, -0.5323595708333857, -0.5330574656948899, -0.534060245714733, -0.5353784159378208, -0.5370193585983328, -0.5389872286643173, -0.541282880958196, -0.5439038298742306, -0.5468442423985138, -0.5500949648119036, -0.5536435831244593, -0.5574745169547826, -0.5615691462322806, -0.5659059697682148, -0.5704607944156591, -0.5752069532224701, -0.5801155506782377, -0.58515573286907, -0.5902949800858053, -0.5954994191848387, -0.600734152778577, -0.6059636021371579, -0.6111518605165909, -0.6162630534927138, -0.6212617027770512, -0.6261130899209894, -0.6307836162797152, -0.6352411556077007, -0.6394553956935605, -0.6433981655137015, -0.6470437444911653, -0.6503691505874413, -0.6533544041300747, -0.6559827644858991, -0.6582409369272322, -0.6601192473042169, -0.6616117824283753, -0.6627164943878909, -0.6634352673511061, -0.6637739457683762, -0.6637423232503645, -0.6633540917796372, -0.6626267512986734, -0.661581480107138, -0.6602429668910791, -0.6586392055926376, -0.6568012547070993, -0.654762962961181, -0.652560663678312, -0.6502328404702116, -0.6478197672054378, -0.6453631254919788, -0.6429056031690339, -0.6404904775303146, -0.6381611871948735, -0.6359608966995081, -0.6339320580072176, -0.6321159732075226, -0.6305523627254778, -0.6292789433560849, -0.628331020399098, -0.6277410980859193, -0.6275385123656623, -0.6277490899523348, -0.6283948373305294, -0.6294936631746089, -0.6310591373579209, -0.6331002894163822, -0.6356214489874733, -0.6386221303740187, -0.642096962985542, -0.646035668991729, -0.6504230890864343, -0.655239256810663, -0.6604595214229831, -0.6660547188403484, -0.6719913897053286, -0.6782320431719704, -0.6847354645460294, -0.6914570644706651, -0.6983492669201697, -0.7053619328559512, -0.7124428160150268, -0.7195380469455024, -0.726592641079445, -0.733551026344847, -0.7403575855677106, -0.7469572087059095, -0.7532958497903772, -0.7593210833285954, -0.764982654852015, -0.7702330202638581, -0.7750278686679297, -0.7793266234324918, -0.7830929163662953, -0.7862950300555299, -0.7889063036300591, -0.7909054974932036, -0.7922771128597147, -0.7930116622990999, -0.7931058878733835, -0.7925629238866213, -0.7913924017245645, -0.7896104947529377, -0.7872399017578399, -0.7843097679473393, -0.7808555430848555, -0.7769187768876263, -0.7725468523925082, -0.7677926585614686, -0.7627142039653689, -0.7573741739418872, -0.7518394341665272, -0.7461804840997537, -0.7404708642733435, -0.7347865218504663, -0.7292051393322656, -0.7238054316844995, -0.7186664175172897, -0.7138666702654916, -0.7094835555834622, -0.7055924613831095, -0.7022660271058049, -0.6995733789248386, -0.6975793776242579, -0.6963438858910509, -0.695921061690267, -0.6963586842666988, -0.6976975191328266, -0.6999707281617721, -0.7032033306076003, -0.7074117205254273, -0.7126032456630446, -0.7187758524470044, -0.7259178011928619, -0.7340074551352573, -0.7430131463031325, -0.7528931206628906, -0.7635955643229568, -0.7750587119418323, -0.7872110378138586, -0.799971529428104, -0.8132500426116603, -0.8269477366850433, -0.8409575873800539, -0.8551649746053095, -0.8694483414972665, -0.8836799205707164, -0.8977265221877047, -0.911450380003058, -0.9247100475230292, -0.9373613394356825, -0.9492583109419754, -0.9602542679388748, -0.9702028005838914, -0.9789588325071213, -0.9863796777349194, -0.9923260972507437, -0.9966633470450244, -0.9992622094982351, -1.0, -0.9987615408321573, -0.9954400945343169, -0.9899382492256017, -0.982168748674033, -0.972055260283173, -0.9595330746013329, -0.944549730448558, -0.9270655602969708, -0.907054151126549, -0.8845027166066006, -0.8594123771178384, -0.8317983448258515, -0.8016900117384435, -0.7691309394205674, -0.734178749795852, -0.6969049172263486, -0.6573944628265392, -0.6157455527267198, -0.5720690027493144, -0.5264876926923869, -0.47913589412258617, -0.430158516258056, -0.3797102751656856, -0.32795479210096795, -0.27506362737713863, -0.22121525665940492, -0.16659399703486577, -0.11138889060622963, -0.055792553693455704, ], [0.0, 0.2130275739341522, 0.4150106295627254, 0.5959282180821931, 0.7476935712321383, 0.8648523702792634, 0.9449947229564615, 0.9888409828381278, 1.0, 0.9844364190440414, 0.9497165485921238, 0.9041261305660687, 0.8557652652787182, 0.8117245792305817, 0.7774329216889748, 0.7562425048053852, 0.7492858201819661, 0.7556040686188322, 0.7725136977853632, 0.796150107211565, 0.8221089535747954, 0.8460978143570971, 0.86451479473013, 0.8748850201394938, 0.8761085284753364, 0.8685005476428665, 0.8536336808035723, 0.8340172939313952, 0.8126691006210272, 0.792645207079711, 0.7765965887429861, 0.7664123332004443, 0.7629944794639865, 0.7661884028578657, 0.7748695643509845, 0.7871653693970704, 0.8007728866291949, 0.8133215904520684, 0.822726451686774, 0.8274808037969013, 0.8268495297678703, 0.8209393642352711, 0.810641952881492, 0.7974639534548429, 0.7832742361368398, 0.7700089950240319, 0.759379968948552, 0.752628606987687, 0.7503605071372017, 0.752481276449855, 0.7582392014225642, 0.7663641717218757, 0.7752785040819099, 0.7833456074230349, 0.7891180922145115, 0.7915484060591769, 0.7901319557827486, 0.7849637582060618, 0.776703172962676, 0.7664551364943761, 0.75558847545415, 0.7455205849907148, 0.7375018311104693, 0.7324320158298145, 0.7307354363351026, 0.7323114823403414, 0.7365658880278964, 0.7425155135653619, 0.7489487129606598, 0.7546155234550378, 0.7584181704823005, 0.7595731674224898, 0.7577213597424162, 0.7529707441744081, 0.7458674243292199, 0.7373010124704921, 0.7283605003865591, 0.7201636744583647, 0.7136865495649071, 0.7096186238930513, 0.7082652136034344, 0.7095104902948498, 0.7128453388874899, 0.7174542545333839, 0.7223467048866397, 0.7265119979156721, 0.7290736230977163, 0.7294196635847949, 0.727290030712489, 0.7228082447828944, 0.7164541624712949, 0.708983083546666, 0.7013046849162212, 0.6943410378222886, 0.6888857320643409, 0.6854855035537623, 0.6843619029882829, 0.6853841038097058, 0.6880959541557181, 0.6917920723021019, 0.6956304330659592, 0.6987635899347937, 0.7004681890371147, 0.7002530803657419, 0.6979299611615289, 0.6936364894817949, 0.6878092273859393, 0.6811114598496086, 0.674327715033801, 0.6682416701016686, 0.6635163601721538, 0.6605949229634536, 0.6596366632802769, 0.6604975819183332, 0.6627575781535503, 0.6657893809087664, 0.6688579832965597, 0.6712348898963021, 0.6723094856869175, 0.6716805643363203, 0.6692143563519218, 0.6650607300160174, 0.6596257553825099, 0.653505512117166, 0.6473918632579385, 0.6419650318225016, 0.6377896047792225, 0.6352298120821012, 0.6343967444208264, 0.6351351037314982, 0.6370509267021812, 0.6395754383545048, 0.6420547409644927, 0.6438512431284644, 0.644441141098439, 0.6434930913390663, 0.6409162992142551, 0.6368710909467484, 0.6317408706086141, 0.6260702860359321, 0.6204795313428397, 0.6155682304633754, 0.6118237598439179, 0.6095479910468364, 0.6088134300061312, 0.6094550860852062, 0.6110988521472922, 0.6132215771236679, 0.6152332218937742, 0.6165682299796043, 0.6167719957450521, 0.6155692403029386, 0.6129040402297942, 0.6089457227076149, 0.6040601403877269, 0.5987511490717671, 0.5935816185474713, 0.5890863314033198, 0.5856902210735183, 0.5836444236456011, 0.5829897418966882, 0.5835528070168887, 0.5849751504920524, 0.5867703511698225, 0.5884001732978404, 0.5893578020696105, 0.5892453280790276, 0.5878336567373721, 0.5850958443011243, 0.5812090360471844, 0.5765250501263448, 0.5715144593151332, 0.566693036037235, 0.5625420371148947, 0.559434628414585, 0.5575796784220404, 0.5569913677986342, 0.5574890133407293, 0.5587268232050835, 0.5602487100006104, 0.5615594922505802, 0.56220138685622, 0.5618239941279917, 0.5602370930127305, 0.5574383081992016, 0.5536116459734871, 0.5490974068041358, 0.5443383721112106, 0.5398107583286123, 0.5359506891813213, 0.5330875233817026, 0.5313942155214588, 0.5308621770629105, 0.5313042724380608, 0.5323852307436823, 0.533674549442476, 0.5347135566827691, 0.5350861994677598, 0.5344826463643758, 0.5327459946851409, 0.5298950537650893, 0.5261199133306524, 0.5217512159819714, 0.5172080849987785, 0.5129328954841808, 0.5093230281365139, 0.5066701220646684, 0.5051161015097567, 0.5046325954419253, 0.5050267185147902, 0.5059721057240041, 0.5070602209061388, 0.507863884003876, 0.5080031496506727, 0.5072033882202567, 0.5053367009004984, 0.502440433344571, 0.49871012272606025, 0.49446816462317317, 0.49011320894161875, 0.48605821784546793, 0.48266680036696036, 0.48019762920233255, 0.4787654273042957, 0.47832439930317655, 0.4786764868575672, 0.47950299169241817, 0.480414527335808, 0.48101147919562165, 0.48094559419866345, 0.47997321629262596, 0.4779920381834936, 0.47505583399243473, 0.47136506429096936, 0.46723497142633647, 0.46304623414283275, 0.459185897607291, 0.4559877367340099, 0.4536812339380887, 0.452356963430376, 0.451953596257356, 0.4522683780692902, 0.45298930745977584, 0.4537439157634925, 0.45415702905988237, 0.45390856196946416, 0.45278244744313884, 0.4506992295459321, 0.447727409373584, 0.44407193600541306, 0.4400417621173516, 0.4360015934559544, 0.43231536113841784, 0.4292901717147283, 0.4271293609658506, 0.4259018219114392, 0.42553222770780613, 0.42581352514072623, 0.4264396378187891, 0.4270532198802327, 0.4273010156777861, 0.4268882551920477, 0.425623715042301, 0.4234485621471693, 0.4204446347949602, 0.416821007360475, 0.4128810351597889, 0.40897506845584475, 0.4054462009634083, 0.40257744814471436, 0.40054848223025286, 0.39940853298205214, 0.3990695364806138, 0.39932047327775794, 0.3998605690271264, 0.4003461389559999, 0.4004437853087392, 0.39988171863323835, 0.39849130770660796, 0.39623251066114973, 0.3931993566265431, 0.3896047402782943, 0.3857469818049958, 0.38196340032386294, 0.3785781213764963, 0.3758521894430344, 0.3739436593674334, 0.3728837637995972, 0.3725727461035459, 0.37279590055150436, 0.3732572460510439, 0.3736255562305071, 0.37358559165680216, 0.3728866141949602, 0.37138072143944323, 0.3690451441877887, 0.36598514927608444, 0.362417194476219, 0.3586350264177939, 0.3549640275978991, 0.3517109037697415, 0.34911648734737694, 0.34731891573013235, 0.3463328108707595, 0.3460475946596801, 0.34624511156857984, 0.3466337540880749, 0.3468937562670604, 0.34672662384532477, 0.34590106368054263, 0.3442883488221987, 0.3418817150397558, 0.33879686940756926, 0.33525361524713004, 0.331541507982208, 0.32797490497195464, 0.3248443839172808, 0.32237203429719125, 0.3206774974652431, 0.3197599453221613, 0.3194987090621692, 0.3196723835612207, 0.3199933859023452, 0.3201525768499679, 0.31986702497334696, 0.3189235367751695, 0.3172112585796244, 0.3147383673374459, 0.3116303400734354, 0.3081101413832234, 0.3044634545416517, 0.30099437529458284, 0.29797843673107605, 0.2956202186757943, 0.29402206054642416, 0.29316866005625897, 0.2929298729451429, 0.2930812140959289, 0.29333883307780184, 0.2934035173877554, 0.2930069047053197, 0.2919527696971753, 0.29014703614333953, 0.2876119263955326, 0.28448212244242316, 0.2809835941050205, 0.27739842026878614, 0.2740210773261233, 0.2711129658116773, 0.2688621945987353, 0.26735480729814926, 0.26656185003628763, 0.26634422205587865, 0.2664745017389905, 0.26667232544817965, 0.2666478177627524, 0.2661463479843449, 0.26498770507211483, 0.26309366623998764, 0.2604997437270243, 0.25734934782258834, 0.25387132174744187, 0.25034436562562834, 0.24705387804175058, 0.24424789615073186, 0.24209893392954462, 0.240677587795766, 0.23994194610286182, 0.2397443892728111, 0.2398546801398975, 0.239995734534461, 0.2398865166996502, 0.2392854211689664, 0.23802744675107382, 0.23604944496012759, 0.2333995810368812, 0.23022959194924436, 0.22677108352862513, 0.22329956771607518, 0.22009182209803962, 0.21738316895062074, 0.2153312656816166, 0.2139919764237473, 0.2133110159462502, 0.21313261402153216, 0.21322381919369499, 0.2133106515543605, 0.21312049569239708, 0.21242417642157085, 0.2110712253134762, 0.20901291283656012, 0.20630952198515182, 0.20312077937287398, 0.19968096115182002, 0.1962625521330043, 0.193134093496815, 0.1905187378949655, 0.18855990634057174, 0.18729933060585877, 0.18667084152025687, 0.18651082615622144, 0.1865837025920885, 0.18661844720026907, 0.18635051259352203, 0.18556265488712295, 0.18411837130804276, 0.18198280309739026, 0.17922790399759575, 0.17602110957553238, 0.17259929049890457, 0.16923204031722133, 0.16617998603827447, 0.1636545664323442, 0.16178548357461114, 0.1606008365837641, 0.1600229793492803, 0.1598807113003519, 0.15993588823067334, 0.159920318182693, 0.1595772277091565, 0.15870088901937207, 0.15716829415607353, 0.15495800099940302, 0.15215326470883567, 0.1489289989458713, 0.14552460798767416, 0.14220690823528445, 0.1392288801803744, 0.13679062578064058, 0.13500855508707385, 0.13389754569755707, 0.13336880829424871, 0.13324376259571483, 0.1332817560485719, 0.1332173240735178, 0.13280122440611902, 0.13183890429510062, 0.13022046522776995, 0.12793751131545303, 0.12508429917067573, 0.12184303442093827, 0.11845560771683754, 0.1151861533833655, 0.11228022460753904, 0.10992689345349149, 0.10822962388157087, 0.10719040365941998, 0.10670956806861209, 0.1066013224269146, 0.10662254659886274, 0.1065104169952975, 0.10602302567295493, 0.10497672048072947, 0.1032744040026622, 0.10092043184479972, 0.09801982501119642, 0.0947619357444682, 0.09139110657833274, 0.08816886794046166, 0.08533352127764368, 0.08306335217258982, 0.08144915087792208, 0.08048027465274459, 0.08004639092335906, 0.0799546167429386, 0.07995939277372816, 0.0798004660267827, 0.07924310769113613, 0.07811435256445078, 0.0763296665012342, 0.07390593136226108, 0.070958753454183, 0.06768452407625714, 0.0643300152424252, 0.06115421645539644, 0.05838831303633972, 0.05619998907168419, 0.05466756558752517, 0.0537679616343765, 0.05338032831821944, 0.05330478394494822, 0.05329334650490345, 0.05308827772844302, 0.05246191120661306, 0.051251811432229274, 0.04938583536584782, 0.046893230800279816, 0.04390006460900121, 0.04060969522981519, 0.03727131342283775, 0.0341414168399848, 0.03144417310897892, 0.029336795127229958, 0.027885275398122456, 0.027054223903283454, 0.026712373980257807, 0.02665289985854059, 0.02662540184298928, 0.026374613869366872, 0.025679851307879937, 0.024389104341492992, 0.022442511100421843, 0.019881586718546018, 0.016842785785905045, 0.01353639618942585, 0.010214028174957852, 0.007129723708090962, 0.004500695933247721, 0.0024737647714023685, 0.001102673909687239, 0.0003397927839736993, 4.348446630908475e-5, -1.1744696147515288e-16, -4.348446630937334e-5, -0.00033979278397381035, -0.0011026739096872693, -0.0024737647714024426, -0.004500695933247606, -0.00712972370808964, -0.010214028174957993, -0.013536396189426208, -0.016842785785905024, -0.019881586718546007, -0.022442511100421548, -0.02438910434149267, -0.025679851307879902, -0.026374613869366646, -0.026625401842989366, -0.026652899858540097, -0.026712373980257307, -0.027054223903283305, -0.027885275398121998, -0.029336795127230232, -0.03144417310897972, -0.034141416839984874, -0.037271313422838605, -0.04060969522981531, -0.043900064609001255, -0.04689323080028092, -0.04938583536584811, -0.051251811432229746, -0.052461911206612956, -0.05308827772844356, -0.0532933465049042, -0.053304783944948156, -0.05338032831821938, -0.05376796163437639, -0.05466756558752512, -0.05619998907168371, -0.05838831303634002, -0.06115421645539635, -0.06433001524242515, -0.0676845240762564, -0.0709587534541824, -0.07390593136226101, -0.07632966650123556, -0.0781143525644504, -0.07924310769113752, -0.0798004660267833, -0.0799593927737283, -0.07995461674293922, -0.08004639092335855, -0.0804802746527448, -0.08144915087792157, -0.08306335217258967, -0.08533352127764353, -0.08816886794046117, -0.09139110657833256, -0.09476193574446827, -0.09801982501119678, -0.10092043184479915, -0.10327440400266245, -0.10497672048072963, -0.10602302567295445, -0.10651041699529715, -0.10662254659886293, -0.10660132242691474, -0.10670956806861243, -0.10719040365941927, -0.10822962388157072, -0.10992689345349159, -0.11228022460753938, -0.115186153383366, -0.1184556077168376, -0.12184303442093855, -0.12508429917067557, -0.12793751131545303, -0.1302204652277701, -0.13183890429510042, -0.1328012244061189, -0.13321732407351788, -0.13328175604857148, -0.13324376259571588, -0.1333688082942488, -0.1338975456975576, -0.13500855508707416, -0.13679062578064136, -0.13922888018037502, -0.14220690823528417, -0.1455246079876728, -0.14892899894587133, -0.15215326470883556, -0.15495800099940302, -0.15716829415607347, -0.15870088901937243, -0.15957722770915603, -0.15992031818269298, -0.15993588823067278, -0.1598807113003518, -0.16002297934928045, -0.1606008365837643, -0.1617854835746113, -0.1636545664323448, -0.16617998603827394, -0.16923204031722136, -0.17259929049890488, -0.17602110957553263, -0.17922790399759544, -0.18198280309739026, -0.1841183713080423, -0.18556265488712345, -0.18635051259352198, -0.1866184472002694, -0.18658370259208884, -0.1865108261562213, -0.18667084152025618, -0.1872993306058592, -0.18855990634057154, -0.19051873789496573, -0.19313409349681496, -0.19626255213300453, -0.1996809611518204, -0.2031207793728738, -0.20630952198515137, -0.20901291283655965, -0.21107122531347522, -0.2124241764215703, -0.21312049569239713, -0.21331065155436066, -0.21322381919369496, -0.21313261402153247, -0.21331101594625015, -0.21399197642374734, -0.2153312656816163, -0.21738316895062149, -0.22009182209803943, -0.22329956771607534, -0.22677108352862418, -0.23022959194924436, -0.23339958103688227, -0.23604944496012728, -0.2380274467510734, -0.23928542116896712, -0.23988651669965022, -0.23999573453446152, -0.23985468013989658, -0.239744389272811, -0.2399419461028624, -0.24067758779576465, -0.2420989339295445, -0.24424789615073178, -0.24705387804175047, -0.25034436562562856, -0.25387132174744076, -0.25734934782258906, -0.2604997437270239, -0.2630936662399871, -0.26498770507211455, -0.2661463479843435, -0.2666478177627522, -0.2666723254481796, -0.2664745017389904, -0.2663442220558786, -0.2665618500362883, -0.26735480729814953, -0.2688621945987353, -0.2711129658116767, -0.2740210773261226, -0.2773984202687861, -0.28098359410502116, -0.28448212244242277, -0.28761192639553157, -0.29014703614333964, -0.29195276969717504, -0.29300690470532015, -0.2934035173877575, -0.29333883307779995, -0.29308121409592813, -0.29292987294514206, -0.293168660056259, -0.29402206054642505, -0.29562021867579424, -0.2979784367310753, -0.3009943752945824, -0.3044634545416511, -0.30811014138322385, -0.31163034007343543, -0.31473836733744676, -0.31721125857962507, -0.3189235367751692, -0.31986702497334635, -0.32015257684996823, -0.31999338590234433, -0.31967238356121985, -0.31949870906216926, -0.31975994532216173, -0.32067749746524365, -0.3223720342971909, -0.3248443839172807, -0.3279749049719537, -0.33154150798220877, -0.3352536152471297, -0.3387968694075689, -0.34188171503975556, -0.3442883488221988, -0.3459010636805426, -0.3467266238453247, -0.34689375626706015, -0.3466337540880748, -0.34624511156857907, -0.3460475946596808, -0.34633281087075946, -0.34731891573013085, -0.3491164873473782, -0.3517109037697411, -0.35496402759789786, -0.3586350264177933, -0.3624171944762189, -0.365985149276084, -0.3690451441877878, -0.37138072143944384, -0.37288661419496105, -0.3735855916568015, -0.3736255562305065, -0.3732572460510436, -0.3727959005515052, -0.37257274610354596, -0.37288376379959626, -0.3739436593674333, -0.3758521894430347, -0.37857812137649594, -0.38196340032386245, -0.3857469818049959, -0.38960474027829384, -0.39319935662654343, -0.39623251066114945, -0.39849130770660746, -0.39988171863323785, -0.40044378530873903, -0.4003461389560014, -0.3998605690271251, -0.399320473277756, -0.39906953648061444, -0.39940853298205226, -0.4005484822302512, -0.40257744814471363, -0.40544620096340805, -0.40897506845584414, -0.41288103515978875, -0.4168210073604739, -0.42044463479496197, -0.42344856214716925, -0.42562371504229957, -0.4268882551920485, -0.4273010156777863, -0.42705321988023476, -0.4264396378187887, -0.4258135251407265, -0.4255322277078061, -0.42590182191143894, -0.42712936096585036, -0.4292901717147279, -0.4323153611384176, -0.43600159345595363, -0.440041762117352, -0.4440719360054127, -0.44772740937358235, -0.45069922954593183, -0.45278244744313884, -0.4539085619694642, -0.4541570290598815, -0.4537439157634922, -0.4529893074597768, -0.4522683780692909, -0.4519535962573556, -0.45235696343037607, -0.453681233938088, -0.45598773673400894, -0.4591858976072912, -0.4630462341428329, -0.46723497142633647, -0.47136506429096914, -0.47505583399243484, -0.4779920381834923, -0.47997321629262624, -0.48094559419866356, -0.48101147919562, -0.4804145273358091, -0.4795029916924188, -0.4786764868575673, -0.4783243993031761, -0.4787654273042944, -0.48019762920233217, -0.48266680036696014, -0.486058217845467, -0.4901132089416176, -0.4944681646231721, -0.49871012272605986, -0.5024404333445713, -0.5053367009004994, -0.5072033882202567, -0.508003149650672, -0.5078638840038774, -0.5070602209061392, -0.5059721057240039, -0.5050267185147898, -0.5046325954419253, -0.5051161015097574, -0.5066701220646675, -0.5093230281365135, -0.5129328954841805, -0.5172080849987756, -0.5217512159819702, -0.5261199133306516, -0.5298950537650896, -0.5327459946851408, -0.5344826463643754, -0.53508619946776, -0.5347135566827684, -0.5336745494424754, -0.532385230743681, -0.5313042724380604, -0.530862177062911, -0.5313942155214584, -0.5330875233817021, -0.5359506891813207, -0.5398107583286129, -0.544338372111211, -0.5490974068041351, -0.5536116459734853, -0.5574383081992015, -0.5602370930127294, -0.5618239941279902, -0.5622013868562195, -0.56155949225058, -0.5602487100006114, -0.558726823205084, -0.5574890133407276, -0.5569913677986335, -0.5575796784220416, -0.5594346284145842, -0.562542037114895, -0.5666930360372336, -0.5715144593151337, -0.5765250501263447, -0.5812090360471838, -0.5850958443011246, -0.5878336567373712, -0.5892453280790256, -0.5893578020696106, -0.5884001732978412, -0.5867703511698222, -0.5849751504920537, -0.5835528070168883, -0.5829897418966868, -0.5836444236456005, -0.5856902210735179, -0.5890863314033203, -0.5935816185474718, -0.5987511490717686, -0.6040601403877278, -0.608945722707613, -0.6129040402297946, -0.6155692403029374, -0.616771995745054, -0.6165682299796039, -0.6152332218937726, -0.6132215771236675, -0.6110988521472922, -0.6094550860852075, -0.6088134300061308, -0.6095479910468373, -0.6118237598439168, -0.6155682304633747, -0.6204795313428406, -0.6260702860359332, -0.6317408706086118, -0.6368710909467487, -0.6409162992142567, -0.6434930913390657, -0.644441141098438, -0.6438512431284651, -0.6420547409644901, -0.6395754383545051, -0.6370509267021818, -0.6351351037314978, -0.6343967444208267, -0.6352298120821008, -0.637789604779223, -0.641965031822501, -0.6473918632579384, -0.6535055121171666, -0.6596257553825075, -0.6650607300160198, -0.6692143563519211, -0.6716805643363215, -0.6723094856869172, -0.6712348898963023, -0.6688579832965577, -0.6657893809087657, -0.6627575781535511, -0.660497581918332, -0.659636663280278, -0.6605949229634539, -0.663516360172154, -0.6682416701016688, -0.6743277150337998, -0.6811114598496095, -0.6878092273859396, -0.6936364894817945, -0.6979299611615278, -0.7002530803657411, -0.7004681890371156, -0.6987635899347935, -0.6956304330659607, -0.6917920723021013, -0.6880959541557168, -0.6853841038097073, -0.6843619029882827, -0.6854855035537624, -0.6888857320643405, -0.6943410378222883, -0.7013046849162212, -0.7089830835466665, -0.7164541624712938, -0.7228082447828953, -0.7272900307124884, -0.7294196635847967, -0.7290736230977181, -0.726511997915671, -0.7223467048866403, -0.7174542545333842, -0.7128453388874889, -0.7095104902948489, -0.7082652136034338, -0.7096186238930501, -0.7136865495649072, -0.7201636744583639, -0.7283605003865591, -0.7373010124704927, -0.7458674243292184, -0.7529707441744069, -0.7577213597424166, -0.7595731674224906, -0.758418170482298, -0.7546155234550388, -0.7489487129606608, -0.7425155135653608, -0.7365658880278962, -0.7323114823403443, -0.7307354363351017, -0.7324320158298144, -0.7375018311104692, -0.7455205849907146, -0.755588475454151, -0.7664551364943758, -0.776703172962676, -0.7849637582060598, -0.79013195578275, -0.7915484060591764, -0.7891180922145121, -0.7833456074230327, -0.7752785040819093, -0.7663641717218757, -0.7582392014225657, -0.7524812764498559, -0.7503605071372027, -0.7526286069876869, -0.7593799689485521, -0.7700089950240323, -0.7832742361368401, -0.7974639534548414, -0.8106419528814938, -0.820939364235271, -0.8268495297678704, -0.8274808037969009, -0.8227264516867743, -0.8133215904520694, -0.8007728866291928, -0.7871653693970723, -0.7748695643509842, -0.7661884028578664, -0.7629944794639866, -0.7664123332004442, -0.776596588742985, -0.7926452070797098, -0.8126691006210244, -0.8340172939313956, -0.8536336808035713, -0.8685005476428636, -0.8761085284753359, -0.8748850201394938, -0.864514794730131, -0.8460978143570991, -0.8221089535747944, -0.7961501072115632, -0.7725136977853647, -0.755604068618834, -0.749285820181966, -0.7562425048053851, -0.7774329216889733, -0.8117245792305773, -0.8557652652787175, -0.9041261305660668, -0.9497165485921211, -0.9844364190440422, -0.9999999999999998, -0.9888409828381299, -0.944994722956467, -0.8648523702792618, -0.7476935712321414, -0.5959282180822053, -0.4150106295627419, -0.21302757393415114, ], [0.0, 0.21246107935840827, 0.41395788852256415, 0.5945391693149347, 0.7461682384066118, 0.863415003952035, 0.9438653606039572, 0.9882084323016468, 0.9999999999999996, 0.9851379327294904, 0.9511179241270636, 0.906161400839487, 0.858319375921111, 0.8146550507871233, 0.7805945570254071, 0.7595113757953544, 0.7525789587637436, 0.7588920108301664, 0.7758241698986038, 0.799562523272542, 0.8257408016719997, 0.8500852308828903, 0.8689904782185258, 0.8799569935252588, 0.8818430720811812, 0.8749119296625136, 0.8606822198593859, 0.8416159715584918, 0.8206976053200078, 0.8009691421921898, 0.7850887897084006, 0.7749729343662921, 0.7715665900431066, 0.7747669580218575, 0.7835019979858705, 0.7959440397412655, 0.8098204742870806, 0.822771780101549, 0.832702935608953, 0.8380778959579288, 0.8381174167019741, 0.8328762862486199, 0.8231945217165604, 0.8105355407141459, 0.7967401086954682, 0.7837358248356942, 0.7732466675052074, 0.7665452335467513, 0.7642823048699315, 0.7664156534483555, 0.772244574649595, 0.7805408598333117, 0.7897530923664089, 0.7982512227166868, 0.8045736602368945, 0.8076401200461591, 0.8068998397484387, 0.8023954147613438, 0.7947356879041663, 0.7849848441243563, 0.7744870667943531, 0.7646550553697591, 0.7567551669578618, 0.7517214053960846, 0.7500251732410791, 0.7516185546462603, 0.7559573810373712, 0.7620982256381763, 0.7688525929893639, 0.7749734987135666, 0.7793454952804612, 0.7811494991451373, 0.7799783474405778, 0.7758870610771453, 0.7693720216917744, 0.7612840968864132, 0.7526905377800945, 0.7447077874084458, 0.7383311457979078, 0.734287055004105, 0.7329297207068785, 0.7341965768047892, 0.7376278833730608, 0.7424459570260901, 0.7476806497431497, 0.7523210308447166, 0.7554697372103731, 0.7564765918113731, 0.7550317487243975, 0.7512051755495046, 0.7454276817849345, 0.7384176396332133, 0.7310656638268862, 0.7242956128892531, 0.7189234691193045, 0.7155355267244258, 0.7144039542555339, 0.7154517719516494, 0.7182715597914836, 0.7221939882269715, 0.7263947928113422, 0.7300232104615909, 0.7323319699630387, 0.7327890738202198, 0.7311547430211726, 0.7275124908196159, 0.7222504543458137, 0.7159967310269134, 0.7095193789277773, 0.7036069122352454, 0.6989478027374912, 0.6960273231608771, 0.6950571148154371, 0.6959476256647666, 0.6983268784155308, 0.7016019298389675, 0.7050529556471199, 0.7079451035153551, 0.7096408003084862, 0.7096954129100911, 0.7079219697756797, 0.7044155847256771, 0.6995345027144381, 0.6938413349393937, 0.6880140523828633, 0.6827407610736678, 0.67861453604613, 0.6760443351284569, 0.6751953249404045, 0.6759672747523217, 0.6780137494400712, 0.6807985796310112, 0.6836804608376438, 0.6860123804734836, 0.6872405026972674, 0.6869874412085195, 0.68510744045484, 0.6817054401696006, 0.6771176131449657, 0.6718568712278415, 0.6665321269878555, 0.6617539809899836, 0.6580414049176075, 0.6557436459168497, 0.6549890695516547, 0.6556683978653004, 0.6574544569054102, 0.6598549522972063, 0.6622897977051303, 0.66418088247463, 0.6650404187521356, 0.6645443974026852, 0.6625801203909416, 0.6592608681922126, 0.6549058605965623, 0.6499889883930587, 0.6450645183666697, 0.6406813915264916, 0.6372993388946467, 0.6352196071394931, 0.6345407074250586, 0.6351456603648075, 0.6367223268159957, 0.6388133439865102, 0.6408877044840106, 0.6424227996274594, 0.6429842741570714, 0.642291513080344, 0.6402589091380284, 0.6370068658639467, 0.6328411795686874, 0.6282042888262793, 0.6236061410200973, 0.6195454580369764, 0.6164335373130413, 0.6145322109455215, 0.6139153022360985, 0.6144592326289572, 0.6158639197111719, 0.6177004660886956, 0.6194780794539638, 0.6207198000878146, 0.6210353688761711, 0.6201801205253277, 0.6180910311980032, 0.6148946279811558, 0.6108858200227947, 0.6064811655984512, 0.6021539643974578, 0.5983612734988167, 0.5954740859326276, 0.5937213228233337, 0.5931560797358504, 0.5936490876529009, 0.5949101423599124, 0.5965339722641104, 0.5980633090865943, 0.5990593594959261, 0.5991688306256766, 0.5981772847908781, 0.5960407788211673, 0.5928911344798997, 0.5890142596703875, 0.584805067118467, 0.5807060867213515, 0.5771393021398754, 0.5744416987977526, 0.5728143554119038, 0.572292758071941, 0.5727427062516567, 0.5738822294253163, 0.575325950375147, 0.5766449259222434, 0.5774326836545183, 0.5773672990025921, 0.5762600429458519, 0.5740832595104978, 0.5709733672211544, 0.5672087165292548, 0.5631658844740889, 0.5592612597796734, 0.555886970359596, 0.5533510027315833, 0.5518306477590893, 0.5513463001326516, 0.551759472652867, 0.5527951527261253, 0.5540849026713869, 0.5552239571792728, 0.5558334778291875, 0.5556183984991813, 0.5544120380393347, 0.5522007437091551, 0.5491249318960718, 0.5454565298292825, 0.541556433381244, 0.5378186294597546, 0.5346096269817087, 0.5322124998924121, 0.5307840773734539, 0.530331747424485, 0.5307132955793092, 0.5316596527662707, 0.5328169212040657, 0.5338011205617073, 0.534257192256346, 0.5339132023475481, 0.5326214759545235, 0.5303804483565598, 0.5273340080234937, 0.5237485714923388, 0.5199715363497133, 0.5163775859566053, 0.5133111391013104, 0.511033777394211, 0.5096846682194456, 0.509259964321275, 0.5096142215217572, 0.5104834886618219, 0.5115264093953916, 0.512376940452611, 0.5127005503280827, 0.5122452764640953, 0.5108798549239624, 0.5086131588246756, 0.5055920743439506, 0.502078258603107, 0.4984074527010328, 0.4949376722911438, 0.4919942654374354, 0.48982026349255914, 0.4885395954921495, 0.4881387278888638, 0.4884694427830448, 0.4892722192937761, 0.49021653326023856, 0.49095182115797453, 0.4911612547942471, 0.49061008349892143, 0.48918117348480394, 0.48689236982112855, 0.4838931141337429, 0.48044093728896836, 0.47686152186610103, 0.4734985244910965, 0.4706608844695869, 0.4685756924623357, 0.46735380392100834, 0.4669733985775676, 0.4672839179630645, 0.4680296848430205, 0.46888950149164804, 0.46952609697191844, 0.46963781527666004, 0.4690046278725667, 0.4675214575975807, 0.465213772357916, 0.4622331404720561, 0.458833513616785, 0.4553319468021106, 0.4520598283828538, 0.44931211815978045, 0.4473023644705455, 0.44613035512589483, 0.44576729719942904, 0.44606072094188937, 0.44675827908768107, 0.4475467279298556, 0.4481000709236542, 0.4481294690727931, 0.44742728142911675, 0.4458985258845552, 0.4435749987759559, 0.440609959987479, 0.4372542678540452, 0.43381767926939274, 0.430621283194451, 0.42794836845288686, 0.42600123930349976, 0.4248705565731745, 0.42452184529695, 0.4248011712742924, 0.42545905411491014, 0.4261889023195653, 0.426674050839886, 0.42663618790376945, 0.4258777698961348, 0.42431196555740375, 0.421975595943484, 0.4190231473188396, 0.4157028297271993, 0.4123183916447287, 0.4091825637219231, 0.4065692641483254, 0.40467186544795747, 0.4035738755501749, 0.4032364727741703, 0.40350474974895384, 0.4041316615041567, 0.4048159749266515, 0.40524839135666024, 0.40515878359732227, 0.40435733794290923, 0.40276334077470854, 0.40041724999524453, 0.3974742516633728, 0.3941803296621143, 0.3908345395057927, 0.3877432711635945, 0.3851734926331104, 0.3833121044944318, 0.3822375875549383, 0.38190823819118375, 0.3821687489487134, 0.38277409330727225, 0.38342703997883765, 0.383823554154665, 0.38369915413289, 0.3828691605882461, 0.38125671986654786, 0.3789043559724784, 0.37596732128499155, 0.37268978944550124, 0.36936754295747376, 0.366302856411546, 0.36375845364511955, 0.3619175463661209, 0.3608560252947567, 0.3605310171367294, 0.3607875257714559, 0.3613821241815049, 0.3620200733640094, 0.36240020887623825, 0.36226076291611703, 0.36141915650327505, 0.35979972091200857, 0.35744514796432886, 0.35450994438438255, 0.3512368995699777, 0.3479201537151376, 0.3448604835165282, 0.3423195986104782, 0.3404803862834913, 0.339419134317272, 0.3390939409197532, 0.33935106352148936, 0.33994823770431254, 0.34059142596079195, 0.34097942256191066, 0.3408495528193195, 0.3400175414824896, 0.3384055097777478, 0.3360538590079544, 0.3331152380512846, 0.3298315032895314, 0.3264971525138461, 0.32341476048798273, 0.320849154268481, 0.31898725865406447, 0.3179096876071424, 0.31757838544296596, 0.31784219959688687, 0.3184595602790503, 0.3191348594348223, 0.3195630514794178, 0.3194757532043067, 0.3186818877164735, 0.31709673249367337, 0.31475497220656196, 0.3118057613082101, 0.30849050817677254, 0.30510669500450754, 0.30196315446684174, 0.2993335186148822, 0.29741484238707433, 0.29629763966312717, 0.2959518675515728, 0.29623100888146314, 0.296893690471489, 0.2976396416513998, 0.29815463814109777, 0.29815771467485214, 0.2974435706902897, 0.2959137923990486, 0.2935921691045467, 0.29062174648429834, 0.2872439831829489, 0.2837630539391006, 0.28050057337944334, 0.27774746604555023, 0.2757201620049927, 0.27452768325611465, 0.27415459228074934, 0.2744624343770139, 0.27520956484552556, 0.276086516005151, 0.27676173307860774, 0.2769309383421668, 0.27636280489747433, 0.2749341245583544, 0.2726491689351367, 0.26964024975148515, 0.2661492471488408, 0.2624926882934329, 0.259015409006528, 0.2560395560205515, 0.2538164293156787, 0.2524883081821301, 0.2520659966546152, 0.25242556327825094, 0.25332496180785097, 0.25443830712489585, 0.25540296841786775, 0.25587271241966225, 0.2555691693313604, 0.2543240504309821, 0.2521058060864497, 0.24902660323754222, 0.24532831413204895, 0.24134924296768498, 0.23747613876898938, 0.2340882429822386, 0.23150137606828605, 0.22992018806181963, 0.22940565300893417, 0.22986281135452358, 0.23105094040264704, 0.232615152523099, 0.23413533007385445, 0.23518574371120676, 0.23539703642917492, 0.23451173754314608, 0.23242519013820842, 0.2292056522981328, 0.22509012497076497, 0.22045579572810717, 0.21577041563113533, 0.21152797417422553, 0.20817827777537368, 0.20606014653332735, 0.20534774614805232, 0.20601806471575795, 0.20784490129107455, 0.21042128345065986, 0.21320841683619157, 0.21560558961578796, 0.21703239965146967, 0.21701266026807237, 0.21524866268940082, 0.21167525343194626, 0.20648536225185915, 0.20012194998017196, 0.19323544353787545, 0.18661009121490937, 0.18106676516635029, 0.1773530434916037, 0.17603349207666222, 0.17739364866211055, 0.1813701784885679, 0.18751710598621116, 0.1950141995038267, 0.2027189204890688, 0.20925837558140592, 0.21315300489008154, 0.21295985439711343, 0.20742067904469788, 0.195599126844329, 0.17699200321352945, 0.15160204993561877, 0.1199635427176247, 0.08311689804412681, 0.042533848248869785, 8.280320273478457e-16, -0.042533848248868814, -0.08311689804412552, -0.11996354271762345, -0.15160204993561782, -0.1769920032135285, -0.19559912684432715, -0.20742067904469771, -0.21295985439711396, -0.2131530048900817, -0.20925837558140628, -0.20271892048906895, -0.19501419950382667, -0.1875171059862115, -0.1813701784885681, -0.1773936486621107, -0.17603349207666175, -0.1773530434916032, -0.18106676516635, -0.1866100912149088, -0.19323544353787547, -0.2001219499801726, -0.20648536225185918, -0.21167525343194682, -0.2152486626894009, -0.2170126602680726, -0.2170323996514707, -0.21560558961578852, -0.21320841683619224, -0.21042128345066025, -0.2078449012910753, -0.20601806471575876, -0.20534774614805254, -0.20606014653332722, -0.20817827777537332, -0.21152797417422553, -0.21577041563113486, -0.2204557957281075, -0.22509012497076467, -0.22920565229813272, -0.23242519013820767, -0.23451173754314578, -0.23539703642917492, -0.2351857437112083, -0.2341353300738542, -0.23261515252310055, -0.23105094040264781, -0.22986281135452347, -0.229405653008935, -0.22992018806181905, -0.231501376068286, -0.23408824298223763, -0.23747613876898901, -0.241349242967685, -0.24532831413204825, -0.24902660323754205, -0.2521058060864496, -0.25432405043098255, -0.25556916933135987, -0.2558727124196627, -0.2554029684178677, -0.25443830712489557, -0.25332496180785063, -0.2524255632782513, -0.25206599665461515, -0.2524883081821305, -0.25381642931567805, -0.2560395560205513, -0.2590154090065279, -0.2624926882934334, -0.26614924714884125, -0.26964024975148504, -0.27264916893513724, -0.27493412455835403, -0.2763628048974743, -0.2769309383421668, -0.27676173307860735, -0.27608651600515105, -0.2752095648455257, -0.27446243437701345, -0.2741545922807503, -0.27452768325611465, -0.2757201620049931, -0.2777474660455504, -0.280500573379444, -0.2837630539391011, -0.2872439831829489, -0.29062174648429706, -0.2935921691045466, -0.2959137923990485, -0.29744357069028987, -0.2981577146748518, -0.29815463814109827, -0.2976396416513992, -0.2968936904714886, -0.2962310088814627, -0.2959518675515729, -0.2962976396631275, -0.2974148423870744, -0.2993335186148823, -0.30196315446684235, -0.3051066950045072, -0.3084905081767725, -0.3118057613082106, -0.31475497220656246, -0.31709673249367315, -0.31868188771647354, -0.31947575320430627, -0.31956305147941827, -0.3191348594348223, -0.3184595602790506, -0.3178421995968871, -0.3175783854429658, -0.31790968760714144, -0.3189872586540652, -0.3208491542684805, -0.32341476048798296, -0.32649715251384603, -0.32983150328953176, -0.3331152380512852, -0.33605385900795454, -0.33840550977774736, -0.340017541482489, -0.34084955281931834, -0.3409794225619102, -0.3405914259607918, -0.3399482377043126, -0.33935106352148897, -0.33909394091975326, -0.33941913431727155, -0.34048038628349137, -0.3423195986104777, -0.34486048351652826, -0.3479201537151374, -0.3512368995699779, -0.35450994438438177, -0.35744514796432864, -0.35979972091200957, -0.361419156503275, -0.3622607629161166, -0.36240020887623897, -0.3620200733640096, -0.36138212418150567, -0.3607875257714551, -0.36053101713672936, -0.36085602529475713, -0.3619175463661198, -0.36375845364511966, -0.3663028564115454, -0.36936754295747415, -0.3726897894455013, -0.3759673212849902, -0.3789043559724791, -0.38125671986654774, -0.38286916058824566, -0.3836991541328897, -0.3838235541546635, -0.3834270399788379, -0.3827740933072725, -0.38216874894871344, -0.38190823819118347, -0.3822375875549394, -0.3833121044944322, -0.3851734926331104, -0.387743271163594, -0.3908345395057924, -0.39418032966211436, -0.39747425166337347, -0.4004172499952442, -0.4027633407747079, -0.4043573379429093, -0.40515878359732194, -0.4052483913566609, -0.4048159749266533, -0.4041316615041546, -0.403504749748953, -0.40323647277416913, -0.4035738755501747, -0.40467186544795813, -0.40656926414832545, -0.40918256372192235, -0.41231839164472817, -0.41570282972719885, -0.41902314731884016, -0.4219755959434833, -0.42431196555740464, -0.4258777698961358, -0.42663618790376895, -0.4266740508398859, -0.4261889023195656, -0.42545905411490953, -0.4248011712742915, -0.42452184529695, -0.4248705565731745, -0.42600123930350076, -0.4279483684528868, -0.43062128319445103, -0.433817679269392, -0.4372542678540458, -0.4406099599874787, -0.44357499877595574, -0.44589852588455564, -0.4474272814291166, -0.4481294690727926, -0.4481000709236546, -0.44754672792985484, -0.44675827908768095, -0.44606072094188803, -0.4457672971994298, -0.44613035512589516, -0.44730236447054383, -0.44931211815978156, -0.4520598283828533, -0.45533194680210876, -0.4588335136167843, -0.4622331404720562, -0.4652137723579155, -0.46752145759758, -0.4690046278725672, -0.4696378152766607, -0.469526096971918, -0.46888950149164776, -0.4680296848430207, -0.46728391796306545, -0.4669733985775675, -0.46735380392100734, -0.4685756924623361, -0.4706608844695873, -0.47349852449109614, -0.4768615218661005, -0.4804409372889686, -0.4838931141337427, -0.4868923698211289, -0.48918117348480333, -0.49061008349892077, -0.4911612547942461, -0.4909518211579745, -0.49021653326023995, -0.4892722192937746, -0.48846944278304344, -0.48813872788886425, -0.4885395954921496, -0.48982026349255675, -0.4919942654374347, -0.4949376722911436, -0.4984074527010323, -0.5020782586031068, -0.5055920743439496, -0.5086131588246778, -0.5108798549239624, -0.5122452764640939, -0.5127005503280837, -0.5123769404526114, -0.5115264093953944, -0.5104834886618216, -0.5096142215217575, -0.5092599643212737, -0.5096846682194451, -0.5110337773942105, -0.5133111391013099, -0.5163775859566055, -0.5199715363497118, -0.523748571492339, -0.5273340080234936, -0.5303804483565587, -0.5326214759545231, -0.5339132023475484, -0.5342571922563464, -0.5338011205617064, -0.5328169212040654, -0.5316596527662719, -0.5307132955793099, -0.5303317474244849, -0.5307840773734545, -0.5322124998924115, -0.5346096269817079, -0.5378186294597549, -0.5415564333812445, -0.5454565298292825, -0.5491249318960718, -0.5522007437091551, -0.5544120380393336, -0.5556183984991818, -0.5558334778291877, -0.5552239571792712, -0.5540849026713879, -0.5527951527261262, -0.5517594726528674, -0.5513463001326514, -0.5518306477590882, -0.5533510027315828, -0.5558869703595959, -0.5592612597796724, -0.5631658844740877, -0.5672087165292532, -0.5709733672211547, -0.5740832595104975, -0.5762600429458532, -0.5773672990025921, -0.5774326836545172, -0.5766449259222447, -0.5753259503751474, -0.5738822294253161, -0.5727427062516565, -0.5722927580719411, -0.5728143554119038, -0.5744416987977519, -0.5771393021398749, -0.5807060867213514, -0.5848050671184639, -0.5890142596703857, -0.5928911344798995, -0.5960407788211678, -0.5981772847908777, -0.5991688306256763, -0.5990593594959267, -0.5980633090865936, -0.5965339722641099, -0.5949101423599112, -0.5936490876529006, -0.5931560797358508, -0.5937213228233329, -0.5954740859326275, -0.5983612734988163, -0.6021539643974585, -0.6064811655984518, -0.6108858200227937, -0.6148946279811541, -0.6180910311980028, -0.6201801205253262, -0.6210353688761698, -0.6207198000878139, -0.6194780794539648, -0.6177004660886962, -0.6158639197111723, -0.614459232628956, -0.6139153022360982, -0.6145322109455228, -0.616433537313041, -0.619545458036977, -0.6236061410200965, -0.6282042888262789, -0.6328411795686876, -0.6370068658639464, -0.6402589091380289, -0.6422915130803434, -0.6429842741570706, -0.6424227996274594, -0.6408877044840108, -0.6388133439865105, -0.6367223268159968, -0.635145660364807, -0.6345407074250576, -0.6352196071394925, -0.6372993388946464, -0.6406813915264921, -0.6450645183666703, -0.6499889883930609, -0.6549058605965624, -0.6592608681922107, -0.662580120390942, -0.6645443974026846, -0.6650404187521377, -0.6641808824746303, -0.6622897977051292, -0.659854952297206, -0.657454456905411, -0.655668397865302, -0.6549890695516537, -0.6557436459168503, -0.6580414049176065, -0.6617539809899825, -0.6665321269878567, -0.671856871227842, -0.6771176131449633, -0.6817054401696007, -0.6851074404548411, -0.686987441208519, -0.6872405026972671, -0.686012380473485, -0.6836804608376407, -0.6807985796310118, -0.6780137494400709, -0.6759672747523219, -0.675195324940405, -0.6760443351284573, -0.6786145360461305, -0.6827407610736678, -0.688014052382863, -0.693841334939394, -0.6995345027144363, -0.7044155847256796, -0.7079219697756791, -0.709695412910091, -0.7096408003084863, -0.7079451035153552, -0.7050529556471176, -0.7016019298389671, -0.6983268784155312, -0.6959476256647658, -0.6950571148154373, -0.696027323160877, -0.6989478027374907, -0.7036069122352459, -0.7095193789277763, -0.7159967310269149, -0.722250454345813, -0.7275124908196153, -0.731154743021171, -0.7327890738202189, -0.7323319699630393, -0.7300232104615915, -0.7263947928113434, -0.7221939882269707, -0.7182715597914825, -0.7154517719516504, -0.714403954255534, -0.7155355267244267, -0.7189234691193044, -0.7242956128892527, -0.731065663826886, -0.7384176396332137, -0.745427681784933, -0.7512051755495053, -0.7550317487243975, -0.7564765918113749, -0.7554697372103748, -0.7523210308447157, -0.7476806497431505, -0.7424459570260904, -0.7376278833730592, -0.7341965768047883, -0.732929720706878, -0.7342870550041036, -0.738331145797908, -0.7447077874084457, -0.7526905377800941, -0.7612840968864137, -0.7693720216917734, -0.7758870610771437, -0.7799783474405783, -0.7811494991451374, -0.7793454952804594, -0.7749734987135674, -0.7688525929893653, -0.762098225638176, -0.7559573810373719, -0.7516185546462636, -0.7500251732410783, -0.7517214053960841, -0.7567551669578609, -0.7646550553697589, -0.7744870667943539, -0.7849848441243558, -0.7947356879041657, -0.8023954147613416, -0.8068998397484396, -0.8076401200461586, -0.8045736602368959, -0.798251222716684, -0.7897530923664089, -0.7805408598333122, -0.7722445746495966, -0.7664156534483565, -0.7642823048699326, -0.7665452335467515, -0.7732466675052088, -0.7837358248356961, -0.7967401086954687, -0.8105355407141447, -0.8231945217165624, -0.8328762862486201, -0.8381174167019746, -0.8380778959579284, -0.8327029356089536, -0.8227717801015505, -0.809820474287078, -0.7959440397412675, -0.7835019979858705, -0.7747669580218577, -0.7715665900431069, -0.7749729343662918, -0.7850887897084, -0.8009691421921882, -0.8206976053200054, -0.841615971558492, -0.8606822198593856, -0.8749119296625103, -0.8818430720811808, -0.8799569935252585, -0.8689904782185262, -0.8500852308828921, -0.8257408016719993, -0.799562523272541, -0.7758241698986054, -0.7588920108301674, -0.7525789587637436, -0.7595113757953552, -0.7805945570254053, -0.8146550507871191, -0.8583193759211101, -0.9061614008394852, -0.9511179241270608, -0.9851379327294911, -1.0, -0.9882084323016482, -0.9438653606039629, -0.8634150039520332, -0.7461682384066144, -0.5945391693149467, -0.4139578885225805, -0.21246107935840736, ], [0.0, 0.21161705005233378, 0.41238939285173326, 0.5924696033961551, 0.7438956206951856, 0.8612734489298135, 0.9421827056174749, 0.9872659851658325, 1.0, 0.986183129161167, 0.9532058558936685, 0.9091937824899068, 0.8621247861146785, 0.8190212071110232, 0.7853051279528793, 0.764381718833835, 0.7574854584744166, 0.7637907683856139, 0.7807564951143371, 0.8046467364023898, 0.8311519500933713, 0.8560261463786417, 0.875658870451514, 0.8875138075893554, 0.8903870600866088, 0.8844643500187288, 0.8711839506487863, 0.8529373625819825, 0.8326594025552225, 0.8133711059136781, 0.7977414554086375, 0.7877275106163889, 0.7843383143516552, 0.7875482842202218, 0.7963635985775804, 0.8090235207391877, 0.8233006194808903, 0.836851768425623, 0.8475670581662493, 0.8538666726312722, 0.8549056212093697, 0.8506612967941547, 0.8418967942836074, 0.8300111071117158, 0.8168031270508043, 0.8041876475655555, 0.7939068829096545, 0.7872798374785228, 0.7850246134158466, 0.7871767040430279, 0.793111403657805, 0.8016629336209016, 0.8113190123744477, 0.8204593367421297, 0.8276011576066465, 0.8316154214374365, 0.8318825775783915, 0.8283671179621456, 0.8216026196004841, 0.8125925511393081, 0.8026443795342192, 0.7931638080527342, 0.7854410191984699, 0.7804609746476378, 0.7787652599996022, 0.7803844696937228, 0.7848490758378824, 0.7912748207718047, 0.7985077013842836, 0.8053051708358934, 0.8105254503651056, 0.813296420000624, 0.8131393884929513, 0.810030447230143, 0.8043918962870925, 0.7970168769761734, 0.7889402486463789, 0.7812764528617206, 0.7750495228974833, 0.771040944068745, 0.7696777633299792, 0.7709767709298988, 0.7745517917749216, 0.7796814932796227, 0.7854260983545394, 0.7907743207547785, 0.7947977287420043, 0.7967891408337534, 0.7963645769728602, 0.7935142157342521, 0.7885957904446101, 0.7822726543360786, 0.7754070211030502, 0.7689254121672595, 0.7636771727699702, 0.760307535654883, 0.7591640857199148, 0.7602500704063172, 0.7632306760401977, 0.7674902877859037, 0.7722311032007693, 0.776597417790482, 0.7798063255091695, 0.7812649695369079, 0.7806568755714277, 0.7779848856597552, 0.7735649878576798, 0.7679728531024697, 0.7619520003999929, 0.7562981506958544, 0.7517376742775195, 0.7488186214922292, 0.7478306106442578, 0.7487652114012685, 0.7513221463371251, 0.7549596178320563, 0.7589804223952628, 0.7626402364494206, 0.7652613215350482, 0.7663343422769192, 0.7655930605446547, 0.763051010612398, 0.7589951820557032, 0.7539383208670811, 0.7485377000335357, 0.7434931706079586, 0.7394402524486258, 0.7368545441571036, 0.7359817809440635, 0.7368037777796779, 0.7390449125450603, 0.7422175954797248, 0.7456992849601077, 0.7488289316291651, 0.7510079543507249, 0.7517903669740014, 0.7509485268005587, 0.7485048523496328, 0.7447251457759521, 0.7400750349698414, 0.7351466239477851, 0.7305668675323082, 0.7269018116544652, 0.7245712890984256, 0.7237868915819539, 0.7245223483588982, 0.7265204119770619, 0.729334761942254, 0.7324001407320696, 0.7351197330240403, 0.7369563122494128, 0.7375132652996321, 0.7365933047393733, 0.7342262061614964, 0.7306617103121069, 0.7263290626299973, 0.721769712149055, 0.7175536947351362, 0.7141925868917492, 0.7120622958410681, 0.7113473133934382, 0.7120146756637968, 0.713821270378983, 0.7163530292614002, 0.7190897145676144, 0.7214851860709433, 0.7230507872527548, 0.7234291420490068, 0.7224472394924722, 0.7201409426887198, 0.7167474792743992, 0.7126673702263913, 0.7084018841075865, 0.7044757642831799, 0.7013571210232621, 0.6993866977547931, 0.6987271803007694, 0.699340071231195, 0.7009933913426046, 0.7032987540513238, 0.7057719065429517, 0.7079073175456652, 0.7092553407528687, 0.7094901935282861, 0.7084584908281133, 0.7062011205635284, 0.7029453626299095, 0.6990687050213962, 0.6950401017230544, 0.6913477987520082, 0.6884248207725698, 0.6865834743391851, 0.6859687591453151, 0.6865376224707861, 0.6880670056794289, 0.6901892291652434, 0.6924491158547954, 0.6943739912292803, 0.695545808539338, 0.6956644077893464, 0.6945923498500165, 0.6923746421169269, 0.6892305415549015, 0.6855188891992813, 0.6816824455774864, 0.6781798506966862, 0.6754156521784922, 0.6736790639370597, 0.6731007138030537, 0.6736338367000825, 0.675062606144352, 0.6770361498006944, 0.679122897587221, 0.6808768540234862, 0.6819056290498449, 0.6819298617476002, 0.680825049181907, 0.6786395280591478, 0.6755860252936873, 0.6720082312089988, 0.6683276398154262, 0.6649788697628608, 0.6623433810183997, 0.6606916853739074, 0.6601427951465997, 0.6606469791319273, 0.6619943127300044, 0.6638475701975343, 0.665794312089316, 0.6674101303877125, 0.6683233622878353, 0.6682714150048538, 0.6671401754413779, 0.664980599197038, 0.6620000776156227, 0.6585300317532283, 0.6549747911282608, 0.651749635570313, 0.6492174859005254, 0.6476338856384052, 0.6471086052684126, 0.6475896296626774, 0.6488718553200123, 0.6506290535154811, 0.6524641231067068, 0.6539698983771894, 0.654791208413812, 0.6546787378400715, 0.6535265297575148, 0.6513874974597705, 0.648464682211867, 0.6450796938968077, 0.6416232358151431, 0.6384953188542919, 0.6360443060151205, 0.6345140675785487, 0.6340072548672829, 0.6344702159894342, 0.6357017628416438, 0.6373843621123475, 0.6391329180487887, 0.6405536578732455, 0.6413041227591869, 0.6411451328830621, 0.6399768493890566, 0.6378535032679171, 0.634974624835357, 0.6316541887636699, 0.6282724431407237, 0.6252178080492413, 0.6228277146087913, 0.6213373874944834, 0.6208443371494838, 0.6212939147031953, 0.6224880634710048, 0.6241158674977263, 0.6258011877060832, 0.6271600919651366, 0.6278593171854537, 0.6276668690990835, 0.6264870849281872, 0.6243748665876158, 0.6215269722816988, 0.6182517471262421, 0.6149219474213724, 0.611917871737325, 0.6095694682997008, 0.608106223842785, 0.6076224367365094, 0.6080631225362372, 0.6092326533684446, 0.6108247733468176, 0.6124693864451283, 0.6137889733060833, 0.6144560435279394, 0.6142428880065217, 0.6130560809493367, 0.6109505108430163, 0.608120838229264, 0.6048717108622322, 0.6015712920986637, 0.5985951869963134, 0.5962692998529342, 0.5948203084498079, 0.5943412729097256, 0.594777589773575, 0.5959354062393282, 0.5975111961956564, 0.5991379870052956, 0.6004412011860767, 0.6010956225657942, 0.600874831813242, 0.5996856053187686, 0.597582058837641, 0.5947573952947455, 0.5915145172770052, 0.588219972958416, 0.5852482330426163, 0.5829247625696987, 0.581476533177221, 0.5809974914787648, 0.5814342292791421, 0.5825940357504394, 0.5841741153064313, 0.5858075426436636, 0.587118985233547, 0.5877817387374626, 0.5875674176475519, 0.586380753077282, 0.5842742043330313, 0.5814401494936544, 0.5781818196326568, 0.5748673666407552, 0.5718740170435298, 0.5695307750424432, 0.5680683674841509, 0.5675840356557448, 0.5680265371802594, 0.5692036641300259, 0.5708111728526605, 0.5724787729833677, 0.5738262297597855, 0.5745210886688115, 0.5743292701471765, 0.573150846128237, 0.5710355408143536, 0.5681755595685649, 0.5648767804119966, 0.5615126237901042, 0.5584675511678729, 0.556078731532904, 0.5545847143377938, 0.5540889095026255, 0.5545434536155947, 0.5557559694911035, 0.5574182662170436, 0.5591527010124346, 0.56056923597313, 0.5613245804777467, 0.561174465135102, 0.5600111018648252, 0.557880098876616, 0.5549741882545952, 0.5516046236686039, 0.5481544889300347, 0.5450209129865247, 0.5425548948637182, 0.5410078409074109, 0.540492940982513, 0.540967301801958, 0.5422376426763758, 0.5439888079180423, 0.5458308952153541, 0.5473579626491116, 0.5482094898397974, 0.548125305859473, 0.5469856344276038, 0.5448301110784145, 0.5418527713704894, 0.5383736243238794, 0.5347909682206867, 0.5315215417725451, 0.5289374860892666, 0.5273096317669504, 0.5267657314122416, 0.5272700570392931, 0.5286275976447797, 0.5305123985982328, 0.5325159347157926, 0.5342083534123143, 0.5352034277081162, 0.5352174294071897, 0.5341129751282102, 0.5319210959716874, 0.5288380145340813, 0.5251969017750229, 0.5214186647300718, 0.5179490152567362, 0.5151912035602838, 0.5134445381346803, 0.5128580330343188, 0.5134063283405279, 0.5148917380061888, 0.5169723761927884, 0.5192123801618606, 0.5211468759400436, 0.5223520347440364, 0.5225096995852913, 0.521456767456323, 0.5192117030226251, 0.5159739211258929, 0.5120958150856976, 0.5080313263105344, 0.5042685304188576, 0.501256212320433, 0.49933543014718873, 0.4986864540860892, 0.49929928354795355, 0.5009725030740543, 0.5033410347876595, 0.5059290037581863, 0.5082201016060217, 0.5097350941420694, 0.5101048961142982, 0.5091281342340287, 0.5068042879638824, 0.503337035022703, 0.49910682527433037, 0.49461631700956243, 0.49041644669363804, 0.48702394063422244, 0.48484254306546226, 0.4840998854749103, 0.4848097793700667, 0.48676606967065567, 0.4895695578863852, 0.49268456773573677, 0.495517212807396, 0.4975040177351153, 0.49819777005977073, 0.4973376346608056, 0.49489265987045966, 0.49107156896706533, 0.48629663438077647, 0.48114476683961693, 0.4762639320269263, 0.47227688416577984, 0.46968637878477915, 0.4687961354834779, 0.4696598039236907, 0.4720662943564904, 0.47556460273020085, 0.47952543507178536, 0.48323136663488686, 0.48598279052921395, 0.4872042095116739, 0.48653494872288455, 0.4838902445765281, 0.47948268028456753, 0.4737995557387346, 0.4675382129713063, 0.46150766146384514, 0.456510124409779, 0.45321954945716414, 0.45207513623580087, 0.4532063062093732, 0.45640143517706844, 0.4611266089669201, 0.4665934735740215, 0.47186794748552785, 0.47600521024594633, 0.4781919378094296, 0.47787493672394626, 0.4748564943207521, 0.4693408504490038, 0.4619227237634933, 0.45351693989774594, 0.44523679942847255, 0.43823667120677373, 0.4335402434971337, 0.4318789811793763, 0.43356505889954733, 0.4384192636748464, 0.4457674721905032, 0.4545101470315756, 0.46325905328971084, 0.4705254616688847, 0.4749358783236531, 0.4754460464418168, 0.47152246603634346, 0.46326335604134034, 0.4514376607720091, 0.4374306545916516, 0.42309672654542047, 0.4105325074611489, 0.40179498026974697, 0.3985980195927595, 0.40202566490643643, 0.41230054296451196, 0.4286410136313945, 0.44923125088452887, 0.4713156189095439, 0.4914138745650616, 0.5056387322040097, 0.5100840463189864, 0.5012420143475252, 0.4764026973577487, 0.43398955380171517, 0.37379066114687637, 0.29705624742295694, 0.20644782777076162, 0.1058409191262169, 2.2367162566654243e-15, -0.1058409191262141, -0.2064478277707581, -0.29705624742295333, -0.3737906611468738, -0.4339895538017131, -0.4764026973577457, -0.501242014347525, -0.5100840463189866, -0.5056387322040092, -0.49141387456506275, -0.47131561890954377, -0.4492312508845295, -0.428641013631395, -0.4123005429645124, -0.4020256649064369, -0.39859801959275887, -0.4017949802697461, -0.4105325074611486, -0.42309672654541947, -0.43743065459165154, -0.4514376607720091, -0.46326335604134034, -0.47152246603634446, -0.47544604644181765, -0.4749358783236533, -0.47052546166888654, -0.46325905328971184, -0.4545101470315765, -0.4457674721905035, -0.43841926367484685, -0.4335650588995482, -0.4318789811793767, -0.4335402434971337, -0.4382366712067737, -0.4452367994284727, -0.4535169398977453, -0.46192272376349347, -0.4693408504490028, -0.47485649432075167, -0.477874936723945, -0.47819193780942953, -0.4760052102459467, -0.4718679474855295, -0.4665934735740215, -0.461126608966922, -0.4564014351770694, -0.4532063062093728, -0.4520751362358015, -0.4532195494571634, -0.4565101244097789, -0.46150766146384425, -0.4675382129713065, -0.4737995557387346, -0.4794826802845669, -0.483890244576528, -0.48653494872288494, -0.48720420951167476, -0.4859827905292136, -0.483231366634887, -0.47952543507178536, -0.47556460273020074, -0.47206629435649006, -0.4696598039236909, -0.4687961354834775, -0.4696863787847797, -0.4722768841657793, -0.47626393202692674, -0.4811447668396166, -0.48629663438077697, -0.4910715689670658, -0.4948926598704598, -0.49733763466080644, -0.49819777005977073, -0.49750401773511554, -0.4955172128073959, -0.49268456773573577, -0.4895695578863848, -0.48676606967065605, -0.48480977937006653, -0.4840998854749107, -0.4848425430654621, -0.4870239406342224, -0.4904164466936385, -0.49461631700956243, -0.49910682527433076, -0.5033370350227033, -0.5068042879638812, -0.509128134234028, -0.5101048961142985, -0.5097350941420699, -0.5082201016060214, -0.5059290037581865, -0.5033410347876592, -0.5009725030740544, -0.49929928354795267, -0.49868645408608875, -0.4993354301471888, -0.5012562123204325, -0.5042685304188578, -0.5080313263105353, -0.5120958150856969, -0.515973921125892, -0.5192117030226254, -0.5214567674563242, -0.5225096995852906, -0.5223520347440367, -0.5211468759400435, -0.519212380161861, -0.5169723761927888, -0.5148917380061894, -0.5134063283405275, -0.5128580330343191, -0.5134445381346788, -0.515191203560284, -0.517949015256735, -0.5214186647300718, -0.5251969017750228, -0.5288380145340816, -0.5319210959716871, -0.5341129751282099, -0.5352174294071895, -0.5352034277081165, -0.5342083534123134, -0.5325159347157923, -0.5305123985982332, -0.5286275976447796, -0.5272700570392925, -0.5267657314122411, -0.5273096317669502, -0.5289374860892667, -0.531521541772545, -0.5347909682206858, -0.5383736243238794, -0.5418527713704901, -0.5448301110784138, -0.5469856344276035, -0.5481253058594737, -0.5482094898397977, -0.5473579626491119, -0.5458308952153547, -0.5439888079180428, -0.5422376426763764, -0.5409673018019575, -0.5404929409825125, -0.5410078409074108, -0.5425548948637167, -0.5450209129865242, -0.5481544889300337, -0.5516046236686042, -0.5549741882545947, -0.5578800988766149, -0.5600111018648266, -0.5611744651351027, -0.561324580477746, -0.5605692359731297, -0.5591527010124332, -0.557418266217044, -0.5557559694911042, -0.554543453615595, -0.5540889095026249, -0.5545847143377947, -0.5560787315329045, -0.558467551167873, -0.5615126237901042, -0.5648767804119957, -0.5681755595685644, -0.5710355408143537, -0.5731508461282366, -0.5743292701471757, -0.5745210886688115, -0.5738262297597855, -0.572478772983368, -0.5708111728526627, -0.569203664130024, -0.5680265371802584, -0.5675840356557443, -0.5680683674841503, -0.5695307750424432, -0.5718740170435294, -0.5748673666407547, -0.5781818196326568, -0.5814401494936542, -0.5842742043330322, -0.5863807530772812, -0.5875674176475532, -0.5877817387374629, -0.5871189852335471, -0.5858075426436636, -0.5841741153064315, -0.5825940357504386, -0.5814342292791415, -0.580997491478765, -0.5814765331772215, -0.5829247625696998, -0.5852482330426158, -0.5882199729584161, -0.5915145172770044, -0.5947573952947458, -0.5975820588376413, -0.5996856053187682, -0.6008748318132435, -0.601095622565794, -0.6004412011860762, -0.5991379870052964, -0.5975111961956568, -0.5959354062393285, -0.5947775897735732, -0.5943412729097266, -0.5948203084498082, -0.5962692998529325, -0.5985951869963145, -0.6015712920986627, -0.6048717108622306, -0.6081208382292634, -0.6109505108430167, -0.613056080949336, -0.6142428880065209, -0.6144560435279399, -0.6137889733060848, -0.612469386445128, -0.6108247733468168, -0.6092326533684445, -0.6080631225362382, -0.6076224367365088, -0.6081062238427839, -0.6095694682997008, -0.6119178717373263, -0.614921947421372, -0.6182517471262409, -0.6215269722816996, -0.6243748665876157, -0.6264870849281877, -0.6276668690990833, -0.6278593171854528, -0.6271600919651358, -0.6258011877060835, -0.6241158674977286, -0.6224880634710033, -0.6212939147031932, -0.620844337149484, -0.621337387494483, -0.6228277146087885, -0.6252178080492403, -0.6282724431407237, -0.6316541887636684, -0.6349746248353565, -0.6378535032679159, -0.6399768493890594, -0.6411451328830623, -0.6413041227591849, -0.6405536578732466, -0.6391329180487889, -0.6373843621123512, -0.6357017628416437, -0.6344702159894342, -0.6340072548672828, -0.6345140675785482, -0.63604430601512, -0.6384953188542914, -0.6416232358151428, -0.6450796938968071, -0.6484646822118674, -0.6513874974597703, -0.6535265297575137, -0.6546787378400709, -0.6547912084138127, -0.6539698983771902, -0.6524641231067053, -0.6506290535154807, -0.6488718553200138, -0.6475896296626783, -0.6471086052684123, -0.6476338856384062, -0.6492174859005245, -0.651749635570312, -0.6549747911282616, -0.6585300317532286, -0.6620000776156226, -0.6649805991970377, -0.6671401754413778, -0.6682714150048523, -0.6683233622878362, -0.6674101303877127, -0.6657943120893143, -0.6638475701975353, -0.6619943127300054, -0.6606469791319277, -0.6601427951465996, -0.660691685373906, -0.6623433810183997, -0.6649788697628596, -0.6683276398154249, -0.672008231208998, -0.6755860252936858, -0.6786395280591483, -0.6808250491819069, -0.6819298617476014, -0.6819056290498454, -0.6808768540234842, -0.6791228975872227, -0.6770361498006952, -0.6750626061443523, -0.6736338367000817, -0.6731007138030539, -0.6736790639370602, -0.675415652178491, -0.6781798506966857, -0.6816824455774863, -0.6855188891992782, -0.6892305415548995, -0.6923746421169262, -0.6945923498500164, -0.6956644077893457, -0.6955458085393375, -0.6943739912292811, -0.6924491158547952, -0.6901892291652432, -0.6880670056794282, -0.6865376224707863, -0.6859687591453159, -0.6865834743391842, -0.6884248207725693, -0.6913477987520072, -0.6950401017230546, -0.6990687050213966, -0.7029453626299081, -0.7062011205635266, -0.7084584908281131, -0.7094901935282846, -0.7092553407528679, -0.7079073175456652, -0.7057719065429533, -0.7032987540513245, -0.7009933913426051, -0.6993400712311935, -0.6987271803007691, -0.699386697754794, -0.7013571210232619, -0.7044757642831813, -0.7084018841075861, -0.712667370226391, -0.7167474792744, -0.7201409426887198, -0.7224472394924725, -0.723429142049006, -0.7230507872527537, -0.7214851860709429, -0.7190897145676151, -0.7163530292614009, -0.7138212703789838, -0.7120146756637962, -0.7113473133934374, -0.7120622958410682, -0.714192586891749, -0.7175536947351367, -0.721769712149055, -0.7263290626299986, -0.7306617103121069, -0.734226206161495, -0.7365933047393727, -0.7375132652996317, -0.7369563122494152, -0.7351197330240398, -0.7324001407320688, -0.729334761942254, -0.7265204119770633, -0.7245223483588986, -0.7237868915819528, -0.7245712890984254, -0.7269018116544647, -0.7305668675323067, -0.7351466239477856, -0.740075034969842, -0.7447251457759495, -0.7485048523496328, -0.7509485268005597, -0.7517903669740013, -0.7510079543507256, -0.7488289316291663, -0.7456992849601057, -0.7422175954797252, -0.7390449125450598, -0.736803777779678, -0.7359817809440641, -0.7368545441571035, -0.7394402524486264, -0.7434931706079595, -0.7485377000335349, -0.7539383208670819, -0.758995182055702, -0.7630510106123998, -0.7655930605446549, -0.7663343422769184, -0.7652613215350483, -0.762640236449421, -0.7589804223952605, -0.7549596178320551, -0.7513221463371251, -0.7487652114012675, -0.7478306106442572, -0.7488186214922284, -0.7517376742775193, -0.7562981506958547, -0.7619520003999923, -0.7679728531024705, -0.7735649878576787, -0.7779848856597538, -0.7806568755714264, -0.781264969536907, -0.7798063255091687, -0.7765974177904819, -0.7722311032007709, -0.7674902877859037, -0.7632306760401963, -0.7602500704063181, -0.7591640857199147, -0.7603075356548835, -0.7636771727699697, -0.76892541216726, -0.7754070211030503, -0.7822726543360797, -0.788595790444609, -0.7935142157342522, -0.7963645769728593, -0.7967891408337552, -0.794797728742007, -0.7907743207547785, -0.7854260983545405, -0.7796814932796227, -0.7745517917749205, -0.7709767709298972, -0.7696777633299781, -0.7710409440687438, -0.7750495228974836, -0.781276452861721, -0.7889402486463778, -0.7970168769761738, -0.8043918962870928, -0.8100304472301421, -0.8131393884929511, -0.8132964200006241, -0.8105254503651035, -0.805305170835894, -0.7985077013842838, -0.7912748207718041, -0.7848490758378824, -0.7803844696937252, -0.7787652599996014, -0.7804609746476376, -0.7854410191984692, -0.7931638080527342, -0.8026443795342203, -0.812592551139307, -0.8216026196004832, -0.828367117962144, -0.8318825775783929, -0.8316154214374359, -0.8276011576066475, -0.820459336742127, -0.8113190123744487, -0.8016629336209002, -0.793111403657807, -0.7871767040430288, -0.7850246134158482, -0.7872798374785233, -0.7939068829096562, -0.8041876475655569, -0.8168031270508046, -0.8300111071117149, -0.8418967942836094, -0.8506612967941547, -0.8549056212093695, -0.8538666726312715, -0.84756705816625, -0.8368517684256244, -0.8233006194808877, -0.8090235207391903, -0.7963635985775809, -0.7875482842202218, -0.7843383143516556, -0.7877275106163888, -0.7977414554086371, -0.8133711059136768, -0.8326594025552208, -0.8529373625819825, -0.8711839506487855, -0.8844643500187265, -0.8903870600866084, -0.887513807589355, -0.875658870451514, -0.8560261463786437, -0.8311519500933705, -0.8046467364023889, -0.7807564951143386, -0.7637907683856155, -0.7574854584744166, -0.7643817188338361, -0.7853051279528772, -0.8190212071110187, -0.8621247861146777, -0.9091937824899048, -0.9532058558936657, -0.9861831291611679, -0.9999999999999998, -0.9872659851658345, -0.9421827056174803, -0.8612734489298117, -0.7438956206951881, -0.5924696033961673, -0.41238939285174947, -0.21161705005233286, ], [0.0, 0.2102253645764875, 0.40980316435431846, 0.5890571806168241, 0.7401483941020087, 0.8577423262558713, 0.9394082444836083, 0.985712022547206, 1.0, 0.9879065109002868, 0.9566485613699223, 0.9141937528342275, 0.8683993716745754, 0.8262203840797773, 0.7930721961050453, 0.7724122286991453, 0.7655755856008604, 0.7718681297882845, 0.7888892048620724, 0.8130298879327837, 0.8400741718829137, 0.8658218794760149, 0.8866541102507768, 0.8999739298872234, 0.9044748932073085, 0.9002149460860043, 0.8884998259456154, 0.8716047421864247, 0.8523827218256037, 0.8338201977389832, 0.8186039194949284, 0.8087580108491221, 0.8053970893181136, 0.8086228913509199, 0.8175705668792695, 0.830589743150438, 0.845527480732635, 0.860067686533764, 0.8720759032090794, 0.8799001421086119, 0.8825870064531368, 0.87998627483207, 0.8727342082330453, 0.8621235736982538, 0.8498842191951242, 0.8379098233749528, 0.8279726688951533, 0.8214682796707452, 0.8192257594318859, 0.8214087530583533, 0.827517866382132, 0.8364902590447721, 0.8468781777167464, 0.8570773895901864, 0.8655702582100566, 0.8711473174096963, 0.8730755943783921, 0.8711907993999966, 0.8659024067680849, 0.8581137724969168, 0.8490718233408591, 0.8401707268384256, 0.8327399502168682, 0.82784847745306, 0.8261536161011452, 0.8278154130067801, 0.8324874122523571, 0.8393829177180638, 0.8474047993177658, 0.8553178270872446, 0.8619368057724811, 0.8663021658032564, 0.8678172756994579, 0.866328083181118, 0.8621347382589316, 0.8559351997223457, 0.8487109174527288, 0.8415730328692282, 0.8355929563284683, 0.8316429317295947, 0.8302701110319816, 0.8316221319313802, 0.8354341173820014, 0.8410776493330147, 0.8476630280840066, 0.8541783811128598, 0.8596440489051376, 0.8632588576611361, 0.8645165905759916, 0.8632758653574812, 0.8597739230154231, 0.854583398247159, 0.8485196754343896, 0.8425136667433999, 0.8374697282093413, 0.8341302739230185, 0.8329672397189859, 0.8341161563979822, 0.8373619279290915, 0.8421775074737247, 0.8478087247078059, 0.8533917274455846, 0.858084852822387, 0.8611948955620066, 0.8622789216281118, 0.861206757607406, 0.8581754278913621, 0.8536741595177427, 0.848406010039539, 0.8431785833327584, 0.8387807389736163, 0.8358640387663075, 0.8348466740984773, 0.8358539729048448, 0.8387038807440481, 0.8429389319035993, 0.8478992214019229, 0.8528248088996787, 0.8569717289734005, 0.8597239613339804, 0.8606845684126367, 0.8597325712991104, 0.8570374694829639, 0.8530297882554353, 0.8483326725237915, 0.8436653392317702, 0.8397332937755845, 0.8371220160082172, 0.8362100874016949, 0.8371146047321392, 0.839676706554968, 0.8434889040495218, 0.847959592912821, 0.8524045791297225, 0.8561515028734451, 0.8586412664997589, 0.859511206937147, 0.8586476768427552, 0.856200447099242, 0.852557181010704, 0.8482822676563448, 0.8440296277265061, 0.8404429259580106, 0.8380583809128291, 0.8372248125170928, 0.8380528172264272, 0.8404004465409093, 0.8438971844217381, 0.8480022239713154, 0.8520879085036299, 0.8555354939701831, 0.8578286535335072, 0.8586306237489431, 0.857833492416727, 0.8555724391229922, 0.8522030954105492, 0.8482457756466556, 0.8443052981455431, 0.8409787253090363, 0.8387650690994106, 0.8379905913056412, 0.8387608577741617, 0.8409465718199081, 0.8442050517233146, 0.8480338173754912, 0.8518479283445201, 0.8550691929822319, 0.8572136670265311, 0.8579642231032685, 0.8572173290561703, 0.855097152433889, 0.8519350979981289, 0.8482181241588875, 0.844513880035027, 0.8413841522598912, 0.8392997979314637, 0.8385700246574366, 0.8392966218038039, 0.8413599027697204, 0.8444382775933847, 0.8480582761637806, 0.8516672567419812, 0.8547176519033827, 0.856749927936147, 0.8574617068909, 0.8567526697370246, 0.8547385852553545, 0.8517325200475971, 0.8481962626444133, 0.8446694852300961, 0.8416874938989467, 0.8397000860377766, 0.8390037983067663, 0.8396977365193324, 0.8416695556431605, 0.8446135599650183, 0.8480780205148165, 0.8515343974666801, 0.8544578740364516, 0.8564069462090094, 0.8570900131841964, 0.8564089376896751, 0.8544730786779865, 0.8515818000461909, 0.8481782425984419, 0.8447814874953445, 0.8419074829092411, 0.8399907652839929, 0.8393188377841104, 0.8399891040779495, 0.8418947998929674, 0.8447419465866912, 0.8480946426841153, 0.8514417247304126, 0.8542746149949103, 0.8561645133210757, 0.856827229772565, 0.856165866540493, 0.8542849465672193, 0.8514739346126923, 0.848162742585522, 0.8448560503765369, 0.8420564653248709, 0.8401882074066592, 0.8395328955189217, 0.8401871445946678, 0.8420483509704618, 0.8448307529933131, 0.8481092576486651, 0.8513843190701281, 0.8541579773084159, 0.8560094947982877, 0.8566591134599025, 0.8560102806510053, 0.8541639771278061, 0.8514030241246441, 0.8481488046684488, 0.8448970149562974, 0.8421422320641467, 0.8403027655545166, 0.8396572002125879, 0.8403022469181926, 0.8421382723886958, 0.8448846702769648, 0.8481227050702117, 0.8513592937258836, 0.8541020192837141, 0.8559339755020205, 0.8565770774809803, 0.8559342332590941, 0.8541039872019184, 0.8513654289004253, 0.8481356762796948, 0.8449064037705389, 0.8421690646668303, 0.8403401695828031, 0.8396979710006903, 0.8403401695828029, 0.8421690646668308, 0.8449064037705387, 0.8481356762796946, 0.8513654289004243, 0.854103987201919, 0.8559342332590942, 0.8565770774809803, 0.8559339755020209, 0.8541020192837144, 0.8513592937258864, 0.8481227050702126, 0.844884670276965, 0.842138272388696, 0.8403022469181928, 0.8396572002125878, 0.8403027655545173, 0.8421422320641471, 0.8448970149562971, 0.8481488046684498, 0.8514030241246444, 0.854163977127806, 0.8560102806510052, 0.8566591134599018, 0.856009494798287, 0.8541579773084154, 0.851384319070128, 0.8481092576486651, 0.8448307529933139, 0.8420483509704614, 0.8401871445946681, 0.8395328955189224, 0.8401882074066593, 0.8420564653248707, 0.8448560503765361, 0.8481627425855222, 0.8514739346126916, 0.8542849465672198, 0.8561658665404926, 0.8568272297725654, 0.856164513321076, 0.8542746149949101, 0.8514417247304124, 0.8480946426841157, 0.8447419465866908, 0.8418947998929679, 0.8399891040779495, 0.839318837784111, 0.8399907652839939, 0.8419074829092416, 0.844781487495345, 0.8481782425984414, 0.8515818000461906, 0.8544730786779859, 0.8564089376896745, 0.8570900131841964, 0.8564069462090094, 0.8544578740364513, 0.8515343974666809, 0.848078020514817, 0.8446135599650192, 0.8416695556431611, 0.8396977365193319, 0.8390037983067653, 0.8397000860377772, 0.8416874938989466, 0.8446694852300962, 0.8481962626444136, 0.8517325200475971, 0.854738585255354, 0.8567526697370242, 0.8574617068909, 0.856749927936147, 0.8547176519033823, 0.8516672567419811, 0.8480582761637814, 0.8444382775933842, 0.8413599027697192, 0.8392966218038048, 0.8385700246574367, 0.8392997979314638, 0.8413841522598923, 0.844513880035026, 0.8482181241588882, 0.8519350979981284, 0.8550971524338892, 0.8572173290561702, 0.8579642231032678, 0.8572136670265307, 0.855069192982232, 0.8518479283445203, 0.848033817375492, 0.8442050517233156, 0.8409465718199075, 0.8387608577741617, 0.8379905913056417, 0.838765069099411, 0.8409787253090361, 0.8443052981455421, 0.8482457756466555, 0.8522030954105484, 0.8555724391229927, 0.8578334924167261, 0.8586306237489423, 0.8578286535335088, 0.8555354939701837, 0.8520879085036299, 0.8480022239713162, 0.8438971844217381, 0.8404004465409093, 0.8380528172264264, 0.8372248125170927, 0.8380583809128289, 0.8404429259580101, 0.8440296277265061, 0.8482822676563446, 0.8525571810107043, 0.8562004470992428, 0.8586476768427547, 0.859511206937147, 0.8586412664997589, 0.8561515028734459, 0.852404579129723, 0.8479595929128219, 0.8434889040495218, 0.8396767065549684, 0.837114604732139, 0.8362100874016951, 0.8371220160082173, 0.8397332937755851, 0.8436653392317698, 0.8483326725237923, 0.8530297882554346, 0.8570374694829636, 0.8597325712991105, 0.8606845684126356, 0.8597239613339805, 0.8569717289733998, 0.8528248088996792, 0.8478992214019229, 0.8429389319036, 0.8387038807440473, 0.8358539729048449, 0.8348466740984766, 0.8358640387663075, 0.8387807389736162, 0.843178583332758, 0.8484060100395386, 0.8536741595177431, 0.8581754278913623, 0.8612067576074057, 0.862278921628111, 0.8611948955620069, 0.8580848528223866, 0.8533917274455848, 0.8478087247078063, 0.8421775074737251, 0.8373619279290921, 0.8341161563979819, 0.8329672397189858, 0.8341302739230184, 0.8374697282093415, 0.8425136667433992, 0.8485196754343894, 0.8545833982471591, 0.8597739230154231, 0.8632758653574816, 0.864516590575992, 0.8632588576611371, 0.8596440489051376, 0.8541783811128597, 0.8476630280840063, 0.8410776493330144, 0.8354341173820015, 0.8316221319313803, 0.8302701110319815, 0.8316429317295949, 0.8355929563284693, 0.8415730328692286, 0.8487109174527282, 0.8559351997223458, 0.8621347382589316, 0.8663280831811176, 0.8678172756994572, 0.8663021658032567, 0.861936805772481, 0.8553178270872441, 0.8474047993177656, 0.839382917718064, 0.8324874122523575, 0.82781541300678, 0.8261536161011448, 0.8278484774530592, 0.8327399502168683, 0.8401707268384265, 0.8490718233408594, 0.8581137724969174, 0.8659024067680838, 0.8711907993999966, 0.8730755943783912, 0.871147317409696, 0.8655702582100574, 0.8570773895901869, 0.8468781777167457, 0.8364902590447721, 0.8275178663821319, 0.8214087530583541, 0.8192257594318861, 0.8214682796707449, 0.827972668895153, 0.8379098233749513, 0.849884219195124, 0.8621235736982527, 0.8727342082330445, 0.8799862748320696, 0.8825870064531379, 0.8799001421086116, 0.8720759032090809, 0.8600676865337638, 0.8455274807326355, 0.830589743150437, 0.8175705668792694, 0.8086228913509199, 0.8053970893181136, 0.8087580108491212, 0.8186039194949283, 0.8338201977389824, 0.8523827218256029, 0.8716047421864239, 0.8884998259456159, 0.9002149460860042, 0.9044748932073082, 0.8999739298872235, 0.8866541102507771, 0.8658218794760154, 0.8400741718829146, 0.8130298879327839, 0.7888892048620723, 0.7718681297882845, 0.7655755856008618, 0.7724122286991452, 0.7930721961050442, 0.8262203840797767, 0.8683993716745728, 0.9141937528342269, 0.9566485613699226, 0.9879065109002865, 0.9999999999999997, 0.9857120225472069, 0.9394082444836082, 0.8577423262558748, 0.7401483941020094, 0.589057180616832, 0.40980316435432007, 0.21022536457649613, 4.55943819047212e-15, -0.21022536457649021, -0.4098031643543132, -0.589057180616825, -0.7401483941020043, -0.857742326255871, -0.9394082444836043, -0.985712022547206, -0.9999999999999998, -0.9879065109002867, -0.9566485613699247, -0.9141937528342284, -0.8683993716745744, -0.8262203840797779, -0.793072196105045, -0.7724122286991455, -0.7655755856008606, -0.7718681297882835, -0.7888892048620719, -0.8130298879327822, -0.840074171882914, -0.8658218794760149, -0.8866541102507766, -0.8999739298872242, -0.9044748932073087, -0.9002149460860046, -0.8884998259456183, -0.8716047421864254, -0.852382721825604, -0.8338201977389832, -0.8186039194949289, -0.8087580108491221, -0.8053970893181137, -0.8086228913509197, -0.8175705668792691, -0.8305897431504373, -0.8455274807326344, -0.8600676865337643, -0.8720759032090794, -0.8799001421086112, -0.8825870064531364, -0.8799862748320698, -0.8727342082330449, -0.862123573698255, -0.8498842191951245, -0.837909823374954, -0.8279726688951535, -0.8214682796707441, -0.8192257594318871, -0.8214087530583529, -0.8275178663821309, -0.8364902590447708, -0.846878177716746, -0.857077389590187, -0.8655702582100563, -0.8711473174096959, -0.8730755943783919, -0.8711907993999974, -0.865902406768084, -0.8581137724969177, -0.8490718233408594, -0.8401707268384264, -0.832739950216868, -0.8278484774530599, -0.8261536161011441, -0.8278154130067812, -0.8324874122523568, -0.8393829177180645, -0.8474047993177656, -0.8553178270872449, -0.8619368057724813, -0.8663021658032563, -0.8678172756994589, -0.8663280831811174, -0.8621347382589316, -0.8559351997223454, -0.8487109174527273, -0.8415730328692282, -0.8355929563284693, -0.8316429317295948, -0.8302701110319822, -0.8316221319313796, -0.8354341173820011, -0.8410776493330147, -0.8476630280840062, -0.85417838111286, -0.859644048905138, -0.8632588576611356, -0.8645165905759911, -0.8632758653574815, -0.8597739230154235, -0.8545833982471591, -0.8485196754343904, -0.8425136667433989, -0.8374697282093414, -0.8341302739230179, -0.8329672397189858, -0.8341161563979823, -0.8373619279290908, -0.842177507473725, -0.8478087247078071, -0.8533917274455848, -0.858084852822385, -0.8611948955620075, -0.862278921628112, -0.8612067576074056, -0.858175427891363, -0.8536741595177431, -0.8484060100395389, -0.8431785833327582, -0.8387807389736169, -0.8358640387663072, -0.8348466740984767, -0.8358539729048435, -0.8387038807440479, -0.8429389319035978, -0.8478992214019226, -0.8528248088996787, -0.8569717289733998, -0.8597239613339813, -0.8606845684126355, -0.8597325712991102, -0.857037469482964, -0.8530297882554334, -0.8483326725237923, -0.84366533923177, -0.8397332937755849, -0.8371220160082167, -0.8362100874016948, -0.8371146047321387, -0.8396767065549684, -0.8434889040495213, -0.8479595929128196, -0.8524045791297231, -0.8561515028734464, -0.8586412664997585, -0.8595112069371462, -0.8586476768427558, -0.856200447099243, -0.8525571810107045, -0.8482822676563453, -0.8440296277265071, -0.8404429259580106, -0.8380583809128291, -0.837224812517092, -0.8380528172264268, -0.8404004465409081, -0.8438971844217381, -0.8480022239713151, -0.8520879085036306, -0.8555354939701835, -0.8578286535335073, -0.8586306237489435, -0.8578334924167269, -0.8555724391229922, -0.8522030954105486, -0.8482457756466545, -0.8443052981455429, -0.8409787253090367, -0.8387650690994111, -0.8379905913056411, -0.838760857774163, -0.8409465718199086, -0.8442050517233158, -0.8480338173754928, -0.8518479283445197, -0.8550691929822304, -0.8572136670265306, -0.8579642231032675, -0.8572173290561698, -0.8550971524338895, -0.8519350979981284, -0.8482181241588881, -0.8445138800350284, -0.8413841522598903, -0.8392997979314628, -0.8385700246574361, -0.8392966218038042, -0.8413599027697191, -0.8444382775933836, -0.84805827616378, -0.8516672567419804, -0.8547176519033818, -0.8567499279361483, -0.8574617068908986, -0.8567526697370256, -0.8547385852553546, -0.8517325200475971, -0.8481962626444142, -0.8446694852300964, -0.8416874938989453, -0.8397000860377761, -0.8390037983067655, -0.8396977365193321, -0.8416695556431621, -0.8446135599650185, -0.8480780205148171, -0.8515343974666797, -0.8544578740364518, -0.85640694620901, -0.8570900131841963, -0.8564089376896767, -0.8544730786779856, -0.8515818000461893, -0.8481782425984423, -0.8447814874953453, -0.8419074829092421, -0.8399907652839909, -0.8393188377841116, -0.8399891040779496, -0.8418947998929657, -0.8447419465866924, -0.8480946426841144, -0.8514417247304107, -0.8542746149949102, -0.856164513321076, -0.8568272297725652, -0.8561658665404921, -0.8542849465672204, -0.8514739346126932, -0.848162742585522, -0.8448560503765356, -0.8420564653248708, -0.8401882074066607, -0.8395328955189212, -0.840187144594667, -0.8420483509704614, -0.8448307529933147, -0.8481092576486648, -0.8513843190701266, -0.854157977308417, -0.8560094947982871, -0.8566591134599025, -0.8560102806510047, -0.8541639771278052, -0.8514030241246436, -0.84814880466845, -0.8448970149563003, -0.8421422320641452, -0.8403027655545157, -0.8396572002125884, -0.8403022469181927, -0.8421382723886929, -0.8448846702769642, -0.8481227050702126, -0.8513592937258845, -0.8541020192837143, -0.8559339755020199, -0.8565770774809844, -0.855934233259094, -0.8541039872019173, -0.8513654289004264, -0.8481356762796951, -0.8449064037705434, -0.8421690646668311, -0.8403401695828033, -0.8396979710006903, -0.8403401695828026, -0.84216906466683, -0.8449064037705387, -0.8481356762796944, -0.8513654289004241, -0.854103987201919, -0.8559342332590938, -0.8565770774809796, -0.8559339755020198, -0.854102019283715, -0.8513592937258844, -0.8481227050702101, -0.8448846702769642, -0.8421382723886979, -0.8403022469181941, -0.8396572002125875, -0.8403027655545181, -0.8421422320641454, -0.8448970149562969, -0.8481488046684502, -0.8514030241246444, -0.8541639771278069, -0.856010280651005, -0.8566591134599022, -0.8560094947982863, -0.854157977308418, -0.8513843190701291, -0.8481092576486626, -0.844830752993314, -0.8420483509704633, -0.8401871445946688, -0.8395328955189216, -0.8401882074066575, -0.8420564653248709, -0.8448560503765353, -0.8481627425855207, -0.8514739346126918, -0.8542849465672175, -0.8561658665404942, -0.856827229772564, -0.8561645133210778, -0.8542746149949119, -0.8514417247304092, -0.8480946426841173, -0.8447419465866918, -0.8418947998929679, -0.8399891040779487, -0.8393188377841111, -0.839990765283993, -0.8419074829092402, -0.8447814874953439, -0.8481782425984418, -0.8515818000461874, -0.8544730786779843, -0.8564089376896746, -0.8570900131841955, -0.8564069462090077, -0.8544578740364507, -0.8515343974666815, -0.8480780205148167, -0.844613559965018, -0.8416695556431603, -0.8396977365193324, -0.8390037983067665, -0.8397000860377755, -0.8416874938989467, -0.844669485230095, -0.8481962626444137, -0.8517325200475975, -0.8547385852553525, -0.8567526697370222, -0.8574617068908995, -0.8567499279361459, -0.8547176519033816, -0.851667256741981, -0.848058276163783, -0.8444382775933852, -0.841359902769721, -0.8392966218038032, -0.8385700246574364, -0.8392997979314641, -0.8413841522598912, -0.8445138800350281, -0.8482181241588881, -0.8519350979981292, -0.8550971524338904, -0.8572173290561704, -0.8579642231032686, -0.8572136670265308, -0.8550691929822309, -0.8518479283445197, -0.8480338173754912, -0.8442050517233156, -0.8409465718199092, -0.8387608577741617, -0.8379905913056404, -0.8387650690994107, -0.8409787253090351, -0.8443052981455439, -0.8482457756466564, -0.8522030954105505, -0.8555724391229922, -0.8578334924167259, -0.8586306237489425, -0.8578286535335071, -0.8555354939701858, -0.8520879085036285, -0.8480022239713145, -0.8438971844217379, -0.8404004465409125, -0.8380528172264272, -0.8372248125170919, -0.8380583809128279, -0.8404429259580106, -0.8440296277265048, -0.8482822676563456, -0.8525571810107048, -0.8562004470992397, -0.8586476768427552, -0.8595112069371481, -0.8586412664997592, -0.8561515028734464, -0.8524045791297229, -0.8479595929128197, -0.8434889040495229, -0.8396767065549673, -0.8371146047321396, -0.8362100874016951, -0.8371220160082168, -0.8397332937755857, -0.843665339231771, -0.848332672523791, -0.8530297882554362, -0.8570374694829627, -0.8597325712991118, -0.8606845684126374, -0.8597239613339798, -0.8569717289734012, -0.8528248088996792, -0.8478992214019212, -0.8429389319035977, -0.838703880744048, -0.8358539729048442, -0.8348466740984762, -0.8358640387663069, -0.8387807389736163, -0.8431785833327592, -0.8484060100395391, -0.8536741595177436, -0.8581754278913605, -0.8612067576074041, -0.8622789216281104, -0.8611948955620062, -0.8580848528223846, -0.8533917274455851, -0.8478087247078072, -0.8421775074737242, -0.8373619279290899, -0.834116156397983, -0.8329672397189857, -0.834130273923019, -0.837469728209341, -0.8425136667434003, -0.8485196754343892, -0.85458339824716, -0.859773923015422, -0.8632758653574814, -0.864516590575991, -0.8632588576611384, -0.8596440489051415, -0.8541783811128602, -0.8476630280840084, -0.8410776493330145, -0.8354341173820004, -0.8316221319313781, -0.8302701110319811, -0.8316429317295932, -0.8355929563284695, -0.8415730328692289, -0.848710917452728, -0.8559351997223457, -0.8621347382589315, -0.8663280831811164, -0.8678172756994573, -0.8663021658032564, -0.8619368057724789, -0.8553178270872448, -0.8474047993177661, -0.8393829177180644, -0.832487412252357, -0.8278154130067825, -0.8261536161011442, -0.8278484774530589, -0.8327399502168685, -0.8401707268384256, -0.8490718233408604, -0.858113772496916, -0.8659024067680827, -0.8711907993999956, -0.8730755943783928, -0.8711473174096955, -0.8655702582100574, -0.8570773895901842, -0.8468781777167488, -0.8364902590447707, -0.8275178663821346, -0.8214087530583535, -0.8192257594318882, -0.821468279670746, -0.8279726688951558, -0.8379098233749555, -0.8498842191951244, -0.8621235736982529, -0.8727342082330468, -0.8799862748320706, -0.8825870064531373, -0.8799001421086119, -0.8720759032090798, -0.8600676865337649, -0.845527480732632, -0.8305897431504404, -0.8175705668792688, -0.8086228913509206, -0.8053970893181144, -0.8087580108491222, -0.8186039194949284, -0.8338201977389819, -0.8523827218256013, -0.8716047421864247, -0.8884998259456158, -0.900214946086003, -0.9044748932073083, -0.8999739298872235, -0.8866541102507776, -0.8658218794760165, -0.840074171882913, -0.8130298879327824, -0.7888892048620728, -0.7718681297882852, -0.7655755856008609, -0.7724122286991462, -0.7930721961050435, -0.8262203840797736, -0.8683993716745748, -0.9141937528342268, -0.9566485613699193, -0.9879065109002869, -0.9999999999999989, -0.9857120225472081, -0.9394082444836137, -0.8577423262558698, -0.7401483941020112, -0.589057180616836, -0.40980316435433445, -0.2102253645764868, ], ];
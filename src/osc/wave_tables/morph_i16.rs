@@ -0,0 +1 @@
+pub static MORPH_I16: [[i16; 1024]; 7] = [[0, 201, 402, 603, 804, 1005, 1206, 1407, 1608, 1809, 2009, 2210, 2410, 2611, 2811, 3012, 3212, 3412, 3612, 3811, 4011, 4210, 4410, 4609, 4808, 5007, 5205, 5404, 5602, 5800, 5998, 6195, 6393, 6590, 6786, 6983, 7179, 7375, 7571, 7767, 7962, 8157, 8351, 8545, 8739, 8933, 9126, 9319, 9512, 9704, 9896, 10087, 10278, 10469, 10659, 10849, 11039, 11228, 11417, 11605, 11793, 11980, 12167, 12353, 12539, 12725, 12910, 13094, 13279, 13462, 13645, 13828, 14010, 14191, 14372, 14553, 14732, 14912, 15090, 15269, 15446, 15623, 15800, 15976, 16151, 16325, 16499, 16673, 16846, 17018, 17189, 17360, 17530, 17700, 17869, 18037, 18204, 18371, 18537, 18703, 18868, 19032, 19195, 19357, 19519, 19680, 19841, 20000, 20159, 20317, 20475, 20631, 20787, 20942, 21096, 21250, 21403, 21554, 21705, 21856, 22005, 22154, 22301, 22448, 22594, 22739, 22884, 23027, 23170, 23311, 23452, 23592, 23731, 23870, 24007, 24143, 24279, 24413, 24547, 24680, 24811, 24942, 25072, 25201, 25329, 25456, 25582, 25708, 25832, 25955, 26077, 26198, 26319, 26438, 26556, 26674, 26790, 26905, 27019, 27133, 27245, 27356, 27466, 27575, 27683, 27790, 27896, 28001, 28105, 28208, 28310, 28411, 28510, 28609, 28706, 28803, 28898, 28992, 29085, 29177, 29268, 29358, 29447, 29534, 29621, 29706, 29791, 29874, 29956, 30037, 30117, 30195, 30273, 30349, 30424, 30498, 30571, 30643, 30714, 30783, 30852, 30919, 30985, 31050, 31113, 31176, 31237, 31297, 31356, 31414, 31470, 31526, 31580, 31633, 31685, 31736, 31785, 31833, 31880, 31926, 31971, 32014, 32057, 32098, 32137, 32176, 32213, 32250, 32285, 32318, 32351, 32382, 32412, 32441, 32469, 32495, 32521, 32545, 32567, 32589, 32609, 32628, 32646, 32663, 32678, 32692, 32705, 32717, 32728, 32737, 32745, 32752, 32757, 32761, 32765, 32766, 32767, 32766, 32765, 32761, 32757, 32752, 32745, 32737, 32728, 32717, 32705, 32692, 32678, 32663, 32646, 32628, 32609, 32589, 32567, 32545, 32521, 32495, 32469, 32441, 32412, 32382, 32351, 32318, 32285, 32250, 32213, 32176, 32137, 32098, 32057, 32014, 31971, 31926, 31880, 31833, 31785, 31736, 31685, 31633, 31580, 31526, 31470, 31414, 31356, 31297, 31237, 31176, 31113, 31050, 30985, 30919, 30852, 30783, 30714, 30643, 30571, 30498, 30424, 30349, 30273, 30195, 30117, 30037, 29956, 29874, 29791, 29706, 29621, 29534, 29447, 29358, 29268, 29177, 29085, 28992, 28898, 28803, 28706, 28609, 28510, 28411, 28310, 28208, 28105, 28001, 27896, 27790, 27683, 27575, 27466, 27356, 27245, 27133, 27019, 26905, 26790, 26674, 26556, 26438, 26319, 26198, 26077, 25955, 25832, 25708, 25582, 25456, 25329, 25201, 25072, 24942, 24811, 24680, 24547, 24413, 24279, 24143, 24007, 23870, 23731, 23592, 23452, 23311, 23170, 23027, 22884, 22739, 22594, 22448, 22301, 22154, 22005, 21856, 21705, 21554, 21403, 21250, 21096, 20942, 20787, 20631, 20475, 20317, 20159, 20000, 19841, 19680, 19519, 19357, 19195, 19032, 18868, 18703, 18537, 18371, 18204, 18037, 17869, 17700, 17530, 17360, 17189, 17018, 16846, 16673, 16499, 16325, 16151, 15976, 15800, 15623, 15446, 15269, 15090, 14912, 14732, 14553, 14372, 14191, 14010, 13828, 13645, 13462, 13279, 13094, 12910, 12725, 12539, 12353, 12167, 11980, 11793, 11605, 11417, 11228, 11039, 10849, 10659, 10469, 10278, 10087, 9896, 9704, 9512, 9319, 9126, 8933, 8739, 8545, 8351, 8157, 7962, 7767, 7571, 7375, 7179, 6983, 6786, 6590, 6393, 6195, 5998, 5800, 5602, 5404, 5205, 5007, 4808, 4609, 4410, 4210, 4011, 3811, 3612, 3412, 3212, 3012, 2811, 2611, 2410, 2210, 2009, 1809, 1608, 1407, 1206, 1005, 804, 603, 402, 201, 0, -201, -402, -603, -804, -1005, -1206, -1407, -1608, -1809, -2009, -2210, -2410, -2611, -2811, -3012, -3212, -3412, -3612, -3811, -4011, -4210, -4410, -4609, -4808, -5007, -5205, -5404, -5602, -5800, -5998, -6195, -6393, -6590, -6786, -6983, -7179, -7375, -7571, -7767, -7962, -8157, -8351, -8545, -8739, -8933, -9126, -9319, -9512, -9704, -9896, -10087, -10278, -10469, -10659, -10849, -11039, -11228, -11417, -11605, -11793, -11980, -12167, -12353, -12539, -12725, -12910, -13094, -13279, -13462, -13645, -13828, -14010, -14191, -14372, -14553, -14732, -14912, -15090, -15269, -15446, -15623, -15800, -15976, -16151, -16325, -16499, -16673, -16846, -17018, -17189, -17360, -17530, -17700, -17869, -18037, -18204, -18371, -18537, -18703, -18868, -19032, -19195, -19357, -19519, -19680, -19841, -20000, -20159, -20317, -20475, -20631, -20787, -20942, -21096, -21250, -21403, -21554, -21705, -21856, -22005, -22154, -22301, -22448, -22594, -22739, -22884, -23027, -23170, -23311, -23452, -23592, -23731, -23870, -24007, -24143, -24279, -24413, -24547, -24680, -24811, -24942, -25072, -25201, -25329, -25456, -25582, -25708, -25832, -25955, -26077, -26198, -26319, -26438, -26556, -26674, -26790, -26905, -27019, -27133, -27245, -27356, -27466, -27575, -27683, -27790, -27896, -28001, -28105, -28208, -28310, -28411, -28510, -28609, -28706, -28803, -28898, -28992, -29085, -29177, -29268, -29358, -29447, -29534, -29621, -29706, -29791, -29874, -29956, -30037, -30117, -30195, -30273, -30349, -30424, -30498, -30571, -30643, -30714, -30783, -30852, -30919, -30985, -31050, -31113, -31176, -31237, -31297, -31356, -31414, -31470, -31526, -31580, -31633, -31685, -31736, -31785, -31833, -31880, -31926, -31971, -32014, -32057, -32098, -32137, -32176, -32213, -32250, -32285, -32318, -32351, -32382, -32412, -32441, -32469, -32495, -32521, -32545, -32567, -32589, -32609, -32628, -32646, -32663, -32678, -32692, -32705, -32717, -32728, -32737, -32745, -32752, -32757, -32761, -32765, -32766, -32767, -32766, -32765, -32761, -32757, -32752, -32745, -32737, -32728, -32717, -32705, -32692, -32678, -32663, -32646, -32628, -32609, -32589, -32567, -32545, -32521, -32495, -32469, -32441, -32412, -32382, -32351, -32318, -32285, -32250, -32213, -32176, -32137, -32098, -32057, -32014, -31971, -31926, -31880, -31833, -31785, -31736, -31685, -31633, -31580, -31526, -31470, -31414, -31356, -31297, -31237, -31176, -31113, -31050, -30985, -30919, -30852, -30783, -30714, -30643, -30571, -30498, -30424, -30349, -30273, -30195, -30117, -30037, -29956, -29874, -29791, -29706, -29621, -29534, -29447, -29358, -29268, -29177, -29085, -28992, -28898, -28803, -28706, -28609, -28510, -28411, -28310, -28208, -28105, -28001, -27896, -27790, -27683, -27575, -27466, -27356, -27245, -27133, -27019, -26905, -26790, -26674, -26556, -26438, -26319, -26198, -26077, -25955, -25832, -25708, -25582, -25456, -25329, -25201, -25072, -24942, -24811, -24680, -24547, -24413, -24279, -24143, -24007, -23870, -23731, -23592, -23452, -23311, -23170, -23027, -22884, -22739, -22594, -22448, -22301, -22154, -22005, -21856, -21705, -21554, -21403, -21250, -21096, -20942, -20787, -20631, -20475, -20317, -20159, -20000, -19841, -19680, -19519, -19357, -19195, -19032, -18868, -18703, -18537, -18371, -18204, -18037, -17869, -17700, -17530, -17360, -17189, -17018, -16846, -16673, -16499, -16325, -16151, -15976, -15800, -15623, -15446, -15269, -15090, -14912, -14732, -14553, -14372, -14191, -14010, -13828, -13645, -13462, -13279, -13094, -12910, -12725, -12539, -12353, -12167, -11980, -11793, -11605, -11417, -11228, -11039, -10849, -10659, -10469, -10278, -10087, -9896, -9704, -9512, -9319, -9126, -8933, -8739, -8545, -8351, -8157, -7962, -7767, -7571, -7375, -7179, -6983, -6786, -6590, -6393, -6195, -5998, -5800, -5602, -5404, -5205, -5007, -4808, -4609, -4410, -4210, -4011, -3811, -3612, -3412, -3212, -3012, -2811, -2611, -2410, -2210, -2009, -1809, -1608, -1407, -1206, -1005, -804, -603, -402, -201, ], [0, 527, 1053, 1579, 2105, 2630, 3154, 3678, 4200, 4721, 5241, 5759, 6276, 6791, 7304, 7815, 8324, 8831, 9335, 9836, 10335, 10831, 11324, 11813, 12300, 12783, 13262, 13738, 14210, 14678, 15142, 15601, 16057, 16508, 16954, 17396, 17833, 18266, 18693, 19115, 19532, 19944, 20351, 20752, 21147, 21537, 21921, 22299, 22672, 23038, 23398, 23752, 24100, 24442, 24777, 25106, 25428, 25744, 26053, 26356, 26651, 26941, 27223, 27498, 27767, 28028, 28283, 28531, 28771, 29005, 29232, 29451, 29663, 29869, 30067, 30258, 30442, 30618, 30788, 30951, 31106, 31254, 31395, 31529, 31656, 31776, 31889, 31995, 32093, 32185, 32270, 32348, 32420, 32484, 32542, 32593, 32637, 32675, 32706, 32731, 32749, 32761, 32767, 32766, 32760, 32747, 32728, 32703, 32672, 32636, 32593, 32545, 32492, 32433, 32369, 32299, 32224, 32144, 32059, 31969, 31875, 31775, 31671, 31562, 31449, 31332, 31211, 31085, 30955, 30822, 30684, 30543, 30399, 30250, 30099, 29944, 29787, 29626, 29462, 29295, 29126, 28954, 28780, 28604, 28425, 28244, 28061, 27876, 27689, 27501, 27311, 27120, 26928, 26734, 26539, 26343, 26146, 25949, 25750, 25552, 25352, 25153, 24953, 24753, 24553, 24353, 24153, 23954, 23754, 23556, 23357, 23160, 22963, 22767, 22572, 22378, 22185, 21993, 21802, 21613, 21425, 21239, 21054, 20870, 20689, 20509, 20331, 20155, 19981, 19810, 19640, 19472, 19307, 19143, 18983, 18824, 18668, 18514, 18363, 18215, 18069, 17925, 17785, 17647, 17512, 17379, 17249, 17123, 16998, 16877, 16759, 16643, 16531, 16421, 16315, 16211, 16110, 16012, 15917, 15825, 15736, 15650, 15567, 15486, 15409, 15334, 15262, 15194, 15128, 15065, 15004, 14947, 14892, 14840, 14790, 14743, 14699, 14658, 14619, 14582, 14548, 14516, 14487, 14460, 14436, 14414, 14394, 14376, 14360, 14346, 14334, 14325, 14317, 14311, 14307, 14304, 14303, 14304, 14306, 14310, 14315, 14322, 14330, 14339, 14350, 14361, 14374, 14387, 14402, 14417, 14433, 14450, 14468, 14486, 14504, 14523, 14543, 14563, 14583, 14603, 14624, 14644, 14664, 14685, 14705, 14725, 14745, 14765, 14784, 14802, 14821, 14838, 14855, 14872, 14887, 14902, 14916, 14929, 14941, 14952, 14962, 14970, 14978, 14985, 14990, 14993, 14996, 14997, 14996, 14994, 14991, 14986, 14979, 14971, 14961, 14949, 14936, 14920, 14903, 14884, 14863, 14840, 14816, 14789, 14760, 14730, 14697, 14662, 14625, 14587, 14546, 14503, 14458, 14411, 14361, 14310, 14256, 14201, 14143, 14083, 14021, 13957, 13891, 13823, 13752, 13680, 13605, 13529, 13450, 13370, 13287, 13203, 13116, 13028, 12937, 12845, 12751, 12655, 12557, 12458, 12356, 12253, 12149, 12042, 11934, 11825, 11714, 11601, 11487, 11372, 11255, 11137, 11018, 10897, 10775, 10652, 10528, 10403, 10276, 10149, 10021, 9892, 9762, 9632, 9500, 9368, 9236, 9103, 8969, 8835, 8700, 8565, 8430, 8295, 8159, 8023, 7887, 7752, 7616, 7480, 7344, 7209, 7073, 6938, 6803, 6669, 6535, 6402, 6269, 6136, 6005, 5874, 5743, 5614, 5485, 5357, 5230, 5104, 4979, 4855, 4732, 4611, 4490, 4371, 4252, 4136, 4020, 3906, 3793, 3681, 3571, 3463, 3356, 3250, 3147, 3044, 2944, 2845, 2747, 2651, 2557, 2465, 2375, 2286, 2199, 2113, 2030, 1948, 1868, 1790, 1714, 1640, 1568, 1497, 1428, 1361, 1296, 1233, 1171, 1112, 1054, 998, 944, 892, 841, 792, 745, 700, 656, 615, 574, 536, 499, 464, 430, 398, 368, 339, 312, 286, 261, 238, 216, 195, 176, 158, 142, 126, 112, 99, 86, 75, 65, 56, 48, 40, 34, 28, 23, 18, 14, 11, 8, 6, 4, 3, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0, -1, -1, -2, -3, -4, -6, -8, -11, -14, -18, -23, -28, -34, -40, -48, -56, -65, -75, -86, -99, -112, -126, -142, -158, -176, -195, -216, -238, -261, -286, -312, -339, -368, -398, -430, -464, -499, -536, -574, -615, -656, -700, -745, -792, -841, -892, -944, -998, -1054, -1112, -1171, -1233, -1296, -1361, -1428, -1497, -1568, -1640, -1714, -1790, -1868, -1948, -2030, -2113, -2199, -2286, -2375, -2465, -2557, -2651, -2747, -2845, -2944, -3044, -3147, -3250, -3356, -3463, -3571, -3681, -3793, -3906, -4020, -4136, -4252, -4371, -4490, -4611, -4732, -4855, -4979, -5104, -5230, -5357, -5485, -5614, -5743, -5874, -6005, -6136, -6269, -6402, -6535, -6669, -6803, -6938, -7073, -7209, -7344, -7480, -7616, -7752, -7887, -8023, -8159, -8295, -8430, -8565, -8700, -8835, -8969, -9103, -9236, -9368, -9500, -9632, -9762, -9892, -10021, -10149, -10276, -10403, -10528, -10652, -10775, -10897, -11018, -11137, -11255, -11372, -11487, -11601, -11714, -11825, -11934, -12042, -12149, -12253, -12356, -12458, -12557, -12655, -12751, -12845, -12937, -13028, -13116, -13203, -13287, -13370, -13450, -13529, -13605, -13680, -13752, -13823, -13891, -13957, -14021, -14083, -14143, -14201, -14256, -14310, -14361, -14411, -14458, -14503, -14546, -14587, -14625, -14662, -14697, -14730, -14760, -14789, -14816, -14840, -14863, -14884, -14903, -14920, -14936, -14949, -14961, -14971, -14979, -14986, -14991, -14994, -14996, -14997, -14996, -14993, -14990, -14985, -14978, -14970, -14962, -14952, -14941, -14929, -14916, -14902, -14887, -14872, -14855, -14838, -14821, -14802, -14784, -14765, -14745, -14725, -14705, -14685, -14664, -14644, -14624, -14603, -14583, -14563, -14543, -14523, -14504, -14486, -14468, -14450, -14433, -14417, -14402, -14387, -14374, -14361, -14350, -14339, -14330, -14322, -14315, -14310, -14306, -14304, -14303, -14304, -14307, -14311, -14317, -14325, -14334, -14346, -14360, -14376, -14394, -14414, -14436, -14460, -14487, -14516, -14548, -14582, -14619, -14658, -14699, -14743, -14790, -14840, -14892, -14947, -15004, -15065, -15128, -15194, -15262, -15334, -15409, -15486, -15567, -15650, -15736, -15825, -15917, -16012, -16110, -16211, -16315, -16421, -16531, -16643, -16759, -16877, -16998, -17123, -17249, -17379, -17512, -17647, -17785, -17925, -18069, -18215, -18363, -18514, -18668, -18824, -18983, -19143, -19307, -19472, -19640, -19810, -19981, -20155, -20331, -20509, -20689, -20870, -21054, -21239, -21425, -21613, -21802, -21993, -22185, -22378, -22572, -22767, -22963, -23160, -23357, -23556, -23754, -23954, -24153, -24353, -24553, -24753, -24953, -25153, -25352, -25552, -25750, -25949, -26146, -26343, -26539, -26734, -26928, -27120, -27311, -27501, -27689, -27876, -28061, -28244, -28425, -28604, -28780, -28954, -29126, -29295, -29462, -29626, -29787, -29944, -30099, -30250, -30399, -30543, -30684, -30822, -30955, -31085, -31211, -31332, -31449, -31562, -31671, -31775, -31875, -31969, -32059, -32144, -32224, -32299, -32369, -32433, -32492, -32545, -32593, -32636, -32672, -32703, -32728, -32747, -32760, -32766, -32767, -32761, -32749, -32731, -32706, -32675, -32637, -32593, -32542, -32484, -32420, -32348, -32270, -32185, -32093, -31995, -31889, -31776, -31656, -31529, -31395, -31254, -31106, -30951, -30788, -30618, -30442, -30258, -30067, -29869, -29663, -29451, -29232, -29005, -28771, -28531, -28283, -28028, -27767, -27498, -27223, -26941, -26651, -26356, -26053, -25744, -25428, -25106, -24777, -24442, -24100, -23752, -23398, -23038, -22672, -22299, -21921, -21537, -21147, -20752, -20351, -19944, -19532, -19115, -18693, -18266, -17833, -17396, -16954, -16508, -16057, -15601, -15142, -14678, -14210, -13738, -13262, -12783, -12300, -11813, -11324, -10831, -10335, -9836, -9335, -8831, -8324, -7815, -7304, -6791, -6276, -5759, -5241, -4721, -4200, -3678, -3154, -2630, -2105, -1579, -1053, -527, ], [0, 1828, 3650, 5459, 7249, 9013, 10746, 12442, 14095, 15700, 17251, 18745, 20176, 21541, 22835, 24057, 25202, 26269, 27256, 28160, 28983, 29721, 30377, 30950, 31441, 31851, 32183, 32437, 32618, 32726, 32767, 32743, 32658, 32516, 32321, 32078, 31791, 31465, 31104, 30715, 30300, 29865, 29416, 28956, 28489, 28021, 27556, 27097, 26648, 26213, 25795, 25396, 25021, 24670, 24346, 24051, 23786, 23552, 23350, 23180, 23042, 22936, 22861, 22818, 22803, 22817, 22858, 22923, 23011, 23120, 23248, 23391, 23549, 23717, 23894, 24077, 24263, 24450, 24636, 24817, 24992, 25158, 25314, 25457, 25586, 25699, 25795, 25873, 25932, 25970, 25988, 25985, 25961, 25916, 25850, 25765, 25660, 25536, 25395, 25238, 25066, 24881, 24683, 24476, 24259, 24036, 23808, 23577, 23345, 23113, 22883, 22657, 22437, 22224, 22019, 21825, 21641, 21470, 21312, 21169, 21040, 20926, 20827, 20745, 20678, 20627, 20591, 20569, 20563, 20569, 20589, 20620, 20661, 20713, 20772, 20839, 20911, 20987, 21066, 21147, 21227, 21306, 21382, 21455, 21521, 21582, 21634, 21678, 21712, 21736, 21749, 21750, 21739, 21715, 21679, 21630, 21569, 21495, 21408, 21311, 21202, 21082, 20953, 20815, 20669, 20516, 20357, 20193, 20026, 19856, 19684, 19513, 19342, 19174, 19009, 18848, 18692, 18543, 18401, 18267, 18141, 18025, 17918, 17822, 17736, 17661, 17597, 17543, 17500, 17467, 17444, 17431, 17426, 17430, 17442, 17461, 17486, 17516, 17550, 17587, 17627, 17668, 17710, 17750, 17789, 17826, 17858, 17886, 17909, 17926, 17936, 17938, 17933, 17919, 17896, 17864, 17823, 17773, 17713, 17644, 17566, 17479, 17383, 17280, 17169, 17052, 16928, 16798, 16665, 16527, 16386, 16243, 16099, 15955, 15811, 15668, 15528, 15391, 15258, 15129, 15006, 14889, 14778, 14675, 14579, 14491, 14411, 14339, 14276, 14221, 14174, 14135, 14105, 14082, 14066, 14057, 14054, 14056, 14064, 14076, 14092, 14110, 14130, 14152, 14173, 14195, 14215, 14233, 14248, 14260, 14268, 14271, 14269, 14260, 14245, 14224, 14195, 14159, 14115, 14064, 14005, 13939, 13865, 13784, 13695, 13601, 13500, 13393, 13281, 13164, 13044, 12920, 12794, 12665, 12536, 12406, 12276, 12148, 12021, 11897, 11776, 11659, 11546, 11438, 11336, 11239, 11149, 11065, 10988, 10918, 10855, 10800, 10751, 10709, 10674, 10646, 10623, 10607, 10596, 10589, 10587, 10589, 10594, 10601, 10611, 10621, 10632, 10642, 10652, 10660, 10665, 10668, 10667, 10662, 10653, 10638, 10618, 10593, 10560, 10522, 10477, 10426, 10367, 10302, 10231, 10153, 10069, 9979, 9884, 9784, 9678, 9569, 9457, 9341, 9223, 9103, 8982, 8860, 8739, 8619, 8500, 8383, 8269, 8158, 8051, 7949, 7851, 7759, 7671, 7590, 7515, 7446, 7383, 7327, 7277, 7233, 7196, 7164, 7138, 7117, 7101, 7089, 7082, 7078, 7076, 7077, 7080, 7084, 7088, 7093, 7096, 7098, 7098, 7096, 7090, 7081, 7067, 7049, 7026, 6998, 6964, 6924, 6879, 6827, 6769, 6705, 6635, 6560, 6478, 6391, 6299, 6203, 6102, 5997, 5888, 5777, 5664, 5549, 5433, 5316, 5199, 5084, 4969, 4856, 4746, 4639, 4535, 4436, 4340, 4250, 4164, 4084, 4010, 3942, 3879, 3822, 3771, 3726, 3687, 3654, 3625, 3602, 3583, 3569, 3558, 3550, 3546, 3544, 3543, 3543, 3544, 3545, 3545, 3544, 3542, 3536, 3528, 3517, 3501, 3482, 3457, 3428, 3393, 3353, 3308, 3256, 3199, 3135, 3066, 2992, 2911, 2826, 2736, 2641, 2542, 2439, 2333, 2224, 2113, 2000, 1886, 1772, 1657, 1544, 1431, 1320, 1212, 1106, 1003, 905, 810, 721, 636, 556, 482, 413, 351, 293, 242, 197, 157, 122, 93, 68, 48, 33, 21, 12, 6, 3, 1, 0, 0, 0, -1, -3, -6, -12, -21, -33, -48, -68, -93, -122, -157, -197, -242, -293, -351, -413, -482, -556, -636, -721, -810, -905, -1003, -1106, -1212, -1320, -1431, -1544, -1657, -1772, -1886, -2000, -2113, -2224, -2333, -2439, -2542, -2641, -2736, -2826, -2911, -2992, -3066, -3135, -3199, -3256, -3308, -3353, -3393, -3428, -3457, -3482, -3501, -3517, -3528, -3536, -3542, -3544, -3545, -3545, -3544, -3543, -3543, -3544, -3546, -3550, -3558, -3569, -3583, -3602, -3625, -3654, -3687, -3726, -3771, -3822, -3879, -3942, -4010, -4084, -4164, -4250, -4340, -4436, -4535, -4639, -4746, -4856, -4969, -5084, -5199, -5316, -5433, -5549, -5664, -5777, -5888, -5997, -6102, -6203, -6299, -6391, -6478, -6560, -6635, -6705, -6769, -6827, -6879, -6924, -6964, -6998, -7026, -7049, -7067, -7081, -7090, -7096, -7098, -7098, -7096, -7093, -7088, -7084, -7080, -7077, -7076, -7078, -7082, -7089, -7101, -7117, -7138, -7164, -7196, -7233, -7277, -7327, -7383, -7446, -7515, -7590, -7671, -7759, -7851, -7949, -8051, -8158, -8269, -8383, -8500, -8619, -8739, -8860, -8982, -9103, -9223, -9341, -9457, -9569, -9678, -9784, -9884, -9979, -10069, -10153, -10231, -10302, -10367, -10426, -10477, -10522, -10560, -10593, -10618, -10638, -10653, -10662, -10667, -10668, -10665, -10660, -10652, -10642, -10632, -10621, -10611, -10601, -10594, -10589, -10587, -10589, -10596, -10607, -10623, -10646, -10674, -10709, -10751, -10800, -10855, -10918, -10988, -11065, -11149, -11239, -11336, -11438, -11546, -11659, -11776, -11897, -12021, -12148, -12276, -12406, -12536, -12665, -12794, -12920, -13044, -13164, -13281, -13393, -13500, -13601, -13695, -13784, -13865, -13939, -14005, -14064, -14115, -14159, -14195, -14224, -14245, -14260, -14269, -14271, -14268, -14260, -14248, -14233, -14215, -14195, -14173, -14152, -14130, -14110, -14092, -14076, -14064, -14056, -14054, -14057, -14066, -14082, -14105, -14135, -14174, -14221, -14276, -14339, -14411, -14491, -14579, -14675, -14778, -14889, -15006, -15129, -15258, -15391, -15528, -15668, -15811, -15955, -16099, -16243, -16386, -16527, -16665, -16798, -16928, -17052, -17169, -17280, -17383, -17479, -17566, -17644, -17713, -17773, -17823, -17864, -17896, -17919, -17933, -17938, -17936, -17926, -17909, -17886, -17858, -17826, -17789, -17750, -17710, -17668, -17627, -17587, -17550, -17516, -17486, -17461, -17442, -17430, -17426, -17431, -17444, -17467, -17500, -17543, -17597, -17661, -17736, -17822, -17918, -18025, -18141, -18267, -18401, -18543, -18692, -18848, -19009, -19174, -19342, -19513, -19684, -19856, -20026, -20193, -20357, -20516, -20669, -20815, -20953, -21082, -21202, -21311, -21408, -21495, -21569, -21630, -21679, -21715, -21739, -21750, -21749, -21736, -21712, -21678, -21634, -21582, -21521, -21455, -21382, -21306, -21227, -21147, -21066, -20987, -20911, -20839, -20772, -20713, -20661, -20620, -20589, -20569, -20563, -20569, -20591, -20627, -20678, -20745, -20827, -20926, -21040, -21169, -21312, -21470, -21641, -21825, -22019, -22224, -22437, -22657, -22883, -23113, -23345, -23577, -23808, -24036, -24259, -24476, -24683, -24881, -25066, -25238, -25395, -25536, -25660, -25765, -25850, -25916, -25961, -25985, -25988, -25970, -25932, -25873, -25795, -25699, -25586, -25457, -25314, -25158, -24992, -24817, -24636, -24450, -24263, -24077, -23894, -23717, -23549, -23391, -23248, -23120, -23011, -22923, -22858, -22817, -22803, -22818, -22861, -22936, -23042, -23180, -23350, -23552, -23786, -24051, -24346, -24670, -25021, -25396, -25795, -26213, -26648, -27097, -27556, -28021, -28489, -28956, -29416, -29865, -30300, -30715, -31104, -31465, -31791, -32078, -32321, -32516, -32658, -32743, -32767, -32726, -32618, -32437, -32183, -31851, -31441, -30950, -30377, -29721, -28983, -28160, -27256, -26269, -25202, -24057, -22835, -21541, -20176, -18745, -17251, -15700, -14095, -12442, -10746, -9013, -7249, -5459, -3650, -1828, ], [0, 6980, 13599, 19527, 24500, 28339, 30965, 32401, 32767, 32257, 31119, 29626, 28041, 26598, 25474, 24780, 24552, 24759, 25313, 26087, 26938, 27724, 28328, 28667, 28707, 28458, 27971, 27328, 26629, 25973, 25447, 25113, 25001, 25106, 25390, 25793, 26239, 26650, 26958, 27114, 27093, 26900, 26562, 26131, 25666, 25231, 24883, 24661, 24587, 24657, 24845, 25111, 25404, 25668, 25857, 25937, 25890, 25721, 25450, 25114, 24758, 24428, 24166, 24000, 23944, 23996, 24135, 24330, 24541, 24726, 24851, 24889, 24828, 24673, 24440, 24159, 23866, 23598, 23385, 23252, 23208, 23249, 23358, 23509, 23669, 23806, 23890, 23901, 23831, 23684, 23476, 23231, 22980, 22751, 22573, 22461, 22424, 22458, 22547, 22668, 22794, 22896, 22952, 22945, 22869, 22728, 22537, 22318, 22096, 21896, 21741, 21646, 21614, 21643, 21717, 21816, 21916, 21994, 22030, 22009, 21928, 21792, 21614, 21413, 21213, 21035, 20898, 20815, 20787, 20811, 20874, 20957, 21038, 21097, 21116, 21085, 21001, 20868, 20700, 20514, 20331, 20170, 20048, 19973, 19949, 19970, 20024, 20093, 20159, 20203, 20210, 20170, 20083, 19953, 19793, 19619, 19450, 19303, 19191, 19124, 19103, 19121, 19168, 19227, 19280, 19311, 19308, 19262, 19172, 19044, 18891, 18727, 18569, 18433, 18331, 18270, 18251, 18267, 18308, 18358, 18401, 18422, 18409, 18357, 18266, 18140, 17992, 17836, 17688, 17561, 17468, 17412, 17395, 17409, 17445, 17487, 17521, 17533, 17513, 17456, 17363, 17239, 17096, 16947, 16807, 16689, 16602, 16551, 16535, 16548, 16579, 16615, 16641, 16646, 16620, 16558, 16463, 16341, 16202, 16060, 15927, 15816, 15735, 15688, 15673, 15685, 15712, 15742, 15761, 15759, 15727, 15662, 15566, 15445, 15310, 15173, 15046, 14941, 14866, 14822, 14809, 14819, 14843, 14868, 14881, 14873, 14836, 14768, 14671, 14551, 14419, 14286, 14166, 14067, 13996, 13956, 13943, 13953, 13973, 13993, 14001, 13988, 13946, 13875, 13777, 13658, 13529, 13401, 13285, 13191, 13125, 13087, 13076, 13085, 13102, 13118, 13121, 13103, 13057, 12983, 12884, 12766, 12640, 12516, 12405, 12316, 12253, 12218, 12208, 12215, 12231, 12243, 12241, 12218, 12169, 12093, 11992, 11875, 11751, 11631, 11525, 11439, 11381, 11348, 11339, 11345, 11358, 11367, 11361, 11334, 11281, 11202, 11101, 10985, 10864, 10747, 10644, 10563, 10508, 10478, 10469, 10475, 10485, 10490, 10481, 10450, 10394, 10313, 10211, 10096, 9976, 9863, 9764, 9687, 9634, 9606, 9598, 9603, 9612, 9614, 9601, 9566, 9507, 9424, 9322, 9207, 9090, 8979, 8884, 8810, 8760, 8734, 8727, 8732, 8738, 8737, 8721, 8683, 8621, 8536, 8433, 8319, 8203, 8095, 8003, 7933, 7886, 7862, 7856, 7859, 7864, 7860, 7841, 7799, 7735, 7648, 7544, 7431, 7317, 7212, 7123, 7056, 7012, 6990, 6984, 6987, 6990, 6983, 6961, 6916, 6849, 6760, 6656, 6543, 6431, 6328, 6243, 6179, 6137, 6117, 6111, 6114, 6115, 6106, 6080, 6033, 5963, 5873, 5768, 5656, 5545, 5445, 5362, 5301, 5262, 5243, 5239, 5241, 5240, 5229, 5200, 5150, 5078, 4986, 4880, 4768, 4660, 4562, 4482, 4424, 4387, 4370, 4366, 4367, 4365, 4351, 4320, 4267, 4192, 4099, 3992, 3881, 3774, 3679, 3602, 3546, 3512, 3497, 3493, 3494, 3490, 3474, 3440, 3384, 3307, 3212, 3105, 2995, 2889, 2796, 2722, 2669, 2637, 2623, 2620, 2620, 2615, 2597, 2560, 2501, 2422, 2325, 2218, 2108, 2004, 1913, 1842, 1791, 1762, 1749, 1747, 1746, 1740, 1719, 1679, 1618, 1537, 1438, 1331, 1221, 1119, 1030, 961, 914, 886, 875, 873, 872, 864, 841, 799, 735, 651, 552, 444, 335, 234, 147, 81, 36, 11, 1, 0, -1, -11, -36, -81, -147, -234, -335, -444, -552, -651, -735, -799, -841, -864, -872, -873, -875, -886, -914, -961, -1030, -1119, -1221, -1331, -1438, -1537, -1618, -1679, -1719, -1740, -1746, -1747, -1749, -1762, -1791, -1842, -1913, -2004, -2108, -2218, -2325, -2422, -2501, -2560, -2597, -2615, -2620, -2620, -2623, -2637, -2669, -2722, -2796, -2889, -2995, -3105, -3212, -3307, -3384, -3440, -3474, -3490, -3494, -3493, -3497, -3512, -3546, -3602, -3679, -3774, -3881, -3992, -4099, -4192, -4267, -4320, -4351, -4365, -4367, -4366, -4370, -4387, -4424, -4482, -4562, -4660, -4768, -4880, -4986, -5078, -5150, -5200, -5229, -5240, -5241, -5239, -5243, -5262, -5301, -5362, -5445, -5545, -5656, -5768, -5873, -5963, -6033, -6080, -6106, -6115, -6114, -6111, -6117, -6137, -6179, -6243, -6328, -6431, -6543, -6656, -6760, -6849, -6916, -6961, -6983, -6990, -6987, -6984, -6990, -7012, -7056, -7123, -7212, -7317, -7431, -7544, -7648, -7735, -7799, -7841, -7860, -7864, -7859, -7856, -7862, -7886, -7933, -8003, -8095, -8203, -8319, -8433, -8536, -8621, -8683, -8721, -8737, -8738, -8732, -8727, -8734, -8760, -8810, -8884, -8979, -9090, -9207, -9322, -9424, -9507, -9566, -9601, -9614, -9612, -9603, -9598, -9606, -9634, -9687, -9764, -9863, -9976, -10096, -10211, -10313, -10394, -10450, -10481, -10490, -10485, -10475, -10469, -10478, -10508, -10563, -10644, -10747, -10864, -10985, -11101, -11202, -11281, -11334, -11361, -11367, -11358, -11345, -11339, -11348, -11381, -11439, -11525, -11631, -11751, -11875, -11992, -12093, -12169, -12218, -12241, -12243, -12231, -12215, -12208, -12218, -12253, -12316, -12405, -12516, -12640, -12766, -12884, -12983, -13057, -13103, -13121, -13118, -13102, -13085, -13076, -13087, -13125, -13191, -13285, -13401, -13529, -13658, -13777, -13875, -13946, -13988, -14001, -13993, -13973, -13953, -13943, -13956, -13996, -14067, -14166, -14286, -14419, -14551, -14671, -14768, -14836, -14873, -14881, -14868, -14843, -14819, -14809, -14822, -14866, -14941, -15046, -15173, -15310, -15445, -15566, -15662, -15727, -15759, -15761, -15742, -15712, -15685, -15673, -15688, -15735, -15816, -15927, -16060, -16202, -16341, -16463, -16558, -16620, -16646, -16641, -16615, -16579, -16548, -16535, -16551, -16602, -16689, -16807, -16947, -17096, -17239, -17363, -17456, -17513, -17533, -17521, -17487, -17445, -17409, -17395, -17412, -17468, -17561, -17688, -17836, -17992, -18140, -18266, -18357, -18409, -18422, -18401, -18358, -18308, -18267, -18251, -18270, -18331, -18433, -18569, -18727, -18891, -19044, -19172, -19262, -19308, -19311, -19280, -19227, -19168, -19121, -19103, -19124, -19191, -19303, -19450, -19619, -19793, -19953, -20083, -20170, -20210, -20203, -20159, -20093, -20024, -19970, -19949, -19973, -20048, -20170, -20331, -20514, -20700, -20868, -21001, -21085, -21116, -21097, -21038, -20957, -20874, -20811, -20787, -20815, -20898, -21035, -21213, -21413, -21614, -21792, -21928, -22009, -22030, -21994, -21916, -21816, -21717, -21643, -21614, -21646, -21741, -21896, -22096, -22318, -22537, -22728, -22869, -22945, -22952, -22896, -22794, -22668, -22547, -22458, -22424, -22461, -22573, -22751, -22980, -23231, -23476, -23684, -23831, -23901, -23890, -23806, -23669, -23509, -23358, -23249, -23208, -23252, -23385, -23598, -23866, -24159, -24440, -24673, -24828, -24889, -24851, -24726, -24541, -24330, -24135, -23996, -23944, -24000, -24166, -24428, -24758, -25114, -25450, -25721, -25890, -25937, -25857, -25668, -25404, -25111, -24845, -24657, -24587, -24661, -24883, -25231, -25666, -26131, -26562, -26900, -27093, -27114, -26958, -26650, -26239, -25793, -25390, -25106, -25001, -25113, -25447, -25973, -26629, -27328, -27971, -28458, -28707, -28667, -28328, -27724, -26938, -26087, -25313, -24759, -24552, -24780, -25474, -26598, -28041, -29626, -31119, -32257, -32767, -32401, -30965, -28339, -24500, -19527, -13599, -6980, ], [0, 6962, 13564, 19481, 24450, 28292, 30928, 32381, 32767, 32280, 31165, 29692, 28125, 26694, 25578, 24887, 24660, 24867, 25421, 26199, 27057, 27855, 28474, 28834, 28895, 28668, 28202, 27577, 26892, 26245, 25725, 25394, 25282, 25387, 25673, 26081, 26535, 26960, 27285, 27461, 27463, 27291, 26974, 26559, 26107, 25681, 25337, 25117, 25043, 25113, 25304, 25576, 25878, 26156, 26363, 26464, 26440, 26292, 26041, 25722, 25378, 25055, 24797, 24632, 24576, 24628, 24770, 24972, 25193, 25394, 25537, 25596, 25558, 25423, 25210, 24945, 24663, 24402, 24193, 24060, 24016, 24057, 24170, 24328, 24499, 24651, 24754, 24787, 24740, 24615, 24425, 24196, 23955, 23733, 23557, 23446, 23409, 23443, 23536, 23664, 23802, 23921, 23996, 24011, 23958, 23838, 23666, 23461, 23249, 23055, 22902, 22807, 22775, 22804, 22882, 22989, 23102, 23197, 23253, 23255, 23196, 23082, 22922, 22735, 22544, 22371, 22236, 22152, 22124, 22149, 22216, 22308, 22402, 22479, 22519, 22511, 22449, 22337, 22187, 22015, 21840, 21684, 21562, 21487, 21462, 21484, 21543, 21621, 21701, 21763, 21791, 21775, 21711, 21602, 21459, 21298, 21137, 20993, 20882, 20814, 20792, 20812, 20863, 20932, 21000, 21050, 21069, 21046, 20979, 20873, 20736, 20584, 20434, 20301, 20199, 20136, 20116, 20134, 20180, 20240, 20298, 20339, 20349, 20321, 20253, 20148, 20017, 19873, 19731, 19607, 19512, 19454, 19436, 19452, 19493, 19547, 19597, 19629, 19633, 19600, 19530, 19427, 19300, 19162, 19028, 18911, 18823, 18769, 18752, 18767, 18804, 18852, 18895, 18921, 18919, 18882, 18811, 18709, 18586, 18453, 18325, 18215, 18132, 18082, 18066, 18080, 18113, 18156, 18193, 18213, 18206, 18166, 18094, 17993, 17873, 17745, 17623, 17518, 17439, 17392, 17377, 17390, 17421, 17459, 17491, 17506, 17495, 17452, 17379, 17279, 17162, 17038, 16920, 16820, 16745, 16701, 16687, 16699, 16727, 16761, 16789, 16800, 16785, 16740, 16666, 16567, 16452, 16331, 16218, 16121, 16050, 16008, 15995, 16006, 16032, 16063, 16087, 16094, 16076, 16029, 15954, 15856, 15743, 15625, 15515, 15422, 15354, 15314, 15301, 15311, 15336, 15364, 15385, 15389, 15368, 15319, 15244, 15146, 15035, 14920, 14813, 14723, 14657, 14618, 14606, 14616, 14639, 14665, 14683, 14684, 14661, 14611, 14535, 14437, 14328, 14215, 14110, 14023, 13959, 13922, 13910, 13919, 13941, 13965, 13981, 13980, 13955, 13903, 13827, 13730, 13621, 13510, 13408, 13322, 13260, 13224, 13213, 13222, 13242, 13265, 13279, 13276, 13250, 13197, 13120, 13024, 12916, 12806, 12705, 12621, 12560, 12525, 12514, 12523, 12542, 12564, 12577, 12573, 12545, 12493, 12416, 12319, 12212, 12103, 12003, 11919, 11859, 11824, 11814, 11822, 11841, 11862, 11875, 11870, 11843, 11790, 11712, 11616, 11509, 11400, 11300, 11217, 11157, 11122, 11111, 11120, 11139, 11160, 11173, 11169, 11141, 11089, 11011, 10915, 10808, 10698, 10597, 10513, 10452, 10417, 10406, 10415, 10435, 10457, 10471, 10468, 10442, 10390, 10314, 10217, 10108, 9997, 9894, 9808, 9745, 9709, 9697, 9707, 9728, 9753, 9770, 9770, 9746, 9696, 9620, 9523, 9412, 9298, 9191, 9101, 9035, 8995, 8983, 8993, 9018, 9047, 9069, 9074, 9056, 9009, 8934, 8835, 8721, 8601, 8487, 8390, 8317, 8273, 8259, 8271, 8301, 8337, 8369, 8384, 8374, 8333, 8261, 8160, 8039, 7908, 7781, 7670, 7586, 7534, 7517, 7532, 7571, 7622, 7672, 7706, 7713, 7684, 7616, 7510, 7376, 7224, 7070, 6931, 6821, 6752, 6729, 6751, 6810, 6895, 6986, 7065, 7112, 7111, 7053, 6936, 6766, 6557, 6332, 6115, 5933, 5811, 5768, 5813, 5943, 6144, 6390, 6642, 6857, 6984, 6978, 6797, 6409, 5799, 4968, 3931, 2723, 1394, 0, -1394, -2723, -3931, -4968, -5799, -6409, -6797, -6978, -6984, -6857, -6642, -6390, -6144, -5943, -5813, -5768, -5811, -5933, -6115, -6332, -6557, -6766, -6936, -7053, -7111, -7112, -7065, -6986, -6895, -6810, -6751, -6729, -6752, -6821, -6931, -7070, -7224, -7376, -7510, -7616, -7684, -7713, -7706, -7672, -7622, -7571, -7532, -7517, -7534, -7586, -7670, -7781, -7908, -8039, -8160, -8261, -8333, -8374, -8384, -8369, -8337, -8301, -8271, -8259, -8273, -8317, -8390, -8487, -8601, -8721, -8835, -8934, -9009, -9056, -9074, -9069, -9047, -9018, -8993, -8983, -8995, -9035, -9101, -9191, -9298, -9412, -9523, -9620, -9696, -9746, -9770, -9770, -9753, -9728, -9707, -9697, -9709, -9745, -9808, -9894, -9997, -10108, -10217, -10314, -10390, -10442, -10468, -10471, -10457, -10435, -10415, -10406, -10417, -10452, -10513, -10597, -10698, -10808, -10915, -11011, -11089, -11141, -11169, -11173, -11160, -11139, -11120, -11111, -11122, -11157, -11217, -11300, -11400, -11509, -11616, -11712, -11790, -11843, -11870, -11875, -11862, -11841, -11822, -11814, -11824, -11859, -11919, -12003, -12103, -12212, -12319, -12416, -12493, -12545, -12573, -12577, -12564, -12542, -12523, -12514, -12525, -12560, -12621, -12705, -12806, -12916, -13024, -13120, -13197, -13250, -13276, -13279, -13265, -13242, -13222, -13213, -13224, -13260, -13322, -13408, -13510, -13621, -13730, -13827, -13903, -13955, -13980, -13981, -13965, -13941, -13919, -13910, -13922, -13959, -14023, -14110, -14215, -14328, -14437, -14535, -14611, -14661, -14684, -14683, -14665, -14639, -14616, -14606, -14618, -14657, -14723, -14813, -14920, -15035, -15146, -15244, -15319, -15368, -15389, -15385, -15364, -15336, -15311, -15301, -15314, -15354, -15422, -15515, -15625, -15743, -15856, -15954, -16029, -16076, -16094, -16087, -16063, -16032, -16006, -15995, -16008, -16050, -16121, -16218, -16331, -16452, -16567, -16666, -16740, -16785, -16800, -16789, -16761, -16727, -16699, -16687, -16701, -16745, -16820, -16920, -17038, -17162, -17279, -17379, -17452, -17495, -17506, -17491, -17459, -17421, -17390, -17377, -17392, -17439, -17518, -17623, -17745, -17873, -17993, -18094, -18166, -18206, -18213, -18193, -18156, -18113, -18080, -18066, -18082, -18132, -18215, -18325, -18453, -18586, -18709, -18811, -18882, -18919, -18921, -18895, -18852, -18804, -18767, -18752, -18769, -18823, -18911, -19028, -19162, -19300, -19427, -19530, -19600, -19633, -19629, -19597, -19547, -19493, -19452, -19436, -19454, -19512, -19607, -19731, -19873, -20017, -20148, -20253, -20321, -20349, -20339, -20298, -20240, -20180, -20134, -20116, -20136, -20199, -20301, -20434, -20584, -20736, -20873, -20979, -21046, -21069, -21050, -21000, -20932, -20863, -20812, -20792, -20814, -20882, -20993, -21137, -21298, -21459, -21602, -21711, -21775, -21791, -21763, -21701, -21621, -21543, -21484, -21462, -21487, -21562, -21684, -21840, -22015, -22187, -22337, -22449, -22511, -22519, -22479, -22402, -22308, -22216, -22149, -22124, -22152, -22236, -22371, -22544, -22735, -22922, -23082, -23196, -23255, -23253, -23197, -23102, -22989, -22882, -22804, -22775, -22807, -22902, -23055, -23249, -23461, -23666, -23838, -23958, -24011, -23996, -23921, -23802, -23664, -23536, -23443, -23409, -23446, -23557, -23733, -23955, -24196, -24425, -24615, -24740, -24787, -24754, -24651, -24499, -24328, -24170, -24057, -24016, -24060, -24193, -24402, -24663, -24945, -25210, -25423, -25558, -25596, -25537, -25394, -25193, -24972, -24770, -24628, -24576, -24632, -24797, -25055, -25378, -25722, -26041, -26292, -26440, -26464, -26363, -26156, -25878, -25576, -25304, -25113, -25043, -25117, -25337, -25681, -26107, -26559, -26974, -27291, -27463, -27461, -27285, -26960, -26535, -26081, -25673, -25387, -25282, -25394, -25725, -26245, -26892, -27577, -28202, -28668, -28895, -28834, -28474, -27855, -27057, -26199, -25421, -24867, -24660, -24887, -25578, -26694, -28125, -29692, -31165, -32280, -32767, -32381, -30928, -28292, -24450, -19481, -13564, -6962, ], [0, 6934, 13513, 19413, 24375, 28221, 30873, 32350, 32767, 32314, 31234, 29792, 28249, 26837, 25732, 25046, 24821, 25027, 25583, 26366, 27234, 28049, 28693, 29081, 29175, 28981, 28546, 27948, 27284, 26652, 26140, 25811, 25700, 25806, 26094, 26509, 26977, 27421, 27772, 27979, 28013, 27874, 27586, 27197, 26764, 26351, 26014, 25797, 25723, 25793, 25988, 26268, 26584, 26884, 27118, 27250, 27258, 27143, 26921, 26626, 26300, 25990, 25737, 25573, 25518, 25571, 25717, 25928, 26165, 26387, 26558, 26649, 26644, 26542, 26358, 26116, 25851, 25600, 25396, 25265, 25220, 25263, 25380, 25548, 25736, 25911, 26043, 26108, 26094, 26001, 25840, 25633, 25408, 25195, 25023, 24913, 24876, 24911, 25009, 25148, 25304, 25447, 25552, 25600, 25580, 25492, 25347, 25164, 24967, 24782, 24632, 24537, 24504, 24535, 24619, 24738, 24870, 24989, 25075, 25110, 25086, 25003, 24870, 24704, 24527, 24362, 24229, 24145, 24116, 24143, 24216, 24320, 24434, 24537, 24608, 24634, 24606, 24526, 24402, 24250, 24089, 23938, 23818, 23742, 23716, 23740, 23806, 23898, 23999, 24088, 24148, 24166, 24136, 24058, 23942, 23800, 23650, 23512, 23402, 23332, 23309, 23331, 23390, 23473, 23562, 23641, 23692, 23705, 23672, 23597, 23486, 23352, 23212, 23084, 22981, 22917, 22895, 22915, 22969, 23045, 23126, 23196, 23240, 23248, 23214, 23140, 23033, 22906, 22774, 22653, 22558, 22497, 22477, 22496, 22546, 22615, 22689, 22753, 22791, 22795, 22760, 22687, 22584, 22462, 22337, 22222, 22131, 22074, 22055, 22073, 22120, 22184, 22253, 22310, 22344, 22345, 22309, 22237, 22137, 22020, 21899, 21789, 21703, 21649, 21631, 21647, 21692, 21752, 21816, 21869, 21899, 21897, 21860, 21789, 21692, 21578, 21462, 21356, 21273, 21221, 21204, 21220, 21262, 21319, 21379, 21429, 21456, 21452, 21414, 21344, 21248, 21137, 21024, 20922, 20841, 20791, 20775, 20790, 20830, 20885, 20942, 20989, 21014, 21008, 20970, 20901, 20806, 20697, 20587, 20487, 20408, 20359, 20343, 20358, 20397, 20450, 20506, 20550, 20573, 20567, 20528, 20459, 20366, 20258, 20149, 20051, 19974, 19926, 19910, 19924, 19963, 20015, 20069, 20112, 20134, 20127, 20088, 20019, 19926, 19820, 19712, 19614, 19538, 19490, 19475, 19489, 19527, 19579, 19632, 19675, 19696, 19689, 19650, 19581, 19488, 19382, 19274, 19177, 19101, 19053, 19038, 19052, 19090, 19142, 19195, 19238, 19260, 19253, 19214, 19145, 19052, 18945, 18837, 18739, 18662, 18614, 18598, 18613, 18651, 18704, 18758, 18803, 18825, 18819, 18780, 18711, 18617, 18509, 18399, 18299, 18221, 18172, 18156, 18171, 18210, 18265, 18322, 18368, 18393, 18388, 18350, 18280, 18185, 18074, 17961, 17859, 17778, 17727, 17710, 17726, 17768, 17825, 17885, 17935, 17963, 17960, 17923, 17852, 17755, 17641, 17523, 17416, 17332, 17278, 17261, 17277, 17322, 17383, 17449, 17504, 17537, 17537, 17501, 17429, 17328, 17209, 17085, 16972, 16881, 16824, 16805, 16823, 16871, 16940, 17013, 17076, 17116, 17121, 17087, 17013, 16907, 16780, 16647, 16523, 16425, 16362, 16340, 16361, 16415, 16493, 16578, 16653, 16702, 16715, 16683, 16606, 16493, 16354, 16207, 16069, 15958, 15887, 15863, 15886, 15950, 16042, 16144, 16237, 16302, 16324, 16296, 16216, 16091, 15934, 15766, 15606, 15475, 15390, 15361, 15389, 15468, 15583, 15713, 15834, 15924, 15964, 15942, 15856, 15711, 15525, 15320, 15122, 14958, 14851, 14813, 14850, 14955, 15110, 15289, 15462, 15597, 15669, 15659, 15560, 15379, 15136, 14860, 14589, 14360, 14206, 14151, 14207, 14366, 14606, 14893, 15180, 15418, 15562, 15579, 15450, 15180, 14792, 14333, 13864, 13452, 13166, 13061, 13173, 13510, 14045, 14720, 15444, 16102, 16568, 16714, 16424, 15610, 14221, 12248, 9734, 6765, 3468, 0, -3468, -6765, -9734, -12248, -14221, -15610, -16424, -16714, -16568, -16102, -15444, -14720, -14045, -13510, -13173, -13061, -13166, -13452, -13864, -14333, -14792, -15180, -15450, -15579, -15562, -15418, -15180, -14893, -14606, -14366, -14207, -14151, -14206, -14360, -14589, -14860, -15136, -15379, -15560, -15659, -15669, -15597, -15462, -15289, -15110, -14955, -14850, -14813, -14851, -14958, -15122, -15320, -15525, -15711, -15856, -15942, -15964, -15924, -15834, -15713, -15583, -15468, -15389, -15361, -15390, -15475, -15606, -15766, -15934, -16091, -16216, -16296, -16324, -16302, -16237, -16144, -16042, -15950, -15886, -15863, -15887, -15958, -16069, -16207, -16354, -16493, -16606, -16683, -16715, -16702, -16653, -16578, -16493, -16415, -16361, -16340, -16362, -16425, -16523, -16647, -16780, -16907, -17013, -17087, -17121, -17116, -17076, -17013, -16940, -16871, -16823, -16805, -16824, -16881, -16972, -17085, -17209, -17328, -17429, -17501, -17537, -17537, -17504, -17449, -17383, -17322, -17277, -17261, -17278, -17332, -17416, -17523, -17641, -17755, -17852, -17923, -17960, -17963, -17935, -17885, -17825, -17768, -17726, -17710, -17727, -17778, -17859, -17961, -18074, -18185, -18280, -18350, -18388, -18393, -18368, -18322, -18265, -18210, -18171, -18156, -18172, -18221, -18299, -18399, -18509, -18617, -18711, -18780, -18819, -18825, -18803, -18758, -18704, -18651, -18613, -18598, -18614, -18662, -18739, -18837, -18945, -19052, -19145, -19214, -19253, -19260, -19238, -19195, -19142, -19090, -19052, -19038, -19053, -19101, -19177, -19274, -19382, -19488, -19581, -19650, -19689, -19696, -19675, -19632, -19579, -19527, -19489, -19475, -19490, -19538, -19614, -19712, -19820, -19926, -20019, -20088, -20127, -20134, -20112, -20069, -20015, -19963, -19924, -19910, -19926, -19974, -20051, -20149, -20258, -20366, -20459, -20528, -20567, -20573, -20550, -20506, -20450, -20397, -20358, -20343, -20359, -20408, -20487, -20587, -20697, -20806, -20901, -20970, -21008, -21014, -20989, -20942, -20885, -20830, -20790, -20775, -20791, -20841, -20922, -21024, -21137, -21248, -21344, -21414, -21452, -21456, -21429, -21379, -21319, -21262, -21220, -21204, -21221, -21273, -21356, -21462, -21578, -21692, -21789, -21860, -21897, -21899, -21869, -21816, -21752, -21692, -21647, -21631, -21649, -21703, -21789, -21899, -22020, -22137, -22237, -22309, -22345, -22344, -22310, -22253, -22184, -22120, -22073, -22055, -22074, -22131, -22222, -22337, -22462, -22584, -22687, -22760, -22795, -22791, -22753, -22689, -22615, -22546, -22496, -22477, -22497, -22558, -22653, -22774, -22906, -23033, -23140, -23214, -23248, -23240, -23196, -23126, -23045, -22969, -22915, -22895, -22917, -22981, -23084, -23212, -23352, -23486, -23597, -23672, -23705, -23692, -23641, -23562, -23473, -23390, -23331, -23309, -23332, -23402, -23512, -23650, -23800, -23942, -24058, -24136, -24166, -24148, -24088, -23999, -23898, -23806, -23740, -23716, -23742, -23818, -23938, -24089, -24250, -24402, -24526, -24606, -24634, -24608, -24537, -24434, -24320, -24216, -24143, -24116, -24145, -24229, -24362, -24527, -24704, -24870, -25003, -25086, -25110, -25075, -24989, -24870, -24738, -24619, -24535, -24504, -24537, -24632, -24782, -24967, -25164, -25347, -25492, -25580, -25600, -25552, -25447, -25304, -25148, -25009, -24911, -24876, -24913, -25023, -25195, -25408, -25633, -25840, -26001, -26094, -26108, -26043, -25911, -25736, -25548, -25380, -25263, -25220, -25265, -25396, -25600, -25851, -26116, -26358, -26542, -26644, -26649, -26558, -26387, -26165, -25928, -25717, -25571, -25518, -25573, -25737, -25990, -26300, -26626, -26921, -27143, -27258, -27250, -27118, -26884, -26584, -26268, -25988, -25793, -25723, -25797, -26014, -26351, -26764, -27197, -27586, -27874, -28013, -27979, -27772, -27421, -26977, -26509, -26094, -25806, -25700, -25811, -26140, -26652, -27284, -27948, -28546, -28981, -29175, -29081, -28693, -28049, -27234, -26366, -25583, -25027, -24821, -25046, -25732, -26837, -28249, -29792, -31234, -32314, -32767, -32350, -30873, -28221, -24375, -19413, -13513, -6934, ], [0, 6888, 13428, 19302, 24252, 28106, 30782, 32299, 32767, 32371, 31347, 29955, 28455, 27073, 25987, 25310, 25086, 25292, 25850, 26641, 27527, 28370, 29053, 29489, 29637, 29497, 29113, 28560, 27930, 27322, 26823, 26501, 26390, 26496, 26789, 27216, 27705, 28182, 28575, 28832, 28920, 28835, 28597, 28249, 27848, 27456, 27130, 26917, 26844, 26915, 27115, 27409, 27750, 28084, 28362, 28545, 28608, 28546, 28373, 28118, 27822, 27530, 27286, 27126, 27071, 27125, 27278, 27504, 27767, 28026, 28243, 28386, 28436, 28387, 28250, 28046, 27810, 27576, 27380, 27250, 27205, 27250, 27375, 27560, 27775, 27989, 28168, 28286, 28328, 28287, 28172, 28002, 27803, 27607, 27441, 27332, 27294, 27331, 27438, 27596, 27780, 27963, 28117, 28219, 28254, 28219, 28120, 27972, 27800, 27628, 27484, 27389, 27355, 27388, 27482, 27621, 27783, 27945, 28080, 28171, 28202, 28171, 28083, 27951, 27797, 27644, 27516, 27430, 27400, 27430, 27514, 27639, 27785, 27931, 28054, 28135, 28164, 28135, 28055, 27936, 27796, 27656, 27539, 27461, 27433, 27460, 27537, 27652, 27786, 27920, 28033, 28108, 28135, 28109, 28035, 27924, 27794, 27665, 27556, 27484, 27458, 27484, 27555, 27662, 27788, 27913, 28018, 28088, 28113, 28088, 28019, 27915, 27794, 27672, 27570, 27501, 27477, 27501, 27569, 27670, 27788, 27907, 28007, 28073, 28096, 28073, 28007, 27909, 27793, 27677, 27580, 27514, 27492, 27514, 27579, 27675, 27789, 27902, 27998, 28062, 28084, 28062, 27999, 27904, 27792, 27681, 27587, 27524, 27502, 27524, 27586, 27680, 27790, 27899, 27992, 28054, 28076, 28054, 27992, 27900, 27792, 27683, 27592, 27530, 27509, 27530, 27591, 27683, 27790, 27897, 27988, 28049, 28070, 28049, 27988, 27898, 27791, 27685, 27594, 27534, 27513, 27534, 27594, 27684, 27790, 27896, 27986, 28046, 28067, 28046, 27986, 27897, 27791, 27685, 27595, 27535, 27514, 27535, 27595, 27685, 27791, 27897, 27986, 28046, 28067, 28046, 27986, 27896, 27790, 27684, 27594, 27534, 27513, 27534, 27594, 27685, 27791, 27898, 27988, 28049, 28070, 28049, 27988, 27897, 27790, 27683, 27591, 27530, 27509, 27530, 27592, 27683, 27792, 27900, 27992, 28054, 28076, 28054, 27992, 27899, 27790, 27680, 27586, 27524, 27502, 27524, 27587, 27681, 27792, 27904, 27999, 28062, 28084, 28062, 27998, 27902, 27789, 27675, 27579, 27514, 27492, 27514, 27580, 27677, 27793, 27909, 28007, 28073, 28096, 28073, 28007, 27907, 27788, 27670, 27569, 27501, 27477, 27501, 27570, 27672, 27794, 27915, 28019, 28088, 28113, 28088, 28018, 27913, 27788, 27662, 27555, 27484, 27458, 27484, 27556, 27665, 27794, 27924, 28035, 28109, 28135, 28108, 28033, 27920, 27786, 27652, 27537, 27460, 27433, 27461, 27539, 27656, 27796, 27936, 28055, 28135, 28164, 28135, 28054, 27931, 27785, 27639, 27514, 27430, 27400, 27430, 27516, 27644, 27797, 27951, 28083, 28171, 28202, 28171, 28080, 27945, 27783, 27621, 27482, 27388, 27355, 27389, 27484, 27628, 27800, 27972, 28120, 28219, 28254, 28219, 28117, 27963, 27780, 27596, 27438, 27331, 27294, 27332, 27441, 27607, 27803, 28002, 28172, 28287, 28328, 28286, 28168, 27989, 27775, 27560, 27375, 27250, 27205, 27250, 27380, 27576, 27810, 28046, 28250, 28387, 28436, 28386, 28243, 28026, 27767, 27504, 27278, 27125, 27071, 27126, 27286, 27530, 27822, 28118, 28373, 28546, 28608, 28545, 28362, 28084, 27750, 27409, 27115, 26915, 26844, 26917, 27130, 27456, 27848, 28249, 28597, 28835, 28920, 28832, 28575, 28182, 27705, 27216, 26789, 26496, 26390, 26501, 26823, 27322, 27930, 28560, 29113, 29497, 29637, 29489, 29053, 28370, 27527, 26641, 25850, 25292, 25086, 25310, 25987, 27073, 28455, 29955, 31347, 32371, 32767, 32299, 30782, 28106, 24252, 19302, 13428, 6888, 0, -6888, -13428, -19302, -24252, -28106, -30782, -32299, -32767, -32371, -31347, -29955, -28455, -27073, -25987, -25310, -25086, -25292, -25850, -26641, -27527, -28370, -29053, -29489, -29637, -29497, -29113, -28560, -27930, -27322, -26823, -26501, -26390, -26496, -26789, -27216, -27705, -28182, -28575, -28832, -28920, -28835, -28597, -28249, -27848, -27456, -27130, -26917, -26844, -26915, -27115, -27409, -27750, -28084, -28362, -28545, -28608, -28546, -28373, -28118, -27822, -27530, -27286, -27126, -27071, -27125, -27278, -27504, -27767, -28026, -28243, -28386, -28436, -28387, -28250, -28046, -27810, -27576, -27380, -27250, -27205, -27250, -27375, -27560, -27775, -27989, -28168, -28286, -28328, -28287, -28172, -28002, -27803, -27607, -27441, -27332, -27294, -27331, -27438, -27596, -27780, -27963, -28117, -28219, -28254, -28219, -28120, -27972, -27800, -27628, -27484, -27389, -27355, -27388, -27482, -27621, -27783, -27945, -28080, -28171, -28202, -28171, -28083, -27951, -27797, -27644, -27516, -27430, -27400, -27430, -27514, -27639, -27785, -27931, -28054, -28135, -28164, -28135, -28055, -27936, -27796, -27656, -27539, -27461, -27433, -27460, -27537, -27652, -27786, -27920, -28033, -28108, -28135, -28109, -28035, -27924, -27794, -27665, -27556, -27484, -27458, -27484, -27555, -27662, -27788, -27913, -28018, -28088, -28113, -28088, -28019, -27915, -27794, -27672, -27570, -27501, -27477, -27501, -27569, -27670, -27788, -27907, -28007, -28073, -28096, -28073, -28007, -27909, -27793, -27677, -27580, -27514, -27492, -27514, -27579, -27675, -27789, -27902, -27998, -28062, -28084, -28062, -27999, -27904, -27792, -27681, -27587, -27524, -27502, -27524, -27586, -27680, -27790, -27899, -27992, -28054, -28076, -28054, -27992, -27900, -27792, -27683, -27592, -27530, -27509, -27530, -27591, -27683, -27790, -27897, -27988, -28049, -28070, -28049, -27988, -27898, -27791, -27685, -27594, -27534, -27513, -27534, -27594, -27684, -27790, -27896, -27986, -28046, -28067, -28046, -27986, -27897, -27791, -27685, -27595, -27535, -27514, -27535, -27595, -27685, -27791, -27897, -27986, -28046, -28067, -28046, -27986, -27896, -27790, -27684, -27594, -27534, -27513, -27534, -27594, -27685, -27791, -27898, -27988, -28049, -28070, -28049, -27988, -27897, -27790, -27683, -27591, -27530, -27509, -27530, -27592, -27683, -27792, -27900, -27992, -28054, -28076, -28054, -27992, -27899, -27790, -27680, -27586, -27524, -27502, -27524, -27587, -27681, -27792, -27904, -27999, -28062, -28084, -28062, -27998, -27902, -27789, -27675, -27579, -27514, -27492, -27514, -27580, -27677, -27793, -27909, -28007, -28073, -28096, -28073, -28007, -27907, -27788, -27670, -27569, -27501, -27477, -27501, -27570, -27672, -27794, -27915, -28019, -28088, -28113, -28088, -28018, -27913, -27788, -27662, -27555, -27484, -27458, -27484, -27556, -27665, -27794, -27924, -28035, -28109, -28135, -28108, -28033, -27920, -27786, -27652, -27537, -27460, -27433, -27461, -27539, -27656, -27796, -27936, -28055, -28135, -28164, -28135, -28054, -27931, -27785, -27639, -27514, -27430, -27400, -27430, -27516, -27644, -27797, -27951, -28083, -28171, -28202, -28171, -28080, -27945, -27783, -27621, -27482, -27388, -27355, -27389, -27484, -27628, -27800, -27972, -28120, -28219, -28254, -28219, -28117, -27963, -27780, -27596, -27438, -27331, -27294, -27332, -27441, -27607, -27803, -28002, -28172, -28287, -28328, -28286, -28168, -27989, -27775, -27560, -27375, -27250, -27205, -27250, -27380, -27576, -27810, -28046, -28250, -28387, -28436, -28386, -28243, -28026, -27767, -27504, -27278, -27125, -27071, -27126, -27286, -27530, -27822, -28118, -28373, -28546, -28608, -28545, -28362, -28084, -27750, -27409, -27115, -26915, -26844, -26917, -27130, -27456, -27848, -28249, -28597, -28835, -28920, -28832, -28575, -28182, -27705, -27216, -26789, -26496, -26390, -26501, -26823, -27322, -27930, -28560, -29113, -29497, -29637, -29489, -29053, -28370, -27527, -26641, -25850, -25292, -25086, -25310, -25987, -27073, -28455, -29955, -31347, -32371, -32767, -32299, -30782, -28106, -24252, -19302, -13428, -6888, ], ];
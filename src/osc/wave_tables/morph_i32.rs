@@ -0,0 +1 @@
+pub static MORPH_I32: [[i32; 1024]; 7] = [[0, 13176712, 26352928, 39528151, 52701887, 65873638, 79042909, 92209205, 105372028, 118530885, 131685278, 144834714, 157978697, 171116732, 184248325, 197372981, 210490206, 223599506, 236700388, 249792358, 262874923, 275947592, 289009871, 302061269, 315101294, 328129457, 341145265, 354148229, 367137860, 380113669, 393075166, 406021864, 418953276, 431868915, 444768293, 457650927, 470516330, 483364019, 496193509, 509004318, 521795963, 534567963, 547319836, 560051103, 572761285, 585449903, 598116478, 610760535, 623381597, 635979190, 648552837, 661102068, 673626408, 686125386, 698598533, 711045377, 723465451, 735858287, 748223418, 760560379, 772868706, 785147934, 797397602, 809617248, 821806413, 833964637, 846091463, 858186434, 870249095, 882278991, 894275670, 906238681, 918167571, 930061894, 941921200, 953745043, 965532978, 977284561, 988999351, 1000676905, 1012316784, 1023918549, 1035481765, 1047005996, 1058490807, 1069935767, 1081340445, 1092704410, 1104027236, 1115308496, 1126547765, 1137744620, 1148898640, 1160009404, 1171076495, 1182099495, 1193077990, 1204011566, 1214899812, 1225742318, 1236538675, 1247288477, 1257991319, 1268646799, 1279254515, 1289814068, 1300325059, 1310787095, 1321199780, 1331562722, 1341875532, 1352137822, 1362349204, 1372509294, 1382617710, 1392674071, 1402677999, 1412629117, 1422527050, 1432371426, 1442161874, 1451898025, 1461579513, 1471205973, 1480777044, 1490292364, 1499751575, 1509154322, 1518500249, 1527789006, 1537020243, 1546193612, 1555308767, 1564365366, 1573363067, 1582301533, 1591180425, 1599999410, 1608758157, 1617456334, 1626093615, 1634669675, 1643184190, 1651636840, 1660027308, 1668355276, 1676620431, 1684822463, 1692961061, 1701035921, 1709046738, 1716993211, 1724875039, 1732691927, 1740443580, 1748129706, 1755750016, 1763304223, 1770792043, 1778213194, 1785567395, 1792854372, 1800073848, 1807225552, 1814309215, 1821324571, 1828271355, 1835149305, 1841958164, 1848697673, 1855367580, 1861967633, 1868497585, 1874957188, 1881346201, 1887664382, 1893911493, 1900087300, 1906191569, 1912224072, 1918184580, 1924072870, 1929888719, 1935631909, 1941302224, 1946899450, 1952423376, 1957873795, 1963250500, 1968553291, 1973781966, 1978936330, 1984016188, 1989021349, 1993951624, 1998806828, 2003586778, 2008291295, 2012920200, 2017473320, 2021950483, 2026351521, 2030676268, 2034924561, 2039096240, 2043191149, 2047209132, 2051150040, 2055013722, 2058800035, 2062508835, 2066139982, 2069693341, 2073168776, 2076566159, 2079885359, 2083126253, 2086288719, 2089372637, 2092377891, 2095304369, 2098151959, 2100920555, 2103610053, 2106220351, 2108751351, 2111202958, 2113575079, 2115867625, 2118080510, 2120213650, 2122266966, 2124240379, 2126133816, 2127947205, 2129680479, 2131333571, 2132906419, 2134398965, 2135811152, 2137142926, 2138394239, 2139565042, 2140655292, 2141664947, 2142593970, 2143442325, 2144209981, 2144896909, 2145503082, 2146028479, 2146473079, 2146836865, 2147119824, 2147321945, 2147443221, 2147483647, 2147443221, 2147321945, 2147119824, 2146836865, 2146473079, 2146028479, 2145503082, 2144896909, 2144209981, 2143442325, 2142593970, 2141664947, 2140655292, 2139565042, 2138394239, 2137142926, 2135811152, 2134398965, 2132906419, 2131333571, 2129680479, 2127947205, 2126133816, 2124240379, 2122266966, 2120213650, 2118080510, 2115867625, 2113575079, 2111202958, 2108751351, 2106220351, 2103610053, 2100920555, 2098151959, 2095304369, 2092377891, 2089372637, 2086288719, 2083126253, 2079885359, 2076566159, 2073168776, 2069693341, 2066139982, 2062508835, 2058800035, 2055013722, 2051150040, 2047209132, 2043191149, 2039096240, 2034924561, 2030676268, 2026351521, 2021950483, 2017473320, 2012920200, 2008291295, 2003586778, 1998806828, 1993951624, 1989021349, 1984016188, 1978936330, 1973781966, 1968553291, 1963250500, 1957873795, 1952423376, 1946899450, 1941302224, 1935631909, 1929888719, 1924072870, 1918184580, 1912224072, 1906191569, 1900087300, 1893911493, 1887664382, 1881346201, 1874957188, 1868497585, 1861967633, 1855367580, 1848697673, 1841958164, 1835149305, 1828271355, 1821324571, 1814309215, 1807225552, 1800073848, 1792854372, 1785567395, 1778213194, 1770792043, 1763304223, 1755750016, 1748129706, 1740443580, 1732691927, 1724875039, 1716993211, 1709046738, 1701035921, 1692961061, 1684822463, 1676620431, 1668355276, 1660027308, 1651636840, 1643184190, 1634669675, 1626093615, 1617456334, 1608758157, 1599999410, 1591180425, 1582301533, 1573363067, 1564365366, 1555308767, 1546193612, 1537020243, 1527789006, 1518500249, 1509154322, 1499751575, 1490292364, 1480777044, 1471205973, 1461579513, 1451898025, 1442161874, 1432371426, 1422527050, 1412629117, 1402677999, 1392674071, 1382617710, 1372509294, 1362349204, 1352137822, 1341875532, 1331562722, 1321199780, 1310787095, 1300325059, 1289814068, 1279254515, 1268646799, 1257991319, 1247288477, 1236538675, 1225742318, 1214899812, 1204011566, 1193077990, 1182099495, 1171076495, 1160009404, 1148898640, 1137744620, 1126547765, 1115308496, 1104027236, 1092704410, 1081340445, 1069935767, 1058490807, 1047005996, 1035481765, 1023918549, 1012316784, 1000676905, 988999351, 977284561, 965532978, 953745043, 941921200, 930061894, 918167571, 906238681, 894275670, 882278991, 870249095, 858186434, 846091463, 833964637, 821806413, 809617248, 797397602, 785147934, 772868706, 760560379, 748223418, 735858287, 723465451, 711045377, 698598533, 686125386, 673626408, 661102068, 648552837, 635979190, 623381597, 610760535, 598116478, 585449903, 572761285, 560051103, 547319836, 534567963, 521795963, 509004318, 496193509, 483364019, 470516330, 457650927, 444768293, 431868915, 418953276, 406021864, 393075166, 380113669, 367137860, 354148229, 341145265, 328129457, 315101294, 302061269, 289009871, 275947592, 262874923, 249792358, 236700388, 223599506, 210490206, 197372981, 184248325, 171116732, 157978697, 144834714, 131685278, 118530885, 105372028, 92209205, 79042909, 65873638, 52701887, 39528151, 26352928, 13176712, 0, -13176712, -26352928, -39528151, -52701887, -65873638, -79042909, -92209205, -105372028, -118530885, -131685278, -144834714, -157978697, -171116732, -184248325, -197372981, -210490206, -223599506, -236700388, -249792358, -262874923, -275947592, -289009871, -302061269, -315101294, -328129457, -341145265, -354148229, -367137860, -380113669, -393075166, -406021864, -418953276, -431868915, -444768293, -457650927, -470516330, -483364019, -496193509, -509004318, -521795963, -534567963, -547319836, -560051103, -572761285, -585449903, -598116478, -610760535, -623381597, -635979190, -648552837, -661102068, -673626408, -686125386, -698598533, -711045377, -723465451, -735858287, -748223418, -760560379, -772868706, -785147934, -797397602, -809617248, -821806413, -833964637, -846091463, -858186434, -870249095, -882278991, -894275670, -906238681, -918167571, -930061894, -941921200, -953745043, -965532978, -977284561, -988999351, -1000676905, -1012316784, -1023918549, -1035481765, -1047005996, -1058490807, -1069935767, -1081340445, -1092704410, -1104027236, -1115308496, -1126547765, -1137744620, -1148898640, -1160009404, -1171076495, -1182099495, -1193077990, -1204011566, -1214899812, -1225742318, -1236538675, -1247288477, -1257991319, -1268646799, -1279254515, -1289814068, -1300325059, -1310787095, -1321199780, -1331562722, -1341875532, -1352137822, -1362349204, -1372509294, -1382617710, -1392674071, -1402677999, -1412629117, -1422527050, -1432371426, -1442161874, -1451898025, -1461579513, -1471205973, -1480777044, -1490292364, -1499751575, -1509154322, -1518500249, -1527789006, -1537020243, -1546193612, -1555308767, -1564365366, -1573363067, -1582301533, -1591180425, -1599999410, -1608758157, -1617456334, -1626093615, -1634669675, -1643184190, -1651636840, -1660027308, -1668355276, -1676620431, -1684822463, -1692961061, -1701035921, -1709046738, -1716993211, -1724875039, -1732691927, -1740443580, -1748129706, -1755750016, -1763304223, -1770792043, -1778213194, -1785567395, -1792854372, -1800073848, -1807225552, -1814309215, -1821324571, -1828271355, -1835149305, -1841958164, -1848697673, -1855367580, -1861967633, -1868497585, -1874957188, -1881346201, -1887664382, -1893911493, -1900087300, -1906191569, -1912224072, -1918184580, -1924072870, -1929888719, -1935631909, -1941302224, -1946899450, -1952423376, -1957873795, -1963250500, -1968553291, -1973781966, -1978936330, -1984016188, -1989021349, -1993951624, -1998806828, -2003586778, -2008291295, -2012920200, -2017473320, -2021950483, -2026351521, -2030676268, -2034924561, -2039096240, -2043191149, -2047209132, -2051150040, -2055013722, -2058800035, -2062508835, -2066139982, -2069693341, -2073168776, -2076566159, -2079885359, -2083126253, -2086288719, -2089372637, -2092377891, -2095304369, -2098151959, -2100920555, -2103610053, -2106220351, -2108751351, -2111202958, -2113575079, -2115867625, -2118080510, -2120213650, -2122266966, -2124240379, -2126133816, -2127947205, -2129680479, -2131333571, -2132906419, -2134398965, -2135811152, -2137142926, -2138394239, -2139565042, -2140655292, -2141664947, -2142593970, -2143442325, -2144209981, -2144896909, -2145503082, -2146028479, -2146473079, -2146836865, -2147119824, -2147321945, -2147443221, -2147483647, -2147443221, -2147321945, -2147119824, -2146836865, -2146473079, -2146028479, -2145503082, -2144896909, -2144209981, -2143442325, -2142593970, -2141664947, -2140655292, -2139565042, -2138394239, -2137142926, -2135811152, -2134398965, -2132906419, -2131333571, -2129680479, -2127947205, -2126133816, -2124240379, -2122266966, -2120213650, -2118080510, -2115867625, -2113575079, -2111202958, -2108751351, -2106220351, -2103610053, -2100920555, -2098151959, -2095304369, -2092377891, -2089372637, -2086288719, -2083126253, -2079885359, -2076566159, -2073168776, -2069693341, -2066139982, -2062508835, -2058800035, -2055013722, -2051150040, -2047209132, -2043191149, -2039096240, -2034924561, -2030676268, -2026351521, -2021950483, -2017473320, -2012920200, -2008291295, -2003586778, -1998806828, -1993951624, -1989021349, -1984016188, -1978936330, -1973781966, -1968553291, -1963250500, -1957873795, -1952423376, -1946899450, -1941302224, -1935631909, -1929888719, -1924072870, -1918184580, -1912224072, -1906191569, -1900087300, -1893911493, -1887664382, -1881346201, -1874957188, -1868497585, -1861967633, -1855367580, -1848697673, -1841958164, -1835149305, -1828271355, -1821324571, -1814309215, -1807225552, -1800073848, -1792854372, -1785567395, -1778213194, -1770792043, -1763304223, -1755750016, -1748129706, -1740443580, -1732691927, -1724875039, -1716993211, -1709046738, -1701035921, -1692961061, -1684822463, -1676620431, -1668355276, -1660027308, -1651636840, -1643184190, -1634669675, -1626093615, -1617456334, -1608758157, -1599999410, -1591180425, -1582301533, -1573363067, -1564365366, -1555308767, -1546193612, -1537020243, -1527789006, -1518500249, -1509154322, -1499751575, -1490292364, -1480777044, -1471205973, -1461579513, -1451898025, -1442161874, -1432371426, -1422527050, -1412629117, -1402677999, -1392674071, -1382617710, -1372509294, -1362349204, -1352137822, -1341875532, -1331562722, -1321199780, -1310787095, -1300325059, -1289814068, -1279254515, -1268646799, -1257991319, -1247288477, -1236538675, -1225742318, -1214899812, -1204011566, -1193077990, -1182099495, -1171076495, -1160009404, -1148898640, -1137744620, -1126547765, -1115308496, -1104027236, -1092704410, -1081340445, -1069935767, -1058490807, -1047005996, -1035481765, -1023918549, -1012316784, -1000676905, -988999351, -977284561, -965532978, -953745043, -941921200, -930061894, -918167571, -906238681, -894275670, -882278991, -870249095, -858186434, -846091463, -833964637, -821806413, -809617248, -797397602, -785147934, -772868706, -760560379, -748223418, -735858287, -723465451, -711045377, -698598533, -686125386, -673626408, -661102068, -648552837, -635979190, -623381597, -610760535, -598116478, -585449903, -572761285, -560051103, -547319836, -534567963, -521795963, -509004318, -496193509, -483364019, -470516330, -457650927, -444768293, -431868915, -418953276, -406021864, -393075166, -380113669, -367137860, -354148229, -341145265, -328129457, -315101294, -302061269, -289009871, -275947592, -262874923, -249792358, -236700388, -223599506, -210490206, -197372981, -184248325, -171116732, -157978697, -144834714, -131685278, -118530885, -105372028, -92209205, -79042909, -65873638, -52701887, -39528151, -26352928, -13176712, ], [0, 34509419, 69009094, 103489285, 137940263, 172352308, 206715720, 241020821, 275257956, 309417504, 343489876, 377465521, 411334934, 445088653, 478717271, 512211435, 545561851, 578759290, 611794589, 644658659, 677342484, 709837130, 742133745, 774223567, 806097922, 837748233, 869166022, 900342913, 931270635, 961941028, 992346045, 1022477755, 1052328348, 1081890136, 1111155558, 1140117185, 1168767716, 1197099992, 1225106987, 1252781821, 1280117758, 1307108208, 1333746733, 1360027046, 1385943017, 1411488673, 1436658201, 1461445951, 1485846437, 1509854341, 1533464513, 1556671974, 1579471918, 1601859713, 1623830902, 1645381209, 1666506535, 1687202962, 1707466754, 1727294359, 1746682408, 1765627719, 1784127296, 1802178329, 1819778198, 1836924471, 1853614905, 1869847447, 1885620233, 1900931592, 1915780042, 1930164291, 1944083240, 1957535978, 1970521786, 1983040137, 1995090689, 2006673294, 2017787991, 2028435007, 2038614755, 2048327838, 2057575042, 2066357337, 2074675879, 2082532004, 2089927230, 2096863254, 2103341952, 2109365376, 2114935752, 2120055481, 2124727133, 2128953450, 2132737340, 2136081877, 2138990297, 2141465998, 2143512539, 2145133631, 2146333142, 2147115092, 2147483647, 2147443122, 2146997976, 2146152805, 2144912347, 2143281473, 2141265187, 2138868622, 2136097037, 2132955812, 2129450450, 2125586568, 2121369897, 2116806278, 2111901659, 2106662089, 2101093718, 2095202793, 2088995651, 2082478721, 2075658515, 2068541626, 2061134728, 2053444567, 2045477960, 2037241791, 2028743007, 2019988615, 2010985675, 2001741301, 1992262653, 1982556937, 1972631398, 1962493316, 1952150004, 1941608804, 1930877082, 1919962226, 1908871637, 1897612733, 1886192940, 1874619687, 1862900408, 1851042530, 1839053479, 1826940666, 1814711492, 1802373338, 1789933566, 1777399509, 1764778476, 1752077741, 1739304544, 1726466082, 1713569513, 1700621947, 1687630444, 1674602010, 1661543596, 1648462091, 1635364322, 1622257049, 1609146963, 1596040680, 1582944744, 1569865616, 1556809677, 1543783224, 1530792465, 1517843517, 1504942406, 1492095061, 1479307312, 1466584888, 1453933418, 1441358420, 1428865307, 1416459382, 1404145833, 1391929737, 1379816051, 1367809614, 1355915146, 1344137245, 1332480381, 1320948903, 1309547031, 1298278855, 1287148336, 1276159304, 1265315455, 1254620352, 1244077422, 1233689958, 1223461113, 1213393905, 1203491211, 1193755771, 1184190185, 1174796910, 1165578265, 1156536427, 1147673432, 1138991174, 1130491404, 1122175734, 1114045633, 1106102429, 1098347307, 1090781314, 1083405355, 1076220195, 1069226459, 1062424634, 1055815068, 1049397974, 1043173425, 1037141361, 1031301587, 1025653774, 1020197461, 1014932056, 1009856838, 1004970956, 1000273434, 995763169, 991438935, 987299385, 983343050, 979568342, 975973558, 972556879, 969316373, 966249997, 963355602, 960630927, 958073611, 955681189, 953451096, 951380671, 949467154, 947707697, 946099359, 944639110, 943323838, 942150347, 941115360, 940215524, 939447411, 938807522, 938292287, 937898071, 937621176, 937457844, 937404257, 937456545, 937610783, 937863001, 938209180, 938645259, 939167137, 939770677, 940451707, 941206024, 942029398, 942917572, 943866271, 944871197, 945928038, 947032470, 948180158, 949366760, 950587932, 951839326, 953116600, 954415414, 955731437, 957060350, 958397846, 959739635, 961081449, 962419040, 963748185, 965064690, 966364392, 967643160, 968896900, 970121557, 971313116, 972467608, 973581107, 974649740, 975669683, 976637165, 977548472, 978399949, 979188001, 979909095, 980559764, 981136608, 981636296, 982055568, 982391236, 982640188, 982799390, 982865885, 982836795, 982709326, 982480767, 982148491, 981709960, 981162720, 980504409, 979732755, 978845578, 977840788, 976716394, 975470494, 974101287, 972607066, 970986222, 969237245, 967358722, 965349341, 963207890, 960933258, 958524434, 955980509, 953300674, 950484223, 947530553, 944439159, 941209641, 937841701, 934335141, 930689865, 926905877, 922983284, 918922291, 914723204, 910386428, 905912467, 901301921, 896555491, 891673970, 886658248, 881509312, 876228239, 870816201, 865274459, 859604366, 853807363, 847884979, 841838830, 835670615, 829382117, 822975203, 816451818, 809813985, 803063807, 796203461, 789235195, 782161333, 774984267, 767706456, 760330427, 752858770, 745294138, 737639242, 729896854, 722069799, 714160958, 706173262, 698109693, 689973278, 681767091, 673494247, 665157901, 656761247, 648307515, 639799967, 631241895, 622636621, 613987493, 605297882, 596571179, 587810795, 579020157, 570202706, 561361893, 552501178, 543624029, 534733915, 525834309, 516928682, 508020500, 499113225, 490210309, 481315194, 472431310, 463562067, 454710861, 445881067, 437076034, 428299090, 419553533, 410842632, 402169624, 393537710, 384950058, 376409795, 367920007, 359483739, 351103987, 342783706, 334525796, 326333110, 318208446, 310154548, 302174102, 294269738, 286444023, 278699464, 271038503, 263463517, 255976816, 248580642, 241277166, 234068489, 226956639, 219943568, 213031156, 206221203, 199515433, 192915491, 186422942, 180039270, 173765877, 167604082, 161555120, 155620143, 149800218, 144096325, 138509357, 133040122, 127689341, 122457645, 117345578, 112353598, 107482070, 102731273, 98101398, 93592545, 89204726, 84937864, 80791795, 76766264, 72860930, 69075363, 65409047, 61861377, 58431663, 55119130, 51922914, 48842072, 45875573, 43022305, 40281072, 37650599, 35129531, 32716430, 30409785, 28208005, 26109424, 24112302, 22214824, 20415104, 18711188, 17101049, 15582596, 14153668, 12812044, 11555436, 10381499, 9287826, 8271952, 7331357, 6463468, 5665658, 4935250, 4269520, 3665696, 3120963, 2632462, 2197295, 1812525, 1475181, 1182254, 930707, 717472, 539452, 393528, 276556, 185370, 116789, 67613, 34629, 14613, 4330, 541, 0, -541, -4330, -14613, -34629, -67613, -116789, -185370, -276556, -393528, -539452, -717472, -930707, -1182254, -1475181, -1812525, -2197295, -2632462, -3120963, -3665696, -4269520, -4935250, -5665658, -6463468, -7331357, -8271952, -9287826, -10381499, -11555436, -12812044, -14153668, -15582596, -17101049, -18711188, -20415104, -22214824, -24112302, -26109424, -28208005, -30409785, -32716430, -35129531, -37650599, -40281072, -43022305, -45875573, -48842072, -51922914, -55119130, -58431663, -61861377, -65409047, -69075363, -72860930, -76766264, -80791795, -84937864, -89204726, -93592545, -98101398, -102731273, -107482070, -112353598, -117345578, -122457645, -127689341, -133040122, -138509357, -144096325, -149800218, -155620143, -161555120, -167604082, -173765877, -180039270, -186422942, -192915491, -199515433, -206221203, -213031156, -219943568, -226956639, -234068489, -241277166, -248580642, -255976816, -263463517, -271038503, -278699464, -286444023, -294269738, -302174102, -310154548, -318208446, -326333110, -334525796, -342783706, -351103987, -359483739, -367920007, -376409795, -384950058, -393537710, -402169624, -410842632, -419553533, -428299090, -437076034, -445881067, -454710861, -463562067, -472431310, -481315194, -490210309, -499113225, -508020500, -516928682, -525834309, -534733915, -543624029, -552501178, -561361893, -570202706, -579020157, -587810795, -596571179, -605297882, -613987493, -622636621, -631241895, -639799967, -648307515, -656761247, -665157901, -673494247, -681767091, -689973278, -698109693, -706173262, -714160958, -722069799, -729896854, -737639242, -745294138, -752858770, -760330427, -767706456, -774984267, -782161333, -789235195, -796203461, -803063807, -809813985, -816451818, -822975203, -829382117, -835670615, -841838830, -847884979, -853807363, -859604366, -865274459, -870816201, -876228239, -881509312, -886658248, -891673970, -896555491, -901301921, -905912467, -910386428, -914723204, -918922291, -922983284, -926905877, -930689865, -934335141, -937841701, -941209641, -944439159, -947530553, -950484223, -953300674, -955980509, -958524434, -960933258, -963207890, -965349341, -967358722, -969237245, -970986222, -972607066, -974101287, -975470494, -976716394, -977840788, -978845578, -979732755, -980504409, -981162720, -981709960, -982148491, -982480767, -982709326, -982836795, -982865885, -982799390, -982640188, -982391236, -982055568, -981636296, -981136608, -980559764, -979909095, -979188001, -978399949, -977548472, -976637165, -975669683, -974649740, -973581107, -972467608, -971313116, -970121557, -968896900, -967643160, -966364392, -965064690, -963748185, -962419040, -961081449, -959739635, -958397846, -957060350, -955731437, -954415414, -953116600, -951839326, -950587932, -949366760, -948180158, -947032470, -945928038, -944871197, -943866271, -942917572, -942029398, -941206024, -940451707, -939770677, -939167137, -938645259, -938209180, -937863001, -937610783, -937456545, -937404257, -937457844, -937621176, -937898071, -938292287, -938807522, -939447411, -940215524, -941115360, -942150347, -943323838, -944639110, -946099359, -947707697, -949467154, -951380671, -953451096, -955681189, -958073611, -960630927, -963355602, -966249997, -969316373, -972556879, -975973558, -979568342, -983343050, -987299385, -991438935, -995763169, -1000273434, -1004970956, -1009856838, -1014932056, -1020197461, -1025653774, -1031301587, -1037141361, -1043173425, -1049397974, -1055815068, -1062424634, -1069226459, -1076220195, -1083405355, -1090781314, -1098347307, -1106102429, -1114045633, -1122175734, -1130491404, -1138991174, -1147673432, -1156536427, -1165578265, -1174796910, -1184190185, -1193755771, -1203491211, -1213393905, -1223461113, -1233689958, -1244077422, -1254620352, -1265315455, -1276159304, -1287148336, -1298278855, -1309547031, -1320948903, -1332480381, -1344137245, -1355915146, -1367809614, -1379816051, -1391929737, -1404145833, -1416459382, -1428865307, -1441358420, -1453933418, -1466584888, -1479307312, -1492095061, -1504942406, -1517843517, -1530792465, -1543783224, -1556809677, -1569865616, -1582944744, -1596040680, -1609146963, -1622257049, -1635364322, -1648462091, -1661543596, -1674602010, -1687630444, -1700621947, -1713569513, -1726466082, -1739304544, -1752077741, -1764778476, -1777399509, -1789933566, -1802373338, -1814711492, -1826940666, -1839053479, -1851042530, -1862900408, -1874619687, -1886192940, -1897612733, -1908871637, -1919962226, -1930877082, -1941608804, -1952150004, -1962493316, -1972631398, -1982556937, -1992262653, -2001741301, -2010985675, -2019988615, -2028743007, -2037241791, -2045477960, -2053444567, -2061134728, -2068541626, -2075658515, -2082478721, -2088995651, -2095202793, -2101093718, -2106662089, -2111901659, -2116806278, -2121369897, -2125586568, -2129450450, -2132955812, -2136097037, -2138868622, -2141265187, -2143281473, -2144912347, -2146152805, -2146997976, -2147443122, -2147483647, -2147115092, -2146333142, -2145133631, -2143512539, -2141465998, -2138990297, -2136081877, -2132737340, -2128953450, -2124727133, -2120055481, -2114935752, -2109365376, -2103341952, -2096863254, -2089927230, -2082532004, -2074675879, -2066357337, -2057575042, -2048327838, -2038614755, -2028435007, -2017787991, -2006673294, -1995090689, -1983040137, -1970521786, -1957535978, -1944083240, -1930164291, -1915780042, -1900931592, -1885620233, -1869847447, -1853614905, -1836924471, -1819778198, -1802178329, -1784127296, -1765627719, -1746682408, -1727294359, -1707466754, -1687202962, -1666506535, -1645381209, -1623830902, -1601859713, -1579471918, -1556671974, -1533464513, -1509854341, -1485846437, -1461445951, -1436658201, -1411488673, -1385943017, -1360027046, -1333746733, -1307108208, -1280117758, -1252781821, -1225106987, -1197099992, -1168767716, -1140117185, -1111155558, -1081890136, -1052328348, -1022477755, -992346045, -961941028, -931270635, -900342913, -869166022, -837748233, -806097922, -774223567, -742133745, -709837130, -677342484, -644658659, -611794589, -578759290, -545561851, -512211435, -478717271, -445088653, -411334934, -377465521, -343489876, -309417504, -275257956, -241020821, -206715720, -172352308, -137940263, -103489285, -69009094, -34509419, ], [0, 119813597, 239205821, 357757884, 475056146, 590694642, 704277553, 815421607, 923758379, 1028936497, 1130623710, 1228508828, 1322303505, 1411743859, 1496591913, 1576636859, 1651696115, 1721616190, 1786273343, 1845574026, 1899455120, 1947883956, 1990858130, 2028405100, 2060581586, 2087472775, 2109191326, 2125876202, 2137691325, 2144824076, 2147483647, 2145899254, 2140318239, 2131004066, 2118234228, 2102298084, 2083494649, 2062130337, 2038516700, 2012968148, 1985799705, 1957324786, 1927853026, 1897688179, 1867126095, 1836452798, 1805942667, 1775856741, 1746441167, 1717925778, 1690522830, 1664425909, 1639808987, 1616825665, 1595608581, 1576269007, 1558896607, 1543559389, 1530303817, 1519155102, 1510117653, 1503175692, 1498294013, 1495418887, 1494479100, 1495387108, 1498040306, 1502322391, 1508104809, 1515248272, 1523604333, 1533017001, 1543324379, 1554360328, 1565956112, 1577942040, 1590149072, 1602410387, 1614562890, 1626448653, 1637916280, 1648822179, 1659031732, 1668420368, 1676874509, 1684292401, 1690584815, 1695675625, 1699502241, 1702015918, 1703181925, 1702979577, 1701402144, 1698456622, 1694163386, 1688555719, 1681679232, 1673591179, 1664359674, 1654062815, 1642787742, 1630629609, 1617690519, 1604078391, 1589905808, 1575288833, 1560345815, 1545196189, 1529959297, 1514753216, 1499693631, 1484892739, 1470458213, 1456492222, 1443090520, 1430341617, 1418326022, 1407115589, 1396772947, 1387351035, 1378892728, 1371430582, 1364986667, 1359572518, 1355189178, 1351827348, 1349467637, 1348080905, 1347628693, 1348063743, 1349330591, 1351366240, 1354100888, 1357458715, 1361358728, 1365715626, 1370440714, 1375442827, 1380629269, 1385906758, 1391182356, 1396364392, 1401363354, 1406092756, 1410469954, 1414416923, 1417860974, 1420735410, 1422980112, 1424542064, 1425375785, 1425443694, 1424716387, 1423172834, 1420800483, 1417595289, 1413561648, 1408712259, 1403067899, 1396657115, 1389515860, 1381687039, 1373220005, 1364169994, 1354597501, 1344567622, 1334149347, 1323414830, 1312438626, 1301296926, 1290066769, 1278825264, 1267648817, 1256612367, 1245788658, 1235247526, 1225055227, 1215273816, 1205960558, 1197167409, 1188940541, 1181319941, 1174339068, 1168024580, 1162396135, 1157466259, 1153240291, 1149716393, 1146885644, 1144732190, 1143233473, 1142360514, 1142078273, 1142346049, 1143117945, 1144343376, 1145967621, 1147932411, 1150176546, 1152636538, 1155247271, 1157942671, 1160656377, 1163322416, 1165875864, 1168253489, 1170394376, 1172240519, 1173737387, 1174834439, 1175485605, 1175649710, 1175290855, 1174378733, 1172888888, 1170802922, 1168108624, 1164800051, 1160877538, 1156347642, 1151223032, 1145522313, 1139269791, 1132495188, 1125233298, 1117523597, 1109409814, 1100939453, 1092163288, 1083134824, 1073909741, 1064545310, 1055099806, 1045631912, 1036200122, 1026862152, 1017674360, 1008691188, 999964624, 991543693, 983473980, 975797195, 968550776, 961767539, 955475376, 949697006, 944449781, 939745543, 935590540, 931985400, 928925162, 926399359, 924392161, 922882567, 921844652, 921247859, 921057339, 921234326, 921736557, 922518722, 923532938, 924729250, 926056151, 927461114, 928891125, 930293228, 931615060, 932805374, 933814559, 934595126, 935102183, 935293871, 935131775, 934581290, 933611953, 932197729, 930317251, 927954009, 925096494, 921738285, 917878088, 913519724, 908672059, 903348891, 897568785, 891354855, 884734513, 877739162, 870403864, 862766961, 854869672, 846755658, 838470571, 830061577, 821576873, 813065191, 804575303, 796155523, 787853223, 779714351, 771782978, 764100856, 756707003, 749637326, 742924264, 736596479, 730678580, 725190894, 720149275, 715564966, 711444505, 707789679, 704597526, 701860385, 699565996, 697697644, 696234346, 695151084, 694419074, 694006078, 693876739, 693992960, 694314299, 694798386, 695401370, 696078361, 696783895, 697472397, 698098645, 698618229, 698987994, 699166482, 699114339, 698794710, 698173604, 697220231, 695907296, 694211273, 692112621, 689595974, 686650280, 683268899, 679449653, 675194832, 670511157, 665409695, 659905733, 654018605, 647771483, 641191131, 634307616, 627153994, 619765966, 612181502, 604440450, 596584124, 588654881, 580695683, 572749664, 564859684, 557067901, 549415339, 541941476, 534683851, 527677680, 520955515, 514546911, 508478141, 502771937, 497447269, 492519159, 487998548, 483892188, 480202591, 476928010, 474062476, 471595862, 469514004, 467798852, 466428669, 465378255, 464619218, 464120269, 463847550, 463764981, 463834639, 464017141, 464272056, 464558313, 464834624, 465059904, 465193687, 465196538, 465030451, 464659235, 464048872, 463167865, 461987549, 460482376, 458630169, 456412342, 453814076, 450824469, 447436630, 443647748, 439459106, 434876065, 429907995, 424568176, 418873651, 412845050, 406506365, 399884708, 393010023, 385914775, 378633620, 371203041, 363660982, 356046448, 348399116, 340758922, 333165662, 325658577, 318275963, 311054780, 304030275, 297235631, 290701628, 284456337, 278524836, 272928963, 267687092, 262813962, 258320520, 254213827, 250496984, 247169110, 244225355, 241656957, 239451337, 237592231, 236059861, 234831141, 233879921, 233177249, 232691680, 232389588, 232235524, 232192567, 232222712, 232287252, 232347176, 232363567, 232297995, 232112914, 231772037, 231240709, 230486255, 229478315, 228189144, 226593900, 224670887, 222401780, 219771801, 216769871, 213388719, 209624951, 205479082, 200955526, 196062552, 190812190, 185220110, 179305459, 173090662, 166601188, 159865297, 152913740, 145779450, 138497204, 131103264, 123635013, 116130565, 108628383, 101166885, 93784051, 86517036, 79401794, 72472711, 65762256, 59300647, 53115552, 47231801, 41671137, 36451995, 31589317, 27094397, 22974774, 19234148, 15872350, 12885342, 10265261, 8000497, 6075815, 4472509, 3168598, 2139043, 1356009, 789147, 405903, 171853, 51050, 6391, 0, -6391, -51050, -171853, -405903, -789147, -1356009, -2139043, -3168598, -4472509, -6075815, -8000497, -10265261, -12885342, -15872350, -19234148, -22974774, -27094397, -31589317, -36451995, -41671137, -47231801, -53115552, -59300647, -65762256, -72472711, -79401794, -86517036, -93784051, -101166885, -108628383, -116130565, -123635013, -131103264, -138497204, -145779450, -152913740, -159865297, -166601188, -173090662, -179305459, -185220110, -190812190, -196062552, -200955526, -205479082, -209624951, -213388719, -216769871, -219771801, -222401780, -224670887, -226593900, -228189144, -229478315, -230486255, -231240709, -231772037, -232112914, -232297995, -232363567, -232347176, -232287252, -232222712, -232192567, -232235524, -232389588, -232691680, -233177249, -233879921, -234831141, -236059861, -237592231, -239451337, -241656957, -244225355, -247169110, -250496984, -254213827, -258320520, -262813962, -267687092, -272928963, -278524836, -284456337, -290701628, -297235631, -304030275, -311054780, -318275963, -325658577, -333165662, -340758922, -348399116, -356046448, -363660982, -371203041, -378633620, -385914775, -393010023, -399884708, -406506365, -412845050, -418873651, -424568176, -429907995, -434876065, -439459106, -443647748, -447436630, -450824469, -453814076, -456412342, -458630169, -460482376, -461987549, -463167865, -464048872, -464659235, -465030451, -465196538, -465193687, -465059904, -464834624, -464558313, -464272056, -464017141, -463834639, -463764981, -463847550, -464120269, -464619218, -465378255, -466428669, -467798852, -469514004, -471595862, -474062476, -476928010, -480202591, -483892188, -487998548, -492519159, -497447269, -502771937, -508478141, -514546911, -520955515, -527677680, -534683851, -541941476, -549415339, -557067901, -564859684, -572749664, -580695683, -588654881, -596584124, -604440450, -612181502, -619765966, -627153994, -634307616, -641191131, -647771483, -654018605, -659905733, -665409695, -670511157, -675194832, -679449653, -683268899, -686650280, -689595974, -692112621, -694211273, -695907296, -697220231, -698173604, -698794710, -699114339, -699166482, -698987994, -698618229, -698098645, -697472397, -696783895, -696078361, -695401370, -694798386, -694314299, -693992960, -693876739, -694006078, -694419074, -695151084, -696234346, -697697644, -699565996, -701860385, -704597526, -707789679, -711444505, -715564966, -720149275, -725190894, -730678580, -736596479, -742924264, -749637326, -756707003, -764100856, -771782978, -779714351, -787853223, -796155523, -804575303, -813065191, -821576873, -830061577, -838470571, -846755658, -854869672, -862766961, -870403864, -877739162, -884734513, -891354855, -897568785, -903348891, -908672059, -913519724, -917878088, -921738285, -925096494, -927954009, -930317251, -932197729, -933611953, -934581290, -935131775, -935293871, -935102183, -934595126, -933814559, -932805374, -931615060, -930293228, -928891125, -927461114, -926056151, -924729250, -923532938, -922518722, -921736557, -921234326, -921057339, -921247859, -921844652, -922882567, -924392161, -926399359, -928925162, -931985400, -935590540, -939745543, -944449781, -949697006, -955475376, -961767539, -968550776, -975797195, -983473980, -991543693, -999964624, -1008691188, -1017674360, -1026862152, -1036200122, -1045631912, -1055099806, -1064545310, -1073909741, -1083134824, -1092163288, -1100939453, -1109409814, -1117523597, -1125233298, -1132495188, -1139269791, -1145522313, -1151223032, -1156347642, -1160877538, -1164800051, -1168108624, -1170802922, -1172888888, -1174378733, -1175290855, -1175649710, -1175485605, -1174834439, -1173737387, -1172240519, -1170394376, -1168253489, -1165875864, -1163322416, -1160656377, -1157942671, -1155247271, -1152636538, -1150176546, -1147932411, -1145967621, -1144343376, -1143117945, -1142346049, -1142078273, -1142360514, -1143233473, -1144732190, -1146885644, -1149716393, -1153240291, -1157466259, -1162396135, -1168024580, -1174339068, -1181319941, -1188940541, -1197167409, -1205960558, -1215273816, -1225055227, -1235247526, -1245788658, -1256612367, -1267648817, -1278825264, -1290066769, -1301296926, -1312438626, -1323414830, -1334149347, -1344567622, -1354597501, -1364169994, -1373220005, -1381687039, -1389515860, -1396657115, -1403067899, -1408712259, -1413561648, -1417595289, -1420800483, -1423172834, -1424716387, -1425443694, -1425375785, -1424542064, -1422980112, -1420735410, -1417860974, -1414416923, -1410469954, -1406092756, -1401363354, -1396364392, -1391182356, -1385906758, -1380629269, -1375442827, -1370440714, -1365715626, -1361358728, -1357458715, -1354100888, -1351366240, -1349330591, -1348063743, -1347628693, -1348080905, -1349467637, -1351827348, -1355189178, -1359572518, -1364986667, -1371430582, -1378892728, -1387351035, -1396772947, -1407115589, -1418326022, -1430341617, -1443090520, -1456492222, -1470458213, -1484892739, -1499693631, -1514753216, -1529959297, -1545196189, -1560345815, -1575288833, -1589905808, -1604078391, -1617690519, -1630629609, -1642787742, -1654062815, -1664359674, -1673591179, -1681679232, -1688555719, -1694163386, -1698456622, -1701402144, -1702979577, -1703181925, -1702015918, -1699502241, -1695675625, -1690584815, -1684292401, -1676874509, -1668420368, -1659031732, -1648822179, -1637916280, -1626448653, -1614562890, -1602410387, -1590149072, -1577942040, -1565956112, -1554360328, -1543324379, -1533017001, -1523604333, -1515248272, -1508104809, -1502322391, -1498040306, -1495387108, -1494479100, -1495418887, -1498294013, -1503175692, -1510117653, -1519155102, -1530303817, -1543559389, -1558896607, -1576269007, -1595608581, -1616825665, -1639808987, -1664425909, -1690522830, -1717925778, -1746441167, -1775856741, -1805942667, -1836452798, -1867126095, -1897688179, -1927853026, -1957324786, -1985799705, -2012968148, -2038516700, -2062130337, -2083494649, -2102298084, -2118234228, -2131004066, -2140318239, -2145899254, -2147483647, -2144824076, -2137691325, -2125876202, -2109191326, -2087472775, -2060581586, -2028405100, -1990858130, -1947883956, -1899455120, -1845574026, -1786273343, -1721616190, -1651696115, -1576636859, -1496591913, -1411743859, -1322303505, -1228508828, -1130623710, -1028936497, -923758379, -815421607, -704277553, -590694642, -475056146, -357757884, -239205821, -119813597, ], [0, 457473231, 891228540, 1279746103, 1605659717, 1857256322, 2029360714, 2123519840, 2147483647, 2114061111, 2039500757, 1941596080, 1837741913, 1743165260, 1669524486, 1624018412, 1609079046, 1622647381, 1658960533, 1709719336, 1765465534, 1816981220, 1856531384, 1878801274, 1881428738, 1865090723, 1833164370, 1791038500, 1745193604, 1702192620, 1667728475, 1645857952, 1638518167, 1645377066, 1664019718, 1690424758, 1719646679, 1746594815, 1766791601, 1777001494, 1775645844, 1762953860, 1740840337, 1712540799, 1682068613, 1653581725, 1630756065, 1616257626, 1611386918, 1615941236, 1628306286, 1645754526, 1664897909, 1682221882, 1694618199, 1699837258, 1696795454, 1685696834, 1667957363, 1645949872, 1622613895, 1600993265, 1583773122, 1572885777, 1569242400, 1572626933, 1581763199, 1594539923, 1608355114, 1620524496, 1628690619, 1631170956, 1627194229, 1616992360, 1601738097, 1583341867, 1564142264, 1546539714, 1532630194, 1523894390, 1520987964, 1523662175, 1530823708, 1540721279, 1551227736, 1560172635, 1565673683, 1566416799, 1561843448, 1552218886, 1538573598, 1522529578, 1506040342, 1491086024, 1479370844, 1472068909, 1469655995, 1471851155, 1477674809, 1485612162, 1493854979, 1500583383, 1504243981, 1503782039, 1498793178, 1489573018, 1477059068, 1462675722, 1448107741, 1435038059, 1424890533, 1418616794, 1416558947, 1418407756, 1423261061, 1429771808, 1436361581, 1441465949, 1443773626, 1442423028, 1437126887, 1428207042, 1416535523, 1403392400, 1390263440, 1378609408, 1369642746, 1364145634, 1362356634, 1363942249, 1368056447, 1373477795, 1378802057, 1382660016, 1383926812, 1381890891, 1376357272, 1367670253, 1356653189, 1344475701, 1332469647, 1321922709, 1313881519, 1308994343, 1307416885, 1308794831, 1312324792, 1316883309, 1321203283, 1324070191, 1324507775, 1321924877, 1316201404, 1307700981, 1297209273, 1285808301, 1274706819, 1265053263, 1257760172, 1253366855, 1251960937, 1253170110, 1256224570, 1260079734, 1263579750, 1265636242, 1265394706, 1262363165, 1256483758, 1248136900, 1238078117, 1227317955, 1216964028, 1208049825, 1201376716, 1197393241, 1196129854, 1197198540, 1199856716, 1203124943, 1205939826, 1207318285, 1206507840, 1203099996, 1197089651, 1188871957, 1179177702, 1168957753, 1159234776, 1150945341, 1144796739, 1141160388, 1140017844, 1140967237, 1143288577, 1146057368, 1148288619, 1149088863, 1147792743, 1144063312, 1137940963, 1129833910, 1120452204, 1110695905, 1101515005, 1093762874, 1088065802, 1084728568, 1083690246, 1084536619, 1086566823, 1088903532, 1090629386, 1090928456, 1089210982, 1085202301, 1078982614, 1070971833, 1061862297, 1052510101, 1043802074, 1036519061, 1031216556, 1028140926, 1027193825, 1027949928, 1029724833, 1031682341, 1032964286, 1032822799, 1030734633, 1026480085, 1020174635, 1012248767, 1003379460, 994384216, 986094206, 979226208, 974273031, 971429182, 970562957, 971238946, 972787130, 974407639, 975294793, 974761214, 972342902, 967869225, 961487290, 953637221, 944982488, 936306292, 928390168, 921893624, 917253318, 914617198, 913823500, 914427582, 915772149, 917089806, 917621944, 916735547, 914019968, 909348863, 902897978, 895116297, 886655271, 878267272, 870689086, 864528487, 860171315, 857723293, 856995304, 857534186, 858694033, 859736787, 859946480, 858739451, 855753567, 850902837, 844389188, 836669809, 828385335, 820260156, 812990325, 807136431, 803037893, 800761785, 800093880, 800573100, 801563832, 802354772, 802268949, 800767906, 797534026, 792518412, 785947123, 778284999, 770162854, 762279445, 755293414, 749721947, 745861692, 743744048, 743131551, 743555715, 744390318, 744948669, 744589755, 742816878, 739353599, 734185392, 727560737, 719951656, 711979967, 704320745, 697598002, 692288672, 688649682, 686679254, 686118253, 686491216, 687180563, 687522423, 686909205, 684883080, 681205990, 675895497, 669221059, 661661490, 653830290, 646380499, 639903820, 634839585, 631407567, 629574903, 629062112, 629387115, 629940347, 630079256, 629227536, 626963799, 623086015, 617641909, 610920706, 603407673, 595708571, 588455782, 582210661, 577377166, 574140077, 572437214, 571969861, 572249635, 572674458, 572621828, 571544930, 569056763, 564989346, 559418940, 552653516, 545184512, 537610431, 530544163, 524518363, 519903502, 516851184, 515271405, 514847155, 515084003, 515386915, 515152372, 513861529, 511160049, 506912323, 501221783, 494414284, 486987193, 479532170, 472643589, 466826800, 462420372, 459544270, 458081918, 457698803, 457894665, 458081136, 457672779, 456177445, 453272005, 448851812, 443046325, 436198552, 428811599, 421470621, 414752307, 409135874, 404929315, 402222250, 400872580, 400528949, 400685450, 400760064, 400184678, 398492767, 395391191, 390805094, 384888993, 378002454, 370654154, 363423039, 356868802, 351445505, 347431680, 344887670, 343646731, 343341213, 343459705, 343426268, 342689487, 340807564, 337516342, 332769773, 326746648, 319822590, 312511716, 305387010, 298991743, 293755632, 289928664, 287542790, 286407335, 286138801, 286220392, 286082025, 285188458, 283121891, 279646320, 274743713, 268616487, 261655924, 254381480, 247360381, 241119946, 236066206, 232421347, 230189639, 229157052, 228924597, 228970175, 228729379, 227682714, 225435791, 221780094, 216724977, 210495971, 203499707, 196260907, 189341202, 183252341, 178377190, 174910720, 172830074, 171898316, 171701232, 171711488, 171370196, 170173278, 167749295, 163916711, 158711779, 152382763, 145351409, 138147656, 131327680, 125387947, 120688557, 117397703, 115465818, 114633382, 114471152, 114446590, 114006208, 112661096, 110062427, 106055274, 100702446, 94274671, 87208656, 80039536, 73318134, 67525848, 63000288, 59883173, 58098503, 57364386, 57236667, 57177615, 56639052, 55147061, 52375203, 48194926, 42695382, 36169607, 29069189, 21934458, 15310965, 9665171, 5312369, 2367974, 729699, 93382, 0, -93382, -729699, -2367974, -5312369, -9665171, -15310965, -21934458, -29069189, -36169607, -42695382, -48194926, -52375203, -55147061, -56639052, -57177615, -57236667, -57364386, -58098503, -59883173, -63000288, -67525848, -73318134, -80039536, -87208656, -94274671, -100702446, -106055274, -110062427, -112661096, -114006208, -114446590, -114471152, -114633382, -115465818, -117397703, -120688557, -125387947, -131327680, -138147656, -145351409, -152382763, -158711779, -163916711, -167749295, -170173278, -171370196, -171711488, -171701232, -171898316, -172830074, -174910720, -178377190, -183252341, -189341202, -196260907, -203499707, -210495971, -216724977, -221780094, -225435791, -227682714, -228729379, -228970175, -228924597, -229157052, -230189639, -232421347, -236066206, -241119946, -247360381, -254381480, -261655924, -268616487, -274743713, -279646320, -283121891, -285188458, -286082025, -286220392, -286138801, -286407335, -287542790, -289928664, -293755632, -298991743, -305387010, -312511716, -319822590, -326746648, -332769773, -337516342, -340807564, -342689487, -343426268, -343459705, -343341213, -343646731, -344887670, -347431680, -351445505, -356868802, -363423039, -370654154, -378002454, -384888993, -390805094, -395391191, -398492767, -400184678, -400760064, -400685450, -400528949, -400872580, -402222250, -404929315, -409135874, -414752307, -421470621, -428811599, -436198552, -443046325, -448851812, -453272005, -456177445, -457672779, -458081136, -457894665, -457698803, -458081918, -459544270, -462420372, -466826800, -472643589, -479532170, -486987193, -494414284, -501221783, -506912323, -511160049, -513861529, -515152372, -515386915, -515084003, -514847155, -515271405, -516851184, -519903502, -524518363, -530544163, -537610431, -545184512, -552653516, -559418940, -564989346, -569056763, -571544930, -572621828, -572674458, -572249635, -571969861, -572437214, -574140077, -577377166, -582210661, -588455782, -595708571, -603407673, -610920706, -617641909, -623086015, -626963799, -629227536, -630079256, -629940347, -629387115, -629062112, -629574903, -631407567, -634839585, -639903820, -646380499, -653830290, -661661490, -669221059, -675895497, -681205990, -684883080, -686909205, -687522423, -687180563, -686491216, -686118253, -686679254, -688649682, -692288672, -697598002, -704320745, -711979967, -719951656, -727560737, -734185392, -739353599, -742816878, -744589755, -744948669, -744390318, -743555715, -743131551, -743744048, -745861692, -749721947, -755293414, -762279445, -770162854, -778284999, -785947123, -792518412, -797534026, -800767906, -802268949, -802354772, -801563832, -800573100, -800093880, -800761785, -803037893, -807136431, -812990325, -820260156, -828385335, -836669809, -844389188, -850902837, -855753567, -858739451, -859946480, -859736787, -858694033, -857534186, -856995304, -857723293, -860171315, -864528487, -870689086, -878267272, -886655271, -895116297, -902897978, -909348863, -914019968, -916735547, -917621944, -917089806, -915772149, -914427582, -913823500, -914617198, -917253318, -921893624, -928390168, -936306292, -944982488, -953637221, -961487290, -967869225, -972342902, -974761214, -975294793, -974407639, -972787130, -971238946, -970562957, -971429182, -974273031, -979226208, -986094206, -994384216, -1003379460, -1012248767, -1020174635, -1026480085, -1030734633, -1032822799, -1032964286, -1031682341, -1029724833, -1027949928, -1027193825, -1028140926, -1031216556, -1036519061, -1043802074, -1052510101, -1061862297, -1070971833, -1078982614, -1085202301, -1089210982, -1090928456, -1090629386, -1088903532, -1086566823, -1084536619, -1083690246, -1084728568, -1088065802, -1093762874, -1101515005, -1110695905, -1120452204, -1129833910, -1137940963, -1144063312, -1147792743, -1149088863, -1148288619, -1146057368, -1143288577, -1140967237, -1140017844, -1141160388, -1144796739, -1150945341, -1159234776, -1168957753, -1179177702, -1188871957, -1197089651, -1203099996, -1206507840, -1207318285, -1205939826, -1203124943, -1199856716, -1197198540, -1196129854, -1197393241, -1201376716, -1208049825, -1216964028, -1227317955, -1238078117, -1248136900, -1256483758, -1262363165, -1265394706, -1265636242, -1263579750, -1260079734, -1256224570, -1253170110, -1251960937, -1253366855, -1257760172, -1265053263, -1274706819, -1285808301, -1297209273, -1307700981, -1316201404, -1321924877, -1324507775, -1324070191, -1321203283, -1316883309, -1312324792, -1308794831, -1307416885, -1308994343, -1313881519, -1321922709, -1332469647, -1344475701, -1356653189, -1367670253, -1376357272, -1381890891, -1383926812, -1382660016, -1378802057, -1373477795, -1368056447, -1363942249, -1362356634, -1364145634, -1369642746, -1378609408, -1390263440, -1403392400, -1416535523, -1428207042, -1437126887, -1442423028, -1443773626, -1441465949, -1436361581, -1429771808, -1423261061, -1418407756, -1416558947, -1418616794, -1424890533, -1435038059, -1448107741, -1462675722, -1477059068, -1489573018, -1498793178, -1503782039, -1504243981, -1500583383, -1493854979, -1485612162, -1477674809, -1471851155, -1469655995, -1472068909, -1479370844, -1491086024, -1506040342, -1522529578, -1538573598, -1552218886, -1561843448, -1566416799, -1565673683, -1560172635, -1551227736, -1540721279, -1530823708, -1523662175, -1520987964, -1523894390, -1532630194, -1546539714, -1564142264, -1583341867, -1601738097, -1616992360, -1627194229, -1631170956, -1628690619, -1620524496, -1608355114, -1594539923, -1581763199, -1572626933, -1569242400, -1572885777, -1583773122, -1600993265, -1622613895, -1645949872, -1667957363, -1685696834, -1696795454, -1699837258, -1694618199, -1682221882, -1664897909, -1645754526, -1628306286, -1615941236, -1611386918, -1616257626, -1630756065, -1653581725, -1682068613, -1712540799, -1740840337, -1762953860, -1775645844, -1777001494, -1766791601, -1746594815, -1719646679, -1690424758, -1664019718, -1645377066, -1638518167, -1645857952, -1667728475, -1702192620, -1745193604, -1791038500, -1833164370, -1865090723, -1881428738, -1878801274, -1856531384, -1816981220, -1765465534, -1709719336, -1658960533, -1622647381, -1609079046, -1624018412, -1669524486, -1743165260, -1837741913, -1941596080, -2039500757, -2114061111, -2147483647, -2123519840, -2029360714, -1857256322, -1605659717, -1279746103, -891228540, -457473231, ], [0, 456256694, 888967796, 1276763144, 1602384090, 1854169602, 2026935427, 2122161448, 2147483647, 2115567601, 2042510188, 1945966790, 1843226824, 1749458400, 1676314046, 1631038259, 1616151007, 1629708183, 1666069718, 1717047443, 1773264868, 1825544132, 1866142841, 1889693254, 1893743577, 1878859062, 1848300992, 1807356536, 1762434687, 1720068135, 1685965337, 1664241703, 1656926635, 1663799373, 1682557728, 1709276809, 1739076226, 1766888943, 1788215937, 1799758576, 1799843447, 1788588205, 1767796774, 1740611819, 1710986354, 1683059867, 1660534574, 1646143354, 1641283751, 1645865083, 1658382596, 1676198732, 1695981851, 1714231447, 1727808778, 1734393950, 1732804211, 1723131032, 1706681893, 1685742116, 1663198311, 1642084227, 1625119346, 1614309425, 1610666794, 1614088555, 1623406114, 1636593477, 1651098370, 1664242915, 1673631706, 1677505775, 1674990746, 1666204776, 1652213835, 1634845149, 1616390621, 1599247795, 1585554062, 1576869443, 1573954590, 1576675142, 1584043817, 1594390551, 1605631969, 1615597111, 1622358906, 1624521110, 1621418333, 1613200830, 1600793757, 1585739806, 1569951558, 1555412984, 1543876393, 1536600842, 1534170809, 1536420980, 1542476429, 1550899780, 1559920939, 1567712906, 1572670930, 1573652553, 1570142854, 1562321177, 1551021040, 1537591271, 1523681263, 1510984338, 1500978976, 1494707294, 1492623788, 1494536145, 1499645552, 1506678671, 1514089693, 1520300533, 1523942014, 1524059294, 1520250853, 1512720949, 1502238905, 1490012920, 1477498926, 1466174620, 1457313619, 1451794154, 1449970919, 1451628668, 1456023439, 1462003817, 1468192609, 1473200369, 1475837741, 1475294296, 1471257025, 1463951285, 1454099001, 1442801644, 1431366843, 1421105853, 1413133156, 1408198756, 1406578316, 1408037162, 1411872695, 1417027719, 1422256510, 1426317584, 1428163424, 1427098226, 1422879973, 1415751934, 1406399626, 1395840723, 1385265504, 1375852811, 1368589909, 1364123719, 1362665793, 1363964919, 1367350785, 1371841210, 1376295865, 1379592457, 1380798214, 1379310521, 1374945537, 1367961827, 1359016084, 1349058437, 1339183990, 1330463740, 1323780941, 1319697874, 1318373072, 1319541154, 1322557696, 1326501650, 1330319045, 1332985620, 1333663299, 1331826667, 1327340382, 1320476158, 1311867309, 1302408385, 1293115792, 1284971050, 1278770862, 1275006832, 1273792981, 1274851708, 1277559802, 1281046950, 1284331176, 1286470178, 1286705266, 1284575937, 1279987825, 1273224016, 1264898490, 1255859318, 1247056825, 1239397213, 1233604154, 1230109461, 1228989339, 1229955596, 1232402703, 1235503070, 1238335549, 1240027245, 1239886833, 1237509019, 1232834412, 1226155969, 1218071443, 1209389527, 1201004410, 1193758178, 1188312229, 1185047292, 1184007163, 1184894445, 1187118551, 1189888268, 1192334368, 1193643304, 1193181425, 1190590785, 1185842067, 1179236811, 1171358978, 1162983585, 1154956712, 1148065431, 1142917640, 1139850126, 1138878755, 1139698124, 1141730410, 1144215625, 1146329177, 1147308584, 1146569871, 1143795910, 1138983340, 1132441159, 1124741492, 1116630371, 1108912422, 1102327277, 1097436680, 1094539490, 1093627445, 1094388207, 1096254944, 1098494599, 1100321101, 1101016048, 1100038354, 1097106134, 1092238441, 1085750712, 1078204850, 1070321854, 1062870558, 1056549639, 1051881006, 1049130792, 1048269936, 1048980140, 1050704090, 1052731989, 1054311007, 1054760763, 1053577131, 1050508570, 1045593402, 1039152549, 1031739056, 1024052320, 1016830338, 1010736553, 1006258637, 1003634651, 1002817737, 1003484572, 1005086095, 1006932537, 1008299615, 1008539528, 1007179769, 1003994685, 999038968, 992638110, 985337467, 977817910, 970791089, 964890426, 960574513, 958057642, 957277981, 957908104, 959406099, 961099279, 962287575, 962350707, 960842770, 957559793, 952570056, 946202684, 938996390, 931616372, 924752164, 919012123, 914830695, 912402572, 911653721, 912253569, 913666361, 915233698, 916275547, 916194237, 914565546, 911203007, 906185692, 899845357, 892715029, 885447003, 878712864, 873100846, 869026213, 866668298, 865943731, 866519852, 867866134, 869335686, 870264293, 870071862, 868350771, 864927688, 859889496, 853569456, 846495812, 839310782, 832672334, 827153777, 823156476, 820848969, 820141696, 820701139, 822001106, 823403298, 824254806, 823987659, 822205261, 818742571, 813690908, 807383674, 800345228, 793210758, 786629394, 781165331, 777212012, 774932413, 774234464, 774785312, 776062202, 777432187, 778248522, 777949064, 776141728, 772664017, 767607610, 761304308, 754275498, 747152841, 740582249, 735125740, 731176062, 728897040, 728198693, 728750860, 730033281, 731414518, 732247734, 731968841, 730182110, 726720298, 721670167, 715359526, 708307760, 701147296, 694527909, 689018312, 685019922, 682705855, 681994389, 682560926, 683886698, 685336892, 686256427, 686068956, 684364142, 680960048, 675931156, 669597773, 662478322, 655211638, 648460936, 642813836, 638693510, 636294336, 635551796, 636151247, 637574345, 639176263, 640282210, 640288816, 638755204, 635470030, 630484382, 624105448, 616851757, 609376518, 602370394, 596458141, 592104539, 589543710, 588742504, 589403590, 591008040, 592891278, 594341296, 594704661, 593484604, 590416536, 585509632, 579048027, 571551156, 563698756, 556231355, 549840760, 545066631, 542214513, 541307606, 542079769, 544011213, 546402104, 548473698, 549482466, 548830612, 546156739, 541393096, 534780558, 526838543, 518293552, 509976125, 502700674, 497145419, 493749844, 492644888, 493626628, 496178116, 499537236, 502801793, 505057539, 505511286, 503610121, 499129295, 492215390, 483377362, 473425216, 463363439, 454252865, 447059447, 442510795, 440980927, 442420425, 446343527, 451876265, 457861589, 463009478, 466073529, 466031139, 462242983, 454569145, 443423939, 429758615, 414969955, 400742119, 388837917, 380862761, 378029046, 380949960, 389489492, 402689919, 418789804, 435335567, 449378940, 457742592, 457327805, 445432516, 420045926, 380087433, 325562923, 257619746, 178492179, 91340744, 0, -91340744, -178492179, -257619746, -325562923, -380087433, -420045926, -445432516, -457327805, -457742592, -449378940, -435335567, -418789804, -402689919, -389489492, -380949960, -378029046, -380862761, -388837917, -400742119, -414969955, -429758615, -443423939, -454569145, -462242983, -466031139, -466073529, -463009478, -457861589, -451876265, -446343527, -442420425, -440980927, -442510795, -447059447, -454252865, -463363439, -473425216, -483377362, -492215390, -499129295, -503610121, -505511286, -505057539, -502801793, -499537236, -496178116, -493626628, -492644888, -493749844, -497145419, -502700674, -509976125, -518293552, -526838543, -534780558, -541393096, -546156739, -548830612, -549482466, -548473698, -546402104, -544011213, -542079769, -541307606, -542214513, -545066631, -549840760, -556231355, -563698756, -571551156, -579048027, -585509632, -590416536, -593484604, -594704661, -594341296, -592891278, -591008040, -589403590, -588742504, -589543710, -592104539, -596458141, -602370394, -609376518, -616851757, -624105448, -630484382, -635470030, -638755204, -640288816, -640282210, -639176263, -637574345, -636151247, -635551796, -636294336, -638693510, -642813836, -648460936, -655211638, -662478322, -669597773, -675931156, -680960048, -684364142, -686068956, -686256427, -685336892, -683886698, -682560926, -681994389, -682705855, -685019922, -689018312, -694527909, -701147296, -708307760, -715359526, -721670167, -726720298, -730182110, -731968841, -732247734, -731414518, -730033281, -728750860, -728198693, -728897040, -731176062, -735125740, -740582249, -747152841, -754275498, -761304308, -767607610, -772664017, -776141728, -777949064, -778248522, -777432187, -776062202, -774785312, -774234464, -774932413, -777212012, -781165331, -786629394, -793210758, -800345228, -807383674, -813690908, -818742571, -822205261, -823987659, -824254806, -823403298, -822001106, -820701139, -820141696, -820848969, -823156476, -827153777, -832672334, -839310782, -846495812, -853569456, -859889496, -864927688, -868350771, -870071862, -870264293, -869335686, -867866134, -866519852, -865943731, -866668298, -869026213, -873100846, -878712864, -885447003, -892715029, -899845357, -906185692, -911203007, -914565546, -916194237, -916275547, -915233698, -913666361, -912253569, -911653721, -912402572, -914830695, -919012123, -924752164, -931616372, -938996390, -946202684, -952570056, -957559793, -960842770, -962350707, -962287575, -961099279, -959406099, -957908104, -957277981, -958057642, -960574513, -964890426, -970791089, -977817910, -985337467, -992638110, -999038968, -1003994685, -1007179769, -1008539528, -1008299615, -1006932537, -1005086095, -1003484572, -1002817737, -1003634651, -1006258637, -1010736553, -1016830338, -1024052320, -1031739056, -1039152549, -1045593402, -1050508570, -1053577131, -1054760763, -1054311007, -1052731989, -1050704090, -1048980140, -1048269936, -1049130792, -1051881006, -1056549639, -1062870558, -1070321854, -1078204850, -1085750712, -1092238441, -1097106134, -1100038354, -1101016048, -1100321101, -1098494599, -1096254944, -1094388207, -1093627445, -1094539490, -1097436680, -1102327277, -1108912422, -1116630371, -1124741492, -1132441159, -1138983340, -1143795910, -1146569871, -1147308584, -1146329177, -1144215625, -1141730410, -1139698124, -1138878755, -1139850126, -1142917640, -1148065431, -1154956712, -1162983585, -1171358978, -1179236811, -1185842067, -1190590785, -1193181425, -1193643304, -1192334368, -1189888268, -1187118551, -1184894445, -1184007163, -1185047292, -1188312229, -1193758178, -1201004410, -1209389527, -1218071443, -1226155969, -1232834412, -1237509019, -1239886833, -1240027245, -1238335549, -1235503070, -1232402703, -1229955596, -1228989339, -1230109461, -1233604154, -1239397213, -1247056825, -1255859318, -1264898490, -1273224016, -1279987825, -1284575937, -1286705266, -1286470178, -1284331176, -1281046950, -1277559802, -1274851708, -1273792981, -1275006832, -1278770862, -1284971050, -1293115792, -1302408385, -1311867309, -1320476158, -1327340382, -1331826667, -1333663299, -1332985620, -1330319045, -1326501650, -1322557696, -1319541154, -1318373072, -1319697874, -1323780941, -1330463740, -1339183990, -1349058437, -1359016084, -1367961827, -1374945537, -1379310521, -1380798214, -1379592457, -1376295865, -1371841210, -1367350785, -1363964919, -1362665793, -1364123719, -1368589909, -1375852811, -1385265504, -1395840723, -1406399626, -1415751934, -1422879973, -1427098226, -1428163424, -1426317584, -1422256510, -1417027719, -1411872695, -1408037162, -1406578316, -1408198756, -1413133156, -1421105853, -1431366843, -1442801644, -1454099001, -1463951285, -1471257025, -1475294296, -1475837741, -1473200369, -1468192609, -1462003817, -1456023439, -1451628668, -1449970919, -1451794154, -1457313619, -1466174620, -1477498926, -1490012920, -1502238905, -1512720949, -1520250853, -1524059294, -1523942014, -1520300533, -1514089693, -1506678671, -1499645552, -1494536145, -1492623788, -1494707294, -1500978976, -1510984338, -1523681263, -1537591271, -1551021040, -1562321177, -1570142854, -1573652553, -1572670930, -1567712906, -1559920939, -1550899780, -1542476429, -1536420980, -1534170809, -1536600842, -1543876393, -1555412984, -1569951558, -1585739806, -1600793757, -1613200830, -1621418333, -1624521110, -1622358906, -1615597111, -1605631969, -1594390551, -1584043817, -1576675142, -1573954590, -1576869443, -1585554062, -1599247795, -1616390621, -1634845149, -1652213835, -1666204776, -1674990746, -1677505775, -1673631706, -1664242915, -1651098370, -1636593477, -1623406114, -1614088555, -1610666794, -1614309425, -1625119346, -1642084227, -1663198311, -1685742116, -1706681893, -1723131032, -1732804211, -1734393950, -1727808778, -1714231447, -1695981851, -1676198732, -1658382596, -1645865083, -1641283751, -1646143354, -1660534574, -1683059867, -1710986354, -1740611819, -1767796774, -1788588205, -1799843447, -1799758576, -1788215937, -1766888943, -1739076226, -1709276809, -1682557728, -1663799373, -1656926635, -1664241703, -1685965337, -1720068135, -1762434687, -1807356536, -1848300992, -1878859062, -1893743577, -1889693254, -1866142841, -1825544132, -1773264868, -1717047443, -1666069718, -1629708183, -1616151007, -1631038259, -1676314046, -1749458400, -1843226824, -1945966790, -2042510188, -2115567601, -2147483647, -2122161448, -2026935427, -1854169602, -1602384090, -1276763144, -888967796, -456256694, ], [0, 454444154, 885599477, 1272318785, 1597503681, 1849570647, 2023321953, 2120137558, 2147483647, 2117812143, 2046993988, 1952478780, 1851398880, 1758834649, 1686429920, 1641497241, 1626687635, 1640228185, 1676661806, 1727965708, 1784885221, 1838302151, 1880463105, 1905921388, 1912091651, 1899372728, 1870853288, 1831669038, 1788122451, 1746701149, 1713136730, 1691631947, 1684353704, 1691247062, 1710177805, 1737364781, 1768024617, 1797125488, 1820136397, 1833664716, 1835895841, 1826781224, 1807959598, 1782435279, 1754071358, 1726979822, 1704902048, 1690670577, 1685827520, 1690449099, 1703193770, 1721558040, 1742294312, 1761923009, 1777259952, 1785880518, 1786454232, 1778904840, 1764378190, 1745029215, 1723665679, 1703306307, 1686721744, 1676027180, 1672385661, 1675862887, 1685450556, 1699249738, 1714782231, 1729379685, 1740590150, 1746540762, 1746203540, 1739527139, 1727418443, 1711580710, 1694236282, 1677778406, 1664406176, 1655797819, 1652870410, 1655660008, 1663337307, 1674353257, 1686689702, 1698174922, 1706815125, 1711091650, 1710179906, 1704058802, 1693496564, 1679917733, 1665173898, 1651254748, 1639984240, 1632748000, 1630292459, 1632624594, 1639025396, 1648172842, 1658353666, 1667730255, 1674621332, 1677753746, 1676447874, 1670709820, 1661218161, 1649209143, 1636279461, 1624137911, 1614344362, 1608075744, 1605954007, 1607961047, 1613452023, 1621263433, 1629898045, 1637757436, 1643386174, 1645690468, 1644098578, 1638639567, 1629929742, 1619070215, 1607472470, 1596639426, 1587935850, 1582383084, 1580508839, 1582274064, 1587086864, 1593900149, 1601377020, 1608097885, 1612777301, 1614457519, 1612649681, 1607401930, 1599285072, 1589299035, 1578715353, 1568880401, 1561009754, 1556004994, 1554320514, 1555899895, 1560190704, 1566234474, 1572817325, 1578657605, 1582601629, 1583797677, 1581822076, 1576738771, 1569084074, 1559779784, 1549988654, 1540934825, 1533716901, 1529142136, 1527606723, 1529039872, 1532919505, 1538356416, 1544233403, 1549377639, 1552739742, 1553552252, 1551443633, 1546490898, 1539203491, 1530441523, 1521281462, 1512850184, 1506152948, 1501921496, 1500505193, 1501821367, 1505371845, 1510322573, 1515633628, 1520219388, 1523114246, 1523618588, 1521403024, 1516555358, 1509563671, 1501238612, 1492587252, 1484658092, 1478381045, 1474426783, 1473106693, 1474328317, 1477612643, 1482170083, 1487023153, 1491156791, 1493673250, 1493927940, 1491625713, 1486863222, 1480111317, 1472140604, 1463901904, 1456380139, 1450444068, 1446714773, 1445472776, 1446617648, 1449685907, 1453924060, 1458405317, 1462171910, 1464381187, 1464433227, 1462060660, 1457367289, 1450809941, 1443126687, 1435222677, 1428031248, 1422371579, 1418824590, 1417645857, 1418728584, 1421621961, 1425601801, 1429782397, 1433252341, 1435213491, 1435101935, 1432672617, 1428034962, 1421634341, 1414182474, 1406547653, 1399621684, 1394183934, 1390783179, 1389655148, 1390688140, 1393441698, 1397215253, 1401156035, 1404389662, 1406153412, 1405911884, 1403437536, 1398843999, 1392567301, 1385298094, 1377875406, 1371158256, 1365894746, 1362608584, 1361520212, 1362514413, 1365159140, 1368772494, 1372527490, 1375578505, 1377190116, 1376848688, 1374339819, 1369779967, 1363597623, 1356467041, 1349204798, 1342645019, 1337512332, 1334311879, 1333253061, 1334218522, 1336782937, 1340278619, 1343897817, 1346816042, 1348317616, 1347904337, 1345370770, 1340834816, 1334719009, 1327685517, 1320534826, 1314083623, 1309040465, 1305898171, 1304859246, 1305805612, 1308317160, 1311736212, 1315267992, 1318101783, 1319534305, 1319076557, 1316527909, 1312006231, 1305929555, 1298952108, 1291864512, 1285473375, 1280478571, 1277366885, 1276338164, 1277275148, 1279761540, 1283145523, 1286639029, 1289437661, 1290843020, 1290368875, 1287815031, 1283297699, 1277231780, 1270267753, 1263192773, 1256811010, 1251821395, 1248711346, 1247682612, 1248620499, 1251111165, 1254504360, 1258012118, 1260828420, 1262251672, 1261791421, 1259243078, 1254719299, 1248633213, 1241636003, 1234518269, 1228090100, 1223058026, 1219917530, 1218877435, 1219827700, 1222355561, 1225807659, 1229388803, 1232282445, 1233774643, 1233362716, 1230832069, 1226289486, 1220147723, 1213063649, 1205839177, 1199299934, 1194169982, 1190961605, 1189896872, 1190872998, 1193476856, 1197046611, 1200771282, 1203813267, 1205435357, 1205112987, 1202614683, 1198038389, 1191797994, 1184561909, 1177152801, 1170423498, 1165127764, 1161805491, 1160699752, 1161718434, 1164446470, 1168207069, 1172162922, 1175442274, 1177270915, 1177090131, 1174642705, 1170013754, 1163619966, 1156148554, 1148454859, 1141433819, 1135884602, 1132388811, 1131220794, 1132303825, 1135219121, 1139266701, 1143569262, 1147203703, 1149340609, 1149370677, 1146998880, 1142291855, 1135670988, 1127851758, 1119738056, 1112287040, 1106364685, 1102613749, 1101354239, 1102531694, 1105721587, 1110189724, 1115000096, 1119154394, 1121742453, 1122081035, 1119819881, 1114998642, 1108045558, 1099717389, 1090988965, 1082908423, 1076439519, 1072314671, 1070921005, 1072237046, 1075830258, 1080916641, 1086474262, 1091394357, 1094647779, 1095441923, 1093344342, 1088353921, 1080908052, 1071823745, 1062180452, 1053161299, 1045875948, 1041191433, 1039596588, 1041121073, 1045322175, 1051342620, 1058032052, 1064115111, 1068381742, 1069871564, 1068024437, 1062773894, 1054568164, 1044314070, 1033250519, 1022769006, 1014206886, 1008643818, 1006732035, 1008586749, 1013754647, 1021267207, 1029773030, 1037731458, 1043640095, 1046263073, 1044825846, 1039146387, 1029681215, 1017476798, 1004030667, 991080156, 980348027, 973281571, 970823962, 973253131, 980114619, 990261852, 1002001854, 1013328701, 1022213405, 1026909367, 1026228612, 1019746556, 1007901801, 991971495, 973920212, 956138746, 941106085, 931020583, 927453050, 931073874, 941498199, 957278357, 976053108, 994841241, 1010445734, 1019917032, 1021012610, 1012586785, 994850481, 969454994, 939375177, 908593301, 881611846, 862848150, 855982729, 863343541, 885408674, 920499567, 964716765, 1012142584, 1055303260, 1085850909, 1095397148, 1076409029, 1023067002, 931985470, 802709332, 637923434, 443343334, 227291643, 0, -227291643, -443343334, -637923434, -802709332, -931985470, -1023067002, -1076409029, -1095397148, -1085850909, -1055303260, -1012142584, -964716765, -920499567, -885408674, -863343541, -855982729, -862848150, -881611846, -908593301, -939375177, -969454994, -994850481, -1012586785, -1021012610, -1019917032, -1010445734, -994841241, -976053108, -957278357, -941498199, -931073874, -927453050, -931020583, -941106085, -956138746, -973920212, -991971495, -1007901801, -1019746556, -1026228612, -1026909367, -1022213405, -1013328701, -1002001854, -990261852, -980114619, -973253131, -970823962, -973281571, -980348027, -991080156, -1004030667, -1017476798, -1029681215, -1039146387, -1044825846, -1046263073, -1043640095, -1037731458, -1029773030, -1021267207, -1013754647, -1008586749, -1006732035, -1008643818, -1014206886, -1022769006, -1033250519, -1044314070, -1054568164, -1062773894, -1068024437, -1069871564, -1068381742, -1064115111, -1058032052, -1051342620, -1045322175, -1041121073, -1039596588, -1041191433, -1045875948, -1053161299, -1062180452, -1071823745, -1080908052, -1088353921, -1093344342, -1095441923, -1094647779, -1091394357, -1086474262, -1080916641, -1075830258, -1072237046, -1070921005, -1072314671, -1076439519, -1082908423, -1090988965, -1099717389, -1108045558, -1114998642, -1119819881, -1122081035, -1121742453, -1119154394, -1115000096, -1110189724, -1105721587, -1102531694, -1101354239, -1102613749, -1106364685, -1112287040, -1119738056, -1127851758, -1135670988, -1142291855, -1146998880, -1149370677, -1149340609, -1147203703, -1143569262, -1139266701, -1135219121, -1132303825, -1131220794, -1132388811, -1135884602, -1141433819, -1148454859, -1156148554, -1163619966, -1170013754, -1174642705, -1177090131, -1177270915, -1175442274, -1172162922, -1168207069, -1164446470, -1161718434, -1160699752, -1161805491, -1165127764, -1170423498, -1177152801, -1184561909, -1191797994, -1198038389, -1202614683, -1205112987, -1205435357, -1203813267, -1200771282, -1197046611, -1193476856, -1190872998, -1189896872, -1190961605, -1194169982, -1199299934, -1205839177, -1213063649, -1220147723, -1226289486, -1230832069, -1233362716, -1233774643, -1232282445, -1229388803, -1225807659, -1222355561, -1219827700, -1218877435, -1219917530, -1223058026, -1228090100, -1234518269, -1241636003, -1248633213, -1254719299, -1259243078, -1261791421, -1262251672, -1260828420, -1258012118, -1254504360, -1251111165, -1248620499, -1247682612, -1248711346, -1251821395, -1256811010, -1263192773, -1270267753, -1277231780, -1283297699, -1287815031, -1290368875, -1290843020, -1289437661, -1286639029, -1283145523, -1279761540, -1277275148, -1276338164, -1277366885, -1280478571, -1285473375, -1291864512, -1298952108, -1305929555, -1312006231, -1316527909, -1319076557, -1319534305, -1318101783, -1315267992, -1311736212, -1308317160, -1305805612, -1304859246, -1305898171, -1309040465, -1314083623, -1320534826, -1327685517, -1334719009, -1340834816, -1345370770, -1347904337, -1348317616, -1346816042, -1343897817, -1340278619, -1336782937, -1334218522, -1333253061, -1334311879, -1337512332, -1342645019, -1349204798, -1356467041, -1363597623, -1369779967, -1374339819, -1376848688, -1377190116, -1375578505, -1372527490, -1368772494, -1365159140, -1362514413, -1361520212, -1362608584, -1365894746, -1371158256, -1377875406, -1385298094, -1392567301, -1398843999, -1403437536, -1405911884, -1406153412, -1404389662, -1401156035, -1397215253, -1393441698, -1390688140, -1389655148, -1390783179, -1394183934, -1399621684, -1406547653, -1414182474, -1421634341, -1428034962, -1432672617, -1435101935, -1435213491, -1433252341, -1429782397, -1425601801, -1421621961, -1418728584, -1417645857, -1418824590, -1422371579, -1428031248, -1435222677, -1443126687, -1450809941, -1457367289, -1462060660, -1464433227, -1464381187, -1462171910, -1458405317, -1453924060, -1449685907, -1446617648, -1445472776, -1446714773, -1450444068, -1456380139, -1463901904, -1472140604, -1480111317, -1486863222, -1491625713, -1493927940, -1493673250, -1491156791, -1487023153, -1482170083, -1477612643, -1474328317, -1473106693, -1474426783, -1478381045, -1484658092, -1492587252, -1501238612, -1509563671, -1516555358, -1521403024, -1523618588, -1523114246, -1520219388, -1515633628, -1510322573, -1505371845, -1501821367, -1500505193, -1501921496, -1506152948, -1512850184, -1521281462, -1530441523, -1539203491, -1546490898, -1551443633, -1553552252, -1552739742, -1549377639, -1544233403, -1538356416, -1532919505, -1529039872, -1527606723, -1529142136, -1533716901, -1540934825, -1549988654, -1559779784, -1569084074, -1576738771, -1581822076, -1583797677, -1582601629, -1578657605, -1572817325, -1566234474, -1560190704, -1555899895, -1554320514, -1556004994, -1561009754, -1568880401, -1578715353, -1589299035, -1599285072, -1607401930, -1612649681, -1614457519, -1612777301, -1608097885, -1601377020, -1593900149, -1587086864, -1582274064, -1580508839, -1582383084, -1587935850, -1596639426, -1607472470, -1619070215, -1629929742, -1638639567, -1644098578, -1645690468, -1643386174, -1637757436, -1629898045, -1621263433, -1613452023, -1607961047, -1605954007, -1608075744, -1614344362, -1624137911, -1636279461, -1649209143, -1661218161, -1670709820, -1676447874, -1677753746, -1674621332, -1667730255, -1658353666, -1648172842, -1639025396, -1632624594, -1630292459, -1632748000, -1639984240, -1651254748, -1665173898, -1679917733, -1693496564, -1704058802, -1710179906, -1711091650, -1706815125, -1698174922, -1686689702, -1674353257, -1663337307, -1655660008, -1652870410, -1655797819, -1664406176, -1677778406, -1694236282, -1711580710, -1727418443, -1739527139, -1746203540, -1746540762, -1740590150, -1729379685, -1714782231, -1699249738, -1685450556, -1675862887, -1672385661, -1676027180, -1686721744, -1703306307, -1723665679, -1745029215, -1764378190, -1778904840, -1786454232, -1785880518, -1777259952, -1761923009, -1742294312, -1721558040, -1703193770, -1690449099, -1685827520, -1690670577, -1704902048, -1726979822, -1754071358, -1782435279, -1807959598, -1826781224, -1835895841, -1833664716, -1820136397, -1797125488, -1768024617, -1737364781, -1710177805, -1691247062, -1684353704, -1691631947, -1713136730, -1746701149, -1788122451, -1831669038, -1870853288, -1899372728, -1912091651, -1905921388, -1880463105, -1838302151, -1784885221, -1727965708, -1676661806, -1640228185, -1626687635, -1641497241, -1686429920, -1758834649, -1851398880, -1952478780, -2046993988, -2117812143, -2147483647, -2120137558, -2023321953, -1849570647, -1597503681, -1272318785, -885599477, -454444154, ], [0, 451455533, 880045594, 1264990663, 1589456573, 1841987619, 2017363843, 2116800449, 2147483647, 2121513077, 2054387141, 1963216134, 1864873450, 1774294764, 1703109572, 1658742630, 1644061051, 1657574186, 1694126667, 1745968389, 1804045546, 1859338327, 1904075202, 1932679297, 1942345042, 1933196876, 1908038847, 1871756930, 1830477956, 1790615239, 1757938530, 1736794603, 1729577079, 1736504436, 1755719423, 1783677891, 1815756438, 1846981292, 1872768741, 1889571166, 1895341163, 1889756135, 1874182440, 1851396276, 1825112463, 1799397643, 1778057767, 1764089697, 1759273922, 1763961865, 1777081086, 1796349152, 1818657038, 1840559678, 1858797975, 1870774618, 1874915562, 1870867995, 1859511258, 1842785294, 1823367856, 1804252897, 1788295425, 1777791068, 1774151380, 1777720062, 1787753104, 1802561089, 1819787949, 1836781047, 1850995195, 1860369734, 1863623408, 1860425392, 1851420252, 1838106844, 1822592816, 1807264326, 1794422209, 1785939596, 1782991486, 1785894929, 1794081105, 1806200498, 1820342491, 1834334105, 1846071537, 1853834280, 1856535241, 1853870804, 1846350440, 1835203873, 1822182127, 1809284322, 1798452546, 1791281123, 1788783526, 1791250806, 1798221047, 1808562425, 1820655372, 1832644779, 1842723189, 1849401955, 1851729883, 1849427429, 1842917698, 1833251297, 1821938033, 1810712219, 1801267920, 1795004354, 1792819580, 1794982738, 1801102869, 1810197572, 1820849712, 1831427331, 1840332774, 1846243148, 1848306036, 1846261638, 1840473951, 1831867521, 1821780541, 1811757520, 1803313516, 1797705840, 1795747488, 1797689924, 1803191996, 1811378628, 1820979359, 1830524894, 1838571352, 1843918078, 1845786261, 1843931845, 1838676459, 1830852604, 1821672298, 1812539823, 1804837440, 1799716668, 1797926594, 1799704720, 1804746216, 1812255403, 1821070909, 1829844849, 1837248483, 1842173005, 1843895223, 1842183397, 1837327822, 1830092211, 1821593932, 1813131821, 1805988060, 1801234270, 1799571091, 1801225226, 1805919011, 1812916543, 1821138755, 1829329496, 1836247109, 1840852332, 1842464139, 1840860196, 1836307151, 1829516691, 1821534551, 1813579747, 1806858708, 1802382591, 1800815415, 1802375770, 1806806632, 1813417392, 1821191280, 1828941507, 1835492180, 1839856460, 1841384993, 1839862348, 1835537134, 1829081658, 1821487603, 1813913907, 1807510129, 1803242203, 1801746937, 1803237158, 1807471607, 1813793808, 1821233680, 1828656193, 1834934312, 1839119912, 1840586787, 1839124189, 1834966963, 1828757990, 1821448906, 1814154430, 1807982552, 1803866432, 1802423479, 1803862865, 1807955315, 1814069516, 1821269376, 1828457180, 1834540766, 1838599291, 1840022464, 1838602198, 1834562953, 1828526350, 1821415620, 1814314552, 1808302489, 1804290436, 1802883164, 1804288153, 1808285064, 1814260227, 1821300762, 1828333903, 1834290288, 1838266392, 1839661437, 1838268079, 1834303173, 1828374071, 1821385688, 1814402523, 1808486672, 1804536448, 1803150107, 1804535334, 1808478168, 1814376013, 1821329640, 1828280161, 1834170119, 1838104215, 1839485266, 1838104769, 1834174345, 1828293336, 1821357495, 1814422685, 1808544294, 1804616772, 1803237661, 1804616772, 1808544294, 1814422685, 1821357495, 1828293336, 1834174345, 1838104769, 1839485266, 1838104215, 1834170119, 1828280161, 1821329640, 1814376013, 1808478168, 1804535334, 1803150107, 1804536448, 1808486672, 1814402523, 1821385688, 1828374071, 1834303173, 1838268079, 1839661437, 1838266392, 1834290288, 1828333903, 1821300762, 1814260227, 1808285064, 1804288153, 1802883164, 1804290436, 1808302489, 1814314552, 1821415620, 1828526350, 1834562953, 1838602198, 1840022464, 1838599291, 1834540766, 1828457180, 1821269376, 1814069516, 1807955315, 1803862865, 1802423479, 1803866432, 1807982552, 1814154430, 1821448906, 1828757990, 1834966963, 1839124189, 1840586787, 1839119912, 1834934312, 1828656193, 1821233680, 1813793808, 1807471607, 1803237158, 1801746937, 1803242203, 1807510129, 1813913907, 1821487603, 1829081658, 1835537134, 1839862348, 1841384993, 1839856460, 1835492180, 1828941507, 1821191280, 1813417392, 1806806632, 1802375770, 1800815415, 1802382591, 1806858708, 1813579747, 1821534551, 1829516691, 1836307151, 1840860196, 1842464139, 1840852332, 1836247109, 1829329496, 1821138755, 1812916543, 1805919011, 1801225226, 1799571091, 1801234270, 1805988060, 1813131821, 1821593932, 1830092211, 1837327822, 1842183397, 1843895223, 1842173005, 1837248483, 1829844849, 1821070909, 1812255403, 1804746216, 1799704720, 1797926594, 1799716668, 1804837440, 1812539823, 1821672298, 1830852604, 1838676459, 1843931845, 1845786261, 1843918078, 1838571352, 1830524894, 1820979359, 1811378628, 1803191996, 1797689924, 1795747488, 1797705840, 1803313516, 1811757520, 1821780541, 1831867521, 1840473951, 1846261638, 1848306036, 1846243148, 1840332774, 1831427331, 1820849712, 1810197572, 1801102869, 1794982738, 1792819580, 1795004354, 1801267920, 1810712219, 1821938033, 1833251297, 1842917698, 1849427429, 1851729883, 1849401955, 1842723189, 1832644779, 1820655372, 1808562425, 1798221047, 1791250806, 1788783526, 1791281123, 1798452546, 1809284322, 1822182127, 1835203873, 1846350440, 1853870804, 1856535241, 1853834280, 1846071537, 1834334105, 1820342491, 1806200498, 1794081105, 1785894929, 1782991486, 1785939596, 1794422209, 1807264326, 1822592816, 1838106844, 1851420252, 1860425392, 1863623408, 1860369734, 1850995195, 1836781047, 1819787949, 1802561089, 1787753104, 1777720062, 1774151380, 1777791068, 1788295425, 1804252897, 1823367856, 1842785294, 1859511258, 1870867995, 1874915562, 1870774618, 1858797975, 1840559678, 1818657038, 1796349152, 1777081086, 1763961865, 1759273922, 1764089697, 1778057767, 1799397643, 1825112463, 1851396276, 1874182440, 1889756135, 1895341163, 1889571166, 1872768741, 1846981292, 1815756438, 1783677891, 1755719423, 1736504436, 1729577079, 1736794603, 1757938530, 1790615239, 1830477956, 1871756930, 1908038847, 1933196876, 1942345042, 1932679297, 1904075202, 1859338327, 1804045546, 1745968389, 1694126667, 1657574186, 1644061051, 1658742630, 1703109572, 1774294764, 1864873450, 1963216134, 2054387141, 2121513077, 2147483647, 2116800449, 2017363843, 1841987619, 1589456573, 1264990663, 880045594, 451455533, 0, -451455533, -880045594, -1264990663, -1589456573, -1841987619, -2017363843, -2116800449, -2147483647, -2121513077, -2054387141, -1963216134, -1864873450, -1774294764, -1703109572, -1658742630, -1644061051, -1657574186, -1694126667, -1745968389, -1804045546, -1859338327, -1904075202, -1932679297, -1942345042, -1933196876, -1908038847, -1871756930, -1830477956, -1790615239, -1757938530, -1736794603, -1729577079, -1736504436, -1755719423, -1783677891, -1815756438, -1846981292, -1872768741, -1889571166, -1895341163, -1889756135, -1874182440, -1851396276, -1825112463, -1799397643, -1778057767, -1764089697, -1759273922, -1763961865, -1777081086, -1796349152, -1818657038, -1840559678, -1858797975, -1870774618, -1874915562, -1870867995, -1859511258, -1842785294, -1823367856, -1804252897, -1788295425, -1777791068, -1774151380, -1777720062, -1787753104, -1802561089, -1819787949, -1836781047, -1850995195, -1860369734, -1863623408, -1860425392, -1851420252, -1838106844, -1822592816, -1807264326, -1794422209, -1785939596, -1782991486, -1785894929, -1794081105, -1806200498, -1820342491, -1834334105, -1846071537, -1853834280, -1856535241, -1853870804, -1846350440, -1835203873, -1822182127, -1809284322, -1798452546, -1791281123, -1788783526, -1791250806, -1798221047, -1808562425, -1820655372, -1832644779, -1842723189, -1849401955, -1851729883, -1849427429, -1842917698, -1833251297, -1821938033, -1810712219, -1801267920, -1795004354, -1792819580, -1794982738, -1801102869, -1810197572, -1820849712, -1831427331, -1840332774, -1846243148, -1848306036, -1846261638, -1840473951, -1831867521, -1821780541, -1811757520, -1803313516, -1797705840, -1795747488, -1797689924, -1803191996, -1811378628, -1820979359, -1830524894, -1838571352, -1843918078, -1845786261, -1843931845, -1838676459, -1830852604, -1821672298, -1812539823, -1804837440, -1799716668, -1797926594, -1799704720, -1804746216, -1812255403, -1821070909, -1829844849, -1837248483, -1842173005, -1843895223, -1842183397, -1837327822, -1830092211, -1821593932, -1813131821, -1805988060, -1801234270, -1799571091, -1801225226, -1805919011, -1812916543, -1821138755, -1829329496, -1836247109, -1840852332, -1842464139, -1840860196, -1836307151, -1829516691, -1821534551, -1813579747, -1806858708, -1802382591, -1800815415, -1802375770, -1806806632, -1813417392, -1821191280, -1828941507, -1835492180, -1839856460, -1841384993, -1839862348, -1835537134, -1829081658, -1821487603, -1813913907, -1807510129, -1803242203, -1801746937, -1803237158, -1807471607, -1813793808, -1821233680, -1828656193, -1834934312, -1839119912, -1840586787, -1839124189, -1834966963, -1828757990, -1821448906, -1814154430, -1807982552, -1803866432, -1802423479, -1803862865, -1807955315, -1814069516, -1821269376, -1828457180, -1834540766, -1838599291, -1840022464, -1838602198, -1834562953, -1828526350, -1821415620, -1814314552, -1808302489, -1804290436, -1802883164, -1804288153, -1808285064, -1814260227, -1821300762, -1828333903, -1834290288, -1838266392, -1839661437, -1838268079, -1834303173, -1828374071, -1821385688, -1814402523, -1808486672, -1804536448, -1803150107, -1804535334, -1808478168, -1814376013, -1821329640, -1828280161, -1834170119, -1838104215, -1839485266, -1838104769, -1834174345, -1828293336, -1821357495, -1814422685, -1808544294, -1804616772, -1803237661, -1804616772, -1808544294, -1814422685, -1821357495, -1828293336, -1834174345, -1838104769, -1839485266, -1838104215, -1834170119, -1828280161, -1821329640, -1814376013, -1808478168, -1804535334, -1803150107, -1804536448, -1808486672, -1814402523, -1821385688, -1828374071, -1834303173, -1838268079, -1839661437, -1838266392, -1834290288, -1828333903, -1821300762, -1814260227, -1808285064, -1804288153, -1802883164, -1804290436, -1808302489, -1814314552, -1821415620, -1828526350, -1834562953, -1838602198, -1840022464, -1838599291, -1834540766, -1828457180, -1821269376, -1814069516, -1807955315, -1803862865, -1802423479, -1803866432, -1807982552, -1814154430, -1821448906, -1828757990, -1834966963, -1839124189, -1840586787, -1839119912, -1834934312, -1828656193, -1821233680, -1813793808, -1807471607, -1803237158, -1801746937, -1803242203, -1807510129, -1813913907, -1821487603, -1829081658, -1835537134, -1839862348, -1841384993, -1839856460, -1835492180, -1828941507, -1821191280, -1813417392, -1806806632, -1802375770, -1800815415, -1802382591, -1806858708, -1813579747, -1821534551, -1829516691, -1836307151, -1840860196, -1842464139, -1840852332, -1836247109, -1829329496, -1821138755, -1812916543, -1805919011, -1801225226, -1799571091, -1801234270, -1805988060, -1813131821, -1821593932, -1830092211, -1837327822, -1842183397, -1843895223, -1842173005, -1837248483, -1829844849, -1821070909, -1812255403, -1804746216, -1799704720, -1797926594, -1799716668, -1804837440, -1812539823, -1821672298, -1830852604, -1838676459, -1843931845, -1845786261, -1843918078, -1838571352, -1830524894, -1820979359, -1811378628, -1803191996, -1797689924, -1795747488, -1797705840, -1803313516, -1811757520, -1821780541, -1831867521, -1840473951, -1846261638, -1848306036, -1846243148, -1840332774, -1831427331, -1820849712, -1810197572, -1801102869, -1794982738, -1792819580, -1795004354, -1801267920, -1810712219, -1821938033, -1833251297, -1842917698, -1849427429, -1851729883, -1849401955, -1842723189, -1832644779, -1820655372, -1808562425, -1798221047, -1791250806, -1788783526, -1791281123, -1798452546, -1809284322, -1822182127, -1835203873, -1846350440, -1853870804, -1856535241, -1853834280, -1846071537, -1834334105, -1820342491, -1806200498, -1794081105, -1785894929, -1782991486, -1785939596, -1794422209, -1807264326, -1822592816, -1838106844, -1851420252, -1860425392, -1863623408, -1860369734, -1850995195, -1836781047, -1819787949, -1802561089, -1787753104, -1777720062, -1774151380, -1777791068, -1788295425, -1804252897, -1823367856, -1842785294, -1859511258, -1870867995, -1874915562, -1870774618, -1858797975, -1840559678, -1818657038, -1796349152, -1777081086, -1763961865, -1759273922, -1764089697, -1778057767, -1799397643, -1825112463, -1851396276, -1874182440, -1889756135, -1895341163, -1889571166, -1872768741, -1846981292, -1815756438, -1783677891, -1755719423, -1736504436, -1729577079, -1736794603, -1757938530, -1790615239, -1830477956, -1871756930, -1908038847, -1933196876, -1942345042, -1932679297, -1904075202, -1859338327, -1804045546, -1745968389, -1694126667, -1657574186, -1644061051, -1658742630, -1703109572, -1774294764, -1864873450, -1963216134, -2054387141, -2121513077, -2147483647, -2116800449, -2017363843, -1841987619, -1589456573, -1264990663, -880045594, -451455533, ], ];
@@ -0,0 +1,2 @@
+#[allow(clippy::approx_constant)]
+pub static PULSE_F32: [[f32; 1024]; 10] = [[0.27183834, 0.91429865, 0.76328987, 0.85290277, 0.7889341, 0.8387683, 0.7978966, 0.8325759, 0.8024318, 0.82911116, 0.80516505, 0.8269, 0.8069905, 0.8253675, 0.8082952, 0.82424355, 0.80927354, 0.82338434, 0.810034, 0.8227065, 0.81064177, 0.8221585, 0.81113845, 0.8217064, 0.81155163, 0.8213273, 0.8119007, 0.821005, 0.8121992, 0.82072777, 0.81245726, 0.82048696, 0.8126825, 0.82027584, 0.81288064, 0.8200895, 0.81305623, 0.8199238, 0.81321275, 0.8197757, 0.8133531, 0.8196426, 0.81347954, 0.8195224, 0.8135939, 0.81941336, 0.8136979, 0.8193142, 0.8137927, 0.8192235, 0.8138794, 0.81914055, 0.8139589, 0.8190643, 0.8140321, 0.8189941, 0.8140995, 0.8189293, 0.8141618, 0.81886935, 0.8142194, 0.81881386, 0.8142729, 0.81876236, 0.8143225, 0.81871456, 0.8143686, 0.81867003, 0.8144116, 0.8186286, 0.8144515, 0.81859, 0.8144888, 0.818554, 0.8145236, 0.8185204, 0.81455606, 0.8184891, 0.81458634, 0.8184598, 0.8146146, 0.8184325, 0.814641, 0.818407, 0.8146656, 0.8183832, 0.8146886, 0.81836104, 0.81470996, 0.8183404, 0.8147299, 0.81832117, 0.8147484, 0.81830335, 0.8147656, 0.81828684, 0.8147815, 0.8182715, 0.8147962, 0.8182574, 0.81480974, 0.8182444, 0.8148222, 0.81823254, 0.8148335, 0.8182217, 0.81484383, 0.8182119, 0.8148532, 0.8182031, 0.81486154, 0.81819516, 0.8148689, 0.81818825, 0.8148754, 0.81818223, 0.81488097, 0.8181771, 0.8148857, 0.8181729, 0.8148895, 0.8181695, 0.8148924, 0.8181669, 0.81489456, 0.81816524, 0.8148958, 0.8181644, 0.8148962, 0.8181644, 0.8148958, 0.81816524, 0.81489456, 0.8181669, 0.8148924, 0.8181695, 0.8148895, 0.8181729, 0.8148857, 0.8181771, 0.81488097, 0.81818223, 0.8148754, 0.81818825, 0.8148689, 0.81819516, 0.81486154, 0.8182031, 0.8148532, 0.8182119, 0.81484383, 0.8182217, 0.8148335, 0.81823254, 0.8148222, 0.8182444, 0.81480974, 0.8182574, 0.8147962, 0.8182715, 0.8147815, 0.81828684, 0.8147656, 0.81830335, 0.8147484, 0.81832117, 0.8147299, 0.8183404, 0.81470996, 0.81836104, 0.8146886, 0.8183832, 0.8146656, 0.818407, 0.814641, 0.8184325, 0.8146146, 0.8184598, 0.81458634, 0.8184891, 0.81455606, 0.8185204, 0.8145236, 0.818554, 0.8144888, 0.81859, 0.8144515, 0.8186286, 0.8144116, 0.81867003, 0.8143686, 0.81871456, 0.8143225, 0.81876236, 0.8142729, 0.81881386, 0.8142194, 0.81886935, 0.8141618, 0.8189293, 0.8140995, 0.8189941, 0.8140321, 0.8190643, 0.8139589, 0.81914055, 0.8138794, 0.8192235, 0.8137927, 0.8193142, 0.8136979, 0.81941336, 0.8135939, 0.8195224, 0.81347954, 0.8196426, 0.8133531, 0.8197757, 0.81321275, 0.8199238, 0.81305623, 0.8200895, 0.81288064, 0.82027584, 0.8126825, 0.82048696, 0.81245726, 0.82072777, 0.8121992, 0.821005, 0.8119007, 0.8213273, 0.81155163, 0.8217064, 0.81113845, 0.8221585, 0.81064177, 0.8227065, 0.810034, 0.82338434, 0.80927354, 0.82424355, 0.8082952, 0.8253675, 0.8069905, 0.8269, 0.80516505, 0.82911116, 0.8024318, 0.8325759, 0.7978966, 0.8387683, 0.7889341, 0.85290277, 0.76328987, 0.91429865, 0.27183834, -0.3692683, -0.21961328, -0.3078723, -0.24525766, -0.29373765, -0.25422043, -0.28754494, -0.25875595, -0.28407976, -0.26148966, -0.2818681, -0.26331568, -0.280335, -0.26462102, -0.2792103, -0.26560014, -0.27835026, -0.26636147, -0.27767158, -0.26697025, -0.2771225, -0.26746798, -0.27666926, -0.26788238, -0.27628893, -0.2682327, -0.27596524, -0.2685326, -0.27568656, -0.26879224, -0.27544415, -0.2690191, -0.2752314, -0.26921898, -0.27504325, -0.26939642, -0.27487567, -0.2695549, -0.27472553, -0.26969734, -0.27459025, -0.269826, -0.27446777, -0.26994273, -0.27435637, -0.27004912, -0.27425465, -0.2701465, -0.2741614, -0.27023587, -0.27407563, -0.2703182, -0.2739965, -0.27039433, -0.27392328, -0.27046484, -0.27385533, -0.27053034, -0.27379212, -0.27059138, -0.27373317, -0.27064833, -0.27367812, -0.2707016, -0.27362657, -0.27075154, -0.27357817, -0.27079841, -0.27353272, -0.2708425, -0.27348992, -0.27088404, -0.27344957, -0.27092326, -0.27341148, -0.2709603, -0.27337545, -0.27099535, -0.27334133, -0.27102858, -0.27330896, -0.27106008, -0.27327827, -0.27109003, -0.27324906, -0.2711185, -0.27322128, -0.27114558, -0.27319485, -0.27117142, -0.27316964, -0.27119604, -0.27314556, -0.27121955, -0.27312258, -0.27124202, -0.2731006, -0.27126354, -0.27307957, -0.2712841, -0.27305946, -0.2713038, -0.27304015, -0.2713227, -0.27302167, -0.27134082, -0.2730039, -0.27135822, -0.27298686, -0.27137494, -0.27297047, -0.271391, -0.2729547, -0.27140644, -0.27293953, -0.2714213, -0.27292496, -0.27143565, -0.2729109, -0.27144945, -0.27289736, -0.27146274, -0.27288428, -0.27147558, -0.27287167, -0.27148795, -0.2728595, -0.2714999, -0.27284777, -0.27151147, -0.27283642, -0.2715226, -0.27282545, -0.2715334, -0.27281484, -0.27154383, -0.2728046, -0.27155393, -0.27279466, -0.2715637, -0.27278504, -0.27157316, -0.27277574, -0.2715823, -0.2727667, -0.2715912, -0.27275798, -0.2715998, -0.2727495, -0.27160814, -0.2727413, -0.27161622, -0.27273333, -0.2716241, -0.27272558, -0.2716317, -0.2727181, -0.27163908, -0.2727108, -0.27164626, -0.27270374, -0.27165323, -0.27269685, -0.27166003, -0.27269018, -0.27166662, -0.27268368, -0.27167302, -0.27267736, -0.27167922, -0.27267122, -0.2716853, -0.27266526, -0.27169117, -0.27265945, -0.2716969, -0.2726538, -0.27170247, -0.2726483, -0.2717079, -0.27264294, -0.27171317, -0.27263772, -0.27171832, -0.27263266, -0.27172333, -0.2726277, -0.27172822, -0.2726229, -0.27173296, -0.27261823, -0.27173758, -0.27261364, -0.2717421, -0.2726092, -0.2717465, -0.27260485, -0.27175078, -0.27260062, -0.27175495, -0.2725965, -0.27175903, -0.27259248, -0.271763, -0.27258855, -0.27176687, -0.27258474, -0.27177066, -0.272581, -0.27177432, -0.27257738, -0.27177793, -0.27257383, -0.2717814, -0.27257037, -0.27178484, -0.27256697, -0.27178818, -0.2725637, -0.27179143, -0.27256048, -0.27179462, -0.27255732, -0.27179772, -0.27255428, -0.27180076, -0.27255127, -0.2718037, -0.27254835, -0.27180657, -0.27254552, -0.2718094, -0.27254274, -0.27181214, -0.27254003, -0.27181482, -0.27253738, -0.27181745, -0.2725348, -0.27181998, -0.27253225, -0.27182248, -0.2725298, -0.27182493, -0.2725274, -0.27182728, -0.27252504, -0.2718296, -0.27252278, -0.27183187, -0.27252054, -0.27183408, -0.27251834, -0.27183622, -0.27251622, -0.27183834, -0.27251413, -0.2718404, -0.2725121, -0.2718424, -0.27251014, -0.27184433, -0.2725082, -0.27184623, -0.27250633, -0.2718481, -0.2725045, -0.2718499, -0.27250272, -0.2718517, -0.27250096, -0.2718534, -0.27249926, -0.2718551, -0.2724976, -0.27185673, -0.27249599, -0.2718583, -0.2724944, -0.27185985, -0.2724929, -0.27186137, -0.2724914, -0.27186286, -0.27248994, -0.2718643, -0.2724885, -0.2718657, -0.27248713, -0.27186704, -0.2724858, -0.27186838, -0.27248448, -0.27186966, -0.27248323, -0.2718709, -0.27248198, -0.27187213, -0.2724808, -0.2718733, -0.27247962, -0.27187446, -0.2724785, -0.27187556, -0.2724774, -0.27187663, -0.27247635, -0.27187768, -0.2724753, -0.2718787, -0.27247432, -0.27187967, -0.27247334, -0.27188063, -0.2724724, -0.27188155, -0.2724715, -0.27188244, -0.27247062, -0.2718833, -0.2724698, -0.27188414, -0.27246895, -0.27188495, -0.27246818, -0.27188572, -0.27246743, -0.27188647, -0.2724667, -0.27188715, -0.272466, -0.27188784, -0.27246532, -0.27188852, -0.2724647, -0.27188915, -0.27246407, -0.27188975, -0.27246347, -0.2718903, -0.2724629, -0.27189088, -0.27246237, -0.2718914, -0.27246186, -0.2718919, -0.27246138, -0.27189234, -0.27246094, -0.2718928, -0.27246052, -0.2718932, -0.2724601, -0.2718936, -0.27245972, -0.27189395, -0.2724594, -0.2718943, -0.27245906, -0.2718946, -0.27245876, -0.2718949, -0.2724585, -0.27189514, -0.27245823, -0.27189538, -0.27245802, -0.2718956, -0.2724578, -0.2718958, -0.27245763, -0.27189595, -0.27245748, -0.2718961, -0.27245736, -0.27189618, -0.27245727, -0.27189627, -0.27245718, -0.27189633, -0.27245715, -0.2718964, -0.27245712, -0.2718964, -0.27245712, -0.2718964, -0.27245715, -0.27189633, -0.27245718, -0.27189627, -0.27245727, -0.27189618, -0.27245736, -0.2718961, -0.27245748, -0.27189595, -0.27245763, -0.2718958, -0.2724578, -0.2718956, -0.27245802, -0.27189538, -0.27245823, -0.27189514, -0.2724585, -0.2718949, -0.27245876, -0.2718946, -0.27245906, -0.2718943, -0.2724594, -0.27189395, -0.27245972, -0.2718936, -0.2724601, -0.2718932, -0.27246052, -0.2718928, -0.27246094, -0.27189234, -0.27246138, -0.2718919, -0.27246186, -0.2718914, -0.27246237, -0.27189088, -0.2724629, -0.2718903, -0.27246347, -0.27188975, -0.27246407, -0.27188915, -0.2724647, -0.27188852, -0.27246532, -0.27188784, -0.272466, -0.27188715, -0.2724667, -0.27188647, -0.27246743, -0.27188572, -0.27246818, -0.27188495, -0.27246895, -0.27188414, -0.2724698, -0.2718833, -0.27247062, -0.27188244, -0.2724715, -0.27188155, -0.2724724, -0.27188063, -0.27247334, -0.27187967, -0.27247432, -0.2718787, -0.2724753, -0.27187768, -0.27247635, -0.27187663, -0.2724774, -0.27187556, -0.2724785, -0.27187446, -0.27247962, -0.2718733, -0.2724808, -0.27187213, -0.27248198, -0.2718709, -0.27248323, -0.27186966, -0.27248448, -0.27186838, -0.2724858, -0.27186704, -0.27248713, -0.2718657, -0.2724885, -0.2718643, -0.27248994, -0.27186286, -0.2724914, -0.27186137, -0.2724929, -0.27185985, -0.2724944, -0.2718583, -0.27249599, -0.27185673, -0.2724976, -0.2718551, -0.27249926, -0.2718534, -0.27250096, -0.2718517, -0.27250272, -0.2718499, -0.2725045, -0.2718481, -0.27250633, -0.27184623, -0.2725082, -0.27184433, -0.27251014, -0.2718424, -0.2725121, -0.2718404, -0.27251413, -0.27183834, -0.27251622, -0.27183622, -0.27251834, -0.27183408, -0.27252054, -0.27183187, -0.27252278, -0.2718296, -0.27252504, -0.27182728, -0.2725274, -0.27182493, -0.2725298, -0.27182248, -0.27253225, -0.27181998, -0.2725348, -0.27181745, -0.27253738, -0.27181482, -0.27254003, -0.27181214, -0.27254274, -0.2718094, -0.27254552, -0.27180657, -0.27254835, -0.2718037, -0.27255127, -0.27180076, -0.27255428, -0.27179772, -0.27255732, -0.27179462, -0.27256048, -0.27179143, -0.2725637, -0.27178818, -0.27256697, -0.27178484, -0.27257037, -0.2717814, -0.27257383, -0.27177793, -0.27257738, -0.27177432, -0.272581, -0.27177066, -0.27258474, -0.27176687, -0.27258855, -0.271763, -0.27259248, -0.27175903, -0.2725965, -0.27175495, -0.27260062, -0.27175078, -0.27260485, -0.2717465, -0.2726092, -0.2717421, -0.27261364, -0.27173758, -0.27261823, -0.27173296, -0.2726229, -0.27172822, -0.2726277, -0.27172333, -0.27263266, -0.27171832, -0.27263772, -0.27171317, -0.27264294, -0.2717079, -0.2726483, -0.27170247, -0.2726538, -0.2716969, -0.27265945, -0.27169117, -0.27266526, -0.2716853, -0.27267122, -0.27167922, -0.27267736, -0.27167302, -0.27268368, -0.27166662, -0.27269018, -0.27166003, -0.27269685, -0.27165323, -0.27270374, -0.27164626, -0.2727108, -0.27163908, -0.2727181, -0.2716317, -0.27272558, -0.2716241, -0.27273333, -0.27161622, -0.2727413, -0.27160814, -0.2727495, -0.2715998, -0.27275798, -0.2715912, -0.2727667, -0.2715823, -0.27277574, -0.27157316, -0.27278504, -0.2715637, -0.27279466, -0.27155393, -0.2728046, -0.27154383, -0.27281484, -0.2715334, -0.27282545, -0.2715226, -0.27283642, -0.27151147, -0.27284777, -0.2714999, -0.2728595, -0.27148795, -0.27287167, -0.27147558, -0.27288428, -0.27146274, -0.27289736, -0.27144945, -0.2729109, -0.27143565, -0.27292496, -0.2714213, -0.27293953, -0.27140644, -0.2729547, -0.271391, -0.27297047, -0.27137494, -0.27298686, -0.27135822, -0.2730039, -0.27134082, -0.27302167, -0.2713227, -0.27304015, -0.2713038, -0.27305946, -0.2712841, -0.27307957, -0.27126354, -0.2731006, -0.27124202, -0.27312258, -0.27121955, -0.27314556, -0.27119604, -0.27316964, -0.27117142, -0.27319485, -0.27114558, -0.27322128, -0.2711185, -0.27324906, -0.27109003, -0.27327827, -0.27106008, -0.27330896, -0.27102858, -0.27334133, -0.27099535, -0.27337545, -0.2709603, -0.27341148, -0.27092326, -0.27344957, -0.27088404, -0.27348992, -0.2708425, -0.27353272, -0.27079841, -0.27357817, -0.27075154, -0.27362657, -0.2707016, -0.27367812, -0.27064833, -0.27373317, -0.27059138, -0.27379212, -0.27053034, -0.27385533, -0.27046484, -0.27392328, -0.27039433, -0.2739965, -0.2703182, -0.27407563, -0.27023587, -0.2741614, -0.2701465, -0.27425465, -0.27004912, -0.27435637, -0.26994273, -0.27446777, -0.269826, -0.27459025, -0.26969734, -0.27472553, -0.2695549, -0.27487567, -0.26939642, -0.27504325, -0.26921898, -0.2752314, -0.2690191, -0.27544415, -0.26879224, -0.27568656, -0.2685326, -0.27596524, -0.2682327, -0.27628893, -0.26788238, -0.27666926, -0.26746798, -0.2771225, -0.26697025, -0.27767158, -0.26636147, -0.27835026, -0.26560014, -0.2792103, -0.26462102, -0.280335, -0.26331568, -0.2818681, -0.26148966, -0.28407976, -0.25875595, -0.28754494, -0.25422043, -0.29373765, -0.24525766, -0.3078723, -0.21961328, -0.3692683, ], [0.2714999, 0.7472114, 0.91464233, 0.82954997, 0.76294094, 0.81134635, 0.8532572, 0.81927013, 0.7885742, 0.81484634, 0.83913386, 0.81766665, 0.79752535, 0.81571305, 0.832953, 0.81714547, 0.8020488, 0.81605077, 0.8295002, 0.8169142, 0.8047699, 0.8162161, 0.8273015, 0.81679195, 0.8065826, 0.8163091, 0.82578194, 0.8167196, 0.80787414, 0.8163665, 0.8246713, 0.8166732, 0.8088388, 0.81640446, 0.8238261, 0.81664175, 0.80958503, 0.8164308, 0.82316285, 0.81661946, 0.81017804, 0.8164499, 0.8226299, 0.816603, 0.8106592, 0.8164642, 0.82219356, 0.81659055, 0.8110563, 0.81647515, 0.8218309, 0.81658083, 0.81138855, 0.81648374, 0.8215258, 0.81657314, 0.81166947, 0.81649065, 0.82126653, 0.81656694, 0.8119092, 0.81649625, 0.82104445, 0.8165618, 0.81211525, 0.8165009, 0.82085305, 0.8165576, 0.8122933, 0.81650484, 0.8206873, 0.816554, 0.8124478, 0.8165081, 0.8205432, 0.8165509, 0.8125822, 0.816511, 0.8204178, 0.8165482, 0.81269926, 0.8165135, 0.8203085, 0.8165459, 0.81280124, 0.8165156, 0.8202134, 0.8165439, 0.81288993, 0.8165176, 0.82013077, 0.816542, 0.81296676, 0.8165193, 0.8200594, 0.81654036, 0.8130329, 0.8165209, 0.8199982, 0.8165389, 0.81308943, 0.81652236, 0.8199463, 0.8165375, 0.81313694, 0.8165237, 0.81990296, 0.8165362, 0.8131762, 0.8165249, 0.8198676, 0.816535, 0.8132077, 0.8165261, 0.81983984, 0.8165338, 0.8132318, 0.8165273, 0.81981933, 0.8165327, 0.8132488, 0.8165284, 0.8198058, 0.8165316, 0.81325895, 0.81652945, 0.81979907, 0.8165305, 0.81326234, 0.8165305, 0.81979907, 0.81652945, 0.81325895, 0.8165316, 0.8198058, 0.8165284, 0.8132488, 0.8165327, 0.81981933, 0.8165273, 0.8132318, 0.8165338, 0.81983984, 0.8165261, 0.8132077, 0.816535, 0.8198676, 0.8165249, 0.8131762, 0.8165362, 0.81990296, 0.8165237, 0.81313694, 0.8165375, 0.8199463, 0.81652236, 0.81308943, 0.8165389, 0.8199982, 0.8165209, 0.8130329, 0.81654036, 0.8200594, 0.8165193, 0.81296676, 0.816542, 0.82013077, 0.8165176, 0.81288993, 0.8165439, 0.8202134, 0.8165156, 0.81280124, 0.8165459, 0.8203085, 0.8165135, 0.81269926, 0.8165482, 0.8204178, 0.816511, 0.8125822, 0.8165509, 0.8205432, 0.8165081, 0.8124478, 0.816554, 0.8206873, 0.81650484, 0.8122933, 0.8165576, 0.82085305, 0.8165009, 0.81211525, 0.8165618, 0.82104445, 0.81649625, 0.8119092, 0.81656694, 0.82126653, 0.81649065, 0.81166947, 0.81657314, 0.8215258, 0.81648374, 0.81138855, 0.81658083, 0.8218309, 0.81647515, 0.8110563, 0.81659055, 0.82219356, 0.8164642, 0.8106592, 0.816603, 0.8226299, 0.8164499, 0.81017804, 0.81661946, 0.82316285, 0.8164308, 0.80958503, 0.81664175, 0.8238261, 0.81640446, 0.8088388, 0.8166732, 0.8246713, 0.8163665, 0.80787414, 0.8167196, 0.82578194, 0.8163091, 0.8065826, 0.81679195, 0.8273015, 0.8162161, 0.8047699, 0.8169142, 0.8295002, 0.81605077, 0.8020488, 0.81714547, 0.832953, 0.81571305, 0.79752535, 0.81766665, 0.83913386, 0.81484634, 0.7885742, 0.81927013, 0.8532572, 0.81134635, 0.76294094, 0.82954997, 0.91464233, 0.7472114, 0.2714999, -0.20285787, -0.36893505, -0.28519657, -0.2199415, -0.2669927, -0.30754906, -0.27491686, -0.245576, -0.27049255, -0.2934241, -0.27331352, -0.2545292, -0.27135915, -0.2872408, -0.2727925, -0.25905553, -0.27169672, -0.28378472, -0.2725613, -0.26178026, -0.2718619, -0.28158185, -0.27243918, -0.26359764, -0.27195475, -0.2800573, -0.27236694, -0.26489455, -0.27201203, -0.2789409, -0.27232075, -0.2658655, -0.2720498, -0.2780889, -0.27228945, -0.26661894, -0.27207607, -0.277418, -0.27226725, -0.26722002, -0.272095, -0.2768765, -0.27225095, -0.26771027, -0.27210912, -0.27643064, -0.27223864, -0.2681174, -0.2721199, -0.27605745, -0.27222914, -0.26846063, -0.27212834, -0.27574077, -0.2722216, -0.26875368, -0.27213508, -0.27546886, -0.27221557, -0.2690066, -0.27214053, -0.27523306, -0.27221066, -0.26922694, -0.272145, -0.27502677, -0.27220657, -0.26942047, -0.2721487, -0.27484488, -0.27220318, -0.2695917, -0.2721518, -0.27468345, -0.27220032, -0.26974416, -0.27215445, -0.27453926, -0.2721979, -0.26988065, -0.2721567, -0.27440983, -0.27219582, -0.27000353, -0.27215865, -0.27429307, -0.272194, -0.27011463, -0.27216032, -0.27418724, -0.27219242, -0.2702155, -0.27216178, -0.27409098, -0.27219105, -0.27030745, -0.27216306, -0.27400306, -0.27218986, -0.27039158, -0.27216423, -0.27392253, -0.27218878, -0.27046877, -0.2721652, -0.2738485, -0.27218783, -0.2705398, -0.2721661, -0.2737803, -0.272187, -0.27060533, -0.2721669, -0.27371725, -0.27218622, -0.27066597, -0.27216765, -0.27365887, -0.27218553, -0.2707222, -0.27216828, -0.2736047, -0.2721849, -0.27077448, -0.27216887, -0.27355427, -0.27218437, -0.27082315, -0.2721694, -0.27350727, -0.27218387, -0.27086854, -0.2721699, -0.2734634, -0.2721834, -0.27091098, -0.27217034, -0.27342236, -0.27218297, -0.27095068, -0.27217072, -0.27338392, -0.27218258, -0.27098793, -0.2721711, -0.27334785, -0.27218223, -0.2710229, -0.27217144, -0.27331394, -0.2721819, -0.27105573, -0.27217177, -0.27328208, -0.2721816, -0.27108666, -0.27217203, -0.27325204, -0.27218133, -0.2711158, -0.2721723, -0.27322373, -0.27218106, -0.27114332, -0.27217257, -0.27319703, -0.27218083, -0.2711693, -0.27217278, -0.27317175, -0.27218062, -0.27119383, -0.27217302, -0.27314788, -0.2721804, -0.27121708, -0.2721732, -0.2731253, -0.2721802, -0.27123907, -0.2721734, -0.2731039, -0.27218002, -0.2712599, -0.27217355, -0.27308357, -0.27217987, -0.2712797, -0.27217373, -0.27306432, -0.2721797, -0.27129844, -0.27217388, -0.27304605, -0.27217954, -0.27131626, -0.27217403, -0.2730287, -0.27217942, -0.27133316, -0.27217415, -0.2730122, -0.27217928, -0.27134925, -0.2721743, -0.27299652, -0.27217916, -0.27136454, -0.27217442, -0.27298158, -0.27217904, -0.2713791, -0.27217454, -0.2729674, -0.27217892, -0.27139294, -0.27217463, -0.2729539, -0.27217883, -0.27140614, -0.27217475, -0.27294102, -0.27217874, -0.2714187, -0.27217484, -0.27292877, -0.27217862, -0.27143064, -0.27217492, -0.2729171, -0.27217853, -0.27144203, -0.272175, -0.27290598, -0.27217847, -0.2714529, -0.2721751, -0.2728954, -0.27217838, -0.27146325, -0.27217516, -0.2728853, -0.2721783, -0.27147308, -0.27217525, -0.27287567, -0.27217823, -0.27148247, -0.2721753, -0.27286652, -0.27217817, -0.2714914, -0.27217537, -0.2728578, -0.27217808, -0.2714999, -0.27217546, -0.2728495, -0.27217802, -0.271508, -0.27217552, -0.27284157, -0.27217796, -0.27151573, -0.27217558, -0.27283406, -0.2721779, -0.2715231, -0.27217564, -0.2728269, -0.27217785, -0.27153006, -0.2721757, -0.2728201, -0.2721778, -0.27153668, -0.27217573, -0.27281365, -0.27217776, -0.271543, -0.2721758, -0.2728075, -0.2721777, -0.27154896, -0.27217585, -0.27280167, -0.27217764, -0.27155462, -0.27217588, -0.27279615, -0.2721776, -0.27155998, -0.27217594, -0.27279094, -0.27217755, -0.27156505, -0.27217597, -0.27278602, -0.27217752, -0.27156985, -0.27217603, -0.27278137, -0.27217746, -0.27157438, -0.27217606, -0.27277696, -0.27217743, -0.27157864, -0.27217612, -0.27277285, -0.27217737, -0.27158263, -0.27217615, -0.27276897, -0.27217734, -0.27158636, -0.27217618, -0.27276537, -0.2721773, -0.27158988, -0.27217624, -0.27276197, -0.27217725, -0.27159312, -0.27217627, -0.27275884, -0.27217722, -0.27159616, -0.2721763, -0.27275592, -0.2721772, -0.27159896, -0.27217633, -0.27275324, -0.27217716, -0.27160153, -0.27217638, -0.27275077, -0.27217713, -0.27160388, -0.2721764, -0.27274853, -0.2721771, -0.27160603, -0.27217644, -0.2727465, -0.27217704, -0.27160794, -0.27217647, -0.2727447, -0.272177, -0.27160966, -0.2721765, -0.27274308, -0.27217698, -0.27161115, -0.27217653, -0.27274168, -0.27217695, -0.27161247, -0.27217656, -0.27274048, -0.27217692, -0.27161357, -0.2721766, -0.27273947, -0.2721769, -0.27161446, -0.27217662, -0.2727387, -0.27217686, -0.27161515, -0.27217665, -0.2727381, -0.27217683, -0.27161565, -0.2721767, -0.27273768, -0.2721768, -0.27161595, -0.27217674, -0.2727375, -0.27217677, -0.27161604, -0.27217677, -0.2727375, -0.27217674, -0.27161595, -0.2721768, -0.27273768, -0.2721767, -0.27161565, -0.27217683, -0.2727381, -0.27217665, -0.27161515, -0.27217686, -0.2727387, -0.27217662, -0.27161446, -0.2721769, -0.27273947, -0.2721766, -0.27161357, -0.27217692, -0.27274048, -0.27217656, -0.27161247, -0.27217695, -0.27274168, -0.27217653, -0.27161115, -0.27217698, -0.27274308, -0.2721765, -0.27160966, -0.272177, -0.2727447, -0.27217647, -0.27160794, -0.27217704, -0.2727465, -0.27217644, -0.27160603, -0.2721771, -0.27274853, -0.2721764, -0.27160388, -0.27217713, -0.27275077, -0.27217638, -0.27160153, -0.27217716, -0.27275324, -0.27217633, -0.27159896, -0.2721772, -0.27275592, -0.2721763, -0.27159616, -0.27217722, -0.27275884, -0.27217627, -0.27159312, -0.27217725, -0.27276197, -0.27217624, -0.27158988, -0.2721773, -0.27276537, -0.27217618, -0.27158636, -0.27217734, -0.27276897, -0.27217615, -0.27158263, -0.27217737, -0.27277285, -0.27217612, -0.27157864, -0.27217743, -0.27277696, -0.27217606, -0.27157438, -0.27217746, -0.27278137, -0.27217603, -0.27156985, -0.27217752, -0.27278602, -0.27217597, -0.27156505, -0.27217755, -0.27279094, -0.27217594, -0.27155998, -0.2721776, -0.27279615, -0.27217588, -0.27155462, -0.27217764, -0.27280167, -0.27217585, -0.27154896, -0.2721777, -0.2728075, -0.2721758, -0.271543, -0.27217776, -0.27281365, -0.27217573, -0.27153668, -0.2721778, -0.2728201, -0.2721757, -0.27153006, -0.27217785, -0.2728269, -0.27217564, -0.2715231, -0.2721779, -0.27283406, -0.27217558, -0.27151573, -0.27217796, -0.27284157, -0.27217552, -0.271508, -0.27217802, -0.2728495, -0.27217546, -0.2714999, -0.27217808, -0.2728578, -0.27217537, -0.2714914, -0.27217817, -0.27286652, -0.2721753, -0.27148247, -0.27217823, -0.27287567, -0.27217525, -0.27147308, -0.2721783, -0.2728853, -0.27217516, -0.27146325, -0.27217838, -0.2728954, -0.2721751, -0.2714529, -0.27217847, -0.27290598, -0.272175, -0.27144203, -0.27217853, -0.2729171, -0.27217492, -0.27143064, -0.27217862, -0.27292877, -0.27217484, -0.2714187, -0.27217874, -0.27294102, -0.27217475, -0.27140614, -0.27217883, -0.2729539, -0.27217463, -0.27139294, -0.27217892, -0.2729674, -0.27217454, -0.2713791, -0.27217904, -0.27298158, -0.27217442, -0.27136454, -0.27217916, -0.27299652, -0.2721743, -0.27134925, -0.27217928, -0.2730122, -0.27217415, -0.27133316, -0.27217942, -0.2730287, -0.27217403, -0.27131626, -0.27217954, -0.27304605, -0.27217388, -0.27129844, -0.2721797, -0.27306432, -0.27217373, -0.2712797, -0.27217987, -0.27308357, -0.27217355, -0.2712599, -0.27218002, -0.2731039, -0.2721734, -0.27123907, -0.2721802, -0.2731253, -0.2721732, -0.27121708, -0.2721804, -0.27314788, -0.27217302, -0.27119383, -0.27218062, -0.27317175, -0.27217278, -0.2711693, -0.27218083, -0.27319703, -0.27217257, -0.27114332, -0.27218106, -0.27322373, -0.2721723, -0.2711158, -0.27218133, -0.27325204, -0.27217203, -0.27108666, -0.2721816, -0.27328208, -0.27217177, -0.27105573, -0.2721819, -0.27331394, -0.27217144, -0.2710229, -0.27218223, -0.27334785, -0.2721711, -0.27098793, -0.27218258, -0.27338392, -0.27217072, -0.27095068, -0.27218297, -0.27342236, -0.27217034, -0.27091098, -0.2721834, -0.2734634, -0.2721699, -0.27086854, -0.27218387, -0.27350727, -0.2721694, -0.27082315, -0.27218437, -0.27355427, -0.27216887, -0.27077448, -0.2721849, -0.2736047, -0.27216828, -0.2707222, -0.27218553, -0.27365887, -0.27216765, -0.27066597, -0.27218622, -0.27371725, -0.2721669, -0.27060533, -0.272187, -0.2737803, -0.2721661, -0.2705398, -0.27218783, -0.2738485, -0.2721652, -0.27046877, -0.27218878, -0.27392253, -0.27216423, -0.27039158, -0.27218986, -0.27400306, -0.27216306, -0.27030745, -0.27219105, -0.27409098, -0.27216178, -0.2702155, -0.27219242, -0.27418724, -0.27216032, -0.27011463, -0.272194, -0.27429307, -0.27215865, -0.27000353, -0.27219582, -0.27440983, -0.2721567, -0.26988065, -0.2721979, -0.27453926, -0.27215445, -0.26974416, -0.27220032, -0.27468345, -0.2721518, -0.2695917, -0.27220318, -0.27484488, -0.2721487, -0.26942047, -0.27220657, -0.27502677, -0.272145, -0.26922694, -0.27221066, -0.27523306, -0.27214053, -0.2690066, -0.27221557, -0.27546886, -0.27213508, -0.26875368, -0.2722216, -0.27574077, -0.27212834, -0.26846063, -0.27222914, -0.27605745, -0.2721199, -0.2681174, -0.27223864, -0.27643064, -0.27210912, -0.26771027, -0.27225095, -0.2768765, -0.272095, -0.26722002, -0.27226725, -0.277418, -0.27207607, -0.26661894, -0.27228945, -0.2780889, -0.2720498, -0.2658655, -0.27232075, -0.2789409, -0.27201203, -0.26489455, -0.27236694, -0.2800573, -0.27195475, -0.26359764, -0.27243918, -0.28158185, -0.2718619, -0.26178026, -0.2725613, -0.28378472, -0.27169672, -0.25905553, -0.2727925, -0.2872408, -0.27135915, -0.2545292, -0.27331352, -0.2934241, -0.27049255, -0.245576, -0.27491686, -0.30754906, -0.2669927, -0.2199415, -0.28519657, -0.36893505, -0.20285787, ], [0.27082315, 0.5342412, 0.74721813, 0.87605834, 0.91534024, 0.88712513, 0.829543, 0.7802571, 0.76222116, 0.7775709, 0.81135356, 0.84217817, 0.8539996, 0.8433642, 0.8192626, 0.79666424, 0.78780824, 0.7959997, 0.8148541, 0.8328023, 0.8399242, 0.8332261, 0.8176586, 0.8026956, 0.7967097, 0.80240244, 0.81572145, 0.8286095, 0.83379495, 0.8288238, 0.81713676, 0.8057717, 0.80117947, 0.8056086, 0.8160598, 0.82626194, 0.83039796, 0.82638997, 0.8169048, 0.80761755, 0.8038424, 0.80751467, 0.81622595, 0.8247765, 0.82825977, 0.82486075, 0.8167817, 0.8088352, 0.8055921, 0.8087652, 0.81631976, 0.82376343, 0.82680607, 0.8238223, 0.8167084, 0.80968827, 0.8068149, 0.8096382, 0.8163782, 0.8230381, 0.8257673, 0.82308096, 0.816661, 0.8103099, 0.80770445, 0.8102729, 0.8164172, 0.82250214, 0.82500064, 0.82253414, 0.81662834, 0.81077415, 0.8083683, 0.8107463, 0.8164449, 0.82209855, 0.82442385, 0.8221228, 0.8166046, 0.81112564, 0.80887055, 0.8111045, 0.81646544, 0.82179236, 0.82398623, 0.8218107, 0.8165866, 0.8113921, 0.8092514, 0.81137615, 0.8164814, 0.82156104, 0.8236556, 0.8215748, 0.8165723, 0.8115919, 0.8095371, 0.8115801, 0.81649435, 0.8213898, 0.82341063, 0.8213998, 0.8165605, 0.81173706, 0.8097449, 0.81172866, 0.8165053, 0.8212688, 0.8232372, 0.82127565, 0.81655025, 0.81183547, 0.8098863, 0.8118301, 0.8165149, 0.8211917, 0.8231261, 0.8211957, 0.8165411, 0.81189215, 0.8099684, 0.8118895, 0.81652385, 0.8211548, 0.82307184, 0.821156, 0.8165324, 0.81190985, 0.8099954, 0.81190985, 0.8165324, 0.821156, 0.82307184, 0.8211548, 0.81652385, 0.8118895, 0.8099684, 0.81189215, 0.8165411, 0.8211957, 0.8231261, 0.8211917, 0.8165149, 0.8118301, 0.8098863, 0.81183547, 0.81655025, 0.82127565, 0.8232372, 0.8212688, 0.8165053, 0.81172866, 0.8097449, 0.81173706, 0.8165605, 0.8213998, 0.82341063, 0.8213898, 0.81649435, 0.8115801, 0.8095371, 0.8115919, 0.8165723, 0.8215748, 0.8236556, 0.82156104, 0.8164814, 0.81137615, 0.8092514, 0.8113921, 0.8165866, 0.8218107, 0.82398623, 0.82179236, 0.81646544, 0.8111045, 0.80887055, 0.81112564, 0.8166046, 0.8221228, 0.82442385, 0.82209855, 0.8164449, 0.8107463, 0.8083683, 0.81077415, 0.81662834, 0.82253414, 0.82500064, 0.82250214, 0.8164172, 0.8102729, 0.80770445, 0.8103099, 0.816661, 0.82308096, 0.8257673, 0.8230381, 0.8163782, 0.8096382, 0.8068149, 0.80968827, 0.8167084, 0.8238223, 0.82680607, 0.82376343, 0.81631976, 0.8087652, 0.8055921, 0.8088352, 0.8167817, 0.82486075, 0.82825977, 0.8247765, 0.81622595, 0.80751467, 0.8038424, 0.80761755, 0.8169048, 0.82638997, 0.83039796, 0.82626194, 0.8160598, 0.8056086, 0.80117947, 0.8057717, 0.81713676, 0.8288238, 0.83379495, 0.8286095, 0.81572145, 0.80240244, 0.7967097, 0.8026956, 0.8176586, 0.8332261, 0.8399242, 0.8328023, 0.8148541, 0.7959997, 0.78780824, 0.79666424, 0.8192626, 0.8433642, 0.8539996, 0.84217817, 0.81135356, 0.7775709, 0.76222116, 0.7802571, 0.829543, 0.88712513, 0.91534024, 0.87605834, 0.74721813, 0.5342412, 0.27082315, 0.008198082, -0.20286436, -0.32978994, -0.36827868, -0.3408569, -0.28519028, -0.23782033, -0.22057812, -0.23513381, -0.26699877, -0.29590496, -0.30693153, -0.29709145, -0.27491096, -0.25423455, -0.24617507, -0.25356936, -0.27049825, -0.28651968, -0.29284295, -0.28694424, -0.273308, -0.26027772, -0.25509304, -0.25998366, -0.27136448, -0.28231263, -0.28669378, -0.28252804, -0.27278727, -0.2633706, -0.25958622, -0.26320615, -0.27170175, -0.2799458, -0.28326985, -0.28007534, -0.27255642, -0.26523823, -0.26227975, -0.26513368, -0.27186665, -0.27843592, -0.28109732, -0.278522, -0.27243456, -0.2664831, -0.26406762, -0.266411, -0.27195925, -0.27739283, -0.27960145, -0.277454, -0.27236256, -0.26736903, -0.26533663, -0.26731652, -0.2720163, -0.27663156, -0.27851215, -0.27667713, -0.2723166, -0.2680297, -0.26628116, -0.26798984, -0.27205387, -0.27605325, -0.27768594, -0.2760884, -0.2722855, -0.26853982, -0.26700947, -0.2685086, -0.2720799, -0.27560028, -0.27703956, -0.27562812, -0.2722635, -0.26894447, -0.26758662, -0.26891947, -0.27209866, -0.27523682, -0.27652144, -0.27525938, -0.27224737, -0.26927248, -0.26805404, -0.26925203, -0.27211264, -0.2749395, -0.2760979, -0.2749581, -0.2722352, -0.26954305, -0.26843935, -0.26952606, -0.27212328, -0.2746924, -0.27574605, -0.27470794, -0.27222583, -0.26976952, -0.2687617, -0.2697552, -0.2721316, -0.27448422, -0.27544984, -0.2744974, -0.27221844, -0.2699614, -0.26903468, -0.2699492, -0.27213818, -0.2743069, -0.27519763, -0.27431816, -0.27221254, -0.27012566, -0.26926827, -0.2701152, -0.2721435, -0.27415437, -0.27498075, -0.2741641, -0.2722077, -0.27026758, -0.26947004, -0.2702585, -0.27214786, -0.2740221, -0.27479273, -0.27403057, -0.27220374, -0.2703911, -0.2696456, -0.27038318, -0.2721515, -0.27390653, -0.27462852, -0.27391395, -0.27220044, -0.27049938, -0.2697994, -0.2704924, -0.2721545, -0.27380493, -0.2744842, -0.2738115, -0.27219766, -0.2705948, -0.269935, -0.27058867, -0.27215707, -0.27371514, -0.27435666, -0.27372095, -0.2721953, -0.27067938, -0.27005515, -0.27067393, -0.27215925, -0.2736354, -0.27424338, -0.27364057, -0.27219328, -0.2707547, -0.27016205, -0.2707498, -0.27216113, -0.27356425, -0.27414235, -0.27356887, -0.27219152, -0.27082196, -0.2702576, -0.2708176, -0.27216277, -0.27350056, -0.27405193, -0.2735047, -0.27219, -0.2708823, -0.27034324, -0.27087837, -0.27216417, -0.27344337, -0.27397075, -0.2734471, -0.2721887, -0.27093655, -0.27042025, -0.27093303, -0.27216542, -0.27339187, -0.27389762, -0.2733952, -0.27218753, -0.27098548, -0.27048972, -0.2709823, -0.27216652, -0.27334535, -0.27383164, -0.2733484, -0.2721865, -0.27102968, -0.27055246, -0.27102682, -0.2721675, -0.2733033, -0.27377194, -0.27330604, -0.27218556, -0.2710697, -0.27060926, -0.2710671, -0.27216837, -0.2732652, -0.27371788, -0.27326772, -0.27218473, -0.27110597, -0.27066073, -0.27110362, -0.27216914, -0.27323067, -0.2736689, -0.27323294, -0.272184, -0.27113888, -0.2707074, -0.27113673, -0.27216986, -0.27319935, -0.2736244, -0.27320138, -0.27218333, -0.27116874, -0.27074978, -0.2711668, -0.27217048, -0.2731709, -0.27358404, -0.27317274, -0.27218273, -0.27119586, -0.27078825, -0.27119407, -0.27217105, -0.27314508, -0.27354738, -0.27314675, -0.27218217, -0.27122045, -0.27082315, -0.27121884, -0.2721716, -0.27312165, -0.27351415, -0.27312317, -0.27218166, -0.27124274, -0.27085477, -0.27124128, -0.2721721, -0.27310044, -0.27348405, -0.27310184, -0.27218118, -0.27126288, -0.2708834, -0.27126157, -0.27217254, -0.27308127, -0.27345684, -0.27308252, -0.27218077, -0.27128112, -0.27090925, -0.27127993, -0.27217296, -0.273064, -0.27343228, -0.2730651, -0.27218035, -0.2712975, -0.27093253, -0.27129644, -0.27217335, -0.27304843, -0.27341023, -0.27304944, -0.27218, -0.2713122, -0.27095342, -0.27131125, -0.2721737, -0.27303454, -0.2733905, -0.27303544, -0.27217963, -0.27132532, -0.27097204, -0.2713245, -0.27217406, -0.27302217, -0.27337298, -0.27302298, -0.2721793, -0.27133694, -0.27098852, -0.2713362, -0.27217436, -0.2730113, -0.27335748, -0.27301198, -0.27217898, -0.27134714, -0.27100304, -0.27134648, -0.2721747, -0.2730018, -0.27334395, -0.2730024, -0.27217868, -0.271356, -0.27101558, -0.27135542, -0.27217498, -0.2729936, -0.27333233, -0.2729941, -0.27217838, -0.27136356, -0.27102634, -0.27136308, -0.27217525, -0.27298665, -0.27332246, -0.2729871, -0.2721781, -0.27136984, -0.2710353, -0.27136946, -0.27217552, -0.27298096, -0.27331436, -0.27298132, -0.27217785, -0.27137494, -0.2710426, -0.27137464, -0.2721758, -0.27297646, -0.27330792, -0.27297673, -0.27217758, -0.27137887, -0.2710482, -0.27137864, -0.27217606, -0.2729731, -0.27330312, -0.2729733, -0.27217734, -0.27138165, -0.27105218, -0.2713815, -0.27217633, -0.27297089, -0.27329993, -0.272971, -0.27217707, -0.2713833, -0.27105457, -0.27138323, -0.27217656, -0.2729698, -0.27329835, -0.27296984, -0.2721768, -0.27138382, -0.27105534, -0.27138382, -0.2721768, -0.27296984, -0.27329835, -0.2729698, -0.27217656, -0.27138323, -0.27105457, -0.2713833, -0.27217707, -0.272971, -0.27329993, -0.27297089, -0.27217633, -0.2713815, -0.27105218, -0.27138165, -0.27217734, -0.2729733, -0.27330312, -0.2729731, -0.27217606, -0.27137864, -0.2710482, -0.27137887, -0.27217758, -0.27297673, -0.27330792, -0.27297646, -0.2721758, -0.27137464, -0.2710426, -0.27137494, -0.27217785, -0.27298132, -0.27331436, -0.27298096, -0.27217552, -0.27136946, -0.2710353, -0.27136984, -0.2721781, -0.2729871, -0.27332246, -0.27298665, -0.27217525, -0.27136308, -0.27102634, -0.27136356, -0.27217838, -0.2729941, -0.27333233, -0.2729936, -0.27217498, -0.27135542, -0.27101558, -0.271356, -0.27217868, -0.2730024, -0.27334395, -0.2730018, -0.2721747, -0.27134648, -0.27100304, -0.27134714, -0.27217898, -0.27301198, -0.27335748, -0.2730113, -0.27217436, -0.2713362, -0.27098852, -0.27133694, -0.2721793, -0.27302298, -0.27337298, -0.27302217, -0.27217406, -0.2713245, -0.27097204, -0.27132532, -0.27217963, -0.27303544, -0.2733905, -0.27303454, -0.2721737, -0.27131125, -0.27095342, -0.2713122, -0.27218, -0.27304944, -0.27341023, -0.27304843, -0.27217335, -0.27129644, -0.27093253, -0.2712975, -0.27218035, -0.2730651, -0.27343228, -0.273064, -0.27217296, -0.27127993, -0.27090925, -0.27128112, -0.27218077, -0.27308252, -0.27345684, -0.27308127, -0.27217254, -0.27126157, -0.2708834, -0.27126288, -0.27218118, -0.27310184, -0.27348405, -0.27310044, -0.2721721, -0.27124128, -0.27085477, -0.27124274, -0.27218166, -0.27312317, -0.27351415, -0.27312165, -0.2721716, -0.27121884, -0.27082315, -0.27122045, -0.27218217, -0.27314675, -0.27354738, -0.27314508, -0.27217105, -0.27119407, -0.27078825, -0.27119586, -0.27218273, -0.27317274, -0.27358404, -0.2731709, -0.27217048, -0.2711668, -0.27074978, -0.27116874, -0.27218333, -0.27320138, -0.2736244, -0.27319935, -0.27216986, -0.27113673, -0.2707074, -0.27113888, -0.272184, -0.27323294, -0.2736689, -0.27323067, -0.27216914, -0.27110362, -0.27066073, -0.27110597, -0.27218473, -0.27326772, -0.27371788, -0.2732652, -0.27216837, -0.2710671, -0.27060926, -0.2710697, -0.27218556, -0.27330604, -0.27377194, -0.2733033, -0.2721675, -0.27102682, -0.27055246, -0.27102968, -0.2721865, -0.2733484, -0.27383164, -0.27334535, -0.27216652, -0.2709823, -0.27048972, -0.27098548, -0.27218753, -0.2733952, -0.27389762, -0.27339187, -0.27216542, -0.27093303, -0.27042025, -0.27093655, -0.2721887, -0.2734471, -0.27397075, -0.27344337, -0.27216417, -0.27087837, -0.27034324, -0.2708823, -0.27219, -0.2735047, -0.27405193, -0.27350056, -0.27216277, -0.2708176, -0.2702576, -0.27082196, -0.27219152, -0.27356887, -0.27414235, -0.27356425, -0.27216113, -0.2707498, -0.27016205, -0.2707547, -0.27219328, -0.27364057, -0.27424338, -0.2736354, -0.27215925, -0.27067393, -0.27005515, -0.27067938, -0.2721953, -0.27372095, -0.27435666, -0.27371514, -0.27215707, -0.27058867, -0.269935, -0.2705948, -0.27219766, -0.2738115, -0.2744842, -0.27380493, -0.2721545, -0.2704924, -0.2697994, -0.27049938, -0.27220044, -0.27391395, -0.27462852, -0.27390653, -0.2721515, -0.27038318, -0.2696456, -0.2703911, -0.27220374, -0.27403057, -0.27479273, -0.2740221, -0.27214786, -0.2702585, -0.26947004, -0.27026758, -0.2722077, -0.2741641, -0.27498075, -0.27415437, -0.2721435, -0.2701152, -0.26926827, -0.27012566, -0.27221254, -0.27431816, -0.27519763, -0.2743069, -0.27213818, -0.2699492, -0.26903468, -0.2699614, -0.27221844, -0.2744974, -0.27544984, -0.27448422, -0.2721316, -0.2697552, -0.2687617, -0.26976952, -0.27222583, -0.27470794, -0.27574605, -0.2746924, -0.27212328, -0.26952606, -0.26843935, -0.26954305, -0.2722352, -0.2749581, -0.2760979, -0.2749395, -0.27211264, -0.26925203, -0.26805404, -0.26927248, -0.27224737, -0.27525938, -0.27652144, -0.27523682, -0.27209866, -0.26891947, -0.26758662, -0.26894447, -0.2722635, -0.27562812, -0.27703956, -0.27560028, -0.2720799, -0.2685086, -0.26700947, -0.26853982, -0.2722855, -0.2760884, -0.27768594, -0.27605325, -0.27205387, -0.26798984, -0.26628116, -0.2680297, -0.2723166, -0.27667713, -0.27851215, -0.27663156, -0.2720163, -0.26731652, -0.26533663, -0.26736903, -0.27236256, -0.277454, -0.27960145, -0.27739283, -0.27195925, -0.266411, -0.26406762, -0.2664831, -0.27243456, -0.278522, -0.28109732, -0.27843592, -0.27186665, -0.26513368, -0.26227975, -0.26523823, -0.27255642, -0.28007534, -0.28326985, -0.2799458, -0.27170175, -0.26320615, -0.25958622, -0.2633706, -0.27278727, -0.28252804, -0.28669378, -0.28231263, -0.27136448, -0.25998366, -0.25509304, -0.26027772, -0.273308, -0.28694424, -0.29284295, -0.28651968, -0.27049825, -0.25356936, -0.24617507, -0.25423455, -0.27491096, -0.29709145, -0.30693153, -0.29590496, -0.26699877, -0.23513381, -0.22057812, -0.23782033, -0.28519028, -0.3408569, -0.36827868, -0.32978994, -0.20286436, 0.008198082, ], [0.26947004, 0.40460464, 0.5332886, 0.64922374, 0.7472455, 0.82377106, 0.87708, 0.907398, 0.9167793, 0.9088055, 0.88813823, 0.859981, 0.82951367, 0.8013631, 0.7791699, 0.76529443, 0.7606898, 0.7649463, 0.77649295, 0.7929214, 0.8113852, 0.8290208, 0.843336, 0.8525184, 0.85563034, 0.8526719, 0.84451205, 0.8327056, 0.81922853, 0.8061703, 0.7954302, 0.7884576, 0.7860702, 0.7883721, 0.79477644, 0.8041264, 0.81489104, 0.8254054, 0.8341189, 0.8398158, 0.8417783, 0.83986974, 0.83453095, 0.8266924, 0.8176185, 0.8087093, 0.8012894, 0.7964154, 0.79472953, 0.7963787, 0.801009, 0.80783415, 0.81576514, 0.8235809, 0.83011335, 0.83441883, 0.8359125, 0.83444506, 0.8303138, 0.82420635, 0.817089, 0.81005555, 0.8041612, 0.80026615, 0.79891187, 0.8002468, 0.8040132, 0.809594, 0.8161122, 0.8225679, 0.8279896, 0.8315796, 0.83283025, 0.8315942, 0.8281009, 0.8229152, 0.8168471, 0.81082636, 0.805761, 0.80240107, 0.8012289, 0.80239004, 0.80567646, 0.8105629, 0.81628966, 0.82198054, 0.82677567, 0.829961, 0.8310737, 0.82996935, 0.82683957, 0.8221797, 0.8167109, 0.81126904, 0.8066776, 0.8036237, 0.8025556, 0.8036175, 0.8066303, 0.81112146, 0.8163988, 0.8216565, 0.8260979, 0.8290555, 0.830091, 0.8290599, 0.8261314, 0.82176095, 0.81661975, 0.81149185, 0.8071553, 0.80426437, 0.8032512, 0.80426157, 0.80713385, 0.811425, 0.81647825, 0.8215238, 0.82579523, 0.8286457, 0.8296456, 0.8286471, 0.82580566, 0.82155645, 0.81654733, 0.8115407, 0.807298, 0.80446374, 0.80346864, 0.80446374, 0.807298, 0.8115407, 0.81654733, 0.82155645, 0.82580566, 0.8286471, 0.8296456, 0.8286457, 0.82579523, 0.8215238, 0.81647825, 0.811425, 0.80713385, 0.80426157, 0.8032512, 0.80426437, 0.8071553, 0.81149185, 0.81661975, 0.82176095, 0.8261314, 0.8290599, 0.830091, 0.8290555, 0.8260979, 0.8216565, 0.8163988, 0.81112146, 0.8066303, 0.8036175, 0.8025556, 0.8036237, 0.8066776, 0.81126904, 0.8167109, 0.8221797, 0.82683957, 0.82996935, 0.8310737, 0.829961, 0.82677567, 0.82198054, 0.81628966, 0.8105629, 0.80567646, 0.80239004, 0.8012289, 0.80240107, 0.805761, 0.81082636, 0.8168471, 0.8229152, 0.8281009, 0.8315942, 0.83283025, 0.8315796, 0.8279896, 0.8225679, 0.8161122, 0.809594, 0.8040132, 0.8002468, 0.79891187, 0.80026615, 0.8041612, 0.81005555, 0.817089, 0.82420635, 0.8303138, 0.83444506, 0.8359125, 0.83441883, 0.83011335, 0.8235809, 0.81576514, 0.80783415, 0.801009, 0.7963787, 0.79472953, 0.7964154, 0.8012894, 0.8087093, 0.8176185, 0.8266924, 0.83453095, 0.83986974, 0.8417783, 0.8398158, 0.8341189, 0.8254054, 0.81489104, 0.8041264, 0.79477644, 0.7883721, 0.7860702, 0.7884576, 0.7954302, 0.8061703, 0.81922853, 0.8327056, 0.84451205, 0.8526719, 0.85563034, 0.8525184, 0.843336, 0.8290208, 0.8113852, 0.7929214, 0.77649295, 0.7649463, 0.7606898, 0.76529443, 0.7791699, 0.8013631, 0.82951367, 0.859981, 0.88813823, 0.9088055, 0.9167793, 0.907398, 0.87708, 0.82377106, 0.7472455, 0.64922374, 0.5332886, 0.40460464, 0.26947004, 0.13474758, 0.007237466, -0.10694078, -0.20288989, -0.27734256, -0.3288938, -0.35803705, -0.3670058, -0.35944477, -0.33995327, -0.31355643, -0.28516638, -0.2590941, -0.23866361, -0.22596657, -0.22177589, -0.22561805, -0.23598412, -0.25064448, -0.26702118, -0.28256816, -0.29511127, -0.30310866, -0.3058042, -0.30326274, -0.29629117, -0.286265, -0.2748899, -0.2639359, -0.2549816, -0.24920358, -0.24723616, -0.2491174, -0.25432265, -0.26187578, -0.2705181, -0.27890724, -0.28581652, -0.29030624, -0.29184425, -0.29036105, -0.28623524, -0.28021488, -0.27328923, -0.2665319, -0.26093936, -0.2572885, -0.2560328, -0.25725073, -0.26065084, -0.26563135, -0.27138224, -0.27701354, -0.28169024, -0.28475326, -0.2858098, -0.2847808, -0.28190044, -0.2776694, -0.27277046, -0.26796055, -0.2639557, -0.26132628, -0.26041734, -0.2613054, -0.26379624, -0.26746306, -0.27171773, -0.27590373, -0.2793961, -0.28169346, -0.28248897, -0.2817098, -0.27952087, -0.27629295, -0.2725412, -0.2688439, -0.26575425, -0.26371863, -0.2630128, -0.26370555, -0.2656542, -0.26853186, -0.2718812, -0.27518642, -0.27795205, -0.27977654, -0.28040993, -0.27978727, -0.27803385, -0.27544147, -0.27242067, -0.2694362, -0.26693618, -0.2652851, -0.2647114, -0.26527622, -0.26686823, -0.26922435, -0.27197254, -0.27469036, -0.2769692, -0.27847558, -0.27899948, -0.2784831, -0.2770264, -0.27486873, -0.2723498, -0.26985663, -0.2677644, -0.26638022, -0.2658985, -0.26637384, -0.26771566, -0.26970473, -0.27202857, -0.27433038, -0.27626345, -0.27754322, -0.2779889, -0.2775487, -0.27630535, -0.274461, -0.27230477, -0.2701676, -0.26837158, -0.2671818, -0.26676723, -0.26717705, -0.26833525, -0.27005434, -0.2720653, -0.2740597, -0.27573672, -0.2768483, -0.2772358, -0.27685243, -0.27576843, -0.27415863, -0.27227443, -0.27040476, -0.26883182, -0.26778868, -0.26742485, -0.26778504, -0.26880395, -0.27031785, -0.27209064, -0.27385065, -0.27533203, -0.27631494, -0.2766579, -0.27631816, -0.27535668, -0.27392745, -0.27225307, -0.27059, -0.2691896, -0.26826, -0.2679355, -0.26825714, -0.26916772, -0.2705218, -0.27210882, -0.27368578, -0.27501422, -0.2758964, -0.27620444, -0.27589893, -0.27503374, -0.2737466, -0.27223745, -0.27073732, -0.2694731, -0.26863328, -0.2683399, -0.26863098, -0.26945564, -0.27068287, -0.27212232, -0.27355367, -0.2747604, -0.2755623, -0.2758425, -0.27556434, -0.27477607, -0.27360258, -0.2722257, -0.2708561, -0.2697011, -0.2689333, -0.26866493, -0.26893145, -0.26968697, -0.27081206, -0.2721326, -0.2734466, -0.27455506, -0.27529213, -0.27554983, -0.2752938, -0.27456778, -0.27348635, -0.27221665, -0.27095288, -0.26988646, -0.26917714, -0.26892906, -0.26917562, -0.26987493, -0.27091694, -0.27214065, -0.273359, -0.2743874, -0.27507162, -0.27531096, -0.275073, -0.27439782, -0.27339154, -0.27220947, -0.27103227, -0.27003834, -0.26937684, -0.2691454, -0.26937562, -0.27002886, -0.27100274, -0.27214706, -0.27328697, -0.27424967, -0.2748905, -0.2751148, -0.27489164, -0.27425826, -0.27331376, -0.2722037, -0.27109763, -0.2701633, -0.26954117, -0.2693234, -0.26954016, -0.2701555, -0.2710733, -0.2721523, -0.27322763, -0.27413625, -0.27474135, -0.27495325, -0.2747423, -0.2741433, -0.27324972, -0.27219895, -0.27115148, -0.27026623, -0.26967654, -0.26947004, -0.2696757, -0.27025983, -0.27113146, -0.27215663, -0.27317882, -0.27404287, -0.2746186, -0.27482027, -0.27461937, -0.2740487, -0.27319694, -0.27219495, -0.27119568, -0.27035078, -0.26978773, -0.26959047, -0.26978704, -0.27034554, -0.2711793, -0.27216032, -0.27313888, -0.27396643, -0.27451807, -0.27471134, -0.2745187, -0.27397117, -0.27315366, -0.27219155, -0.27123165, -0.27041972, -0.2698784, -0.2696887, -0.26987785, -0.27041546, -0.27121836, -0.27216348, -0.2731066, -0.2739045, -0.2744366, -0.2746231, -0.2744371, -0.27390832, -0.2731185, -0.2721886, -0.27126047, -0.2704751, -0.26995128, -0.26976764, -0.26995084, -0.2704717, -0.2712499, -0.27216625, -0.27308106, -0.2738553, -0.27437177, -0.27455288, -0.2743722, -0.27385828, -0.2730904, -0.27218598, -0.2712829, -0.27051848, -0.27000844, -0.26982957, -0.27000812, -0.2705159, -0.27127478, -0.27216876, -0.27306154, -0.27381742, -0.27432185, -0.2744988, -0.27432215, -0.27381968, -0.27306855, -0.2721836, -0.27129963, -0.2705511, -0.27005148, -0.26987618, -0.2700512, -0.27054918, -0.2712937, -0.27217105, -0.27304754, -0.2737899, -0.27428547, -0.27445936, -0.27428567, -0.27379146, -0.27305242, -0.2721814, -0.271311, -0.2705737, -0.27008143, -0.26990867, -0.27008128, -0.27057248, -0.27130714, -0.2721732, -0.27303872, -0.27377203, -0.27426174, -0.2744336, -0.27426186, -0.27377295, -0.27304158, -0.27217928, -0.27131736, -0.27058697, -0.2700991, -0.26992786, -0.27009904, -0.27058634, -0.27131546, -0.27217525, -0.2730348, -0.27376333, -0.27425003, -0.2744209, -0.27425006, -0.27376366, -0.27303576, -0.27217725, -0.27131882, -0.27059114, -0.27010491, -0.2699342, -0.27010491, -0.27059114, -0.27131882, -0.27217725, -0.27303576, -0.27376366, -0.27425006, -0.2744209, -0.27425003, -0.27376333, -0.2730348, -0.27217525, -0.27131546, -0.27058634, -0.27009904, -0.26992786, -0.2700991, -0.27058697, -0.27131736, -0.27217928, -0.27304158, -0.27377295, -0.27426186, -0.2744336, -0.27426174, -0.27377203, -0.27303872, -0.2721732, -0.27130714, -0.27057248, -0.27008128, -0.26990867, -0.27008143, -0.2705737, -0.271311, -0.2721814, -0.27305242, -0.27379146, -0.27428567, -0.27445936, -0.27428547, -0.2737899, -0.27304754, -0.27217105, -0.2712937, -0.27054918, -0.2700512, -0.26987618, -0.27005148, -0.2705511, -0.27129963, -0.2721836, -0.27306855, -0.27381968, -0.27432215, -0.2744988, -0.27432185, -0.27381742, -0.27306154, -0.27216876, -0.27127478, -0.2705159, -0.27000812, -0.26982957, -0.27000844, -0.27051848, -0.2712829, -0.27218598, -0.2730904, -0.27385828, -0.2743722, -0.27455288, -0.27437177, -0.2738553, -0.27308106, -0.27216625, -0.2712499, -0.2704717, -0.26995084, -0.26976764, -0.26995128, -0.2704751, -0.27126047, -0.2721886, -0.2731185, -0.27390832, -0.2744371, -0.2746231, -0.2744366, -0.2739045, -0.2731066, -0.27216348, -0.27121836, -0.27041546, -0.26987785, -0.2696887, -0.2698784, -0.27041972, -0.27123165, -0.27219155, -0.27315366, -0.27397117, -0.2745187, -0.27471134, -0.27451807, -0.27396643, -0.27313888, -0.27216032, -0.2711793, -0.27034554, -0.26978704, -0.26959047, -0.26978773, -0.27035078, -0.27119568, -0.27219495, -0.27319694, -0.2740487, -0.27461937, -0.27482027, -0.2746186, -0.27404287, -0.27317882, -0.27215663, -0.27113146, -0.27025983, -0.2696757, -0.26947004, -0.26967654, -0.27026623, -0.27115148, -0.27219895, -0.27324972, -0.2741433, -0.2747423, -0.27495325, -0.27474135, -0.27413625, -0.27322763, -0.2721523, -0.2710733, -0.2701555, -0.26954016, -0.2693234, -0.26954117, -0.2701633, -0.27109763, -0.2722037, -0.27331376, -0.27425826, -0.27489164, -0.2751148, -0.2748905, -0.27424967, -0.27328697, -0.27214706, -0.27100274, -0.27002886, -0.26937562, -0.2691454, -0.26937684, -0.27003834, -0.27103227, -0.27220947, -0.27339154, -0.27439782, -0.275073, -0.27531096, -0.27507162, -0.2743874, -0.273359, -0.27214065, -0.27091694, -0.26987493, -0.26917562, -0.26892906, -0.26917714, -0.26988646, -0.27095288, -0.27221665, -0.27348635, -0.27456778, -0.2752938, -0.27554983, -0.27529213, -0.27455506, -0.2734466, -0.2721326, -0.27081206, -0.26968697, -0.26893145, -0.26866493, -0.2689333, -0.2697011, -0.2708561, -0.2722257, -0.27360258, -0.27477607, -0.27556434, -0.2758425, -0.2755623, -0.2747604, -0.27355367, -0.27212232, -0.27068287, -0.26945564, -0.26863098, -0.2683399, -0.26863328, -0.2694731, -0.27073732, -0.27223745, -0.2737466, -0.27503374, -0.27589893, -0.27620444, -0.2758964, -0.27501422, -0.27368578, -0.27210882, -0.2705218, -0.26916772, -0.26825714, -0.2679355, -0.26826, -0.2691896, -0.27059, -0.27225307, -0.27392745, -0.27535668, -0.27631816, -0.2766579, -0.27631494, -0.27533203, -0.27385065, -0.27209064, -0.27031785, -0.26880395, -0.26778504, -0.26742485, -0.26778868, -0.26883182, -0.27040476, -0.27227443, -0.27415863, -0.27576843, -0.27685243, -0.2772358, -0.2768483, -0.27573672, -0.2740597, -0.2720653, -0.27005434, -0.26833525, -0.26717705, -0.26676723, -0.2671818, -0.26837158, -0.2701676, -0.27230477, -0.274461, -0.27630535, -0.2775487, -0.2779889, -0.27754322, -0.27626345, -0.27433038, -0.27202857, -0.26970473, -0.26771566, -0.26637384, -0.2658985, -0.26638022, -0.2677644, -0.26985663, -0.2723498, -0.27486873, -0.2770264, -0.2784831, -0.27899948, -0.27847558, -0.2769692, -0.27469036, -0.27197254, -0.26922435, -0.26686823, -0.26527622, -0.2647114, -0.2652851, -0.26693618, -0.2694362, -0.27242067, -0.27544147, -0.27803385, -0.27978727, -0.28040993, -0.27977654, -0.27795205, -0.27518642, -0.2718812, -0.26853186, -0.2656542, -0.26370555, -0.2630128, -0.26371863, -0.26575425, -0.2688439, -0.2725412, -0.27629295, -0.27952087, -0.2817098, -0.28248897, -0.28169346, -0.2793961, -0.27590373, -0.27171773, -0.26746306, -0.26379624, -0.2613054, -0.26041734, -0.26132628, -0.2639557, -0.26796055, -0.27277046, -0.2776694, -0.28190044, -0.2847808, -0.2858098, -0.28475326, -0.28169024, -0.27701354, -0.27138224, -0.26563135, -0.26065084, -0.25725073, -0.2560328, -0.2572885, -0.26093936, -0.2665319, -0.27328923, -0.28021488, -0.28623524, -0.29036105, -0.29184425, -0.29030624, -0.28581652, -0.27890724, -0.2705181, -0.26187578, -0.25432265, -0.2491174, -0.24723616, -0.24920358, -0.2549816, -0.2639359, -0.2748899, -0.286265, -0.29629117, -0.30326274, -0.3058042, -0.30310866, -0.29511127, -0.28256816, -0.26702118, -0.25064448, -0.23598412, -0.22561805, -0.22177589, -0.22596657, -0.23866361, -0.2590941, -0.28516638, -0.31355643, -0.33995327, -0.35944477, -0.3670058, -0.35803705, -0.3288938, -0.27734256, -0.20288989, -0.10694078, 0.007237466, 0.13474758, ], [0.26676723, 0.33477026, 0.40211007, 0.46792746, 0.531395, 0.5917363, 0.64824325, 0.70029247, 0.74735856, 0.7890255, 0.8249945, 0.85508966, 0.8792593, 0.8975746, 0.9102256, 0.9175129, 0.9198378, 0.917689, 0.9116283, 0.90227336, 0.8902804, 0.8763262, 0.86108905, 0.84523135, 0.82938266, 0.8141238, 0.79997355, 0.787377, 0.7766966, 0.7682058, 0.76208574, 0.7584244, 0.75721914, 0.7583812, 0.7617432, 0.76706856, 0.7740628, 0.78238595, 0.7916664, 0.80151486, 0.8115387, 0.82135534, 0.8306056, 0.83896506, 0.846154, 0.85194594, 0.85617363, 0.8587328, 0.859584, 0.8587514, 0.85632056, 0.85243285, 0.84727913, 0.8410911, 0.83413184, 0.82668555, 0.8190465, 0.8115083, 0.80435294, 0.79784113, 0.792203, 0.78763086, 0.7842726, 0.7822275, 0.78154373, 0.7822177, 0.78419507, 0.7873741, 0.79161006, 0.79672176, 0.8024986, 0.80870914, 0.8151099, 0.82145464, 0.82750356, 0.83303195, 0.8378385, 0.84175205, 0.84463793, 0.84640205, 0.8469938, 0.84640765, 0.8446822, 0.8418987, 0.83817685, 0.83367044, 0.82856065, 0.8230494, 0.81735116, 0.811685, 0.8062664, 0.8012991, 0.79696757, 0.7934304, 0.7908146, 0.78921115, 0.788672, 0.78920805, 0.79078984, 0.7933483, 0.79677814, 0.80094177, 0.8056749, 0.8107929, 0.8160978, 0.82138586, 0.82645524, 0.8311137, 0.8351856, 0.8385186, 0.8409891, 0.84250695, 0.8430185, 0.8425084, 0.8410004, 0.8385558, 0.8352715, 0.8312757, 0.82672334, 0.82179016, 0.8166657, 0.81154656, 0.8066286, 0.8020996, 0.7981325, 0.7948784, 0.7924614, 0.79097337, 0.79047096, 0.79097337, 0.7924614, 0.7948784, 0.7981325, 0.8020996, 0.8066286, 0.81154656, 0.8166657, 0.82179016, 0.82672334, 0.8312757, 0.8352715, 0.8385558, 0.8410004, 0.8425084, 0.8430185, 0.84250695, 0.8409891, 0.8385186, 0.8351856, 0.8311137, 0.82645524, 0.82138586, 0.8160978, 0.8107929, 0.8056749, 0.80094177, 0.79677814, 0.7933483, 0.79078984, 0.78920805, 0.788672, 0.78921115, 0.7908146, 0.7934304, 0.79696757, 0.8012991, 0.8062664, 0.811685, 0.81735116, 0.8230494, 0.82856065, 0.83367044, 0.83817685, 0.8418987, 0.8446822, 0.84640765, 0.8469938, 0.84640205, 0.84463793, 0.84175205, 0.8378385, 0.83303195, 0.82750356, 0.82145464, 0.8151099, 0.80870914, 0.8024986, 0.79672176, 0.79161006, 0.7873741, 0.78419507, 0.7822177, 0.78154373, 0.7822275, 0.7842726, 0.78763086, 0.792203, 0.79784113, 0.80435294, 0.8115083, 0.8190465, 0.82668555, 0.83413184, 0.8410911, 0.84727913, 0.85243285, 0.85632056, 0.8587514, 0.859584, 0.8587328, 0.85617363, 0.85194594, 0.846154, 0.83896506, 0.8306056, 0.82135534, 0.8115387, 0.80151486, 0.7916664, 0.78238595, 0.7740628, 0.76706856, 0.7617432, 0.7583812, 0.75721914, 0.7584244, 0.76208574, 0.7682058, 0.7766966, 0.787377, 0.79997355, 0.8141238, 0.82938266, 0.84523135, 0.86108905, 0.8763262, 0.8902804, 0.90227336, 0.9116283, 0.917689, 0.9198378, 0.9175129, 0.9102256, 0.8975746, 0.8792593, 0.85508966, 0.8249945, 0.7890255, 0.74735856, 0.70029247, 0.64824325, 0.5917363, 0.531395, 0.46792746, 0.40211007, 0.33477026, 0.26676723, 0.1989723, 0.13224879, 0.06743228, 0.005311807, -0.053387742, -0.10802135, -0.15803738, -0.20298848, -0.24253976, -0.27647382, -0.3046928, -0.32721722, -0.3441818, -0.3558286, -0.3624972, -0.3646131, -0.36267352, -0.35723263, -0.34888494, -0.33824858, -0.3259485, -0.31260005, -0.29879344, -0.28507963, -0.2719576, -0.25986406, -0.24916483, -0.24014913, -0.23302597, -0.22792321, -0.22488907, -0.22389577, -0.22484548, -0.22757794, -0.23187968, -0.23749448, -0.24413447, -0.2514919, -0.2592508, -0.26709825, -0.2747352, -0.28188628, -0.2883079, -0.29379553, -0.29818895, -0.30137563, -0.30329263, -0.3039266, -0.30331174, -0.30152678, -0.29869, -0.2949533, -0.29049534, -0.28551403, -0.28021842, -0.2748209, -0.26952904, -0.2645386, -0.26002645, -0.2561452, -0.25301823, -0.25073636, -0.24935572, -0.24889684, -0.24934515, -0.25065282, -0.25274143, -0.25550616, -0.25882027, -0.26254058, -0.2665134, -0.2705804, -0.27458462, -0.2783763, -0.28181824, -0.2847904, -0.28719395, -0.2889543, -0.29002312, -0.29037946, -0.29002976, -0.2890067, -0.28736755, -0.28519106, -0.28257412, -0.27962765, -0.27647206, -0.27323252, -0.2700341, -0.26699725, -0.26423323, -0.26184025, -0.25990018, -0.25847578, -0.2576089, -0.25731924, -0.2576044, -0.2584402, -0.2597823, -0.26156825, -0.26372015, -0.26614815, -0.26875386, -0.2714343, -0.27408594, -0.2766086, -0.27890903, -0.28090435, -0.282525, -0.28371704, -0.2844438, -0.284687, -0.28444704, -0.28374258, -0.28260952, -0.28109932, -0.27927673, -0.27721703, -0.27500317, -0.27272227, -0.27046248, -0.2683094, -0.26634312, -0.26463515, -0.2632459, -0.26222268, -0.261598, -0.2613887, -0.26159558, -0.26220366, -0.2631829, -0.2644898, -0.26606905, -0.26785594, -0.269779, -0.27176267, -0.27373037, -0.27560738, -0.27732354, -0.27881598, -0.28003132, -0.28092745, -0.28147516, -0.28165883, -0.281477, -0.28094205, -0.28007966, -0.27892756, -0.2775339, -0.27595538, -0.27425483, -0.27249894, -0.27075544, -0.26909068, -0.26756707, -0.26624084, -0.26515982, -0.26436198, -0.2638739, -0.26371008, -0.26387244, -0.2643505, -0.26512188, -0.26615328, -0.267402, -0.2688176, -0.2703439, -0.27192128, -0.2734888, -0.27498677, -0.27635887, -0.2775542, -0.27852932, -0.27924958, -0.2796905, -0.27983862, -0.27969167, -0.27925873, -0.27855963, -0.2776241, -0.27649066, -0.27520475, -0.27381727, -0.27238232, -0.27095526, -0.26959056, -0.26833963, -0.26724908, -0.26635882, -0.26570082, -0.26529768, -0.2651622, -0.26529673, -0.2656934, -0.26633433, -0.26719257, -0.2682331, -0.2694143, -0.2706897, -0.27200955, -0.273323, -0.27457985, -0.27573267, -0.27673832, -0.27755976, -0.2781673, -0.27853972, -0.27866498, -0.2785405, -0.27817333, -0.27757975, -0.27678445, -0.27581963, -0.2747237, -0.27353975, -0.2723138, -0.27109307, -0.26992428, -0.26885164, -0.2679154, -0.26715022, -0.26658398, -0.26623666, -0.2661198, -0.26623604, -0.266579, -0.2671338, -0.26787752, -0.26878023, -0.26980615, -0.27091506, -0.27206394, -0.2732085, -0.27430496, -0.27531174, -0.27619094, -0.27690986, -0.27744216, -0.2777688, -0.27787873, -0.2777693, -0.27744624, -0.2769234, -0.27622214, -0.27537057, -0.27440226, -0.2733551, -0.2722697, -0.27118787, -0.270151, -0.26919848, -0.26836625, -0.26768544, -0.2671811, -0.26687148, -0.26676723, -0.26687106, -0.26717773, -0.2676743, -0.2683406, -0.26915008, -0.2700709, -0.2710672, -0.27210036, -0.2731306, -0.27411845, -0.27502632, -0.2758199, -0.2764694, -0.27695075, -0.2772464, -0.27734596, -0.2772467, -0.27695352, -0.27647853, -0.27584094, -0.275066, -0.27418402, -0.27322942, -0.27223906, -0.27125108, -0.27030337, -0.26943198, -0.26867, -0.26804608, -0.26758352, -0.2672993, -0.26720354, -0.26729903, -0.26758128, -0.2680387, -0.26865295, -0.26939982, -0.2702502, -0.271171, -0.27212664, -0.27308038, -0.27399567, -0.27483752, -0.275574, -0.27617726, -0.27662468, -0.2768997, -0.2769924, -0.27689993, -0.27662647, -0.27618313, -0.27558756, -0.2748631, -0.27403796, -0.2731441, -0.27221605, -0.27128953, -0.27040005, -0.26958156, -0.26886526, -0.2682783, -0.26784283, -0.26757503, -0.26748472, -0.26757488, -0.26784146, -0.26827377, -0.26885483, -0.26956186, -0.27036744, -0.27124044, -0.27214718, -0.27305275, -0.2739225, -0.27472308, -0.27542394, -0.2759985, -0.27642494, -0.27668723, -0.27677575, -0.27668738, -0.27642593, -0.27600178, -0.27543157, -0.27473742, -0.27394623, -0.27308854, -0.27219737, -0.271307, -0.27045158, -0.2696639, -0.26897404, -0.26840833, -0.2679883, -0.26772985, -0.26764262, -0.26772976, -0.26798764, -0.26840618, -0.26896906, -0.2696545, -0.27043608, -0.27128363, -0.27216458, -0.27304506, -0.27389127, -0.27467078, -0.2753537, -0.27591392, -0.27633002, -0.27658615, -0.2766726, -0.2765862, -0.27633032, -0.27591497, -0.27535614, -0.2746754, -0.27389893, -0.2730566, -0.27218077, -0.2713051, -0.27046323, -0.26968744, -0.26900753, -0.26844957, -0.26803502, -0.26777974, -0.26769355, -0.26777974, -0.26803502, -0.26844957, -0.26900753, -0.26968744, -0.27046323, -0.2713051, -0.27218077, -0.2730566, -0.27389893, -0.2746754, -0.27535614, -0.27591497, -0.27633032, -0.2765862, -0.2766726, -0.27658615, -0.27633002, -0.27591392, -0.2753537, -0.27467078, -0.27389127, -0.27304506, -0.27216458, -0.27128363, -0.27043608, -0.2696545, -0.26896906, -0.26840618, -0.26798764, -0.26772976, -0.26764262, -0.26772985, -0.2679883, -0.26840833, -0.26897404, -0.2696639, -0.27045158, -0.271307, -0.27219737, -0.27308854, -0.27394623, -0.27473742, -0.27543157, -0.27600178, -0.27642593, -0.27668738, -0.27677575, -0.27668723, -0.27642494, -0.2759985, -0.27542394, -0.27472308, -0.2739225, -0.27305275, -0.27214718, -0.27124044, -0.27036744, -0.26956186, -0.26885483, -0.26827377, -0.26784146, -0.26757488, -0.26748472, -0.26757503, -0.26784283, -0.2682783, -0.26886526, -0.26958156, -0.27040005, -0.27128953, -0.27221605, -0.2731441, -0.27403796, -0.2748631, -0.27558756, -0.27618313, -0.27662647, -0.27689993, -0.2769924, -0.2768997, -0.27662468, -0.27617726, -0.275574, -0.27483752, -0.27399567, -0.27308038, -0.27212664, -0.271171, -0.2702502, -0.26939982, -0.26865295, -0.2680387, -0.26758128, -0.26729903, -0.26720354, -0.2672993, -0.26758352, -0.26804608, -0.26867, -0.26943198, -0.27030337, -0.27125108, -0.27223906, -0.27322942, -0.27418402, -0.275066, -0.27584094, -0.27647853, -0.27695352, -0.2772467, -0.27734596, -0.2772464, -0.27695075, -0.2764694, -0.2758199, -0.27502632, -0.27411845, -0.2731306, -0.27210036, -0.2710672, -0.2700709, -0.26915008, -0.2683406, -0.2676743, -0.26717773, -0.26687106, -0.26676723, -0.26687148, -0.2671811, -0.26768544, -0.26836625, -0.26919848, -0.270151, -0.27118787, -0.2722697, -0.2733551, -0.27440226, -0.27537057, -0.27622214, -0.2769234, -0.27744624, -0.2777693, -0.27787873, -0.2777688, -0.27744216, -0.27690986, -0.27619094, -0.27531174, -0.27430496, -0.2732085, -0.27206394, -0.27091506, -0.26980615, -0.26878023, -0.26787752, -0.2671338, -0.266579, -0.26623604, -0.2661198, -0.26623666, -0.26658398, -0.26715022, -0.2679154, -0.26885164, -0.26992428, -0.27109307, -0.2723138, -0.27353975, -0.2747237, -0.27581963, -0.27678445, -0.27757975, -0.27817333, -0.2785405, -0.27866498, -0.27853972, -0.2781673, -0.27755976, -0.27673832, -0.27573267, -0.27457985, -0.273323, -0.27200955, -0.2706897, -0.2694143, -0.2682331, -0.26719257, -0.26633433, -0.2656934, -0.26529673, -0.2651622, -0.26529768, -0.26570082, -0.26635882, -0.26724908, -0.26833963, -0.26959056, -0.27095526, -0.27238232, -0.27381727, -0.27520475, -0.27649066, -0.2776241, -0.27855963, -0.27925873, -0.27969167, -0.27983862, -0.2796905, -0.27924958, -0.27852932, -0.2775542, -0.27635887, -0.27498677, -0.2734888, -0.27192128, -0.2703439, -0.2688176, -0.267402, -0.26615328, -0.26512188, -0.2643505, -0.26387244, -0.26371008, -0.2638739, -0.26436198, -0.26515982, -0.26624084, -0.26756707, -0.26909068, -0.27075544, -0.27249894, -0.27425483, -0.27595538, -0.2775339, -0.27892756, -0.28007966, -0.28094205, -0.281477, -0.28165883, -0.28147516, -0.28092745, -0.28003132, -0.27881598, -0.27732354, -0.27560738, -0.27373037, -0.27176267, -0.269779, -0.26785594, -0.26606905, -0.2644898, -0.2631829, -0.26220366, -0.26159558, -0.2613887, -0.261598, -0.26222268, -0.2632459, -0.26463515, -0.26634312, -0.2683094, -0.27046248, -0.27272227, -0.27500317, -0.27721703, -0.27927673, -0.28109932, -0.28260952, -0.28374258, -0.28444704, -0.284687, -0.2844438, -0.28371704, -0.282525, -0.28090435, -0.27890903, -0.2766086, -0.27408594, -0.2714343, -0.26875386, -0.26614815, -0.26372015, -0.26156825, -0.2597823, -0.2584402, -0.2576044, -0.25731924, -0.2576089, -0.25847578, -0.25990018, -0.26184025, -0.26423323, -0.26699725, -0.2700341, -0.27323252, -0.27647206, -0.27962765, -0.28257412, -0.28519106, -0.28736755, -0.2890067, -0.29002976, -0.29037946, -0.29002312, -0.2889543, -0.28719395, -0.2847904, -0.28181824, -0.2783763, -0.27458462, -0.2705804, -0.2665134, -0.26254058, -0.25882027, -0.25550616, -0.25274143, -0.25065282, -0.24934515, -0.24889684, -0.24935572, -0.25073636, -0.25301823, -0.2561452, -0.26002645, -0.2645386, -0.26952904, -0.2748209, -0.28021842, -0.28551403, -0.29049534, -0.2949533, -0.29869, -0.30152678, -0.30331174, -0.3039266, -0.30329263, -0.30137563, -0.29818895, -0.29379553, -0.2883079, -0.28188628, -0.2747352, -0.26709825, -0.2592508, -0.2514919, -0.24413447, -0.23749448, -0.23187968, -0.22757794, -0.22484548, -0.22389577, -0.22488907, -0.22792321, -0.23302597, -0.24014913, -0.24916483, -0.25986406, -0.2719576, -0.28507963, -0.29879344, -0.31260005, -0.3259485, -0.33824858, -0.34888494, -0.35723263, -0.36267352, -0.3646131, -0.3624972, -0.3558286, -0.3441818, -0.32721722, -0.3046928, -0.27647382, -0.24253976, -0.20298848, -0.15803738, -0.10802135, -0.053387742, 0.005311807, 0.06743228, 0.13224879, 0.1989723, ], [0.2613887, 0.2954449, 0.32949683, 0.3634355, 0.39715222, 0.43053913, 0.46348986, 0.49590012, 0.52766836, 0.5586962, 0.58888924, 0.6181574, 0.6464154, 0.6735835, 0.69958776, 0.72436035, 0.74784017, 0.76997304, 0.790712, 0.8100176, 0.827858, 0.84420913, 0.8590549, 0.8723871, 0.88420546, 0.8945175, 0.9033386, 0.9106916, 0.9166069, 0.9211217, 0.9242803, 0.9261333, 0.92673737, 0.926155, 0.92445356, 0.9217054, 0.917987, 0.9133783, 0.90796244, 0.9018252, 0.8950539, 0.8877376, 0.8799657, 0.871828, 0.8634136, 0.8548106, 0.84610546, 0.83738256, 0.8287233, 0.82020605, 0.8119054, 0.8038917, 0.7962309, 0.78898376, 0.78220594, 0.7759474, 0.7702523, 0.76515883, 0.76069885, 0.7568981, 0.7537757, 0.75134474, 0.74961156, 0.7485766, 0.74823385, 0.74857163, 0.74957234, 0.7512129, 0.75346506, 0.7562956, 0.7596671, 0.7635376, 0.76786184, 0.77259105, 0.77767384, 0.78305644, 0.78868335, 0.7944978, 0.800442, 0.80645806, 0.8124884, 0.8184759, 0.8243648, 0.8301009, 0.83563215, 0.84090906, 0.84588504, 0.8505168, 0.8547647, 0.85859305, 0.8619703, 0.8648695, 0.8672682, 0.8691489, 0.87049884, 0.8713103, 0.8715806, 0.8713119, 0.8705114, 0.86919105, 0.8673675, 0.8650618, 0.86229944, 0.85910964, 0.8555256, 0.85158384, 0.8473239, 0.8427879, 0.83802056, 0.8330682, 0.8279786, 0.8228009, 0.81758434, 0.8123787, 0.8072332, 0.80219656, 0.79731596, 0.7926374, 0.7882045, 0.78405875, 0.7802388, 0.7767802, 0.773715, 0.77107173, 0.7688748, 0.7671447, 0.76589733, 0.7651443, 0.76489246, 0.7651443, 0.76589733, 0.7671447, 0.7688748, 0.77107173, 0.773715, 0.7767802, 0.7802388, 0.78405875, 0.7882045, 0.7926374, 0.79731596, 0.80219656, 0.8072332, 0.8123787, 0.81758434, 0.8228009, 0.8279786, 0.8330682, 0.83802056, 0.8427879, 0.8473239, 0.85158384, 0.8555256, 0.85910964, 0.86229944, 0.8650618, 0.8673675, 0.86919105, 0.8705114, 0.8713119, 0.8715806, 0.8713103, 0.87049884, 0.8691489, 0.8672682, 0.8648695, 0.8619703, 0.85859305, 0.8547647, 0.8505168, 0.84588504, 0.84090906, 0.83563215, 0.8301009, 0.8243648, 0.8184759, 0.8124884, 0.80645806, 0.800442, 0.7944978, 0.78868335, 0.78305644, 0.77767384, 0.77259105, 0.76786184, 0.7635376, 0.7596671, 0.7562956, 0.75346506, 0.7512129, 0.74957234, 0.74857163, 0.74823385, 0.7485766, 0.74961156, 0.75134474, 0.7537757, 0.7568981, 0.76069885, 0.76515883, 0.7702523, 0.7759474, 0.78220594, 0.78898376, 0.7962309, 0.8038917, 0.8119054, 0.82020605, 0.8287233, 0.83738256, 0.84610546, 0.8548106, 0.8634136, 0.871828, 0.8799657, 0.8877376, 0.8950539, 0.9018252, 0.90796244, 0.9133783, 0.917987, 0.9217054, 0.92445356, 0.926155, 0.92673737, 0.9261333, 0.9242803, 0.9211217, 0.9166069, 0.9106916, 0.9033386, 0.8945175, 0.88420546, 0.8723871, 0.8590549, 0.84420913, 0.827858, 0.8100176, 0.790712, 0.76997304, 0.74784017, 0.72436035, 0.69958776, 0.6735835, 0.6464154, 0.6181574, 0.58888924, 0.5586962, 0.52766836, 0.49590012, 0.46348986, 0.43053913, 0.39715222, 0.3634355, 0.32949683, 0.2954449, 0.2613887, 0.22743678, 0.19369675, 0.16027461, 0.12727413, 0.094796345, 0.06293899, 0.031795915, 0.0014566322, -0.02799417, -0.056477062, -0.08391827, -0.110250056, -0.135411, -0.15934628, -0.182008, -0.20335522, -0.2233542, -0.24197848, -0.2592089, -0.27503356, -0.28944796, -0.30245462, -0.3140632, -0.3242901, -0.33315846, -0.34069777, -0.34694347, -0.35193685, -0.35572454, -0.3583581, -0.35989368, -0.36039153, -0.35991552, -0.35853276, -0.3563129, -0.35332787, -0.34965125, -0.34535778, -0.34052286, -0.33522204, -0.32953054, -0.3235228, -0.317272, -0.3108496, -0.3043249, -0.2977647, -0.29123285, -0.28479007, -0.2784934, -0.27239612, -0.26654738, -0.26099205, -0.25577056, -0.2509187, -0.24646749, -0.24244323, -0.23886743, -0.23575674, -0.23312305, -0.23097357, -0.22931091, -0.22813328, -0.22743455, -0.22720455, -0.22742924, -0.228091, -0.22916885, -0.23063873, -0.23247387, -0.23464502, -0.23712087, -0.2398683, -0.24285276, -0.24603862, -0.24938947, -0.25286853, -0.25643888, -0.26006395, -0.26370758, -0.2673346, -0.27091092, -0.2744039, -0.27778253, -0.28101772, -0.28408244, -0.28695205, -0.28960428, -0.29201943, -0.29418054, -0.29607344, -0.29768673, -0.29901195, -0.3000435, -0.30077857, -0.30121732, -0.30136248, -0.30121958, -0.3007966, -0.300104, -0.29915446, -0.29796284, -0.29654583, -0.29492193, -0.29311112, -0.2911347, -0.289015, -0.28677532, -0.2844395, -0.28203183, -0.27957675, -0.27709866, -0.2746216, -0.2721693, -0.26976454, -0.2674294, -0.26518485, -0.2630505, -0.26104462, -0.25918397, -0.25748357, -0.2559567, -0.2546147, -0.25346708, -0.25252122, -0.25178257, -0.2512545, -0.25093836, -0.25083345, -0.25093713, -0.25124487, -0.25175023, -0.25244507, -0.25331956, -0.25436243, -0.2555609, -0.25690106, -0.25836775, -0.25994498, -0.26161587, -0.2633631, -0.26516867, -0.26701447, -0.2688823, -0.27075392, -0.2726114, -0.27443722, -0.2762144, -0.27792668, -0.27955866, -0.2810959, -0.28252506, -0.28383404, -0.28501207, -0.2860497, -0.28693894, -0.2876734, -0.28824815, -0.2886599, -0.28890693, -0.28898904, -0.28890765, -0.2886657, -0.28826764, -0.28771928, -0.28702778, -0.2862016, -0.28525037, -0.28418472, -0.2830163, -0.28175753, -0.28042158, -0.27902216, -0.2775734, -0.2760898, -0.2745859, -0.27307636, -0.2715757, -0.2700981, -0.26865748, -0.2672672, -0.26593995, -0.26468772, -0.26352164, -0.26245195, -0.26148775, -0.26063716, -0.25990707, -0.25930312, -0.2588298, -0.2584902, -0.25828618, -0.2582183, -0.2582857, -0.25848645, -0.25881723, -0.25927353, -0.25984973, -0.2605391, -0.26133397, -0.26222566, -0.2632047, -0.2642609, -0.26538333, -0.2665607, -0.2677812, -0.26903272, -0.270303, -0.2715797, -0.2728506, -0.27410352, -0.27532667, -0.27650857, -0.2776383, -0.2787055, -0.27970052, -0.28061444, -0.2814392, -0.28216767, -0.2827937, -0.28331217, -0.28371903, -0.28401124, -0.28418702, -0.28424558, -0.28418732, -0.28401375, -0.28372744, -0.28333202, -0.28283215, -0.2822334, -0.28154227, -0.28076607, -0.27991292, -0.27899152, -0.27801126, -0.2769819, -0.2759137, -0.27481717, -0.27370298, -0.272582, -0.27146494, -0.2703625, -0.26928517, -0.26824307, -0.26724592, -0.26630303, -0.265423, -0.26461387, -0.26388296, -0.2632367, -0.26268077, -0.2622199, -0.26185793, -0.26159763, -0.26144096, -0.2613887, -0.26144075, -0.26159593, -0.2618522, -0.26220644, -0.26265472, -0.26319215, -0.26381308, -0.26451108, -0.26527902, -0.26610917, -0.26699317, -0.26792228, -0.26888737, -0.26987895, -0.2708874, -0.27190298, -0.27291587, -0.2739164, -0.27489504, -0.27584252, -0.27674994, -0.27760875, -0.278411, -0.27914926, -0.27981675, -0.28040746, -0.28091604, -0.281338, -0.28166974, -0.28190842, -0.28205228, -0.28210026, -0.2820524, -0.28190956, -0.28167352, -0.28134692, -0.28093332, -0.28043702, -0.27986315, -0.27921748, -0.27850655, -0.2777374, -0.27691767, -0.2760554, -0.27515903, -0.27423728, -0.27329907, -0.2723535, -0.27140966, -0.27047658, -0.26956317, -0.26867813, -0.26782987, -0.26702636, -0.2662752, -0.2655834, -0.2649574, -0.26440302, -0.2639253, -0.26352865, -0.26321658, -0.26299185, -0.2628563, -0.26281106, -0.26285622, -0.26299113, -0.26321426, -0.2635232, -0.2639147, -0.2643849, -0.26492897, -0.26554158, -0.26621664, -0.2669475, -0.26772705, -0.26854765, -0.26940137, -0.2702799, -0.27117482, -0.2720774, -0.27297905, -0.27387106, -0.27474493, -0.2755923, -0.27640504, -0.27717546, -0.27789626, -0.27856055, -0.2791621, -0.27969527, -0.280155, -0.280537, -0.28083777, -0.28105456, -0.28118536, -0.28122908, -0.2811854, -0.28105488, -0.2808389, -0.28053963, -0.28016004, -0.2797039, -0.27917564, -0.27858046, -0.27792412, -0.277213, -0.27645397, -0.27565438, -0.27482194, -0.27396467, -0.27309084, -0.2722088, -0.2713271, -0.2704542, -0.26959845, -0.26876807, -0.267971, -0.26721492, -0.26650706, -0.26585418, -0.26526254, -0.26473784, -0.26428506, -0.26390857, -0.2636119, -0.26339796, -0.26326877, -0.2632256, -0.26326877, -0.26339796, -0.2636119, -0.26390857, -0.26428506, -0.26473784, -0.26526254, -0.26585418, -0.26650706, -0.26721492, -0.267971, -0.26876807, -0.26959845, -0.2704542, -0.2713271, -0.2722088, -0.27309084, -0.27396467, -0.27482194, -0.27565438, -0.27645397, -0.277213, -0.27792412, -0.27858046, -0.27917564, -0.2797039, -0.28016004, -0.28053963, -0.2808389, -0.28105488, -0.2811854, -0.28122908, -0.28118536, -0.28105456, -0.28083777, -0.280537, -0.280155, -0.27969527, -0.2791621, -0.27856055, -0.27789626, -0.27717546, -0.27640504, -0.2755923, -0.27474493, -0.27387106, -0.27297905, -0.2720774, -0.27117482, -0.2702799, -0.26940137, -0.26854765, -0.26772705, -0.2669475, -0.26621664, -0.26554158, -0.26492897, -0.2643849, -0.2639147, -0.2635232, -0.26321426, -0.26299113, -0.26285622, -0.26281106, -0.2628563, -0.26299185, -0.26321658, -0.26352865, -0.2639253, -0.26440302, -0.2649574, -0.2655834, -0.2662752, -0.26702636, -0.26782987, -0.26867813, -0.26956317, -0.27047658, -0.27140966, -0.2723535, -0.27329907, -0.27423728, -0.27515903, -0.2760554, -0.27691767, -0.2777374, -0.27850655, -0.27921748, -0.27986315, -0.28043702, -0.28093332, -0.28134692, -0.28167352, -0.28190956, -0.2820524, -0.28210026, -0.28205228, -0.28190842, -0.28166974, -0.281338, -0.28091604, -0.28040746, -0.27981675, -0.27914926, -0.278411, -0.27760875, -0.27674994, -0.27584252, -0.27489504, -0.2739164, -0.27291587, -0.27190298, -0.2708874, -0.26987895, -0.26888737, -0.26792228, -0.26699317, -0.26610917, -0.26527902, -0.26451108, -0.26381308, -0.26319215, -0.26265472, -0.26220644, -0.2618522, -0.26159593, -0.26144075, -0.2613887, -0.26144096, -0.26159763, -0.26185793, -0.2622199, -0.26268077, -0.2632367, -0.26388296, -0.26461387, -0.265423, -0.26630303, -0.26724592, -0.26824307, -0.26928517, -0.2703625, -0.27146494, -0.272582, -0.27370298, -0.27481717, -0.2759137, -0.2769819, -0.27801126, -0.27899152, -0.27991292, -0.28076607, -0.28154227, -0.2822334, -0.28283215, -0.28333202, -0.28372744, -0.28401375, -0.28418732, -0.28424558, -0.28418702, -0.28401124, -0.28371903, -0.28331217, -0.2827937, -0.28216767, -0.2814392, -0.28061444, -0.27970052, -0.2787055, -0.2776383, -0.27650857, -0.27532667, -0.27410352, -0.2728506, -0.2715797, -0.270303, -0.26903272, -0.2677812, -0.2665607, -0.26538333, -0.2642609, -0.2632047, -0.26222566, -0.26133397, -0.2605391, -0.25984973, -0.25927353, -0.25881723, -0.25848645, -0.2582857, -0.2582183, -0.25828618, -0.2584902, -0.2588298, -0.25930312, -0.25990707, -0.26063716, -0.26148775, -0.26245195, -0.26352164, -0.26468772, -0.26593995, -0.2672672, -0.26865748, -0.2700981, -0.2715757, -0.27307636, -0.2745859, -0.2760898, -0.2775734, -0.27902216, -0.28042158, -0.28175753, -0.2830163, -0.28418472, -0.28525037, -0.2862016, -0.28702778, -0.28771928, -0.28826764, -0.2886657, -0.28890765, -0.28898904, -0.28890693, -0.2886599, -0.28824815, -0.2876734, -0.28693894, -0.2860497, -0.28501207, -0.28383404, -0.28252506, -0.2810959, -0.27955866, -0.27792668, -0.2762144, -0.27443722, -0.2726114, -0.27075392, -0.2688823, -0.26701447, -0.26516867, -0.2633631, -0.26161587, -0.25994498, -0.25836775, -0.25690106, -0.2555609, -0.25436243, -0.25331956, -0.25244507, -0.25175023, -0.25124487, -0.25093713, -0.25083345, -0.25093836, -0.2512545, -0.25178257, -0.25252122, -0.25346708, -0.2546147, -0.2559567, -0.25748357, -0.25918397, -0.26104462, -0.2630505, -0.26518485, -0.2674294, -0.26976454, -0.2721693, -0.2746216, -0.27709866, -0.27957675, -0.28203183, -0.2844395, -0.28677532, -0.289015, -0.2911347, -0.29311112, -0.29492193, -0.29654583, -0.29796284, -0.29915446, -0.300104, -0.3007966, -0.30121958, -0.30136248, -0.30121732, -0.30077857, -0.3000435, -0.29901195, -0.29768673, -0.29607344, -0.29418054, -0.29201943, -0.28960428, -0.28695205, -0.28408244, -0.28101772, -0.27778253, -0.2744039, -0.27091092, -0.2673346, -0.26370758, -0.26006395, -0.25643888, -0.25286853, -0.24938947, -0.24603862, -0.24285276, -0.2398683, -0.23712087, -0.23464502, -0.23247387, -0.23063873, -0.22916885, -0.228091, -0.22742924, -0.22720455, -0.22743455, -0.22813328, -0.22931091, -0.23097357, -0.23312305, -0.23575674, -0.23886743, -0.24244323, -0.24646749, -0.2509187, -0.25577056, -0.26099205, -0.26654738, -0.27239612, -0.2784934, -0.28479007, -0.29123285, -0.2977647, -0.3043249, -0.3108496, -0.317272, -0.3235228, -0.32953054, -0.33522204, -0.34052286, -0.34535778, -0.34965125, -0.35332787, -0.3563129, -0.35853276, -0.35991552, -0.36039153, -0.35989368, -0.3583581, -0.35572454, -0.35193685, -0.34694347, -0.34069777, -0.33315846, -0.3242901, -0.3140632, -0.30245462, -0.28944796, -0.27503356, -0.2592089, -0.24197848, -0.2233542, -0.20335522, -0.182008, -0.15934628, -0.135411, -0.110250056, -0.08391827, -0.056477062, -0.02799417, 0.0014566322, 0.031795915, 0.06293899, 0.094796345, 0.12727413, 0.16027461, 0.19369675, 0.22743678, ], [0.25083345, 0.2678684, 0.28494233, 0.30204198, 0.31915396, 0.33626485, 0.35336113, 0.3704293, 0.38745585, 0.4044272, 0.42132992, 0.43815047, 0.45487553, 0.47149172, 0.48798585, 0.5043448, 0.5205557, 0.5366056, 0.5524819, 0.56817216, 0.5836641, 0.59894574, 0.61400527, 0.62883115, 0.6434122, 0.6577373, 0.67179596, 0.68557775, 0.69907266, 0.7122711, 0.7251637, 0.73774153, 0.7499962, 0.76191944, 0.7735036, 0.7847414, 0.7956259, 0.8061509, 0.8163102, 0.82609856, 0.83551073, 0.8445423, 0.8531892, 0.8614478, 0.86931515, 0.87678856, 0.883866, 0.8905459, 0.8968272, 0.9027094, 0.9081925, 0.91327685, 0.9179635, 0.92225397, 0.9261502, 0.92965466, 0.93277043, 0.935501, 0.93785024, 0.9398227, 0.94142324, 0.9426573, 0.9435307, 0.9440498, 0.9442212, 0.9440522, 0.94355035, 0.94272363, 0.9415804, 0.9401294, 0.9383798, 0.9363411, 0.9340231, 0.9314358, 0.9285898, 0.9254958, 0.92216474, 0.9186079, 0.9148368, 0.91086316, 0.9066989, 0.902356, 0.89784694, 0.89318395, 0.88837963, 0.88344663, 0.87839764, 0.8732455, 0.868003, 0.86268306, 0.8572985, 0.85186225, 0.846387, 0.84088576, 0.835371, 0.82985544, 0.8243515, 0.8188716, 0.8134279, 0.8080325, 0.8026972, 0.7974336, 0.79225314, 0.787167, 0.7821861, 0.777321, 0.77258205, 0.76797926, 0.7635223, 0.7592206, 0.755083, 0.75111824, 0.7473344, 0.7437395, 0.7403408, 0.73714536, 0.7341597, 0.73138994, 0.72884184, 0.7265205, 0.7244306, 0.72257656, 0.720962, 0.71959025, 0.71846414, 0.71758586, 0.7169573, 0.7165796, 0.7164536, 0.7165796, 0.7169573, 0.71758586, 0.71846414, 0.71959025, 0.720962, 0.72257656, 0.7244306, 0.7265205, 0.72884184, 0.73138994, 0.7341597, 0.73714536, 0.7403408, 0.7437395, 0.7473344, 0.75111824, 0.755083, 0.7592206, 0.7635223, 0.76797926, 0.77258205, 0.777321, 0.7821861, 0.787167, 0.79225314, 0.7974336, 0.8026972, 0.8080325, 0.8134279, 0.8188716, 0.8243515, 0.82985544, 0.835371, 0.84088576, 0.846387, 0.85186225, 0.8572985, 0.86268306, 0.868003, 0.8732455, 0.87839764, 0.88344663, 0.88837963, 0.89318395, 0.89784694, 0.902356, 0.9066989, 0.91086316, 0.9148368, 0.9186079, 0.92216474, 0.9254958, 0.9285898, 0.9314358, 0.9340231, 0.9363411, 0.9383798, 0.9401294, 0.9415804, 0.94272363, 0.94355035, 0.9440522, 0.9442212, 0.9440498, 0.9435307, 0.9426573, 0.94142324, 0.9398227, 0.93785024, 0.935501, 0.93277043, 0.92965466, 0.9261502, 0.92225397, 0.9179635, 0.91327685, 0.9081925, 0.9027094, 0.8968272, 0.8905459, 0.883866, 0.87678856, 0.86931515, 0.8614478, 0.8531892, 0.8445423, 0.83551073, 0.82609856, 0.8163102, 0.8061509, 0.7956259, 0.7847414, 0.7735036, 0.76191944, 0.7499962, 0.73774153, 0.7251637, 0.7122711, 0.69907266, 0.68557775, 0.67179596, 0.6577373, 0.6434122, 0.62883115, 0.61400527, 0.59894574, 0.5836641, 0.56817216, 0.5524819, 0.5366056, 0.5205557, 0.5043448, 0.48798585, 0.47149172, 0.45487553, 0.43815047, 0.42132992, 0.4044272, 0.38745585, 0.3704293, 0.35336113, 0.33626485, 0.31915396, 0.30204198, 0.28494233, 0.2678684, 0.25083345, 0.23385067, 0.21693315, 0.20009379, 0.18334539, 0.16670054, 0.15017165, 0.13377096, 0.11751043, 0.10140185, 0.08545674, 0.06968634, 0.05410163, 0.03871331, 0.023531765, 0.008567066, -0.006171037, -0.02067313, -0.03493015, -0.04893339, -0.06267451, -0.07614554, -0.0893389, -0.10224741, -0.11486426, -0.12718308, -0.13919789, -0.15090314, -0.16229366, -0.17336474, -0.1841121, -0.19453189, -0.20462064, -0.21437539, -0.22379355, -0.23287298, -0.24161197, -0.25000927, -0.25806403, -0.26577577, -0.27314454, -0.28017077, -0.28685522, -0.29319918, -0.29920426, -0.30487245, -0.31020623, -0.31520838, -0.31988207, -0.3242308, -0.32825848, -0.33196935, -0.33536798, -0.33845925, -0.3412484, -0.3437409, -0.3459426, -0.34785953, -0.3494981, -0.35086486, -0.35196674, -0.35281074, -0.3534042, -0.35375464, -0.3538697, -0.3537573, -0.35342538, -0.35288224, -0.3521361, -0.35119542, -0.35006872, -0.34876463, -0.34729183, -0.34565914, -0.34387532, -0.3419492, -0.3398897, -0.33770564, -0.33540592, -0.33299935, -0.33049473, -0.32790083, -0.32522637, -0.3224799, -0.31967002, -0.31680515, -0.3138936, -0.31094357, -0.30796313, -0.30496022, -0.30194256, -0.2989178, -0.29589337, -0.29287645, -0.28987417, -0.2868933, -0.28394052, -0.28102225, -0.27814466, -0.2753137, -0.27253512, -0.2698144, -0.26715678, -0.2645672, -0.26205036, -0.2596108, -0.25725263, -0.25497982, -0.252796, -0.25070456, -0.2487086, -0.24681097, -0.2450142, -0.2433206, -0.24173217, -0.24025065, -0.23887752, -0.23761395, -0.23646091, -0.23541903, -0.23448876, -0.23367022, -0.23296335, -0.23236777, -0.23188291, -0.23150796, -0.23124184, -0.23108329, -0.2310308, -0.23108268, -0.23123701, -0.23149168, -0.2318444, -0.23229267, -0.23283385, -0.23346514, -0.23418353, -0.23498593, -0.23586905, -0.23682953, -0.23786385, -0.23896837, -0.2401394, -0.24137308, -0.24266554, -0.2440128, -0.24541083, -0.24685553, -0.24834277, -0.24986836, -0.25142813, -0.25301787, -0.25463334, -0.2562703, -0.2579246, -0.259592, -0.26126835, -0.26294956, -0.2646315, -0.26631021, -0.2679817, -0.26964208, -0.27128753, -0.27291435, -0.27451885, -0.27609754, -0.27764696, -0.27916378, -0.28064483, -0.282087, -0.28348732, -0.28484297, -0.2861513, -0.28740972, -0.28861588, -0.28976747, -0.29086244, -0.2918988, -0.29287478, -0.29378876, -0.2946393, -0.29542503, -0.29614484, -0.29679778, -0.297383, -0.29789987, -0.29834792, -0.29872683, -0.29903638, -0.29927668, -0.2994478, -0.29955012, -0.2995841, -0.29955035, -0.29944968, -0.299283, -0.29905137, -0.298756, -0.29839826, -0.29797956, -0.29750156, -0.29696596, -0.29637456, -0.29572937, -0.29503235, -0.29428574, -0.29349175, -0.29265273, -0.29177105, -0.29084927, -0.2898899, -0.28889558, -0.28786898, -0.28681287, -0.28573, -0.28462318, -0.28349528, -0.28234914, -0.28118768, -0.28001377, -0.27883032, -0.27764022, -0.2764464, -0.2752517, -0.27405897, -0.27287105, -0.27169076, -0.27052078, -0.26936388, -0.2682227, -0.26709983, -0.2659978, -0.26491907, -0.26386607, -0.26284108, -0.26184633, -0.26088402, -0.25995615, -0.2590647, -0.25821155, -0.25739843, -0.256627, -0.25589883, -0.25521532, -0.25457782, -0.2539875, -0.25344548, -0.25295272, -0.25251007, -0.25211823, -0.25177783, -0.25148934, -0.2512531, -0.25106934, -0.25093818, -0.2508596, -0.25083345, -0.25085947, -0.2509373, -0.25106645, -0.25124627, -0.25147605, -0.25175497, -0.25208202, -0.25245622, -0.25287634, -0.25334117, -0.2538493, -0.25439933, -0.2549897, -0.2556188, -0.2562849, -0.2569862, -0.2577209, -0.25848702, -0.2592826, -0.26010558, -0.26095387, -0.2618253, -0.2627177, -0.26362884, -0.26455647, -0.26549828, -0.26645193, -0.26741514, -0.26838556, -0.26936084, -0.27033862, -0.27131656, -0.27229235, -0.27326363, -0.2742282, -0.27518365, -0.27612785, -0.27705857, -0.27797362, -0.2788709, -0.27974838, -0.28060398, -0.2814358, -0.2822419, -0.2830205, -0.28376985, -0.28448826, -0.28517413, -0.28582594, -0.28644225, -0.28702176, -0.28756317, -0.28806534, -0.28852722, -0.28894782, -0.2893263, -0.28966188, -0.2899539, -0.29020178, -0.29040512, -0.29056355, -0.2906768, -0.29074475, -0.2907674, -0.2907448, -0.29067716, -0.29056472, -0.2904079, -0.2902072, -0.2899632, -0.2896766, -0.2893482, -0.2889789, -0.28856966, -0.28812155, -0.28763574, -0.2871135, -0.28655612, -0.28596503, -0.2853417, -0.28468773, -0.2840047, -0.2832943, -0.2825583, -0.28179848, -0.2810167, -0.28021494, -0.27939504, -0.27855906, -0.27770898, -0.2768469, -0.27597484, -0.27509493, -0.2742093, -0.27332002, -0.27242926, -0.27153912, -0.27065176, -0.26976925, -0.2688937, -0.2680272, -0.2671718, -0.26632953, -0.2655024, -0.26469234, -0.26390126, -0.26313108, -0.26238355, -0.2616605, -0.2609636, -0.26029447, -0.2596547, -0.2590458, -0.25846922, -0.25792632, -0.25741833, -0.25694647, -0.25651187, -0.2561155, -0.25575832, -0.25544116, -0.25516477, -0.2549298, -0.2547368, -0.25458622, -0.25447842, -0.25441363, -0.25439203, -0.25441363, -0.25447842, -0.25458622, -0.2547368, -0.2549298, -0.25516477, -0.25544116, -0.25575832, -0.2561155, -0.25651187, -0.25694647, -0.25741833, -0.25792632, -0.25846922, -0.2590458, -0.2596547, -0.26029447, -0.2609636, -0.2616605, -0.26238355, -0.26313108, -0.26390126, -0.26469234, -0.2655024, -0.26632953, -0.2671718, -0.2680272, -0.2688937, -0.26976925, -0.27065176, -0.27153912, -0.27242926, -0.27332002, -0.2742093, -0.27509493, -0.27597484, -0.2768469, -0.27770898, -0.27855906, -0.27939504, -0.28021494, -0.2810167, -0.28179848, -0.2825583, -0.2832943, -0.2840047, -0.28468773, -0.2853417, -0.28596503, -0.28655612, -0.2871135, -0.28763574, -0.28812155, -0.28856966, -0.2889789, -0.2893482, -0.2896766, -0.2899632, -0.2902072, -0.2904079, -0.29056472, -0.29067716, -0.2907448, -0.2907674, -0.29074475, -0.2906768, -0.29056355, -0.29040512, -0.29020178, -0.2899539, -0.28966188, -0.2893263, -0.28894782, -0.28852722, -0.28806534, -0.28756317, -0.28702176, -0.28644225, -0.28582594, -0.28517413, -0.28448826, -0.28376985, -0.2830205, -0.2822419, -0.2814358, -0.28060398, -0.27974838, -0.2788709, -0.27797362, -0.27705857, -0.27612785, -0.27518365, -0.2742282, -0.27326363, -0.27229235, -0.27131656, -0.27033862, -0.26936084, -0.26838556, -0.26741514, -0.26645193, -0.26549828, -0.26455647, -0.26362884, -0.2627177, -0.2618253, -0.26095387, -0.26010558, -0.2592826, -0.25848702, -0.2577209, -0.2569862, -0.2562849, -0.2556188, -0.2549897, -0.25439933, -0.2538493, -0.25334117, -0.25287634, -0.25245622, -0.25208202, -0.25175497, -0.25147605, -0.25124627, -0.25106645, -0.2509373, -0.25085947, -0.25083345, -0.2508596, -0.25093818, -0.25106934, -0.2512531, -0.25148934, -0.25177783, -0.25211823, -0.25251007, -0.25295272, -0.25344548, -0.2539875, -0.25457782, -0.25521532, -0.25589883, -0.256627, -0.25739843, -0.25821155, -0.2590647, -0.25995615, -0.26088402, -0.26184633, -0.26284108, -0.26386607, -0.26491907, -0.2659978, -0.26709983, -0.2682227, -0.26936388, -0.27052078, -0.27169076, -0.27287105, -0.27405897, -0.2752517, -0.2764464, -0.27764022, -0.27883032, -0.28001377, -0.28118768, -0.28234914, -0.28349528, -0.28462318, -0.28573, -0.28681287, -0.28786898, -0.28889558, -0.2898899, -0.29084927, -0.29177105, -0.29265273, -0.29349175, -0.29428574, -0.29503235, -0.29572937, -0.29637456, -0.29696596, -0.29750156, -0.29797956, -0.29839826, -0.298756, -0.29905137, -0.299283, -0.29944968, -0.29955035, -0.2995841, -0.29955012, -0.2994478, -0.29927668, -0.29903638, -0.29872683, -0.29834792, -0.29789987, -0.297383, -0.29679778, -0.29614484, -0.29542503, -0.2946393, -0.29378876, -0.29287478, -0.2918988, -0.29086244, -0.28976747, -0.28861588, -0.28740972, -0.2861513, -0.28484297, -0.28348732, -0.282087, -0.28064483, -0.27916378, -0.27764696, -0.27609754, -0.27451885, -0.27291435, -0.27128753, -0.26964208, -0.2679817, -0.26631021, -0.2646315, -0.26294956, -0.26126835, -0.259592, -0.2579246, -0.2562703, -0.25463334, -0.25301787, -0.25142813, -0.24986836, -0.24834277, -0.24685553, -0.24541083, -0.2440128, -0.24266554, -0.24137308, -0.2401394, -0.23896837, -0.23786385, -0.23682953, -0.23586905, -0.23498593, -0.23418353, -0.23346514, -0.23283385, -0.23229267, -0.2318444, -0.23149168, -0.23123701, -0.23108268, -0.2310308, -0.23108329, -0.23124184, -0.23150796, -0.23188291, -0.23236777, -0.23296335, -0.23367022, -0.23448876, -0.23541903, -0.23646091, -0.23761395, -0.23887752, -0.24025065, -0.24173217, -0.2433206, -0.2450142, -0.24681097, -0.2487086, -0.25070456, -0.252796, -0.25497982, -0.25725263, -0.2596108, -0.26205036, -0.2645672, -0.26715678, -0.2698144, -0.27253512, -0.2753137, -0.27814466, -0.28102225, -0.28394052, -0.2868933, -0.28987417, -0.29287645, -0.29589337, -0.2989178, -0.30194256, -0.30496022, -0.30796313, -0.31094357, -0.3138936, -0.31680515, -0.31967002, -0.3224799, -0.32522637, -0.32790083, -0.33049473, -0.33299935, -0.33540592, -0.33770564, -0.3398897, -0.3419492, -0.34387532, -0.34565914, -0.34729183, -0.34876463, -0.35006872, -0.35119542, -0.3521361, -0.35288224, -0.35342538, -0.3537573, -0.3538697, -0.35375464, -0.3534042, -0.35281074, -0.35196674, -0.35086486, -0.3494981, -0.34785953, -0.3459426, -0.3437409, -0.3412484, -0.33845925, -0.33536798, -0.33196935, -0.32825848, -0.3242308, -0.31988207, -0.31520838, -0.31020623, -0.30487245, -0.29920426, -0.29319918, -0.28685522, -0.28017077, -0.27314454, -0.26577577, -0.25806403, -0.25000927, -0.24161197, -0.23287298, -0.22379355, -0.21437539, -0.20462064, -0.19453189, -0.1841121, -0.17336474, -0.16229366, -0.15090314, -0.13919789, -0.12718308, -0.11486426, -0.10224741, -0.0893389, -0.07614554, -0.06267451, -0.04893339, -0.03493015, -0.02067313, -0.006171037, 0.008567066, 0.023531765, 0.03871331, 0.05410163, 0.06968634, 0.08545674, 0.10140185, 0.11751043, 0.13377096, 0.15017165, 0.16670054, 0.18334539, 0.20009379, 0.21693315, 0.23385067, ], [0.2310308, 0.23954913, 0.24809211, 0.2566583, 0.26524615, 0.27385426, 0.2824811, 0.29112518, 0.29978496, 0.30845895, 0.31714556, 0.3258433, 0.3345506, 0.34326583, 0.3519875, 0.36071402, 0.36944377, 0.37817517, 0.38690662, 0.3956365, 0.40436321, 0.41308513, 0.4218006, 0.43050808, 0.43920583, 0.44789225, 0.45656574, 0.4652246, 0.4738672, 0.4824919, 0.49109706, 0.499681, 0.5082421, 0.51677865, 0.52528906, 0.53377163, 0.54222476, 0.5506467, 0.5590359, 0.5673907, 0.5757094, 0.5839904, 0.59223205, 0.6004327, 0.6085907, 0.6167045, 0.6247724, 0.6327929, 0.6407643, 0.6486849, 0.6565533, 0.6643678, 0.6721269, 0.6798289, 0.6874723, 0.69505554, 0.7025771, 0.7100354, 0.717429, 0.72475624, 0.7320157, 0.7392059, 0.7463254, 0.75337255, 0.7603461, 0.76724446, 0.7740663, 0.7808102, 0.78747463, 0.7940583, 0.8005599, 0.8069779, 0.8133111, 0.81955814, 0.8257176, 0.8317884, 0.83776915, 0.8436585, 0.84945536, 0.85515845, 0.86076653, 0.86627847, 0.8716931, 0.87700915, 0.88222563, 0.88734144, 0.89235544, 0.89726657, 0.90207374, 0.906776, 0.9113723, 0.9158617, 0.92024326, 0.92451596, 0.92867893, 0.9327313, 0.9366721, 0.9405006, 0.94421595, 0.9478173, 0.951304, 0.9546752, 0.95793015, 0.9610682, 0.96408874, 0.96699095, 0.96977437, 0.9724383, 0.9749822, 0.97740555, 0.9797078, 0.98188835, 0.9839469, 0.98588294, 0.98769605, 0.9893858, 0.99095184, 0.9923939, 0.9937116, 0.99490464, 0.9959728, 0.9969158, 0.99773353, 0.9984258, 0.9989923, 0.9994331, 0.99974805, 0.999937, 1.0, 0.999937, 0.99974805, 0.9994331, 0.9989923, 0.9984258, 0.99773353, 0.9969158, 0.9959728, 0.99490464, 0.9937116, 0.9923939, 0.99095184, 0.9893858, 0.98769605, 0.98588294, 0.9839469, 0.98188835, 0.9797078, 0.97740555, 0.9749822, 0.9724383, 0.96977437, 0.96699095, 0.96408874, 0.9610682, 0.95793015, 0.9546752, 0.951304, 0.9478173, 0.94421595, 0.9405006, 0.9366721, 0.9327313, 0.92867893, 0.92451596, 0.92024326, 0.9158617, 0.9113723, 0.906776, 0.90207374, 0.89726657, 0.89235544, 0.88734144, 0.88222563, 0.87700915, 0.8716931, 0.86627847, 0.86076653, 0.85515845, 0.84945536, 0.8436585, 0.83776915, 0.8317884, 0.8257176, 0.81955814, 0.8133111, 0.8069779, 0.8005599, 0.7940583, 0.78747463, 0.7808102, 0.7740663, 0.76724446, 0.7603461, 0.75337255, 0.7463254, 0.7392059, 0.7320157, 0.72475624, 0.717429, 0.7100354, 0.7025771, 0.69505554, 0.6874723, 0.6798289, 0.6721269, 0.6643678, 0.6565533, 0.6486849, 0.6407643, 0.6327929, 0.6247724, 0.6167045, 0.6085907, 0.6004327, 0.59223205, 0.5839904, 0.5757094, 0.5673907, 0.5590359, 0.5506467, 0.54222476, 0.53377163, 0.52528906, 0.51677865, 0.5082421, 0.499681, 0.49109706, 0.4824919, 0.4738672, 0.4652246, 0.45656574, 0.44789225, 0.43920583, 0.43050808, 0.4218006, 0.41308513, 0.40436321, 0.3956365, 0.38690662, 0.37817517, 0.36944377, 0.36071402, 0.3519875, 0.34326583, 0.3345506, 0.3258433, 0.31714556, 0.30845895, 0.29978496, 0.29112518, 0.2824811, 0.27385426, 0.26524615, 0.2566583, 0.24809211, 0.23954913, 0.2310308, 0.22253856, 0.21407387, 0.2056381, 0.1972327, 0.18885905, 0.18051854, 0.17221251, 0.16394234, 0.15570936, 0.14751486, 0.1393602, 0.13124663, 0.12317542, 0.115147844, 0.107165135, 0.099228516, 0.091339186, 0.08349834, 0.075707145, 0.067966744, 0.06027828, 0.052642867, 0.045061592, 0.037535537, 0.03006576, 0.022653295, 0.01529916, 0.008004353, 0.00076985173, -0.006403387, -0.013514427, -0.02056235, -0.027546264, -0.034465294, -0.041318584, -0.048105307, -0.05482465, -0.061475825, -0.068058066, -0.074570626, -0.08101279, -0.087383844, -0.09368312, -0.099909954, -0.10606372, -0.1121438, -0.11814961, -0.124080576, -0.12993616, -0.13571584, -0.14141911, -0.14704552, -0.15259458, -0.15806589, -0.16345903, -0.1687736, -0.17400928, -0.1791657, -0.18424256, -0.18923955, -0.19415641, -0.1989929, -0.20374878, -0.20842387, -0.21301796, -0.21753092, -0.22196262, -0.22631292, -0.23058176, -0.23476905, -0.23887476, -0.24289888, -0.24684139, -0.25070232, -0.2544817, -0.2581796, -0.26179612, -0.2653314, -0.26878548, -0.27215856, -0.27545086, -0.2786625, -0.2817937, -0.28484476, -0.28781584, -0.2907073, -0.29351938, -0.29625243, -0.29890674, -0.30148268, -0.30398065, -0.306401, -0.3087442, -0.3110106, -0.31320068, -0.31531492, -0.3173538, -0.31931782, -0.32120746, -0.3230233, -0.32476586, -0.32643574, -0.32803348, -0.32955974, -0.33101505, -0.33240014, -0.3337156, -0.33496207, -0.33614025, -0.33725083, -0.33829454, -0.33927208, -0.34018415, -0.34103152, -0.34181497, -0.34253523, -0.34319308, -0.34378934, -0.3443248, -0.3448003, -0.34521663, -0.34557465, -0.34587517, -0.3461191, -0.3463073, -0.3464406, -0.34651995, -0.3465462, -0.34652027, -0.34644303, -0.34631544, -0.34613845, -0.3459129, -0.3456398, -0.34532008, -0.3449547, -0.34454456, -0.3440907, -0.343594, -0.34305552, -0.34247616, -0.34185693, -0.3411988, -0.34050277, -0.3397698, -0.3390009, -0.33819705, -0.33735925, -0.3364885, -0.33558574, -0.33465204, -0.33368835, -0.3326957, -0.33167505, -0.33062738, -0.32955375, -0.3284551, -0.3273324, -0.32618672, -0.32501897, -0.32383016, -0.32262126, -0.32139325, -0.3201471, -0.3188838, -0.3176043, -0.31630954, -0.31500053, -0.31367815, -0.3123434, -0.3109972, -0.30964044, -0.30827412, -0.3068991, -0.30551633, -0.3041267, -0.30273113, -0.30133045, -0.2999256, -0.29851744, -0.29710683, -0.29569462, -0.29428166, -0.2928688, -0.29145685, -0.29004666, -0.288639, -0.2872347, -0.2858345, -0.28443924, -0.28304964, -0.2816665, -0.28029048, -0.2789224, -0.27756298, -0.27621287, -0.27487278, -0.27354345, -0.27222547, -0.27091956, -0.26962635, -0.26834646, -0.26708052, -0.26582915, -0.26459295, -0.26337245, -0.26216826, -0.26098093, -0.259811, -0.25865898, -0.2575254, -0.25641075, -0.2553155, -0.25424016, -0.25318512, -0.25215086, -0.25113782, -0.2501464, -0.24917693, -0.24822989, -0.24730559, -0.2464044, -0.24552663, -0.24467263, -0.2438427, -0.24303713, -0.2422562, -0.24150015, -0.24076925, -0.24006373, -0.23938379, -0.23872964, -0.23810148, -0.23749946, -0.23692374, -0.23637447, -0.23585176, -0.23535575, -0.23488651, -0.23444414, -0.2340287, -0.23364024, -0.2332788, -0.2329444, -0.23263706, -0.23235679, -0.23210353, -0.23187728, -0.23167798, -0.23150557, -0.23135999, -0.23124114, -0.23114891, -0.2310832, -0.23104388, -0.2310308, -0.23104383, -0.23108277, -0.23114747, -0.23123772, -0.23135333, -0.23149407, -0.23165971, -0.23185003, -0.23206477, -0.23230366, -0.23256643, -0.2328528, -0.23316246, -0.23349513, -0.23385046, -0.23422815, -0.23462786, -0.23504922, -0.23549189, -0.23595549, -0.23643968, -0.23694403, -0.23746818, -0.23801172, -0.23857422, -0.23915529, -0.2397545, -0.2403714, -0.24100557, -0.24165654, -0.24232388, -0.24300711, -0.24370576, -0.24441938, -0.24514747, -0.24588954, -0.24664512, -0.24741371, -0.2481948, -0.2489879, -0.24979247, -0.25060803, -0.25143406, -0.25227, -0.2531154, -0.25396967, -0.2548323, -0.25570276, -0.2565805, -0.25746503, -0.25835577, -0.2592522, -0.26015377, -0.26105994, -0.26197016, -0.26288393, -0.26380065, -0.2647198, -0.26564088, -0.2665633, -0.26748654, -0.26841003, -0.2693333, -0.27025574, -0.27117687, -0.27209616, -0.27301306, -0.27392703, -0.27483755, -0.27574414, -0.2766463, -0.27754343, -0.27843505, -0.27932072, -0.2801999, -0.28107208, -0.2819368, -0.28279355, -0.28364184, -0.28448126, -0.28531128, -0.28613144, -0.28694132, -0.28774047, -0.2885284, -0.28930473, -0.290069, -0.2908208, -0.29155973, -0.29228535, -0.29299727, -0.29369512, -0.29437852, -0.29504704, -0.2957004, -0.2963382, -0.2969601, -0.2975657, -0.29815477, -0.29872695, -0.29928192, -0.29981938, -0.30033904, -0.30084062, -0.30132386, -0.30178848, -0.30223423, -0.3026609, -0.30306825, -0.303456, -0.30382404, -0.30417207, -0.30449998, -0.30480757, -0.30509466, -0.30536112, -0.30560678, -0.30583152, -0.30603525, -0.3062178, -0.3063791, -0.3065191, -0.30663767, -0.30673477, -0.30681035, -0.30686435, -0.30689678, -0.3069076, -0.30689678, -0.30686435, -0.30681035, -0.30673477, -0.30663767, -0.3065191, -0.3063791, -0.3062178, -0.30603525, -0.30583152, -0.30560678, -0.30536112, -0.30509466, -0.30480757, -0.30449998, -0.30417207, -0.30382404, -0.303456, -0.30306825, -0.3026609, -0.30223423, -0.30178848, -0.30132386, -0.30084062, -0.30033904, -0.29981938, -0.29928192, -0.29872695, -0.29815477, -0.2975657, -0.2969601, -0.2963382, -0.2957004, -0.29504704, -0.29437852, -0.29369512, -0.29299727, -0.29228535, -0.29155973, -0.2908208, -0.290069, -0.28930473, -0.2885284, -0.28774047, -0.28694132, -0.28613144, -0.28531128, -0.28448126, -0.28364184, -0.28279355, -0.2819368, -0.28107208, -0.2801999, -0.27932072, -0.27843505, -0.27754343, -0.2766463, -0.27574414, -0.27483755, -0.27392703, -0.27301306, -0.27209616, -0.27117687, -0.27025574, -0.2693333, -0.26841003, -0.26748654, -0.2665633, -0.26564088, -0.2647198, -0.26380065, -0.26288393, -0.26197016, -0.26105994, -0.26015377, -0.2592522, -0.25835577, -0.25746503, -0.2565805, -0.25570276, -0.2548323, -0.25396967, -0.2531154, -0.25227, -0.25143406, -0.25060803, -0.24979247, -0.2489879, -0.2481948, -0.24741371, -0.24664512, -0.24588954, -0.24514747, -0.24441938, -0.24370576, -0.24300711, -0.24232388, -0.24165654, -0.24100557, -0.2403714, -0.2397545, -0.23915529, -0.23857422, -0.23801172, -0.23746818, -0.23694403, -0.23643968, -0.23595549, -0.23549189, -0.23504922, -0.23462786, -0.23422815, -0.23385046, -0.23349513, -0.23316246, -0.2328528, -0.23256643, -0.23230366, -0.23206477, -0.23185003, -0.23165971, -0.23149407, -0.23135333, -0.23123772, -0.23114747, -0.23108277, -0.23104383, -0.2310308, -0.23104388, -0.2310832, -0.23114891, -0.23124114, -0.23135999, -0.23150557, -0.23167798, -0.23187728, -0.23210353, -0.23235679, -0.23263706, -0.2329444, -0.2332788, -0.23364024, -0.2340287, -0.23444414, -0.23488651, -0.23535575, -0.23585176, -0.23637447, -0.23692374, -0.23749946, -0.23810148, -0.23872964, -0.23938379, -0.24006373, -0.24076925, -0.24150015, -0.2422562, -0.24303713, -0.2438427, -0.24467263, -0.24552663, -0.2464044, -0.24730559, -0.24822989, -0.24917693, -0.2501464, -0.25113782, -0.25215086, -0.25318512, -0.25424016, -0.2553155, -0.25641075, -0.2575254, -0.25865898, -0.259811, -0.26098093, -0.26216826, -0.26337245, -0.26459295, -0.26582915, -0.26708052, -0.26834646, -0.26962635, -0.27091956, -0.27222547, -0.27354345, -0.27487278, -0.27621287, -0.27756298, -0.2789224, -0.28029048, -0.2816665, -0.28304964, -0.28443924, -0.2858345, -0.2872347, -0.288639, -0.29004666, -0.29145685, -0.2928688, -0.29428166, -0.29569462, -0.29710683, -0.29851744, -0.2999256, -0.30133045, -0.30273113, -0.3041267, -0.30551633, -0.3068991, -0.30827412, -0.30964044, -0.3109972, -0.3123434, -0.31367815, -0.31500053, -0.31630954, -0.3176043, -0.3188838, -0.3201471, -0.32139325, -0.32262126, -0.32383016, -0.32501897, -0.32618672, -0.3273324, -0.3284551, -0.32955375, -0.33062738, -0.33167505, -0.3326957, -0.33368835, -0.33465204, -0.33558574, -0.3364885, -0.33735925, -0.33819705, -0.3390009, -0.3397698, -0.34050277, -0.3411988, -0.34185693, -0.34247616, -0.34305552, -0.343594, -0.3440907, -0.34454456, -0.3449547, -0.34532008, -0.3456398, -0.3459129, -0.34613845, -0.34631544, -0.34644303, -0.34652027, -0.3465462, -0.34651995, -0.3464406, -0.3463073, -0.3461191, -0.34587517, -0.34557465, -0.34521663, -0.3448003, -0.3443248, -0.34378934, -0.34319308, -0.34253523, -0.34181497, -0.34103152, -0.34018415, -0.33927208, -0.33829454, -0.33725083, -0.33614025, -0.33496207, -0.3337156, -0.33240014, -0.33101505, -0.32955974, -0.32803348, -0.32643574, -0.32476586, -0.3230233, -0.32120746, -0.31931782, -0.3173538, -0.31531492, -0.31320068, -0.3110106, -0.3087442, -0.306401, -0.30398065, -0.30148268, -0.29890674, -0.29625243, -0.29351938, -0.2907073, -0.28781584, -0.28484476, -0.2817937, -0.2786625, -0.27545086, -0.27215856, -0.26878548, -0.2653314, -0.26179612, -0.2581796, -0.2544817, -0.25070232, -0.24684139, -0.24289888, -0.23887476, -0.23476905, -0.23058176, -0.22631292, -0.22196262, -0.21753092, -0.21301796, -0.20842387, -0.20374878, -0.1989929, -0.19415641, -0.18923955, -0.18424256, -0.1791657, -0.17400928, -0.1687736, -0.16345903, -0.15806589, -0.15259458, -0.14704552, -0.14141911, -0.13571584, -0.12993616, -0.124080576, -0.11814961, -0.1121438, -0.10606372, -0.099909954, -0.09368312, -0.087383844, -0.08101279, -0.074570626, -0.068058066, -0.061475825, -0.05482465, -0.048105307, -0.041318584, -0.034465294, -0.027546264, -0.02056235, -0.013514427, -0.006403387, 0.00076985173, 0.008004353, 0.01529916, 0.022653295, 0.03006576, 0.037535537, 0.045061592, 0.052642867, 0.06027828, 0.067966744, 0.075707145, 0.08349834, 0.091339186, 0.099228516, 0.107165135, 0.115147844, 0.12317542, 0.13124663, 0.1393602, 0.14751486, 0.15570936, 0.16394234, 0.17221251, 0.18051854, 0.18885905, 0.1972327, 0.2056381, 0.21407387, 0.22253856, ], [0.3465462, 0.3529187, 0.35927743, 0.3656217, 0.37195072, 0.37826383, 0.38456032, 0.39083943, 0.39710045, 0.40334272, 0.40956548, 0.415768, 0.42194963, 0.42810962, 0.4342473, 0.4403619, 0.44645277, 0.4525192, 0.45856047, 0.46457592, 0.47056484, 0.4765265, 0.4824603, 0.48836544, 0.4942413, 0.5000872, 0.50590247, 0.5116864, 0.51743835, 0.5231576, 0.5288435, 0.5344955, 0.54011273, 0.5456947, 0.5512407, 0.55675006, 0.5622222, 0.5676564, 0.57305205, 0.57840854, 0.5837253, 0.58900154, 0.59423673, 0.5994303, 0.6045816, 0.60969, 0.6147549, 0.6197757, 0.62475187, 0.6296827, 0.63456774, 0.6394063, 0.64419794, 0.64894193, 0.6536378, 0.6582851, 0.662883, 0.6674312, 0.671929, 0.676376, 0.6807716, 0.6851153, 0.6894065, 0.69364476, 0.6978296, 0.7019605, 0.7060369, 0.71005845, 0.71402454, 0.7179348, 0.72178864, 0.7255857, 0.7293255, 0.73300755, 0.7366315, 0.7401968, 0.7437031, 0.74715, 0.750537, 0.75386375, 0.75712985, 0.7603349, 0.7634785, 0.7665603, 0.76957995, 0.772537, 0.77543116, 0.7782621, 0.7810294, 0.78373283, 0.78637195, 0.78894657, 0.7914563, 0.7939008, 0.79627985, 0.7985931, 0.8008403, 0.80302125, 0.8051356, 0.80718315, 0.8091636, 0.8110767, 0.8129223, 0.81470007, 0.8164099, 0.8180515, 0.8196248, 0.8211294, 0.8225653, 0.82393223, 0.82523006, 0.8264587, 0.8276178, 0.82870746, 0.8297274, 0.83067757, 0.8315578, 0.832368, 0.83310807, 0.8337779, 0.8343775, 0.8349067, 0.8353655, 0.83575374, 0.8360715, 0.8363187, 0.8364953, 0.83660126, 0.83663654, 0.83660126, 0.8364953, 0.8363187, 0.8360715, 0.83575374, 0.8353655, 0.8349067, 0.8343775, 0.8337779, 0.83310807, 0.832368, 0.8315578, 0.83067757, 0.8297274, 0.82870746, 0.8276178, 0.8264587, 0.82523006, 0.82393223, 0.8225653, 0.8211294, 0.8196248, 0.8180515, 0.8164099, 0.81470007, 0.8129223, 0.8110767, 0.8091636, 0.80718315, 0.8051356, 0.80302125, 0.8008403, 0.7985931, 0.79627985, 0.7939008, 0.7914563, 0.78894657, 0.78637195, 0.78373283, 0.7810294, 0.7782621, 0.77543116, 0.772537, 0.76957995, 0.7665603, 0.7634785, 0.7603349, 0.75712985, 0.75386375, 0.750537, 0.74715, 0.7437031, 0.7401968, 0.7366315, 0.73300755, 0.7293255, 0.7255857, 0.72178864, 0.7179348, 0.71402454, 0.71005845, 0.7060369, 0.7019605, 0.6978296, 0.69364476, 0.6894065, 0.6851153, 0.6807716, 0.676376, 0.671929, 0.6674312, 0.662883, 0.6582851, 0.6536378, 0.64894193, 0.64419794, 0.6394063, 0.63456774, 0.6296827, 0.62475187, 0.6197757, 0.6147549, 0.60969, 0.6045816, 0.5994303, 0.59423673, 0.58900154, 0.5837253, 0.57840854, 0.57305205, 0.5676564, 0.5622222, 0.55675006, 0.5512407, 0.5456947, 0.54011273, 0.5344955, 0.5288435, 0.5231576, 0.51743835, 0.5116864, 0.50590247, 0.5000872, 0.4942413, 0.48836544, 0.4824603, 0.4765265, 0.47056484, 0.46457592, 0.45856047, 0.4525192, 0.44645277, 0.4403619, 0.4342473, 0.42810962, 0.42194963, 0.415768, 0.40956548, 0.40334272, 0.39710045, 0.39083943, 0.38456032, 0.37826383, 0.37195072, 0.3656217, 0.35927743, 0.3529187, 0.3465462, 0.34016067, 0.3337628, 0.3273533, 0.32093295, 0.31450242, 0.30806246, 0.30161378, 0.2951571, 0.28869313, 0.28222263, 0.2757463, 0.26926482, 0.26277897, 0.25628942, 0.24979694, 0.24330221, 0.23680596, 0.2303089, 0.22381175, 0.2173152, 0.21081999, 0.20432682, 0.1978364, 0.19134942, 0.1848666, 0.17838866, 0.17191626, 0.16545014, 0.15899096, 0.15253943, 0.14609626, 0.13966212, 0.13323769, 0.12682368, 0.12042074, 0.11402957, 0.107650846, 0.10128524, 0.09493341, 0.08859604, 0.08227378, 0.075967304, 0.069677256, 0.06340429, 0.05714906, 0.050912216, 0.044694394, 0.038496234, 0.03231837, 0.026161432, 0.020026047, 0.013912838, 0.00782242, 0.0017554099, -0.004287586, -0.0103059625, -0.016299121, -0.022266464, -0.028207403, -0.034121353, -0.040007733, -0.045865964, -0.05169548, -0.057495717, -0.06326611, -0.06900611, -0.07471516, -0.08039272, -0.086038254, -0.09165122, -0.097231105, -0.10277738, -0.10828952, -0.11376703, -0.1192094, -0.12461613, -0.12998673, -0.13532071, -0.14061761, -0.14587693, -0.1510982, -0.156281, -0.16142482, -0.16652925, -0.17159383, -0.17661814, -0.18160175, -0.18654422, -0.19144516, -0.19630414, -0.20112078, -0.20589466, -0.21062541, -0.21531263, -0.21995598, -0.22455506, -0.22910953, -0.23361903, -0.23808321, -0.24250175, -0.2468743, -0.25120056, -0.25548017, -0.25971285, -0.2638983, -0.26803625, -0.27212635, -0.27616835, -0.280162, -0.28410703, -0.28800318, -0.29185015, -0.29564777, -0.2993958, -0.30309397, -0.30674207, -0.31033993, -0.31388733, -0.31738403, -0.3208299, -0.32422474, -0.32756835, -0.3308606, -0.33410135, -0.3372904, -0.34042767, -0.34351298, -0.3465462, -0.34952724, -0.352456, -0.35533234, -0.3581562, -0.36092746, -0.3636461, -0.36631197, -0.3689251, -0.37148535, -0.3739927, -0.37644717, -0.37884864, -0.38119715, -0.38349268, -0.38573518, -0.38792467, -0.39006117, -0.3921447, -0.3941753, -0.39615294, -0.3980777, -0.39994964, -0.4017688, -0.40353525, -0.40524903, -0.40691024, -0.40851897, -0.4100753, -0.41157934, -0.4130312, -0.41443098, -0.41577882, -0.41707486, -0.4183192, -0.41951203, -0.42065346, -0.4217437, -0.42278284, -0.4237711, -0.4247087, -0.4255958, -0.42643255, -0.42721918, -0.42795596, -0.42864302, -0.4292806, -0.42986897, -0.43040836, -0.430899, -0.4313411, -0.431735, -0.43208092, -0.43237913, -0.43262988, -0.43283352, -0.4329903, -0.43310052, -0.43316448, -0.4331825, -0.43315488, -0.43308195, -0.43296406, -0.43280149, -0.43259463, -0.4323438, -0.43204936, -0.43171164, -0.43133107, -0.43090793, -0.4304427, -0.42993563, -0.4293872, -0.42879778, -0.42816776, -0.42749754, -0.4267875, -0.4260381, -0.42524973, -0.4244228, -0.42355776, -0.42265502, -0.42171502, -0.4207382, -0.419725, -0.4186759, -0.4175913, -0.4164717, -0.41531754, -0.4141293, -0.41290745, -0.41165245, -0.41036478, -0.40904492, -0.40769336, -0.4063106, -0.4048971, -0.40345338, -0.40197995, -0.4004773, -0.39894593, -0.39738637, -0.3957991, -0.39418465, -0.39254352, -0.3908763, -0.3891834, -0.38746545, -0.38572294, -0.38395637, -0.3821663, -0.38035327, -0.37851784, -0.3766605, -0.37478182, -0.37288234, -0.3709626, -0.36902314, -0.36706454, -0.3650873, -0.36309204, -0.36107925, -0.3590495, -0.35700336, -0.35494137, -0.35286412, -0.3507721, -0.34866598, -0.3465462, -0.3444134, -0.3422681, -0.34011087, -0.33794227, -0.3357629, -0.33357325, -0.33137396, -0.32916552, -0.32694855, -0.32472357, -0.32249114, -0.32025185, -0.31800625, -0.3157549, -0.31349835, -0.31123716, -0.30897188, -0.30670306, -0.3044313, -0.3021571, -0.29988107, -0.2976037, -0.29532555, -0.2930472, -0.2907692, -0.28849202, -0.2862163, -0.28394252, -0.28167126, -0.279403, -0.27713832, -0.27487773, -0.27262178, -0.270371, -0.2681259, -0.265887, -0.2636548, -0.26142985, -0.25921267, -0.25700375, -0.25480363, -0.25261277, -0.2504317, -0.24826089, -0.24610086, -0.2439521, -0.2418151, -0.23969033, -0.2375783, -0.23547946, -0.2333943, -0.23132327, -0.22926685, -0.22722551, -0.22519968, -0.22318985, -0.22119643, -0.21921988, -0.21726064, -0.21531917, -0.21339586, -0.21149117, -0.2096055, -0.20773926, -0.20589289, -0.20406678, -0.20226134, -0.20047696, -0.19871403, -0.19697295, -0.19525409, -0.19355781, -0.19188452, -0.19023456, -0.18860829, -0.18700607, -0.18542825, -0.18387517, -0.18234716, -0.18084458, -0.17936772, -0.17791693, -0.1764925, -0.17509475, -0.17372398, -0.17238049, -0.17106456, -0.16977648, -0.16851653, -0.16728498, -0.1660821, -0.16490814, -0.16376334, -0.16264798, -0.16156226, -0.16050644, -0.15948075, -0.1584854, -0.15752059, -0.15658654, -0.15568344, -0.1548115, -0.1539709, -0.15316181, -0.15238442, -0.15163888, -0.15092535, -0.15024398, -0.14959493, -0.14897834, -0.14839432, -0.147843, -0.14732452, -0.14683896, -0.14638644, -0.14596705, -0.14558089, -0.14522803, -0.14490855, -0.14462252, -0.14436999, -0.14415103, -0.14396568, -0.14381397, -0.14369594, -0.14361161, -0.143561, -0.14354414, -0.143561, -0.14361161, -0.14369594, -0.14381397, -0.14396568, -0.14415103, -0.14436999, -0.14462252, -0.14490855, -0.14522803, -0.14558089, -0.14596705, -0.14638644, -0.14683896, -0.14732452, -0.147843, -0.14839432, -0.14897834, -0.14959493, -0.15024398, -0.15092535, -0.15163888, -0.15238442, -0.15316181, -0.1539709, -0.1548115, -0.15568344, -0.15658654, -0.15752059, -0.1584854, -0.15948075, -0.16050644, -0.16156226, -0.16264798, -0.16376334, -0.16490814, -0.1660821, -0.16728498, -0.16851653, -0.16977648, -0.17106456, -0.17238049, -0.17372398, -0.17509475, -0.1764925, -0.17791693, -0.17936772, -0.18084458, -0.18234716, -0.18387517, -0.18542825, -0.18700607, -0.18860829, -0.19023456, -0.19188452, -0.19355781, -0.19525409, -0.19697295, -0.19871403, -0.20047696, -0.20226134, -0.20406678, -0.20589289, -0.20773926, -0.2096055, -0.21149117, -0.21339586, -0.21531917, -0.21726064, -0.21921988, -0.22119643, -0.22318985, -0.22519968, -0.22722551, -0.22926685, -0.23132327, -0.2333943, -0.23547946, -0.2375783, -0.23969033, -0.2418151, -0.2439521, -0.24610086, -0.24826089, -0.2504317, -0.25261277, -0.25480363, -0.25700375, -0.25921267, -0.26142985, -0.2636548, -0.265887, -0.2681259, -0.270371, -0.27262178, -0.27487773, -0.27713832, -0.279403, -0.28167126, -0.28394252, -0.2862163, -0.28849202, -0.2907692, -0.2930472, -0.29532555, -0.2976037, -0.29988107, -0.3021571, -0.3044313, -0.30670306, -0.30897188, -0.31123716, -0.31349835, -0.3157549, -0.31800625, -0.32025185, -0.32249114, -0.32472357, -0.32694855, -0.32916552, -0.33137396, -0.33357325, -0.3357629, -0.33794227, -0.34011087, -0.3422681, -0.3444134, -0.3465462, -0.34866598, -0.3507721, -0.35286412, -0.35494137, -0.35700336, -0.3590495, -0.36107925, -0.36309204, -0.3650873, -0.36706454, -0.36902314, -0.3709626, -0.37288234, -0.37478182, -0.3766605, -0.37851784, -0.38035327, -0.3821663, -0.38395637, -0.38572294, -0.38746545, -0.3891834, -0.3908763, -0.39254352, -0.39418465, -0.3957991, -0.39738637, -0.39894593, -0.4004773, -0.40197995, -0.40345338, -0.4048971, -0.4063106, -0.40769336, -0.40904492, -0.41036478, -0.41165245, -0.41290745, -0.4141293, -0.41531754, -0.4164717, -0.4175913, -0.4186759, -0.419725, -0.4207382, -0.42171502, -0.42265502, -0.42355776, -0.4244228, -0.42524973, -0.4260381, -0.4267875, -0.42749754, -0.42816776, -0.42879778, -0.4293872, -0.42993563, -0.4304427, -0.43090793, -0.43133107, -0.43171164, -0.43204936, -0.4323438, -0.43259463, -0.43280149, -0.43296406, -0.43308195, -0.43315488, -0.4331825, -0.43316448, -0.43310052, -0.4329903, -0.43283352, -0.43262988, -0.43237913, -0.43208092, -0.431735, -0.4313411, -0.430899, -0.43040836, -0.42986897, -0.4292806, -0.42864302, -0.42795596, -0.42721918, -0.42643255, -0.4255958, -0.4247087, -0.4237711, -0.42278284, -0.4217437, -0.42065346, -0.41951203, -0.4183192, -0.41707486, -0.41577882, -0.41443098, -0.4130312, -0.41157934, -0.4100753, -0.40851897, -0.40691024, -0.40524903, -0.40353525, -0.4017688, -0.39994964, -0.3980777, -0.39615294, -0.3941753, -0.3921447, -0.39006117, -0.38792467, -0.38573518, -0.38349268, -0.38119715, -0.37884864, -0.37644717, -0.3739927, -0.37148535, -0.3689251, -0.36631197, -0.3636461, -0.36092746, -0.3581562, -0.35533234, -0.352456, -0.34952724, -0.3465462, -0.34351298, -0.34042767, -0.3372904, -0.33410135, -0.3308606, -0.32756835, -0.32422474, -0.3208299, -0.31738403, -0.31388733, -0.31033993, -0.30674207, -0.30309397, -0.2993958, -0.29564777, -0.29185015, -0.28800318, -0.28410703, -0.280162, -0.27616835, -0.27212635, -0.26803625, -0.2638983, -0.25971285, -0.25548017, -0.25120056, -0.2468743, -0.24250175, -0.23808321, -0.23361903, -0.22910953, -0.22455506, -0.21995598, -0.21531263, -0.21062541, -0.20589466, -0.20112078, -0.19630414, -0.19144516, -0.18654422, -0.18160175, -0.17661814, -0.17159383, -0.16652925, -0.16142482, -0.156281, -0.1510982, -0.14587693, -0.14061761, -0.13532071, -0.12998673, -0.12461613, -0.1192094, -0.11376703, -0.10828952, -0.10277738, -0.097231105, -0.09165122, -0.086038254, -0.08039272, -0.07471516, -0.06900611, -0.06326611, -0.057495717, -0.05169548, -0.045865964, -0.040007733, -0.034121353, -0.028207403, -0.022266464, -0.016299121, -0.0103059625, -0.004287586, 0.0017554099, 0.00782242, 0.013912838, 0.020026047, 0.026161432, 0.03231837, 0.038496234, 0.044694394, 0.050912216, 0.05714906, 0.06340429, 0.069677256, 0.075967304, 0.08227378, 0.08859604, 0.09493341, 0.10128524, 0.107650846, 0.11402957, 0.12042074, 0.12682368, 0.13323769, 0.13966212, 0.14609626, 0.15253943, 0.15899096, 0.16545014, 0.17191626, 0.17838866, 0.1848666, 0.19134942, 0.1978364, 0.20432682, 0.21081999, 0.2173152, 0.22381175, 0.2303089, 0.23680596, 0.24330221, 0.24979694, 0.25628942, 0.26277897, 0.26926482, 0.2757463, 0.28222263, 0.28869313, 0.2951571, 0.30161378, 0.30806246, 0.31450242, 0.32093295, 0.3273533, 0.3337628, 0.34016067, ], [0.3465462, 0.34866604, 0.35077277, 0.3528663, 0.3549465, 0.35701337, 0.35906678, 0.3611067, 0.36313298, 0.36514562, 0.36714453, 0.36912957, 0.37110075, 0.37305793, 0.3750011, 0.37693012, 0.37884495, 0.38074553, 0.38263178, 0.3845036, 0.38636097, 0.3882038, 0.390032, 0.3918455, 0.39364424, 0.3954282, 0.39719725, 0.39895135, 0.40069044, 0.4024144, 0.40412325, 0.40581688, 0.40749523, 0.40915823, 0.41080582, 0.41243798, 0.41405457, 0.41565558, 0.41724095, 0.4188106, 0.4203645, 0.42190257, 0.42342475, 0.424931, 0.42642123, 0.42789543, 0.4293535, 0.4307954, 0.43222108, 0.43363053, 0.4350236, 0.43640032, 0.4377606, 0.4391044, 0.44043168, 0.44174236, 0.44303644, 0.4443138, 0.44557446, 0.44681832, 0.44804537, 0.44925556, 0.4504488, 0.45162514, 0.45278445, 0.45392668, 0.45505187, 0.4561599, 0.45725077, 0.4583244, 0.4593808, 0.4604199, 0.46144167, 0.46244603, 0.46343303, 0.46440256, 0.4653546, 0.4662891, 0.4672061, 0.46810547, 0.46898723, 0.46985132, 0.47069773, 0.4715264, 0.47233734, 0.4731305, 0.47390583, 0.47466332, 0.47540295, 0.47612467, 0.4768285, 0.47751433, 0.4781822, 0.47883207, 0.4794639, 0.48007768, 0.4806734, 0.481251, 0.4818105, 0.48235187, 0.48287505, 0.48338008, 0.4838669, 0.48433548, 0.48478585, 0.48521796, 0.48563182, 0.4860274, 0.48640463, 0.4867636, 0.4871042, 0.4874265, 0.4877304, 0.488016, 0.4882832, 0.488532, 0.48876244, 0.48897445, 0.48916805, 0.48934326, 0.48950002, 0.48963836, 0.48975825, 0.48985973, 0.48994273, 0.4900073, 0.49005345, 0.49008113, 0.49009034, 0.49008113, 0.49005345, 0.4900073, 0.48994273, 0.48985973, 0.48975825, 0.48963836, 0.48950002, 0.48934326, 0.48916805, 0.48897445, 0.48876244, 0.488532, 0.4882832, 0.488016, 0.4877304, 0.4874265, 0.4871042, 0.4867636, 0.48640463, 0.4860274, 0.48563182, 0.48521796, 0.48478585, 0.48433548, 0.4838669, 0.48338008, 0.48287505, 0.48235187, 0.4818105, 0.481251, 0.4806734, 0.48007768, 0.4794639, 0.47883207, 0.4781822, 0.47751433, 0.4768285, 0.47612467, 0.47540295, 0.47466332, 0.47390583, 0.4731305, 0.47233734, 0.4715264, 0.47069773, 0.46985132, 0.46898723, 0.46810547, 0.4672061, 0.4662891, 0.4653546, 0.46440256, 0.46343303, 0.46244603, 0.46144167, 0.4604199, 0.4593808, 0.4583244, 0.45725077, 0.4561599, 0.45505187, 0.45392668, 0.45278445, 0.45162514, 0.4504488, 0.44925556, 0.44804537, 0.44681832, 0.44557446, 0.4443138, 0.44303644, 0.44174236, 0.44043168, 0.4391044, 0.4377606, 0.43640032, 0.4350236, 0.43363053, 0.43222108, 0.4307954, 0.4293535, 0.42789543, 0.42642123, 0.424931, 0.42342475, 0.42190257, 0.4203645, 0.4188106, 0.41724095, 0.41565558, 0.41405457, 0.41243798, 0.41080582, 0.40915823, 0.40749523, 0.40581688, 0.40412325, 0.4024144, 0.40069044, 0.39895135, 0.39719725, 0.3954282, 0.39364424, 0.3918455, 0.390032, 0.3882038, 0.38636097, 0.3845036, 0.38263178, 0.38074553, 0.37884495, 0.37693012, 0.3750011, 0.37305793, 0.37110075, 0.36912957, 0.36714453, 0.36514562, 0.36313298, 0.3611067, 0.35906678, 0.35701337, 0.3549465, 0.3528663, 0.35077277, 0.34866604, 0.3465462, 0.3444133, 0.34226745, 0.34010872, 0.33793718, 0.3357529, 0.33355597, 0.3313465, 0.32912457, 0.32689023, 0.32464358, 0.32238472, 0.32011372, 0.31783065, 0.31553563, 0.31322873, 0.31091002, 0.30857962, 0.3062376, 0.30388406, 0.30151907, 0.29914272, 0.2967551, 0.29435635, 0.29194647, 0.28952563, 0.28709388, 0.2846513, 0.28219804, 0.27973413, 0.2772597, 0.27477482, 0.27227962, 0.26977414, 0.26725852, 0.26473284, 0.26219717, 0.25965163, 0.25709635, 0.25453135, 0.2519568, 0.24937274, 0.24677931, 0.24417657, 0.24156465, 0.23894362, 0.23631361, 0.2336747, 0.23102699, 0.22837058, 0.22570558, 0.22303207, 0.22035018, 0.21765998, 0.21496159, 0.2122551, 0.20954062, 0.20681825, 0.2040881, 0.20135027, 0.19860485, 0.19585197, 0.19309169, 0.19032416, 0.18754946, 0.1847677, 0.18197897, 0.1791834, 0.17638108, 0.17357212, 0.17075664, 0.16793472, 0.16510646, 0.162272, 0.15943143, 0.15658486, 0.15373239, 0.15087414, 0.1480102, 0.1451407, 0.14226572, 0.13938539, 0.1364998, 0.1336091, 0.13071334, 0.12781268, 0.124907196, 0.12199701, 0.11908224, 0.11616298, 0.11323935, 0.11031145, 0.1073794, 0.10444331, 0.10150328, 0.09855944, 0.095611885, 0.092660725, 0.08970608, 0.086748056, 0.08378677, 0.080822326, 0.07785484, 0.07488442, 0.071911186, 0.068935245, 0.065956704, 0.06297568, 0.05999229, 0.05700664, 0.05401884, 0.051029008, 0.048037253, 0.045043692, 0.042048436, 0.039051592, 0.03605328, 0.033053614, 0.030052701, 0.027050657, 0.024047593, 0.021043625, 0.018038865, 0.015033425, 0.012027419, 0.009020961, 0.006014162, 0.003007138, 3.8474357e-17, -0.003007138, -0.006014162, -0.009020961, -0.012027419, -0.015033425, -0.018038865, -0.021043625, -0.024047593, -0.027050657, -0.030052701, -0.033053614, -0.03605328, -0.039051592, -0.042048436, -0.045043692, -0.048037253, -0.051029008, -0.05401884, -0.05700664, -0.05999229, -0.06297568, -0.065956704, -0.068935245, -0.071911186, -0.07488442, -0.07785484, -0.080822326, -0.08378677, -0.086748056, -0.08970608, -0.092660725, -0.095611885, -0.09855944, -0.10150328, -0.10444331, -0.1073794, -0.11031145, -0.11323935, -0.11616298, -0.11908224, -0.12199701, -0.124907196, -0.12781268, -0.13071334, -0.1336091, -0.1364998, -0.13938539, -0.14226572, -0.1451407, -0.1480102, -0.15087414, -0.15373239, -0.15658486, -0.15943143, -0.162272, -0.16510646, -0.16793472, -0.17075664, -0.17357212, -0.17638108, -0.1791834, -0.18197897, -0.1847677, -0.18754946, -0.19032416, -0.19309169, -0.19585197, -0.19860485, -0.20135027, -0.2040881, -0.20681825, -0.20954062, -0.2122551, -0.21496159, -0.21765998, -0.22035018, -0.22303207, -0.22570558, -0.22837058, -0.23102699, -0.2336747, -0.23631361, -0.23894362, -0.24156465, -0.24417657, -0.24677931, -0.24937274, -0.2519568, -0.25453135, -0.25709635, -0.25965163, -0.26219717, -0.26473284, -0.26725852, -0.26977414, -0.27227962, -0.27477482, -0.2772597, -0.27973413, -0.28219804, -0.2846513, -0.28709388, -0.28952563, -0.29194647, -0.29435635, -0.2967551, -0.29914272, -0.30151907, -0.30388406, -0.3062376, -0.30857962, -0.31091002, -0.31322873, -0.31553563, -0.31783065, -0.32011372, -0.32238472, -0.32464358, -0.32689023, -0.32912457, -0.3313465, -0.33355597, -0.3357529, -0.33793718, -0.34010872, -0.34226745, -0.3444133, -0.3465462, -0.34866604, -0.35077277, -0.3528663, -0.3549465, -0.35701337, -0.35906678, -0.3611067, -0.36313298, -0.36514562, -0.36714453, -0.36912957, -0.37110075, -0.37305793, -0.3750011, -0.37693012, -0.37884495, -0.38074553, -0.38263178, -0.3845036, -0.38636097, -0.3882038, -0.390032, -0.3918455, -0.39364424, -0.3954282, -0.39719725, -0.39895135, -0.40069044, -0.4024144, -0.40412325, -0.40581688, -0.40749523, -0.40915823, -0.41080582, -0.41243798, -0.41405457, -0.41565558, -0.41724095, -0.4188106, -0.4203645, -0.42190257, -0.42342475, -0.424931, -0.42642123, -0.42789543, -0.4293535, -0.4307954, -0.43222108, -0.43363053, -0.4350236, -0.43640032, -0.4377606, -0.4391044, -0.44043168, -0.44174236, -0.44303644, -0.4443138, -0.44557446, -0.44681832, -0.44804537, -0.44925556, -0.4504488, -0.45162514, -0.45278445, -0.45392668, -0.45505187, -0.4561599, -0.45725077, -0.4583244, -0.4593808, -0.4604199, -0.46144167, -0.46244603, -0.46343303, -0.46440256, -0.4653546, -0.4662891, -0.4672061, -0.46810547, -0.46898723, -0.46985132, -0.47069773, -0.4715264, -0.47233734, -0.4731305, -0.47390583, -0.47466332, -0.47540295, -0.47612467, -0.4768285, -0.47751433, -0.4781822, -0.47883207, -0.4794639, -0.48007768, -0.4806734, -0.481251, -0.4818105, -0.48235187, -0.48287505, -0.48338008, -0.4838669, -0.48433548, -0.48478585, -0.48521796, -0.48563182, -0.4860274, -0.48640463, -0.4867636, -0.4871042, -0.4874265, -0.4877304, -0.488016, -0.4882832, -0.488532, -0.48876244, -0.48897445, -0.48916805, -0.48934326, -0.48950002, -0.48963836, -0.48975825, -0.48985973, -0.48994273, -0.4900073, -0.49005345, -0.49008113, -0.49009034, -0.49008113, -0.49005345, -0.4900073, -0.48994273, -0.48985973, -0.48975825, -0.48963836, -0.48950002, -0.48934326, -0.48916805, -0.48897445, -0.48876244, -0.488532, -0.4882832, -0.488016, -0.4877304, -0.4874265, -0.4871042, -0.4867636, -0.48640463, -0.4860274, -0.48563182, -0.48521796, -0.48478585, -0.48433548, -0.4838669, -0.48338008, -0.48287505, -0.48235187, -0.4818105, -0.481251, -0.4806734, -0.48007768, -0.4794639, -0.47883207, -0.4781822, -0.47751433, -0.4768285, -0.47612467, -0.47540295, -0.47466332, -0.47390583, -0.4731305, -0.47233734, -0.4715264, -0.47069773, -0.46985132, -0.46898723, -0.46810547, -0.4672061, -0.4662891, -0.4653546, -0.46440256, -0.46343303, -0.46244603, -0.46144167, -0.4604199, -0.4593808, -0.4583244, -0.45725077, -0.4561599, -0.45505187, -0.45392668, -0.45278445, -0.45162514, -0.4504488, -0.44925556, -0.44804537, -0.44681832, -0.44557446, -0.4443138, -0.44303644, -0.44174236, -0.44043168, -0.4391044, -0.4377606, -0.43640032, -0.4350236, -0.43363053, -0.43222108, -0.4307954, -0.4293535, -0.42789543, -0.42642123, -0.424931, -0.42342475, -0.42190257, -0.4203645, -0.4188106, -0.41724095, -0.41565558, -0.41405457, -0.41243798, -0.41080582, -0.40915823, -0.40749523, -0.40581688, -0.40412325, -0.4024144, -0.40069044, -0.39895135, -0.39719725, -0.3954282, -0.39364424, -0.3918455, -0.390032, -0.3882038, -0.38636097, -0.3845036, -0.38263178, -0.38074553, -0.37884495, -0.37693012, -0.3750011, -0.37305793, -0.37110075, -0.36912957, -0.36714453, -0.36514562, -0.36313298, -0.3611067, -0.35906678, -0.35701337, -0.3549465, -0.3528663, -0.35077277, -0.34866604, -0.3465462, -0.3444133, -0.34226745, -0.34010872, -0.33793718, -0.3357529, -0.33355597, -0.3313465, -0.32912457, -0.32689023, -0.32464358, -0.32238472, -0.32011372, -0.31783065, -0.31553563, -0.31322873, -0.31091002, -0.30857962, -0.3062376, -0.30388406, -0.30151907, -0.29914272, -0.2967551, -0.29435635, -0.29194647, -0.28952563, -0.28709388, -0.2846513, -0.28219804, -0.27973413, -0.2772597, -0.27477482, -0.27227962, -0.26977414, -0.26725852, -0.26473284, -0.26219717, -0.25965163, -0.25709635, -0.25453135, -0.2519568, -0.24937274, -0.24677931, -0.24417657, -0.24156465, -0.23894362, -0.23631361, -0.2336747, -0.23102699, -0.22837058, -0.22570558, -0.22303207, -0.22035018, -0.21765998, -0.21496159, -0.2122551, -0.20954062, -0.20681825, -0.2040881, -0.20135027, -0.19860485, -0.19585197, -0.19309169, -0.19032416, -0.18754946, -0.1847677, -0.18197897, -0.1791834, -0.17638108, -0.17357212, -0.17075664, -0.16793472, -0.16510646, -0.162272, -0.15943143, -0.15658486, -0.15373239, -0.15087414, -0.1480102, -0.1451407, -0.14226572, -0.13938539, -0.1364998, -0.1336091, -0.13071334, -0.12781268, -0.124907196, -0.12199701, -0.11908224, -0.11616298, -0.11323935, -0.11031145, -0.1073794, -0.10444331, -0.10150328, -0.09855944, -0.095611885, -0.092660725, -0.08970608, -0.086748056, -0.08378677, -0.080822326, -0.07785484, -0.07488442, -0.071911186, -0.068935245, -0.065956704, -0.06297568, -0.05999229, -0.05700664, -0.05401884, -0.051029008, -0.048037253, -0.045043692, -0.042048436, -0.039051592, -0.03605328, -0.033053614, -0.030052701, -0.027050657, -0.024047593, -0.021043625, -0.018038865, -0.015033425, -0.012027419, -0.009020961, -0.006014162, -0.003007138, -7.6948715e-17, 0.003007138, 0.006014162, 0.009020961, 0.012027419, 0.015033425, 0.018038865, 0.021043625, 0.024047593, 0.027050657, 0.030052701, 0.033053614, 0.03605328, 0.039051592, 0.042048436, 0.045043692, 0.048037253, 0.051029008, 0.05401884, 0.05700664, 0.05999229, 0.06297568, 0.065956704, 0.068935245, 0.071911186, 0.07488442, 0.07785484, 0.080822326, 0.08378677, 0.086748056, 0.08970608, 0.092660725, 0.095611885, 0.09855944, 0.10150328, 0.10444331, 0.1073794, 0.11031145, 0.11323935, 0.11616298, 0.11908224, 0.12199701, 0.124907196, 0.12781268, 0.13071334, 0.1336091, 0.1364998, 0.13938539, 0.14226572, 0.1451407, 0.1480102, 0.15087414, 0.15373239, 0.15658486, 0.15943143, 0.162272, 0.16510646, 0.16793472, 0.17075664, 0.17357212, 0.17638108, 0.1791834, 0.18197897, 0.1847677, 0.18754946, 0.19032416, 0.19309169, 0.19585197, 0.19860485, 0.20135027, 0.2040881, 0.20681825, 0.20954062, 0.2122551, 0.21496159, 0.21765998, 0.22035018, 0.22303207, 0.22570558, 0.22837058, 0.23102699, 0.2336747, 0.23631361, 0.23894362, 0.24156465, 0.24417657, 0.24677931, 0.24937274, 0.2519568, 0.25453135, 0.25709635, 0.25965163, 0.26219717, 0.26473284, 0.26725852, 0.26977414, 0.27227962, 0.27477482, 0.2772597, 0.27973413, 0.28219804, 0.2846513, 0.28709388, 0.28952563, 0.29194647, 0.29435635, 0.2967551, 0.29914272, 0.30151907, 0.30388406, 0.3062376, 0.30857962, 0.31091002, 0.31322873, 0.31553563, 0.31783065, 0.32011372, 0.32238472, 0.32464358, 0.32689023, 0.32912457, 0.3313465, 0.33355597, 0.3357529, 0.33793718, 0.34010872, 0.34226745, 0.3444133, ], ];
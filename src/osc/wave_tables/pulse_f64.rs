@@ -0,0 +1,2 @@
+#[allow(clippy::approx_constant)]
+pub static PULSE_F64: [[f64; 1024]; 10] = [[0.27183833134374263, 0.9142986736143313, 0.7632898936655629, 0.852902774328565, 0.7889341181300477, 0.8387683104672619, 0.7978966242784632, 0.8325759165360885, 0.8024318048681855, 0.829111140434319, 0.8051650521751584, 0.8268999954156275, 0.8069905153595918, 0.8253675286746505, 0.8082951659855202, 0.8242435185578988, 0.809273522796144, 0.8233843181819552, 0.8100340017476376, 0.8227065475745244, 0.8106417899704748, 0.822158500539279, 0.8111384305081091, 0.821706414380148, 0.8115516474588055, 0.8213273101393022, 0.8119006489964354, 0.8210050068810292, 0.812199162966529, 0.8207277816907486, 0.8124572606601501, 0.8204869338081432, 0.812682497847822, 0.820275870056699, 0.8128806536990429, 0.8200895034001737, 0.8130562237197541, 0.8199238463202713, 0.8132127572067684, 0.8197757291714667, 0.8133530935708043, 0.8196426008879, 0.813479531201015, 0.8195223852584099, 0.813593950313539, 0.8194133754984031, 0.813697903776104, 0.8193141557209821, 0.8137926852411969, 0.8192235416279517, 0.8138793809364792, 0.8191405351486181, 0.8139589095093048, 0.8190642893445982, 0.814032053020309, 0.818994080969181, 0.8140994812974233, 0.8189292888023203, 0.8141617712519595, 0.8188693763916379, 0.814219422331611, 0.818813878188817, 0.8142728689821191, 0.8187623883272871, 0.814322490771561, 0.8187145514727158, 0.81436862067276, 0.8186700553134629, 0.8144115518827147, 0.8186286243584852, 0.8144515434714366, 0.818590014785159, 0.8144888250875574, 0.8185540101358738, 0.8145236008989625, 0.8185204177051117, 0.8145560529091653, 0.8184890654917604, 0.8145863437612524, 0.8184597996166256, 0.814614619118889, 0.81843248212498, 0.8146410096963367, 0.8184069891094085, 0.8146656329958304, 0.818383209100441, 0.8146885947996632, 0.8183610416820687, 0.8147099904557688, 0.8183403962970921, 0.8147299059886953, 0.8183211912132966, 0.8147484190622305, 0.818303352626583, 0.8147655998154878, 0.8182868138811416, 0.8147815115905872, 0.8182715147901228, 0.814796211567101, 0.8182574010429485, 0.8148097513158791, 0.8182444236876372, 0.814822177283016, 0.8182325386783568, 0.8148335312128471, 0.8182217064799445, 0.8148438505176172, 0.8182118917224833, 0.8148531686001874, 0.8182030628999467, 0.8148615151352262, 0.8181951921080223, 0.8148689163134502, 0.8181882548168344, 0.8148753950528246, 0.8181822296750291, 0.8148809711799428, 0.8181770983422286, 0.8148856615843664, 0.8181728453473247, 0.8148894803481919, 0.818169457970579, 0.8148924388527308, 0.8181669261478028, 0.8148945458638207, 0.818165242395232, 0.8148958075969931, 0.8181644017541024, 0.8148962277633738, 0.8181644017541024, 0.8148958075969931, 0.818165242395232, 0.8148945458638207, 0.8181669261478028, 0.8148924388527308, 0.818169457970579, 0.8148894803481919, 0.8181728453473247, 0.8148856615843664, 0.8181770983422286, 0.8148809711799428, 0.8181822296750291, 0.8148753950528246, 0.8181882548168344, 0.8148689163134502, 0.8181951921080223, 0.8148615151352262, 0.8182030628999467, 0.8148531686001874, 0.8182118917224833, 0.8148438505176172, 0.8182217064799445, 0.8148335312128471, 0.8182325386783568, 0.814822177283016, 0.8182444236876372, 0.8148097513158791, 0.8182574010429485, 0.814796211567101, 0.8182715147901228, 0.8147815115905872, 0.8182868138811416, 0.8147655998154878, 0.8183033526265835, 0.8147484190622316, 0.8183211912132956, 0.8147299059886953, 0.8183403962970915, 0.8147099904557688, 0.8183610416820687, 0.8146885947996632, 0.818383209100441, 0.8146656329958304, 0.8184069891094085, 0.8146410096963366, 0.8184324821249803, 0.8146146191188874, 0.8184597996166262, 0.8145863437612512, 0.8184890654917603, 0.8145560529091653, 0.8185204177051117, 0.8145236008989625, 0.8185540101358737, 0.8144888250875574, 0.8185900147851575, 0.8144515434714382, 0.8186286243584834, 0.8144115518827156, 0.8186700553134635, 0.8143686206727593, 0.8187145514727158, 0.814322490771561, 0.8187623883272871, 0.8142728689821184, 0.8188138781888171, 0.8142194223316114, 0.8188693763916396, 0.81416177125196, 0.8189292888023195, 0.8140994812974236, 0.8189940809691812, 0.814032053020309, 0.8190642893445982, 0.8139589095093048, 0.819140535148618, 0.8138793809364789, 0.8192235416279519, 0.8137926852411954, 0.819314155720983, 0.8136979037761037, 0.8194133754984034, 0.813593950313539, 0.8195223852584099, 0.813479531201015, 0.8196426008879011, 0.8133530935708051, 0.8197757291714682, 0.8132127572067687, 0.8199238463202713, 0.8130562237197533, 0.8200895034001734, 0.8128806536990424, 0.8202758700566998, 0.812682497847822, 0.8204869338081432, 0.8124572606601489, 0.8207277816907494, 0.8121991629665282, 0.8210050068810286, 0.8119006489964342, 0.8213273101393033, 0.8115516474588061, 0.821706414380148, 0.8111384305081091, 0.822158500539279, 0.8106417899704748, 0.8227065475745247, 0.8100340017476371, 0.8233843181819548, 0.8092735227961441, 0.8242435185578988, 0.8082951659855199, 0.8253675286746492, 0.8069905153595913, 0.8268999954156275, 0.8051650521751582, 0.8291111404343194, 0.802431804868185, 0.8325759165360896, 0.7978966242784629, 0.8387683104672635, 0.7889341181300472, 0.8529027743285658, 0.7632898936655629, 0.9142986736143304, 0.27183833134374263, -0.3692683072302487, -0.2196132819463575, -0.30787230599832816, -0.24525765935406701, -0.293737638167924, -0.2542204205358862, -0.28754493809097753, -0.25875595844147764, -0.2840797534358244, -0.2614896656164847, -0.2818680971475579, -0.26331569156872286, -0.28033501603402106, -0.26462100828838026, -0.27921028797600855, -0.2656001350241554, -0.27835026554459646, -0.26636148831799406, -0.2776715681411709, -0.26697025596752755, -0.2771224888611164, -0.26746798176596737, -0.27666926421675775, -0.2678823906453866, -0.2762889143738697, -0.2682326916990657, -0.27596525743387246, -0.26853261377930887, -0.27568656943103137, -0.268792229273246, -0.2754441484635288, -0.26901909513886035, -0.2752314001208442, -0.26921899182682113, -0.2750432360377769, -0.2693964162204503, -0.27487566726953894, -0.26955491909289164, -0.27472552264372596, -0.2696973414330462, -0.27459024946417665, -0.2698259833133018, -0.27446776978270904, -0.2699427267414919, -0.27435637496745174, -0.2700491264891703, -0.27425464717014914, -0.2701464782287214, -0.27416140001310013, -0.27023587032801916, -0.27407563322356626, -0.2703182236994679, -0.27399649753389305, -0.27039432279846287, -0.2739232672358211, -0.2704648399825998, -0.27385531851012107, -0.27053035483334426, -0.273792112161825, -0.27059136961495694, -0.27373317975045003, -0.2706483217425767, -0.2736781123611073, -0.2707015939132967, -0.2736265514479347, -0.270751522395868, -0.27357818131700495, -0.27079840385794174, -0.2735327229161775, -0.2708425010232325, -0.2734899286742929, -0.2708840473860586, -0.2734495781885075, -0.270923251161535, -0.27341147460152515, -0.2709602986121623, -0.2733754415433011, -0.27099535686273407, -0.2733413205372304, -0.2710285762930623, -0.27330896879055155, -0.2710600925805879, -0.2732782573041633, -0.2710900284512347, -0.2732490692492859, -0.27111849518596026, -0.27322129856800165, -0.27114559392188253, -0.2731948487624862, -0.27117141677993756, -0.2731696318438925, -0.2711960478454381, -0.2731455674168802, -0.2712195640234579, -0.27312258187976796, -0.27124203578728945, -0.2731006077236524, -0.27126352783523683, -0.2730795829164998, -0.2712840996685959, -0.27305945036040585, -0.27130380610161814, -0.27304015741210996, -0.2713226977126328, -0.2730216554583136, -0.2713408212440694, -0.2730038995386431, -0.27135821995805043, -0.2729868480101213, -0.2713749339531482, -0.2729704622479312, -0.2713910004472188, -0.2729547063779702, -0.271406454030449, -0.27293954703731077, -0.27142132689219695, -0.2729249531592545, -0.27143564902478234, -0.272910895780055, -0.2714494484068652, -0.27289734786483405, -0.27146275116878693, -0.27288428415048005, -0.27147558174190434, -0.27287168100364234, -0.2714879629936861, -0.2728595162921505, -0.2714999163501358, -0.27284776926840487, -0.27151146190691267, -0.2728364204634523, -0.27152261853033427, -0.2728254515906337, -0.2715334039493351, -0.2728148454578, -0.2715438348392923, -0.27280458588722756, -0.2715539268985619, -0.2727946576424327, -0.27156369491845583, -0.272785046361277, -0.27157315284728073, -0.2727757384946213, -0.2715823138490479, -0.27276672125011425, -0.271591190357353, -0.2727579825405573, -0.27159979412485846, -0.27274951093643396, -0.2716081362688526, -0.2727412956222178, -0.27161622731316165, -0.2727333263561132, -0.27162407722682397, -0.27272559343292263, -0.2716316954597695, -0.272718087649752, -0.27163909097578415, -0.27271080027430045, -0.27164627228300436, -0.2727037230155423, -0.271653247462141, -0.2726968479965453, -0.27166002419263724, -0.2726901677292915, -0.27166660977693474, -0.27268367509129254, -0.2716730111629872, -0.27267736330388376, -0.2716792349652124, -0.2726712259120422, -0.2716852874839367, -0.2726652567656051, -0.27169117472353144, -0.2726594500017785, -0.2716969024092872, -0.27265380002883566, -0.2717024760031567, -0.27264830151092684, -0.27170790071844186, -0.2726429493538733, -0.27171318153351165, -0.2726377386919129, -0.27171832320461353, -0.272632664875311, -0.27172333027787254, -0.2726277234587571, -0.27172820710050116, -0.2726229101905042, -0.2717329578313106, -0.27261822100220917, -0.2717375864505733, -0.27261365199938303, -0.2717420967692268, -0.2726091994524513, -0.27174649243757887, -0.27260485978834914, -0.27175077695342625, -0.27260062958263487, -0.27175495366971064, -0.2725965055520668, -0.27175902580171324, -0.272592484547634, -0.2717629964338174, -0.2725885635479889, -0.27176686852588133, -0.2725847396532593, -0.27177064491924047, -0.27258101007923774, -0.27177432834235876, -0.2725773721518701, -0.2717779214161652, -0.27257382330209234, -0.27178142665908644, -0.2725703610609312, -0.2717848464917904, -0.27256698305489846, -0.27178818324167886, -0.27256368700162276, -0.27179143914712534, -0.2725604707057285, -0.27179461636147884, -0.27255733205494187, -0.2717977169568636, -0.2725542690163941, -0.2718007429277575, -0.2725512796331444, -0.2718036961943965, -0.2725483620208623, -0.271806578605986, -0.27254551436470004, -0.27180939194376036, -0.27254273491632464, -0.2718121379238576, -0.27254002199109606, -0.27181481820007625, -0.27253737396541083, -0.2718174343664703, -0.2725347892741514, -0.2718199879598162, -0.2725322664082901, -0.2718224804619652, -0.2725298039125994, -0.2718249133020651, -0.27252740038348133, -0.27182728785867827, -0.27252505446690495, -0.27182960546179674, -0.2725227648564415, -0.27183186739475235, -0.2725205302913964, -0.27183407489604255, -0.27251834955503706, -0.2718362291610595, -0.27251622147290766, -0.27183833134374397, -0.272514144911181, -0.2718403825581469, -0.27251211877520026, -0.27184238387993936, -0.27251014200794144, -0.27184433634782507, -0.27250821358866967, -0.2718462409649117, -0.27250633253158885, -0.2718480986999955, -0.2725044978845886, -0.27184991048880425, -0.2725027087280288, -0.2718516772351749, -0.2725009641735998, -0.271853399812172, -0.2724992633632065, -0.27185507906317274, -0.2724976054679414, -0.2718567158028714, -0.2724959896870678, -0.27185831081828377, -0.27249441524706863, -0.27185986486965935, -0.27249288140073263, -0.2718613786913842, -0.27249138742628976, -0.27186285299282265, -0.27248993262657556, -0.2718642884591434, -0.27248851632821985, -0.2718656857520986, -0.272487137880914, -0.27186704551074187, -0.2724857966566664, -0.27186836835217354, -0.27248449204911, -0.271869654872197, -0.27248322347284276, -0.27187090564596955, -0.27248199036279214, -0.2718721212286268, -0.2724807921736076, -0.27187330215588185, -0.2724796283790826, -0.2718744489445766, -0.27247849847159517, -0.27187556209323616, -0.2724774019615904, -0.2718766420825795, -0.2724763383770615, -0.27187768937601964, -0.27247530726307173, -0.271878704420138, -0.2724743081812943, -0.27187968764511, -0.2724733407095646, -0.2718806394651897, -0.27247240444146265, -0.2718815602790754, -0.2724714989859138, -0.27188245047031334, -0.27247062396679345, -0.2718833104076917, -0.27246977902257363, -0.2718841404455785, -0.27246896380595353, -0.2718849409242736, -0.2724681779835492, -0.27188571217033347, -0.2724674212355548, -0.27188645449688353, -0.2724666932554506, -0.27188716820391695, -0.27246599374971364, -0.2718878535785616, -0.2724653224375333, -0.2718885108953625, -0.2724646790505593, -0.2718891404165389, -0.2724640633326511, -0.27188974239221725, -0.272463475039642, -0.27189031706065986, -0.2724629139391122, -0.27189086464849105, -0.2724623798101836, -0.2718913853708993, -0.2724618724433141, -0.27189187943182613, -0.2724613916401123, -0.27189234702415693, -0.272460937213157, -0.2718927883298883, -0.27246050898583013, -0.2718932035202958, -0.2724601067921682, -0.2718935927560815, -0.2724597304766947, -0.27189395618751444, -0.2724593798943079, -0.27189429395457376, -0.27245905491013217, -0.2718946061870537, -0.2724587553994127, -0.2718948930046959, -0.27245848124740546, -0.27189515451727936, -0.27245823234926425, -0.2718953908247308, -0.2724580086099746, -0.27189560201718055, -0.27245780994424357, -0.27189578817509524, -0.2724576362764393, -0.27189594936929296, -0.27245748754054233, -0.27189608566102175, -0.2724573636800439, -0.2718961971020368, -0.2724572646479443, -0.2718962837346091, -0.27245719040668825, -0.2718963455915823, -0.2724571409281381, -0.2718963826963953, -0.27245711619354773, -0.2718963950631055, -0.272457116193548, -0.27189638269639804, -0.2724571409281364, -0.2718963455915828, -0.27245719040668853, -0.27189628373460883, -0.2724572646479441, -0.2718961971020366, -0.27245736368004225, -0.271896085661022, -0.2724574875405419, -0.2718959493692902, -0.2724576362764439, -0.271895788175095, -0.27245780994424257, -0.27189560201718344, -0.2724580086099755, -0.27189539082472414, -0.27245823234926697, -0.2718951545172792, -0.27245848124740407, -0.27189489300469505, -0.2724587553994135, -0.2718946061870546, -0.27245905491013256, -0.27189429395457393, -0.2724593798943064, -0.2718939561875135, -0.2724597304766956, -0.27189359275608066, -0.27246010679216814, -0.27189320352029567, -0.27246050898583263, -0.2718927883298873, -0.2724609372131576, -0.27189234702415593, -0.2724613916401116, -0.2718918794318254, -0.2724618724433134, -0.27189138537089913, -0.2724623798101827, -0.27189086464849144, -0.2724629139391131, -0.27189031706065986, -0.272463475039643, -0.27188974239221564, -0.2724640633326507, -0.27188914041653883, -0.2724646790505597, -0.27188851089536176, -0.272465322437532, -0.2718878535785588, -0.272465993749713, -0.27188716820391523, -0.2724666932554522, -0.27188645449688575, -0.27246742123555295, -0.27188571217033347, -0.2724681779835462, -0.27188494092427157, -0.2724689638059528, -0.27188414044558123, -0.27246977902257324, -0.27188331040769137, -0.2724706239667953, -0.27188245047031484, -0.2724714989859169, -0.27188156027907545, -0.272472404441464, -0.27188063946519164, -0.27247334070956186, -0.2718796876451108, -0.2724743081812945, -0.2718787044201329, -0.2724753072630717, -0.2718776893760202, -0.2724763383770577, -0.27187664208258105, -0.2724774019615892, -0.27187556209323777, -0.2724784984715948, -0.271874448944575, -0.2724796283790806, -0.27187330215588146, -0.27248079217360477, -0.27187212122862586, -0.2724819903627918, -0.27187090564597066, -0.2724832234728414, -0.27186965487219883, -0.27248449204910996, -0.27186836835217576, -0.2724857966566679, -0.27186704551074414, -0.27248713788091455, -0.2718656857520984, -0.2724885163282192, -0.27186428845914606, -0.2724899326265757, -0.2718628529928225, -0.2724913874262903, -0.27186137869138244, -0.2724928814007364, -0.2718598648696602, -0.27249441524706797, -0.2718583108182821, -0.2724959896870691, -0.2718567158028707, -0.27249760546794205, -0.2718550790631695, -0.27249926336320657, -0.2718533998121741, -0.2725009641735974, -0.27185167723517517, -0.2725027087280318, -0.27184991048880575, -0.2725044978845885, -0.2718480986999953, -0.2725063325315887, -0.2718462409649151, -0.27250821358866945, -0.2718443363478242, -0.27251014200794293, -0.271842383879939, -0.27251211877520193, -0.2718403825581457, -0.2725141449111773, -0.2718383313437436, -0.2725162214728999, -0.2718362291610593, -0.2725183495550374, -0.2718340748960428, -0.2725205302913954, -0.2718318673947518, -0.2725227648564403, -0.2718296054617945, -0.2725250544669055, -0.2718272878586769, -0.272527400383482, -0.2718249133020653, -0.2725298039126007, -0.27182248046196666, -0.2725322664082874, -0.27181998795981577, -0.27253478927415087, -0.2718174343664713, -0.272537373965411, -0.2718148182000757, -0.27254002199109834, -0.2718121379238578, -0.27254273491632314, -0.2718093919437605, -0.2725455143646996, -0.2718065786059859, -0.27254836202086274, -0.27180369619439265, -0.2725512796331431, -0.27180074292776024, -0.27255426901639374, -0.27179771695686233, -0.2725573320549401, -0.27179461636148045, -0.2725604707057282, -0.27179143914712844, -0.2725636870016201, -0.271788183241679, -0.27256698305489907, -0.27178484649178697, -0.27257036106093097, -0.27178142665908633, -0.2725738233020904, -0.2717779214161656, -0.27257737215186667, -0.27177432834235876, -0.27258101007923996, -0.2717706449192409, -0.272584739653261, -0.27176686852588083, -0.27258856354798555, -0.27176299643381857, -0.2725924845476329, -0.2717590258017056, -0.2725965055520663, -0.2717549536697128, -0.27260062958263565, -0.27175077695342775, -0.2726048597883515, -0.27174649243757804, -0.2726091994524533, -0.271742096769227, -0.2726136519993863, -0.2717375864505733, -0.2726182210022093, -0.27173295783131196, -0.2726229101905043, -0.2717282071005006, -0.2726277234587566, -0.2717233302778721, -0.27263266487531185, -0.27171832320461353, -0.272637738691916, -0.2717131815335091, -0.27264294935387223, -0.27170790071844214, -0.2726483015109258, -0.2717024760031574, -0.2726538000288325, -0.27169690240928623, -0.2726594500017786, -0.27169117472353144, -0.2726652567656062, -0.2716852874839363, -0.2726712259120428, -0.2716792349652107, -0.2726773633038848, -0.2716730111629865, -0.27268367509129154, -0.27166660977693236, -0.27269016772929067, -0.2716600241926355, -0.27269684799654575, -0.2716532474621423, -0.2727037230155434, -0.2716462722830038, -0.27271080027429956, -0.2716390909757829, -0.27271808764975347, -0.27163169545977184, -0.27272559343292613, -0.2716240772268226, -0.2727333263561121, -0.2716162273131607, -0.27274129562221616, -0.271608136268854, -0.27274951093643407, -0.2715997941248625, -0.2727579825405596, -0.2715911903573468, -0.27276672125011353, -0.2715823138490487, -0.27277573849462106, -0.27157315284727956, -0.2727850463612781, -0.27156369491845467, -0.2727946576424317, -0.27155392689856095, -0.2728045858872231, -0.27154383483929084, -0.2728148454578006, -0.2715334039493362, -0.27282545159063337, -0.2715226185303316, -0.2728364204634527, -0.27151146190691294, -0.2728477692684029, -0.2714999163501348, -0.27285951629215155, -0.27148796299368555, -0.27287168100364484, -0.27147558174190395, -0.27288428415048116, -0.27146275116878504, -0.2728973478648367, -0.27144944840686475, -0.2729108957800572, -0.27143564902478007, -0.27292495315925197, -0.2714213268921971, -0.2729395470373104, -0.2714064540304476, -0.2729547063779717, -0.27139100044722014, -0.2729704622479318, -0.27137493395314827, -0.27298684801012213, -0.2713582199580497, -0.27300389953864407, -0.27134082124407144, -0.273021655458314, -0.2713226977126306, -0.2730401574121059, -0.2713038061016163, -0.2730594503604069, -0.2712840996685961, -0.27307958291649803, -0.2712635278352359, -0.2731006077236538, -0.2712420357872905, -0.27312258187975835, -0.271219564023451, -0.27314556741687884, -0.27119604784543816, -0.27316963184390214, -0.2711714167799367, -0.27319484876248956, -0.2711455939218821, -0.2732212985680043, -0.271118495185959, -0.27324906924928416, -0.27109002845123426, -0.2732782573041623, -0.27106009258058916, -0.27330896879055105, -0.2710285762930603, -0.2733413205372277, -0.27099535686273446, -0.27337544154330284, -0.2709602986121639, -0.2734114746015251, -0.27092325116153665, -0.2734495781885064, -0.2708840473860605, -0.2734899286742932, -0.2708425010232336, -0.2735327229161796, -0.270798403857943, -0.27357818131700334, -0.27075152239586947, -0.2736265514479343, -0.27070159391329673, -0.2736781123611084, -0.2706483217425766, -0.2737331797504519, -0.2705913696149579, -0.27379211216182464, -0.27053035483334115, -0.2738553185101217, -0.27046483998260246, -0.2739232672358204, -0.2703943227984636, -0.2739964975338909, -0.27031822369946645, -0.2740756332235688, -0.2702358703280171, -0.2741614000131022, -0.27014647822871934, -0.27425464717014814, -0.2700491264891713, -0.274356374967452, -0.26994272674149206, -0.2744677697827109, -0.2698259833133043, -0.2745902494641756, -0.2696973414330447, -0.2747255226437274, -0.26955491909289037, -0.2748756672695391, -0.2693964162204495, -0.2750432360377754, -0.2692189918268208, -0.27523140012084174, -0.2690190951388611, -0.2754441484635297, -0.2687922292732451, -0.27568656943102926, -0.2685326137793093, -0.27596525743387396, -0.2682326916990653, -0.2762889143738682, -0.2678823906453864, -0.27666926421675775, -0.2674679817659691, -0.2771224888611151, -0.26697025596752744, -0.27767156814116833, -0.2663614883179961, -0.2783502655445952, -0.2656001350241541, -0.2792102879760104, -0.26462100828837964, -0.28033501603402, -0.2633156915687217, -0.2818680971475583, -0.2614896656164854, -0.2840797534358232, -0.2587559584414746, -0.28754493809097853, -0.25422042053588695, -0.29373763816792264, -0.2452576593540655, -0.30787230599832616, -0.21961328194636207, -0.3692683072302486, ], [0.27149991635013454, 0.7472114029316308, 0.9146423247141549, 0.8295499777627363, 0.762940914906757, 0.8113463674294553, 0.8532571747400242, 0.8192701250149136, 0.7885741995507682, 0.8148463456400088, 0.8391338463349534, 0.8176666662168597, 0.7975253693089075, 0.8157130640219659, 0.8329529952049359, 0.8171454937079948, 0.8020487950236519, 0.8160507673596638, 0.8295001919089432, 0.8169142047761884, 0.8047698455347093, 0.8162160994333233, 0.8273014739467828, 0.8167919251655229, 0.8065826449111734, 0.8163090851982807, 0.8257819144860852, 0.8167195629259878, 0.8078741378233838, 0.8163665082270084, 0.8246713197286292, 0.8166732264397929, 0.8088388141546533, 0.8164044444887717, 0.8238260727008541, 0.8166417709409113, 0.8095850588535256, 0.8164308203219053, 0.8231628255862987, 0.8166194326679692, 0.8101780256932363, 0.8164499091468319, 0.8226299068034324, 0.8166029883338377, 0.8106592217855834, 0.816464179607253, 0.8221935909673634, 0.8165905211869051, 0.8110563324718677, 0.8164751384377363, 0.8218309393930874, 0.816580833465517, 0.8113885240631284, 0.8164837475486467, 0.8215258146762382, 0.8165731454223744, 0.8116694791218376, 0.8164906444693165, 0.8212665410251889, 0.8165669316630876, 0.8119092198827286, 0.8164962653696093, 0.8210444687685627, 0.8165618276133497, 0.8121152488893767, 0.8165009169236876, 0.8208530602067483, 0.8165575737645852, 0.8122932874654282, 0.8165048199583628, 0.8206872886448953, 0.8165539812146151, 0.8124477681451207, 0.816508136872254, 0.8205432323102603, 0.8165509096894138, 0.8125821715529128, 0.8165109893747577, 0.8204177933196418, 0.8165482531324783, 0.8126992620730732, 0.816513470264773, 0.820308499083727, 0.816545930021289, 0.8128012559693274, 0.8165156514357006, 0.8202133593782781, 0.8165438767151353, 0.8128899433818066, 0.8165175894311995, 0.8201307618276062, 0.8165420427926896, 0.812966778175078, 0.8165193293760663, 0.8200593944226744, 0.8165403877229759, 0.8130329449479067, 0.8165209078079737, 0.8199981874185162, 0.816538878446274, 0.8130894095270544, 0.8165223547528537, 0.8199462693678642, 0.8165374875859861, 0.8131369573099939, 0.8165236952721934, 0.8199029336443715, 0.8165361921038461, 0.8131762225127395, 0.8165249506371729, 0.8198676128869367, 0.816534972269863, 0.8132077104866214, 0.8165261392369177, 0.8198398595387398, 0.8165338108570676, 0.8132318146473634, 0.8165272772966875, 0.8198193311761719, 0.816532692496822, 0.8132488291192632, 0.816528379460771, 0.8198057796972317, 0.8165316031475691, 0.8132589578767322, 0.8165294592808852, 0.8197990437153317, 0.8165305296414938, 0.813262320925284, 0.8165305296414938, 0.8197990437153317, 0.8165294592808852, 0.8132589578767322, 0.8165316031475691, 0.8198057796972317, 0.816528379460771, 0.8132488291192632, 0.816532692496822, 0.8198193311761719, 0.8165272772966875, 0.8132318146473634, 0.8165338108570676, 0.8198398595387398, 0.8165261392369177, 0.8132077104866214, 0.816534972269863, 0.8198676128869367, 0.8165249506371729, 0.8131762225127395, 0.8165361921038461, 0.8199029336443715, 0.8165236952721934, 0.8131369573099939, 0.8165374875859861, 0.8199462693678642, 0.8165223547528537, 0.8130894095270544, 0.816538878446274, 0.8199981874185162, 0.8165209078079737, 0.8130329449479067, 0.8165403877229759, 0.8200593944226744, 0.816519329376067, 0.8129667781750782, 0.8165420427926896, 0.8201307618276062, 0.8165175894311982, 0.8128899433818066, 0.8165438767151353, 0.8202133593782781, 0.8165156514357006, 0.8128012559693274, 0.816545930021289, 0.8203084990837267, 0.8165134702647739, 0.8126992620730722, 0.8165482531324791, 0.8204177933196414, 0.8165109893747576, 0.8125821715529128, 0.8165509096894138, 0.8205432323102603, 0.8165081368722542, 0.8124477681451201, 0.8165539812146143, 0.8206872886448966, 0.8165048199583618, 0.8122932874654286, 0.8165575737645846, 0.8208530602067481, 0.8165009169236876, 0.8121152488893767, 0.8165618276133497, 0.8210444687685626, 0.8164962653696103, 0.8119092198827292, 0.8165669316630872, 0.8212665410251889, 0.8164906444693169, 0.8116694791218373, 0.8165731454223742, 0.8215258146762382, 0.8164837475486467, 0.8113885240631284, 0.8165808334655164, 0.8218309393930873, 0.8164751384377374, 0.8110563324718676, 0.8165905211869053, 0.8221935909673634, 0.8164641796072528, 0.8106592217855834, 0.8166029883338377, 0.8226299068034324, 0.8164499091468321, 0.8101780256932372, 0.8166194326679702, 0.8231628255862987, 0.8164308203219048, 0.8095850588535254, 0.8166417709409116, 0.8238260727008533, 0.8164044444887719, 0.8088388141546533, 0.8166732264397933, 0.8246713197286288, 0.8163665082270091, 0.8078741378233822, 0.8167195629259872, 0.8257819144860838, 0.8163090851982813, 0.8065826449111735, 0.8167919251655229, 0.8273014739467828, 0.8162160994333233, 0.8047698455347092, 0.8169142047761885, 0.8295001919089424, 0.8160507673596634, 0.8020487950236515, 0.8171454937079956, 0.8329529952049359, 0.8157130640219654, 0.7975253693089083, 0.8176666662168597, 0.8391338463349529, 0.8148463456400092, 0.7885741995507685, 0.8192701250149126, 0.8532571747400242, 0.8113463674294558, 0.7629409149067575, 0.8295499777627373, 0.9146423247141549, 0.7472114029316295, 0.27149991635013454, -0.2028578623220399, -0.36893503915360043, -0.2851965669557416, -0.21994149000616012, -0.26699269691949595, -0.3075490732729301, -0.2749168443040174, -0.2455759992785291, -0.2704925447399055, -0.2934241105938396, -0.27331351628901235, -0.25452921419078567, -0.2713591318463276, -0.287240801882301, -0.2727924756486587, -0.2590555117774954, -0.27169670262094153, -0.2837847102416613, -0.27256132007739653, -0.2617802696113647, -0.27186190043221997, -0.2815818631450954, -0.2724391757370233, -0.2635976231005207, -0.2719547498110035, -0.2800573210874971, -0.27236695110963277, -0.264894530945653, -0.27201203388918216, -0.2789408748560382, -0.27232075502739583, -0.2658654998583845, -0.2720498281757675, -0.2780888892028088, -0.27228944319578857, -0.2666189345430261, -0.2720760585253237, -0.2774179950356194, -0.2722672523505302, -0.26722001161032877, -0.27209499784677277, -0.27687649632835504, -0.27225095973155133, -0.2677102642726615, -0.2721091142399694, -0.27643063988683225, -0.2722386491491518, -0.2681174074460736, -0.2721199138581693, -0.2760574556248643, -0.2722291234438197, -0.2684606407346866, -0.27212835798724716, -0.2757407708829087, -0.2722216035166935, -0.2687536839934722, -0.2721350834824623, -0.27546887045893814, -0.27221556467489566, -0.2690066010717077, -0.27214052578279063, -0.27523306077060977, -0.2722106431064314, -0.26922694081908216, -0.2721449907664761, -0.2750267553114389, -0.2722065801345894, -0.2694204759798289, -0.2721486983900133, -0.2748448732314704, -0.27220318776863645, -0.2695916961070448, -0.2721518100965845, -0.2746834327370748, -0.27220032673707256, -0.26974414499604227, -0.27215444654265053, -0.27453926946586404, -0.2721978920902909, -0.269880656988203, -0.27215669936241, -0.27440983721184087, -0.27219580353242767, -0.2700035258181557, -0.27215863915619487, -0.2742930642169495, -0.2721939987870722, -0.27011462744663467, -0.27216032102689536, -0.27418724775783837, -0.27219242895579043, -0.27021551087095835, -0.2721617884881818, -0.27409097563034623, -0.2721910552136163, -0.2703074662455585, -0.2721630762696073, -0.2740030668524499, -0.272189846418899, -0.2703915766611631, -0.2721642123605395, -0.2739225263136351, -0.2721887773594116, -0.2704687579793905, -0.27216521952013345, -0.2738485096889739, -0.27218782744827735, -0.2705397898176955, -0.27216611640698934, -0.27378029600651516, -0.27218697974256256, -0.2706053398959486, -0.27216691843413965, -0.27371726598913504, -0.27218622019641503, -0.270665983346259, -0.2721676384231294, -0.2736588848012367, -0.27218553708683546, -0.27072221816082104, -0.2721682871093646, -0.2736046881897226, -0.2721849205679193, -0.2707744776495508, -0.27216887353616404, -0.273554271265188, -0.2721843623217632, -0.27082314056141654, -0.272169405364665, -0.2735072793548404, -0.27218385528280215, -0.2708685393649275, -0.27216988911950907, -0.27346340049432816, -0.27218339341843045, -0.2709109670667274, -0.27217033038507515, -0.27342235922599606, -0.2721829715531705, -0.2709506828605954, -0.2721707339633053, -0.2733839114459838, -0.2721825852267595, -0.2709879168343091, -0.27217110400150757, -0.2733478400990498, -0.27218223057890023, -0.2710228739125639, -0.27217144409644434, -0.2733139515628455, -0.2721819042550949, -0.2710557371767092, -0.2721717573796455, -0.2732820725963078, -0.27218160332925473, -0.2710866706731543, -0.2721720465876957, -0.2732520477521553, -0.27218132523978494, -0.27111582179994614, -0.27217231412044723, -0.27322373717328596, -0.2721810677364871, -0.27114332334354985, -0.27217256208948154, -0.27319701470828484, -0.2721808288362881, -0.2711692952241493, -0.27217279235863107, -0.27317176629347767, -0.2721806067861062, -0.27119384599693974, -0.2721730065780169, -0.2731478885586188, -0.272180400031614, -0.27121707414823554, -0.2721732062127659, -0.2731252876210163, -0.27218020719081804, -0.27123906921834157, -0.27217339256732925, -0.27310387803909913, -0.272180027031648, -0.27125991277754297, -0.27217356680615934, -0.2730835819013911, -0.2721798584528655, -0.27127967927712854, -0.2721737299713609, -0.27306432803096176, -0.27217970046773254, -0.27129843679365273, -0.27217388299779516, -0.2730460512886394, -0.27217955219001894, -0.2713162476817259, -0.27217402672606544, -0.2730286919610124, -0.2721794128219546, -0.2713331691481319, -0.27217416191371746, -0.2730121952215034, -0.27217928164381106, -0.27134925375807695, -0.27217428924490294, -0.2729965106545179, -0.2721791580049072, -0.27136454988272135, -0.27217440933878256, -0.27298159183428317, -0.27217904131578213, -0.2713791020957586, -0.2721745227568282, -0.2729673959512193, -0.2721789310413713, -0.2713929515256333, -0.2721746300091751, -0.27295388347971716, -0.2721788266950681, -0.2714061361690594, -0.2721747315602038, -0.27294101788210523, -0.2721787278335072, -0.27141869117068246, -0.2721748278334096, -0.2729287653443294, -0.27217863405199105, -0.27143064907303693, -0.27217491921569326, -0.2729170945394634, -0.27217854498047106, -0.2714420400403863, -0.272175006061139, -0.2729059764157422, -0.27217846027999554, -0.2714528920595466, -0.2721750886943474, -0.2728953840062131, -0.2721783796395752, -0.27146323112037807, -0.27217516741339154, -0.2728852922575172, -0.2721783027734044, -0.2714730813782704, -0.27217524249242736, -0.27287567787563316, -0.2721782294183936, -0.27148246530065984, -0.27217531418402413, -0.2728665191866587, -0.2721781593319781, -0.27149140379934034, -0.2721753827212302, -0.2728577960110057, -0.27217809229016077, -0.2714999163501355, -0.27217544831942925, -0.27284948954954874, -0.27217802808576613, -0.2715080211012579, -0.2721755111779801, -0.2728415822804361, -0.27217796652687154, -0.27151573497158044, -0.27217557148171045, -0.2728340578654807, -0.2721779074354201, -0.27152307373985196, -0.27217562940223244, -0.27282690106511714, -0.2721778506459448, -0.27153005212577846, -0.27217568509914625, -0.27282009766106846, -0.2721777960044432, -0.2715366838637983, -0.2721757387211087, -0.2728136343859438, -0.2721777433673627, -0.2715429817702671, -0.27217579040680595, -0.27280749885912453, -0.2721776926006579, -0.2715489578046927, -0.27217584028583675, -0.27280167952826007, -0.27217764357898727, -0.27155462312558665, -0.27217588847949753, -0.2727961656159385, -0.27217759618491716, -0.2715599881414381, -0.2721759351015137, -0.2727909470709618, -0.27217755030826485, -0.2715650625572761, -0.2721759802586869, -0.2727860145238577, -0.2721775058454592, -0.27156985541716333, -0.27217602405150143, -0.2727813592462489, -0.27217746269897003, -0.271574375143073, -0.2721760665746586, -0.27277697311370563, -0.27217742077679197, -0.2715786295703653, -0.2721761079175884, -0.2727728485718274, -0.27217737999196373, -0.27158262598023203, -0.27217614816490004, -0.2727689786052384, -0.27217734026212487, -0.271586371129295, -0.2721761873967982, -0.2727653567093134, -0.2721773015091217, -0.27158987127664164, -0.2721762256894806, -0.2727619768643459, -0.2721772636586335, -0.271593132208472, -0.272176263115483, -0.27275883351203556, -0.2721772266398193, -0.2715961592605297, -0.27217629974401936, -0.27275592153408756, -0.2721771903850129, -0.2715989573385317, -0.27217633564128946, -0.2727532362327605, -0.2721771548294208, -0.271601530936634, -0.27217637087074964, -0.2727507733132675, -0.2721771199108464, -0.2716038841542333, -0.2721764054933995, -0.2727485288678506, -0.27217708556943215, -0.2716060207110229, -0.2721764395680152, -0.2727464993614834, -0.2721770517474174, -0.27160794396057125, -0.27217647315139687, -0.272744681619041, -0.2721770183889112, -0.2716096569024034, -0.27217650629858126, -0.2727430728139113, -0.27217698543967817, -0.27161116219273795, -0.2721765390630581, -0.2727416704579203, -0.27217695284692855, -0.2716124621538948, -0.27217657149696434, -0.27274047239255217, -0.2721769205591233, -0.2716135587824811, -0.2721766036512806, -0.2727394767813723, -0.27217688852579097, -0.2716144537563627, -0.2721766355760191, -0.27273868210362134, -0.27217685669734903, -0.2716151484405118, -0.2721766673203885, -0.27273808714893827, -0.27217682502491236, -0.27161564389171555, -0.27217669893297575, -0.2727376910131801, -0.27217679346014034, -0.2716159408622535, -0.27217673046191865, -0.27273749309530315, -0.27217676195506574, -0.27161603980245247, -0.2721767619550659, -0.27273749309530554, -0.2721767304619174, -0.2716159408622545, -0.2721767934601406, -0.27273769101318135, -0.2721766989329758, -0.2716156438917156, -0.27217682502491186, -0.27273808714893866, -0.27217666732038837, -0.2716151484405088, -0.2721768566973483, -0.27273868210362107, -0.27217663557601895, -0.2716144537563627, -0.2721768885257924, -0.27273947678137095, -0.2721766036512829, -0.271613558782481, -0.27217692055912296, -0.2727404723925524, -0.272176571496965, -0.27161246215389584, -0.27217695284692855, -0.2727416704579197, -0.27217653906305705, -0.2716111621927367, -0.27217698543967866, -0.2727430728139105, -0.27217650629858137, -0.27160965690240346, -0.27217701838891306, -0.2727446816190424, -0.2721764731513972, -0.2716079439605705, -0.27217705174741735, -0.27274649936148365, -0.2721764395680143, -0.27160602071102263, -0.2721770855694311, -0.2727485288678507, -0.27217640549339855, -0.27160388415423276, -0.27217711991084664, -0.2727507733132654, -0.27217637087074914, -0.2716015309366341, -0.272177154829422, -0.27275323623276115, -0.2721763356412886, -0.27159895733852757, -0.2721771903850121, -0.272755921534085, -0.27217629974402113, -0.2715961592605323, -0.2721772266398182, -0.272758833512036, -0.2721762631154812, -0.2715931322084701, -0.27217726365863365, -0.2727619768643487, -0.27217622568948013, -0.271589871276642, -0.27217730150912317, -0.2727653567093138, -0.27217618739680044, -0.27158637112929407, -0.2721773402621256, -0.27276897860523985, -0.2721761481648971, -0.27158262598023153, -0.2721773799919642, -0.27277284857182615, -0.2721761079175889, -0.2715786295703646, -0.27217742077678947, -0.2727769731137068, -0.2721760665746563, -0.27157437514307214, -0.27217746269897014, -0.27278135924624763, -0.2721760240515003, -0.2715698554171632, -0.2721775058454553, -0.2727860145238569, -0.2721759802586877, -0.27156506255727647, -0.27217755030826407, -0.27279094707096224, -0.2721759351015142, -0.2715599881414414, -0.2721775961849175, -0.2727961656159403, -0.2721758884794989, -0.27155462312558615, -0.27217764357898605, -0.2728016795282608, -0.2721758402858372, -0.27154895780469335, -0.27217769260065955, -0.272807498859123, -0.2721757904068073, -0.2715429817702667, -0.2721777433673623, -0.2728136343859434, -0.272175738721109, -0.27153668386379776, -0.2721777960044441, -0.27282009766106546, -0.2721756850991463, -0.2715300521257797, -0.2721778506459432, -0.2728269010651174, -0.27217562940223405, -0.2715230737398525, -0.27217790743542, -0.27283405786547976, -0.27217557148170973, -0.27151573497158227, -0.272177966526872, -0.27284158228043554, -0.27217551117798133, -0.2715080211012579, -0.2721780280857674, -0.2728494895495473, -0.2721754483194293, -0.27149991635013504, -0.2721780922901598, -0.27285779601100596, -0.2721753827212307, -0.27149140379934045, -0.2721781593319772, -0.27286651918665894, -0.27217531418402335, -0.27148246530065806, -0.2721782294183936, -0.27287567787563294, -0.27217524249242836, -0.27147308137827003, -0.27217830277340704, -0.2728852922575172, -0.272175167413391, -0.27146323112037873, -0.27217837963957503, -0.27289538400621455, -0.27217508869434764, -0.2714528920595466, -0.2721784602799963, -0.2729059764157426, -0.2721750060611384, -0.27144204004038636, -0.2721785449804707, -0.272917094539463, -0.2721749192156926, -0.2714306490730333, -0.2721786340519906, -0.2729287653443312, -0.2721748278334095, -0.2714186911706804, -0.2721787278335064, -0.27294101788210606, -0.272174731560204, -0.27140613616906173, -0.2721788266950658, -0.27295388347971655, -0.27217463000917597, -0.27139295152563325, -0.272178931041372, -0.27296739595121994, -0.2721745227568263, -0.2713791020957605, -0.27217904131578013, -0.2729815918342832, -0.27217440933878395, -0.27136454988272, -0.27217915800490866, -0.272996510654517, -0.2721742892449022, -0.2713492537580771, -0.2721792816438097, -0.27301219522150005, -0.2721741619137177, -0.27133316914813327, -0.272179412821955, -0.27302869196101154, -0.27217402672606744, -0.2713162476817252, -0.27217955219002093, -0.27304605128863874, -0.2721738829977944, -0.27129843679365273, -0.27217970046773204, -0.27306432803096414, -0.2721737299713612, -0.2712796792771279, -0.272179858452865, -0.2730835819013906, -0.2721735668061596, -0.2712599127775438, -0.2721800270316503, -0.2731038780390962, -0.27217339256732825, -0.2712390692183414, -0.2721802071908176, -0.2731252876210175, -0.27217320621276336, -0.27121707414823487, -0.2721804000316141, -0.2731478885586194, -0.27217300657801785, -0.27119384599693863, -0.27218060678610456, -0.27317176629347617, -0.27217279235863173, -0.27116929522415045, -0.27218082883628797, -0.27319701470828506, -0.2721725620894803, -0.2711433233435484, -0.27218106773648737, -0.27322373717328574, -0.2721723141204475, -0.2711158217999454, -0.27218132523978417, -0.27325204775215484, -0.2721720465876971, -0.27108667067315617, -0.2721816033292584, -0.2732820725963079, -0.2721717573796445, -0.2710557371767088, -0.27218190425509414, -0.2733139515628473, -0.27217144409644467, -0.27102287391256785, -0.2721822305789021, -0.2733478400990496, -0.2721711040015097, -0.27098791683430923, -0.27218258522675975, -0.2733839114459826, -0.27217073396330616, -0.27095068286059437, -0.27218297155317017, -0.2734223592259947, -0.27217033038507304, -0.2709109670667277, -0.2721833934184316, -0.27346340049432916, -0.2721698891195097, -0.27086853936492444, -0.27218385528280203, -0.2735072793548398, -0.2721694053646638, -0.2708231405614163, -0.27218436232176174, -0.27355427126518744, -0.2721688735361662, -0.27077447764954987, -0.2721849205679194, -0.27360468818972034, -0.2721682871093668, -0.2707222181608209, -0.2721855370868365, -0.2736588848012372, -0.27216763842312586, -0.2706659833462574, -0.27218622019641464, -0.27371726598913404, -0.2721669184341388, -0.2706053398959491, -0.2721869797425624, -0.2737802960065164, -0.27216611640699, -0.2705397898176953, -0.27218782744827885, -0.2738485096889746, -0.2721652195201337, -0.27046875797938824, -0.27218877735940983, -0.27392252631363634, -0.2721642123605409, -0.2703915766611645, -0.2721898464188973, -0.274003066852449, -0.2721630762696073, -0.27030746624555896, -0.27219105521361253, -0.27409097563034285, -0.2721617884881806, -0.2702155108709574, -0.2721924289557937, -0.27418724775783815, -0.2721603210268947, -0.2701146274466337, -0.2721939987870744, -0.27429306421694927, -0.27215863915619354, -0.2700035258181557, -0.27219580353242656, -0.27440983721184276, -0.27215669936240977, -0.2698806569882011, -0.27219789209028866, -0.2745392694658641, -0.2721544465426519, -0.269744144996044, -0.2722003267370718, -0.2746834327370747, -0.2721518100965841, -0.26959169610704753, -0.2722031877686361, -0.2748448732314696, -0.27214869839001543, -0.2694204759798296, -0.27220658013458854, -0.27502675531143944, -0.2721449907664767, -0.26922694081908244, -0.2722106431064317, -0.2752330607706098, -0.27214052578279263, -0.26900660107170854, -0.2722155646748957, -0.2754688704589363, -0.2721350834824643, -0.268753683993473, -0.2722216035166921, -0.2757407708829089, -0.27212835798724455, -0.2684606407346865, -0.2722291234438222, -0.2760574556248632, -0.2721199138581714, -0.268117407446074, -0.27223864914915297, -0.2764306398868335, -0.27210911423996925, -0.2677102642726621, -0.27225095973155317, -0.27687649632835715, -0.2720949978467716, -0.2672200116103281, -0.27226725235052984, -0.2774179950356195, -0.27207605852532374, -0.2666189345430218, -0.2722894431957871, -0.27808888920280855, -0.2720498281757661, -0.2658654998583853, -0.27232075502739733, -0.278940874856038, -0.27201203388918127, -0.2648945309456529, -0.27236695110963427, -0.28005732108749765, -0.2719547498110036, -0.2635976231005217, -0.2724391757370233, -0.2815818631450967, -0.27186190043222086, -0.2617802696113647, -0.2725613200773938, -0.283784710241663, -0.2716967026209438, -0.25905551177749453, -0.27279247564865927, -0.28724080188230144, -0.271359131846331, -0.2545292141907848, -0.2733135162890114, -0.2934241105938401, -0.27049254473990414, -0.2455759992785271, -0.27491684430401464, -0.3075490732729306, -0.26699269691949523, -0.21994149000615854, -0.28519656695573053, -0.36893503915360243, -0.20285786232204078, ], [0.2708231405614157, 0.5342411823893489, 0.747218126594883, 0.8760583492457666, 0.9153402251143827, 0.8871251617104047, 0.8295430147917932, 0.780257131132612, 0.7622211379803459, 0.7775709245667229, 0.8113535827598384, 0.8421781610964914, 0.8539996211197066, 0.8433641722711592, 0.8192626433826712, 0.7966642295384929, 0.7878082469792701, 0.7959996824395054, 0.8148541084840997, 0.8328023118825876, 0.8399241886890247, 0.8332260779474056, 0.8176586061984608, 0.802695553502524, 0.7967097034216675, 0.8024024639758804, 0.8157214383235053, 0.8286095276502944, 0.833794972138522, 0.8288238335811613, 0.8171367867641092, 0.8057717184356098, 0.8011794618383099, 0.8056085676670169, 0.8160598266708895, 0.8262619454910766, 0.8303979885361255, 0.82638999197414, 0.8169047718785438, 0.8076175562506696, 0.8038424115952095, 0.8075146562767767, 0.8162259287734462, 0.8247764974206625, 0.8282597908943528, 0.824860753437818, 0.8167816747304276, 0.8088352317046382, 0.8055921217883673, 0.8087651952472173, 0.8163197833555712, 0.8237634101350659, 0.8268060506293027, 0.8238223404621728, 0.8167083882447738, 0.8096882887003275, 0.8068148913019529, 0.8096382113836451, 0.816378190632704, 0.8230380808620099, 0.8257672720385262, 0.823080974607551, 0.8166610024577576, 0.8103098601330175, 0.8077044542600426, 0.8102728879725464, 0.8164172468351109, 0.822502124945269, 0.8250006575913211, 0.8225341459406822, 0.8166283501841393, 0.810774166119205, 0.8083683056997072, 0.8107463391094216, 0.8164449031585235, 0.8220985622979652, 0.824423827516728, 0.8221227934773843, 0.8166046400437715, 0.8111256079148788, 0.8088705445328287, 0.811104495115634, 0.8164654637777472, 0.8217923545623133, 0.8239862617691198, 0.8218107333084524, 0.8165866144248815, 0.8113921278871371, 0.8092514184351088, 0.8113761718185466, 0.8164814357378353, 0.821561027239124, 0.8236556151184552, 0.8215748140096596, 0.8165723135056215, 0.8115919211738565, 0.8095370971930714, 0.8115800971021337, 0.8164943742073653, 0.8213897847680084, 0.8234106167013217, 0.8213998144475572, 0.8165604849081456, 0.8117370426416105, 0.809744907587972, 0.811728670877141, 0.8165053028627789, 0.8212688149873808, 0.8232371628370394, 0.8212756384183639, 0.816550278820226, 0.8118354404871175, 0.8098862781642449, 0.8118300789460177, 0.8165149389672456, 0.8211917448944003, 0.8231260824574894, 0.8211957106840111, 0.816541078803594, 0.8118921321030739, 0.8099684401525077, 0.811889514144136, 0.8165238230198443, 0.8211547474884191, 0.8230718449671636, 0.8211560487886478, 0.8165324002798472, 0.8119098754957005, 0.809995399637282, 0.8119098754957005, 0.8165324002798472, 0.8211560487886478, 0.8230718449671636, 0.8211547474884191, 0.8165238230198443, 0.811889514144136, 0.8099684401525077, 0.8118921321030739, 0.816541078803594, 0.8211957106840111, 0.8231260824574894, 0.8211917448944003, 0.8165149389672456, 0.8118300789460177, 0.8098862781642449, 0.8118354404871175, 0.816550278820226, 0.8212756384183639, 0.8232371628370394, 0.8212688149873808, 0.8165053028627789, 0.811728670877141, 0.809744907587972, 0.8117370426416105, 0.8165604849081456, 0.8213998144475572, 0.8234106167013217, 0.8213897847680084, 0.8164943742073653, 0.8115800971021337, 0.8095370971930714, 0.8115919211738565, 0.8165723135056215, 0.8215748140096603, 0.8236556151184554, 0.8215610272391239, 0.8164814357378356, 0.8113761718185456, 0.8092514184351088, 0.8113921278871371, 0.8165866144248815, 0.8218107333084524, 0.8239862617691198, 0.8217923545623133, 0.8164654637777469, 0.8111044951156334, 0.8088705445328283, 0.8111256079148794, 0.8166046400437715, 0.8221227934773843, 0.824423827516728, 0.8220985622979652, 0.8164449031585235, 0.810746339109421, 0.8083683056997067, 0.8107741661192046, 0.8166283501841394, 0.8225341459406822, 0.8250006575913211, 0.8225021249452688, 0.8164172468351105, 0.8102728879725464, 0.8077044542600426, 0.8103098601330175, 0.8166610024577576, 0.8230809746075515, 0.8257672720385258, 0.8230380808620102, 0.8163781906327038, 0.8096382113836439, 0.8068148913019527, 0.8096882887003272, 0.8167083882447738, 0.8238223404621728, 0.8268060506293027, 0.8237634101350657, 0.8163197833555713, 0.8087651952472186, 0.8055921217883673, 0.8088352317046387, 0.8167816747304276, 0.8248607534378177, 0.8282597908943528, 0.8247764974206625, 0.8162259287734462, 0.8075146562767769, 0.8038424115952101, 0.8076175562506701, 0.816904771878543, 0.82638999197414, 0.8303979885361255, 0.8262619454910762, 0.8160598266708892, 0.8056085676670168, 0.8011794618383099, 0.8057717184356099, 0.8171367867641084, 0.8288238335811613, 0.8337949721385209, 0.8286095276502942, 0.815721438323505, 0.8024024639758806, 0.7967097034216675, 0.802695553502524, 0.8176586061984608, 0.8332260779474056, 0.8399241886890247, 0.8328023118825882, 0.8148541084840991, 0.7959996824395055, 0.7878082469792701, 0.7966642295384933, 0.8192626433826717, 0.8433641722711596, 0.853999621119707, 0.8421781610964914, 0.8113535827598383, 0.777570924566723, 0.762221137980346, 0.7802571311326112, 0.8295430147917928, 0.887125161710406, 0.9153402251143832, 0.8760583492457658, 0.747218126594882, 0.534241182389348, 0.2708231405614157, 0.00819808289395145, -0.20286435890845403, -0.32978994288095675, -0.3682786744943559, -0.3408569129816914, -0.2851902859728267, -0.23782033145994103, -0.220578120965867, -0.23513380914642174, -0.26699877307241054, -0.29590497370869756, -0.3069315324356552, -0.2970914596969204, -0.27491096285487576, -0.25423454075619206, -0.24617506175172224, -0.25356935833733746, -0.270498241012189, -0.28651966215789904, -0.29284294466179184, -0.2869442259859922, -0.2733079962221612, -0.26027770736735706, -0.25509303721481286, -0.2599836551857267, -0.2713644841637847, -0.28231261860370044, -0.28669379470340217, -0.28252805505928485, -0.2727872831032102, -0.2633705924375318, -0.2595862051080505, -0.2632061397439901, -0.27170174292681926, -0.27994580323811974, -0.2832698524305615, -0.28007532715670846, -0.2725564248926538, -0.2652382373013958, -0.2622797478649514, -0.2651336796588591, -0.27186665722872433, -0.27843590450525313, -0.28109732964253314, -0.27852200379674513, -0.2724345509551994, -0.2664830863604065, -0.2640676266322678, -0.2664110149564606, -0.2719592486165139, -0.27739283113635443, -0.2796014517193599, -0.27745399489322126, -0.27236257255497826, -0.26736904240855147, -0.26533664396763507, -0.26731652556508806, -0.2720162976261095, -0.2766315775355182, -0.2785121574339199, -0.27667712538810346, -0.2723166009483658, -0.2680296885516681, -0.2662811662145382, -0.2679898382748205, -0.2720538775010477, -0.27605325748868426, -0.27768594478768843, -0.2760883910720715, -0.27228549395947027, -0.2685398023455102, -0.2670094714851994, -0.26850861668070747, -0.27207991211325006, -0.27560027240000523, -0.27703956505060406, -0.2756281211445708, -0.27226349018058726, -0.2689444705622929, -0.26758662186204496, -0.26891946706585634, -0.2720986726320428, -0.2752368245885649, -0.276521431258693, -0.27525938291529267, -0.27224736848258074, -0.2692724764951338, -0.26805404662001336, -0.26925203450855, -0.2721126256273911, -0.2749395080739045, -0.2760978893479951, -0.27495810645615026, -0.27223521411159873, -0.26954304761860426, -0.2684393660566336, -0.26952606475210916, -0.2721232759043078, -0.27469238222566994, -0.2757460596144419, -0.27470794177282515, -0.2722258311746762, -0.2697695103349714, -0.2687616933735898, -0.2697552110290611, -0.2721315835583779, -0.2744842209587342, -0.2754498520608668, -0.27449739927977085, -0.27221844169199244, -0.26996138767135364, -0.26903466927965636, -0.2699492107186439, -0.27213818439244924, -0.27430689391993307, -0.27519762732462844, -0.2743181728125759, -0.2722125219607146, -0.27012566220781065, -0.26926828489555066, -0.2701151916990366, -0.2721435129138928, -0.2741543713105882, -0.27498076162476076, -0.27416411164610355, -0.27220770904563873, -0.27026756752272363, -0.26947002202454795, -0.27025848884898446, -0.27214787417562075, -0.2740220890857457, -0.2747927329079133, -0.27403056636006456, -0.27220374504703254, -0.2703911013859803, -0.26964559139907346, -0.27038317230011744, -0.2721514874026314, -0.27390653101731205, -0.27462852003754, -0.27391395906948773, -0.2722004426628283, -0.27049936845639966, -0.26979942502264004, -0.27049239950784487, -0.27215451338981533, -0.27380494588933335, -0.27448419673262736, -0.27381149313797504, -0.2721976632317208, -0.27059481506222155, -0.2699350130952104, -0.2705886560467322, -0.2721570722194222, -0.2737151514885279, -0.27435665042194063, -0.27372095230044663, -0.2721953023442868, -0.27067939370878313, -0.27005513986427687, -0.2706739240824005, -0.27215925501768706, -0.273635395846839, -0.2742433833973396, -0.2736405586581729, -0.27219328018208355, -0.27075468066723735, -0.27016205206668803, -0.27074980263549425, -0.2721611319365031, -0.27356425714873495, -0.27414236948954546, -0.27356887037064104, -0.27219153488960285, -0.2708219615331006, -0.27025758139686645, -0.27081759498404867, -0.27216275768406517, -0.2735005703046065, -0.2740519490013905, -0.27350470668714516, -0.27219001793813097, -0.27088229447973555, -0.27034323498762247, -0.27087837321203095, -0.27216417542814714, -0.2734433722649413, -0.27397075050640723, -0.2734470921685086, -0.27218869082583563, -0.2709365576987654, -0.2704202632309762, -0.2709330265750244, -0.27216541959695234, -0.2733918607313053, -0.273897631838158, -0.273395214611362, -0.2721875226915306, -0.27098548544688544, -0.27048971128326293, -0.2709822982184261, -0.27216651791944785, -0.27334536259295117, -0.2738316350023419, -0.2733483929097606, -0.2721864885640967, -0.27102969576161834, -0.2705524586474609, -0.2710268133872918, -0.2721674929322677, -0.2733033095239367, -0.2737719513335144, -0.273306052226352, -0.2721855680612299, -0.27106971200278795, -0.2706092499242987, -0.27106710133614303, -0.27216836310679915, -0.27326521892059463, -0.273717894288251, -0.2732677046103782, -0.2721847444103718, -0.27110597976122075, -0.2706607189404354, -0.2711036125155633, -0.2721691437020578, -0.2732306788693771, -0.2736688779987037, -0.27323293372315227, -0.2721840037037728, -0.2711388802515228, -0.2707074078526111, -0.27113673217692524, -0.27216984741702016, -0.27319933618998954, -0.27362440021955287, -0.273201382695347, -0.2721833343258, -0.271168741008142, -0.27074978240008235, -0.27116679123177967, -0.27217048489462936, -0.27317088684905133, -0.2735840286601182, -0.27317274439677175, -0.27218272650836384, -0.27119584449266326, -0.27078824417484654, -0.27119407498622544, -0.27217106511482214, -0.2731450682185083, -0.2735473899497759, -0.27314675358232776, -0.27218217198268135, -0.27122043506823534, -0.2708231405614164, -0.2712188302150647, -0.2721715957037461, -0.2731216527824821, -0.2735141606702513, -0.27312318051020557, -0.2721816637041439, -0.27124272468644534, -0.2708547728396335, -0.27124127092763783, -0.27217208317906383, -0.2731004429910217, -0.27348406002393316, -0.27310182572518704, -0.2721811956331661, -0.27126289755052085, -0.2708834028274658, -0.27126158309402554, -0.27217253314610995, -0.27308126702934166, -0.27345684380768126, -0.2730825157714252, -0.27218076255927953, -0.2712811139582439, -0.27090925835424395, -0.27127992853863037, -0.2721729504559484, -0.2730639753234415, -0.27343229943641645, -0.2730650996524999, -0.27218035995883944, -0.27129751348251496, -0.27093253778982257, -0.2712964481618058, -0.272173339333697, -0.2730484376425772, -0.27341024181731277, -0.2730494458968374, -0.27217998387909015, -0.2713122176131304, -0.27095341380596105, -0.2713112646151291, -0.2721737034834917, -0.2730345406890593, -0.273390509918305, -0.27303544011713576, -0.2721796308429619, -0.27132533195694014, -0.27097203650863877, -0.2713244845291372, -0.2721740461749605, -0.2730221860889504, -0.27337296390760096, -0.2730229829759999, -0.2721792977703481, -0.2713369480733498, -0.2709885360510538, -0.271336200371672, -0.272174370315063, -0.2730112887151294, -0.2733574827664481, -0.27301198848814195, -0.27217898191244017, -0.2713471450063255, -0.2710030248145284, -0.27134649199896094, -0.27217467850822646, -0.27300177528807607, -0.2733439622972261, -0.2730023826036687, -0.27217868079652285, -0.27135599056166343, -0.2710155992268855, -0.27135542794897777, -0.27217497310714184, -0.27299358321081535, -0.27333231346473374, -0.2729941020282068, -0.27217839217911804, -0.2713635423685369, -0.2710263412739175, -0.27136306651667647, -0.2721752562561015, -0.27298665960310786, -0.27332246102088215, -0.2729870932444249, -0.27217811400579783, -0.2713698487565086, -0.27103531974840694, -0.2713694566427588, -0.27217552992836047, -0.2729809605070462, -0.27331434237312074, -0.27298131170667, -0.27217784437633613, -0.27137494947285, -0.2710425912721619, -0.2713746386412419, -0.27217579595877, -0.27297645024189127, -0.27330790666494026, -0.2729767211862036, -0.272177581514047, -0.2713788762599064, -0.27104820111917277, -0.27137864478585794, -0.2721760560726803, -0.2729731008906712, -0.2733031140435455, -0.27297329324925745, -0.2721773237384186, -0.27138165330791764, -0.27105218386194824, -0.2713814997709742, -0.27217631191196845, -0.27297089190498064, -0.2732999350953219, -0.2729710068541235, -0.27217706944024783, -0.2713832975951456, -0.2710545638578838, -0.2713832210591199, -0.27217656505891824, -0.2729698098177658, -0.273298350434489, -0.2729698480567982, -0.272176817058588, -0.27138381912399256, -0.2710553555881864, -0.27138381912399234, -0.27217681705858826, -0.27296984805679825, -0.27329835043448947, -0.2729698098177659, -0.2721765650589185, -0.27138322105911955, -0.2710545638578834, -0.27138329759514657, -0.2721770694402479, -0.27297100685412334, -0.2732999350953211, -0.27297089190498036, -0.27217631191196817, -0.27138149977097425, -0.27105218386194774, -0.2713816533079182, -0.2721773237384176, -0.272973293249259, -0.2733031140435457, -0.27297310089067095, -0.2721760560726806, -0.271378644785858, -0.2710482011191738, -0.27137887625990587, -0.2721775815140459, -0.2729767211862034, -0.2733079066649399, -0.27297645024189116, -0.27217579595877, -0.27137463864124184, -0.27104259127216196, -0.2713749494728509, -0.272177844376337, -0.2729813117066705, -0.27331434237312036, -0.27298096050704623, -0.272175529928361, -0.2713694566427582, -0.2710353197484069, -0.27136984875650794, -0.27217811400579794, -0.27298709324442477, -0.2733224610208818, -0.27298665960310825, -0.2721752562561004, -0.2713630665166762, -0.2710263412739168, -0.27136354236853644, -0.2721783921791179, -0.2729941020282065, -0.2733323134647322, -0.27299358321081524, -0.27217497310714117, -0.2713554279489787, -0.27101559922688606, -0.27135599056166315, -0.27217868079652313, -0.27300238260366766, -0.27334396229722524, -0.2730017752880761, -0.2721746785082275, -0.2713464919989604, -0.27100302481452915, -0.2713471450063257, -0.2721789819124404, -0.2730119884881431, -0.27335748276644617, -0.27301128871512925, -0.2721743703150641, -0.27133620037166994, -0.27098853605105244, -0.27133694807334985, -0.2721792977703474, -0.2730229829760009, -0.27337296390760085, -0.27302218608894835, -0.2721740461749607, -0.2713244845291366, -0.27097203650863855, -0.27132533195693953, -0.27217963084296076, -0.27303544011713465, -0.27339050991830505, -0.27303454068905586, -0.2721737034834913, -0.2713112646151296, -0.2709534138059611, -0.2713122176131303, -0.272179983879091, -0.27304944589683694, -0.27341024181731355, -0.2730484376425768, -0.2721733393336988, -0.2712964481618063, -0.27093253778982296, -0.27129751348251424, -0.2721803599588404, -0.27306509965250014, -0.2734322994364165, -0.27306397532344034, -0.27217295045594714, -0.2712799285386308, -0.27090925835424423, -0.2712811139582426, -0.2721807625592789, -0.2730825157714247, -0.2734568438076801, -0.2730812670293415, -0.27217253314610906, -0.27126158309402626, -0.27088340282746715, -0.2712628975505211, -0.27218119563316595, -0.273101825725188, -0.2734840600239337, -0.27310044299102126, -0.2721720831790643, -0.2712412709276374, -0.2708547728396353, -0.2712427246864451, -0.2721816637041436, -0.2731231805102067, -0.27351416067025247, -0.2731216527824831, -0.2721715957037462, -0.27121883021506493, -0.2708231405614161, -0.27122043506823507, -0.2721821719826812, -0.27314675358232776, -0.2735473899497771, -0.27314506821850726, -0.2721710651148225, -0.27119407498622383, -0.27078824417484476, -0.27119584449266326, -0.27218272650836367, -0.2731727443967724, -0.27358402866011744, -0.2731708868490528, -0.2721704848946291, -0.2711667912317804, -0.27074978240008224, -0.2711687410081425, -0.27218333432580083, -0.2732013826953461, -0.273624400219553, -0.2731993361899892, -0.27216984741702, -0.271136732176925, -0.2707074078526111, -0.27113888025152266, -0.27218400370377194, -0.27323293372315216, -0.2736688779987003, -0.27323067886937635, -0.27216914370205897, -0.27110361251556286, -0.27066071894043514, -0.27110597976122025, -0.27218474441037227, -0.2732677046103779, -0.27371789428825155, -0.27326521892059197, -0.27216836310679915, -0.27106710133614265, -0.27060924992429813, -0.2710697120027887, -0.2721855680612303, -0.27330605222635007, -0.2737719513335154, -0.2733033095239364, -0.27216749293226694, -0.27102681338729256, -0.27055245864745947, -0.2710296957616202, -0.272186488564096, -0.2733483929097603, -0.27383163500234314, -0.27334536259294934, -0.27216651791944524, -0.27098229821842584, -0.2704897112832641, -0.2709854854468854, -0.2721875226915309, -0.27339521461136285, -0.2738976318381585, -0.27339186073130584, -0.2721654195969518, -0.2709330265750229, -0.27042026323097584, -0.2709365576987649, -0.2721886908258375, -0.2734470921685084, -0.2739707505064066, -0.273443372264941, -0.27216417542814625, -0.2708783732120309, -0.270343234987623, -0.2708822944797353, -0.2721900179381295, -0.2735047066871447, -0.27405194900139007, -0.2735005703046066, -0.2721627576840656, -0.27081759498404695, -0.27025758139686584, -0.27082196153310123, -0.2721915348896027, -0.27356887037064326, -0.27414236948954435, -0.27356425714873445, -0.27216113193650276, -0.2707498026354949, -0.2701620520666885, -0.27075468066723685, -0.27219328018208394, -0.2736405586581727, -0.2742433833973389, -0.2736353958468397, -0.2721592550176874, -0.2706739240824008, -0.27005513986427615, -0.27067939370878213, -0.2721953023442866, -0.2737209523004467, -0.27435665042194185, -0.27371515148852965, -0.2721570722194218, -0.270588656046731, -0.26993501309521034, -0.27059481506222105, -0.2721976632317221, -0.27381149313797604, -0.2744841967326281, -0.27380494588933546, -0.2721545133898154, -0.2704923995078461, -0.2697994250226403, -0.27049936845640027, -0.27220044266282717, -0.27391395906948807, -0.2746285200375391, -0.2739065310173126, -0.27215148740263007, -0.27038317230011755, -0.2696455913990735, -0.2703911013859807, -0.272203745047033, -0.27403056636006545, -0.27479273290791095, -0.27402208908574627, -0.27214787417562203, -0.2702584888489843, -0.26947002202454784, -0.27026756752272274, -0.2722077090456392, -0.2741641116461046, -0.27498076162475976, -0.2741543713105889, -0.2721435129138913, -0.27011519169903875, -0.26926828489555094, -0.27012566220781287, -0.2722125219607147, -0.2743181728125744, -0.2751976273246268, -0.27430689391993246, -0.27213818439244924, -0.2699492107186427, -0.2690346692796566, -0.2699613876713534, -0.2722184416919931, -0.2744973992797708, -0.275449852060867, -0.27448422095873504, -0.27213158355837813, -0.2697552110290615, -0.26876169337358896, -0.2697695103349698, -0.2722258311746776, -0.27470794177282565, -0.275746059614443, -0.274692382225669, -0.272123275904307, -0.2695260647521092, -0.26843936605663365, -0.26954304761860426, -0.2722352141115955, -0.2749581064561496, -0.27609788934799445, -0.27493950807390516, -0.27211262562739097, -0.2692520345085497, -0.26805404662001314, -0.2692724764951361, -0.27224736848258096, -0.275259382915292, -0.2765214312586929, -0.2752368245885645, -0.2720986726320441, -0.26891946706585695, -0.2675866218620443, -0.2689444705622928, -0.27226349018058743, -0.2756281211445716, -0.2770395650506055, -0.27560027240000534, -0.27207991211325, -0.2685086166807061, -0.2670094714851999, -0.26853980234550884, -0.27228549395946877, -0.2760883910720722, -0.277685944787689, -0.2760532574886829, -0.2720538775010483, -0.26798983827482126, -0.26628116621453873, -0.268029688551668, -0.2723166009483648, -0.2766771253881038, -0.27851215743392105, -0.2766315775355179, -0.2720162976261096, -0.2673165255650888, -0.2653366439676371, -0.2673690424085504, -0.2723625725549784, -0.2774539948932193, -0.27960145171935946, -0.27739283113635643, -0.27195924861651477, -0.26641101495646186, -0.2640676266322658, -0.2664830863604088, -0.2724345509551999, -0.2785220037967449, -0.28109732964253253, -0.27843590450525535, -0.27186665722872533, -0.26513367965885803, -0.26227974786495056, -0.2652382373013956, -0.27255642489265347, -0.28007532715670796, -0.2832698524305597, -0.27994580323812074, -0.2717017429268185, -0.26320613974399015, -0.2595862051080507, -0.26337059243753314, -0.27278728310321027, -0.2825280550592835, -0.2866937947034023, -0.2823126186037013, -0.27136448416378534, -0.2599836551857237, -0.2550930372148121, -0.26027770736735695, -0.2733079962221613, -0.2869442259859909, -0.29284294466179245, -0.2865196621578956, -0.2704982410121904, -0.2535693583373383, -0.24617506175172113, -0.2542345407561918, -0.2749109628548744, -0.2970914596969188, -0.3069315324356544, -0.2959049737086986, -0.26699877307241304, -0.23513380914642135, -0.22057812096586502, -0.23782033145993894, -0.2851902859728225, -0.3408569129816904, -0.3682786744943538, -0.32978994288096114, -0.20286435890847027, 0.008198082893950784, ], [0.26947002202454795, 0.404604650740732, 0.5332885901918375, 0.6492237346315567, 0.7472454782282609, 0.8237710881422365, 0.8770800137702387, 0.9073979610897164, 0.9167792977534913, 0.9088055137842618, 0.88813822019266, 0.8599810183094557, 0.8295136455522418, 0.8013630954682425, 0.7791699044140924, 0.765294454850924, 0.7606897690177493, 0.7649462595688722, 0.7764929510457536, 0.7929214062194854, 0.811385200194657, 0.8290208181948012, 0.8433359812732847, 0.8525183670721342, 0.8556303537201992, 0.8526719320720053, 0.8445120170295605, 0.8327056221677802, 0.8192285134636051, 0.8061702788515299, 0.7954301920402926, 0.7884575830386535, 0.7860702523113234, 0.7883721001355047, 0.7947764288286266, 0.8041264103473591, 0.814891055214664, 0.8254054128688874, 0.8341188731570797, 0.839815823360592, 0.8417783009443, 0.8398697377350496, 0.8345309472905089, 0.826692377498891, 0.81761849048471, 0.8087092994044047, 0.8012893718239193, 0.7964153739611702, 0.7947295104164666, 0.7963786784428636, 0.8010089978266243, 0.8078341320796935, 0.8157651327215332, 0.8235809401060414, 0.8301133459474759, 0.8344188475680828, 0.8359124977488253, 0.8344450855021343, 0.8303137779142504, 0.8242063694137041, 0.8170890343398456, 0.8100555459834635, 0.8041611709877297, 0.8002661684806577, 0.7989118438801696, 0.8002467969810111, 0.8040132108889639, 0.8095939470143894, 0.816112201379793, 0.8225678622100954, 0.8279895821445254, 0.8315796490767757, 0.83283023378754, 0.8315942289866092, 0.8281009342844488, 0.8229152044577286, 0.8168471062655036, 0.8108263745362725, 0.8057609676983083, 0.802401088989472, 0.8012289003201748, 0.8023900269797712, 0.8056764881406234, 0.810562882063641, 0.8162896832711553, 0.8219805580944359, 0.8267756948140882, 0.8299609795908188, 0.831073718081486, 0.829969339740523, 0.826839537797907, 0.8221796711543669, 0.8167108715129974, 0.811269022013183, 0.806677609185426, 0.8036236957692484, 0.8025556078316628, 0.8036175005714806, 0.8066303004652322, 0.8111214831283536, 0.8163987997194192, 0.8216565104379272, 0.826097895229547, 0.8290554917521646, 0.8300909877688362, 0.8290598781204477, 0.8261313903851027, 0.8217609662204007, 0.8166197327702326, 0.8114918376771522, 0.807155291590255, 0.804264357964495, 0.8032512047704022, 0.8042615487964975, 0.8071338405374433, 0.8114249433414632, 0.8164782496932874, 0.8215238037647841, 0.8257952167922961, 0.8286456827695614, 0.8296455911510194, 0.8286470544435145, 0.8258056909322982, 0.8215564665757582, 0.816547331117509, 0.8115407394268492, 0.8072979758702735, 0.8044637739601779, 0.8034686614089587, 0.8044637739601779, 0.8072979758702735, 0.8115407394268492, 0.816547331117509, 0.8215564665757582, 0.8258056909322982, 0.8286470544435145, 0.8296455911510194, 0.8286456827695614, 0.8257952167922961, 0.8215238037647841, 0.8164782496932874, 0.8114249433414632, 0.8071338405374433, 0.8042615487964975, 0.8032512047704022, 0.804264357964495, 0.807155291590255, 0.8114918376771522, 0.8166197327702326, 0.8217609662204007, 0.8261313903851027, 0.8290598781204477, 0.8300909877688362, 0.8290554917521646, 0.826097895229547, 0.8216565104379272, 0.8163987997194192, 0.8111214831283536, 0.8066303004652322, 0.8036175005714806, 0.8025556078316628, 0.8036236957692484, 0.806677609185426, 0.8112690220131836, 0.8167108715129979, 0.8221796711543663, 0.8268395377979071, 0.8299693397405228, 0.831073718081486, 0.8299609795908188, 0.8267756948140882, 0.8219805580944359, 0.8162896832711553, 0.810562882063641, 0.8056764881406235, 0.8023900269797709, 0.8012289003201744, 0.8024010889894727, 0.8057609676983083, 0.8108263745362723, 0.8168471062655036, 0.8229152044577286, 0.8281009342844488, 0.8315942289866088, 0.8328302337875395, 0.8315796490767756, 0.8279895821445254, 0.8225678622100949, 0.8161122013797935, 0.8095939470143894, 0.8040132108889639, 0.8002467969810111, 0.7989118438801696, 0.8002661684806577, 0.8041611709877297, 0.8100555459834639, 0.8170890343398455, 0.8242063694137037, 0.83031377791425, 0.8344450855021338, 0.8359124977488247, 0.8344188475680828, 0.8301133459474759, 0.8235809401060414, 0.8157651327215332, 0.8078341320796935, 0.8010089978266244, 0.7963786784428641, 0.7947295104164669, 0.7964153739611706, 0.8012893718239191, 0.8087092994044044, 0.81761849048471, 0.826692377498891, 0.8345309472905089, 0.8398697377350496, 0.8417783009443003, 0.8398158233605921, 0.83411887315708, 0.8254054128688869, 0.8148910552146637, 0.8041264103473585, 0.7947764288286263, 0.7883721001355047, 0.7860702523113234, 0.7884575830386535, 0.795430192040292, 0.80617027885153, 0.8192285134636046, 0.8327056221677798, 0.8445120170295604, 0.8526719320720058, 0.8556303537201991, 0.8525183670721344, 0.8433359812732847, 0.8290208181948012, 0.811385200194657, 0.7929214062194857, 0.7764929510457531, 0.7649462595688722, 0.7606897690177487, 0.765294454850924, 0.779169904414093, 0.8013630954682431, 0.8295136455522423, 0.8599810183094557, 0.8881382201926599, 0.9088055137842617, 0.9167792977534914, 0.9073979610897166, 0.8770800137702394, 0.8237710881422359, 0.7472454782282603, 0.6492237346315557, 0.5332885901918365, 0.4046046507407315, 0.26947002202454795, 0.13474757309691365, 0.00723746617987131, -0.1069407826758654, -0.20288989500700108, -0.27734256550728503, -0.32889381303838977, -0.3580370607340264, -0.3670058032208171, -0.359444778863779, -0.33995328252200696, -0.3135564333894343, -0.2851663875913163, -0.25909409263519995, -0.23866361132907504, -0.22596657076785903, -0.2217758927231247, -0.2256180426114959, -0.23598411653782456, -0.2506444802494896, -0.26702119083131093, -0.28256816596504797, -0.295111277443397, -0.3031086575181821, -0.3058042063580308, -0.30326272689881056, -0.29629116404013944, -0.2862649753295516, -0.274889886408327, -0.2639358815364588, -0.25498161286524923, -0.24920357462690432, -0.24723615800902632, -0.2491174095833179, -0.254322641649427, -0.26187577651237237, -0.2705181001328564, -0.2789072484396626, -0.2858165279879754, -0.2903062547275065, -0.2918442521508061, -0.29036103763345744, -0.28623523319379945, -0.2802148862116466, -0.27328924369461155, -0.2665319003557926, -0.2609393457326357, -0.2572884731148674, -0.25603280394766703, -0.25725071139122346, -0.26065083144492035, -0.2656313546452963, -0.2713822293274348, -0.2770135448301449, -0.2816902526861226, -0.2847532695359379, -0.2858097877124424, -0.2847807856541879, -0.2819004433881343, -0.2776693983488584, -0.2727704560912502, -0.2679605488149674, -0.26395571252683825, -0.26132627492780913, -0.2604173379592594, -0.2613053954459853, -0.2637962391879585, -0.26746305555310607, -0.2717177322505074, -0.2759037309088617, -0.279396079841536, -0.2816934548574575, -0.28248896104220483, -0.2817097945265572, -0.27952086755237054, -0.2762929608470925, -0.27254120045602803, -0.26884387816252864, -0.26575425252252116, -0.26371864131588046, -0.26301280163347296, -0.2637055407846639, -0.265654209009994, -0.26853186207231655, -0.2718811828520717, -0.27518640698447083, -0.27795205475006096, -0.2797765549093857, -0.28040991733324744, -0.27978726544516164, -0.27803384283870036, -0.27544146780011586, -0.2724206639913515, -0.2694362160817526, -0.26693618058647006, -0.2652851154142726, -0.26471140457275166, -0.2652762172781952, -0.2668682349188898, -0.26922433643026966, -0.2719725518547661, -0.27469035160346655, -0.27696920378036965, -0.27847559736597094, -0.2789994737168417, -0.2784830894556268, -0.2770264112979529, -0.2748687373450838, -0.2723498027188511, -0.26985662900426355, -0.2677643804298115, -0.2663802164877394, -0.2658984982975801, -0.2663738364007949, -0.267715664979902, -0.26970472910351406, -0.27202858030567123, -0.27433038356309986, -0.2762634534552826, -0.27754322359747763, -0.27798889585692416, -0.2775487095966493, -0.27630534127510475, -0.27446099023877113, -0.27230476279062416, -0.2701675757384496, -0.26837157989668814, -0.26718180171624356, -0.26676723196344526, -0.2671770449660856, -0.2683352607435817, -0.27005433503979787, -0.27206531057218997, -0.2740597147261609, -0.2757367048128887, -0.2768482817321726, -0.27723580187894054, -0.2768524361582525, -0.27576842464541484, -0.2741586130083838, -0.272274429375647, -0.2704047661110354, -0.2688318295254699, -0.2677886829169888, -0.2674248495195031, -0.2677850315527376, -0.26880395096327764, -0.270317845913108, -0.27209064229580604, -0.273850640099475, -0.27533203289893066, -0.2763149342125946, -0.27665790104713484, -0.2763181612142253, -0.27535667118343227, -0.27392745658234724, -0.27225306253880954, -0.2705900079431234, -0.269189589747151, -0.2682600106212974, -0.2679355225519342, -0.26825714482353047, -0.26916770943772766, -0.27052179104687163, -0.27210882762553046, -0.2736857719180995, -0.2750142244772251, -0.2758963885485276, -0.27620443710543774, -0.27589894438163365, -0.27503373808248416, -0.2737466094115088, -0.272237457981201, -0.27073732450031057, -0.26947309647897605, -0.2686332669376143, -0.268339902530925, -0.26863097909468553, -0.2694556290607689, -0.2706828669075027, -0.27212231826604955, -0.2735536838007334, -0.27476038820714566, -0.2755622848410429, -0.2758424870687282, -0.2755643393798424, -0.2747760742960275, -0.27360258740178156, -0.2722257140426019, -0.27085611237377155, -0.2697010913837529, -0.26893328836467517, -0.2686649198316835, -0.2689314382408936, -0.2696869660346345, -0.2708120749155902, -0.272132607534578, -0.2734465839666996, -0.2745550439855881, -0.27529212334329667, -0.2755498250467194, -0.2752937932839534, -0.274567793620623, -0.27348633222132157, -0.272216644853975, -0.27095288117113486, -0.26988646238937497, -0.26917713116745823, -0.26892906534904293, -0.2691756209544042, -0.2698749322796386, -0.2709169351085797, -0.2721406468455286, -0.27335899712763084, -0.2743873851145276, -0.27507161019472953, -0.2753109560629546, -0.27507297804718844, -0.274397828303834, -0.273391554465352, -0.2722094798766751, -0.27103226433344985, -0.2700383294831459, -0.2693768521603085, -0.26914540815951865, -0.2693756118476969, -0.27002886005232096, -0.27100274288269044, -0.2721470657140998, -0.27328698073942403, -0.27424967048358884, -0.2748905160718384, -0.27511479262442556, -0.27489164155029816, -0.2742582631678146, -0.27331376878108865, -0.2722037006138828, -0.27109763297213235, -0.27016329740666795, -0.2695411755570046, -0.26932340387703624, -0.2695401539764303, -0.2701554979679265, -0.2710733179907994, -0.27215229446459205, -0.2732276397884508, -0.27413623899246164, -0.27474136585439596, -0.2749532333093596, -0.27474229298297725, -0.2741433173069524, -0.2732497065742759, -0.2721989473371246, -0.27115148266370326, -0.27026623801755323, -0.26967653234827255, -0.2694700220245484, -0.2696756914893734, -0.27025981835415475, -0.27113146929198695, -0.27215663588413486, -0.27317881606067496, -0.2740428834248959, -0.27461860737271354, -0.2748202612233155, -0.2746193690660865, -0.2740486986781912, -0.27319694513156995, -0.27219496349633376, -0.27119568484224327, -0.27035079511709137, -0.26978773010663487, -0.26959047241758793, -0.26978704140037113, -0.2703455371012607, -0.2711792929978631, -0.2721603087494639, -0.273138883448665, -0.2739664406620733, -0.2745180680036547, -0.2747113547377431, -0.27451868910135635, -0.2739711825058329, -0.2731536660988043, -0.2721915613411777, -0.271231654432381, -0.27041971912272245, -0.26987839656405294, -0.26968868614277774, -0.2698778383918782, -0.27041545769605246, -0.27121836950153605, -0.2721634752051325, -0.27310660996387304, -0.27390451686527056, -0.27443659058589615, -0.2746230927305392, -0.27443708990686233, -0.27390832898218204, -0.273118494159615, -0.27218859994456995, -0.27126046394003206, -0.2704750938116453, -0.269951279891899, -0.2697676411197566, -0.26995083588418173, -0.27047170399232867, -0.271249896263262, -0.27216625854012316, -0.27308106305457486, -0.27385529964073035, -0.2743717847481908, -0.2745528849896627, -0.27437217650464996, -0.27385829054052785, -0.27309038709814587, -0.27218597071401707, -0.2712829222529055, -0.2705184924262569, -0.2700084553119556, -0.2698295860892269, -0.27000811317035095, -0.2705158803172519, -0.27127477908751213, -0.2721687550866839, -0.27306154389945153, -0.27381742800696246, -0.2743218548394752, -0.27449878567660696, -0.2743221496190084, -0.2738196785249323, -0.2730685598099032, -0.2721835875434252, -0.2712996294662407, -0.2705510861150179, -0.27005146857890544, -0.26987619615577746, -0.2700512192574929, -0.2705491826516532, -0.2712936954910146, -0.2721710424378831, -0.2730475416984566, -0.2737899019815432, -0.2742854802997986, -0.2744593636428006, -0.27428568574683343, -0.27379147048198155, -0.2730524314361513, -0.2721813798961157, -0.27131101488455867, -0.2705737191612288, -0.2700814355314443, -0.26990868070697704, -0.2700812726719884, -0.27057247579919075, -0.2713071387549604, -0.27217318532584445, -0.27303870225211274, -0.27377202026574576, -0.27426173317196856, -0.2744336130048598, -0.27426185445222606, -0.27377294618821935, -0.27304158877584134, -0.2721792877555755, -0.27131736275978013, -0.2705869601971663, -0.27009910992401753, -0.2699278569533187, -0.2700990294787136, -0.27058634603207155, -0.27131544812691394, -0.27217524001196924, -0.27303480719330064, -0.27376333869159847, -0.27425002301383095, -0.27442089341515463, -0.27425006311469324, -0.2737636448443042, -0.27303576161082543, -0.2721772577546942, -0.27131882864860113, -0.27059113523000433, -0.27010492727784874, -0.26993419748206887, -0.2701049272778487, -0.2705911352300042, -0.2713188286486011, -0.2721772577546951, -0.2730357616108258, -0.2737636448443046, -0.274250063114693, -0.27442089341515463, -0.2742500230138313, -0.27376333869159836, -0.2730348071933006, -0.2721752400119695, -0.27131544812691394, -0.2705863460320715, -0.2700990294787136, -0.2699278569533183, -0.2700991099240181, -0.2705869601971652, -0.27131736275978, -0.2721792877555756, -0.27304158877584106, -0.27377294618821935, -0.27426185445222573, -0.27443361300486063, -0.2742617331719678, -0.2737720202657452, -0.2730387022521126, -0.2721731853258431, -0.27130713875496026, -0.2705724757991907, -0.2700812726719883, -0.2699086807069772, -0.27008143553144515, -0.2705737191612293, -0.27131101488455917, -0.2721813798961152, -0.27305243143615116, -0.27379147048198155, -0.27428568574683354, -0.27445936364280027, -0.2742854802997984, -0.2737899019815436, -0.27304754169845624, -0.272171042437883, -0.27129369549101534, -0.270549182651652, -0.2700512192574924, -0.26987619615577624, -0.2700514685789057, -0.2705510861150181, -0.2712996294662407, -0.2721835875434252, -0.2730685598099026, -0.27381967852493183, -0.27432214961900875, -0.2744987856766067, -0.27432185483947513, -0.2738174280069626, -0.273061543899451, -0.2721687550866833, -0.2712747790875123, -0.27051588031725204, -0.27000811317034995, -0.2698295860892274, -0.2700084553119555, -0.27051849242625775, -0.2712829222529059, -0.2721859707140163, -0.2730903870981452, -0.2738582905405286, -0.2743721765046489, -0.27455288498966174, -0.27437178474819085, -0.2738552996407304, -0.2730810630545757, -0.27216625854012316, -0.27124989626326196, -0.2704717039923287, -0.2699508358841814, -0.2697676411197566, -0.2699512798918987, -0.2704750938116445, -0.27126046394003184, -0.2721885999445698, -0.27311849415961353, -0.27390832898218165, -0.2744370899068626, -0.27462309273053936, -0.274436590585896, -0.27390451686527084, -0.27310660996387326, -0.2721634752051324, -0.27121836950153566, -0.27041545769605274, -0.2698778383918788, -0.26968868614277824, -0.26987839656405227, -0.27041971912272206, -0.27123165443238095, -0.27219156134117817, -0.27315366609880354, -0.2739711825058322, -0.27451868910135624, -0.27471135473774344, -0.27451806800365375, -0.2739664406620728, -0.27313888344866405, -0.2721603087494636, -0.27117929299786403, -0.27034553710125997, -0.26978704140037046, -0.2695904724175882, -0.26978773010663476, -0.27035079511709065, -0.27119568484224327, -0.27219496349633376, -0.2731969451315697, -0.2740486986781913, -0.2746193690660856, -0.27482026122331654, -0.2746186073727132, -0.27404288342489586, -0.2731788160606753, -0.2721566358841349, -0.2711314692919879, -0.2702598183541548, -0.26967569148937365, -0.26947002202454773, -0.269676532348272, -0.27026623801755295, -0.2711514826637036, -0.27219894733712496, -0.27324970657427533, -0.27414331730695285, -0.27474229298297653, -0.27495323330935817, -0.2747413658543959, -0.2741362389924614, -0.27322763978845105, -0.27215229446459155, -0.2710733179907992, -0.2701554979679266, -0.26954015397643044, -0.2693234038770364, -0.2695411755570045, -0.2701632974066676, -0.2710976329721324, -0.2722037006138831, -0.27331376878108854, -0.2742582631678147, -0.27489164155029855, -0.275114792624425, -0.2748905160718378, -0.27424967048358856, -0.2732869807394241, -0.2721470657140985, -0.27100274288269105, -0.270028860052321, -0.26937561184769615, -0.26914540815951826, -0.2693768521603078, -0.2700383294831457, -0.2710322643334497, -0.2722094798766746, -0.27339155446535074, -0.27439782830383364, -0.27507297804718794, -0.275310956062955, -0.2750716101947305, -0.2743873851145279, -0.27335899712762957, -0.2721406468455295, -0.27091693510858045, -0.2698749322796382, -0.26917562095440417, -0.2689290653490426, -0.26917713116745934, -0.26988646238937414, -0.27095288117113486, -0.2722166448539753, -0.27348633222131985, -0.2745677936206223, -0.2752937932839534, -0.27554982504672043, -0.27529212334329667, -0.27455504398558794, -0.27344658396669963, -0.272132607534578, -0.27081207491559034, -0.2696869660346339, -0.2689314382408932, -0.2686649198316834, -0.26893328836467445, -0.2697010913837531, -0.27085611237377133, -0.27222571404260204, -0.27360258740178145, -0.27477607429602674, -0.2755643393798425, -0.2758424870687282, -0.27556228484104217, -0.27476038820714505, -0.2735536838007333, -0.27212231826604977, -0.2706828669075033, -0.2694556290607694, -0.2686309790946836, -0.26833990253092416, -0.26863326693761486, -0.26947309647897594, -0.2707373245003117, -0.27223745798119975, -0.2737466094115084, -0.27503373808248427, -0.27589894438163465, -0.27620443710543824, -0.27589638854852755, -0.27501422447722434, -0.27368577191809945, -0.27210882762553024, -0.2705217910468718, -0.2691677094377281, -0.2682571448235305, -0.26793552255193387, -0.2682600106212966, -0.2691895897471508, -0.27059000794312343, -0.2722530625388108, -0.2739274565823486, -0.27535667118343166, -0.276318161214224, -0.2766579010471346, -0.2763149342125941, -0.2753320328989316, -0.27385064009947496, -0.2720906422958058, -0.2703178459131083, -0.2688039509632775, -0.26778503155273853, -0.2674248495195033, -0.2677886829169895, -0.2688318295254691, -0.27040476611103553, -0.2722744293756475, -0.27415861300838423, -0.2757684246454133, -0.2768524361582521, -0.27723580187894115, -0.27684828173217274, -0.275736704812889, -0.2740597147261611, -0.2720653105721888, -0.2700543350397981, -0.268335260743582, -0.2671770449660855, -0.26676723196344554, -0.26718180171624295, -0.2683715798966883, -0.27016757573845007, -0.27230476279062327, -0.27446099023877113, -0.27630534127510464, -0.2775487095966508, -0.27798889585692427, -0.2775432235974794, -0.2762634534552824, -0.27433038356310013, -0.27202858030566945, -0.26970472910351356, -0.2677156649799023, -0.2663738364007942, -0.2658984982975799, -0.26638021648773896, -0.26776438042981193, -0.26985662900426344, -0.27234980271885056, -0.27486873734508505, -0.277026411297953, -0.2784830894556271, -0.27899947371684036, -0.2784755973659705, -0.2769692037803706, -0.274690351603467, -0.2719725518547676, -0.26922433643026933, -0.2668682349188888, -0.26527621727819595, -0.26471140457275155, -0.2652851154142724, -0.26693618058646956, -0.26943621608175267, -0.272420663991352, -0.27544146780011636, -0.27803384283869975, -0.2797872654451614, -0.28040991733324766, -0.27977655490938763, -0.27795205475006224, -0.27518640698447067, -0.2718811828520715, -0.2685318620723157, -0.26565420900999454, -0.26370554078466407, -0.26301280163347274, -0.26371864131587996, -0.2657542525225205, -0.2688438781625286, -0.27254120045602853, -0.2762929608470925, -0.27952086755237027, -0.28170979452655626, -0.28248896104220544, -0.2816934548574569, -0.27939607984153414, -0.2759037309088627, -0.2717177322505082, -0.2674630555531055, -0.26379623918795886, -0.2613053954459872, -0.26041733795925914, -0.2613262749278088, -0.2639557125268372, -0.2679605488149672, -0.27277045609125145, -0.2776693983488584, -0.28190044338813347, -0.2847807856541873, -0.2858097877124434, -0.2847532695359377, -0.2816902526861231, -0.27701354483014345, -0.2713822293274345, -0.26563135464529686, -0.2606508314449214, -0.2572507113912241, -0.25603280394766736, -0.25728847311486763, -0.2609393457326367, -0.26653190035579194, -0.2732892436946111, -0.28021488621164664, -0.2862352331938013, -0.2903610376334575, -0.2918442521508068, -0.29030625472750715, -0.28581652798797547, -0.27890724843966247, -0.2705181001328552, -0.26187577651237365, -0.2543226416494271, -0.24911740958331774, -0.24723615800902635, -0.24920357462690512, -0.25498161286524895, -0.2639358815364581, -0.27488988640832573, -0.2862649753295525, -0.29629116404013883, -0.3032627268988092, -0.3058042063580304, -0.3031086575181823, -0.29511127744339793, -0.2825681659650496, -0.2670211908313103, -0.25064448024948777, -0.23598411653782536, -0.2256180426114975, -0.2217758927231244, -0.22596657076785873, -0.23866361132907477, -0.2590940926351971, -0.2851663875913157, -0.3135564333894333, -0.33995328252200546, -0.3594447788637798, -0.36700580322081533, -0.358037060734027, -0.32889381303839366, -0.27734256550728487, -0.20288989500700386, -0.10694078267587288, 0.007237466179859759, 0.13474757309691376, ], [0.26676723196344515, 0.3347702480739619, 0.4021100643766091, 0.4679274520123884, 0.5313950334907156, 0.5917362897000729, 0.6482432613175135, 0.7002924596399014, 0.7473585526646417, 0.7890254560435744, 0.8249945333995742, 0.8550896941601215, 0.8792592669757234, 0.8975746202051736, 0.9102255950041195, 0.9175129083673529, 0.9198377702383221, 0.917689037861332, 0.9116282995067538, 0.9022733364623226, 0.8902804550561408, 0.8763262082019778, 0.8610890377577217, 0.8452313645900517, 0.8293826328809863, 0.8141237796357225, 0.7999735507741906, 0.7873770232656114, 0.7766966205324523, 0.768205828158426, 0.7620857313733936, 0.7584244075977656, 0.7572191193158561, 0.7583811674898533, 0.7617431862850547, 0.7670685885115933, 0.7740628100760052, 0.7823859527055825, 0.7916663886805041, 0.8015148702537831, 0.8115386803487779, 0.8213553699989888, 0.8306056513481047, 0.8389650519056304, 0.8461539847682941, 0.8519459489006456, 0.8561736412293849, 0.8587328359056415, 0.8595839631187808, 0.8587513977112328, 0.8563205439554928, 0.8524328747100735, 0.8472791484405181, 0.841091084192215, 0.834131820766458, 0.826685520684453, 0.8190465010447697, 0.8115082815510961, 0.8043529347293187, 0.7978411050419453, 0.7922030330578511, 0.7876308792667416, 0.784272591121635, 0.7822274983332483, 0.7815437574473915, 0.7822176995911162, 0.7841950673374529, 0.78737406027331, 0.7916100463398893, 0.7967217394696321, 0.8024985953569025, 0.8087091379660419, 0.8151099008482179, 0.8214546503695713, 0.8275035529967577, 0.8330319558670924, 0.8378384685928522, 0.8417520638131072, 0.8446379532400501, 0.846402043350774, 0.8469938286783827, 0.846407638878768, 0.8446822162756511, 0.8418986612397675, 0.8381768413776371, 0.8336704150226877, 0.8285606680322251, 0.823049403723726, 0.8173511575466523, 0.8116850297293308, 0.8062664399813406, 0.8012991080781864, 0.7969675529125466, 0.7934303808628573, 0.7908146029740989, 0.7892111806743417, 0.788671953063004, 0.7892080469399132, 0.7907898156084533, 0.7933482960969338, 0.7967781188468478, 0.8009417511209863, 0.8056749072814013, 0.8107929173899058, 0.8160978117645014, 0.8213858543642836, 0.8264552430201726, 0.8311136900647805, 0.8351856029496807, 0.8385186007001665, 0.8409891279058511, 0.8425069623955114, 0.8430184545048416, 0.8425083833648762, 0.8410003671627708, 0.8385558179624402, 0.835271485457146, 0.8312756859971948, 0.8267233615028143, 0.8217901556840193, 0.8166657307974943, 0.8115465756848104, 0.8066285740704098, 0.8020996104046427, 0.7981324886316464, 0.7948784272304886, 0.7924613721755287, 0.7909733388940688, 0.7904709559934735, 0.7909733388940688, 0.7924613721755287, 0.7948784272304886, 0.7981324886316464, 0.8020996104046427, 0.8066285740704098, 0.8115465756848104, 0.8166657307974943, 0.8217901556840193, 0.8267233615028143, 0.8312756859971948, 0.835271485457146, 0.8385558179624402, 0.8410003671627708, 0.8425083833648762, 0.8430184545048416, 0.8425069623955114, 0.8409891279058511, 0.8385186007001665, 0.8351856029496807, 0.8311136900647805, 0.8264552430201726, 0.8213858543642836, 0.8160978117645014, 0.8107929173899058, 0.8056749072814013, 0.8009417511209863, 0.7967781188468478, 0.7933482960969338, 0.7907898156084533, 0.7892080469399132, 0.788671953063004, 0.7892111806743417, 0.7908146029740989, 0.7934303808628572, 0.7969675529125475, 0.8012991080781858, 0.8062664399813407, 0.8116850297293308, 0.8173511575466523, 0.823049403723726, 0.8285606680322251, 0.8336704150226877, 0.8381768413776371, 0.8418986612397675, 0.8446822162756515, 0.8464076388787678, 0.8469938286783827, 0.8464020433507743, 0.8446379532400501, 0.8417520638131074, 0.8378384685928522, 0.8330319558670924, 0.8275035529967577, 0.821454650369571, 0.8151099008482179, 0.8087091379660419, 0.8024985953569027, 0.7967217394696318, 0.7916100463398893, 0.78737406027331, 0.7841950673374533, 0.7822176995911162, 0.7815437574473915, 0.7822274983332483, 0.784272591121635, 0.7876308792667418, 0.7922030330578508, 0.797841105041945, 0.8043529347293186, 0.811508281551096, 0.8190465010447696, 0.8266855206844528, 0.834131820766458, 0.841091084192215, 0.8472791484405181, 0.8524328747100731, 0.856320543955493, 0.8587513977112331, 0.8595839631187812, 0.8587328359056415, 0.8561736412293847, 0.8519459489006451, 0.8461539847682941, 0.8389650519056304, 0.8306056513481047, 0.8213553699989891, 0.8115386803487781, 0.801514870253783, 0.7916663886805048, 0.7823859527055822, 0.774062810076005, 0.7670685885115931, 0.7617431862850543, 0.7583811674898533, 0.7572191193158561, 0.7584244075977656, 0.7620857313733936, 0.7682058281584263, 0.7766966205324519, 0.7873770232656113, 0.7999735507741907, 0.8141237796357235, 0.8293826328809863, 0.8452313645900518, 0.8610890377577217, 0.8763262082019778, 0.8902804550561406, 0.9022733364623227, 0.9116282995067533, 0.9176890378613317, 0.919837770238322, 0.917512908367353, 0.9102255950041196, 0.8975746202051735, 0.8792592669757234, 0.8550896941601215, 0.8249945333995742, 0.7890254560435748, 0.7473585526646421, 0.7002924596399022, 0.6482432613175145, 0.5917362897000721, 0.5313950334907149, 0.4679274520123878, 0.40211006437660857, 0.3347702480739616, 0.26676723196344515, 0.19897230494020707, 0.13224878230572024, 0.06743227648320947, 0.0053118071495548095, -0.05338774146876835, -0.10802135053926673, -0.15803737380972696, -0.20298847930182776, -0.2425397595640147, -0.2764738284346425, -0.30469281066189, -0.32721722088772964, -0.3441818176687886, -0.35582860367529706, -0.36249722242165494, -0.36461307250245756, -0.36267352029436245, -0.35723263975102254, -0.34888494198479186, -0.33824857695544364, -0.3259484943955706, -0.3126000411862386, -0.2987934483060728, -0.28507962319966784, -0.271957614333019, -0.25986405555964154, -0.24916483073451715, -0.2401491260270803, -0.23302596098648087, -0.22792321205561053, -0.22488906635380132, -0.22389577149877202, -0.22484548119691258, -0.22757793824457792, -0.23187968810632986, -0.23749447868291632, -0.2441344761888953, -0.2514919137631326, -0.2592507886601006, -0.26709823533412075, -0.27473522476043544, -0.2818862739035455, -0.28830789199232093, -0.29379554057141083, -0.2981889403376855, -0.3013756175611629, -0.303292644376933, -0.30392658835480474, -0.3033117455041075, -0.3015267853752967, -0.29868998548634035, -0.2949532734767166, -0.29049532800309014, -0.2855140125806442, -0.2802184298214236, -0.27482088665119403, -0.2695290542695408, -0.264538590355926, -0.26002646612621955, -0.2561452083918101, -0.2530182280789487, -0.25073636322214266, -0.24935571787021443, -0.2488968303227057, -0.24934515634129964, -0.25065280709965027, -0.25274143918370784, -0.25550615631569207, -0.2588202508304093, -0.26254058822827797, -0.2665134210468163, -0.2705804092313354, -0.2745846232566558, -0.27837631327854173, -0.2818182421256438, -0.28479040127692656, -0.2871939561804447, -0.28895429924636146, -0.29002312433776833, -0.2903794742396974, -0.29002975101672784, -0.28900671698463093, -0.2873675498866312, -0.2851910485450926, -0.2825741136606843, -0.27962765163624625, -0.2764720666005679, -0.2732325167133799, -0.2700341151018774, -0.26699725340871355, -0.26423321715735837, -0.2618402474235765, -0.2599001833057532, -0.2584757952518214, -0.25760889140880733, -0.2573192488986063, -0.25760439043962247, -0.2584401951978867, -0.2597823023105429, -0.2615682372635578, -0.26372016620770916, -0.26614816221137005, -0.2687538510619536, -0.2714342930424235, -0.2740859514283935, -0.2766085983723468, -0.27890901425568965, -0.2809043471901643, -0.2825250146475171, -0.283717048538251, -0.2844438076592255, -0.284687006393908, -0.28444703491641293, -0.28374257292547045, -0.28260952512485604, -0.2810993313195836, -0.27927672623284583, -0.27721704319217016, -0.27500317104024097, -0.2727222845043845, -0.2704624744843911, -0.2683094061435324, -0.2663431293437836, -0.26463515805993504, -0.2632459233094384, -0.2622226883648681, -0.2615979962196806, -0.2613886982025312, -0.26159559009953237, -0.26220365900682635, -0.2631829212654158, -0.2644898100701735, -0.26606905148470117, -0.2678559503398516, -0.2697789934453094, -0.271762667171665, -0.2737303800925991, -0.2756073791872552, -0.2773235501084012, -0.2788159980834239, -0.2800313158451891, -0.28092745816528586, -0.28147515854232463, -0.2816588417456785, -0.281477005522016, -0.2809420650859598, -0.2800796742695104, -0.27892755664293495, -0.2775338978292605, -0.2759553659687058, -0.27425484029175823, -0.2724989375830915, -0.27075543264041396, -0.2690906714649702, -0.2675670748160371, -0.2662408250125531, -0.26515982069785415, -0.26436197304977044, -0.2638739030790137, -0.2637100837662936, -0.26387245346461086, -0.2643505089048277, -0.26512186797720205, -0.26615327490027263, -0.2674020040815496, -0.2688176045169686, -0.2703439144866212, -0.27192126700651215, -0.27348880030407513, -0.2749867846929487, -0.27635887769831186, -0.2775542230693105, -0.27852931622793875, -0.2792495684474642, -0.27969051422881763, -0.2798386204646215, -0.27969167149471613, -0.2792587204653555, -0.2785596138849407, -0.2776241123010425, -0.2764906450091594, -0.2752047500917431, -0.2738172623909736, -0.2723823208386868, -0.270955272597392, -0.26959055451108016, -0.26833963234136793, -0.2672490752073162, -0.26635883670338995, -0.2657008055938266, -0.26529767812360705, -0.26516219127884677, -0.26529674227039823, -0.26569340464703434, -0.2663343363369811, -0.26719256014028114, -0.26823308330279455, -0.26941431031016894, -0.27068969240370766, -0.2720095489223423, -0.27332298971054175, -0.2745798646974821, -0.2757326664401264, -0.27673831391679005, -0.2775597510377539, -0.2781673009848443, -0.2785397272912209, -0.278664964136325, -0.27854049120644625, -0.2781733421609049, -0.27757974972336247, -0.2767844441570823, -0.27581963486551203, -0.2747237166019679, -0.2735397518420128, -0.2723137889035841, -0.27109308110750147, -0.2699242754588824, -0.2688516398993765, -0.2679153961315127, -0.267150220449258, -0.26658396811655344, -0.2662366678978191, -0.26611982271569434, -0.26623604050707983, -0.26657900662910516, -0.26713379611936505, -0.2678775112354508, -0.26878021747318726, -0.2698061401481914, -0.27091507403320036, -0.2720639508250778, -0.2732085036482507, -0.27430496457983594, -0.27531173041014584, -0.276190933541672, -0.2769098589978448, -0.27744215478857814, -0.2777687911092836, -0.27787873370814614, -0.2777693078581932, -0.2774462412871165, -0.2769233866919367, -0.2762221366303811, -0.2753705551763603, -0.27440226131801265, -0.2733551082692195, -0.27226971032051517, -0.27118787430217056, -0.27015099597794284, -0.26919848262395296, -0.26836626165303296, -0.2676854314884573, -0.26718110512525045, -0.266871489174068, -0.26676723196344543, -0.26687106384010173, -0.2671777415570788, -0.2676742970071397, -0.2683405789872368, -0.2691500656073508, -0.2700709148003042, -0.2710672115280045, -0.27210036304229934, -0.27313058821034025, -0.2741184436469192, -0.275026328321871, -0.2758199094576125, -0.27646941584444823, -0.27695075004170616, -0.27724637808750435, -0.27734596402771833, -0.2772467264576852, -0.2769535049671524, -0.2764785354796854, -0.27584094455902525, -0.2750659833962618, -0.2741840319916477, -0.2732294126357283, -0.2722390588562409, -0.27125109126950364, -0.2703033550663579, -0.26943197505790706, -0.2686699832699204, -0.2680460710525803, -0.26758351268867847, -0.2672993007368845, -0.2672035251013887, -0.2672990183951214, -0.2675812799239226, -0.26803867995600494, -0.26865293526157624, -0.26939983661731093, -0.2702501994584891, -0.2711710004861803, -0.27212665611107306, -0.27308039339654067, -0.27399566084259125, -0.2748375250477978, -0.27557400003980087, -0.27617725883959726, -0.276624681507317, -0.27689970032226213, -0.27699241062765123, -0.2768999249159043, -0.27662645759535204, -0.27618313818597895, -0.2755875610470116, -0.27486308974899815, -0.2740379434883319, -0.27314410115248733, -0.27221606547123295, -0.2712895348778475, -0.27040003406322355, -0.2695815556129312, -0.2688652645257595, -0.2682783148508119, -0.267842823252458, -0.26757503819263606, -0.2674847358458392, -0.2675748651262097, -0.2678414546439762, -0.2682737843862124, -0.26885481481688084, -0.2695618562978546, -0.27036745261754447, -0.27124044432183364, -0.27214717077819545, -0.27305276472194134, -0.27392248962647237, -0.27472306873228935, -0.2754239550148828, -0.2759984937477218, -0.27642493352833314, -0.27668724751828866, -0.2767757339740639, -0.27668737363322266, -0.27642593084355604, -0.2760017951251985, -0.27543156976323463, -0.274737423663692, -0.2739462317030305, -0.2730885368190823, -0.2721973738267604, -0.2713070001408319, -0.2704515820556589, -0.2696638868392777, -0.26897403058869795, -0.2684083295799016, -0.2679882988174503, -0.26772983580092247, -0.26764262039810416, -0.26772975342127747, -0.26798764736079583, -0.2684061730885367, -0.2689690565707379, -0.26965451009723934, -0.2704360736050614, -0.2712836336875387, -0.2721645811389861, -0.27304506264413214, -0.2738912786791196, -0.27467077797983047, -0.27535369911658153, -0.2759139117831391, -0.2763300142816405, -0.2765861492142696, -0.2766726063652543, -0.2765861899061378, -0.2763303360720144, -0.275914976992395, -0.2753561560541391, -0.27467540965594783, -0.27389893912338814, -0.2730566045511791, -0.27218077912144417, -0.2713051077551102, -0.2704632175870254, -0.26968742957615727, -0.26900752050153764, -0.26844958265563396, -0.26803502480383445, -0.2677797525748559, -0.26769355958841123, -0.267779752574856, -0.26803502480383423, -0.2684495826556342, -0.2690075205015382, -0.2696874295761576, -0.27046321758702613, -0.2713051077551099, -0.2721807791214444, -0.27305660455117853, -0.2738989391233883, -0.27467540965594783, -0.2753561560541394, -0.2759149769923949, -0.2763303360720148, -0.2765861899061384, -0.2766726063652539, -0.2765861492142697, -0.2763300142816399, -0.2759139117831392, -0.2753536991165813, -0.27467077797983047, -0.2738912786791197, -0.27304506264413186, -0.2721645811389865, -0.27128363368753816, -0.2704360736050615, -0.2696545100972393, -0.2689690565707373, -0.2684061730885367, -0.2679876473607959, -0.2677297534212772, -0.26764262039810405, -0.26772983580092374, -0.2679882988174505, -0.26840832957990196, -0.26897403058869745, -0.2696638868392784, -0.2704515820556589, -0.2713070001408325, -0.2721973738267601, -0.2730885368190818, -0.273946231703031, -0.2747374236636919, -0.2754315697632349, -0.27600179512519835, -0.27642593084355566, -0.2766873736332221, -0.27677573397406385, -0.27668724751828905, -0.27642493352833397, -0.27599849374772184, -0.27542395501488287, -0.27472306873228924, -0.2739224896264721, -0.2730527647219422, -0.27214717077819567, -0.2712404443218336, -0.2703674526175442, -0.26956185629785445, -0.2688548148168806, -0.26827378438621263, -0.2678414546439762, -0.26757486512620954, -0.26748473584583976, -0.2675750381926359, -0.26784282325245834, -0.2682783148508119, -0.26886526452575943, -0.26958155561293057, -0.2704000340632238, -0.2712895348778471, -0.27221606547123184, -0.2731441011524872, -0.2740379434883316, -0.2748630897489988, -0.27558756104701143, -0.2761831381859791, -0.27662645759535204, -0.2768999249159041, -0.27699241062765095, -0.276899700322262, -0.27662468150731656, -0.27617725883959754, -0.2755740000398008, -0.2748375250477974, -0.27399566084259136, -0.273080393396541, -0.2721266561110735, -0.27117100048618054, -0.27025019945848966, -0.269399836617311, -0.26865293526157624, -0.26803867995600505, -0.2675812799239234, -0.2672990183951221, -0.26720352510138895, -0.2672993007368841, -0.2675835126886781, -0.26804607105258016, -0.26866998326992114, -0.2694319750579061, -0.2703033550663581, -0.27125109126950353, -0.2722390588562406, -0.2732294126357281, -0.2741840319916473, -0.2750659833962609, -0.27584094455902525, -0.2764785354796863, -0.27695350496715204, -0.2772467264576849, -0.2773459640277185, -0.27724637808750496, -0.27695075004170544, -0.27646941584444795, -0.27581990945761226, -0.27502632832187074, -0.27411844364691934, -0.27313058821034, -0.2721003630423003, -0.27106721152800445, -0.2700709148003045, -0.26915006560735083, -0.268340578987237, -0.26767429700713996, -0.26717774155707863, -0.26687106384010195, -0.26676723196344515, -0.26687148917406717, -0.26718110512525006, -0.26768543148845786, -0.26836626165303334, -0.26919848262395263, -0.27015099597794323, -0.2711878743021703, -0.2722697103205143, -0.27335510826921944, -0.27440226131801293, -0.27537055517636055, -0.2762221366303809, -0.27692338669193634, -0.27744624128711604, -0.2777693078581935, -0.27787873370814636, -0.27776879110928415, -0.2774421547885779, -0.27690985899784554, -0.2761909335416728, -0.2753117304101454, -0.27430496457983583, -0.2732085036482508, -0.27206395082507756, -0.27091507403319975, -0.26980614014819176, -0.26878021747318753, -0.26787751123545067, -0.26713379611936505, -0.26657900662910516, -0.2662360405070797, -0.26611982271569407, -0.2662366678978187, -0.2665839681165536, -0.26715022044925785, -0.2679153961315124, -0.2688516398993759, -0.2699242754588818, -0.27109308110750174, -0.27231378890358454, -0.2735397518420129, -0.2747237166019677, -0.27581963486551136, -0.27678444415708314, -0.27757974972336336, -0.27817334216090506, -0.27854049120644614, -0.2786649641363245, -0.2785397272912218, -0.27816730098484355, -0.277559751037754, -0.2767383139167901, -0.2757326664401261, -0.2745798646974818, -0.2733229897105417, -0.27200954892234336, -0.2706896924037076, -0.269414310310169, -0.26823308330279455, -0.26719256014028114, -0.2663343363369811, -0.2656934046470346, -0.26529674227039807, -0.2651621912788465, -0.26529767812360644, -0.2657008055938268, -0.2663588367033905, -0.2672490752073167, -0.2683396323413677, -0.26959055451107977, -0.270955272597392, -0.2723823208386867, -0.2738172623909736, -0.27520475009174283, -0.2764906450091594, -0.2776241123010428, -0.278559613884941, -0.2792587204653552, -0.27969167149471574, -0.27983862046462155, -0.2796905142288177, -0.2792495684474643, -0.2785293162279389, -0.2775542230693101, -0.2763588776983119, -0.2749867846929489, -0.2734888003040759, -0.27192126700651237, -0.27034391448662093, -0.26881760451696857, -0.26740200408155024, -0.2661532749002729, -0.26512186797720205, -0.2643505089048277, -0.26387245346461086, -0.26371008376629373, -0.26387390307901276, -0.26436197304977044, -0.26515982069785415, -0.2662408250125531, -0.26756707481603814, -0.2690906714649698, -0.2707554326404128, -0.27249893758309146, -0.2742548402917575, -0.2759553659687066, -0.2775338978292605, -0.2789275566429346, -0.2800796742695104, -0.28094206508595987, -0.2814770055220161, -0.28165884174567885, -0.28147515854232574, -0.2809274581652855, -0.2800313158451896, -0.27881599808342394, -0.27732355010840154, -0.2756073791872547, -0.2737303800925984, -0.27176266717166525, -0.2697789934453094, -0.2678559503398518, -0.266069051484701, -0.2644898100701734, -0.26318292126541604, -0.26220365900682635, -0.2615955900995323, -0.26138869820253147, -0.26159799621968033, -0.26222268836486795, -0.2632459233094384, -0.2646351580599349, -0.2663431293437833, -0.2683094061435321, -0.27046247448439203, -0.2727222845043844, -0.2750031710402417, -0.27721704319217, -0.27927672623284583, -0.2810993313195821, -0.2826095251248563, -0.28374257292547017, -0.28444703491641227, -0.28468700639390787, -0.2844438076592255, -0.2837170485382512, -0.28252501464751706, -0.28090434719016394, -0.27890901425569037, -0.27660859837234697, -0.2740859514283942, -0.27143429304242306, -0.2687538510619531, -0.26614816221137017, -0.2637201662077091, -0.2615682372635582, -0.2597823023105432, -0.25844019519788647, -0.257604390439623, -0.2573192488986059, -0.2576088914088069, -0.2584757952518212, -0.2599001833057532, -0.26184024742357714, -0.2642332171573582, -0.2669972534087131, -0.27003411510187664, -0.2732325167133798, -0.2764720666005694, -0.27962765163624653, -0.2825741136606839, -0.28519104854509203, -0.2873675498866306, -0.2890067169846311, -0.2900297510167284, -0.2903794742396965, -0.2900231243377679, -0.2889542992463609, -0.28719395618044463, -0.2847904012769277, -0.2818182421256441, -0.278376313278542, -0.274584623256655, -0.270580409231336, -0.26651342104681575, -0.2625405882282778, -0.2588202508304099, -0.2555061563156926, -0.25274143918370734, -0.2506528070996504, -0.24934515634129994, -0.2488968303227054, -0.24935571787021474, -0.2507363632221426, -0.25301822807894814, -0.2561452083918106, -0.2600264661262195, -0.2645385903559255, -0.2695290542695397, -0.27482088665119514, -0.2802184298214232, -0.28551401258064407, -0.29049532800309025, -0.2949532734767167, -0.2986899854863397, -0.30152678537529704, -0.3033117455041076, -0.3039265883548047, -0.30329264437693343, -0.3013756175611637, -0.2981889403376846, -0.29379554057141105, -0.28830789199232143, -0.28188627390354565, -0.2747352247604351, -0.2670982353341209, -0.2592507886601015, -0.2514919137631321, -0.24413447618889542, -0.2374944786829162, -0.2318796881063301, -0.2275779382445774, -0.22484548119691208, -0.22389577149877196, -0.224889066353802, -0.22792321205561064, -0.23302596098648057, -0.24014912602707914, -0.24916483073451814, -0.25986405555964043, -0.27195761433301846, -0.2850796231996663, -0.2987934483060724, -0.3126000411862382, -0.3259484943955704, -0.33824857695544414, -0.34888494198479125, -0.35723263975102293, -0.36267352029436256, -0.3646130725024575, -0.3624972224216551, -0.35582860367529817, -0.3441818176687904, -0.3272172208877293, -0.3046928106618914, -0.2764738284346448, -0.24253975956401364, -0.20298847930182717, -0.15803737380972888, -0.10802135053927055, -0.053387741468768464, 0.005311807149552941, 0.06743227648320416, 0.1322487823057138, 0.1989723049402075, ], [0.26138869820253113, 0.29544490580896243, 0.3294968280950052, 0.3634355064209186, 0.39715222470252093, 0.4305391306906527, 0.4634898541284384, 0.4959001176127853, 0.5276683360205271, 0.558696200423826, 0.5888892425134873, 0.6181573756721882, 0.6464154089914365, 0.6735835307053645, 0.6995877577199853, 0.724360348146851, 0.7478401740035326, 0.7699730515181993, 0.7907120267697864, 0.8100176147067233, 0.82785798991366, 0.8442091278346617, 0.8590548955105114, 0.8723870912444328, 0.8842054329720935, 0.8945174954755265, 0.9033385969438483, 0.9106916357436837, 0.9166068786163364, 0.9211217018642828, 0.924280287423961, 0.9261332760425278, 0.9267373800808774, 0.9261549587514023, 0.9244535588646539, 0.9217054244021018, 0.9179869784508663, 0.9133782812288593, 0.9079624680937955, 0.9018251715657687, 0.8950539314994657, 0.8877375976178151, 0.8799657286633701, 0.8718279924366271, 0.8634135709717252, 0.8548105750496571, 0.8461054721676528, 0.8373825319713973, 0.8287232930150354, 0.8202060545436171, 0.8119053967950378, 0.8038917330951078, 0.796230896771899, 0.7889837656458495, 0.7822059265622738, 0.775947382125222, 0.7702523014683703, 0.7651588165622815, 0.760698865210546, 0.7568980815326587, 0.7537757343717074, 0.7513447137028135, 0.7496115647565714, 0.7485765692131902, 0.7482338724704873, 0.7485716556449272, 0.749572350632234, 0.7512128962352522, 0.7534650330641135, 0.7562956346297295, 0.7596670717882836, 0.7635376074537882, 0.767861818279697, 0.7725910398206697, 0.7776738315232644, 0.7830564577608542, 0.7886833810243483, 0.7944977633071265, 0.8004419716805027, 0.8064580840452189, 0.8124883910650526, 0.8184758903402864, 0.8243647689611353, 0.8301008706935303, 0.8356321441909723, 0.8409090687953263, 0.8458850546850892, 0.8505168143501489, 0.8547647026156938, 0.8585930227026644, 0.8619702960958848, 0.8648694942915153, 0.8672682308102369, 0.8691489121892405, 0.8704988470018598, 0.8713103122960792, 0.8715805771892576, 0.871311883703632, 0.8705113852726215, 0.8691910436890184, 0.8673674856000063, 0.8650618199780302, 0.8622994183082605, 0.8591096595302725, 0.8555256420512685, 0.8515838654085043, 0.8473238843974776, 0.8427879386980303, 0.8380205612211142, 0.8330681685630658, 0.8279786370905929, 0.8228008682871729, 0.8175843470694472, 0.8123786968298178, 0.8072332349785087, 0.8021965327446996, 0.7973159829521774, 0.7926373794105832, 0.7882045114594821, 0.7840587770699167, 0.780238817748001, 0.7767801782987072, 0.7737149942968731, 0.7710717098782813, 0.768874828208385, 0.7671446967118886, 0.7658973288552207, 0.7651442639682768, 0.7648924662741245, 0.7651442639682768, 0.7658973288552207, 0.7671446967118886, 0.768874828208385, 0.7710717098782813, 0.7737149942968731, 0.7767801782987072, 0.780238817748001, 0.7840587770699167, 0.7882045114594821, 0.7926373794105832, 0.7973159829521774, 0.8021965327446996, 0.8072332349785087, 0.8123786968298178, 0.8175843470694472, 0.8228008682871729, 0.8279786370905929, 0.8330681685630658, 0.8380205612211142, 0.8427879386980303, 0.8473238843974776, 0.8515838654085043, 0.8555256420512685, 0.8591096595302725, 0.8622994183082605, 0.8650618199780302, 0.8673674856000063, 0.8691910436890184, 0.8705113852726215, 0.871311883703632, 0.8715805771892576, 0.8713103122960792, 0.8704988470018598, 0.8691489121892404, 0.8672682308102376, 0.8648694942915152, 0.8619702960958848, 0.8585930227026641, 0.8547647026156938, 0.8505168143501489, 0.8458850546850892, 0.8409090687953263, 0.8356321441909723, 0.8301008706935303, 0.8243647689611355, 0.8184758903402869, 0.8124883910650526, 0.806458084045219, 0.8004419716805027, 0.7944977633071265, 0.7886833810243483, 0.7830564577608542, 0.7776738315232644, 0.7725910398206696, 0.767861818279697, 0.7635376074537882, 0.759667071788284, 0.7562956346297295, 0.7534650330641135, 0.7512128962352523, 0.7495723506322343, 0.7485716556449272, 0.7482338724704873, 0.7485765692131902, 0.7496115647565714, 0.7513447137028135, 0.7537757343717073, 0.7568980815326587, 0.760698865210546, 0.7651588165622814, 0.7702523014683703, 0.7759473821252219, 0.7822059265622738, 0.7889837656458495, 0.796230896771899, 0.8038917330951078, 0.811905396795038, 0.8202060545436171, 0.8287232930150356, 0.8373825319713976, 0.846105472167653, 0.8548105750496571, 0.8634135709717252, 0.8718279924366271, 0.8799657286633701, 0.8877375976178153, 0.8950539314994657, 0.9018251715657686, 0.9079624680937957, 0.9133782812288596, 0.9179869784508663, 0.9217054244021017, 0.9244535588646537, 0.9261549587514023, 0.9267373800808774, 0.9261332760425278, 0.924280287423961, 0.9211217018642829, 0.9166068786163362, 0.9106916357436837, 0.9033385969438483, 0.8945174954755267, 0.8842054329720934, 0.8723870912444328, 0.8590548955105114, 0.8442091278346617, 0.82785798991366, 0.8100176147067234, 0.7907120267697864, 0.7699730515181996, 0.7478401740035331, 0.7243603481468507, 0.6995877577199852, 0.6735835307053643, 0.6464154089914365, 0.6181573756721882, 0.5888892425134873, 0.5586962004238262, 0.5276683360205275, 0.49590011761278585, 0.463489854128439, 0.43053913069065214, 0.3971522247025207, 0.3634355064209184, 0.3294968280950048, 0.29544490580896227, 0.26138869820253113, 0.22743678624655883, 0.1936967582242079, 0.16027460401200885, 0.12727412266917387, 0.09479634722926168, 0.06293899044641543, 0.031795915080166166, 0.0014566321095814362, -0.02799416994903635, -0.05647706168500243, -0.08391827271877124, -0.11025005250805255, -0.13541098992382583, -0.15934628979028434, -0.1820080048984481, -0.20335522232748324, -0.22335420323816396, -0.24197847563694572, -0.25920887994423913, -0.2750335675342344, -0.28944795274351115, -0.30245461916926464, -0.3140631813929391, -0.3242901035690863, -0.333158476610172, -0.3406977559738031, -0.3469434623174648, -0.3519368475255801, -0.3557245288328764, -0.3583580939652234, -0.3598936803929685, -0.36039153194129014, -0.35991553612625143, -0.3585327456834126, -0.35631288782752174, -0.3533278648266559, -0.34965124949213916, -0.34535777917676797, -0.34052285183860026, -0.33522202766636633, -0.32953053967617035, -0.32352281657841975, -0.3172720210799859, -0.31084960663067285, -0.30432489544658753, -0.2977646804475011, -0.2912328535324568, -0.28479006238952065, -0.27849339779360266, -0.27239611309267037, -0.2665473773195049, -0.26099206309555883, -0.25577057021756383, -0.25091868553852725, -0.24646747947481287, -0.24244323919225963, -0.23886743824893283, -0.23575674220214934, -0.23312304942494405, -0.2309735661240498, -0.22931091430960548, -0.22813327123793586, -0.22743453863446106, -0.22720453980558747, -0.22742924256765037, -0.2280910057588164, -0.22916884695732742, -0.23063872890746465, -0.23247386205381282, -0.23464502050532815, -0.23712086869371582, -0.23986829595584744, -0.24285275625739897, -0.24603861028435603, -0.2493894671601784, -0.25286852309866603, -0.2564388943752877, -0.26006394209200473, -0.26370758632151237, -0.26733460734516085, -0.270910931843338, -0.2744039020564918, -0.27778252610764526, -0.28101770786177765, -0.2840824548920721, -0.28695206332609363, -0.28960427855471776, -0.2920194310012907, -0.29418054636622887, -0.29607342998131525, -0.29768672512648736, -0.2990119453781415, -0.30004348127027275, -0.3007785817563149, -0.30121731115886236, -0.3013624824849391, -0.30121956816470186, -0.3007965894401361, -0.30010398578613573, -0.29915446588829, -0.297962841828751, -0.2965458482428748, -0.29492194830420565, -0.2931111284732994, -0.2911346840063789, -0.28901499726267454, -0.2867753108744218, -0.28443949785082895, -0.28203183067719345, -0.2795767514429634, -0.27709864498842024, -0.27462161699942444, -0.27216927890399684, -0.2697645413343004, -0.26742941781377305, -0.2651848402128197, -0.2630504873887369, -0.2610446282877044, -0.25918398063994186, -0.2574835862249923, -0.2559567035238448, -0.2546147184097752, -0.2534670733617913, -0.2525212155149081, -0.2517825636916194, -0.2512544943903141, -0.25093834654046815, -0.25083344467257257, -0.2509371399942969, -0.25124486871460827, -0.25175022681564196, -0.25244506033920827, -0.2533195701319156, -0.2543624298809431, -0.25556091617232307, -0.2569010492159092, -0.25836774280661434, -0.2599449620304671, -0.2616158871769471, -0.2633630822860826, -0.2651686667401395, -0.26701448830526, -0.2688822960380859, -0.2707539114959101, -0.27261139672586765, -0.2744372175586276, -0.27621440079438936, -0.27792668394298775, -0.2795586562648235, -0.2810958899542609, -0.2825250604110993, -0.2838340546577618, -0.28501206707880666, -0.2860496817841665, -0.2869389410269886, -0.2876733992398657, -0.28824816238840323, -0.2886599124772923, -0.2889069171800374, -0.28898902469812765, -0.2889076440875012, -0.28866571141848024, -0.2882676422589426, -0.2877192710881692, -0.28702777835972065, -0.2862016060348372, -0.285250362502454, -0.28418471788720057, -0.28301629082207536, -0.2817575278272566, -0.2804215764903068, -0.2790221536854588, -0.2775734101004823, -0.2760897923586871, -0.2745859040308507, -0.2730763668273479, -0.27157568324464604, -0.27009810191290107, -0.2686574868529789, -0.2672671918023332, -0.26593994071027727, -0.2646877154349925, -0.2635216515977679, -0.26245194346526807, -0.2614877586389235, -0.2606371632326959, -0.25990705811745535, -0.2593031267029822, -0.2588297946181825, -0.25849020153750285, -0.2582861852877944, -0.25821827825602073, -0.25828571600528843, -0.2584864578956903, -0.2588172193983662, -0.2592735156869917, -0.2598497159914574, -0.2605391081046998, -0.2613339723462494, -0.2622256642058386, -0.26320470481799974, -0.26426087835456913, -0.265383335366933, -0.26656070106406615, -0.26778118747633256, -0.26903270842881866, -0.27030299623184445, -0.27157971899029015, -0.27285059743746926, -0.2741035202133295, -0.27532665653058375, -0.27650856520565104, -0.2776382990736474, -0.2787055038576534, -0.2797005106215602, -0.28061442100235, -0.2814391844910603, -0.2821676671111586, -0.28279371092786143, -0.28331218391126567, -0.28371901976913927, -0.2840112474610069, -0.2841870102028341, -0.28424557387027305, -0.2841873248071915, -0.2840137571441127, -0.2837274498274347, -0.2833320336539222, -0.2828321486952174, -0.28223339258312297, -0.28154226020749973, -0.2807660754539824, -0.27991291567781534, -0.2789915296722603, -0.2780112499447719, -0.27698190016100493, -0.2759136986553241, -0.27481715893655206, -0.2737029881389958, -0.27258198438119036, -0.2714649339982291, -0.27036250960808855, -0.2692851699580281, -0.26824306247422747, -0.26724592940652114, -0.26630301842076515, -0.26542299844444245, -0.26461388151704834, -0.2638829513361267, -0.2632366991231897, -0.26268076736174334, -0.2622199018829907, -0.2618579126942203, -0.2615976438611445, -0.2614409526693724, -0.26138869820253113, -0.261440739386149, -0.26159594245806844, -0.26185219773870716, -0.262206445488704, -0.2626547105581707, -0.2631921454516646, -0.2638130813568253, -0.26451108661306044, -0.26527903203034714, -0.2661091624077197, -0.26699317354689583, -0.26792229400915646, -0.2688873708235103, -0.26987895832161224, -0.2708874092501734, -0.27190296729485947, -0.2729158601410394, -0.27391639219628844, -0.27489503610715965, -0.27584252221842065, -0.2767499251463785, -0.2776087466689865, -0.27841099417367826, -0.27914925394902484, -0.27981675865785505, -0.2804074483869221, -0.28091602473100163, -0.2813379974368336, -0.2816697232039718, -0.28190843631462487, -0.28205227084236784, -0.28210027426931344, -0.28205241242231666, -0.2819095657202341, -0.2816735168054405, -0.28134692971294484, -0.28093332080885897, -0.2804370218058688, -0.27986313523610773, -0.27921748283074943, -0.27850654732008473, -0.27773740822729676, -0.27691767228299374, -0.27605539913540145, -0.2751590230724856, -0.27423727150682214, -0.27329908100147154, -0.27235351163522475, -0.27140966051817017, -0.270476575273549, -0.2695631682992703, -0.26867813261227386, -0.26782986006135256, -0.2670263626691774, -0.2662751978324094, -0.2655833980702716, -0.2649574059670993, -0.26440301490369655, -0.2639253161162575, -0.26352865256066565, -0.26321657999484177, -0.26299183562294537, -0.2628563145734409, -0.26281105440887903, -0.26285622778948275, -0.26299114333591517, -0.26321425465974235, -0.26352317745366666, -0.2639147144584611, -0.2643848880502597, -0.2649289801211486, -0.2655415788585812, -0.2662166319655027, -0.2669475058039154, -0.2677270498903984, -0.26854766612334907, -0.26940138207886, -0.2702799276755973, -0.2711748144790603, -0.2720774168925443, -0.2729790544660979, -0.27387107454595644, -0.2747449344854032, -0.27559228264373586, -0.2764050374129473, -0.27717546353173905, -0.2778962449733557, -0.2785605537272025, -0.27916211383398165, -0.2796952600797326, -0.280154990805307, -0.2805370143439263, -0.280837788660025, -0.2810545538270648, -0.2811853570497153, -0.2812290700061907, -0.2811853983588929, -0.28105488335519274, -0.2808388955144794, -0.2805396204718511, -0.28016003712229043, -0.2797038882812195, -0.27917564414726037, -0.27858045892020705, -0.2779241209910266, -0.27721299718054215, -0.2764539715587848, -0.27565437942729754, -0.2748219370914828, -0.27396466808900194, -0.2730908265728848, -0.2722088185741122, -0.2713271218877348, -0.27045420533894815, -0.269598448190787, -0.2687680604532756, -0.26797100484489905, -0.26721492114130996, -0.2665070536233606, -0.2658541823070995, -0.2652625586025394, -0.2647378460061547, -0.2642850663845742, -0.26390855235426874, -0.26361190620462716, -0.26339796575029834, -0.2632687774335118, -0.2632255769289794, -0.2632687774335119, -0.2633979657502983, -0.2636119062046271, -0.2639085523542687, -0.26428506638457444, -0.26473784600615474, -0.26526255860253933, -0.2658541823070993, -0.2665070536233603, -0.2672149211413103, -0.26797100484489944, -0.26876806045327556, -0.2695984481907868, -0.2704542053389482, -0.271327121887735, -0.2722088185741122, -0.27309082657288497, -0.27396466808900183, -0.2748219370914826, -0.2756543794272973, -0.27645397155878493, -0.2772129971805422, -0.27792412099102637, -0.27858045892020716, -0.27917564414726, -0.2797038882812191, -0.28016003712229043, -0.2805396204718513, -0.2808388955144795, -0.2810548833551926, -0.2811853983588929, -0.28122907000619096, -0.2811853570497157, -0.281054553827065, -0.280837788660025, -0.280537014343926, -0.2801549908053073, -0.2796952600797327, -0.2791621138339818, -0.2785605537272025, -0.2778962449733554, -0.27717546353173955, -0.27640503741294725, -0.27559228264373625, -0.27474493448540327, -0.27387107454595616, -0.27297905446609766, -0.27207741689254394, -0.27117481447906033, -0.2702799276755976, -0.26940138207886005, -0.26854766612334885, -0.2677270498903989, -0.2669475058039154, -0.266216631965503, -0.26554157885858115, -0.26492898012114885, -0.26438488805025945, -0.26391471445846115, -0.2635231774536666, -0.26321425465974274, -0.26299114333591517, -0.26285622778948237, -0.2628110544088796, -0.26285631457344066, -0.2629918356229458, -0.263216579994842, -0.26352865256066554, -0.26392531611625714, -0.2644030149036971, -0.26495740596709916, -0.26558339807027165, -0.26627519783240955, -0.2670263626691772, -0.26782986006135273, -0.2686781326122738, -0.2695631682992703, -0.270476575273549, -0.2714096605181695, -0.2723535116352247, -0.2732990810014713, -0.2742372715068217, -0.27515902307248585, -0.2760553991354012, -0.27691767228299374, -0.2777374082272969, -0.2785065473200847, -0.2792174828307495, -0.2798631352361077, -0.28043702180586916, -0.28093332080885913, -0.2813469297129452, -0.28167351680544045, -0.28190956572023423, -0.28205241242231693, -0.2821002742693136, -0.2820522708423678, -0.2819084363146246, -0.2816697232039716, -0.2813379974368341, -0.28091602473100086, -0.28040744838692233, -0.27981675865785527, -0.27914925394902473, -0.2784109941736782, -0.27760874666898633, -0.2767499251463786, -0.27584252221842065, -0.27489503610716026, -0.2739163921962881, -0.2729158601410392, -0.2719029672948597, -0.2708874092501738, -0.26987895832161196, -0.26888737082351044, -0.26792229400915646, -0.2669931735468959, -0.2661091624077197, -0.26527903203034703, -0.26451108661306066, -0.2638130813568253, -0.26319214545166497, -0.26265471055817075, -0.26220644548870375, -0.2618521977387071, -0.26159594245806894, -0.26144073938614937, -0.2613886982025313, -0.2614409526693719, -0.26159764386114426, -0.2618579126942207, -0.2622199018829908, -0.2626807673617429, -0.2632366991231897, -0.2638829513361265, -0.26461388151704784, -0.2654229984444426, -0.2663030184207653, -0.267245929406521, -0.26824306247422725, -0.26928516995802804, -0.27036250960808855, -0.271464933998229, -0.2725819843811902, -0.27370298813899613, -0.2748171589365518, -0.2759136986553244, -0.27698190016100516, -0.27801124994477183, -0.2789915296722604, -0.2799129156778155, -0.2807660754539825, -0.2815422602074991, -0.2822333925831231, -0.28283214869521756, -0.2833320336539222, -0.2837274498274346, -0.2840137571441129, -0.2841873248071914, -0.284245573870273, -0.2841870102028338, -0.2840112474610073, -0.2837190197691393, -0.28331218391126595, -0.2827937109278613, -0.28216766711115837, -0.28143918449106065, -0.2806144210023501, -0.2797005106215605, -0.27870550385765336, -0.277638299073647, -0.2765085652056514, -0.27532665653058447, -0.27410352021333, -0.2728505974374693, -0.2715797189902899, -0.2703029962318451, -0.2690327084288183, -0.2677811874763329, -0.26656070106406615, -0.26538333536693276, -0.2642608783545687, -0.26320470481799946, -0.2622256642058392, -0.2613339723462495, -0.2605391081047, -0.25984971599145745, -0.25927351568699136, -0.2588172193983667, -0.2584864578956907, -0.2582857160052887, -0.2582182782560203, -0.2582861852877941, -0.2584902015375031, -0.2588297946181825, -0.25930312670298256, -0.2599070581174554, -0.2606371632326957, -0.26148775863892365, -0.2624519434652679, -0.26352165159776797, -0.2646877154349925, -0.2659399407102772, -0.26726719180233327, -0.26865748685297897, -0.27009810191290096, -0.27157568324464615, -0.27307636682734754, -0.2745859040308508, -0.276089792358687, -0.27757341010048237, -0.2790221536854589, -0.2804215764903066, -0.28175752782725666, -0.2830162908220756, -0.2841847178872002, -0.28525036250245367, -0.28620160603483735, -0.2870277783597208, -0.28771927108816936, -0.2882676422589424, -0.2886657114184804, -0.28890764408750097, -0.28898902469812815, -0.28890691718003675, -0.28865991247729217, -0.2882481623884035, -0.2876733992398657, -0.2869389410269891, -0.28604968178416623, -0.28501206707880633, -0.2838340546577623, -0.2825250604110986, -0.28109588995426116, -0.27955865626482357, -0.2779266839429877, -0.2762144007943897, -0.2744372175586277, -0.27261139672586804, -0.27075391149591027, -0.26888229603808633, -0.26701448830526014, -0.26516866674013967, -0.2633630822860826, -0.2616158871769475, -0.2599449620304675, -0.25836774280661406, -0.25690104921590895, -0.25556091617232285, -0.25436242988094326, -0.2533195701319159, -0.25244506033920794, -0.2517502268156415, -0.25124486871460805, -0.2509371399942973, -0.2508334446725728, -0.25093834654046804, -0.25125449439031405, -0.2517825636916192, -0.2525212155149086, -0.2534670733617915, -0.25461471840977534, -0.25595670352384486, -0.2574835862249918, -0.25918398063994214, -0.26104462828770403, -0.26305048738873704, -0.2651848402128191, -0.2674294178137732, -0.26976454133430033, -0.27216927890399606, -0.2746216169994241, -0.2770986449884202, -0.27957675144296334, -0.2820318306771935, -0.28443949785082834, -0.2867753108744225, -0.2890149972626743, -0.2911346840063786, -0.2931111284732998, -0.29492194830420543, -0.29654584824287494, -0.29796284182875077, -0.29915446588828964, -0.30010398578613534, -0.30079658944013626, -0.3012195681647018, -0.3013624824849389, -0.3012173111588621, -0.3007785817563148, -0.30004348127027297, -0.29901194537814213, -0.2976867251264874, -0.29607342998131536, -0.29418054636622804, -0.29201943100129074, -0.28960427855471854, -0.286952063326094, -0.2840824548920723, -0.2810177078617774, -0.2777825261076454, -0.2744039020564919, -0.2709109318433383, -0.2673346073451608, -0.2637075863215125, -0.26006394209200434, -0.25643889437528794, -0.2528685230986668, -0.2493894671601785, -0.24603861028435622, -0.24285275625739858, -0.23986829595584802, -0.23712086869371551, -0.23464502050532862, -0.23247386205381337, -0.23063872890746492, -0.22916884695732698, -0.2280910057588164, -0.22742924256765068, -0.2272045398055873, -0.22743453863446103, -0.2281332712379354, -0.22931091430960518, -0.2309735661240501, -0.23312304942494436, -0.2357567422021489, -0.2388674382489321, -0.24244323919225982, -0.24646747947481248, -0.25091868553852725, -0.2557705702175639, -0.26099206309555883, -0.26654737731950434, -0.27239611309267, -0.27849339779360316, -0.2847900623895203, -0.2912328535324564, -0.29776468044750076, -0.30432489544658714, -0.31084960663067274, -0.3172720210799854, -0.3235228165784199, -0.32953053967617024, -0.33522202766636583, -0.3405228518386003, -0.3453577791767676, -0.349651249492139, -0.3533278648266555, -0.35631288782752146, -0.35853274568341204, -0.3599155361262514, -0.36039153194129014, -0.35989368039296854, -0.3583580939652236, -0.3557245288328767, -0.35193684752558, -0.3469434623174652, -0.34069775597380314, -0.3331584766101725, -0.32429010356908716, -0.31406318139293904, -0.30245461916926525, -0.28944795274351215, -0.27503356753423397, -0.25920887994423847, -0.24197847563694666, -0.22335420323816585, -0.20335522232748218, -0.18200800489844887, -0.1593462897902862, -0.13541098992382863, -0.1102500525080522, -0.08391827271877245, -0.05647706168500456, -0.027994169949035596, 0.0014566321095811042, 0.03179591508016487, 0.06293899044641339, 0.09479634722926154, 0.12727412266917246, 0.16027460401200602, 0.19369675822420485, 0.22743678624655883, ], [0.2508334446725725, 0.26786839333982165, 0.28494233627350324, 0.30204198606957905, 0.3191539705333332, 0.3362648505125369, 0.353361137908446, 0.37042931383726496, 0.3874558469143624, 0.40442721163321965, 0.4213299068108337, 0.43815047407107466, 0.454875516337339, 0.4714917163057091, 0.48798585486975804, 0.5043448294681148, 0.5205556723259129, 0.5366055685613299, 0.5524818741285212, 0.5681721335684293, 0.5836640975391518, 0.5989457400978072, 0.6140052757061452, 0.628831175932499, 0.6434121858230671, 0.6577373399159593, 0.6717959778719313, 0.68557775969625, 0.6990726805267221, 0.7122710849635276, 0.7251636809171524, 0.7377415529514263, 0.7499961750994041, 0.7619194231306007, 0.7735035862489186, 0.7847413782014425, 0.7956259477791708, 0.8061508886916694, 0.8163102487985807, 0.8260985386819086, 0.8355107395440028, 0.8445423104172074, 0.8531891946721974, 0.8614478258131179, 0.8693151325487413, 0.8767885431299982, 0.8838659889453697, 0.8905459073668065, 0.8968272438400087, 0.9027094532140956, 0.9081925003068957, 0.9132768597032997, 0.9179635147853304, 0.9222539559938138, 0.9261501783227588, 0.9296546780487716, 0.9327704486990657, 0.9355009762628476, 0.9378502336520664, 0.939822674418736, 0.9414232257372314, 0.9426572806611462, 0.9435306896654877, 0.9440497514861229, 0.9442212032695515, 0.9440522100471966, 0.9435503535495051, 0.9427236203762326, 0.941580389540339, 0.9401294194039531, 0.9383798340258631, 0.936341108940954, 0.934023056392962, 0.9314358100428156, 0.9285898091756996, 0.9254957824308202, 0.9221647310786437, 0.9186079118711349, 0.914836819491244, 0.9108631686285663, 0.9066988757087246, 0.9023560403046288, 0.8978469262582917, 0.8931839425423982, 0.8883796238912663, 0.8834466112312371, 0.8783976319409104, 0.8732454799719196, 0.8680029958612226, 0.8626830466660811, 0.8572985058530601, 0.8518622331724914, 0.8463870545498956, 0.840885742025868, 0.8353709937758828, 0.8298554142413815, 0.8243514944033515, 0.818871592229422, 0.8134279133252309, 0.8080324918205444, 0.8026971715202434, 0.797433587349901, 0.7922531471252309, 0.7871670136741921, 0.7821860873399965, 0.7773209888926722, 0.7725820428762161, 0.7679792614176845, 0.7635223285238552, 0.7592205848903316, 0.755083013247164, 0.7511182242642116, 0.7473344430386027, 0.7437394961857252, 0.7403407995542352, 0.7371453465845814, 0.7341596973295356, 0.7313899681541627, 0.7288418221315976, 0.7265204601498899, 0.7244306127440502, 0.722576532666285, 0.720961988206232, 0.7195902572718175, 0.7184641222401557, 0.7175858655866708, 0.7169572662994056, 0.7165795970842149, 0.716453622365293, 0.7165795970842149, 0.7169572662994056, 0.7175858655866708, 0.7184641222401557, 0.7195902572718175, 0.720961988206232, 0.722576532666285, 0.7244306127440502, 0.7265204601498899, 0.7288418221315976, 0.7313899681541627, 0.7341596973295356, 0.7371453465845814, 0.7403407995542352, 0.7437394961857252, 0.7473344430386027, 0.7511182242642116, 0.755083013247164, 0.7592205848903316, 0.7635223285238552, 0.7679792614176845, 0.7725820428762161, 0.7773209888926722, 0.7821860873399965, 0.7871670136741921, 0.7922531471252309, 0.797433587349901, 0.8026971715202434, 0.8080324918205444, 0.8134279133252309, 0.818871592229422, 0.8243514944033515, 0.8298554142413815, 0.8353709937758828, 0.8408857420258679, 0.8463870545498959, 0.8518622331724913, 0.8572985058530602, 0.8626830466660813, 0.8680029958612226, 0.8732454799719196, 0.8783976319409104, 0.8834466112312371, 0.8883796238912663, 0.8931839425423982, 0.8978469262582915, 0.9023560403046288, 0.9066988757087246, 0.9108631686285664, 0.914836819491244, 0.9186079118711349, 0.9221647310786437, 0.9254957824308202, 0.9285898091756996, 0.9314358100428155, 0.934023056392962, 0.9363411089409539, 0.9383798340258632, 0.9401294194039532, 0.941580389540339, 0.9427236203762326, 0.9435503535495053, 0.9440522100471966, 0.9442212032695515, 0.9440497514861229, 0.9435306896654877, 0.9426572806611462, 0.9414232257372311, 0.9398226744187362, 0.9378502336520664, 0.9355009762628475, 0.9327704486990657, 0.9296546780487713, 0.9261501783227588, 0.9222539559938138, 0.9179635147853304, 0.9132768597033, 0.9081925003068958, 0.9027094532140958, 0.8968272438400088, 0.8905459073668066, 0.8838659889453697, 0.8767885431299981, 0.8693151325487413, 0.8614478258131179, 0.8531891946721974, 0.8445423104172075, 0.8355107395440028, 0.8260985386819086, 0.816310248798581, 0.8061508886916694, 0.7956259477791707, 0.7847413782014424, 0.7735035862489185, 0.7619194231306007, 0.7499961750994041, 0.7377415529514263, 0.7251636809171524, 0.7122710849635279, 0.6990726805267222, 0.6855777596962501, 0.6717959778719312, 0.6577373399159593, 0.643412185823067, 0.628831175932499, 0.6140052757061452, 0.5989457400978072, 0.5836640975391518, 0.5681721335684294, 0.5524818741285213, 0.5366055685613302, 0.5205556723259134, 0.5043448294681145, 0.4879858548697579, 0.4714917163057089, 0.454875516337339, 0.43815047407107466, 0.4213299068108337, 0.40442721163321976, 0.38745584691436247, 0.3704293138372652, 0.35336113790844625, 0.33626485051253663, 0.31915397053333305, 0.302041986069579, 0.28494233627350307, 0.26786839333982154, 0.2508334446725725, 0.2338506752567483, 0.21693315026126198, 0.2000937978992992, 0.18334539257158922, 0.16670053831756954, 0.1501716525988152, 0.13377095043847284, 0.11751042893975405, 0.10140185220585093, 0.0854567366828732, 0.06968633694664438, 0.054101631953379255, 0.038713311773428294, 0.0235317648264099, 0.008567065635157564, -0.006171036885011536, -0.020673130586136432, -0.03493015068196502, -0.04893338976319328, -0.06267450722098011, -0.07614553806714164, -0.08933890114046125, -0.1022474066896018, -0.11486426332416493, -0.1271830843265096, -0.1391978933180183, -0.15090312927458255, -0.16229365088716177, -0.1733647402643631, -0.18411210597506983, -0.19453188543023872, -0.20462064660406928, -0.21437538909582132, -0.22379354453463063, -0.23287297633073614, -0.24161197877757992, -0.25000927551027896, -0.2580640173269978, -0.2657757793807547, -0.2731445577501861, -0.28017076539876967, -0.28685522753295195, -0.2931991763705641, -0.29920424533180584, -0.30487246266596507, -0.31020624452789175, -0.31520838751907304, -0.3198820607089472, -0.32423079715287145, -0.3282584849238834, -0.33196935767610253, -0.33536798475828894, -0.3384592608967035, -0.341248395467019, -0.34374090137559016, -0.3459425835709103, -0.34785952720657676, -0.34949808547752753, -0.35086486715172116, -0.35196672381980243, -0.3528107368856198, -0.3534042043207487, -0.3537546272064242, -0.35386969608648455, -0.3537572771550948, -0.3534253983031419, -0.35288223504726635, -0.3521360963655439, -0.35119541046381814, -0.35006871049664773, -0.34876462026674376, -0.34729183992665114, -0.34565913170625323, -0.34387530568949154, -0.3419492056634245, -0.3398896950624909, -0.33770564303050504, -0.3354059106225618, -0.3329993371686366, -0.3304947268202337, -0.3279008353009705, -0.3252263568814985, -0.32247991159862216, -0.3196700327379163, -0.31680515459856956, -0.3138936005585314, -0.3109435714574238, -0.3079631343139862, -0.3049602113941258, -0.3019425696449326, -0.29891781050926, -0.29589336013471673, -0.2928764599901242, -0.2898741579016945, -0.2868932995203575, -0.2839405202308433, -0.2810222375122611, -0.2781446437590717, -0.2753136995704738, -0.27253512751533804, -0.2698144063789567, -0.26715676589696397, -0.2645671819809008, -0.26205037243899737, -0.25961079319484914, -0.2572526350057608, -0.2549798206816509, -0.25279600280450626, -0.25070456194749724, -0.24870860539199702, -0.24681096633986094, -0.2450142036174869, -0.24332060186731377, -0.24173217222159574, -0.24025065345246724, -0.2388775135915116, -0.2376139520112658, -0.23646090196032918, -0.23541903354299493, -0.234488757133603, -0.23367022721511413, -0.232963346630722, -0.23236777123667357, -0.23188291494383628, -0.23150795513495415, -0.23124183844396065, -0.2310832868831742, -0.2310308043036853, -0.2310826831737634, -0.23123701165965613, -0.23149168099273187, -0.23184439310652957, -0.23229266852692002, -0.23283385449826, -0.23346513332813307, -0.23418353093300848, -0.2349859255669358, -0.2358690567151961, -0.23682953413468646, -0.23786384702268654, -0.23896837329558065, -0.24013938895905274, -0.24137307755125892, -0.24266553964050408, -0.24401280235899733, -0.2454108289543516, -0.24685552834061317, -0.24834276463075855, -0.24986836663278184, -0.25142813729171926, -0.25301786306019564, -0.2546333231803714, -0.25627029886046826, -0.25792458232939336, -0.2595919857533552, -0.26126834999875453, -0.26294955322605307, -0.2646315192997833, -0.26631022600032006, -0.2679817130235409, -0.26964208975502346, -0.2712875428059569, -0.27291434329852227, -0.27451885388906516, -0.27609753551798477, -0.27764695387588895, -0.27916378557618066, -0.28064482402489865, -0.2820869849792929, -0.28348731178727693, -0.28484298030059085, -0.2861513034551927, -0.2874097355130947, -0.2886158759605609, -0.2897674730583037, -0.2908624270400086, -0.2918987929562529, -0.29287478316158355, -0.29378876944323895, -0.294639284790717, -0.2954250248060929, -0.29614484875569896, -0.2967977802644832, -0.29738300765504033, -0.29789988393401096, -0.29834792642920227, -0.29872681608145313, -0.2990363963959194, -0.299276672058083, -0.2994478072204196, -0.29955012346626236, -0.2995840974579878, -0.2995503582772223, -0.2994496844653276, -0.29928300077294395, -0.2990513746278951, -0.2987560123312435, -0.29839825499175543, -0.29797957420947874, -0.297501567519563, -0.2969659536078422, -0.29637456731007655, -0.29572935440709597, -0.29503236622840395, -0.2942857540770982, -0.2934917634892239, -0.29265272834092343, -0.29177106481694, -0.29084926525423554, -0.289889891874615, -0.28889557042039277, -0.28786898370721653, -0.28681286510824666, -0.2857299919839145, -0.2846231790715021, -0.2834952718487635, -0.2823491398857605, -0.2811876701990174, -0.2800137606219824, -0.27883031320567325, -0.2776402276632089, -0.27644639487175865, -0.2752516904452236, -0.2740589683907307, -0.27287105486176555, -0.2716907420204749, -0.27052078202137325, -0.2693638811283486, -0.2682226939765149, -0.26709981799007715, -0.2659977879669936, -0.26491907084078686, -0.26386606062943996, -0.26284107358085246, -0.2618463435238652, -0.26088401743338424, -0.2599561512176321, -0.2590647057350424, -0.25821154304779437, -0.2573984229184465, -0.25662699955557694, -0.2558988186137978, -0.2552153144529293, -0.2545778076605652, -0.2539875028416816, -0.25344548667835204, -0.2529527262620652, -0.2525100677005452, -0.2521182350003847, -0.2517778292262342, -0.25148932793668244, -0.25125308489639664, -0.2510693300635141, -0.2509381698506898, -0.2508595876576555, -0.2508334446725725, -0.2508594809389145, -0.2509373166840753, -0.2510664539053643, -0.25124627820852585, -0.2514760608934239, -0.2517549612810272, -0.2520820292753534, -0.2524562081535713, -0.25287633757700534, -0.253341156815355, -0.25384930817603746, -0.25439934063015296, -0.254989713626208, -0.2556188010823699, -0.256284895547695, -0.25698621252245535, -0.2577208949273991, -0.2584870177115138, -0.25928259258760367, -0.2601055728847873, -0.26095385850680025, -0.2618253009848314, -0.2627177086134572, -0.2636288516581152, -0.2645564676224561, -0.2654982665638356, -0.26645193644515264, -0.26741514851121145, -0.26838556267779135, -0.2693608329216078, -0.27033861265942216, -0.27131656010460375, -0.2722923435895556, -0.273263646842529, -0.2742281742074973, -0.2751836557959176, -0.2761278525594079, -0.2770585612725656, -0.27797361941539306, -0.2788709099450533, -0.27974836594693564, -0.280603975155331, -0.281435784334307, -0.28224190350971884, -0.2830205100436401, -0.28376985254286263, -0.28448825459349786, -0.28517411831412137, -0.2858259277203008, -0.28644225189378825, -0.2870217479500991, -0.2875631637986372, -0.2880653406900141, -0.2885272155456632, -0.28894782306533895, -0.289326297608592, -0.2896618748467921, -0.28995389318278336, -0.2902017949357714, -0.29040512728953355, -0.2905635430025822, -0.29067680087941145, -0.29074476600247634, -0.2907674097250789, -0.29074480942583925, -0.2906771480259437, -0.2905647132708831, -0.29040789677887563, -0.29020719285868174, -0.28996319710001334, -0.28967660474019896, -0.2893482088112705, -0.2889788980720791, -0.288569654730509, -0.2881215519613027, -0.2876357512254357, -0.28711349939739084, -0.2865561257070988, -0.2859650385036768, -0.2853417218484827, -0.28468773194535496, -0.284004693416232, -0.283294295430682, -0.28255828769815705, -0.28179847633207683, -0.281016719595102, -0.2802149235352028, -0.2793950375223378, -0.27855904969577466, -0.27770898233223884, -0.2768468871452465, -0.2759748405261141, -0.27509493873722146, -0.27420929306823183, -0.27332002496600033, -0.2724292611489674, -0.271539128716841, -0.27065175026637556, -0.26976923902401767, -0.2688936940061511, -0.2680271952175892, -0.2671717988988735, -0.2663295328328233, -0.2655023917206276, -0.26469233263762776, -0.263901270578736, -0.26313107410325154, -0.2623835610885902, -0.26166049460222107, -0.2609635789008154, -0.2602944555653532, -0.2596546997806115, -0.2590458167671524, -0.25846923837358665, -0.25792631983653336, -0.2574183367153378, -0.2569464820082104, -0.2565118634560723, -0.2561155010399591, -0.2557583246774387, -0.2554411721230378, -0.25516478707724977, -0.25492981750822397, -0.2547368141897898, -0.25458622945898457, -0.25447841619578865, -0.25441362702727743, -0.25439201375792253, -0.2544136270272773, -0.25447841619578876, -0.2545862294589846, -0.2547368141897898, -0.254929817508224, -0.2551647870772497, -0.25544117212303774, -0.25575832467743853, -0.25611550103995895, -0.2565118634560724, -0.2569464820082107, -0.2574183367153375, -0.2579263198365335, -0.2584692383735866, -0.25904581676715244, -0.2596546997806115, -0.2602944555653533, -0.2609635789008155, -0.2616604946022209, -0.2623835610885901, -0.26313107410325154, -0.2639012705787363, -0.26469233263762776, -0.2655023917206276, -0.26632953283282296, -0.2671717988988732, -0.26802719521758905, -0.26889369400615126, -0.26976923902401756, -0.27065175026637533, -0.27153912871684094, -0.27242926114896754, -0.2733200249660006, -0.2742092930682317, -0.27509493873722146, -0.2759748405261137, -0.2768468871452467, -0.27770898233223856, -0.2785590496957749, -0.2793950375223378, -0.28021492353520244, -0.2810167195951023, -0.2817984763320767, -0.2825582876981574, -0.28329429543068196, -0.2840046934162319, -0.28468773194535457, -0.28534172184848244, -0.28596503850367677, -0.28655612570709915, -0.28711349939739084, -0.2876357512254355, -0.2881215519613028, -0.288569654730509, -0.2889788980720792, -0.2893482088112706, -0.2896766047401988, -0.28996319710001317, -0.29020719285868185, -0.29040789677887546, -0.29056471327088346, -0.2906771480259436, -0.290744809425839, -0.29076740972507936, -0.2907447660024763, -0.29067680087941156, -0.2905635430025821, -0.2904051272895336, -0.29020179493577136, -0.28995389318278353, -0.28966187484679207, -0.2893262976085922, -0.2889478230653391, -0.2885272155456631, -0.2880653406900142, -0.2875631637986372, -0.28702174795009916, -0.2864422518937886, -0.2858259277203004, -0.28517411831412126, -0.28448825459349797, -0.28376985254286274, -0.2830205100436403, -0.2822419035097187, -0.281435784334307, -0.280603975155331, -0.27974836594693564, -0.27887090994505354, -0.27797361941539317, -0.2770585612725657, -0.27612785255940786, -0.27518365579591775, -0.27422817420749734, -0.2732636468425292, -0.2722923435895558, -0.2713165601046038, -0.27033861265942205, -0.2693608329216076, -0.26838556267779135, -0.26741514851121184, -0.26645193644515225, -0.2654982665638356, -0.2645564676224562, -0.2636288516581153, -0.2627177086134574, -0.2618253009848313, -0.26095385850680036, -0.26010557288478736, -0.2592825925876039, -0.2584870177115138, -0.2577208949273991, -0.25698621252245535, -0.256284895547695, -0.25561880108236995, -0.2549897136262081, -0.2543993406301529, -0.25384930817603757, -0.25334115681535496, -0.2528763375770053, -0.2524562081535714, -0.2520820292753532, -0.25175496128102726, -0.2514760608934239, -0.251246278208526, -0.2510664539053643, -0.25093731668407543, -0.25085948093891447, -0.2508334446725726, -0.2508595876576556, -0.25093816985068973, -0.2510693300635141, -0.25125308489639653, -0.25148932793668227, -0.2517778292262343, -0.2521182350003847, -0.25251006770054507, -0.2529527262620652, -0.2534454866783519, -0.2539875028416815, -0.2545778076605653, -0.2552153144529292, -0.2558988186137979, -0.25662699955557683, -0.25739842291844617, -0.25821154304779453, -0.2590647057350424, -0.25995615121763227, -0.26088401743338435, -0.26184634352386504, -0.26284107358085257, -0.26386606062943996, -0.264919070840787, -0.2659977879669933, -0.2670998179900769, -0.2682226939765149, -0.2693638811283487, -0.27052078202137336, -0.27169074202047483, -0.27287105486176527, -0.2740589683907307, -0.27525169044522335, -0.2764463948717588, -0.27764022766320895, -0.2788303132056733, -0.28001376062198235, -0.28118767019901714, -0.2823491398857608, -0.2834952718487633, -0.28462317907150214, -0.28572999198391436, -0.2868128651082466, -0.2878689837072167, -0.28889557042039277, -0.28988989187461545, -0.29084926525423577, -0.2917710648169399, -0.29265272834092354, -0.29349176348922384, -0.2942857540770982, -0.2950323662284037, -0.29572935440709575, -0.2963745673100765, -0.2969659536078421, -0.2975015675195633, -0.29797957420947896, -0.29839825499175543, -0.2987560123312435, -0.29905137462789483, -0.2992830007729442, -0.2994496844653277, -0.29955035827722226, -0.29958409745798764, -0.29955012346626236, -0.29944780722041975, -0.29927667205808317, -0.2990363963959197, -0.29872681608145313, -0.2983479264292021, -0.2978998839340112, -0.2973830076550402, -0.2967977802644834, -0.2961448487556991, -0.2954250248060928, -0.294639284790717, -0.29378876944323895, -0.29287478316158383, -0.29189879295625315, -0.2908624270400085, -0.2897674730583036, -0.28861587596056104, -0.2874097355130946, -0.2861513034551931, -0.28484298030059085, -0.28348731178727693, -0.2820869849792929, -0.28064482402489876, -0.27916378557618093, -0.27764695387588917, -0.2760975355179849, -0.274518853889065, -0.27291434329852254, -0.27128754280595674, -0.2696420897550236, -0.26798171302354135, -0.2663102260003199, -0.26463151929978307, -0.2629495532260532, -0.26126834999875465, -0.25959198575335557, -0.2579245823293932, -0.25627029886046815, -0.2546333231803718, -0.2530178630601955, -0.25142813729171953, -0.24986836663278197, -0.24834276463075844, -0.24685552834061325, -0.2454108289543516, -0.24401280235899767, -0.24266553964050408, -0.2413730775512592, -0.24013938895905274, -0.2389683732955808, -0.23786384702268662, -0.23682953413468655, -0.2358690567151962, -0.23498592556693573, -0.23418353093300842, -0.23346513332813315, -0.23283385449826, -0.2322926685269201, -0.23184439310652954, -0.23149168099273176, -0.2312370116596562, -0.2310826831737634, -0.2310308043036854, -0.23108328688317398, -0.23124183844396093, -0.23150795513495417, -0.2318829149438363, -0.23236777123667357, -0.2329633466307219, -0.2336702272151141, -0.234488757133603, -0.23541903354299493, -0.2364609019603291, -0.23761395201126564, -0.23887751359151158, -0.24025065345246718, -0.24173217222159582, -0.24332060186731355, -0.24501420361748671, -0.246810966339861, -0.24870860539199682, -0.25070456194749724, -0.25279600280450587, -0.25497982068165126, -0.2572526350057605, -0.25961079319484875, -0.26205037243899765, -0.2645671819809008, -0.26715676589696397, -0.2698144063789564, -0.2725351275153379, -0.2753136995704737, -0.2781446437590718, -0.2810222375122607, -0.28394052023084315, -0.2868932995203573, -0.28987415790169424, -0.2928764599901243, -0.2958933601347169, -0.2989178105092597, -0.30194256964493227, -0.30496021139412566, -0.3079631343139861, -0.310943571457424, -0.31389360055853077, -0.3168051545985697, -0.3196700327379159, -0.32247991159862166, -0.3252263568814985, -0.32790083530097053, -0.3304947268202337, -0.3329993371686361, -0.3354059106225616, -0.3377056430305052, -0.33988969506249106, -0.3419492056634244, -0.3438753056894914, -0.34565913170625306, -0.3472918399266506, -0.34876462026674393, -0.35006871049664795, -0.35119541046381797, -0.3521360963655437, -0.35288223504726596, -0.35342539830314196, -0.35375727715509514, -0.35386969608648433, -0.3537546272064243, -0.35340420432074837, -0.3528107368856199, -0.3519667238198025, -0.3508648671517213, -0.34949808547752775, -0.34785952720657665, -0.3459425835709102, -0.34374090137558994, -0.34124839546701946, -0.33845926089670353, -0.33536798475828905, -0.3319693576761025, -0.3282584849238836, -0.3242307971528716, -0.31988206070894765, -0.31520838751907354, -0.3102062445278923, -0.30487246266596485, -0.2992042453318063, -0.29319917637056486, -0.2868552275329519, -0.2801707653987695, -0.27314455775018615, -0.2657757793807555, -0.25806401732699746, -0.25000927551027935, -0.24161197877758042, -0.23287297633073697, -0.22379354453463035, -0.21437538909582152, -0.20462064660407034, -0.19453188543023853, -0.18411210597507005, -0.17336474026436335, -0.16229365088716252, -0.15090312927458244, -0.13919789331801885, -0.1271830843265108, -0.11486426332416616, -0.10224740668960168, -0.0893389011404619, -0.07614553806714255, -0.06267450722097978, -0.0489333897631929, -0.03493015068196562, -0.020673130586137633, -0.006171036885010891, 0.008567065635156853, 0.023531764826408983, 0.03871331177342662, 0.054101631953379276, 0.06968633694664372, 0.08545673668287178, 0.10140185220585154, 0.11751042893975369, 0.13377095043847223, 0.15017165259881413, 0.16670053831756945, 0.1833453925715887, 0.20009379789929757, 0.21693315026126028, 0.23385067525674819, ], [0.23103080430368522, 0.2395491344773429, 0.2480921108752959, 0.2566582737413698, 0.2652461512442842, 0.2738542598321211, 0.2824811045909717, 0.2911251796076563, 0.29978496833641516, 0.30845894396946266, 0.3171455698113014, 0.3258432996566855, 0.3345505781721257, 0.34326584128082505, 0.3519875165509337, 0.3607140235870106, 0.36944377442457776, 0.37817517392765176, 0.3869066201891374, 0.3956365049339659, 0.40436321392485985, 0.4130851273706057, 0.4218006203367139, 0.43050806315834717, 0.43920582185539325, 0.4478922585495609, 0.45656573188337807, 0.4652245974409631, 0.4738672081704512, 0.4824919148079454, 0.4910970663028699, 0.4996810102445977, 0.5082420932902267, 0.5167786615933764, 0.5252890612338772, 0.5337716386482267, 0.5422247410606811, 0.5506467169148541, 0.5590359163056977, 0.5673906914117288, 0.575709396927379, 0.5839903904953351, 0.5922320331387392, 0.6004326896931215, 0.6085907292379346, 0.6167045255275592, 0.6247724574216532, 0.632792909314713, 0.6407642715647172, 0.648684940920727, 0.6565533209493088, 0.6643678224596559, 0.6721268639272758, 0.6798288719161192, 0.6874722814990214, 0.6950555366763276, 0.7025770907925766, 0.7100354069511181, 0.7174289584265351, 0.7247562290747475, 0.7320157137406733, 0.7392059186633226, 0.7463253618782013, 0.7533725736169037, 0.7603460967037694, 0.7672444869494874, 0.7740663135415258, 0.7808101594312674, 0.7874746217177357, 0.7940583120277906, 0.800559856892684, 0.8069778981208532, 0.8133110931668419, 0.8195581154962397, 0.8257176549465209, 0.8317884180836791, 0.837769128554545, 0.8436585274346803, 0.8494553735717436, 0.8551584439242204, 0.8607665338954126, 0.866278457662592, 0.8716930485012081, 0.877009159104055, 0.8822256618953046, 0.8873414493392981, 0.8923554342440141, 0.8972665500591098, 0.9020737511684509, 0.9067760131770366, 0.9113723331922328, 0.9158617300992282, 0.9202432448306268, 0.9245159406300956, 0.9286789033099847, 0.9327312415028448, 0.9366720869067577, 0.9405005945244121, 0.9442159428958465, 0.9478173343247879, 0.9513039950985184, 0.9546751757012032, 0.9579301510206085, 0.961068220548153, 0.9640887085722268, 0.9669909643647195, 0.9697743623607012, 0.9724383023311983, 0.974982209549014, 0.9774055349475405, 0.9797077552725146, 0.9818883732266704, 0.9839469176072432, 0.9858829434362829, 0.9876960320837391, 0.9893857913832753, 0.99095185574078, 0.9923938862355408, 0.9937115707140488, 0.9949046238764069, 0.9959727873553117, 0.9969158297875891, 0.9977335468782577, 0.9984257614571019, 0.9989923235277396, 0.9994331103091625, 0.999748026269744, 0.9999370031537013, 1.0, 0.9999370031537013, 0.999748026269744, 0.9994331103091625, 0.9989923235277396, 0.9984257614571019, 0.9977335468782577, 0.9969158297875891, 0.9959727873553117, 0.9949046238764069, 0.9937115707140488, 0.9923938862355408, 0.99095185574078, 0.9893857913832753, 0.9876960320837391, 0.9858829434362829, 0.9839469176072432, 0.9818883732266704, 0.9797077552725146, 0.9774055349475405, 0.974982209549014, 0.9724383023311983, 0.9697743623607012, 0.9669909643647195, 0.9640887085722268, 0.961068220548153, 0.9579301510206085, 0.9546751757012032, 0.9513039950985184, 0.9478173343247879, 0.9442159428958465, 0.9405005945244121, 0.9366720869067577, 0.9327312415028448, 0.9286789033099847, 0.9245159406300956, 0.9202432448306271, 0.9158617300992282, 0.9113723331922328, 0.9067760131770366, 0.9020737511684509, 0.8972665500591098, 0.8923554342440141, 0.8873414493392981, 0.8822256618953046, 0.877009159104055, 0.8716930485012081, 0.8662784576625924, 0.8607665338954126, 0.8551584439242202, 0.8494553735717436, 0.8436585274346803, 0.837769128554545, 0.8317884180836791, 0.8257176549465209, 0.8195581154962397, 0.8133110931668419, 0.8069778981208532, 0.8005598568926844, 0.7940583120277905, 0.7874746217177356, 0.7808101594312674, 0.7740663135415258, 0.7672444869494874, 0.7603460967037694, 0.7533725736169037, 0.7463253618782013, 0.7392059186633226, 0.7320157137406733, 0.7247562290747476, 0.717428958426535, 0.7100354069511179, 0.7025770907925766, 0.6950555366763274, 0.6874722814990214, 0.6798288719161192, 0.6721268639272758, 0.664367822459656, 0.6565533209493091, 0.6486849409207271, 0.6407642715647172, 0.6327929093147128, 0.6247724574216532, 0.6167045255275592, 0.6085907292379346, 0.6004326896931215, 0.5922320331387392, 0.5839903904953351, 0.575709396927379, 0.5673906914117288, 0.5590359163056979, 0.5506467169148541, 0.5422247410606809, 0.5337716386482266, 0.525289061233877, 0.5167786615933764, 0.5082420932902267, 0.4996810102445977, 0.4910970663028699, 0.48249191480794545, 0.47386720817045125, 0.4652245974409633, 0.4565657318833779, 0.4478922585495609, 0.4392058218553932, 0.43050806315834717, 0.4218006203367139, 0.4130851273706057, 0.40436321392485985, 0.395636504933966, 0.3869066201891375, 0.37817517392765193, 0.369443774424578, 0.3607140235870106, 0.3519875165509336, 0.34326584128082493, 0.3345505781721257, 0.3258432996566855, 0.31714556981130143, 0.3084589439694627, 0.2997849683364152, 0.2911251796076564, 0.28248110459097175, 0.27385425983212097, 0.26524615124428413, 0.2566582737413698, 0.24809211087529578, 0.23954913447734283, 0.23103080430368522, 0.22253856768519778, 0.2140738591816417, 0.20563810024032475, 0.1972326988593327, 0.1888590492554291, 0.1805185315367143, 0.17221251138013774, 0.16394233971395075, 0.15570935240519237, 0.14751486995229213, 0.1393601971828755, 0.13124662295685624, 0.12317541987489633, 0.11514784399231347, 0.10716513453851532, 0.09922851364203371, 0.09133918606123789, 0.08349833892079331, 0.07570714145394118, 0.06796674475066544, 0.06027828151181405, 0.05264286580923816, 0.04506159285201378, 0.03753553875880489, 0.030065760336427307, 0.02265329486467015, 0.015299159887427636, 0.008004353010197312, 0.0007698517039896981, -0.00640338688429731, -0.013514426114996375, -0.020562350032235777, -0.027546263533910504, -0.03446529253501883, -0.04131858412432781, -0.048105306714332365, -0.0548246501844735, -0.06147582601758717, -0.06805806742955223, -0.0745706294921142, -0.08101278924885746, -0.08738384582430611, -0.09368312052613202, -0.0999099569404545, -0.10606372102021407, -0.11214380116660848, -0.11814960830358082, -0.12408057594534808, -0.12993616025696889, -0.13571584010793997, -0.14141911711882604, -0.1470455157009183, -0.15259458308892657, -0.1580658893667093, -0.16345902748604946, -0.16877361327848395, -0.17400928546019961, -0.1791657056300083, -0.1842425582604181, -0.18923955068181805, -0.19415641305979725, -0.19899289836562037, -0.2037487823398847, -0.20842386344938504, -0.21301796283721638, -0.2175309242661449, -0.22196261405527998, -0.22631292101008438, -0.2305817563457583, -0.23476905360403746, -0.2388747685634481, -0.2428988791430599, -0.2468413852997864, -0.2507023089192759, -0.2544816937004483, -0.2581796050337233, -0.2617961298729995, -0.2653313766014374, -0.26878547489110144, -0.2721585755565279, -0.27545085040226935, -0.27866249206449123, -0.28179371384667395, -0.2848447495494964, -0.28781585329496373, -0.2907072993448539, -0.2935193819135505, -0.2962524149753418, -0.298906732066257, -0.30148268608051626, -0.3039806490616798, -0.3064010119885672, -0.30874418455603814, -0.31101059495071076, -0.31320068962170805, -0.3153149330465154, -0.31735380749203973, -0.3193178127709568, -0.3212074659934422, -0.3230233013143732, -0.32476586967609733, -0.3264357385468646, -0.32803349165501405, -0.32955972871901856, -0.331015065173481, -0.33240013189118395, -0.3337155749012944, -0.33496205510382343, -0.33614024798044506, -0.3372508433017782, -0.3382945448312366, -0.3392720700255517, -0.34018414973207806, -0.3410315278829841, -0.34181496118644356, -0.3425352188149301, -0.3431930820907289, -0.3437893441687774, -0.34432480971694224, -0.3448002945938483, -0.3452166255243715, -0.34557463977290825, -0.3458751848145358, -0.3461191180041782, -0.34630730624389183, -0.3464406256483866, -0.3465199612088985, -0.3465462064555278, -0.34652026311816203, -0.3464430407860975, -0.34631545656647733, -0.34613843474166334, -0.34591290642565814, -0.3456398092196937, -0.34532008686710697, -0.3449546889076141, -0.3445445703311069, -0.3440906912310841, -0.3435940164578352, -0.3430555152714968, -0.3424761609950936, -0.3418569306676834, -0.34119880469772335, -0.34050276651676964, -0.33976980223362996, -0.33900090028908214, -0.33819705111127385, -0.3373592467719182, -0.33648848064339976, -0.33558574705690175, -0.33465204096167195, -0.33368835758553367, -0.3326956920967583, -0.33167503926740793, -0.3306273931382578, -0.3295537466854111, -0.3284550914887091, -0.32733241740205166, -0.32618671222572815, -0.3250189613808702, -0.32383014758612827, -0.322621250536676, -0.32139324658564994, -0.32014710842811966, -0.31888380478769646, -0.31760430010587487, -0.31630955423421075, -0.3150005221294285, -0.3136781535515599, -0.3123433927652032, -0.3109971782440029, -0.30964044237843796, -0.30827411118701203, -0.30689910403093634, -0.3055163333323925, -0.30412670429646327, -0.3027311146368167, -0.30133045430522853, -0.29992560522502515, -0.29851744102852973, -0.2971068267985884, -0.2956946188142594, -0.294281664300737, -0.29286880118358843, -0.29145685784737657, -0.29004665289873904, -0.28863899493399464, -0.2872346823113462, -0.28583450292774476, -0.2844392340004808, -0.2830496418535667, -0.2816664817089692, -0.28029049748275353, -0.278922421586197, -0.2775629747319255, -0.27621286574512866, -0.27487279137990717, -0.27354343614079885, -0.2722254721095337, -0.270919558777066, -0.26962634288092424, -0.2683464582479241, -0.2670805256422835, -0.2658291526191799, -0.2645929333837839, -0.2633724486558055, -0.26216826553958644, -0.2609809373997653, -0.2598110037425488, -0.25865899010261123, -0.257525407935651, -0.25641075451662065, -0.25531551284365855, -0.25424015154773233, -0.2531851248080162, -0.25215087227301464, -0.25113781898744164, -0.2501463753248722, -0.24917693692616566, -0.2482298846436736, -0.24730558449123285, -0.24640438759994635, -0.2455266301797506, -0.24467263348676893, -0.24384270379644526, -0.24303713238245023, -0.24225619550135608, -0.241500154383063, -0.24076925522697093, -0.24006372920387695, -0.23938379246358507, -0.23872964614820716, -0.238101476411138, -0.23749945444167667, -0.2369237364952748, -0.23637446392938108, -0.2358517632448554, -0.23535574613292307, -0.23488650952763218, -0.23444413566378697, -0.23402869214031305, -0.23364023198902212, -0.23327879374873253, -0.23294440154470697, -0.23263706517335903, -0.2323567801921884, -0.23210352801489245, -0.23187727601160757, -0.23167797761422934, -0.23150557242675682, -0.2313599863406093, -0.23124113165485652, -0.23114890720130624, -0.23108319847438855, -0.23104387776577787, -0.23103080430368522, -0.2310438243967629, -0.231082771582549, -0.23114746678038833, -0.23123771844876043, -0.23135332274694087, -0.23149406370092926, -0.23165971337356484, -0.23185003203875837, -0.23206476835976259, -0.2323036595714052, -0.23256643166620203, -0.23285279958427504, -0.23316246740698876, -0.23349512855422505, -0.2338504659852128, -0.23422815240282474, -0.23462785046125767, -0.23504921297700773, -0.23549188314305156, -0.23595549474614416, -0.23643967238714486, -0.23694403170427544, -0.23746817959922303, -0.23801171446599095, -0.23857422642240314, -0.23915529754417106, -0.23975450210141988, -0.24037140679758548, -0.24100557101057904, -0.24165654703612238, -0.2423238803331561, -0.243007109771222, -0.24370576787971507, -0.24441938109891187, -0.245147470032666, -0.24588954970267496, -0.24664512980421494, -0.24741371496323844, -0.24819480499473476, -0.2489878951622502, -0.2497924764384613, -0.25060803576670065, -0.25143405632333093, -0.25227001778085917, -0.253115396571694, -0.25396966615243327, -0.2548322972685832, -0.25570275821960364, -0.2565805151241718, -0.25746503218556344, -0.25835577195704595, -0.25925219560717727, -0.26015376318490885, -0.2610599338843881, -0.26197016630935366, -0.2628839187370263, -0.2638006493813847, -0.2647198166557279, -0.26564087943442216, -0.26656329731372663, -0.26748653087159974, -0.2684100419263838, -0.26933329379426535, -0.27025575154541503, -0.2711768822587043, -0.2720961552749, -0.2730130424482427, -0.27392701839630496, -0.2748375607480377, -0.2757441503899076, -0.27664627171002837, -0.27754341284019324, -0.27843506589571615, -0.2793207272129854, -0.28019989758464237, -0.28107208249229204, -0.2819367923366555, -0.2827935426650779, -0.283641854396302, -0.2844812540424216, -0.2853112739279303, -0.28613145240578053, -0.286941334070369, -0.28774046996737, -0.2885284178003311, -0.28930474213395696, -0.29006901459399964, -0.29082081406368054, -0.29155972687656895, -0.2922853470058416, -0.292997276249852, -0.2936951244139398, -0.29437850948840755, -0.2950470578225979, -0.29570040429500855, -0.2963381924793718, -0.2969600748066444, -0.2975657127228381, -0.29815477684263636, -0.29872694709873415, -0.299281912886848, -0.2998193732063361, -0.3003390367963806, -0.30084062226767433, -0.3013238582295664, -0.30178848341261544, -0.3022342467865042, -0.3026609076732722, -0.3030682358558215, -0.3034560116816543, -0.30382402616180393, -0.3041720810649217, -0.30449998900648156, -0.30480757353307153, -0.30509466920173806, -0.305361121654353, -0.30560678768697647, -0.30583153531418733, -0.3060352438283577, -0.3062178038538493, -0.30637911739611057, -0.30651909788565546, -0.30663767021690813, -0.30673477078189804, -0.3068103474987931, -0.3068643598352598, -0.3068967788266406, -0.30690758708894433, -0.3068967788266406, -0.3068643598352598, -0.30681034749879316, -0.30673477078189804, -0.30663767021690813, -0.30651909788565546, -0.30637911739611057, -0.3062178038538493, -0.3060352438283576, -0.30583153531418733, -0.30560678768697663, -0.305361121654353, -0.305094669201738, -0.30480757353307153, -0.30449998900648156, -0.3041720810649219, -0.3038240261618041, -0.3034560116816543, -0.3030682358558215, -0.3026609076732722, -0.3022342467865042, -0.30178848341261555, -0.3013238582295665, -0.30084062226767433, -0.3003390367963805, -0.2998193732063361, -0.29928191288684813, -0.29872694709873426, -0.29815477684263636, -0.2975657127228381, -0.29696007480664444, -0.2963381924793718, -0.2957004042950086, -0.29504705782259805, -0.2943785094884075, -0.2936951244139397, -0.29299727624985206, -0.29228534700584163, -0.291559726876569, -0.2908208140636806, -0.29006901459399953, -0.2893047421339572, -0.2885284178003311, -0.28774046996737024, -0.28694133407036915, -0.28613145240578036, -0.28531127392793026, -0.2844812540424216, -0.2836418543963022, -0.28279354266507806, -0.2819367923366555, -0.28107208249229204, -0.28019989758464253, -0.2793207272129854, -0.2784350658957163, -0.27754341284019335, -0.27664627171002826, -0.2757441503899075, -0.2748375607480377, -0.273927018396305, -0.2730130424482428, -0.27209615527490005, -0.27117688225870423, -0.27025575154541526, -0.2693332937942654, -0.2684100419263839, -0.2674865308715999, -0.2665632973137265, -0.26564087943442216, -0.264719816655728, -0.26380064938138476, -0.2628839187370264, -0.26197016630935366, -0.2610599338843879, -0.2601537631849089, -0.2592521956071773, -0.25835577195704607, -0.25746503218556355, -0.2565805151241717, -0.25570275821960364, -0.2548322972685833, -0.25396966615243327, -0.2531153965716941, -0.2522700177808592, -0.25143405632333093, -0.25060803576670077, -0.24979247643846136, -0.24898789516225045, -0.24819480499473492, -0.2474137149632383, -0.2466451298042149, -0.24588954970267504, -0.2451474700326659, -0.24441938109891187, -0.2437057678797151, -0.24300710977122192, -0.24232388033315616, -0.24165654703612233, -0.24100557101057912, -0.24037140679758562, -0.23975450210141974, -0.23915529754417097, -0.2385742264224033, -0.2380117144659908, -0.23746817959922303, -0.23694403170427544, -0.23643967238714494, -0.23595549474614438, -0.23549188314305156, -0.23504921297700782, -0.23462785046125767, -0.2342281524028246, -0.2338504659852128, -0.23349512855422505, -0.23316246740698868, -0.23285279958427493, -0.23256643166620203, -0.2323036595714052, -0.23206476835976275, -0.2318500320387584, -0.23165971337356486, -0.23149406370092931, -0.2313533227469408, -0.23123771844876043, -0.2311474667803884, -0.23108277158254886, -0.2310438243967629, -0.2310308043036853, -0.23104387776577787, -0.2310831984743887, -0.23114890720130624, -0.23124113165485652, -0.2313599863406093, -0.23150557242675682, -0.2316779776142293, -0.23187727601160754, -0.2321035280148923, -0.23235678019218833, -0.23263706517335903, -0.23294440154470697, -0.2332787937487327, -0.23364023198902212, -0.23402869214031294, -0.23444413566378697, -0.2348865095276321, -0.23535574613292307, -0.2358517632448555, -0.23637446392938094, -0.23692373649527473, -0.2374994544416766, -0.23810147641113807, -0.23872964614820733, -0.239383792463585, -0.24006372920387686, -0.24076925522697093, -0.24150015438306308, -0.24225619550135608, -0.24303713238245014, -0.24384270379644502, -0.24467263348676888, -0.2455266301797506, -0.24640438759994643, -0.24730558449123308, -0.2482298846436736, -0.2491769369261655, -0.2501463753248722, -0.25113781898744175, -0.2521508722730146, -0.2531851248080162, -0.25424015154773205, -0.25531551284365855, -0.25641075451662065, -0.257525407935651, -0.2586589901026115, -0.2598110037425488, -0.26098093739976513, -0.26216826553958633, -0.2633724486558056, -0.2645929333837839, -0.26582915261917983, -0.2670805256422833, -0.2683464582479239, -0.26962634288092424, -0.27091955877706614, -0.2722254721095339, -0.2735434361407988, -0.2748727913799071, -0.27621286574512854, -0.2775629747319255, -0.2789224215861972, -0.2802904974827534, -0.28166648170896913, -0.2830496418535666, -0.2844392340004808, -0.28583450292774476, -0.2872346823113464, -0.2886389949339946, -0.2900466528987388, -0.2914568578473764, -0.29286880118358855, -0.29428166430073716, -0.29569461881425935, -0.29710682679858846, -0.2985174410285296, -0.29992560522502515, -0.3013304543052285, -0.3027311146368168, -0.30412670429646316, -0.3055163333323923, -0.3068991040309362, -0.30827411118701203, -0.3096404423784381, -0.3109971782440029, -0.3123433927652033, -0.31367815355155976, -0.3150005221294285, -0.3163095542342106, -0.3176043001058751, -0.31888380478769646, -0.32014710842811944, -0.3213932465856498, -0.322621250536676, -0.32383014758612827, -0.3250189613808703, -0.3261867122257282, -0.32733241740205143, -0.328455091488709, -0.32955374668541104, -0.330627393138258, -0.3316750392674078, -0.33269569209675826, -0.33368835758553345, -0.3346520409616722, -0.33558574705690186, -0.33648848064339965, -0.33735924677191836, -0.3381970511112738, -0.3390009002890823, -0.33976980223362996, -0.34050276651676964, -0.34119880469772323, -0.3418569306676833, -0.34247616099509365, -0.3430555152714968, -0.34359401645783527, -0.34409069123108404, -0.344544570331107, -0.34495468890761405, -0.3453200868671068, -0.3456398092196937, -0.34591290642565803, -0.34613843474166334, -0.3463154565664772, -0.3464430407860973, -0.34652026311816225, -0.3465462064555278, -0.34651996120889844, -0.3464406256483866, -0.34630730624389183, -0.3461191180041783, -0.3458751848145359, -0.3455746397729082, -0.34521662552437155, -0.3448002945938483, -0.34432480971694235, -0.3437893441687773, -0.3431930820907289, -0.3425352188149301, -0.3418149611864436, -0.34103152788298413, -0.3401841497320779, -0.33927207002555176, -0.3382945448312365, -0.33725084330177824, -0.3361402479804451, -0.33496205510382343, -0.3337155749012945, -0.33240013189118395, -0.3310150651734811, -0.3295597287190186, -0.32803349165501416, -0.3264357385468647, -0.32476586967609744, -0.323023301314373, -0.3212074659934423, -0.31931781277095705, -0.31735380749203995, -0.3153149330465155, -0.31320068962170805, -0.31101059495071093, -0.3087441845560381, -0.30640101198856745, -0.3039806490616799, -0.3014826860805165, -0.29890673206625673, -0.296252414975342, -0.2935193819135507, -0.2907072993448542, -0.2878158532949639, -0.28484474954949646, -0.28179371384667423, -0.2786624920644912, -0.27545085040226963, -0.272158575556528, -0.2687854748911018, -0.26533137660143713, -0.26179612987299966, -0.25817960503372356, -0.25448169370044865, -0.2507023089192762, -0.24684138529978647, -0.2428988791430603, -0.238874768563448, -0.23476905360403774, -0.23058175634575862, -0.22631292101008477, -0.2219626140552798, -0.217530924266145, -0.21301796283721686, -0.20842386344938554, -0.2037487823398846, -0.19899289836562045, -0.19415641305979756, -0.1892395506818179, -0.18424255826041827, -0.17916570563000858, -0.17400928546019998, -0.16877361327848386, -0.16345902748604965, -0.15806588936670987, -0.15259458308892654, -0.1470455157009185, -0.14141911711882613, -0.1357158401079404, -0.12993616025696872, -0.12408057594534849, -0.11814960830358139, -0.11214380116660913, -0.106063721020214, -0.09990995694045472, -0.09368312052613263, -0.087383845824306, -0.08101278924885738, -0.07457062949211427, -0.06805806742955271, -0.06147582601758697, -0.054824650184473876, -0.048105306714332705, -0.04131858412432844, -0.034465292535018684, -0.027546263533910792, -0.02056235003223647, -0.013514426114996269, -0.006403386884297532, 0.0007698517039895923, 0.008004353010196773, 0.015299159887427818, 0.022653294864669687, 0.030065760336426595, 0.03753553875880418, 0.04506159285201392, 0.052642865809237875, 0.06027828151181338, 0.06796674475066564, 0.07570714145394129, 0.08349833892079313, 0.09133918606123737, 0.09922851364203389, 0.10716513453851487, 0.11514784399231309, 0.12317541987489554, 0.13124662295685632, 0.13936019718287523, 0.14751486995229138, 0.15570935240519254, 0.1639423397139505, 0.17221251138013755, 0.18051853153671368, 0.1888590492554291, 0.19723269885933228, 0.2056381002403239, 0.21407385918164096, 0.22253856768519784, ], [0.34654620645552786, 0.35291870540982884, 0.3592774368501938, 0.3656216811221648, 0.37195071926137746, 0.3782638330929309, 0.3845603053306966, 0.3908394196765501, 0.39710046091951123, 0.40334271503477703, 0.4095654692826353, 0.4157680123072417, 0.4219496342352475, 0.4281096267742625, 0.43424728331113926, 0.44036189901006323, 0.44645277091043617, 0.4525191980245357, 0.45856048143493944, 0.46457592439169687, 0.4705648324092367, 0.476526513362994, 0.4824602775857431, 0.4883654379636231, 0.49424131003184, 0.500087212070033, 0.5059024651972903, 0.5116863934667997, 0.5174383239601209, 0.5231575868810662, 0.5288435156491734, 0.5344954469927592, 0.5401127210415397, 0.5456946814188014, 0.551240675333113, 0.5567500536695609, 0.562222171080498, 0.5676563860757898, 0.5730520611125464, 0.5784085626843248, 0.583725261409793, 0.5890015321208358, 0.5942367539500967, 0.5994303104179363, 0.6045815895187998, 0.6096899838069781, 0.6147548904817486, 0.6197757114718871, 0.6247518535195342, 0.6296827282634061, 0.6345677523213378, 0.6394063473721441, 0.644197940236789, 0.64894196295885, 0.653637852884266, 0.6582850527403564, 0.6628830107140999, 0.6674311805296628, 0.6719290215251623, 0.6763759987286572, 0.6807715829333513, 0.6851152507720008, 0.6894064847905134, 0.6936447735207296, 0.6978296115523724, 0.7019604996041593, 0.7060369445940616, 0.7100584597087038, 0.7140245644718914, 0.7179347848122564, 0.7217886531300126, 0.7255857083628091, 0.7293254960506709, 0.7330075684000218, 0.7366314843467755, 0.7401968096184861, 0.7437031167955518, 0.7471499853714596, 0.7505370018120635, 0.7538637596138881, 0.757129859361445, 0.760334908783562, 0.7634785228087058, 0.7665603236192978, 0.7695799407050149, 0.7725370109150606, 0.7754311785094072, 0.7782620952089963, 0.7810294202448901, 0.7837328204063699, 0.7863719700879711, 0.7889465513354487, 0.7914562538906672, 0.7939007752354084, 0.7962798206340891, 0.7985931031753838, 0.8008403438127452, 0.8030212714038186, 0.805135622748741, 0.8071831426273214, 0.8091635838350977, 0.8110767072182624, 0.8129222817074535, 0.8147000843504048, 0.8164099003434518, 0.818051523061888, 0.8196247540891664, 0.8211294032449423, 0.8225652886119553, 0.8239322365617416, 0.8252300817791787, 0.8264586672858547, 0.8276178444622599, 0.8287074730687979, 0.8297274212656128, 0.83067756563123, 0.8315577911800053, 0.8323679913783855, 0.8331080681599699, 0.8337779319393787, 0.8343775016249199, 0.8349067046300572, 0.8353654768836748, 0.8357537628391363, 0.8360715154821423, 0.8363186963373777, 0.8364952754739527, 0.836601231509637, 0.836636551613882, 0.836601231509637, 0.8364952754739527, 0.8363186963373777, 0.8360715154821423, 0.8357537628391363, 0.8353654768836748, 0.8349067046300572, 0.8343775016249199, 0.8337779319393787, 0.8331080681599699, 0.8323679913783855, 0.8315577911800053, 0.83067756563123, 0.8297274212656128, 0.8287074730687979, 0.8276178444622599, 0.8264586672858547, 0.8252300817791787, 0.8239322365617416, 0.8225652886119553, 0.8211294032449423, 0.8196247540891664, 0.818051523061888, 0.8164099003434518, 0.8147000843504048, 0.8129222817074535, 0.8110767072182624, 0.8091635838350977, 0.8071831426273214, 0.805135622748741, 0.8030212714038186, 0.8008403438127452, 0.7985931031753838, 0.7962798206340891, 0.7939007752354086, 0.7914562538906673, 0.7889465513354487, 0.7863719700879711, 0.7837328204063699, 0.7810294202448901, 0.7782620952089963, 0.7754311785094072, 0.7725370109150606, 0.7695799407050149, 0.7665603236192978, 0.7634785228087058, 0.7603349087835621, 0.757129859361445, 0.7538637596138879, 0.7505370018120635, 0.7471499853714596, 0.7437031167955518, 0.7401968096184861, 0.7366314843467755, 0.7330075684000218, 0.7293254960506709, 0.7255857083628091, 0.7217886531300128, 0.7179347848122564, 0.7140245644718913, 0.7100584597087038, 0.7060369445940616, 0.7019604996041593, 0.6978296115523724, 0.6936447735207296, 0.6894064847905135, 0.6851152507720008, 0.6807715829333513, 0.6763759987286573, 0.6719290215251622, 0.6674311805296627, 0.6628830107140999, 0.6582850527403563, 0.653637852884266, 0.64894196295885, 0.644197940236789, 0.6394063473721441, 0.6345677523213379, 0.6296827282634062, 0.6247518535195341, 0.6197757114718871, 0.6147548904817486, 0.609689983806978, 0.6045815895187998, 0.5994303104179363, 0.5942367539500967, 0.589001532120836, 0.583725261409793, 0.5784085626843248, 0.5730520611125464, 0.5676563860757898, 0.562222171080498, 0.5567500536695608, 0.5512406753331128, 0.5456946814188014, 0.5401127210415397, 0.5344954469927592, 0.5288435156491734, 0.5231575868810663, 0.517438323960121, 0.5116863934667998, 0.5059024651972903, 0.500087212070033, 0.49424131003183996, 0.4883654379636231, 0.4824602775857431, 0.476526513362994, 0.4705648324092367, 0.46457592439169687, 0.45856048143493944, 0.45251919802453583, 0.4464527709104362, 0.44036189901006323, 0.4342472833111392, 0.4281096267742625, 0.4219496342352475, 0.4157680123072417, 0.4095654692826353, 0.40334271503477703, 0.3971004609195113, 0.3908394196765501, 0.3845603053306967, 0.37826383309293077, 0.37195071926137746, 0.3656216811221648, 0.35927743685019375, 0.35291870540982884, 0.34654620645552786, 0.34016066023242253, 0.3337627874768669, 0.3273533093169671, 0.3209329471731068, 0.3145024226584839, 0.30806245747967254, 0.30161377333722567, 0.2951570918263304, 0.2886931343375351, 0.2822226219575587, 0.27574627537019913, 0.2692648147573546, 0.2627789597001723, 0.2562894290803393, 0.2497969409815305, 0.2433022125910262, 0.2368059601015177, 0.23030889861310977, 0.2238117420355399, 0.2173152029906248, 0.21081999271495092, 0.20432682096282112, 0.19783639590947377, 0.19134942405458708, 0.18486661012608324, 0.17838865698424708, 0.17191626552617137, 0.16545013459054658, 0.15899096086280345, 0.1525394387806279, 0.14609626043985852, 0.1396621155007817, 0.13323769109483807, 0.12682367173175363, 0.12042073920710936, 0.11402957251036248, 0.10765084773333371, 0.10128523797917255, 0.09493341327181563, 0.08859604046594872, 0.082273783157489, 0.0759673015945975, 0.06967725258923688, 0.06340428942928607, 0.057149061791225315, 0.05091221565340458, 0.04469439320990634, 0.03849623278501836, 0.032318368748325145, 0.026161431430434565, 0.020026047039346802, 0.01391283757748355, 0.00782242075938509, 0.0017554099300893682, -0.004287586015795899, -0.01030596271431564, -0.016299120411673883, -0.022266464043010924, -0.02820740331044184, -0.034121352760342956, -0.04000773185987722, -0.045865965072745526, -0.05169548193415421, -0.05749571712498656, -0.0632661105451699, -0.06900610738622524, -0.0747151582029901, -0.08039271898450445, -0.08603825122404869, -0.09165122198832452, -0.09723110398576787, -0.10277737563398358, -0.10828952112629629, -0.11376703049739906, -0.11920939968810211, -0.12461613060916064, -0.12998673120418142, -0.13532071551159608, -0.14061760372568824, -0.14587692225667473, -0.15109820378982117, -0.15628098734359552, -0.161424818326839, -0.16652924859495788, -0.1715938365051174, -0.17661814697044037, -0.18160175151319255, -0.18654422831695555, -0.1914451622777767, -0.1963041450542851, -0.20112077511677487, -0.20589465779523958, -0.21062540532635957, -0.21531263689942712, -0.21995597870121253, -0.22455506395975397, -0.22910953298707643, -0.2336190332208228, -0.23808321926480025, -0.24250175292843199, -0.24687430326510693, -0.2512005466094288, -0.25548016661335055, -0.2597128542811989, -0.2638983080035739, -0.26803623359012957, -0.2721263443012206, -0.2761683608784198, -0.2801620115738969, -0.2841070321786553, -0.2880031660496263, -0.2918501641356114, -0.2956477850020768, -0.29939579485478796, -0.30309396756229123, -0.3067420846772287, -0.3103399354564937, -0.31388731688021704, -0.31738403366958534, -0.3208298983034893, -0.32422473103399596, -0.32756835990065003, -0.33086062074359446, -0.3341013572155167, -0.337290420792412, -0.34042767078316966, -0.34351297433797423, -0.3465462064555278, -0.34952724998908635, -0.35245599565131447, -0.35533234201795716, -0.3581561955303249, -0.36092747049659946, -0.36364608909195195, -0.3663119813574825, -0.3689250851979732, -0.37148534637846375, -0.3739927185196445, -0.37644716309207044, -0.3788486494091982, -0.3811971546192459, -0.3834926636958795, -0.38573516942772457, -0.3879246724067099, -0.3900611810152402, -0.392144711412205, -0.394175287517822, -0.39615294099732185, -0.39807771124347346, -0.39994964535795613, -0.401768798131579, -0.4035352320233534, -0.40524901713842176, -0.40691023120484365, -0.4085189595492482, -0.41007529507135226, -0.41157933821735104, -0.41303119695218565, -0.41443098673069134, -0.41577883046763164, -0.4170748585066238, -0.41831920858795957, -0.4195120258153285, -0.42065346262144726, -0.4217436787326013, -0.4227828411321061, -0.42377112402269096, -0.42470870878781475, -0.4255957839519178, -0.42643254513961665, -0.4272191950338493, -0.4279559433329765, -0.42864300670684685, -0.429280608751832, -0.4298689799448406, -0.4304083575963164, -0.43089898580222985, -0.43134111539506975, -0.4317350038938432, -0.4320809154530924, -0.43237912081093455, -0.4326298972361362, -0.43283352847422707, -0.4329903046926647, -0.4331005224250569, -0.43316448451445194, -0.43318250005570413, -0.4331548843369261, -0.4330819587800345, -0.4329640508804004, -0.4328014941456132, -0.43259462803336757, -0.4323437978884841, -0.4320493548790718, -0.4317116559318455, -0.4313310636666038, -0.43090794632988355, -0.43044267772779615, -0.4299356371580594, -0.4293872093412343, -0.42879778435117777, -0.42816775754472275, -0.4274975294905951, -0.42678750589758185, -0.42603809754195676, -0.42524972019418, -0.4244227945448805, -0.4235577461301318, -0.4226550052560359, -0.42171500692262437, -0.42073819074709035, -0.4197250008863612, -0.41867588595902755, -0.4175912989666359, -0.41647169721436267, -0.4153175422310775, -0.41412929968880946, -0.412907439321632, -0.41165243484397296, -0.41036476386836845, -0.40904490782267006, -0.4076933518667188, -0.4063105848084992, -0.40489709901978654, -0.40345339035130007, -0.4019799580473754, -0.40047730466016973, -0.3989459359634122, -0.39738636086571477, -0.395799091323454, -0.394184642253241, -0.39254353144398935, -0.390876279468598, -0.38918340959525966, -0.3874654476984116, -0.3857229221693404, -0.38395636382645415, -0.38216630582523947, -0.38035328356791187, -0.3785178346127795, -0.3766604985833283, -0.374781817077048, -0.3728823335740085, -0.3709625933452053, -0.36902314336068265, -0.3670645321974549, -0.36508730994723504, -0.36309202812398716, -0.36107923957131716, -0.35904949836971495, -0.35700335974366415, -0.3549413799686307, -0.3528641162779486, -0.3507721267696137, -0.3486659703130026, -0.34654620645552786, -0.3444133953292488, -0.3422680975574469, -0.34011087416118335, -0.3379422864658536, -0.33576289600775056, -0.33357326444065427, -0.3313739534424587, -0.3291655246218545, -0.326948539425077, -0.3247235590427391, -0.32249114431675824, -0.32025185564739683, -0.3180062529004263, -0.3157548953144306, -0.3134983414082654, -0.311237148888683, -0.3089718745581417, -0.30670307422280985, -0.30443130260078255, -0.3021571132305211, -0.29988105837953327, -0.29760368895330463, -0.2953255544044989, -0.2930472026424377, -0.2907691799428752, -0.28849203085808334, -0.28621629812725635, -0.28394252258725544, -0.2816712430837, -0.279402996382426, -0.27713831708131775, -0.2748777375225348, -0.2726217877051403, -0.2703709951981477, -0.2681258850540001, -0.265886979722492, -0.26365479896515065, -0.2614298597700869, -0.2592126762673311, -0.25700375964466426, -0.25480361806396223, -0.25261275657805826, -0.2504316770481455, -0.24826087806172456, -0.24610085485111297, -0.24395209921252867, -0.2418150994257574, -0.23969034017442065, -0.23757830246685083, -0.23547946355759253, -0.2333942968695342, -0.2313232719166907, -0.22926685422763984, -0.2272255052696326, -0.22519968237338264, -0.22318983865854986, -0.22119642295992942, -0.21921987975435514, -0.21726064908833198, -0.2153191665064046, -0.21339586298027816, -0.21149116483869598, -0.2096054936980914, -0.20773926639401827, -0.20589289491337617, -0.20406678632743588, -0.20226134272567908, -0.20047696115046076, -0.1987140335325034, -0.19697294662723622, -0.19525408195198427, -0.19355781572402236, -0.19188451879949844, -0.1902345566132401, -0.18860828911944935, -0.18700607073329883, -0.18542825027343487, -0.18387517090539782, -0.18234717008596968, -0.18084457950845395, -0.17936772504890028, -0.17791692671327825, -0.17649249858561203, -0.1750947487770805, -0.1737239793760937, -0.17238048639935027, -0.17106455974388604, -0.16977648314011964, -0.16851653410590237, -0.16728498390157992, -0.16608209748607242, -0.16490813347398028, -0.16376334409372048, -0.16264797514670237, -0.16156226596754747, -0.1605064493853595, -0.15948075168605094, -0.15848539257573255, -0.15752058514517003, -0.15658653583531348, -0.15568344440390722, -0.1548115038931811, -0.15397090059863233, -0.1531618140388994, -0.15238441692673502, -0.15163887514108054, -0.1509253477002482, -0.15024398673621361, -0.14959493747002262, -0.14897833818831838, -0.14839432022098822, -0.1478430079199386, -0.14732451863899657, -0.14683896271494537, -0.14638644344969265, -0.14596705709357838, -0.14558089282982128, -0.1452280327601084, -0.1449085518913294, -0.14462251812345747, -0.14436999223857883, -0.14415102789107262, -0.14396567159894239, -0.14381396273630076, -0.14369593352700852, -0.14361160903946857, -0.14356100718257625, -0.14354413870282623, -0.14356100718257625, -0.14361160903946857, -0.14369593352700857, -0.14381396273630076, -0.14396567159894239, -0.14415102789107262, -0.14436999223857883, -0.14462251812345747, -0.14490855189132937, -0.1452280327601084, -0.1455808928298213, -0.14596705709357832, -0.14638644344969265, -0.14683896271494534, -0.14732451863899654, -0.14784300791993857, -0.14839432022098828, -0.14897833818831832, -0.14959493747002264, -0.15024398673621345, -0.15092534770024824, -0.1516388751410805, -0.1523844169267349, -0.15316181403889942, -0.1539709005986323, -0.15481150389318105, -0.15568344440390716, -0.1565865358353135, -0.15752058514517, -0.15848539257573258, -0.15948075168605078, -0.16050644938535943, -0.16156226596754739, -0.16264797514670232, -0.16376334409372048, -0.1649081334739802, -0.16608209748607242, -0.16728498390157973, -0.16851653410590237, -0.16977648314011964, -0.17106455974388599, -0.1723804863993502, -0.17372397937609363, -0.1750947487770805, -0.17649249858561183, -0.1779169267132782, -0.1793677250489002, -0.18084457950845392, -0.1823471700859695, -0.1838751709053979, -0.18542825027343476, -0.18700607073329883, -0.18860828911944919, -0.19023455661324, -0.19188451879949836, -0.19355781572402211, -0.19525408195198432, -0.1969729466272361, -0.1987140335325033, -0.2004769611504605, -0.20226134272567917, -0.2040667863274358, -0.2058928949133761, -0.20773926639401813, -0.2096054936980913, -0.2114911648386959, -0.21339586298027793, -0.21531916650640465, -0.2172606490883319, -0.21921987975435514, -0.2211964229599292, -0.22318983865854988, -0.22519968237338248, -0.22722550526963245, -0.22926685422763993, -0.2313232719166906, -0.2333942968695341, -0.23547946355759225, -0.2375783024668509, -0.23969034017442056, -0.2418150994257574, -0.24395209921252844, -0.24610085485111305, -0.24826087806172448, -0.2504316770481454, -0.25261275657805843, -0.2548036180639622, -0.2570037596446643, -0.2592126762673308, -0.261429859770087, -0.26365479896515054, -0.26588697972249187, -0.26812588505399976, -0.2703709951981478, -0.2726217877051402, -0.27487773752253464, -0.27713831708131775, -0.27940299638242583, -0.28167124308369995, -0.28394252258725516, -0.2862162981272564, -0.28849203085808317, -0.2907691799428752, -0.2930472026424374, -0.295325554404499, -0.2976036889533046, -0.299881058379533, -0.30215711323052125, -0.3044313026007825, -0.3067030742228098, -0.3089718745581414, -0.31123714888868304, -0.31349834140826527, -0.31575489531443046, -0.31800625290042606, -0.3202518556473969, -0.3224911443167582, -0.3247235590427388, -0.3269485394250771, -0.32916552462185444, -0.3313739534424586, -0.333573264440654, -0.3357628960077506, -0.33794228646585356, -0.34011087416118324, -0.3422680975574467, -0.3444133953292489, -0.3465462064555278, -0.3486659703130023, -0.3507721267696138, -0.35286411627794856, -0.35494137996863057, -0.35700335974366393, -0.359049498369715, -0.361079239571317, -0.363092028123987, -0.36508730994723493, -0.367064532197455, -0.3690231433606826, -0.3709625933452051, -0.37288233357400863, -0.37478181707704794, -0.37666049858332823, -0.3785178346127793, -0.38035328356791187, -0.38216630582523936, -0.38395636382645415, -0.38572292216934023, -0.3874654476984117, -0.3891834095952595, -0.3908762794685978, -0.39254353144398935, -0.3941846422532409, -0.39579909132345387, -0.3973863608657146, -0.3989459359634123, -0.4004773046601697, -0.4019799580473753, -0.4034533903512999, -0.4048970990197865, -0.4063105848084992, -0.40769335186671873, -0.4090449078226701, -0.41036476386836845, -0.4116524348439728, -0.4129074393216319, -0.4141292996888095, -0.4153175422310773, -0.41647169721436267, -0.41759129896663577, -0.41867588595902755, -0.4197250008863612, -0.42073819074709023, -0.4217150069226244, -0.4226550052560359, -0.4235577461301317, -0.42442279454488047, -0.4252497201941801, -0.4260380975419567, -0.42678750589758174, -0.4274975294905951, -0.4281677575447227, -0.42879778435117777, -0.4293872093412343, -0.4299356371580595, -0.43044267772779615, -0.43090794632988355, -0.4313310636666038, -0.4317116559318455, -0.43204935487907187, -0.432343797888484, -0.4325946280333675, -0.4328014941456132, -0.43296405088040046, -0.4330819587800345, -0.43315488433692617, -0.43318250005570413, -0.4331644845144519, -0.4331005224250569, -0.4329903046926647, -0.4328335284742271, -0.4326298972361362, -0.43237912081093455, -0.4320809154530924, -0.4317350038938433, -0.43134111539506975, -0.43089898580222985, -0.4304083575963164, -0.4298689799448407, -0.4292806087518321, -0.4286430067068468, -0.42795594333297654, -0.4272191950338494, -0.42643254513961665, -0.4255957839519178, -0.4247087087878148, -0.42377112402269096, -0.4227828411321061, -0.42174367873260143, -0.42065346262144726, -0.41951202581532865, -0.41831920858795946, -0.4170748585066238, -0.4157788304676318, -0.41443098673069134, -0.41303119695218565, -0.41157933821735104, -0.41007529507135243, -0.4085189595492482, -0.40691023120484365, -0.4052490171384218, -0.40353523202335373, -0.401768798131579, -0.3999496453579563, -0.3980777112434737, -0.39615294099732185, -0.39417528751782205, -0.3921447114122051, -0.39006118101524045, -0.38792467240670986, -0.3857351694277246, -0.38349266369587953, -0.38119715461924614, -0.3788486494091982, -0.3764471630920706, -0.3739927185196447, -0.37148534637846375, -0.3689250851979732, -0.3663119813574826, -0.3636460890919523, -0.3609274704965993, -0.358156195530325, -0.35533234201795716, -0.35245599565131475, -0.34952724998908624, -0.3465462064555279, -0.3435129743379745, -0.34042767078316954, -0.3372904207924121, -0.3341013572155168, -0.33086062074359485, -0.32756835990065, -0.32422473103399607, -0.32082989830348935, -0.31738403366958573, -0.3138873168802169, -0.31033993545649385, -0.3067420846772291, -0.3030939675622912, -0.2993957948547881, -0.29564778500207683, -0.2918501641356118, -0.28800316604962617, -0.28410703217865546, -0.2801620115738971, -0.2761683608784202, -0.2721263443012205, -0.2680362335901297, -0.26389830800357433, -0.2597128542811988, -0.25548016661335077, -0.251200546609429, -0.24687430326510743, -0.24250175292843182, -0.2380832192648004, -0.233619033220823, -0.22910953298707692, -0.22455506395975416, -0.21995597870121272, -0.21531263689942762, -0.21062540532635937, -0.2058946577952398, -0.20112077511677506, -0.19630414505428564, -0.19144516227777653, -0.18654422831695575, -0.18160175151319274, -0.1766181469704409, -0.17159383650511756, -0.16652924859495807, -0.16142481832683955, -0.15628098734359536, -0.1510982037898214, -0.1458769222566749, -0.14061760372568888, -0.1353207155115959, -0.1299867312041816, -0.12461613060916084, -0.1192093996881027, -0.11376703049739932, -0.10828952112629656, -0.10277737563398422, -0.09723110398576766, -0.09165122198832472, -0.08603825122404911, -0.08039271898450508, -0.0747151582029901, -0.06900610738622544, -0.06326611054517035, -0.057495717124987133, -0.05169548193415421, -0.04586596507274583, -0.04000773185987761, -0.03412135276034276, -0.02820740331044184, -0.022266464043011348, -0.016299120411674497, -0.010305962714315678, -0.0042875860157961295, 0.001755409930088868, 0.007822420759385244, 0.013912837577483511, 0.02002604703934657, 0.026161431430434104, 0.03231836874832538, 0.0384962327850181, 0.04469439320990584, 0.05091221565340381, 0.057149061791225274, 0.06340428942928585, 0.06967725258923638, 0.07596730159459772, 0.08227378315748897, 0.0885960404659485, 0.09493341327181512, 0.10128523797917273, 0.10765084773333349, 0.114029572510362, 0.12042073920710865, 0.12682367173175363, 0.1332376910948378, 0.13966211550078117, 0.1460962604398587, 0.15253943878062787, 0.15899096086280323, 0.16545013459054608, 0.17191626552617156, 0.17838865698424677, 0.1848666101260828, 0.19134942405458633, 0.19783639590947377, 0.20432682096282084, 0.21081999271495044, 0.21731520299062507, 0.22381174203553983, 0.23030889861310955, 0.23680596010151722, 0.24330221259102636, 0.2497969409815302, 0.25628942908033886, 0.26277895970017157, 0.26926481475735453, 0.2757462753701989, 0.2822226219575582, 0.2886931343375353, 0.2951570918263304, 0.3016137733372254, 0.30806245747967204, 0.3145024226584839, 0.3209329471731065, 0.3273533093169666, 0.33376278747686616, 0.3401606602324225, ], [0.34654620645552786, 0.34866605036953885, 0.35077276720382033, 0.3528662776416741, 0.3549465028636155, 0.3570133645503407, 0.3590667848856754, 0.3611066865595044, 0.36313299277068284, 0.365145627229927, 0.3671445141626872, 0.36912957831199994, 0.37110074494132217, 0.37305793983734437, 0.3750010893127849, 0.3769301202091643, 0.3788449598995595, 0.3807455362913386, 0.3826317778288746, 0.3845036134962396, 0.38636097281987886, 0.3882037858712636, 0.3900319832695238, 0.391845496184061, 0.3936442563371389, 0.3954281960064541, 0.39719724802768686, 0.398951345797028, 0.4006904232736881, 0.40241441498238306, 0.4041232560157996, 0.4058168820370385, 0.40749522928203724, 0.40915823456197087, 0.41080583526563036, 0.41243796936178045, 0.414054575401495, 0.41565559252047024, 0.4172409604413166, 0.4188106194758279, 0.4203645105272287, 0.421902575092399, 0.4234247552640774, 0.4249309937330409, 0.42642123379026214, 0.42789541932904557, 0.4293534948471386, 0.4307954054488223, 0.4322210968469774, 0.4336305153651284, 0.43502360793946504, 0.4364003221208391, 0.43776060607673983, 0.4391044085932449, 0.4404316790769493, 0.4417423675568695, 0.4430364246863249, 0.4443138017447961, 0.4455744506397587, 0.4468183239084945, 0.44804537471987793, 0.4492555568761394, 0.4504488248146047, 0.45162513360941037, 0.45278443897319537, 0.4539266972587677, 0.4550518654607487, 0.45615990121719147, 0.45725076281117605, 0.4583244091723799, 0.4593807998786244, 0.4604198951573966, 0.46144165588734654, 0.4624460435997601, 0.4634330204800078, 0.4644025493689677, 0.46535459376442534, 0.4662891178224472, 0.46720608635873073, 0.4681054648499288, 0.4689872194349495, 0.46985131691623117, 0.470697724760992, 0.4715264111024549, 0.4723373447410477, 0.47313049514557715, 0.47390583245437873, 0.4746633274764412, 0.4754029516925049, 0.4761246772561361, 0.47682847699477554, 0.4775143244107605, 0.4781821936823238, 0.47883205966456444, 0.47946389789039573, 0.4800776845714656, 0.48067339659905234, 0.4812510115449348, 0.48181050766223676, 0.4823518638862457, 0.48287505983520557, 0.48338007581108483, 0.4838668928003173, 0.4843354924745185, 0.48478585719117556, 0.4852179699943115, 0.48563181461512345, 0.4860273754725953, 0.48640463767408443, 0.48676358701588207, 0.48710420998374854, 0.4874264937534215, 0.4877304261910993, 0.4880159958538972, 0.4882831919902791, 0.48853200454046125, 0.48876242413679183, 0.4889744421041034, 0.4891680504600392, 0.489343241915354, 0.4895000098741887, 0.4896383484343181, 0.48975825238737364, 0.4898597172190393, 0.48994273910922154, 0.4900073149321931, 0.49005344225671066, 0.49008111934610654, 0.4900903451583541, 0.49008111934610654, 0.49005344225671066, 0.4900073149321931, 0.48994273910922154, 0.4898597172190393, 0.48975825238737364, 0.4896383484343181, 0.4895000098741887, 0.489343241915354, 0.4891680504600392, 0.4889744421041034, 0.48876242413679183, 0.48853200454046125, 0.4882831919902791, 0.4880159958538972, 0.4877304261910993, 0.4874264937534215, 0.48710420998374854, 0.48676358701588207, 0.48640463767408443, 0.4860273754725953, 0.48563181461512345, 0.4852179699943115, 0.48478585719117556, 0.4843354924745185, 0.4838668928003173, 0.48338007581108483, 0.48287505983520557, 0.4823518638862457, 0.48181050766223676, 0.4812510115449348, 0.48067339659905234, 0.4800776845714656, 0.47946389789039573, 0.4788320596645645, 0.4781821936823238, 0.4775143244107605, 0.47682847699477554, 0.4761246772561361, 0.4754029516925049, 0.4746633274764412, 0.47390583245437873, 0.47313049514557715, 0.4723373447410477, 0.4715264111024549, 0.470697724760992, 0.46985131691623117, 0.4689872194349495, 0.46810546484992877, 0.46720608635873073, 0.4662891178224472, 0.46535459376442534, 0.4644025493689677, 0.4634330204800078, 0.4624460435997601, 0.46144165588734654, 0.4604198951573967, 0.4593807998786244, 0.4583244091723799, 0.457250762811176, 0.45615990121719147, 0.4550518654607487, 0.4539266972587677, 0.45278443897319537, 0.45162513360941037, 0.4504488248146047, 0.4492555568761394, 0.44804537471987793, 0.4468183239084945, 0.4455744506397587, 0.444313801744796, 0.4430364246863249, 0.44174236755686946, 0.4404316790769493, 0.4391044085932449, 0.43776060607673983, 0.4364003221208391, 0.43502360793946515, 0.43363051536512853, 0.43222109684697735, 0.4307954054488223, 0.4293534948471386, 0.42789541932904557, 0.42642123379026214, 0.4249309937330409, 0.4234247552640774, 0.421902575092399, 0.4203645105272287, 0.4188106194758279, 0.4172409604413166, 0.41565559252047024, 0.41405457540149493, 0.4124379693617804, 0.4108058352656303, 0.40915823456197087, 0.40749522928203724, 0.4058168820370385, 0.4041232560157996, 0.4024144149823832, 0.40069042327368815, 0.398951345797028, 0.3971972480276868, 0.3954281960064541, 0.3936442563371388, 0.391845496184061, 0.3900319832695238, 0.3882037858712636, 0.38636097281987886, 0.3845036134962396, 0.3826317778288746, 0.38074553629133867, 0.3788449598995596, 0.3769301202091643, 0.3750010893127849, 0.37305793983734437, 0.37110074494132217, 0.36912957831199994, 0.3671445141626872, 0.365145627229927, 0.36313299277068284, 0.3611066865595044, 0.3590667848856754, 0.3570133645503407, 0.3549465028636155, 0.3528662776416741, 0.3507727672038203, 0.34866605036953885, 0.34654620645552786, 0.34441331527271246, 0.3422674571232403, 0.3401087127974578, 0.33793716357086867, 0.335752891201074, 0.33355597792469377, 0.3313465064542714, 0.32912455997515877, 0.3268902221423851, 0.3246435770775068, 0.3223847093654409, 0.3201137040512799, 0.31783064663709043, 0.3155356230786936, 0.3132287197824294, 0.31091002360190284, 0.30857962183471477, 0.3062376022191746, 0.3038840529309971, 0.3015190625799826, 0.2991427202066813, 0.29675511527904036, 0.29435633768903585, 0.29194647774928817, 0.28952562618966204, 0.2870938741538505, 0.28465131319594306, 0.2821980352769794, 0.27973413276148656, 0.2772596984140016, 0.2747748253955793, 0.2722796072602841, 0.2697741379516686, 0.2672585117992362, 0.2647328235148897, 0.26219716818936545, 0.25965164128865326, 0.25709633865040227, 0.25453135648031255, 0.25195679134851307, 0.24937274018592584, 0.24677930028061668, 0.24417656927413217, 0.24156464515782367, 0.23894362626915783, 0.23631361128801448, 0.23367469923297107, 0.23102698945757505, 0.22837058164660284, 0.22570557581230732, 0.22303207229065175, 0.22035017173753269, 0.21765997512499013, 0.214961583737406, 0.21225509916769097, 0.20954062331345932, 0.20681825837319276, 0.20408810684239262, 0.20135027150972085, 0.19860485545313042, 0.1958519620359841, 0.19309169490316316, 0.19032415797716493, 0.18754945545419047, 0.18476769180022165, 0.1819789717470876, 0.17918340028852223, 0.17638108267621086, 0.1735721244158277, 0.1707566312630637, 0.1679347092196445, 0.16510646452934055, 0.16227200367396535, 0.15943143336936855, 0.15658486056141624, 0.15373239242196587, 0.15087413634483096, 0.14801019994173684, 0.14514069103827085, 0.14226571766982085, 0.13938538807750972, 0.13649981070411824, 0.13360909419000394, 0.13071334736900936, 0.12781267926436599, 0.12490719908458815, 0.1219970162193626, 0.11908224023542961, 0.11616298087245715, 0.1132393480389105, 0.11031145180791323, 0.10737940241310387, 0.10444331024448447, 0.10150328584426623, 0.09855943990270562, 0.09561188325393888, 0.09266072687180747, 0.08970608186568142, 0.08674805947627541, 0.08378677107146013, 0.0808223281420707, 0.07785484229770744, 0.07488442526253564, 0.07191118887107731, 0.06893524506400259, 0.06595670588391332, 0.06297568347112645, 0.05999229005945108, 0.05700663797196252, 0.05401883961677486, 0.051029007482806976, 0.04803725413554928, 0.04504369221282388, 0.042048434420545774, 0.039051593528477355, 0.03605328236598479, 0.03305361381778841, 0.030052700819713746, 0.027050656354439283, 0.02404759344724192, 0.021043625161743303, 0.018038864595651006, 0.015033424876502508, 0.012027419157404113, 0.009020960612772572, 0.0060141624340724325, 0.003007137825556077, 3.847435775034301e-17, -0.0030071378255560385, -0.0060141624340723935, -0.009020960612772534, -0.012027419157404073, -0.015033424876502468, -0.01803886459565093, -0.021043625161743264, -0.02404759344724192, -0.027050656354439206, -0.03005270081971367, -0.03305361381778833, -0.03605328236598479, -0.03905159352847728, -0.0420484344205457, -0.04504369221282388, -0.0480372541355492, -0.0510290074828069, -0.05401883961677482, -0.05700663797196248, -0.059992290059451, -0.06297568347112638, -0.06595670588391324, -0.06893524506400252, -0.07191118887107724, -0.07488442526253555, -0.0778548422977074, -0.08082232814207062, -0.08378677107146008, -0.08674805947627537, -0.08970608186568138, -0.09266072687180743, -0.09561188325393881, -0.09855943990270553, -0.10150328584426616, -0.10444331024448443, -0.10737940241310379, -0.11031145180791316, -0.11323934803891048, -0.11616298087245706, -0.11908224023542958, -0.12199701621936257, -0.12490719908458808, -0.12781267926436596, -0.13071334736900928, -0.1336090941900039, -0.1364998107041182, -0.1393853880775097, -0.14226571766982077, -0.14514069103827076, -0.14801019994173678, -0.15087413634483088, -0.15373239242196582, -0.15658486056141618, -0.15943143336936852, -0.16227200367396533, -0.16510646452934047, -0.16793470921964448, -0.17075663126306362, -0.17357212441582756, -0.17638108267621078, -0.17918340028852206, -0.18197897174708758, -0.18476769180022148, -0.18754945545419047, -0.19032415797716498, -0.19309169490316305, -0.19585196203598418, -0.19860485545313036, -0.20135027150972085, -0.20408810684239248, -0.20681825837319273, -0.20954062331345916, -0.2122550991676909, -0.21496158373740587, -0.21765997512499005, -0.2203501717375327, -0.2230320722906517, -0.22570557581230732, -0.2283705816466028, -0.23102698945757502, -0.23367469923297093, -0.23631361128801437, -0.23894362626915772, -0.24156464515782358, -0.24417656927413203, -0.24677930028061662, -0.24937274018592592, -0.251956791348513, -0.25453135648031255, -0.2570963386504022, -0.25965164128865326, -0.2621971681893653, -0.2647328235148897, -0.26725851179923604, -0.2697741379516686, -0.27227960726028394, -0.2747748253955792, -0.2772596984140017, -0.2797341327614865, -0.28219803527697945, -0.284651313195943, -0.28709387415385046, -0.28952562618966193, -0.2919464777492881, -0.2943563376890357, -0.2967551152790403, -0.29914272020668137, -0.30151906257998257, -0.3038840529309971, -0.30623760221917457, -0.3085796218347148, -0.3109100236019028, -0.31322871978242933, -0.3155356230786935, -0.3178306466370903, -0.32011370405127976, -0.32238470936544084, -0.32464357707750685, -0.326890222142385, -0.3291245599751588, -0.33134650645427133, -0.3335559779246938, -0.3357528912010738, -0.33793716357086867, -0.3401087127974577, -0.34226745712324025, -0.34441331527271235, -0.3465462064555278, -0.3486660503695389, -0.3507727672038203, -0.35286627764167416, -0.3549465028636155, -0.3570133645503407, -0.3590667848856753, -0.36110668655950434, -0.36313299277068267, -0.365145627229927, -0.367144514162687, -0.36912957831199994, -0.3711007449413222, -0.37305793983734437, -0.3750010893127849, -0.3769301202091642, -0.3788449598995596, -0.3807455362913386, -0.3826317778288746, -0.38450361349623957, -0.38636097281987886, -0.3882037858712635, -0.3900319832695238, -0.3918454961840611, -0.3936442563371388, -0.3954281960064541, -0.3971972480276868, -0.398951345797028, -0.4006904232736881, -0.40241441498238306, -0.4041232560157995, -0.4058168820370385, -0.4074952292820373, -0.40915823456197076, -0.41080583526563036, -0.4124379693617804, -0.414054575401495, -0.41565559252047013, -0.4172409604413166, -0.4188106194758278, -0.42036451052722856, -0.4219025750923989, -0.4234247552640774, -0.4249309937330409, -0.42642123379026214, -0.42789541932904557, -0.4293534948471386, -0.4307954054488223, -0.43222109684697735, -0.4336305153651284, -0.43502360793946504, -0.4364003221208391, -0.43776060607673967, -0.4391044085932449, -0.4404316790769493, -0.44174236755686946, -0.4430364246863249, -0.444313801744796, -0.44557445063975876, -0.4468183239084945, -0.44804537471987793, -0.44925555687613933, -0.4504488248146047, -0.45162513360941037, -0.4527844389731953, -0.4539266972587678, -0.45505186546074866, -0.45615990121719147, -0.457250762811176, -0.4583244091723799, -0.4593807998786244, -0.4604198951573966, -0.4614416558873465, -0.4624460435997601, -0.46343302048000773, -0.4644025493689677, -0.46535459376442534, -0.4662891178224472, -0.46720608635873073, -0.46810546484992877, -0.4689872194349495, -0.4698513169162311, -0.470697724760992, -0.4715264111024549, -0.4723373447410477, -0.4731304951455771, -0.47390583245437873, -0.4746633274764412, -0.4754029516925048, -0.47612467725613616, -0.47682847699477554, -0.4775143244107605, -0.4781821936823237, -0.4788320596645645, -0.47946389789039573, -0.4800776845714656, -0.48067339659905234, -0.4812510115449348, -0.48181050766223676, -0.4823518638862457, -0.48287505983520557, -0.48338007581108483, -0.4838668928003173, -0.4843354924745185, -0.48478585719117556, -0.4852179699943115, -0.48563181461512345, -0.4860273754725953, -0.48640463767408443, -0.48676358701588207, -0.48710420998374854, -0.4874264937534215, -0.48773042619109924, -0.4880159958538972, -0.4882831919902791, -0.48853200454046125, -0.48876242413679183, -0.4889744421041034, -0.4891680504600392, -0.489343241915354, -0.4895000098741887, -0.4896383484343181, -0.48975825238737364, -0.4898597172190393, -0.48994273910922154, -0.49000731493219307, -0.49005344225671066, -0.49008111934610654, -0.4900903451583541, -0.49008111934610654, -0.49005344225671066, -0.4900073149321931, -0.48994273910922154, -0.4898597172190393, -0.48975825238737364, -0.4896383484343181, -0.4895000098741887, -0.489343241915354, -0.4891680504600392, -0.48897444210410346, -0.48876242413679183, -0.48853200454046125, -0.4882831919902791, -0.4880159958538972, -0.4877304261910993, -0.4874264937534215, -0.48710420998374854, -0.4867635870158821, -0.48640463767408443, -0.4860273754725953, -0.48563181461512345, -0.4852179699943115, -0.48478585719117556, -0.4843354924745185, -0.4838668928003173, -0.4833800758110849, -0.48287505983520557, -0.4823518638862457, -0.4818105076622368, -0.4812510115449348, -0.48067339659905234, -0.4800776845714656, -0.47946389789039584, -0.47883205966456444, -0.4781821936823237, -0.4775143244107606, -0.47682847699477554, -0.4761246772561361, -0.4754029516925049, -0.4746633274764412, -0.4739058324543789, -0.4731304951455771, -0.47233734474104777, -0.471526411102455, -0.4706977247609919, -0.4698513169162311, -0.4689872194349496, -0.4681054648499288, -0.46720608635873073, -0.4662891178224472, -0.4653545937644254, -0.4644025493689678, -0.46343302048000773, -0.4624460435997601, -0.46144165588734654, -0.4604198951573966, -0.4593807998786244, -0.45832440917237993, -0.45725076281117605, -0.45615990121719147, -0.4550518654607487, -0.4539266972587678, -0.4527844389731955, -0.45162513360941037, -0.45044882481460474, -0.4492555568761395, -0.4480453747198779, -0.4468183239084946, -0.44557445063975887, -0.4443138017447962, -0.44303642468632487, -0.44174236755686946, -0.4404316790769493, -0.4391044085932448, -0.4377606060767397, -0.4364003221208391, -0.4350236079394652, -0.4336305153651284, -0.43222109684697735, -0.4307954054488224, -0.42935349484713875, -0.42789541932904546, -0.4264212337902622, -0.42493099373304094, -0.4234247552640774, -0.421902575092399, -0.42036451052722873, -0.418810619475828, -0.41724096044131653, -0.41565559252047024, -0.4140545754014951, -0.41243796936178057, -0.4108058352656303, -0.40915823456197087, -0.4074952292820373, -0.40581688203703836, -0.4041232560157995, -0.4024144149823832, -0.40069042327368826, -0.39895134579702796, -0.3971972480276868, -0.3954281960064543, -0.39364425633713895, -0.391845496184061, -0.39003198326952393, -0.3882037858712637, -0.38636097281987886, -0.3845036134962396, -0.3826317778288747, -0.3807455362913388, -0.3788449598995595, -0.3769301202091643, -0.375001089312785, -0.3730579398373446, -0.37110074494132217, -0.369129578312, -0.36714451416268723, -0.36514562722992694, -0.3631329927706828, -0.3611066865595044, -0.35906678488567545, -0.35701336455034066, -0.3549465028636156, -0.3528662776416742, -0.3507727672038205, -0.34866605036953885, -0.3465462064555279, -0.3444133152727126, -0.3422674571232402, -0.34010871279745775, -0.3379371635708687, -0.3357528912010741, -0.3335559779246937, -0.3313465064542714, -0.3291245599751589, -0.3268902221423853, -0.32464357707750674, -0.32238470936544095, -0.32011370405128003, -0.31783064663709026, -0.3155356230786936, -0.3132287197824294, -0.310910023601903, -0.30857962183471477, -0.3062376022191747, -0.3038840529309972, -0.30151906257998284, -0.29914272020668126, -0.29675511527904036, -0.29435633768903596, -0.291946477749288, -0.28952562618966204, -0.2870938741538505, -0.2846513131959433, -0.2821980352769794, -0.2797341327614867, -0.2772596984140018, -0.2747748253955795, -0.272279607260284, -0.2697741379516687, -0.2672585117992364, -0.2647328235148896, -0.2621971681893654, -0.25965164128865337, -0.2570963386504025, -0.2545313564803125, -0.25195679134851307, -0.249372740185926, -0.24677930028061693, -0.24417656927413214, -0.24156464515782372, -0.23894362626915797, -0.23631361128801434, -0.23367469923297107, -0.2310269894575751, -0.2283705816466031, -0.2257055758123073, -0.2230320722906518, -0.22035017173753282, -0.21765997512499036, -0.21496158373740595, -0.21225509916769097, -0.2095406233134595, -0.20681825837319265, -0.2040881068423926, -0.201350271509721, -0.19860485545313067, -0.1958519620359841, -0.19309169490316316, -0.1903241579771651, -0.18754945545419036, -0.18476769180022157, -0.1819789717470877, -0.17918340028852237, -0.1763810826762107, -0.17357212441582767, -0.17075663126306373, -0.16793470921964476, -0.1651064645293404, -0.16227200367396544, -0.15943143336936863, -0.15658486056141607, -0.15373239242196593, -0.150874136344831, -0.1480101999417371, -0.14514069103827065, -0.1422657176698209, -0.1393853880775098, -0.13649981070411854, -0.1336090941900038, -0.13071334736900939, -0.12781267926436607, -0.124907199084588, -0.12199701621936268, -0.1190822402354297, -0.11616298087245737, -0.11323934803891039, -0.11031145180791331, -0.10737940241310391, -0.10444331024448478, -0.10150328584426603, -0.09855943990270565, -0.09561188325393892, -0.09266072687180736, -0.08970608186568149, -0.08674805947627548, -0.08378677107146043, -0.08082232814207051, -0.07785484229770748, -0.07488442526253568, -0.07191118887107757, -0.06893524506400243, -0.06595670588391339, -0.06297568347112673, -0.05999229005945093, -0.0570066379719626, -0.054018839616774894, -0.05102900748280725, -0.04803725413554912, -0.045043692212823956, -0.042048434420545816, -0.039051593528477625, -0.03605328236598467, -0.033053613817788485, -0.030052700819714017, -0.02705065635443913, -0.024047593447241996, -0.02104362516174334, -0.018038864595651273, -0.015033424876502353, -0.01202741915740419, -0.009020960612772648, -0.006014162434072701, -0.003007137825555923, -7.694871550068602e-17, 0.003007137825555769, 0.006014162434072548, 0.009020960612772496, 0.012027419157403997, 0.015033424876502161, 0.018038864595651082, 0.021043625161743185, 0.024047593447241843, 0.027050656354438936, 0.030052700819713826, 0.03305361381778829, 0.03605328236598448, 0.03905159352847744, 0.04204843442054566, 0.045043692212823803, 0.048037254135548935, 0.05102900748280705, 0.05401883961677474, 0.057006637971962405, 0.05999229005945073, 0.06297568347112653, 0.06595670588391324, 0.06893524506400224, 0.07191118887107739, 0.07488442526253548, 0.07785484229770732, 0.08082232814207035, 0.08378677107146024, 0.08674805947627533, 0.0897060818656813, 0.09266072687180717, 0.09561188325393877, 0.09855943990270549, 0.10150328584426592, 0.10444331024448458, 0.10737940241310372, 0.11031145180791312, 0.1132393480389102, 0.11616298087245722, 0.1190822402354295, 0.12199701621936249, 0.12490719908458785, 0.12781267926436587, 0.13071334736900925, 0.1336090941900036, 0.13649981070411835, 0.1393853880775096, 0.14226571766982077, 0.14514069103827051, 0.14801019994173695, 0.15087413634483085, 0.1537323924219658, 0.15658486056141593, 0.15943143336936844, 0.16227200367396524, 0.16510646452934022, 0.1679347092196446, 0.1707566312630636, 0.1735721244158275, 0.17638108267621055, 0.17918340028852223, 0.1819789717470875, 0.1847676918002214, 0.18754945545419022, 0.19032415797716493, 0.19309169490316302, 0.19585196203598396, 0.19860485545313053, 0.20135027150972085, 0.20408810684239242, 0.20681825837319248, 0.20954062331345932, 0.21225509916769084, 0.2149615837374058, 0.2176599751249902, 0.22035017173753269, 0.22303207229065167, 0.2257055758123071, 0.2283705816466029, 0.23102698945757494, 0.23367469923297088, 0.23631361128801417, 0.23894362626915777, 0.24156464515782358, 0.24417656927413198, 0.2467793002806168, 0.24937274018592584, 0.25195679134851295, 0.25453135648031233, 0.2570963386504023, 0.2596516412886532, 0.2621971681893652, 0.2647328235148894, 0.2672585117992362, 0.2697741379516685, 0.2722796072602839, 0.27477482539557935, 0.2772596984140016, 0.2797341327614865, 0.28219803527697923, 0.2846513131959431, 0.28709387415385035, 0.28952562618966193, 0.2919464777492879, 0.29435633768903585, 0.29675511527904025, 0.2991427202066811, 0.30151906257998273, 0.303884052930997, 0.3062376022191745, 0.3085796218347146, 0.3109100236019029, 0.3132287197824293, 0.31553562307869343, 0.31783064663709015, 0.32011370405127987, 0.3223847093654408, 0.32464357707750663, 0.3268902221423851, 0.32912455997515877, 0.3313465064542713, 0.33355597792469355, 0.335752891201074, 0.3379371635708686, 0.34010871279745764, 0.3422674571232401, 0.34441331527271246, ], ];
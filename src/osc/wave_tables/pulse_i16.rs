@@ -0,0 +1 @@
+pub static PULSE_I16: [[i16; 1024]; 10] = [[8907, 29959, 25011, 27947, 25851, 27484, 26145, 27281, 26293, 27167, 26383, 27095, 26443, 27045, 26485, 27008, 26517, 26980, 26542, 26958, 26562, 26940, 26579, 26925, 26592, 26912, 26604, 26902, 26613, 26893, 26622, 26885, 26629, 26878, 26636, 26872, 26641, 26866, 26647, 26862, 26651, 26857, 26655, 26853, 26659, 26850, 26662, 26846, 26666, 26843, 26668, 26841, 26671, 26838, 26673, 26836, 26676, 26834, 26678, 26832, 26680, 26830, 26681, 26828, 26683, 26827, 26684, 26825, 26686, 26824, 26687, 26823, 26688, 26822, 26689, 26820, 26691, 26819, 26692, 26818, 26692, 26818, 26693, 26817, 26694, 26816, 26695, 26815, 26696, 26815, 26696, 26814, 26697, 26813, 26697, 26813, 26698, 26812, 26698, 26812, 26699, 26811, 26699, 26811, 26700, 26811, 26700, 26810, 26700, 26810, 26701, 26810, 26701, 26810, 26701, 26809, 26701, 26809, 26701, 26809, 26701, 26809, 26702, 26809, 26702, 26809, 26702, 26809, 26702, 26809, 26702, 26809, 26702, 26809, 26702, 26809, 26701, 26809, 26701, 26809, 26701, 26809, 26701, 26810, 26701, 26810, 26701, 26810, 26700, 26810, 26700, 26811, 26700, 26811, 26699, 26811, 26699, 26812, 26698, 26812, 26698, 26813, 26697, 26813, 26697, 26814, 26696, 26815, 26696, 26815, 26695, 26816, 26694, 26817, 26693, 26818, 26692, 26818, 26692, 26819, 26691, 26820, 26689, 26822, 26688, 26823, 26687, 26824, 26686, 26825, 26684, 26827, 26683, 26828, 26681, 26830, 26680, 26832, 26678, 26834, 26676, 26836, 26673, 26838, 26671, 26841, 26668, 26843, 26666, 26846, 26662, 26850, 26659, 26853, 26655, 26857, 26651, 26862, 26647, 26866, 26641, 26872, 26636, 26878, 26629, 26885, 26622, 26893, 26613, 26902, 26604, 26912, 26592, 26925, 26579, 26940, 26562, 26958, 26542, 26980, 26517, 27008, 26485, 27045, 26443, 27095, 26383, 27167, 26293, 27281, 26145, 27484, 25851, 27947, 25011, 29959, 8907, -12100, -7196, -10088, -8036, -9625, -8330, -9422, -8479, -9308, -8568, -9236, -8628, -9186, -8671, -9149, -8703, -9121, -8728, -9098, -8748, -9080, -8764, -9066, -8778, -9053, -8789, -9043, -8799, -9033, -8808, -9025, -8815, -9019, -8821, -9012, -8827, -9007, -8833, -9002, -8837, -8997, -8841, -8993, -8845, -8990, -8849, -8987, -8852, -8983, -8855, -8981, -8858, -8978, -8860, -8976, -8862, -8973, -8864, -8971, -8866, -8969, -8868, -8968, -8870, -8966, -8872, -8964, -8873, -8963, -8875, -8961, -8876, -8960, -8877, -8959, -8879, -8958, -8880, -8957, -8881, -8956, -8882, -8955, -8883, -8954, -8884, -8953, -8885, -8952, -8885, -8951, -8886, -8950, -8887, -8949, -8888, -8949, -8888, -8948, -8889, -8947, -8890, -8947, -8890, -8946, -8891, -8946, -8892, -8945, -8892, -8944, -8893, -8944, -8893, -8943, -8894, -8943, -8894, -8942, -8895, -8942, -8895, -8942, -8895, -8941, -8896, -8941, -8896, -8940, -8897, -8940, -8897, -8940, -8897, -8939, -8898, -8939, -8898, -8939, -8898, -8938, -8899, -8938, -8899, -8938, -8899, -8937, -8900, -8937, -8900, -8937, -8900, -8937, -8900, -8936, -8901, -8936, -8901, -8936, -8901, -8936, -8901, -8935, -8901, -8935, -8902, -8935, -8902, -8935, -8902, -8935, -8902, -8934, -8903, -8934, -8903, -8934, -8903, -8934, -8903, -8934, -8903, -8934, -8903, -8933, -8904, -8933, -8904, -8933, -8904, -8933, -8904, -8933, -8904, -8933, -8904, -8932, -8904, -8932, -8905, -8932, -8905, -8932, -8905, -8932, -8905, -8932, -8905, -8932, -8905, -8932, -8905, -8931, -8905, -8931, -8906, -8931, -8906, -8931, -8906, -8931, -8906, -8931, -8906, -8931, -8906, -8931, -8906, -8931, -8906, -8930, -8906, -8930, -8906, -8930, -8907, -8930, -8907, -8930, -8907, -8930, -8907, -8930, -8907, -8930, -8907, -8930, -8907, -8930, -8907, -8930, -8907, -8930, -8907, -8930, -8907, -8929, -8907, -8929, -8907, -8929, -8908, -8929, -8908, -8929, -8908, -8929, -8908, -8929, -8908, -8929, -8908, -8929, -8908, -8929, -8908, -8929, -8908, -8929, -8908, -8929, -8908, -8929, -8908, -8929, -8908, -8929, -8908, -8929, -8908, -8929, -8908, -8928, -8908, -8928, -8908, -8928, -8908, -8928, -8908, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8909, -8928, -8908, -8928, -8908, -8928, -8908, -8928, -8908, -8928, -8908, -8929, -8908, -8929, -8908, -8929, -8908, -8929, -8908, -8929, -8908, -8929, -8908, -8929, -8908, -8929, -8908, -8929, -8908, -8929, -8908, -8929, -8908, -8929, -8908, -8929, -8908, -8929, -8908, -8929, -8908, -8929, -8907, -8929, -8907, -8929, -8907, -8930, -8907, -8930, -8907, -8930, -8907, -8930, -8907, -8930, -8907, -8930, -8907, -8930, -8907, -8930, -8907, -8930, -8907, -8930, -8907, -8930, -8906, -8930, -8906, -8930, -8906, -8931, -8906, -8931, -8906, -8931, -8906, -8931, -8906, -8931, -8906, -8931, -8906, -8931, -8906, -8931, -8905, -8931, -8905, -8932, -8905, -8932, -8905, -8932, -8905, -8932, -8905, -8932, -8905, -8932, -8905, -8932, -8904, -8932, -8904, -8933, -8904, -8933, -8904, -8933, -8904, -8933, -8904, -8933, -8904, -8933, -8903, -8934, -8903, -8934, -8903, -8934, -8903, -8934, -8903, -8934, -8903, -8934, -8902, -8935, -8902, -8935, -8902, -8935, -8902, -8935, -8901, -8935, -8901, -8936, -8901, -8936, -8901, -8936, -8901, -8936, -8900, -8937, -8900, -8937, -8900, -8937, -8900, -8937, -8899, -8938, -8899, -8938, -8899, -8938, -8898, -8939, -8898, -8939, -8898, -8939, -8897, -8940, -8897, -8940, -8897, -8940, -8896, -8941, -8896, -8941, -8895, -8942, -8895, -8942, -8895, -8942, -8894, -8943, -8894, -8943, -8893, -8944, -8893, -8944, -8892, -8945, -8892, -8946, -8891, -8946, -8890, -8947, -8890, -8947, -8889, -8948, -8888, -8949, -8888, -8949, -8887, -8950, -8886, -8951, -8885, -8952, -8885, -8953, -8884, -8954, -8883, -8955, -8882, -8956, -8881, -8957, -8880, -8958, -8879, -8959, -8877, -8960, -8876, -8961, -8875, -8963, -8873, -8964, -8872, -8966, -8870, -8968, -8868, -8969, -8866, -8971, -8864, -8973, -8862, -8976, -8860, -8978, -8858, -8981, -8855, -8983, -8852, -8987, -8849, -8990, -8845, -8993, -8841, -8997, -8837, -9002, -8833, -9007, -8827, -9012, -8821, -9019, -8815, -9025, -8808, -9033, -8799, -9043, -8789, -9053, -8778, -9066, -8764, -9080, -8748, -9098, -8728, -9121, -8703, -9149, -8671, -9186, -8628, -9236, -8568, -9308, -8479, -9422, -8330, -9625, -8036, -10088, -7196, -12100, ], [8896, 24484, 29970, 27182, 24999, 26585, 27959, 26845, 25839, 26700, 27496, 26792, 26133, 26728, 27293, 26775, 26281, 26740, 27180, 26768, 26370, 26745, 27108, 26764, 26429, 26748, 27058, 26761, 26472, 26750, 27022, 26760, 26503, 26751, 26994, 26759, 26528, 26752, 26973, 26758, 26547, 26753, 26955, 26758, 26563, 26753, 26941, 26757, 26576, 26753, 26929, 26757, 26587, 26754, 26919, 26757, 26596, 26754, 26910, 26756, 26604, 26754, 26903, 26756, 26611, 26754, 26897, 26756, 26616, 26754, 26891, 26756, 26621, 26755, 26887, 26756, 26626, 26755, 26883, 26756, 26630, 26755, 26879, 26756, 26633, 26755, 26876, 26756, 26636, 26755, 26873, 26756, 26638, 26755, 26871, 26756, 26641, 26755, 26869, 26756, 26643, 26755, 26867, 26755, 26644, 26755, 26866, 26755, 26645, 26755, 26865, 26755, 26646, 26755, 26864, 26755, 26647, 26755, 26863, 26755, 26648, 26755, 26863, 26755, 26648, 26755, 26862, 26755, 26648, 26755, 26862, 26755, 26648, 26755, 26863, 26755, 26648, 26755, 26863, 26755, 26647, 26755, 26864, 26755, 26646, 26755, 26865, 26755, 26645, 26755, 26866, 26755, 26644, 26755, 26867, 26755, 26643, 26756, 26869, 26755, 26641, 26756, 26871, 26755, 26638, 26756, 26873, 26755, 26636, 26756, 26876, 26755, 26633, 26756, 26879, 26755, 26630, 26756, 26883, 26755, 26626, 26756, 26887, 26755, 26621, 26756, 26891, 26754, 26616, 26756, 26897, 26754, 26611, 26756, 26903, 26754, 26604, 26756, 26910, 26754, 26596, 26757, 26919, 26754, 26587, 26757, 26929, 26753, 26576, 26757, 26941, 26753, 26563, 26758, 26955, 26753, 26547, 26758, 26973, 26752, 26528, 26759, 26994, 26751, 26503, 26760, 27022, 26750, 26472, 26761, 27058, 26748, 26429, 26764, 27108, 26745, 26370, 26768, 27180, 26740, 26281, 26775, 27293, 26728, 26133, 26792, 27496, 26700, 25839, 26845, 27959, 26585, 24999, 27182, 29970, 24484, 8896, -6647, -12089, -9345, -7207, -8749, -10077, -9008, -8047, -8863, -9615, -8956, -8340, -8892, -9412, -8939, -8488, -8903, -9299, -8931, -8578, -8908, -9227, -8927, -8637, -8911, -9177, -8925, -8680, -8913, -9140, -8923, -8712, -8914, -9112, -8922, -8736, -8915, -9090, -8921, -8756, -8916, -9072, -8921, -8772, -8916, -9058, -8920, -8785, -8917, -9046, -8920, -8797, -8917, -9035, -8920, -8806, -8917, -9026, -8920, -8815, -8917, -9019, -8920, -8822, -8917, -9012, -8919, -8828, -8917, -9006, -8919, -8834, -8918, -9001, -8919, -8839, -8918, -8996, -8919, -8843, -8918, -8992, -8919, -8847, -8918, -8988, -8919, -8851, -8918, -8984, -8919, -8854, -8918, -8981, -8919, -8857, -8918, -8978, -8919, -8860, -8918, -8976, -8919, -8862, -8918, -8973, -8919, -8865, -8918, -8971, -8919, -8867, -8918, -8969, -8919, -8869, -8918, -8967, -8919, -8871, -8918, -8965, -8919, -8872, -8918, -8964, -8919, -8874, -8918, -8962, -8919, -8876, -8918, -8961, -8919, -8877, -8918, -8959, -8919, -8878, -8918, -8958, -8919, -8879, -8918, -8957, -8919, -8881, -8918, -8956, -8919, -8882, -8918, -8955, -8919, -8883, -8918, -8954, -8919, -8884, -8918, -8953, -8919, -8885, -8918, -8952, -8919, -8885, -8918, -8951, -8919, -8886, -8918, -8950, -8919, -8887, -8918, -8949, -8919, -8888, -8918, -8949, -8919, -8888, -8918, -8948, -8919, -8889, -8918, -8947, -8919, -8890, -8918, -8947, -8919, -8890, -8918, -8946, -8919, -8891, -8918, -8946, -8918, -8891, -8918, -8945, -8918, -8892, -8918, -8945, -8918, -8892, -8918, -8944, -8918, -8893, -8918, -8944, -8918, -8893, -8918, -8943, -8918, -8894, -8918, -8943, -8918, -8894, -8918, -8943, -8918, -8894, -8918, -8942, -8918, -8895, -8918, -8942, -8918, -8895, -8918, -8942, -8918, -8895, -8918, -8941, -8918, -8896, -8918, -8941, -8918, -8896, -8918, -8941, -8918, -8896, -8918, -8940, -8918, -8897, -8918, -8940, -8918, -8897, -8918, -8940, -8918, -8897, -8918, -8940, -8918, -8897, -8918, -8939, -8918, -8897, -8918, -8939, -8918, -8898, -8918, -8939, -8918, -8898, -8918, -8939, -8918, -8898, -8918, -8939, -8918, -8898, -8918, -8939, -8918, -8898, -8918, -8938, -8918, -8899, -8918, -8938, -8918, -8899, -8918, -8938, -8918, -8899, -8918, -8938, -8918, -8899, -8918, -8938, -8918, -8899, -8918, -8938, -8918, -8899, -8918, -8938, -8918, -8899, -8918, -8937, -8918, -8899, -8918, -8937, -8918, -8899, -8918, -8937, -8918, -8900, -8918, -8937, -8918, -8900, -8918, -8937, -8918, -8900, -8918, -8937, -8918, -8900, -8918, -8937, -8918, -8900, -8918, -8937, -8918, -8900, -8918, -8937, -8918, -8900, -8918, -8937, -8918, -8900, -8918, -8937, -8918, -8900, -8918, -8937, -8918, -8900, -8918, -8937, -8918, -8900, -8918, -8937, -8918, -8900, -8918, -8937, -8918, -8900, -8918, -8937, -8918, -8900, -8918, -8937, -8918, -8900, -8918, -8937, -8918, -8900, -8918, -8937, -8918, -8900, -8918, -8937, -8918, -8900, -8918, -8937, -8918, -8900, -8918, -8937, -8918, -8900, -8918, -8937, -8918, -8900, -8918, -8937, -8918, -8900, -8918, -8937, -8918, -8900, -8918, -8937, -8918, -8900, -8918, -8937, -8918, -8900, -8918, -8937, -8918, -8899, -8918, -8937, -8918, -8899, -8918, -8937, -8918, -8899, -8918, -8938, -8918, -8899, -8918, -8938, -8918, -8899, -8918, -8938, -8918, -8899, -8918, -8938, -8918, -8899, -8918, -8938, -8918, -8899, -8918, -8938, -8918, -8899, -8918, -8938, -8918, -8898, -8918, -8939, -8918, -8898, -8918, -8939, -8918, -8898, -8918, -8939, -8918, -8898, -8918, -8939, -8918, -8898, -8918, -8939, -8918, -8897, -8918, -8939, -8918, -8897, -8918, -8940, -8918, -8897, -8918, -8940, -8918, -8897, -8918, -8940, -8918, -8897, -8918, -8940, -8918, -8896, -8918, -8941, -8918, -8896, -8918, -8941, -8918, -8896, -8918, -8941, -8918, -8895, -8918, -8942, -8918, -8895, -8918, -8942, -8918, -8895, -8918, -8942, -8918, -8894, -8918, -8943, -8918, -8894, -8918, -8943, -8918, -8894, -8918, -8943, -8918, -8893, -8918, -8944, -8918, -8893, -8918, -8944, -8918, -8892, -8918, -8945, -8918, -8892, -8918, -8945, -8918, -8891, -8918, -8946, -8918, -8891, -8919, -8946, -8918, -8890, -8919, -8947, -8918, -8890, -8919, -8947, -8918, -8889, -8919, -8948, -8918, -8888, -8919, -8949, -8918, -8888, -8919, -8949, -8918, -8887, -8919, -8950, -8918, -8886, -8919, -8951, -8918, -8885, -8919, -8952, -8918, -8885, -8919, -8953, -8918, -8884, -8919, -8954, -8918, -8883, -8919, -8955, -8918, -8882, -8919, -8956, -8918, -8881, -8919, -8957, -8918, -8879, -8919, -8958, -8918, -8878, -8919, -8959, -8918, -8877, -8919, -8961, -8918, -8876, -8919, -8962, -8918, -8874, -8919, -8964, -8918, -8872, -8919, -8965, -8918, -8871, -8919, -8967, -8918, -8869, -8919, -8969, -8918, -8867, -8919, -8971, -8918, -8865, -8919, -8973, -8918, -8862, -8919, -8976, -8918, -8860, -8919, -8978, -8918, -8857, -8919, -8981, -8918, -8854, -8919, -8984, -8918, -8851, -8919, -8988, -8918, -8847, -8919, -8992, -8918, -8843, -8919, -8996, -8918, -8839, -8919, -9001, -8918, -8834, -8919, -9006, -8917, -8828, -8919, -9012, -8917, -8822, -8920, -9019, -8917, -8815, -8920, -9026, -8917, -8806, -8920, -9035, -8917, -8797, -8920, -9046, -8917, -8785, -8920, -9058, -8916, -8772, -8921, -9072, -8916, -8756, -8921, -9090, -8915, -8736, -8922, -9112, -8914, -8712, -8923, -9140, -8913, -8680, -8925, -9177, -8911, -8637, -8927, -9227, -8908, -8578, -8931, -9299, -8903, -8488, -8939, -9412, -8892, -8340, -8956, -9615, -8863, -8047, -9008, -10077, -8749, -7207, -9345, -12089, -6647, ], [8874, 17505, 24484, 28706, 29993, 29068, 27182, 25567, 24976, 25479, 26586, 27596, 27983, 27635, 26845, 26104, 25814, 26083, 26700, 27288, 27522, 27302, 26792, 26302, 26106, 26292, 26729, 27151, 27321, 27158, 26775, 26403, 26252, 26397, 26740, 27074, 27210, 27078, 26768, 26463, 26340, 26460, 26745, 27025, 27140, 27028, 26763, 26503, 26397, 26501, 26748, 26992, 27092, 26994, 26761, 26531, 26437, 26529, 26750, 26968, 27058, 26970, 26760, 26551, 26466, 26550, 26752, 26951, 27033, 26952, 26758, 26567, 26488, 26566, 26752, 26938, 27014, 26938, 26758, 26578, 26504, 26577, 26753, 26928, 27000, 26928, 26757, 26587, 26517, 26586, 26754, 26920, 26989, 26921, 26757, 26593, 26526, 26593, 26754, 26914, 26981, 26915, 26756, 26598, 26533, 26598, 26754, 26911, 26975, 26911, 26756, 26601, 26538, 26601, 26755, 26908, 26971, 26908, 26756, 26603, 26540, 26603, 26755, 26907, 26970, 26907, 26755, 26604, 26541, 26604, 26755, 26907, 26970, 26907, 26755, 26603, 26540, 26603, 26756, 26908, 26971, 26908, 26755, 26601, 26538, 26601, 26756, 26911, 26975, 26911, 26754, 26598, 26533, 26598, 26756, 26915, 26981, 26914, 26754, 26593, 26526, 26593, 26757, 26921, 26989, 26920, 26754, 26586, 26517, 26587, 26757, 26928, 27000, 26928, 26753, 26577, 26504, 26578, 26758, 26938, 27014, 26938, 26752, 26566, 26488, 26567, 26758, 26952, 27033, 26951, 26752, 26550, 26466, 26551, 26760, 26970, 27058, 26968, 26750, 26529, 26437, 26531, 26761, 26994, 27092, 26992, 26748, 26501, 26397, 26503, 26763, 27028, 27140, 27025, 26745, 26460, 26340, 26463, 26768, 27078, 27210, 27074, 26740, 26397, 26252, 26403, 26775, 27158, 27321, 27151, 26729, 26292, 26106, 26302, 26792, 27302, 27522, 27288, 26700, 26083, 25814, 26104, 26845, 27635, 27983, 27596, 26586, 25479, 24976, 25567, 27182, 29068, 29993, 28706, 24484, 17505, 8874, 269, -6647, -10806, -12067, -11169, -9345, -7793, -7228, -7705, -8749, -9696, -10057, -9735, -9008, -8331, -8066, -8309, -8863, -9388, -9596, -9402, -8955, -8529, -8359, -8519, -8892, -9251, -9394, -9258, -8938, -8630, -8506, -8624, -8903, -9173, -9282, -9177, -8931, -8691, -8594, -8688, -8908, -9124, -9211, -9126, -8927, -8732, -8653, -8729, -8911, -9089, -9162, -9091, -8925, -8761, -8694, -8759, -8913, -9064, -9126, -9066, -8923, -8783, -8725, -8781, -8914, -9045, -9099, -9047, -8922, -8799, -8749, -8798, -8915, -9031, -9078, -9032, -8921, -8813, -8768, -8812, -8916, -9019, -9061, -9019, -8921, -8823, -8783, -8823, -8916, -9009, -9047, -9010, -8920, -8832, -8796, -8832, -8917, -9001, -9035, -9001, -8920, -8840, -8807, -8839, -8917, -8994, -9026, -8994, -8920, -8846, -8815, -8845, -8917, -8988, -9017, -8989, -8920, -8851, -8823, -8851, -8917, -8983, -9010, -8984, -8919, -8856, -8830, -8856, -8917, -8979, -9004, -8979, -8919, -8860, -8835, -8860, -8918, -8975, -8999, -8975, -8919, -8863, -8841, -8863, -8918, -8972, -8994, -8972, -8919, -8867, -8845, -8866, -8918, -8969, -8990, -8969, -8919, -8869, -8849, -8869, -8918, -8966, -8986, -8966, -8919, -8872, -8852, -8872, -8918, -8964, -8983, -8964, -8919, -8874, -8856, -8874, -8918, -8962, -8980, -8962, -8919, -8876, -8858, -8876, -8918, -8960, -8977, -8960, -8919, -8878, -8861, -8878, -8918, -8958, -8975, -8958, -8919, -8879, -8863, -8879, -8918, -8957, -8973, -8957, -8919, -8881, -8865, -8881, -8918, -8955, -8971, -8955, -8919, -8882, -8867, -8882, -8918, -8954, -8969, -8954, -8919, -8883, -8869, -8883, -8918, -8953, -8967, -8953, -8919, -8884, -8870, -8884, -8918, -8952, -8966, -8952, -8919, -8885, -8872, -8885, -8918, -8951, -8965, -8951, -8919, -8886, -8873, -8886, -8918, -8950, -8963, -8950, -8919, -8887, -8874, -8887, -8918, -8949, -8962, -8949, -8919, -8888, -8875, -8888, -8918, -8949, -8961, -8949, -8919, -8888, -8876, -8888, -8918, -8948, -8960, -8948, -8919, -8889, -8877, -8889, -8918, -8947, -8960, -8948, -8919, -8890, -8878, -8890, -8918, -8947, -8959, -8947, -8919, -8890, -8878, -8890, -8918, -8947, -8958, -8947, -8919, -8891, -8879, -8890, -8918, -8946, -8958, -8946, -8918, -8891, -8879, -8891, -8918, -8946, -8957, -8946, -8918, -8891, -8880, -8891, -8918, -8945, -8957, -8945, -8918, -8892, -8880, -8892, -8918, -8945, -8956, -8945, -8918, -8892, -8881, -8892, -8918, -8945, -8956, -8945, -8918, -8892, -8881, -8892, -8918, -8945, -8956, -8945, -8918, -8892, -8881, -8892, -8918, -8945, -8955, -8945, -8918, -8892, -8881, -8892, -8918, -8945, -8955, -8945, -8918, -8892, -8882, -8892, -8918, -8944, -8955, -8944, -8918, -8892, -8882, -8892, -8918, -8944, -8955, -8944, -8918, -8892, -8882, -8892, -8918, -8944, -8955, -8944, -8918, -8892, -8882, -8892, -8918, -8944, -8955, -8944, -8918, -8892, -8882, -8892, -8918, -8945, -8955, -8945, -8918, -8892, -8881, -8892, -8918, -8945, -8955, -8945, -8918, -8892, -8881, -8892, -8918, -8945, -8956, -8945, -8918, -8892, -8881, -8892, -8918, -8945, -8956, -8945, -8918, -8892, -8881, -8892, -8918, -8945, -8956, -8945, -8918, -8892, -8880, -8892, -8918, -8945, -8957, -8945, -8918, -8891, -8880, -8891, -8918, -8946, -8957, -8946, -8918, -8891, -8879, -8891, -8918, -8946, -8958, -8946, -8918, -8890, -8879, -8891, -8919, -8947, -8958, -8947, -8918, -8890, -8878, -8890, -8919, -8947, -8959, -8947, -8918, -8890, -8878, -8890, -8919, -8948, -8960, -8947, -8918, -8889, -8877, -8889, -8919, -8948, -8960, -8948, -8918, -8888, -8876, -8888, -8919, -8949, -8961, -8949, -8918, -8888, -8875, -8888, -8919, -8949, -8962, -8949, -8918, -8887, -8874, -8887, -8919, -8950, -8963, -8950, -8918, -8886, -8873, -8886, -8919, -8951, -8965, -8951, -8918, -8885, -8872, -8885, -8919, -8952, -8966, -8952, -8918, -8884, -8870, -8884, -8919, -8953, -8967, -8953, -8918, -8883, -8869, -8883, -8919, -8954, -8969, -8954, -8918, -8882, -8867, -8882, -8919, -8955, -8971, -8955, -8918, -8881, -8865, -8881, -8919, -8957, -8973, -8957, -8918, -8879, -8863, -8879, -8919, -8958, -8975, -8958, -8918, -8878, -8861, -8878, -8919, -8960, -8977, -8960, -8918, -8876, -8858, -8876, -8919, -8962, -8980, -8962, -8918, -8874, -8856, -8874, -8919, -8964, -8983, -8964, -8918, -8872, -8852, -8872, -8919, -8966, -8986, -8966, -8918, -8869, -8849, -8869, -8919, -8969, -8990, -8969, -8918, -8866, -8845, -8867, -8919, -8972, -8994, -8972, -8918, -8863, -8841, -8863, -8919, -8975, -8999, -8975, -8918, -8860, -8835, -8860, -8919, -8979, -9004, -8979, -8917, -8856, -8830, -8856, -8919, -8984, -9010, -8983, -8917, -8851, -8823, -8851, -8920, -8989, -9017, -8988, -8917, -8845, -8815, -8846, -8920, -8994, -9026, -8994, -8917, -8839, -8807, -8840, -8920, -9001, -9035, -9001, -8917, -8832, -8796, -8832, -8920, -9010, -9047, -9009, -8916, -8823, -8783, -8823, -8921, -9019, -9061, -9019, -8916, -8812, -8768, -8813, -8921, -9032, -9078, -9031, -8915, -8798, -8749, -8799, -8922, -9047, -9099, -9045, -8914, -8781, -8725, -8783, -8923, -9066, -9126, -9064, -8913, -8759, -8694, -8761, -8925, -9091, -9162, -9089, -8911, -8729, -8653, -8732, -8927, -9126, -9211, -9124, -8908, -8688, -8594, -8691, -8931, -9177, -9282, -9173, -8903, -8624, -8506, -8630, -8938, -9258, -9394, -9251, -8892, -8519, -8359, -8529, -8955, -9402, -9596, -9388, -8863, -8309, -8066, -8331, -9008, -9735, -10057, -9696, -8749, -7705, -7228, -7793, -9345, -11169, -12067, -10806, -6647, 269, ], [8830, 13258, 17474, 21273, 24485, 26993, 28739, 29733, 30040, 29779, 29102, 28179, 27181, 26258, 25531, 25076, 24926, 25065, 25443, 25982, 26587, 27165, 27634, 27934, 28036, 27940, 27672, 27285, 26844, 26416, 26064, 25835, 25757, 25833, 26042, 26349, 26702, 27046, 27332, 27518, 27583, 27520, 27345, 27088, 26791, 26499, 26256, 26096, 26041, 26095, 26247, 26470, 26730, 26986, 27200, 27341, 27390, 27342, 27207, 27007, 26774, 26543, 26350, 26222, 26178, 26222, 26345, 26528, 26742, 26953, 27131, 27248, 27289, 27249, 27134, 26964, 26766, 26568, 26402, 26292, 26254, 26292, 26400, 26560, 26747, 26934, 27091, 27195, 27232, 27196, 27093, 26940, 26761, 26583, 26432, 26332, 26297, 26332, 26431, 26578, 26751, 26923, 27069, 27166, 27200, 27166, 27070, 26927, 26758, 26590, 26448, 26353, 26320, 26353, 26447, 26588, 26754, 26919, 27059, 27152, 27185, 27152, 27059, 26920, 26756, 26592, 26453, 26360, 26327, 26360, 26453, 26592, 26756, 26920, 27059, 27152, 27185, 27152, 27059, 26919, 26754, 26588, 26447, 26353, 26320, 26353, 26448, 26590, 26758, 26927, 27070, 27166, 27200, 27166, 27069, 26923, 26751, 26578, 26431, 26332, 26297, 26332, 26432, 26583, 26761, 26940, 27093, 27196, 27232, 27195, 27091, 26934, 26747, 26560, 26400, 26292, 26254, 26292, 26402, 26568, 26766, 26964, 27134, 27249, 27289, 27248, 27131, 26953, 26742, 26528, 26345, 26222, 26178, 26222, 26350, 26543, 26774, 27007, 27207, 27342, 27390, 27341, 27200, 26986, 26730, 26470, 26247, 26095, 26041, 26096, 26256, 26499, 26791, 27088, 27345, 27520, 27583, 27518, 27332, 27046, 26702, 26349, 26042, 25833, 25757, 25835, 26064, 26416, 26844, 27285, 27672, 27940, 28036, 27934, 27634, 27165, 26587, 25982, 25443, 25065, 24926, 25076, 25531, 26258, 27181, 28179, 29102, 29779, 30040, 29733, 28739, 26993, 24485, 21273, 17474, 13258, 8830, 4415, 237, -3504, -6648, -9088, -10777, -11732, -12026, -11778, -11139, -10274, -9344, -8490, -7820, -7404, -7267, -7393, -7732, -8213, -8749, -9259, -9670, -9932, -10020, -9937, -9709, -9380, -9007, -8648, -8355, -8166, -8101, -8163, -8333, -8581, -8864, -9139, -9365, -9512, -9563, -9514, -9379, -9182, -8955, -8733, -8550, -8431, -8389, -8429, -8541, -8704, -8892, -9077, -9230, -9331, -9365, -9331, -9237, -9098, -8938, -8780, -8649, -8563, -8533, -8562, -8644, -8764, -8903, -9041, -9155, -9230, -9256, -9231, -9159, -9053, -8930, -8809, -8708, -8641, -8618, -8641, -8705, -8799, -8909, -9017, -9108, -9167, -9188, -9168, -9110, -9025, -8926, -8829, -8747, -8693, -8674, -8692, -8744, -8822, -8912, -9001, -9075, -9125, -9142, -9125, -9077, -9007, -8924, -8842, -8774, -8728, -8713, -8728, -8772, -8837, -8914, -8989, -9052, -9094, -9109, -9094, -9054, -8993, -8923, -8853, -8794, -8755, -8741, -8755, -8793, -8849, -8915, -8980, -9035, -9071, -9084, -9072, -9036, -8983, -8922, -8860, -8809, -8775, -8763, -8775, -8808, -8858, -8916, -8973, -9022, -9054, -9065, -9054, -9023, -8976, -8921, -8866, -8821, -8790, -8779, -8790, -8820, -8864, -8916, -8968, -9011, -9040, -9050, -9040, -9012, -8970, -8920, -8871, -8830, -8802, -8793, -8802, -8829, -8869, -8917, -8964, -9003, -9029, -9039, -9029, -9004, -8965, -8920, -8875, -8837, -8812, -8803, -8812, -8837, -8874, -8917, -8960, -8996, -9020, -9029, -9021, -8997, -8961, -8920, -8878, -8843, -8820, -8812, -8820, -8843, -8877, -8917, -8957, -8991, -9013, -9021, -9013, -8991, -8958, -8919, -8881, -8848, -8827, -8819, -8827, -8848, -8880, -8917, -8955, -8986, -9007, -9015, -9007, -8987, -8956, -8919, -8883, -8852, -8832, -8825, -8832, -8852, -8882, -8918, -8953, -8983, -9002, -9009, -9002, -8983, -8954, -8919, -8885, -8856, -8836, -8830, -8836, -8856, -8884, -8918, -8951, -8980, -8998, -9005, -8998, -8980, -8952, -8919, -8886, -8859, -8840, -8834, -8840, -8858, -8886, -8918, -8950, -8977, -8995, -9001, -8995, -8977, -8950, -8919, -8887, -8861, -8843, -8837, -8843, -8861, -8887, -8918, -8949, -8975, -8992, -8999, -8992, -8975, -8949, -8919, -8888, -8863, -8845, -8839, -8845, -8863, -8888, -8918, -8948, -8973, -8990, -8996, -8990, -8974, -8948, -8919, -8889, -8864, -8847, -8842, -8847, -8864, -8889, -8918, -8947, -8972, -8989, -8995, -8989, -8972, -8948, -8919, -8890, -8865, -8849, -8843, -8849, -8865, -8889, -8918, -8947, -8971, -8988, -8993, -8988, -8971, -8947, -8919, -8890, -8866, -8850, -8844, -8850, -8866, -8890, -8918, -8947, -8971, -8987, -8992, -8987, -8971, -8947, -8918, -8890, -8866, -8850, -8845, -8850, -8866, -8890, -8918, -8947, -8970, -8986, -8992, -8986, -8970, -8947, -8918, -8890, -8866, -8851, -8845, -8851, -8866, -8890, -8918, -8947, -8970, -8986, -8992, -8986, -8970, -8947, -8918, -8890, -8866, -8850, -8845, -8850, -8866, -8890, -8918, -8947, -8971, -8987, -8992, -8987, -8971, -8947, -8918, -8890, -8866, -8850, -8844, -8850, -8866, -8890, -8919, -8947, -8971, -8988, -8993, -8988, -8971, -8947, -8918, -8889, -8865, -8849, -8843, -8849, -8865, -8890, -8919, -8948, -8972, -8989, -8995, -8989, -8972, -8947, -8918, -8889, -8864, -8847, -8842, -8847, -8864, -8889, -8919, -8948, -8974, -8990, -8996, -8990, -8973, -8948, -8918, -8888, -8863, -8845, -8839, -8845, -8863, -8888, -8919, -8949, -8975, -8992, -8999, -8992, -8975, -8949, -8918, -8887, -8861, -8843, -8837, -8843, -8861, -8887, -8919, -8950, -8977, -8995, -9001, -8995, -8977, -8950, -8918, -8886, -8858, -8840, -8834, -8840, -8859, -8886, -8919, -8952, -8980, -8998, -9005, -8998, -8980, -8951, -8918, -8884, -8856, -8836, -8830, -8836, -8856, -8885, -8919, -8954, -8983, -9002, -9009, -9002, -8983, -8953, -8918, -8882, -8852, -8832, -8825, -8832, -8852, -8883, -8919, -8956, -8987, -9007, -9015, -9007, -8986, -8955, -8917, -8880, -8848, -8827, -8819, -8827, -8848, -8881, -8919, -8958, -8991, -9013, -9021, -9013, -8991, -8957, -8917, -8877, -8843, -8820, -8812, -8820, -8843, -8878, -8920, -8961, -8997, -9021, -9029, -9020, -8996, -8960, -8917, -8874, -8837, -8812, -8803, -8812, -8837, -8875, -8920, -8965, -9004, -9029, -9039, -9029, -9003, -8964, -8917, -8869, -8829, -8802, -8793, -8802, -8830, -8871, -8920, -8970, -9012, -9040, -9050, -9040, -9011, -8968, -8916, -8864, -8820, -8790, -8779, -8790, -8821, -8866, -8921, -8976, -9023, -9054, -9065, -9054, -9022, -8973, -8916, -8858, -8808, -8775, -8763, -8775, -8809, -8860, -8922, -8983, -9036, -9072, -9084, -9071, -9035, -8980, -8915, -8849, -8793, -8755, -8741, -8755, -8794, -8853, -8923, -8993, -9054, -9094, -9109, -9094, -9052, -8989, -8914, -8837, -8772, -8728, -8713, -8728, -8774, -8842, -8924, -9007, -9077, -9125, -9142, -9125, -9075, -9001, -8912, -8822, -8744, -8692, -8674, -8693, -8747, -8829, -8926, -9025, -9110, -9168, -9188, -9167, -9108, -9017, -8909, -8799, -8705, -8641, -8618, -8641, -8708, -8809, -8930, -9053, -9159, -9231, -9256, -9230, -9155, -9041, -8903, -8764, -8644, -8562, -8533, -8563, -8649, -8780, -8938, -9098, -9237, -9331, -9365, -9331, -9230, -9077, -8892, -8704, -8541, -8429, -8389, -8431, -8550, -8733, -8955, -9182, -9379, -9514, -9563, -9512, -9365, -9139, -8864, -8581, -8333, -8163, -8101, -8166, -8355, -8648, -9007, -9380, -9709, -9937, -10020, -9932, -9670, -9259, -8749, -8213, -7732, -7393, -7267, -7404, -7820, -8490, -9344, -10274, -11139, -11778, -12026, -11732, -10777, -9088, -6648, -3504, 237, 4415, ], [8741, 10969, 13176, 15333, 17412, 19389, 21241, 22946, 24489, 25854, 27033, 28019, 28811, 29411, 29825, 30064, 30140, 30070, 29871, 29565, 29172, 28715, 28215, 27696, 27176, 26676, 26213, 25800, 25450, 25172, 24971, 24851, 24812, 24850, 24960, 25135, 25364, 25636, 25941, 26263, 26592, 26913, 27216, 27490, 27726, 27916, 28054, 28138, 28166, 28139, 28059, 27932, 27763, 27560, 27332, 27088, 26838, 26591, 26356, 26143, 25958, 25808, 25698, 25631, 25609, 25631, 25696, 25800, 25939, 26106, 26295, 26499, 26709, 26917, 27115, 27296, 27453, 27582, 27676, 27734, 27753, 27734, 27678, 27586, 27465, 27317, 27149, 26969, 26782, 26596, 26419, 26256, 26114, 25998, 25913, 25860, 25842, 25860, 25912, 25996, 26108, 26244, 26400, 26567, 26741, 26914, 27080, 27233, 27367, 27476, 27557, 27606, 27623, 27606, 27557, 27477, 27369, 27238, 27089, 26928, 26760, 26592, 26431, 26282, 26152, 26046, 25967, 25918, 25901, 25918, 25967, 26046, 26152, 26282, 26431, 26592, 26760, 26928, 27089, 27238, 27369, 27477, 27557, 27606, 27623, 27606, 27557, 27476, 27367, 27233, 27080, 26914, 26741, 26567, 26400, 26244, 26108, 25996, 25912, 25860, 25842, 25860, 25913, 25998, 26114, 26256, 26419, 26596, 26782, 26969, 27149, 27317, 27465, 27586, 27678, 27734, 27753, 27734, 27676, 27582, 27453, 27296, 27115, 26917, 26709, 26499, 26295, 26106, 25939, 25800, 25696, 25631, 25609, 25631, 25698, 25808, 25958, 26143, 26356, 26591, 26838, 27088, 27332, 27560, 27763, 27932, 28059, 28139, 28166, 28138, 28054, 27916, 27726, 27490, 27216, 26913, 26592, 26263, 25941, 25636, 25364, 25135, 24960, 24850, 24812, 24851, 24971, 25172, 25450, 25800, 26213, 26676, 27176, 27696, 28215, 28715, 29172, 29565, 29871, 30070, 30140, 30064, 29825, 29411, 28811, 28019, 27033, 25854, 24489, 22946, 21241, 19389, 17412, 15333, 13176, 10969, 8741, 6520, 4333, 2210, 174, -1749, -3540, -5178, -6651, -7947, -9059, -9984, -10722, -11278, -11659, -11878, -11947, -11884, -11705, -11432, -11083, -10680, -10243, -9791, -9341, -8911, -8515, -8164, -7869, -7636, -7468, -7369, -7336, -7368, -7457, -7598, -7782, -8000, -8241, -8495, -8752, -9002, -9237, -9447, -9627, -9771, -9875, -9938, -9959, -9939, -9880, -9787, -9665, -9519, -9355, -9182, -9005, -8832, -8668, -8520, -8393, -8291, -8216, -8171, -8156, -8170, -8213, -8282, -8372, -8481, -8603, -8733, -8866, -8997, -9122, -9234, -9332, -9410, -9468, -9503, -9515, -9503, -9470, -9416, -9345, -9259, -9163, -9059, -8953, -8848, -8749, -8658, -8580, -8516, -8469, -8441, -8432, -8441, -8468, -8512, -8571, -8641, -8721, -8806, -8894, -8981, -9064, -9139, -9204, -9257, -9297, -9320, -9328, -9320, -9297, -9260, -9211, -9151, -9084, -9011, -8936, -8862, -8792, -8727, -8671, -8626, -8592, -8572, -8565, -8572, -8592, -8624, -8667, -8718, -8777, -8840, -8905, -8969, -9031, -9087, -9136, -9176, -9205, -9223, -9229, -9223, -9206, -9177, -9140, -9094, -9042, -8987, -8929, -8872, -8817, -8767, -8724, -8688, -8662, -8646, -8641, -8646, -8662, -8687, -8721, -8762, -8808, -8858, -8910, -8961, -9010, -9055, -9095, -9127, -9150, -9165, -9169, -9165, -9150, -9128, -9097, -9060, -9018, -8972, -8925, -8878, -8834, -8793, -8757, -8728, -8706, -8693, -8689, -8693, -8706, -8727, -8755, -8789, -8828, -8870, -8913, -8956, -8997, -9035, -9068, -9095, -9115, -9127, -9131, -9127, -9115, -9095, -9069, -9038, -9002, -8963, -8923, -8883, -8845, -8809, -8779, -8754, -8735, -8724, -8720, -8724, -8735, -8753, -8778, -8807, -8841, -8877, -8915, -8952, -8988, -9021, -9050, -9074, -9091, -9102, -9105, -9102, -9091, -9074, -9051, -9023, -8991, -8957, -8921, -8886, -8852, -8821, -8794, -8771, -8755, -8745, -8741, -8745, -8755, -8771, -8793, -8819, -8849, -8882, -8916, -8950, -8982, -9012, -9038, -9059, -9075, -9085, -9088, -9085, -9075, -9059, -9038, -9013, -8984, -8953, -8920, -8888, -8857, -8828, -8804, -8783, -8768, -8759, -8755, -8759, -8768, -8783, -8803, -8827, -8855, -8885, -8917, -8948, -8978, -9006, -9030, -9050, -9064, -9073, -9076, -9073, -9064, -9050, -9030, -9006, -8979, -8950, -8920, -8889, -8860, -8833, -8810, -8791, -8776, -8768, -8765, -8768, -8776, -8791, -8810, -8833, -8859, -8888, -8917, -8947, -8976, -9002, -9025, -9044, -9058, -9066, -9069, -9066, -9058, -9044, -9025, -9002, -8976, -8948, -8919, -8890, -8862, -8836, -8813, -8795, -8781, -8773, -8770, -8773, -8781, -8795, -8813, -8836, -8861, -8889, -8918, -8947, -8975, -9000, -9023, -9041, -9055, -9063, -9066, -9063, -9055, -9041, -9023, -9000, -8975, -8947, -8919, -8890, -8862, -8837, -8815, -8796, -8783, -8774, -8772, -8774, -8783, -8796, -8815, -8837, -8862, -8890, -8919, -8947, -8975, -9000, -9023, -9041, -9055, -9063, -9066, -9063, -9055, -9041, -9023, -9000, -8975, -8947, -8918, -8889, -8861, -8836, -8813, -8795, -8781, -8773, -8770, -8773, -8781, -8795, -8813, -8836, -8862, -8890, -8919, -8948, -8976, -9002, -9025, -9044, -9058, -9066, -9069, -9066, -9058, -9044, -9025, -9002, -8976, -8947, -8917, -8888, -8859, -8833, -8810, -8791, -8776, -8768, -8765, -8768, -8776, -8791, -8810, -8833, -8860, -8889, -8920, -8950, -8979, -9006, -9030, -9050, -9064, -9073, -9076, -9073, -9064, -9050, -9030, -9006, -8978, -8948, -8917, -8885, -8855, -8827, -8803, -8783, -8768, -8759, -8755, -8759, -8768, -8783, -8804, -8828, -8857, -8888, -8920, -8953, -8984, -9013, -9038, -9059, -9075, -9085, -9088, -9085, -9075, -9059, -9038, -9012, -8982, -8950, -8916, -8882, -8849, -8819, -8793, -8771, -8755, -8745, -8741, -8745, -8755, -8771, -8794, -8821, -8852, -8886, -8921, -8957, -8991, -9023, -9051, -9074, -9091, -9102, -9105, -9102, -9091, -9074, -9050, -9021, -8988, -8952, -8915, -8877, -8841, -8807, -8778, -8753, -8735, -8724, -8720, -8724, -8735, -8754, -8779, -8809, -8845, -8883, -8923, -8963, -9002, -9038, -9069, -9095, -9115, -9127, -9131, -9127, -9115, -9095, -9068, -9035, -8997, -8956, -8913, -8870, -8828, -8789, -8755, -8727, -8706, -8693, -8689, -8693, -8706, -8728, -8757, -8793, -8834, -8878, -8925, -8972, -9018, -9060, -9097, -9128, -9150, -9165, -9169, -9165, -9150, -9127, -9095, -9055, -9010, -8961, -8910, -8858, -8808, -8762, -8721, -8687, -8662, -8646, -8641, -8646, -8662, -8688, -8724, -8767, -8817, -8872, -8929, -8987, -9042, -9094, -9140, -9177, -9206, -9223, -9229, -9223, -9205, -9176, -9136, -9087, -9031, -8969, -8905, -8840, -8777, -8718, -8667, -8624, -8592, -8572, -8565, -8572, -8592, -8626, -8671, -8727, -8792, -8862, -8936, -9011, -9084, -9151, -9211, -9260, -9297, -9320, -9328, -9320, -9297, -9257, -9204, -9139, -9064, -8981, -8894, -8806, -8721, -8641, -8571, -8512, -8468, -8441, -8432, -8441, -8469, -8516, -8580, -8658, -8749, -8848, -8953, -9059, -9163, -9259, -9345, -9416, -9470, -9503, -9515, -9503, -9468, -9410, -9332, -9234, -9122, -8997, -8866, -8733, -8603, -8481, -8372, -8282, -8213, -8170, -8156, -8171, -8216, -8291, -8393, -8520, -8668, -8832, -9005, -9182, -9355, -9519, -9665, -9787, -9880, -9939, -9959, -9938, -9875, -9771, -9627, -9447, -9237, -9002, -8752, -8495, -8241, -8000, -7782, -7598, -7457, -7368, -7336, -7369, -7468, -7636, -7869, -8164, -8515, -8911, -9341, -9791, -10243, -10680, -11083, -11432, -11705, -11884, -11947, -11878, -11659, -11278, -10722, -9984, -9059, -7947, -6651, -5178, -3540, -1749, 174, 2210, 4333, 6520, ], [8565, 9681, 10797, 11909, 13013, 14107, 15187, 16249, 17290, 18307, 19296, 20255, 21181, 22071, 22923, 23735, 24504, 25230, 25909, 26542, 27126, 27662, 28149, 28586, 28973, 29311, 29600, 29841, 30034, 30182, 30286, 30347, 30366, 30347, 30292, 30202, 30080, 29929, 29751, 29550, 29328, 29088, 28834, 28567, 28291, 28010, 27724, 27439, 27155, 26876, 26604, 26341, 26090, 25853, 25631, 25425, 25239, 25072, 24926, 24801, 24699, 24619, 24563, 24529, 24517, 24528, 24561, 24615, 24689, 24782, 24892, 25019, 25161, 25315, 25482, 25658, 25843, 26033, 26228, 26425, 26623, 26819, 27012, 27200, 27381, 27554, 27717, 27869, 28008, 28134, 28244, 28339, 28418, 28479, 28524, 28550, 28559, 28550, 28524, 28481, 28421, 28345, 28255, 28150, 28033, 27904, 27764, 27616, 27459, 27297, 27130, 26961, 26790, 26619, 26451, 26286, 26126, 25972, 25827, 25691, 25566, 25453, 25352, 25266, 25194, 25137, 25096, 25071, 25063, 25071, 25096, 25137, 25194, 25266, 25352, 25453, 25566, 25691, 25827, 25972, 26126, 26286, 26451, 26619, 26790, 26961, 27130, 27297, 27459, 27616, 27764, 27904, 28033, 28150, 28255, 28345, 28421, 28481, 28524, 28550, 28559, 28550, 28524, 28479, 28418, 28339, 28244, 28134, 28008, 27869, 27717, 27554, 27381, 27200, 27012, 26819, 26623, 26425, 26228, 26033, 25843, 25658, 25482, 25315, 25161, 25019, 24892, 24782, 24689, 24615, 24561, 24528, 24517, 24529, 24563, 24619, 24699, 24801, 24926, 25072, 25239, 25425, 25631, 25853, 26090, 26341, 26604, 26876, 27155, 27439, 27724, 28010, 28291, 28567, 28834, 29088, 29328, 29550, 29751, 29929, 30080, 30202, 30292, 30347, 30366, 30347, 30286, 30182, 30034, 29841, 29600, 29311, 28973, 28586, 28149, 27662, 27126, 26542, 25909, 25230, 24504, 23735, 22923, 22071, 21181, 20255, 19296, 18307, 17290, 16249, 15187, 14107, 13013, 11909, 10797, 9681, 8565, 7452, 6347, 5252, 4170, 3106, 2062, 1042, 48, -917, -1851, -2750, -3613, -4437, -5221, -5964, -6663, -7319, -7929, -8493, -9012, -9484, -9911, -10291, -10626, -10917, -11164, -11368, -11532, -11656, -11742, -11793, -11809, -11793, -11748, -11675, -11577, -11457, -11316, -11158, -10984, -10798, -10601, -10396, -10186, -9972, -9757, -9543, -9332, -9125, -8926, -8734, -8552, -8381, -8222, -8076, -7944, -7827, -7725, -7639, -7568, -7514, -7475, -7452, -7445, -7452, -7474, -7509, -7557, -7617, -7689, -7770, -7860, -7958, -8062, -8172, -8286, -8403, -8522, -8641, -8760, -8877, -8991, -9102, -9208, -9309, -9403, -9489, -9569, -9639, -9701, -9754, -9798, -9832, -9856, -9870, -9875, -9870, -9856, -9834, -9802, -9763, -9717, -9664, -9604, -9540, -9470, -9397, -9320, -9241, -9161, -9080, -8999, -8918, -8839, -8763, -8689, -8619, -8554, -8493, -8437, -8387, -8343, -8305, -8274, -8250, -8233, -8222, -8219, -8222, -8233, -8249, -8272, -8301, -8335, -8374, -8418, -8466, -8518, -8572, -8630, -8689, -8749, -8810, -8872, -8933, -8992, -9051, -9107, -9160, -9211, -9257, -9300, -9339, -9373, -9402, -9426, -9445, -9459, -9467, -9469, -9467, -9459, -9446, -9428, -9405, -9378, -9347, -9312, -9274, -9232, -9189, -9143, -9095, -9047, -8997, -8948, -8899, -8850, -8803, -8758, -8714, -8673, -8635, -8600, -8568, -8540, -8516, -8497, -8481, -8470, -8463, -8461, -8463, -8470, -8481, -8496, -8514, -8537, -8563, -8592, -8624, -8659, -8696, -8734, -8774, -8815, -8857, -8899, -8940, -8982, -9022, -9060, -9097, -9132, -9165, -9195, -9222, -9246, -9266, -9283, -9297, -9306, -9312, -9314, -9312, -9306, -9297, -9284, -9268, -9248, -9225, -9200, -9172, -9142, -9110, -9076, -9041, -9005, -8968, -8932, -8895, -8859, -8824, -8790, -8757, -8726, -8697, -8671, -8647, -8625, -8607, -8592, -8580, -8572, -8567, -8565, -8567, -8572, -8580, -8592, -8606, -8624, -8644, -8667, -8692, -8720, -8749, -8779, -8811, -8843, -8876, -8909, -8943, -8975, -9007, -9039, -9068, -9096, -9123, -9147, -9169, -9188, -9205, -9219, -9229, -9237, -9242, -9244, -9242, -9237, -9230, -9219, -9205, -9189, -9170, -9149, -9126, -9101, -9074, -9046, -9016, -8986, -8955, -8924, -8893, -8863, -8833, -8804, -8776, -8750, -8725, -8702, -8682, -8664, -8648, -8635, -8625, -8617, -8613, -8612, -8613, -8617, -8625, -8635, -8648, -8663, -8681, -8701, -8723, -8747, -8773, -8800, -8827, -8856, -8886, -8915, -8945, -8974, -9003, -9030, -9057, -9082, -9106, -9128, -9147, -9165, -9180, -9192, -9202, -9209, -9214, -9215, -9214, -9209, -9202, -9192, -9180, -9165, -9148, -9128, -9107, -9083, -9059, -9032, -9005, -8977, -8948, -8919, -8891, -8862, -8834, -8807, -8781, -8756, -8733, -8711, -8692, -8675, -8660, -8647, -8638, -8631, -8627, -8625, -8627, -8631, -8638, -8647, -8660, -8675, -8692, -8711, -8733, -8756, -8781, -8807, -8834, -8862, -8891, -8919, -8948, -8977, -9005, -9032, -9059, -9083, -9107, -9128, -9148, -9165, -9180, -9192, -9202, -9209, -9214, -9215, -9214, -9209, -9202, -9192, -9180, -9165, -9147, -9128, -9106, -9082, -9057, -9030, -9003, -8974, -8945, -8915, -8886, -8856, -8827, -8800, -8773, -8747, -8723, -8701, -8681, -8663, -8648, -8635, -8625, -8617, -8613, -8612, -8613, -8617, -8625, -8635, -8648, -8664, -8682, -8702, -8725, -8750, -8776, -8804, -8833, -8863, -8893, -8924, -8955, -8986, -9016, -9046, -9074, -9101, -9126, -9149, -9170, -9189, -9205, -9219, -9230, -9237, -9242, -9244, -9242, -9237, -9229, -9219, -9205, -9188, -9169, -9147, -9123, -9096, -9068, -9039, -9007, -8975, -8943, -8909, -8876, -8843, -8811, -8779, -8749, -8720, -8692, -8667, -8644, -8624, -8606, -8592, -8580, -8572, -8567, -8565, -8567, -8572, -8580, -8592, -8607, -8625, -8647, -8671, -8697, -8726, -8757, -8790, -8824, -8859, -8895, -8932, -8968, -9005, -9041, -9076, -9110, -9142, -9172, -9200, -9225, -9248, -9268, -9284, -9297, -9306, -9312, -9314, -9312, -9306, -9297, -9283, -9266, -9246, -9222, -9195, -9165, -9132, -9097, -9060, -9022, -8982, -8940, -8899, -8857, -8815, -8774, -8734, -8696, -8659, -8624, -8592, -8563, -8537, -8514, -8496, -8481, -8470, -8463, -8461, -8463, -8470, -8481, -8497, -8516, -8540, -8568, -8600, -8635, -8673, -8714, -8758, -8803, -8850, -8899, -8948, -8997, -9047, -9095, -9143, -9189, -9232, -9274, -9312, -9347, -9378, -9405, -9428, -9446, -9459, -9467, -9469, -9467, -9459, -9445, -9426, -9402, -9373, -9339, -9300, -9257, -9211, -9160, -9107, -9051, -8992, -8933, -8872, -8810, -8749, -8689, -8630, -8572, -8518, -8466, -8418, -8374, -8335, -8301, -8272, -8249, -8233, -8222, -8219, -8222, -8233, -8250, -8274, -8305, -8343, -8387, -8437, -8493, -8554, -8619, -8689, -8763, -8839, -8918, -8999, -9080, -9161, -9241, -9320, -9397, -9470, -9540, -9604, -9664, -9717, -9763, -9802, -9834, -9856, -9870, -9875, -9870, -9856, -9832, -9798, -9754, -9701, -9639, -9569, -9489, -9403, -9309, -9208, -9102, -8991, -8877, -8760, -8641, -8522, -8403, -8286, -8172, -8062, -7958, -7860, -7770, -7689, -7617, -7557, -7509, -7474, -7452, -7445, -7452, -7475, -7514, -7568, -7639, -7725, -7827, -7944, -8076, -8222, -8381, -8552, -8734, -8926, -9125, -9332, -9543, -9757, -9972, -10186, -10396, -10601, -10798, -10984, -11158, -11316, -11457, -11577, -11675, -11748, -11793, -11809, -11793, -11742, -11656, -11532, -11368, -11164, -10917, -10626, -10291, -9911, -9484, -9012, -8493, -7929, -7319, -6663, -5964, -5221, -4437, -3613, -2750, -1851, -917, 48, 1042, 2062, 3106, 4170, 5252, 6347, 7452, ], [8219, 8777, 9337, 9897, 10458, 11018, 11579, 12138, 12696, 13252, 13806, 14357, 14905, 15449, 15990, 16526, 17057, 17583, 18103, 18617, 19125, 19626, 20119, 20605, 21083, 21552, 22013, 22464, 22907, 23339, 23761, 24174, 24575, 24966, 25345, 25714, 26070, 26415, 26748, 27069, 27377, 27673, 27956, 28227, 28485, 28730, 28962, 29181, 29386, 29579, 29759, 29925, 30079, 30219, 30347, 30462, 30564, 30654, 30731, 30795, 30848, 30888, 30917, 30934, 30939, 30934, 30917, 30890, 30853, 30805, 30748, 30681, 30605, 30520, 30427, 30326, 30217, 30100, 29976, 29846, 29710, 29568, 29420, 29267, 29110, 28948, 28782, 28614, 28442, 28268, 28091, 27913, 27734, 27553, 27373, 27192, 27012, 26832, 26654, 26477, 26302, 26130, 25960, 25793, 25630, 25470, 25315, 25164, 25018, 24877, 24742, 24612, 24488, 24370, 24259, 24154, 24056, 23965, 23882, 23806, 23737, 23677, 23624, 23579, 23542, 23513, 23493, 23480, 23476, 23480, 23493, 23513, 23542, 23579, 23624, 23677, 23737, 23806, 23882, 23965, 24056, 24154, 24259, 24370, 24488, 24612, 24742, 24877, 25018, 25164, 25315, 25470, 25630, 25793, 25960, 26130, 26302, 26477, 26654, 26832, 27012, 27192, 27373, 27553, 27734, 27913, 28091, 28268, 28442, 28614, 28782, 28948, 29110, 29267, 29420, 29568, 29710, 29846, 29976, 30100, 30217, 30326, 30427, 30520, 30605, 30681, 30748, 30805, 30853, 30890, 30917, 30934, 30939, 30934, 30917, 30888, 30848, 30795, 30731, 30654, 30564, 30462, 30347, 30219, 30079, 29925, 29759, 29579, 29386, 29181, 28962, 28730, 28485, 28227, 27956, 27673, 27377, 27069, 26748, 26415, 26070, 25714, 25345, 24966, 24575, 24174, 23761, 23339, 22907, 22464, 22013, 21552, 21083, 20605, 20119, 19626, 19125, 18617, 18103, 17583, 17057, 16526, 15990, 15449, 14905, 14357, 13806, 13252, 12696, 12138, 11579, 11018, 10458, 9897, 9337, 8777, 8219, 7663, 7108, 6556, 6008, 5462, 4921, 4383, 3850, 3323, 2800, 2283, 1773, 1269, 771, 281, -202, -677, -1145, -1603, -2054, -2495, -2927, -3350, -3764, -4167, -4561, -4945, -5318, -5681, -6033, -6374, -6705, -7024, -7333, -7631, -7917, -8192, -8456, -8709, -8950, -9180, -9399, -9607, -9804, -9990, -10165, -10328, -10482, -10624, -10756, -10878, -10989, -11090, -11182, -11263, -11336, -11398, -11452, -11497, -11533, -11561, -11580, -11591, -11595, -11592, -11581, -11563, -11538, -11508, -11471, -11428, -11380, -11326, -11268, -11205, -11137, -11066, -10990, -10911, -10829, -10744, -10657, -10567, -10475, -10381, -10285, -10189, -10091, -9993, -9894, -9795, -9696, -9597, -9498, -9401, -9304, -9208, -9114, -9021, -8930, -8841, -8754, -8669, -8587, -8507, -8429, -8355, -8283, -8215, -8149, -8087, -8028, -7973, -7921, -7872, -7827, -7786, -7748, -7714, -7683, -7657, -7634, -7614, -7598, -7586, -7577, -7572, -7570, -7572, -7577, -7585, -7597, -7612, -7629, -7650, -7673, -7700, -7729, -7760, -7794, -7830, -7869, -7909, -7951, -7996, -8041, -8089, -8137, -8187, -8239, -8291, -8344, -8397, -8451, -8506, -8561, -8616, -8671, -8726, -8781, -8835, -8889, -8943, -8995, -9047, -9098, -9147, -9196, -9243, -9289, -9333, -9376, -9418, -9457, -9495, -9531, -9565, -9597, -9627, -9654, -9680, -9704, -9725, -9744, -9761, -9776, -9788, -9799, -9806, -9812, -9815, -9816, -9815, -9812, -9807, -9799, -9789, -9778, -9764, -9748, -9731, -9711, -9690, -9667, -9643, -9617, -9589, -9560, -9530, -9499, -9466, -9433, -9398, -9363, -9326, -9289, -9252, -9214, -9175, -9136, -9097, -9058, -9019, -8980, -8941, -8902, -8864, -8826, -8789, -8752, -8716, -8681, -8646, -8613, -8580, -8548, -8518, -8489, -8461, -8434, -8409, -8385, -8363, -8342, -8322, -8305, -8289, -8274, -8261, -8250, -8241, -8233, -8227, -8222, -8220, -8219, -8220, -8222, -8227, -8233, -8240, -8249, -8260, -8272, -8286, -8301, -8318, -8336, -8355, -8376, -8398, -8421, -8445, -8470, -8496, -8523, -8551, -8579, -8608, -8638, -8669, -8700, -8731, -8762, -8794, -8826, -8858, -8890, -8922, -8954, -8986, -9017, -9048, -9078, -9108, -9138, -9167, -9195, -9222, -9248, -9274, -9298, -9322, -9344, -9366, -9386, -9405, -9423, -9439, -9454, -9468, -9480, -9491, -9501, -9509, -9516, -9521, -9525, -9527, -9528, -9527, -9525, -9521, -9516, -9509, -9501, -9492, -9481, -9469, -9456, -9441, -9425, -9408, -9390, -9370, -9350, -9328, -9306, -9283, -9259, -9234, -9208, -9182, -9155, -9128, -9100, -9071, -9043, -9014, -8985, -8956, -8927, -8898, -8868, -8840, -8811, -8782, -8754, -8727, -8700, -8673, -8647, -8622, -8598, -8574, -8551, -8529, -8508, -8488, -8469, -8451, -8435, -8419, -8405, -8392, -8380, -8370, -8361, -8353, -8347, -8342, -8338, -8336, -8336, -8336, -8338, -8342, -8347, -8353, -8361, -8370, -8380, -8392, -8405, -8419, -8435, -8451, -8469, -8488, -8508, -8529, -8551, -8574, -8598, -8622, -8647, -8673, -8700, -8727, -8754, -8782, -8811, -8840, -8868, -8898, -8927, -8956, -8985, -9014, -9043, -9071, -9100, -9128, -9155, -9182, -9208, -9234, -9259, -9283, -9306, -9328, -9350, -9370, -9390, -9408, -9425, -9441, -9456, -9469, -9481, -9492, -9501, -9509, -9516, -9521, -9525, -9527, -9528, -9527, -9525, -9521, -9516, -9509, -9501, -9491, -9480, -9468, -9454, -9439, -9423, -9405, -9386, -9366, -9344, -9322, -9298, -9274, -9248, -9222, -9195, -9167, -9138, -9108, -9078, -9048, -9017, -8986, -8954, -8922, -8890, -8858, -8826, -8794, -8762, -8731, -8700, -8669, -8638, -8608, -8579, -8551, -8523, -8496, -8470, -8445, -8421, -8398, -8376, -8355, -8336, -8318, -8301, -8286, -8272, -8260, -8249, -8240, -8233, -8227, -8222, -8220, -8219, -8220, -8222, -8227, -8233, -8241, -8250, -8261, -8274, -8289, -8305, -8322, -8342, -8363, -8385, -8409, -8434, -8461, -8489, -8518, -8548, -8580, -8613, -8646, -8681, -8716, -8752, -8789, -8826, -8864, -8902, -8941, -8980, -9019, -9058, -9097, -9136, -9175, -9214, -9252, -9289, -9326, -9363, -9398, -9433, -9466, -9499, -9530, -9560, -9589, -9617, -9643, -9667, -9690, -9711, -9731, -9748, -9764, -9778, -9789, -9799, -9807, -9812, -9815, -9816, -9815, -9812, -9806, -9799, -9788, -9776, -9761, -9744, -9725, -9704, -9680, -9654, -9627, -9597, -9565, -9531, -9495, -9457, -9418, -9376, -9333, -9289, -9243, -9196, -9147, -9098, -9047, -8995, -8943, -8889, -8835, -8781, -8726, -8671, -8616, -8561, -8506, -8451, -8397, -8344, -8291, -8239, -8187, -8137, -8089, -8041, -7996, -7951, -7909, -7869, -7830, -7794, -7760, -7729, -7700, -7673, -7650, -7629, -7612, -7597, -7585, -7577, -7572, -7570, -7572, -7577, -7586, -7598, -7614, -7634, -7657, -7683, -7714, -7748, -7786, -7827, -7872, -7921, -7973, -8028, -8087, -8149, -8215, -8283, -8355, -8429, -8507, -8587, -8669, -8754, -8841, -8930, -9021, -9114, -9208, -9304, -9401, -9498, -9597, -9696, -9795, -9894, -9993, -10091, -10189, -10285, -10381, -10475, -10567, -10657, -10744, -10829, -10911, -10990, -11066, -11137, -11205, -11268, -11326, -11380, -11428, -11471, -11508, -11538, -11563, -11581, -11592, -11595, -11591, -11580, -11561, -11533, -11497, -11452, -11398, -11336, -11263, -11182, -11090, -10989, -10878, -10756, -10624, -10482, -10328, -10165, -9990, -9804, -9607, -9399, -9180, -8950, -8709, -8456, -8192, -7917, -7631, -7333, -7024, -6705, -6374, -6033, -5681, -5318, -4945, -4561, -4167, -3764, -3350, -2927, -2495, -2054, -1603, -1145, -677, -202, 281, 771, 1269, 1773, 2283, 2800, 3323, 3850, 4383, 4921, 5462, 6008, 6556, 7108, 7663, ], [7570, 7849, 8129, 8410, 8691, 8973, 9256, 9539, 9823, 10107, 10392, 10677, 10962, 11248, 11534, 11820, 12106, 12392, 12678, 12964, 13250, 13536, 13821, 14106, 14391, 14676, 14960, 15244, 15527, 15810, 16092, 16373, 16654, 16933, 17212, 17490, 17767, 18043, 18318, 18592, 18864, 19136, 19406, 19674, 19942, 20208, 20472, 20735, 20996, 21255, 21513, 21769, 22024, 22276, 22526, 22775, 23021, 23266, 23508, 23748, 23986, 24222, 24455, 24686, 24914, 25140, 25364, 25585, 25803, 26019, 26232, 26442, 26650, 26854, 27056, 27255, 27451, 27644, 27834, 28021, 28205, 28385, 28563, 28737, 28908, 29076, 29240, 29401, 29558, 29712, 29863, 30010, 30154, 30294, 30430, 30563, 30692, 30817, 30939, 31057, 31171, 31282, 31388, 31491, 31590, 31685, 31777, 31864, 31947, 32027, 32102, 32174, 32241, 32304, 32364, 32419, 32471, 32518, 32561, 32600, 32635, 32666, 32693, 32715, 32734, 32748, 32759, 32765, 32767, 32765, 32759, 32748, 32734, 32715, 32693, 32666, 32635, 32600, 32561, 32518, 32471, 32419, 32364, 32304, 32241, 32174, 32102, 32027, 31947, 31864, 31777, 31685, 31590, 31491, 31388, 31282, 31171, 31057, 30939, 30817, 30692, 30563, 30430, 30294, 30154, 30010, 29863, 29712, 29558, 29401, 29240, 29076, 28908, 28737, 28563, 28385, 28205, 28021, 27834, 27644, 27451, 27255, 27056, 26854, 26650, 26442, 26232, 26019, 25803, 25585, 25364, 25140, 24914, 24686, 24455, 24222, 23986, 23748, 23508, 23266, 23021, 22775, 22526, 22276, 22024, 21769, 21513, 21255, 20996, 20735, 20472, 20208, 19942, 19674, 19406, 19136, 18864, 18592, 18318, 18043, 17767, 17490, 17212, 16933, 16654, 16373, 16092, 15810, 15527, 15244, 14960, 14676, 14391, 14106, 13821, 13536, 13250, 12964, 12678, 12392, 12106, 11820, 11534, 11248, 10962, 10677, 10392, 10107, 9823, 9539, 9256, 8973, 8691, 8410, 8129, 7849, 7570, 7292, 7015, 6738, 6463, 6188, 5915, 5643, 5372, 5102, 4834, 4566, 4301, 4036, 3773, 3511, 3251, 2993, 2736, 2481, 2227, 1975, 1725, 1477, 1230, 985, 742, 501, 262, 25, -210, -443, -674, -903, -1129, -1354, -1576, -1796, -2014, -2230, -2443, -2655, -2863, -3070, -3274, -3475, -3675, -3871, -4066, -4258, -4447, -4634, -4818, -5000, -5179, -5356, -5530, -5702, -5871, -6037, -6201, -6362, -6520, -6676, -6829, -6980, -7128, -7273, -7416, -7555, -7693, -7827, -7959, -8088, -8215, -8339, -8460, -8578, -8694, -8807, -8918, -9026, -9131, -9234, -9334, -9431, -9526, -9618, -9707, -9794, -9879, -9961, -10040, -10117, -10191, -10263, -10332, -10399, -10463, -10525, -10585, -10642, -10696, -10749, -10799, -10846, -10892, -10935, -10976, -11014, -11051, -11085, -11117, -11147, -11175, -11200, -11224, -11245, -11265, -11282, -11298, -11312, -11323, -11333, -11341, -11347, -11352, -11354, -11355, -11354, -11352, -11348, -11342, -11335, -11326, -11315, -11303, -11290, -11275, -11259, -11241, -11222, -11202, -11180, -11157, -11133, -11108, -11082, -11054, -11026, -10996, -10966, -10934, -10901, -10868, -10834, -10798, -10762, -10726, -10688, -10650, -10611, -10571, -10531, -10490, -10449, -10407, -10365, -10322, -10278, -10235, -10190, -10146, -10101, -10056, -10011, -9965, -9920, -9874, -9828, -9782, -9735, -9689, -9643, -9596, -9550, -9504, -9458, -9412, -9366, -9320, -9275, -9229, -9184, -9139, -9095, -9051, -9007, -8963, -8920, -8877, -8835, -8793, -8751, -8710, -8670, -8630, -8590, -8552, -8513, -8475, -8438, -8402, -8366, -8331, -8296, -8262, -8229, -8197, -8165, -8134, -8103, -8074, -8045, -8017, -7990, -7964, -7938, -7913, -7889, -7866, -7844, -7822, -7802, -7782, -7763, -7745, -7728, -7712, -7697, -7682, -7668, -7656, -7644, -7633, -7623, -7614, -7605, -7598, -7591, -7586, -7581, -7577, -7574, -7572, -7571, -7570, -7571, -7572, -7574, -7577, -7581, -7585, -7591, -7597, -7604, -7612, -7621, -7630, -7640, -7651, -7663, -7675, -7688, -7702, -7716, -7732, -7747, -7764, -7781, -7799, -7817, -7836, -7856, -7876, -7897, -7918, -7940, -7963, -7986, -8009, -8033, -8057, -8082, -8107, -8133, -8159, -8185, -8212, -8239, -8266, -8294, -8322, -8350, -8379, -8407, -8436, -8466, -8495, -8524, -8554, -8584, -8614, -8644, -8674, -8704, -8734, -8765, -8795, -8825, -8855, -8886, -8916, -8946, -8976, -9006, -9035, -9065, -9094, -9123, -9153, -9181, -9210, -9238, -9266, -9294, -9322, -9349, -9376, -9402, -9428, -9454, -9480, -9505, -9529, -9554, -9577, -9601, -9624, -9646, -9668, -9689, -9710, -9730, -9750, -9770, -9788, -9807, -9824, -9841, -9858, -9873, -9889, -9903, -9917, -9931, -9943, -9955, -9967, -9978, -9988, -9997, -10006, -10014, -10021, -10028, -10034, -10039, -10044, -10048, -10051, -10053, -10055, -10056, -10056, -10056, -10055, -10053, -10051, -10048, -10044, -10039, -10034, -10028, -10021, -10014, -10006, -9997, -9988, -9978, -9967, -9955, -9943, -9931, -9917, -9903, -9889, -9873, -9858, -9841, -9824, -9807, -9788, -9770, -9750, -9730, -9710, -9689, -9668, -9646, -9624, -9601, -9577, -9554, -9529, -9505, -9480, -9454, -9428, -9402, -9376, -9349, -9322, -9294, -9266, -9238, -9210, -9181, -9153, -9123, -9094, -9065, -9035, -9006, -8976, -8946, -8916, -8886, -8855, -8825, -8795, -8765, -8734, -8704, -8674, -8644, -8614, -8584, -8554, -8524, -8495, -8466, -8436, -8407, -8379, -8350, -8322, -8294, -8266, -8239, -8212, -8185, -8159, -8133, -8107, -8082, -8057, -8033, -8009, -7986, -7963, -7940, -7918, -7897, -7876, -7856, -7836, -7817, -7799, -7781, -7764, -7747, -7732, -7716, -7702, -7688, -7675, -7663, -7651, -7640, -7630, -7621, -7612, -7604, -7597, -7591, -7585, -7581, -7577, -7574, -7572, -7571, -7570, -7571, -7572, -7574, -7577, -7581, -7586, -7591, -7598, -7605, -7614, -7623, -7633, -7644, -7656, -7668, -7682, -7697, -7712, -7728, -7745, -7763, -7782, -7802, -7822, -7844, -7866, -7889, -7913, -7938, -7964, -7990, -8017, -8045, -8074, -8103, -8134, -8165, -8197, -8229, -8262, -8296, -8331, -8366, -8402, -8438, -8475, -8513, -8552, -8590, -8630, -8670, -8710, -8751, -8793, -8835, -8877, -8920, -8963, -9007, -9051, -9095, -9139, -9184, -9229, -9275, -9320, -9366, -9412, -9458, -9504, -9550, -9596, -9643, -9689, -9735, -9782, -9828, -9874, -9920, -9965, -10011, -10056, -10101, -10146, -10190, -10235, -10278, -10322, -10365, -10407, -10449, -10490, -10531, -10571, -10611, -10650, -10688, -10726, -10762, -10798, -10834, -10868, -10901, -10934, -10966, -10996, -11026, -11054, -11082, -11108, -11133, -11157, -11180, -11202, -11222, -11241, -11259, -11275, -11290, -11303, -11315, -11326, -11335, -11342, -11348, -11352, -11354, -11355, -11354, -11352, -11347, -11341, -11333, -11323, -11312, -11298, -11282, -11265, -11245, -11224, -11200, -11175, -11147, -11117, -11085, -11051, -11014, -10976, -10935, -10892, -10846, -10799, -10749, -10696, -10642, -10585, -10525, -10463, -10399, -10332, -10263, -10191, -10117, -10040, -9961, -9879, -9794, -9707, -9618, -9526, -9431, -9334, -9234, -9131, -9026, -8918, -8807, -8694, -8578, -8460, -8339, -8215, -8088, -7959, -7827, -7693, -7555, -7416, -7273, -7128, -6980, -6829, -6676, -6520, -6362, -6201, -6037, -5871, -5702, -5530, -5356, -5179, -5000, -4818, -4634, -4447, -4258, -4066, -3871, -3675, -3475, -3274, -3070, -2863, -2655, -2443, -2230, -2014, -1796, -1576, -1354, -1129, -903, -674, -443, -210, 25, 262, 501, 742, 985, 1230, 1477, 1725, 1975, 2227, 2481, 2736, 2993, 3251, 3511, 3773, 4036, 4301, 4566, 4834, 5102, 5372, 5643, 5915, 6188, 6463, 6738, 7015, 7292, ], [11355, 11564, 11772, 11980, 12188, 12395, 12601, 12807, 13012, 13216, 13420, 13623, 13826, 14028, 14229, 14429, 14629, 14828, 15026, 15223, 15419, 15614, 15809, 16002, 16195, 16386, 16577, 16766, 16955, 17142, 17329, 17514, 17698, 17881, 18063, 18243, 18422, 18600, 18777, 18953, 19127, 19300, 19471, 19642, 19810, 19978, 20144, 20308, 20471, 20633, 20793, 20951, 21108, 21264, 21418, 21570, 21721, 21870, 22017, 22163, 22307, 22449, 22590, 22729, 22866, 23001, 23135, 23266, 23396, 23525, 23651, 23775, 23898, 24018, 24137, 24254, 24369, 24482, 24593, 24702, 24809, 24914, 25017, 25118, 25217, 25314, 25409, 25501, 25592, 25681, 25767, 25851, 25934, 26014, 26092, 26168, 26241, 26313, 26382, 26449, 26514, 26577, 26637, 26695, 26751, 26805, 26857, 26906, 26953, 26998, 27040, 27081, 27119, 27154, 27188, 27219, 27248, 27274, 27298, 27320, 27340, 27357, 27372, 27385, 27396, 27404, 27409, 27413, 27414, 27413, 27409, 27404, 27396, 27385, 27372, 27357, 27340, 27320, 27298, 27274, 27248, 27219, 27188, 27154, 27119, 27081, 27040, 26998, 26953, 26906, 26857, 26805, 26751, 26695, 26637, 26577, 26514, 26449, 26382, 26313, 26241, 26168, 26092, 26014, 25934, 25851, 25767, 25681, 25592, 25501, 25409, 25314, 25217, 25118, 25017, 24914, 24809, 24702, 24593, 24482, 24369, 24254, 24137, 24018, 23898, 23775, 23651, 23525, 23396, 23266, 23135, 23001, 22866, 22729, 22590, 22449, 22307, 22163, 22017, 21870, 21721, 21570, 21418, 21264, 21108, 20951, 20793, 20633, 20471, 20308, 20144, 19978, 19810, 19642, 19471, 19300, 19127, 18953, 18777, 18600, 18422, 18243, 18063, 17881, 17698, 17514, 17329, 17142, 16955, 16766, 16577, 16386, 16195, 16002, 15809, 15614, 15419, 15223, 15026, 14828, 14629, 14429, 14229, 14028, 13826, 13623, 13420, 13216, 13012, 12807, 12601, 12395, 12188, 11980, 11772, 11564, 11355, 11146, 10936, 10726, 10516, 10305, 10094, 9883, 9671, 9460, 9248, 9035, 8823, 8610, 8398, 8185, 7972, 7759, 7547, 7334, 7121, 6908, 6695, 6483, 6270, 6058, 5845, 5633, 5421, 5210, 4998, 4787, 4576, 4366, 4156, 3946, 3736, 3527, 3319, 3111, 2903, 2696, 2489, 2283, 2078, 1873, 1668, 1465, 1261, 1059, 857, 656, 456, 256, 58, -140, -338, -534, -730, -924, -1118, -1311, -1503, -1694, -1884, -2073, -2261, -2448, -2634, -2819, -3003, -3186, -3368, -3548, -3728, -3906, -4083, -4259, -4434, -4608, -4780, -4951, -5121, -5289, -5457, -5623, -5787, -5951, -6112, -6273, -6432, -6590, -6747, -6902, -7055, -7207, -7358, -7507, -7655, -7801, -7946, -8089, -8231, -8371, -8510, -8647, -8783, -8917, -9049, -9180, -9309, -9437, -9563, -9687, -9810, -9931, -10051, -10169, -10285, -10400, -10513, -10624, -10733, -10841, -10947, -11052, -11155, -11256, -11355, -11453, -11549, -11643, -11736, -11827, -11916, -12003, -12089, -12172, -12255, -12335, -12414, -12491, -12566, -12639, -12711, -12781, -12849, -12916, -12981, -13044, -13105, -13165, -13223, -13279, -13333, -13386, -13437, -13486, -13534, -13580, -13624, -13666, -13707, -13746, -13784, -13819, -13853, -13886, -13916, -13945, -13973, -13999, -14023, -14045, -14066, -14086, -14103, -14119, -14134, -14147, -14158, -14168, -14176, -14183, -14188, -14191, -14194, -14194, -14193, -14191, -14187, -14182, -14175, -14167, -14157, -14146, -14133, -14120, -14104, -14088, -14070, -14050, -14030, -14008, -13985, -13960, -13934, -13907, -13879, -13849, -13818, -13786, -13753, -13719, -13683, -13647, -13609, -13570, -13530, -13489, -13446, -13403, -13359, -13314, -13267, -13220, -13172, -13122, -13072, -13021, -12969, -12916, -12862, -12808, -12752, -12696, -12639, -12581, -12522, -12463, -12403, -12342, -12280, -12218, -12155, -12092, -12028, -11963, -11897, -11831, -11765, -11698, -11630, -11562, -11494, -11425, -11355, -11285, -11215, -11144, -11073, -11002, -10930, -10858, -10786, -10713, -10640, -10567, -10494, -10420, -10346, -10272, -10198, -10124, -10050, -9975, -9901, -9826, -9752, -9677, -9602, -9528, -9453, -9378, -9304, -9230, -9155, -9081, -9007, -8933, -8859, -8786, -8712, -8639, -8566, -8494, -8421, -8349, -8277, -8206, -8135, -8064, -7994, -7924, -7854, -7785, -7716, -7648, -7580, -7512, -7445, -7379, -7313, -7248, -7183, -7119, -7055, -6992, -6930, -6868, -6807, -6746, -6687, -6627, -6569, -6511, -6454, -6398, -6342, -6287, -6233, -6180, -6128, -6076, -6025, -5975, -5926, -5877, -5830, -5783, -5737, -5692, -5648, -5605, -5563, -5522, -5481, -5442, -5404, -5366, -5329, -5294, -5259, -5226, -5193, -5161, -5131, -5101, -5073, -5045, -5019, -4993, -4969, -4945, -4923, -4902, -4882, -4862, -4844, -4827, -4811, -4797, -4783, -4770, -4759, -4748, -4739, -4731, -4723, -4717, -4712, -4708, -4706, -4704, -4704, -4704, -4706, -4708, -4712, -4717, -4723, -4731, -4739, -4748, -4759, -4770, -4783, -4797, -4811, -4827, -4844, -4862, -4882, -4902, -4923, -4945, -4969, -4993, -5019, -5045, -5073, -5101, -5131, -5161, -5193, -5226, -5259, -5294, -5329, -5366, -5404, -5442, -5481, -5522, -5563, -5605, -5648, -5692, -5737, -5783, -5830, -5877, -5926, -5975, -6025, -6076, -6128, -6180, -6233, -6287, -6342, -6398, -6454, -6511, -6569, -6627, -6687, -6746, -6807, -6868, -6930, -6992, -7055, -7119, -7183, -7248, -7313, -7379, -7445, -7512, -7580, -7648, -7716, -7785, -7854, -7924, -7994, -8064, -8135, -8206, -8277, -8349, -8421, -8494, -8566, -8639, -8712, -8786, -8859, -8933, -9007, -9081, -9155, -9230, -9304, -9378, -9453, -9528, -9602, -9677, -9752, -9826, -9901, -9975, -10050, -10124, -10198, -10272, -10346, -10420, -10494, -10567, -10640, -10713, -10786, -10858, -10930, -11002, -11073, -11144, -11215, -11285, -11355, -11425, -11494, -11562, -11630, -11698, -11765, -11831, -11897, -11963, -12028, -12092, -12155, -12218, -12280, -12342, -12403, -12463, -12522, -12581, -12639, -12696, -12752, -12808, -12862, -12916, -12969, -13021, -13072, -13122, -13172, -13220, -13267, -13314, -13359, -13403, -13446, -13489, -13530, -13570, -13609, -13647, -13683, -13719, -13753, -13786, -13818, -13849, -13879, -13907, -13934, -13960, -13985, -14008, -14030, -14050, -14070, -14088, -14104, -14120, -14133, -14146, -14157, -14167, -14175, -14182, -14187, -14191, -14193, -14194, -14194, -14191, -14188, -14183, -14176, -14168, -14158, -14147, -14134, -14119, -14103, -14086, -14066, -14045, -14023, -13999, -13973, -13945, -13916, -13886, -13853, -13819, -13784, -13746, -13707, -13666, -13624, -13580, -13534, -13486, -13437, -13386, -13333, -13279, -13223, -13165, -13105, -13044, -12981, -12916, -12849, -12781, -12711, -12639, -12566, -12491, -12414, -12335, -12255, -12172, -12089, -12003, -11916, -11827, -11736, -11643, -11549, -11453, -11355, -11256, -11155, -11052, -10947, -10841, -10733, -10624, -10513, -10400, -10285, -10169, -10051, -9931, -9810, -9687, -9563, -9437, -9309, -9180, -9049, -8917, -8783, -8647, -8510, -8371, -8231, -8089, -7946, -7801, -7655, -7507, -7358, -7207, -7055, -6902, -6747, -6590, -6432, -6273, -6112, -5951, -5787, -5623, -5457, -5289, -5121, -4951, -4780, -4608, -4434, -4259, -4083, -3906, -3728, -3548, -3368, -3186, -3003, -2819, -2634, -2448, -2261, -2073, -1884, -1694, -1503, -1311, -1118, -924, -730, -534, -338, -140, 58, 256, 456, 656, 857, 1059, 1261, 1465, 1668, 1873, 2078, 2283, 2489, 2696, 2903, 3111, 3319, 3527, 3736, 3946, 4156, 4366, 4576, 4787, 4998, 5210, 5421, 5633, 5845, 6058, 6270, 6483, 6695, 6908, 7121, 7334, 7547, 7759, 7972, 8185, 8398, 8610, 8823, 9035, 9248, 9460, 9671, 9883, 10094, 10305, 10516, 10726, 10936, 11146, ], [11355, 11425, 11494, 11562, 11631, 11698, 11766, 11832, 11899, 11965, 12030, 12095, 12160, 12224, 12288, 12351, 12414, 12476, 12538, 12599, 12660, 12720, 12780, 12840, 12899, 12957, 13015, 13072, 13129, 13186, 13242, 13297, 13352, 13407, 13461, 13514, 13567, 13620, 13672, 13723, 13774, 13824, 13874, 13924, 13973, 14021, 14069, 14116, 14163, 14209, 14254, 14300, 14344, 14388, 14432, 14475, 14517, 14559, 14600, 14641, 14681, 14721, 14760, 14798, 14836, 14874, 14911, 14947, 14983, 15018, 15053, 15087, 15120, 15153, 15185, 15217, 15248, 15279, 15309, 15338, 15367, 15396, 15423, 15451, 15477, 15503, 15528, 15553, 15578, 15601, 15624, 15647, 15669, 15690, 15711, 15731, 15750, 15769, 15787, 15805, 15822, 15839, 15855, 15870, 15885, 15899, 15913, 15926, 15938, 15950, 15961, 15972, 15981, 15991, 16000, 16008, 16015, 16022, 16029, 16034, 16039, 16044, 16048, 16051, 16054, 16056, 16058, 16058, 16059, 16058, 16058, 16056, 16054, 16051, 16048, 16044, 16039, 16034, 16029, 16022, 16015, 16008, 16000, 15991, 15981, 15972, 15961, 15950, 15938, 15926, 15913, 15899, 15885, 15870, 15855, 15839, 15822, 15805, 15787, 15769, 15750, 15731, 15711, 15690, 15669, 15647, 15624, 15601, 15578, 15553, 15528, 15503, 15477, 15451, 15423, 15396, 15367, 15338, 15309, 15279, 15248, 15217, 15185, 15153, 15120, 15087, 15053, 15018, 14983, 14947, 14911, 14874, 14836, 14798, 14760, 14721, 14681, 14641, 14600, 14559, 14517, 14475, 14432, 14388, 14344, 14300, 14254, 14209, 14163, 14116, 14069, 14021, 13973, 13924, 13874, 13824, 13774, 13723, 13672, 13620, 13567, 13514, 13461, 13407, 13352, 13297, 13242, 13186, 13129, 13072, 13015, 12957, 12899, 12840, 12780, 12720, 12660, 12599, 12538, 12476, 12414, 12351, 12288, 12224, 12160, 12095, 12030, 11965, 11899, 11832, 11766, 11698, 11631, 11562, 11494, 11425, 11355, 11285, 11215, 11144, 11073, 11002, 10930, 10857, 10784, 10711, 10638, 10564, 10489, 10414, 10339, 10264, 10188, 10111, 10034, 9957, 9880, 9802, 9724, 9645, 9566, 9487, 9407, 9327, 9247, 9166, 9085, 9004, 8922, 8840, 8757, 8675, 8591, 8508, 8424, 8340, 8256, 8171, 8086, 8001, 7915, 7829, 7743, 7657, 7570, 7483, 7396, 7308, 7220, 7132, 7044, 6955, 6866, 6777, 6687, 6598, 6508, 6417, 6327, 6236, 6145, 6054, 5963, 5871, 5779, 5687, 5595, 5503, 5410, 5317, 5224, 5131, 5037, 4944, 4850, 4756, 4662, 4567, 4473, 4378, 4283, 4188, 4093, 3997, 3902, 3806, 3711, 3615, 3519, 3422, 3326, 3229, 3133, 3036, 2939, 2842, 2745, 2648, 2551, 2454, 2356, 2259, 2161, 2064, 1966, 1868, 1770, 1672, 1574, 1476, 1378, 1280, 1181, 1083, 985, 886, 788, 690, 591, 493, 394, 296, 197, 99, 0, -99, -197, -296, -394, -493, -591, -690, -788, -886, -985, -1083, -1181, -1280, -1378, -1476, -1574, -1672, -1770, -1868, -1966, -2064, -2161, -2259, -2356, -2454, -2551, -2648, -2745, -2842, -2939, -3036, -3133, -3229, -3326, -3422, -3519, -3615, -3711, -3806, -3902, -3997, -4093, -4188, -4283, -4378, -4473, -4567, -4662, -4756, -4850, -4944, -5037, -5131, -5224, -5317, -5410, -5503, -5595, -5687, -5779, -5871, -5963, -6054, -6145, -6236, -6327, -6417, -6508, -6598, -6687, -6777, -6866, -6955, -7044, -7132, -7220, -7308, -7396, -7483, -7570, -7657, -7743, -7829, -7915, -8001, -8086, -8171, -8256, -8340, -8424, -8508, -8591, -8675, -8757, -8840, -8922, -9004, -9085, -9166, -9247, -9327, -9407, -9487, -9566, -9645, -9724, -9802, -9880, -9957, -10034, -10111, -10188, -10264, -10339, -10414, -10489, -10564, -10638, -10711, -10784, -10857, -10930, -11002, -11073, -11144, -11215, -11285, -11355, -11425, -11494, -11562, -11631, -11698, -11766, -11832, -11899, -11965, -12030, -12095, -12160, -12224, -12288, -12351, -12414, -12476, -12538, -12599, -12660, -12720, -12780, -12840, -12899, -12957, -13015, -13072, -13129, -13186, -13242, -13297, -13352, -13407, -13461, -13514, -13567, -13620, -13672, -13723, -13774, -13824, -13874, -13924, -13973, -14021, -14069, -14116, -14163, -14209, -14254, -14300, -14344, -14388, -14432, -14475, -14517, -14559, -14600, -14641, -14681, -14721, -14760, -14798, -14836, -14874, -14911, -14947, -14983, -15018, -15053, -15087, -15120, -15153, -15185, -15217, -15248, -15279, -15309, -15338, -15367, -15396, -15423, -15451, -15477, -15503, -15528, -15553, -15578, -15601, -15624, -15647, -15669, -15690, -15711, -15731, -15750, -15769, -15787, -15805, -15822, -15839, -15855, -15870, -15885, -15899, -15913, -15926, -15938, -15950, -15961, -15972, -15981, -15991, -16000, -16008, -16015, -16022, -16029, -16034, -16039, -16044, -16048, -16051, -16054, -16056, -16058, -16058, -16059, -16058, -16058, -16056, -16054, -16051, -16048, -16044, -16039, -16034, -16029, -16022, -16015, -16008, -16000, -15991, -15981, -15972, -15961, -15950, -15938, -15926, -15913, -15899, -15885, -15870, -15855, -15839, -15822, -15805, -15787, -15769, -15750, -15731, -15711, -15690, -15669, -15647, -15624, -15601, -15578, -15553, -15528, -15503, -15477, -15451, -15423, -15396, -15367, -15338, -15309, -15279, -15248, -15217, -15185, -15153, -15120, -15087, -15053, -15018, -14983, -14947, -14911, -14874, -14836, -14798, -14760, -14721, -14681, -14641, -14600, -14559, -14517, -14475, -14432, -14388, -14344, -14300, -14254, -14209, -14163, -14116, -14069, -14021, -13973, -13924, -13874, -13824, -13774, -13723, -13672, -13620, -13567, -13514, -13461, -13407, -13352, -13297, -13242, -13186, -13129, -13072, -13015, -12957, -12899, -12840, -12780, -12720, -12660, -12599, -12538, -12476, -12414, -12351, -12288, -12224, -12160, -12095, -12030, -11965, -11899, -11832, -11766, -11698, -11631, -11562, -11494, -11425, -11355, -11285, -11215, -11144, -11073, -11002, -10930, -10857, -10784, -10711, -10638, -10564, -10489, -10414, -10339, -10264, -10188, -10111, -10034, -9957, -9880, -9802, -9724, -9645, -9566, -9487, -9407, -9327, -9247, -9166, -9085, -9004, -8922, -8840, -8757, -8675, -8591, -8508, -8424, -8340, -8256, -8171, -8086, -8001, -7915, -7829, -7743, -7657, -7570, -7483, -7396, -7308, -7220, -7132, -7044, -6955, -6866, -6777, -6687, -6598, -6508, -6417, -6327, -6236, -6145, -6054, -5963, -5871, -5779, -5687, -5595, -5503, -5410, -5317, -5224, -5131, -5037, -4944, -4850, -4756, -4662, -4567, -4473, -4378, -4283, -4188, -4093, -3997, -3902, -3806, -3711, -3615, -3519, -3422, -3326, -3229, -3133, -3036, -2939, -2842, -2745, -2648, -2551, -2454, -2356, -2259, -2161, -2064, -1966, -1868, -1770, -1672, -1574, -1476, -1378, -1280, -1181, -1083, -985, -886, -788, -690, -591, -493, -394, -296, -197, -99, 0, 99, 197, 296, 394, 493, 591, 690, 788, 886, 985, 1083, 1181, 1280, 1378, 1476, 1574, 1672, 1770, 1868, 1966, 2064, 2161, 2259, 2356, 2454, 2551, 2648, 2745, 2842, 2939, 3036, 3133, 3229, 3326, 3422, 3519, 3615, 3711, 3806, 3902, 3997, 4093, 4188, 4283, 4378, 4473, 4567, 4662, 4756, 4850, 4944, 5037, 5131, 5224, 5317, 5410, 5503, 5595, 5687, 5779, 5871, 5963, 6054, 6145, 6236, 6327, 6417, 6508, 6598, 6687, 6777, 6866, 6955, 7044, 7132, 7220, 7308, 7396, 7483, 7570, 7657, 7743, 7829, 7915, 8001, 8086, 8171, 8256, 8340, 8424, 8508, 8591, 8675, 8757, 8840, 8922, 9004, 9085, 9166, 9247, 9327, 9407, 9487, 9566, 9645, 9724, 9802, 9880, 9957, 10034, 10111, 10188, 10264, 10339, 10414, 10489, 10564, 10638, 10711, 10784, 10857, 10930, 11002, 11073, 11144, 11215, 11285, ], ];
@@ -0,0 +1 @@
+pub static PULSE_I32: [[i32; 1024]; 10] = [[583768371, 1963441450, 1639152565, 1831594760, 1694223117, 1801241230, 1713469953, 1787943166, 1723209179, 1780502616, 1729078783, 1775754218, 1732998935, 1772463271, 1735800651, 1770049477, 1737901656, 1768204358, 1739534772, 1766748857, 1740839988, 1765571935, 1741906515, 1764601088, 1742793892, 1763786967, 1743543367, 1763094826, 1744184421, 1762499490, 1744738681, 1761982273, 1745222374, 1761529017, 1745647911, 1761128798, 1746024945, 1760773052, 1746361098, 1760454973, 1746662468, 1760169082, 1746933990, 1759910921, 1747179704, 1759676824, 1747402942, 1759463751, 1747606484, 1759269159, 1747792661, 1759090904, 1747963448, 1758927167, 1748120522, 1758776396, 1748265323, 1758637256, 1748399090, 1758508595, 1748522895, 1758389413, 1748637670, 1758278840, 1748744232, 1758176111, 1748843296, 1758080556, 1748935490, 1757991584, 1749021371, 1757908670, 1749101433, 1757831351, 1749176113, 1757759212, 1749245803, 1757691883, 1749310852, 1757629035, 1749371573, 1757570372, 1749428246, 1757515626, 1749481125, 1757464559, 1749530435, 1757416954, 1749576382, 1757372619, 1749619150, 1757331376, 1749658906, 1757293068, 1749695802, 1757257551, 1749729972, 1757224697, 1749761540, 1757194388, 1749790616, 1757166519, 1749817301, 1757140996, 1749841683, 1757117734, 1749863844, 1757096657, 1749883854, 1757077698, 1749901778, 1757060795, 1749917672, 1757045897, 1749931585, 1757032958, 1749943560, 1757021939, 1749953632, 1757012806, 1749961833, 1757005531, 1749968187, 1757000094, 1749972711, 1756996479, 1749975421, 1756994673, 1749976323, 1756994673, 1749975421, 1756996479, 1749972711, 1757000094, 1749968187, 1757005531, 1749961833, 1757012806, 1749953632, 1757021939, 1749943560, 1757032958, 1749931585, 1757045897, 1749917672, 1757060795, 1749901778, 1757077698, 1749883854, 1757096657, 1749863844, 1757117734, 1749841683, 1757140996, 1749817301, 1757166519, 1749790616, 1757194388, 1749761540, 1757224697, 1749729972, 1757257551, 1749695802, 1757293068, 1749658906, 1757331376, 1749619150, 1757372619, 1749576382, 1757416954, 1749530435, 1757464559, 1749481125, 1757515626, 1749428246, 1757570372, 1749371573, 1757629035, 1749310852, 1757691883, 1749245803, 1757759212, 1749176113, 1757831351, 1749101433, 1757908670, 1749021371, 1757991584, 1748935490, 1758080556, 1748843296, 1758176111, 1748744232, 1758278840, 1748637670, 1758389413, 1748522895, 1758508595, 1748399090, 1758637256, 1748265323, 1758776396, 1748120522, 1758927167, 1747963448, 1759090904, 1747792661, 1759269159, 1747606484, 1759463751, 1747402942, 1759676824, 1747179704, 1759910921, 1746933990, 1760169082, 1746662468, 1760454973, 1746361098, 1760773052, 1746024945, 1761128798, 1745647911, 1761529017, 1745222374, 1761982273, 1744738681, 1762499490, 1744184421, 1763094826, 1743543367, 1763786967, 1742793892, 1764601088, 1741906515, 1765571935, 1740839988, 1766748857, 1739534772, 1768204358, 1737901656, 1770049477, 1735800651, 1772463271, 1732998935, 1775754218, 1729078783, 1780502616, 1723209179, 1787943166, 1713469953, 1801241230, 1694223117, 1831594760, 1639152565, 1963441450, 583768371, -792997651, -471615932, -661150742, -526686813, -630796774, -545934196, -617498052, -555674189, -610056625, -561544781, -605307129, -565466142, -602014863, -568269288, -599599528, -570371947, -597752643, -572006940, -596295152, -573314259, -595116013, -574383117, -594142721, -575273053, -593325925, -576025319, -592630877, -576669397, -592032400, -577226917, -591511804, -577714108, -591054931, -578143382, -590650852, -578524398, -590291000, -578864781, -589968567, -579170630, -589678070, -579446887, -589415047, -579697591, -589175829, -579926083, -588957370, -580135144, -588757123, -580327112, -588572940, -580503965, -588402998, -580667386, -588245737, -580818821, -588099818, -580959513, -587964084, -581090541, -587837527, -581212845, -587719271, -581327246, -587608545, -581434467, -587504671, -581535144, -587407049, -581629842, -587315149, -581719062, -587228497, -581803251, -587146671, -581882810, -587069290, -581958097, -586996016, -582029435, -586926541, -582097116, -586860589, -582161403, -586797908, -582222535, -586738271, -582280729, -586681470, -582336183, -586627317, -582389078, -586575639, -582439578, -586526278, -582487836, -586479089, -582533990, -586433939, -582578168, -586390704, -582620487, -586349273, -582661056, -586309540, -582699976, -586271410, -582737340, -586234792, -582773233, -586199604, -582807735, -586165768, -582840922, -586133214, -582872861, -586101874, -582903617, -586071686, -582933251, -586042592, -582961819, -586014538, -582989372, -585987473, -583015961, -585961349, -583041631, -585936123, -583066424, -585911751, -583090383, -585888196, -583113545, -585865419, -583135945, -585843387, -583157617, -585822066, -583178594, -585801426, -583198905, -585781438, -583218578, -585762073, -583237640, -585743307, -583256116, -585725114, -583274031, -585707472, -583291406, -585690358, -583308264, -585673752, -583324624, -585657633, -583340506, -585641984, -583355927, -585626786, -583370907, -585612022, -583385459, -585597676, -583399602, -585583733, -583413349, -585570179, -583426714, -585556999, -583439712, -585544180, -583452355, -585531710, -583464655, -585519577, -583476624, -585507769, -583488274, -585496275, -583499614, -585485085, -583510656, -585474189, -583521408, -585463578, -583531881, -585453241, -583542083, -585443171, -583552023, -585433360, -583561709, -585423798, -583571149, -585414478, -583580350, -585405394, -583589319, -585396538, -583598064, -585387903, -583606591, -585379483, -583614906, -585371271, -583623016, -585363262, -583630926, -585355449, -583638642, -585347828, -583646169, -585340393, -583653513, -585333139, -583660679, -585326061, -583667671, -585319154, -583674494, -585312413, -583681152, -585305836, -583687651, -585299416, -583693993, -585293150, -583700183, -585287035, -583706224, -585281066, -583712121, -585275240, -583717877, -585269554, -583723495, -585264003, -583728979, -585258585, -583734332, -585253297, -583739556, -585248136, -583744655, -585243098, -583749632, -585238181, -583754490, -585233382, -583759231, -585228699, -583763857, -585224129, -583768371, -585219670, -583772776, -585215319, -583777074, -585211074, -583781267, -585206932, -583785357, -585202893, -583789346, -585198953, -583793237, -585195111, -583797031, -585191364, -583800730, -585187712, -583804337, -585184152, -583807852, -585180682, -583811277, -585177301, -583814614, -585174007, -583817865, -585170798, -583821031, -585167674, -583824114, -585164633, -583827114, -585161673, -583830034, -585158792, -583832875, -585155991, -583835638, -585153266, -583838324, -585150618, -583840934, -585148045, -583843470, -585145546, -583845933, -585143120, -583848324, -585140765, -583850643, -585138481, -583852892, -585136267, -583855072, -585134121, -583857183, -585132043, -583859227, -585130033, -583861205, -585128088, -583863116, -585126209, -583864963, -585124395, -583866745, -585122644, -583868465, -585120957, -583870121, -585119331, -583871715, -585117768, -583873248, -585116266, -583874719, -585114824, -583876131, -585113443, -583877483, -585112120, -583878776, -585110857, -583880010, -585109652, -583881186, -585108505, -583882304, -585107416, -583883365, -585106383, -583884369, -585105407, -583885317, -585104488, -583886208, -585103624, -583887044, -585102816, -583887825, -585102063, -583888550, -585101365, -583889220, -585100722, -583889836, -585100133, -583890398, -585099598, -583890905, -585099118, -583891359, -585098691, -583891759, -585098318, -583892105, -585097999, -583892398, -585097733, -583892637, -585097520, -583892823, -585097361, -583892956, -585097255, -583893036, -585097202, -583893062, -585097202, -583893036, -585097255, -583892956, -585097361, -583892823, -585097520, -583892637, -585097733, -583892398, -585097999, -583892105, -585098318, -583891759, -585098691, -583891359, -585099118, -583890905, -585099598, -583890398, -585100133, -583889836, -585100722, -583889220, -585101365, -583888550, -585102063, -583887825, -585102816, -583887044, -585103624, -583886208, -585104488, -583885317, -585105407, -583884369, -585106383, -583883365, -585107416, -583882304, -585108505, -583881186, -585109652, -583880010, -585110857, -583878776, -585112120, -583877483, -585113443, -583876131, -585114824, -583874719, -585116266, -583873248, -585117768, -583871715, -585119331, -583870121, -585120957, -583868465, -585122644, -583866745, -585124395, -583864963, -585126209, -583863116, -585128088, -583861205, -585130033, -583859227, -585132043, -583857183, -585134121, -583855072, -585136267, -583852892, -585138481, -583850643, -585140765, -583848324, -585143120, -583845933, -585145546, -583843470, -585148045, -583840934, -585150618, -583838324, -585153266, -583835638, -585155991, -583832875, -585158792, -583830034, -585161673, -583827114, -585164633, -583824114, -585167674, -583821031, -585170798, -583817865, -585174007, -583814614, -585177301, -583811277, -585180682, -583807852, -585184152, -583804337, -585187712, -583800730, -585191364, -583797031, -585195111, -583793237, -585198953, -583789346, -585202893, -583785357, -585206932, -583781267, -585211074, -583777074, -585215319, -583772776, -585219670, -583768371, -585224129, -583763857, -585228699, -583759231, -585233382, -583754490, -585238181, -583749632, -585243098, -583744655, -585248136, -583739556, -585253297, -583734332, -585258585, -583728979, -585264003, -583723495, -585269554, -583717877, -585275240, -583712121, -585281066, -583706224, -585287035, -583700183, -585293150, -583693993, -585299416, -583687651, -585305836, -583681152, -585312413, -583674494, -585319154, -583667671, -585326061, -583660679, -585333139, -583653513, -585340393, -583646169, -585347828, -583638642, -585355449, -583630926, -585363262, -583623016, -585371271, -583614906, -585379483, -583606591, -585387903, -583598064, -585396538, -583589319, -585405394, -583580350, -585414478, -583571149, -585423798, -583561709, -585433360, -583552023, -585443171, -583542083, -585453241, -583531881, -585463578, -583521408, -585474189, -583510656, -585485085, -583499614, -585496275, -583488274, -585507769, -583476624, -585519577, -583464655, -585531710, -583452355, -585544180, -583439712, -585556999, -583426714, -585570179, -583413349, -585583733, -583399602, -585597676, -583385459, -585612022, -583370907, -585626786, -583355927, -585641984, -583340506, -585657633, -583324624, -585673752, -583308264, -585690358, -583291406, -585707472, -583274031, -585725114, -583256116, -585743307, -583237640, -585762073, -583218578, -585781438, -583198905, -585801426, -583178594, -585822066, -583157617, -585843387, -583135945, -585865419, -583113545, -585888196, -583090383, -585911751, -583066424, -585936123, -583041631, -585961349, -583015961, -585987473, -582989372, -586014538, -582961819, -586042592, -582933251, -586071686, -582903617, -586101874, -582872861, -586133214, -582840922, -586165768, -582807735, -586199604, -582773233, -586234792, -582737340, -586271410, -582699976, -586309540, -582661056, -586349273, -582620487, -586390704, -582578168, -586433939, -582533990, -586479089, -582487836, -586526278, -582439578, -586575639, -582389078, -586627317, -582336183, -586681470, -582280729, -586738271, -582222535, -586797908, -582161403, -586860589, -582097116, -586926541, -582029435, -586996016, -581958097, -587069290, -581882810, -587146671, -581803251, -587228497, -581719062, -587315149, -581629842, -587407049, -581535144, -587504671, -581434467, -587608545, -581327246, -587719271, -581212845, -587837527, -581090541, -587964084, -580959513, -588099818, -580818821, -588245737, -580667386, -588402998, -580503965, -588572940, -580327112, -588757123, -580135144, -588957370, -579926083, -589175829, -579697591, -589415047, -579446887, -589678070, -579170630, -589968567, -578864781, -590291000, -578524398, -590650852, -578143382, -591054931, -577714108, -591511804, -577226917, -592032400, -576669397, -592630877, -576025319, -593325925, -575273053, -594142721, -574383117, -595116013, -573314259, -596295152, -572006940, -597752643, -570371947, -599599528, -568269288, -602014863, -565466142, -605307129, -561544781, -610056625, -555674189, -617498052, -545934196, -630796774, -526686813, -661150742, -471615932, -792997651, ], [583041631, 1604624269, 1964179435, 1781445012, 1638403138, 1742353056, 1832355829, 1759369196, 1693450198, 1749869202, 1802026213, 1755925794, 1712672689, 1751730466, 1788752936, 1754806585, 1722386671, 1752455678, 1781338097, 1754309896, 1728230083, 1752810726, 1776616386, 1754047302, 1732123040, 1753010411, 1773353157, 1753891906, 1734896500, 1753133726, 1770968173, 1753792399, 1736968126, 1753215194, 1769153019, 1753724849, 1738570675, 1753271836, 1767728707, 1753676877, 1739844061, 1753312828, 1766584272, 1753641564, 1740877422, 1753343474, 1765647291, 1753614791, 1741730211, 1753367008, 1764868503, 1753593986, 1742443587, 1753385496, 1764213253, 1753577476, 1743046933, 1753400307, 1763656467, 1753564132, 1743561773, 1753412378, 1763179570, 1753553172, 1744004216, 1753422367, 1762768523, 1753544036, 1744386551, 1753430749, 1762412532, 1753536322, 1744718296, 1753437872, 1762103173, 1753529726, 1745006925, 1753443997, 1761833795, 1753524021, 1745258375, 1753449325, 1761599087, 1753519032, 1745477405, 1753454009, 1761394776, 1753514622, 1745667860, 1753458171, 1761217399, 1753510684, 1745832862, 1753461907, 1761064139, 1753507130, 1745974954, 1753465297, 1760932698, 1753503889, 1746096211, 1753468404, 1760821205, 1753500902, 1746198319, 1753471283, 1760728142, 1753498120, 1746282640, 1753473979, 1760652291, 1753495500, 1746350260, 1753476531, 1760592692, 1753493006, 1746402023, 1753478975, 1760548607, 1753490604, 1746438561, 1753481342, 1760519506, 1753488265, 1746460313, 1753483661, 1760505040, 1753485960, 1746467535, 1753485960, 1760505040, 1753483661, 1746460313, 1753488265, 1760519506, 1753481342, 1746438561, 1753490604, 1760548607, 1753478975, 1746402023, 1753493006, 1760592692, 1753476531, 1746350260, 1753495500, 1760652291, 1753473979, 1746282640, 1753498120, 1760728142, 1753471283, 1746198319, 1753500902, 1760821205, 1753468404, 1746096211, 1753503889, 1760932698, 1753465297, 1745974954, 1753507130, 1761064139, 1753461907, 1745832862, 1753510684, 1761217399, 1753458171, 1745667860, 1753514622, 1761394776, 1753454009, 1745477405, 1753519032, 1761599087, 1753449325, 1745258375, 1753524021, 1761833795, 1753443997, 1745006925, 1753529726, 1762103173, 1753437872, 1744718296, 1753536322, 1762412532, 1753430749, 1744386551, 1753544036, 1762768523, 1753422367, 1744004216, 1753553172, 1763179570, 1753412378, 1743561773, 1753564132, 1763656467, 1753400307, 1743046933, 1753577476, 1764213253, 1753385496, 1742443587, 1753593986, 1764868503, 1753367008, 1741730211, 1753614791, 1765647291, 1753343474, 1740877422, 1753641564, 1766584272, 1753312828, 1739844061, 1753676877, 1767728707, 1753271836, 1738570675, 1753724849, 1769153019, 1753215194, 1736968126, 1753792399, 1770968173, 1753133726, 1734896500, 1753891906, 1773353157, 1753010411, 1732123040, 1754047302, 1776616386, 1752810726, 1728230083, 1754309896, 1781338097, 1752455678, 1722386671, 1754806585, 1788752936, 1751730466, 1712672689, 1755925794, 1802026213, 1749869202, 1693450198, 1759369196, 1832355829, 1742353056, 1638403138, 1781445012, 1964179435, 1604624269, 583041631, -435633942, -792281963, -612454964, -472320753, -573362451, -660456606, -590379427, -527370443, -580878316, -630123479, -586936307, -546597325, -582739298, -616844925, -585817380, -556317475, -583464226, -609423025, -585320978, -562168848, -583818985, -604692446, -585058675, -566071585, -584018378, -601418517, -584903573, -568856673, -584141395, -599020967, -584804368, -570941813, -584222557, -597191342, -584737127, -572559802, -584278886, -595750608, -584689472, -573850605, -584319558, -594587748, -584654484, -574903415, -584349873, -593630279, -584628047, -575777748, -584373065, -592828872, -584607591, -576514836, -584391199, -592148796, -584591442, -577144141, -584405642, -591564895, -584578474, -577687277, -584417329, -591058497, -584567905, -578160453, -584426917, -590615460, -584559179, -578576066, -584434879, -590224871, -584551894, -578943759, -584441562, -589878180, -584545750, -579271140, -584447223, -589568592, -584540522, -579564298, -584452061, -589290638, -584536037, -579828156, -584456227, -589039870, -584532161, -580066745, -584459839, -588812631, -584528790, -580283391, -584462990, -588605888, -584525840, -580480863, -584465756, -588417105, -584523244, -580661489, -584468195, -588244146, -584520948, -580827235, -584470358, -588085196, -584518908, -580979774, -584472284, -587938709, -584517088, -581120542, -584474007, -587803353, -584515457, -581250773, -584475553, -587677980, -584513990, -581371536, -584476946, -587561594, -584512666, -581483763, -584478205, -587453324, -584511467, -581588266, -584479347, -587352410, -584510378, -581685759, -584480386, -587258181, -584509386, -581776872, -584481334, -587170045, -584508480, -581862161, -584482200, -587087479, -584507651, -581942120, -584482995, -587010017, -584506889, -582017190, -584483725, -586937241, -584506188, -582087763, -584484398, -586868782, -584505542, -582154192, -584485019, -586804304, -584504945, -582216794, -584485594, -586743508, -584504392, -582275853, -584486126, -586686121, -584503879, -582331627, -584486621, -586631901, -584503402, -582384349, -584487081, -586580624, -584502958, -582434232, -584487509, -586532089, -584502544, -582481466, -584487910, -586486112, -584502157, -582526227, -584488284, -586442526, -584501795, -582568675, -584488634, -586401179, -584501456, -582608956, -584488963, -586361930, -584501137, -582647205, -584489272, -586324651, -584500838, -582683544, -584489562, -586289225, -584500556, -582718085, -584489835, -586255542, -584500291, -582750933, -584490093, -586223504, -584500040, -582782184, -584490337, -586193019, -584499803, -582811925, -584490567, -586164001, -584499579, -582840239, -584490785, -586136372, -584499367, -582867201, -584490992, -586110060, -584499166, -582892880, -584491188, -586084998, -584498974, -582917342, -584491375, -586061122, -584498793, -582940647, -584491552, -586038374, -584498619, -582962850, -584491721, -586016703, -584498454, -582984003, -584491882, -585996056, -584498297, -583004155, -584492036, -585976388, -584498146, -583023350, -584492184, -585957655, -584498002, -583041631, -584492324, -585939817, -584497864, -583059035, -584492459, -585922836, -584497732, -583075601, -584492589, -585906678, -584497605, -583091361, -584492713, -585891308, -584497483, -583106347, -584492833, -585876698, -584497366, -583120588, -584492948, -585862819, -584497253, -583134113, -584493059, -585849643, -584497144, -583146946, -584493166, -585837146, -584497039, -583159112, -584493270, -585825305, -584496937, -583170634, -584493370, -585814098, -584496838, -583181531, -584493467, -585803505, -584496743, -583191824, -584493561, -585793508, -584496650, -583201530, -584493652, -585784089, -584496560, -583210666, -584493741, -585775232, -584496473, -583219248, -584493827, -585766921, -584496387, -583227291, -584493912, -585759143, -584496304, -583234807, -584493994, -585751885, -584496223, -583241810, -584494074, -585745135, -584496143, -583248311, -584494153, -585738881, -584496065, -583254319, -584494230, -585733114, -584495989, -583259846, -584494306, -585727825, -584495914, -583264900, -584494380, -585723005, -584495840, -583269488, -584494453, -585718647, -584495768, -583273618, -584494525, -585714744, -584495696, -583277297, -584494596, -585711289, -584495625, -583280529, -584494667, -585708277, -584495555, -583283321, -584494736, -585705704, -584495486, -583285676, -584494805, -585703566, -584495417, -583287598, -584494874, -585701860, -584495349, -583289090, -584494942, -585700582, -584495281, -583290154, -584495010, -585699731, -584495213, -583290791, -584495078, -585699306, -584495145, -583291004, -584495145, -585699306, -584495078, -583290791, -584495213, -585699731, -584495010, -583290154, -584495281, -585700582, -584494942, -583289090, -584495349, -585701860, -584494874, -583287598, -584495417, -585703566, -584494805, -583285676, -584495486, -585705704, -584494736, -583283321, -584495555, -585708277, -584494667, -583280529, -584495625, -585711289, -584494596, -583277297, -584495696, -585714744, -584494525, -583273618, -584495768, -585718647, -584494453, -583269488, -584495840, -585723005, -584494380, -583264900, -584495914, -585727825, -584494306, -583259846, -584495989, -585733114, -584494230, -583254319, -584496065, -585738881, -584494153, -583248311, -584496143, -585745135, -584494074, -583241810, -584496223, -585751885, -584493994, -583234807, -584496304, -585759143, -584493912, -583227291, -584496387, -585766921, -584493827, -583219248, -584496473, -585775232, -584493741, -583210666, -584496560, -585784089, -584493652, -583201530, -584496650, -585793508, -584493561, -583191824, -584496743, -585803505, -584493467, -583181531, -584496838, -585814098, -584493370, -583170634, -584496937, -585825305, -584493270, -583159112, -584497039, -585837146, -584493166, -583146946, -584497144, -585849643, -584493059, -583134113, -584497253, -585862819, -584492948, -583120588, -584497366, -585876698, -584492833, -583106347, -584497483, -585891308, -584492713, -583091361, -584497605, -585906678, -584492589, -583075601, -584497732, -585922836, -584492459, -583059035, -584497864, -585939817, -584492324, -583041631, -584498002, -585957655, -584492184, -583023350, -584498146, -585976388, -584492036, -583004155, -584498297, -585996056, -584491882, -582984003, -584498454, -586016703, -584491721, -582962850, -584498619, -586038374, -584491552, -582940647, -584498793, -586061122, -584491375, -582917342, -584498974, -586084998, -584491188, -582892880, -584499166, -586110060, -584490992, -582867201, -584499367, -586136372, -584490785, -582840239, -584499579, -586164001, -584490567, -582811925, -584499803, -586193019, -584490337, -582782184, -584500040, -586223504, -584490093, -582750933, -584500291, -586255542, -584489835, -582718085, -584500556, -586289225, -584489562, -582683544, -584500838, -586324651, -584489272, -582647205, -584501137, -586361930, -584488963, -582608956, -584501456, -586401179, -584488634, -582568675, -584501795, -586442526, -584488284, -582526227, -584502157, -586486112, -584487910, -582481466, -584502544, -586532089, -584487509, -582434232, -584502958, -586580624, -584487081, -582384349, -584503402, -586631901, -584486621, -582331627, -584503879, -586686121, -584486126, -582275853, -584504392, -586743508, -584485594, -582216794, -584504945, -586804304, -584485019, -582154192, -584505542, -586868782, -584484398, -582087763, -584506188, -586937241, -584483725, -582017190, -584506889, -587010017, -584482995, -581942120, -584507651, -587087479, -584482200, -581862161, -584508480, -587170045, -584481334, -581776872, -584509386, -587258181, -584480386, -581685759, -584510378, -587352410, -584479347, -581588266, -584511467, -587453324, -584478205, -581483763, -584512666, -587561594, -584476946, -581371536, -584513990, -587677980, -584475553, -581250773, -584515457, -587803353, -584474007, -581120542, -584517088, -587938709, -584472284, -580979774, -584518908, -588085196, -584470358, -580827235, -584520948, -588244146, -584468195, -580661489, -584523244, -588417105, -584465756, -580480863, -584525840, -588605888, -584462990, -580283391, -584528790, -588812631, -584459839, -580066745, -584532161, -589039870, -584456227, -579828156, -584536037, -589290638, -584452061, -579564298, -584540522, -589568592, -584447223, -579271140, -584545750, -589878180, -584441562, -578943759, -584551894, -590224871, -584434879, -578576066, -584559179, -590615460, -584426917, -578160453, -584567905, -591058497, -584417329, -577687277, -584578474, -591564895, -584405642, -577144141, -584591442, -592148796, -584391199, -576514836, -584607591, -592828872, -584373065, -575777748, -584628047, -593630279, -584349873, -574903415, -584654484, -594587748, -584319558, -573850605, -584689472, -595750608, -584278886, -572559802, -584737127, -597191342, -584222557, -570941813, -584804368, -599020967, -584141395, -568856673, -584903573, -601418517, -584018378, -566071585, -585058675, -604692446, -583818985, -562168848, -585320978, -609423025, -583464226, -556317475, -585817380, -616844925, -582739298, -546597325, -586936307, -630123479, -580878316, -527370443, -590379427, -660456606, -573362451, -472320753, -612454964, -792281963, -435633942, ], [581588266, 1147274203, 1604638708, 1881320979, 1965678165, 1905086778, 1781430059, 1675589430, 1636857429, 1669820845, 1742368551, 1808563829, 1833950221, 1811110768, 1759353129, 1710823405, 1691805327, 1709396301, 1749885873, 1788429346, 1803723460, 1789339377, 1755908486, 1723775575, 1710921060, 1723146170, 1751748449, 1779425410, 1790561068, 1779885629, 1754787887, 1730381589, 1720519793, 1730031225, 1752475133, 1774384016, 1783266101, 1774658994, 1754289639, 1734345495, 1726238434, 1734124519, 1752831834, 1771194041, 1778674356, 1771374979, 1754025290, 1736960433, 1729995908, 1736810031, 1753033385, 1769018452, 1775552473, 1769145004, 1753867908, 1738792359, 1732621785, 1738684819, 1753158814, 1767460820, 1773321713, 1767552933, 1753766148, 1740127174, 1734532107, 1740047777, 1753242687, 1766309863, 1771675421, 1766378628, 1753696028, 1741124263, 1735957717, 1741064505, 1753302078, 1765443219, 1770436688, 1765495255, 1753645111, 1741878979, 1737036267, 1741833639, 1753346232, 1764785643, 1769497023, 1764825111, 1753606401, 1742451326, 1737854187, 1742417061, 1753380531, 1764288871, 1768786964, 1764318478, 1753575690, 1742880379, 1738467678, 1742854987, 1753408316, 1763921131, 1768260834, 1763942669, 1753550288, 1743192025, 1738913947, 1743174047, 1753431786, 1763661350, 1767888345, 1763676003, 1753528371, 1743403333, 1739217538, 1743391819, 1753452479, 1763495843, 1767649801, 1763504360, 1753508614, 1743525077, 1739393980, 1743519455, 1753471557, 1763416392, 1767533327, 1763419186, 1753489977, 1743563180, 1739451875, 1743563180, 1753489977, 1763419186, 1767533327, 1763416392, 1753471557, 1743519455, 1739393980, 1743525077, 1753508614, 1763504360, 1767649801, 1763495843, 1753452479, 1743391819, 1739217538, 1743403333, 1753528371, 1763676003, 1767888345, 1763661350, 1753431786, 1743174047, 1738913947, 1743192025, 1753550288, 1763942669, 1768260834, 1763921131, 1753408316, 1742854987, 1738467678, 1742880379, 1753575690, 1764318478, 1768786964, 1764288871, 1753380531, 1742417061, 1737854187, 1742451326, 1753606401, 1764825111, 1769497023, 1764785643, 1753346232, 1741833639, 1737036267, 1741878979, 1753645111, 1765495255, 1770436688, 1765443219, 1753302078, 1741064505, 1735957717, 1741124263, 1753696028, 1766378628, 1771675421, 1766309863, 1753242687, 1740047777, 1734532107, 1740127174, 1753766148, 1767552933, 1773321713, 1767460820, 1753158814, 1738684819, 1732621785, 1738792359, 1753867908, 1769145004, 1775552473, 1769018452, 1753033385, 1736810031, 1729995908, 1736960433, 1754025290, 1771374979, 1778674356, 1771194041, 1752831834, 1734124519, 1726238434, 1734345495, 1754289639, 1774658994, 1783266101, 1774384016, 1752475133, 1730031225, 1720519793, 1730381589, 1754787887, 1779885629, 1790561068, 1779425410, 1751748449, 1723146170, 1710921060, 1723775575, 1755908486, 1789339377, 1803723460, 1788429346, 1749885873, 1709396301, 1691805327, 1710823405, 1759353129, 1811110768, 1833950221, 1808563829, 1742368551, 1669820845, 1636857429, 1675589430, 1781430059, 1905086778, 1965678165, 1881320979, 1604638708, 1147274203, 581588266, 17605249, -435647893, -708218509, -790872431, -731984647, -612441475, -510715273, -473687908, -504946010, -573375499, -635451092, -659130447, -637999051, -590366797, -545964519, -528656919, -544536050, -580890549, -615296289, -628875435, -616208033, -586924452, -558942120, -547808126, -558310648, -582750792, -606261732, -615670236, -606724378, -585806230, -565584040, -557457130, -565230881, -583475050, -601179035, -608317376, -601457185, -585310465, -569594777, -563241469, -569370241, -583829201, -597936552, -603651919, -598121448, -585048743, -572268070, -567080910, -572113298, -584028039, -595696569, -600439545, -595827917, -584894171, -574170646, -569806104, -574057867, -584150551, -594061789, -598100304, -594159602, -584795447, -575589373, -571834450, -575503795, -584231253, -592819856, -596326025, -592895305, -584728646, -576684834, -573398474, -576617863, -584287162, -591847078, -594937936, -591906883, -584681393, -577553852, -574637895, -577500158, -584327450, -591066580, -593825252, -591115023, -584646772, -578258240, -575641682, -578214341, -584357414, -590428098, -592915702, -590468037, -584620670, -578839287, -576469149, -578802816, -584380285, -589897399, -592160154, -589930813, -584600521, -579325612, -577161341, -579294904, -584398126, -589450376, -591524053, -589478676, -584584652, -579737665, -577747553, -579711516, -584412301, -589069569, -590982404, -589093790, -584571939, -580090442, -578249238, -580067957, -584423744, -588742029, -590516689, -588762946, -584561604, -580395182, -578682466, -580375685, -584433109, -588457955, -590112900, -588476160, -584553091, -580660469, -579059498, -580643441, -584440869, -588209796, -589760256, -588225748, -584545999, -580892970, -579389853, -580878005, -584447367, -587991644, -589450324, -588005704, -584540031, -581097940, -579681026, -581084714, -584452862, -587798812, -589176420, -587811269, -584534961, -581279572, -579938997, -581267826, -584457550, -587627538, -588933181, -587638625, -584530618, -581441249, -580168589, -581430774, -584461580, -587474769, -588716255, -587484675, -584526870, -581585734, -580373737, -581576357, -584465071, -587338002, -588522079, -587346885, -584523612, -581715298, -580557676, -581706877, -584468116, -587215170, -588347706, -587223159, -584520762, -581831827, -580723093, -581824244, -584470788, -587104550, -588190685, -587111753, -584518254, -581936899, -580872232, -581930054, -584473146, -587004696, -588048958, -587011204, -584516033, -582031839, -581006981, -582025650, -584475240, -586914388, -587920788, -586920278, -584514056, -582117774, -581128939, -582112167, -584477109, -586832589, -587804702, -586837927, -584512288, -582195658, -581239468, -582190575, -584478785, -586758415, -587699440, -586763257, -584510697, -582266311, -581339731, -582261698, -584480297, -586691107, -587603925, -586695502, -584509259, -582330437, -581430730, -582326250, -584481666, -586630012, -587517228, -586634001, -584507954, -582388641, -581513326, -582384841, -584482912, -586574567, -587438547, -586578187, -584506763, -582441449, -581588266, -582438003, -584484051, -586524283, -587367187, -586527564, -584505672, -582489316, -581656195, -582486194, -584485098, -586478735, -587302547, -586481705, -584504667, -582532637, -581717678, -582529814, -584486064, -586437555, -587244100, -586440237, -584503737, -582571756, -581773202, -582569210, -584486960, -586400422, -587191392, -586402836, -584502872, -582606974, -581823194, -582604686, -584487795, -586367055, -587144023, -586369220, -584502064, -582638551, -581868025, -582636504, -584488577, -586337211, -587101649, -586339143, -584501306, -582666713, -581908017, -582664894, -584489313, -586310680, -587063970, -586312391, -584500591, -582691659, -581943450, -582690053, -584490009, -586287278, -587030724, -586288781, -584499913, -582713557, -581974564, -582712154, -584490671, -586266848, -587001689, -586268152, -584499266, -582732552, -582001567, -582731344, -584491304, -586249256, -586976673, -586250370, -584498646, -582748770, -582024636, -582747748, -584491912, -586234387, -586955515, -586235319, -584498049, -582762312, -582043917, -582761470, -584492500, -586222149, -586938081, -586222903, -584497470, -582773266, -582059532, -582772599, -584493071, -586212463, -586924260, -586213045, -584496905, -582781699, -582071579, -582781202, -584493630, -586205270, -586913968, -586205683, -584496352, -582787663, -582080132, -582787333, -584494179, -586200526, -586907141, -586200773, -584495806, -582791194, -582085243, -582791029, -584494723, -586198203, -586903738, -586198285, -584495264, -582792314, -582086944, -582792314, -584495264, -586198285, -586903738, -586198203, -584494723, -582791029, -582085243, -582791194, -584495806, -586200773, -586907141, -586200526, -584494179, -582787333, -582080132, -582787663, -584496352, -586205683, -586913968, -586205270, -584493630, -582781202, -582071579, -582781699, -584496905, -586213045, -586924260, -586212463, -584493071, -582772599, -582059532, -582773266, -584497470, -586222903, -586938081, -586222149, -584492500, -582761470, -582043917, -582762312, -584498049, -586235319, -586955515, -586234387, -584491912, -582747748, -582024636, -582748770, -584498646, -586250370, -586976673, -586249256, -584491304, -582731344, -582001567, -582732552, -584499266, -586268152, -587001689, -586266848, -584490671, -582712154, -581974564, -582713557, -584499913, -586288781, -587030724, -586287278, -584490009, -582690053, -581943450, -582691659, -584500591, -586312391, -587063970, -586310680, -584489313, -582664894, -581908017, -582666713, -584501306, -586339143, -587101649, -586337211, -584488577, -582636504, -581868025, -582638551, -584502064, -586369220, -587144023, -586367055, -584487795, -582604686, -581823194, -582606974, -584502872, -586402836, -587191392, -586400422, -584486960, -582569210, -581773202, -582571756, -584503737, -586440237, -587244100, -586437555, -584486064, -582529814, -581717678, -582532637, -584504667, -586481705, -587302547, -586478735, -584485098, -582486194, -581656195, -582489316, -584505672, -586527564, -587367187, -586524283, -584484051, -582438003, -581588266, -582441449, -584506763, -586578187, -587438547, -586574567, -584482912, -582384841, -581513326, -582388641, -584507954, -586634001, -587517228, -586630012, -584481666, -582326250, -581430730, -582330437, -584509259, -586695502, -587603925, -586691107, -584480297, -582261698, -581339731, -582266311, -584510697, -586763257, -587699440, -586758415, -584478785, -582190575, -581239468, -582195658, -584512288, -586837927, -587804702, -586832589, -584477109, -582112167, -581128939, -582117774, -584514056, -586920278, -587920788, -586914388, -584475240, -582025650, -581006981, -582031839, -584516033, -587011204, -588048958, -587004696, -584473146, -581930054, -580872232, -581936899, -584518254, -587111753, -588190685, -587104550, -584470788, -581824244, -580723093, -581831827, -584520762, -587223159, -588347706, -587215170, -584468116, -581706877, -580557676, -581715298, -584523612, -587346885, -588522079, -587338002, -584465071, -581576357, -580373737, -581585734, -584526870, -587484675, -588716255, -587474769, -584461580, -581430774, -580168589, -581441249, -584530618, -587638625, -588933181, -587627538, -584457550, -581267826, -579938997, -581279572, -584534961, -587811269, -589176420, -587798812, -584452862, -581084714, -579681026, -581097940, -584540031, -588005704, -589450324, -587991644, -584447367, -580878005, -579389853, -580892970, -584545999, -588225748, -589760256, -588209796, -584440869, -580643441, -579059498, -580660469, -584553091, -588476160, -590112900, -588457955, -584433109, -580375685, -578682466, -580395182, -584561604, -588762946, -590516689, -588742029, -584423744, -580067957, -578249238, -580090442, -584571939, -589093790, -590982404, -589069569, -584412301, -579711516, -577747553, -579737665, -584584652, -589478676, -591524053, -589450376, -584398126, -579294904, -577161341, -579325612, -584600521, -589930813, -592160154, -589897399, -584380285, -578802816, -576469149, -578839287, -584620670, -590468037, -592915702, -590428098, -584357414, -578214341, -575641682, -578258240, -584646772, -591115023, -593825252, -591066580, -584327450, -577500158, -574637895, -577553852, -584681393, -591906883, -594937936, -591847078, -584287162, -576617863, -573398474, -576684834, -584728646, -592895305, -596326025, -592819856, -584231253, -575503795, -571834450, -575589373, -584795447, -594159602, -598100304, -594061789, -584150551, -574057867, -569806104, -574170646, -584894171, -595827917, -600439545, -595696569, -584028039, -572113298, -567080910, -572268070, -585048743, -598121448, -603651919, -597936552, -583829201, -569370241, -563241469, -569594777, -585310465, -601457185, -608317376, -601179035, -583475050, -565230881, -557457130, -565584040, -585806230, -606724378, -615670236, -606261732, -582750792, -558310648, -547808126, -558942120, -586924452, -616208033, -628875435, -615296289, -580890549, -544536050, -528656919, -545964519, -590366797, -637999051, -659130447, -635451092, -573375499, -504946010, -473687908, -510715273, -612441475, -731984647, -790872431, -708218509, -435647893, 17605249, ], [578682466, 868881871, 1145228527, 1394197353, 1604697445, 1769034941, 1883514987, 1948622283, 1968768550, 1951644979, 1907262304, 1846795174, 1781366989, 1720914143, 1673254628, 1643457327, 1633568839, 1642709583, 1667505914, 1702785753, 1742436449, 1780308650, 1811050229, 1830769252, 1837452192, 1831099030, 1813575746, 1788221706, 1759279836, 1731237491, 1708173330, 1693199766, 1688073012, 1693016193, 1706769384, 1726848316, 1749965215, 1772544626, 1791256640, 1803490747, 1807705136, 1803606527, 1792141562, 1775308362, 1755822338, 1736689996, 1720755823, 1710288992, 1706668627, 1710210189, 1720153724, 1734810588, 1751842282, 1768626601, 1782654836, 1791900830, 1795108419, 1791957175, 1783085260, 1769969700, 1754685339, 1739581038, 1726922964, 1718558510, 1715650120, 1718516910, 1726605222, 1738589762, 1752587607, 1766451033, 1778094088, 1785803698, 1788489308, 1785835008, 1778333214, 1767196944, 1754165803, 1741236380, 1730358502, 1723143217, 1720625961, 1723119461, 1730177083, 1740670534, 1752968746, 1765189807, 1775487284, 1782327631, 1784717219, 1782345585, 1775624386, 1765617399, 1753873241, 1742186958, 1732326974, 1725768745, 1723475044, 1725755441, 1732225379, 1741870121, 1753203072, 1764493920, 1774031721, 1780383111, 1782606822, 1780392531, 1774103651, 1764718237, 1753677522, 1742665451, 1733352789, 1727144557, 1724968827, 1727138524, 1733306723, 1742521797, 1753373689, 1764208934, 1773381724, 1779503053, 1781650340, 1779505999, 1773404217, 1764279077, 1753522041, 1742770467, 1733659201, 1727572799, 1725435811, 1727572799, 1733659201, 1742770467, 1753522041, 1764279077, 1773404217, 1779505999, 1781650340, 1779503053, 1773381724, 1764208934, 1753373689, 1742521797, 1733306723, 1727138524, 1724968827, 1727144557, 1733352789, 1742665451, 1753677522, 1764718237, 1774103651, 1780392531, 1782606822, 1780383111, 1774031721, 1764493920, 1753203072, 1741870121, 1732225379, 1725755441, 1723475044, 1725768745, 1732326974, 1742186958, 1753873241, 1765617399, 1775624386, 1782345585, 1784717219, 1782327631, 1775487284, 1765189807, 1752968746, 1740670534, 1730177083, 1723119461, 1720625961, 1723143217, 1730358502, 1741236380, 1754165803, 1767196944, 1778333214, 1785835008, 1788489308, 1785803698, 1778094088, 1766451033, 1752587607, 1738589762, 1726605222, 1718516910, 1715650120, 1718558510, 1726922964, 1739581038, 1754685339, 1769969700, 1783085260, 1791957175, 1795108419, 1791900830, 1782654836, 1768626601, 1751842282, 1734810588, 1720153724, 1710210189, 1706668627, 1710288992, 1720755823, 1736689996, 1755822338, 1775308362, 1792141562, 1803606527, 1807705136, 1803490747, 1791256640, 1772544626, 1749965215, 1726848316, 1706769384, 1693016193, 1688073012, 1693199766, 1708173330, 1731237491, 1759279836, 1788221706, 1813575746, 1831099030, 1837452192, 1830769252, 1811050229, 1780308650, 1742436449, 1702785753, 1667505914, 1642709583, 1633568839, 1643457327, 1673254628, 1720914143, 1781366989, 1846795174, 1907262304, 1951644979, 1968768550, 1948622283, 1883514987, 1769034941, 1604697445, 1394197353, 1145228527, 868881871, 578682466, 289368210, 15542340, -229653582, -435702732, -595588624, -706294085, -768878733, -788138961, -771901785, -730044115, -673357313, -612390154, -556400327, -512526202, -485259515, -476260103, -484511057, -506772031, -538254923, -573423641, -606810516, -633746642, -650920885, -656709532, -651251747, -636280430, -614749353, -590321536, -566797989, -547568844, -535160601, -530935606, -534975563, -546153714, -562373948, -580933196, -598948755, -613786320, -623427935, -626730759, -623545580, -614685482, -601756886, -586884182, -572372897, -560362978, -552522789, -549826260, -552441696, -559743398, -570438990, -582788900, -594882058, -604925211, -611502990, -613771845, -611562080, -605376592, -596290492, -585770094, -575440897, -566840576, -561193902, -559241975, -561149064, -566498110, -574372538, -583509387, -592498750, -599998512, -604932088, -606640424, -604967177, -600266492, -593334615, -585277771, -577337832, -570702911, -566331470, -564815690, -566303336, -570488070, -576667782, -583860394, -590958309, -596897492, -600815576, -602175712, -600838577, -597073131, -591506048, -585018921, -578609868, -573241083, -569695447, -568463412, -569676339, -573095170, -578154860, -584056608, -589893038, -594786836, -598021791, -599146807, -598037881, -594909688, -590276119, -584866748, -579512698, -575019628, -572047159, -571012677, -572033458, -574915013, -579186495, -584176928, -589120013, -593271249, -596019534, -596976608, -596031315, -593361202, -589400488, -584770025, -580180451, -576323579, -573768550, -572878268, -573758335, -576245584, -579937268, -584255805, -588538756, -592140064, -594527158, -595359351, -594536079, -592208182, -588751138, -584704885, -580689813, -577311958, -575071817, -574290491, -575063976, -577252089, -580503154, -584310205, -588089771, -591271038, -593381803, -594118318, -593388733, -591323948, -588254733, -584659000, -581087617, -578080242, -576083986, -575387153, -576077832, -578033254, -580941122, -584349258, -587735720, -590588550, -592482983, -593144512, -592488471, -590630455, -587866367, -584625489, -581403977, -578689068, -576885548, -576255553, -576880635, -578651557, -581287030, -584378228, -587452063, -590043441, -591765500, -592367230, -591769913, -590077126, -587557082, -584600269, -581659072, -579178683, -577529839, -576953522, -577525866, -579148349, -581564502, -584400324, -587222067, -589602467, -591185333, -591738743, -591188919, -589629847, -587307426, -584580793, -581866881, -579576765, -578053487, -577520770, -578050244, -579552004, -581789688, -584417589, -587033976, -589242422, -590711785, -591225776, -590714722, -589264849, -587103892, -584565407, -582037355, -579902897, -578482385, -577985363, -578479721, -579882561, -581973959, -584431373, -586879322, -588946683, -590322888, -590804518, -590325305, -588965135, -586936849, -584552996, -582177734, -580171263, -578835267, -578367606, -578833073, -580154514, -582125518, -584442602, -586751888, -588703090, -590002590, -590457572, -590004581, -588718291, -586799276, -584542788, -582293375, -580392326, -579125943, -578682466, -579124137, -580378540, -582250396, -584451925, -586647040, -588502611, -589738968, -590172017, -589740604, -588515099, -586685972, -584534233, -582388298, -580573911, -579364739, -578941131, -579363260, -580562620, -582353097, -584459812, -586561286, -588338451, -589523062, -589938142, -589524396, -588348634, -586593031, -584526927, -582465542, -580721925, -579559443, -579152043, -579558245, -580712773, -582437013, -584466612, -586491979, -588205471, -589348090, -589748601, -589349163, -588213657, -586517500, -584520567, -582527410, -580840841, -579715959, -579321598, -579715006, -580833561, -582504716, -584472589, -586437117, -588099778, -589208921, -589597831, -589209762, -588106201, -586457140, -584514921, -582575639, -580934039, -579838742, -579454624, -579838008, -580928429, -582558152, -584477951, -586395200, -588018449, -589101697, -589481653, -589102330, -588023282, -586410267, -584509803, -582611518, -581004033, -579931113, -579554718, -579930577, -580999945, -582598775, -584482863, -586365131, -587959337, -589023584, -589396995, -589024025, -587962706, -586375631, -584505062, -582635968, -581052637, -579995466, -579624478, -579995116, -581049967, -582627644, -584487465, -586346148, -587920937, -588972587, -589341696, -588972847, -587922925, -586352347, -584500570, -582649600, -581081072, -580033422, -579665659, -580033249, -581079753, -582645488, -584491877, -586337784, -587902293, -588947440, -589314381, -588947526, -587902950, -586339833, -584496210, -582652748, -581090038, -580045914, -579679275, -580045914, -581090038, -582652748, -584496210, -586339833, -587902950, -588947526, -589314381, -588947440, -587902293, -586337784, -584491877, -582645488, -581079753, -580033249, -579665659, -580033422, -581081072, -582649600, -584500570, -586352347, -587922925, -588972847, -589341696, -588972587, -587920937, -586346148, -584487465, -582627644, -581049967, -579995116, -579624478, -579995466, -581052637, -582635968, -584505062, -586375631, -587962706, -589024025, -589396995, -589023584, -587959337, -586365131, -584482863, -582598775, -580999945, -579930577, -579554718, -579931113, -581004033, -582611518, -584509803, -586410267, -588023282, -589102330, -589481653, -589101697, -588018449, -586395200, -584477951, -582558152, -580928429, -579838008, -579454624, -579838742, -580934039, -582575639, -584514921, -586457140, -588106201, -589209762, -589597831, -589208921, -588099778, -586437117, -584472589, -582504716, -580833561, -579715006, -579321598, -579715959, -580840841, -582527410, -584520567, -586517500, -588213657, -589349163, -589748601, -589348090, -588205471, -586491979, -584466612, -582437013, -580712773, -579558245, -579152043, -579559443, -580721925, -582465542, -584526927, -586593031, -588348634, -589524396, -589938142, -589523062, -588338451, -586561286, -584459812, -582353097, -580562620, -579363260, -578941131, -579364739, -580573911, -582388298, -584534233, -586685972, -588515099, -589740604, -590172017, -589738968, -588502611, -586647040, -584451925, -582250396, -580378540, -579124137, -578682466, -579125943, -580392326, -582293375, -584542788, -586799276, -588718291, -590004581, -590457572, -590002590, -588703090, -586751888, -584442602, -582125518, -580154514, -578833073, -578367606, -578835267, -580171263, -582177734, -584552996, -586936849, -588965135, -590325305, -590804518, -590322888, -588946683, -586879322, -584431373, -581973959, -579882561, -578479721, -577985363, -578482385, -579902897, -582037355, -584565407, -587103892, -589264849, -590714722, -591225776, -590711785, -589242422, -587033976, -584417589, -581789688, -579552004, -578050244, -577520770, -578053487, -579576765, -581866881, -584580793, -587307426, -589629847, -591188919, -591738743, -591185333, -589602467, -587222067, -584400324, -581564502, -579148349, -577525866, -576953522, -577529839, -579178683, -581659072, -584600269, -587557082, -590077126, -591769913, -592367230, -591765500, -590043441, -587452063, -584378228, -581287030, -578651557, -576880635, -576255553, -576885548, -578689068, -581403977, -584625489, -587866367, -590630455, -592488471, -593144512, -592482983, -590588550, -587735720, -584349258, -580941122, -578033254, -576077832, -575387153, -576083986, -578080242, -581087617, -584659000, -588254733, -591323948, -593388733, -594118318, -593381803, -591271038, -588089771, -584310205, -580503154, -577252089, -575063976, -574290491, -575071817, -577311958, -580689813, -584704885, -588751138, -592208182, -594536079, -595359351, -594527158, -592140064, -588538756, -584255805, -579937268, -576245584, -573758335, -572878268, -573768550, -576323579, -580180451, -584770025, -589400488, -593361202, -596031315, -596976608, -596019534, -593271249, -589120013, -584176928, -579186495, -574915013, -572033458, -571012677, -572047159, -575019628, -579512698, -584866748, -590276119, -594909688, -598037881, -599146807, -598021791, -594786836, -589893038, -584056608, -578154860, -573095170, -569676339, -568463412, -569695447, -573241083, -578609868, -585018921, -591506048, -597073131, -600838577, -602175712, -600815576, -596897492, -590958309, -583860394, -576667782, -570488070, -566303336, -564815690, -566331470, -570702911, -577337832, -585277771, -593334615, -600266492, -604967177, -606640424, -604932088, -599998512, -592498750, -583509387, -574372538, -566498110, -561149064, -559241975, -561193902, -566840576, -575440897, -585770094, -596290492, -605376592, -611562080, -613771845, -611502990, -604925211, -594882058, -582788900, -570438990, -559743398, -552441696, -549826260, -552522789, -560362978, -572372897, -586884182, -601756886, -614685482, -623545580, -626730759, -623427935, -613786320, -598948755, -580933196, -562373948, -546153714, -534975563, -530935606, -535160601, -547568844, -566797989, -590321536, -614749353, -636280430, -651251747, -656709532, -650920885, -633746642, -606810516, -573423641, -538254923, -506772031, -484511057, -476260103, -485259515, -512526202, -556400327, -612390154, -673357313, -730044115, -771901785, -788138961, -768878733, -706294085, -595588624, -435702732, -229653582, 15542340, 289368210, ], [572878268, 718913633, 863524788, 1004866551, 1141162145, 1270744005, 1392091803, 1503866605, 1604940270, 1694419264, 1771662269, 1836291135, 1888194897, 1927526819, 1954694580, 1970343967, 1975336569, 1970722202, 1957706865, 1937617235, 1911862718, 1881896202, 1849174627, 1815120533, 1781085641, 1748317503, 1717930118, 1690879281, 1667943291, 1649709454, 1636566646, 1628704013, 1626115676, 1628611155, 1635831036, 1647267250, 1662287226, 1680161039, 1700090624, 1721240077, 1742766045, 1763847225, 1783712053, 1801663729, 1817101845, 1829539993, 1838618894, 1844114722, 1845942504, 1844154583, 1838934365, 1830585659, 1819518116, 1806229349, 1791284445, 1775293637, 1758888967, 1742700764, 1727334774, 1713350726, 1701243059, 1691424433, 1684212564, 1679820761, 1678352439, 1679799718, 1684046083, 1690872919, 1699969629, 1710946907, 1723352610, 1736689649, 1750435183, 1764060428, 1777050348, 1788922503, 1799244410, 1807648792, 1813846192, 1817634547, 1818905396, 1817646563, 1813941246, 1807963607, 1799971060, 1790293583, 1779320485, 1767485135, 1755248245, 1743080328, 1731443995, 1720776731, 1711474787, 1703878768, 1698261428, 1694818105, 1693660122, 1694811375, 1698208197, 1703702492, 1711067981, 1720009313, 1730173688, 1741164531, 1752556705, 1763912690, 1774799119, 1784803058, 1793547425, 1800704983, 1806010399, 1809269924, 1810368345, 1809272976, 1806034536, 1800784906, 1793731856, 1785150942, 1775374899, 1764780921, 1753776302, 1742783000, 1732221672, 1722495797, 1713976467, 1706988424, 1701797838, 1698602310, 1697523451, 1698602310, 1701797838, 1706988424, 1713976467, 1722495797, 1732221672, 1742783000, 1753776302, 1764780921, 1775374899, 1785150942, 1793731856, 1800784906, 1806034536, 1809272976, 1810368345, 1809269924, 1806010399, 1800704983, 1793547425, 1784803058, 1774799119, 1763912690, 1752556705, 1741164531, 1730173688, 1720009313, 1711067981, 1703702492, 1698208197, 1694811375, 1693660122, 1694818105, 1698261428, 1703878768, 1711474787, 1720776731, 1731443995, 1743080328, 1755248245, 1767485135, 1779320485, 1790293583, 1799971060, 1807963607, 1813941246, 1817646563, 1818905396, 1817634547, 1813846192, 1807648792, 1799244410, 1788922503, 1777050348, 1764060428, 1750435183, 1736689649, 1723352610, 1710946907, 1699969629, 1690872919, 1684046083, 1679799718, 1678352439, 1679820761, 1684212564, 1691424433, 1701243059, 1713350726, 1727334774, 1742700764, 1758888967, 1775293637, 1791284445, 1806229349, 1819518116, 1830585659, 1838934365, 1844154583, 1845942504, 1844114722, 1838618894, 1829539993, 1817101845, 1801663729, 1783712053, 1763847225, 1742766045, 1721240077, 1700090624, 1680161039, 1662287226, 1647267250, 1635831036, 1628611155, 1626115676, 1628704013, 1636566646, 1649709454, 1667943291, 1690879281, 1717930118, 1748317503, 1781085641, 1815120533, 1849174627, 1881896202, 1911862718, 1937617235, 1957706865, 1970722202, 1975336569, 1970343967, 1954694580, 1927526819, 1888194897, 1836291135, 1771662269, 1694419264, 1604940270, 1503866605, 1392091803, 1270744005, 1141162145, 1004866551, 863524788, 718913633, 572878268, 427289771, 284002097, 144809711, 11407019, -114649302, -231974084, -339382676, -435914440, -520850167, -593723025, -654322828, -702693631, -739124825, -764136108, -778456857, -783000611, -778835454, -767151252, -749224708, -726383288, -699969061, -671303476, -641654044, -612203829, -584024529, -558053810, -535077399, -515716321, -500419441, -489461371, -482945592, -480812508, -482851994, -488719901, -497957838, -510015509, -524274795, -540074772, -556736829, -573589093, -589989402, -605346164, -619136483, -630921119, -640355873, -647199210, -651315994, -652677378, -651357013, -647523841, -641431859, -633407331, -623833966, -613136673, -601764496, -590173360, -578809236, -568092297, -558402584, -550067646, -543352507, -538452240, -535487326, -534501873, -535464646, -538272804, -542758108, -548695292, -555812256, -563801620, -572333213, -581067004, -589665988, -597808580, -605200066, -611582730, -616744324, -620524632, -622819917, -623585172, -622834147, -620637199, -617117114, -612443113, -606823288, -600495809, -593719242, -586762361, -579893846, -573372235, -567436513, -562297649, -558131394, -555072543, -553210882, -552588879, -553201216, -554996093, -557878246, -561713512, -566334744, -571548826, -577144500, -582900706, -588595099, -594012442, -598952547, -603237492, -606717849, -609277722, -610838425, -611360691, -610845356, -609332535, -606899334, -603656217, -599742203, -595319067, -590564813, -585666646, -580813741, -576190062, -571967515, -568299674, -565316315, -563118935, -561777419, -561327955, -561772252, -563078070, -565181020, -567987542, -571378937, -575216273, -579345977, -583605884, -587831515, -591862340, -595547789, -598752796, -601362671, -603287122, -604463300, -604857757, -604467266, -603318491, -601466520, -598992367, -595999507, -592609636, -588957785, -585187012, -581442864, -577867817, -574595918, -571747818, -569426379, -567713014, -566664892, -566313092, -566661779, -567688395, -569344876, -571559805, -574241431, -577281410, -580559135, -583946474, -587312726, -590529623, -593476171, -596043155, -598137152, -599683882, -600630806, -600948861, -600633291, -599703535, -598202216, -596193241, -593759139, -590997700, -588018093, -584936580, -581872017, -578941307, -576254972, -573913019, -572001246, -570588135, -569722425, -569431470, -569720416, -570572242, -571948632, -573791654, -576026160, -578562826, -581301688, -584136058, -586956651, -589655769, -592131392, -594291004, -596055026, -597359730, -598159509, -598428453, -598161150, -597372703, -596097973, -594390068, -592318155, -589964689, -587422144, -584789409, -582167958, -579657967, -577354500, -575343932, -573700730, -572484712, -571738891, -571487967, -571737543, -572474057, -573665459, -575262575, -577201122, -579404274, -581785691, -584252885, -586710794, -589065426, -591227439, -593115513, -594659394, -595802490, -596503937, -596740036, -596505046, -595811266, -594688444, -593182521, -591353764, -589274369, -587025625, -584694750, -582371525, -580144846, -578099339, -576312158, -574850087, -573767054, -573102159, -572878268, -573101245, -573759831, -574826176, -576257005, -577995364, -579972873, -582112404, -584331080, -586543472, -588664875, -590614543, -592318745, -593713549, -594747207, -595382063, -595595922, -595382811, -594753123, -593733134, -592363918, -590699701, -588805725, -586755696, -584628927, -582507283, -580472035, -578600760, -576964396, -575624554, -574631218, -574020877, -573815201, -574020271, -574626423, -575608682, -576927785, -578531744, -580357884, -582335289, -584387544, -586435679, -588401201, -590209091, -591790659, -593086147, -594046980, -594637578, -594836672, -594638061, -594050794, -593098773, -591819781, -590263990, -588492002, -586572490, -584579549, -582589840, -580679651, -578921982, -577383759, -576123294, -575188083, -574613019, -574419096, -574612647, -575185144, -576113565, -577361318, -578879678, -580609683, -582484419, -584431599, -586376347, -588244067, -589963298, -591468439, -592702252, -593618024, -594181339, -594371363, -594181610, -593620166, -592709342, -591484792, -589994125, -588295053, -586453167, -584539409, -582627346, -580790350, -579098787, -577617332, -576402498, -575500489, -574945444, -574758151, -574945267, -575499090, -576397867, -577606651, -579078651, -580757046, -582577167, -584468987, -586359807, -588177042, -589851004, -591317566, -592520614, -593414187, -593964232, -594149898, -593964320, -593414878, -592522901, -591322842, -589860950, -588193493, -586384593, -584503772, -582623282, -580815337, -579149345, -577689251, -576491089, -575600833, -575052640, -574867542, -575052640, -575600833, -576491089, -577689251, -579149345, -580815337, -582623282, -584503772, -586384593, -588193493, -589860950, -591322842, -592522901, -593414878, -593964320, -594149898, -593964232, -593414187, -592520614, -591317566, -589851004, -588177042, -586359807, -584468987, -582577167, -580757046, -579078651, -577606651, -576397867, -575499090, -574945267, -574758151, -574945444, -575500489, -576402498, -577617332, -579098787, -580790350, -582627346, -584539409, -586453167, -588295053, -589994125, -591484792, -592709342, -593620166, -594181610, -594371363, -594181339, -593618024, -592702252, -591468439, -589963298, -588244067, -586376347, -584431599, -582484419, -580609683, -578879678, -577361318, -576113565, -575185144, -574612647, -574419096, -574613019, -575188083, -576123294, -577383759, -578921982, -580679651, -582589840, -584579549, -586572490, -588492002, -590263990, -591819781, -593098773, -594050794, -594638061, -594836672, -594637578, -594046980, -593086147, -591790659, -590209091, -588401201, -586435679, -584387544, -582335289, -580357884, -578531744, -576927785, -575608682, -574626423, -574020271, -573815201, -574020877, -574631218, -575624554, -576964396, -578600760, -580472035, -582507283, -584628927, -586755696, -588805725, -590699701, -592363918, -593733134, -594753123, -595382811, -595595922, -595382063, -594747207, -593713549, -592318745, -590614543, -588664875, -586543472, -584331080, -582112404, -579972873, -577995364, -576257005, -574826176, -573759831, -573101245, -572878268, -573102159, -573767054, -574850087, -576312158, -578099339, -580144846, -582371525, -584694750, -587025625, -589274369, -591353764, -593182521, -594688444, -595811266, -596505046, -596740036, -596503937, -595802490, -594659394, -593115513, -591227439, -589065426, -586710794, -584252885, -581785691, -579404274, -577201122, -575262575, -573665459, -572474057, -571737543, -571487967, -571738891, -572484712, -573700730, -575343932, -577354500, -579657967, -582167958, -584789409, -587422144, -589964689, -592318155, -594390068, -596097973, -597372703, -598161150, -598428453, -598159509, -597359730, -596055026, -594291004, -592131392, -589655769, -586956651, -584136058, -581301688, -578562826, -576026160, -573791654, -571948632, -570572242, -569720416, -569431470, -569722425, -570588135, -572001246, -573913019, -576254972, -578941307, -581872017, -584936580, -588018093, -590997700, -593759139, -596193241, -598202216, -599703535, -600633291, -600948861, -600630806, -599683882, -598137152, -596043155, -593476171, -590529623, -587312726, -583946474, -580559135, -577281410, -574241431, -571559805, -569344876, -567688395, -566661779, -566313092, -566664892, -567713014, -569426379, -571747818, -574595918, -577867817, -581442864, -585187012, -588957785, -592609636, -595999507, -598992367, -601466520, -603318491, -604467266, -604857757, -604463300, -603287122, -601362671, -598752796, -595547789, -591862340, -587831515, -583605884, -579345977, -575216273, -571378937, -567987542, -565181020, -563078070, -561772252, -561327955, -561777419, -563118935, -565316315, -568299674, -571967515, -576190062, -580813741, -585666646, -590564813, -595319067, -599742203, -603656217, -606899334, -609332535, -610845356, -611360691, -610838425, -609277722, -606717849, -603237492, -598952547, -594012442, -588595099, -582900706, -577144500, -571548826, -566334744, -561713512, -557878246, -554996093, -553201216, -552588879, -553210882, -555072543, -558131394, -562297649, -567436513, -573372235, -579893846, -586762361, -593719242, -600495809, -606823288, -612443113, -617117114, -620637199, -622834147, -623585172, -622819917, -620524632, -616744324, -611582730, -605200066, -597808580, -589665988, -581067004, -572333213, -563801620, -555812256, -548695292, -542758108, -538272804, -535464646, -534501873, -535487326, -538452240, -543352507, -550067646, -558402584, -568092297, -578809236, -590173360, -601764496, -613136673, -623833966, -633407331, -641431859, -647523841, -651357013, -652677378, -651315994, -647199210, -640355873, -630921119, -619136483, -605346164, -589989402, -573589093, -556736829, -540074772, -524274795, -510015509, -497957838, -488719901, -482851994, -480812508, -482945592, -489461371, -500419441, -515716321, -535077399, -558053810, -584024529, -612203829, -641654044, -671303476, -699969061, -726383288, -749224708, -767151252, -778835454, -783000611, -778456857, -764136108, -739124825, -702693631, -654322828, -593723025, -520850167, -435914440, -339382676, -231974084, -114649302, 11407019, 144809711, 284002097, 427289771, ], [561327955, 634463104, 707589050, 780471807, 852877908, 924575743, 995336882, 1064937393, 1133159123, 1199790954, 1264630018, 1327482856, 1388166520, 1446509617, 1502353269, 1555552002, 1605974544, 1653504537, 1698041147, 1739499581, 1777811495, 1812925297, 1844806340, 1873437012, 1898816708, 1920961693, 1939904865, 1955695395, 1968398283, 1978093792, 1984876802, 1988856065, 1990153369, 1988902629, 1985248900, 1979347326, 1971362024, 1961464922, 1949834552, 1936654808, 1922113681, 1906401974, 1889712012, 1872236357, 1854166524, 1835691731, 1816997665, 1798265294, 1779669720, 1761379089, 1743553563, 1726344351, 1709892830, 1694329734, 1679774436, 1666334314, 1654104221, 1643166046, 1633588373, 1625426253, 1618721063, 1613500486, 1609778577, 1607555941, 1606820005, 1607545389, 1609694365, 1613217410, 1618053837, 1624132508, 1631372614, 1639684526, 1648970698, 1659126624, 1670041836, 1681600938, 1693684663, 1706170954, 1718936045, 1731855547, 1744805533, 1757663590, 1770309861, 1782628045, 1794506365, 1805838474, 1816524322, 1826470950, 1835593221, 1843814476, 1851067115, 1857293096, 1862444343, 1866483076, 1869382039, 1871124647, 1871705037, 1871128022, 1869408964, 1866573552, 1862657491, 1857706112, 1851773900, 1844923945, 1837227326, 1828762425, 1819614185, 1809873316, 1799635451, 1789000269, 1778070583, 1766951409, 1755749015, 1744569967, 1733520171, 1722703936, 1712223035, 1702175810, 1692656299, 1683753402, 1675550102, 1668122730, 1661540298, 1655863888, 1651146120, 1647430691, 1644751989, 1643134794, 1642594063, 1643134794, 1644751989, 1647430691, 1651146120, 1655863888, 1661540298, 1668122730, 1675550102, 1683753402, 1692656299, 1702175810, 1712223035, 1722703936, 1733520171, 1744569967, 1755749015, 1766951409, 1778070583, 1789000269, 1799635451, 1809873316, 1819614185, 1828762425, 1837227326, 1844923945, 1851773900, 1857706112, 1862657491, 1866573552, 1869408964, 1871128022, 1871705037, 1871124647, 1869382039, 1866483076, 1862444343, 1857293096, 1851067115, 1843814476, 1835593221, 1826470950, 1816524322, 1805838474, 1794506365, 1782628045, 1770309861, 1757663590, 1744805533, 1731855547, 1718936045, 1706170954, 1693684663, 1681600938, 1670041836, 1659126624, 1648970698, 1639684526, 1631372614, 1624132508, 1618053837, 1613217410, 1609694365, 1607545389, 1606820005, 1607555941, 1609778577, 1613500486, 1618721063, 1625426253, 1633588373, 1643166046, 1654104221, 1666334314, 1679774436, 1694329734, 1709892830, 1726344351, 1743553563, 1761379089, 1779669720, 1798265294, 1816997665, 1835691731, 1854166524, 1872236357, 1889712012, 1906401974, 1922113681, 1936654808, 1949834552, 1961464922, 1971362024, 1979347326, 1985248900, 1988902629, 1990153369, 1988856065, 1984876802, 1978093792, 1968398283, 1955695395, 1939904865, 1920961693, 1898816708, 1873437012, 1844806340, 1812925297, 1777811495, 1739499581, 1698041147, 1653504537, 1605974544, 1555552002, 1502353269, 1446509617, 1388166520, 1327482856, 1264630018, 1199790954, 1133159123, 1064937393, 995336882, 924575743, 852877908, 780471807, 707589050, 634463104, 561327955, 488416779, 415960621, 344187091, 273319097, 203573605, 135160453, 68281208, 3128094, -60117022, -121283566, -180213118, -236760185, -290792886, -342193552, -390859214, -436702014, -479649499, -519644819, -556646831, -590630089, -621584745, -649516349, -674445546, -696407694, -715452380, -731642860, -745055412, -755778625, -763912609, -769568147, -772865793, -773934921, -772912728, -769943208, -765176100, -758765812, -750870340, -741650183, -731267256, -719883823, -707661445, -694759958, -681336477, -667544447, -653532736, -639444782, -625417790, -611582002, -598060018, -584966198, -572406134, -560476187, -549263117, -538843774, -529284882, -520642891, -512963917, -506283749, -500627936, -496011956, -492441439, -489912469, -488411952, -487918034, -488400579, -489821705, -492136351, -495292899, -499233817, -503896344, -509213188, -515113243, -521522323, -528363892, -535559802, -543031018, -550698332, -558483063, -566307729, -574096698, -581776796, -589277892, -596533432, -603480932, -610062426, -616224863, -621920452, -627106953, -631747913, -635812849, -639277374, -642123263, -644338469, -645917086, -646859250, -647171003, -646864097, -645955757, -644468402, -642429323, -639870330, -636827360, -633340061, -629451355, -625206973, -620654980, -615845290, -610829170, -605658744, -600386502, -595064809, -589745432, -584479076, -579314941, -574300301, -569480108, -564896620, -560589070, -556593360, -552941791, -549662835, -546780944, -544316395, -542285181, -540698938, -539564918, -538885996, -538660721, -538883405, -539544247, -540629495, -542121639, -543999634, -546239159, -548812888, -551690802, -554840503, -558227555, -561815840, -565567912, -569445376, -573409247, -577420334, -581439597, -585428516, -589349437, -593165909, -596843009, -600347643, -603648827, -606717947, -609528991, -612058753, -614287014, -616196684, -617773921, -619008215, -619892442, -620422880, -620599205, -620424441, -619904895, -619050048, -617872430, -616387460, -614613269, -612570489, -610282034, -607772856, -605069683, -602200750, -599195512, -596084359, -592898314, -589668739, -586427032, -583204339, -580031257, -576937560, -573951924, -571101674, -568412540, -565908437, -563611257, -561540686, -559714046, -558146157, -556849224, -555832751, -555103481, -554665359, -554519530, -554664351, -555095441, -555805746, -556785635, -558023016, -559503474, -561210432, -563125326, -565227799, -567495915, -569906373, -572434746, -575055721, -577743342, -580471264, -583213005, -585942196, -588632827, -591259492, -593797622, -596223707, -598515512, -600652273, -602614880, -604386046, -605950451, -607294870, -608408282, -609281955, -609909509, -610286957, -610412722, -610287633, -609914899, -609300059, -608450909, -607377414, -606091595, -604607400, -602940556, -601108409, -599129748, -597024613, -594814101, -592520156, -590165355, -587772691, -585365354, -582966506, -580599068, -578285499, -576047590, -573906263, -571881377, -569991549, -568253983, -566684323, -565296507, -564102652, -563112951, -562335585, -561776662, -561440171, -561327955, -561439712, -561773009, -562323313, -563084054, -564046696, -565200828, -566534278, -568033233, -569682383, -571465075, -573363474, -575358745, -577431232, -579560650, -581726282, -583907176, -586082347, -588230973, -590332595, -592367306, -594315939, -596160244, -597883057, -599468458, -600901913, -602170410, -603262569, -604168749, -604881124, -605393757, -605702639, -605805726, -605702943, -605396182, -604889271, -604187931, -603299712, -602233918, -601001506, -599614978, -598088256, -596436542, -594676173, -592824455, -590899502, -588920056, -586905307, -584874712, -582847808, -580844022, -578882496, -576981896, -575160245, -573434747, -571821633, -570336004, -568991696, -567801151, -566775300, -565923472, -565253301, -564770666, -564479637, -564382442, -564479451, -564769180, -565248308, -565911714, -566752534, -567762224, -568930652, -570246198, -571695864, -573265403, -574939461, -576701721, -578535062, -580421725, -582343480, -584281803, -586218055, -588133654, -590010254, -591829920, -593575298, -595229775, -596777642, -598204234, -599496074, -600640997, -601628261, -602448651, -603094559, -603560058, -603840956, -603934829, -603841045, -603560766, -603096936, -602454247, -601639098, -600659526, -599525130, -598246980, -596837505, -595310378, -593680383, -591963272, -590175616, -588334645, -586458084, -584563986, -582670557, -580795983, -578958259, -577175015, -575463351, -573839673, -572319539, -570917509, -569647007, -568520195, -567547858, -566739300, -566102258, -565642824, -565365394, -565272622, -565365394, -565642824, -566102258, -566739300, -567547858, -568520195, -569647007, -570917509, -572319539, -573839673, -575463351, -577175015, -578958259, -580795983, -582670557, -584563986, -586458084, -588334645, -590175616, -591963272, -593680383, -595310378, -596837505, -598246980, -599525130, -600659526, -601639098, -602454247, -603096936, -603560766, -603841045, -603934829, -603840956, -603560058, -603094559, -602448651, -601628261, -600640997, -599496074, -598204234, -596777642, -595229775, -593575298, -591829920, -590010254, -588133654, -586218055, -584281803, -582343480, -580421725, -578535062, -576701721, -574939461, -573265403, -571695864, -570246198, -568930652, -567762224, -566752534, -565911714, -565248308, -564769180, -564479451, -564382442, -564479637, -564770666, -565253301, -565923472, -566775300, -567801151, -568991696, -570336004, -571821633, -573434747, -575160245, -576981896, -578882496, -580844022, -582847808, -584874712, -586905307, -588920056, -590899502, -592824455, -594676173, -596436542, -598088256, -599614978, -601001506, -602233918, -603299712, -604187931, -604889271, -605396182, -605702943, -605805726, -605702639, -605393757, -604881124, -604168749, -603262569, -602170410, -600901913, -599468458, -597883057, -596160244, -594315939, -592367306, -590332595, -588230973, -586082347, -583907176, -581726282, -579560650, -577431232, -575358745, -573363474, -571465075, -569682383, -568033233, -566534278, -565200828, -564046696, -563084054, -562323313, -561773009, -561439712, -561327955, -561440171, -561776662, -562335585, -563112951, -564102652, -565296507, -566684323, -568253983, -569991549, -571881377, -573906263, -576047590, -578285499, -580599068, -582966506, -585365354, -587772691, -590165355, -592520156, -594814101, -597024613, -599129748, -601108409, -602940556, -604607400, -606091595, -607377414, -608450909, -609300059, -609914899, -610287633, -610412722, -610286957, -609909509, -609281955, -608408282, -607294870, -605950451, -604386046, -602614880, -600652273, -598515512, -596223707, -593797622, -591259492, -588632827, -585942196, -583213005, -580471264, -577743342, -575055721, -572434746, -569906373, -567495915, -565227799, -563125326, -561210432, -559503474, -558023016, -556785635, -555805746, -555095441, -554664351, -554519530, -554665359, -555103481, -555832751, -556849224, -558146157, -559714046, -561540686, -563611257, -565908437, -568412540, -571101674, -573951924, -576937560, -580031257, -583204339, -586427032, -589668739, -592898314, -596084359, -599195512, -602200750, -605069683, -607772856, -610282034, -612570489, -614613269, -616387460, -617872430, -619050048, -619904895, -620424441, -620599205, -620422880, -619892442, -619008215, -617773921, -616196684, -614287014, -612058753, -609528991, -606717947, -603648827, -600347643, -596843009, -593165909, -589349437, -585428516, -581439597, -577420334, -573409247, -569445376, -565567912, -561815840, -558227555, -554840503, -551690802, -548812888, -546239159, -543999634, -542121639, -540629495, -539544247, -538883405, -538660721, -538885996, -539564918, -540698938, -542285181, -544316395, -546780944, -549662835, -552941791, -556593360, -560589070, -564896620, -569480108, -574300301, -579314941, -584479076, -589745432, -595064809, -600386502, -605658744, -610829170, -615845290, -620654980, -625206973, -629451355, -633340061, -636827360, -639870330, -642429323, -644468402, -645955757, -646864097, -647171003, -646859250, -645917086, -644338469, -642123263, -639277374, -635812849, -631747913, -627106953, -621920452, -616224863, -610062426, -603480932, -596533432, -589277892, -581776796, -574096698, -566307729, -558483063, -550698332, -543031018, -535559802, -528363892, -521522323, -515113243, -509213188, -503896344, -499233817, -495292899, -492136351, -489821705, -488400579, -487918034, -488411952, -489912469, -492441439, -496011956, -500627936, -506283749, -512963917, -520642891, -529284882, -538843774, -549263117, -560476187, -572406134, -584966198, -598060018, -611582002, -625417790, -639444782, -653532736, -667544447, -681336477, -694759958, -707661445, -719883823, -731267256, -741650183, -750870340, -758765812, -765176100, -769943208, -772912728, -773934921, -772865793, -769568147, -763912609, -755778625, -745055412, -731642860, -715452380, -696407694, -674445546, -649516349, -621584745, -590630089, -556646831, -519644819, -479649499, -436702014, -390859214, -342193552, -290792886, -236760185, -180213118, -121283566, -60117022, 3128094, 68281208, 135160453, 203573605, 273319097, 344187091, 415960621, 488416779, ], [538660721, 575242994, 611909007, 648630226, 685377933, 722123268, 758837265, 795490894, 832055095, 868500823, 904799085, 940920978, 976837733, 1012520750, 1047941643, 1083072274, 1117884794, 1152351683, 1186445790, 1220140366, 1253409105, 1286226182, 1318566289, 1350404667, 1381717147, 1412480181, 1442670877, 1472267028, 1501247149, 1529590507, 1557277146, 1584287921, 1610604521, 1636209502, 1661086302, 1685219277, 1708593712, 1731195850, 1753012910, 1774033103, 1794245650, 1813640801, 1832209843, 1849945119, 1866840031, 1882889058, 1898087757, 1912432773, 1925921840, 1938553789, 1950328543, 1961247121, 1971311637, 1980525289, 1988892363, 1996418218, 2003109285, 2008973048, 2014018040, 2018253824, 2021690982, 2024341095, 2026216726, 2027331403, 2027699593, 2027336683, 2026258954, 2024483558, 2022028489, 2018912554, 2015155348, 2010777219, 2005799240, 2000243170, 1994131430, 1987487058, 1980333680, 1972695469, 1964597110, 1956063759, 1947121008, 1937794840, 1928111592, 1918097910, 1907780715, 1897187151, 1886344550, 1875280388, 1864022239, 1852597735, 1841034522, 1829360215, 1817602359, 1805788380, 1793945548, 1782100931, 1770281354, 1758513353, 1746823142, 1735236562, 1723779049, 1712475588, 1701350678, 1690428289, 1679731831, 1669284112, 1659107303, 1649222905, 1639651715, 1630413791, 1621528423, 1613014104, 1604888495, 1597168406, 1589869760, 1583007577, 1576595944, 1570647996, 1565175894, 1560190807, 1555702894, 1551721288, 1548254080, 1545308310, 1542889953, 1541003912, 1539654005, 1538842967, 1538572438, 1538842967, 1539654005, 1541003912, 1542889953, 1545308310, 1548254080, 1551721288, 1555702894, 1560190807, 1565175894, 1570647996, 1576595944, 1583007577, 1589869760, 1597168406, 1604888495, 1613014104, 1621528423, 1630413791, 1639651715, 1649222905, 1659107303, 1669284112, 1679731831, 1690428289, 1701350678, 1712475588, 1723779049, 1735236562, 1746823142, 1758513353, 1770281354, 1782100931, 1793945548, 1805788380, 1817602359, 1829360215, 1841034522, 1852597735, 1864022239, 1875280388, 1886344550, 1897187151, 1907780715, 1918097910, 1928111592, 1937794840, 1947121008, 1956063759, 1964597110, 1972695469, 1980333680, 1987487058, 1994131430, 2000243170, 2005799240, 2010777219, 2015155348, 2018912554, 2022028489, 2024483558, 2026258954, 2027336683, 2027699593, 2027331403, 2026216726, 2024341095, 2021690982, 2018253824, 2014018040, 2008973048, 2003109285, 1996418218, 1988892363, 1980525289, 1971311637, 1961247121, 1950328543, 1938553789, 1925921840, 1912432773, 1898087757, 1882889058, 1866840031, 1849945119, 1832209843, 1813640801, 1794245650, 1774033103, 1753012910, 1731195850, 1708593712, 1685219277, 1661086302, 1636209502, 1610604521, 1584287921, 1557277146, 1529590507, 1501247149, 1472267028, 1442670877, 1412480181, 1381717147, 1350404667, 1318566289, 1286226182, 1253409105, 1220140366, 1186445790, 1152351683, 1117884794, 1083072274, 1047941643, 1012520750, 976837733, 940920978, 904799085, 868500823, 832055095, 795490894, 758837265, 722123268, 685377933, 648630226, 611909007, 575242994, 538660721, 502190501, 465860393, 429698159, 393731232, 357986680, 322491168, 287270929, 252351725, 217758819, 183516945, 149650269, 116182370, 83136204, 50534080, 18397633, -13252201, -44395210, -75011927, -105083654, -134592479, -163521298, -191853829, -219574634, -246669127, -273123594, -298925200, -324062002, -348522961, -372297945, -395377737, -417754043, -439419492, -460367642, -480592977, -500090908, -518857773, -536890831, -554188257, -570749140, -586573471, -601662137, -616016910, -629640437, -642536224, -654708628, -666162837, -676904858, -686941494, -696280335, -704929728, -712898767, -720197263, -726835728, -732825349, -738177965, -742906041, -747022646, -750541423, -753476565, -755842784, -757655288, -758929750, -759682277, -759929386, -759687968, -758975263, -757808829, -756206508, -754186401, -751766831, -748966319, -745803547, -742297333, -738466596, -734330327, -729907562, -725217346, -720278708, -715110631, -709732021, -704161682, -698418283, -692520337, -686486168, -680333889, -674081374, -667746235, -661345795, -654897067, -648416731, -641921110, -635426152, -628947408, -622500014, -616098669, -609757624, -603490660, -597311074, -591231668, -585264730, -579422025, -573714786, -568153697, -562748890, -557509933, -552445827, -547564995, -542875282, -538383947, -534097663, -530022514, -526163996, -522527013, -519115887, -515934349, -512985554, -510272076, -507795920, -505558525, -503560771, -501802992, -500284977, -499005989, -497964768, -497159548, -496588067, -496247580, -496134874, -496246283, -496577701, -497124599, -497882043, -498844707, -500006895, -501362556, -502905303, -504628432, -506524942, -508587552, -510808722, -513180674, -515695411, -518344737, -521120278, -524013503, -527015742, -530118210, -533312026, -536588231, -539937813, -543351723, -546820898, -550336276, -553888823, -557469544, -561069509, -564679866, -568291860, -571896855, -575486346, -579051978, -582585562, -586079089, -589524750, -592914943, -596242293, -599499664, -602680170, -605777187, -608784366, -611695642, -614505245, -617207707, -619797874, -622270910, -624622306, -626847884, -628943807, -630906578, -632733046, -634420410, -635966220, -637368380, -638625146, -639735129, -640697293, -641510952, -642175771, -642691759, -643059269, -643278992, -643351950, -643279496, -643063300, -642705350, -642207937, -641573651, -640805373, -639906263, -638879751, -637729529, -636459537, -635073953, -633577182, -631973844, -630268763, -628466948, -626573590, -624594041, -622533802, -620398513, -618193935, -615925938, -613600485, -611223623, -608801460, -606340161, -603845923, -601324972, -598783538, -596227849, -593664112, -591098504, -588537153, -585986128, -583451426, -580938956, -578454530, -576003849, -573592491, -571225900, -568909372, -566648050, -564446907, -562310741, -560244161, -558251584, -556337219, -554505066, -552758904, -551102285, -549538528, -548070714, -546701679, -545434009, -544270038, -543211843, -542261241, -541419787, -540688771, -540069219, -539561891, -539167281, -538885616, -538716862, -538660721, -538716633, -538883784, -539161104, -539547274, -540040728, -540639662, -541342036, -542145579, -543047800, -544045991, -545137238, -546318424, -547586240, -548937195, -550367622, -551873689, -553451407, -555096643, -556805128, -558572464, -560394144, -562265552, -564181983, -566138648, -568130688, -570153186, -572201176, -574269658, -576353607, -578447984, -580547750, -582647876, -584743355, -586829213, -588900520, -590952401, -592980048, -594978730, -596943802, -598870719, -600755041, -602592448, -604378745, -606109872, -607781917, -609391118, -610933875, -612406756, -613806506, -615130052, -616374510, -617537192, -618615608, -619607477, -620510725, -621323493, -622044139, -622671244, -623203609, -623640262, -623980457, -624223676, -624369630, -624418257, -624369724, -624224422, -623982970, -623646209, -623215201, -622691224, -622075772, -621370547, -620577458, -619698615, -618736321, -617693072, -616571545, -615374594, -614105244, -612766681, -611362249, -609895435, -608369867, -606789302, -605157620, -603478810, -601756966, -599996274, -598201004, -596375498, -594524163, -592651457, -590761882, -588859973, -586950284, -585037383, -583125838, -581220208, -579325029, -577444811, -575584019, -573747069, -571938316, -570162044, -568422456, -566723663, -565069679, -563464407, -561911633, -560415018, -558978087, -557604222, -556296655, -555058463, -553892554, -552801669, -551788368, -550855032, -550003850, -549236820, -548555740, -547962208, -547457614, -547043143, -546719765, -546488237, -546349104, -546302689, -546349104, -546488237, -546719765, -547043143, -547457614, -547962208, -548555740, -549236820, -550003850, -550855032, -551788368, -552801669, -553892554, -555058463, -556296655, -557604222, -558978087, -560415018, -561911633, -563464407, -565069679, -566723663, -568422456, -570162044, -571938316, -573747069, -575584019, -577444811, -579325029, -581220208, -583125838, -585037383, -586950284, -588859973, -590761882, -592651457, -594524163, -596375498, -598201004, -599996274, -601756966, -603478810, -605157620, -606789302, -608369867, -609895435, -611362249, -612766681, -614105244, -615374594, -616571545, -617693072, -618736321, -619698615, -620577458, -621370547, -622075772, -622691224, -623215201, -623646209, -623982970, -624224422, -624369724, -624418257, -624369630, -624223676, -623980457, -623640262, -623203609, -622671244, -622044139, -621323493, -620510725, -619607477, -618615608, -617537192, -616374510, -615130052, -613806506, -612406756, -610933875, -609391118, -607781917, -606109872, -604378745, -602592448, -600755041, -598870719, -596943802, -594978730, -592980048, -590952401, -588900520, -586829213, -584743355, -582647876, -580547750, -578447984, -576353607, -574269658, -572201176, -570153186, -568130688, -566138648, -564181983, -562265552, -560394144, -558572464, -556805128, -555096643, -553451407, -551873689, -550367622, -548937195, -547586240, -546318424, -545137238, -544045991, -543047800, -542145579, -541342036, -540639662, -540040728, -539547274, -539161104, -538883784, -538716633, -538660721, -538716862, -538885616, -539167281, -539561891, -540069219, -540688771, -541419787, -542261241, -543211843, -544270038, -545434009, -546701679, -548070714, -549538528, -551102285, -552758904, -554505066, -556337219, -558251584, -560244161, -562310741, -564446907, -566648050, -568909372, -571225900, -573592491, -576003849, -578454530, -580938956, -583451426, -585986128, -588537153, -591098504, -593664112, -596227849, -598783538, -601324972, -603845923, -606340161, -608801460, -611223623, -613600485, -615925938, -618193935, -620398513, -622533802, -624594041, -626573590, -628466948, -630268763, -631973844, -633577182, -635073953, -636459537, -637729529, -638879751, -639906263, -640805373, -641573651, -642207937, -642705350, -643063300, -643279496, -643351950, -643278992, -643059269, -642691759, -642175771, -641510952, -640697293, -639735129, -638625146, -637368380, -635966220, -634420410, -632733046, -630906578, -628943807, -626847884, -624622306, -622270910, -619797874, -617207707, -614505245, -611695642, -608784366, -605777187, -602680170, -599499664, -596242293, -592914943, -589524750, -586079089, -582585562, -579051978, -575486346, -571896855, -568291860, -564679866, -561069509, -557469544, -553888823, -550336276, -546820898, -543351723, -539937813, -536588231, -533312026, -530118210, -527015742, -524013503, -521120278, -518344737, -515695411, -513180674, -510808722, -508587552, -506524942, -504628432, -502905303, -501362556, -500006895, -498844707, -497882043, -497124599, -496577701, -496246283, -496134874, -496247580, -496588067, -497159548, -497964768, -499005989, -500284977, -501802992, -503560771, -505558525, -507795920, -510272076, -512985554, -515934349, -519115887, -522527013, -526163996, -530022514, -534097663, -538383947, -542875282, -547564995, -552445827, -557509933, -562748890, -568153697, -573714786, -579422025, -585264730, -591231668, -597311074, -603490660, -609757624, -616098669, -622500014, -628947408, -635426152, -641921110, -648416731, -654897067, -661345795, -667746235, -674081374, -680333889, -686486168, -692520337, -698418283, -704161682, -709732021, -715110631, -720278708, -725217346, -729907562, -734330327, -738466596, -742297333, -745803547, -748966319, -751766831, -754186401, -756206508, -757808829, -758975263, -759687968, -759929386, -759682277, -758929750, -757655288, -755842784, -753476565, -750541423, -747022646, -742906041, -738177965, -732825349, -726835728, -720197263, -712898767, -704929728, -696280335, -686941494, -676904858, -666162837, -654708628, -642536224, -629640437, -616016910, -601662137, -586573471, -570749140, -554188257, -536890831, -518857773, -500090908, -480592977, -460367642, -439419492, -417754043, -395377737, -372297945, -348522961, -324062002, -298925200, -273123594, -246669127, -219574634, -191853829, -163521298, -134592479, -105083654, -75011927, -44395210, -13252201, 18397633, 50534080, 83136204, 116182370, 149650269, 183516945, 217758819, 252351725, 287270929, 322491168, 357986680, 393731232, 429698159, 465860393, 502190501, ], [496134874, 514427849, 532773751, 551169446, 569611772, 588097545, 606623553, 625186562, 643783317, 662410538, 681064925, 699743157, 718441896, 737157781, 755887436, 774627467, 793374464, 812125002, 830875640, 849622925, 868363389, 887093556, 905809934, 924509026, 943187320, 961841301, 980467443, 999062215, 1017622080, 1036143497, 1054622919, 1073056798, 1091441584, 1109773725, 1128049669, 1146265865, 1164418764, 1182504820, 1200520488, 1218462231, 1236326515, 1254109814, 1271808606, 1289419382, 1306938639, 1324362884, 1341688635, 1358912425, 1376030795, 1393040303, 1409937520, 1426719034, 1443381449, 1459921385, 1476335482, 1492620399, 1508772813, 1524789425, 1540666956, 1556402150, 1571991775, 1587432622, 1602721510, 1617855282, 1632830809, 1647644989, 1662294750, 1676777049, 1691088873, 1705227240, 1719189201, 1732971840, 1746572272, 1759987651, 1773215161, 1786252026, 1799095504, 1811742891, 1824191524, 1836438774, 1848482055, 1860318822, 1871946567, 1883362827, 1894565182, 1905551252, 1916318702, 1926865243, 1937188629, 1947286660, 1957157182, 1966798088, 1976207320, 1985382864, 1994322758, 2003025088, 2011487989, 2019709647, 2027688297, 2035422226, 2042909773, 2050149328, 2057139334, 2063878287, 2070364736, 2076597283, 2082574584, 2088295352, 2093758351, 2098962403, 2103906383, 2108589225, 2113009915, 2117167499, 2121061077, 2124689808, 2128052905, 2131149642, 2133979348, 2136541410, 2138835274, 2140860442, 2142616476, 2144102995, 2145319678, 2146266261, 2146942538, 2147348362, 2147483647, 2147348362, 2146942538, 2146266261, 2145319678, 2144102995, 2142616476, 2140860442, 2138835274, 2136541410, 2133979348, 2131149642, 2128052905, 2124689808, 2121061077, 2117167499, 2113009915, 2108589225, 2103906383, 2098962403, 2093758351, 2088295352, 2082574584, 2076597283, 2070364736, 2063878287, 2057139334, 2050149328, 2042909773, 2035422226, 2027688297, 2019709647, 2011487989, 2003025088, 1994322758, 1985382864, 1976207320, 1966798088, 1957157182, 1947286660, 1937188629, 1926865243, 1916318702, 1905551252, 1894565182, 1883362827, 1871946567, 1860318822, 1848482055, 1836438774, 1824191524, 1811742891, 1799095504, 1786252026, 1773215161, 1759987651, 1746572272, 1732971840, 1719189201, 1705227240, 1691088873, 1676777049, 1662294750, 1647644989, 1632830809, 1617855282, 1602721510, 1587432622, 1571991775, 1556402150, 1540666956, 1524789425, 1508772813, 1492620399, 1476335482, 1459921385, 1443381449, 1426719034, 1409937520, 1393040303, 1376030795, 1358912425, 1341688635, 1324362884, 1306938639, 1289419382, 1271808606, 1254109814, 1236326515, 1218462231, 1200520488, 1182504820, 1164418764, 1146265865, 1128049669, 1109773725, 1091441584, 1073056798, 1054622919, 1036143497, 1017622080, 999062215, 980467443, 961841301, 943187320, 924509026, 905809934, 887093556, 868363389, 849622925, 830875640, 812125002, 793374464, 774627467, 755887436, 737157781, 718441896, 699743157, 681064925, 662410538, 643783317, 625186562, 606623553, 588097545, 569611772, 551169446, 532773751, 514427849, 496134874, 477897935, 459720112, 441604457, 423553995, 405571720, 387660594, 369823552, 352063494, 334383288, 316785771, 299273744, 281849977, 264517200, 247278112, 230135374, 213091610, 196149408, 179311317, 162579848, 145957473, 129446624, 113049693, 96769034, 80606956, 64565729, 48647580, 32854696, 17189217, 1653244, -13751169, -29022009, -44157310, -59155150, -74013652, -88730984, -103305360, -117735040, -132018331, -146153587, -160139207, -173973640, -187655380, -201182969, -214554999, -227770106, -240826979, -253724352, -266461008, -279035779, -291447547, -303695241, -315777840, -327694372, -339443913, -351025588, -362438575, -373682095, -384755423, -395657881, -406388840, -416947722, -427333995, -437547178, -447586838, -457452592, -467144103, -476661084, -486003297, -495170551, -504162703, -512979659, -521621371, -530087838, -538379109, -546495276, -554436480, -562202908, -569794792, -577212412, -584456090, -591526197, -598423145, -605147392, -611699442, -618079838, -624289171, -630328073, -636197217, -641897319, -647429138, -652793473, -657991163, -663023087, -667890167, -672593359, -677133662, -681512112, -685729781, -689787781, -693687257, -697429394, -701015410, -704446559, -707724128, -710849439, -713823847, -716648740, -719325536, -721855686, -724240671, -726482003, -728581222, -730539899, -732359629, -734042039, -735588781, -737001532, -738281995, -739431898, -740452994, -741347058, -742115888, -742761303, -743285146, -743689277, -743975578, -744145950, -744202311, -744146598, -743980765, -743706780, -743326628, -742842310, -742255838, -741569240, -740784553, -739903830, -738929133, -737862532, -736706109, -735461955, -734132168, -732718853, -731224123, -729650094, -727998890, -726272637, -724473466, -722603510, -720664904, -718659785, -716590291, -714458558, -712266723, -710016920, -707711282, -705351938, -702941014, -700480630, -697972905, -695419946, -692823860, -690186741, -687510680, -684797756, -682050041, -679269595, -676458470, -673618705, -670752328, -667861355, -664947786, -662013613, -659060807, -656091330, -653107124, -650110118, -647102223, -644085333, -641061323, -638032052, -634999358, -631965062, -628930961, -625898836, -622870444, -619847522, -616831783, -613824921, -610828604, -607844477, -604874163, -601919260, -598981339, -596061949, -593162612, -590284824, -587430056, -584599750, -581795322, -579018162, -576269631, -573551061, -570863758, -568208998, -565588027, -563002063, -560452295, -557939882, -555465951, -553031602, -550637902, -548285889, -545976568, -543710915, -541489875, -539314359, -537185250, -535103397, -533069618, -531084699, -529149393, -527264423, -525430479, -523648219, -521918267, -520241218, -518617632, -517048038, -515532933, -514072780, -512668011, -511319027, -510026195, -508789850, -507610296, -506487805, -505422616, -504414938, -503464947, -502572789, -501738577, -500962395, -500244293, -499584293, -498982386, -498438531, -497952658, -497524668, -497154431, -496841787, -496586549, -496388498, -496247390, -496162949, -496134874, -496162835, -496246473, -496385405, -496579219, -496827477, -497129716, -497485446, -497894152, -498355295, -498868310, -499432609, -500047579, -500712586, -501426970, -502190052, -503001127, -503859472, -504764341, -505714968, -506710566, -507750330, -508833433, -509959032, -511126265, -512334250, -513582091, -514868873, -516193665, -517555523, -518953483, -520386570, -521853794, -523354151, -524886624, -526450183, -528043787, -529666383, -531316907, -532994285, -534697433, -536425258, -538176659, -539950524, -541745738, -543561175, -545395705, -547248191, -549117492, -551002460, -552901946, -554814795, -556739851, -558675952, -560621939, -562576648, -564538917, -566507581, -568481477, -570459445, -572440322, -574422951, -576406176, -578388844, -580369807, -582347920, -584322044, -586291044, -588253792, -590209167, -592156054, -594093345, -596019940, -597934751, -599836694, -601724698, -603597701, -605454651, -607294508, -609116244, -610918841, -612701295, -614462615, -616201823, -617917954, -619610059, -621277203, -622918465, -624532942, -626119746, -627678003, -629206859, -630705477, -632173035, -633608732, -635011783, -636381422, -637716904, -639017502, -640282508, -641511234, -642703014, -643857201, -644973170, -646050317, -647088058, -648085833, -649043103, -649959350, -650834080, -651666823, -652457128, -653204570, -653908747, -654569280, -655185813, -655758015, -656285579, -656768221, -657205682, -657597726, -657944144, -658244750, -658499382, -658707904, -658870204, -658986195, -659055814, -659079024, -659055814, -658986195, -658870204, -658707904, -658499382, -658244750, -657944144, -657597726, -657205682, -656768221, -656285579, -655758015, -655185813, -654569280, -653908747, -653204570, -652457128, -651666823, -650834080, -649959350, -649043103, -648085833, -647088058, -646050317, -644973170, -643857201, -642703014, -641511234, -640282508, -639017502, -637716904, -636381422, -635011783, -633608732, -632173035, -630705477, -629206859, -627678003, -626119746, -624532942, -622918465, -621277203, -619610059, -617917954, -616201823, -614462615, -612701295, -610918841, -609116244, -607294508, -605454651, -603597701, -601724698, -599836694, -597934751, -596019940, -594093345, -592156054, -590209167, -588253792, -586291044, -584322044, -582347920, -580369807, -578388844, -576406176, -574422951, -572440322, -570459445, -568481477, -566507581, -564538917, -562576648, -560621939, -558675952, -556739851, -554814795, -552901946, -551002460, -549117492, -547248191, -545395705, -543561175, -541745738, -539950524, -538176659, -536425258, -534697433, -532994285, -531316907, -529666383, -528043787, -526450183, -524886624, -523354151, -521853794, -520386570, -518953483, -517555523, -516193665, -514868873, -513582091, -512334250, -511126265, -509959032, -508833433, -507750330, -506710566, -505714968, -504764341, -503859472, -503001127, -502190052, -501426970, -500712586, -500047579, -499432609, -498868310, -498355295, -497894152, -497485446, -497129716, -496827477, -496579219, -496385405, -496246473, -496162835, -496134874, -496162949, -496247390, -496388498, -496586549, -496841787, -497154431, -497524668, -497952658, -498438531, -498982386, -499584293, -500244293, -500962395, -501738577, -502572789, -503464947, -504414938, -505422616, -506487805, -507610296, -508789850, -510026195, -511319027, -512668011, -514072780, -515532933, -517048038, -518617632, -520241218, -521918267, -523648219, -525430479, -527264423, -529149393, -531084699, -533069618, -535103397, -537185250, -539314359, -541489875, -543710915, -545976568, -548285889, -550637902, -553031602, -555465951, -557939882, -560452295, -563002063, -565588027, -568208998, -570863758, -573551061, -576269631, -579018162, -581795322, -584599750, -587430056, -590284824, -593162612, -596061949, -598981339, -601919260, -604874163, -607844477, -610828604, -613824921, -616831783, -619847522, -622870444, -625898836, -628930961, -631965062, -634999358, -638032052, -641061323, -644085333, -647102223, -650110118, -653107124, -656091330, -659060807, -662013613, -664947786, -667861355, -670752328, -673618705, -676458470, -679269595, -682050041, -684797756, -687510680, -690186741, -692823860, -695419946, -697972905, -700480630, -702941014, -705351938, -707711282, -710016920, -712266723, -714458558, -716590291, -718659785, -720664904, -722603510, -724473466, -726272637, -727998890, -729650094, -731224123, -732718853, -734132168, -735461955, -736706109, -737862532, -738929133, -739903830, -740784553, -741569240, -742255838, -742842310, -743326628, -743706780, -743980765, -744146598, -744202311, -744145950, -743975578, -743689277, -743285146, -742761303, -742115888, -741347058, -740452994, -739431898, -738281995, -737001532, -735588781, -734042039, -732359629, -730539899, -728581222, -726482003, -724240671, -721855686, -719325536, -716648740, -713823847, -710849439, -707724128, -704446559, -701015410, -697429394, -693687257, -689787781, -685729781, -681512112, -677133662, -672593359, -667890167, -663023087, -657991163, -652793473, -647429138, -641897319, -636197217, -630328073, -624289171, -618079838, -611699442, -605147392, -598423145, -591526197, -584456090, -577212412, -569794792, -562202908, -554436480, -546495276, -538379109, -530087838, -521621371, -512979659, -504162703, -495170551, -486003297, -476661084, -467144103, -457452592, -447586838, -437547178, -427333995, -416947722, -406388840, -395657881, -384755423, -373682095, -362438575, -351025588, -339443913, -327694372, -315777840, -303695241, -291447547, -279035779, -266461008, -253724352, -240826979, -227770106, -214554999, -201182969, -187655380, -173973640, -160139207, -146153587, -132018331, -117735040, -103305360, -88730984, -74013652, -59155150, -44157310, -29022009, -13751169, 1653244, 17189217, 32854696, 48647580, 64565729, 80606956, 96769034, 113049693, 129446624, 145957473, 162579848, 179311317, 196149408, 213091610, 230135374, 247278112, 264517200, 281849977, 299273744, 316785771, 334383288, 352063494, 369823552, 387660594, 405571720, 423553995, 441604457, 459720112, 477897935, ], [744202311, 757887149, 771542420, 785166581, 798758087, 812315396, 825836967, 839321262, 852766746, 866171885, 879535148, 892855007, 906129939, 919358423, 932538940, 945669977, 958750025, 971777578, 984751135, 997669200, 1010530282, 1023332895, 1036075556, 1048756792, 1061375131, 1073929110, 1086417271, 1098838162, 1111190339, 1123472363, 1135682802, 1147820232, 1159883236, 1171870405, 1183780336, 1195611636, 1207362918, 1219032806, 1230619930, 1242122930, 1253540453, 1264871158, 1276113712, 1287266789, 1298329077, 1309299270, 1320176074, 1330958205, 1341644389, 1352233362, 1362723871, 1373114675, 1383404542, 1393592253, 1403676600, 1413656386, 1423530425, 1433297546, 1442956586, 1452506396, 1461945842, 1471273797, 1480489152, 1489590808, 1498577679, 1507448694, 1516202793, 1524838931, 1533356076, 1541753210, 1550029329, 1558183443, 1566214576, 1574121766, 1581904067, 1589560544, 1597090282, 1604492375, 1611765938, 1618910096, 1625923992, 1632806783, 1639557643, 1646175759, 1652660338, 1659010598, 1665225775, 1671305123, 1677247908, 1683053415, 1688720946, 1694249817, 1699639363, 1704888932, 1709997893, 1714965630, 1719791542, 1724475049, 1729015583, 1733412599, 1737665564, 1741773965, 1745737306, 1749555108, 1753226910, 1756752268, 1760130756, 1763361966, 1766445506, 1769381004, 1772168106, 1774806473, 1777295787, 1779635747, 1781826069, 1783866488, 1785756758, 1787496650, 1789085953, 1790524474, 1791812040, 1792948495, 1793933701, 1794767539, 1795449907, 1795980724, 1796359925, 1796587464, 1796663313, 1796587464, 1796359925, 1795980724, 1795449907, 1794767539, 1793933701, 1792948495, 1791812040, 1790524474, 1789085953, 1787496650, 1785756758, 1783866488, 1781826069, 1779635747, 1777295787, 1774806473, 1772168106, 1769381004, 1766445506, 1763361966, 1760130756, 1756752268, 1753226910, 1749555108, 1745737306, 1741773965, 1737665564, 1733412599, 1729015583, 1724475049, 1719791542, 1714965630, 1709997893, 1704888932, 1699639363, 1694249817, 1688720946, 1683053415, 1677247908, 1671305123, 1665225775, 1659010598, 1652660338, 1646175759, 1639557643, 1632806783, 1625923992, 1618910096, 1611765938, 1604492375, 1597090282, 1589560544, 1581904067, 1574121766, 1566214576, 1558183443, 1550029329, 1541753210, 1533356076, 1524838931, 1516202793, 1507448694, 1498577679, 1489590808, 1480489152, 1471273797, 1461945842, 1452506396, 1442956586, 1433297546, 1423530425, 1413656386, 1403676600, 1393592253, 1383404542, 1373114675, 1362723871, 1352233362, 1341644389, 1330958205, 1320176074, 1309299270, 1298329077, 1287266789, 1276113712, 1264871158, 1253540453, 1242122930, 1230619930, 1219032806, 1207362918, 1195611636, 1183780336, 1171870405, 1159883236, 1147820232, 1135682802, 1123472363, 1111190339, 1098838162, 1086417271, 1073929110, 1061375131, 1048756792, 1036075556, 1023332895, 1010530282, 997669200, 984751135, 971777578, 958750025, 945669977, 932538940, 919358423, 906129939, 892855007, 879535148, 866171885, 852766746, 839321262, 825836967, 812315396, 798758087, 785166581, 771542420, 757887149, 744202311, 730489455, 716750128, 702985879, 689198256, 675388810, 661559090, 647710646, 633845028, 619963785, 606068465, 592160617, 578241786, 564313519, 550377358, 536434846, 522487523, 508536927, 494584594, 480632056, 466680845, 452732487, 438788507, 424850425, 410919759, 396998022, 383086724, 369187369, 355301458, 341430488, 327575950, 313739330, 299922109, 286125763, 272351761, 258601568, 244876642, 231178435, 217508392, 203867953, 190258548, 176681604, 163138538, 149630761, 136159675, 122726676, 109333151, 95980479, 82670030, 69403168, 56181246, 43005609, 29877591, 16798521, 3769714, -9207521, -22131886, -35002095, -47816867, -60574937, -73275047, -85915950, -98496410, -111015202, -123471112, -135862938, -148189487, -160449580, -172642049, -184765738, -196819500, -208802206, -220712733, -232549976, -244312838, -256000236, -267611103, -279144380, -290599024, -301974004, -313268305, -324480922, -335610865, -346657158, -357618838, -368494958, -379284582, -389986792, -400600680, -411125355, -421559941, -431903576, -442155411, -452314614, -462380367, -472351867, -482228328, -492008975, -501693053, -511279820, -520768549, -530158529, -539449066, -548639480, -557729107, -566717301, -575603428, -584386874, -593067039, -601643338, -610115206, -618482089, -626743455, -634898784, -642947573, -650889339, -658723611, -666449936, -674067880, -681577022, -688976960, -696267308, -703447696, -710517772, -717477201, -724325663, -731062856, -737688495, -744202311, -750604054, -756893487, -763070394, -769134573, -775085841, -780924030, -786648990, -792260587, -797758706, -803143247, -808414127, -813571279, -818614656, -823544224, -828359968, -833061890, -837650008, -842124355, -846484984, -850731963, -854865375, -858885323, -862791924, -866585312, -870265637, -873833067, -877287785, -880629990, -883859898, -886977741, -889983767, -892878239, -895661438, -898333660, -900895215, -903346432, -905687653, -907919238, -910041559, -912055007, -913959986, -915756917, -917446235, -919028390, -920503847, -921873087, -923136605, -924294909, -925348526, -926297992, -927143861, -927886700, -928527091, -929065630, -929502924, -929839599, -930076289, -930213647, -930252335, -930193031, -930036424, -929783219, -929434131, -928989889, -928451236, -927818924, -927093721, -926276406, -925367768, -924368611, -923279750, -922102010, -920836230, -919483257, -918043954, -916519190, -914909847, -913216820, -911441011, -909583333, -907644712, -905626081, -903528384, -901352576, -899099618, -896770486, -894366159, -891887630, -889335899, -886711974, -884016872, -881251620, -878417250, -875514806, -872545336, -869509899, -866409558, -863245386, -860018463, -856729874, -853380711, -849972076, -846505073, -842980815, -839400418, -835765008, -832075713, -828333668, -824540012, -820695892, -816802457, -812860860, -808872261, -804837823, -800758714, -796636103, -792471166, -788265080, -784019028, -779734193, -775411762, -771052926, -766658877, -762230809, -757769919, -753277406, -748754470, -744202311, -739622134, -735015142, -730382540, -725725534, -721045328, -716343130, -711620146, -706877581, -702116642, -697338533, -692544459, -687735623, -682913228, -678078474, -673232562, -668376688, -663512048, -658639836, -653761244, -648877459, -643989669, -639099055, -634206799, -629314075, -624422059, -619531919, -614644820, -609761924, -604884388, -600013366, -595150004, -590295446, -585450831, -580617291, -575795953, -570987941, -566194369, -561416349, -556654983, -551911371, -547186603, -542481764, -537797931, -533136176, -528497561, -523883144, -519293972, -514731086, -510195519, -505688297, -501210436, -496762944, -492346820, -487963057, -483612635, -479296529, -475015701, -470771107, -466563691, -462394389, -458264126, -454173818, -450124370, -446116677, -442151625, -438230087, -434352926, -430520996, -426735137, -422996182, -419304948, -415662244, -412068866, -408525599, -405033217, -401592479, -398204135, -394868923, -391587566, -388360777, -385189256, -382073691, -379014755, -376013110, -373069405, -370184276, -367358345, -364592221, -361886501, -359241767, -356658588, -354137520, -351679103, -349283867, -346952324, -344684975, -342482306, -340344789, -338272881, -336267025, -334327651, -332455173, -330649991, -328912491, -327243043, -325642005, -324109716, -322646505, -321252682, -319928545, -318674376, -317490442, -316376995, -315334271, -314362493, -313461868, -312632587, -311874825, -311188745, -310574493, -310032197, -309561975, -309163925, -308838133, -308584667, -308403582, -308294915, -308258690, -308294915, -308403582, -308584667, -308838133, -309163925, -309561975, -310032197, -310574493, -311188745, -311874825, -312632587, -313461868, -314362493, -315334271, -316376995, -317490442, -318674376, -319928545, -321252682, -322646505, -324109716, -325642005, -327243043, -328912491, -330649991, -332455173, -334327651, -336267025, -338272881, -340344789, -342482306, -344684975, -346952324, -349283867, -351679103, -354137520, -356658588, -359241767, -361886501, -364592221, -367358345, -370184276, -373069405, -376013110, -379014755, -382073691, -385189256, -388360777, -391587566, -394868923, -398204135, -401592479, -405033217, -408525599, -412068866, -415662244, -419304948, -422996182, -426735137, -430520996, -434352926, -438230087, -442151625, -446116677, -450124370, -454173818, -458264126, -462394389, -466563691, -470771107, -475015701, -479296529, -483612635, -487963057, -492346820, -496762944, -501210436, -505688297, -510195519, -514731086, -519293972, -523883144, -528497561, -533136176, -537797931, -542481764, -547186603, -551911371, -556654983, -561416349, -566194369, -570987941, -575795953, -580617291, -585450831, -590295446, -595150004, -600013366, -604884388, -609761924, -614644820, -619531919, -624422059, -629314075, -634206799, -639099055, -643989669, -648877459, -653761244, -658639836, -663512048, -668376688, -673232562, -678078474, -682913228, -687735623, -692544459, -697338533, -702116642, -706877581, -711620146, -716343130, -721045328, -725725534, -730382540, -735015142, -739622134, -744202311, -748754470, -753277406, -757769919, -762230809, -766658877, -771052926, -775411762, -779734193, -784019028, -788265080, -792471166, -796636103, -800758714, -804837823, -808872261, -812860860, -816802457, -820695892, -824540012, -828333668, -832075713, -835765008, -839400418, -842980815, -846505073, -849972076, -853380711, -856729874, -860018463, -863245386, -866409558, -869509899, -872545336, -875514806, -878417250, -881251620, -884016872, -886711974, -889335899, -891887630, -894366159, -896770486, -899099618, -901352576, -903528384, -905626081, -907644712, -909583333, -911441011, -913216820, -914909847, -916519190, -918043954, -919483257, -920836230, -922102010, -923279750, -924368611, -925367768, -926276406, -927093721, -927818924, -928451236, -928989889, -929434131, -929783219, -930036424, -930193031, -930252335, -930213647, -930076289, -929839599, -929502924, -929065630, -928527091, -927886700, -927143861, -926297992, -925348526, -924294909, -923136605, -921873087, -920503847, -919028390, -917446235, -915756917, -913959986, -912055007, -910041559, -907919238, -905687653, -903346432, -900895215, -898333660, -895661438, -892878239, -889983767, -886977741, -883859898, -880629990, -877287785, -873833067, -870265637, -866585312, -862791924, -858885323, -854865375, -850731963, -846484984, -842124355, -837650008, -833061890, -828359968, -823544224, -818614656, -813571279, -808414127, -803143247, -797758706, -792260587, -786648990, -780924030, -775085841, -769134573, -763070394, -756893487, -750604054, -744202311, -737688495, -731062856, -724325663, -717477201, -710517772, -703447696, -696267308, -688976960, -681577022, -674067880, -666449936, -658723611, -650889339, -642947573, -634898784, -626743455, -618482089, -610115206, -601643338, -593067039, -584386874, -575603428, -566717301, -557729107, -548639480, -539449066, -530158529, -520768549, -511279820, -501693053, -492008975, -482228328, -472351867, -462380367, -452314614, -442155411, -431903576, -421559941, -411125355, -400600680, -389986792, -379284582, -368494958, -357618838, -346657158, -335610865, -324480922, -313268305, -301974004, -290599024, -279144380, -267611103, -256000236, -244312838, -232549976, -220712733, -208802206, -196819500, -184765738, -172642049, -160449580, -148189487, -135862938, -123471112, -111015202, -98496410, -85915950, -73275047, -60574937, -47816867, -35002095, -22131886, -9207521, 3769714, 16798521, 29877591, 43005609, 56181246, 69403168, 82670030, 95980479, 109333151, 122726676, 136159675, 149630761, 163138538, 176681604, 190258548, 203867953, 217508392, 231178435, 244876642, 258601568, 272351761, 286125763, 299922109, 313739330, 327575950, 341430488, 355301458, 369187369, 383086724, 396998022, 410919759, 424850425, 438788507, 452732487, 466680845, 480632056, 494584594, 508536927, 522487523, 536434846, 550377358, 564313519, 578241786, 592160617, 606068465, 619963785, 633845028, 647710646, 661559090, 675388810, 689198256, 702985879, 716750128, 730489455, ], [744202311, 748754641, 753278781, 757774561, 762241810, 766680362, 771090049, 775470704, 779822164, 784144263, 788436840, 792699733, 796932781, 801135825, 805308707, 809451269, 813563356, 817644813, 821695486, 825715222, 829703871, 833661282, 837587306, 841481795, 845344603, 849175584, 852974595, 856741491, 860476131, 864178375, 867848084, 871485118, 875089341, 878660618, 882198813, 885703795, 889175430, 892613588, 896018139, 899388957, 902725912, 906028881, 909297738, 912532360, 915732626, 918898416, 922029609, 925126088, 928187737, 931214441, 934206084, 937162555, 940083743, 942969537, 945819828, 948634511, 951413477, 954156623, 956863846, 959535044, 962170115, 964768962, 967331485, 969857589, 972347178, 974800159, 977216440, 979595928, 981938536, 984244174, 986512755, 988744196, 990938410, 993095316, 995214833, 997296880, 999341380, 1001348255, 1003317430, 1005248831, 1007142384, 1008998020, 1010815667, 1012595257, 1014336724, 1016040001, 1017705025, 1019331734, 1020920064, 1022469958, 1023981357, 1025454203, 1026888441, 1028284018, 1029640880, 1030958977, 1032238259, 1033478677, 1034680186, 1035842740, 1036966295, 1038050808, 1039096240, 1040102550, 1041069701, 1041997656, 1042886380, 1043735841, 1044546005, 1045316843, 1046048325, 1046740424, 1047393114, 1048006371, 1048580170, 1049114491, 1049609313, 1050064618, 1050480389, 1050856610, 1051193266, 1051490346, 1051747838, 1051965732, 1052144020, 1052282696, 1052381753, 1052441189, 1052461002, 1052441189, 1052381753, 1052282696, 1052144020, 1051965732, 1051747838, 1051490346, 1051193266, 1050856610, 1050480389, 1050064618, 1049609313, 1049114491, 1048580170, 1048006371, 1047393114, 1046740424, 1046048325, 1045316843, 1044546005, 1043735841, 1042886380, 1041997656, 1041069701, 1040102550, 1039096240, 1038050808, 1036966295, 1035842740, 1034680186, 1033478677, 1032238259, 1030958977, 1029640880, 1028284018, 1026888441, 1025454203, 1023981357, 1022469958, 1020920064, 1019331734, 1017705025, 1016040001, 1014336724, 1012595257, 1010815667, 1008998020, 1007142384, 1005248831, 1003317430, 1001348255, 999341380, 997296880, 995214833, 993095316, 990938410, 988744196, 986512755, 984244174, 981938536, 979595928, 977216440, 974800159, 972347178, 969857589, 967331485, 964768962, 962170115, 959535044, 956863846, 954156623, 951413477, 948634511, 945819828, 942969537, 940083743, 937162555, 934206084, 931214441, 928187737, 925126088, 922029609, 918898416, 915732626, 912532360, 909297738, 906028881, 902725912, 899388957, 896018139, 892613588, 889175430, 885703795, 882198813, 878660618, 875089341, 871485118, 867848084, 864178375, 860476131, 856741491, 852974595, 849175584, 845344603, 841481795, 837587306, 833661282, 829703871, 825715222, 821695486, 817644813, 813563356, 809451269, 805308707, 801135825, 796932781, 792699733, 788436840, 784144263, 779822164, 775470704, 771090049, 766680362, 762241810, 757774561, 753278781, 748754641, 744202311, 739621962, 735013767, 730377899, 725714532, 721023843, 716306008, 711561204, 706789610, 701991406, 697166773, 692315891, 687438945, 682536116, 677607591, 672653554, 667674191, 662669692, 657640243, 652586034, 647507256, 642404100, 637276757, 632125422, 626950287, 621751548, 616529400, 611284040, 606015666, 600724476, 595410668, 590074444, 584716004, 579335550, 573933284, 568509409, 563064131, 557597654, 552110183, 546601926, 541073089, 535523882, 529954512, 524365189, 518756125, 513127530, 507479616, 501812595, 496126682, 490422090, 484699033, 478957728, 473198390, 467421237, 461626486, 455814354, 449985062, 444138828, 438275872, 432396415, 426500679, 420588886, 414661257, 408718017, 402759389, 396785597, 390796866, 384793422, 378775491, 372743299, 366697073, 360637042, 354563433, 348476474, 342376396, 336263427, 330137799, 323999741, 317849484, 311687261, 305513302, 299327842, 293131111, 286923345, 280704776, 274475639, 268236167, 261986597, 255727164, 249458102, 243179648, 236892039, 230595511, 224290301, 217976646, 211654785, 205324956, 198987396, 192642344, 186290039, 179930721, 173564628, 167192001, 160813079, 154428102, 148037311, 141640947, 135239250, 128832462, 122420823, 116004575, 109583959, 103159218, 96730592, 90298325, 83862658, 77423834, 70982095, 64537684, 58090842, 51641814, 45190841, 38738167, 32284034, 25828686, 19372365, 12915315, 6457779, 0, -6457779, -12915315, -19372365, -25828686, -32284034, -38738167, -45190841, -51641814, -58090842, -64537684, -70982095, -77423834, -83862658, -90298325, -96730592, -103159218, -109583959, -116004575, -122420823, -128832462, -135239250, -141640947, -148037311, -154428102, -160813079, -167192001, -173564628, -179930721, -186290039, -192642344, -198987396, -205324956, -211654785, -217976646, -224290301, -230595511, -236892039, -243179648, -249458102, -255727164, -261986597, -268236167, -274475639, -280704776, -286923345, -293131111, -299327842, -305513302, -311687261, -317849484, -323999741, -330137799, -336263427, -342376396, -348476474, -354563433, -360637042, -366697073, -372743299, -378775491, -384793422, -390796866, -396785597, -402759389, -408718017, -414661257, -420588886, -426500679, -432396415, -438275872, -444138828, -449985062, -455814354, -461626486, -467421237, -473198390, -478957728, -484699033, -490422090, -496126682, -501812595, -507479616, -513127530, -518756125, -524365189, -529954512, -535523882, -541073089, -546601926, -552110183, -557597654, -563064131, -568509409, -573933284, -579335550, -584716004, -590074444, -595410668, -600724476, -606015666, -611284040, -616529400, -621751548, -626950287, -632125422, -637276757, -642404100, -647507256, -652586034, -657640243, -662669692, -667674191, -672653554, -677607591, -682536116, -687438945, -692315891, -697166773, -701991406, -706789610, -711561204, -716306008, -721023843, -725714532, -730377899, -735013767, -739621962, -744202311, -748754641, -753278781, -757774561, -762241810, -766680362, -771090049, -775470704, -779822164, -784144263, -788436840, -792699733, -796932781, -801135825, -805308707, -809451269, -813563356, -817644813, -821695486, -825715222, -829703871, -833661282, -837587306, -841481795, -845344603, -849175584, -852974595, -856741491, -860476131, -864178375, -867848084, -871485118, -875089341, -878660618, -882198813, -885703795, -889175430, -892613588, -896018139, -899388957, -902725912, -906028881, -909297738, -912532360, -915732626, -918898416, -922029609, -925126088, -928187737, -931214441, -934206084, -937162555, -940083743, -942969537, -945819828, -948634511, -951413477, -954156623, -956863846, -959535044, -962170115, -964768962, -967331485, -969857589, -972347178, -974800159, -977216440, -979595928, -981938536, -984244174, -986512755, -988744196, -990938410, -993095316, -995214833, -997296880, -999341380, -1001348255, -1003317430, -1005248831, -1007142384, -1008998020, -1010815667, -1012595257, -1014336724, -1016040001, -1017705025, -1019331734, -1020920064, -1022469958, -1023981357, -1025454203, -1026888441, -1028284018, -1029640880, -1030958977, -1032238259, -1033478677, -1034680186, -1035842740, -1036966295, -1038050808, -1039096240, -1040102550, -1041069701, -1041997656, -1042886380, -1043735841, -1044546005, -1045316843, -1046048325, -1046740424, -1047393114, -1048006371, -1048580170, -1049114491, -1049609313, -1050064618, -1050480389, -1050856610, -1051193266, -1051490346, -1051747838, -1051965732, -1052144020, -1052282696, -1052381753, -1052441189, -1052461002, -1052441189, -1052381753, -1052282696, -1052144020, -1051965732, -1051747838, -1051490346, -1051193266, -1050856610, -1050480389, -1050064618, -1049609313, -1049114491, -1048580170, -1048006371, -1047393114, -1046740424, -1046048325, -1045316843, -1044546005, -1043735841, -1042886380, -1041997656, -1041069701, -1040102550, -1039096240, -1038050808, -1036966295, -1035842740, -1034680186, -1033478677, -1032238259, -1030958977, -1029640880, -1028284018, -1026888441, -1025454203, -1023981357, -1022469958, -1020920064, -1019331734, -1017705025, -1016040001, -1014336724, -1012595257, -1010815667, -1008998020, -1007142384, -1005248831, -1003317430, -1001348255, -999341380, -997296880, -995214833, -993095316, -990938410, -988744196, -986512755, -984244174, -981938536, -979595928, -977216440, -974800159, -972347178, -969857589, -967331485, -964768962, -962170115, -959535044, -956863846, -954156623, -951413477, -948634511, -945819828, -942969537, -940083743, -937162555, -934206084, -931214441, -928187737, -925126088, -922029609, -918898416, -915732626, -912532360, -909297738, -906028881, -902725912, -899388957, -896018139, -892613588, -889175430, -885703795, -882198813, -878660618, -875089341, -871485118, -867848084, -864178375, -860476131, -856741491, -852974595, -849175584, -845344603, -841481795, -837587306, -833661282, -829703871, -825715222, -821695486, -817644813, -813563356, -809451269, -805308707, -801135825, -796932781, -792699733, -788436840, -784144263, -779822164, -775470704, -771090049, -766680362, -762241810, -757774561, -753278781, -748754641, -744202311, -739621962, -735013767, -730377899, -725714532, -721023843, -716306008, -711561204, -706789610, -701991406, -697166773, -692315891, -687438945, -682536116, -677607591, -672653554, -667674191, -662669692, -657640243, -652586034, -647507256, -642404100, -637276757, -632125422, -626950287, -621751548, -616529400, -611284040, -606015666, -600724476, -595410668, -590074444, -584716004, -579335550, -573933284, -568509409, -563064131, -557597654, -552110183, -546601926, -541073089, -535523882, -529954512, -524365189, -518756125, -513127530, -507479616, -501812595, -496126682, -490422090, -484699033, -478957728, -473198390, -467421237, -461626486, -455814354, -449985062, -444138828, -438275872, -432396415, -426500679, -420588886, -414661257, -408718017, -402759389, -396785597, -390796866, -384793422, -378775491, -372743299, -366697073, -360637042, -354563433, -348476474, -342376396, -336263427, -330137799, -323999741, -317849484, -311687261, -305513302, -299327842, -293131111, -286923345, -280704776, -274475639, -268236167, -261986597, -255727164, -249458102, -243179648, -236892039, -230595511, -224290301, -217976646, -211654785, -205324956, -198987396, -192642344, -186290039, -179930721, -173564628, -167192001, -160813079, -154428102, -148037311, -141640947, -135239250, -128832462, -122420823, -116004575, -109583959, -103159218, -96730592, -90298325, -83862658, -77423834, -70982095, -64537684, -58090842, -51641814, -45190841, -38738167, -32284034, -25828686, -19372365, -12915315, -6457779, 0, 6457779, 12915315, 19372365, 25828686, 32284034, 38738167, 45190841, 51641814, 58090842, 64537684, 70982095, 77423834, 83862658, 90298325, 96730592, 103159218, 109583959, 116004575, 122420823, 128832462, 135239250, 141640947, 148037311, 154428102, 160813079, 167192001, 173564628, 179930721, 186290039, 192642344, 198987396, 205324956, 211654785, 217976646, 224290301, 230595511, 236892039, 243179648, 249458102, 255727164, 261986597, 268236167, 274475639, 280704776, 286923345, 293131111, 299327842, 305513302, 311687261, 317849484, 323999741, 330137799, 336263427, 342376396, 348476474, 354563433, 360637042, 366697073, 372743299, 378775491, 384793422, 390796866, 396785597, 402759389, 408718017, 414661257, 420588886, 426500679, 432396415, 438275872, 444138828, 449985062, 455814354, 461626486, 467421237, 473198390, 478957728, 484699033, 490422090, 496126682, 501812595, 507479616, 513127530, 518756125, 524365189, 529954512, 535523882, 541073089, 546601926, 552110183, 557597654, 563064131, 568509409, 573933284, 579335550, 584716004, 590074444, 595410668, 600724476, 606015666, 611284040, 616529400, 621751548, 626950287, 632125422, 637276757, 642404100, 647507256, 652586034, 657640243, 662669692, 667674191, 672653554, 677607591, 682536116, 687438945, 692315891, 697166773, 701991406, 706789610, 711561204, 716306008, 721023843, 725714532, 730377899, 735013767, 739621962, ], ];
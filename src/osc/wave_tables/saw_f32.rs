@@ -0,0 +1,2 @@
+#[allow(clippy::approx_constant)]
+pub static SAW_F32: [[f32; 1024]; 10] = [[0.0, 1.0, 0.76372004, 0.90085113, 0.8004321, 0.8754654, 0.8111083, 0.86247516, 0.8148746, 0.85374194, 0.8158286, 0.84696525, 0.8153659, 0.84124774, 0.81409043, 0.8361676, 0.81230575, 0.83150065, 0.8101812, 0.8271168, 0.80781835, 0.8229353, 0.8052822, 0.81890345, 0.80261594, 0.81498545, 0.7998495, 0.811156, 0.79700446, 0.8073967, 0.7940964, 0.8036941, 0.7911372, 0.8000379, 0.7881358, 0.7964201, 0.7850993, 0.7928345, 0.78203326, 0.7892762, 0.77894205, 0.7857411, 0.77582926, 0.782226, 0.77269787, 0.7787283, 0.76955026, 0.7752457, 0.76638854, 0.7717764, 0.7632143, 0.76831883, 0.7600291, 0.76487166, 0.756834, 0.7614337, 0.75363016, 0.75800407, 0.7504185, 0.75458187, 0.7471998, 0.75116634, 0.7439747, 0.74775696, 0.74074376, 0.74435306, 0.73750764, 0.74095416, 0.7342667, 0.73755985, 0.73102134, 0.7341697, 0.727772, 0.73078346, 0.72451895, 0.7274007, 0.7212625, 0.72402126, 0.718003, 0.72064483, 0.71474046, 0.7172712, 0.7114753, 0.71390015, 0.70820767, 0.71053153, 0.70493764, 0.7071651, 0.7016655, 0.7038008, 0.6983913, 0.70043844, 0.69511527, 0.69707793, 0.69183743, 0.6937191, 0.6885579, 0.6903619, 0.68527687, 0.6870062, 0.6819944, 0.6836519, 0.67871046, 0.680299, 0.6754253, 0.6769473, 0.67213887, 0.6735968, 0.6688513, 0.67024744, 0.6655627, 0.6668991, 0.662273, 0.6635518, 0.6589823, 0.6602055, 0.65569067, 0.65686, 0.6523982, 0.6535154, 0.6491049, 0.65017164, 0.6458108, 0.64682865, 0.6425159, 0.64348644, 0.6392203, 0.6401449, 0.63592404, 0.636804, 0.63262707, 0.63346374, 0.6293295, 0.63012415, 0.62603134, 0.6267851, 0.62273264, 0.6234466, 0.61943334, 0.62010866, 0.6161336, 0.6167712, 0.61283326, 0.61343426, 0.6095325, 0.6100978, 0.6062312, 0.60676175, 0.60292953, 0.60342616, 0.59962744, 0.600091, 0.5963249, 0.5967562, 0.593022, 0.5934218, 0.5897187, 0.59008783, 0.58641505, 0.58675414, 0.5831111, 0.5834208, 0.57980675, 0.5800878, 0.5765021, 0.5767551, 0.5731971, 0.57342273, 0.56989187, 0.57009065, 0.5665863, 0.5667589, 0.5632805, 0.5634273, 0.55997443, 0.5600961, 0.55666804, 0.556765, 0.5533615, 0.5534343, 0.5500546, 0.5501038, 0.5467475, 0.5467735, 0.5434402, 0.5434434, 0.54013264, 0.54011357, 0.53682494, 0.53678393, 0.533517, 0.5334545, 0.5302088, 0.5301252, 0.5269005, 0.52679616, 0.52359194, 0.5234673, 0.5202832, 0.5201386, 0.5169744, 0.51681006, 0.5136653, 0.51348174, 0.51035607, 0.51015353, 0.5070467, 0.5068255, 0.50373715, 0.5034976, 0.5004275, 0.5001699, 0.49711764, 0.4968423, 0.49380767, 0.49351487, 0.49049756, 0.49018756, 0.4871873, 0.48686036, 0.4838769, 0.48353332, 0.4805664, 0.4802064, 0.4772558, 0.4768796, 0.47394502, 0.47355294, 0.47063416, 0.47022638, 0.46732318, 0.4668999, 0.4640121, 0.46357358, 0.46070087, 0.46024734, 0.45738956, 0.4569212, 0.45407817, 0.45359516, 0.45076665, 0.45026925, 0.44745505, 0.4469434, 0.44414335, 0.44361767, 0.44083154, 0.440292, 0.43751967, 0.43696645, 0.43420768, 0.433641, 0.43089563, 0.43031558, 0.4275835, 0.42699027, 0.42427126, 0.42366505, 0.42095897, 0.4203399, 0.4176466, 0.41701484, 0.41433412, 0.41368982, 0.4110216, 0.4103649, 0.407709, 0.40704006, 0.40439633, 0.40371525, 0.4010836, 0.40039057, 0.39777076, 0.3970659, 0.39445788, 0.3937413, 0.39114496, 0.3904168, 0.38783196, 0.38709235, 0.3845189, 0.38376793, 0.38120577, 0.3804436, 0.37789258, 0.37711933, 0.37457934, 0.3737951, 0.37126604, 0.3704709, 0.3679527, 0.36714682, 0.3646393, 0.36382276, 0.36132583, 0.36049873, 0.35801235, 0.35717478, 0.35469878, 0.35385087, 0.35138518, 0.350527, 0.34807152, 0.3472032, 0.34475783, 0.3438794, 0.34144408, 0.3405557, 0.3381303, 0.33723202, 0.33481646, 0.33390838, 0.3315026, 0.3305848, 0.32818866, 0.32726124, 0.3248747, 0.3239377, 0.3215607, 0.32061425, 0.31824666, 0.3172908, 0.31493258, 0.3139674, 0.31161848, 0.31064406, 0.3083043, 0.30732074, 0.30499014, 0.30399746, 0.30167592, 0.3006742, 0.29836166, 0.29735097, 0.29504737, 0.2940278, 0.29173303, 0.29070464, 0.28841868, 0.28738153, 0.2851043, 0.28405842, 0.28178987, 0.28073537, 0.27847543, 0.27741235, 0.27516094, 0.27408937, 0.27184644, 0.27076638, 0.2685319, 0.26744345, 0.26521733, 0.26412052, 0.26190275, 0.26079765, 0.25858814, 0.25747478, 0.2552735, 0.25415197, 0.25195882, 0.25082916, 0.24864411, 0.24750637, 0.2453294, 0.2441836, 0.24201465, 0.24086086, 0.23869988, 0.23753816, 0.23538509, 0.23421547, 0.23207027, 0.2308928, 0.22875544, 0.22757016, 0.22544058, 0.22424753, 0.2221257, 0.22092493, 0.2188108, 0.21760236, 0.21549588, 0.21427979, 0.21218094, 0.21095724, 0.20886599, 0.20763472, 0.20555101, 0.20431222, 0.20223601, 0.20098972, 0.198921, 0.19766726, 0.19560596, 0.1943448, 0.19229092, 0.19102238, 0.18897586, 0.18769996, 0.18566078, 0.18437755, 0.18234567, 0.18105517, 0.17903057, 0.1777328, 0.17571543, 0.17441045, 0.17240028, 0.1710881, 0.16908513, 0.16776578, 0.16576996, 0.16444346, 0.16245477, 0.16112116, 0.15913957, 0.15779889, 0.15582436, 0.15447661, 0.15250914, 0.15115435, 0.1491939, 0.14783211, 0.14587864, 0.14450987, 0.14256339, 0.14118764, 0.1392481, 0.13786544, 0.13593282, 0.13454324, 0.13261752, 0.13122104, 0.12930222, 0.12789886, 0.1259869, 0.124576695, 0.122671574, 0.12125453, 0.11935624, 0.11793239, 0.11604089, 0.11461024, 0.11272553, 0.11128811, 0.109410174, 0.10796599, 0.1060948, 0.104643874, 0.10277942, 0.101321764, 0.09946403, 0.09799967, 0.09614863, 0.094677575, 0.09283323, 0.09135549, 0.08951782, 0.088033415, 0.086202405, 0.08471134, 0.08288698, 0.08138928, 0.079571545, 0.07806722, 0.07625611, 0.07474517, 0.07294067, 0.07142312, 0.06962522, 0.06810108, 0.06630977, 0.06477904, 0.06299431, 0.06145701, 0.05967885, 0.058134988, 0.05636338, 0.054812964, 0.05304791, 0.051490948, 0.049732435, 0.04816893, 0.046416957, 0.044846922, 0.043101475, 0.041524913, 0.03978599, 0.038202908, 0.0364705, 0.034880906, 0.03315501, 0.03155891, 0.029839514, 0.028236913, 0.026524018, 0.024914918, 0.02320852, 0.021592924, 0.01989302, 0.018270934, 0.01657752, 0.014948944, 0.013262017, 0.011626955, 0.009946513, 0.008304968, 0.0066310093, 0.0049829804, 0.0033155049, 0.0016609933, -1.2847379e-16, -0.0016609933, -0.0033155049, -0.0049829804, -0.0066310093, -0.008304968, -0.009946513, -0.011626955, -0.013262017, -0.014948944, -0.01657752, -0.018270934, -0.01989302, -0.021592924, -0.02320852, -0.024914918, -0.026524018, -0.028236913, -0.029839514, -0.03155891, -0.03315501, -0.034880906, -0.0364705, -0.038202908, -0.03978599, -0.041524913, -0.043101475, -0.044846922, -0.046416957, -0.04816893, -0.049732435, -0.051490948, -0.05304791, -0.054812964, -0.05636338, -0.058134988, -0.05967885, -0.06145701, -0.06299431, -0.06477904, -0.06630977, -0.06810108, -0.06962522, -0.07142312, -0.07294067, -0.07474517, -0.07625611, -0.07806722, -0.079571545, -0.08138928, -0.08288698, -0.08471134, -0.086202405, -0.088033415, -0.08951782, -0.09135549, -0.09283323, -0.094677575, -0.09614863, -0.09799967, -0.09946403, -0.101321764, -0.10277942, -0.104643874, -0.1060948, -0.10796599, -0.109410174, -0.11128811, -0.11272553, -0.11461024, -0.11604089, -0.11793239, -0.11935624, -0.12125453, -0.122671574, -0.124576695, -0.1259869, -0.12789886, -0.12930222, -0.13122104, -0.13261752, -0.13454324, -0.13593282, -0.13786544, -0.1392481, -0.14118764, -0.14256339, -0.14450987, -0.14587864, -0.14783211, -0.1491939, -0.15115435, -0.15250914, -0.15447661, -0.15582436, -0.15779889, -0.15913957, -0.16112116, -0.16245477, -0.16444346, -0.16576996, -0.16776578, -0.16908513, -0.1710881, -0.17240028, -0.17441045, -0.17571543, -0.1777328, -0.17903057, -0.18105517, -0.18234567, -0.18437755, -0.18566078, -0.18769996, -0.18897586, -0.19102238, -0.19229092, -0.1943448, -0.19560596, -0.19766726, -0.198921, -0.20098972, -0.20223601, -0.20431222, -0.20555101, -0.20763472, -0.20886599, -0.21095724, -0.21218094, -0.21427979, -0.21549588, -0.21760236, -0.2188108, -0.22092493, -0.2221257, -0.22424753, -0.22544058, -0.22757016, -0.22875544, -0.2308928, -0.23207027, -0.23421547, -0.23538509, -0.23753816, -0.23869988, -0.24086086, -0.24201465, -0.2441836, -0.2453294, -0.24750637, -0.24864411, -0.25082916, -0.25195882, -0.25415197, -0.2552735, -0.25747478, -0.25858814, -0.26079765, -0.26190275, -0.26412052, -0.26521733, -0.26744345, -0.2685319, -0.27076638, -0.27184644, -0.27408937, -0.27516094, -0.27741235, -0.27847543, -0.28073537, -0.28178987, -0.28405842, -0.2851043, -0.28738153, -0.28841868, -0.29070464, -0.29173303, -0.2940278, -0.29504737, -0.29735097, -0.29836166, -0.3006742, -0.30167592, -0.30399746, -0.30499014, -0.30732074, -0.3083043, -0.31064406, -0.31161848, -0.3139674, -0.31493258, -0.3172908, -0.31824666, -0.32061425, -0.3215607, -0.3239377, -0.3248747, -0.32726124, -0.32818866, -0.3305848, -0.3315026, -0.33390838, -0.33481646, -0.33723202, -0.3381303, -0.3405557, -0.34144408, -0.3438794, -0.34475783, -0.3472032, -0.34807152, -0.350527, -0.35138518, -0.35385087, -0.35469878, -0.35717478, -0.35801235, -0.36049873, -0.36132583, -0.36382276, -0.3646393, -0.36714682, -0.3679527, -0.3704709, -0.37126604, -0.3737951, -0.37457934, -0.37711933, -0.37789258, -0.3804436, -0.38120577, -0.38376793, -0.3845189, -0.38709235, -0.38783196, -0.3904168, -0.39114496, -0.3937413, -0.39445788, -0.3970659, -0.39777076, -0.40039057, -0.4010836, -0.40371525, -0.40439633, -0.40704006, -0.407709, -0.4103649, -0.4110216, -0.41368982, -0.41433412, -0.41701484, -0.4176466, -0.4203399, -0.42095897, -0.42366505, -0.42427126, -0.42699027, -0.4275835, -0.43031558, -0.43089563, -0.433641, -0.43420768, -0.43696645, -0.43751967, -0.440292, -0.44083154, -0.44361767, -0.44414335, -0.4469434, -0.44745505, -0.45026925, -0.45076665, -0.45359516, -0.45407817, -0.4569212, -0.45738956, -0.46024734, -0.46070087, -0.46357358, -0.4640121, -0.4668999, -0.46732318, -0.47022638, -0.47063416, -0.47355294, -0.47394502, -0.4768796, -0.4772558, -0.4802064, -0.4805664, -0.48353332, -0.4838769, -0.48686036, -0.4871873, -0.49018756, -0.49049756, -0.49351487, -0.49380767, -0.4968423, -0.49711764, -0.5001699, -0.5004275, -0.5034976, -0.50373715, -0.5068255, -0.5070467, -0.51015353, -0.51035607, -0.51348174, -0.5136653, -0.51681006, -0.5169744, -0.5201386, -0.5202832, -0.5234673, -0.52359194, -0.52679616, -0.5269005, -0.5301252, -0.5302088, -0.5334545, -0.533517, -0.53678393, -0.53682494, -0.54011357, -0.54013264, -0.5434434, -0.5434402, -0.5467735, -0.5467475, -0.5501038, -0.5500546, -0.5534343, -0.5533615, -0.556765, -0.55666804, -0.5600961, -0.55997443, -0.5634273, -0.5632805, -0.5667589, -0.5665863, -0.57009065, -0.56989187, -0.57342273, -0.5731971, -0.5767551, -0.5765021, -0.5800878, -0.57980675, -0.5834208, -0.5831111, -0.58675414, -0.58641505, -0.59008783, -0.5897187, -0.5934218, -0.593022, -0.5967562, -0.5963249, -0.600091, -0.59962744, -0.60342616, -0.60292953, -0.60676175, -0.6062312, -0.6100978, -0.6095325, -0.61343426, -0.61283326, -0.6167712, -0.6161336, -0.62010866, -0.61943334, -0.6234466, -0.62273264, -0.6267851, -0.62603134, -0.63012415, -0.6293295, -0.63346374, -0.63262707, -0.636804, -0.63592404, -0.6401449, -0.6392203, -0.64348644, -0.6425159, -0.64682865, -0.6458108, -0.65017164, -0.6491049, -0.6535154, -0.6523982, -0.65686, -0.65569067, -0.6602055, -0.6589823, -0.6635518, -0.662273, -0.6668991, -0.6655627, -0.67024744, -0.6688513, -0.6735968, -0.67213887, -0.6769473, -0.6754253, -0.680299, -0.67871046, -0.6836519, -0.6819944, -0.6870062, -0.68527687, -0.6903619, -0.6885579, -0.6937191, -0.69183743, -0.69707793, -0.69511527, -0.70043844, -0.6983913, -0.7038008, -0.7016655, -0.7071651, -0.70493764, -0.71053153, -0.70820767, -0.71390015, -0.7114753, -0.7172712, -0.71474046, -0.72064483, -0.718003, -0.72402126, -0.7212625, -0.7274007, -0.72451895, -0.73078346, -0.727772, -0.7341697, -0.73102134, -0.73755985, -0.7342667, -0.74095416, -0.73750764, -0.74435306, -0.74074376, -0.74775696, -0.7439747, -0.75116634, -0.7471998, -0.75458187, -0.7504185, -0.75800407, -0.75363016, -0.7614337, -0.756834, -0.76487166, -0.7600291, -0.76831883, -0.7632143, -0.7717764, -0.76638854, -0.7752457, -0.76955026, -0.7787283, -0.77269787, -0.782226, -0.77582926, -0.7857411, -0.77894205, -0.7892762, -0.78203326, -0.7928345, -0.7850993, -0.7964201, -0.7881358, -0.8000379, -0.7911372, -0.8036941, -0.7940964, -0.8073967, -0.79700446, -0.811156, -0.7998495, -0.81498545, -0.80261594, -0.81890345, -0.8052822, -0.8229353, -0.80781835, -0.8271168, -0.8101812, -0.83150065, -0.81230575, -0.8361676, -0.81409043, -0.84124774, -0.8153659, -0.84696525, -0.8158286, -0.85374194, -0.8148746, -0.86247516, -0.8111083, -0.8754654, -0.8004321, -0.90085113, -0.76372004, -1.0, ], [0.0, 0.74080247, 0.998339, 0.8638891, 0.7604045, 0.83426344, 0.8958682, 0.84120744, 0.79380107, 0.8330884, 0.86716044, 0.8320675, 0.80116177, 0.82780355, 0.8508482, 0.8246167, 0.80161256, 0.821693, 0.838793, 0.81761837, 0.7992511, 0.8153134, 0.82869434, 0.8107901, 0.79547286, 0.8088209, 0.8196548, 0.80403984, 0.7908819, 0.8022729, 0.81125265, 0.7973302, 0.78578174, 0.7956945, 0.8032637, 0.79064375, 0.7803417, 0.78909796, 0.7955579, 0.78397155, 0.7746634, 0.78249013, 0.7880544, 0.7773086, 0.7688117, 0.77587473, 0.78070056, 0.7706518, 0.76282996, 0.76925415, 0.7734605, 0.7639994, 0.7567481, 0.76262987, 0.766309, 0.7573501, 0.7505875, 0.75600296, 0.7592278, 0.75070316, 0.74436396, 0.749374, 0.75220317, 0.74405795, 0.73808926, 0.7427435, 0.74522495, 0.73741406, 0.7317725, 0.7361119, 0.7382851, 0.7307712, 0.7254205, 0.72947925, 0.73137754, 0.7241292, 0.71903896, 0.7228459, 0.72449714, 0.7174879, 0.7126323, 0.71621203, 0.71764, 0.7108471, 0.70620406, 0.70957756, 0.7108029, 0.70420676, 0.6997572, 0.7029427, 0.7039831, 0.69756675, 0.69329417, 0.6963076, 0.6971785, 0.6909271, 0.686817, 0.6896722, 0.6903871, 0.68428767, 0.68032736, 0.68303645, 0.68360746, 0.6776485, 0.67382663, 0.6764006, 0.6768382, 0.6710095, 0.66731614, 0.6697645, 0.6700782, 0.66437066, 0.66079694, 0.6631283, 0.6633265, 0.65773195, 0.6542699, 0.65649194, 0.6565822, 0.65109336, 0.6477357, 0.6498555, 0.6498446, 0.64445484, 0.64119524, 0.64321893, 0.6431131, 0.6378165, 0.634649, 0.6365823, 0.63638705, 0.63117814, 0.6280975, 0.62994564, 0.62966603, 0.6245399, 0.62154114, 0.62330884, 0.6229496, 0.61790174, 0.61498046, 0.616672, 0.61623734, 0.61126363, 0.6084158, 0.6100351, 0.60952896, 0.6046256, 0.6018474, 0.60339814, 0.60282403, 0.59798753, 0.59527564, 0.59676117, 0.59612244, 0.5913496, 0.5887007, 0.59012413, 0.5894238, 0.5847116, 0.582123, 0.5834871, 0.58272797, 0.57807374, 0.5755425, 0.57685, 0.5760347, 0.57143587, 0.56895953, 0.5702129, 0.56934386, 0.564798, 0.5623743, 0.56357574, 0.5626552, 0.5581602, 0.55578685, 0.5569386, 0.5559687, 0.5515224, 0.54919744, 0.5503014, 0.5492841, 0.54488456, 0.5426061, 0.54366416, 0.5426013, 0.5382468, 0.53601307, 0.53702694, 0.5359202, 0.53160906, 0.52941835, 0.5303897, 0.5292407, 0.5249713, 0.52282214, 0.52375245, 0.52256274, 0.5183336, 0.51622444, 0.5171152, 0.5158861, 0.5116959, 0.5096254, 0.5104779, 0.5092109, 0.5050582, 0.503025, 0.5038406, 0.50253683, 0.4984205, 0.49642345, 0.4972033, 0.495864, 0.49178284, 0.48982075, 0.490566, 0.48919225, 0.48514518, 0.483217, 0.48392865, 0.48252156, 0.47850755, 0.4766122, 0.47729132, 0.47585183, 0.4718699, 0.47000644, 0.47065398, 0.4691831, 0.46523225, 0.46339977, 0.46401662, 0.4625152, 0.45859462, 0.45679224, 0.45737925, 0.45584816, 0.45195702, 0.4501839, 0.4507419, 0.44918194, 0.4453194, 0.44357476, 0.44410452, 0.44251645, 0.43868178, 0.4369649, 0.43746716, 0.4358517, 0.43204418, 0.43035433, 0.43082976, 0.42918763, 0.4254066, 0.4237431, 0.42419237, 0.4225242, 0.418769, 0.41713122, 0.41755497, 0.4158614, 0.41213143, 0.41051874, 0.41091758, 0.4091992, 0.40549383, 0.40390566, 0.4042802, 0.40253755, 0.39885625, 0.39729205, 0.39764276, 0.39587644, 0.39221868, 0.3906779, 0.39100537, 0.38921586, 0.3855811, 0.38406327, 0.38436794, 0.38255575, 0.37894353, 0.37744814, 0.37773052, 0.37589613, 0.372306, 0.3708326, 0.3710931, 0.36923695, 0.36566842, 0.36421657, 0.36445567, 0.36257818, 0.35903087, 0.35760015, 0.35781825, 0.3559198, 0.3523933, 0.35098332, 0.35118082, 0.34926185, 0.34575576, 0.3443661, 0.3445434, 0.34260428, 0.3391182, 0.33774853, 0.33790594, 0.33594704, 0.33248067, 0.3311306, 0.33126852, 0.32929015, 0.32584313, 0.32451233, 0.3246311, 0.3226336, 0.31920558, 0.31789374, 0.31799364, 0.31597736, 0.31256804, 0.31127486, 0.3113562, 0.3093214, 0.3059305, 0.30465567, 0.30471876, 0.30266577, 0.29929295, 0.2980362, 0.2980813, 0.29601038, 0.29265544, 0.29141647, 0.29144385, 0.28935528, 0.2860179, 0.28479645, 0.2848064, 0.28270042, 0.27938035, 0.27817622, 0.27816895, 0.2760458, 0.27274284, 0.27155572, 0.2715315, 0.26939142, 0.2661053, 0.26493502, 0.26489404, 0.26273724, 0.25946778, 0.25831407, 0.25825658, 0.2560833, 0.25283027, 0.25169295, 0.25161913, 0.24942957, 0.24619274, 0.2450716, 0.24498166, 0.24277602, 0.23955521, 0.23845007, 0.23834419, 0.23612267, 0.2329177, 0.23182835, 0.23170674, 0.2294695, 0.22628017, 0.22520645, 0.22506927, 0.2228165, 0.21964265, 0.21858439, 0.2184318, 0.21616365, 0.21300514, 0.21196216, 0.21179433, 0.20951098, 0.20636763, 0.20533979, 0.20515686, 0.20285845, 0.1997301, 0.19871725, 0.1985194, 0.19620606, 0.19309258, 0.1920946, 0.19188192, 0.18955381, 0.18645509, 0.18547179, 0.18524446, 0.1829017, 0.17981757, 0.17884885, 0.17860699, 0.17624971, 0.17318006, 0.1722258, 0.17196952, 0.16959783, 0.16654254, 0.16560262, 0.16533203, 0.16294609, 0.15990503, 0.15897934, 0.15869457, 0.15629444, 0.15326753, 0.15235595, 0.1520571, 0.14964288, 0.14663002, 0.14573248, 0.14541961, 0.14299144, 0.13999252, 0.1391089, 0.13878213, 0.13634008, 0.133355, 0.13248523, 0.13214466, 0.12968881, 0.12671751, 0.12586147, 0.12550718, 0.12303763, 0.12008, 0.11923763, 0.1188697, 0.11638652, 0.113442495, 0.112613715, 0.11223222, 0.10973548, 0.106805, 0.10598974, 0.10559474, 0.10308452, 0.10016749, 0.09936569, 0.098957255, 0.09643361, 0.09352999, 0.09274158, 0.09231977, 0.08978277, 0.08689249, 0.08611742, 0.085682295, 0.08313198, 0.080254994, 0.0794932, 0.07904481, 0.07648123, 0.073617496, 0.072868936, 0.07240733, 0.06983054, 0.06698, 0.066244625, 0.06576984, 0.06317989, 0.060342498, 0.059620272, 0.059132352, 0.056529272, 0.053705, 0.052995887, 0.05249487, 0.04987869, 0.047067504, 0.04637147, 0.04585738, 0.04322814, 0.04043001, 0.039747026, 0.039219894, 0.036577612, 0.033792514, 0.03312256, 0.032582406, 0.029927108, 0.027155017, 0.02649807, 0.025944915, 0.023276621, 0.020517524, 0.019873565, 0.019307425, 0.016626148, 0.013880031, 0.01324905, 0.012669936, 0.009975685, 0.0072425385, 0.0066245273, 0.006032445, 0.0033252276, 0.0006050466, -3.8351296e-16, -0.0006050466, -0.0033252276, -0.006032445, -0.0066245273, -0.0072425385, -0.009975685, -0.012669936, -0.01324905, -0.013880031, -0.016626148, -0.019307425, -0.019873565, -0.020517524, -0.023276621, -0.025944915, -0.02649807, -0.027155017, -0.029927108, -0.032582406, -0.03312256, -0.033792514, -0.036577612, -0.039219894, -0.039747026, -0.04043001, -0.04322814, -0.04585738, -0.04637147, -0.047067504, -0.04987869, -0.05249487, -0.052995887, -0.053705, -0.056529272, -0.059132352, -0.059620272, -0.060342498, -0.06317989, -0.06576984, -0.066244625, -0.06698, -0.06983054, -0.07240733, -0.072868936, -0.073617496, -0.07648123, -0.07904481, -0.0794932, -0.080254994, -0.08313198, -0.085682295, -0.08611742, -0.08689249, -0.08978277, -0.09231977, -0.09274158, -0.09352999, -0.09643361, -0.098957255, -0.09936569, -0.10016749, -0.10308452, -0.10559474, -0.10598974, -0.106805, -0.10973548, -0.11223222, -0.112613715, -0.113442495, -0.11638652, -0.1188697, -0.11923763, -0.12008, -0.12303763, -0.12550718, -0.12586147, -0.12671751, -0.12968881, -0.13214466, -0.13248523, -0.133355, -0.13634008, -0.13878213, -0.1391089, -0.13999252, -0.14299144, -0.14541961, -0.14573248, -0.14663002, -0.14964288, -0.1520571, -0.15235595, -0.15326753, -0.15629444, -0.15869457, -0.15897934, -0.15990503, -0.16294609, -0.16533203, -0.16560262, -0.16654254, -0.16959783, -0.17196952, -0.1722258, -0.17318006, -0.17624971, -0.17860699, -0.17884885, -0.17981757, -0.1829017, -0.18524446, -0.18547179, -0.18645509, -0.18955381, -0.19188192, -0.1920946, -0.19309258, -0.19620606, -0.1985194, -0.19871725, -0.1997301, -0.20285845, -0.20515686, -0.20533979, -0.20636763, -0.20951098, -0.21179433, -0.21196216, -0.21300514, -0.21616365, -0.2184318, -0.21858439, -0.21964265, -0.2228165, -0.22506927, -0.22520645, -0.22628017, -0.2294695, -0.23170674, -0.23182835, -0.2329177, -0.23612267, -0.23834419, -0.23845007, -0.23955521, -0.24277602, -0.24498166, -0.2450716, -0.24619274, -0.24942957, -0.25161913, -0.25169295, -0.25283027, -0.2560833, -0.25825658, -0.25831407, -0.25946778, -0.26273724, -0.26489404, -0.26493502, -0.2661053, -0.26939142, -0.2715315, -0.27155572, -0.27274284, -0.2760458, -0.27816895, -0.27817622, -0.27938035, -0.28270042, -0.2848064, -0.28479645, -0.2860179, -0.28935528, -0.29144385, -0.29141647, -0.29265544, -0.29601038, -0.2980813, -0.2980362, -0.29929295, -0.30266577, -0.30471876, -0.30465567, -0.3059305, -0.3093214, -0.3113562, -0.31127486, -0.31256804, -0.31597736, -0.31799364, -0.31789374, -0.31920558, -0.3226336, -0.3246311, -0.32451233, -0.32584313, -0.32929015, -0.33126852, -0.3311306, -0.33248067, -0.33594704, -0.33790594, -0.33774853, -0.3391182, -0.34260428, -0.3445434, -0.3443661, -0.34575576, -0.34926185, -0.35118082, -0.35098332, -0.3523933, -0.3559198, -0.35781825, -0.35760015, -0.35903087, -0.36257818, -0.36445567, -0.36421657, -0.36566842, -0.36923695, -0.3710931, -0.3708326, -0.372306, -0.37589613, -0.37773052, -0.37744814, -0.37894353, -0.38255575, -0.38436794, -0.38406327, -0.3855811, -0.38921586, -0.39100537, -0.3906779, -0.39221868, -0.39587644, -0.39764276, -0.39729205, -0.39885625, -0.40253755, -0.4042802, -0.40390566, -0.40549383, -0.4091992, -0.41091758, -0.41051874, -0.41213143, -0.4158614, -0.41755497, -0.41713122, -0.418769, -0.4225242, -0.42419237, -0.4237431, -0.4254066, -0.42918763, -0.43082976, -0.43035433, -0.43204418, -0.4358517, -0.43746716, -0.4369649, -0.43868178, -0.44251645, -0.44410452, -0.44357476, -0.4453194, -0.44918194, -0.4507419, -0.4501839, -0.45195702, -0.45584816, -0.45737925, -0.45679224, -0.45859462, -0.4625152, -0.46401662, -0.46339977, -0.46523225, -0.4691831, -0.47065398, -0.47000644, -0.4718699, -0.47585183, -0.47729132, -0.4766122, -0.47850755, -0.48252156, -0.48392865, -0.483217, -0.48514518, -0.48919225, -0.490566, -0.48982075, -0.49178284, -0.495864, -0.4972033, -0.49642345, -0.4984205, -0.50253683, -0.5038406, -0.503025, -0.5050582, -0.5092109, -0.5104779, -0.5096254, -0.5116959, -0.5158861, -0.5171152, -0.51622444, -0.5183336, -0.52256274, -0.52375245, -0.52282214, -0.5249713, -0.5292407, -0.5303897, -0.52941835, -0.53160906, -0.5359202, -0.53702694, -0.53601307, -0.5382468, -0.5426013, -0.54366416, -0.5426061, -0.54488456, -0.5492841, -0.5503014, -0.54919744, -0.5515224, -0.5559687, -0.5569386, -0.55578685, -0.5581602, -0.5626552, -0.56357574, -0.5623743, -0.564798, -0.56934386, -0.5702129, -0.56895953, -0.57143587, -0.5760347, -0.57685, -0.5755425, -0.57807374, -0.58272797, -0.5834871, -0.582123, -0.5847116, -0.5894238, -0.59012413, -0.5887007, -0.5913496, -0.59612244, -0.59676117, -0.59527564, -0.59798753, -0.60282403, -0.60339814, -0.6018474, -0.6046256, -0.60952896, -0.6100351, -0.6084158, -0.61126363, -0.61623734, -0.616672, -0.61498046, -0.61790174, -0.6229496, -0.62330884, -0.62154114, -0.6245399, -0.62966603, -0.62994564, -0.6280975, -0.63117814, -0.63638705, -0.6365823, -0.634649, -0.6378165, -0.6431131, -0.64321893, -0.64119524, -0.64445484, -0.6498446, -0.6498555, -0.6477357, -0.65109336, -0.6565822, -0.65649194, -0.6542699, -0.65773195, -0.6633265, -0.6631283, -0.66079694, -0.66437066, -0.6700782, -0.6697645, -0.66731614, -0.6710095, -0.6768382, -0.6764006, -0.67382663, -0.6776485, -0.68360746, -0.68303645, -0.68032736, -0.68428767, -0.6903871, -0.6896722, -0.686817, -0.6909271, -0.6971785, -0.6963076, -0.69329417, -0.69756675, -0.7039831, -0.7029427, -0.6997572, -0.70420676, -0.7108029, -0.70957756, -0.70620406, -0.7108471, -0.71764, -0.71621203, -0.7126323, -0.7174879, -0.72449714, -0.7228459, -0.71903896, -0.7241292, -0.73137754, -0.72947925, -0.7254205, -0.7307712, -0.7382851, -0.7361119, -0.7317725, -0.73741406, -0.74522495, -0.7427435, -0.73808926, -0.74405795, -0.75220317, -0.749374, -0.74436396, -0.75070316, -0.7592278, -0.75600296, -0.7505875, -0.7573501, -0.766309, -0.76262987, -0.7567481, -0.7639994, -0.7734605, -0.76925415, -0.76282996, -0.7706518, -0.78070056, -0.77587473, -0.7688117, -0.7773086, -0.7880544, -0.78249013, -0.7746634, -0.78397155, -0.7955579, -0.78909796, -0.7803417, -0.79064375, -0.8032637, -0.7956945, -0.78578174, -0.7973302, -0.81125265, -0.8022729, -0.7908819, -0.80403984, -0.8196548, -0.8088209, -0.79547286, -0.8107901, -0.82869434, -0.8153134, -0.7992511, -0.81761837, -0.838793, -0.821693, -0.80161256, -0.8246167, -0.8508482, -0.82780355, -0.80116177, -0.8320675, -0.86716044, -0.8330884, -0.79380107, -0.84120744, -0.8958682, -0.83426344, -0.7604045, -0.8638891, -0.998339, -0.74080247, ], [0.0, 0.41034257, 0.7401974, 0.9374896, 0.9950138, 0.9484606, 0.8578567, 0.78144777, 0.75378, 0.7769192, 0.8270209, 0.8712589, 0.88589245, 0.8668087, 0.8285375, 0.7938594, 0.780552, 0.79248583, 0.8192084, 0.84326917, 0.85053426, 0.83762956, 0.81276006, 0.79008156, 0.7812882, 0.7892874, 0.807286, 0.82336414, 0.8275716, 0.817398, 0.7986718, 0.7816963, 0.7751145, 0.7811046, 0.79453796, 0.8063339, 0.8088659, 0.8002335, 0.78503597, 0.77139634, 0.76612854, 0.7708983, 0.7815209, 0.7906434, 0.7921167, 0.7844751, 0.77157027, 0.76012206, 0.75572586, 0.75967485, 0.7683909, 0.7756837, 0.7764267, 0.7694763, 0.75818247, 0.7482856, 0.7445104, 0.7478691, 0.7552054, 0.7611658, 0.761374, 0.7549339, 0.7448353, 0.7360956, 0.7327859, 0.73569894, 0.7419895, 0.74693525, 0.7467345, 0.74068695, 0.7315114, 0.7236689, 0.7207214, 0.72328585, 0.7287555, 0.73290193, 0.732378, 0.7266422, 0.7182017, 0.711076, 0.7084187, 0.7107028, 0.71551013, 0.7190099, 0.71822387, 0.7127418, 0.7049013, 0.69836205, 0.69594276, 0.697996, 0.7022572, 0.7052225, 0.70421934, 0.69894814, 0.691607, 0.68555707, 0.68333673, 0.6851965, 0.6889991, 0.69151473, 0.69032854, 0.6852355, 0.6783171, 0.67268205, 0.67063063, 0.6723258, 0.6757374, 0.6778689, 0.67652625, 0.6715859, 0.66503036, 0.6597519, 0.6578459, 0.65939903, 0.66247296, 0.66427237, 0.6627942, 0.6579863, 0.6517459, 0.64677763, 0.6449983, 0.6464275, 0.6492065, 0.6507155, 0.64911866, 0.64442694, 0.6384632, 0.63376755, 0.63209957, 0.6334196, 0.6359385, 0.6371911, 0.63548946, 0.63090056, 0.6251818, 0.62072784, 0.61915874, 0.6203818, 0.62266934, 0.62369365, 0.6218986, 0.6174014, 0.6119015, 0.6076636, 0.6061829, 0.607319, 0.60939926, 0.61021876, 0.6083399, 0.60392517, 0.598622, 0.5945785, 0.5931775, 0.5942353, 0.59612846, 0.596763, 0.59480834, 0.59046817, 0.58534324, 0.58147585, 0.5801471, 0.5811338, 0.582857, 0.58332354, 0.5812999, 0.5770276, 0.57206494, 0.5683581, 0.56709516, 0.568017, 0.5695851, 0.56989807, 0.5678114, 0.56360126, 0.5587871, 0.55522734, 0.55402476, 0.55488706, 0.55631274, 0.5564847, 0.55434024, 0.5501872, 0.5455097, 0.54208523, 0.5409382, 0.54174566, 0.54304004, 0.543082, 0.5408841, 0.53678375, 0.5322325, 0.5289332, 0.52783763, 0.5285943, 0.5297671, 0.5296885, 0.527441, 0.52338964, 0.51895565, 0.5157726, 0.5147247, 0.5154342, 0.5164939, 0.5163032, 0.51400965, 0.5100038, 0.50567895, 0.5026042, 0.50160086, 0.5022663, 0.5032205, 0.5029251, 0.50058854, 0.49662524, 0.4924025, 0.489429, 0.4884673, 0.48909155, 0.48994693, 0.48955345, 0.4871765, 0.48325318, 0.4791262, 0.47624773, 0.47532517, 0.47591066, 0.47667322, 0.47618753, 0.47377267, 0.4698869, 0.46585, 0.46306103, 0.46217528, 0.46272433, 0.46339938, 0.46282676, 0.4603761, 0.4565258, 0.45257393, 0.44986945, 0.44901848, 0.44953308, 0.4501254, 0.44947064, 0.44698614, 0.44316936, 0.439298, 0.4366735, 0.43585545, 0.43633738, 0.43685135, 0.43611866, 0.4336021, 0.42981714, 0.42602214, 0.42347354, 0.42268682, 0.42313772, 0.4235772, 0.42277047, 0.42022339, 0.41646868, 0.41274637, 0.41027004, 0.40951306, 0.40993443, 0.41030297, 0.40942568, 0.40684953, 0.40312368, 0.39947066, 0.39706326, 0.39633468, 0.39672786, 0.39702865, 0.396084, 0.3934801, 0.3897818, 0.38619503, 0.3838535, 0.3831521, 0.3835183, 0.3837543, 0.38274518, 0.38011467, 0.3764428, 0.37291944, 0.37064105, 0.3699657, 0.37030604, 0.37047988, 0.3694089, 0.3667529, 0.36310634, 0.3596439, 0.35742617, 0.3567758, 0.3570913, 0.35720542, 0.356075, 0.35339448, 0.3497723, 0.34636843, 0.344209, 0.3435827, 0.34387428, 0.34393093, 0.34274325, 0.3400391, 0.3364404, 0.333093, 0.33098978, 0.33038667, 0.3306552, 0.33065638, 0.32941347, 0.32668656, 0.3231105, 0.3198176, 0.31776866, 0.31718794, 0.3174342, 0.3173818, 0.31608552, 0.31333655, 0.30978242, 0.30654222, 0.3045458, 0.30398676, 0.30421147, 0.30410716, 0.30275923, 0.29998893, 0.296456, 0.2932669, 0.29132134, 0.29078332, 0.29098713, 0.29083252, 0.28943443, 0.28664345, 0.28313112, 0.2799916, 0.27809545, 0.27757782, 0.2777613, 0.27755785, 0.27611107, 0.27329996, 0.26980767, 0.2667163, 0.2648682, 0.2643704, 0.26453415, 0.26428315, 0.26278898, 0.2599583, 0.2564855, 0.25344107, 0.2516397, 0.25116125, 0.25130573, 0.25100842, 0.24946809, 0.24661833, 0.24316452, 0.24016581, 0.23841009, 0.23795046, 0.2380762, 0.23773369, 0.23614827, 0.2332799, 0.22984463, 0.22689061, 0.22517943, 0.22473821, 0.2248456, 0.22445892, 0.22282945, 0.21994287, 0.21652575, 0.21361542, 0.21194783, 0.21152462, 0.21161406, 0.21118414, 0.20951153, 0.20660712, 0.20320778, 0.20034024, 0.19871534, 0.19830978, 0.19838165, 0.19790934, 0.19619446, 0.19327258, 0.18989064, 0.18706508, 0.18548207, 0.18509382, 0.18514842, 0.18463452, 0.18287814, 0.17993909, 0.17657427, 0.17378993, 0.17224807, 0.17187683, 0.17191446, 0.1713597, 0.1695625, 0.16660659, 0.1632586, 0.16051482, 0.1590134, 0.1586589, 0.15867986, 0.15808485, 0.1562475, 0.15327498, 0.14994355, 0.1472397, 0.14577816, 0.14544015, 0.14544463, 0.14480999, 0.14293306, 0.13994417, 0.13662907, 0.1339646, 0.13254237, 0.13222063, 0.13220888, 0.13153511, 0.12961912, 0.12661408, 0.1233151, 0.120689504, 0.11930611, 0.11900042, 0.11897266, 0.118260235, 0.11630564, 0.11328462, 0.110001594, 0.10741443, 0.10606942, 0.10577962, 0.105735995, 0.10498534, 0.10299255, 0.09995573, 0.09668848, 0.09413937, 0.09283237, 0.092558295, 0.092498966, 0.09171043, 0.08967981, 0.08662733, 0.08337571, 0.08086432, 0.07959499, 0.07933651, 0.07926161, 0.07843551, 0.076367356, 0.07329935, 0.07006324, 0.067589276, 0.066357344, 0.066114336, 0.066023976, 0.06516058, 0.06305516, 0.05997172, 0.056751028, 0.05431424, 0.053119473, 0.052891847, 0.05278612, 0.051885642, 0.04974316, 0.04664438, 0.043439016, 0.04103922, 0.039881423, 0.039669108, 0.03954808, 0.038610693, 0.036431313, 0.033317257, 0.03012716, 0.02776421, 0.026643245, 0.026446175, 0.026309907, 0.025335731, 0.023119578, 0.01999029, 0.016815418, 0.01448921, 0.013404976, 0.013223119, 0.013071644, 0.0120607605, 0.009807906, 0.00666342, 0.0035037445, 0.0012142197, 0.00016666477, -2.7855176e-16, -0.00016666477, -0.0012142197, -0.0035037445, -0.00666342, -0.009807906, -0.0120607605, -0.013071644, -0.013223119, -0.013404976, -0.01448921, -0.016815418, -0.01999029, -0.023119578, -0.025335731, -0.026309907, -0.026446175, -0.026643245, -0.02776421, -0.03012716, -0.033317257, -0.036431313, -0.038610693, -0.03954808, -0.039669108, -0.039881423, -0.04103922, -0.043439016, -0.04664438, -0.04974316, -0.051885642, -0.05278612, -0.052891847, -0.053119473, -0.05431424, -0.056751028, -0.05997172, -0.06305516, -0.06516058, -0.066023976, -0.066114336, -0.066357344, -0.067589276, -0.07006324, -0.07329935, -0.076367356, -0.07843551, -0.07926161, -0.07933651, -0.07959499, -0.08086432, -0.08337571, -0.08662733, -0.08967981, -0.09171043, -0.092498966, -0.092558295, -0.09283237, -0.09413937, -0.09668848, -0.09995573, -0.10299255, -0.10498534, -0.105735995, -0.10577962, -0.10606942, -0.10741443, -0.110001594, -0.11328462, -0.11630564, -0.118260235, -0.11897266, -0.11900042, -0.11930611, -0.120689504, -0.1233151, -0.12661408, -0.12961912, -0.13153511, -0.13220888, -0.13222063, -0.13254237, -0.1339646, -0.13662907, -0.13994417, -0.14293306, -0.14480999, -0.14544463, -0.14544015, -0.14577816, -0.1472397, -0.14994355, -0.15327498, -0.1562475, -0.15808485, -0.15867986, -0.1586589, -0.1590134, -0.16051482, -0.1632586, -0.16660659, -0.1695625, -0.1713597, -0.17191446, -0.17187683, -0.17224807, -0.17378993, -0.17657427, -0.17993909, -0.18287814, -0.18463452, -0.18514842, -0.18509382, -0.18548207, -0.18706508, -0.18989064, -0.19327258, -0.19619446, -0.19790934, -0.19838165, -0.19830978, -0.19871534, -0.20034024, -0.20320778, -0.20660712, -0.20951153, -0.21118414, -0.21161406, -0.21152462, -0.21194783, -0.21361542, -0.21652575, -0.21994287, -0.22282945, -0.22445892, -0.2248456, -0.22473821, -0.22517943, -0.22689061, -0.22984463, -0.2332799, -0.23614827, -0.23773369, -0.2380762, -0.23795046, -0.23841009, -0.24016581, -0.24316452, -0.24661833, -0.24946809, -0.25100842, -0.25130573, -0.25116125, -0.2516397, -0.25344107, -0.2564855, -0.2599583, -0.26278898, -0.26428315, -0.26453415, -0.2643704, -0.2648682, -0.2667163, -0.26980767, -0.27329996, -0.27611107, -0.27755785, -0.2777613, -0.27757782, -0.27809545, -0.2799916, -0.28313112, -0.28664345, -0.28943443, -0.29083252, -0.29098713, -0.29078332, -0.29132134, -0.2932669, -0.296456, -0.29998893, -0.30275923, -0.30410716, -0.30421147, -0.30398676, -0.3045458, -0.30654222, -0.30978242, -0.31333655, -0.31608552, -0.3173818, -0.3174342, -0.31718794, -0.31776866, -0.3198176, -0.3231105, -0.32668656, -0.32941347, -0.33065638, -0.3306552, -0.33038667, -0.33098978, -0.333093, -0.3364404, -0.3400391, -0.34274325, -0.34393093, -0.34387428, -0.3435827, -0.344209, -0.34636843, -0.3497723, -0.35339448, -0.356075, -0.35720542, -0.3570913, -0.3567758, -0.35742617, -0.3596439, -0.36310634, -0.3667529, -0.3694089, -0.37047988, -0.37030604, -0.3699657, -0.37064105, -0.37291944, -0.3764428, -0.38011467, -0.38274518, -0.3837543, -0.3835183, -0.3831521, -0.3838535, -0.38619503, -0.3897818, -0.3934801, -0.396084, -0.39702865, -0.39672786, -0.39633468, -0.39706326, -0.39947066, -0.40312368, -0.40684953, -0.40942568, -0.41030297, -0.40993443, -0.40951306, -0.41027004, -0.41274637, -0.41646868, -0.42022339, -0.42277047, -0.4235772, -0.42313772, -0.42268682, -0.42347354, -0.42602214, -0.42981714, -0.4336021, -0.43611866, -0.43685135, -0.43633738, -0.43585545, -0.4366735, -0.439298, -0.44316936, -0.44698614, -0.44947064, -0.4501254, -0.44953308, -0.44901848, -0.44986945, -0.45257393, -0.4565258, -0.4603761, -0.46282676, -0.46339938, -0.46272433, -0.46217528, -0.46306103, -0.46585, -0.4698869, -0.47377267, -0.47618753, -0.47667322, -0.47591066, -0.47532517, -0.47624773, -0.4791262, -0.48325318, -0.4871765, -0.48955345, -0.48994693, -0.48909155, -0.4884673, -0.489429, -0.4924025, -0.49662524, -0.50058854, -0.5029251, -0.5032205, -0.5022663, -0.50160086, -0.5026042, -0.50567895, -0.5100038, -0.51400965, -0.5163032, -0.5164939, -0.5154342, -0.5147247, -0.5157726, -0.51895565, -0.52338964, -0.527441, -0.5296885, -0.5297671, -0.5285943, -0.52783763, -0.5289332, -0.5322325, -0.53678375, -0.5408841, -0.543082, -0.54304004, -0.54174566, -0.5409382, -0.54208523, -0.5455097, -0.5501872, -0.55434024, -0.5564847, -0.55631274, -0.55488706, -0.55402476, -0.55522734, -0.5587871, -0.56360126, -0.5678114, -0.56989807, -0.5695851, -0.568017, -0.56709516, -0.5683581, -0.57206494, -0.5770276, -0.5812999, -0.58332354, -0.582857, -0.5811338, -0.5801471, -0.58147585, -0.58534324, -0.59046817, -0.59480834, -0.596763, -0.59612846, -0.5942353, -0.5931775, -0.5945785, -0.598622, -0.60392517, -0.6083399, -0.61021876, -0.60939926, -0.607319, -0.6061829, -0.6076636, -0.6119015, -0.6174014, -0.6218986, -0.62369365, -0.62266934, -0.6203818, -0.61915874, -0.62072784, -0.6251818, -0.63090056, -0.63548946, -0.6371911, -0.6359385, -0.6334196, -0.63209957, -0.63376755, -0.6384632, -0.64442694, -0.64911866, -0.6507155, -0.6492065, -0.6464275, -0.6449983, -0.64677763, -0.6517459, -0.6579863, -0.6627942, -0.66427237, -0.66247296, -0.65939903, -0.6578459, -0.6597519, -0.66503036, -0.6715859, -0.67652625, -0.6778689, -0.6757374, -0.6723258, -0.67063063, -0.67268205, -0.6783171, -0.6852355, -0.69032854, -0.69151473, -0.6889991, -0.6851965, -0.68333673, -0.68555707, -0.691607, -0.69894814, -0.70421934, -0.7052225, -0.7022572, -0.697996, -0.69594276, -0.69836205, -0.7049013, -0.7127418, -0.71822387, -0.7190099, -0.71551013, -0.7107028, -0.7084187, -0.711076, -0.7182017, -0.7266422, -0.732378, -0.73290193, -0.7287555, -0.72328585, -0.7207214, -0.7236689, -0.7315114, -0.74068695, -0.7467345, -0.74693525, -0.7419895, -0.73569894, -0.7327859, -0.7360956, -0.7448353, -0.7549339, -0.761374, -0.7611658, -0.7552054, -0.7478691, -0.7445104, -0.7482856, -0.75818247, -0.7694763, -0.7764267, -0.7756837, -0.7683909, -0.75967485, -0.75572586, -0.76012206, -0.77157027, -0.7844751, -0.7921167, -0.7906434, -0.7815209, -0.7708983, -0.76612854, -0.77139634, -0.78503597, -0.8002335, -0.8088659, -0.8063339, -0.79453796, -0.7811046, -0.7751145, -0.7816963, -0.7986718, -0.817398, -0.8275716, -0.82336414, -0.807286, -0.7892874, -0.7812882, -0.79008156, -0.81276006, -0.83762956, -0.85053426, -0.84326917, -0.8192084, -0.79248583, -0.780552, -0.7938594, -0.8285375, -0.8668087, -0.88589245, -0.8712589, -0.8270209, -0.7769192, -0.75378, -0.78144777, -0.8578567, -0.9484606, -0.9950138, -0.9374896, -0.7401974, -0.41034257, ], [0.0, 0.21054588, 0.41017592, 0.58898586, 0.7389832, 0.85477716, 0.9339859, 0.9773213, 0.98835033, 0.9729681, 0.9386527, 0.8935934, 0.8457959, 0.80226827, 0.7683761, 0.74743253, 0.7405569, 0.74680156, 0.76351416, 0.78687525, 0.81253165, 0.83624107, 0.8544435, 0.8646929, 0.8659022, 0.8583828, 0.84368914, 0.8243013, 0.8032018, 0.7834112, 0.7675495, 0.7574839, 0.75410587, 0.7572626, 0.7658426, 0.77799517, 0.7914442, 0.80384666, 0.813142, 0.81784093, 0.81721705, 0.81137574, 0.80119824, 0.7881738, 0.77414936, 0.76103866, 0.75053346, 0.7438608, 0.74161905, 0.74371517, 0.749406, 0.7574363, 0.7662468, 0.77421993, 0.77992517, 0.7823272, 0.7809272, 0.77581924, 0.76765484, 0.7575262, 0.7467861, 0.73683554, 0.7289102, 0.7238994, 0.7222226, 0.72378033, 0.72798514, 0.7338655, 0.7402237, 0.7458245, 0.7495829, 0.75072443, 0.74889416, 0.7441989, 0.7371783, 0.7287117, 0.71987534, 0.71177405, 0.70537233, 0.7013518, 0.7000142, 0.70124495, 0.70454097, 0.7090962, 0.7139316, 0.7180484, 0.72058016, 0.7209222, 0.71881735, 0.7143878, 0.7081077, 0.7007237, 0.6931347, 0.68625224, 0.68086046, 0.67749983, 0.67638934, 0.67739964, 0.6800799, 0.6837329, 0.6875266, 0.6906232, 0.692308, 0.6920954, 0.6897993, 0.6855559, 0.6797965, 0.67317677, 0.666472, 0.6604569, 0.65578663, 0.6528992, 0.65195215, 0.652803, 0.6550367, 0.6580332, 0.66106606, 0.66341525, 0.66447735, 0.66385573, 0.66141826, 0.657313, 0.65194136, 0.6458924, 0.63984996, 0.6344864, 0.6303596, 0.6278296, 0.62700623, 0.62773603, 0.6296295, 0.6321246, 0.634575, 0.63635063, 0.6369336, 0.63599664, 0.63344985, 0.62945175, 0.6243813, 0.6187768, 0.61325115, 0.60839707, 0.6046962, 0.602447, 0.601721, 0.6023552, 0.60397977, 0.6060778, 0.60806596, 0.60938543, 0.60958683, 0.6083981, 0.6057639, 0.6018517, 0.59702307, 0.5917759, 0.5866666, 0.5822237, 0.57886714, 0.57684517, 0.5761981, 0.5767546, 0.5781604, 0.5799347, 0.58154553, 0.582492, 0.58238083, 0.5809856, 0.5782797, 0.57443815, 0.5698087, 0.5648565, 0.56009126, 0.5559886, 0.5529174, 0.5510841, 0.5505026, 0.55099446, 0.55221784, 0.553722, 0.55501753, 0.55565196, 0.55527896, 0.5537105, 0.5509443, 0.5471623, 0.54270065, 0.537997, 0.5335221, 0.5297071, 0.5268772, 0.52520365, 0.5246778, 0.5251148, 0.5261831, 0.5274574, 0.52848434, 0.52885264, 0.5282561, 0.5265397, 0.523722, 0.5199908, 0.515673, 0.5111828, 0.5069574, 0.5033896, 0.5007676, 0.49923167, 0.49875382, 0.49914333, 0.5000777, 0.5011532, 0.50194746, 0.5020851, 0.5012947, 0.4994497, 0.4965872, 0.49290034, 0.48870778, 0.48440358, 0.48039582, 0.4770439, 0.4746035, 0.47318798, 0.4727521, 0.47310007, 0.47391695, 0.47481787, 0.47540787, 0.47534275, 0.47438172, 0.4724236, 0.4695216, 0.46587384, 0.46179184, 0.4576519, 0.45383656, 0.45067564, 0.448396, 0.44708717, 0.4466885, 0.4469996, 0.44771215, 0.44845796, 0.44886625, 0.44862068, 0.4475077, 0.44544876, 0.44251156, 0.43889865, 0.43491542, 0.43092233, 0.42727906, 0.4242891, 0.42215344, 0.42094022, 0.42057493, 0.42085296, 0.42147177, 0.4220782, 0.4223231, 0.42191517, 0.42066535, 0.41851553, 0.4155466, 0.4119652, 0.40807113, 0.40421066, 0.4007229, 0.39788756, 0.39588225, 0.39475557, 0.39442053, 0.39466852, 0.39520234, 0.39568225, 0.39577875, 0.39522323, 0.39384902, 0.39161655, 0.38861874, 0.38506597, 0.38125318, 0.37751368, 0.37416783, 0.37147364, 0.36958736, 0.3685398, 0.3682324, 0.36845297, 0.36890894, 0.36927295, 0.36923346, 0.3685426, 0.36705428, 0.36474591, 0.36172155, 0.35819516, 0.35445705, 0.35082883, 0.3476136, 0.3450494, 0.34327278, 0.34229815, 0.34201625, 0.34221148, 0.3425956, 0.34285256, 0.34268737, 0.34187144, 0.34027752, 0.3378989, 0.33485, 0.33134803, 0.32767916, 0.3241541, 0.32106006, 0.3186165, 0.3169417, 0.31603485, 0.31577668, 0.3159483, 0.31626558, 0.3164229, 0.31614068, 0.3152082, 0.31351587, 0.31107178, 0.30799997, 0.30452076, 0.30091655, 0.29748788, 0.2945071, 0.29217634, 0.2905968, 0.28975335, 0.28951734, 0.28966692, 0.28992155, 0.28998548, 0.2895935, 0.28855163, 0.28676692, 0.28426135, 0.281168, 0.27771023, 0.27416682, 0.27082884, 0.2679546, 0.26573005, 0.2642402, 0.2634565, 0.2632414, 0.26337016, 0.2635657, 0.26354146, 0.26304585, 0.2619007, 0.26002872, 0.257465, 0.25435132, 0.2509138, 0.24742794, 0.24417579, 0.24140249, 0.23927857, 0.23787378, 0.2371467, 0.23695146, 0.23706046, 0.23719987, 0.23709193, 0.23649783, 0.23525451, 0.23329955, 0.23068056, 0.2275475, 0.22412927, 0.22069821, 0.21752784, 0.21485074, 0.21282274, 0.21149905, 0.21082602, 0.2106497, 0.21073984, 0.21082565, 0.21063772, 0.20994951, 0.20861232, 0.20657799, 0.20390609, 0.2007545, 0.19735475, 0.19397616, 0.19088416, 0.18829927, 0.18636325, 0.18511736, 0.1844962, 0.18433805, 0.18441007, 0.18444441, 0.18417959, 0.18340091, 0.18197346, 0.17986277, 0.17713997, 0.17397052, 0.17058857, 0.16726054, 0.16424406, 0.16174805, 0.15990074, 0.1587299, 0.15815876, 0.15801816, 0.1580727, 0.1580573, 0.15771821, 0.15685208, 0.15533733, 0.1531528, 0.15038073, 0.14719403, 0.1438293, 0.14055026, 0.13760692, 0.13519706, 0.13343576, 0.13233769, 0.1318151, 0.13169152, 0.13172907, 0.1316654, 0.13125414, 0.13030303, 0.12870345, 0.12644708, 0.12362711, 0.12042361, 0.117075644, 0.113844275, 0.1109722, 0.10864628, 0.10696879, 0.105941676, 0.10546644, 0.10535946, 0.10538043, 0.10526961, 0.10478789, 0.10375378, 0.10207129, 0.099744745, 0.09687793, 0.09365799, 0.090326436, 0.08714173, 0.08433942, 0.0820957, 0.0805003, 0.07954271, 0.07911388, 0.079023175, 0.07902789, 0.07887082, 0.07831995, 0.07720435, 0.07544045, 0.073044956, 0.07013211, 0.06689602, 0.063580595, 0.060441792, 0.05770811, 0.055545278, 0.05403071, 0.053141583, 0.052758466, 0.052683804, 0.052672498, 0.05246982, 0.051850747, 0.050654747, 0.048810508, 0.04634694, 0.043388646, 0.040136606, 0.036837116, 0.033743683, 0.03107786, 0.028995031, 0.027560422, 0.026739052, 0.026401184, 0.026342403, 0.026315225, 0.02606736, 0.02538069, 0.02410498, 0.022181064, 0.019649973, 0.016646573, 0.013378702, 0.010095038, 0.007046665, 0.0044482644, 0.0024449462, 0.0010898282, 0.0003358343, 4.297789e-5, -1.1607875e-16, -4.297789e-5, -0.0003358343, -0.0010898282, -0.0024449462, -0.0044482644, -0.007046665, -0.010095038, -0.013378702, -0.016646573, -0.019649973, -0.022181064, -0.02410498, -0.02538069, -0.02606736, -0.026315225, -0.026342403, -0.026401184, -0.026739052, -0.027560422, -0.028995031, -0.03107786, -0.033743683, -0.036837116, -0.040136606, -0.043388646, -0.04634694, -0.048810508, -0.050654747, -0.051850747, -0.05246982, -0.052672498, -0.052683804, -0.052758466, -0.053141583, -0.05403071, -0.055545278, -0.05770811, -0.060441792, -0.063580595, -0.06689602, -0.07013211, -0.073044956, -0.07544045, -0.07720435, -0.07831995, -0.07887082, -0.07902789, -0.079023175, -0.07911388, -0.07954271, -0.0805003, -0.0820957, -0.08433942, -0.08714173, -0.090326436, -0.09365799, -0.09687793, -0.099744745, -0.10207129, -0.10375378, -0.10478789, -0.10526961, -0.10538043, -0.10535946, -0.10546644, -0.105941676, -0.10696879, -0.10864628, -0.1109722, -0.113844275, -0.117075644, -0.12042361, -0.12362711, -0.12644708, -0.12870345, -0.13030303, -0.13125414, -0.1316654, -0.13172907, -0.13169152, -0.1318151, -0.13233769, -0.13343576, -0.13519706, -0.13760692, -0.14055026, -0.1438293, -0.14719403, -0.15038073, -0.1531528, -0.15533733, -0.15685208, -0.15771821, -0.1580573, -0.1580727, -0.15801816, -0.15815876, -0.1587299, -0.15990074, -0.16174805, -0.16424406, -0.16726054, -0.17058857, -0.17397052, -0.17713997, -0.17986277, -0.18197346, -0.18340091, -0.18417959, -0.18444441, -0.18441007, -0.18433805, -0.1844962, -0.18511736, -0.18636325, -0.18829927, -0.19088416, -0.19397616, -0.19735475, -0.2007545, -0.20390609, -0.20657799, -0.20861232, -0.20994951, -0.21063772, -0.21082565, -0.21073984, -0.2106497, -0.21082602, -0.21149905, -0.21282274, -0.21485074, -0.21752784, -0.22069821, -0.22412927, -0.2275475, -0.23068056, -0.23329955, -0.23525451, -0.23649783, -0.23709193, -0.23719987, -0.23706046, -0.23695146, -0.2371467, -0.23787378, -0.23927857, -0.24140249, -0.24417579, -0.24742794, -0.2509138, -0.25435132, -0.257465, -0.26002872, -0.2619007, -0.26304585, -0.26354146, -0.2635657, -0.26337016, -0.2632414, -0.2634565, -0.2642402, -0.26573005, -0.2679546, -0.27082884, -0.27416682, -0.27771023, -0.281168, -0.28426135, -0.28676692, -0.28855163, -0.2895935, -0.28998548, -0.28992155, -0.28966692, -0.28951734, -0.28975335, -0.2905968, -0.29217634, -0.2945071, -0.29748788, -0.30091655, -0.30452076, -0.30799997, -0.31107178, -0.31351587, -0.3152082, -0.31614068, -0.3164229, -0.31626558, -0.3159483, -0.31577668, -0.31603485, -0.3169417, -0.3186165, -0.32106006, -0.3241541, -0.32767916, -0.33134803, -0.33485, -0.3378989, -0.34027752, -0.34187144, -0.34268737, -0.34285256, -0.3425956, -0.34221148, -0.34201625, -0.34229815, -0.34327278, -0.3450494, -0.3476136, -0.35082883, -0.35445705, -0.35819516, -0.36172155, -0.36474591, -0.36705428, -0.3685426, -0.36923346, -0.36927295, -0.36890894, -0.36845297, -0.3682324, -0.3685398, -0.36958736, -0.37147364, -0.37416783, -0.37751368, -0.38125318, -0.38506597, -0.38861874, -0.39161655, -0.39384902, -0.39522323, -0.39577875, -0.39568225, -0.39520234, -0.39466852, -0.39442053, -0.39475557, -0.39588225, -0.39788756, -0.4007229, -0.40421066, -0.40807113, -0.4119652, -0.4155466, -0.41851553, -0.42066535, -0.42191517, -0.4223231, -0.4220782, -0.42147177, -0.42085296, -0.42057493, -0.42094022, -0.42215344, -0.4242891, -0.42727906, -0.43092233, -0.43491542, -0.43889865, -0.44251156, -0.44544876, -0.4475077, -0.44862068, -0.44886625, -0.44845796, -0.44771215, -0.4469996, -0.4466885, -0.44708717, -0.448396, -0.45067564, -0.45383656, -0.4576519, -0.46179184, -0.46587384, -0.4695216, -0.4724236, -0.47438172, -0.47534275, -0.47540787, -0.47481787, -0.47391695, -0.47310007, -0.4727521, -0.47318798, -0.4746035, -0.4770439, -0.48039582, -0.48440358, -0.48870778, -0.49290034, -0.4965872, -0.4994497, -0.5012947, -0.5020851, -0.50194746, -0.5011532, -0.5000777, -0.49914333, -0.49875382, -0.49923167, -0.5007676, -0.5033896, -0.5069574, -0.5111828, -0.515673, -0.5199908, -0.523722, -0.5265397, -0.5282561, -0.52885264, -0.52848434, -0.5274574, -0.5261831, -0.5251148, -0.5246778, -0.52520365, -0.5268772, -0.5297071, -0.5335221, -0.537997, -0.54270065, -0.5471623, -0.5509443, -0.5537105, -0.55527896, -0.55565196, -0.55501753, -0.553722, -0.55221784, -0.55099446, -0.5505026, -0.5510841, -0.5529174, -0.5559886, -0.56009126, -0.5648565, -0.5698087, -0.57443815, -0.5782797, -0.5809856, -0.58238083, -0.582492, -0.58154553, -0.5799347, -0.5781604, -0.5767546, -0.5761981, -0.57684517, -0.57886714, -0.5822237, -0.5866666, -0.5917759, -0.59702307, -0.6018517, -0.6057639, -0.6083981, -0.60958683, -0.60938543, -0.60806596, -0.6060778, -0.60397977, -0.6023552, -0.601721, -0.602447, -0.6046962, -0.60839707, -0.61325115, -0.6187768, -0.6243813, -0.62945175, -0.63344985, -0.63599664, -0.6369336, -0.63635063, -0.634575, -0.6321246, -0.6296295, -0.62773603, -0.62700623, -0.6278296, -0.6303596, -0.6344864, -0.63984996, -0.6458924, -0.65194136, -0.657313, -0.66141826, -0.66385573, -0.66447735, -0.66341525, -0.66106606, -0.6580332, -0.6550367, -0.652803, -0.65195215, -0.6528992, -0.65578663, -0.6604569, -0.666472, -0.67317677, -0.6797965, -0.6855559, -0.6897993, -0.6920954, -0.692308, -0.6906232, -0.6875266, -0.6837329, -0.6800799, -0.67739964, -0.67638934, -0.67749983, -0.68086046, -0.68625224, -0.6931347, -0.7007237, -0.7081077, -0.7143878, -0.71881735, -0.7209222, -0.72058016, -0.7180484, -0.7139316, -0.7090962, -0.70454097, -0.70124495, -0.7000142, -0.7013518, -0.70537233, -0.71177405, -0.71987534, -0.7287117, -0.7371783, -0.7441989, -0.74889416, -0.75072443, -0.7495829, -0.7458245, -0.7402237, -0.7338655, -0.72798514, -0.72378033, -0.7222226, -0.7238994, -0.7289102, -0.73683554, -0.7467861, -0.7575262, -0.76765484, -0.77581924, -0.7809272, -0.7823272, -0.77992517, -0.77421993, -0.7662468, -0.7574363, -0.749406, -0.74371517, -0.74161905, -0.7438608, -0.75053346, -0.76103866, -0.77414936, -0.7881738, -0.80119824, -0.81137574, -0.81721705, -0.81784093, -0.813142, -0.80384666, -0.7914442, -0.77799517, -0.7658426, -0.7572626, -0.75410587, -0.7574839, -0.7675495, -0.7834112, -0.8032018, -0.8243013, -0.84368914, -0.8583828, -0.8659022, -0.8646929, -0.8544435, -0.83624107, -0.81253165, -0.78687525, -0.76351416, -0.74680156, -0.7405569, -0.74743253, -0.7683761, -0.80226827, -0.8457959, -0.8935934, -0.9386527, -0.9729681, -0.98835033, -0.9773213, -0.9339859, -0.85477716, -0.7389832, -0.58898586, -0.41017592, -0.21054588, ], [0.0, 0.10596191, 0.21050291, 0.3122358, 0.40984008, 0.5020926, 0.58789605, 0.66630334, 0.7365383, 0.798011, 0.85032886, 0.8933012, 0.9269392, 0.9514501, 0.9672263, 0.9748297, 0.97497165, 0.9684898, 0.95632154, 0.93947554, 0.9190027, 0.8959662, 0.87141234, 0.8463431, 0.8216909, 0.798296, 0.7768876, 0.7580694, 0.74230874, 0.7299309, 0.7211173, 0.71590924, 0.7142145, 0.7158188, 0.7204004, 0.7275469, 0.73677516, 0.7475518, 0.75931484, 0.771495, 0.7835367, 0.7949169, 0.8051632, 0.8138678, 0.8206998, 0.825414, 0.8278558, 0.8279633, 0.82576555, 0.82137793, 0.81499416, 0.80687636, 0.79734224, 0.7867515, 0.77549076, 0.763958, 0.752547, 0.7416334, 0.7315604, 0.72262734, 0.7150797, 0.70910174, 0.7048114, 0.7022579, 0.70142204, 0.7022192, 0.70450413, 0.70807874, 0.712701, 0.7180954, 0.72396445, 0.73000073, 0.7358989, 0.7413672, 0.7461386, 0.74997985, 0.7527002, 0.754157, 0.75426036, 0.75297517, 0.75032103, 0.7463702, 0.7412436, 0.7351049, 0.7281533, 0.72061485, 0.7127333, 0.70476025, 0.696945, 0.6895255, 0.6827187, 0.67671335, 0.6716626, 0.66767955, 0.66483283, 0.66314584, 0.6625959, 0.6631167, 0.66460127, 0.6669073, 0.6698633, 0.6732754, 0.67693603, 0.6806315, 0.6841511, 0.68729466, 0.6898805, 0.691752, 0.6927834, 0.6928839, 0.69200075, 0.6901205, 0.6872692, 0.6835102, 0.6789413, 0.67369044, 0.6679101, 0.66177094, 0.65545493, 0.64914787, 0.6430324, 0.6372806, 0.63204765, 0.627466, 0.6236406, 0.6206449, 0.618519, 0.61726815, 0.6168632, 0.61724234, 0.61831385, 0.61996037, 0.6220435, 0.62440974, 0.6268967, 0.6293395, 0.63157743, 0.63345987, 0.63485235, 0.63564134, 0.6357386, 0.6350843, 0.63364875, 0.6314335, 0.6284706, 0.62482095, 0.6205718, 0.61583275, 0.61073124, 0.6054074, 0.60000825, 0.5946821, 0.5895723, 0.5848123, 0.5805199, 0.5767932, 0.573707, 0.5713098, 0.56962276, 0.5686386, 0.56832266, 0.5686143, 0.5694298, 0.5706658, 0.5722033, 0.57391304, 0.5756604, 0.57731074, 0.5787346, 0.5798129, 0.5804415, 0.580535, 0.5800299, 0.57888716, 0.5770929, 0.5746592, 0.5716233, 0.56804556, 0.56400704, 0.55960625, 0.55495495, 0.5501735, 0.5453864, 0.5407169, 0.53628266, 0.5321908, 0.528534, 0.5253868, 0.5228031, 0.5208141, 0.5194272, 0.5186259, 0.51837116, 0.5186025, 0.51924086, 0.52019197, 0.52134997, 0.52260196, 0.5238322, 0.5249269, 0.52577853, 0.52629024, 0.52637917, 0.5259801, 0.5250474, 0.523557, 0.5215068, 0.518917, 0.5158288, 0.5123027, 0.50841594, 0.50425935, 0.49993372, 0.49554566, 0.4912033, 0.4870122, 0.48307112, 0.47946814, 0.4762773, 0.4735559, 0.47134224, 0.46965426, 0.46848917, 0.46782342, 0.46761408, 0.4678005, 0.46830684, 0.46904522, 0.46991932, 0.47082812, 0.4716699, 0.47234648, 0.47276676, 0.47285044, 0.47253108, 0.4717586, 0.47050115, 0.46874622, 0.46650097, 0.46379197, 0.46066377, 0.45717722, 0.45340693, 0.44943812, 0.44536337, 0.44127873, 0.4372801, 0.43345925, 0.42990047, 0.42667705, 0.42384866, 0.42145893, 0.41953394, 0.41808125, 0.41708976, 0.41653025, 0.41635656, 0.41650763, 0.41691, 0.41748065, 0.41813046, 0.41876778, 0.41930187, 0.41964674, 0.41972432, 0.41946748, 0.41882277, 0.4177524, 0.41623542, 0.41426885, 0.41186735, 0.40906268, 0.40590236, 0.40244767, 0.39877123, 0.39495388, 0.39108175, 0.38724256, 0.38352227, 0.3800017, 0.37675333, 0.37383854, 0.37130517, 0.36918575, 0.36749637, 0.36623594, 0.36538652, 0.36491397, 0.36476952, 0.3648916, 0.36520845, 0.365641, 0.366106, 0.36651927, 0.3667992, 0.36686972, 0.3666635, 0.36612436, 0.36520964, 0.3638917, 0.3621589, 0.36001608, 0.35748428, 0.35459977, 0.3514126, 0.34798458, 0.3443867, 0.3406964, 0.33699435, 0.33336136, 0.32987523, 0.32660747, 0.32362077, 0.3209664, 0.31868222, 0.31679142, 0.31530145, 0.31420395, 0.313475, 0.31307635, 0.31295672, 0.31305408, 0.31329814, 0.31361294, 0.31392017, 0.3141421, 0.31420463, 0.31404033, 0.3135909, 0.31280968, 0.31166315, 0.31013247, 0.308214, 0.30591947, 0.30327538, 0.300322, 0.2971117, 0.2937067, 0.2901768, 0.28659645, 0.2830418, 0.27958775, 0.2763049, 0.27325687, 0.2704978, 0.26807013, 0.26600316, 0.26431173, 0.26299587, 0.2620407, 0.26141715, 0.26108313, 0.26098529, 0.261061, 0.2612411, 0.2614525, 0.26162106, 0.26167458, 0.26154566, 0.26117435, 0.26051044, 0.2595154, 0.25816405, 0.2564452, 0.25436237, 0.2519334, 0.24918976, 0.24617529, 0.24294439, 0.23955989, 0.2360905, 0.23260805, 0.22918476, 0.22589023, 0.22278884, 0.21993707, 0.21738146, 0.21515666, 0.21328413, 0.2117714, 0.21061167, 0.20978425, 0.20925535, 0.20897946, 0.20890117, 0.20895739, 0.2090799, 0.20919803, 0.20924142, 0.20914285, 0.20884092, 0.20828238, 0.20742427, 0.2062356, 0.20469852, 0.20280899, 0.20057695, 0.19802582, 0.19519167, 0.19212158, 0.18887196, 0.18550612, 0.18209189, 0.17869882, 0.17539549, 0.1722468, 0.16931136, 0.16663925, 0.16427003, 0.16223116, 0.16053703, 0.1591883, 0.158172, 0.15746203, 0.1570202, 0.15679781, 0.15673754, 0.1567758, 0.15684518, 0.15687719, 0.15680493, 0.15656577, 0.15610375, 0.15537186, 0.15433386, 0.15296565, 0.15125628, 0.14920828, 0.14683759, 0.14417292, 0.14125454, 0.1381327, 0.13486563, 0.1315172, 0.12815443, 0.124844775, 0.12165352, 0.11864114, 0.11586095, 0.113357, 0.11116236, 0.10929778, 0.10777095, 0.10657616, 0.10569457, 0.10509496, 0.10473502, 0.104562975, 0.10451969, 0.104540996, 0.10456028, 0.10451104, 0.1043296, 0.103957586, 0.103344224, 0.10244835, 0.10124005, 0.09970177, 0.09782909, 0.09563078, 0.09312853, 0.09035603, 0.08735762, 0.084186554, 0.08090288, 0.07757103, 0.074257284, 0.071027175, 0.06794283, 0.06506057, 0.062428672, 0.060085457, 0.05805779, 0.056360006, 0.054993395, 0.053946108, 0.053193673, 0.052699964, 0.052418634, 0.05229495, 0.05226798, 0.052272998, 0.05224405, 0.05211656, 0.051829908, 0.05132979, 0.050570395, 0.049516197, 0.048143364, 0.04644069, 0.044410035, 0.04206625, 0.039436575, 0.036559567, 0.033483554, 0.030264733, 0.02696494, 0.023649191, 0.02038312, 0.01723037, 0.014250082, 0.011494555, 0.009007171, 0.0068206857, 0.00495593, 0.0034209914, 0.002210892, 0.0013077906, 0.00068168814, 0.00029161797, 8.726815e-5, 1.0973721e-5, -2.982339e-17, -1.0973721e-5, -8.726815e-5, -0.00029161797, -0.00068168814, -0.0013077906, -0.002210892, -0.0034209914, -0.00495593, -0.0068206857, -0.009007171, -0.011494555, -0.014250082, -0.01723037, -0.02038312, -0.023649191, -0.02696494, -0.030264733, -0.033483554, -0.036559567, -0.039436575, -0.04206625, -0.044410035, -0.04644069, -0.048143364, -0.049516197, -0.050570395, -0.05132979, -0.051829908, -0.05211656, -0.05224405, -0.052272998, -0.05226798, -0.05229495, -0.052418634, -0.052699964, -0.053193673, -0.053946108, -0.054993395, -0.056360006, -0.05805779, -0.060085457, -0.062428672, -0.06506057, -0.06794283, -0.071027175, -0.074257284, -0.07757103, -0.08090288, -0.084186554, -0.08735762, -0.09035603, -0.09312853, -0.09563078, -0.09782909, -0.09970177, -0.10124005, -0.10244835, -0.103344224, -0.103957586, -0.1043296, -0.10451104, -0.10456028, -0.104540996, -0.10451969, -0.104562975, -0.10473502, -0.10509496, -0.10569457, -0.10657616, -0.10777095, -0.10929778, -0.11116236, -0.113357, -0.11586095, -0.11864114, -0.12165352, -0.124844775, -0.12815443, -0.1315172, -0.13486563, -0.1381327, -0.14125454, -0.14417292, -0.14683759, -0.14920828, -0.15125628, -0.15296565, -0.15433386, -0.15537186, -0.15610375, -0.15656577, -0.15680493, -0.15687719, -0.15684518, -0.1567758, -0.15673754, -0.15679781, -0.1570202, -0.15746203, -0.158172, -0.1591883, -0.16053703, -0.16223116, -0.16427003, -0.16663925, -0.16931136, -0.1722468, -0.17539549, -0.17869882, -0.18209189, -0.18550612, -0.18887196, -0.19212158, -0.19519167, -0.19802582, -0.20057695, -0.20280899, -0.20469852, -0.2062356, -0.20742427, -0.20828238, -0.20884092, -0.20914285, -0.20924142, -0.20919803, -0.2090799, -0.20895739, -0.20890117, -0.20897946, -0.20925535, -0.20978425, -0.21061167, -0.2117714, -0.21328413, -0.21515666, -0.21738146, -0.21993707, -0.22278884, -0.22589023, -0.22918476, -0.23260805, -0.2360905, -0.23955989, -0.24294439, -0.24617529, -0.24918976, -0.2519334, -0.25436237, -0.2564452, -0.25816405, -0.2595154, -0.26051044, -0.26117435, -0.26154566, -0.26167458, -0.26162106, -0.2614525, -0.2612411, -0.261061, -0.26098529, -0.26108313, -0.26141715, -0.2620407, -0.26299587, -0.26431173, -0.26600316, -0.26807013, -0.2704978, -0.27325687, -0.2763049, -0.27958775, -0.2830418, -0.28659645, -0.2901768, -0.2937067, -0.2971117, -0.300322, -0.30327538, -0.30591947, -0.308214, -0.31013247, -0.31166315, -0.31280968, -0.3135909, -0.31404033, -0.31420463, -0.3141421, -0.31392017, -0.31361294, -0.31329814, -0.31305408, -0.31295672, -0.31307635, -0.313475, -0.31420395, -0.31530145, -0.31679142, -0.31868222, -0.3209664, -0.32362077, -0.32660747, -0.32987523, -0.33336136, -0.33699435, -0.3406964, -0.3443867, -0.34798458, -0.3514126, -0.35459977, -0.35748428, -0.36001608, -0.3621589, -0.3638917, -0.36520964, -0.36612436, -0.3666635, -0.36686972, -0.3667992, -0.36651927, -0.366106, -0.365641, -0.36520845, -0.3648916, -0.36476952, -0.36491397, -0.36538652, -0.36623594, -0.36749637, -0.36918575, -0.37130517, -0.37383854, -0.37675333, -0.3800017, -0.38352227, -0.38724256, -0.39108175, -0.39495388, -0.39877123, -0.40244767, -0.40590236, -0.40906268, -0.41186735, -0.41426885, -0.41623542, -0.4177524, -0.41882277, -0.41946748, -0.41972432, -0.41964674, -0.41930187, -0.41876778, -0.41813046, -0.41748065, -0.41691, -0.41650763, -0.41635656, -0.41653025, -0.41708976, -0.41808125, -0.41953394, -0.42145893, -0.42384866, -0.42667705, -0.42990047, -0.43345925, -0.4372801, -0.44127873, -0.44536337, -0.44943812, -0.45340693, -0.45717722, -0.46066377, -0.46379197, -0.46650097, -0.46874622, -0.47050115, -0.4717586, -0.47253108, -0.47285044, -0.47276676, -0.47234648, -0.4716699, -0.47082812, -0.46991932, -0.46904522, -0.46830684, -0.4678005, -0.46761408, -0.46782342, -0.46848917, -0.46965426, -0.47134224, -0.4735559, -0.4762773, -0.47946814, -0.48307112, -0.4870122, -0.4912033, -0.49554566, -0.49993372, -0.50425935, -0.50841594, -0.5123027, -0.5158288, -0.518917, -0.5215068, -0.523557, -0.5250474, -0.5259801, -0.52637917, -0.52629024, -0.52577853, -0.5249269, -0.5238322, -0.52260196, -0.52134997, -0.52019197, -0.51924086, -0.5186025, -0.51837116, -0.5186259, -0.5194272, -0.5208141, -0.5228031, -0.5253868, -0.528534, -0.5321908, -0.53628266, -0.5407169, -0.5453864, -0.5501735, -0.55495495, -0.55960625, -0.56400704, -0.56804556, -0.5716233, -0.5746592, -0.5770929, -0.57888716, -0.5800299, -0.580535, -0.5804415, -0.5798129, -0.5787346, -0.57731074, -0.5756604, -0.57391304, -0.5722033, -0.5706658, -0.5694298, -0.5686143, -0.56832266, -0.5686386, -0.56962276, -0.5713098, -0.573707, -0.5767932, -0.5805199, -0.5848123, -0.5895723, -0.5946821, -0.60000825, -0.6054074, -0.61073124, -0.61583275, -0.6205718, -0.62482095, -0.6284706, -0.6314335, -0.63364875, -0.6350843, -0.6357386, -0.63564134, -0.63485235, -0.63345987, -0.63157743, -0.6293395, -0.6268967, -0.62440974, -0.6220435, -0.61996037, -0.61831385, -0.61724234, -0.6168632, -0.61726815, -0.618519, -0.6206449, -0.6236406, -0.627466, -0.63204765, -0.6372806, -0.6430324, -0.64914787, -0.65545493, -0.66177094, -0.6679101, -0.67369044, -0.6789413, -0.6835102, -0.6872692, -0.6901205, -0.69200075, -0.6928839, -0.6927834, -0.691752, -0.6898805, -0.68729466, -0.6841511, -0.6806315, -0.67693603, -0.6732754, -0.6698633, -0.6669073, -0.66460127, -0.6631167, -0.6625959, -0.66314584, -0.66483283, -0.66767955, -0.6716626, -0.67671335, -0.6827187, -0.6895255, -0.696945, -0.70476025, -0.7127333, -0.72061485, -0.7281533, -0.7351049, -0.7412436, -0.7463702, -0.75032103, -0.75297517, -0.75426036, -0.754157, -0.7527002, -0.74997985, -0.7461386, -0.7413672, -0.7358989, -0.73000073, -0.72396445, -0.7180954, -0.712701, -0.70807874, -0.70450413, -0.7022192, -0.70142204, -0.7022579, -0.7048114, -0.70910174, -0.7150797, -0.72262734, -0.7315604, -0.7416334, -0.752547, -0.763958, -0.77549076, -0.7867515, -0.79734224, -0.80687636, -0.81499416, -0.82137793, -0.82576555, -0.8279633, -0.8278558, -0.825414, -0.8206998, -0.8138678, -0.8051632, -0.7949169, -0.7835367, -0.771495, -0.75931484, -0.7475518, -0.73677516, -0.7275469, -0.7204004, -0.7158188, -0.7142145, -0.71590924, -0.7211173, -0.7299309, -0.74230874, -0.7580694, -0.7768876, -0.798296, -0.8216909, -0.8463431, -0.87141234, -0.8959662, -0.9190027, -0.93947554, -0.95632154, -0.9684898, -0.97497165, -0.9748297, -0.9672263, -0.9514501, -0.9269392, -0.8933012, -0.85032886, -0.798011, -0.7365383, -0.66630334, -0.58789605, -0.5020926, -0.40984008, -0.3122358, -0.21050291, -0.10596191, ], [0.0, 0.053068787, 0.10595094, 0.15846096, 0.21041563, 0.26163515, 0.3119442, 0.361173, 0.40915838, 0.4557447, 0.5007848, 0.5441409, 0.58568513, 0.62530077, 0.6628823, 0.6983365, 0.73158234, 0.7625519, 0.7911903, 0.81745625, 0.8413217, 0.8627722, 0.8818067, 0.89843726, 0.91268915, 0.9246, 0.9342197, 0.9416099, 0.9468432, 0.9500025, 0.95118046, 0.95047873, 0.9480067, 0.9438812, 0.9382251, 0.9311665, 0.922838, 0.91337514, 0.90291595, 0.89159983, 0.87956613, 0.8669538, 0.85389996, 0.8405391, 0.8270023, 0.81341624, 0.79990244, 0.78657657, 0.7735476, 0.7609173, 0.7487798, 0.7372207, 0.72631717, 0.71613723, 0.7067396, 0.6981736, 0.69047886, 0.68368554, 0.6778143, 0.67287624, 0.66887325, 0.6657985, 0.66363627, 0.6623628, 0.66194654, 0.6623487, 0.6635239, 0.66542053, 0.66798174, 0.6711458, 0.6748469, 0.67901605, 0.6835815, 0.6884696, 0.6936057, 0.6989146, 0.70432144, 0.7097523, 0.715135, 0.7203995, 0.7254789, 0.73030937, 0.7348315, 0.73898995, 0.74273455, 0.74602014, 0.74880725, 0.7510621, 0.752757, 0.75387037, 0.75438684, 0.7542972, 0.7535985, 0.7522939, 0.75039226, 0.7479085, 0.7448627, 0.74128026, 0.7371914, 0.7326306, 0.7276366, 0.7222514, 0.7165203, 0.7104911, 0.7042137, 0.6977394, 0.69112074, 0.6844105, 0.6776617, 0.6709265, 0.6642562, 0.6577005, 0.651307, 0.6451211, 0.6391851, 0.63353825, 0.6282162, 0.6232508, 0.61866975, 0.6144965, 0.6107501, 0.6074449, 0.6045907, 0.60219264, 0.60025114, 0.5987621, 0.5977169, 0.5971027, 0.5969024, 0.5970951, 0.5976562, 0.59855783, 0.5997691, 0.6012564, 0.6029838, 0.6049136, 0.6070065, 0.60922205, 0.6115193, 0.6138568, 0.61619353, 0.6184888, 0.620703, 0.6227977, 0.62473655, 0.62648475, 0.6280102, 0.62928337, 0.63027763, 0.63096946, 0.6313387, 0.6313688, 0.63104665, 0.630363, 0.6293122, 0.62789255, 0.6261059, 0.623958, 0.62145793, 0.6186184, 0.6154554, 0.61198777, 0.6082375, 0.604229, 0.59998906, 0.59554654, 0.590932, 0.5861774, 0.5813157, 0.5763807, 0.5714066, 0.5664274, 0.56147707, 0.5565887, 0.5517946, 0.54712564, 0.5426112, 0.5382787, 0.5341536, 0.5302589, 0.52661496, 0.5232396, 0.52014756, 0.51735073, 0.5148577, 0.51267415, 0.5108023, 0.5092415, 0.5079877, 0.5070338, 0.50637, 0.50598335, 0.50585836, 0.505977, 0.50631887, 0.5068616, 0.50758106, 0.50845134, 0.5094453, 0.5105349, 0.5116913, 0.51288515, 0.51408714, 0.51526797, 0.51639897, 0.5174521, 0.5184004, 0.5192181, 0.51988107, 0.52036697, 0.5206554, 0.5207281, 0.52056915, 0.52016515, 0.51950526, 0.51858133, 0.5173879, 0.5159225, 0.5141851, 0.51217866, 0.50990885, 0.5073838, 0.5046144, 0.50161374, 0.49839726, 0.49498242, 0.4913886, 0.48763683, 0.48374963, 0.47975066, 0.47566462, 0.47151685, 0.46733317, 0.46313956, 0.45896196, 0.4548259, 0.4507564, 0.4467775, 0.44291225, 0.4391824, 0.4356081, 0.43220782, 0.42899817, 0.4259937, 0.42320672, 0.42064732, 0.4183232, 0.41623953, 0.41439918, 0.41280237, 0.4114469, 0.41032815, 0.40943912, 0.40877047, 0.40831074, 0.4080464, 0.40796202, 0.4080404, 0.40826285, 0.4086093, 0.40905854, 0.4095884, 0.41017613, 0.41079843, 0.41143182, 0.41205284, 0.41263834, 0.41316554, 0.41361254, 0.41395828, 0.41418287, 0.41426778, 0.41419598, 0.41395214, 0.4135228, 0.4128964, 0.41206348, 0.41101673, 0.40975106, 0.40826362, 0.40655383, 0.4046234, 0.40247622, 0.40011844, 0.39755827, 0.39480597, 0.39187363, 0.3887752, 0.38552618, 0.3821436, 0.37864566, 0.37505174, 0.37138203, 0.36765745, 0.36389935, 0.36012927, 0.35636887, 0.35263953, 0.3489622, 0.34535727, 0.34184423, 0.3384416, 0.33516666, 0.3320353, 0.3290619, 0.32625914, 0.32363796, 0.32120728, 0.31897423, 0.3169437, 0.31511864, 0.3134998, 0.31208593, 0.31087357, 0.3098573, 0.30902976, 0.30838165, 0.30790183, 0.3075776, 0.30739468, 0.30733737, 0.30738887, 0.30753118, 0.3077456, 0.3080127, 0.30831254, 0.30862504, 0.30893, 0.30920738, 0.3094375, 0.3096013, 0.30968037, 0.30965728, 0.30951568, 0.30924058, 0.3088183, 0.30823678, 0.30748555, 0.30655602, 0.30544132, 0.30413657, 0.3026389, 0.30094722, 0.29906264, 0.29698813, 0.29472855, 0.2922907, 0.2896831, 0.28691608, 0.28400147, 0.28095254, 0.27778402, 0.27451167, 0.2711523, 0.26772356, 0.26424378, 0.2607317, 0.25720632, 0.25368682, 0.25019214, 0.24674092, 0.2433514, 0.24004102, 0.2368264, 0.23372318, 0.23074575, 0.2279072, 0.22521918, 0.22269173, 0.2203333, 0.2181505, 0.21614818, 0.21432936, 0.21269514, 0.21124473, 0.20997551, 0.20888297, 0.20796086, 0.20720117, 0.20659429, 0.20612903, 0.20579283, 0.20557183, 0.20545103, 0.20541446, 0.20544532, 0.20552614, 0.20563906, 0.20576584, 0.20588824, 0.20598802, 0.20604728, 0.20604853, 0.20597497, 0.20581055, 0.20554021, 0.20514998, 0.20462719, 0.20396051, 0.20314011, 0.20215777, 0.20100693, 0.19968274, 0.19818218, 0.19650398, 0.19464871, 0.19261876, 0.19041826, 0.1880531, 0.18553084, 0.1828606, 0.18005303, 0.1771201, 0.17407511, 0.17093243, 0.1677074, 0.1644162, 0.1610756, 0.15770291, 0.1543157, 0.15093164, 0.14756837, 0.14424329, 0.14097331, 0.13777485, 0.13466349, 0.13165396, 0.12875988, 0.12599365, 0.12336643, 0.12088786, 0.11856609, 0.11640764, 0.114417374, 0.112598404, 0.11095211, 0.1094781, 0.10817423, 0.10703661, 0.106059685, 0.10523623, 0.10455751, 0.10401327, 0.10359195, 0.103280716, 0.10306564, 0.102931835, 0.1028636, 0.10284457, 0.102857925, 0.10288651, 0.10291305, 0.102920316, 0.10289127, 0.10280929, 0.10265831, 0.10242297, 0.1020888, 0.101642355, 0.10107134, 0.10036477, 0.09951301, 0.098507956, 0.097343065, 0.09601343, 0.09451582, 0.09284875, 0.091012426, 0.089008816, 0.086841576, 0.08451605, 0.08203916, 0.0794194, 0.07666669, 0.073792316, 0.070808806, 0.06772976, 0.064569786, 0.06134428, 0.058069296, 0.0547614, 0.05143747, 0.048114546, 0.04480964, 0.041539576, 0.03832081, 0.035169274, 0.03210019, 0.029127939, 0.026265914, 0.023526361, 0.020920284, 0.01845731, 0.016145607, 0.013991791, 0.012000865, 0.010176169, 0.008519341, 0.007030307, 0.005707278, 0.004546771, 0.0035436435, 0.002691148, 0.0019809993, 0.0014034604, 0.0009474417, 0.00060061406, 0.00034953508, 0.00017978597, 7.611864e-5, 2.2611282e-5, 2.8307395e-6, 1.5497908e-16, -2.8307395e-6, -2.2611282e-5, -7.611864e-5, -0.00017978597, -0.00034953508, -0.00060061406, -0.0009474417, -0.0014034604, -0.0019809993, -0.002691148, -0.0035436435, -0.004546771, -0.005707278, -0.007030307, -0.008519341, -0.010176169, -0.012000865, -0.013991791, -0.016145607, -0.01845731, -0.020920284, -0.023526361, -0.026265914, -0.029127939, -0.03210019, -0.035169274, -0.03832081, -0.041539576, -0.04480964, -0.048114546, -0.05143747, -0.0547614, -0.058069296, -0.06134428, -0.064569786, -0.06772976, -0.070808806, -0.073792316, -0.07666669, -0.0794194, -0.08203916, -0.08451605, -0.086841576, -0.089008816, -0.091012426, -0.09284875, -0.09451582, -0.09601343, -0.097343065, -0.098507956, -0.09951301, -0.10036477, -0.10107134, -0.101642355, -0.1020888, -0.10242297, -0.10265831, -0.10280929, -0.10289127, -0.102920316, -0.10291305, -0.10288651, -0.102857925, -0.10284457, -0.1028636, -0.102931835, -0.10306564, -0.103280716, -0.10359195, -0.10401327, -0.10455751, -0.10523623, -0.106059685, -0.10703661, -0.10817423, -0.1094781, -0.11095211, -0.112598404, -0.114417374, -0.11640764, -0.11856609, -0.12088786, -0.12336643, -0.12599365, -0.12875988, -0.13165396, -0.13466349, -0.13777485, -0.14097331, -0.14424329, -0.14756837, -0.15093164, -0.1543157, -0.15770291, -0.1610756, -0.1644162, -0.1677074, -0.17093243, -0.17407511, -0.1771201, -0.18005303, -0.1828606, -0.18553084, -0.1880531, -0.19041826, -0.19261876, -0.19464871, -0.19650398, -0.19818218, -0.19968274, -0.20100693, -0.20215777, -0.20314011, -0.20396051, -0.20462719, -0.20514998, -0.20554021, -0.20581055, -0.20597497, -0.20604853, -0.20604728, -0.20598802, -0.20588824, -0.20576584, -0.20563906, -0.20552614, -0.20544532, -0.20541446, -0.20545103, -0.20557183, -0.20579283, -0.20612903, -0.20659429, -0.20720117, -0.20796086, -0.20888297, -0.20997551, -0.21124473, -0.21269514, -0.21432936, -0.21614818, -0.2181505, -0.2203333, -0.22269173, -0.22521918, -0.2279072, -0.23074575, -0.23372318, -0.2368264, -0.24004102, -0.2433514, -0.24674092, -0.25019214, -0.25368682, -0.25720632, -0.2607317, -0.26424378, -0.26772356, -0.2711523, -0.27451167, -0.27778402, -0.28095254, -0.28400147, -0.28691608, -0.2896831, -0.2922907, -0.29472855, -0.29698813, -0.29906264, -0.30094722, -0.3026389, -0.30413657, -0.30544132, -0.30655602, -0.30748555, -0.30823678, -0.3088183, -0.30924058, -0.30951568, -0.30965728, -0.30968037, -0.3096013, -0.3094375, -0.30920738, -0.30893, -0.30862504, -0.30831254, -0.3080127, -0.3077456, -0.30753118, -0.30738887, -0.30733737, -0.30739468, -0.3075776, -0.30790183, -0.30838165, -0.30902976, -0.3098573, -0.31087357, -0.31208593, -0.3134998, -0.31511864, -0.3169437, -0.31897423, -0.32120728, -0.32363796, -0.32625914, -0.3290619, -0.3320353, -0.33516666, -0.3384416, -0.34184423, -0.34535727, -0.3489622, -0.35263953, -0.35636887, -0.36012927, -0.36389935, -0.36765745, -0.37138203, -0.37505174, -0.37864566, -0.3821436, -0.38552618, -0.3887752, -0.39187363, -0.39480597, -0.39755827, -0.40011844, -0.40247622, -0.4046234, -0.40655383, -0.40826362, -0.40975106, -0.41101673, -0.41206348, -0.4128964, -0.4135228, -0.41395214, -0.41419598, -0.41426778, -0.41418287, -0.41395828, -0.41361254, -0.41316554, -0.41263834, -0.41205284, -0.41143182, -0.41079843, -0.41017613, -0.4095884, -0.40905854, -0.4086093, -0.40826285, -0.4080404, -0.40796202, -0.4080464, -0.40831074, -0.40877047, -0.40943912, -0.41032815, -0.4114469, -0.41280237, -0.41439918, -0.41623953, -0.4183232, -0.42064732, -0.42320672, -0.4259937, -0.42899817, -0.43220782, -0.4356081, -0.4391824, -0.44291225, -0.4467775, -0.4507564, -0.4548259, -0.45896196, -0.46313956, -0.46733317, -0.47151685, -0.47566462, -0.47975066, -0.48374963, -0.48763683, -0.4913886, -0.49498242, -0.49839726, -0.50161374, -0.5046144, -0.5073838, -0.50990885, -0.51217866, -0.5141851, -0.5159225, -0.5173879, -0.51858133, -0.51950526, -0.52016515, -0.52056915, -0.5207281, -0.5206554, -0.52036697, -0.51988107, -0.5192181, -0.5184004, -0.5174521, -0.51639897, -0.51526797, -0.51408714, -0.51288515, -0.5116913, -0.5105349, -0.5094453, -0.50845134, -0.50758106, -0.5068616, -0.50631887, -0.505977, -0.50585836, -0.50598335, -0.50637, -0.5070338, -0.5079877, -0.5092415, -0.5108023, -0.51267415, -0.5148577, -0.51735073, -0.52014756, -0.5232396, -0.52661496, -0.5302589, -0.5341536, -0.5382787, -0.5426112, -0.54712564, -0.5517946, -0.5565887, -0.56147707, -0.5664274, -0.5714066, -0.5763807, -0.5813157, -0.5861774, -0.590932, -0.59554654, -0.59998906, -0.604229, -0.6082375, -0.61198777, -0.6154554, -0.6186184, -0.62145793, -0.623958, -0.6261059, -0.62789255, -0.6293122, -0.630363, -0.63104665, -0.6313688, -0.6313387, -0.63096946, -0.63027763, -0.62928337, -0.6280102, -0.62648475, -0.62473655, -0.6227977, -0.620703, -0.6184888, -0.61619353, -0.6138568, -0.6115193, -0.60922205, -0.6070065, -0.6049136, -0.6029838, -0.6012564, -0.5997691, -0.59855783, -0.5976562, -0.5970951, -0.5969024, -0.5971027, -0.5977169, -0.5987621, -0.60025114, -0.60219264, -0.6045907, -0.6074449, -0.6107501, -0.6144965, -0.61866975, -0.6232508, -0.6282162, -0.63353825, -0.6391851, -0.6451211, -0.651307, -0.6577005, -0.6642562, -0.6709265, -0.6776617, -0.6844105, -0.69112074, -0.6977394, -0.7042137, -0.7104911, -0.7165203, -0.7222514, -0.7276366, -0.7326306, -0.7371914, -0.74128026, -0.7448627, -0.7479085, -0.75039226, -0.7522939, -0.7535985, -0.7542972, -0.75438684, -0.75387037, -0.752757, -0.7510621, -0.74880725, -0.74602014, -0.74273455, -0.73898995, -0.7348315, -0.73030937, -0.7254789, -0.7203995, -0.715135, -0.7097523, -0.70432144, -0.6989146, -0.6936057, -0.6884696, -0.6835815, -0.67901605, -0.6748469, -0.6711458, -0.66798174, -0.66542053, -0.6635239, -0.6623487, -0.66194654, -0.6623628, -0.66363627, -0.6657985, -0.66887325, -0.67287624, -0.6778143, -0.68368554, -0.69047886, -0.6981736, -0.7067396, -0.71613723, -0.72631717, -0.7372207, -0.7487798, -0.7609173, -0.7735476, -0.78657657, -0.79990244, -0.81341624, -0.8270023, -0.8405391, -0.85389996, -0.8669538, -0.87956613, -0.89159983, -0.90291595, -0.91337514, -0.922838, -0.9311665, -0.9382251, -0.9438812, -0.9480067, -0.95047873, -0.95118046, -0.9500025, -0.9468432, -0.9416099, -0.9342197, -0.9246, -0.91268915, -0.89843726, -0.8818067, -0.8627722, -0.8413217, -0.81745625, -0.7911903, -0.7625519, -0.73158234, -0.6983365, -0.6628823, -0.62530077, -0.58568513, -0.5441409, -0.5007848, -0.4557447, -0.40915838, -0.361173, -0.3119442, -0.26163515, -0.21041563, -0.15846096, -0.10595094, -0.053068787, ], [0.0, 0.026545718, 0.053065956, 0.079535276, 0.105928324, 0.13221987, 0.15838484, 0.18439837, 0.21023585, 0.23587294, 0.2612856, 0.28645024, 0.31134358, 0.3359428, 0.36022556, 0.38417003, 0.40775493, 0.43095952, 0.45376372, 0.47614804, 0.49809366, 0.5195825, 0.5405972, 0.5611211, 0.5811384, 0.6006339, 0.6195935, 0.6380037, 0.655852, 0.67312676, 0.68981713, 0.70591325, 0.72140616, 0.7362878, 0.75055104, 0.76418966, 0.77719855, 0.7895733, 0.80131066, 0.81240815, 0.8228644, 0.83267885, 0.8418519, 0.85038495, 0.8582803, 0.86554104, 0.87217134, 0.8781761, 0.8835612, 0.88833326, 0.8924998, 0.89606917, 0.8990505, 0.90145355, 0.90328914, 0.90456843, 0.9053036, 0.90550727, 0.90519285, 0.9043742, 0.9030659, 0.9012831, 0.89904124, 0.89635646, 0.89324534, 0.8897247, 0.8858119, 0.8815246, 0.8768808, 0.8718987, 0.86659676, 0.8609936, 0.8551082, 0.8489594, 0.8425663, 0.835948, 0.8291237, 0.8221123, 0.8149331, 0.80760497, 0.80014676, 0.79257715, 0.7849147, 0.7771776, 0.7693839, 0.7615514, 0.7536975, 0.7458393, 0.7379935, 0.7301763, 0.7224038, 0.7146913, 0.7070537, 0.6995055, 0.6920607, 0.6847326, 0.67753416, 0.67047745, 0.6635742, 0.65683556, 0.65027183, 0.6438928, 0.6377077, 0.63172495, 0.6259524, 0.62039727, 0.6150659, 0.6099642, 0.60509723, 0.6004695, 0.5960848, 0.59194607, 0.58805585, 0.5844159, 0.58102727, 0.57789034, 0.575005, 0.57237035, 0.569985, 0.5678467, 0.56595296, 0.5643005, 0.56288546, 0.5617035, 0.56074977, 0.56001884, 0.55950487, 0.5592015, 0.55910194, 0.55919904, 0.55948514, 0.5599522, 0.56059206, 0.56139594, 0.5623549, 0.56345975, 0.564701, 0.566069, 0.5675538, 0.56914544, 0.5708336, 0.5726081, 0.57445854, 0.5763744, 0.57834524, 0.58036053, 0.5824099, 0.58448285, 0.5865691, 0.5886583, 0.5907404, 0.5928053, 0.5948433, 0.59684473, 0.5988002, 0.60070044, 0.60253656, 0.60429996, 0.6059822, 0.6075752, 0.6090712, 0.61046296, 0.6117433, 0.6129056, 0.61394364, 0.6148514, 0.61562353, 0.6162549, 0.6167409, 0.6170773, 0.6172603, 0.61728656, 0.6171533, 0.61685795, 0.6163986, 0.6157737, 0.6149821, 0.6140233, 0.61289704, 0.61160356, 0.61014354, 0.6085181, 0.60672885, 0.60477763, 0.60266685, 0.6003993, 0.5979782, 0.5954069, 0.59268934, 0.58982986, 0.5868329, 0.58370334, 0.5804465, 0.57706773, 0.5735729, 0.56996787, 0.56625897, 0.5624526, 0.5585555, 0.55457443, 0.5505165, 0.5463887, 0.54219836, 0.5379529, 0.5336597, 0.5293263, 0.5249603, 0.52056915, 0.5161606, 0.51174206, 0.5073212, 0.5029053, 0.498502, 0.49411842, 0.48976183, 0.4854393, 0.48115772, 0.47692388, 0.47274432, 0.46862546, 0.46457344, 0.46059424, 0.45669353, 0.4528768, 0.44914922, 0.44551575, 0.44198096, 0.43854925, 0.4352246, 0.43201074, 0.42891106, 0.42592865, 0.42306623, 0.42032617, 0.41771057, 0.41522112, 0.4128592, 0.4106258, 0.40852153, 0.4065468, 0.40470156, 0.4029854, 0.40139762, 0.39993718, 0.3986027, 0.39739245, 0.3963044, 0.39533624, 0.3944853, 0.3937486, 0.39312303, 0.39260498, 0.39219075, 0.3918763, 0.39165735, 0.39152947, 0.39148793, 0.3915278, 0.39164403, 0.39183137, 0.39208436, 0.3923975, 0.39276502, 0.39318117, 0.39364007, 0.3941357, 0.39466208, 0.3952131, 0.39578262, 0.39636454, 0.39695272, 0.39754108, 0.39812347, 0.39869395, 0.3992465, 0.3997753, 0.40027452, 0.40073848, 0.40116167, 0.40153864, 0.40186414, 0.4021331, 0.4023406, 0.40248188, 0.4025525, 0.40254802, 0.40246445, 0.4022979, 0.4020448, 0.40170172, 0.4012656, 0.40073353, 0.40010303, 0.39937174, 0.39853764, 0.39759904, 0.39655444, 0.39540273, 0.39414304, 0.3927748, 0.39129773, 0.38971183, 0.38801742, 0.3862151, 0.38430575, 0.3822905, 0.38017085, 0.3779485, 0.3756254, 0.37320378, 0.3706862, 0.36807537, 0.36537424, 0.36258605, 0.35971424, 0.3567624, 0.3537344, 0.35063425, 0.34746614, 0.34423447, 0.34094372, 0.33759856, 0.33420375, 0.3307642, 0.32728493, 0.32377097, 0.32022753, 0.31665978, 0.31307298, 0.30947244, 0.30586344, 0.3022513, 0.29864132, 0.29503876, 0.29144886, 0.28787675, 0.2843276, 0.2808064, 0.2773181, 0.27386752, 0.27045938, 0.26709825, 0.26378855, 0.26053458, 0.2573405, 0.25421017, 0.2511474, 0.24815574, 0.24523857, 0.24239904, 0.23964007, 0.23696436, 0.23437439, 0.23187241, 0.2294604, 0.2271401, 0.22491302, 0.22278039, 0.22074318, 0.21880214, 0.2169577, 0.2152101, 0.21355923, 0.21200481, 0.21054627, 0.20918275, 0.20791318, 0.20673624, 0.20565033, 0.20465364, 0.20374413, 0.20291951, 0.20217729, 0.20151474, 0.20092896, 0.2004168, 0.199975, 0.19960003, 0.19928825, 0.19903584, 0.19883884, 0.19869314, 0.19859451, 0.1985386, 0.19852097, 0.19853708, 0.19858229, 0.19865194, 0.19874126, 0.19884549, 0.19895981, 0.1990794, 0.19919942, 0.19931507, 0.19942155, 0.19951409, 0.199588, 0.1996386, 0.19966134, 0.19965173, 0.19960536, 0.19951795, 0.19938533, 0.19920348, 0.19896848, 0.19867662, 0.19832428, 0.19790807, 0.19742475, 0.19687128, 0.19624479, 0.19554265, 0.19476241, 0.19390182, 0.1929589, 0.19193187, 0.19081916, 0.18961945, 0.18833168, 0.18695498, 0.18548873, 0.18393262, 0.18228649, 0.18055046, 0.1787249, 0.17681043, 0.17480788, 0.17271832, 0.17054304, 0.16828363, 0.16594179, 0.16351955, 0.16101906, 0.15844274, 0.15579319, 0.15307319, 0.15028574, 0.14743397, 0.1445212, 0.14155094, 0.1385268, 0.13545255, 0.1323321, 0.12916945, 0.12596875, 0.12273422, 0.119470164, 0.116180964, 0.11287107, 0.10954498, 0.10620722, 0.10286236, 0.09951497, 0.09616961, 0.092830874, 0.08950328, 0.08619134, 0.08289952, 0.07963221, 0.07639375, 0.07318839, 0.070020266, 0.06689345, 0.06381187, 0.06077933, 0.057799514, 0.054875948, 0.052012004, 0.0492109, 0.04647567, 0.04380918, 0.041214097, 0.038692903, 0.036247876, 0.03388109, 0.0315944, 0.02938945, 0.027267665, 0.025230238, 0.023278141, 0.021412112, 0.019632658, 0.017940054, 0.016334336, 0.014815316, 0.013382563, 0.01203542, 0.010772998, 0.009594181, 0.0084976265, 0.0074817752, 0.0065448484, 0.0056848563, 0.004899604, 0.004186694, 0.0035435376, 0.0029673579, 0.0024551987, 0.0020039328, 0.0016102698, 0.0012707652, 0.0009818296, 0.0007397383, 0.0005406414, 0.000380574, 0.00025546673, 0.00016115687, 9.3399336e-5, 4.7878028e-5, 2.021745e-5, 5.9943604e-6, 7.495956e-7, 0.0, -7.495956e-7, -5.9943604e-6, -2.021745e-5, -4.7878028e-5, -9.3399336e-5, -0.00016115687, -0.00025546673, -0.000380574, -0.0005406414, -0.0007397383, -0.0009818296, -0.0012707652, -0.0016102698, -0.0020039328, -0.0024551987, -0.0029673579, -0.0035435376, -0.004186694, -0.004899604, -0.0056848563, -0.0065448484, -0.0074817752, -0.0084976265, -0.009594181, -0.010772998, -0.01203542, -0.013382563, -0.014815316, -0.016334336, -0.017940054, -0.019632658, -0.021412112, -0.023278141, -0.025230238, -0.027267665, -0.02938945, -0.0315944, -0.03388109, -0.036247876, -0.038692903, -0.041214097, -0.04380918, -0.04647567, -0.0492109, -0.052012004, -0.054875948, -0.057799514, -0.06077933, -0.06381187, -0.06689345, -0.070020266, -0.07318839, -0.07639375, -0.07963221, -0.08289952, -0.08619134, -0.08950328, -0.092830874, -0.09616961, -0.09951497, -0.10286236, -0.10620722, -0.10954498, -0.11287107, -0.116180964, -0.119470164, -0.12273422, -0.12596875, -0.12916945, -0.1323321, -0.13545255, -0.1385268, -0.14155094, -0.1445212, -0.14743397, -0.15028574, -0.15307319, -0.15579319, -0.15844274, -0.16101906, -0.16351955, -0.16594179, -0.16828363, -0.17054304, -0.17271832, -0.17480788, -0.17681043, -0.1787249, -0.18055046, -0.18228649, -0.18393262, -0.18548873, -0.18695498, -0.18833168, -0.18961945, -0.19081916, -0.19193187, -0.1929589, -0.19390182, -0.19476241, -0.19554265, -0.19624479, -0.19687128, -0.19742475, -0.19790807, -0.19832428, -0.19867662, -0.19896848, -0.19920348, -0.19938533, -0.19951795, -0.19960536, -0.19965173, -0.19966134, -0.1996386, -0.199588, -0.19951409, -0.19942155, -0.19931507, -0.19919942, -0.1990794, -0.19895981, -0.19884549, -0.19874126, -0.19865194, -0.19858229, -0.19853708, -0.19852097, -0.1985386, -0.19859451, -0.19869314, -0.19883884, -0.19903584, -0.19928825, -0.19960003, -0.199975, -0.2004168, -0.20092896, -0.20151474, -0.20217729, -0.20291951, -0.20374413, -0.20465364, -0.20565033, -0.20673624, -0.20791318, -0.20918275, -0.21054627, -0.21200481, -0.21355923, -0.2152101, -0.2169577, -0.21880214, -0.22074318, -0.22278039, -0.22491302, -0.2271401, -0.2294604, -0.23187241, -0.23437439, -0.23696436, -0.23964007, -0.24239904, -0.24523857, -0.24815574, -0.2511474, -0.25421017, -0.2573405, -0.26053458, -0.26378855, -0.26709825, -0.27045938, -0.27386752, -0.2773181, -0.2808064, -0.2843276, -0.28787675, -0.29144886, -0.29503876, -0.29864132, -0.3022513, -0.30586344, -0.30947244, -0.31307298, -0.31665978, -0.32022753, -0.32377097, -0.32728493, -0.3307642, -0.33420375, -0.33759856, -0.34094372, -0.34423447, -0.34746614, -0.35063425, -0.3537344, -0.3567624, -0.35971424, -0.36258605, -0.36537424, -0.36807537, -0.3706862, -0.37320378, -0.3756254, -0.3779485, -0.38017085, -0.3822905, -0.38430575, -0.3862151, -0.38801742, -0.38971183, -0.39129773, -0.3927748, -0.39414304, -0.39540273, -0.39655444, -0.39759904, -0.39853764, -0.39937174, -0.40010303, -0.40073353, -0.4012656, -0.40170172, -0.4020448, -0.4022979, -0.40246445, -0.40254802, -0.4025525, -0.40248188, -0.4023406, -0.4021331, -0.40186414, -0.40153864, -0.40116167, -0.40073848, -0.40027452, -0.3997753, -0.3992465, -0.39869395, -0.39812347, -0.39754108, -0.39695272, -0.39636454, -0.39578262, -0.3952131, -0.39466208, -0.3941357, -0.39364007, -0.39318117, -0.39276502, -0.3923975, -0.39208436, -0.39183137, -0.39164403, -0.3915278, -0.39148793, -0.39152947, -0.39165735, -0.3918763, -0.39219075, -0.39260498, -0.39312303, -0.3937486, -0.3944853, -0.39533624, -0.3963044, -0.39739245, -0.3986027, -0.39993718, -0.40139762, -0.4029854, -0.40470156, -0.4065468, -0.40852153, -0.4106258, -0.4128592, -0.41522112, -0.41771057, -0.42032617, -0.42306623, -0.42592865, -0.42891106, -0.43201074, -0.4352246, -0.43854925, -0.44198096, -0.44551575, -0.44914922, -0.4528768, -0.45669353, -0.46059424, -0.46457344, -0.46862546, -0.47274432, -0.47692388, -0.48115772, -0.4854393, -0.48976183, -0.49411842, -0.498502, -0.5029053, -0.5073212, -0.51174206, -0.5161606, -0.52056915, -0.5249603, -0.5293263, -0.5336597, -0.5379529, -0.54219836, -0.5463887, -0.5505165, -0.55457443, -0.5585555, -0.5624526, -0.56625897, -0.56996787, -0.5735729, -0.57706773, -0.5804465, -0.58370334, -0.5868329, -0.58982986, -0.59268934, -0.5954069, -0.5979782, -0.6003993, -0.60266685, -0.60477763, -0.60672885, -0.6085181, -0.61014354, -0.61160356, -0.61289704, -0.6140233, -0.6149821, -0.6157737, -0.6163986, -0.61685795, -0.6171533, -0.61728656, -0.6172603, -0.6170773, -0.6167409, -0.6162549, -0.61562353, -0.6148514, -0.61394364, -0.6129056, -0.6117433, -0.61046296, -0.6090712, -0.6075752, -0.6059822, -0.60429996, -0.60253656, -0.60070044, -0.5988002, -0.59684473, -0.5948433, -0.5928053, -0.5907404, -0.5886583, -0.5865691, -0.58448285, -0.5824099, -0.58036053, -0.57834524, -0.5763744, -0.57445854, -0.5726081, -0.5708336, -0.56914544, -0.5675538, -0.566069, -0.564701, -0.56345975, -0.5623549, -0.56139594, -0.56059206, -0.5599522, -0.55948514, -0.55919904, -0.55910194, -0.5592015, -0.55950487, -0.56001884, -0.56074977, -0.5617035, -0.56288546, -0.5643005, -0.56595296, -0.5678467, -0.569985, -0.57237035, -0.575005, -0.57789034, -0.58102727, -0.5844159, -0.58805585, -0.59194607, -0.5960848, -0.6004695, -0.60509723, -0.6099642, -0.6150659, -0.62039727, -0.6259524, -0.63172495, -0.6377077, -0.6438928, -0.65027183, -0.65683556, -0.6635742, -0.67047745, -0.67753416, -0.6847326, -0.6920607, -0.6995055, -0.7070537, -0.7146913, -0.7224038, -0.7301763, -0.7379935, -0.7458393, -0.7536975, -0.7615514, -0.7693839, -0.7771776, -0.7849147, -0.79257715, -0.80014676, -0.80760497, -0.8149331, -0.8221123, -0.8291237, -0.835948, -0.8425663, -0.8489594, -0.8551082, -0.8609936, -0.86659676, -0.8718987, -0.8768808, -0.8815246, -0.8858119, -0.8897247, -0.89324534, -0.89635646, -0.89904124, -0.9012831, -0.9030659, -0.9043742, -0.90519285, -0.90550727, -0.9053036, -0.90456843, -0.90328914, -0.90145355, -0.8990505, -0.89606917, -0.8924998, -0.88833326, -0.8835612, -0.8781761, -0.87217134, -0.86554104, -0.8582803, -0.85038495, -0.8418519, -0.83267885, -0.8228644, -0.81240815, -0.80131066, -0.7895733, -0.77719855, -0.76418966, -0.75055104, -0.7362878, -0.72140616, -0.70591325, -0.68981713, -0.67312676, -0.655852, -0.6380037, -0.6195935, -0.6006339, -0.5811384, -0.5611211, -0.5405972, -0.5195825, -0.49809366, -0.47614804, -0.45376372, -0.43095952, -0.40775493, -0.38417003, -0.36022556, -0.3359428, -0.31134358, -0.28645024, -0.2612856, -0.23587294, -0.21023585, -0.18439837, -0.15838484, -0.13221987, -0.105928324, -0.079535276, -0.053065956, -0.026545718, ], [0.0, 0.013274359, 0.02654497, 0.039808083, 0.05305996, 0.06629687, 0.07951506, 0.09271082, 0.10588045, 0.11902022, 0.13212647, 0.14519551, 0.15822369, 0.17120735, 0.1841429, 0.19702673, 0.20985527, 0.22262497, 0.2353323, 0.24797376, 0.26054588, 0.27304524, 0.28546843, 0.29781204, 0.3100728, 0.3222474, 0.33433253, 0.346325, 0.35822162, 0.37001926, 0.38171482, 0.39330527, 0.40478757, 0.41615877, 0.42741597, 0.43855634, 0.449577, 0.46047527, 0.47124842, 0.4818938, 0.4924088, 0.5027909, 0.5130377, 0.5231466, 0.53311545, 0.5429418, 0.5526235, 0.56215835, 0.5715442, 0.5807791, 0.5898609, 0.5987879, 0.6075581, 0.61616975, 0.62462115, 0.63291067, 0.64103675, 0.6489978, 0.65679246, 0.6644193, 0.6718771, 0.6791646, 0.6862806, 0.69322413, 0.699994, 0.7065895, 0.71300966, 0.71925366, 0.72532076, 0.7312104, 0.736922, 0.74245507, 0.7478091, 0.7529838, 0.7579789, 0.7627942, 0.7674296, 0.7718849, 0.7761603, 0.7802558, 0.7841715, 0.7879077, 0.79146475, 0.79484296, 0.7980427, 0.80106467, 0.8039093, 0.8065773, 0.8090694, 0.81138635, 0.8135291, 0.8154984, 0.81729543, 0.8189211, 0.8203766, 0.8216631, 0.82278186, 0.82373416, 0.82452136, 0.82514495, 0.82560635, 0.8259071, 0.8260489, 0.8260333, 0.8258621, 0.82553697, 0.82505983, 0.8244325, 0.8236569, 0.8227351, 0.8216689, 0.8204606, 0.81911224, 0.817626, 0.81600404, 0.81424856, 0.81236196, 0.81034654, 0.8082046, 0.8059386, 0.80355096, 0.80104417, 0.7984207, 0.79568315, 0.792834, 0.7898759, 0.7868115, 0.78364336, 0.7803742, 0.7770068, 0.7735437, 0.7699878, 0.76634175, 0.76260835, 0.75879043, 0.7548907, 0.7509121, 0.7468573, 0.74272925, 0.73853076, 0.7342647, 0.7299338, 0.72554106, 0.7210893, 0.7165814, 0.71202016, 0.7074085, 0.70274913, 0.6980451, 0.6932991, 0.68851405, 0.6836927, 0.6788379, 0.67395246, 0.66903913, 0.6641007, 0.65913993, 0.65415955, 0.64916223, 0.64415073, 0.6391277, 0.6340958, 0.6290576, 0.62401575, 0.61897284, 0.6139314, 0.60889393, 0.60386294, 0.59884083, 0.5938301, 0.5888331, 0.5838522, 0.5788896, 0.5739478, 0.56902885, 0.5641351, 0.5592686, 0.5544315, 0.5496259, 0.54485387, 0.5401173, 0.5354183, 0.5307587, 0.5261403, 0.52156496, 0.5170345, 0.5125506, 0.5081149, 0.50372905, 0.49939463, 0.4951132, 0.49088615, 0.48671496, 0.48260102, 0.47854558, 0.47454995, 0.47061533, 0.46674287, 0.46293372, 0.4591889, 0.4555094, 0.4518962, 0.44835016, 0.44487214, 0.4414629, 0.4381232, 0.43485367, 0.431655, 0.42852768, 0.42547226, 0.42248917, 0.41957885, 0.4167416, 0.41397777, 0.41128758, 0.4086712, 0.40612876, 0.40366036, 0.40126604, 0.39894575, 0.39669943, 0.39452696, 0.39242813, 0.39040273, 0.3884505, 0.3865711, 0.38476416, 0.38302925, 0.3813659, 0.3797736, 0.37825176, 0.37679976, 0.375417, 0.37410274, 0.37285626, 0.37167674, 0.3705634, 0.36951533, 0.36853164, 0.36761138, 0.36675355, 0.36595714, 0.36522108, 0.3645443, 0.36392564, 0.36336392, 0.362858, 0.3624066, 0.36200848, 0.36166236, 0.3613669, 0.36112076, 0.36092257, 0.36077094, 0.36066443, 0.3606016, 0.36058098, 0.3606011, 0.36066043, 0.36075744, 0.3608906, 0.36105835, 0.36125907, 0.36149123, 0.3617532, 0.36204335, 0.3623601, 0.3627017, 0.36306664, 0.3634532, 0.3638597, 0.36428455, 0.36472604, 0.36518246, 0.3656522, 0.36613357, 0.36662486, 0.36712447, 0.3676307, 0.36814186, 0.36865634, 0.36917248, 0.36968863, 0.37020314, 0.3707144, 0.37122083, 0.37172076, 0.37221265, 0.3726949, 0.373166, 0.37362432, 0.3740684, 0.37449673, 0.3749078, 0.3753001, 0.37567228, 0.37602282, 0.37635034, 0.37665346, 0.37693083, 0.37718114, 0.37740302, 0.37759522, 0.3777565, 0.37788564, 0.3779814, 0.37804264, 0.3780682, 0.378057, 0.37800798, 0.37792006, 0.37779224, 0.37762356, 0.37741306, 0.37715983, 0.376863, 0.37652177, 0.37613526, 0.37570274, 0.3752235, 0.37469682, 0.37412205, 0.3734986, 0.37282583, 0.37210324, 0.37133032, 0.37050658, 0.36963162, 0.36870503, 0.3677265, 0.36669567, 0.3656123, 0.36447614, 0.36328703, 0.36204475, 0.36074924, 0.35940042, 0.35799822, 0.35654268, 0.35503381, 0.35347173, 0.3518565, 0.35018831, 0.34846738, 0.3466939, 0.34486812, 0.3429904, 0.34106106, 0.33908048, 0.33704907, 0.3349673, 0.3328356, 0.33065456, 0.3284247, 0.32614657, 0.3238209, 0.3214482, 0.3190293, 0.31656483, 0.31405553, 0.31150225, 0.30890572, 0.3062668, 0.30358642, 0.30086538, 0.29810467, 0.2953052, 0.29246795, 0.2895939, 0.2866841, 0.28373957, 0.2807614, 0.27775064, 0.27470845, 0.2716359, 0.26853418, 0.26540443, 0.26224783, 0.25906563, 0.25585896, 0.25262913, 0.24937731, 0.2461048, 0.24281287, 0.23950277, 0.2361758, 0.23283328, 0.22947647, 0.2261067, 0.22272532, 0.2193336, 0.2159329, 0.21252455, 0.20910987, 0.2056902, 0.2022669, 0.19884127, 0.19541465, 0.1919884, 0.18856381, 0.18514222, 0.18172495, 0.17831331, 0.17490862, 0.17151216, 0.16812523, 0.1647491, 0.16138504, 0.15803431, 0.15469816, 0.15137783, 0.14807451, 0.14478941, 0.14152373, 0.13827865, 0.1350553, 0.13185483, 0.12867835, 0.12552696, 0.122401744, 0.11930373, 0.11623399, 0.113193505, 0.11018327, 0.10720426, 0.1042574, 0.10134361, 0.09846378, 0.09561878, 0.09280943, 0.09003655, 0.08730091, 0.08460327, 0.081944354, 0.07932484, 0.076745406, 0.07420668, 0.07170926, 0.06925373, 0.066840604, 0.06447042, 0.06214363, 0.059860688, 0.057622, 0.055427946, 0.05327887, 0.05117508, 0.049116854, 0.047104433, 0.04513803, 0.043217823, 0.041343946, 0.03951651, 0.037735585, 0.036001213, 0.0343134, 0.032672115, 0.03107729, 0.029528834, 0.028026612, 0.02657046, 0.025160179, 0.023795536, 0.022476265, 0.021202069, 0.019972617, 0.018787542, 0.017646452, 0.016548917, 0.015494477, 0.014482642, 0.01351289, 0.0125846695, 0.011697398, 0.010850463, 0.010043225, 0.009275014, 0.008545131, 0.007852853, 0.0071974266, 0.0065780724, 0.0059939856, 0.0054443358, 0.0049282676, 0.0044449023, 0.003993337, 0.0035726458, 0.0031818806, 0.0028200725, 0.002486231, 0.0021793463, 0.0018983884, 0.0016423092, 0.001410043, 0.0012005063, 0.0010126, 0.0008452092, 0.00069720414, 0.0005674415, 0.00045476467, 0.00035800488, 0.00027598196, 0.00020750519, 0.00015137414, 0.00010637958, 7.130426e-5, 4.492386e-5, 2.6007836e-5, 1.3320299e-5, 5.6209087e-6, 1.6657523e-6, 2.0824137e-7, 0.0, -2.0824137e-7, -1.6657523e-6, -5.6209087e-6, -1.3320299e-5, -2.6007836e-5, -4.492386e-5, -7.130426e-5, -0.00010637958, -0.00015137414, -0.00020750519, -0.00027598196, -0.00035800488, -0.00045476467, -0.0005674415, -0.00069720414, -0.0008452092, -0.0010126, -0.0012005063, -0.001410043, -0.0016423092, -0.0018983884, -0.0021793463, -0.002486231, -0.0028200725, -0.0031818806, -0.0035726458, -0.003993337, -0.0044449023, -0.0049282676, -0.0054443358, -0.0059939856, -0.0065780724, -0.0071974266, -0.007852853, -0.008545131, -0.009275014, -0.010043225, -0.010850463, -0.011697398, -0.0125846695, -0.01351289, -0.014482642, -0.015494477, -0.016548917, -0.017646452, -0.018787542, -0.019972617, -0.021202069, -0.022476265, -0.023795536, -0.025160179, -0.02657046, -0.028026612, -0.029528834, -0.03107729, -0.032672115, -0.0343134, -0.036001213, -0.037735585, -0.03951651, -0.041343946, -0.043217823, -0.04513803, -0.047104433, -0.049116854, -0.05117508, -0.05327887, -0.055427946, -0.057622, -0.059860688, -0.06214363, -0.06447042, -0.066840604, -0.06925373, -0.07170926, -0.07420668, -0.076745406, -0.07932484, -0.081944354, -0.08460327, -0.08730091, -0.09003655, -0.09280943, -0.09561878, -0.09846378, -0.10134361, -0.1042574, -0.10720426, -0.11018327, -0.113193505, -0.11623399, -0.11930373, -0.122401744, -0.12552696, -0.12867835, -0.13185483, -0.1350553, -0.13827865, -0.14152373, -0.14478941, -0.14807451, -0.15137783, -0.15469816, -0.15803431, -0.16138504, -0.1647491, -0.16812523, -0.17151216, -0.17490862, -0.17831331, -0.18172495, -0.18514222, -0.18856381, -0.1919884, -0.19541465, -0.19884127, -0.2022669, -0.2056902, -0.20910987, -0.21252455, -0.2159329, -0.2193336, -0.22272532, -0.2261067, -0.22947647, -0.23283328, -0.2361758, -0.23950277, -0.24281287, -0.2461048, -0.24937731, -0.25262913, -0.25585896, -0.25906563, -0.26224783, -0.26540443, -0.26853418, -0.2716359, -0.27470845, -0.27775064, -0.2807614, -0.28373957, -0.2866841, -0.2895939, -0.29246795, -0.2953052, -0.29810467, -0.30086538, -0.30358642, -0.3062668, -0.30890572, -0.31150225, -0.31405553, -0.31656483, -0.3190293, -0.3214482, -0.3238209, -0.32614657, -0.3284247, -0.33065456, -0.3328356, -0.3349673, -0.33704907, -0.33908048, -0.34106106, -0.3429904, -0.34486812, -0.3466939, -0.34846738, -0.35018831, -0.3518565, -0.35347173, -0.35503381, -0.35654268, -0.35799822, -0.35940042, -0.36074924, -0.36204475, -0.36328703, -0.36447614, -0.3656123, -0.36669567, -0.3677265, -0.36870503, -0.36963162, -0.37050658, -0.37133032, -0.37210324, -0.37282583, -0.3734986, -0.37412205, -0.37469682, -0.3752235, -0.37570274, -0.37613526, -0.37652177, -0.376863, -0.37715983, -0.37741306, -0.37762356, -0.37779224, -0.37792006, -0.37800798, -0.378057, -0.3780682, -0.37804264, -0.3779814, -0.37788564, -0.3777565, -0.37759522, -0.37740302, -0.37718114, -0.37693083, -0.37665346, -0.37635034, -0.37602282, -0.37567228, -0.3753001, -0.3749078, -0.37449673, -0.3740684, -0.37362432, -0.373166, -0.3726949, -0.37221265, -0.37172076, -0.37122083, -0.3707144, -0.37020314, -0.36968863, -0.36917248, -0.36865634, -0.36814186, -0.3676307, -0.36712447, -0.36662486, -0.36613357, -0.3656522, -0.36518246, -0.36472604, -0.36428455, -0.3638597, -0.3634532, -0.36306664, -0.3627017, -0.3623601, -0.36204335, -0.3617532, -0.36149123, -0.36125907, -0.36105835, -0.3608906, -0.36075744, -0.36066043, -0.3606011, -0.36058098, -0.3606016, -0.36066443, -0.36077094, -0.36092257, -0.36112076, -0.3613669, -0.36166236, -0.36200848, -0.3624066, -0.362858, -0.36336392, -0.36392564, -0.3645443, -0.36522108, -0.36595714, -0.36675355, -0.36761138, -0.36853164, -0.36951533, -0.3705634, -0.37167674, -0.37285626, -0.37410274, -0.375417, -0.37679976, -0.37825176, -0.3797736, -0.3813659, -0.38302925, -0.38476416, -0.3865711, -0.3884505, -0.39040273, -0.39242813, -0.39452696, -0.39669943, -0.39894575, -0.40126604, -0.40366036, -0.40612876, -0.4086712, -0.41128758, -0.41397777, -0.4167416, -0.41957885, -0.42248917, -0.42547226, -0.42852768, -0.431655, -0.43485367, -0.4381232, -0.4414629, -0.44487214, -0.44835016, -0.4518962, -0.4555094, -0.4591889, -0.46293372, -0.46674287, -0.47061533, -0.47454995, -0.47854558, -0.48260102, -0.48671496, -0.49088615, -0.4951132, -0.49939463, -0.50372905, -0.5081149, -0.5125506, -0.5170345, -0.52156496, -0.5261403, -0.5307587, -0.5354183, -0.5401173, -0.54485387, -0.5496259, -0.5544315, -0.5592686, -0.5641351, -0.56902885, -0.5739478, -0.5788896, -0.5838522, -0.5888331, -0.5938301, -0.59884083, -0.60386294, -0.60889393, -0.6139314, -0.61897284, -0.62401575, -0.6290576, -0.6340958, -0.6391277, -0.64415073, -0.64916223, -0.65415955, -0.65913993, -0.6641007, -0.66903913, -0.67395246, -0.6788379, -0.6836927, -0.68851405, -0.6932991, -0.6980451, -0.70274913, -0.7074085, -0.71202016, -0.7165814, -0.7210893, -0.72554106, -0.7299338, -0.7342647, -0.73853076, -0.74272925, -0.7468573, -0.7509121, -0.7548907, -0.75879043, -0.76260835, -0.76634175, -0.7699878, -0.7735437, -0.7770068, -0.7803742, -0.78364336, -0.7868115, -0.7898759, -0.792834, -0.79568315, -0.7984207, -0.80104417, -0.80355096, -0.8059386, -0.8082046, -0.81034654, -0.81236196, -0.81424856, -0.81600404, -0.817626, -0.81911224, -0.8204606, -0.8216689, -0.8227351, -0.8236569, -0.8244325, -0.82505983, -0.82553697, -0.8258621, -0.8260333, -0.8260489, -0.8259071, -0.82560635, -0.82514495, -0.82452136, -0.82373416, -0.82278186, -0.8216631, -0.8203766, -0.8189211, -0.81729543, -0.8154984, -0.8135291, -0.81138635, -0.8090694, -0.8065773, -0.8039093, -0.80106467, -0.7980427, -0.79484296, -0.79146475, -0.7879077, -0.7841715, -0.7802558, -0.7761603, -0.7718849, -0.7674296, -0.7627942, -0.7579789, -0.7529838, -0.7478091, -0.74245507, -0.736922, -0.7312104, -0.72532076, -0.71925366, -0.71300966, -0.7065895, -0.699994, -0.69322413, -0.6862806, -0.6791646, -0.6718771, -0.6644193, -0.65679246, -0.6489978, -0.64103675, -0.63291067, -0.62462115, -0.61616975, -0.6075581, -0.5987879, -0.5898609, -0.5807791, -0.5715442, -0.56215835, -0.5526235, -0.5429418, -0.53311545, -0.5231466, -0.5130377, -0.5027909, -0.4924088, -0.4818938, -0.47124842, -0.46047527, -0.449577, -0.43855634, -0.42741597, -0.41615877, -0.40478757, -0.39330527, -0.38171482, -0.37001926, -0.35822162, -0.346325, -0.33433253, -0.3222474, -0.3100728, -0.29781204, -0.28546843, -0.27304524, -0.26054588, -0.24797376, -0.2353323, -0.22262497, -0.20985527, -0.19702673, -0.1841429, -0.17120735, -0.15822369, -0.14519551, -0.13212647, -0.11902022, -0.10588045, -0.09271082, -0.07951506, -0.06629687, -0.05305996, -0.039808083, -0.02654497, -0.013274359, ], [0.0, 0.0066373874, 0.01327415, 0.019909663, 0.026543302, 0.033174444, 0.03980246, 0.046426736, 0.053046644, 0.059661556, 0.06627086, 0.07287393, 0.079470135, 0.08605888, 0.09263952, 0.09921146, 0.10577407, 0.11232674, 0.11886885, 0.1253998, 0.13191897, 0.13842574, 0.14491953, 0.1513997, 0.15786567, 0.16431683, 0.17075258, 0.17717233, 0.18357547, 0.18996139, 0.19632953, 0.20267929, 0.20901006, 0.21532129, 0.22161236, 0.22788273, 0.23413178, 0.24035896, 0.2465637, 0.25274542, 0.25890356, 0.26503757, 0.27114683, 0.27723086, 0.28328907, 0.28932092, 0.29532582, 0.3013033, 0.30725273, 0.31317365, 0.3190655, 0.32492775, 0.33075988, 0.33656135, 0.34233165, 0.3480703, 0.35377672, 0.35945046, 0.365091, 0.37069786, 0.3762705, 0.38180846, 0.38731128, 0.39277846, 0.39820948, 0.40360394, 0.40896136, 0.41428122, 0.4195631, 0.4248066, 0.4300112, 0.4351765, 0.440302, 0.44538733, 0.45043206, 0.45543572, 0.46039796, 0.46531832, 0.4701964, 0.4750318, 0.47982413, 0.484573, 0.48927805, 0.49393883, 0.49855503, 0.50312626, 0.50765216, 0.51213235, 0.5165665, 0.5209543, 0.5252954, 0.52958935, 0.53383595, 0.53803486, 0.5421857, 0.54628825, 0.55034214, 0.5543471, 0.55830276, 0.562209, 0.5660654, 0.5698717, 0.5736277, 0.5773331, 0.58098763, 0.58459103, 0.5881431, 0.59164363, 0.59509236, 0.598489, 0.6018334, 0.6051253, 0.60836464, 0.61155105, 0.6146844, 0.61776453, 0.62079126, 0.62376434, 0.6266837, 0.6295492, 0.6323606, 0.63511777, 0.63782066, 0.640469, 0.6430628, 0.64560187, 0.6480861, 0.6505154, 0.6528896, 0.65520877, 0.65747267, 0.6596813, 0.66183454, 0.6639324, 0.6659748, 0.6679616, 0.66989285, 0.6717685, 0.67358845, 0.67535275, 0.6770613, 0.6787142, 0.68031144, 0.6818529, 0.6833387, 0.6847688, 0.6861432, 0.68746203, 0.68872523, 0.6899328, 0.691085, 0.69218165, 0.6932229, 0.69420886, 0.6951395, 0.69601506, 0.69683546, 0.6976009, 0.69831145, 0.69896716, 0.6995682, 0.7001147, 0.7006068, 0.70104456, 0.7014282, 0.7017578, 0.7020335, 0.7022555, 0.70242393, 0.702539, 0.7026009, 0.7026097, 0.70256567, 0.70246905, 0.7023199, 0.7021186, 0.70186514, 0.7015599, 0.7012031, 0.7007949, 0.70033556, 0.69982535, 0.6992644, 0.6986531, 0.69799167, 0.69728035, 0.6965194, 0.69570905, 0.6948496, 0.6939415, 0.69298476, 0.6919798, 0.690927, 0.6898266, 0.68867886, 0.68748415, 0.6862427, 0.684955, 0.6836212, 0.68224174, 0.68081695, 0.6793471, 0.6778326, 0.6762738, 0.67467105, 0.6730247, 0.67133516, 0.6696027, 0.6678278, 0.66601074, 0.66415197, 0.66225183, 0.66031075, 0.65832907, 0.6563073, 0.6542457, 0.65214473, 0.65000486, 0.64782643, 0.64560986, 0.6433556, 0.6410641, 0.6387357, 0.63637084, 0.63397, 0.6315337, 0.6290622, 0.62655604, 0.6240156, 0.6214414, 0.6188339, 0.61619353, 0.6135207, 0.61081594, 0.6080796, 0.6053123, 0.6025144, 0.5996864, 0.5968287, 0.5939419, 0.5910264, 0.58808273, 0.58511126, 0.58211255, 0.57908714, 0.5760354, 0.5729578, 0.569855, 0.5667273, 0.56357527, 0.5603995, 0.55720025, 0.5539782, 0.5507338, 0.5474676, 0.54418, 0.54087144, 0.53754264, 0.53419393, 0.53082585, 0.52743894, 0.5240336, 0.52061045, 0.51716995, 0.51371264, 0.51023895, 0.5067494, 0.5032445, 0.49972478, 0.49619073, 0.49264285, 0.48908165, 0.48550764, 0.4819213, 0.47832313, 0.47471362, 0.47109333, 0.46746272, 0.4638223, 0.46017256, 0.456514, 0.45284712, 0.4491724, 0.4454904, 0.44180152, 0.4381063, 0.43440524, 0.4306988, 0.4269875, 0.4232718, 0.4195522, 0.4158292, 0.41210327, 0.40837488, 0.4046445, 0.4009126, 0.3971797, 0.39344624, 0.3897127, 0.38597953, 0.3822472, 0.37851623, 0.374787, 0.37105998, 0.36733568, 0.3636145, 0.35989693, 0.35618338, 0.35247433, 0.3487702, 0.3450714, 0.34137845, 0.3376917, 0.33401164, 0.33033866, 0.32667318, 0.32301566, 0.31936646, 0.31572604, 0.3120948, 0.30847314, 0.30486146, 0.30126017, 0.29766965, 0.2940903, 0.29052252, 0.28696668, 0.2834232, 0.27989236, 0.27637464, 0.27287033, 0.26937985, 0.26590353, 0.26244172, 0.2589948, 0.25556308, 0.25214696, 0.24874672, 0.24536273, 0.24199529, 0.23864476, 0.23531145, 0.23199566, 0.2286977, 0.22541791, 0.22215655, 0.21891396, 0.2156904, 0.21248618, 0.20930156, 0.20613684, 0.20299228, 0.19986816, 0.19676472, 0.19368224, 0.19062096, 0.18758114, 0.18456301, 0.18156682, 0.1785928, 0.17564118, 0.17271216, 0.16980599, 0.16692285, 0.16406298, 0.16122656, 0.15841377, 0.15562482, 0.15285991, 0.1501192, 0.14740285, 0.14471106, 0.14204398, 0.13940176, 0.13678457, 0.13419254, 0.13162582, 0.12908453, 0.12656881, 0.1240788, 0.1216146, 0.11917633, 0.1167641, 0.114378005, 0.112018146, 0.10968462, 0.10737751, 0.10509688, 0.10284282, 0.100615405, 0.09841468, 0.09624072, 0.09409357, 0.09197327, 0.08987986, 0.08781339, 0.08577388, 0.08376135, 0.08177583, 0.079817325, 0.07788584, 0.075981386, 0.074103944, 0.072253525, 0.0704301, 0.06863365, 0.066864155, 0.06512158, 0.063405894, 0.06171705, 0.060054995, 0.058419686, 0.05681106, 0.055229057, 0.053673603, 0.052144628, 0.050642047, 0.04916578, 0.047715742, 0.04629183, 0.04489394, 0.043521974, 0.042175822, 0.040855363, 0.039560482, 0.03829105, 0.03704693, 0.035827994, 0.034634102, 0.033465106, 0.03232085, 0.031201186, 0.03010595, 0.029034978, 0.0279881, 0.026965141, 0.025965922, 0.024990259, 0.024037965, 0.023108844, 0.022202702, 0.021319335, 0.020458538, 0.019620098, 0.018803803, 0.018009432, 0.017236762, 0.016485564, 0.015755609, 0.015046658, 0.014358472, 0.013690809, 0.013043418, 0.012416049, 0.011808446, 0.011220349, 0.010651496, 0.010101619, 0.009570449, 0.00905771, 0.008563125, 0.008086413, 0.00762729, 0.007185468, 0.006760656, 0.0063525587, 0.0059608794, 0.005585317, 0.0052255676, 0.004881325, 0.004552279, 0.004238117, 0.003938524, 0.0036531815, 0.0033817682, 0.0031239612, 0.0028794338, 0.0026478579, 0.0024289018, 0.0022222325, 0.002027514, 0.001844408, 0.0016725746, 0.001511671, 0.001361353, 0.0012212739, 0.0010910855, 0.0009704373, 0.0008589774, 0.00075635174, 0.00066220504, 0.0005761801, 0.0004979183, 0.00042705954, 0.00036324238, 0.00030610393, 0.00025528015, 0.00021040578, 0.0001711144, 0.0001370386, 0.000107809945, 8.3059094e-5, 6.2415864e-5, 4.5509307e-5, 3.1967757e-5, 2.1418922e-5, 1.3489952e-5, 7.807494e-6, 3.9977754e-6, 1.6866726e-6, 4.9977837e-7, 6.247406e-8, 0.0, -6.247406e-8, -4.9977837e-7, -1.6866726e-6, -3.9977754e-6, -7.807494e-6, -1.3489952e-5, -2.1418922e-5, -3.1967757e-5, -4.5509307e-5, -6.2415864e-5, -8.3059094e-5, -0.000107809945, -0.0001370386, -0.0001711144, -0.00021040578, -0.00025528015, -0.00030610393, -0.00036324238, -0.00042705954, -0.0004979183, -0.0005761801, -0.00066220504, -0.00075635174, -0.0008589774, -0.0009704373, -0.0010910855, -0.0012212739, -0.001361353, -0.001511671, -0.0016725746, -0.001844408, -0.002027514, -0.0022222325, -0.0024289018, -0.0026478579, -0.0028794338, -0.0031239612, -0.0033817682, -0.0036531815, -0.003938524, -0.004238117, -0.004552279, -0.004881325, -0.0052255676, -0.005585317, -0.0059608794, -0.0063525587, -0.006760656, -0.007185468, -0.00762729, -0.008086413, -0.008563125, -0.00905771, -0.009570449, -0.010101619, -0.010651496, -0.011220349, -0.011808446, -0.012416049, -0.013043418, -0.013690809, -0.014358472, -0.015046658, -0.015755609, -0.016485564, -0.017236762, -0.018009432, -0.018803803, -0.019620098, -0.020458538, -0.021319335, -0.022202702, -0.023108844, -0.024037965, -0.024990259, -0.025965922, -0.026965141, -0.0279881, -0.029034978, -0.03010595, -0.031201186, -0.03232085, -0.033465106, -0.034634102, -0.035827994, -0.03704693, -0.03829105, -0.039560482, -0.040855363, -0.042175822, -0.043521974, -0.04489394, -0.04629183, -0.047715742, -0.04916578, -0.050642047, -0.052144628, -0.053673603, -0.055229057, -0.05681106, -0.058419686, -0.060054995, -0.06171705, -0.063405894, -0.06512158, -0.066864155, -0.06863365, -0.0704301, -0.072253525, -0.074103944, -0.075981386, -0.07788584, -0.079817325, -0.08177583, -0.08376135, -0.08577388, -0.08781339, -0.08987986, -0.09197327, -0.09409357, -0.09624072, -0.09841468, -0.100615405, -0.10284282, -0.10509688, -0.10737751, -0.10968462, -0.112018146, -0.114378005, -0.1167641, -0.11917633, -0.1216146, -0.1240788, -0.12656881, -0.12908453, -0.13162582, -0.13419254, -0.13678457, -0.13940176, -0.14204398, -0.14471106, -0.14740285, -0.1501192, -0.15285991, -0.15562482, -0.15841377, -0.16122656, -0.16406298, -0.16692285, -0.16980599, -0.17271216, -0.17564118, -0.1785928, -0.18156682, -0.18456301, -0.18758114, -0.19062096, -0.19368224, -0.19676472, -0.19986816, -0.20299228, -0.20613684, -0.20930156, -0.21248618, -0.2156904, -0.21891396, -0.22215655, -0.22541791, -0.2286977, -0.23199566, -0.23531145, -0.23864476, -0.24199529, -0.24536273, -0.24874672, -0.25214696, -0.25556308, -0.2589948, -0.26244172, -0.26590353, -0.26937985, -0.27287033, -0.27637464, -0.27989236, -0.2834232, -0.28696668, -0.29052252, -0.2940903, -0.29766965, -0.30126017, -0.30486146, -0.30847314, -0.3120948, -0.31572604, -0.31936646, -0.32301566, -0.32667318, -0.33033866, -0.33401164, -0.3376917, -0.34137845, -0.3450714, -0.3487702, -0.35247433, -0.35618338, -0.35989693, -0.3636145, -0.36733568, -0.37105998, -0.374787, -0.37851623, -0.3822472, -0.38597953, -0.3897127, -0.39344624, -0.3971797, -0.4009126, -0.4046445, -0.40837488, -0.41210327, -0.4158292, -0.4195522, -0.4232718, -0.4269875, -0.4306988, -0.43440524, -0.4381063, -0.44180152, -0.4454904, -0.4491724, -0.45284712, -0.456514, -0.46017256, -0.4638223, -0.46746272, -0.47109333, -0.47471362, -0.47832313, -0.4819213, -0.48550764, -0.48908165, -0.49264285, -0.49619073, -0.49972478, -0.5032445, -0.5067494, -0.51023895, -0.51371264, -0.51716995, -0.52061045, -0.5240336, -0.52743894, -0.53082585, -0.53419393, -0.53754264, -0.54087144, -0.54418, -0.5474676, -0.5507338, -0.5539782, -0.55720025, -0.5603995, -0.56357527, -0.5667273, -0.569855, -0.5729578, -0.5760354, -0.57908714, -0.58211255, -0.58511126, -0.58808273, -0.5910264, -0.5939419, -0.5968287, -0.5996864, -0.6025144, -0.6053123, -0.6080796, -0.61081594, -0.6135207, -0.61619353, -0.6188339, -0.6214414, -0.6240156, -0.62655604, -0.6290622, -0.6315337, -0.63397, -0.63637084, -0.6387357, -0.6410641, -0.6433556, -0.64560986, -0.64782643, -0.65000486, -0.65214473, -0.6542457, -0.6563073, -0.65832907, -0.66031075, -0.66225183, -0.66415197, -0.66601074, -0.6678278, -0.6696027, -0.67133516, -0.6730247, -0.67467105, -0.6762738, -0.6778326, -0.6793471, -0.68081695, -0.68224174, -0.6836212, -0.684955, -0.6862427, -0.68748415, -0.68867886, -0.6898266, -0.690927, -0.6919798, -0.69298476, -0.6939415, -0.6948496, -0.69570905, -0.6965194, -0.69728035, -0.69799167, -0.6986531, -0.6992644, -0.69982535, -0.70033556, -0.7007949, -0.7012031, -0.7015599, -0.70186514, -0.7021186, -0.7023199, -0.70246905, -0.70256567, -0.7026097, -0.7026009, -0.702539, -0.70242393, -0.7022555, -0.7020335, -0.7017578, -0.7014282, -0.70104456, -0.7006068, -0.7001147, -0.6995682, -0.69896716, -0.69831145, -0.6976009, -0.69683546, -0.69601506, -0.6951395, -0.69420886, -0.6932229, -0.69218165, -0.691085, -0.6899328, -0.68872523, -0.68746203, -0.6861432, -0.6847688, -0.6833387, -0.6818529, -0.68031144, -0.6787142, -0.6770613, -0.67535275, -0.67358845, -0.6717685, -0.66989285, -0.6679616, -0.6659748, -0.6639324, -0.66183454, -0.6596813, -0.65747267, -0.65520877, -0.6528896, -0.6505154, -0.6480861, -0.64560187, -0.6430628, -0.640469, -0.63782066, -0.63511777, -0.6323606, -0.6295492, -0.6266837, -0.62376434, -0.62079126, -0.61776453, -0.6146844, -0.61155105, -0.60836464, -0.6051253, -0.6018334, -0.598489, -0.59509236, -0.59164363, -0.5881431, -0.58459103, -0.58098763, -0.5773331, -0.5736277, -0.5698717, -0.5660654, -0.562209, -0.55830276, -0.5543471, -0.55034214, -0.54628825, -0.5421857, -0.53803486, -0.53383595, -0.52958935, -0.5252954, -0.5209543, -0.5165665, -0.51213235, -0.50765216, -0.50312626, -0.49855503, -0.49393883, -0.48927805, -0.484573, -0.47982413, -0.4750318, -0.4701964, -0.46531832, -0.46039796, -0.45543572, -0.45043206, -0.44538733, -0.440302, -0.4351765, -0.4300112, -0.4248066, -0.4195631, -0.41428122, -0.40896136, -0.40360394, -0.39820948, -0.39277846, -0.38731128, -0.38180846, -0.3762705, -0.37069786, -0.365091, -0.35945046, -0.35377672, -0.3480703, -0.34233165, -0.33656135, -0.33075988, -0.32492775, -0.3190655, -0.31317365, -0.30725273, -0.3013033, -0.29532582, -0.28932092, -0.28328907, -0.27723086, -0.27114683, -0.26503757, -0.25890356, -0.25274542, -0.2465637, -0.24035896, -0.23413178, -0.22788273, -0.22161236, -0.21532129, -0.20901006, -0.20267929, -0.19632953, -0.18996139, -0.18357547, -0.17717233, -0.17075258, -0.16431683, -0.15786567, -0.1513997, -0.14491953, -0.13842574, -0.13191897, -0.1253998, -0.11886885, -0.11232674, -0.10577407, -0.09921146, -0.09263952, -0.08605888, -0.079470135, -0.07287393, -0.06627086, -0.059661556, -0.053046644, -0.046426736, -0.03980246, -0.033174444, -0.026543302, -0.019909663, -0.01327415, -0.0066373874, ], [0.0, 0.003318725, 0.006637325, 0.009955675, 0.01327365, 0.016591126, 0.019907977, 0.023224078, 0.026539305, 0.029853532, 0.033166636, 0.036478493, 0.039788973, 0.043097958, 0.04640532, 0.049710933, 0.053014673, 0.05631642, 0.059616048, 0.062913425, 0.066208445, 0.06950096, 0.07279087, 0.07607803, 0.079362325, 0.082643636, 0.08592184, 0.0891968, 0.09246841, 0.09573653, 0.09900105, 0.10226185, 0.10551879, 0.10877176, 0.112020634, 0.115265295, 0.11850561, 0.121741466, 0.12497274, 0.12819931, 0.13142104, 0.13463783, 0.13784957, 0.14105609, 0.14425732, 0.14745311, 0.15064335, 0.15382792, 0.1570067, 0.16017957, 0.1633464, 0.16650708, 0.1696615, 0.17280953, 0.17595105, 0.17908596, 0.18221411, 0.18533541, 0.18844973, 0.19155695, 0.19465695, 0.19774964, 0.20083487, 0.20391256, 0.20698255, 0.21004476, 0.21309905, 0.21614532, 0.21918346, 0.22221334, 0.22523487, 0.22824791, 0.23125236, 0.23424809, 0.23723501, 0.24021299, 0.24318194, 0.24614173, 0.24909225, 0.25203338, 0.25496504, 0.2578871, 0.26079944, 0.26370198, 0.26659456, 0.26947713, 0.27234954, 0.2752117, 0.2780635, 0.28090483, 0.2837356, 0.28655568, 0.28936493, 0.29216334, 0.29495072, 0.29772702, 0.30049208, 0.30324584, 0.3059882, 0.308719, 0.31143823, 0.31414568, 0.31684133, 0.31952506, 0.32219675, 0.3248563, 0.32750365, 0.33013865, 0.33276123, 0.33537126, 0.33796868, 0.34055337, 0.34312522, 0.34568417, 0.34823012, 0.35076293, 0.35328254, 0.3557889, 0.3582818, 0.36076123, 0.36322707, 0.36567926, 0.36811766, 0.3705422, 0.37295282, 0.37534937, 0.3777318, 0.38009998, 0.3824539, 0.38479337, 0.38711837, 0.38942882, 0.3917246, 0.3940056, 0.3962718, 0.39852306, 0.4007593, 0.4029805, 0.4051865, 0.40737724, 0.40955266, 0.41171265, 0.41385713, 0.41598606, 0.4180993, 0.4201968, 0.4222785, 0.42434427, 0.4263941, 0.42842785, 0.43044546, 0.4324469, 0.43443203, 0.43640083, 0.43835318, 0.44028902, 0.4422083, 0.44411093, 0.44599682, 0.44786593, 0.44971818, 0.45155352, 0.45337182, 0.45517308, 0.4569572, 0.4587241, 0.46047375, 0.46220604, 0.46392092, 0.46561837, 0.46729827, 0.46896058, 0.47060522, 0.47223216, 0.4738413, 0.47543263, 0.47700605, 0.47856152, 0.48009893, 0.48161831, 0.48311955, 0.48460257, 0.48606738, 0.48751387, 0.48894203, 0.49035174, 0.49174303, 0.49311578, 0.49446997, 0.49580556, 0.49712247, 0.49842066, 0.49970007, 0.5009607, 0.50220245, 0.5034253, 0.5046292, 0.5058141, 0.50697994, 0.5081267, 0.50925434, 0.5103628, 0.511452, 0.512522, 0.5135727, 0.51460403, 0.51561606, 0.5166086, 0.5175817, 0.5185354, 0.51946944, 0.520384, 0.521279, 0.52215433, 0.52300996, 0.523846, 0.52466226, 0.52545875, 0.52623546, 0.5269924, 0.52772945, 0.5284467, 0.5291439, 0.52982134, 0.5304788, 0.53111625, 0.5317337, 0.53233117, 0.53290856, 0.5334659, 0.53400314, 0.5345203, 0.5350174, 0.53549427, 0.53595096, 0.5363875, 0.53680384, 0.53720003, 0.5375759, 0.5379316, 0.538267, 0.5385822, 0.53887707, 0.53915167, 0.53940594, 0.53963995, 0.53985363, 0.54004693, 0.54021996, 0.5403726, 0.540505, 0.5406169, 0.54070854, 0.5407798, 0.54083073, 0.5408613, 0.54087144, 0.5408613, 0.54083073, 0.5407798, 0.54070854, 0.5406169, 0.540505, 0.5403726, 0.54021996, 0.54004693, 0.53985363, 0.53963995, 0.53940594, 0.53915167, 0.53887707, 0.5385822, 0.538267, 0.5379316, 0.5375759, 0.53720003, 0.53680384, 0.5363875, 0.53595096, 0.53549427, 0.5350174, 0.5345203, 0.53400314, 0.5334659, 0.53290856, 0.53233117, 0.5317337, 0.53111625, 0.5304788, 0.52982134, 0.5291439, 0.5284467, 0.52772945, 0.5269924, 0.52623546, 0.52545875, 0.52466226, 0.523846, 0.52300996, 0.52215433, 0.521279, 0.520384, 0.51946944, 0.5185354, 0.5175817, 0.5166086, 0.51561606, 0.51460403, 0.5135727, 0.512522, 0.511452, 0.5103628, 0.50925434, 0.5081267, 0.50697994, 0.5058141, 0.5046292, 0.5034253, 0.50220245, 0.5009607, 0.49970007, 0.49842066, 0.49712247, 0.49580556, 0.49446997, 0.49311578, 0.49174303, 0.49035174, 0.48894203, 0.48751387, 0.48606738, 0.48460257, 0.48311955, 0.48161831, 0.48009893, 0.47856152, 0.47700605, 0.47543263, 0.4738413, 0.47223216, 0.47060522, 0.46896058, 0.46729827, 0.46561837, 0.46392092, 0.46220604, 0.46047375, 0.4587241, 0.4569572, 0.45517308, 0.45337182, 0.45155352, 0.44971818, 0.44786593, 0.44599682, 0.44411093, 0.4422083, 0.44028902, 0.43835318, 0.43640083, 0.43443203, 0.4324469, 0.43044546, 0.42842785, 0.4263941, 0.42434427, 0.4222785, 0.4201968, 0.4180993, 0.41598606, 0.41385713, 0.41171265, 0.40955266, 0.40737724, 0.4051865, 0.4029805, 0.4007593, 0.39852306, 0.3962718, 0.3940056, 0.3917246, 0.38942882, 0.38711837, 0.38479337, 0.3824539, 0.38009998, 0.3777318, 0.37534937, 0.37295282, 0.3705422, 0.36811766, 0.36567926, 0.36322707, 0.36076123, 0.3582818, 0.3557889, 0.35328254, 0.35076293, 0.34823012, 0.34568417, 0.34312522, 0.34055337, 0.33796868, 0.33537126, 0.33276123, 0.33013865, 0.32750365, 0.3248563, 0.32219675, 0.31952506, 0.31684133, 0.31414568, 0.31143823, 0.308719, 0.3059882, 0.30324584, 0.30049208, 0.29772702, 0.29495072, 0.29216334, 0.28936493, 0.28655568, 0.2837356, 0.28090483, 0.2780635, 0.2752117, 0.27234954, 0.26947713, 0.26659456, 0.26370198, 0.26079944, 0.2578871, 0.25496504, 0.25203338, 0.24909225, 0.24614173, 0.24318194, 0.24021299, 0.23723501, 0.23424809, 0.23125236, 0.22824791, 0.22523487, 0.22221334, 0.21918346, 0.21614532, 0.21309905, 0.21004476, 0.20698255, 0.20391256, 0.20083487, 0.19774964, 0.19465695, 0.19155695, 0.18844973, 0.18533541, 0.18221411, 0.17908596, 0.17595105, 0.17280953, 0.1696615, 0.16650708, 0.1633464, 0.16017957, 0.1570067, 0.15382792, 0.15064335, 0.14745311, 0.14425732, 0.14105609, 0.13784957, 0.13463783, 0.13142104, 0.12819931, 0.12497274, 0.121741466, 0.11850561, 0.115265295, 0.112020634, 0.10877176, 0.10551879, 0.10226185, 0.09900105, 0.09573653, 0.09246841, 0.0891968, 0.08592184, 0.082643636, 0.079362325, 0.07607803, 0.07279087, 0.06950096, 0.066208445, 0.062913425, 0.059616048, 0.05631642, 0.053014673, 0.049710933, 0.04640532, 0.043097958, 0.039788973, 0.036478493, 0.033166636, 0.029853532, 0.026539305, 0.023224078, 0.019907977, 0.016591126, 0.01327365, 0.009955675, 0.006637325, 0.003318725, 6.623765e-17, -0.003318725, -0.006637325, -0.009955675, -0.01327365, -0.016591126, -0.019907977, -0.023224078, -0.026539305, -0.029853532, -0.033166636, -0.036478493, -0.039788973, -0.043097958, -0.04640532, -0.049710933, -0.053014673, -0.05631642, -0.059616048, -0.062913425, -0.066208445, -0.06950096, -0.07279087, -0.07607803, -0.079362325, -0.082643636, -0.08592184, -0.0891968, -0.09246841, -0.09573653, -0.09900105, -0.10226185, -0.10551879, -0.10877176, -0.112020634, -0.115265295, -0.11850561, -0.121741466, -0.12497274, -0.12819931, -0.13142104, -0.13463783, -0.13784957, -0.14105609, -0.14425732, -0.14745311, -0.15064335, -0.15382792, -0.1570067, -0.16017957, -0.1633464, -0.16650708, -0.1696615, -0.17280953, -0.17595105, -0.17908596, -0.18221411, -0.18533541, -0.18844973, -0.19155695, -0.19465695, -0.19774964, -0.20083487, -0.20391256, -0.20698255, -0.21004476, -0.21309905, -0.21614532, -0.21918346, -0.22221334, -0.22523487, -0.22824791, -0.23125236, -0.23424809, -0.23723501, -0.24021299, -0.24318194, -0.24614173, -0.24909225, -0.25203338, -0.25496504, -0.2578871, -0.26079944, -0.26370198, -0.26659456, -0.26947713, -0.27234954, -0.2752117, -0.2780635, -0.28090483, -0.2837356, -0.28655568, -0.28936493, -0.29216334, -0.29495072, -0.29772702, -0.30049208, -0.30324584, -0.3059882, -0.308719, -0.31143823, -0.31414568, -0.31684133, -0.31952506, -0.32219675, -0.3248563, -0.32750365, -0.33013865, -0.33276123, -0.33537126, -0.33796868, -0.34055337, -0.34312522, -0.34568417, -0.34823012, -0.35076293, -0.35328254, -0.3557889, -0.3582818, -0.36076123, -0.36322707, -0.36567926, -0.36811766, -0.3705422, -0.37295282, -0.37534937, -0.3777318, -0.38009998, -0.3824539, -0.38479337, -0.38711837, -0.38942882, -0.3917246, -0.3940056, -0.3962718, -0.39852306, -0.4007593, -0.4029805, -0.4051865, -0.40737724, -0.40955266, -0.41171265, -0.41385713, -0.41598606, -0.4180993, -0.4201968, -0.4222785, -0.42434427, -0.4263941, -0.42842785, -0.43044546, -0.4324469, -0.43443203, -0.43640083, -0.43835318, -0.44028902, -0.4422083, -0.44411093, -0.44599682, -0.44786593, -0.44971818, -0.45155352, -0.45337182, -0.45517308, -0.4569572, -0.4587241, -0.46047375, -0.46220604, -0.46392092, -0.46561837, -0.46729827, -0.46896058, -0.47060522, -0.47223216, -0.4738413, -0.47543263, -0.47700605, -0.47856152, -0.48009893, -0.48161831, -0.48311955, -0.48460257, -0.48606738, -0.48751387, -0.48894203, -0.49035174, -0.49174303, -0.49311578, -0.49446997, -0.49580556, -0.49712247, -0.49842066, -0.49970007, -0.5009607, -0.50220245, -0.5034253, -0.5046292, -0.5058141, -0.50697994, -0.5081267, -0.50925434, -0.5103628, -0.511452, -0.512522, -0.5135727, -0.51460403, -0.51561606, -0.5166086, -0.5175817, -0.5185354, -0.51946944, -0.520384, -0.521279, -0.52215433, -0.52300996, -0.523846, -0.52466226, -0.52545875, -0.52623546, -0.5269924, -0.52772945, -0.5284467, -0.5291439, -0.52982134, -0.5304788, -0.53111625, -0.5317337, -0.53233117, -0.53290856, -0.5334659, -0.53400314, -0.5345203, -0.5350174, -0.53549427, -0.53595096, -0.5363875, -0.53680384, -0.53720003, -0.5375759, -0.5379316, -0.538267, -0.5385822, -0.53887707, -0.53915167, -0.53940594, -0.53963995, -0.53985363, -0.54004693, -0.54021996, -0.5403726, -0.540505, -0.5406169, -0.54070854, -0.5407798, -0.54083073, -0.5408613, -0.54087144, -0.5408613, -0.54083073, -0.5407798, -0.54070854, -0.5406169, -0.540505, -0.5403726, -0.54021996, -0.54004693, -0.53985363, -0.53963995, -0.53940594, -0.53915167, -0.53887707, -0.5385822, -0.538267, -0.5379316, -0.5375759, -0.53720003, -0.53680384, -0.5363875, -0.53595096, -0.53549427, -0.5350174, -0.5345203, -0.53400314, -0.5334659, -0.53290856, -0.53233117, -0.5317337, -0.53111625, -0.5304788, -0.52982134, -0.5291439, -0.5284467, -0.52772945, -0.5269924, -0.52623546, -0.52545875, -0.52466226, -0.523846, -0.52300996, -0.52215433, -0.521279, -0.520384, -0.51946944, -0.5185354, -0.5175817, -0.5166086, -0.51561606, -0.51460403, -0.5135727, -0.512522, -0.511452, -0.5103628, -0.50925434, -0.5081267, -0.50697994, -0.5058141, -0.5046292, -0.5034253, -0.50220245, -0.5009607, -0.49970007, -0.49842066, -0.49712247, -0.49580556, -0.49446997, -0.49311578, -0.49174303, -0.49035174, -0.48894203, -0.48751387, -0.48606738, -0.48460257, -0.48311955, -0.48161831, -0.48009893, -0.47856152, -0.47700605, -0.47543263, -0.4738413, -0.47223216, -0.47060522, -0.46896058, -0.46729827, -0.46561837, -0.46392092, -0.46220604, -0.46047375, -0.4587241, -0.4569572, -0.45517308, -0.45337182, -0.45155352, -0.44971818, -0.44786593, -0.44599682, -0.44411093, -0.4422083, -0.44028902, -0.43835318, -0.43640083, -0.43443203, -0.4324469, -0.43044546, -0.42842785, -0.4263941, -0.42434427, -0.4222785, -0.4201968, -0.4180993, -0.41598606, -0.41385713, -0.41171265, -0.40955266, -0.40737724, -0.4051865, -0.4029805, -0.4007593, -0.39852306, -0.3962718, -0.3940056, -0.3917246, -0.38942882, -0.38711837, -0.38479337, -0.3824539, -0.38009998, -0.3777318, -0.37534937, -0.37295282, -0.3705422, -0.36811766, -0.36567926, -0.36322707, -0.36076123, -0.3582818, -0.3557889, -0.35328254, -0.35076293, -0.34823012, -0.34568417, -0.34312522, -0.34055337, -0.33796868, -0.33537126, -0.33276123, -0.33013865, -0.32750365, -0.3248563, -0.32219675, -0.31952506, -0.31684133, -0.31414568, -0.31143823, -0.308719, -0.3059882, -0.30324584, -0.30049208, -0.29772702, -0.29495072, -0.29216334, -0.28936493, -0.28655568, -0.2837356, -0.28090483, -0.2780635, -0.2752117, -0.27234954, -0.26947713, -0.26659456, -0.26370198, -0.26079944, -0.2578871, -0.25496504, -0.25203338, -0.24909225, -0.24614173, -0.24318194, -0.24021299, -0.23723501, -0.23424809, -0.23125236, -0.22824791, -0.22523487, -0.22221334, -0.21918346, -0.21614532, -0.21309905, -0.21004476, -0.20698255, -0.20391256, -0.20083487, -0.19774964, -0.19465695, -0.19155695, -0.18844973, -0.18533541, -0.18221411, -0.17908596, -0.17595105, -0.17280953, -0.1696615, -0.16650708, -0.1633464, -0.16017957, -0.1570067, -0.15382792, -0.15064335, -0.14745311, -0.14425732, -0.14105609, -0.13784957, -0.13463783, -0.13142104, -0.12819931, -0.12497274, -0.121741466, -0.11850561, -0.115265295, -0.112020634, -0.10877176, -0.10551879, -0.10226185, -0.09900105, -0.09573653, -0.09246841, -0.0891968, -0.08592184, -0.082643636, -0.079362325, -0.07607803, -0.07279087, -0.06950096, -0.066208445, -0.062913425, -0.059616048, -0.05631642, -0.053014673, -0.049710933, -0.04640532, -0.043097958, -0.039788973, -0.036478493, -0.033166636, -0.029853532, -0.026539305, -0.023224078, -0.019907977, -0.016591126, -0.01327365, -0.009955675, -0.006637325, -0.003318725, ], ];
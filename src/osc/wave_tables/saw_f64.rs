@@ -0,0 +1,2 @@
+#[allow(clippy::approx_constant)]
+pub static SAW_F64: [[f64; 1024]; 10] = [[0.0, 0.9999999999999981, 0.7637200602243935, 0.9008511346151458, 0.8004321025866225, 0.8754654006786345, 0.811108261845836, 0.8624751557731393, 0.8148745651092693, 0.8537419495106258, 0.8158285915858802, 0.8469652570895849, 0.8153658854754401, 0.8412477195029955, 0.8140904143833043, 0.8361675863544149, 0.8123057755414743, 0.8315006585508818, 0.8101811814665572, 0.8271167984088448, 0.8078183754335421, 0.8229353029581944, 0.8052821983306914, 0.8189034744314997, 0.8026159232385688, 0.8149854456724561, 0.7998495321156447, 0.8111559594313913, 0.7970044536044163, 0.8073967162080631, 0.7940964094880761, 0.8036941332200699, 0.7911371955620778, 0.8000379169837489, 0.7881358349064799, 0.796420124607072, 0.7850993472424771, 0.7928345291492616, 0.7820332756192744, 0.7892761800379438, 0.7789420552605536, 0.7857410920169215, 0.7758292771255723, 0.7822260208214862, 0.7726978796511593, 0.7787282986251017, 0.7695502905128044, 0.7752457114686738, 0.7663885329704627, 0.7717764066868606, 0.7632143067078889, 0.7683188221029389, 0.7600290500278345, 0.7648716312458431, 0.7568339882336598, 0.761433700513579, 0.7536301716487411, 0.7580040553504194, 0.7504185057734944, 0.7545818533002063, 0.7471997754136233, 0.7511663623584011, 0.7439746641403289, 0.7477569434459066, 0.740743770103098, 0.7443530361173039, 0.7375076189684854, 0.7409541468279127, 0.7342666745763425, 0.7375598392406929, 0.731021347769858, 0.7341697261709256, 0.7277720037543447, 0.7307834628546565, 0.7245189682630632, 0.7274007412938949, 0.7212625327497484, 0.7240212854828615, 0.718002958782489, 0.7206448473591679, 0.7147404817786773, 0.7172712033547016, 0.7114753141935184, 0.7139001514450466, 0.7082076482531222, 0.7105315086153808, 0.7049376583063302, 0.707165108675829, 0.7016655028558801, 0.7038008003713278, 0.6983913263188215, 0.7004384457406732, 0.6951152605573301, 0.6970779186872876, 0.6918374262141667, 0.6937191037304792, 0.6885579338811885, 0.6903618949111637, 0.6852768851248312, 0.6870061948301993, 0.6819943733885484, 0.6836519138009806, 0.6787104847890831, 0.6802989691007149, 0.6754252988208845, 0.6769472843072833, 0.6721388889807965, 0.673596788710442, 0.6688513233233239, 0.6702474167878508, 0.6655626649553604, 0.6668991077377392, 0.6622729724778955, 0.6635518050611864, 0.6589823003812365, 0.6602054561879881, 0.6556906993993529, 0.6568600121408924, 0.6523982168281948, 0.6535154272336934, 0.649104896812163, 0.6501716587992593, 0.6458107806024331, 0.6468286669440922, 0.6425159067902567, 0.6434864143264456, 0.6392203115180697, 0.6401448659554047, 0.635924028670785, 0.6368039890086697, 0.6326270900494556, 0.6334637526670057, 0.6293295255291246, 0.630124127963637, 0.6260313632025735, 0.6267850876470479, 0.6227326295113491, 0.6234466060557543, 0.619433349365441, 0.6201086590039322, 0.6161335462526563, 0.6167712236767614, 0.6128332423387925, 0.613434278534537, 0.6095324585594344, 0.610097803224732, 0.6062312147042275, 0.6067617785012382, 0.6029295294943051, 0.6034261861500945, 0.5996274206535273, 0.6000910089211469, 0.5963249049740922, 0.5967562304650295, 0.5930219983770503, 0.5934218352750591, 0.5897187159681354, 0.5900878086335516, 0.5864150720893874, 0.5867541365621836, 0.5831110803668811, 0.5834208057760816, 0.5798067537549308, 0.5800878036412547, 0.576502104577057, 0.576755118135154, 0.5731971445639961, 0.5734227378100406, 0.5698918848889816, 0.5700906517589767, 0.5665863362005626, 0.5667588495841872, 0.5632805086531026, 0.5634273213676217, 0.5599744119351925, 0.5600960576435314, 0.5566680552961194, 0.5567650493729157, 0.5533614475705623, 0.5534342879196604, 0.5500545972016297, 0.5501037650282861, 0.5467475122623812, 0.5467734728031266, 0.5434402004759473, 0.5434434036888792, 0.5401326692343532, 0.5401135504523933, 0.5368249256161396, 0.5367839061656157, 0.533516976402867, 0.5334544641896091, 0.5302088280945932, 0.5301252181595635, 0.5269004869243976, 0.5267961619707261, 0.5235919588720065, 0.523467289765196, 0.5202832496765968, 0.5201385959195131, 0.5169743648488363, 0.5168100750329727, 0.5136653096822015, 0.5134817219166556, 0.5103560892636421, 0.5101535315830849, 0.5070467084836033, 0.5068254992364799, 0.5037371720454913, 0.5034976202635792, 0.5004274844745855, 0.5001698902249802, 0.4971176501264545, 0.4968423048469654, 0.4938076731948949, 0.4935148600137879, 0.4904975577194276, 0.49018755176037954, 0.48718730759238604, 0.48686037626546363, 0.4838769265656208, 0.4835333298450367, 0.4805664182568302, 0.4802064089462019, 0.47725578615556874, 0.4768796101413312, 0.4739450336289182, 0.47355293012254085, 0.4706341639268749, 0.4702263656964462, 0.46732318018745217, 0.46689991377919854, 0.4640120854415147, 0.46357357139177036, 0.4607008826173686, 0.460247335655487, 0.4573895745451183, 0.4569212037877781, 0.45407816396080614, 0.45359517309815106, 0.45076665351033807, 0.4502692409843498, 0.44745504575322353, 0.44694340492872386, 0.44414334316612497, 0.44361766249475365, 0.4408315481462402, 0.4402920113237645, 0.43751966301451123, 0.43696644913177796, 0.4342076900186918, 0.4336409737065321, 0.4308956313362664, 0.43031558290463223, 0.427583489077217, 0.4269902746488412, 0.42427126528668485, 0.4236650469254863, 0.4209589619474884, 0.4203398977820024, 0.41764658098253155, 0.41701482532457046, 0.4143341242571049, 0.413689827715877, 0.4110215935810827, 0.4103649031729674, 0.40770899071101285, 0.4070400499651958, 0.4043963173521274, 0.40371526641226857, 0.4010835751602465, 0.4003905508823816, 0.39777076574361325, 0.397065901790414, 0.3944578906646497, 0.3937413175962339, 0.3911449514416012, 0.3904167968030562, 0.3878319495501856, 0.387092337955871, 0.38451888642508564, 0.38376793963995215, 0.3812057634614361, 0.3804436004794056, 0.37789258201622095, 0.3771193191358143, 0.3745793434096269, 0.3737950943069007, 0.37126604892633697, 0.37047092472526516, 0.36795269981676276, 0.36714680915719, 0.3646392972982379, 0.36382274640144935, 0.3613258425561415, 0.3604987352882152, 0.35801233674502286, 0.35717477467797254, 0.35469878098962365, 0.3538508634604965, 0.3513851763858967, 0.3505270005538676, 0.3480715240019691, 0.3472031849035143, 0.34475782487908185, 0.34387941548130374, 0.3414440800324734, 0.34055569128467433, 0.3381302904522478, 0.337232011335778, 0.3348164571041927, 0.3339083746806841, 0.33150258093058427, 0.3305847803885851, 0.3281886628509353, 0.3272612275510625, 0.32487470376276856, 0.32393771528134896, 0.3215607045422659, 0.3206142427136431, 0.3182466660450019, 0.31729080900243994, 0.31493258910658567, 0.31396741332187733, 0.31161847454328295, 0.3106440548651259, 0.3083043231526366, 0.30732073284378036, 0.304990135714059, 0.30399744648728944, 0.301675912989386, 0.3006741950423916, 0.2983616557234294, 0.2973509777725855, 0.29504736464451303, 0.29402779395760925, 0.2917330404649638, 0.290704642892936, 0.28841868388162345, 0.28738152388929955, 0.2851042955763069, 0.28405843627222166, 0.2817898762162641, 0.28073537938156284, 0.27847542645463097, 0.27741235257109403, 0.27516094693084187, 0.2740893552080665, 0.27184643827105476, 0.2707663866728126, 0.2685319010885454, 0.26744344635835354, 0.26521733598408875, 0.26412053367002314, 0.26190274354634324, 0.26079764802508526, 0.2585881243522004, 0.25747478885240016, 0.2552734789671436, 0.2541519555920626, 0.25195880794557285, 0.2508291476950869, 0.24864411183115187, 0.24750636462306716, 0.2453293911570984, 0.24418360584787646, 0.24201464644651768, 0.24086087085136754, 0.23869987821268476, 0.23753815912507464, 0.23538508695932747, 0.23421547016992583, 0.2320702731809234, 0.23089280349597657, 0.22875543736295118, 0.22757015862214233, 0.22544057998216435, 0.2242475350759383, 0.2221257015068464, 0.2209249323932279, 0.2188108023970468, 0.21760235011798074, 0.21549588310483245, 0.21427978780203835, 0.21218094407451346, 0.2109572450048825, 0.2088659857428727, 0.20763472129341512, 0.20555100853937816, 0.20431221624174165, 0.2022360128864018, 0.20098972943095877, 0.19892099919942532, 0.19766726044895044, 0.1956059678872448, 0.19434480889020567, 0.1922909193521565, 0.1910223743555911, 0.18897585399015612, 0.18769995645219772, 0.1856607721911185, 0.18437755479314522, 0.182345674338975, 0.18105516899739765, 0.1790305608119051, 0.1777327986896025, 0.17571543198248205, 0.1744104434999148, 0.172400288217857, 0.1710881030638409, 0.16908512987991617, 0.16776577702206863, 0.16576995732543182, 0.16444346502031304, 0.16245477090622815, 0.16112116670918158, 0.15913957096931763, 0.15779888174400142, 0.1558243578570596, 0.15447660978469566, 0.1525091319072947, 0.15115435049562656, 0.14919389345348105, 0.1478321035454698, 0.1458786428248501, 0.144509868607076, 0.14256338034650398, 0.14118764535733888, 0.13924810633957976, 0.13786543347706243, 0.13593282112135802, 0.1345432326508534, 0.1326175250053879, 0.1312210425669761, 0.12930221830161343, 0.12789886291724709, 0.12598690131648935, 0.12457669339691436, 0.1226715743530923, 0.12125453370454192, 0.11935623771124819, 0.11793238354189797, 0.11604089168762435, 0.11461024261383997, 0.11272553657585369, 0.11128811062822182, 0.10941017266664216, 0.10796598729576762, 0.10609480024784129, 0.104643872329984, 0.10277941960461623, 0.10132176544705145, 0.09946403101947965, 0.09799966636571644, 0.09614863477242858, 0.09467757480721725, 0.09283323114103084, 0.0913554904951603, 0.08951782040052322, 0.0880334131554417, 0.08620240282390099, 0.0847113425161486, 0.08288697868201707, 0.08138927830746999, 0.07957154824367084, 0.07806722026159306, 0.0762561117756939, 0.07474516811264166, 0.07294066954304443, 0.07142312159656038, 0.06962522180888514, 0.06810108045104812, 0.06630976883468896, 0.06477904441545528, 0.06299431088029582, 0.06145701323071464, 0.05967884820401953, 0.05813498663924939, 0.05636338106272345, 0.05481296438489186, 0.05304790971189538, 0.05149094621281139, 0.04973243440573906, 0.04816893186941524, 0.046416955397247245, 0.0448469211022864, 0.043101472938287255, 0.04152491366009429, 0.03978598727966671, 0.03820290929252352, 0.036470498671228695, 0.03488090775019419, 0.03315500736191632, 0.03155890878457683, 0.029839513599853786, 0.028236912147929817, 0.026524017632426466, 0.024914917593213706, 0.02320851970634564, 0.02159292487402004, 0.019893020067734288, 0.018270933744492294, 0.016577518962198928, 0.014948943959258245, 0.013262016634901737, 0.011626955273346354, 0.009946513330637428, 0.008304967442120826, 0.00663100929390378, 0.00498298022120193, 0.003315504768981295, 0.0016609933663816953, -1.2847379435798652e-16, -0.0016609933663923864, -0.0033155047689814882, -0.004982980221201377, -0.006631009293903892, -0.008304967442119818, -0.009946513330637423, -0.011626955273345781, -0.013262016634902453, -0.014948943959256823, -0.01657751896219875, -0.018270933744492082, -0.019893020067734295, -0.02159292487401875, -0.023208519706345205, -0.024914917593215076, -0.02652401763242593, -0.02823691214792986, -0.029839513599853797, -0.031558908784575755, -0.03315500736191688, -0.03488090775019388, -0.03647049867122877, -0.038202909292527155, -0.03978598727966726, -0.041524913660093324, -0.04310147293828973, -0.0448469211022824, -0.046416955397249424, -0.04816893186941665, -0.049732434405736645, -0.05149094621280936, -0.053047909711895894, -0.054812964384893546, -0.056363381062721186, -0.05813498663924879, -0.059678848204015984, -0.061457013230715715, -0.06299431088029415, -0.06477904441545576, -0.06630976883468921, -0.06810108045104799, -0.06962522180888572, -0.07142312159656179, -0.07294066954304315, -0.07474516811264344, -0.07625611177569513, -0.0780672202615933, -0.07957154824367178, -0.08138927830746759, -0.08288697868201733, -0.08471134251614923, -0.08620240282390007, -0.08803341315544204, -0.08951782040052562, -0.0913554904951606, -0.09283323114103034, -0.0946775748072169, -0.0961486347724282, -0.09799966636571426, -0.09946403101948023, -0.10132176544704712, -0.10277941960461408, -0.1046438723299797, -0.10609480024784207, -0.10796598729576855, -0.10941017266663755, -0.11128811062822147, -0.11272553657585502, -0.11461024261384176, -0.11604089168762469, -0.11793238354189697, -0.11935623771124468, -0.12125453370454015, -0.12267157435309445, -0.1245766933969145, -0.12598690131649193, -0.12789886291724825, -0.12930221830161362, -0.1312210425669766, -0.1326175250053889, -0.1345432326508532, -0.13593282112136018, -0.13786543347706323, -0.13924810633958098, -0.14118764535733708, -0.1425633803465046, -0.14450986860707543, -0.14587864282485039, -0.14783210354547038, -0.14919389345348413, -0.15115435049562637, -0.15250913190729698, -0.1544766097846951, -0.15582435785705945, -0.1577988817440009, -0.15913957096931783, -0.16112116670918028, -0.16245477090622945, -0.16444346502031154, -0.1657699573254322, -0.16776577702206552, -0.1690851298799166, -0.1710881030638404, -0.1724002882178589, -0.174410443499914, -0.17571543198248202, -0.17773279868960207, -0.17903056081190555, -0.1810551689973938, -0.18234567433898463, -0.18437755479314494, -0.18566077219111385, -0.18769995645219825, -0.18897585399015576, -0.19102237435558347, -0.192290919352161, -0.19434480889020636, -0.19560596788724433, -0.19766726044895475, -0.1989209991994259, -0.20098972943095794, -0.2022360128864005, -0.20431221624174029, -0.20555100853937672, -0.20763472129341523, -0.20886598574287293, -0.21095724500488236, -0.21218094407451515, -0.21427978780203963, -0.21549588310483633, -0.21760235011797793, -0.21881080239704703, -0.22092493239322689, -0.2221257015068451, -0.2242475350759373, -0.22544057998216485, -0.22757015862214383, -0.22875543736295006, -0.23089280349597568, -0.23207027318092305, -0.23421547016992497, -0.23538508695932844, -0.23753815912507237, -0.2386998782126847, -0.24086087085136845, -0.24201464644651685, -0.24418360584787635, -0.24532939115709884, -0.24750636462306552, -0.24864411183115062, -0.25082914769508674, -0.25195880794557457, -0.25415195559206344, -0.2552734789671441, -0.2574747888523981, -0.2585881243522003, -0.26079764802508526, -0.2619027435463427, -0.26412053367002275, -0.26521733598408903, -0.2674434463583562, -0.26853190108854663, -0.27076638667281505, -0.2718464382710549, -0.2740893552080655, -0.27516094693084314, -0.277412352571094, -0.2784754264546312, -0.2807353793815628, -0.2817898762162642, -0.2840584362722218, -0.28510429557630684, -0.2873815238893018, -0.2884186838816227, -0.29070464289293557, -0.29173304046496307, -0.2940277939576101, -0.2950473646445118, -0.2973509777725844, -0.2983616557234278, -0.30067419504239007, -0.30167591298938523, -0.30399744648729016, -0.3049901357140591, -0.30732073284377853, -0.30830432315263767, -0.3106440548651226, -0.31161847454328045, -0.31396741332187733, -0.31493258910658545, -0.31729080900243917, -0.31824666604500185, -0.3206142427136431, -0.32156070454226526, -0.32393771528134746, -0.32487470376276817, -0.3272612275510613, -0.3281886628509419, -0.3305847803885833, -0.33150258093058493, -0.3339083746806839, -0.33481645710419217, -0.33723201133577674, -0.33813029045224796, -0.3405556912846695, -0.341444080032477, -0.343879415481303, -0.34475782487908596, -0.3472031849035124, -0.34807152400196806, -0.3505270005538685, -0.3513851763858956, -0.35385086346049344, -0.3546987809896223, -0.3571747746779719, -0.3580123367450245, -0.3604987352882131, -0.36132584255614286, -0.3638227464014494, -0.364639297298235, -0.3671468091571929, -0.3679526998167635, -0.37047092472526744, -0.37126604892633697, -0.37379509430690055, -0.37457934340962723, -0.3771193191358161, -0.37789258201621884, -0.3804436004794038, -0.3812057634614351, -0.38376793963995653, -0.3845188864250877, -0.38709233795587256, -0.3878319495501835, -0.39041679680305746, -0.39114495144159994, -0.39374131759623476, -0.3944578906646449, -0.39706590179041246, -0.3977707657436165, -0.40039055088237935, -0.4010835751602455, -0.4037152664122719, -0.40439631735212805, -0.40704004996519466, -0.40770899071101274, -0.4103649031729671, -0.4110215935810871, -0.41368982771587615, -0.41433412425710264, -0.41701482532457074, -0.4176465809825324, -0.42033989778200576, -0.42095896194748844, -0.4236650469254863, -0.4242712652866866, -0.4269902746488391, -0.42758348907721666, -0.4303155829046322, -0.4308956313362658, -0.43364097370653015, -0.4342076900186927, -0.4369664491317755, -0.4375196630145078, -0.4402920113237644, -0.4408315481462386, -0.4436176624947548, -0.44414334316612514, -0.4469434049287221, -0.4474550457532264, -0.4502692409843479, -0.4507666535103375, -0.45359517309815145, -0.4540781639608077, -0.456921203787778, -0.45738957454511725, -0.4602473356554883, -0.46070088261736925, -0.4635735713917699, -0.4640120854415153, -0.46689991377919654, -0.46732318018745334, -0.47022636569644566, -0.47063416392686946, -0.47355293012254096, -0.47394503362891965, -0.4768796101413308, -0.4772557861555669, -0.48020640894619976, -0.480566418256832, -0.4835333298450363, -0.48387692656562276, -0.48686037626545997, -0.4871873075923857, -0.49018755176038026, -0.49049755771942205, -0.49351486001378714, -0.4938076731948959, -0.49684230484696384, -0.4971176501264546, -0.5001698902249747, -0.5004274844745861, -0.5034976202635818, -0.5037371720454927, -0.50682549923648, -0.507046708483603, -0.5101535315830805, -0.5103560892636436, -0.5134817219166534, -0.5136653096821945, -0.5168100750329712, -0.5169743648488373, -0.5201385959195135, -0.5202832496765983, -0.5234672897651966, -0.5235919588720057, -0.5267961619707258, -0.5269004869243967, -0.5301252181595626, -0.5302088280945929, -0.5334544641896101, -0.5335169764028653, -0.5367839061656144, -0.5368249256161401, -0.5401135504523945, -0.5401326692343537, -0.5434434036888767, -0.5434402004759482, -0.5467734728031295, -0.5467475122623798, -0.5501037650282858, -0.5500545972016335, -0.5534342879196614, -0.5533614475705634, -0.556765049372911, -0.5566680552961182, -0.5600960576435351, -0.5599744119351915, -0.5634273213676235, -0.5632805086531016, -0.5667588495841852, -0.5665863362005621, -0.5700906517589777, -0.5698918848889811, -0.5734227378100386, -0.573197144563995, -0.5767551181351521, -0.5765021045770569, -0.580087803641254, -0.5798067537549317, -0.5834208057760809, -0.5831110803668802, -0.5867541365621836, -0.5864150720893858, -0.5900878086335521, -0.5897187159681375, -0.5934218352750625, -0.5930219983770502, -0.5967562304650268, -0.5963249049740936, -0.6000910089211444, -0.5996274206535293, -0.6034261861500945, -0.6029295294943031, -0.6067617785012371, -0.6062312147042277, -0.6100978032247312, -0.6095324585594328, -0.613434278534539, -0.612833242338791, -0.6167712236767633, -0.6161335462526554, -0.6201086590039312, -0.6194333493654387, -0.6234466060557519, -0.6227326295113508, -0.6267850876470463, -0.6260313632025714, -0.6301241279636356, -0.6293295255291198, -0.6334637526670057, -0.632627090049455, -0.6368039890086687, -0.6359240286707872, -0.6401448659554091, -0.6392203115180715, -0.6434864143264454, -0.6425159067902575, -0.6468286669440928, -0.6458107806024278, -0.6501716587992648, -0.6491048968121619, -0.6535154272336956, -0.6523982168281918, -0.6568600121408888, -0.6556906993993523, -0.6602054561879873, -0.6589823003812357, -0.6635518050611876, -0.6622729724778972, -0.6668991077377397, -0.665562664955361, -0.6702474167878508, -0.6688513233233241, -0.6735967887104397, -0.6721388889807963, -0.676947284307283, -0.675425298820884, -0.6802989691007104, -0.6787104847890816, -0.6836519138009811, -0.6819943733885498, -0.6870061948301972, -0.6852768851248298, -0.6903618949111648, -0.6885579338811887, -0.6937191037304666, -0.6918374262141567, -0.6970779186872875, -0.6951152605573305, -0.7004384457406885, -0.6983913263188214, -0.7038008003713354, -0.7016655028558809, -0.7071651086758312, -0.704937658306329, -0.7105315086153774, -0.7082076482531214, -0.7139001514450467, -0.7114753141935193, -0.717271203354701, -0.7147404817786749, -0.720644847359165, -0.7180029587824894, -0.7240212854828626, -0.721262532749751, -0.7274007412938956, -0.7245189682630656, -0.7307834628546558, -0.7277720037543456, -0.7341697261709237, -0.7310213477698606, -0.7375598392406906, -0.734266674576342, -0.7409541468279096, -0.7375076189684893, -0.7443530361173042, -0.7407437701030988, -0.7477569434459092, -0.7439746641403303, -0.751166362358403, -0.7471997754136223, -0.7545818533002062, -0.7504185057734953, -0.7580040553504168, -0.753630171648749, -0.7614337005135785, -0.75683398823366, -0.7648716312458418, -0.7600290500278322, -0.7683188221029368, -0.7632143067078905, -0.7717764066868631, -0.7663885329704629, -0.7752457114686692, -0.7695502905128065, -0.7787282986251035, -0.7726978796511598, -0.7822260208214848, -0.7758292771255734, -0.7857410920169197, -0.7789420552605534, -0.7892761800379419, -0.7820332756192733, -0.7928345291492602, -0.785099347242473, -0.7964201246070728, -0.7881358349064798, -0.8000379169837483, -0.7911371955620773, -0.8036941332200701, -0.7940964094880768, -0.8073967162080616, -0.7970044536044157, -0.8111559594313905, -0.7998495321156418, -0.8149854456724589, -0.802615923238568, -0.8189034744315008, -0.8052821983306919, -0.8229353029581946, -0.8078183754335403, -0.8271167984088433, -0.8101811814665558, -0.8315006585508805, -0.8123057755414764, -0.8361675863544173, -0.814090414383303, -0.8412477195029955, -0.8153658854754403, -0.8469652570895849, -0.815828591585881, -0.8537419495106258, -0.8148745651092701, -0.862475155773141, -0.8111082618458351, -0.8754654006786325, -0.8004321025866217, -0.9008511346151478, -0.7637200602244004, -1.0, ], [0.0, 0.740802475928694, 0.9983390066336059, 0.8638891130006268, 0.7604045554554124, 0.834263428318842, 0.8958681543939454, 0.8412074645567238, 0.79380109329272, 0.8330884120197518, 0.8671604332365133, 0.8320674904181751, 0.8011617485151978, 0.8278035336934186, 0.8508482004997938, 0.8246166988829603, 0.8016125484743666, 0.821692989829848, 0.8387930055513692, 0.8176183490109495, 0.799251072623681, 0.8153134112572052, 0.8286943233450924, 0.81079014201136, 0.7954728654077078, 0.8088209113238697, 0.8196547946289755, 0.8040398506816038, 0.7908818946769596, 0.8022728994453102, 0.8112526687612013, 0.7973301867266701, 0.78578175790905, 0.7956944656321127, 0.8032637464029531, 0.7906437565279846, 0.7803416678667012, 0.789097980321364, 0.7955578896242695, 0.7839715649139124, 0.7746633680716251, 0.7824901126091341, 0.7880543952080027, 0.7773085818120736, 0.7688116996594624, 0.7758747147277694, 0.7807005651389759, 0.7706518163881015, 0.7628299359589005, 0.7692541375430416, 0.7734605320123614, 0.7639994000423862, 0.7567480591773568, 0.76262988151067, 0.7663090383291064, 0.7573501166716065, 0.7505874982071655, 0.7560029407233566, 0.7592277843386486, 0.750703147244311, 0.744363975082337, 0.7493739949058424, 0.7522031870072583, 0.7440579238311309, 0.738089285850181, 0.7427435215639353, 0.7452249525988547, 0.7374140425993968, 0.7317724538437557, 0.736111864094503, 0.7382851379678226, 0.7307712100459169, 0.7254204990384578, 0.7294792745636286, 0.731377515918546, 0.7241292087119707, 0.7190389647389779, 0.7228459413738055, 0.724497135622486, 0.7174878747122546, 0.7126322864258665, 0.7162120076256956, 0.7176400115658726, 0.7108470826449322, 0.7062040553166861, 0.7095775835863888, 0.7108028992249242, 0.7042067352368492, 0.6997572101081163, 0.702942755330684, 0.7039831305124612, 0.6975667560990053, 0.69329417873711, 0.6963075908411834, 0.6971784912070802, 0.6909270845687602, 0.6868169847267918, 0.6896721443866142, 0.6903871283793942, 0.6842876719791302, 0.6803273280258714, 0.6830364597121722, 0.6836074795867884, 0.677648478921147, 0.6738266472039314, 0.6764005723964494, 0.6768382180904017, 0.6710094732082973, 0.6673161678331363, 0.6697645116147628, 0.6700782100184194, 0.6643706283445604, 0.6607969405077109, 0.6631283014737976, 0.6633264805432023, 0.6577319223585797, 0.6542698710010646, 0.6564919620326448, 0.6565821869344908, 0.6510933369072135, 0.6477357443941445, 0.6498555100916951, 0.6498445969113548, 0.6444548565796725, 0.6411952445357131, 0.6432189598078311, 0.6431130711159245, 0.6378164683525097, 0.6346489698552563, 0.6365823231782485, 0.6363870488215363, 0.6311781611592241, 0.6280974463018482, 0.6299456104240717, 0.6296660361996904, 0.6245399255477468, 0.6215411380004882, 0.6233088302967321, 0.6229495966268518, 0.6179017534058667, 0.6149804560822336, 0.6166719903244344, 0.6162373426290302, 0.6112636377396451, 0.6084157660430966, 0.6100350970117174, 0.6095289291501148, 0.6046255724934245, 0.6018473939099694, 0.6033981560020308, 0.60282404789698, 0.5979875524027425, 0.5952756314332591, 0.5967611722109731, 0.5961224225656125, 0.591349572873482, 0.5887007404808752, 0.5901241499360839, 0.5894238047921926, 0.5847116298820033, 0.5821229567732897, 0.58348709294779, 0.5827279707038491, 0.5780737198922175, 0.5755424930721592, 0.5768500045651026, 0.5760347179679837, 0.5714358397863907, 0.5689595419135423, 0.5702128877189474, 0.5693438632580419, 0.564797986807136, 0.5623742779598262, 0.5635757450053464, 0.5626552400687551, 0.5581601585085971, 0.5557868600310312, 0.5569385787303035, 0.5559686968258588, 0.5515223527151415, 0.5491974328653354, 0.550301390947813, 0.5492840952450478, 0.5448845674863426, 0.5426061286500367, 0.5436641834921889, 0.5426013089025923, 0.538246801087117, 0.5360130683571068, 0.5370269580056511, 0.5359202219864775, 0.5316090519622101, 0.5294183629118706, 0.5303897159619553, 0.5292407282019813, 0.5249713187142968, 0.5228221142186039, 0.523752458686698, 0.5225627298098874, 0.5183336000851363, 0.5162244160631176, 0.5171151873748288, 0.5158861367789147, 0.5116958949392982, 0.5096253549091669, 0.5104779031057772, 0.509210866036875, 0.5050582022500687, 0.5030250106030284, 0.5038406068565963, 0.5025368408073665, 0.4984205210872067, 0.49642345699831364, 0.4972032995133783, 0.4958639900208363, 0.4917828506062831, 0.4898207625114191, 0.49056598188121486, 0.4891922477904521, 0.48514519003936535, 0.48321699061638573, 0.48392865469291224, 0.4825215529445931, 0.4785075386868694, 0.47661220028677986, 0.4772913186166245, 0.4758518486089885, 0.4718698959103932, 0.4700064463910812, 0.47065397426256833, 0.4691830818323971, 0.4652322611264296, 0.46339978004719806, 0.4640166221889325, 0.46251520325068674, 0.4585946338008054, 0.4567922489409493, 0.45737926290709957, 0.45584816678474094, 0.4519570134437767, 0.450183897612737, 0.45074189688625976, 0.44918192936830165, 0.44531939960568134, 0.44357476771603077, 0.44410452455751237, 0.4425164507023503, 0.43868179187307704, 0.4369648982508811, 0.43746714631749783, 0.4358516930330279, 0.4320441898653159, 0.43035432577519805, 0.43082976253163563, 0.42918762095052143, 0.4254065932314825, 0.423743084596271, 0.4241923735370193, 0.42252420120663164, 0.4187690016476637, 0.4171312069446226, 0.4175549796449905, 0.41586140254902476, 0.41213141481451004, 0.4105187231320786, 0.41091758114345306, 0.40919919557041073, 0.40549383245505044, 0.40390566169572717, 0.40428017829893936, 0.4025375525711089, 0.3988562543127229, 0.39729204952918573, 0.39764277135847814, 0.3958764474336122, 0.3922186801496136, 0.3906779120024912, 0.3910053605512587, 0.3892158555079566, 0.3855811097448768, 0.38406327307173305, 0.38436794609014857, 0.3825557535068382, 0.3789435428932921, 0.37744815537946447, 0.37773052817304675, 0.3758961194094636, 0.372305979403974, 0.3708325803467499, 0.3710931069841131, 0.3692369323733642, 0.36566841909920267, 0.3642165682577085, 0.36445568269489076, 0.36257817265336056, 0.35903086181336785, 0.35760013833720594, 0.35781825546530044, 0.35591982152702795, 0.3523933073920064, 0.35098330882237677, 0.35118082544456286, 0.3492618612260589, 0.34575575569093076, 0.3443660970285199, 0.34454339277203383, 0.3426042748729653, 0.33911820657543174, 0.3377485194099078, 0.3379059575779506, 0.3359470464226598, 0.33248065991956494, 0.33113059161593483, 0.33126851998413165, 0.329290160608462, 0.3258431156054885, 0.3245123285430446, 0.3246310801045972, 0.32263360289216103, 0.31920557352285955, 0.3178937443827921, 0.317993638046143, 0.3159773594177267, 0.31256803356829366, 0.3112748526663874, 0.3113561939088704, 0.30932141696844184, 0.3059304956448539, 0.30465566630600227, 0.3047187477866629, 0.30266576292708824, 0.29929295966160385, 0.29803619763315337, 0.2980812997676222, 0.29601038523894657, 0.29265542553317464, 0.2914164584343518, 0.2914438499344742, 0.28935527237741315, 0.2860178931793853, 0.2847964599843002, 0.2848063983649448, 0.2827004133119645, 0.27938036252489057, 0.2781762130767968, 0.2781689451320847, 0.2760457974783207, 0.2727428334988507, 0.2715557280535675, 0.271531490304595, 0.26939141475059514, 0.26610530603462945, 0.26493501483115445, 0.2648940339471094, 0.2627372554153068, 0.2594677800695304, 0.25831408292605085, 0.2582565761204609, 0.25608331014707514, 0.2528302555445289, 0.25169294147819893, 0.2516191168819208, 0.24942956998589494, 0.2461927324040458, 0.2450715992729951, 0.24498165628542834, 0.2427760263158376, 0.23955521059572468, 0.23845006476188818, 0.23834419438180124, 0.23612267084509853, 0.23291769007024807, 0.23182834608171612, 0.2317067312189192, 0.22946949558726884, 0.22628017078112705, 0.22520645107285686, 0.22506926684190728, 0.22281649284373886, 0.21964265268455443, 0.2185843872962807, 0.21843180129329853, 0.21616365518716962, 0.213005135739236, 0.21196216204958984, 0.21179433461318772, 0.20951097544592007, 0.20636761990624372, 0.20533978238213413, 0.20515686683936818, 0.20285844668937955, 0.19973010514888573, 0.19871725510923796, 0.19851939800746257, 0.19620606221413536, 0.1930925914325751, 0.19209458682565647, 0.19188192815105115, 0.1895538155309278, 0.18645507872471664, 0.1854717839182526, 0.18524445730176844, 0.1829017003522887, 0.17981756699460483, 0.17884885257800937, 0.17860698548942225, 0.17624971058087752, 0.17318005621330954, 0.17222579881139566, 0.17196951274208128, 0.16959784029841143, 0.16654254635360138, 0.1656026284511299, 0.16533203908616537, 0.1629460837551769, 0.15990503738984319, 0.15897934716640905, 0.1586945645465321, 0.15629443536006618, 0.15326752929793916, 0.1523559604726159, 0.152057089146549, 0.14964288967111253, 0.1466300220552374, 0.14573247374055828, 0.1454196129081658, 0.1429914413864681, 0.13999251564047094, 0.1391088922052701, 0.13878213585198157, 0.13634008533581513, 0.13335501003369246, 0.13248522097440418, 0.13214465799730601, 0.1296888164721644, 0.1267175052162187, 0.12586146503624512, 0.12550717936221273, 0.12303762986401162, 0.12008000117057058, 0.11923762926738625, 0.11886969996359699, 0.11638652068784619, 0.11344249788042733, 0.11261371844006736, 0.11223221981721723, 0.10973548422093854, 0.10680499533057763, 0.10598973722923152, 0.10559473893774494, 0.1030845158344325, 0.10016749350688217, 0.09936569021929155, 0.09895725733880655, 0.09643361098672741, 0.09352999239621688, 0.092741581910655, 0.0923197750330141, 0.08978276521704115, 0.086892491986468, 0.08611741672601543, 0.08568229203200718, 0.08313197413924568, 0.08025499226647151, 0.07949319901642747, 0.07904480834648503, 0.07648123343589873, 0.07361749322598533, 0.07286893306718328, 0.07240732398622322, 0.06983053885243035, 0.06697999485568161, 0.0662446231035186, 0.06576983896012106, 0.06317988619154555, 0.06034249714709971, 0.05962027329616033, 0.05913235327620329, 0.056529271307738625, 0.05370500009263554, 0.05299588776672401, 0.05249486694165589, 0.049878690101969644, 0.04706750368551949, 0.04637147059297635, 0.04585737996283914, 0.043228138516455114, 0.040430007919795605, 0.03974702581399855, 0.039219892345304826, 0.03657761252956019, 0.03379251279031137, 0.03312255743522762, 0.03258240409381562, 0.029927108150796345, 0.027155018292696422, 0.026498069433429968, 0.025944915212347087, 0.023276621415900922, 0.020517524423361216, 0.0198735657615881, 0.019307425704107484, 0.01662614838196127, 0.013880031179479361, 0.01324905035373468, 0.012669935571541495, 0.009975685122630572, 0.007242538558985527, 0.006624527129728482, 0.006032444816338038, 0.003325227723355207, 0.0006050465605673037, -3.8351296315409235e-16, -0.0006050465605672834, -0.003325227723354558, -0.006032444816337557, -0.0066245271297291335, -0.0072425385589848625, -0.009975685122630765, -0.012669935571540721, -0.013249050353735255, -0.013880031179479176, -0.016626148381960994, -0.019307425704107126, -0.019873565761588382, -0.02051752442335988, -0.02327662141590064, -0.025944915212347444, -0.026498069433429545, -0.02715501829269625, -0.029927108150797247, -0.03258240409381504, -0.033122557435227906, -0.033792512790311466, -0.03657761252955937, -0.03921989234530771, -0.039747025813999685, -0.04043000791979508, -0.04322813851645644, -0.04585737996283435, -0.04637147059297739, -0.047067503685519194, -0.0498786901019682, -0.05249486694165464, -0.05299588776672468, -0.0537050000926359, -0.05652927130773617, -0.05913235327620235, -0.05962027329615649, -0.060342497147100216, -0.06317988619154473, -0.06576983896012176, -0.06624462310351846, -0.06697999485568056, -0.06983053885243014, -0.07240732398622546, -0.07286893306718202, -0.07361749322598649, -0.07648123343589906, -0.07904480834648493, -0.07949319901642891, -0.08025499226646945, -0.0831319741392467, -0.08568229203200718, -0.0861174167260157, -0.08689249198646808, -0.08978276521704232, -0.0923197750330145, -0.09274158191065482, -0.09352999239621754, -0.09643361098672659, -0.09895725733880537, -0.099365690219292, -0.10016749350687891, -0.10308451583443161, -0.10559473893774297, -0.10598973722923156, -0.10680499533057831, -0.10973548422093538, -0.11223221981721677, -0.11261371844006839, -0.11344249788042793, -0.11638652068784677, -0.11886969996359711, -0.11923762926738249, -0.12008000117056836, -0.12303762986401437, -0.12550717936221298, -0.12586146503624898, -0.12671750521621905, -0.12968881647216376, -0.13214465799730607, -0.13248522097440488, -0.1333550100336929, -0.13634008533581615, -0.13878213585198243, -0.1391088922052712, -0.1399925156404716, -0.14299144138646797, -0.14541961290816596, -0.14573247374055873, -0.14663002205523673, -0.14964288967111264, -0.15205708914654886, -0.15235596047261765, -0.15326752929793844, -0.15629443536006526, -0.1586945645465314, -0.15897934716640907, -0.15990503738984216, -0.16294608375517708, -0.16533203908616514, -0.16560262845113047, -0.16654254635359866, -0.16959784029841185, -0.1719695127420809, -0.17222579881139546, -0.17318005621330895, -0.17624971058087732, -0.1786069854894216, -0.17884885257800998, -0.1798175669946014, -0.18290170035228878, -0.1852444573017681, -0.18547178391825145, -0.18645507872471656, -0.1895538155309276, -0.1918819281510512, -0.19209458682566075, -0.19309259143257498, -0.1962060622141346, -0.19851939800746493, -0.19871725510923807, -0.19973010514888456, -0.20285844668937736, -0.20515686683936718, -0.2053397823821327, -0.2063676199062437, -0.20951097544591898, -0.21179433461318775, -0.21196216204959012, -0.2130051357392367, -0.2161636551871717, -0.21843180129329662, -0.21858438729628146, -0.21964265268455369, -0.22281649284373853, -0.22506926684190637, -0.22520645107285753, -0.22628017078112878, -0.22946949558726773, -0.23170673121891872, -0.23182834608171626, -0.23291769007024743, -0.23612267084509858, -0.23834419438180018, -0.23845006476188776, -0.23955521059572596, -0.24277602631583597, -0.24498165628542817, -0.24507159927299543, -0.24619273240404516, -0.2494295699858952, -0.2516191168819212, -0.2516929414782, -0.25283025554452876, -0.25608331014707447, -0.25825657612045944, -0.25831408292604996, -0.2594677800695307, -0.26273725541530574, -0.2648940339471097, -0.2649350148311548, -0.2661053060346311, -0.26939141475059597, -0.27153149030459617, -0.27155572805356776, -0.2727428334988502, -0.276045797478321, -0.2781689451320843, -0.2781762130767974, -0.27938036252488996, -0.28270041331196377, -0.28480639836494454, -0.2847964599843004, -0.2860178931793863, -0.28935527237741115, -0.29144384993447386, -0.2914164584343519, -0.29265542553317736, -0.2960103852389467, -0.298081299767621, -0.2980361976331513, -0.2992929596616031, -0.30266576292708697, -0.30471874778666325, -0.3046556663060032, -0.30593049564485375, -0.30932141696844295, -0.31135619390886826, -0.31127485266638477, -0.3125680335682939, -0.315977359417728, -0.3179936380461428, -0.31789374438279233, -0.3192055735228597, -0.3226336028921613, -0.32463108010459585, -0.32451232854304485, -0.32584311560548734, -0.32929016060846555, -0.33126851998412904, -0.33113059161593517, -0.3324806599195655, -0.33594704642265916, -0.33790595757794967, -0.3377485194099072, -0.33911820657542796, -0.34260427487296735, -0.344543392772031, -0.3443660970285201, -0.34575575569092964, -0.34926186122605785, -0.35118082544456386, -0.35098330882237544, -0.35239330739200225, -0.3559198215270262, -0.35781825546530066, -0.35760013833720694, -0.3590308618133673, -0.362578172653361, -0.3644556826948917, -0.3642165682577103, -0.3656684190992032, -0.36923693237336513, -0.371093106984115, -0.3708325803467495, -0.37230597940397325, -0.37589611940946366, -0.3777305281730475, -0.3774481553794635, -0.3789435428932907, -0.3825557535068365, -0.3843679460901506, -0.3840632730717329, -0.38558110974487797, -0.38921585550795534, -0.391005360551259, -0.3906779120024904, -0.39221868014961514, -0.3958764474336085, -0.3976427713584772, -0.3972920495291877, -0.39885625431272054, -0.40253755257110757, -0.40428017829894003, -0.40390566169572806, -0.40549383245505, -0.4091991955704095, -0.41091758114345217, -0.4105187231320809, -0.4121314148145106, -0.41586140254902365, -0.4175549796449912, -0.4171312069446237, -0.41876900164766745, -0.42252420120663026, -0.4241923735370198, -0.4237430845962725, -0.4254065932314813, -0.4291876209505208, -0.43082976253163563, -0.43035432577519767, -0.43204418986531473, -0.43585169303302934, -0.43746714631749606, -0.43696489825087914, -0.4386817918730767, -0.44251645070235024, -0.44410452455751354, -0.4435747677160299, -0.445319399605681, -0.44918192936830176, -0.4507418968862599, -0.4501838976127377, -0.4519570134437763, -0.4558481667847437, -0.45737926290709896, -0.45679224894094905, -0.4585946338008063, -0.4625152032506868, -0.46401662218893275, -0.46339978004719856, -0.46523226112642796, -0.46918308183239704, -0.4706539742625685, -0.47000644639107536, -0.47186989591039274, -0.4758518486089898, -0.47729131861662394, -0.4766122002867776, -0.47850753868686813, -0.4825215529445949, -0.48392865469291185, -0.4832169906163857, -0.48514519003936246, -0.4891922477904509, -0.49056598188121503, -0.48982076251141915, -0.49178285060628246, -0.4958639900208371, -0.4972032995133777, -0.49642345699831564, -0.49842052108720336, -0.502536840807367, -0.5038406068565984, -0.5030250106030275, -0.5050582022500693, -0.5092108660368745, -0.5104779031057755, -0.509625354909168, -0.5116958949392958, -0.5158861367789125, -0.5171151873748286, -0.5162244160631175, -0.5183336000851367, -0.5225627298098855, -0.5237524586866996, -0.5228221142186036, -0.5249713187142963, -0.5292407282019796, -0.5303897159619521, -0.5294183629118699, -0.5316090519622102, -0.535920221986478, -0.5370269580056504, -0.536013068357108, -0.5382468010871176, -0.5426013089025923, -0.5436641834921875, -0.5426061286500373, -0.5448845674863442, -0.549284095245045, -0.5503013909478125, -0.5491974328653402, -0.5515223527151419, -0.5559686968258591, -0.5569385787303003, -0.5557868600310304, -0.5581601585085978, -0.5626552400687536, -0.563575745005347, -0.5623742779598244, -0.5647979868071356, -0.5693438632580406, -0.5702128877189471, -0.5689595419135438, -0.571435839786389, -0.5760347179679833, -0.5768500045650998, -0.5755424930721602, -0.578073719892216, -0.582727970703848, -0.5834870929477887, -0.5821229567732885, -0.5847116298820029, -0.5894238047921913, -0.5901241499360871, -0.5887007404808765, -0.5913495728734852, -0.596122422565615, -0.5967611722109716, -0.5952756314332608, -0.59798755240274, -0.6028240478969826, -0.6033981560020305, -0.6018473939099696, -0.6046255724934234, -0.6095289291501146, -0.610035097011719, -0.6084157660430959, -0.6112636377396466, -0.6162373426290274, -0.6166719903244353, -0.6149804560822323, -0.6179017534058662, -0.622949596626849, -0.6233088302967311, -0.6215411380004905, -0.6245399255477458, -0.6296660361996886, -0.6299456104240729, -0.6280974463018432, -0.6311781611592243, -0.6363870488215355, -0.6365823231782481, -0.6346489698552574, -0.6378164683525098, -0.6431130711159234, -0.6432189598078308, -0.641195244535713, -0.6444548565796732, -0.6498445969113489, -0.6498555100916994, -0.6477357443941443, -0.6510933369072137, -0.6565821869344903, -0.6564919620326397, -0.6542698710010624, -0.6577319223585781, -0.6633264805432012, -0.6631283014737969, -0.6607969405077108, -0.6643706283445611, -0.6700782100184199, -0.6697645116147635, -0.6673161678331369, -0.6710094732082968, -0.6768382180904026, -0.6764005723964495, -0.673826647203931, -0.6776484789211452, -0.6836074795867897, -0.6830364597121726, -0.6803273280258736, -0.6842876719791289, -0.6903871283793914, -0.6896721443866143, -0.686816984726793, -0.6909270845687548, -0.6971784912070754, -0.6963075908411825, -0.6932941787371086, -0.6975667560990108, -0.7039831305124602, -0.7029427553306834, -0.6997572101081149, -0.7042067352368506, -0.7108028992249239, -0.7095775835863857, -0.7062040553166865, -0.7108470826449305, -0.717640011565873, -0.7162120076256958, -0.7126322864258654, -0.7174878747122533, -0.7244971356224871, -0.7228459413738063, -0.7190389647389788, -0.7241292087119715, -0.7313775159185448, -0.7294792745636278, -0.7254204990384615, -0.7307712100459163, -0.7382851379678236, -0.736111864094503, -0.7317724538437558, -0.7374140425993954, -0.7452249525988583, -0.7427435215639376, -0.7380892858501815, -0.7440579238311323, -0.75220318700726, -0.7493739949058449, -0.7443639750823361, -0.7507031472443112, -0.7592277843386486, -0.7560029407233559, -0.750587498207168, -0.7573501166716068, -0.7663090383291056, -0.7626298815106669, -0.7567480591773567, -0.7639994000423861, -0.7734605320123625, -0.7692541375430427, -0.762829935958901, -0.7706518163881019, -0.7807005651389773, -0.7758747147277694, -0.7688116996594643, -0.7773085818120721, -0.7880543952080032, -0.7824901126091328, -0.774663368071625, -0.7839715649139103, -0.795557889624269, -0.789097980321364, -0.780341667866697, -0.7906437565279831, -0.8032637464029533, -0.7956944656321135, -0.7857817579090488, -0.7973301867266701, -0.811252668761201, -0.8022728994453101, -0.790881894676959, -0.8040398506816033, -0.8196547946289746, -0.808820911323872, -0.7954728654077078, -0.8107901420113609, -0.8286943233450939, -0.8153134112572076, -0.7992510726236801, -0.8176183490109465, -0.8387930055513699, -0.8216929898298523, -0.8016125484743681, -0.8246166988829595, -0.8508482004997935, -0.8278035336934229, -0.801161748515198, -0.8320674904181732, -0.8671604332365133, -0.8330884120197515, -0.7938010932927209, -0.8412074645567202, -0.8958681543939448, -0.8342634283188396, -0.760404555455411, -0.863889113000616, -0.9983390066336079, -0.7408024759286942, ], [0.0, 0.4103425695601375, 0.7401974293681266, 0.9374896183204237, 0.9950137789102514, 0.9484605979904545, 0.8578566681842902, 0.7814477518974724, 0.7537800283256835, 0.7769191662428927, 0.8270208897598558, 0.8712589265193403, 0.8858924692713149, 0.86680873224827, 0.8285375289851826, 0.7938594254876643, 0.7805520429389847, 0.7924858559025624, 0.819208380840273, 0.8432691447472056, 0.850534284854552, 0.8376295785429877, 0.8127600647140674, 0.7900815465072685, 0.7812881827536113, 0.7892874116900224, 0.8072860092700562, 0.8233641652293622, 0.8275715790838938, 0.8173980187374353, 0.7986717836706126, 0.7816963194307442, 0.775114479040938, 0.7811046335943931, 0.7945379715371527, 0.8063338989336983, 0.8088658974005712, 0.8002334956454599, 0.785035944917134, 0.7713963350719443, 0.7661285151884529, 0.7708982918478914, 0.7815208984668957, 0.790643421051507, 0.7921167108155328, 0.7844750883903202, 0.7715702496660544, 0.7601220688707775, 0.7557258395937079, 0.7596748762526646, 0.7683909034040731, 0.7756836967328605, 0.7764266561125208, 0.7694763045762837, 0.758182470718765, 0.7482855971604844, 0.7445104240839808, 0.7478690596409655, 0.7552053957597908, 0.7611657977139046, 0.7613739786592313, 0.7549339034688589, 0.7448353197850138, 0.7360955818532549, 0.732785870142325, 0.7356989377620329, 0.741989465539477, 0.7469352234187101, 0.7467344750952147, 0.7406869562457106, 0.7315114032517815, 0.7236688882797357, 0.7207213945705416, 0.7232858813504938, 0.7287554831742643, 0.732901927762005, 0.7323780034327229, 0.7266421816727152, 0.7182017259537905, 0.7110760279545549, 0.7084187449681083, 0.7107027743943475, 0.7155101177534526, 0.7190099035081532, 0.7182238563555708, 0.7127418002062479, 0.7049012578258489, 0.6983620544622829, 0.695942766592281, 0.6979960163004453, 0.7022572215017843, 0.7052224952999842, 0.7042193317030778, 0.6989481194893862, 0.6916070080416169, 0.685557096305084, 0.6833367369424734, 0.6851965451579651, 0.6889991452765725, 0.6915147095167976, 0.6903285578731145, 0.6852355063290532, 0.678317108010378, 0.672682062299956, 0.6706306424513399, 0.6723257805030147, 0.6757373895242021, 0.677868919785534, 0.6765262731120649, 0.6715859225114326, 0.6650303416385932, 0.6597519166539967, 0.6578459162965105, 0.6593990216412328, 0.66247294832714, 0.6642723393935129, 0.6627941733519205, 0.6579863064098237, 0.6517458899055056, 0.6467776510391896, 0.6449982848630463, 0.6464274875053857, 0.649206501398964, 0.6507154671651738, 0.6491186711728263, 0.6444269676540232, 0.6384631848933866, 0.6337675206855629, 0.6320995486209493, 0.6334195918623341, 0.635938526233358, 0.6371910959031275, 0.6354894683779189, 0.6309005653896083, 0.6251818227821802, 0.6207278460937946, 0.6191587354036886, 0.6203817685354484, 0.6226693662140763, 0.6236936593906421, 0.621898617279976, 0.6174014368068057, 0.6119015100823216, 0.607663548562204, 0.606182869771071, 0.6073190215666172, 0.6093992733930587, 0.6102187903797689, 0.6083398860545322, 0.6039251434460616, 0.5986220293497583, 0.5945785172100073, 0.5931774997027327, 0.5942353016318217, 0.596128436157586, 0.5967630141122595, 0.594808319150322, 0.5904681571172976, 0.5853432167149482, 0.5814758662599948, 0.5801470654514633, 0.5811337695149669, 0.5828569975920036, 0.5833235312543046, 0.5812999262300572, 0.5770276377624044, 0.5720649467929504, 0.5683581190389932, 0.5670951631114148, 0.568016984287123, 0.5695850679459178, 0.5698980612268963, 0.5678114578384558, 0.5636012733247011, 0.5587871223286843, 0.5552273419411344, 0.5540247363675586, 0.5548870401504793, 0.5563127332754478, 0.556484727198014, 0.5543402408413473, 0.5501871623320251, 0.5455096669524566, 0.5420852435389535, 0.5409382182644942, 0.5417456675732231, 0.5430400615432444, 0.5430819703611461, 0.540884055847014, 0.5367837264678952, 0.5322325200222277, 0.528933248980242, 0.5278376375603826, 0.528594309132664, 0.5297671069967712, 0.5296884851543404, 0.5274410446242176, 0.5233896445606921, 0.5189556328929646, 0.5157725565732685, 0.5147246995747418, 0.5154341771501657, 0.5164939133585733, 0.5163031696853756, 0.5140096392883772, 0.5100038021097253, 0.5056789661790668, 0.5026041813445163, 0.5016008483925375, 0.5022662980215987, 0.5032205161831388, 0.5029250873555223, 0.5005885075095238, 0.49662525224275206, 0.4924024877188725, 0.4894289889389457, 0.4884673152551269, 0.4890915466938132, 0.489946944620157, 0.48955343683746105, 0.4871765096661308, 0.4832531851940749, 0.47912617104279204, 0.47624772227212936, 0.4753251565894593, 0.4759106737515734, 0.4766732227490808, 0.4761875283595993, 0.4737726650122754, 0.469886904209579, 0.46584999420752865, 0.4630610226803856, 0.4621752841754096, 0.4627243268994098, 0.46339937060007036, 0.46282676480318125, 0.46037612472035555, 0.456525806353001, 0.4525739388997501, 0.44986944685037333, 0.44901848928490623, 0.44953306814683697, 0.4501254049428102, 0.4494706265095695, 0.4469861502219754, 0.4431693670879976, 0.4392979897403049, 0.43667348047953447, 0.4358554621535812, 0.436337387667658, 0.43685133990158537, 0.43611865897455954, 0.4336020956700046, 0.4298171277964955, 0.4260221337393217, 0.4234735493814017, 0.42268680780566614, 0.42313771506131914, 0.42357718743901307, 0.42277046280884806, 0.42022339363436706, 0.4164686856004867, 0.4127463598659259, 0.4102700285769954, 0.4095130590055681, 0.4099344285677574, 0.41030295773951714, 0.40942568549175135, 0.4068495433559519, 0.4031236850056417, 0.39947065870583953, 0.3970632497864067, 0.39633468692763135, 0.39672786265739435, 0.3970286595156061, 0.39608401455477227, 0.3934801010395844, 0.3897818109967659, 0.3861950221869482, 0.3838535076401955, 0.38315211000051697, 0.38351831432153755, 0.38375430025418694, 0.3827451719133323, 0.38011467178393116, 0.37644278329856773, 0.37291944335784516, 0.37064106485931253, 0.36996570128120876, 0.3703060483161539, 0.3704798864159914, 0.36940890912663327, 0.36675290283427664, 0.3631063515779825, 0.3596439162079949, 0.35742615659856997, 0.3567757946369737, 0.35709130155727065, 0.3572054235979848, 0.35607500341247045, 0.3533944779110857, 0.3497722914120605, 0.3463684355208214, 0.3442089941077355, 0.34358268995506114, 0.3438742868245354, 0.34393091666644404, 0.34274325427974384, 0.3400391124185383, 0.3364404008819682, 0.33309299675302145, 0.3309897678327109, 0.33038665755482544, 0.3306551958973486, 0.33065636986655306, 0.32941348066913084, 0.3266865493768869, 0.32311049768192013, 0.31981759593489417, 0.317768650054901, 0.31718794194213495, 0.3174342022231121, 0.31738178691315955, 0.31608551851401906, 0.3133365559532542, 0.30978241665378436, 0.30654222958762156, 0.3045457971476929, 0.3039867650185916, 0.3042114631977151, 0.3041071710662524, 0.30275921865066, 0.29998892048966297, 0.29645600767532765, 0.2932668946543059, 0.29132135151404165, 0.2907833288367451, 0.29098712212315414, 0.2908325251940561, 0.28943444501986404, 0.28664344994607865, 0.2831311338435719, 0.27999158844219146, 0.2780954432572356, 0.2775778179755265, 0.2777613098951005, 0.27755785182596004, 0.2761110731131321, 0.2732999676913419, 0.2698076699055193, 0.2667163085741213, 0.26486819162752456, 0.264370401596679, 0.26453414646365175, 0.26428315319712864, 0.2627889886245246, 0.25995831158691235, 0.25648550089704547, 0.2534410529475203, 0.2516397062796879, 0.25116123523218203, 0.2513057421028599, 0.2510084312862091, 0.24946808627639716, 0.24661833231795957, 0.24316452095763147, 0.24016581969968012, 0.2384100883706215, 0.23795046234403439, 0.23807619851841808, 0.23773368784733412, 0.23614826879249864, 0.23327989193414966, 0.22984463229414348, 0.2268906071782473, 0.225179431521023, 0.22473821569072028, 0.22484560981798363, 0.22445892443735863, 0.22282944599645207, 0.21994286256875026, 0.21652574427134802, 0.21361541391606778, 0.21194782266136078, 0.21152461852907714, 0.21161406336446545, 0.21118414243909572, 0.2095115340171389, 0.20660712530982106, 0.2032077726105237, 0.20034023860970188, 0.19871534277899616, 0.1983097856755592, 0.1983816405294424, 0.19790934308121072, 0.1961944545855192, 0.1932725692014227, 0.1898906386804628, 0.18706508010100445, 0.18548206758068655, 0.185093824447182, 0.1851484173610363, 0.18463452745526382, 0.1828781344097875, 0.1799390903562552, 0.17657426886764735, 0.1737899373613472, 0.1722480680825348, 0.17187683549925956, 0.17191446517847714, 0.17135969653034594, 0.16956250461778608, 0.16660659116390994, 0.1632585940143792, 0.16051480947803556, 0.15901341113758005, 0.15865891357450806, 0.1586798511036712, 0.1580848511656666, 0.1562475002572, 0.1532749795813076, 0.14994354891530456, 0.1472396956426435, 0.1457781599098213, 0.145440148175938, 0.14544463853862916, 0.14480999212137172, 0.14293305984547053, 0.13994416849380822, 0.13662907186414774, 0.13396459514098213, 0.13254237430213098, 0.13222062417421274, 0.13220888759631674, 0.13153512006784682, 0.12961912496242173, 0.12661407513709208, 0.12331510424362911, 0.12068950734447441, 0.11930611134456606, 0.11900042235867693, 0.11897265549148657, 0.1182602355937088, 0.11630563987961719, 0.11328462057122907, 0.11000159015248824, 0.10741443170275666, 0.10606942554866852, 0.10577961994002984, 0.10573599689713847, 0.10498533921265067, 0.10299255122117398, 0.09995572919952489, 0.09668847606428886, 0.09413936773734748, 0.09283236923267583, 0.09255829101161761, 0.09249896427157939, 0.09171043136927626, 0.08967980765145207, 0.08662732832555849, 0.08337571051341645, 0.0808643150362803, 0.0795949928219427, 0.07933650697546372, 0.07926160816040392, 0.07843551244405496, 0.07636735958558127, 0.07329934774273082, 0.0700632438041568, 0.0675892732495464, 0.0663573451283736, 0.06611433693845947, 0.06602397747726567, 0.06516058275748662, 0.06305515891918641, 0.059971719351130465, 0.05675102773922205, 0.05431424208529944, 0.05311947361233175, 0.05289184808355205, 0.05278611976686286, 0.05188564257353866, 0.04974315877412172, 0.0466443767971916, 0.04343901536447591, 0.04103922130674162, 0.039881424630014775, 0.039669106020304115, 0.039548081453225144, 0.038610692102463394, 0.03643131325724431, 0.03331725513193988, 0.030127160726915767, 0.027764210729624164, 0.02664324366918582, 0.026446175118802578, 0.02630990807616382, 0.025335731502987416, 0.023119577229555266, 0.019990290484004306, 0.016815418643182804, 0.01448921022032485, 0.013404975575795031, 0.013223118830575335, 0.01307164451842048, 0.0120607608839726, 0.009807906083190314, 0.006663419743891025, 0.0035037444760891118, 0.0012142196944630885, 0.00016666477395980132, -2.785517621999257e-16, -0.00016666477395987496, -0.00121421969446288, -0.003503744476089124, -0.006663419743889692, -0.009807906083189748, -0.012060760883972217, -0.013071644518420487, -0.013223118830575777, -0.0134049755757945, -0.014489210220324527, -0.01681541864318252, -0.019990290484004296, -0.02311957722955397, -0.02533573150298714, -0.02630990807616333, -0.026446175118802435, -0.02664324366918506, -0.027764210729623897, -0.030127160726916947, -0.033317255131940296, -0.03643131325724532, -0.03861069210246329, -0.039548081453228474, -0.03966910602030513, -0.03988142463001455, -0.04103922130674337, -0.043439015364475864, -0.046644376797193646, -0.04974315877412165, -0.05188564257353737, -0.052786119766861914, -0.052891848083551456, -0.05311947361233177, -0.05431424208529808, -0.05675102773922144, -0.05997171935112869, -0.06305515891918696, -0.0651605827574861, -0.06602397747726564, -0.06611433693846029, -0.06635734512837302, -0.06758927324954628, -0.07006324380415922, -0.07329934774273042, -0.07636735958558222, -0.07843551244405582, -0.07926160816040428, -0.07933650697546493, -0.07959499282194014, -0.08086431503628082, -0.08337571051341641, -0.08662732832555807, -0.08967980765145199, -0.09171043136927634, -0.09249896427158007, -0.09255829101161756, -0.09283236923267697, -0.0941393677373466, -0.09668847606428863, -0.09995572919952543, -0.10299255122117322, -0.1049853392126508, -0.10573599689713825, -0.10577961994002961, -0.10606942554866877, -0.10741443170275518, -0.11000159015248799, -0.11328462057123012, -0.11630563987961762, -0.11826023559370988, -0.11897265549148692, -0.119000422358677, -0.11930611134456576, -0.1206895073444748, -0.1233151042436286, -0.12661407513709322, -0.12961912496242162, -0.131535120067847, -0.13220888759631658, -0.13222062417421349, -0.132542374302131, -0.1339645951409826, -0.1366290718641468, -0.13994416849380903, -0.14293305984547022, -0.1448099921213714, -0.14544463853862857, -0.1454401481759376, -0.1457781599098214, -0.14723969564264322, -0.14994354891530381, -0.1532749795813079, -0.15624750025719955, -0.15808485116566584, -0.15867985110367044, -0.15865891357450848, -0.15901341113758, -0.1605148094780355, -0.16325859401437964, -0.16660659116390983, -0.16956250461778516, -0.17135969653034627, -0.17191446517847722, -0.1718768354992598, -0.1722480680825341, -0.17378993736134724, -0.17657426886764657, -0.17993909035625555, -0.18287813440978473, -0.18463452745526382, -0.18514841736103602, -0.18509382444718198, -0.18548206758068614, -0.1870650801010051, -0.1898906386804627, -0.1932725692014247, -0.1961944545855192, -0.19790934308121078, -0.19838164052944274, -0.19830978567555974, -0.19871534277899583, -0.20034023860970054, -0.20320777261052256, -0.20660712530982073, -0.20951153401713846, -0.21118414243909528, -0.21161406336446545, -0.21152461852907709, -0.21194782266136084, -0.21361541391606756, -0.21652574427134774, -0.21994286256875076, -0.2228294459964512, -0.22445892443735754, -0.22484560981798227, -0.22473821569072033, -0.22517943152102501, -0.2268906071782468, -0.22984463229414298, -0.23327989193414994, -0.2361482687924984, -0.23773368784733445, -0.2380761985184175, -0.23795046234403394, -0.2384100883706215, -0.24016581969967843, -0.24316452095763116, -0.246618332317959, -0.249468086276397, -0.25100843128620953, -0.2513057421028597, -0.25116123523218276, -0.2516397062796875, -0.2534410529475191, -0.2564855008970443, -0.25995831158691235, -0.26278898862452454, -0.26428315319712836, -0.26453414646365153, -0.2643704015966794, -0.26486819162752473, -0.2667163085741219, -0.2698076699055199, -0.2732999676913414, -0.2761110731131315, -0.2775578518259606, -0.27776130989510023, -0.27757781797552605, -0.2780954432572349, -0.2799915884421912, -0.2831311338435713, -0.2866434499460787, -0.2894344450198646, -0.2908325251940546, -0.2909871221231535, -0.29078332883674446, -0.2913213515140414, -0.29326689465430555, -0.2964560076753267, -0.29998892048966114, -0.3027592186506597, -0.304107171066252, -0.3042114631977161, -0.3039867650185932, -0.30454579714769336, -0.3065422295876231, -0.309782416653783, -0.3133365559532527, -0.3160855185140199, -0.3173817869131594, -0.3174342022231118, -0.3171879419421348, -0.31776865005490174, -0.3198175959348946, -0.32311049768191924, -0.32668654937688596, -0.3294134806691301, -0.33065636986655433, -0.3306551958973477, -0.33038665755482416, -0.33098976783271084, -0.3330929967530206, -0.3364404008819682, -0.34003911241853846, -0.342743254279741, -0.343930916666444, -0.34387428682453364, -0.34358268995506214, -0.3442089941077345, -0.34636843552082003, -0.349772291412061, -0.3533944779110856, -0.35607500341246756, -0.3572054235979843, -0.35709130155727137, -0.3567757946369752, -0.35742615659856997, -0.35964391620799635, -0.3631063515779824, -0.3667529028342764, -0.3694089091266326, -0.37047988641599283, -0.3703060483161545, -0.36996570128120854, -0.37064106485931103, -0.37291944335784505, -0.3764427832985684, -0.38011467178393, -0.3827451719133311, -0.3837543002541868, -0.38351831432153805, -0.3831521100005183, -0.38385350764019627, -0.3861950221869473, -0.3897818109967658, -0.39348010103958314, -0.39608401455477416, -0.39702865951560407, -0.3967278626573945, -0.39633468692763363, -0.39706324978640645, -0.39947065870583826, -0.40312368500564094, -0.40684954335595225, -0.4094256854917505, -0.4103029577395173, -0.40993442856775675, -0.4095130590055708, -0.41027002857699535, -0.412746359865925, -0.41646868560048755, -0.4202233936343679, -0.4227704628088508, -0.42357718743901285, -0.42313771506131914, -0.42268680780566725, -0.42347354938140197, -0.42602213373932113, -0.42981712779649534, -0.43360209567000435, -0.43611865897455787, -0.43685133990158614, -0.43633738766765623, -0.4358554621535793, -0.4366734804795341, -0.4392979897403047, -0.4431693670879984, -0.446986150221974, -0.4494706265095695, -0.4501254049428103, -0.44953306814683774, -0.4490184892849061, -0.4498694468503737, -0.4525739388997511, -0.456525806353, -0.4603761247203554, -0.46282676480318163, -0.4633993706000699, -0.46272432689941045, -0.46217528417541087, -0.4630610226803847, -0.4658499942075284, -0.4698869042095787, -0.4737726650122714, -0.47618752835959827, -0.47667322274908197, -0.4759106737515725, -0.4753251565894585, -0.4762477222721282, -0.47912617104279287, -0.4832531851940743, -0.48717650966613035, -0.4895534368374579, -0.48994694462015626, -0.48909154669381266, -0.4884673152551273, -0.48942898893894593, -0.49240248771887335, -0.4966252522427509, -0.5005885075095251, -0.5029250873555215, -0.5032205161831383, -0.5022662980215999, -0.501600848392536, -0.502604181344517, -0.505678966179066, -0.5100038021097244, -0.514009639288379, -0.516303169685373, -0.5164939133585701, -0.5154341771501653, -0.5147246995747423, -0.5157725565732685, -0.5189556328929644, -0.5233896445606933, -0.5274410446242168, -0.5296884851543406, -0.5297671069967703, -0.5285943091326618, -0.5278376375603824, -0.528933248980242, -0.5322325200222288, -0.5367837264678943, -0.5408840558470148, -0.5430819703611466, -0.5430400615432441, -0.5417456675732222, -0.5409382182644944, -0.5420852435389535, -0.5455096669524538, -0.5501871623320247, -0.5543402408413483, -0.5564847271980146, -0.5563127332754481, -0.5548870401504774, -0.5540247363675577, -0.5552273419411351, -0.558787122328683, -0.5636012733247014, -0.5678114578384548, -0.5698980612268961, -0.5695850679459175, -0.5680169842871219, -0.5670951631114143, -0.5683581190389926, -0.5720649467929501, -0.5770276377624035, -0.5812999262300573, -0.5833235312543037, -0.5828569975920049, -0.5811337695149665, -0.5801470654514622, -0.5814758662599951, -0.5853432167149468, -0.590468157117298, -0.5948083191503225, -0.5967630141122608, -0.5961284361575874, -0.5942353016318199, -0.5931774997027343, -0.5945785172100047, -0.5986220293497606, -0.6039251434460617, -0.6083398860545323, -0.6102187903797682, -0.6093992733930587, -0.6073190215666183, -0.6061828697710709, -0.6076635485622054, -0.6119015100823201, -0.6174014368068048, -0.6218986172799753, -0.6236936593906434, -0.6226693662140735, -0.6203817685354495, -0.6191587354036896, -0.6207278460937933, -0.6251818227821778, -0.6309005653896098, -0.6354894683779149, -0.6371910959031278, -0.6359385262333582, -0.6334195918623338, -0.6320995486209489, -0.6337675206855627, -0.6384631848933867, -0.6444269676540234, -0.6491186711728261, -0.650715467165176, -0.6492065013989586, -0.6464274875053903, -0.6449982848630459, -0.6467776510391912, -0.6517458899055053, -0.6579863064098209, -0.6627941733519188, -0.6642723393935117, -0.6624729483271402, -0.6593990216412309, -0.6578459162965115, -0.6597519166539961, -0.6650303416385938, -0.6715859225114328, -0.6765262731120647, -0.6778689197855341, -0.6757373895242024, -0.672325780503015, -0.6706306424513405, -0.6726820622999535, -0.6783171080103789, -0.6852355063290525, -0.6903285578731168, -0.6915147095167965, -0.688999145276571, -0.6851965451579651, -0.6833367369424743, -0.6855570963050832, -0.6916070080416123, -0.6989481194893854, -0.7042193317030764, -0.7052224952999853, -0.7022572215017842, -0.697996016300445, -0.6959427665922798, -0.6983620544622855, -0.7049012578258492, -0.7127418002062461, -0.7182238563555712, -0.7190099035081531, -0.7155101177534529, -0.7107027743943473, -0.7084187449681072, -0.7110760279545543, -0.7182017259537906, -0.7266421816727149, -0.7323780034327234, -0.7329019277620068, -0.7287554831742625, -0.723285881350493, -0.7207213945705427, -0.7236688882797345, -0.7315114032517817, -0.7406869562457102, -0.7467344750952145, -0.7469352234187084, -0.7419894655394782, -0.7356989377620351, -0.7327858701423255, -0.7360955818532561, -0.7448353197850143, -0.7549339034688589, -0.7613739786592312, -0.7611657977139048, -0.755205395759792, -0.7478690596409651, -0.7445104240839832, -0.7482855971604838, -0.7581824707187642, -0.7694763045762809, -0.7764266561125195, -0.7756836967328609, -0.7683909034040753, -0.7596748762526652, -0.7557258395937061, -0.7601220688707799, -0.7715702496660543, -0.7844750883903202, -0.7921167108155326, -0.7906434210515073, -0.7815208984668961, -0.7708982918478903, -0.7661285151884527, -0.7713963350719429, -0.7850359449171344, -0.8002334956454593, -0.8088658974005689, -0.8063338989336994, -0.7945379715371533, -0.7811046335943943, -0.7751144790409367, -0.7816963194307445, -0.798671783670612, -0.8173980187374337, -0.827571579083893, -0.823364165229362, -0.8072860092700568, -0.7892874116900179, -0.7812881827536104, -0.790081546507269, -0.8127600647140669, -0.8376295785429857, -0.8505342848545514, -0.8432691447472019, -0.8192083808402755, -0.7924858559025656, -0.7805520429389851, -0.7938594254876646, -0.8285375289851805, -0.866808732248267, -0.8858924692713146, -0.8712589265193423, -0.8270208897598599, -0.7769191662428925, -0.7537800283256848, -0.7814477518974697, -0.8578566681842815, -0.9484605979904548, -0.9950137789102502, -0.9374896183204332, -0.74019742936815, -0.4103425695601371, ], [0.0, 0.21054587921015805, 0.4101759047861776, 0.5889858683789057, 0.7389832096736637, 0.8547771507913895, 0.9339858738443354, 0.977321340546482, 0.9883503591663619, 0.9729680883386255, 0.9386526919072633, 0.893593385876667, 0.8457959073003181, 0.8022682794267095, 0.7683761073790524, 0.7474325512412716, 0.7405569094951083, 0.7468015526069872, 0.763514190667098, 0.7868752444128878, 0.812531679539531, 0.8362410787097108, 0.8544435078761577, 0.8646929238841384, 0.8659021787873115, 0.8583828281990092, 0.8436891550187142, 0.8243012920080518, 0.8032017974821966, 0.7834111751087277, 0.7675495174115017, 0.7574839047881886, 0.7541058678201824, 0.7572625831536728, 0.7658426122333779, 0.7779951755669164, 0.7914441701106492, 0.8038466860410586, 0.8131419840202896, 0.8178409496359372, 0.8172170297226121, 0.8113757154957351, 0.8011982652857437, 0.7881737848193209, 0.7741493726116033, 0.7610386667933312, 0.7505334650540421, 0.743860754035159, 0.7416190767333067, 0.7437151398451766, 0.7494059870600068, 0.7574363043735473, 0.7662467879633155, 0.7742199124479485, 0.7799251498648867, 0.7823271514261487, 0.7809272022866997, 0.7758192123555385, 0.7676548599633138, 0.7575262094391196, 0.746786141097073, 0.7368355379414893, 0.7289101996638819, 0.7238994459103397, 0.7222226309573869, 0.7237803165927273, 0.7279851599820617, 0.7338654745189211, 0.7402237294518526, 0.7458245236392989, 0.7495828711944769, 0.7507244128351489, 0.7488941780494411, 0.7441989054465391, 0.7371783367262738, 0.7287117204889332, 0.7198753621596468, 0.7117740263094917, 0.7053723575946775, 0.7013518217958368, 0.7000141782499945, 0.7012449479152164, 0.7045409467195176, 0.7090961701535045, 0.7139316252173483, 0.7180483940786256, 0.7205801772473487, 0.720922186487039, 0.718817363072803, 0.7143877883395814, 0.7081077288047394, 0.7007236852662221, 0.6931347372219996, 0.6862522141156034, 0.6808604607103734, 0.6774998436406954, 0.6763893326182444, 0.6773996251672378, 0.6800798834307245, 0.6837329431282242, 0.6875265883677927, 0.6906232450844298, 0.6923079862194434, 0.6920953834868323, 0.6897993277869622, 0.6855558735102265, 0.679796496924831, 0.6731767559746857, 0.6664720393494893, 0.6604568946549136, 0.6557866328889054, 0.6528992293744045, 0.6519521330723622, 0.6528030223174982, 0.6550366904082896, 0.658033173750329, 0.6610660280224433, 0.6634152445140036, 0.664477321649617, 0.6638557270068669, 0.6614182494597076, 0.6573130113787737, 0.6519413522476865, 0.6458924076181983, 0.6398499805723639, 0.6344863697740145, 0.6303595849561167, 0.6278296129245252, 0.6270062502022945, 0.6277360078921908, 0.6296295122133645, 0.6321246142116581, 0.6345750338367218, 0.6363506073957264, 0.6369336332662222, 0.6359966279460386, 0.6334498545239845, 0.6294517714798915, 0.6243813163660938, 0.6187767920670004, 0.6132511676580715, 0.6083970816698789, 0.6046962327882502, 0.6024469758802751, 0.6017209722118645, 0.6023551532280795, 0.6039797700059278, 0.6060777659987401, 0.6080659758297897, 0.6093854315509099, 0.6095868235183761, 0.6083980797451739, 0.6057639282956314, 0.6018517237508911, 0.597023056710293, 0.5917759132363528, 0.5866666058859437, 0.5822236872224656, 0.5788671403582377, 0.5768451757355741, 0.5761981207938964, 0.5767546264076806, 0.5781604000922166, 0.5799346873268664, 0.5815455226124702, 0.5824919953529972, 0.5823808316440077, 0.5809856057664576, 0.5782796878617619, 0.5744381595279697, 0.5698087403607778, 0.5648565211328811, 0.5600912657044773, 0.555988624428683, 0.5529174159236553, 0.5510840754322882, 0.5505026184163433, 0.5509944665666106, 0.5522178563906246, 0.5537220137515942, 0.5550175258591409, 0.5556519426231719, 0.5552789463846806, 0.5537105320974508, 0.5509443521217701, 0.5471622691365767, 0.5427006192321856, 0.5379970255841477, 0.5335221568759502, 0.5297070561478181, 0.5268772452014121, 0.525203663769561, 0.5246778233679644, 0.52511476849078, 0.5261831340203849, 0.5274574326194176, 0.5284843357985376, 0.5288526374289241, 0.5282561155024185, 0.5265396951914999, 0.5237219667092047, 0.5199908053049255, 0.5156730017112674, 0.5111827965722889, 0.506957411480032, 0.5033895977904226, 0.5007675971214794, 0.4992316803478806, 0.4987538069520802, 0.49914333863270205, 0.5000777124204799, 0.5011531514515571, 0.5019474521628545, 0.5020850954148856, 0.5012946509178864, 0.49944970983495207, 0.49658718275580926, 0.49290032891620206, 0.488707788101645, 0.4844035660896271, 0.48039581418333005, 0.477043905500364, 0.4746034992929609, 0.4731879820326374, 0.47275209184932887, 0.4731000777101689, 0.47391695406054657, 0.4748178706410838, 0.4754078682261357, 0.4753427507657284, 0.4743817007130508, 0.4724236026173173, 0.4695216041504985, 0.46587383059045484, 0.46179185182430454, 0.45765191182570003, 0.45383654682429436, 0.45067564337651517, 0.4483960105097482, 0.447087167277817, 0.4466884991874867, 0.44699961390437093, 0.44771214472639104, 0.44845796211439926, 0.4488662627892626, 0.44862069025120677, 0.44750769455465067, 0.4454487453977246, 0.44251154586300656, 0.4388986574466517, 0.43491543363688323, 0.43092233148929865, 0.4272790424542907, 0.42428909540084087, 0.4221534573210971, 0.42094021865577885, 0.4205749300918723, 0.42085295051073146, 0.42147176920097346, 0.4220782032517793, 0.42232311231729114, 0.4219151603429619, 0.4206653516317795, 0.4185155384866343, 0.41554660580916875, 0.41196519233281026, 0.40807111939315666, 0.40421065579842164, 0.4007228983448215, 0.3978875654661059, 0.3958822362758116, 0.39475556702692094, 0.3944205197129682, 0.3946685331865537, 0.39520233701442625, 0.3956822502280288, 0.39577875903582993, 0.3952232402352232, 0.3938490270964992, 0.39161654422533665, 0.3886187253458263, 0.3850659849869693, 0.3812531680143077, 0.37751366389849483, 0.3741678222349867, 0.37147364642948655, 0.3695873500437865, 0.3685398018786368, 0.36823240742703733, 0.3684529622058267, 0.3689089331959963, 0.36927295269415344, 0.36923345369337823, 0.3685426190679176, 0.3670542694221363, 0.36474590080660285, 0.36172155373657267, 0.3581951643286363, 0.35445705716966425, 0.3508288241675219, 0.3476135980635498, 0.3450494056606786, 0.3432727751071477, 0.34229815801521085, 0.34201626447055045, 0.34221148037840293, 0.3425955953521332, 0.34285256859911756, 0.3426873832100668, 0.34187144052469093, 0.3402775132152138, 0.3378989158519545, 0.3348500075634101, 0.33134803104132227, 0.32767916849277257, 0.32415411512658476, 0.32106006351782007, 0.3186165158828197, 0.3169417197963431, 0.3160348568061744, 0.3157766638547839, 0.3159483151082995, 0.31626557788744314, 0.3164229143177021, 0.3161406890178831, 0.31520819211834517, 0.3135158613487855, 0.31107177840139905, 0.30799995853871537, 0.3045207688989074, 0.30091656464927274, 0.29748789892945565, 0.29450709496699007, 0.29217634930505976, 0.29059680914389213, 0.2897533504629246, 0.2895173451358887, 0.2896669232166248, 0.28992154102988693, 0.289985471790862, 0.2895934795037267, 0.28855162478981733, 0.28676692738332504, 0.28426135075355397, 0.2811680078923779, 0.2777102361535523, 0.27416682830483624, 0.2708288301944274, 0.2679545971346289, 0.2657300465979163, 0.2642402198179793, 0.2634565002234148, 0.26324140753081293, 0.2633701695024086, 0.26356568863643737, 0.263541466456743, 0.26304583862114284, 0.2619006934826947, 0.2600287195226867, 0.2574650152753497, 0.2543513203516842, 0.25091381203112323, 0.24742794368136478, 0.24417578909600676, 0.2414024958862041, 0.23927856830305877, 0.23787378034123893, 0.23714670860983933, 0.23695145324590294, 0.23706045926400054, 0.23719987042552937, 0.23709192493926676, 0.23649783195562213, 0.2352545124878759, 0.23329955370736247, 0.2306805597470799, 0.2275474998937606, 0.2241292818540617, 0.22069820795387626, 0.21752783142017648, 0.21485073310906794, 0.21282273377617306, 0.2114990467571303, 0.21082601922461797, 0.2106496956182469, 0.21073983828291185, 0.21082565907776288, 0.21063771846329374, 0.20994951106187817, 0.2086123213482583, 0.2065779874724217, 0.20390609015346525, 0.20075449524733138, 0.1973547496730857, 0.19397616389156166, 0.19088415067484685, 0.18829926302641112, 0.18636325115607963, 0.1851173606759197, 0.1844961932624329, 0.18433804201991635, 0.1844100694714803, 0.1844444093174547, 0.18417959605264317, 0.18340091660555163, 0.18197345841142964, 0.17986276880340699, 0.177139963288658, 0.173970526869839, 0.17058857075645156, 0.16726054782998195, 0.1642440488871896, 0.16174804951262262, 0.15990074079887048, 0.15872989451998146, 0.1581587691147325, 0.1580181584374762, 0.1580726925763771, 0.15805730391386352, 0.15771821032111716, 0.15685208066231732, 0.15533733997871968, 0.15315279594346143, 0.15038073382331227, 0.14719402959843864, 0.14382929857216178, 0.14055024883028128, 0.13760691373260342, 0.1351970641208875, 0.133435753910841, 0.1323376873816749, 0.1318151095792104, 0.13169152061815223, 0.13172907146092946, 0.13166539009524295, 0.1312541378395204, 0.1303030284121623, 0.12870344357867716, 0.12644708525947848, 0.12362711201141004, 0.12042360683185374, 0.11707564243220606, 0.11384427606744095, 0.110972200318141, 0.10864628462680072, 0.10696878763579083, 0.10594167395597505, 0.1054664399271001, 0.1053594553082502, 0.10538043222621815, 0.10526960889226125, 0.10478789550376942, 0.10375377939123574, 0.10207129428872316, 0.09974474506103216, 0.09687792925523993, 0.09365799322834487, 0.09032643301130643, 0.08714173229624682, 0.08433941640368953, 0.08209569395334115, 0.0805002975239895, 0.07954270835884757, 0.07911387921907294, 0.07902317417489219, 0.07902789456663843, 0.07887081925921376, 0.07831995394799308, 0.07720434841312275, 0.07544045330154345, 0.07304495380641522, 0.0701321094624391, 0.066896023680773, 0.06358059367002847, 0.06044179179822851, 0.05770811016058434, 0.05554527938414469, 0.05403070808318115, 0.05314158419297919, 0.0527584666657305, 0.05268380235727489, 0.052672498159298704, 0.05246981836043023, 0.051850748783609804, 0.05065474623697046, 0.048810508121566516, 0.046346941503927666, 0.04338864462373285, 0.04013660686602434, 0.03683711600806374, 0.03374368159624745, 0.031077859785948573, 0.028995032000787704, 0.027560421955187247, 0.026739051911777376, 0.026401184417573983, 0.02634240314801367, 0.026315225474467168, 0.02606735909066286, 0.025380690263481904, 0.024104980035660476, 0.022181063906696993, 0.01964997337407213, 0.016646573380861347, 0.013378702035637211, 0.010095038415254933, 0.007046664987648628, 0.00444826444212397, 0.0024449463003086242, 0.0010898281546827592, 0.0003358343200825437, 4.297788789474147e-5, -1.1607874655696521e-16, -4.2977887895026707e-5, -0.0003358343200826535, -0.001089828154682789, -0.002444946300308697, -0.004448264442123855, -0.007046664987647322, -0.010095038415255072, -0.013378702035637565, -0.016646573380861326, -0.019649973374072115, -0.0221810639066967, -0.024104980035660157, -0.02538069026348187, -0.026067359090662635, -0.026315225474467255, -0.026342403148013182, -0.02640118441757349, -0.02673905191177723, -0.027560421955186792, -0.028995032000787974, -0.031077859785949367, -0.03374368159624753, -0.03683711600806458, -0.04013660686602445, -0.04338864462373288, -0.046346941503928756, -0.0488105081215668, -0.05065474623697092, -0.0518507487836097, -0.05246981836043076, -0.05267249815929945, -0.052683802357274837, -0.05275846666573044, -0.05314158419297907, -0.0540307080831811, -0.05554527938414422, -0.05770811016058463, -0.06044179179822842, -0.06358059367002844, -0.06689602368077228, -0.07013210946243849, -0.07304495380641517, -0.07544045330154478, -0.07720434841312238, -0.07831995394799446, -0.07887081925921434, -0.07902789456663856, -0.0790231741748928, -0.07911387921907242, -0.07954270835884777, -0.08050029752398899, -0.08209569395334101, -0.08433941640368937, -0.08714173229624633, -0.09032643301130625, -0.09365799322834492, -0.09687792925524029, -0.09974474506103159, -0.1020712942887234, -0.10375377939123591, -0.10478789550376894, -0.10526960889226092, -0.10538043222621835, -0.10535945530825033, -0.10546643992710043, -0.10594167395597436, -0.10696878763579068, -0.10864628462680082, -0.11097220031814134, -0.11384427606744145, -0.11707564243220611, -0.12042360683185402, -0.12362711201140986, -0.1264470852594785, -0.1287034435786773, -0.1303030284121621, -0.13125413783952025, -0.13166539009524303, -0.13172907146092905, -0.13169152061815326, -0.13181510957921047, -0.13233768738167542, -0.1334357539108413, -0.1351970641208883, -0.137606913732604, -0.14055024883028097, -0.14382929857216045, -0.14719402959843866, -0.15038073382331216, -0.15315279594346143, -0.15533733997871962, -0.15685208066231768, -0.15771821032111666, -0.1580573039138635, -0.15807269257637657, -0.15801815843747613, -0.15815876911473264, -0.15872989451998165, -0.15990074079887062, -0.16174804951262323, -0.16424404888718905, -0.16726054782998198, -0.17058857075645187, -0.17397052686983921, -0.1771399632886577, -0.17986276880340699, -0.1819734584114292, -0.18340091660555213, -0.1841795960526431, -0.18444440931745504, -0.18441006947148064, -0.18433804201991622, -0.18449619326243222, -0.18511736067592013, -0.18636325115607946, -0.18829926302641134, -0.19088415067484676, -0.19397616389156186, -0.19735474967308608, -0.20075449524733116, -0.20390609015346478, -0.20657798747242123, -0.20861232134825736, -0.20994951106187765, -0.2106377184632938, -0.21082565907776304, -0.21073983828291182, -0.2106496956182472, -0.2108260192246179, -0.21149904675713035, -0.21282273377617278, -0.21485073310906866, -0.21752783142017632, -0.2206982079538764, -0.22412928185406078, -0.2275474998937606, -0.230680559747081, -0.23329955370736216, -0.2352545124878755, -0.23649783195562285, -0.23709192493926678, -0.2371998704255299, -0.23706045926399966, -0.23695145324590283, -0.2371467086098399, -0.23787378034123757, -0.23927856830305866, -0.24140249588620402, -0.24417578909600665, -0.247427943681365, -0.2509138120311221, -0.2543513203516849, -0.2574650152753493, -0.2600287195226862, -0.2619006934826944, -0.26304583862114145, -0.26354146645674276, -0.26356568863643726, -0.26337016950240855, -0.2632414075308129, -0.26345650022341544, -0.26424021981797957, -0.2657300465979163, -0.26795459713462827, -0.27082883019442666, -0.2741668283048362, -0.27771023615355295, -0.2811680078923775, -0.28426135075355285, -0.28676692738332515, -0.2885516247898171, -0.28959347950372716, -0.28998547179086404, -0.28992154102988504, -0.289666923216624, -0.2895173451358879, -0.28975335046292466, -0.290596809143893, -0.2921763493050597, -0.29450709496698935, -0.29748789892945526, -0.30091656464927213, -0.30452076889890783, -0.3079999585387154, -0.3110717784013999, -0.3135158613487862, -0.31520819211834494, -0.3161406890178825, -0.31642291431770236, -0.3162655778874423, -0.3159483151082987, -0.315776663854784, -0.3160348568061748, -0.31694171979634367, -0.31861651588281936, -0.32106006351781996, -0.3241541151265839, -0.3276791684927733, -0.331348031041322, -0.33485000756340977, -0.3378989158519542, -0.3402775132152139, -0.3418714405246909, -0.34268738321006675, -0.34285256859911734, -0.3425955953521331, -0.34221148037840216, -0.34201626447055117, -0.3422981580152108, -0.3432727751071462, -0.3450494056606799, -0.3476135980635494, -0.35082882416752076, -0.35445705716966375, -0.3581951643286362, -0.3617215537365722, -0.36474590080660185, -0.3670542694221369, -0.3685426190679184, -0.36923345369337757, -0.36927295269415283, -0.3689089331959961, -0.36845296220582746, -0.36823240742703744, -0.36853980187863583, -0.36958735004378634, -0.3714736464294869, -0.37416782223498635, -0.37751366389849433, -0.3812531680143078, -0.38506598498696887, -0.38861872534582664, -0.3916165442253364, -0.3938490270964987, -0.3952232402352227, -0.39577875903582976, -0.3956822502280303, -0.3952023370144249, -0.39466853318655176, -0.3944205197129689, -0.39475556702692105, -0.3958822362758099, -0.39788756546610515, -0.4007228983448213, -0.404210655798421, -0.4080711193931565, -0.41196519233280915, -0.4155466058091706, -0.41851553848663425, -0.42066535163177804, -0.42191516034296267, -0.4223231123172913, -0.42207820325178136, -0.4214717692009731, -0.42085295051073174, -0.42057493009187225, -0.42094021865577863, -0.4221534573210969, -0.42428909540084053, -0.42727904245429055, -0.43092233148929787, -0.4349154336368837, -0.43889865744665135, -0.44251154586300484, -0.44544874539772433, -0.44750769455465067, -0.4486206902512068, -0.4488662627892617, -0.44845796211439903, -0.4477121447263919, -0.4469996139043716, -0.4466884991874864, -0.44708716727781705, -0.4483960105097476, -0.4506756433765142, -0.4538365468242946, -0.45765191182570025, -0.46179185182430454, -0.4658738305904546, -0.4695216041504986, -0.472423602617316, -0.474381700713051, -0.47534275076572846, -0.47540786822613407, -0.47481787064108494, -0.4739169540605471, -0.473100077710169, -0.4727520918493284, -0.47318798203263607, -0.4746034992929605, -0.47704390550036374, -0.4803958141833291, -0.4844035660896259, -0.48870778810164395, -0.4929003289162017, -0.4965871827558096, -0.49944970983495296, -0.5012946509178864, -0.5020850954148848, -0.5019474521628559, -0.5011531514515576, -0.5000777124204796, -0.49914333863270166, -0.49875380695208027, -0.4992316803478813, -0.5007675971214787, -0.5033895977904222, -0.5069574114800318, -0.511182796572286, -0.5156730017112663, -0.5199908053049247, -0.5237219667092049, -0.5265396951914997, -0.528256115502418, -0.5288526374289242, -0.528484335798537, -0.5274574326194169, -0.5261831340203836, -0.5251147684907796, -0.5246778233679649, -0.5252036637695605, -0.5268772452014117, -0.5297070561478175, -0.5335221568759507, -0.5379970255841482, -0.5427006192321848, -0.5471622691365751, -0.55094435212177, -0.5537105320974496, -0.5552789463846791, -0.5556519426231713, -0.5550175258591405, -0.5537220137515951, -0.5522178563906253, -0.5509944665666089, -0.5505026184163426, -0.5510840754322893, -0.5529174159236546, -0.5559886244286834, -0.560091265704476, -0.5648565211328814, -0.5698087403607777, -0.5744381595279692, -0.5782796878617623, -0.5809856057664567, -0.5823808316440058, -0.5824919953529973, -0.581545522612471, -0.5799346873268661, -0.5781604000922179, -0.5767546264076803, -0.5761981207938951, -0.5768451757355736, -0.5788671403582373, -0.5822236872224662, -0.5866666058859442, -0.5917759132363544, -0.5970230567102938, -0.6018517237508891, -0.6057639282956319, -0.6083980797451727, -0.609586823518378, -0.6093854315509095, -0.6080659758297882, -0.6060777659987397, -0.6039797700059278, -0.6023551532280809, -0.6017209722118642, -0.6024469758802761, -0.6046962327882491, -0.6083970816698783, -0.6132511676580725, -0.6187767920670014, -0.6243813163660917, -0.6294517714798917, -0.6334498545239862, -0.6359966279460382, -0.6369336332662213, -0.6363506073957271, -0.6345750338367192, -0.6321246142116586, -0.629629512213365, -0.6277360078921904, -0.6270062502022948, -0.627829612924525, -0.630359584956117, -0.6344863697740138, -0.6398499805723638, -0.6458924076181989, -0.651941352247684, -0.6573130113787761, -0.661418249459707, -0.663855727006868, -0.6644773216496166, -0.6634152445140037, -0.6610660280224413, -0.6580331737503283, -0.6550366904082904, -0.652803022317497, -0.6519521330723633, -0.6528992293744049, -0.6557866328889056, -0.6604568946549139, -0.6664720393494883, -0.6731767559746867, -0.6797964969248312, -0.685555873510226, -0.6897993277869611, -0.6920953834868315, -0.6923079862194443, -0.6906232450844295, -0.6875265883677942, -0.6837329431282236, -0.6800798834307231, -0.6773996251672393, -0.6763893326182441, -0.6774998436406955, -0.6808604607103731, -0.6862522141156031, -0.6931347372219996, -0.7007236852662226, -0.7081077288047383, -0.7143877883395823, -0.7188173630728023, -0.7209221864870408, -0.7205801772473505, -0.7180483940786246, -0.713931625217349, -0.7090961701535047, -0.7045409467195167, -0.7012449479152154, -0.7000141782499938, -0.7013518217958356, -0.7053723575946776, -0.7117740263094909, -0.7198753621596468, -0.7287117204889337, -0.7371783367262722, -0.7441989054465379, -0.7488941780494416, -0.7507244128351498, -0.7495828711944743, -0.7458245236392999, -0.7402237294518537, -0.73386547451892, -0.7279851599820616, -0.7237803165927302, -0.722222630957386, -0.7238994459103396, -0.7289101996638818, -0.736835537941489, -0.746786141097074, -0.7575262094391193, -0.7676548599633138, -0.7758192123555364, -0.780927202286701, -0.7823271514261484, -0.7799251498648874, -0.7742199124479464, -0.7662467879633149, -0.7574363043735473, -0.7494059870600083, -0.7437151398451775, -0.7416190767333078, -0.7438607540351589, -0.7505334650540422, -0.7610386667933318, -0.7741493726116037, -0.7881737848193194, -0.8011982652857454, -0.811375715495735, -0.8172170297226122, -0.8178409496359369, -0.8131419840202898, -0.8038466860410596, -0.791444170110647, -0.7779951755669183, -0.7658426122333776, -0.7572625831536735, -0.7541058678201825, -0.7574839047881885, -0.7675495174115007, -0.7834111751087265, -0.8032017974821938, -0.824301292008052, -0.8436891550187132, -0.8583828281990061, -0.8659021787873111, -0.8646929238841384, -0.8544435078761586, -0.8362410787097126, -0.8125316795395301, -0.786875244412886, -0.7635141906670996, -0.7468015526069889, -0.7405569094951082, -0.7474325512412715, -0.7683761073790509, -0.8022682794267051, -0.8457959073003175, -0.8935933858766653, -0.9386526919072605, -0.9729680883386264, -0.9883503591663617, -0.977321340546484, -0.9339858738443408, -0.8547771507913878, -0.7389832096736668, -0.5889858683789176, -0.4101759047861939, -0.210545879210157, ], [0.0, 0.10596191173407948, 0.21050290132226296, 0.31223581329223304, 0.4098400704660948, 0.5020926048813802, 0.587896040224223, 0.6663033424269199, 0.7365382633733552, 0.7980110313017734, 0.8503288863492651, 0.8933012160890146, 0.926939208856687, 0.9514501069435597, 0.9672263021312276, 0.9748296674913509, 0.9749716571307243, 0.9684898253606065, 0.9563215149577644, 0.9394755378429334, 0.9190027185331912, 0.8959661899176404, 0.87141232196997, 0.8463431275781622, 0.8216909272646581, 0.7982959685478414, 0.7768875891632271, 0.7580693900625606, 0.7423087482883897, 0.7299308560858109, 0.7211173257668041, 0.7159092547272283, 0.7142145063470954, 0.7158188347128872, 0.7204003681894139, 0.7275468722829551, 0.7367751387553212, 0.7475517975964019, 0.7593148224577001, 0.7714949988686219, 0.7835366475387434, 0.7949169410190465, 0.8051632189237623, 0.813867792098049, 0.8206998262799098, 0.8254140072225314, 0.8278558078760749, 0.8279632998664639, 0.825765571921287, 0.8213779329730009, 0.8149941835752758, 0.8068763325515912, 0.7973422135147872, 0.7867515157114505, 0.7754907838864855, 0.7639579615797348, 0.752547051245225, 0.7416334433440923, 0.7315604263251179, 0.7226273320374308, 0.7150796990510704, 0.7091017524958083, 0.704811406628889, 0.7022578989060988, 0.7014220654629079, 0.702219171194747, 0.7045041164879426, 0.7080787602541446, 0.7127010280403977, 0.7180954169308015, 0.7239644674837359, 0.7300007482205872, 0.7358988907265045, 0.7413672231316144, 0.7461385758804746, 0.7499798749521943, 0.7527001922220603, 0.7541569881671225, 0.7542603559659082, 0.7529751553252638, 0.7503210060418404, 0.7463701922998615, 0.7412436060332956, 0.7351049285453152, 0.728153311479328, 0.7206148690519246, 0.7127333315177762, 0.704760233973867, 0.6969450241984819, 0.6895254681851486, 0.6827187128453379, 0.6767133330071355, 0.671662645794828, 0.6676795216111301, 0.6648328594685197, 0.663145827783143, 0.6625959025584149, 0.6631166658002488, 0.6646012606261048, 0.6669073383134795, 0.6698632787011591, 0.6732754208137478, 0.6769360068495575, 0.6806315208432854, 0.684151094009974, 0.6872946521340546, 0.6898804960442593, 0.6917520334087381, 0.6927834175686405, 0.6928838953062503, 0.6920007184148427, 0.6901205315734312, 0.6872692090583545, 0.6835101729177525, 0.6789412831002987, 0.6736904434921137, 0.667910114902282, 0.6617709650472514, 0.6554549151503974, 0.6491478619083126, 0.6430323617058384, 0.6372805609416535, 0.6320476424377207, 0.6274660338424392, 0.6236405907716207, 0.6206449265684891, 0.618519013684122, 0.6172681306695721, 0.6168631756491362, 0.6172423139824245, 0.6183138766656275, 0.6199603788095832, 0.6220434860260862, 0.6244097222738367, 0.62689668688313, 0.6293395319748221, 0.6315774448250525, 0.6334598830285609, 0.6348523233211585, 0.635641307006872, 0.6357385951270352, 0.6350842835369224, 0.633648770402944, 0.6314335145964746, 0.628470571217587, 0.6248209381650045, 0.620571793435129, 0.6158327449368743, 0.610731251466795, 0.605407403738414, 0.600008276910256, 0.5946820801283622, 0.5895723337474845, 0.5848123010271152, 0.5805198884699709, 0.5767932081734276, 0.5737069674994353, 0.5713098172077316, 0.5696227503349071, 0.5686386020991245, 0.5683226576318423, 0.5686143310875085, 0.5694298383360059, 0.5706657475845843, 0.5722032593378427, 0.5739130403115353, 0.5756604162426641, 0.5773107166723059, 0.5787345610964605, 0.5798128804400601, 0.5804414803460217, 0.5805349727137336, 0.5800299284170674, 0.5788871360825664, 0.5770928879148786, 0.5746592523714867, 0.5716233334743644, 0.5680455561342146, 0.5640070545162696, 0.5596062747483967, 0.5549549328612782, 0.5501734926501659, 0.5453863452875028, 0.5407168823922024, 0.5362826565596823, 0.5321908180596209, 0.5285340037944856, 0.525386835241911, 0.52280315679651, 0.5208141157238533, 0.5194271510643175, 0.5186259226323859, 0.5183711741807678, 0.5186024882930256, 0.5192408560525058, 0.5201919533385019, 0.5213499889107431, 0.5226019682625132, 0.5238322023293537, 0.5249268820609957, 0.5257785388551695, 0.5262902168916865, 0.5263791961972403, 0.5259801242585536, 0.5250474383894616, 0.5235569898479722, 0.5215068127303809, 0.5189170146623913, 0.5158288009171228, 0.5123026774588724, 0.5084159102215682, 0.5042593464550141, 0.4999337281214238, 0.4955456461900558, 0.49120329756325565, 0.48701221281625495, 0.4830711227439376, 0.4794681249399043, 0.47627729860227064, 0.47355589702514567, 0.47134222357145117, 0.46965426929540366, 0.46848915990292445, 0.46782342762003176, 0.46761409105244683, 0.4678004945388412, 0.46830682905506504, 0.4690452305546042, 0.46991932973237016, 0.47082811040119826, 0.47166992259424995, 0.47234649154456, 0.4727667649960323, 0.47285044877273635, 0.4725310938391567, 0.4717586166608356, 0.47050115775805423, 0.4687462099989579, 0.466500977333782, 0.46379195516876065, 0.4606637542123759, 0.4571772191961674, 0.4534069212253095, 0.4494381265930113, 0.4453633647752643, 0.44127873325656625, 0.43728008626994014, 0.43345925813177183, 0.42990046951048666, 0.42667705681795937, 0.4238486513107199, 0.4214589160026786, 0.4195339258783539, 0.41808125106845645, 0.4170897746416133, 0.41653024758937657, 0.4163565545840477, 0.41650763630756454, 0.4169099886675726, 0.4174806370198901, 0.41813046545623456, 0.41876776797634907, 0.41930188043548505, 0.41964674982132844, 0.4197243007276535, 0.4194674676921547, 0.41882277597555007, 0.4177523718025549, 0.41623542531234564, 0.4142688545823685, 0.4118673460919776, 0.40906267481044684, 0.40590235463022406, 0.4024476760410077, 0.3987712117328124, 0.39495389130521963, 0.3910817626587318, 0.38724256933070866, 0.38352227957912494, 0.3800017041830644, 0.37675333570244995, 0.3738385325157091, 0.37130515673061176, 0.3691857566245652, 0.3674963623627201, 0.366235939244365, 0.36538651661627514, 0.364913983900553, 0.3647695189659619, 0.36489158935403115, 0.36520844461824015, 0.36564099910883024, 0.36610598966468855, 0.36651928242183063, 0.3667991976956804, 0.3668697218236976, 0.3666634799435857, 0.36612435370531204, 0.3652096424549033, 0.3638916848909936, 0.36215887983701184, 0.36001606872654907, 0.3574842677140513, 0.3545997629956066, 0.3514126079439472, 0.3479845840467408, 0.3443867084755412, 0.34069638859620677, 0.33699433718760713, 0.33336137105266805, 0.3298752197536581, 0.32660747024432635, 0.3236207672648011, 0.32096637876496026, 0.31868222076572256, 0.3167914175581981, 0.31530145172180035, 0.3142039349694519, 0.3134750062331656, 0.31307633865922124, 0.31295671326308283, 0.31305409483817975, 0.31329812618426556, 0.3136129405782039, 0.3139201802742376, 0.31414210115364205, 0.31420464072895005, 0.31404032864152376, 0.31359092547784156, 0.3128096868875611, 0.31166316515857057, 0.3101324789779972, 0.30821400333917076, 0.30591945459689285, 0.3032753696129041, 0.300322001824309, 0.2971116799693841, 0.29370669621972423, 0.29017680877441565, 0.2865964588602067, 0.28304181297745246, 0.27958774772045175, 0.27630489634306404, 0.2732568733800241, 0.27049778620075104, 0.2680701306743704, 0.26600315263782026, 0.2643117382064238, 0.26299587489376924, 0.26204070285330777, 0.26141715221566314, 0.26108313939462097, 0.26098527328045473, 0.2610610022925882, 0.26124111610368606, 0.26145250214098265, 0.26162104724673196, 0.26167456950821616, 0.261545664446534, 0.2611743535001742, 0.26051043089215037, 0.2595154171931831, 0.258164043693716, 0.25644521044089846, 0.25436238173540776, 0.25193340518545937, 0.24918976319854347, 0.2461752881561625, 0.24294439358305506, 0.23955989256370006, 0.23609049073517727, 0.23260805377139043, 0.22918475788339968, 0.22589023616615123, 0.22278883346580283, 0.21993707784285413, 0.21738146785806747, 0.2151566621712546, 0.2132841418301161, 0.2117713967818175, 0.210611667313969, 0.20978424915053231, 0.20925534866126214, 0.20897945296929923, 0.20890115951317997, 0.20895739163054058, 0.20907991168460535, 0.20919803173672905, 0.20924141422404222, 0.2091428518180388, 0.20884091673659874, 0.208282375204409, 0.20742427228071783, 0.20623560551327222, 0.20469852230233962, 0.2028089948135495, 0.20057694700964554, 0.19802583005746055, 0.19519166415017766, 0.1921215858070354, 0.1888719591457946, 0.18550612670480068, 0.1820918894529707, 0.17869881611965324, 0.17539548849605333, 0.1722467916572941, 0.16931135604759784, 0.16663925214894298, 0.1642700282699653, 0.1622311682569427, 0.16053702919130525, 0.15918830006461282, 0.15817200176934612, 0.15746202732996098, 0.15702019996947847, 0.15679780620378073, 0.15673754248153005, 0.15677579767565544, 0.15684518061749117, 0.1568771923582786, 0.15680493731333245, 0.1565657661023869, 0.15610374579087816, 0.1553718602394515, 0.154333854099305, 0.1529656482150291, 0.1512562712473634, 0.14920827152822097, 0.14683759374822167, 0.1441729262383594, 0.141254545506316, 0.13813270450031415, 0.13486562901923696, 0.1315172020620375, 0.12815442810959932, 0.12484477788176046, 0.12165351867950883, 0.1186411358331588, 0.11586094702170914, 0.11335700346087982, 0.11116236048995166, 0.10929778537826666, 0.10777095280621006, 0.10657615914166348, 0.10569456609696481, 0.10509496342543671, 0.10473501982681056, 0.10456297198547666, 0.10451968442229169, 0.10454099827759857, 0.10456027583153248, 0.10451103994997331, 0.10432960401675999, 0.10395758841337581, 0.10334422421159771, 0.10244835326602338, 0.10124004599114891, 0.09970177330021043, 0.09782908686723374, 0.09563078134894569, 0.09312853269874277, 0.09035602741072356, 0.0873576176303477, 0.08418655576688326, 0.08090287880467158, 0.0775710262838292, 0.07425728636511797, 0.07102717109939068, 0.06794282472780558, 0.06506056744181236, 0.062428671590476985, 0.06008545805530205, 0.058057787788465216, 0.056360007834427635, 0.05499339314848643, 0.0539461059016458, 0.053193673495322516, 0.052699966010269814, 0.052418634091263655, 0.052294950100488284, 0.05226797947451852, 0.05227299621844714, 0.05224404687744989, 0.05211656151755762, 0.05182990845057968, 0.051329791721556293, 0.050570396648293624, 0.04951619871158527, 0.04814336444444134, 0.04644068913015672, 0.04441003445278622, 0.04206624902458912, 0.03943657516149408, 0.03655956558194143, 0.03348355306491484, 0.030264733755431237, 0.026964940053838034, 0.02364919126775772, 0.020383118960017516, 0.01723036884786219, 0.014250082005104404, 0.011494554955250102, 0.009007171146336299, 0.006820685548324721, 0.004955930136999413, 0.0034209913770000414, 0.002210892149061905, 0.0013077906203782387, 0.0006816881201237635, 0.0002916179618291708, 8.726814504690101e-5, 1.0973721232901479e-5, -2.982339157175221e-17, -1.0973721233789546e-5, -8.726814504712807e-5, -0.00029161796182888, -0.0006816881201236228, -0.0013077906203782087, -0.0022108921490613326, -0.0034209913769999586, -0.00495593013699945, -0.006820685548324623, -0.009007171146336158, -0.01149455495524996, -0.014250082005104265, -0.017230368847862273, -0.020383118960017467, -0.02364919126775765, -0.026964940053837365, -0.030264733755430814, -0.033483553064914295, -0.036559565581940824, -0.039436575161494195, -0.042066249024589934, -0.0444100344527864, -0.04644068913015702, -0.048143364444441554, -0.04951619871158551, -0.050570396648293756, -0.051329791721556564, -0.051829908450579186, -0.05211656151755768, -0.05224404687745029, -0.0522729962184469, -0.05226797947451838, -0.05229495010048824, -0.05241863409126334, -0.05269996601026998, -0.053193673495322225, -0.05394610590164602, -0.054993393148485864, -0.056360007834427295, -0.05805778778846465, -0.06008545805530186, -0.06242867159047685, -0.06506056744181254, -0.06794282472780536, -0.07102717109939105, -0.07425728636511805, -0.07757102628382916, -0.08090287880467208, -0.08418655576688273, -0.08735761763034769, -0.09035602741072327, -0.09312853269874265, -0.0956307813489452, -0.09782908686723338, -0.09970177330021028, -0.10124004599114905, -0.10244835326602343, -0.10334422421159749, -0.10395758841337585, -0.10432960401675978, -0.10451103994997318, -0.10456027583153212, -0.10454099827759875, -0.10451968442229173, -0.10456297198547661, -0.10473501982681034, -0.10509496342543659, -0.10569456609696463, -0.10657615914166362, -0.10777095280621042, -0.1092977853782668, -0.11116236048995169, -0.11335700346087955, -0.11586094702170918, -0.11864113583315876, -0.12165351867950838, -0.12484477788176036, -0.12815442810959912, -0.13151720206203685, -0.1348656290192366, -0.13813270450031426, -0.1412545455063161, -0.14417292623835914, -0.14683759374822197, -0.14920827152822133, -0.15125627124736307, -0.15296564821502837, -0.15433385409930525, -0.15537186023945135, -0.1561037457908781, -0.1565657661023864, -0.15680493731333248, -0.1568771923582782, -0.15684518061749134, -0.1567757976756549, -0.15673754248152985, -0.1567978062037809, -0.1570201999694785, -0.15746202732996106, -0.15817200176934706, -0.15918830006461226, -0.16053702919130536, -0.16223116825694306, -0.16427002826996506, -0.16663925214894348, -0.16931135604759767, -0.17224679165729376, -0.17539548849605352, -0.17869881611965327, -0.18209188945297086, -0.18550612670480057, -0.18887195914579435, -0.19212158580703434, -0.19519166415017744, -0.19802583005746024, -0.20057694700964565, -0.2028089948135494, -0.20469852230233926, -0.20623560551327252, -0.20742427228071753, -0.20828237520440884, -0.20884091673659835, -0.20914285181803816, -0.20924141422404197, -0.20919803173672893, -0.20907991168460546, -0.20895739163054058, -0.2089011595131801, -0.20897945296929926, -0.20925534866126205, -0.20978424915053226, -0.2106116673139695, -0.21177139678181728, -0.21328414183011649, -0.21515666217125393, -0.21738146785806747, -0.21993707784285393, -0.2227888334658025, -0.2258902361661508, -0.22918475788340012, -0.23260805377139065, -0.23609049073517788, -0.23955989256370003, -0.2429443935830543, -0.24617528815616266, -0.2491897631985429, -0.25193340518545954, -0.25436238173540754, -0.2564452104408985, -0.25816404369371604, -0.2595154171931826, -0.26051043089215065, -0.2611743535001741, -0.26154566444653443, -0.26167456950821616, -0.26162104724673224, -0.26145250214098253, -0.26124111610368594, -0.26106100229258794, -0.26098527328045434, -0.26108313939462235, -0.26141715221566364, -0.26204070285330766, -0.2629958748937682, -0.26431173820642406, -0.2660031526378204, -0.2680701306743716, -0.2704977862007508, -0.2732568733800229, -0.27630489634306404, -0.2795877477204517, -0.2830418129774529, -0.2865964588602069, -0.2901768087744152, -0.2937066962197234, -0.2971116799693839, -0.30032200182430896, -0.30327536961290524, -0.3059194545968927, -0.30821400333917043, -0.3101324789779971, -0.3116631651585702, -0.31280968688756206, -0.313590925477842, -0.31404032864152404, -0.3142046407289499, -0.3141421011536423, -0.3139201802742377, -0.31361294057820427, -0.3132981261842653, -0.3130540948381797, -0.31295671326308294, -0.31307633865922135, -0.313475006233166, -0.31420393496945187, -0.3153014517218005, -0.3167914175581973, -0.31868222076572283, -0.32096637876496037, -0.32362076726480027, -0.32660747024432585, -0.3298752197536575, -0.33336137105266833, -0.3369943371876069, -0.3406963885962069, -0.34438670847554087, -0.3479845840467409, -0.35141260794394735, -0.35459976299560625, -0.35748426771405034, -0.36001606872654995, -0.3621588798370116, -0.3638916848909932, -0.3652096424549033, -0.3661243537053124, -0.36666347994358583, -0.36686972182369737, -0.366799197695681, -0.36651928242183096, -0.3661059896646885, -0.36564099910883024, -0.3652084446182404, -0.3648915893540321, -0.3647695189659616, -0.3649139839005524, -0.3653865166162751, -0.36623593924436515, -0.36749636236272043, -0.36918575662456443, -0.3713051567306124, -0.3738385325157088, -0.3767533357024494, -0.38000170418306434, -0.3835222795791246, -0.387242569330708, -0.39108176265873185, -0.39495389130522063, -0.39877121173281094, -0.4024476760410067, -0.40590235463022484, -0.4090626748104476, -0.4118673460919764, -0.41426885458236806, -0.4162354253123455, -0.41775237180255437, -0.4188227759755501, -0.4194674676921544, -0.4197243007276548, -0.4196467498213283, -0.41930188043548494, -0.4187677679763492, -0.41813046545623495, -0.41748063701989024, -0.4169099886675723, -0.416507636307565, -0.4163565545840478, -0.41653024758937657, -0.4170897746416128, -0.41808125106845684, -0.4195339258783538, -0.42145891600267826, -0.4238486513107201, -0.42667705681795903, -0.42990046951048533, -0.43345925813177105, -0.4372800862699405, -0.44127873325656647, -0.4453633647752637, -0.44943812659301097, -0.45340692122530896, -0.457177219196168, -0.4606637542123752, -0.46379195516876087, -0.4665009773337812, -0.46874620999895805, -0.47050115775805507, -0.47175861666083535, -0.47253109383915654, -0.4728504487727364, -0.47276676499603254, -0.47234649154455893, -0.47166992259425045, -0.4708281104011988, -0.4699193297323695, -0.46904523055460445, -0.4683068290550657, -0.4678004945388408, -0.46761409105244595, -0.46782342762003126, -0.46848915990292467, -0.4696542692954037, -0.4713422235714506, -0.4735558970251453, -0.47627729860226947, -0.4794681249399048, -0.4830711227439376, -0.48701221281625445, -0.49120329756325554, -0.49554564619005503, -0.4999337281214247, -0.5042593464550147, -0.5084159102215685, -0.5123026774588718, -0.5158288009171222, -0.5189170146623918, -0.5215068127303802, -0.5235569898479727, -0.5250474383894612, -0.5259801242585527, -0.5263791961972397, -0.5262902168916856, -0.5257785388551703, -0.5249268820609954, -0.5238322023293536, -0.5226019682625132, -0.5213499889107428, -0.5201919533385021, -0.5192408560525059, -0.5186024882930254, -0.5183711741807681, -0.5186259226323855, -0.5194271510643178, -0.5208141157238536, -0.5228031567965106, -0.5253868352419108, -0.5285340037944853, -0.5321908180596201, -0.5362826565596822, -0.5407168823922022, -0.5453863452875019, -0.5501734926501655, -0.5549549328612775, -0.5596062747483971, -0.5640070545162699, -0.5680455561342141, -0.5716233334743639, -0.5746592523714872, -0.5770928879148778, -0.5788871360825656, -0.5800299284170671, -0.5805349727137344, -0.5804414803460222, -0.5798128804400603, -0.5787345610964608, -0.577310716672305, -0.5756604162426634, -0.5739130403115358, -0.5722032593378436, -0.5706657475845839, -0.5694298383360064, -0.5686143310875079, -0.5683226576318422, -0.5686386020991242, -0.569622750334907, -0.5713098172077319, -0.5737069674994353, -0.5767932081734285, -0.5805198884699713, -0.5848123010271138, -0.5895723337474846, -0.5946820801283611, -0.6000082769102573, -0.605407403738414, -0.6107312514667942, -0.6158327449368746, -0.6205717934351288, -0.6248209381650045, -0.6284705712175869, -0.6314335145964757, -0.6336487704029432, -0.6350842835369221, -0.6357385951270356, -0.6356413070068727, -0.6348523233211572, -0.6334598830285608, -0.6315774448250527, -0.6293395319748217, -0.6268966868831298, -0.6244097222738367, -0.622043486026086, -0.6199603788095834, -0.6183138766656278, -0.617242313982424, -0.6168631756491363, -0.6172681306695718, -0.6185190136841218, -0.6206449265684889, -0.6236405907716213, -0.6274660338424392, -0.6320476424377196, -0.6372805609416549, -0.6430323617058378, -0.6491478619083125, -0.6554549151503972, -0.6617709650472511, -0.6679101149022802, -0.6736904434921143, -0.6789412831002987, -0.6835101729177516, -0.6872692090583544, -0.6901205315734317, -0.6920007184148428, -0.6928838953062506, -0.69278341756864, -0.691752033408739, -0.6898804960442593, -0.6872946521340553, -0.6841510940099738, -0.6806315208432846, -0.6769360068495579, -0.6732754208137472, -0.6698632787011602, -0.66690733831348, -0.6646012606261044, -0.6631166658002495, -0.6625959025584143, -0.6631458277831433, -0.6648328594685198, -0.6676795216111299, -0.6716626457948284, -0.6767133330071357, -0.6827187128453372, -0.6895254681851489, -0.6969450241984807, -0.704760233973868, -0.7127333315177763, -0.7206148690519238, -0.7281533114793278, -0.7351049285453147, -0.7412436060332955, -0.7463701922998612, -0.7503210060418387, -0.7529751553252629, -0.7542603559659085, -0.7541569881671218, -0.7527001922220616, -0.7499798749521948, -0.7461385758804744, -0.7413672231316141, -0.7358988907265057, -0.730000748220587, -0.7239644674837353, -0.7180954169308021, -0.7127010280403988, -0.708078760254144, -0.704504116487943, -0.7022191711947476, -0.7014220654629072, -0.7022578989060996, -0.7048114066288899, -0.7091017524958079, -0.7150796990510709, -0.7226273320374298, -0.7315604263251173, -0.7416334433440902, -0.7525470512452256, -0.7639579615797338, -0.7754907838864847, -0.7867515157114513, -0.7973422135147867, -0.8068763325515903, -0.8149941835752759, -0.8213779329730008, -0.825765571921287, -0.8279632998664641, -0.8278558078760752, -0.8254140072225308, -0.8206998262799107, -0.813867792098049, -0.805163218923762, -0.7949169410190464, -0.7835366475387441, -0.7714949988686227, -0.7593148224577001, -0.7475517975964023, -0.7367751387553209, -0.7275468722829564, -0.7204003681894136, -0.7158188347128875, -0.7142145063470948, -0.7159092547272287, -0.7211173257668041, -0.7299308560858107, -0.7423087482883882, -0.758069390062561, -0.7768875891632262, -0.7982959685478401, -0.8216909272646553, -0.8463431275781615, -0.8714123219699691, -0.8959661899176391, -0.9190027185331922, -0.9394755378429329, -0.9563215149577642, -0.9684898253606056, -0.9749716571307244, -0.9748296674913512, -0.967226302131228, -0.9514501069435619, -0.9269392088566863, -0.893301216089016, -0.850328886349269, -0.7980110313017723, -0.7365382633733562, -0.6663033424269241, -0.5878960402242305, -0.502092604881379, -0.4098400704660969, -0.3122358132922404, -0.21050290132227215, -0.10596191173407876, ], [0.0, 0.05306878779187182, 0.1059509380128464, 0.1584609574356115, 0.21041563317721587, 0.26163515208037635, 0.3119441953304041, 0.3611730003590857, 0.4091583823459708, 0.45574470794733973, 0.5007848142610019, 0.544140866467847, 0.5856851480751615, 0.6253007782180968, 0.66288235104992, 0.6983364928640596, 0.7315823332363556, 0.7625518871490676, 0.7911903457534488, 0.8174562741393958, 0.8413217152029286, 0.8627721994291363, 0.8818066611337643, 0.8984372624249262, 0.9126891268515831, 0.9245999853917968, 0.9342197380956971, 0.9416099353303579, 0.9468431831712103, 0.9500024780425409, 0.9511804762235927, 0.9504787043027708, 0.9480067170768869, 0.9438812097520617, 0.9382250916051756, 0.9311665285074169, 0.92283796189285, 0.9133751118738365, 0.902915972260992, 0.8915998052380188, 0.8795661433716973, 0.8669538065054841, 0.8538999408930511, 0.840539087677682, 0.8270022875171837, 0.8134162277948153, 0.7999024384480049, 0.7865765419921026, 0.7735475628202164, 0.7609173003267399, 0.7487797698362558, 0.7372207147260776, 0.7263171925149335, 0.7161372370575951, 0.7067395983410045, 0.698173560727222, 0.6904788398378101, 0.6836855576285622, 0.6778142945682535, 0.6728762172155276, 0.6688732788893542, 0.66579849055549, 0.6636362585087813, 0.6623627849232334, 0.6619465268725768, 0.6623487089972367, 0.6635238846123991, 0.6654205397190766, 0.6679817340981498, 0.6711457734381626, 0.6748469062726857, 0.6790160393832813, 0.6835814652599985, 0.6884695952028859, 0.6936056916947562, 0.6989145937764035, 0.7043214293092138, 0.7097523082147553, 0.7151349910341939, 0.7203995274494946, 0.7254788597502787, 0.7303093866114765, 0.7348314829637443, 0.7389899721870234, 0.7427345473332853, 0.7460201385829663, 0.7488072246562365, 0.7510620864302457, 0.7527570015521052, 0.7538703793796404, 0.7543868361231405, 0.754297210596521, 0.7535985215109567, 0.7522938677534431, 0.7503922735826344, 0.7479084811404636, 0.7448626931166153, 0.7412802688102424, 0.7371913772061182, 0.7326306110177137, 0.7276365659449279, 0.7222513896471091, 0.7165203051408676, 0.7104911134955336, 0.7042136808160439, 0.6977394145729976, 0.6911207343625052, 0.6844105421546165, 0.677661697019252, 0.6709264992039182, 0.6642561882795245, 0.6577004598713747, 0.6513070052540759, 0.6451210778144164, 0.6391850900780687, 0.6335382446575342, 0.6282162021135203, 0.6232507883335593, 0.6186697436240558, 0.6144965152889804, 0.6107500950343117, 0.6074449020960845, 0.6045907125458354, 0.6021926347843687, 0.6002511307973563, 0.598762082318159, 0.5977169006285002, 0.5971026783297894, 0.5969023810406159, 0.5970950766224856, 0.5976561992092705, 0.5985578450189212, 0.599769096661132, 0.601256372424071, 0.6029837968287078, 0.6049135885822218, 0.6070064619434338, 0.6092220374339615, 0.6115192577891385, 0.6138568050426065, 0.6161935146775255, 0.6184887828546755, 0.6207029628423211, 0.6227977469229864, 0.6247365302365527, 0.6264847532351335, 0.6280102196707349, 0.629283387309017, 0.6302776288587651, 0.6309694609238242, 0.6313387391190352, 0.6313688178407785, 0.6310466735425516, 0.6303629907331049, 0.629312210285362, 0.6278925400151846, 0.6261059278563091, 0.6239579983181662, 0.6214579532632266, 0.6186184383768963, 0.6154553770226037, 0.6119877734747091, 0.6082374877995467, 0.6042289849077591, 0.5999890605269796, 0.5955465470408725, 0.5909320023069562, 0.5861773847002058, 0.5813157177311062, 0.5763807476549858, 0.5714065975237034, 0.5664274211311082, 0.5614770602704126, 0.556588708655368, 0.5517945857588388, 0.5471256236932712, 0.5426111700991767, 0.5382787098219051, 0.5341536079456972, 0.5302588765195677, 0.5266149670544599, 0.5232395905979872, 0.5201475669047488, 0.5173507039196068, 0.5148577084814957, 0.51267412883936, 0.5108023292528512, 0.5092414966315693, 0.5079876788510284, 0.5070338540742074, 0.506370030107487, 0.5059833725318257, 0.5058583600768847, 0.505976965450078, 0.5063188595964677, 0.5068616371513027, 0.5075810606566266, 0.5084513209485179, 0.5094453109835183, 0.5105349102628371, 0.5116912769318127, 0.5128851445804785, 0.5140871207491352, 0.5152679841506359, 0.5163989776582522, 0.5174520941740138, 0.5184003525863429, 0.519218061146558, 0.5198810657400089, 0.520366980697567, 0.5206553999851111, 0.5207280868204566, 0.520569139996661, 0.5201651354353788, 0.5195052417514439, 0.518581308877508, 0.5173879290725872, 0.5159224699181103, 0.5141850791865976, 0.5121786617487734, 0.5099088289618718, 0.5073838212524535, 0.504614404868631, 0.501613744026611, 0.49839724991256024, 0.49498240822075773, 0.4913885871107172, 0.48763682764759686, 0.48374961895012125, 0.47975066040693387, 0.47566461343465355, 0.471516845337911, 0.46733316789263624, 0.4631395733084203, 0.4589619702337018, 0.4548259224489219, 0.4507563928480574, 0.44677749523859805, 0.44291225639504084, 0.4391823906823722, 0.43560808942512047, 0.43220782703596106, 0.428998185737245, 0.4259937005111109, 0.4232067257011218, 0.42064732446280256, 0.41832318202440066, 0.41623954347503117, 0.4143991765475792, 0.41280235961082207, 0.41144689483176, 0.4103281462175746, 0.40943910199951644, 0.40877046058074107, 0.4083107390390315, 0.40804640295570893, 0.40796201613595595, 0.40804040859521573, 0.4082628610131248, 0.4086093037021674, 0.40905852800436565, 0.40958840791704765, 0.410176129659051, 0.4107984268224048, 0.4114318187121173, 0.412052849458445, 0.4126383254920195, 0.413165549002245, 0.41361254505301354, 0.4139582801063908, 0.41418286980356794, 0.4142677739720085, 0.41419597696698496, 0.4139521516131454, 0.4135228051857074, 0.41289640605948846, 0.41206348985535574, 0.41101674412565875, 0.40975107084072115, 0.40826362616518036, 0.4065538372436358, 0.40462339594737456, 0.4024762297655318, 0.4001184502526323, 0.39755827966776625, 0.3948059566564912, 0.39187362203277465, 0.38877518591291954, 0.3855261776345324, 0.38214358005948174, 0.37864565000884226, 0.3750517267086419, 0.3713820302365852, 0.3676574520508231, 0.3638993397514144, 0.36012927827284175, 0.3563688697313875, 0.35263951415419253, 0.3489621932975158, 0.3453572597203353, 0.34184423321654045, 0.33844160662524725, 0.3351666629351793, 0.3320353054767047, 0.3290619028553344, 0.32625915012470186, 0.3236379475269407, 0.32120729794567443, 0.3189742240234372, 0.3169437056932387, 0.3151186386652113, 0.31349981419597933, 0.31208592025270326, 0.31087356396784327, 0.3098573150667488, 0.3090297697403118, 0.30838163423122095, 0.307901827206867, 0.3075775998065364, 0.3073946720770785, 0.3073373843513873, 0.30738886197936155, 0.30753119169288723, 0.30774560777606175, 0.3080126861203429, 0.3083125441724481, 0.3086250447312769, 0.30893000151930416, 0.3092073844440738, 0.30943752247655637, 0.30960130210511183, 0.3096803593761613, 0.30965726360484375, 0.3095156909301107, 0.3092405859979345, 0.3088183101823544, 0.30823677489570944, 0.307485558695077, 0.3065560070600361, 0.3054413138957354, 0.30413658400295757, 0.3026388759515457, 0.30094722499328214, 0.299062645852912, 0.29698811543961534, 0.29472853572367147, 0.29229067722235924, 0.2896831037332891, 0.28691607914046363, 0.2840014572964783, 0.2809525561517625, 0.2777840174568567, 0.2745116535049802, 0.27115228250817913, 0.2677235543099057, 0.2642437682289513, 0.2607316849033646, 0.25720633405763565, 0.25368682015150396, 0.2501921278840629, 0.24674092952210708, 0.24335139599723266, 0.2400410136721684, 0.23682640861378895, 0.2337231801289322, 0.2307457452203156, 0.22790719550464147, 0.22521916800455286, 0.2226917310818408, 0.2203332866226524, 0.21815048941809692, 0.21614818450720083, 0.21432936306553568, 0.2126951372338143, 0.2112447340882689, 0.20997550876066745, 0.20888297652227247, 0.2079608634549308, 0.2072011751456494, 0.20659428266037747, 0.20612902488005247, 0.20579282611900881, 0.20557182779420652, 0.20545103277489624, 0.20541446091768473, 0.20544531418270806, 0.20552614963380403, 0.20563905855013784, 0.20576584981934032, 0.2058882357434421, 0.20598801836905048, 0.2060472744524881, 0.2060485371889427, 0.2059749728718496, 0.20581055070431523, 0.20554020405778775, 0.20514998156363087, 0.20462718652976136, 0.20396050329603552, 0.2031401092772733, 0.20215777159036843, 0.20100692732029096, 0.19968274664736, 0.19818217823320317, 0.19650397644358214, 0.194648710170907, 0.1926187532058853, 0.19041825629454576, 0.18805310120185442, 0.18553083728454975, 0.18286060125176556, 0.18005302096074915, 0.17712010425483987, 0.17407511400026016, 0.17093243061565094, 0.16770740351238142, 0.16441619297317572, 0.16107560409046745, 0.15770291446326581, 0.1543156974113146, 0.15093164250751712, 0.14756837525348174, 0.144243277728465, 0.14097331202891655, 0.1377748482844121, 0.13466349898636765, 0.13165396129906168, 0.12875986893890332, 0.12599365510835228, 0.12336642785658468, 0.12088785911094282, 0.11856608848282635, 0.11640764280035193, 0.11441737215940055, 0.1125984031162065, 0.11095210947014583, 0.10947810090659021, 0.10817422958846984, 0.10703661460331476, 0.10605968399187003, 0.10523623390676248, 0.10455750427684285, 0.1040132701865734, 0.1035919480217609, 0.1032807152846843, 0.10306564284468801, 0.10293183826600022, 0.1028635987440952, 0.10284457208648501, 0.10285792409431029, 0.10288651063828859, 0.1029130526771286, 0.10292031243879006, 0.10289126897529897, 0.10280929131023966, 0.10265830742446046, 0.10242296736965001, 0.10208879886081258, 0.10164235377663315, 0.1010713440905103, 0.10036476586363788, 0.0995130100538605, 0.09850795902885097, 0.0973430678180777, 0.09601342929358225, 0.0945158226331815, 0.09284874458967196, 0.09101242326426817, 0.08900881426003145, 0.08684157926968263, 0.08451604733012706, 0.08203915915145221, 0.07941939509932287, 0.0766666875748763, 0.07379231869373823, 0.07080880431405007, 0.06772976560097, 0.06456978944052091, 0.06134427912775303, 0.05806929685175223, 0.054761399582151746, 0.051437470027623104, 0.04811454438572043, 0.04480963863490576, 0.04153957513333213, 0.03832081128483422, 0.03516927201064287, 0.03210018772584424, 0.029127939462918778, 0.02626591271145432, 0.023526361454011945, 0.020920283774112185, 0.018457310294390152, 0.016145606572400436, 0.013991790439617296, 0.012000865117319131, 0.010176168782832954, 0.008519341092618444, 0.00703030699663118, 0.005707278003012905, 0.004546770875235032, 0.0035436435670994503, 0.0026911480263294625, 0.0019809993265570225, 0.0014034604221192274, 0.0009474416618480738, 0.000600614048566489, 0.00034953509184271425, 0.0001797859740404967, 7.611863519182433e-5, 2.261128181186457e-5, 2.8307395122207732e-6, 1.549790831859623e-16, -2.830739512219366e-6, -2.2611281811860815e-5, -7.611863519182244e-5, -0.00017978597404050232, -0.00034953509184271425, -0.0006006140485664777, -0.0009474416618480588, -0.0014034604221192049, -0.001980999326557, -0.002691148026329455, -0.003543643567099424, -0.004546770875234946, -0.0057072780030128665, -0.0070303069966310945, -0.008519341092618402, -0.010176168782832938, -0.012000865117318986, -0.013991790439617196, -0.01614560657240023, -0.018457310294390055, -0.020920283774112175, -0.023526361454011827, -0.02626591271145407, -0.02912793946291867, -0.03210018772584421, -0.035169272010642745, -0.038320811284834236, -0.0415395751333318, -0.0448096386349056, -0.048114544385720465, -0.051437470027622965, -0.05476139958215156, -0.05806929685175211, -0.06134427912775273, -0.0645697894405208, -0.06772976560096965, -0.07080880431404998, -0.07379231869373822, -0.07666668757487599, -0.0794193950993226, -0.0820391591514519, -0.08451604733012683, -0.08684157926968268, -0.0890088142600314, -0.09101242326426806, -0.09284874458967204, -0.09451582263318156, -0.09601342929358225, -0.09734306781807787, -0.09850795902885094, -0.09951301005386047, -0.10036476586363804, -0.10107134409051025, -0.10164235377663315, -0.10208879886081253, -0.10242296736964986, -0.10265830742446046, -0.10280929131023947, -0.10289126897529897, -0.1029203124387901, -0.1029130526771285, -0.10288651063828844, -0.10285792409431026, -0.10284457208648491, -0.10286359874409524, -0.10293183826600026, -0.10306564284468799, -0.10328071528468437, -0.10359194802176087, -0.1040132701865734, -0.10455750427684274, -0.10523623390676261, -0.10605968399187009, -0.10703661460331458, -0.10817422958846966, -0.10947810090659008, -0.11095210947014585, -0.11259840311620663, -0.11441737215940052, -0.11640764280035205, -0.11856608848282622, -0.12088785911094263, -0.12336642785658485, -0.12599365510835234, -0.12875986893890348, -0.13165396129906168, -0.13466349898636726, -0.1377748482844122, -0.14097331202891658, -0.14424327772846463, -0.14756837525348127, -0.1509316425075168, -0.15431569741131418, -0.15770291446326584, -0.16107560409046723, -0.16441619297317514, -0.16770740351238134, -0.17093243061565092, -0.17407511400026004, -0.17712010425484018, -0.18005302096074877, -0.1828606012517659, -0.18553083728454983, -0.18805310120185412, -0.19041825629454598, -0.19261875320588528, -0.19464871017090676, -0.19650397644358225, -0.19818217823320303, -0.19968274664735994, -0.20100692732029093, -0.20215777159036838, -0.20314010927727297, -0.2039605032960354, -0.20462718652976114, -0.20514998156363096, -0.2055402040577874, -0.20581055070431484, -0.20597497287184988, -0.2060485371889431, -0.20604727445248805, -0.20598801836905056, -0.20588823574344178, -0.2057658498193405, -0.2056390585501381, -0.20552614963380395, -0.20544531418270817, -0.20541446091768462, -0.2054510327748964, -0.2055718277942064, -0.20579282611900887, -0.2061290248800524, -0.20659428266037755, -0.20720117514564942, -0.20796086345493067, -0.20888297652227267, -0.20997550876066742, -0.2112447340882689, -0.21269513723381434, -0.21432936306553566, -0.21614818450720064, -0.21815048941809675, -0.2203332866226523, -0.22269173108184062, -0.22521916800455322, -0.22790719550464125, -0.2307457452203153, -0.23372318012893195, -0.23682640861378898, -0.24004101367216846, -0.24335139599723224, -0.24674092952210708, -0.25019212788406264, -0.25368682015150373, -0.25720633405763527, -0.2607316849033657, -0.2642437682289513, -0.2677235543099054, -0.27115228250817885, -0.2745116535049799, -0.27778401745685727, -0.28095255615176257, -0.2840014572964779, -0.28691607914046263, -0.2896831037332889, -0.2922906772223594, -0.2947285357236722, -0.2969881154396153, -0.2990626458529113, -0.3009472249932822, -0.30263887595154576, -0.30413658400295795, -0.3054413138957357, -0.3065560070600357, -0.3074855586950764, -0.3082367748957092, -0.30881831018235467, -0.30924058599793525, -0.30951569093011067, -0.3096572636048432, -0.3096803593761618, -0.3096013021051117, -0.3094375224765569, -0.3092073844440738, -0.3089300015193044, -0.30862504473127633, -0.3083125441724482, -0.308012686120343, -0.3077456077760621, -0.3075311916928872, -0.3073888619793616, -0.3073373843513876, -0.307394672077078, -0.3075775998065369, -0.3079018272068672, -0.3083816342312208, -0.30902976974031116, -0.30985731506674924, -0.3108735639678434, -0.31208592025270326, -0.31349981419597955, -0.3151186386652109, -0.3169437056932387, -0.3189742240234374, -0.3212072979456745, -0.3236379475269407, -0.3262591501247014, -0.32906190285533443, -0.3320353054767044, -0.33516666293517866, -0.33844160662524764, -0.34184423321654, -0.3453572597203349, -0.3489621932975156, -0.3526395141541924, -0.35636886973138737, -0.36012927827284114, -0.3638993397514151, -0.36765745205082334, -0.37138203023658517, -0.3750517267086414, -0.3786456500088422, -0.38214358005948185, -0.38552617763453195, -0.38877518591291965, -0.39187362203277454, -0.3948059566564909, -0.3975582796677664, -0.40011845025263176, -0.402476229765532, -0.4046233959473747, -0.40655383724363553, -0.40826362616518047, -0.4097510708407209, -0.41101674412565875, -0.41206348985535585, -0.41289640605948935, -0.41352280518570667, -0.41395215161314486, -0.4141959769669857, -0.4142677739720091, -0.4141828698035673, -0.4139582801063908, -0.4136125450530137, -0.41316554900224506, -0.4126383254920195, -0.4120528494584448, -0.41143181871211754, -0.41079842682240475, -0.41017612965905104, -0.40958840791704765, -0.4090585280043653, -0.40860930370216736, -0.40826286101312526, -0.40804040859521573, -0.407962016135956, -0.4080464029557087, -0.40831073903903126, -0.4087704605807417, -0.40943910199951644, -0.4103281462175745, -0.41144689483175995, -0.41280235961082185, -0.41439917654757824, -0.4162395434750312, -0.41832318202440094, -0.42064732446280234, -0.4232067257011215, -0.425993700511111, -0.4289981857372451, -0.4322078270359611, -0.4356080894251199, -0.4391823906823725, -0.44291225639504045, -0.44677749523859794, -0.45075639284805746, -0.4548259224489217, -0.4589619702337015, -0.4631395733084202, -0.46733316789263635, -0.4715168453379103, -0.4756646134346535, -0.47975066040693387, -0.4837496189501207, -0.48763682764759675, -0.49138858711071726, -0.4949824082207573, -0.49839724991256007, -0.5016137440266105, -0.504614404868631, -0.5073838212524535, -0.509908828961872, -0.5121786617487732, -0.5141850791865967, -0.515922469918111, -0.5173879290725873, -0.5185813088775076, -0.519505241751444, -0.5201651354353782, -0.5205691399966615, -0.5207280868204569, -0.5206553999851117, -0.5203669806975673, -0.519881065740008, -0.5192180611465586, -0.5184003525863424, -0.5174520941740145, -0.516398977658252, -0.5152679841506357, -0.5140871207491347, -0.5128851445804775, -0.5116912769318133, -0.510534910262837, -0.5094453109835183, -0.5084513209485177, -0.5075810606566262, -0.5068616371513032, -0.5063188595964682, -0.5059769654500784, -0.5058583600768846, -0.5059833725318252, -0.5063700301074875, -0.5070338540742071, -0.5079876788510289, -0.5092414966315693, -0.5108023292528507, -0.51267412883936, -0.5148577084814954, -0.5173507039196068, -0.5201475669047484, -0.5232395905979872, -0.5266149670544598, -0.5302588765195677, -0.5341536079456973, -0.538278709821905, -0.542611170099176, -0.5471256236932712, -0.5517945857588382, -0.5565887086553676, -0.5614770602704128, -0.5664274211311081, -0.5714065975237037, -0.5763807476549858, -0.5813157177311059, -0.5861773847002052, -0.590932002306956, -0.5955465470408721, -0.5999890605269799, -0.6042289849077583, -0.6082374877995471, -0.6119877734747089, -0.6154553770226037, -0.6186184383768962, -0.6214579532632265, -0.6239579983181668, -0.6261059278563089, -0.6278925400151849, -0.6293122102853623, -0.6303629907331043, -0.6310466735425521, -0.6313688178407776, -0.6313387391190358, -0.6309694609238244, -0.6302776288587647, -0.6292833873090172, -0.6280102196707347, -0.6264847532351341, -0.6247365302365522, -0.6227977469229871, -0.6207029628423208, -0.6184887828546756, -0.6161935146775256, -0.613856805042607, -0.6115192577891381, -0.6092220374339613, -0.6070064619434333, -0.6049135885822214, -0.6029837968287078, -0.6012563724240714, -0.5997690966611318, -0.5985578450189204, -0.5976561992092704, -0.597095076622486, -0.5969023810406159, -0.597102678329789, -0.5977169006285002, -0.5987620823181586, -0.6002511307973571, -0.6021926347843691, -0.6045907125458352, -0.6074449020960848, -0.6107500950343107, -0.6144965152889805, -0.6186697436240552, -0.6232507883335596, -0.6282162021135196, -0.633538244657534, -0.6391850900780687, -0.6451210778144154, -0.6513070052540756, -0.657700459871375, -0.6642561882795243, -0.6709264992039179, -0.6776616970192509, -0.6844105421546172, -0.6911207343625048, -0.6977394145729972, -0.7042136808160446, -0.710491113495533, -0.7165203051408674, -0.7222513896471081, -0.7276365659449283, -0.7326306110177131, -0.7371913772061178, -0.7412802688102424, -0.7448626931166152, -0.7479084811404635, -0.7503922735826342, -0.7522938677534433, -0.753598521510957, -0.7542972105965211, -0.7543868361231407, -0.75387037937964, -0.7527570015521045, -0.7510620864302463, -0.7488072246562368, -0.7460201385829661, -0.7427345473332854, -0.738989972187024, -0.7348314829637439, -0.7303093866114765, -0.7254788597502784, -0.7203995274494948, -0.7151349910341942, -0.709752308214755, -0.7043214293092145, -0.6989145937764043, -0.693605691694756, -0.6884695952028858, -0.6835814652599993, -0.679016039383281, -0.6748469062726857, -0.6711457734381634, -0.6679817340981503, -0.6654205397190762, -0.6635238846123994, -0.6623487089972372, -0.6619465268725767, -0.6623627849232333, -0.6636362585087815, -0.6657984905554897, -0.6688732788893542, -0.6728762172155274, -0.6778142945682529, -0.6836855576285608, -0.69047883983781, -0.6981735607272218, -0.706739598341004, -0.7161372370575956, -0.7263171925149334, -0.7372207147260768, -0.748779769836255, -0.7609173003267401, -0.7735475628202165, -0.786576541992102, -0.7999024384480037, -0.8134162277948153, -0.8270022875171833, -0.840539087677681, -0.853899940893051, -0.8669538065054837, -0.8795661433716965, -0.8915998052380177, -0.9029159722609919, -0.9133751118738368, -0.9228379618928497, -0.9311665285074165, -0.9382250916051751, -0.9438812097520621, -0.9480067170768866, -0.9504787043027711, -0.9511804762235933, -0.9500024780425411, -0.9468431831712102, -0.941609935330358, -0.9342197380956974, -0.9245999853917974, -0.9126891268515844, -0.8984372624249259, -0.8818066611337649, -0.862772199429138, -0.8413217152029283, -0.8174562741393958, -0.7911903457534499, -0.7625518871490706, -0.731582333236355, -0.6983364928640607, -0.6628823510499221, -0.6253007782181008, -0.5856851480751609, -0.544140866467849, -0.5007848142610054, -0.4557447079473387, -0.40915838234597196, -0.36117300035908845, -0.31194419533040824, -0.2616351520803757, -0.21041563317721718, -0.1584609574356155, -0.10595093801285121, -0.053068787791871595, ], [0.0, 0.026545718196944883, 0.05306595705235952, 0.07953527846432243, 0.10592832673103444, 0.13221986955330658, 0.15838483880041973, 0.18439837096124598, 0.2102358472031752, 0.23587293296220607, 0.2612856169885333, 0.28645024977309635, 0.3113435812818376, 0.33594279792585735, 0.36022555869723794, 0.38417003040204106, 0.4077549219238515, 0.43095951745325606, 0.45376370862078264, 0.4761480254731004, 0.49809366623467216, 0.5195825257995645, 0.5405972229007474, 0.5611211259069443, 0.5811383771999267, 0.6006339160880814, 0.619593500215084, 0.6380037254256234, 0.655852044053289, 0.6731267815989707, 0.6898171517714409, 0.7059132698651307, 0.7214061644535227, 0.7362877873800369, 0.7505510220317487, 0.7641896898847942, 0.7771985553138318, 0.7895733286614548, 0.8013106675669954, 0.8124081765576693, 0.8228644049085386, 0.8326788427812566, 0.8418519156550239, 0.8503849770666193, 0.8582802996797523, 0.8655410647073257, 0.8721713497134719, 0.8781761148254554, 0.8835611873886643, 0.8883332451010011, 0.8924997976659524, 0.8960691670065295, 0.8990504660850542, 0.9014535763764813, 0.9032891240455243, 0.904568454880339, 0.9053036080378782, 0.9055072886582625, 0.9051928394076352, 0.9043742110109343, 0.9030659318378722, 0.901283076607105, 0.899041234275148, 0.8963564751780033, 0.8932453174947357, 0.8897246931033526, 0.8858119129003096, 0.8815246316557722, 0.8768808124774223, 0.8718986909560988, 0.8665967390668962, 0.8609936288995403, 0.8551081962918803, 0.8489594044402007, 0.8425663075597867, 0.8359480146687139, 0.8291236535672538, 0.8221123350845269, 0.8149331176631424, 0.8076049723515175, 0.8001467482723745, 0.7925771386345807, 0.7849146473540319, 0.77717755634766, 0.7693838935629244, 0.7615514018032605, 0.7536975084079999, 0.7458392958431392, 0.7379934732571526, 0.730176349053681, 0.722403804530547, 0.7146912686319761, 0.7070536938583328, 0.6995055333749577, 0.6920607193589211, 0.6847326426196723, 0.6775341335266345, 0.670477444273839, 0.6635742325086624, 0.6568355463486621, 0.6502718108074048, 0.6438928156470403, 0.6377077046722169, 0.6317249664767534, 0.6259524266512956, 0.6203972414569989, 0.615065892967085, 0.6099641856749543, 0.6050972445643714, 0.6004695146341203, 0.5960847618664097, 0.5919460756252715, 0.5880558724681598, 0.5844159013510097, 0.5810272502041016, 0.5778903538532438, 0.5750050032580135, 0.5723703560361058, 0.5699849482402289, 0.5678467073514617, 0.5659529664505647, 0.5643004795263951, 0.5628854378783614, 0.5617034875677157, 0.5607497478704926, 0.5600188306829889, 0.5595048608289234, 0.5592014972157515, 0.5591019547860916, 0.5591990272088221, 0.5594851102531418, 0.559952225787763, 0.5605920463463989, 0.5613959201998663, 0.5623548968743886, 0.5634597530551205, 0.564701018813466, 0.5660690040964693, 0.5675538254164021, 0.5691454326786474, 0.5708336360861123, 0.5726081330586582, 0.5744585351064383, 0.5763743945965694, 0.5783452313532361, 0.580360559032124, 0.5824099112110159, 0.5844828671394394, 0.5865690770914415, 0.5886582872668662, 0.5907403641879332, 0.592805318539449, 0.5948433284026236, 0.5968447618342216, 0.5988001987446093, 0.6007004520302209, 0.6025365879179876, 0.6042999454814034, 0.6059821552900944, 0.6075751571570385, 0.6090712169499267, 0.6104629424355601, 0.6117432981286499, 0.6129056191188982, 0.6139436238528013, 0.614851425849228, 0.6156235443304391, 0.6162549137529045, 0.6167408922249328, 0.6170772688008371, 0.6172602696440628, 0.6172865630544089, 0.6171532633571746, 0.6168579336547545, 0.6163985874438785, 0.6157736891043425, 0.6149821532676926, 0.6140233430769146, 0.6128970673507238, 0.6116035766685386, 0.6101435583946757, 0.6085181306626812, 0.6067288353430397, 0.6047776300197489, 0.6026668790034396, 0.6003993434108034, 0.5979781703421287, 0.5954068811906642, 0.5926893591193683, 0.5898298357423569, 0.5868328770499956, 0.5837033686181387, 0.5804465001434395, 0.5770677493480035, 0.5735728652978611, 0.569967851180847, 0.5662589465904675, 0.5624526093632034, 0.5585554970174534, 0.5545744478429632, 0.5505164616900879, 0.5463886805086438, 0.5421983686863601, 0.5379528932371089, 0.5336597038891021, 0.5293263131231655, 0.5249602762109838, 0.5205691713028723, 0.5161605796141897, 0.511742065758944, 0.5073211582784515, 0.5029053304121325, 0.49850198115662, 0.4941184166583522, 0.4897618319837094, 0.4854392933095485, 0.48115772057566997, 0.47692387063936964, 0.4727443209707152, 0.46862545392562444, 0.4645734416321644, 0.46059423152374473, 0.45669353255108386, 0.45287680210294645, 0.44914923366370746, 0.44551574523381143, 0.44198096853714336, 0.43854923903722576, 0.4352245867820335, 0.432010728095021, 0.4289110581277731, 0.4259286442874423, 0.4230662205498893, 0.4203261826671729, 0.4177105842757715, 0.41522113390961257, 0.41285919291974044, 0.41062577430015823, 0.40852154241713073, 0.4065468136370042, 0.4047015578453687, 0.402985400848217, 0.4013976276435965, 0.39993718655013827, 0.39860269417678473, 0.3973924412160187, 0.3963043990409268, 0.3953362270845367, 0.3944852809780163, 0.3937486214225511, 0.3931230237680212, 0.3926049882699534, 0.3921907509946962, 0.3918762953412839, 0.39165736414708124, 0.3915294723430061, 0.39148792012293115, 0.39152780659074954, 0.39164404384758933, 0.39183137148074243, 0.39208437141506397, 0.3923974830868905, 0.3927650188999123, 0.3931811799219359, 0.39364007178107974, 0.3941357207196403, 0.39466208976369344, 0.39521309496640583, 0.39578262168305833, 0.39636454083591344, 0.3969527251272911, 0.39754106515955684, 0.39812348542116116, 0.39869396009841107, 0.3992465286732943, 0.3997753112684068, 0.40027452370086936, 0.40073849220803304, 0.40116166780879253, 0.4015386402654166, 0.4018641516119899, 0.4021331092168155, 0.40234059834747493, 0.40248189420864056, 0.40255247342422396, 0.402548024936985, 0.40246446030033406, 0.40229792333871955, 0.4020447991547122, 0.40170172246265773, 0.4012655852305806, 0.4007335436138615, 0.40010302416609334, 0.3993717293144348, 0.398537642088701, 0.3975990300953921, 0.3965544487298331, 0.3954027436215527, 0.3941430523100353, 0.3927748051499406, 0.3912977254468672, 0.3897118288267029, 0.3880174218435458, 0.386215099833111, 0.3843057440204448, 0.3822905178926342, 0.3801708628490417, 0.37794849314340223, 0.37562539013385815, 0.37320379585874197, 0.37068620595754914, 0.3680753619581652, 0.36537424295294424, 0.36258605668771626, 0.3597142300892152, 0.3567623992577648, 0.3537343989533208, 0.35063425160417305, 0.3474661558687175, 0.34423447478174135, 0.34094372351762353, 0.3375985568037032, 0.33420375601785257, 0.33076421600498185, 0.3272849316477845, 0.3237709842275512, 0.32022752761128115, 0.3166597743016368, 0.3130729813865164, 0.3094724364251409, 0.3058634433075838, 0.30225130812461865, 0.29864132508459706, 0.2950387625138203, 0.2914488489765338, 0.28787675955024017, 0.28432760229149096, 0.2808064049267354, 0.27731810180207384, 0.27386752112500984, 0.2704593725304211, 0.2670982350020186, 0.26378854517956246, 0.26053458608099017, 0.25734047626745526, 0.2542101594780438, 0.25114739475963227, 0.24815574711598679, 0.24523857869880308, 0.2423990405618887, 0.23964006499819412, 0.2369643584778126, 0.23437439520346734, 0.231872411298357, 0.22946039963955098, 0.22714010534840656, 0.22491302194776255, 0.22278038819389562, 0.22074318558945696, 0.2188021365818399, 0.21695770344961615, 0.21521008787791673, 0.21355923122182552, 0.21200481545508423, 0.21054626479963687, 0.20918274802979317, 0.20791318144305038, 0.20673623248792086, 0.20565032403742187, 0.20465363929524996, 0.20374412732005592, 0.20291950915166393, 0.20217728452157432, 0.2015147391286048, 0.20092895245912112, 0.2004168061299386, 0.19997499273068586, 0.19960002514117273, 0.1992882462981456, 0.19903583938470057, 0.1988388384146001, 0.1986931391827824, 0.19859451055246402, 0.19853860604844537, 0.19852097572549707, 0.1985370782800676, 0.1985822933729995, 0.198651934130463, 0.1987412597899316, 0.1988454884577261, 0.19895980994443582, 0.19907939864440083, 0.19919942642540583, 0.19931507549477542, 0.19942155120820612, 0.1995140947878864, 0.19958799591676946, 0.19963860517625184, 0.199661346294994, 0.19965172817717433, 0.19960535667910934, 0.19951794610389184, 0.19938533038449868, 0.1992034739266841, 0.198968482083924, 0.1986766112376888, 0.19832427845739714, 0.1979080707155601, 0.197424753634815, 0.19687127974482557, 0.1962447962283395, 0.19554265213705904, 0.19476240505940234, 0.1939018272237001, 0.19295891102185628, 0.19193187394005728, 0.19081916288467451, 0.18961945789309598, 0.18833167522086244, 0.18695496979810258, 0.18548873704993196, 0.18393261407714714, 0.18228648019520932, 0.18055045683120302, 0.1787249067801208, 0.17681043282349226, 0.17480787571504336, 0.1727183115397067, 0.17054304845392831, 0.1682836228168248, 0.16594179472331538, 0.16351954295188986, 0.1610190593411998, 0.15844274261110702, 0.15579319164526853, 0.15307319825371277, 0.15028573943518309, 0.14743396916031742, 0.14452120969795026, 0.1415509425079684, 0.1385267987252867, 0.13545254926050604, 0.13233209454381167, 0.12916945393955576, 0.1259687548597888, 0.12273422160576765, 0.11947016396712953, 0.1161809656090268, 0.112871072278028, 0.10954497985804011, 0.10620722230783845, 0.10286235951209186, 0.09951496507794265, 0.0961696141093082, 0.09283087099110582, 0.08950327721552741, 0.086191339282355, 0.08289951670508418, 0.07963221015430162, 0.07639374976938526, 0.07318838366912175, 0.07002026669127528, 0.0668934493905366, 0.06381186732355576, 0.06077933064900039, 0.05779951406972814, 0.05487594714324001, 0.05201200498560403, 0.04921089939298029, 0.0464756704037735, 0.04380917832326309, 0.04121409623134174, 0.038692902992701866, 0.03624787678749556, 0.03388108917910965, 0.031594399734281686, 0.02938945120933373, 0.02726766531480166, 0.02523023906922666, 0.02327814175131816, 0.021412112458135492, 0.019632658275337358, 0.017940053063952507, 0.016334336866500643, 0.01481531593367719, 0.013382563371191451, 0.012035420404720155, 0.010772998259329638, 0.009594180648108167, 0.008497626863162624, 0.007481775460557727, 0.006544848529230385, 0.005684856532381179, 0.004899603708358025, 0.004186694016586286, 0.0035435376126760326, 0.0029673578354616516, 0.0024551986873936203, 0.002003932788417401, 0.0016102697822395124, 0.0012707651727091416, 0.0009818295669113471, 0.0007397383005268191, 0.0005406414200015239, 0.0003805739951507978, 0.0002554667349638405, 0.00016115687857385752, 9.33993326623873e-5, 4.7878025906496334e-5, 2.0217450525552818e-5, 5.994360493067937e-6, 7.495955711638791e-7, 0.0, -7.495955711638791e-7, -5.9943604930660596e-6, -2.0217450525552818e-5, -4.7878025906501965e-5, -9.33993326623873e-5, -0.00016115687857385752, -0.000255466734963833, -0.0003805739951507866, -0.0005406414200015201, -0.0007397383005269993, -0.0009818295669113395, -0.0012707651727089614, -0.0016102697822395048, -0.0020039327884172134, -0.002455198687393613, -0.0029673578354618094, -0.0035435376126758526, -0.004186694016586255, -0.004899603708357815, -0.005684856532381134, -0.006544848529230498, -0.007481775460557704, -0.008497626863162423, -0.009594180648108144, -0.010772998259329713, -0.012035420404720103, -0.013382563371191526, -0.014815315933676965, -0.01633433686650056, -0.01794005306395254, -0.019632658275337268, -0.021412112458135388, -0.023278141751318097, -0.025230239069226487, -0.027267665314801594, -0.02938945120933353, -0.03159439973428161, -0.033881089179109654, -0.03624787678749542, -0.03869290299270176, -0.04121409623134159, -0.043809178323263, -0.04647567040377338, -0.049210899392980165, -0.052012004985603905, -0.054875947143239866, -0.05779951406972795, -0.06077933064900021, -0.06381186732355568, -0.06689344939053647, -0.07002026669127516, -0.07318838366912163, -0.07639374976938511, -0.07963221015430148, -0.08289951670508403, -0.08619133928235492, -0.08950327721552725, -0.0928308709911057, -0.09616961410930805, -0.09951496507794248, -0.1028623595120918, -0.10620722230783827, -0.10954497985804007, -0.11287107227802784, -0.11618096560902666, -0.11947016396712942, -0.12273422160576761, -0.12596875485978873, -0.1291694539395555, -0.13233209454381156, -0.1354525492605058, -0.13852679872528667, -0.1415509425079683, -0.14452120969795001, -0.1474339691603173, -0.15028573943518297, -0.15307319825371268, -0.15579319164526859, -0.15844274261110686, -0.16101905934119984, -0.16351954295188992, -0.16594179472331516, -0.1682836228168249, -0.17054304845392806, -0.17271831153970682, -0.17480787571504325, -0.17681043282349235, -0.1787249067801207, -0.1805504568312032, -0.18228648019520924, -0.18393261407714692, -0.18548873704993205, -0.18695496979810242, -0.1883316752208626, -0.18961945789309576, -0.19081916288467424, -0.1919318739400572, -0.1929589110218563, -0.19390182722370014, -0.1947624050594025, -0.19554265213705896, -0.19624479622833976, -0.19687127974482554, -0.19742475363481468, -0.19790807071556024, -0.19832427845739706, -0.19867661123768876, -0.198968482083924, -0.19920347392668417, -0.19938533038449877, -0.19951794610389198, -0.19960535667910934, -0.1996517281771742, -0.19966134629499405, -0.1996386051762516, -0.19958799591676968, -0.1995140947878864, -0.1994215512082061, -0.19931507549477542, -0.19919942642540583, -0.19907939864440077, -0.1989598099444359, -0.1988454884577261, -0.19874125978993168, -0.19865193413046295, -0.19858229337299937, -0.1985370782800678, -0.19852097572549715, -0.19853860604844537, -0.198594510552464, -0.19869313918278222, -0.19883883841460007, -0.19903583938470068, -0.1992882462981455, -0.19960002514117253, -0.19997499273068575, -0.20041680612993848, -0.20092895245912115, -0.20151473912860504, -0.20217728452157402, -0.20291950915166412, -0.2037441273200557, -0.2046536392952499, -0.20565032403742173, -0.20673623248792117, -0.20791318144305054, -0.2091827480297929, -0.21054626479963678, -0.21200481545508418, -0.21355923122182582, -0.2152100878779168, -0.21695770344961593, -0.2188021365818395, -0.22074318558945688, -0.22278038819389545, -0.224913021947763, -0.22714010534840637, -0.22946039963955062, -0.23187241129835684, -0.2343743952034671, -0.23696435847781278, -0.23964006499819399, -0.24239904056188868, -0.24523857869880253, -0.2481557471159866, -0.25114739475963216, -0.2542101594780444, -0.2573404762674552, -0.2605345860809897, -0.2637885451795623, -0.2670982350020185, -0.2704593725304213, -0.27386752112500956, -0.2773181018020738, -0.28080640492673503, -0.28432760229149073, -0.28787675955024006, -0.29144884897653445, -0.2950387625138202, -0.2986413250845966, -0.30225130812461837, -0.3058634433075836, -0.30947243642514105, -0.3130729813865163, -0.31665977430163655, -0.3202275276112806, -0.3237709842275509, -0.3272849316477843, -0.3307642160049821, -0.3342037560178522, -0.33759855680370254, -0.3409437235176233, -0.3442344747817413, -0.3474661558687175, -0.35063425160417255, -0.3537343989533211, -0.3567623992577644, -0.35971423008921505, -0.36258605668771615, -0.3653742429529445, -0.36807536195816537, -0.37068620595754864, -0.37320379585874186, -0.3756253901338582, -0.37794849314340234, -0.380170862849042, -0.38229051789263385, -0.3843057440204446, -0.3862150998331108, -0.38801742184354576, -0.38971182882670324, -0.39129772544686686, -0.39277480514994023, -0.39414305231003516, -0.39540274362155264, -0.3965544487298332, -0.3975990300953918, -0.39853764208870124, -0.39937172931443493, -0.4001030241660933, -0.40073354361386154, -0.40126558523058065, -0.4017017224626579, -0.402044799154712, -0.4022979233387195, -0.402464460300334, -0.402548024936985, -0.40255247342422407, -0.4024818942086402, -0.40234059834747476, -0.40213310921681555, -0.40186415161198996, -0.40153864026541675, -0.4011616678087923, -0.4007384922080333, -0.40027452370086947, -0.3997753112684071, -0.3992465286732942, -0.39869396009841085, -0.3981234854211614, -0.397541065159557, -0.396952725127291, -0.3963645408359135, -0.39578262168305817, -0.395213094966406, -0.3946620897636936, -0.3941357207196404, -0.39364007178107974, -0.39318117992193574, -0.3927650188999123, -0.3923974830868905, -0.3920843714150641, -0.3918313714807424, -0.39164404384758933, -0.3915278065907495, -0.3914879201229312, -0.3915294723430063, -0.3916573641470812, -0.39187629534128404, -0.392190750994696, -0.3926049882699534, -0.39312302376802116, -0.3937486214225514, -0.39448528097801594, -0.3953362270845365, -0.39630439904092674, -0.3973924412160186, -0.39860269417678484, -0.39993718655013827, -0.40139762764359666, -0.402985400848217, -0.4047015578453682, -0.40654681363700435, -0.4085215424171307, -0.410625774300158, -0.4128591929197403, -0.4152211339096123, -0.4177105842757715, -0.4203261826671728, -0.4230662205498895, -0.4259286442874422, -0.4289110581277727, -0.4320107280950209, -0.4352245867820333, -0.438549239037226, -0.44198096853714314, -0.44551574523381077, -0.44914923366370746, -0.45287680210294606, -0.4566935325510838, -0.46059423152374473, -0.46457344163216446, -0.46862545392562444, -0.4727443209707144, -0.47692387063937003, -0.4811577205756697, -0.485439293309548, -0.4897618319837092, -0.4941184166583518, -0.49850198115662, -0.502905330412132, -0.5073211582784519, -0.5117420657589443, -0.516160579614189, -0.5205691713028722, -0.5249602762109836, -0.5293263131231656, -0.5336597038891012, -0.5379528932371083, -0.54219836868636, -0.5463886805086431, -0.5505164616900878, -0.5545744478429633, -0.5585554970174534, -0.5624526093632031, -0.5662589465904668, -0.5699678511808471, -0.5735728652978609, -0.5770677493480033, -0.5804465001434397, -0.5837033686181385, -0.586832877049996, -0.5898298357423567, -0.5926893591193686, -0.5954068811906641, -0.5979781703421284, -0.600399343410803, -0.6026668790034395, -0.6047776300197492, -0.6067288353430393, -0.6085181306626812, -0.6101435583946756, -0.6116035766685384, -0.612897067350724, -0.6140233430769146, -0.6149821532676925, -0.6157736891043425, -0.6163985874438783, -0.6168579336547545, -0.6171532633571748, -0.617286563054409, -0.6172602696440631, -0.617077268800837, -0.6167408922249329, -0.6162549137529046, -0.615623544330439, -0.6148514258492278, -0.6139436238528017, -0.6129056191188982, -0.6117432981286499, -0.6104629424355603, -0.6090712169499269, -0.6075751571570385, -0.6059821552900939, -0.6042999454814035, -0.6025365879179878, -0.6007004520302209, -0.5988001987446094, -0.5968447618342216, -0.594843328402624, -0.592805318539449, -0.5907403641879339, -0.5886582872668663, -0.586569077091441, -0.5844828671394394, -0.582409911211016, -0.5803605590321245, -0.578345231353236, -0.5763743945965697, -0.5744585351064383, -0.5726081330586582, -0.5708336360861124, -0.5691454326786475, -0.5675538254164019, -0.5660690040964692, -0.5647010188134657, -0.5634597530551206, -0.5623548968743886, -0.5613959201998664, -0.5605920463463989, -0.5599522257877627, -0.5594851102531421, -0.5591990272088221, -0.5591019547860917, -0.5592014972157512, -0.5595048608289235, -0.5600188306829887, -0.5607497478704926, -0.561703487567716, -0.5628854378783613, -0.5643004795263951, -0.5659529664505643, -0.5678467073514615, -0.5699849482402288, -0.5723703560361058, -0.5750050032580134, -0.5778903538532438, -0.5810272502041016, -0.5844159013510095, -0.5880558724681597, -0.5919460756252717, -0.5960847618664095, -0.6004695146341198, -0.6050972445643708, -0.6099641856749543, -0.6150658929670848, -0.6203972414569986, -0.6259524266512957, -0.6317249664767531, -0.6377077046722168, -0.6438928156470394, -0.650271810807405, -0.6568355463486619, -0.663574232508662, -0.6704774442738386, -0.6775341335266343, -0.684732642619672, -0.6920607193589206, -0.6995055333749578, -0.7070536938583328, -0.7146912686319754, -0.7224038045305465, -0.7301763490536811, -0.7379934732571519, -0.7458392958431388, -0.7536975084079985, -0.7615514018032606, -0.7693838935629238, -0.7771775563476596, -0.7849146473540315, -0.7925771386345806, -0.8001467482723742, -0.8076049723515168, -0.8149331176631425, -0.8221123350845267, -0.8291236535672534, -0.8359480146687135, -0.8425663075597865, -0.8489594044402005, -0.8551081962918794, -0.8609936288995405, -0.8665967390668962, -0.8718986909560984, -0.876880812477422, -0.8815246316557719, -0.8858119129003096, -0.8897246931033526, -0.8932453174947351, -0.8963564751780037, -0.8990412342751479, -0.9012830766071048, -0.9030659318378725, -0.9043742110109342, -0.9051928394076352, -0.9055072886582621, -0.9053036080378781, -0.9045684548803389, -0.9032891240455242, -0.9014535763764814, -0.8990504660850542, -0.8960691670065297, -0.8924997976659531, -0.8883332451010009, -0.8835611873886646, -0.878176114825456, -0.8721713497134723, -0.8655410647073254, -0.8582802996797527, -0.8503849770666201, -0.8418519156550236, -0.8326788427812566, -0.8228644049085392, -0.8124081765576702, -0.8013106675669952, -0.7895733286614552, -0.7771985553138323, -0.7641896898847956, -0.7505510220317483, -0.7362877873800374, -0.721406164453524, -0.7059132698651304, -0.6898171517714415, -0.6731267815989713, -0.6558520440532906, -0.6380037254256232, -0.6195935002150843, -0.6006339160880829, -0.5811383771999287, -0.5611211259069441, -0.5405972229007483, -0.5195825257995662, -0.4980936662346718, -0.47614802547310026, -0.45376370862078375, -0.43095951745325806, -0.40775492192385093, -0.38417003040204195, -0.3602255586972391, -0.33594279792585974, -0.3113435812818373, -0.2864502497730976, -0.2612856169885356, -0.23587293296220538, -0.21023584720317612, -0.18439837096124706, -0.15838483880042184, -0.13221986955330622, -0.10592832673103517, -0.07953527846432475, -0.05306595705236202, -0.02654571819694487, ], [0.0, 0.013274358331948408, 0.02654496860137373, 0.03980808441078111, 0.05305996269186631, 0.06629686536794671, 0.07951506101379674, 0.09271082651202435, 0.10588044870512793, 0.11902022604237456, 0.13212647022064417, 0.14519550781838703, 0.15822368192184588, 0.17120735374269866, 0.18414290422628213, 0.19702673564956263, 0.2098552732080243, 0.22262496659065373, 0.2353322915422047, 0.24797375141193365, 0.2605458786880065, 0.2730452365167815, 0.28546842020618496, 0.2978120587124043, 0.31007281610912846, 0.32224739303858446, 0.33433252814361764, 0.3463249994800829, 0.3582216259088207, 0.37001926846650274, 0.38171483171464743, 0.39330526506611263, 0.40478756408838984, 0.4161587717830343, 0.42741597984058, 0.43855632987030085, 0.4495770146041964, 0.4604752790745893, 0.4712484217647425, 0.4818937957319152, 0.49240880970229106, 0.5027909291372342, 0.5130376772703341, 0.5231466361147267, 0.5331154474401897, 0.542941813719528, 0.5526234990437817, 0.5621583300058107, 0.5715441965518185, 0.5807790528004053, 0.5898609178287517, 0.5987878764255559, 0.6075580798103637, 0.6161697463189505, 0.6246211620544323, 0.6329106815038029, 0.6410367281196119, 0.6489977948665174, 0.6567924447324701, 0.6644193112042978, 0.6718770987074885, 0.6791645830099804, 0.6862806115897934, 0.6932241039663543, 0.6999940519953874, 0.7065895201272635, 0.7130096456287188, 0.7192536387678761, 0.725320782962522, 0.7312104348916115, 0.7369220245699927, 0.7424550553863637, 0.7478091041044981, 0.7529838208277868, 0.7579789289271732, 0.7627942249325719, 0.7674295783878856, 0.7718849316697528, 0.7761602997701739, 0.7802557700431951, 0.784171501915837, 0.7879077265634759, 0.7914647465499149, 0.7948429354323867, 0.7980427373317609, 0.801064666468239, 0.8039093066628461, 0.8065773108050381, 0.8090694002867722, 0.8113863644033932, 0.8135290597217216, 0.8154984094157309, 0.8172954025702319, 0.8189210934529908, 0.8203766007557275, 0.821663106804459, 0.8227818567396642, 0.8237341576667665, 0.8245213777774453, 0.8251449454423011, 0.8256063482754161, 0.8259071321713659, 0.8260489003152544, 0.8260333121663505, 0.8258620824159325, 0.8255369799199451, 0.8250598266070959, 0.824432496363029, 0.8236569138912229, 0.822735053551277, 0.8216689381752549, 0.8204606378627728, 0.8191122687555231, 0.8176259917919427, 0.8160040114427352, 0.8142485744279759, 0.8123619684165292, 0.810346520708523, 0.8082045969016263, 0.8059385995418927, 0.8035509667599299, 0.8010441708931686, 0.7984207170950129, 0.7956831419316492, 0.7928340119673095, 0.7898759223387811, 0.7868114953199631, 0.7836433788772725, 0.7803742452167076, 0.77700678932338, 0.7735437274943262, 0.769987795865417, 0.76634174893318, 0.7626083580723559, 0.7587904100500046, 0.7548907055369866, 0.7509120576176339, 0.7468572902984341, 0.7427292370165434, 0.7385307391489477, 0.7342646445230844, 0.7299338059297409, 0.7255410796390346, 0.7210893239202877, 0.7165813975665934, 0.7120201584248751, 0.7074084619322322, 0.7027491596593615, 0.6980450978618363, 0.6932991160400218, 0.6885140455083961, 0.6836927079750432, 0.6788379141320712, 0.6739524622577079, 0.669039136830814, 0.6641007071585426, 0.6591399260178737, 0.654159528311735, 0.6491622297404107, 0.6441507254889394, 0.6391276889311748, 0.6340957703511914, 0.6290575956826908, 0.6240157652670598, 0.6189728526307166, 0.6139314032823755, 0.6088939335308352, 0.6038629293238973, 0.5988408451089959, 0.5938301027161158, 0.5888330902635537, 0.5838521610870694, 0.5788896326929561, 0.5739477857355461, 0.5690288630196473, 0.5641350685284021, 0.5592685664770316, 0.5544314803929199, 0.549625892222478, 0.5448538414652023, 0.5401173243353379, 0.5354182929515289, 0.530758654554829, 0.5261402707554237, 0.5215649568084009, 0.517034480918888, 0.5125505635768551, 0.5081148769218683, 0.5037290441380583, 0.49939463887954905, 0.49511318472657617, 0.4908861546725088, 0.4867149706419599, 0.482601003040165, 0.47854557033378153, 0.4745499386632462, 0.4706153214868063, 0.46674287925632896, 0.462933719124962, 0.4591888946867157, 0.4555094057480024, 0.45189619813116244, 0.44835016350998125, 0.44487213927718405, 0.44146290844387726, 0.43812319957088725, 0.4348536867319277, 0.4316549895085085, 0.4285276730164808, 0.4254722479640964, 0.42248917074143855, 0.4195788435410656, 0.41674161450968783, 0.4139777779306879, 0.41128757443726527, 0.40867119125598067, 0.40612876248044744, 0.4036603693749121, 0.40126604070743627, 0.39894575311238556, 0.3966994314819109, 0.39452694938608934, 0.39242812952137957, 0.3904027441870283, 0.38845051578905054, 0.38657111737138716, 0.38476417317383554, 0.3830292592163258, 0.3813659039091079, 0.37977358868839356, 0.37825174867699213, 0.37679977336945564, 0.375417007341244, 0.3741027509813995, 0.37285626124821936, 0.37167675244738635, 0.3705633970320227, 0.36951532642410606, 0.3685316318566862, 0.367611365236323, 0.36675354002515864, 0.3659571321420275, 0.3652210808819944, 0.36454428985370546, 0.36392562793392563, 0.36336393023862784, 0.36285799910998967, 0.3624066051186496, 0.3620084880805612, 0.361662358087782, 0.36136689655252596, 0.3611207572637965, 0.3609225674559237, 0.3607709288883082, 0.3606644189356838, 0.3606015916881958, 0.36058097906059444, 0.3606010919098352, 0.36066042116037655, 0.3607574389364632, 0.360890599700678, 0.3610583413980475, 0.36125908660498063, 0.3614912436823218, 0.3617532079317988, 0.3620433627551433, 0.3623600808151657, 0.36270172519806304, 0.3630666505762428, 0.363453204370945, 0.3638597279139476, 0.364284557607644, 0.3647260260827801, 0.36518246335314786, 0.3656521979665307, 0.36613355815120147, 0.3666248729572809, 0.36712447339226567, 0.3676306935500424, 0.36814187173270985, 0.368656351564538, 0.3691724830973961, 0.36968862390699436, 0.3702031401792852, 0.3707144077863825, 0.37122081335136237, 0.3717207553013194, 0.37221264490805817, 0.3726949073158144, 0.37316598255540245, 0.3736243265442033, 0.37406841207141006, 0.37449672976796555, 0.37490778906063205, 0.37530011910964634, 0.375672269729426, 0.37602281229180445, 0.37635034061127964, 0.37665347181178205, 0.37693084717447445, 0.37718113296610783, 0.37740302124747793, 0.37759523066153233, 0.3777565072006976, 0.3778856249530076, 0.37798138682662696, 0.3780426252523818, 0.3780682028639219, 0.3780570131551524, 0.37800798111459194, 0.3779200638363254, 0.377792251107237, 0.3776235659702249, 0.3774130652631136, 0.3771598401329968, 0.3768630165257597, 0.3765217556505433, 0.3761352544189352, 0.37570274585868135, 0.37522349950173284, 0.3746968217464594, 0.3741220561938768, 0.3734985839577476, 0.3728258239484431, 0.37210323313045607, 0.3713303067534837, 0.3705065785570097, 0.36963162094833074, 0.3687050451539986, 0.36772650134465223, 0.3666956787332414, 0.3656123056466583, 0.36447614957080693, 0.36328701716915857, 0.3620447542748604, 0.3607492458564799, 0.35940041595747757, 0.3579982276095335, 0.35654268271984685, 0.3550338219325646, 0.3534717244645002, 0.3518565079153189, 0.35018832805239036, 0.3484673785705153, 0.3466938908267536, 0.3448681335505973, 0.3429904125297446, 0.3410610702717445, 0.3390804856418051, 0.3370490734770586, 0.33496728417760724, 0.33283560327467343, 0.3306545509762018, 0.3284246816902689, 0.32614658352667464, 0.3238208777770937, 0.3214482183741898, 0.3190292913301018, 0.31656481415471843, 0.314055535254185, 0.3115022333100785, 0.3089057166397162, 0.3062668225380625, 0.3035864166017143, 0.3008653920354559, 0.2981046689418834, 0.2953051935946084, 0.2924679376955609, 0.2895938976169235, 0.28668409362822717, 0.28373956910916676, 0.2807613897486776, 0.27775064273084765, 0.27470843590822663, 0.2716358969631131, 0.26853417255740225, 0.2654044274715841, 0.26224784373348836, 0.2590656197373752, 0.25585896935398417, 0.2526291210321433, 0.24937731689256462, 0.24610481181443578, 0.24281287251543857, 0.2395027766258159, 0.23617581175711863, 0.23283327456626238, 0.22947646981552847, 0.22610670942914388, 0.22272531154707473, 0.21933359957667406, 0.21593290124281356, 0.21252454763714693, 0.2091098722671293, 0.20569021010543867, 0.20226689664042727, 0.19884126692823823, 0.1954146546472173, 0.191988391155247, 0.18856380455063054, 0.18514221873714368, 0.18172495249387724, 0.17831331855047844, 0.1749086226684084, 0.17151216272881104, 0.1681252278276025, 0.1647490973783669, 0.16138504022365213, 0.15803431375524374, 0.154698163043992, 0.1513778199797635, 0.14807450242207118, 0.1447894133619424, 0.1415237400955628, 0.13827865341023834, 0.13505530678319536, 0.13185483559374478, 0.12867835634931174, 0.1255269659258331, 0.12240174082301226, 0.11930373643490579, 0.11623398633631446, 0.11319350158542958, 0.11018327004318659, 0.10720425570975282, 0.10425739807857798, 0.10134361150841148, 0.09846378461368968, 0.09561877967367412, 0.0928094320607141, 0.09003654968799576, 0.08730091247711837, 0.08460327184583695, 0.08194435021628321, 0.07932484054397755, 0.07674540586791583, 0.07420667888201428, 0.07170926152816987, 0.06925372461118937, 0.06684060743581534, 0.06447041746607113, 0.0621436300071285, 0.059860687909883366, 0.057622001298418164, 0.05542794732050328, 0.053278869921285874, 0.05117507964028882, 0.04911685343183602, 0.0471044345089953, 0.04513803221112643, 0.04321782189509187, 0.041343944850185235, 0.03951650823680923, 0.037735585048919607, 0.036001214100240986, 0.034313400034237246, 0.03267211335780963, 0.031077290498677437, 0.02952883388638042, 0.028026612056826924, 0.02657045978029809, 0.025160178212797306, 0.023795535070627025, 0.022476264828052227, 0.021202068937899436, 0.019972616074923087, 0.018787542401756424, 0.017646451857248856, 0.016548916466979022, 0.015494476675714621, 0.014482641701579417, 0.013512889911673409, 0.0125846692188711, 0.01169739749952218, 0.010850463031751387, 0.01004322495405023, 0.00927501374383657, 0.008545131715644213, 0.00785285353859306, 0.007197426772779204, 0.006578072424207632, 0.005993985517882828, 0.00544433568866018, 0.004928267789442095, 0.004444902516305456, 0.003993337050120896, 0.0035726457142248825, 0.00318188064768952, 0.002820072493726352, 0.0024862311027485694, 0.0021793462496127345, 0.0018983883645419307, 0.0016423092772333092, 0.0014100429736385082, 0.0012005063648964241, 0.0010126000678969997, 0.0008452091969364445, 0.0006972041659275773, 0.0005674415006152227, 0.0004547646602430531, 0.00035800486810893064, 0.0002759819504476946, 0.0002075051830628489, 0.00015137414513633957, 0.00010637957963232085, 7.130425970715164e-5, 4.4923860541562e-5, 2.6007835995077533e-5, 1.3320299491804626e-5, 5.6209085359102885e-6, 1.66575225647358e-6, 2.0824137551522492e-7, 0.0, -2.0824137551522492e-7, -1.6657522564726417e-6, -5.620908535912165e-6, -1.3320299491804626e-5, -2.6007835995075656e-5, -4.4923860541562e-5, -7.130425970714414e-5, -0.0001063795796323171, -0.00015137414513633957, -0.0002075051830628489, -0.0002759819504476871, -0.00035800486810894566, -0.0004547646602430606, -0.0005674415006152227, -0.0006972041659275773, -0.0008452091969364445, -0.0010126000678969997, -0.0012005063648964167, -0.0014100429736385006, -0.0016423092772332867, -0.0018983883645419231, -0.0021793462496127497, -0.0024862311027485547, -0.002820072493726352, -0.0031818806476895047, -0.0035726457142248678, -0.003993337050120865, -0.004444902516305411, -0.004928267789442066, -0.0054443356886601495, -0.005993985517882798, -0.0065780724242075865, -0.007197426772779175, -0.007852853538593031, -0.008545131715644213, -0.00927501374383651, -0.010043224954050214, -0.010850463031751356, -0.011697397499522151, -0.012584669218871039, -0.013512889911673378, -0.014482641701579388, -0.015494476675714576, -0.016548916466978963, -0.017646451857248856, -0.01878754240175636, -0.019972616074922952, -0.021202068937899332, -0.022476264828052213, -0.02379553507062698, -0.02516017821279726, -0.02657045978029806, -0.028026612056826834, -0.02952883388638036, -0.031077290498677374, -0.03267211335780957, -0.03431340003423717, -0.03600121410024091, -0.0377355850489195, -0.039516508236809154, -0.041343944850185194, -0.04321782189509178, -0.045138032211126354, -0.04710443450899521, -0.049116853431835944, -0.05117507964028876, -0.05327886992128577, -0.05542794732050322, -0.05762200129841806, -0.05986068790988326, -0.06214363000712838, -0.06447041746607103, -0.06684060743581521, -0.06925372461118928, -0.07170926152816977, -0.07420667888201418, -0.07674540586791573, -0.07932484054397748, -0.08194435021628312, -0.08460327184583682, -0.08730091247711826, -0.09003654968799565, -0.09280943206071397, -0.095618779673674, -0.09846378461368956, -0.10134361150841137, -0.10425739807857788, -0.10720425570975269, -0.11018327004318651, -0.11319350158542946, -0.11623398633631432, -0.11930373643490566, -0.12240174082301218, -0.12552696592583296, -0.12867835634931163, -0.13185483559374458, -0.13505530678319522, -0.13827865341023815, -0.1415237400955627, -0.14478941336194232, -0.14807450242207099, -0.15137781997976343, -0.1546981630439918, -0.15803431375524357, -0.16138504022365202, -0.16474909737836682, -0.1681252278276023, -0.1715121627288109, -0.1749086226684082, -0.17831331855047836, -0.1817249524938771, -0.1851422187371435, -0.18856380455063046, -0.19198839115524677, -0.19541465464721716, -0.19884126692823811, -0.2022668966404272, -0.20569021010543856, -0.20910987226712913, -0.21252454763714676, -0.2159329012428134, -0.2193335995766739, -0.22272531154707456, -0.22610670942914374, -0.22947646981552827, -0.23283327456626227, -0.23617581175711852, -0.23950277662581587, -0.24281287251543843, -0.24610481181443558, -0.2493773168925644, -0.25262912103214313, -0.25585896935398406, -0.25906561973737496, -0.26224784373348814, -0.26540442747158394, -0.2685341725574021, -0.27163589696311297, -0.2747084359082267, -0.2777506427308474, -0.2807613897486776, -0.28373956910916653, -0.28668409362822705, -0.28959389761692317, -0.29246793769556123, -0.2953051935946083, -0.2981046689418832, -0.3008653920354557, -0.3035864166017143, -0.3062668225380626, -0.30890571663971594, -0.3115022333100785, -0.3140555352541847, -0.3165648141547183, -0.3190292913301016, -0.32144821837419, -0.3238208777770936, -0.3261465835266744, -0.3284246816902687, -0.33065455097620156, -0.3328356032746735, -0.3349672841776071, -0.3370490734770586, -0.3390804856418047, -0.34106107027174437, -0.34299041252974455, -0.34486813355059764, -0.3466938908267536, -0.3484673785705151, -0.3501883280523903, -0.35185650791531886, -0.35347172446450037, -0.3550338219325645, -0.3565426827198468, -0.35799822760953326, -0.3594004159574775, -0.36074924585647977, -0.3620447542748607, -0.3632870171691585, -0.36447614957080676, -0.36561230564665825, -0.36669567873324127, -0.36772650134465235, -0.3687050451539986, -0.36963162094833074, -0.3705065785570094, -0.3713303067534837, -0.37210323313045607, -0.37282582394844316, -0.3734985839577476, -0.3741220561938765, -0.37469682174645946, -0.3752234995017328, -0.3757027458586814, -0.3761352544189352, -0.37652175565054324, -0.37686301652575954, -0.37715984013299686, -0.3774130652631137, -0.377623565970225, -0.37779225110723696, -0.37792006383632526, -0.37800798111459183, -0.3780570131551524, -0.37806820286392206, -0.3780426252523819, -0.37798138682662696, -0.37788562495300754, -0.3777565072006976, -0.3775952306615324, -0.37740302124747804, -0.37718113296610783, -0.37693084717447434, -0.37665347181178194, -0.3763503406112796, -0.3760228122918046, -0.37567226972942613, -0.3753001191096462, -0.37490778906063205, -0.37449672976796566, -0.37406841207141023, -0.3736243265442033, -0.37316598255540245, -0.37269490731581434, -0.37221264490805805, -0.3717207553013193, -0.3712208133513625, -0.3707144077863824, -0.37020314017928513, -0.36968862390699425, -0.3691724830973962, -0.3686563515645381, -0.36814187173270996, -0.3676306935500424, -0.3671244733922657, -0.36662487295728097, -0.36613355815120147, -0.36565219796653076, -0.3651824633531479, -0.3647260260827801, -0.364284557607644, -0.3638597279139478, -0.3634532043709451, -0.3630666505762428, -0.3627017251980631, -0.36236008081516563, -0.36204336275514326, -0.3617532079317988, -0.3614912436823219, -0.36125908660498063, -0.3610583413980475, -0.36089059970067794, -0.3607574389364632, -0.3606604211603766, -0.3606010919098352, -0.3605809790605945, -0.3606015916881958, -0.3606644189356837, -0.36077092888830825, -0.3609225674559237, -0.3611207572637965, -0.361366896552526, -0.36166235808778213, -0.3620084880805611, -0.3624066051186495, -0.36285799910998967, -0.36336393023862784, -0.36392562793392563, -0.36454428985370546, -0.3652210808819944, -0.3659571321420275, -0.36675354002515853, -0.36761136523632304, -0.3685316318566862, -0.3695153264241059, -0.37056339703202246, -0.37167675244738635, -0.37285626124821913, -0.3741027509813995, -0.37541700734124417, -0.37679977336945575, -0.37825174867699185, -0.3797735886883934, -0.3813659039091079, -0.38302925921632586, -0.3847641731738353, -0.3865711173713868, -0.3884505157890504, -0.3904027441870283, -0.39242812952137945, -0.39452694938608945, -0.3966994314819109, -0.3989457531123855, -0.40126604070743604, -0.4036603693749121, -0.4061287624804474, -0.40867119125598045, -0.41128757443726505, -0.4139777779306878, -0.41674161450968766, -0.4195788435410654, -0.4224891707414389, -0.4254722479640964, -0.42852767301648037, -0.43165498950850817, -0.4348536867319277, -0.4381231995708873, -0.44146290844387687, -0.44487213927718355, -0.44835016350998097, -0.4518961981311622, -0.45550940574800225, -0.459188894686716, -0.462933719124962, -0.46674287925632857, -0.47061532148680585, -0.4745499386632461, -0.47854557033378153, -0.4826010030401647, -0.48671497064196, -0.4908861546725087, -0.49511318472657617, -0.49939463887954877, -0.5037290441380587, -0.5081148769218683, -0.5125505635768546, -0.5170344809188875, -0.521564956808401, -0.5261402707554237, -0.5307586545548286, -0.5354182929515289, -0.5401173243353378, -0.5448538414652021, -0.5496258922224779, -0.5544314803929202, -0.5592685664770314, -0.5641350685284017, -0.5690288630196465, -0.5739477857355461, -0.5788896326929561, -0.583852161087069, -0.5888330902635539, -0.5938301027161157, -0.5988408451089957, -0.6038629293238968, -0.6088939335308354, -0.6139314032823753, -0.6189728526307161, -0.6240157652670593, -0.6290575956826908, -0.6340957703511914, -0.6391276889311742, -0.6441507254889396, -0.6491622297404106, -0.6541595283117345, -0.6591399260178735, -0.6641007071585427, -0.669039136830814, -0.6739524622577076, -0.6788379141320704, -0.6836927079750436, -0.6885140455083963, -0.6932991160400215, -0.6980450978618365, -0.7027491596593614, -0.7074084619322323, -0.7120201584248748, -0.7165813975665937, -0.7210893239202877, -0.7255410796390341, -0.7299338059297406, -0.7342646445230843, -0.7385307391489477, -0.742729237016543, -0.7468572902984342, -0.7509120576176336, -0.7548907055369861, -0.7587904100500044, -0.7626083580723559, -0.76634174893318, -0.7699877958654164, -0.7735437274943255, -0.7770067893233802, -0.7803742452167076, -0.7836433788772721, -0.7868114953199632, -0.789875922338781, -0.7928340119673096, -0.7956831419316492, -0.798420717095013, -0.8010441708931686, -0.8035509667599295, -0.8059385995418927, -0.8082045969016262, -0.810346520708523, -0.8123619684165291, -0.8142485744279759, -0.8160040114427352, -0.8176259917919424, -0.8191122687555232, -0.8204606378627729, -0.8216689381752548, -0.8227350535512767, -0.8236569138912226, -0.8244324963630291, -0.825059826607096, -0.8255369799199452, -0.8258620824159325, -0.8260333121663505, -0.8260489003152545, -0.8259071321713659, -0.8256063482754159, -0.8251449454423012, -0.8245213777774456, -0.8237341576667668, -0.8227818567396642, -0.821663106804459, -0.8203766007557275, -0.8189210934529908, -0.817295402570232, -0.8154984094157312, -0.8135290597217218, -0.8113863644033932, -0.8090694002867722, -0.8065773108050384, -0.8039093066628463, -0.801064666468239, -0.798042737331761, -0.794842935432387, -0.7914647465499148, -0.7879077265634761, -0.7841715019158371, -0.7802557700431956, -0.7761602997701736, -0.7718849316697529, -0.7674295783878863, -0.7627942249325723, -0.7579789289271733, -0.752983820827787, -0.7478091041044986, -0.7424550553863636, -0.7369220245699929, -0.7312104348916122, -0.7253207829625226, -0.7192536387678758, -0.713009645628719, -0.7065895201272642, -0.6999940519953883, -0.6932241039663541, -0.6862806115897937, -0.6791645830099808, -0.6718770987074884, -0.664419311204298, -0.6567924447324703, -0.6489977948665181, -0.6410367281196117, -0.6329106815038031, -0.6246211620544329, -0.6161697463189502, -0.607558079810364, -0.5987878764255563, -0.5898609178287524, -0.5807790528004051, -0.5715441965518189, -0.5621583300058116, -0.5526234990437826, -0.5429418137195278, -0.5331154474401901, -0.5231466361147277, -0.5130376772703339, -0.5027909291372341, -0.4924088097022915, -0.48189379573191604, -0.4712484217647424, -0.4604752790745896, -0.44957701460419675, -0.4385563298703019, -0.42741597984057983, -0.4161587717830348, -0.40478756408839084, -0.3933052650661125, -0.3817148317146478, -0.3700192684665032, -0.35822162590882173, -0.3463249994800827, -0.334332528143618, -0.32224739303858546, -0.31007281610912957, -0.29781205871240407, -0.2854684202061855, -0.27304523651678253, -0.26054587868800627, -0.2479737514119335, -0.23533229154220517, -0.22262496659065495, -0.20985527320802408, -0.19702673564956308, -0.18414290422628268, -0.17120735374269982, -0.15822368192184572, -0.14519550781838755, -0.1321264702206454, -0.11902022604237436, -0.10588044870512842, -0.09271082651202488, -0.07951506101379793, -0.06629686536794653, -0.05305996269186681, -0.039808084410782305, -0.02654496860137497, -0.013274358331948254, ], [0.0, 0.006637387408525834, 0.013274150090572815, 0.019909663399631097, 0.026543302849117337, 0.03317444419230918, 0.03980246350224521, 0.04642673725157881, 0.05304664239237466, 0.05966155643583603, 0.06627085753195172, 0.07287392454905098, 0.0794701371532551, 0.08605887588781405, 0.09263952225231704, 0.09921145878176524, 0.1057740691254956, 0.11232673812594425, 0.11886885189723814, 0.12539979790360375, 0.1319189650375814, 0.1384257436980341, 0.14491952586793933, 0.15139970519195362, 0.15786567705373689, 0.1643168386530275, 0.17075258908245566, 0.1771723294040843, 0.18357546272566694, 0.1899613942766108, 0.1963295314836352, 0.20267928404611343, 0.2090100640110879, 0.21532128584794752, 0.2216123665227567, 0.2278827255722251, 0.23413178517730815, 0.2403589702364264, 0.24656370843829517, 0.252745430334352, 0.2589035694107731, 0.2650375621600676, 0.2711468481522396, 0.2772308701055077, 0.28328907395657227, 0.2893209089304201, 0.29532582760965564, 0.301303286003351, 0.3072527436154021, 0.3131736635123845, 0.3190655123908949, 0.3249277606443733, 0.33075988242939297, 0.33656135573140966, 0.34233166242996216, 0.3480702883633119, 0.3537767233925152, 0.3594504614649174, 0.3650910006770606, 0.37069784333699507, 0.3762704960259872, 0.38180846965961296, 0.38731127954822975, 0.39277844545681795, 0.39820949166418246, 0.4036039470215075, 0.40896134501025516, 0.4142812237993991, 0.41956312630198683, 0.42480660023102196, 0.4300111981546567, 0.43517647755068983, 0.44030200086035987, 0.4453873355414276, 0.45043205412053916, 0.4554357342448625, 0.46039795873299116, 0.46531831562510684, 0.47019639823239295, 0.4750318051856954, 0.4798241404834199, 0.484573013538662, 0.4892780392255608, 0.49393883792487253, 0.49855503556875463, 0.5031262636847571, 0.5076521594390123, 0.5121323656786182, 0.5165665309732107, 0.5209543096557158, 0.5252953618622791, 0.5295893535713656, 0.5338359566420254, 0.5380348488513168, 0.5421857139308878, 0.5462882416027031, 0.5503421276139193, 0.5543470737708976, 0.5583027879723531, 0.5622089842416335, 0.5660653827581247, 0.5698717098877787, 0.5736276982127586, 0.577333086560197, 0.5809876200300657, 0.5845910500221492, 0.5881431342621235, 0.5916436368267308, 0.5950923281680519, 0.5984889851368704, 0.6018333910051255, 0.6051253354874517, 0.6083646147618023, 0.6115510314891519, 0.6146843948322802, 0.617764520473628, 0.6207912306322291, 0.6237643540797134, 0.6266837261553783, 0.6295491887803278, 0.6323605904706793, 0.6351177863498308, 0.6378206381597951, 0.6404690142715915, 0.6430627896947014, 0.64560184608558, 0.6480860717552278, 0.6505153616758201, 0.6528896174863921, 0.6552087474975817, 0.6574726666954277, 0.6596812967442239, 0.6618345659884298, 0.663932409453637, 0.6659747688465902, 0.6679615925542682, 0.6698928356420188, 0.6717684598507521, 0.6735884335931936, 0.6753527319491957, 0.6770613366601093, 0.6787142361222183, 0.6803114253792354, 0.6818529061138643, 0.6833386866384269, 0.6847687818845583, 0.6861432133919715, 0.6874620092962953, 0.6887252043159837, 0.6899328397383041, 0.6910849634044018, 0.6921816296934487, 0.6932228995058717, 0.6942088402456732, 0.6951395258018369, 0.6960150365288296, 0.6968354592261963, 0.6976008871172584, 0.6983114198269119, 0.6989671633585341, 0.6995682300700001, 0.7001147386488137, 0.7006068140863575, 0.701044587651265, 0.7014281968619193, 0.7017577854580848, 0.7020335033716727, 0.7022555066966489, 0.7024239576580867, 0.7025390245803695, 0.7026008818545493, 0.7026097099048665, 0.7025656951544346, 0.702469029990097, 0.7023199127264604, 0.702118547569111, 0.7018651445770193, 0.7015599196241392, 0.7012030943602067, 0.7007948961707469, 0.7003355581362921, 0.6998253189908196, 0.6992644230794166, 0.6986531203151747, 0.6979916661353263, 0.6972803214566246, 0.6965193526299785, 0.695709031394346, 0.6948496348298943, 0.6939414453104364, 0.6929847504551473, 0.69197984307957, 0.6909270211459193, 0.6898265877126889, 0.6886788508835712, 0.6874841237556979, 0.6862427243672072, 0.6849549756441502, 0.6836212053467383, 0.682241746014946, 0.6808169349134736, 0.6793471139760804, 0.6778326297492948, 0.6762738333355126, 0.6746710803354908, 0.6730247307902444, 0.6713351491223584, 0.6696027040767211, 0.6678277686606889, 0.6660107200836928, 0.6641519396962929, 0.6622518129286943, 0.6603107292287291, 0.6583290819993197, 0.6563072685354264, 0.6542456899604957, 0.6521447511624119, 0.6500048607289693, 0.6478264308828687, 0.6456098774162505, 0.6433556196247764, 0.6410640802412649, 0.6387356853688957, 0.6363708644139902, 0.6339700500183795, 0.6315336779913691, 0.6290621872413124, 0.6265560197068015, 0.6240156202874884, 0.6214414367745419, 0.6188339197807586, 0.6161935226703318, 0.613520701488291, 0.6108159148896248, 0.6080796240680957, 0.6053122926847574, 0.6025143867961879, 0.5996863747824455, 0.5968287272747637, 0.5939419170829896, 0.591026419122783, 0.588082710342583, 0.5851112696503551, 0.582112577840129, 0.5790871175183392, 0.5760353730299782, 0.5729578303845745, 0.5698549771820074, 0.5667273025381658, 0.5635752970104688, 0.5603994525232527, 0.5572002622930399, 0.5539782207537007, 0.5507338234815153, 0.5474675671201538, 0.5441799493055798, 0.5408714685908917, 0.5375426243711131, 0.5341939168079416, 0.5308258467544702, 0.5274389156798907, 0.5240336255941896, 0.5206104789728524, 0.5171699786815801, 0.5137126279010368, 0.5102389300516351, 0.5067493887183718, 0.5032445075757261, 0.4997247903126294, 0.4961907405575211, 0.49264286180349837, 0.4890816573335737, 0.48550763014604964, 0.4819212828800236, 0.4783231177410318, 0.47471363642684555, 0.4710933400534303, 0.46746272908107733, 0.4638223032407211, 0.4601725614604522, 0.456514001792237, 0.4528471213388545, 0.4491724161810634, 0.4454903813050065, 0.44180151052986794, 0.4381062964357895, 0.43440523029206063, 0.4306988019855897, 0.42698749994967017, 0.4232718110930497, 0.41955222072931414, 0.41582921250659693, 0.4121032683376239, 0.40837486833010433, 0.404644490717478, 0.4009126117900298, 0.3971797058263804, 0.3934462450253648, 0.3897126994383076, 0.3859795369017069, 0.3822472229703338, 0.378516220850761, 0.37478699133532717, 0.3710599927365496, 0.3673356808219928, 0.3636145087496036, 0.3598969270035231, 0.3561833833303824, 0.35247432267609563, 0.34877018712315494, 0.3450714158284409, 0.341378444961555, 0.3376917076436839, 0.33401163388700483, 0.3303386505346413, 0.3266731812011765, 0.323015646213736, 0.3193664625536448, 0.3157260437986704, 0.31209480006585893, 0.30847313795497305, 0.30486146049254015, 0.301260167076518, 0.29766965342158846, 0.29409031150508375, 0.2905225295135569, 0.28696669179000134, 0.2834231787817282, 0.27989236698891173, 0.27637462891380327, 0.27287033301063107, 0.2693798436361835, 0.2659035210010906, 0.2624417211218066, 0.2589947957732998, 0.25556309244246317, 0.2521469542822423, 0.24874672006649798, 0.2453627241456005, 0.24199529640277012, 0.23864476221116324, 0.23531144239171678, 0.23199565317175042, 0.22869770614433899, 0.225417908228456, 0.2221565616298957, 0.21891396380298134, 0.21569040741306097, 0.21248618029980051, 0.2093015654412751, 0.2061368409188687, 0.20299227988298119, 0.19986815051955412, 0.1967647160174134, 0.1936822345364402, 0.19062095917656866, 0.18758113794761752, 0.18456301373996187, 0.18156682429604382, 0.17859280218273263, 0.17564117476453095, 0.17271216417763874, 0.16980598730487054, 0.16692285575143698, 0.16406297582158763, 0.1612265484961219, 0.15841376941077148, 0.15562482883545337, 0.15285991165440227, 0.1501191973471779, 0.14740285997055738, 0.14471106814130735, 0.14204398501984553, 0.13940176829478737, 0.13678457016838455, 0.1341925373428544, 0.13162581100760243, 0.1290845268273417, 0.12656881493110594, 0.1240787999021638, 0.12161460076882864, 0.11917633099617153, 0.11676409847863216, 0.11437800553353428, 0.11201814889550045, 0.1096846197117724, 0.10737750353843226, 0.10509688033752748, 0.10284282447510029, 0.10061540472011843, 0.09841468424431093, 0.0962407206229042, 0.0940935658362631, 0.09197326627243028, 0.0898798627305693, 0.0878133904253051, 0.08577387899196512, 0.08376135249271702, 0.08177582942360212, 0.07981732272246561, 0.07788583977777744, 0.07598138243834697, 0.07410394702392531, 0.0722535243366972, 0.07043009967365585, 0.06863365283986382, 0.06686415816259211, 0.06512158450633919, 0.06340589528872487, 0.061717048497256254, 0.06005499670696537, 0.05841968709891093, 0.05681106147954596, 0.05522905630094368, 0.05367360268188162, 0.052144626429776936, 0.05064204806347311, 0.04916578283687051, 0.04771574076339877, 0.04629182664132707, 0.04489394007990645, 0.04352197552634197, 0.042175822293587684, 0.04085536458896256, 0.039560481543579974, 0.03829104724258793, 0.03704693075621344, 0.035827996171607326, 0.034634102625483276, 0.0334651043375455, 0.03232085064470105, 0.031201186036048965, 0.03010595018864299, 0.029034978004019253, 0.027988099645486056, 0.02696514057616641, 0.02596592159779001, 0.02499025889022627, 0.024037964051753482, 0.02310884414005623, 0.022202701713945307, 0.021319334875793527, 0.020458537314679302, 0.01962009835023293, 0.018803802977176275, 0.018009431910551005, 0.01723676163162589, 0.016485564434477688, 0.01575560847323617, 0.01504665780998787, 0.014358472463328945, 0.013690808457560008, 0.013043417872516108, 0.012416048894021398, 0.011808445864963466, 0.011220349336976215, 0.01065149612272538, 0.010101619348786499, 0.009570448509108305, 0.00905770951905188, 0.008563124769997668, 0.008086413184511409, 0.007627290272059658, 0.007185468185267197, 0.006760655776706016, 0.0063525586562076875, 0.0059608792486897265, 0.005585316852486749, 0.005225567698176947, 0.004881325007894864, 0.0045522790551206305, 0.004238117224936695, 0.003938524074741718, 0.003653181395412538, 0.0033817682729045703, 0.0031239611502800785, 0.002879433890155546, 0.002647857837557091, 0.0024289018831751188, 0.0022222325270070085, 0.002027513942378644, 0.0018444080403340962, 0.0016725745343835333, 0.0015116710055988062, 0.001361352968046667, 0.001221273934549108, 0.0010910854827603789, 0.0009704373215501973, 0.0008589773576827851, 0.0007563517627809753, 0.0006622050405647391, 0.0005761800943539743, 0.0004979182948240085, 0.00042705954800389447, 0.00036324236350631424, 0.00030610392297834957, 0.00025528014876232606, 0.00021040577275587634, 0.000171114405460249, 0.00013703860520603904, 0.00010780994754525344, 8.305909479892577e-5, 6.241586574900167e-5, 4.550930546380298e-5, 3.196775524569481e-5, 2.141892269016194e-5, 1.3489951845027902e-5, 7.807493458822025e-6, 3.997775307161212e-6, 1.6866725859828609e-6, 4.997783605659614e-7, 6.24740591445087e-8, 0.0, -6.24740591445087e-8, -4.997783605659614e-7, -1.6866725859828609e-6, -3.997775307161212e-6, -7.807493458822025e-6, -1.3489951845024148e-5, -2.141892269016194e-5, -3.196775524569481e-5, -4.550930546380298e-5, -6.241586574900167e-5, -8.305909479892577e-5, -0.00010780994754526095, -0.00013703860520603904, -0.0001711144054602565, -0.00021040577275587634, -0.00025528014876232606, -0.00030610392297834957, -0.00036324236350631424, -0.00042705954800389447, -0.000497918294824001, -0.0005761800943539743, -0.0006622050405647542, -0.0007563517627809603, -0.0008589773576828001, -0.0009704373215501973, -0.001091085482760364, -0.001221273934549108, -0.001361352968046652, -0.0015116710055987912, -0.0016725745343835184, -0.0018444080403340962, -0.0020275139423786294, -0.0022222325270069934, -0.002428901883175104, -0.002647857837557091, -0.0028794338901555307, -0.0031239611502800785, -0.003381768272904555, -0.003653181395412538, -0.003938524074741687, -0.004238117224936695, -0.0045522790551206305, -0.004881325007894864, -0.005225567698176947, -0.005585316852486749, -0.005960879248689696, -0.006352558656207628, -0.0067606557767059565, -0.007185468185267197, -0.007627290272059658, -0.008086413184511379, -0.008563124769997668, -0.00905770951905185, -0.009570448509108275, -0.010101619348786468, -0.01065149612272535, -0.011220349336976186, -0.011808445864963435, -0.012416048894021368, -0.013043417872516077, -0.013690808457560008, -0.014358472463328885, -0.01504665780998784, -0.01575560847323614, -0.01648556443447766, -0.017236761631625862, -0.018009431910550974, -0.018803802977176244, -0.019620098350232897, -0.02045853731467927, -0.0213193348757935, -0.022202701713945276, -0.02310884414005617, -0.02403796405175345, -0.02499025889022624, -0.025965921597789984, -0.026965140576166377, -0.027988099645486056, -0.029034978004019225, -0.030105950188642927, -0.031201186036048906, -0.03232085064470099, -0.03346510433754544, -0.03463410262548322, -0.035827996171607264, -0.03704693075621338, -0.0382910472425879, -0.03956048154357991, -0.04085536458896256, -0.04217582229358763, -0.043521975526341905, -0.04489394007990639, -0.04629182664132707, -0.04771574076339871, -0.049165782836870446, -0.05064204806347302, -0.052144626429776846, -0.05367360268188153, -0.05522905630094368, -0.056811061479545934, -0.05841968709891084, -0.06005499670696534, -0.0617170484972562, -0.06340589528872477, -0.06512158450633909, -0.06686415816259203, -0.06863365283986372, -0.07043009967365577, -0.07225352433669711, -0.07410394702392528, -0.07598138243834687, -0.07788583977777735, -0.07981732272246558, -0.08177582942360209, -0.08376135249271693, -0.08577387899196505, -0.08781339042530498, -0.08987986273056922, -0.0919732662724302, -0.09409356583626305, -0.09624072062290415, -0.09841468424431081, -0.1006154047201183, -0.10284282447510017, -0.10509688033752738, -0.1073775035384322, -0.10968461971177235, -0.1120181488955004, -0.11437800553353415, -0.11676409847863205, -0.11917633099617145, -0.12161460076882857, -0.12407879990216374, -0.12656881493110583, -0.1290845268273416, -0.13162581100760234, -0.1341925373428543, -0.1367845701683844, -0.1394017682947873, -0.14204398501984525, -0.1447110681413074, -0.14740285997055727, -0.1501191973471778, -0.15285991165440194, -0.15562482883545348, -0.15841376941077132, -0.16122654849612178, -0.16406297582158733, -0.16692285575143706, -0.16980598730487045, -0.1727121641776384, -0.17564117476453103, -0.17859280218273246, -0.18156682429604373, -0.1845630137399615, -0.1875811379476176, -0.19062095917656857, -0.1936822345364401, -0.19676471601741308, -0.19986815051955395, -0.20299227988298107, -0.20613684091886836, -0.2093015654412752, -0.21248618029980035, -0.21569040741306084, -0.218913963802981, -0.2221565616298958, -0.2254179082284559, -0.22869770614433885, -0.23199565317175005, -0.23531144239171667, -0.23864476221116313, -0.24199529640276973, -0.24536272414560062, -0.24874672006649787, -0.25214695428224215, -0.2555630924424628, -0.2589947957732999, -0.26244172112180647, -0.26590352100109055, -0.2693798436361831, -0.2728703330106309, -0.27637462891380316, -0.27989236698891135, -0.2834231787817284, -0.28696669179000117, -0.29052252951355667, -0.2940903115050834, -0.2976696534215884, -0.3012601670765178, -0.3048614604925398, -0.30847313795497266, -0.31209480006585893, -0.3157260437986702, -0.3193664625536446, -0.32301564621373613, -0.3266731812011765, -0.33033865053464107, -0.33401163388700444, -0.33769170764368384, -0.34137844496155484, -0.3450714158284406, -0.34877018712315505, -0.35247432267609563, -0.35618338333038235, -0.3598969270035229, -0.3636145087496037, -0.3673356808219927, -0.3710599927365493, -0.3747869913353268, -0.378516220850761, -0.3822472229703337, -0.38597953690170655, -0.3897126994383077, -0.3934462450253647, -0.3971797058263803, -0.40091261179002957, -0.4046444907174781, -0.4083748683301042, -0.4121032683376237, -0.41582921250659655, -0.41955222072931414, -0.42327181109304957, -0.4269874999496699, -0.4306988019855898, -0.4344052302920605, -0.43810629643578936, -0.44180151052986766, -0.4454903813050066, -0.44917241618106324, -0.4528471213388543, -0.45651400179223656, -0.4601725614604522, -0.463822303240721, -0.46746272908107706, -0.4710933400534305, -0.47471363642684555, -0.47832311774103164, -0.48192128288002334, -0.48550763014604975, -0.4890816573335736, -0.4926428618034981, -0.4961907405575207, -0.49972479031262934, -0.5032445075757259, -0.5067493887183716, -0.5102389300516352, -0.5137126279010368, -0.5171699786815799, -0.5206104789728521, -0.5240336255941896, -0.5274389156798904, -0.53082584675447, -0.5341939168079411, -0.5375426243711131, -0.5408714685908916, -0.5441799493055796, -0.5474675671201538, -0.5507338234815153, -0.5539782207537006, -0.5572002622930396, -0.5603994525232527, -0.5635752970104687, -0.5667273025381656, -0.569854977182007, -0.5729578303845745, -0.5760353730299781, -0.579087117518339, -0.5821125778401292, -0.5851112696503551, -0.5880827103425829, -0.5910264191227826, -0.5939419170829896, -0.5968287272747635, -0.5996863747824452, -0.6025143867961874, -0.6053122926847574, -0.6080796240680955, -0.6108159148896246, -0.6135207014882911, -0.6161935226703318, -0.6188339197807584, -0.6214414367745416, -0.6240156202874884, -0.6265560197068015, -0.629062187241312, -0.6315336779913687, -0.6339700500183794, -0.63637086441399, -0.6387356853688955, -0.6410640802412649, -0.6433556196247764, -0.6456098774162505, -0.6478264308828684, -0.6500048607289693, -0.6521447511624118, -0.6542456899604956, -0.6563072685354263, -0.6583290819993197, -0.660310729228729, -0.6622518129286941, -0.664151939696293, -0.6660107200836928, -0.6678277686606887, -0.6696027040767208, -0.6713351491223584, -0.6730247307902443, -0.6746710803354906, -0.6762738333355125, -0.6778326297492947, -0.6793471139760803, -0.6808169349134735, -0.682241746014946, -0.6836212053467383, -0.6849549756441502, -0.6862427243672071, -0.6874841237556978, -0.6886788508835712, -0.6898265877126888, -0.6909270211459193, -0.69197984307957, -0.6929847504551473, -0.6939414453104363, -0.6948496348298944, -0.695709031394346, -0.6965193526299785, -0.6972803214566244, -0.6979916661353263, -0.6986531203151747, -0.6992644230794166, -0.6998253189908196, -0.7003355581362921, -0.7007948961707469, -0.7012030943602067, -0.7015599196241392, -0.7018651445770193, -0.702118547569111, -0.7023199127264603, -0.702469029990097, -0.7025656951544346, -0.7026097099048665, -0.7026008818545493, -0.7025390245803695, -0.7024239576580867, -0.7022555066966489, -0.7020335033716727, -0.7017577854580848, -0.7014281968619193, -0.7010445876512651, -0.7006068140863575, -0.7001147386488137, -0.6995682300700001, -0.6989671633585341, -0.6983114198269119, -0.6976008871172584, -0.6968354592261964, -0.6960150365288296, -0.6951395258018371, -0.6942088402456732, -0.6932228995058718, -0.6921816296934487, -0.6910849634044021, -0.6899328397383041, -0.6887252043159837, -0.6874620092962953, -0.6861432133919716, -0.6847687818845584, -0.6833386866384269, -0.6818529061138643, -0.6803114253792354, -0.6787142361222184, -0.6770613366601095, -0.6753527319491958, -0.6735884335931938, -0.671768459850752, -0.6698928356420188, -0.6679615925542683, -0.6659747688465903, -0.6639324094536369, -0.6618345659884299, -0.659681296744224, -0.6574726666954279, -0.6552087474975817, -0.6528896174863923, -0.6505153616758202, -0.6480860717552277, -0.64560184608558, -0.6430627896947017, -0.6404690142715919, -0.6378206381597951, -0.635117786349831, -0.6323605904706794, -0.6295491887803283, -0.6266837261553783, -0.6237643540797135, -0.6207912306322293, -0.6177645204736278, -0.6146843948322803, -0.6115510314891521, -0.6083646147618026, -0.6051253354874517, -0.6018333910051256, -0.5984889851368707, -0.5950923281680524, -0.5916436368267308, -0.5881431342621236, -0.5845910500221496, -0.5809876200300654, -0.5773330865601971, -0.5736276982127587, -0.569871709887779, -0.5660653827581246, -0.5622089842416336, -0.5583027879723534, -0.5543470737708981, -0.5503421276139193, -0.5462882416027032, -0.542185713930888, -0.5380348488513168, -0.5338359566420254, -0.5295893535713659, -0.5252953618622794, -0.5209543096557158, -0.5165665309732109, -0.5121323656786186, -0.5076521594390128, -0.5031262636847571, -0.49855503556875486, -0.4939388379248728, -0.4892780392255607, -0.4845730135386621, -0.4798241404834202, -0.47503180518569577, -0.47019639823239284, -0.465318315625107, -0.4603979587329916, -0.45543573424486306, -0.4504320541205392, -0.44538733554142784, -0.44030200086036025, -0.4351764775506897, -0.4300111981546568, -0.42480660023102235, -0.4195631263019874, -0.414281223799399, -0.4089613450102554, -0.403603947021508, -0.39820949166418307, -0.39277844545681795, -0.38731127954823, -0.38180846965961346, -0.3762704960259871, -0.3706978433369952, -0.3650910006770609, -0.35945046146491794, -0.3537767233925151, -0.34807028836331205, -0.34233166242996255, -0.33656135573140944, -0.33075988242939297, -0.32492776064437356, -0.3190655123908954, -0.31317366351238446, -0.3072527436154023, -0.30130328600335127, -0.2953258276096562, -0.28932090893042006, -0.28328907395657255, -0.2772308701055081, -0.2711468481522394, -0.2650375621600677, -0.2589035694107734, -0.25274543033435254, -0.24656370843829506, -0.24035897023642655, -0.2341317851773085, -0.22788272557222575, -0.22161236652275665, -0.21532128584794774, -0.20901006401108838, -0.20267928404611324, -0.19632953148363527, -0.18996139427661107, -0.18357546272566747, -0.1771723294040842, -0.1707525890824558, -0.1643168386530279, -0.15786567705373755, -0.15139970519195362, -0.1449195258679396, -0.13842574369803456, -0.13191896503758124, -0.12539979790360384, -0.11886885189723848, -0.11232673812594485, -0.10577406912549549, -0.0992114587817654, -0.09263952225231747, -0.08605887588781473, -0.0794701371532551, -0.07287392454905123, -0.06627085753195223, -0.05966155643583585, -0.05304664239237474, -0.046426737251579156, -0.039802463502245804, -0.03317444419230909, -0.02654330284911751, -0.019909663399631534, -0.013274150090573514, -0.006637387408525837, ], [0.0, 0.0033187249412924896, 0.00663732493446669, 0.009955675036108539, 0.01327365031221225, 0.016591125842884004, 0.019907976727045112, 0.023224078087134482, 0.026539305073810176, 0.029853532870649912, 0.03316663669885036, 0.03647849182192495, 0.039788973550400175, 0.04309795724651005, 0.046405318328888646, 0.049710932277260554, 0.053014674637128965, 0.0563164210244613, 0.059616047130372225, 0.06291342872580381, 0.06620844166620271, 0.06950096189619402, 0.07279086545425205, 0.07607802847736728, 0.07936232720570983, 0.08264363798728885, 0.08592183728260802, 0.0891968016693167, 0.09246840784685678, 0.0957365326411048, 0.09900105300900935, 0.10226184604322376, 0.10551878897673327, 0.10877175918747727, 0.1120206342029659, 0.1152652917048911, 0.11850560953373183, 0.12174146569335324, 0.12497273835559987, 0.12819930586488226, 0.1314210467427574, 0.13463783969250215, 0.13784956360368011, 0.14105609755670126, 0.1442573208273746, 0.1474531128914534, 0.15064335342917265, 0.15382792232977932, 0.15700669969605408, 0.16017956584882584, 0.16334640133147732, 0.16650708691444235, 0.1696615035996953, 0.17280953262523074, 0.1759510554695352, 0.1790859538560492, 0.18221410975762026, 0.18533540540094678, 0.188449723271012, 0.19155694611550822, 0.19465695694925164, 0.1977496390585865, 0.20083487600577934, 0.2039125516334029, 0.2069825500687093, 0.21004475572799258, 0.21309905332094053, 0.21614532785497498, 0.21918346463958158, 0.22221334929062744, 0.225234867734668, 0.22824790621324165, 0.2312523512871526, 0.23424808984074194, 0.23723500908614634, 0.24021299656754438, 0.24318194016539058, 0.24614172810063661, 0.24909224893893947, 0.2520333915948573, 0.2549650453360314, 0.25788709978735547, 0.2607994449351309, 0.263701971131209, 0.2665945690971189, 0.26947712992818224, 0.27234954509761283, 0.27521170646060306, 0.2780635062583953, 0.2809048371223392, 0.28373559207793336, 0.2865556645488538, 0.2893649483609659, 0.29216333774632197, 0.2949507273471433, 0.29772701221978676, 0.30049208783869613, 0.3032458501003373, 0.3059881953271173, 0.30871902027128856, 0.3114382221188353, 0.3141456984933448, 0.3168413474598619, 0.3195250675287266, 0.3221967576593952, 0.32485631726424424, 0.3275036462123578, 0.33013864483329725, 0.33276121392085384, 0.3353712547367837, 0.3379686690145254, 0.3405533589628993, 0.34312522726978983, 0.3456841771058088, 0.34823011212794114, 0.35076293648317247, 0.35328255481209714, 0.3557888722525092, 0.3582817944429737, 0.36076122752637907, 0.36322707815347116, 0.36567925348636754, 0.3681176612020529, 0.370542209495855, 0.3729528070849008, 0.37534936321155377, 0.3777317876468301, 0.3800999906937963, 0.38245388319094625, 0.38479337651555795, 0.3871183825870299, 0.38942881387019773, 0.39172458337862925, 0.3940056046779003, 0.3962717918888481, 0.3985230596908049, 0.4007593233248106, 0.4029804985968032, 0.4051865018807891, 0.4073772501219915, 0.4095526608399774, 0.4117126521317627, 0.4138571426748963, 0.4159860517305211, 0.4180992991464146, 0.4201968053600058, 0.4222784914013714, 0.4243442788962086, 0.42639409006878565, 0.4284278477448706, 0.4304454753546362, 0.4324468969355436, 0.4344320371352013, 0.4364008212142028, 0.43835317504894045, 0.4402890251343956, 0.4422082985869069, 0.44411092314691347, 0.4459968271816761, 0.4478659396879738, 0.449718190294777, 0.4515535092658974, 0.453371827502613, 0.45517307654626993, 0.4569571885808598, 0.45872409643557277, 0.460473733587327, 0.46220603416327233, 0.4639209329432714, 0.4656183653623543, 0.4672982675131498, 0.46896057614829156, 0.47060522868279886, 0.47223216319643346, 0.47384131843603045, 0.47543263381780443, 0.47700604942963076, 0.4785615060333011, 0.48009894506675327, 0.4816183086462767, 0.48311953956869136, 0.48460258131350153, 0.48606737804502376, 0.487513874614489, 0.4889420165621189, 0.49035175011917637, 0.4917430222099898, 0.4931157804539514, 0.494469973167489, 0.4958055493660124, 0.4971224587658326, 0.498420651786055, 0.49970007955044615, 0.5009606938892739, 0.5022024473411207, 0.5034252931546713, 0.5046291852904716, 0.5058140784226633, 0.5069799279406898, 0.5081266899509754, 0.5092543212785787, 0.5103627794688177, 0.5114520227888677, 0.5125220102293337, 0.5135727015057931, 0.5146040570603134, 0.5156160380629407, 0.5166086064131623, 0.5175817247413408, 0.5185353564101212, 0.51946946551581, 0.5203840168897276, 0.5212789760995314, 0.5221543094505132, 0.523009983986867, 0.5238459674929301, 0.5246622284943961, 0.5254587362594996, 0.5262354608001732, 0.5269923728731775, 0.5277294439812001, 0.5284466463739309, 0.5291439530491049, 0.5298213377535199, 0.5304787749840247, 0.5311162399884793, 0.5317337087666864, 0.5323311580712955, 0.532908565408678, 0.5334659090397741, 0.534003167980911, 0.5345203220045931, 0.535017351640264, 0.5354942381750385, 0.5359509636544083, 0.5363875108829174, 0.536803863424809, 0.5372000056046455, 0.5375759225078977, 0.5379315999815065, 0.5382670246344162, 0.5385821838380783, 0.5388770657269267, 0.539151659198825, 0.5394059539154843, 0.5396399403028521, 0.5398536095514732, 0.5400469536168212, 0.5402199652196013, 0.5403726378460244, 0.5405049657480525, 0.5406169439436148, 0.5407085682167956, 0.5407798351179927, 0.5408307419640476, 0.5408612868383463, 0.5408714685908917, 0.5408612868383463, 0.5408307419640476, 0.5407798351179927, 0.5407085682167956, 0.5406169439436148, 0.5405049657480525, 0.5403726378460244, 0.5402199652196013, 0.5400469536168212, 0.5398536095514732, 0.5396399403028521, 0.5394059539154843, 0.539151659198825, 0.5388770657269267, 0.5385821838380783, 0.5382670246344163, 0.5379315999815065, 0.5375759225078977, 0.5372000056046455, 0.536803863424809, 0.5363875108829174, 0.5359509636544083, 0.5354942381750385, 0.535017351640264, 0.5345203220045931, 0.534003167980911, 0.5334659090397741, 0.532908565408678, 0.5323311580712955, 0.5317337087666864, 0.5311162399884793, 0.5304787749840247, 0.5298213377535199, 0.5291439530491049, 0.5284466463739309, 0.5277294439812001, 0.5269923728731775, 0.5262354608001734, 0.5254587362594996, 0.5246622284943961, 0.5238459674929302, 0.523009983986867, 0.5221543094505132, 0.5212789760995314, 0.5203840168897276, 0.5194694655158101, 0.5185353564101212, 0.5175817247413408, 0.5166086064131623, 0.5156160380629408, 0.5146040570603134, 0.5135727015057931, 0.5125220102293337, 0.5114520227888678, 0.5103627794688177, 0.5092543212785787, 0.5081266899509754, 0.5069799279406898, 0.5058140784226633, 0.5046291852904716, 0.5034252931546713, 0.5022024473411209, 0.5009606938892739, 0.49970007955044615, 0.49842065178605505, 0.4971224587658326, 0.4958055493660124, 0.494469973167489, 0.4931157804539514, 0.4917430222099899, 0.49035175011917637, 0.48894201656211894, 0.487513874614489, 0.4860673780450238, 0.4846025813135016, 0.4831195395686913, 0.48161830864627675, 0.48009894506675327, 0.47856150603330116, 0.4770060494296308, 0.4754326338178045, 0.47384131843603045, 0.4722321631964335, 0.4706052286827989, 0.4689605761482916, 0.46729826751314985, 0.46561836536235424, 0.4639209329432714, 0.46220603416327233, 0.460473733587327, 0.4587240964355728, 0.45695718858085993, 0.45517307654627004, 0.4533718275026131, 0.45155350926589743, 0.4497181902947771, 0.4478659396879738, 0.44599682718167605, 0.4441109231469135, 0.4422082985869069, 0.4402890251343957, 0.43835317504894045, 0.4364008212142029, 0.43443203713520134, 0.4324468969355437, 0.4304454753546363, 0.4284278477448705, 0.4263940900687857, 0.42434427889620857, 0.4222784914013715, 0.4201968053600058, 0.41809929914641464, 0.4159860517305211, 0.41385714267489637, 0.41171265213176284, 0.4095526608399775, 0.40737725012199155, 0.4051865018807891, 0.40298049859680324, 0.40075932332481057, 0.39852305969080504, 0.3962717918888481, 0.39400560467790036, 0.39172458337862925, 0.3894288138701978, 0.38711838258702996, 0.38479337651555806, 0.3824538831909463, 0.38009999069379624, 0.3777317876468301, 0.37534936321155377, 0.3729528070849009, 0.370542209495855, 0.3681176612020531, 0.36567925348636754, 0.3632270781534713, 0.3607612275263791, 0.3582817944429738, 0.35578887225250927, 0.3532825548120971, 0.3507629364831725, 0.34823011212794114, 0.34568417710580884, 0.34312522726978983, 0.34055335896289946, 0.3379686690145254, 0.3353712547367839, 0.3327612139208539, 0.3301386448332974, 0.3275036462123579, 0.3248563172642442, 0.32219675765939526, 0.3195250675287266, 0.316841347459862, 0.3141456984933448, 0.3114382221188354, 0.30871902027128856, 0.30598819532711746, 0.30324585010033733, 0.30049208783869613, 0.2977270122197868, 0.2949507273471432, 0.292163337746322, 0.2893649483609659, 0.2865556645488539, 0.28373559207793336, 0.28090483712233927, 0.2780635062583954, 0.2752117064606032, 0.2723495450976129, 0.2694771299281822, 0.266594569097119, 0.26370197113120897, 0.260799444935131, 0.25788709978735547, 0.25496504533603154, 0.2520333915948573, 0.24909224893893958, 0.24614172810063664, 0.24318194016539077, 0.24021299656754444, 0.23723500908614628, 0.234248089840742, 0.23125235128715257, 0.22824790621324176, 0.225234867734668, 0.22221334929062755, 0.2191834646395816, 0.21614532785497514, 0.21309905332094056, 0.21004475572799275, 0.20698255006870936, 0.20391255163340283, 0.20083487600577943, 0.19774963905858647, 0.19465695694925178, 0.19155694611550825, 0.18844972327101212, 0.18533540540094684, 0.18221410975762042, 0.17908595385604922, 0.17595105546953538, 0.1728095326252308, 0.16966150359969526, 0.16650708691444244, 0.1633464013314773, 0.16017956584882598, 0.1570066996960541, 0.15382792232977943, 0.1506433534291727, 0.14745311289145355, 0.14425732082737466, 0.14105609755670143, 0.13784956360368017, 0.13463783969250212, 0.1314210467427575, 0.12819930586488226, 0.12497273835559998, 0.12174146569335324, 0.11850560953373196, 0.11526529170489112, 0.11202063420296605, 0.10877175918747732, 0.10551878897673346, 0.10226184604322384, 0.09900105300900933, 0.09573653264110489, 0.09246840784685678, 0.08919680166931683, 0.08592183728260804, 0.08264363798728899, 0.07936232720570986, 0.07607802847736746, 0.0727908654542521, 0.06950096189619398, 0.0662084416662028, 0.0629134287258038, 0.05961604713037232, 0.05631642102446129, 0.05301467463712908, 0.04971093227726057, 0.04640531832888879, 0.04309795724651008, 0.03978897355040034, 0.036478491821925016, 0.03316663669885031, 0.02985353287065, 0.02653930507381015, 0.02322407808713459, 0.019907976727045112, 0.016591125842884132, 0.01327365031221227, 0.009955675036108691, 0.006637324934466734, 0.0033187249412926644, 6.623765127599638e-17, -0.003318724941292532, -0.006637324934466601, -0.00995567503610856, -0.013273650312212138, -0.016591125842884, -0.019907976727044977, -0.02322407808713446, -0.02653930507381002, -0.029853532870649867, -0.03316663669885018, -0.036478491821924884, -0.03978897355040022, -0.04309795724650995, -0.04640531832888867, -0.04971093227726044, -0.05301467463712896, -0.05631642102446116, -0.0596160471303722, -0.06291342872580367, -0.06620844166620266, -0.06950096189619384, -0.07279086545425198, -0.07607802847736733, -0.07936232720570974, -0.08264363798728885, -0.0859218372826079, -0.08919680166931669, -0.09246840784685666, -0.09573653264110475, -0.09900105300900919, -0.1022618460432237, -0.10551878897673332, -0.10877175918747718, -0.11202063420296592, -0.11526529170489101, -0.11850560953373183, -0.12174146569335313, -0.12497273835559985, -0.12819930586488215, -0.13142104674275737, -0.134637839692502, -0.13784956360368006, -0.14105609755670132, -0.14425732082737455, -0.14745311289145344, -0.15064335342917257, -0.15382792232977927, -0.15700669969605394, -0.16017956584882584, -0.16334640133147715, -0.16650708691444233, -0.16966150359969512, -0.1728095326252307, -0.17595105546953524, -0.1790859538560491, -0.18221410975762029, -0.1853354054009467, -0.188449723271012, -0.1915569461155081, -0.19465695694925164, -0.19774963905858636, -0.2008348760057793, -0.20391255163340272, -0.20698255006870925, -0.21004475572799264, -0.21309905332094045, -0.216145327854975, -0.21918346463958147, -0.22221334929062744, -0.2252348677346679, -0.22824790621324162, -0.23125235128715244, -0.23424808984074186, -0.23723500908614614, -0.2402129965675443, -0.24318194016539066, -0.24614172810063653, -0.2490922489389395, -0.2520333915948572, -0.2549650453360314, -0.25788709978735536, -0.2607994449351309, -0.26370197113120886, -0.26659456909711887, -0.26947712992818207, -0.2723495450976128, -0.2752117064606031, -0.27806350625839527, -0.2809048371223392, -0.28373559207793325, -0.2865556645488538, -0.28936494836096577, -0.29216333774632197, -0.2949507273471431, -0.2977270122197867, -0.300492087838696, -0.3032458501003372, -0.30598819532711735, -0.3087190202712885, -0.3114382221188353, -0.31414569849334467, -0.3168413474598619, -0.3195250675287265, -0.32219675765939515, -0.3248563172642441, -0.3275036462123577, -0.33013864483329725, -0.3327612139208538, -0.33537125473678375, -0.3379686690145253, -0.3405533589628993, -0.3431252272697897, -0.3456841771058088, -0.3482301121279411, -0.3507629364831724, -0.35328255481209697, -0.35578887225250916, -0.3582817944429737, -0.360761227526379, -0.3632270781534712, -0.3656792534863675, -0.3681176612020529, -0.37054220949585487, -0.3729528070849008, -0.3753493632115536, -0.37773178764683, -0.38009999069379613, -0.38245388319094625, -0.38479337651555795, -0.38711838258702985, -0.38942881387019773, -0.3917245833786292, -0.3940056046779003, -0.396271791888848, -0.3985230596908049, -0.4007593233248105, -0.4029804985968031, -0.405186501880789, -0.4073772501219915, -0.40955266083997727, -0.4117126521317629, -0.4138571426748963, -0.41598605173052106, -0.4180992991464144, -0.42019680536000587, -0.4222784914013714, -0.42434427889620846, -0.4263940900687855, -0.4284278477448706, -0.4304454753546362, -0.43244689693554345, -0.4344320371352014, -0.4364008212142028, -0.43835317504894034, -0.4402890251343955, -0.44220829858690697, -0.44411092314691347, -0.445996827181676, -0.4478659396879736, -0.449718190294777, -0.4515535092658974, -0.45337182750261296, -0.4551730765462701, -0.4569571885808598, -0.45872409643557277, -0.46047373358732685, -0.46220603416327244, -0.46392093294327136, -0.4656183653623542, -0.4672982675131497, -0.46896057614829156, -0.47060522868279886, -0.47223216319643335, -0.4738413184360305, -0.47543263381780443, -0.47700604942963076, -0.478561506033301, -0.4800989450667533, -0.4816183086462767, -0.4831195395686913, -0.4846025813135014, -0.48606737804502376, -0.4875138746144889, -0.4889420165621188, -0.4903517501191764, -0.49174302220998983, -0.4931157804539513, -0.49446997316748886, -0.4958055493660124, -0.49712245876583255, -0.49842065178605494, -0.49970007955044604, -0.5009606938892739, -0.5022024473411207, -0.5034252931546712, -0.5046291852904716, -0.5058140784226633, -0.5069799279406897, -0.5081266899509753, -0.5092543212785787, -0.5103627794688176, -0.5114520227888677, -0.5125220102293337, -0.5135727015057931, -0.5146040570603134, -0.5156160380629407, -0.5166086064131623, -0.5175817247413408, -0.5185353564101212, -0.51946946551581, -0.5203840168897276, -0.5212789760995314, -0.5221543094505132, -0.523009983986867, -0.5238459674929301, -0.5246622284943961, -0.5254587362594996, -0.5262354608001734, -0.5269923728731775, -0.5277294439812001, -0.5284466463739308, -0.5291439530491049, -0.5298213377535199, -0.5304787749840247, -0.5311162399884793, -0.5317337087666864, -0.5323311580712955, -0.532908565408678, -0.5334659090397741, -0.534003167980911, -0.5345203220045931, -0.5350173516402639, -0.5354942381750385, -0.5359509636544083, -0.5363875108829174, -0.536803863424809, -0.5372000056046455, -0.5375759225078977, -0.5379315999815065, -0.5382670246344163, -0.5385821838380783, -0.5388770657269267, -0.539151659198825, -0.5394059539154843, -0.5396399403028521, -0.5398536095514732, -0.5400469536168212, -0.5402199652196013, -0.5403726378460244, -0.5405049657480525, -0.5406169439436148, -0.5407085682167956, -0.5407798351179927, -0.5408307419640476, -0.5408612868383463, -0.5408714685908917, -0.5408612868383463, -0.5408307419640476, -0.5407798351179927, -0.5407085682167956, -0.5406169439436148, -0.5405049657480525, -0.5403726378460244, -0.5402199652196013, -0.5400469536168212, -0.5398536095514732, -0.5396399403028521, -0.5394059539154843, -0.539151659198825, -0.5388770657269267, -0.5385821838380783, -0.5382670246344163, -0.5379315999815065, -0.5375759225078977, -0.5372000056046455, -0.536803863424809, -0.5363875108829174, -0.5359509636544083, -0.5354942381750385, -0.5350173516402639, -0.5345203220045931, -0.534003167980911, -0.5334659090397742, -0.532908565408678, -0.5323311580712956, -0.5317337087666865, -0.5311162399884793, -0.5304787749840247, -0.5298213377535199, -0.5291439530491049, -0.5284466463739308, -0.5277294439812001, -0.5269923728731775, -0.5262354608001734, -0.5254587362594996, -0.5246622284943961, -0.5238459674929302, -0.5230099839868672, -0.5221543094505132, -0.5212789760995314, -0.5203840168897276, -0.51946946551581, -0.5185353564101212, -0.5175817247413408, -0.5166086064131624, -0.5156160380629408, -0.5146040570603134, -0.5135727015057933, -0.5125220102293339, -0.5114520227888677, -0.5103627794688177, -0.5092543212785788, -0.5081266899509753, -0.5069799279406898, -0.5058140784226635, -0.5046291852904717, -0.5034252931546713, -0.5022024473411209, -0.500960693889274, -0.4997000795504461, -0.498420651786055, -0.49712245876583266, -0.49580554936601245, -0.4944699731674889, -0.4931157804539514, -0.4917430222099899, -0.49035175011917653, -0.4889420165621189, -0.48751387461448903, -0.48606737804502387, -0.4846025813135015, -0.48311953956869136, -0.48161830864627675, -0.4800989450667534, -0.47856150603330105, -0.4770060494296308, -0.47543263381780454, -0.4738413184360306, -0.47223216319643346, -0.4706052286827989, -0.4689605761482917, -0.46729826751314973, -0.4656183653623543, -0.46392093294327147, -0.4622060341632725, -0.46047373358732696, -0.4587240964355729, -0.45695718858085993, -0.4551730765462702, -0.453371827502613, -0.45155350926589743, -0.4497181902947771, -0.4478659396879737, -0.4459968271816761, -0.4441109231469136, -0.4422082985869071, -0.4402890251343956, -0.4383531750489405, -0.4364008212142029, -0.4344320371352015, -0.4324468969355436, -0.43044547535463634, -0.4284278477448707, -0.4263940900687856, -0.4243442788962086, -0.4222784914013715, -0.420196805360006, -0.4180992991464145, -0.41598605173052117, -0.4138571426748964, -0.411712652131763, -0.40955266083997743, -0.4073772501219916, -0.40518650188078925, -0.4029804985968031, -0.4007593233248106, -0.39852305969080504, -0.39627179188884826, -0.39400560467790025, -0.39172458337862937, -0.38942881387019784, -0.38711838258703013, -0.38479337651555795, -0.38245388319094636, -0.38009999069379646, -0.37773178764682996, -0.37534936321155377, -0.372952807084901, -0.37054220949585526, -0.3681176612020529, -0.3656792534863676, -0.36322707815347133, -0.3607612275263794, -0.3582817944429737, -0.3557888722525093, -0.3532825548120973, -0.3507629364831724, -0.3482301121279412, -0.3456841771058089, -0.34312522726979006, -0.3405533589628993, -0.33796866901452544, -0.335371254736784, -0.33276121392085417, -0.33013864483329725, -0.3275036462123579, -0.3248563172642444, -0.32219675765939515, -0.31952506752872667, -0.3168413474598621, -0.31414569849334506, -0.31143822211883526, -0.3087190202712886, -0.3059881953271175, -0.3032458501003376, -0.30049208783869613, -0.2977270122197869, -0.2949507273471435, -0.2921633377463219, -0.28936494836096593, -0.28655566454885395, -0.28373559207793364, -0.28090483712233916, -0.27806350625839543, -0.2752117064606033, -0.27234954509761317, -0.26947712992818224, -0.2665945690971191, -0.26370197113120925, -0.26079944493513085, -0.2578870997873555, -0.2549650453360316, -0.2520333915948576, -0.24909224893893944, -0.2461417281006367, -0.24318194016539083, -0.2402129965675447, -0.23723500908614634, -0.23424808984074205, -0.23125235128715282, -0.2282479062132416, -0.22523486773466808, -0.2222133492906276, -0.21918346463958185, -0.21614532785497498, -0.21309905332094062, -0.21004475572799283, -0.20698255006870964, -0.2039125516334029, -0.2008348760057795, -0.19774963905858678, -0.19465695694925159, -0.1915569461155083, -0.18844972327101217, -0.1853354054009471, -0.18221410975762023, -0.17908595385604928, -0.17595105546953543, -0.17280953262523063, -0.1696615035996953, -0.1665070869144425, -0.16334640133147757, -0.1601795658488258, -0.15700669969605416, -0.1538279223297795, -0.15064335342917298, -0.1474531128914534, -0.14425732082737475, -0.14105609755670148, -0.13784956360368, -0.13463783969250218, -0.13142104674275756, -0.12819930586488257, -0.1249727383555998, -0.12174146569335333, -0.11850560953373203, -0.11526529170489143, -0.11202063420296589, -0.10877175918747738, -0.10551878897673352, -0.10226184604322366, -0.0990010530090094, -0.09573653264110496, -0.09246840784685709, -0.08919680166931665, -0.08592183728260809, -0.08264363798728905, -0.07936232720571018, -0.07607802847736728, -0.07279086545425219, -0.06950096189619429, -0.06620844166620263, -0.06291342872580387, -0.05961604713037239, -0.05631642102446159, -0.05301467463712891, -0.04971093227726064, -0.04640531832888886, -0.04309795724651039, -0.03978897355040017, -0.03647849182192508, -0.03316663669885062, -0.02985353287064983, -0.026539305073810218, -0.023224078087134656, -0.019907976727045418, -0.01659112584288396, -0.013273650312212336, -0.009955675036108759, -0.00663732493446704, -0.0033187249412924905, ], ];
@@ -0,0 +1 @@
+pub static SAW_I32: [[i32; 1024]; 10] = [[0, 2147483647, 1640076340, 1934563080, 1718914851, 1880047631, 1741841728, 1852151293, 1749929803, 1833396875, 1751978559, 1818844039, 1750984905, 1806565721, 1748245852, 1795656218, 1744413369, 1785634067, 1739850838, 1776219799, 1734776751, 1767240106, 1729330352, 1758581820, 1723604570, 1750167917, 1717663790, 1741944158, 1711554031, 1733871245, 1705309054, 1725920008, 1698954190, 1718068344, 1692508817, 1710299194, 1685988009, 1702599186, 1679403671, 1694957690, 1672765326, 1687366146, 1666080685, 1679817588, 1659356061, 1672306287, 1652596664, 1664827488, 1645806842, 1657377213, 1638990243, 1649952106, 1632149956, 1642549320, 1625288613, 1635166420, 1618408470, 1627801313, 1611511470, 1620452190, 1604599299, 1613117479, 1597673425, 1605795808, 1590735133, 1598485973, 1583785551, 1591186913, 1576825676, 1583897693, 1569856390, 1576617481, 1562878477, 1569345536, 1555892636, 1562081197, 1548899494, 1554823871, 1541899612, 1547573025, 1534893496, 1540328180, 1527881602, 1533088901, 1520864343, 1525854795, 1513842093, 1518625507, 1506815193, 1511400710, 1499783952, 1504180108, 1492748655, 1496963431, 1485709559, 1489750431, 1478666903, 1482540880, 1471620904, 1475334569, 1464571764, 1468131305, 1457519667, 1460930911, 1450464784, 1453733223, 1443407273, 1446538088, 1436347279, 1439345367, 1429284939, 1432154928, 1422220378, 1424966650, 1415153714, 1417780421, 1408085054, 1410596134, 1401014502, 1403413693, 1393942151, 1396233005, 1386868090, 1389053985, 1379792403, 1381876552, 1372715166, 1374700631, 1365636452, 1367526153, 1358556331, 1360353050, 1351474865, 1353181260, 1344392115, 1346010726, 1337308138, 1338841391, 1330222988, 1331673205, 1323136715, 1324506117, 1316049366, 1317340082, 1308960987, 1310175055, 1301871620, 1303010997, 1294781305, 1295847867, 1287690080, 1288685628, 1280597982, 1281524246, 1273505044, 1274363687, 1266411299, 1267203919, 1259316778, 1260044913, 1252221509, 1252886640, 1245125522, 1245729072, 1238028842, 1238572185, 1230931494, 1231415952, 1223833503, 1224260352, 1216734892, 1217105361, 1209635681, 1209950959, 1202535892, 1202797125, 1195435546, 1195643839, 1188334660, 1188491083, 1181233252, 1181338840, 1174131342, 1174187091, 1167028944, 1167035822, 1159926074, 1159885017, 1152822749, 1152734660, 1145718982, 1145584738, 1138614788, 1138435237, 1131510179, 1131286143, 1124405169, 1124137445, 1117299770, 1116989129, 1110193994, 1109841185, 1103087853, 1102693601, 1095981356, 1095546367, 1088874515, 1088399471, 1081767339, 1081252906, 1074659839, 1074106660, 1067552024, 1066960725, 1060443903, 1059815091, 1053335484, 1052669751, 1046226776, 1045524696, 1039117787, 1038379919, 1032008525, 1031235410, 1024898996, 1024091164, 1017789209, 1016947173, 1010679171, 1009803431, 1003568887, 1002659930, 996458365, 995516664, 989347612, 988373627, 982236632, 981230813, 975125432, 974088217, 968014017, 966945832, 960902394, 959803653, 953790566, 952661676, 946678541, 945519894, 939566322, 938378304, 932453914, 931236900, 925341322, 924095677, 918228551, 916954632, 911115604, 909813760, 904002487, 902673057, 896889203, 895532518, 889775756, 888392140, 882662151, 881251919, 875548390, 874111851, 868434478, 866971933, 861320419, 859832160, 854206215, 852692531, 847091870, 845553041, 839977387, 838413687, 832862769, 831274466, 825748021, 824135375, 818633143, 816996411, 811518140, 809857571, 804403014, 802718852, 797287769, 795580253, 790172406, 788441769, 783056928, 781303398, 775941338, 774165139, 768825639, 767026988, 761709832, 759888943, 754593920, 752751002, 747477906, 745613162, 740361791, 738475421, 733245578, 731337778, 726129269, 724200230, 719012866, 717062774, 711896371, 709925410, 704779787, 702788134, 697663114, 695650946, 690546355, 688513843, 683429511, 681376824, 676312585, 674239886, 669195578, 667103028, 662078492, 659966248, 654961329, 652829545, 647844090, 645692917, 640726777, 638556362, 633609391, 631419879, 626491934, 624283467, 619374407, 617147123, 612256812, 610010847, 605139151, 602874636, 598021424, 595738491, 590903634, 588602408, 583785781, 581466388, 576667866, 574330428, 569549892, 567194527, 562431859, 560058684, 555313768, 552922899, 548195622, 545787168, 541077420, 538651493, 533959164, 531515871, 526840856, 524380300, 519722496, 517244781, 512604085, 510109312, 505485625, 502973892, 498367117, 495838520, 491248561, 488703194, 484129959, 481567914, 477011312, 474432680, 469892620, 467297488, 462773885, 460162340, 455655108, 453027234, 448536289, 445892169, 441417429, 438757143, 434298531, 431622157, 427179593, 424487209, 420060617, 417352299, 412941605, 410217425, 405822556, 403082587, 398703472, 395947784, 391584354, 388813015, 384465202, 381678279, 377346017, 374543575, 370226800, 367408904, 363107551, 360274263, 355988273, 353139652, 348868964, 346005071, 341749626, 338870518, 334630260, 331735993, 327510867, 324601496, 320391446, 317467025, 313272000, 310332580, 306152528, 303198160, 299033031, 296063764, 291913510, 288929392, 284793966, 281795043, 277674399, 274660717, 270554810, 267526412, 263435200, 260392128, 256315569, 253257865, 249195917, 246123622, 242076246, 238989398, 234956557, 231855192, 227836849, 224721005, 220717123, 217586834, 213597380, 210452681, 206477621, 203318544, 199357846, 196184422, 192238055, 189050315, 185118250, 181916223, 177998431, 174782144, 170878599, 167648079, 163758753, 160514026, 156638895, 153379986, 149519025, 146245957, 142399144, 139111939, 135279252, 131977931, 128159351, 124843933, 121039439, 117709945, 113919519, 110575965, 106799590, 103441993, 99679653, 96308030, 92559708, 89174073, 85439757, 82040123, 78319799, 74906179, 71199836, 67772241, 64079867, 60638307, 56959894, 53504378, 49839917, 46370453, 42719935, 39236531, 35599951, 32102613, 28479964, 24968696, 21359975, 17834782, 14239984, 10700869, 7119992, 3566956, 0, -3566956, -7119992, -10700869, -14239984, -17834782, -21359975, -24968696, -28479964, -32102613, -35599951, -39236531, -42719935, -46370453, -49839917, -53504378, -56959894, -60638307, -64079867, -67772241, -71199836, -74906179, -78319799, -82040123, -85439757, -89174073, -92559708, -96308030, -99679653, -103441993, -106799590, -110575965, -113919519, -117709945, -121039439, -124843933, -128159351, -131977931, -135279252, -139111939, -142399144, -146245957, -149519025, -153379986, -156638895, -160514026, -163758753, -167648079, -170878599, -174782144, -177998431, -181916223, -185118250, -189050315, -192238055, -196184422, -199357846, -203318544, -206477621, -210452681, -213597380, -217586834, -220717123, -224721005, -227836849, -231855192, -234956557, -238989398, -242076246, -246123622, -249195917, -253257865, -256315569, -260392128, -263435200, -267526412, -270554810, -274660717, -277674399, -281795043, -284793966, -288929392, -291913510, -296063764, -299033031, -303198160, -306152528, -310332580, -313272000, -317467025, -320391446, -324601496, -327510867, -331735993, -334630260, -338870518, -341749626, -346005071, -348868964, -353139652, -355988273, -360274263, -363107551, -367408904, -370226800, -374543575, -377346017, -381678279, -384465202, -388813015, -391584354, -395947784, -398703472, -403082587, -405822556, -410217425, -412941605, -417352299, -420060617, -424487209, -427179593, -431622157, -434298531, -438757143, -441417429, -445892169, -448536289, -453027234, -455655108, -460162340, -462773885, -467297488, -469892620, -474432680, -477011312, -481567914, -484129959, -488703194, -491248561, -495838520, -498367117, -502973892, -505485625, -510109312, -512604085, -517244781, -519722496, -524380300, -526840856, -531515871, -533959164, -538651493, -541077420, -545787168, -548195622, -552922899, -555313768, -560058684, -562431859, -567194527, -569549892, -574330428, -576667866, -581466388, -583785781, -588602408, -590903634, -595738491, -598021424, -602874636, -605139151, -610010847, -612256812, -617147123, -619374407, -624283467, -626491934, -631419879, -633609391, -638556362, -640726777, -645692917, -647844090, -652829545, -654961329, -659966248, -662078492, -667103028, -669195578, -674239886, -676312585, -681376824, -683429511, -688513843, -690546355, -695650946, -697663114, -702788134, -704779787, -709925410, -711896371, -717062774, -719012866, -724200230, -726129269, -731337778, -733245578, -738475421, -740361791, -745613162, -747477906, -752751002, -754593920, -759888943, -761709832, -767026988, -768825639, -774165139, -775941338, -781303398, -783056928, -788441769, -790172406, -795580253, -797287769, -802718852, -804403014, -809857571, -811518140, -816996411, -818633143, -824135375, -825748021, -831274466, -832862769, -838413687, -839977387, -845553041, -847091870, -852692531, -854206215, -859832160, -861320419, -866971933, -868434478, -874111851, -875548390, -881251919, -882662151, -888392140, -889775756, -895532518, -896889203, -902673057, -904002487, -909813760, -911115604, -916954632, -918228551, -924095677, -925341322, -931236900, -932453914, -938378304, -939566322, -945519894, -946678541, -952661676, -953790566, -959803653, -960902394, -966945832, -968014017, -974088217, -975125432, -981230813, -982236632, -988373627, -989347612, -995516664, -996458365, -1002659930, -1003568887, -1009803431, -1010679171, -1016947173, -1017789209, -1024091164, -1024898996, -1031235410, -1032008525, -1038379919, -1039117787, -1045524696, -1046226776, -1052669751, -1053335484, -1059815091, -1060443903, -1066960725, -1067552024, -1074106660, -1074659839, -1081252906, -1081767339, -1088399471, -1088874515, -1095546367, -1095981356, -1102693601, -1103087853, -1109841185, -1110193994, -1116989129, -1117299770, -1124137445, -1124405169, -1131286143, -1131510179, -1138435237, -1138614788, -1145584738, -1145718982, -1152734660, -1152822749, -1159885017, -1159926074, -1167035822, -1167028944, -1174187091, -1174131342, -1181338840, -1181233252, -1188491083, -1188334660, -1195643839, -1195435546, -1202797125, -1202535892, -1209950959, -1209635681, -1217105361, -1216734892, -1224260352, -1223833503, -1231415952, -1230931494, -1238572185, -1238028842, -1245729072, -1245125522, -1252886640, -1252221509, -1260044913, -1259316778, -1267203919, -1266411299, -1274363687, -1273505044, -1281524246, -1280597982, -1288685628, -1287690080, -1295847867, -1294781305, -1303010997, -1301871620, -1310175055, -1308960987, -1317340082, -1316049366, -1324506117, -1323136715, -1331673205, -1330222988, -1338841391, -1337308138, -1346010726, -1344392115, -1353181260, -1351474865, -1360353050, -1358556331, -1367526153, -1365636452, -1374700631, -1372715166, -1381876552, -1379792403, -1389053985, -1386868090, -1396233005, -1393942151, -1403413693, -1401014502, -1410596134, -1408085054, -1417780421, -1415153714, -1424966650, -1422220378, -1432154928, -1429284939, -1439345367, -1436347279, -1446538088, -1443407273, -1453733223, -1450464784, -1460930911, -1457519667, -1468131305, -1464571764, -1475334569, -1471620904, -1482540880, -1478666903, -1489750431, -1485709559, -1496963431, -1492748655, -1504180108, -1499783952, -1511400710, -1506815193, -1518625507, -1513842093, -1525854795, -1520864343, -1533088901, -1527881602, -1540328180, -1534893496, -1547573025, -1541899612, -1554823871, -1548899494, -1562081197, -1555892636, -1569345536, -1562878477, -1576617481, -1569856390, -1583897693, -1576825676, -1591186913, -1583785551, -1598485973, -1590735133, -1605795808, -1597673425, -1613117479, -1604599299, -1620452190, -1611511470, -1627801313, -1618408470, -1635166420, -1625288613, -1642549320, -1632149956, -1649952106, -1638990243, -1657377213, -1645806842, -1664827488, -1652596664, -1672306287, -1659356061, -1679817588, -1666080685, -1687366146, -1672765326, -1694957690, -1679403671, -1702599186, -1685988009, -1710299194, -1692508817, -1718068344, -1698954190, -1725920008, -1705309054, -1733871245, -1711554031, -1741944158, -1717663790, -1750167917, -1723604570, -1758581820, -1729330352, -1767240106, -1734776751, -1776219799, -1739850838, -1785634067, -1744413369, -1795656218, -1748245852, -1806565721, -1750984905, -1818844039, -1751978559, -1833396875, -1749929803, -1852151293, -1741841728, -1880047631, -1718914851, -1934563080, -1640076340, -2147483647, ], [0, 1590861203, 2143916691, 1855187743, 1632956348, 1791567070, 1923862211, 1806479274, 1704674867, 1789043741, 1862212850, 1786851329, 1720481754, 1777694552, 1827182597, 1770850876, 1721449839, 1764572259, 1801294263, 1755822034, 1716378608, 1750872218, 1779607508, 1741158571, 1708264970, 1736929680, 1760195268, 1726662431, 1698405936, 1722867932, 1742151840, 1712253537, 1687453475, 1708740853, 1724995760, 1697894538, 1675770971, 1694575009, 1708447558, 1683566115, 1663576915, 1680384721, 1692333927, 1669257468, 1651010553, 1666178262, 1676541697, 1654962173, 1638164813, 1651960681, 1660993844, 1640676218, 1625104082, 1637735199, 1645636128, 1626396991, 1611874378, 1623503952, 1630429251, 1612122732, 1598509464, 1609268400, 1615344043, 1597852224, 1585034671, 1595029566, 1600358399, 1583584598, 1571469378, 1580788191, 1585455261, 1569319223, 1557828659, 1566544813, 1570621255, 1555055634, 1544124418, 1552299838, 1555845751, 1540793478, 1530366181, 1538053574, 1541120189, 1526532485, 1516561660, 1523806257, 1526437602, 1512272448, 1502717166, 1509558072, 1511792261, 1498013201, 1488837911, 1495309165, 1497179409, 1483754615, 1474928243, 1481059652, 1482595068, 1469496585, 1460991812, 1466809628, 1468035883, 1455239027, 1447031706, 1452559168, 1453499005, 1440981871, 1433050558, 1438308336, 1438981998, 1426725060, 1419050624, 1424057183, 1424482770, 1412468547, 1405033849, 1409805753, 1409999509, 1398212294, 1391001919, 1395554081, 1395530645, 1383956266, 1376956302, 1381302198, 1381074803, 1369700436, 1362898284, 1367050129, 1366630781, 1355444779, 1348828995, 1352797897, 1352197516, 1341189277, 1334749430, 1338545520, 1337774072, 1326933911, 1320660473, 1324293015, 1323359616, 1312678666, 1306562908, 1310040395, 1308953408, 1298423529, 1292457436, 1295787673, 1294554785, 1284168490, 1278344684, 1281534858, 1280163154, 1269913537, 1264225213, 1267281962, 1265777982, 1255658663, 1250099530, 1253028990, 1251398788, 1241403860, 1235968092, 1238775952, 1237025137, 1227149121, 1221831312, 1224522852, 1222656636, 1212894441, 1207689565, 1210269696, 1208292927, 1198639813, 1193543193, 1196016490, 1193933685, 1184385233, 1179392506, 1181763238, 1179578612, 1170130698, 1165237788, 1167509944, 1165227438, 1155876203, 1151079299, 1153256610, 1150879913, 1141621746, 1136917277, 1139003242, 1136535809, 1127367322, 1122751941, 1124749840, 1122194917, 1113112930, 1108583492, 1110496409, 1107857042, 1098858567, 1094412116, 1096242949, 1093522008, 1084604230, 1080237984, 1081989464, 1079189648, 1070349918, 1066061256, 1067735955, 1064859810, 1056095630, 1051882077, 1053482424, 1050532352, 1041841362, 1037700585, 1039228872, 1036207144, 1027587114, 1023516906, 1024975302, 1021884063, 1013332885, 1009331158, 1010721713, 1007562996, 999078673, 995143450, 996468108, 993243835, 984824477, 980953885, 982214488, 978926484, 970570296, 966762558, 967960853, 964610848, 956316128, 952569560, 953707204, 950296841, 942061974, 938374973, 939453543, 935984383, 927807833, 924178877, 925199870, 921673397, 913553702, 909981345, 910946185, 907363813, 899299583, 895782446, 896692491, 893055561, 885045474, 881582245, 882438786, 878748581, 870791374, 867380803, 868185072, 864442811, 856537284, 853178179, 853931349, 850138197, 842283202, 838974427, 839677618, 835834685, 828029128, 824769598, 825423879, 821532225, 813775061, 810563741, 811170132, 807230769, 799521002, 796356902, 796916379, 792930274, 785266950, 782149124, 782662619, 778630697, 771012905, 767940449, 768408852, 764331996, 756758865, 753730916, 754155080, 750034136, 742504831, 739520562, 739901302, 735737078, 728250803, 725309422, 725647518, 721440788, 713996780, 711097531, 711393729, 707145235, 699742762, 696884919, 697139936, 692850386, 685488749, 682671618, 682886138, 678556212, 671234741, 668457656, 668632335, 664262685, 656980737, 654243061, 654378528, 649969776, 642726737, 640027861, 640124717, 635677462, 628472741, 625812079, 625870902, 621385716, 614218748, 611595741, 611617083, 607094515, 599964760, 597378869, 597363261, 592803836, 585710775, 583161485, 583109435, 578513658, 571456793, 568943612, 568855606, 564223959, 557202815, 554725269, 554601774, 549934721, 542948839, 540506476, 540347939, 535645923, 528694867, 526287252, 526094101, 521357546, 514440897, 512067615, 511840260, 507069574, 500186931, 497847582, 497586416, 492781989, 485932966, 483627171, 483332570, 478494775, 471679005, 469406397, 469078721, 464207915, 457425046, 455185277, 454824870, 449921394, 443171089, 440963825, 440571017, 435635197, 428917135, 426742056, 426317161, 421349310, 414663182, 412519984, 412063303, 407063719, 400409232, 398297623, 397809443, 392778411, 386155285, 384074986, 383555581, 378493371, 371901339, 369852087, 369301716, 364208589, 357647395, 355628936, 355047850, 349924050, 343393453, 341405548, 340793982, 335639744, 329139513, 327181934, 326540112, 321355658, 314885575, 312958104, 312286241, 307071782, 300631638, 298734071, 298032367, 292788104, 286377703, 284509846, 283778492, 278504613, 272123770, 270285438, 269524615, 264221298, 257869839, 256060859, 255270737, 249938150, 243615909, 241836119, 241016857, 235655158, 229361981, 227611227, 226762975, 221372312, 215108054, 213386195, 212509092, 207089603, 200854129, 199161031, 198255207, 192807020, 186600206, 184935744, 184001321, 178524555, 172346283, 170710345, 169747433, 164242198, 158092363, 156484842, 155493544, 149959940, 143838444, 142259245, 141239654, 135677772, 129584526, 128033562, 126985762, 121395686, 115330609, 113807802, 112731868, 107113671, 101076694, 99581975, 98477974, 92831721, 86822781, 85356088, 84224077, 78549825, 72568869, 71130150, 69970180, 64267975, 58314958, 56904171, 55716281, 49986164, 44061048, 42678157, 41462381, 35704382, 29807140, 28452119, 27208479, 21422621, 15553233, 14226064, 12954577, 7140872, 1299328, 0, -1299328, -7140872, -12954577, -14226064, -15553233, -21422621, -27208479, -28452119, -29807140, -35704382, -41462381, -42678157, -44061048, -49986164, -55716281, -56904171, -58314958, -64267975, -69970180, -71130150, -72568869, -78549825, -84224077, -85356088, -86822781, -92831721, -98477974, -99581975, -101076694, -107113671, -112731868, -113807802, -115330609, -121395686, -126985762, -128033562, -129584526, -135677772, -141239654, -142259245, -143838444, -149959940, -155493544, -156484842, -158092363, -164242198, -169747433, -170710345, -172346283, -178524555, -184001321, -184935744, -186600206, -192807020, -198255207, -199161031, -200854129, -207089603, -212509092, -213386195, -215108054, -221372312, -226762975, -227611227, -229361981, -235655158, -241016857, -241836119, -243615909, -249938150, -255270737, -256060859, -257869839, -264221298, -269524615, -270285438, -272123770, -278504613, -283778492, -284509846, -286377703, -292788104, -298032367, -298734071, -300631638, -307071782, -312286241, -312958104, -314885575, -321355658, -326540112, -327181934, -329139513, -335639744, -340793982, -341405548, -343393453, -349924050, -355047850, -355628936, -357647395, -364208589, -369301716, -369852087, -371901339, -378493371, -383555581, -384074986, -386155285, -392778411, -397809443, -398297623, -400409232, -407063719, -412063303, -412519984, -414663182, -421349310, -426317161, -426742056, -428917135, -435635197, -440571017, -440963825, -443171089, -449921394, -454824870, -455185277, -457425046, -464207915, -469078721, -469406397, -471679005, -478494775, -483332570, -483627171, -485932966, -492781989, -497586416, -497847582, -500186931, -507069574, -511840260, -512067615, -514440897, -521357546, -526094101, -526287252, -528694867, -535645923, -540347939, -540506476, -542948839, -549934721, -554601774, -554725269, -557202815, -564223959, -568855606, -568943612, -571456793, -578513658, -583109435, -583161485, -585710775, -592803836, -597363261, -597378869, -599964760, -607094515, -611617083, -611595741, -614218748, -621385716, -625870902, -625812079, -628472741, -635677462, -640124717, -640027861, -642726737, -649969776, -654378528, -654243061, -656980737, -664262685, -668632335, -668457656, -671234741, -678556212, -682886138, -682671618, -685488749, -692850386, -697139936, -696884919, -699742762, -707145235, -711393729, -711097531, -713996780, -721440788, -725647518, -725309422, -728250803, -735737078, -739901302, -739520562, -742504831, -750034136, -754155080, -753730916, -756758865, -764331996, -768408852, -767940449, -771012905, -778630697, -782662619, -782149124, -785266950, -792930274, -796916379, -796356902, -799521002, -807230769, -811170132, -810563741, -813775061, -821532225, -825423879, -824769598, -828029128, -835834685, -839677618, -838974427, -842283202, -850138197, -853931349, -853178179, -856537284, -864442811, -868185072, -867380803, -870791374, -878748581, -882438786, -881582245, -885045474, -893055561, -896692491, -895782446, -899299583, -907363813, -910946185, -909981345, -913553702, -921673397, -925199870, -924178877, -927807833, -935984383, -939453543, -938374973, -942061974, -950296841, -953707204, -952569560, -956316128, -964610848, -967960853, -966762558, -970570296, -978926484, -982214488, -980953885, -984824477, -993243835, -996468108, -995143450, -999078673, -1007562996, -1010721713, -1009331158, -1013332885, -1021884063, -1024975302, -1023516906, -1027587114, -1036207144, -1039228872, -1037700585, -1041841362, -1050532352, -1053482424, -1051882077, -1056095630, -1064859810, -1067735955, -1066061256, -1070349918, -1079189648, -1081989464, -1080237984, -1084604230, -1093522008, -1096242949, -1094412116, -1098858567, -1107857042, -1110496409, -1108583492, -1113112930, -1122194917, -1124749840, -1122751941, -1127367322, -1136535809, -1139003242, -1136917277, -1141621746, -1150879913, -1153256610, -1151079299, -1155876203, -1165227438, -1167509944, -1165237788, -1170130698, -1179578612, -1181763238, -1179392506, -1184385233, -1193933685, -1196016490, -1193543193, -1198639813, -1208292927, -1210269696, -1207689565, -1212894441, -1222656636, -1224522852, -1221831312, -1227149121, -1237025137, -1238775952, -1235968092, -1241403860, -1251398788, -1253028990, -1250099530, -1255658663, -1265777982, -1267281962, -1264225213, -1269913537, -1280163154, -1281534858, -1278344684, -1284168490, -1294554785, -1295787673, -1292457436, -1298423529, -1308953408, -1310040395, -1306562908, -1312678666, -1323359616, -1324293015, -1320660473, -1326933911, -1337774072, -1338545520, -1334749430, -1341189277, -1352197516, -1352797897, -1348828995, -1355444779, -1366630781, -1367050129, -1362898284, -1369700436, -1381074803, -1381302198, -1376956302, -1383956266, -1395530645, -1395554081, -1391001919, -1398212294, -1409999509, -1409805753, -1405033849, -1412468547, -1424482770, -1424057183, -1419050624, -1426725060, -1438981998, -1438308336, -1433050558, -1440981871, -1453499005, -1452559168, -1447031706, -1455239027, -1468035883, -1466809628, -1460991812, -1469496585, -1482595068, -1481059652, -1474928243, -1483754615, -1497179409, -1495309165, -1488837911, -1498013201, -1511792261, -1509558072, -1502717166, -1512272448, -1526437602, -1523806257, -1516561660, -1526532485, -1541120189, -1538053574, -1530366181, -1540793478, -1555845751, -1552299838, -1544124418, -1555055634, -1570621255, -1566544813, -1557828659, -1569319223, -1585455261, -1580788191, -1571469378, -1583584598, -1600358399, -1595029566, -1585034671, -1597852224, -1615344043, -1609268400, -1598509464, -1612122732, -1630429251, -1623503952, -1611874378, -1626396991, -1645636128, -1637735199, -1625104082, -1640676218, -1660993844, -1651960681, -1638164813, -1654962173, -1676541697, -1666178262, -1651010553, -1669257468, -1692333927, -1680384721, -1663576915, -1683566115, -1708447558, -1694575009, -1675770971, -1697894538, -1724995760, -1708740853, -1687453475, -1712253537, -1742151840, -1722867932, -1698405936, -1726662431, -1760195268, -1736929680, -1708264970, -1741158571, -1779607508, -1750872218, -1716378608, -1755822034, -1801294263, -1764572259, -1721449839, -1770850876, -1827182597, -1777694552, -1720481754, -1786851329, -1862212850, -1789043741, -1704674867, -1806479274, -1923862211, -1791567070, -1632956348, -1855187743, -2143916691, -1590861203, ], [0, 881203958, 1589561875, 2013243625, 2136775819, 2036803624, 1842233166, 1678146268, 1618730284, 1668421205, 1776013836, 1871014297, 1902439591, 1861457578, 1779270794, 1704800134, 1676222748, 1701850416, 1759236601, 1810906698, 1826508468, 1798795822, 1745388948, 1696687201, 1677803596, 1694981809, 1733633503, 1768161080, 1777196433, 1755348878, 1715134595, 1678680063, 1664545668, 1677409427, 1706257301, 1731588862, 1737026287, 1718488346, 1685851854, 1656561015, 1645248458, 1655491475, 1678303349, 1697893817, 1701057683, 1684647424, 1656934494, 1632349713, 1622908882, 1631389374, 1650106900, 1665768054, 1667363547, 1652437781, 1628184457, 1606931083, 1598823961, 1606036576, 1621791238, 1634591103, 1635038168, 1621208212, 1599521669, 1580753225, 1573645673, 1579901438, 1593410243, 1604031178, 1603600074, 1590613126, 1570908776, 1554067103, 1547737409, 1553244602, 1564990483, 1573894905, 1572769786, 1560452202, 1542326462, 1527024142, 1521317670, 1526222586, 1536546277, 1544062010, 1542373986, 1530601360, 1513763924, 1499721092, 1494525711, 1498935031, 1508085899, 1514453776, 1512299499, 1500979657, 1485214740, 1472222653, 1467454468, 1471448376, 1479614397, 1485016530, 1482469289, 1471532044, 1456674897, 1444573728, 1440168338, 1443808619, 1451134994, 1455712420, 1452829108, 1442219786, 1428141783, 1416806452, 1412713347, 1416048616, 1422649823, 1426513986, 1423339649, 1413014833, 1399613641, 1388944429, 1385123269, 1388192458, 1394160345, 1397400825, 1393971731, 1383896375, 1371089249, 1361005387, 1357423444, 1360258215, 1365667586, 1368357458, 1364703241, 1354848647, 1342567741, 1333002899, 1329633259, 1332259703, 1337172281, 1339371934, 1335517111, 1325859489, 1314048486, 1304947533, 1301767800, 1304207667, 1308674974, 1310434873, 1306399957, 1296919370, 1285531019, 1276847643, 1273838980, 1276110593, 1280176068, 1281538814, 1277341138, 1268020711, 1257014986, 1248709914, 1245856336, 1247975267, 1251675871, 1252677744, 1248332086, 1239157416, 1228500118, 1220539766, 1217827589, 1219807185, 1223174619, 1223846767, 1219365820, 1210324518, 1199986207, 1192341637, 1189759061, 1191610845, 1194672497, 1195041851, 1190436602, 1181517934, 1171473089, 1164119196, 1161655978, 1163389962, 1166169652, 1166259650, 1161539665, 1152734275, 1142960633, 1135875503, 1133522695, 1135147635, 1137666199, 1137497360, 1132671018, 1123970703, 1114448735, 1107613131, 1105362875, 1106886467, 1109162233, 1108752614, 1103827295, 1095224825, 1085937311, 1079334260, 1077179619, 1078608661, 1080657829, 1080023401, 1075005634, 1066494608, 1057426290, 1051040750, 1048975572, 1050316098, 1052153051, 1051308000, 1046203588, 1037778313, 1028915617, 1022734196, 1020753001, 1022010389, 1023647951, 1022604930, 1017419051, 1009074443, 1000405245, 994415974, 992513865, 993692925, 995142570, 993912909, 988650199, 980381704, 971895133, 966087280, 964259863, 965364913, 966636946, 965230820, 959895448, 951698969, 943385249, 937749158, 935992477, 937027405, 938131109, 936557688, 931153410, 923025253, 914875565, 909402522, 907713008, 908681324, 909625083, 907892655, 902422866, 894359692, 886366058, 881048177, 879422597, 880327482, 881118892, 879234964, 873702741, 865701521, 857856707, 852686836, 851122259, 851966597, 852612554, 850583944, 844992082, 837050065, 829347495, 824319131, 822812891, 823599308, 824106084, 821938998, 816290042, 808404721, 800838406, 795945626, 794495293, 795226183, 795599498, 793299591, 787595861, 779764952, 772329429, 767566826, 766170185, 766847731, 767092806, 764665247, 758908862, 751130276, 743820551, 739183186, 737838208, 738464408, 738586019, 736035534, 730228433, 722500259, 715311763, 710795114, 709499944, 710076626, 710079147, 707410063, 701554022, 693874510, 686803057, 682402980, 681155918, 681684758, 681572197, 678788482, 672885130, 665252674, 658294425, 654007119, 652806607, 653289142, 653065177, 650170471, 644221301, 636634429, 629785860, 625607838, 624452443, 624890086, 624558092, 621555738, 615562121, 608019480, 601277357, 597205417, 596093825, 596487871, 596050948, 592944014, 586907211, 579407559, 572768911, 568800110, 567731114, 568082754, 567543750, 564335056, 558256223, 550798419, 544260517, 540392154, 539364645, 539674972, 539036501, 535728636, 529608836, 522191832, 515752170, 511981766, 510994727, 511264743, 510529207, 507124545, 500964753, 493587589, 487243869, 483569147, 482621643, 482852270, 482021870, 478522591, 472323701, 464985495, 458735608, 455154483, 454245659, 454437741, 453514492, 449922593, 443685423, 436385369, 430227386, 426737949, 425867022, 426021329, 425007078, 421324383, 415049682, 407787041, 401719200, 398319707, 397485961, 397603199, 396499628, 392727803, 386416254, 379190355, 373211048, 369899909, 369102694, 369183503, 367992146, 364132706, 357784930, 350595161, 344702928, 341478700, 340717422, 340762385, 339484633, 335538952, 329155512, 322001319, 316194839, 313056214, 312330340, 312339983, 310977090, 306946409, 300527813, 293408698, 287686777, 284632581, 283941628, 283916424, 282469519, 278354951, 271901656, 264817170, 259178743, 256207923, 255551461, 255491832, 253961922, 249764460, 243276870, 236226616, 230670736, 227782357, 227160004, 227066324, 225454299, 221174820, 214653294, 207636921, 202162753, 199355995, 198767416, 198640013, 196946652, 192585920, 186030771, 179047975, 173654794, 170928945, 170373851, 170213007, 168438980, 163997656, 157409151, 150459670, 145146859, 142501314, 141979457, 141785412, 139931286, 135409923, 128788287, 121871904, 116638947, 114073201, 113584379, 113357329, 111423569, 106822620, 100168036, 93284575, 88131057, 85644707, 85188756, 84928858, 82915830, 78235649, 71548261, 64697585, 59623189, 57215930, 56792729, 56500097, 54408069, 49648914, 42928822, 36110837, 31115342, 28786966, 28396431, 28071143, 25900287, 21062318, 14309585, 7524234, 2607517, 357910, 0, -357910, -2607517, -7524234, -14309585, -21062318, -25900287, -28071143, -28396431, -28786966, -31115342, -36110837, -42928822, -49648914, -54408069, -56500097, -56792729, -57215930, -59623189, -64697585, -71548261, -78235649, -82915830, -84928858, -85188756, -85644707, -88131057, -93284575, -100168036, -106822620, -111423569, -113357329, -113584379, -114073201, -116638947, -121871904, -128788287, -135409923, -139931286, -141785412, -141979457, -142501314, -145146859, -150459670, -157409151, -163997656, -168438980, -170213007, -170373851, -170928945, -173654794, -179047975, -186030771, -192585920, -196946652, -198640013, -198767416, -199355995, -202162753, -207636921, -214653294, -221174820, -225454299, -227066324, -227160004, -227782357, -230670736, -236226616, -243276870, -249764460, -253961922, -255491832, -255551461, -256207923, -259178743, -264817170, -271901656, -278354951, -282469519, -283916424, -283941628, -284632581, -287686777, -293408698, -300527813, -306946409, -310977090, -312339983, -312330340, -313056214, -316194839, -322001319, -329155512, -335538952, -339484633, -340762385, -340717422, -341478700, -344702928, -350595161, -357784930, -364132706, -367992146, -369183503, -369102694, -369899909, -373211048, -379190355, -386416254, -392727803, -396499628, -397603199, -397485961, -398319707, -401719200, -407787041, -415049682, -421324383, -425007078, -426021329, -425867022, -426737949, -430227386, -436385369, -443685423, -449922593, -453514492, -454437741, -454245659, -455154483, -458735608, -464985495, -472323701, -478522591, -482021870, -482852270, -482621643, -483569147, -487243869, -493587589, -500964753, -507124545, -510529207, -511264743, -510994727, -511981766, -515752170, -522191832, -529608836, -535728636, -539036501, -539674972, -539364645, -540392154, -544260517, -550798419, -558256223, -564335056, -567543750, -568082754, -567731114, -568800110, -572768911, -579407559, -586907211, -592944014, -596050948, -596487871, -596093825, -597205417, -601277357, -608019480, -615562121, -621555738, -624558092, -624890086, -624452443, -625607838, -629785860, -636634429, -644221301, -650170471, -653065177, -653289142, -652806607, -654007119, -658294425, -665252674, -672885130, -678788482, -681572197, -681684758, -681155918, -682402980, -686803057, -693874510, -701554022, -707410063, -710079147, -710076626, -709499944, -710795114, -715311763, -722500259, -730228433, -736035534, -738586019, -738464408, -737838208, -739183186, -743820551, -751130276, -758908862, -764665247, -767092806, -766847731, -766170185, -767566826, -772329429, -779764952, -787595861, -793299591, -795599498, -795226183, -794495293, -795945626, -800838406, -808404721, -816290042, -821938998, -824106084, -823599308, -822812891, -824319131, -829347495, -837050065, -844992082, -850583944, -852612554, -851966597, -851122259, -852686836, -857856707, -865701521, -873702741, -879234964, -881118892, -880327482, -879422597, -881048177, -886366058, -894359692, -902422866, -907892655, -909625083, -908681324, -907713008, -909402522, -914875565, -923025253, -931153410, -936557688, -938131109, -937027405, -935992477, -937749158, -943385249, -951698969, -959895448, -965230820, -966636946, -965364913, -964259863, -966087280, -971895133, -980381704, -988650199, -993912909, -995142570, -993692925, -992513865, -994415974, -1000405245, -1009074443, -1017419051, -1022604930, -1023647951, -1022010389, -1020753001, -1022734196, -1028915617, -1037778313, -1046203588, -1051308000, -1052153051, -1050316098, -1048975572, -1051040750, -1057426290, -1066494608, -1075005634, -1080023401, -1080657829, -1078608661, -1077179619, -1079334260, -1085937311, -1095224825, -1103827295, -1108752614, -1109162233, -1106886467, -1105362875, -1107613131, -1114448735, -1123970703, -1132671018, -1137497360, -1137666199, -1135147635, -1133522695, -1135875503, -1142960633, -1152734275, -1161539665, -1166259650, -1166169652, -1163389962, -1161655978, -1164119196, -1171473089, -1181517934, -1190436602, -1195041851, -1194672497, -1191610845, -1189759061, -1192341637, -1199986207, -1210324518, -1219365820, -1223846767, -1223174619, -1219807185, -1217827589, -1220539766, -1228500118, -1239157416, -1248332086, -1252677744, -1251675871, -1247975267, -1245856336, -1248709914, -1257014986, -1268020711, -1277341138, -1281538814, -1280176068, -1276110593, -1273838980, -1276847643, -1285531019, -1296919370, -1306399957, -1310434873, -1308674974, -1304207667, -1301767800, -1304947533, -1314048486, -1325859489, -1335517111, -1339371934, -1337172281, -1332259703, -1329633259, -1333002899, -1342567741, -1354848647, -1364703241, -1368357458, -1365667586, -1360258215, -1357423444, -1361005387, -1371089249, -1383896375, -1393971731, -1397400825, -1394160345, -1388192458, -1385123269, -1388944429, -1399613641, -1413014833, -1423339649, -1426513986, -1422649823, -1416048616, -1412713347, -1416806452, -1428141783, -1442219786, -1452829108, -1455712420, -1451134994, -1443808619, -1440168338, -1444573728, -1456674897, -1471532044, -1482469289, -1485016530, -1479614397, -1471448376, -1467454468, -1472222653, -1485214740, -1500979657, -1512299499, -1514453776, -1508085899, -1498935031, -1494525711, -1499721092, -1513763924, -1530601360, -1542373986, -1544062010, -1536546277, -1526222586, -1521317670, -1527024142, -1542326462, -1560452202, -1572769786, -1573894905, -1564990483, -1553244602, -1547737409, -1554067103, -1570908776, -1590613126, -1603600074, -1604031178, -1593410243, -1579901438, -1573645673, -1580753225, -1599521669, -1621208212, -1635038168, -1634591103, -1621791238, -1606036576, -1598823961, -1606931083, -1628184457, -1652437781, -1667363547, -1665768054, -1650106900, -1631389374, -1622908882, -1632349713, -1656934494, -1684647424, -1701057683, -1697893817, -1678303349, -1655491475, -1645248458, -1656561015, -1685851854, -1718488346, -1737026287, -1731588862, -1706257301, -1677409427, -1664545668, -1678680063, -1715134595, -1755348878, -1777196433, -1768161080, -1733633503, -1694981809, -1677803596, -1696687201, -1745388948, -1798795822, -1826508468, -1810906698, -1759236601, -1701850416, -1676222748, -1704800134, -1779270794, -1861457578, -1902439591, -1871014297, -1776013836, -1668421205, -1618730284, -1678146268, -1842233166, -2036803624, -2136775819, -2013243625, -1589561875, -881203958, ], [0, 452143833, 880846048, 1264837521, 1586954358, 1835619953, 2005719391, 2098781597, 2122466234, 2089433059, 2015741306, 1918977183, 1816332880, 1722858011, 1650075125, 1605099181, 1590333853, 1603744122, 1639634239, 1689801720, 1744898494, 1795814041, 1834903460, 1856913914, 1859510769, 1843363086, 1811808664, 1770173545, 1724862725, 1682362687, 1648300037, 1626684298, 1619430019, 1626209014, 1644634486, 1670731917, 1699613413, 1726247613, 1746209113, 1756300065, 1754960207, 1742416081, 1720560173, 1692590314, 1662473118, 1634318092, 1611758343, 1597428805, 1592614840, 1597116101, 1609337102, 1626582077, 1645502447, 1662624601, 1674876505, 1680034764, 1677028396, 1666059072, 1648526258, 1626775147, 1603711026, 1582342268, 1565322734, 1554562222, 1550961289, 1554306394, 1563336226, 1575964106, 1589618354, 1601645968, 1609716958, 1612168400, 1608238001, 1598154980, 1583078423, 1564896503, 1545920568, 1528523082, 1514775603, 1506141568, 1503269000, 1505912058, 1512990162, 1522772430, 1533156490, 1541997184, 1547434147, 1548168606, 1543648532, 1534136093, 1520649768, 1504792655, 1488495513, 1473715408, 1462136705, 1454919835, 1452535031, 1454704618, 1460460428, 1468305314, 1476452105, 1483102125, 1486720079, 1486263518, 1481332776, 1472220027, 1459851860, 1445636075, 1431237806, 1418320381, 1408291070, 1402090418, 1400056544, 1401883815, 1406680581, 1413115480, 1419628485, 1424673389, 1426954182, 1425619318, 1420384875, 1411568943, 1400033393, 1387043383, 1374067370, 1362549103, 1353686900, 1348253827, 1346485669, 1348052812, 1352119081, 1357477272, 1362739508, 1366552523, 1367804562, 1365792358, 1360323204, 1351737386, 1340848666, 1328813042, 1316946854, 1306522784, 1298575271, 1293745029, 1292185948, 1293547841, 1297036679, 1301542091, 1305811739, 1308645249, 1309077735, 1306524927, 1300868130, 1292466735, 1282097251, 1270829096, 1259856942, 1250315847, 1243107718, 1238765582, 1237376042, 1238571129, 1241590005, 1245400257, 1248859500, 1250892035, 1250653312, 1247657088, 1241846173, 1233596554, 1223654952, 1213020142, 1202786834, 1193976479, 1187381109, 1183444040, 1182195371, 1183251607, 1185878816, 1189108970, 1191891061, 1193253460, 1192452457, 1189084313, 1183143987, 1175022025, 1165440705, 1155339815, 1145730107, 1137537241, 1131460268, 1127866279, 1126737046, 1127675378, 1129969676, 1132706211, 1134911469, 1135702391, 1134421369, 1130735385, 1124684359, 1116671751, 1107399338, 1097756696, 1088682751, 1081020929, 1075390226, 1072091870, 1071065644, 1071902157, 1073908710, 1076218197, 1077923945, 1078219532, 1076522065, 1072560084, 1066412854, 1058495396, 1049491983, 1040248737, 1031642155, 1024443986, 1019203254, 1016163453, 1015227386, 1015974680, 1017728909, 1019663613, 1020930623, 1020790784, 1018726945, 1014521961, 1008289967, 1000456433, 991690450, 982799997, 974606563, 967818574, 962923100, 960112381, 959256247, 959924361, 961454509, 963056140, 963932959, 963405596, 961015456, 956593896, 950286308, 942527690, 933973782, 925398660, 917574756, 911153894, 906567646, 903962236, 903177785, 903774829, 905103732, 906406039, 906931977, 906055907, 903371963, 898755275, 892379541, 884688514, 876326056, 868035773, 860545871, 854457040, 850150629, 847731125, 847011616, 847544221, 848690556, 849721162, 849928413, 848735445, 845784345, 840990125, 834552358, 826922906, 818734944, 810704420, 803519279, 797733581, 793682790, 791433198, 790773073, 791246711, 792225901, 793007627, 792922804, 791439248, 788243041, 783285857, 776791121, 769218258, 761190734, 753399163, 746494517, 740987956, 737172671, 735079697, 734474335, 734893558, 735718439, 736270284, 735915551, 734163328, 730740395, 725632396, 719084915, 711564478, 703685656, 696115661, 689471236, 684223758, 680627160, 678679687, 678125222, 678493840, 679175157, 679513034, 678906960, 676904438, 673270185, 668021557, 661424874, 653953371, 646213402, 638850398, 632449170, 627443932, 624051896, 622240582, 621733764, 622054981, 622601768, 622739059, 621897262, 619659896, 615827287, 610446602, 603803699, 596378191, 588768780, 581600484, 575428115, 570650930, 567451551, 565768526, 565306618, 565583132, 566003006, 565950990, 564886637, 562427456, 558407423, 552901910, 546215301, 538833308, 531347463, 524363514, 518407912, 513846813, 510830053, 509268679, 508849371, 509083460, 509382843, 509151032, 507875227, 505205218, 501006976, 495382730, 488654535, 481313968, 473945793, 467137461, 461388436, 457033340, 454190744, 452745429, 452366777, 452560356, 452744655, 452341056, 450863142, 447991549, 443622850, 437884994, 431116996, 423816098, 416560640, 409920592, 404369588, 400212034, 397536505, 396202558, 395862931, 396017609, 396091353, 395522671, 393850469, 390785026, 386252355, 380405174, 373598862, 366336166, 359189291, 352711409, 347351291, 343384226, 340869853, 339643370, 339341411, 339458522, 339425475, 338697277, 336837278, 333584397, 328893125, 322940167, 316096771, 308871067, 301829361, 295508597, 290333484, 286551099, 284193020, 283070792, 282805387, 282886027, 282749272, 281866115, 279823623, 276388540, 271543048, 265487201, 258607726, 251418028, 244478721, 238310985, 233316120, 229713722, 227508012, 226487455, 226257707, 226302755, 226064764, 225030292, 222809545, 219196435, 214200209, 208043769, 201129009, 193974538, 187135445, 181117518, 176299160, 172873073, 170816665, 169895762, 169700974, 169711111, 169373795, 168190820, 165795076, 162007140, 156862844, 150607558, 143658117, 136538285, 129797759, 123927223, 119282579, 116030062, 114120683, 113297944, 113137604, 113113328, 112678077, 111348635, 108780239, 104819768, 99529299, 93176405, 86192707, 79107104, 72464004, 66739196, 62266357, 59185555, 57421677, 56696112, 56569880, 56511516, 55979227, 54504617, 51765050, 47633472, 42197996, 35748244, 28730544, 21678930, 15132598, 9552575, 5250482, 2340388, 721199, 92294, 0, -92294, -721199, -2340388, -5250482, -9552575, -15132598, -21678930, -28730544, -35748244, -42197996, -47633472, -51765050, -54504617, -55979227, -56511516, -56569880, -56696112, -57421677, -59185555, -62266357, -66739196, -72464004, -79107104, -86192707, -93176405, -99529299, -104819768, -108780239, -111348635, -112678077, -113113328, -113137604, -113297944, -114120683, -116030062, -119282579, -123927223, -129797759, -136538285, -143658117, -150607558, -156862844, -162007140, -165795076, -168190820, -169373795, -169711111, -169700974, -169895762, -170816665, -172873073, -176299160, -181117518, -187135445, -193974538, -201129009, -208043769, -214200209, -219196435, -222809545, -225030292, -226064764, -226302755, -226257707, -226487455, -227508012, -229713722, -233316120, -238310985, -244478721, -251418028, -258607726, -265487201, -271543048, -276388540, -279823623, -281866115, -282749272, -282886027, -282805387, -283070792, -284193020, -286551099, -290333484, -295508597, -301829361, -308871067, -316096771, -322940167, -328893125, -333584397, -336837278, -338697277, -339425475, -339458522, -339341411, -339643370, -340869853, -343384226, -347351291, -352711409, -359189291, -366336166, -373598862, -380405174, -386252355, -390785026, -393850469, -395522671, -396091353, -396017609, -395862931, -396202558, -397536505, -400212034, -404369588, -409920592, -416560640, -423816098, -431116996, -437884994, -443622850, -447991549, -450863142, -452341056, -452744655, -452560356, -452366777, -452745429, -454190744, -457033340, -461388436, -467137461, -473945793, -481313968, -488654535, -495382730, -501006976, -505205218, -507875227, -509151032, -509382843, -509083460, -508849371, -509268679, -510830053, -513846813, -518407912, -524363514, -531347463, -538833308, -546215301, -552901910, -558407423, -562427456, -564886637, -565950990, -566003006, -565583132, -565306618, -565768526, -567451551, -570650930, -575428115, -581600484, -588768780, -596378191, -603803699, -610446602, -615827287, -619659896, -621897262, -622739059, -622601768, -622054981, -621733764, -622240582, -624051896, -627443932, -632449170, -638850398, -646213402, -653953371, -661424874, -668021557, -673270185, -676904438, -678906960, -679513034, -679175157, -678493840, -678125222, -678679687, -680627160, -684223758, -689471236, -696115661, -703685656, -711564478, -719084915, -725632396, -730740395, -734163328, -735915551, -736270284, -735718439, -734893558, -734474335, -735079697, -737172671, -740987956, -746494517, -753399163, -761190734, -769218258, -776791121, -783285857, -788243041, -791439248, -792922804, -793007627, -792225901, -791246711, -790773073, -791433198, -793682790, -797733581, -803519279, -810704420, -818734944, -826922906, -834552358, -840990125, -845784345, -848735445, -849928413, -849721162, -848690556, -847544221, -847011616, -847731125, -850150629, -854457040, -860545871, -868035773, -876326056, -884688514, -892379541, -898755275, -903371963, -906055907, -906931977, -906406039, -905103732, -903774829, -903177785, -903962236, -906567646, -911153894, -917574756, -925398660, -933973782, -942527690, -950286308, -956593896, -961015456, -963405596, -963932959, -963056140, -961454509, -959924361, -959256247, -960112381, -962923100, -967818574, -974606563, -982799997, -991690450, -1000456433, -1008289967, -1014521961, -1018726945, -1020790784, -1020930623, -1019663613, -1017728909, -1015974680, -1015227386, -1016163453, -1019203254, -1024443986, -1031642155, -1040248737, -1049491983, -1058495396, -1066412854, -1072560084, -1076522065, -1078219532, -1077923945, -1076218197, -1073908710, -1071902157, -1071065644, -1072091870, -1075390226, -1081020929, -1088682751, -1097756696, -1107399338, -1116671751, -1124684359, -1130735385, -1134421369, -1135702391, -1134911469, -1132706211, -1129969676, -1127675378, -1126737046, -1127866279, -1131460268, -1137537241, -1145730107, -1155339815, -1165440705, -1175022025, -1183143987, -1189084313, -1192452457, -1193253460, -1191891061, -1189108970, -1185878816, -1183251607, -1182195371, -1183444040, -1187381109, -1193976479, -1202786834, -1213020142, -1223654952, -1233596554, -1241846173, -1247657088, -1250653312, -1250892035, -1248859500, -1245400257, -1241590005, -1238571129, -1237376042, -1238765582, -1243107718, -1250315847, -1259856942, -1270829096, -1282097251, -1292466735, -1300868130, -1306524927, -1309077735, -1308645249, -1305811739, -1301542091, -1297036679, -1293547841, -1292185948, -1293745029, -1298575271, -1306522784, -1316946854, -1328813042, -1340848666, -1351737386, -1360323204, -1365792358, -1367804562, -1366552523, -1362739508, -1357477272, -1352119081, -1348052812, -1346485669, -1348253827, -1353686900, -1362549103, -1374067370, -1387043383, -1400033393, -1411568943, -1420384875, -1425619318, -1426954182, -1424673389, -1419628485, -1413115480, -1406680581, -1401883815, -1400056544, -1402090418, -1408291070, -1418320381, -1431237806, -1445636075, -1459851860, -1472220027, -1481332776, -1486263518, -1486720079, -1483102125, -1476452105, -1468305314, -1460460428, -1454704618, -1452535031, -1454919835, -1462136705, -1473715408, -1488495513, -1504792655, -1520649768, -1534136093, -1543648532, -1548168606, -1547434147, -1541997184, -1533156490, -1522772430, -1512990162, -1505912058, -1503269000, -1506141568, -1514775603, -1528523082, -1545920568, -1564896503, -1583078423, -1598154980, -1608238001, -1612168400, -1609716958, -1601645968, -1589618354, -1575964106, -1563336226, -1554306394, -1550961289, -1554562222, -1565322734, -1582342268, -1603711026, -1626775147, -1648526258, -1666059072, -1677028396, -1680034764, -1674876505, -1662624601, -1645502447, -1626582077, -1609337102, -1597116101, -1592614840, -1597428805, -1611758343, -1634318092, -1662473118, -1692590314, -1720560173, -1742416081, -1754960207, -1756300065, -1746209113, -1726247613, -1699613413, -1670731917, -1644634486, -1626209014, -1619430019, -1626684298, -1648300037, -1682362687, -1724862725, -1770173545, -1811808664, -1843363086, -1859510769, -1856913914, -1834903460, -1795814041, -1744898494, -1689801720, -1639634239, -1603744122, -1590333853, -1605099181, -1650075125, -1722858011, -1816332880, -1918977183, -2015741306, -2089433059, -2122466234, -2098781597, -2005719391, -1835619953, -1586954358, -1264837521, -880846048, -452143833, ], [0, 227551473, 452051538, 670521303, 880124849, 1078235658, 1262497133, 1430875532, 1581703876, 1713715640, 1826067378, 1918349753, 1990586793, 2043223546, 2077102667, 2093430770, 2093735690, 2079816062, 2053684815, 2017508354, 1973543310, 1924072741, 1871343711, 1817508026, 1764567829, 1714327538, 1668353393, 1627941618, 1594095898, 1567514577, 1548587665, 1537403417, 1533763973, 1537209242, 1547048010, 1562395011, 1582212562, 1605355261, 1630616164, 1656772894, 1682632137, 1707071132, 1729074846, 1747767774, 1762439456, 1772563083, 1777806809, 1778037647, 1773318062, 1763895679, 1750186682, 1732753729, 1712279365, 1689536014, 1665353777, 1640587229, 1616082486, 1592645692, 1571014052, 1551830378, 1535621960, 1522784418, 1513570970, 1508087354, 1506292415, 1508004187, 1512911069, 1520587558, 1530513803, 1542098165, 1554701855, 1567664669, 1580330834, 1592073988, 1602320390, 1610569517, 1616411354, 1619539799, 1619761780, 1617001833, 1611302090, 1602817783, 1591808522, 1578625813, 1563697329, 1547508647, 1530583174, 1513461078, 1496678042, 1480744667, 1466127271, 1453230816, 1442384548, 1433830854, 1427717694, 1424094821, 1422913865, 1424032196, 1427220339, 1432172603, 1438520437, 1445847956, 1453709005, 1461645061, 1469203286, 1475954026, 1481507084, 1485526180, 1487741060, 1487956834, 1486060227, 1482022556, 1475899388, 1467826919, 1458015303, 1446739211, 1434326049, 1421142325, 1407578712, 1394034418, 1380901481, 1368549583, 1357311976, 1347473047, 1339257970, 1332824830, 1328259467, 1325573216, 1324703582, 1325517776, 1327818939, 1331354775, 1335828214, 1340909668, 1346250383, 1351496353, 1356302235, 1360344740, 1363334983, 1365029312, 1365238237, 1363833113, 1360750372, 1355993147, 1349630274, 1341792747, 1332667778, 1322490749, 1311535375, 1300102499, 1288507963, 1277070042, 1266096945, 1255874853, 1246656967, 1238653982, 1232026331, 1226878490, 1223255541, 1221142099, 1220463613, 1221089977, 1222841266, 1225495361, 1228797142, 1232468869, 1236221330, 1239765323, 1242823006, 1245138679, 1246488587, 1246689360, 1245604786, 1243150658, 1239297540, 1234071347, 1227551761, 1219868543, 1211195926, 1201745324, 1191756643, 1181488578, 1171208258, 1161180663, 1151658235, 1142871079, 1135018130, 1128259637, 1122711230, 1118439797, 1115461313, 1113740688, 1113193620, 1113690363, 1115061247, 1117103713, 1119590576, 1122279181, 1124921088, 1127271895, 1129100814, 1130199634, 1130390716, 1129533715, 1127530788, 1124330074, 1119927352, 1114365803, 1107733915, 1100161622, 1091814853, 1082888700, 1073599506, 1064176172, 1054851049, 1045850763, 1037387336, 1029649958, 1022797710, 1016953545, 1012199717, 1008574863, 1006072810, 1004643160, 1004193614, 1004593912, 1005681257, 1007266962, 1009144076, 1011095668, 1012903446, 1014356366, 1015258897, 1015438606, 1014752797, 1013093915, 1010393542, 1006624821, 1001803220, 995985639, 989267879, 981780602, 973683949, 965161027, 956410543, 947638863, 939051834, 930846668, 923204228, 916282002, 910208047, 905076130, 900942245, 897822650, 895693470, 894491895, 894118892, 894443338, 895307383, 896532841, 897928337, 899296934, 900443931, 901184533, 901351072, 900799527, 899415062, 897116387, 893858769, 889635591, 884478390, 878455405, 871668669, 864249803, 856354656, 848157023, 839841690, 831597085, 823607824, 816047446, 809071627, 802812135, 797371752, 792820375, 789192429, 786485690, 784661569, 783646813, 783336577, 783598721, 784279163, 785208066, 786206626, 787094165, 787695279, 787846728, 787403827, 786246062, 784281735, 781451443, 777730272, 773128620, 767691619, 761497192, 754652829, 747291204, 739564825, 731639923, 723689828, 715888093, 708401640, 701384201, 694970306, 689270050, 684364858, 680304389, 677104711, 674747812, 673182450, 672326318, 672069424, 672278549, 672802603, 673478661, 674138454, 674615025, 674749328, 674396470, 673431384, 671753687, 669291551, 666004427, 661884532, 656957026, 651278897, 644936588, 638042474, 630730327, 623149952, 615461209, 607827665, 600410116, 593360246, 586814667, 580889572, 575676222, 571237420, 567605136, 564779341, 562728124, 561389059, 560671772, 560461606, 560624233, 561011025, 561464973, 561826921, 561941859, 561665037, 560867653, 559441890, 557305115, 554403062, 550711896, 546239055, 541022868, 535130941, 528657406, 521719112, 514450952, 507000468, 499521992, 492170520, 485095588, 478435377, 472311278, 466823147, 462045414, 458024207, 454775611, 452285111, 450508244, 449372439, 448779958, 448611824, 448732581, 448995691, 449249352, 449342515, 449130854, 448482454, 447282995, 445440233, 442887590, 439586729, 435529000, 430735714, 425257232, 419170907, 412577964, 405599444, 398371374, 391039355, 383752785, 376658943, 369897168, 363593368, 357855069, 352767199, 348388781, 344750645, 341854271, 339671787, 338147129, 337198312, 336720725, 336591309, 336673462, 336822460, 336891205, 336736039, 336222422, 335230241, 333658529, 331429428, 328491228, 324820369, 320422323, 315331331, 309609001, 303341827, 296637724, 289621733, 282431041, 275209539, 268102119, 261248942, 254779899, 248809489, 243432311, 238719351, 234715207, 231436359, 228870559, 226977352, 225689715, 224916742, 224547272, 224454313, 224500084, 224541482, 224435749, 224046119, 223247221, 221930032, 220006163, 217411343, 214107928, 210086364, 205365539, 199992001, 194038091, 187599055, 180789252, 173737609, 166582510, 159466308, 152529688, 145906105, 139716505, 134064551, 129032539, 124678150, 121032195, 118097412, 115848380, 114232544, 113172315, 112568160, 112302550, 112244631, 112255405, 112193236, 111919464, 111303881, 110229888, 108599100, 106335227, 103387088, 99730620, 95369823, 90336582, 84689400, 78511069, 71905383, 64993021, 57906768, 50786252, 43772415, 37001935, 30601818, 24684369, 19342753, 14647311, 10642779, 7346523, 4747855, 2808459, 1463914, 626245, 187407, 23566, 0, -23566, -187407, -626245, -1463914, -2808459, -4747855, -7346523, -10642779, -14647311, -19342753, -24684369, -30601818, -37001935, -43772415, -50786252, -57906768, -64993021, -71905383, -78511069, -84689400, -90336582, -95369823, -99730620, -103387088, -106335227, -108599100, -110229888, -111303881, -111919464, -112193236, -112255405, -112244631, -112302550, -112568160, -113172315, -114232544, -115848380, -118097412, -121032195, -124678150, -129032539, -134064551, -139716505, -145906105, -152529688, -159466308, -166582510, -173737609, -180789252, -187599055, -194038091, -199992001, -205365539, -210086364, -214107928, -217411343, -220006163, -221930032, -223247221, -224046119, -224435749, -224541482, -224500084, -224454313, -224547272, -224916742, -225689715, -226977352, -228870559, -231436359, -234715207, -238719351, -243432311, -248809489, -254779899, -261248942, -268102119, -275209539, -282431041, -289621733, -296637724, -303341827, -309609001, -315331331, -320422323, -324820369, -328491228, -331429428, -333658529, -335230241, -336222422, -336736039, -336891205, -336822460, -336673462, -336591309, -336720725, -337198312, -338147129, -339671787, -341854271, -344750645, -348388781, -352767199, -357855069, -363593368, -369897168, -376658943, -383752785, -391039355, -398371374, -405599444, -412577964, -419170907, -425257232, -430735714, -435529000, -439586729, -442887590, -445440233, -447282995, -448482454, -449130854, -449342515, -449249352, -448995691, -448732581, -448611824, -448779958, -449372439, -450508244, -452285111, -454775611, -458024207, -462045414, -466823147, -472311278, -478435377, -485095588, -492170520, -499521992, -507000468, -514450952, -521719112, -528657406, -535130941, -541022868, -546239055, -550711896, -554403062, -557305115, -559441890, -560867653, -561665037, -561941859, -561826921, -561464973, -561011025, -560624233, -560461606, -560671772, -561389059, -562728124, -564779341, -567605136, -571237420, -575676222, -580889572, -586814667, -593360246, -600410116, -607827665, -615461209, -623149952, -630730327, -638042474, -644936588, -651278897, -656957026, -661884532, -666004427, -669291551, -671753687, -673431384, -674396470, -674749328, -674615025, -674138454, -673478661, -672802603, -672278549, -672069424, -672326318, -673182450, -674747812, -677104711, -680304389, -684364858, -689270050, -694970306, -701384201, -708401640, -715888093, -723689828, -731639923, -739564825, -747291204, -754652829, -761497192, -767691619, -773128620, -777730272, -781451443, -784281735, -786246062, -787403827, -787846728, -787695279, -787094165, -786206626, -785208066, -784279163, -783598721, -783336577, -783646813, -784661569, -786485690, -789192429, -792820375, -797371752, -802812135, -809071627, -816047446, -823607824, -831597085, -839841690, -848157023, -856354656, -864249803, -871668669, -878455405, -884478390, -889635591, -893858769, -897116387, -899415062, -900799527, -901351072, -901184533, -900443931, -899296934, -897928337, -896532841, -895307383, -894443338, -894118892, -894491895, -895693470, -897822650, -900942245, -905076130, -910208047, -916282002, -923204228, -930846668, -939051834, -947638863, -956410543, -965161027, -973683949, -981780602, -989267879, -995985639, -1001803220, -1006624821, -1010393542, -1013093915, -1014752797, -1015438606, -1015258897, -1014356366, -1012903446, -1011095668, -1009144076, -1007266962, -1005681257, -1004593912, -1004193614, -1004643160, -1006072810, -1008574863, -1012199717, -1016953545, -1022797710, -1029649958, -1037387336, -1045850763, -1054851049, -1064176172, -1073599506, -1082888700, -1091814853, -1100161622, -1107733915, -1114365803, -1119927352, -1124330074, -1127530788, -1129533715, -1130390716, -1130199634, -1129100814, -1127271895, -1124921088, -1122279181, -1119590576, -1117103713, -1115061247, -1113690363, -1113193620, -1113740688, -1115461313, -1118439797, -1122711230, -1128259637, -1135018130, -1142871079, -1151658235, -1161180663, -1171208258, -1181488578, -1191756643, -1201745324, -1211195926, -1219868543, -1227551761, -1234071347, -1239297540, -1243150658, -1245604786, -1246689360, -1246488587, -1245138679, -1242823006, -1239765323, -1236221330, -1232468869, -1228797142, -1225495361, -1222841266, -1221089977, -1220463613, -1221142099, -1223255541, -1226878490, -1232026331, -1238653982, -1246656967, -1255874853, -1266096945, -1277070042, -1288507963, -1300102499, -1311535375, -1322490749, -1332667778, -1341792747, -1349630274, -1355993147, -1360750372, -1363833113, -1365238237, -1365029312, -1363334983, -1360344740, -1356302235, -1351496353, -1346250383, -1340909668, -1335828214, -1331354775, -1327818939, -1325517776, -1324703582, -1325573216, -1328259467, -1332824830, -1339257970, -1347473047, -1357311976, -1368549583, -1380901481, -1394034418, -1407578712, -1421142325, -1434326049, -1446739211, -1458015303, -1467826919, -1475899388, -1482022556, -1486060227, -1487956834, -1487741060, -1485526180, -1481507084, -1475954026, -1469203286, -1461645061, -1453709005, -1445847956, -1438520437, -1432172603, -1427220339, -1424032196, -1422913865, -1424094821, -1427717694, -1433830854, -1442384548, -1453230816, -1466127271, -1480744667, -1496678042, -1513461078, -1530583174, -1547508647, -1563697329, -1578625813, -1591808522, -1602817783, -1611302090, -1617001833, -1619761780, -1619539799, -1616411354, -1610569517, -1602320390, -1592073988, -1580330834, -1567664669, -1554701855, -1542098165, -1530513803, -1520587558, -1512911069, -1508004187, -1506292415, -1508087354, -1513570970, -1522784418, -1535621960, -1551830378, -1571014052, -1592645692, -1616082486, -1640587229, -1665353777, -1689536014, -1712279365, -1732753729, -1750186682, -1763895679, -1773318062, -1778037647, -1777806809, -1772563083, -1762439456, -1747767774, -1729074846, -1707071132, -1682632137, -1656772894, -1630616164, -1605355261, -1582212562, -1562395011, -1547048010, -1537209242, -1533763973, -1537403417, -1548587665, -1567514577, -1594095898, -1627941618, -1668353393, -1714327538, -1764567829, -1817508026, -1871343711, -1924072741, -1973543310, -2017508354, -2053684815, -2079816062, -2093735690, -2093430770, -2077102667, -2043223546, -1990586793, -1918349753, -1826067378, -1713715640, -1581703876, -1430875532, -1262497133, -1078235658, -880124849, -670521303, -452051538, -227551473, ], [0, 113964354, 227527907, 340292315, 451864131, 561857211, 669895058, 775613112, 878660935, 978704308, 1075427199, 1168533612, 1257749278, 1342823196, 1423529009, 1499666199, 1571061097, 1637567708, 1699068329, 1755473981, 1806724625, 1852789189, 1893665385, 1929379329, 1959984975, 1985563349, 2006221610, 2022091938, 2033330252, 2040114786, 2042644518, 2041137474, 2035828922, 2026969463, 2014823041, 1999664893, 1981779432, 1961458116, 1938997285, 1914696001, 1888853909, 1861769122, 1833736159, 1805043945, 1775973888, 1746798047, 1717777406, 1689160261, 1661180741, 1634057459, 1607992311, 1583169429, 1559754293, 1537893006, 1517711730, 1499316304, 1482792017, 1468203555, 1455595113, 1444990673, 1436394428, 1429791371, 1425148013, 1422413249, 1421519342, 1422383021, 1424906692, 1428979727, 1434479850, 1441274573, 1449222695, 1458175841, 1467980018, 1478477197, 1489506880, 1500907661, 1512518752, 1524181475, 1535740699, 1547046205, 1557953988, 1568327465, 1578038593, 1586968881, 1595010294, 1602066048, 1608051270, 1612893548, 1616533351, 1618924312, 1620033394, 1619840925, 1618340501, 1615538779, 1611455136, 1606121233, 1599580453, 1591887255, 1583106427, 1573312256, 1562587626, 1551023048, 1538715638, 1525768048, 1512287364, 1498383983, 1484170475, 1469760447, 1455267413, 1440803685, 1426479302, 1412400982, 1398671143, 1385386965, 1372639528, 1360513020, 1349084021, 1338420876, 1328583157, 1319621218, 1311575841, 1304477994, 1298348668, 1293198836, 1289029487, 1285831780, 1283587270, 1282268237, 1281838102, 1282251913, 1283456914, 1285393184, 1287994327, 1291188227, 1294897843, 1299042039, 1303536451, 1308294363, 1313227606, 1318247450, 1323265496, 1328194547, 1332949462, 1337447977, 1341611482, 1345365763, 1348641677, 1351375784, 1353510901, 1354996599, 1355789618, 1355854212, 1355162412, 1353694214, 1351437680, 1348388962, 1344552241, 1339939598, 1334570792, 1328472980, 1321680358, 1314233736, 1306180059, 1297571864, 1288466696, 1278926471, 1269016811, 1258806348, 1248365998, 1237768230, 1227086324, 1216393624, 1205762805, 1195265150, 1184969849, 1174943330, 1165248614, 1155944727, 1147086138, 1138722266, 1130897030, 1123648464, 1117008394, 1111002176, 1105648509, 1100959308, 1096939649, 1093587786, 1090895233, 1088846910, 1087421359, 1086591018, 1086322556, 1086577259, 1087311471, 1088477077, 1090022027, 1091890897, 1094025474, 1096365371, 1098848650, 1101412461, 1103993685, 1106529570, 1108958360, 1111219910, 1113256280, 1115012296, 1116436087, 1117479581, 1118098957, 1118255051, 1117913715, 1117046122, 1115629011, 1113644880, 1111082117, 1107935067, 1104204049, 1099895300, 1095020872, 1089598459, 1083651182, 1077207312, 1070299944, 1062966627, 1055248955, 1047192113, 1038844396, 1030256698, 1021481979, 1012574715, 1003590336, 994584660, 985613326, 976731231, 967991982, 959447365, 951146828, 943137002, 935461249, 928159241, 921266588, 914814506, 908829523, 903333250, 898342193, 893867613, 889915455, 886486317, 883575478, 881172984, 879263776, 877827879, 876840635, 876272978, 876091758, 876260105, 876737818, 877481798, 878446500, 879584408, 880846531, 882182904, 883543103, 884876756, 886134056, 887266260, 888226177, 888968637, 889450940, 889633270, 889479087, 888955476, 888033462, 886688280, 884899606, 882651737, 879933724, 876739461, 873067717, 868922126, 864311122, 859247829, 853749904, 847839336, 841542195, 834888354, 827911162, 820647089, 813135341, 805417450, 797536837, 789538366, 781467881, 773371736, 765296320, 757287590, 749390604, 741649068, 734104901, 726797816, 719764928, 713040389, 706655055, 700636190, 695007200, 689787420, 684991930, 680631425, 676712123, 673235724, 670199410, 667595895, 665413517, 663636377, 662244517, 661214139, 660517866, 660125031, 660002007, 660112554, 660418205, 660878660, 661452207, 662096147, 662767237, 663422126, 664017802, 664512019, 664863733, 665033508, 664983910, 664679885, 664089101, 663182271, 661933433, 660320209, 658324012, 655930227, 653128341, 649912037, 646279244, 642232141, 637777121, 632924711, 627689450, 622089728, 616147588, 609888485, 603341020, 596536635, 589509287, 582295093, 574931955, 567459171, 559917030, 552346396, 544788298, 537283503, 529872111, 522593143, 515484151, 508580840, 501916707, 495522714, 489426975, 483654480, 478226851, 473162130, 468474609, 464174692, 460268802, 456759329, 453644612, 450918971, 448572776, 446592553, 444961135, 443657844, 442658710, 441936729, 441462138, 441202733, 441124196, 441190453, 441364045, 441606515, 441878798, 442141619, 442355901, 442483152, 442485864, 442327886, 441974792, 441394227, 440556231, 439433537, 438001845, 436240063, 434130509, 431659089, 428815433, 425592987, 421989076, 418004922, 413645623, 408920091, 403840960, 398424439, 392690151, 386660918, 380362527, 373823461, 367074599, 360148907, 353081086, 345907226, 338664430, 331390437, 324123234, 316900673, 309760080, 302737882, 295869234, 289187662, 282724729, 276509713, 270569314, 264927386, 259604701, 254618736, 249983509, 245709436, 241803229, 238267841, 235102431, 232302389, 229859379, 227761437, 225993091, 224535531, 223366797, 222462014, 221793647, 221331783, 221044439, 220897896, 220857037, 220885710, 220947099, 221004098, 221019688, 220957318, 220781272, 220457036, 219951648, 219234026, 218275293, 217049059, 215531693, 213702562, 211544231, 209042646, 206187269, 202971183, 199391161, 195447691, 191144973, 186490871, 181496830, 176177753, 170551852, 164640458, 158467798, 152060749, 145448564, 138662567, 131735836, 124702865, 117599210, 110461126, 103325197, 96227966, 89205558, 82293316, 75525437, 68934628, 62551774, 56405618, 50522476, 44925967, 39636772, 34672426, 30047141, 25771662, 21853156, 18295146, 15097469, 12256286, 9764116, 7609917, 5779196, 4254164, 3013908, 2034615, 1289809, 750621, 386087, 163464, 48557, 6079, 0, -6079, -48557, -163464, -386087, -750621, -1289809, -2034615, -3013908, -4254164, -5779196, -7609917, -9764116, -12256286, -15097469, -18295146, -21853156, -25771662, -30047141, -34672426, -39636772, -44925967, -50522476, -56405618, -62551774, -68934628, -75525437, -82293316, -89205558, -96227966, -103325197, -110461126, -117599210, -124702865, -131735836, -138662567, -145448564, -152060749, -158467798, -164640458, -170551852, -176177753, -181496830, -186490871, -191144973, -195447691, -199391161, -202971183, -206187269, -209042646, -211544231, -213702562, -215531693, -217049059, -218275293, -219234026, -219951648, -220457036, -220781272, -220957318, -221019688, -221004098, -220947099, -220885710, -220857037, -220897896, -221044439, -221331783, -221793647, -222462014, -223366797, -224535531, -225993091, -227761437, -229859379, -232302389, -235102431, -238267841, -241803229, -245709436, -249983509, -254618736, -259604701, -264927386, -270569314, -276509713, -282724729, -289187662, -295869234, -302737882, -309760080, -316900673, -324123234, -331390437, -338664430, -345907226, -353081086, -360148907, -367074599, -373823461, -380362527, -386660918, -392690151, -398424439, -403840960, -408920091, -413645623, -418004922, -421989076, -425592987, -428815433, -431659089, -434130509, -436240063, -438001845, -439433537, -440556231, -441394227, -441974792, -442327886, -442485864, -442483152, -442355901, -442141619, -441878798, -441606515, -441364045, -441190453, -441124196, -441202733, -441462138, -441936729, -442658710, -443657844, -444961135, -446592553, -448572776, -450918971, -453644612, -456759329, -460268802, -464174692, -468474609, -473162130, -478226851, -483654480, -489426975, -495522714, -501916707, -508580840, -515484151, -522593143, -529872111, -537283503, -544788298, -552346396, -559917030, -567459171, -574931955, -582295093, -589509287, -596536635, -603341020, -609888485, -616147588, -622089728, -627689450, -632924711, -637777121, -642232141, -646279244, -649912037, -653128341, -655930227, -658324012, -660320209, -661933433, -663182271, -664089101, -664679885, -664983910, -665033508, -664863733, -664512019, -664017802, -663422126, -662767237, -662096147, -661452207, -660878660, -660418205, -660112554, -660002007, -660125031, -660517866, -661214139, -662244517, -663636377, -665413517, -667595895, -670199410, -673235724, -676712123, -680631425, -684991930, -689787420, -695007200, -700636190, -706655055, -713040389, -719764928, -726797816, -734104901, -741649068, -749390604, -757287590, -765296320, -773371736, -781467881, -789538366, -797536837, -805417450, -813135341, -820647089, -827911162, -834888354, -841542195, -847839336, -853749904, -859247829, -864311122, -868922126, -873067717, -876739461, -879933724, -882651737, -884899606, -886688280, -888033462, -888955476, -889479087, -889633270, -889450940, -888968637, -888226177, -887266260, -886134056, -884876756, -883543103, -882182904, -880846531, -879584408, -878446500, -877481798, -876737818, -876260105, -876091758, -876272978, -876840635, -877827879, -879263776, -881172984, -883575478, -886486317, -889915455, -893867613, -898342193, -903333250, -908829523, -914814506, -921266588, -928159241, -935461249, -943137002, -951146828, -959447365, -967991982, -976731231, -985613326, -994584660, -1003590336, -1012574715, -1021481979, -1030256698, -1038844396, -1047192113, -1055248955, -1062966627, -1070299944, -1077207312, -1083651182, -1089598459, -1095020872, -1099895300, -1104204049, -1107935067, -1111082117, -1113644880, -1115629011, -1117046122, -1117913715, -1118255051, -1118098957, -1117479581, -1116436087, -1115012296, -1113256280, -1111219910, -1108958360, -1106529570, -1103993685, -1101412461, -1098848650, -1096365371, -1094025474, -1091890897, -1090022027, -1088477077, -1087311471, -1086577259, -1086322556, -1086591018, -1087421359, -1088846910, -1090895233, -1093587786, -1096939649, -1100959308, -1105648509, -1111002176, -1117008394, -1123648464, -1130897030, -1138722266, -1147086138, -1155944727, -1165248614, -1174943330, -1184969849, -1195265150, -1205762805, -1216393624, -1227086324, -1237768230, -1248365998, -1258806348, -1269016811, -1278926471, -1288466696, -1297571864, -1306180059, -1314233736, -1321680358, -1328472980, -1334570792, -1339939598, -1344552241, -1348388962, -1351437680, -1353694214, -1355162412, -1355854212, -1355789618, -1354996599, -1353510901, -1351375784, -1348641677, -1345365763, -1341611482, -1337447977, -1332949462, -1328194547, -1323265496, -1318247450, -1313227606, -1308294363, -1303536451, -1299042039, -1294897843, -1291188227, -1287994327, -1285393184, -1283456914, -1282251913, -1281838102, -1282268237, -1283587270, -1285831780, -1289029487, -1293198836, -1298348668, -1304477994, -1311575841, -1319621218, -1328583157, -1338420876, -1349084021, -1360513020, -1372639528, -1385386965, -1398671143, -1412400982, -1426479302, -1440803685, -1455267413, -1469760447, -1484170475, -1498383983, -1512287364, -1525768048, -1538715638, -1551023048, -1562587626, -1573312256, -1583106427, -1591887255, -1599580453, -1606121233, -1611455136, -1615538779, -1618340501, -1619840925, -1620033394, -1618924312, -1616533351, -1612893548, -1608051270, -1602066048, -1595010294, -1586968881, -1578038593, -1568327465, -1557953988, -1547046205, -1535740699, -1524181475, -1512518752, -1500907661, -1489506880, -1478477197, -1467980018, -1458175841, -1449222695, -1441274573, -1434479850, -1428979727, -1424906692, -1422383021, -1421519342, -1422413249, -1425148013, -1429791371, -1436394428, -1444990673, -1455595113, -1468203555, -1482792017, -1499316304, -1517711730, -1537893006, -1559754293, -1583169429, -1607992311, -1634057459, -1661180741, -1689160261, -1717777406, -1746798047, -1775973888, -1805043945, -1833736159, -1861769122, -1888853909, -1914696001, -1938997285, -1961458116, -1981779432, -1999664893, -2014823041, -2026969463, -2035828922, -2041137474, -2042644518, -2040114786, -2033330252, -2022091938, -2006221610, -1985563349, -1959984975, -1929379329, -1893665385, -1852789189, -1806724625, -1755473981, -1699068329, -1637567708, -1571061097, -1499666199, -1423529009, -1342823196, -1257749278, -1168533612, -1075427199, -978704308, -878660935, -775613112, -669895058, -561857211, -451864131, -340292315, -227527907, -113964354, ], [0, 57006496, 113958275, 170800710, 227479349, 283940008, 340128851, 395992486, 451478044, 506533266, 561106590, 615147227, 668605249, 721431665, 773578497, 824998858, 875647027, 925478516, 974450144, 1022520098, 1069648003, 1115794977, 1160923696, 1204998442, 1247985162, 1289851513, 1330566909, 1370102567, 1408431539, 1445528756, 1481371053, 1515937203, 1549207941, 1581165983, 1611796046, 1641084862, 1669021188, 1695595811, 1720801555, 1744633274, 1767087853, 1788164198, 1807863222, 1826187832, 1843142908, 1858735282, 1872973711, 1885868846, 1897433201, 1907681117, 1916628720, 1924293883, 1930696174, 1935856814, 1939798622, 1942545964, 1944124694, 1944562095, 1943886820, 1942128829, 1939319321, 1935490668, 1930676349, 1924910872, 1918229712, 1910669229, 1902266597, 1893059731, 1883087205, 1872388181, 1861002326, 1848969738, 1836330868, 1823126438, 1809397367, 1795184691, 1780529487, 1765472796, 1750055544, 1734318471, 1718302057, 1702046444, 1685591369, 1668976093, 1652239330, 1635419182, 1618553074, 1601677691, 1584828915, 1568041769, 1551350357, 1534787812, 1518386245, 1502176694, 1486189078, 1470452153, 1454993472, 1439839347, 1425014813, 1410543595, 1396448080, 1382749292, 1369466867, 1356619035, 1344222600, 1332292931, 1320843947, 1309888114, 1299436438, 1289498463, 1280082278, 1271194517, 1262840370, 1255023591, 1247746518, 1241010085, 1234813841, 1229155980, 1224033355, 1219441518, 1215374740, 1211826052, 1208787273, 1206249054, 1204200914, 1202631281, 1201527539, 1200876071, 1200662305, 1200870766, 1201485125, 1202488248, 1203862252, 1205588558, 1207647945, 1210020605, 1212686203, 1215623929, 1218812559, 1222230509, 1225855899, 1229666602, 1233640310, 1237754587, 1241986927, 1246314810, 1250715760, 1255167399, 1259647501, 1264134046, 1268605272, 1273039727, 1277416320, 1281714366, 1285913635, 1289994397, 1293937469, 1297724251, 1301336769, 1304757714, 1307970478, 1310959186, 1313708729, 1316204794, 1318433892, 1320383382, 1322041494, 1323397350, 1324440980, 1325163344, 1325556335, 1325612800, 1325326541, 1324692325, 1323705887, 1322363928, 1320664117, 1318605088, 1316186429, 1313408679, 1310273314, 1306782735, 1302940252, 1298750071, 1294217267, 1289347772, 1284148342, 1278626541, 1272790706, 1266649927, 1260214007, 1253493439, 1246499367, 1239243555, 1231738349, 1223996640, 1216031828, 1207857781, 1199488796, 1190939558, 1182225099, 1173360756, 1164362130, 1155245041, 1146025487, 1136719601, 1127343608, 1117913783, 1108446404, 1098957718, 1089463891, 1079980973, 1070524853, 1061111219, 1051755525, 1042472944, 1033278337, 1024186213, 1015210698, 1006365499, 997663869, 989118580, 980741893, 972545527, 964540634, 956737777, 949146902, 941777319, 934637683, 927735974, 921079483, 914674798, 908527790, 902643604, 897026649, 891680595, 886608365, 881812135, 877293332, 873052634, 869089977, 865404558, 861994841, 858858568, 855992767, 853393769, 851057216, 848978083, 847150690, 845568726, 844225265, 843112792, 842223224, 841547936, 841077785, 840803139, 840713906, 840799562, 841049180, 841451463, 841994776, 842667178, 843456455, 844350154, 845335617, 846400015, 847530384, 848713659, 849936708, 851186370, 852449486, 853712936, 854963674, 856188759, 857375391, 858510943, 859582994, 860579359, 861488121, 862297664, 862996694, 863574276, 864019855, 864323286, 864474854, 864465301, 864285847, 863928212, 863384632, 862647880, 861711282, 860568732, 859214702, 857644258, 855853069, 853837415, 851594194, 849120926, 846415759, 843477471, 840305467, 836899779, 833261068, 829390611, 825290301, 820962636, 816410711, 811638208, 806649383, 801449049, 796042565, 790435821, 784635212, 778647627, 772480427, 766141418, 759638837, 752981321, 746177888, 739237905, 732171071, 724987380, 717697101, 710310745, 702839039, 695292894, 687683379, 680021687, 672319108, 664586996, 656836743, 649079741, 641327362, 633590918, 625881637, 618210633, 610588876, 603027163, 595536089, 588126023, 580807080, 573589092, 566481587, 559493763, 552634464, 545912160, 539334923, 532910409, 526645837, 520547976, 514623121, 508877085, 503315181, 497942211, 492762456, 487779662, 482997037, 478417241, 474042381, 469874010, 465913120, 462160144, 458614957, 455276874, 452144661, 449216531, 446490157, 443962679, 441630708, 439490344, 437537182, 435766328, 434172412, 432749607, 431491640, 430391814, 429443027, 428637790, 427968250, 427426210, 427003154, 426690267, 426478464, 426358410, 426320549, 426355129, 426452228, 426601780, 426793605, 427017435, 427262938, 427519753, 427777511, 428025865, 428254520, 428453256, 428611957, 428720640, 428769476, 428748821, 428649239, 428461527, 428176736, 427786203, 427281562, 426654774, 425898145, 425004345, 423966430, 422777854, 421432491, 419924648, 418249080, 416401003, 414376106, 412170561, 409781032, 407204685, 404439193, 401482740, 398334030, 394992281, 391457235, 387729154, 383808815, 379697513, 375397054, 370909750, 366238408, 361386328, 356357291, 351155544, 345785797, 340253199, 334563331, 328722190, 322736168, 316612038, 310356934, 303978334, 297484035, 290882134, 284181009, 277389290, 270515841, 263569734, 256560223, 249496724, 242388782, 235246053, 228078273, 220895235, 213706760, 206522674, 199352777, 192206824, 185094492, 178025356, 171008869, 164054328, 157170857, 150367378, 143652589, 137034942, 130522619, 124123511, 117845199, 111694930, 105679602, 99805742, 94079494, 88506598, 83092376, 77841723, 72759085, 67848457, 63113366, 58556865, 54181526, 49989429, 45982161, 42160813, 38525971, 35077721, 31815649, 28738836, 25845869, 23134838, 20603346, 18248515, 16066990, 14054955, 12208136, 10521819, 8990857, 7609689, 6372352, 5272499, 4303413, 3458028, 2728947, 2108463, 1588576, 1161019, 817276, 548611, 346082, 200574, 102817, 43417, 12873, 1610, 0, -1610, -12873, -43417, -102817, -200574, -346082, -548611, -817276, -1161019, -1588576, -2108463, -2728947, -3458028, -4303413, -5272499, -6372352, -7609689, -8990857, -10521819, -12208136, -14054955, -16066990, -18248515, -20603346, -23134838, -25845869, -28738836, -31815649, -35077721, -38525971, -42160813, -45982161, -49989429, -54181526, -58556865, -63113366, -67848457, -72759085, -77841723, -83092376, -88506598, -94079494, -99805742, -105679602, -111694930, -117845199, -124123511, -130522619, -137034942, -143652589, -150367378, -157170857, -164054328, -171008869, -178025356, -185094492, -192206824, -199352777, -206522674, -213706760, -220895235, -228078273, -235246053, -242388782, -249496724, -256560223, -263569734, -270515841, -277389290, -284181009, -290882134, -297484035, -303978334, -310356934, -316612038, -322736168, -328722190, -334563331, -340253199, -345785797, -351155544, -356357291, -361386328, -366238408, -370909750, -375397054, -379697513, -383808815, -387729154, -391457235, -394992281, -398334030, -401482740, -404439193, -407204685, -409781032, -412170561, -414376106, -416401003, -418249080, -419924648, -421432491, -422777854, -423966430, -425004345, -425898145, -426654774, -427281562, -427786203, -428176736, -428461527, -428649239, -428748821, -428769476, -428720640, -428611957, -428453256, -428254520, -428025865, -427777511, -427519753, -427262938, -427017435, -426793605, -426601780, -426452228, -426355129, -426320549, -426358410, -426478464, -426690267, -427003154, -427426210, -427968250, -428637790, -429443027, -430391814, -431491640, -432749607, -434172412, -435766328, -437537182, -439490344, -441630708, -443962679, -446490157, -449216531, -452144661, -455276874, -458614957, -462160144, -465913120, -469874010, -474042381, -478417241, -482997037, -487779662, -492762456, -497942211, -503315181, -508877085, -514623121, -520547976, -526645837, -532910409, -539334923, -545912160, -552634464, -559493763, -566481587, -573589092, -580807080, -588126023, -595536089, -603027163, -610588876, -618210633, -625881637, -633590918, -641327362, -649079741, -656836743, -664586996, -672319108, -680021687, -687683379, -695292894, -702839039, -710310745, -717697101, -724987380, -732171071, -739237905, -746177888, -752981321, -759638837, -766141418, -772480427, -778647627, -784635212, -790435821, -796042565, -801449049, -806649383, -811638208, -816410711, -820962636, -825290301, -829390611, -833261068, -836899779, -840305467, -843477471, -846415759, -849120926, -851594194, -853837415, -855853069, -857644258, -859214702, -860568732, -861711282, -862647880, -863384632, -863928212, -864285847, -864465301, -864474854, -864323286, -864019855, -863574276, -862996694, -862297664, -861488121, -860579359, -859582994, -858510943, -857375391, -856188759, -854963674, -853712936, -852449486, -851186370, -849936708, -848713659, -847530384, -846400015, -845335617, -844350154, -843456455, -842667178, -841994776, -841451463, -841049180, -840799562, -840713906, -840803139, -841077785, -841547936, -842223224, -843112792, -844225265, -845568726, -847150690, -848978083, -851057216, -853393769, -855992767, -858858568, -861994841, -865404558, -869089977, -873052634, -877293332, -881812135, -886608365, -891680595, -897026649, -902643604, -908527790, -914674798, -921079483, -927735974, -934637683, -941777319, -949146902, -956737777, -964540634, -972545527, -980741893, -989118580, -997663869, -1006365499, -1015210698, -1024186213, -1033278337, -1042472944, -1051755525, -1061111219, -1070524853, -1079980973, -1089463891, -1098957718, -1108446404, -1117913783, -1127343608, -1136719601, -1146025487, -1155245041, -1164362130, -1173360756, -1182225099, -1190939558, -1199488796, -1207857781, -1216031828, -1223996640, -1231738349, -1239243555, -1246499367, -1253493439, -1260214007, -1266649927, -1272790706, -1278626541, -1284148342, -1289347772, -1294217267, -1298750071, -1302940252, -1306782735, -1310273314, -1313408679, -1316186429, -1318605088, -1320664117, -1322363928, -1323705887, -1324692325, -1325326541, -1325612800, -1325556335, -1325163344, -1324440980, -1323397350, -1322041494, -1320383382, -1318433892, -1316204794, -1313708729, -1310959186, -1307970478, -1304757714, -1301336769, -1297724251, -1293937469, -1289994397, -1285913635, -1281714366, -1277416320, -1273039727, -1268605272, -1264134046, -1259647501, -1255167399, -1250715760, -1246314810, -1241986927, -1237754587, -1233640310, -1229666602, -1225855899, -1222230509, -1218812559, -1215623929, -1212686203, -1210020605, -1207647945, -1205588558, -1203862252, -1202488248, -1201485125, -1200870766, -1200662305, -1200876071, -1201527539, -1202631281, -1204200914, -1206249054, -1208787273, -1211826052, -1215374740, -1219441518, -1224033355, -1229155980, -1234813841, -1241010085, -1247746518, -1255023591, -1262840370, -1271194517, -1280082278, -1289498463, -1299436438, -1309888114, -1320843947, -1332292931, -1344222600, -1356619035, -1369466867, -1382749292, -1396448080, -1410543595, -1425014813, -1439839347, -1454993472, -1470452153, -1486189078, -1502176694, -1518386245, -1534787812, -1551350357, -1568041769, -1584828915, -1601677691, -1618553074, -1635419182, -1652239330, -1668976093, -1685591369, -1702046444, -1718302057, -1734318471, -1750055544, -1765472796, -1780529487, -1795184691, -1809397367, -1823126438, -1836330868, -1848969738, -1861002326, -1872388181, -1883087205, -1893059731, -1902266597, -1910669229, -1918229712, -1924910872, -1930676349, -1935490668, -1939319321, -1942128829, -1943886820, -1944562095, -1944124694, -1942545964, -1939798622, -1935856814, -1930696174, -1924293883, -1916628720, -1907681117, -1897433201, -1885868846, -1872973711, -1858735282, -1843142908, -1826187832, -1807863222, -1788164198, -1767087853, -1744633274, -1720801555, -1695595811, -1669021188, -1641084862, -1611796046, -1581165983, -1549207941, -1515937203, -1481371053, -1445528756, -1408431539, -1370102567, -1330566909, -1289851513, -1247985162, -1204998442, -1160923696, -1115794977, -1069648003, -1022520098, -974450144, -925478516, -875647027, -824998858, -773578497, -721431665, -668605249, -615147227, -561106590, -506533266, -451478044, -395992486, -340128851, -283940008, -227479349, -170800710, -113958275, -57006496, ], [0, 28506467, 57004886, 85487210, 113945402, 142371434, 170757293, 199094984, 227376532, 255593989, 283739434, 311804979, 339782769, 367664992, 395443876, 423111693, 450660767, 478083475, 505372248, 532519576, 559518014, 586360180, 613038764, 639546526, 665876302, 692021007, 717973637, 743727273, 769275084, 794610328, 819726359, 844616625, 869274674, 893694157, 917868827, 941792547, 965459287, 988863132, 1011998279, 1034859046, 1057439866, 1079735298, 1101740022, 1123448846, 1144856705, 1165958666, 1186749927, 1207225821, 1227381816, 1247213518, 1266716675, 1285887173, 1304721041, 1323214454, 1341363731, 1359165339, 1376615891, 1393712151, 1410451035, 1426829606, 1442845082, 1458494836, 1473776391, 1488687427, 1503225780, 1517389440, 1531176554, 1544585427, 1557614520, 1570262451, 1582527997, 1594410090, 1605907822, 1617020442, 1627747355, 1638088124, 1648042470, 1657610268, 1666791551, 1675586507, 1683995477, 1692018958, 1699657600, 1706912206, 1713783728, 1720273271, 1726382090, 1732111585, 1737463306, 1742438949, 1747040352, 1751269498, 1755128512, 1758619656, 1761745335, 1764508085, 1766910582, 1768955633, 1770646175, 1771985277, 1772976132, 1773622060, 1773926505, 1773893030, 1773525317, 1772827164, 1771802485, 1770455304, 1768789753, 1766810073, 1764520608, 1761925803, 1759030202, 1755838447, 1752355270, 1748585498, 1744534043, 1740205902, 1735606155, 1730739963, 1725612561, 1720229258, 1714595433, 1708716535, 1702598075, 1696245626, 1689664819, 1682861341, 1675840930, 1668609374, 1661172505, 1653536200, 1645706374, 1637688978, 1629489997, 1621115445, 1612571364, 1603863818, 1594998891, 1585982685, 1576821317, 1567520912, 1558087604, 1548527531, 1538846833, 1529051647, 1519148104, 1509142328, 1499040433, 1488848514, 1478572653, 1468218910, 1457793320, 1447301892, 1436750606, 1426145409, 1415492212, 1404796890, 1394065273, 1383303149, 1372516260, 1361710297, 1350890900, 1340063651, 1329234079, 1318407649, 1307589765, 1296785766, 1286000922, 1275240435, 1264509432, 1253812968, 1243156020, 1232543484, 1221980178, 1211470834, 1201020101, 1190632538, 1180312616, 1170064715, 1159893121, 1149802028, 1139795531, 1129877627, 1120052216, 1110323093, 1100693954, 1091168389, 1081749885, 1072441820, 1063247468, 1054169990, 1045212440, 1036377762, 1027668787, 1019088233, 1010638707, 1002322701, 994142591, 986100642, 978199000, 970439696, 962824644, 955355644, 948034377, 940862406, 933841181, 926972031, 920256170, 913694695, 907288585, 901038705, 894945802, 889010508, 883233340, 877614700, 872154876, 866854042, 861712261, 856729481, 851905542, 847240172, 842732991, 838383509, 834191130, 830155153, 826274770, 822549070, 818977042, 815557571, 812289445, 809171352, 806201884, 803379540, 800702724, 798169748, 795778835, 793528121, 791415653, 789439395, 787597230, 785886957, 784306299, 782852901, 781524335, 780318098, 779231619, 778262258, 777407308, 776664000, 776029501, 775500921, 775075311, 774749670, 774520942, 774386021, 774341756, 774384948, 774512357, 774720701, 775006661, 775366884, 775797981, 776296534, 776859098, 777482201, 778162348, 778896024, 779679695, 780509813, 781382815, 782295130, 783243177, 784223368, 785232116, 786265829, 787320919, 788393803, 789480903, 790578649, 791683486, 792791870, 793900274, 795005190, 796103128, 797190626, 798264243, 799320568, 800356219, 801367845, 802352131, 803305798, 804225603, 805108346, 805950869, 806750056, 807502840, 808206202, 808857171, 809452830, 809990315, 810466816, 810879583, 811225922, 811503200, 811708847, 811840356, 811895283, 811871253, 811765958, 811577157, 811302681, 810940433, 810488386, 809944589, 809307165, 808574313, 807744308, 806815503, 805786329, 804655297, 803420998, 802082101, 800637360, 799085608, 797425761, 795656819, 793777861, 791788055, 789686648, 787472974, 785146448, 782706571, 780152929, 777485189, 774703106, 771806516, 768795339, 765669581, 762429327, 759074748, 755606097, 752023708, 748327997, 744519461, 740598677, 736566302, 732423071, 728169798, 723807374, 719336765, 714759015, 710075241, 705286633, 700394455, 695400040, 690304792, 685110186, 679817762, 674429126, 668945952, 663369975, 657702993, 651946865, 646103509, 640174902, 634163074, 628070113, 621898159, 615649403, 609326085, 602930493, 596464963, 589931874, 583333647, 576672744, 569951668, 563172956, 556339182, 549452953, 542516906, 535533710, 528506059, 521436673, 514328296, 507183694, 500005650, 492796966, 485560461, 478298964, 471015318, 463712374, 456392991, 449060031, 441716363, 434364853, 427008369, 419649775, 412291930, 404937687, 397589887, 390251364, 382924936, 375613407, 368319565, 361046177, 353795992, 346571735, 339376104, 332211775, 325081393, 317987572, 310932897, 303919918, 296951147, 290029063, 283156103, 276334666, 269567107, 262855737, 256202823, 249610585, 243081194, 236616771, 230219386, 223891057, 217633748, 211449367, 205339766, 199306738, 193352018, 187477282, 181684143, 175974152, 170348798, 164809504, 159357629, 153994466, 148721241, 143539111, 138449167, 133452429, 128549848, 123742305, 119030610, 114415502, 109897647, 105477640, 101156003, 96933186, 92809566, 88785445, 84861055, 81036552, 77312019, 73687465, 70162829, 66737973, 63412688, 60186691, 57059628, 54031071, 51100522, 48267411, 45531096, 42890866, 40345940, 37895467, 35538527, 33274135, 31101236, 29018710, 27025371, 25119970, 23301192, 21567661, 19917940, 18350531, 16863875, 15456356, 14126303, 12871986, 11691622, 10583374, 9545355, 8575626, 7672198, 6833037, 6056060, 5339141, 4680110, 4076758, 3526832, 3028044, 2578068, 2174542, 1815073, 1497235, 1218571, 976600, 768810, 592667, 445614, 325074, 228448, 153125, 96473, 55851, 28605, 12071, 3577, 447, 0, -447, -3577, -12071, -28605, -55851, -96473, -153125, -228448, -325074, -445614, -592667, -768810, -976600, -1218571, -1497235, -1815073, -2174542, -2578068, -3028044, -3526832, -4076758, -4680110, -5339141, -6056060, -6833037, -7672198, -8575626, -9545355, -10583374, -11691622, -12871986, -14126303, -15456356, -16863875, -18350531, -19917940, -21567661, -23301192, -25119970, -27025371, -29018710, -31101236, -33274135, -35538527, -37895467, -40345940, -42890866, -45531096, -48267411, -51100522, -54031071, -57059628, -60186691, -63412688, -66737973, -70162829, -73687465, -77312019, -81036552, -84861055, -88785445, -92809566, -96933186, -101156003, -105477640, -109897647, -114415502, -119030610, -123742305, -128549848, -133452429, -138449167, -143539111, -148721241, -153994466, -159357629, -164809504, -170348798, -175974152, -181684143, -187477282, -193352018, -199306738, -205339766, -211449367, -217633748, -223891057, -230219386, -236616771, -243081194, -249610585, -256202823, -262855737, -269567107, -276334666, -283156103, -290029063, -296951147, -303919918, -310932897, -317987572, -325081393, -332211775, -339376104, -346571735, -353795992, -361046177, -368319565, -375613407, -382924936, -390251364, -397589887, -404937687, -412291930, -419649775, -427008369, -434364853, -441716363, -449060031, -456392991, -463712374, -471015318, -478298964, -485560461, -492796966, -500005650, -507183694, -514328296, -521436673, -528506059, -535533710, -542516906, -549452953, -556339182, -563172956, -569951668, -576672744, -583333647, -589931874, -596464963, -602930493, -609326085, -615649403, -621898159, -628070113, -634163074, -640174902, -646103509, -651946865, -657702993, -663369975, -668945952, -674429126, -679817762, -685110186, -690304792, -695400040, -700394455, -705286633, -710075241, -714759015, -719336765, -723807374, -728169798, -732423071, -736566302, -740598677, -744519461, -748327997, -752023708, -755606097, -759074748, -762429327, -765669581, -768795339, -771806516, -774703106, -777485189, -780152929, -782706571, -785146448, -787472974, -789686648, -791788055, -793777861, -795656819, -797425761, -799085608, -800637360, -802082101, -803420998, -804655297, -805786329, -806815503, -807744308, -808574313, -809307165, -809944589, -810488386, -810940433, -811302681, -811577157, -811765958, -811871253, -811895283, -811840356, -811708847, -811503200, -811225922, -810879583, -810466816, -809990315, -809452830, -808857171, -808206202, -807502840, -806750056, -805950869, -805108346, -804225603, -803305798, -802352131, -801367845, -800356219, -799320568, -798264243, -797190626, -796103128, -795005190, -793900274, -792791870, -791683486, -790578649, -789480903, -788393803, -787320919, -786265829, -785232116, -784223368, -783243177, -782295130, -781382815, -780509813, -779679695, -778896024, -778162348, -777482201, -776859098, -776296534, -775797981, -775366884, -775006661, -774720701, -774512357, -774384948, -774341756, -774386021, -774520942, -774749670, -775075311, -775500921, -776029501, -776664000, -777407308, -778262258, -779231619, -780318098, -781524335, -782852901, -784306299, -785886957, -787597230, -789439395, -791415653, -793528121, -795778835, -798169748, -800702724, -803379540, -806201884, -809171352, -812289445, -815557571, -818977042, -822549070, -826274770, -830155153, -834191130, -838383509, -842732991, -847240172, -851905542, -856729481, -861712261, -866854042, -872154876, -877614700, -883233340, -889010508, -894945802, -901038705, -907288585, -913694695, -920256170, -926972031, -933841181, -940862406, -948034377, -955355644, -962824644, -970439696, -978199000, -986100642, -994142591, -1002322701, -1010638707, -1019088233, -1027668787, -1036377762, -1045212440, -1054169990, -1063247468, -1072441820, -1081749885, -1091168389, -1100693954, -1110323093, -1120052216, -1129877627, -1139795531, -1149802028, -1159893121, -1170064715, -1180312616, -1190632538, -1201020101, -1211470834, -1221980178, -1232543484, -1243156020, -1253812968, -1264509432, -1275240435, -1286000922, -1296785766, -1307589765, -1318407649, -1329234079, -1340063651, -1350890900, -1361710297, -1372516260, -1383303149, -1394065273, -1404796890, -1415492212, -1426145409, -1436750606, -1447301892, -1457793320, -1468218910, -1478572653, -1488848514, -1499040433, -1509142328, -1519148104, -1529051647, -1538846833, -1548527531, -1558087604, -1567520912, -1576821317, -1585982685, -1594998891, -1603863818, -1612571364, -1621115445, -1629489997, -1637688978, -1645706374, -1653536200, -1661172505, -1668609374, -1675840930, -1682861341, -1689664819, -1696245626, -1702598075, -1708716535, -1714595433, -1720229258, -1725612561, -1730739963, -1735606155, -1740205902, -1744534043, -1748585498, -1752355270, -1755838447, -1759030202, -1761925803, -1764520608, -1766810073, -1768789753, -1770455304, -1771802485, -1772827164, -1773525317, -1773893030, -1773926505, -1773622060, -1772976132, -1771985277, -1770646175, -1768955633, -1766910582, -1764508085, -1761745335, -1758619656, -1755128512, -1751269498, -1747040352, -1742438949, -1737463306, -1732111585, -1726382090, -1720273271, -1713783728, -1706912206, -1699657600, -1692018958, -1683995477, -1675586507, -1666791551, -1657610268, -1648042470, -1638088124, -1627747355, -1617020442, -1605907822, -1594410090, -1582527997, -1570262451, -1557614520, -1544585427, -1531176554, -1517389440, -1503225780, -1488687427, -1473776391, -1458494836, -1442845082, -1426829606, -1410451035, -1393712151, -1376615891, -1359165339, -1341363731, -1323214454, -1304721041, -1285887173, -1266716675, -1247213518, -1227381816, -1207225821, -1186749927, -1165958666, -1144856705, -1123448846, -1101740022, -1079735298, -1057439866, -1034859046, -1011998279, -988863132, -965459287, -941792547, -917868827, -893694157, -869274674, -844616625, -819726359, -794610328, -769275084, -743727273, -717973637, -692021007, -665876302, -639546526, -613038764, -586360180, -559518014, -532519576, -505372248, -478083475, -450660767, -423111693, -395443876, -367664992, -339782769, -311804979, -283739434, -255593989, -227376532, -199094984, -170757293, -142371434, -113945402, -85487210, -57004886, -28506467, ], [0, 14253681, 28506020, 42755677, 57001309, 71241576, 85475139, 99700659, 113916797, 128122217, 142315583, 156495561, 170660820, 184810029, 198941859, 213054985, 227148084, 241219833, 255268916, 269294015, 283293820, 297267021, 311212312, 325128391, 339013960, 352867724, 366688393, 380474680, 394225304, 407938988, 421614458, 435250448, 448845695, 462398940, 475908933, 489374427, 502794180, 516166958, 529491532, 542766678, 555991181, 569163831, 582283422, 595348760, 608358654, 621311921, 634207385, 647043879, 659820242, 672535321, 685187970, 697777052, 710301439, 722760008, 735151647, 747475252, 759729728, 771913988, 784026954, 796067557, 808034737, 819927445, 831744639, 843485289, 855148371, 866732876, 878237801, 889662153, 901004953, 912265227, 923442016, 934534369, 945541347, 956462020, 967295470, 978040792, 988697087, 999263473, 1009739076, 1020123033, 1030414495, 1040612622, 1050716588, 1060725577, 1070638786, 1080455424, 1090174711, 1099795880, 1109318178, 1118740861, 1128063199, 1137284476, 1146403987, 1155421039, 1164334954, 1173145065, 1181850719, 1190451276, 1198946107, 1207334600, 1215616153, 1223790178, 1231856101, 1239813362, 1247661413, 1255399720, 1263027763, 1270545035, 1277951043, 1285245308, 1292427365, 1299496762, 1306453062, 1313295839, 1320024686, 1326639205, 1333139016, 1339523750, 1345793054, 1351946588, 1357984027, 1363905060, 1369709390, 1375396735, 1380966825, 1386419407, 1391754241, 1396971101, 1402069777, 1407050071, 1411911800, 1416654797, 1421278907, 1425783992, 1430169925, 1434436597, 1438583910, 1442611782, 1446520146, 1450308948, 1453978148, 1457527723, 1460957661, 1464267966, 1467458655, 1470529761, 1473481330, 1476313423, 1479026114, 1481619491, 1484093658, 1486448731, 1488684840, 1490802132, 1492800764, 1494680909, 1496442753, 1498086497, 1499612355, 1501020553, 1502311334, 1503484952, 1504541676, 1505481788, 1506305582, 1507013368, 1507605468, 1508082217, 1508443962, 1508691067, 1508823904, 1508842862, 1508748341, 1508540754, 1508220528, 1507788099, 1507243920, 1506588455, 1505822178, 1504945579, 1503959159, 1502863428, 1501658913, 1500346151, 1498925689, 1497398088, 1495763920, 1494023768, 1492178228, 1490227906, 1488173419, 1486015397, 1483754479, 1481391316, 1478926570, 1476360913, 1473695028, 1470929609, 1468065359, 1465102993, 1462043234, 1458886818, 1455634488, 1452286998, 1448845112, 1445309603, 1441681254, 1437960857, 1434149212, 1430247130, 1426255430, 1422174938, 1418006493, 1413750938, 1409409127, 1404981920, 1400470189, 1395874809, 1391196666, 1386436654, 1381595672, 1376674629, 1371674439, 1366596025, 1361440315, 1356208246, 1350900760, 1345518806, 1340063340, 1334535323, 1328935723, 1323265513, 1317525674, 1311717189, 1305841049, 1299898250, 1293889793, 1287816683, 1281679932, 1275480554, 1269219570, 1262898004, 1256516883, 1250077242, 1243580115, 1237026544, 1230417571, 1223754245, 1217037615, 1210268734, 1203448660, 1196578451, 1189659170, 1182691880, 1175677648, 1168617542, 1161512634, 1154363995, 1147172701, 1139939825, 1132666446, 1125353641, 1118002490, 1110614072, 1103189468, 1095729758, 1088236025, 1080709350, 1073150815, 1065561501, 1057942490, 1050294861, 1042619696, 1034918074, 1027191073, 1019439771, 1011665244, 1003868566, 996050811, 988213051, 980356353, 972481788, 964590418, 956683309, 948761519, 940826107, 932878128, 924918634, 916948674, 908969293, 900981533, 892986434, 884985030, 876978352, 868967427, 860953278, 852936923, 844919377, 836901649, 828884744, 820869660, 812857394, 804848935, 796845266, 788847368, 780856211, 772872765, 764897991, 756932844, 748978273, 741035223, 733104628, 725187420, 717284522, 709396850, 701525315, 693670818, 685834256, 678016516, 670218479, 662441019, 654685001, 646951282, 639240713, 631554135, 623892381, 616256278, 608646642, 601064281, 593509996, 585984578, 578488809, 571023463, 563589304, 556187089, 548817562, 541481461, 534179514, 526912438, 519680942, 512485724, 505327474, 498206871, 491124584, 484081272, 477077583, 470114157, 463191623, 456310597, 449471689, 442675495, 435922602, 429213585, 422549010, 415929431, 409355393, 402827426, 396346054, 389911786, 383525122, 377186551, 370896548, 364655581, 358464103, 352322558, 346231376, 340190979, 334201775, 328264161, 322378521, 316545231, 310764652, 305037135, 299363018, 293742628, 288176279, 282664277, 277206910, 271804460, 266457194, 261165366, 255929222, 250748992, 245624896, 240557143, 235545927, 230591433, 225693832, 220853284, 216069936, 211343925, 206675374, 202064394, 197511085, 193015535, 188577820, 184198002, 179876135, 175612256, 171406395, 167258567, 163168776, 159137014, 155163262, 151247487, 147389647, 143589686, 139847538, 136163123, 132536352, 128967123, 125455323, 122000825, 118603495, 115263184, 111979733, 108752970, 105582715, 102468773, 99410941, 96409002, 93462731, 90571889, 87736227, 84955487, 82229398, 79557678, 76940036, 74376169, 71865764, 69408498, 67004037, 64652036, 62352140, 60103986, 57907198, 55761392, 53666172, 51621135, 49625865, 47679939, 45782923, 43934374, 42133840, 40380859, 38674961, 37015664, 35402480, 33834912, 32312452, 30834585, 29400787, 28010527, 26663262, 25358444, 24095517, 22873914, 21693062, 20552382, 19451283, 18389170, 17365440, 16379481, 15430675, 14518398, 13642016, 12800891, 11994377, 11221821, 10482566, 9775945, 9101287, 8457916, 7845147, 7262292, 6708655, 6183537, 5686231, 5216027, 4772208, 4354053, 3960836, 3591826, 3246289, 2923483, 2622666, 2343088, 2083998, 1844640, 1624253, 1422074, 1237337, 1069271, 917103, 780057, 657353, 548210, 451843, 367465, 294288, 231520, 178368, 134037, 97730, 68650, 45997, 28969, 16766, 8585, 3622, 1073, 134, 0, -134, -1073, -3622, -8585, -16766, -28969, -45997, -68650, -97730, -134037, -178368, -231520, -294288, -367465, -451843, -548210, -657353, -780057, -917103, -1069271, -1237337, -1422074, -1624253, -1844640, -2083998, -2343088, -2622666, -2923483, -3246289, -3591826, -3960836, -4354053, -4772208, -5216027, -5686231, -6183537, -6708655, -7262292, -7845147, -8457916, -9101287, -9775945, -10482566, -11221821, -11994377, -12800891, -13642016, -14518398, -15430675, -16379481, -17365440, -18389170, -19451283, -20552382, -21693062, -22873914, -24095517, -25358444, -26663262, -28010527, -29400787, -30834585, -32312452, -33834912, -35402480, -37015664, -38674961, -40380859, -42133840, -43934374, -45782923, -47679939, -49625865, -51621135, -53666172, -55761392, -57907198, -60103986, -62352140, -64652036, -67004037, -69408498, -71865764, -74376169, -76940036, -79557678, -82229398, -84955487, -87736227, -90571889, -93462731, -96409002, -99410941, -102468773, -105582715, -108752970, -111979733, -115263184, -118603495, -122000825, -125455323, -128967123, -132536352, -136163123, -139847538, -143589686, -147389647, -151247487, -155163262, -159137014, -163168776, -167258567, -171406395, -175612256, -179876135, -184198002, -188577820, -193015535, -197511085, -202064394, -206675374, -211343925, -216069936, -220853284, -225693832, -230591433, -235545927, -240557143, -245624896, -250748992, -255929222, -261165366, -266457194, -271804460, -277206910, -282664277, -288176279, -293742628, -299363018, -305037135, -310764652, -316545231, -322378521, -328264161, -334201775, -340190979, -346231376, -352322558, -358464103, -364655581, -370896548, -377186551, -383525122, -389911786, -396346054, -402827426, -409355393, -415929431, -422549010, -429213585, -435922602, -442675495, -449471689, -456310597, -463191623, -470114157, -477077583, -484081272, -491124584, -498206871, -505327474, -512485724, -519680942, -526912438, -534179514, -541481461, -548817562, -556187089, -563589304, -571023463, -578488809, -585984578, -593509996, -601064281, -608646642, -616256278, -623892381, -631554135, -639240713, -646951282, -654685001, -662441019, -670218479, -678016516, -685834256, -693670818, -701525315, -709396850, -717284522, -725187420, -733104628, -741035223, -748978273, -756932844, -764897991, -772872765, -780856211, -788847368, -796845266, -804848935, -812857394, -820869660, -828884744, -836901649, -844919377, -852936923, -860953278, -868967427, -876978352, -884985030, -892986434, -900981533, -908969293, -916948674, -924918634, -932878128, -940826107, -948761519, -956683309, -964590418, -972481788, -980356353, -988213051, -996050811, -1003868566, -1011665244, -1019439771, -1027191073, -1034918074, -1042619696, -1050294861, -1057942490, -1065561501, -1073150815, -1080709350, -1088236025, -1095729758, -1103189468, -1110614072, -1118002490, -1125353641, -1132666446, -1139939825, -1147172701, -1154363995, -1161512634, -1168617542, -1175677648, -1182691880, -1189659170, -1196578451, -1203448660, -1210268734, -1217037615, -1223754245, -1230417571, -1237026544, -1243580115, -1250077242, -1256516883, -1262898004, -1269219570, -1275480554, -1281679932, -1287816683, -1293889793, -1299898250, -1305841049, -1311717189, -1317525674, -1323265513, -1328935723, -1334535323, -1340063340, -1345518806, -1350900760, -1356208246, -1361440315, -1366596025, -1371674439, -1376674629, -1381595672, -1386436654, -1391196666, -1395874809, -1400470189, -1404981920, -1409409127, -1413750938, -1418006493, -1422174938, -1426255430, -1430247130, -1434149212, -1437960857, -1441681254, -1445309603, -1448845112, -1452286998, -1455634488, -1458886818, -1462043234, -1465102993, -1468065359, -1470929609, -1473695028, -1476360913, -1478926570, -1481391316, -1483754479, -1486015397, -1488173419, -1490227906, -1492178228, -1494023768, -1495763920, -1497398088, -1498925689, -1500346151, -1501658913, -1502863428, -1503959159, -1504945579, -1505822178, -1506588455, -1507243920, -1507788099, -1508220528, -1508540754, -1508748341, -1508842862, -1508823904, -1508691067, -1508443962, -1508082217, -1507605468, -1507013368, -1506305582, -1505481788, -1504541676, -1503484952, -1502311334, -1501020553, -1499612355, -1498086497, -1496442753, -1494680909, -1492800764, -1490802132, -1488684840, -1486448731, -1484093658, -1481619491, -1479026114, -1476313423, -1473481330, -1470529761, -1467458655, -1464267966, -1460957661, -1457527723, -1453978148, -1450308948, -1446520146, -1442611782, -1438583910, -1434436597, -1430169925, -1425783992, -1421278907, -1416654797, -1411911800, -1407050071, -1402069777, -1396971101, -1391754241, -1386419407, -1380966825, -1375396735, -1369709390, -1363905060, -1357984027, -1351946588, -1345793054, -1339523750, -1333139016, -1326639205, -1320024686, -1313295839, -1306453062, -1299496762, -1292427365, -1285245308, -1277951043, -1270545035, -1263027763, -1255399720, -1247661413, -1239813362, -1231856101, -1223790178, -1215616153, -1207334600, -1198946107, -1190451276, -1181850719, -1173145065, -1164334954, -1155421039, -1146403987, -1137284476, -1128063199, -1118740861, -1109318178, -1099795880, -1090174711, -1080455424, -1070638786, -1060725577, -1050716588, -1040612622, -1030414495, -1020123033, -1009739076, -999263473, -988697087, -978040792, -967295470, -956462020, -945541347, -934534369, -923442016, -912265227, -901004953, -889662153, -878237801, -866732876, -855148371, -843485289, -831744639, -819927445, -808034737, -796067557, -784026954, -771913988, -759729728, -747475252, -735151647, -722760008, -710301439, -697777052, -685187970, -672535321, -659820242, -647043879, -634207385, -621311921, -608358654, -595348760, -582283422, -569163831, -555991181, -542766678, -529491532, -516166958, -502794180, -489374427, -475908933, -462398940, -448845695, -435250448, -421614458, -407938988, -394225304, -380474680, -366688393, -352867724, -339013960, -325128391, -311212312, -297267021, -283293820, -269294015, -255268916, -241219833, -227148084, -213054985, -198941859, -184810029, -170660820, -156495561, -142315583, -128122217, -113916797, -99700659, -85475139, -71241576, -57001309, -42755677, -28506020, -14253681, ], [0, 7126908, 14253547, 21379649, 28504947, 35629171, 42752054, 49873328, 56992724, 64109974, 71224810, 78336965, 85446170, 92552158, 99654662, 106753414, 113848147, 120938593, 128024486, 135105559, 142181546, 149252179, 156317193, 163376322, 170429300, 177475861, 184515740, 191548673, 198574394, 205592638, 212603142, 219605642, 226599874, 233585574, 240562480, 247530329, 254488859, 261437807, 268376912, 275305913, 282224549, 289132559, 296029684, 302915663, 309790237, 316653149, 323504138, 330342948, 337169320, 343982998, 350783726, 357571246, 364345305, 371105645, 377852014, 384584157, 391301821, 398004752, 404692699, 411365409, 418022632, 424664116, 431289612, 437898870, 444491641, 451067678, 457626732, 464168557, 470692906, 477199534, 483688195, 490158646, 496610643, 503043942, 509458303, 515853482, 522229240, 528585336, 534921531, 541237587, 547533265, 553808330, 560062543, 566295671, 572507478, 578697730, 584866194, 591012639, 597136833, 603238544, 609317544, 615373604, 621406495, 627415990, 633401864, 639363890, 645301845, 651215504, 657104646, 662969048, 668808489, 674622750, 680411612, 686174857, 691912268, 697623629, 703308725, 708967341, 714599265, 720204285, 725782190, 731332769, 736855814, 742351117, 747818471, 753257670, 758668509, 764050785, 769404295, 774728837, 780024210, 785290217, 790526658, 795733335, 800910054, 806056619, 811172837, 816258514, 821313460, 826337484, 831330396, 836292009, 841222137, 846120593, 850987193, 855821754, 860624093, 865394031, 870131387, 874835983, 879507642, 884146188, 888751446, 893323243, 897861408, 902365768, 906836155, 911272400, 915674336, 920041797, 924374619, 928672639, 932935695, 937163627, 941356275, 945513481, 949635090, 953720945, 957770893, 961784782, 965762459, 969703777, 973608586, 977476738, 981308090, 985102496, 988859813, 992579900, 996262617, 999907825, 1003515388, 1007085168, 1010617033, 1014110848, 1017566483, 1020983806, 1024362691, 1027703008, 1031004633, 1034267442, 1037491311, 1040676119, 1043821746, 1046928073, 1049994985, 1053022365, 1056010099, 1058958075, 1061866181, 1064734309, 1067562351, 1070350199, 1073097749, 1075804898, 1078471543, 1081097585, 1083682923, 1086227462, 1088731105, 1091193757, 1093615327, 1095995723, 1098334855, 1100632636, 1102888978, 1105103797, 1107277010, 1109408534, 1111498290, 1113546198, 1115552182, 1117516166, 1119438077, 1121317841, 1123155388, 1124950649, 1126703556, 1128414043, 1130082047, 1131707503, 1133290351, 1134830531, 1136327986, 1137782659, 1139194494, 1140563440, 1141889444, 1143172457, 1144412430, 1145609316, 1146763071, 1147873650, 1148941014, 1149965120, 1150945930, 1151883408, 1152777518, 1153628227, 1154435503, 1155199314, 1155919633, 1156596432, 1157229686, 1157819371, 1158365465, 1158867947, 1159326798, 1159742002, 1160113541, 1160441403, 1160725575, 1160966046, 1161162808, 1161315853, 1161425174, 1161490769, 1161512634, 1161490769, 1161425174, 1161315853, 1161162808, 1160966046, 1160725575, 1160441403, 1160113541, 1159742002, 1159326798, 1158867947, 1158365465, 1157819371, 1157229686, 1156596432, 1155919633, 1155199314, 1154435503, 1153628227, 1152777518, 1151883408, 1150945930, 1149965120, 1148941014, 1147873650, 1146763071, 1145609316, 1144412430, 1143172457, 1141889444, 1140563440, 1139194494, 1137782659, 1136327986, 1134830531, 1133290351, 1131707503, 1130082047, 1128414043, 1126703556, 1124950649, 1123155388, 1121317841, 1119438077, 1117516166, 1115552182, 1113546198, 1111498290, 1109408534, 1107277010, 1105103797, 1102888978, 1100632636, 1098334855, 1095995723, 1093615327, 1091193757, 1088731105, 1086227462, 1083682923, 1081097585, 1078471543, 1075804898, 1073097749, 1070350199, 1067562351, 1064734309, 1061866181, 1058958075, 1056010099, 1053022365, 1049994985, 1046928073, 1043821746, 1040676119, 1037491311, 1034267442, 1031004633, 1027703008, 1024362691, 1020983806, 1017566483, 1014110848, 1010617033, 1007085168, 1003515388, 999907825, 996262617, 992579900, 988859813, 985102496, 981308090, 977476738, 973608586, 969703777, 965762459, 961784782, 957770893, 953720945, 949635090, 945513481, 941356275, 937163627, 932935695, 928672639, 924374619, 920041797, 915674336, 911272400, 906836155, 902365768, 897861408, 893323243, 888751446, 884146188, 879507642, 874835983, 870131387, 865394031, 860624093, 855821754, 850987193, 846120593, 841222137, 836292009, 831330396, 826337484, 821313460, 816258514, 811172837, 806056619, 800910054, 795733335, 790526658, 785290217, 780024210, 774728837, 769404295, 764050785, 758668509, 753257670, 747818471, 742351117, 736855814, 731332769, 725782190, 720204285, 714599265, 708967341, 703308725, 697623629, 691912268, 686174857, 680411612, 674622750, 668808489, 662969048, 657104646, 651215504, 645301845, 639363890, 633401864, 627415990, 621406495, 615373604, 609317544, 603238544, 597136833, 591012639, 584866194, 578697730, 572507478, 566295671, 560062543, 553808330, 547533265, 541237587, 534921531, 528585336, 522229240, 515853482, 509458303, 503043942, 496610643, 490158646, 483688195, 477199534, 470692906, 464168557, 457626732, 451067678, 444491641, 437898870, 431289612, 424664116, 418022632, 411365409, 404692699, 398004752, 391301821, 384584157, 377852014, 371105645, 364345305, 357571246, 350783726, 343982998, 337169320, 330342948, 323504138, 316653149, 309790237, 302915663, 296029684, 289132559, 282224549, 275305913, 268376912, 261437807, 254488859, 247530329, 240562480, 233585574, 226599874, 219605642, 212603142, 205592638, 198574394, 191548673, 184515740, 177475861, 170429300, 163376322, 156317193, 149252179, 142181546, 135105559, 128024486, 120938593, 113848147, 106753414, 99654662, 92552158, 85446170, 78336965, 71224810, 64109974, 56992724, 49873328, 42752054, 35629171, 28504947, 21379649, 14253547, 7126908, 0, -7126908, -14253547, -21379649, -28504947, -35629171, -42752054, -49873328, -56992724, -64109974, -71224810, -78336965, -85446170, -92552158, -99654662, -106753414, -113848147, -120938593, -128024486, -135105559, -142181546, -149252179, -156317193, -163376322, -170429300, -177475861, -184515740, -191548673, -198574394, -205592638, -212603142, -219605642, -226599874, -233585574, -240562480, -247530329, -254488859, -261437807, -268376912, -275305913, -282224549, -289132559, -296029684, -302915663, -309790237, -316653149, -323504138, -330342948, -337169320, -343982998, -350783726, -357571246, -364345305, -371105645, -377852014, -384584157, -391301821, -398004752, -404692699, -411365409, -418022632, -424664116, -431289612, -437898870, -444491641, -451067678, -457626732, -464168557, -470692906, -477199534, -483688195, -490158646, -496610643, -503043942, -509458303, -515853482, -522229240, -528585336, -534921531, -541237587, -547533265, -553808330, -560062543, -566295671, -572507478, -578697730, -584866194, -591012639, -597136833, -603238544, -609317544, -615373604, -621406495, -627415990, -633401864, -639363890, -645301845, -651215504, -657104646, -662969048, -668808489, -674622750, -680411612, -686174857, -691912268, -697623629, -703308725, -708967341, -714599265, -720204285, -725782190, -731332769, -736855814, -742351117, -747818471, -753257670, -758668509, -764050785, -769404295, -774728837, -780024210, -785290217, -790526658, -795733335, -800910054, -806056619, -811172837, -816258514, -821313460, -826337484, -831330396, -836292009, -841222137, -846120593, -850987193, -855821754, -860624093, -865394031, -870131387, -874835983, -879507642, -884146188, -888751446, -893323243, -897861408, -902365768, -906836155, -911272400, -915674336, -920041797, -924374619, -928672639, -932935695, -937163627, -941356275, -945513481, -949635090, -953720945, -957770893, -961784782, -965762459, -969703777, -973608586, -977476738, -981308090, -985102496, -988859813, -992579900, -996262617, -999907825, -1003515388, -1007085168, -1010617033, -1014110848, -1017566483, -1020983806, -1024362691, -1027703008, -1031004633, -1034267442, -1037491311, -1040676119, -1043821746, -1046928073, -1049994985, -1053022365, -1056010099, -1058958075, -1061866181, -1064734309, -1067562351, -1070350199, -1073097749, -1075804898, -1078471543, -1081097585, -1083682923, -1086227462, -1088731105, -1091193757, -1093615327, -1095995723, -1098334855, -1100632636, -1102888978, -1105103797, -1107277010, -1109408534, -1111498290, -1113546198, -1115552182, -1117516166, -1119438077, -1121317841, -1123155388, -1124950649, -1126703556, -1128414043, -1130082047, -1131707503, -1133290351, -1134830531, -1136327986, -1137782659, -1139194494, -1140563440, -1141889444, -1143172457, -1144412430, -1145609316, -1146763071, -1147873650, -1148941014, -1149965120, -1150945930, -1151883408, -1152777518, -1153628227, -1154435503, -1155199314, -1155919633, -1156596432, -1157229686, -1157819371, -1158365465, -1158867947, -1159326798, -1159742002, -1160113541, -1160441403, -1160725575, -1160966046, -1161162808, -1161315853, -1161425174, -1161490769, -1161512634, -1161490769, -1161425174, -1161315853, -1161162808, -1160966046, -1160725575, -1160441403, -1160113541, -1159742002, -1159326798, -1158867947, -1158365465, -1157819371, -1157229686, -1156596432, -1155919633, -1155199314, -1154435503, -1153628227, -1152777518, -1151883408, -1150945930, -1149965120, -1148941014, -1147873650, -1146763071, -1145609316, -1144412430, -1143172457, -1141889444, -1140563440, -1139194494, -1137782659, -1136327986, -1134830531, -1133290351, -1131707503, -1130082047, -1128414043, -1126703556, -1124950649, -1123155388, -1121317841, -1119438077, -1117516166, -1115552182, -1113546198, -1111498290, -1109408534, -1107277010, -1105103797, -1102888978, -1100632636, -1098334855, -1095995723, -1093615327, -1091193757, -1088731105, -1086227462, -1083682923, -1081097585, -1078471543, -1075804898, -1073097749, -1070350199, -1067562351, -1064734309, -1061866181, -1058958075, -1056010099, -1053022365, -1049994985, -1046928073, -1043821746, -1040676119, -1037491311, -1034267442, -1031004633, -1027703008, -1024362691, -1020983806, -1017566483, -1014110848, -1010617033, -1007085168, -1003515388, -999907825, -996262617, -992579900, -988859813, -985102496, -981308090, -977476738, -973608586, -969703777, -965762459, -961784782, -957770893, -953720945, -949635090, -945513481, -941356275, -937163627, -932935695, -928672639, -924374619, -920041797, -915674336, -911272400, -906836155, -902365768, -897861408, -893323243, -888751446, -884146188, -879507642, -874835983, -870131387, -865394031, -860624093, -855821754, -850987193, -846120593, -841222137, -836292009, -831330396, -826337484, -821313460, -816258514, -811172837, -806056619, -800910054, -795733335, -790526658, -785290217, -780024210, -774728837, -769404295, -764050785, -758668509, -753257670, -747818471, -742351117, -736855814, -731332769, -725782190, -720204285, -714599265, -708967341, -703308725, -697623629, -691912268, -686174857, -680411612, -674622750, -668808489, -662969048, -657104646, -651215504, -645301845, -639363890, -633401864, -627415990, -621406495, -615373604, -609317544, -603238544, -597136833, -591012639, -584866194, -578697730, -572507478, -566295671, -560062543, -553808330, -547533265, -541237587, -534921531, -528585336, -522229240, -515853482, -509458303, -503043942, -496610643, -490158646, -483688195, -477199534, -470692906, -464168557, -457626732, -451067678, -444491641, -437898870, -431289612, -424664116, -418022632, -411365409, -404692699, -398004752, -391301821, -384584157, -377852014, -371105645, -364345305, -357571246, -350783726, -343982998, -337169320, -330342948, -323504138, -316653149, -309790237, -302915663, -296029684, -289132559, -282224549, -275305913, -268376912, -261437807, -254488859, -247530329, -240562480, -233585574, -226599874, -219605642, -212603142, -205592638, -198574394, -191548673, -184515740, -177475861, -170429300, -163376322, -156317193, -149252179, -142181546, -135105559, -128024486, -120938593, -113848147, -106753414, -99654662, -92552158, -85446170, -78336965, -71224810, -64109974, -56992724, -49873328, -42752054, -35629171, -28504947, -21379649, -14253547, -7126908, ], ];
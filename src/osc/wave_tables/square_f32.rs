@@ -0,0 +1,2 @@
+#[allow(clippy::approx_constant)]
+pub static SQUARE_F32: [[f32; 1024]; 10] = [[0.0, 0.92596954, 0.7090738, 0.83738387, 0.74607664, 0.8169874, 0.759011, 0.80804974, 0.76555765, 0.8030474, 0.76950455, 0.7998538, 0.77214175, 0.79763925, 0.77402765, 0.796014, 0.77544284, 0.79477066, 0.77654374, 0.793789, 0.77742445, 0.7929945, 0.77814484, 0.7923383, 0.778745, 0.7917873, 0.7792526, 0.7913182, 0.77968746, 0.790914, 0.7800641, 0.7905622, 0.7803935, 0.7902533, 0.7806838, 0.7899798, 0.7809417, 0.7897362, 0.7811723, 0.7895177, 0.7813796, 0.78932077, 0.7815669, 0.7891423, 0.7817371, 0.78897995, 0.78189224, 0.78883153, 0.7820343, 0.7886954, 0.7821648, 0.7885701, 0.78228515, 0.7884545, 0.78239644, 0.78834736, 0.7824996, 0.78824794, 0.7825955, 0.7881554, 0.78268486, 0.78806907, 0.7827683, 0.7879883, 0.7828464, 0.7879127, 0.78291965, 0.78784174, 0.7829884, 0.78777504, 0.78305316, 0.7877122, 0.7831142, 0.7876529, 0.7831718, 0.78759694, 0.78322625, 0.787544, 0.78327775, 0.7874938, 0.78332657, 0.78744626, 0.78337294, 0.7874011, 0.7834169, 0.78735816, 0.7834588, 0.78731734, 0.78349864, 0.7872785, 0.7835366, 0.7872414, 0.78357285, 0.787206, 0.7836074, 0.7871722, 0.78364044, 0.78713995, 0.783672, 0.7871091, 0.7837022, 0.7870795, 0.7837311, 0.7870512, 0.7837588, 0.7870241, 0.7837854, 0.78699803, 0.78381085, 0.7869731, 0.78383535, 0.78694916, 0.78385884, 0.7869261, 0.78388137, 0.786904, 0.78390306, 0.7868827, 0.7839239, 0.78686225, 0.783944, 0.7868426, 0.7839633, 0.78682363, 0.7839819, 0.78680533, 0.78399986, 0.78678775, 0.7840171, 0.7867708, 0.7840338, 0.7867545, 0.7840498, 0.78673875, 0.78406525, 0.78672355, 0.7840802, 0.7867089, 0.7840946, 0.7866947, 0.78410846, 0.78668106, 0.78412193, 0.7866679, 0.78413486, 0.7866552, 0.7841474, 0.7866429, 0.7841594, 0.78663105, 0.7841711, 0.78661954, 0.78418237, 0.7866085, 0.7841932, 0.7865978, 0.7842037, 0.7865875, 0.78421384, 0.7865775, 0.7842237, 0.78656787, 0.78423315, 0.78655857, 0.7842423, 0.7865496, 0.7842511, 0.7865409, 0.7842596, 0.7865325, 0.78426784, 0.7865245, 0.78427577, 0.78651667, 0.78428346, 0.78650916, 0.78429085, 0.7865019, 0.784298, 0.78649485, 0.78430486, 0.7864881, 0.7843115, 0.7864816, 0.7843179, 0.78647536, 0.78432405, 0.7864693, 0.78432995, 0.7864635, 0.7843357, 0.7864579, 0.78434116, 0.78645253, 0.7843464, 0.78644735, 0.78435147, 0.7864424, 0.7843563, 0.78643763, 0.784361, 0.7864331, 0.7843654, 0.78642875, 0.7843697, 0.7864246, 0.78437376, 0.7864206, 0.78437763, 0.78641677, 0.7843814, 0.7864132, 0.7843849, 0.78640974, 0.78438824, 0.78640646, 0.78439146, 0.78640336, 0.78439444, 0.78640044, 0.7843973, 0.7863977, 0.7844, 0.7863951, 0.7844025, 0.7863926, 0.7844048, 0.78639036, 0.784407, 0.7863883, 0.78440905, 0.7863863, 0.7844109, 0.7863845, 0.7844126, 0.7863829, 0.78441423, 0.7863814, 0.7844156, 0.78638005, 0.78441685, 0.78637886, 0.784418, 0.78637785, 0.78441894, 0.78637695, 0.7844198, 0.78637624, 0.78442043, 0.78637564, 0.78442097, 0.78637516, 0.7844213, 0.78637487, 0.7844215, 0.78637475, 0.7844216, 0.78637475, 0.7844215, 0.78637487, 0.7844213, 0.78637516, 0.78442097, 0.78637564, 0.78442043, 0.78637624, 0.7844198, 0.78637695, 0.78441894, 0.78637785, 0.784418, 0.78637886, 0.78441685, 0.78638005, 0.7844156, 0.7863814, 0.78441423, 0.7863829, 0.7844126, 0.7863845, 0.7844109, 0.7863863, 0.78440905, 0.7863883, 0.784407, 0.78639036, 0.7844048, 0.7863926, 0.7844025, 0.7863951, 0.7844, 0.7863977, 0.7843973, 0.78640044, 0.78439444, 0.78640336, 0.78439146, 0.78640646, 0.78438824, 0.78640974, 0.7843849, 0.7864132, 0.7843814, 0.78641677, 0.78437763, 0.7864206, 0.78437376, 0.7864246, 0.7843697, 0.78642875, 0.7843654, 0.7864331, 0.784361, 0.78643763, 0.7843563, 0.7864424, 0.78435147, 0.78644735, 0.7843464, 0.78645253, 0.78434116, 0.7864579, 0.7843357, 0.7864635, 0.78432995, 0.7864693, 0.78432405, 0.78647536, 0.7843179, 0.7864816, 0.7843115, 0.7864881, 0.78430486, 0.78649485, 0.784298, 0.7865019, 0.78429085, 0.78650916, 0.78428346, 0.78651667, 0.78427577, 0.7865245, 0.78426784, 0.7865325, 0.7842596, 0.7865409, 0.7842511, 0.7865496, 0.7842423, 0.78655857, 0.78423315, 0.78656787, 0.7842237, 0.7865775, 0.78421384, 0.7865875, 0.7842037, 0.7865978, 0.7841932, 0.7866085, 0.78418237, 0.78661954, 0.7841711, 0.78663105, 0.7841594, 0.7866429, 0.7841474, 0.7866552, 0.78413486, 0.7866679, 0.78412193, 0.78668106, 0.78410846, 0.7866947, 0.7840946, 0.7867089, 0.7840802, 0.78672355, 0.78406525, 0.78673875, 0.7840498, 0.7867545, 0.7840338, 0.7867708, 0.7840171, 0.78678775, 0.78399986, 0.78680533, 0.7839819, 0.78682363, 0.7839633, 0.7868426, 0.783944, 0.78686225, 0.7839239, 0.7868827, 0.78390306, 0.786904, 0.78388137, 0.7869261, 0.78385884, 0.78694916, 0.78383535, 0.7869731, 0.78381085, 0.78699803, 0.7837854, 0.7870241, 0.7837588, 0.7870512, 0.7837311, 0.7870795, 0.7837022, 0.7871091, 0.783672, 0.78713995, 0.78364044, 0.7871722, 0.7836074, 0.787206, 0.78357285, 0.7872414, 0.7835366, 0.7872785, 0.78349864, 0.78731734, 0.7834588, 0.78735816, 0.7834169, 0.7874011, 0.78337294, 0.78744626, 0.78332657, 0.7874938, 0.78327775, 0.787544, 0.78322625, 0.78759694, 0.7831718, 0.7876529, 0.7831142, 0.7877122, 0.78305316, 0.78777504, 0.7829884, 0.78784174, 0.78291965, 0.7879127, 0.7828464, 0.7879883, 0.7827683, 0.78806907, 0.78268486, 0.7881554, 0.7825955, 0.78824794, 0.7824996, 0.78834736, 0.78239644, 0.7884545, 0.78228515, 0.7885701, 0.7821648, 0.7886954, 0.7820343, 0.78883153, 0.78189224, 0.78897995, 0.7817371, 0.7891423, 0.7815669, 0.78932077, 0.7813796, 0.7895177, 0.7811723, 0.7897362, 0.7809417, 0.7899798, 0.7806838, 0.7902533, 0.7803935, 0.7905622, 0.7800641, 0.790914, 0.77968746, 0.7913182, 0.7792526, 0.7917873, 0.778745, 0.7923383, 0.77814484, 0.7929945, 0.77742445, 0.793789, 0.77654374, 0.79477066, 0.77544284, 0.796014, 0.77402765, 0.79763925, 0.77214175, 0.7998538, 0.76950455, 0.8030474, 0.76555765, 0.80804974, 0.759011, 0.8169874, 0.74607664, 0.83738387, 0.7090738, 0.92596954, 3.265935e-14, -0.92596954, -0.7090738, -0.83738387, -0.74607664, -0.8169874, -0.759011, -0.80804974, -0.76555765, -0.8030474, -0.76950455, -0.7998538, -0.77214175, -0.79763925, -0.77402765, -0.796014, -0.77544284, -0.79477066, -0.77654374, -0.793789, -0.77742445, -0.7929945, -0.77814484, -0.7923383, -0.778745, -0.7917873, -0.7792526, -0.7913182, -0.77968746, -0.790914, -0.7800641, -0.7905622, -0.7803935, -0.7902533, -0.7806838, -0.7899798, -0.7809417, -0.7897362, -0.7811723, -0.7895177, -0.7813796, -0.78932077, -0.7815669, -0.7891423, -0.7817371, -0.78897995, -0.78189224, -0.78883153, -0.7820343, -0.7886954, -0.7821648, -0.7885701, -0.78228515, -0.7884545, -0.78239644, -0.78834736, -0.7824996, -0.78824794, -0.7825955, -0.7881554, -0.78268486, -0.78806907, -0.7827683, -0.7879883, -0.7828464, -0.7879127, -0.78291965, -0.78784174, -0.7829884, -0.78777504, -0.78305316, -0.7877122, -0.7831142, -0.7876529, -0.7831718, -0.78759694, -0.78322625, -0.787544, -0.78327775, -0.7874938, -0.78332657, -0.78744626, -0.78337294, -0.7874011, -0.7834169, -0.78735816, -0.7834588, -0.78731734, -0.78349864, -0.7872785, -0.7835366, -0.7872414, -0.78357285, -0.787206, -0.7836074, -0.7871722, -0.78364044, -0.78713995, -0.783672, -0.7871091, -0.7837022, -0.7870795, -0.7837311, -0.7870512, -0.7837588, -0.7870241, -0.7837854, -0.78699803, -0.78381085, -0.7869731, -0.78383535, -0.78694916, -0.78385884, -0.7869261, -0.78388137, -0.786904, -0.78390306, -0.7868827, -0.7839239, -0.78686225, -0.783944, -0.7868426, -0.7839633, -0.78682363, -0.7839819, -0.78680533, -0.78399986, -0.78678775, -0.7840171, -0.7867708, -0.7840338, -0.7867545, -0.7840498, -0.78673875, -0.78406525, -0.78672355, -0.7840802, -0.7867089, -0.7840946, -0.7866947, -0.78410846, -0.78668106, -0.78412193, -0.7866679, -0.78413486, -0.7866552, -0.7841474, -0.7866429, -0.7841594, -0.78663105, -0.7841711, -0.78661954, -0.78418237, -0.7866085, -0.7841932, -0.7865978, -0.7842037, -0.7865875, -0.78421384, -0.7865775, -0.7842237, -0.78656787, -0.78423315, -0.78655857, -0.7842423, -0.7865496, -0.7842511, -0.7865409, -0.7842596, -0.7865325, -0.78426784, -0.7865245, -0.78427577, -0.78651667, -0.78428346, -0.78650916, -0.78429085, -0.7865019, -0.784298, -0.78649485, -0.78430486, -0.7864881, -0.7843115, -0.7864816, -0.7843179, -0.78647536, -0.78432405, -0.7864693, -0.78432995, -0.7864635, -0.7843357, -0.7864579, -0.78434116, -0.78645253, -0.7843464, -0.78644735, -0.78435147, -0.7864424, -0.7843563, -0.78643763, -0.784361, -0.7864331, -0.7843654, -0.78642875, -0.7843697, -0.7864246, -0.78437376, -0.7864206, -0.78437763, -0.78641677, -0.7843814, -0.7864132, -0.7843849, -0.78640974, -0.78438824, -0.78640646, -0.78439146, -0.78640336, -0.78439444, -0.78640044, -0.7843973, -0.7863977, -0.7844, -0.7863951, -0.7844025, -0.7863926, -0.7844048, -0.78639036, -0.784407, -0.7863883, -0.78440905, -0.7863863, -0.7844109, -0.7863845, -0.7844126, -0.7863829, -0.78441423, -0.7863814, -0.7844156, -0.78638005, -0.78441685, -0.78637886, -0.784418, -0.78637785, -0.78441894, -0.78637695, -0.7844198, -0.78637624, -0.78442043, -0.78637564, -0.78442097, -0.78637516, -0.7844213, -0.78637487, -0.7844215, -0.78637475, -0.7844216, -0.78637475, -0.7844215, -0.78637487, -0.7844213, -0.78637516, -0.78442097, -0.78637564, -0.78442043, -0.78637624, -0.7844198, -0.78637695, -0.78441894, -0.78637785, -0.784418, -0.78637886, -0.78441685, -0.78638005, -0.7844156, -0.7863814, -0.78441423, -0.7863829, -0.7844126, -0.7863845, -0.7844109, -0.7863863, -0.78440905, -0.7863883, -0.784407, -0.78639036, -0.7844048, -0.7863926, -0.7844025, -0.7863951, -0.7844, -0.7863977, -0.7843973, -0.78640044, -0.78439444, -0.78640336, -0.78439146, -0.78640646, -0.78438824, -0.78640974, -0.7843849, -0.7864132, -0.7843814, -0.78641677, -0.78437763, -0.7864206, -0.78437376, -0.7864246, -0.7843697, -0.78642875, -0.7843654, -0.7864331, -0.784361, -0.78643763, -0.7843563, -0.7864424, -0.78435147, -0.78644735, -0.7843464, -0.78645253, -0.78434116, -0.7864579, -0.7843357, -0.7864635, -0.78432995, -0.7864693, -0.78432405, -0.78647536, -0.7843179, -0.7864816, -0.7843115, -0.7864881, -0.78430486, -0.78649485, -0.784298, -0.7865019, -0.78429085, -0.78650916, -0.78428346, -0.78651667, -0.78427577, -0.7865245, -0.78426784, -0.7865325, -0.7842596, -0.7865409, -0.7842511, -0.7865496, -0.7842423, -0.78655857, -0.78423315, -0.78656787, -0.7842237, -0.7865775, -0.78421384, -0.7865875, -0.7842037, -0.7865978, -0.7841932, -0.7866085, -0.78418237, -0.78661954, -0.7841711, -0.78663105, -0.7841594, -0.7866429, -0.7841474, -0.7866552, -0.78413486, -0.7866679, -0.78412193, -0.78668106, -0.78410846, -0.7866947, -0.7840946, -0.7867089, -0.7840802, -0.78672355, -0.78406525, -0.78673875, -0.7840498, -0.7867545, -0.7840338, -0.7867708, -0.7840171, -0.78678775, -0.78399986, -0.78680533, -0.7839819, -0.78682363, -0.7839633, -0.7868426, -0.783944, -0.78686225, -0.7839239, -0.7868827, -0.78390306, -0.786904, -0.78388137, -0.7869261, -0.78385884, -0.78694916, -0.78383535, -0.7869731, -0.78381085, -0.78699803, -0.7837854, -0.7870241, -0.7837588, -0.7870512, -0.7837311, -0.7870795, -0.7837022, -0.7871091, -0.783672, -0.78713995, -0.78364044, -0.7871722, -0.7836074, -0.787206, -0.78357285, -0.7872414, -0.7835366, -0.7872785, -0.78349864, -0.78731734, -0.7834588, -0.78735816, -0.7834169, -0.7874011, -0.78337294, -0.78744626, -0.78332657, -0.7874938, -0.78327775, -0.787544, -0.78322625, -0.78759694, -0.7831718, -0.7876529, -0.7831142, -0.7877122, -0.78305316, -0.78777504, -0.7829884, -0.78784174, -0.78291965, -0.7879127, -0.7828464, -0.7879883, -0.7827683, -0.78806907, -0.78268486, -0.7881554, -0.7825955, -0.78824794, -0.7824996, -0.78834736, -0.78239644, -0.7884545, -0.78228515, -0.7885701, -0.7821648, -0.7886954, -0.7820343, -0.78883153, -0.78189224, -0.78897995, -0.7817371, -0.7891423, -0.7815669, -0.78932077, -0.7813796, -0.7895177, -0.7811723, -0.7897362, -0.7809417, -0.7899798, -0.7806838, -0.7902533, -0.7803935, -0.7905622, -0.7800641, -0.790914, -0.77968746, -0.7913182, -0.7792526, -0.7917873, -0.778745, -0.7923383, -0.77814484, -0.7929945, -0.77742445, -0.793789, -0.77654374, -0.79477066, -0.77544284, -0.796014, -0.77402765, -0.79763925, -0.77214175, -0.7998538, -0.76950455, -0.8030474, -0.76555765, -0.80804974, -0.759011, -0.8169874, -0.74607664, -0.83738387, -0.7090738, -0.92596954, ], [0.0, 0.68538237, 0.9259725, 0.8041851, 0.7090678, 0.7779168, 0.83739287, 0.7893533, 0.7460646, 0.78296643, 0.8170024, 0.78703994, 0.75899297, 0.7842169, 0.8080707, 0.78628814, 0.7655336, 0.78470397, 0.8030745, 0.78595454, 0.7694745, 0.78494245, 0.7998868, 0.7857782, 0.7721057, 0.78507644, 0.79767835, 0.7856739, 0.7739855, 0.7851592, 0.79605913, 0.7856072, 0.7753946, 0.78521377, 0.7948219, 0.785562, 0.77648944, 0.7852517, 0.7938464, 0.7855299, 0.77736396, 0.7852791, 0.79305804, 0.7855063, 0.77807826, 0.78529954, 0.79240805, 0.7854885, 0.77867216, 0.78531516, 0.79186326, 0.78547466, 0.77917355, 0.78532743, 0.79140043, 0.78546375, 0.7796021, 0.78533715, 0.7910025, 0.785455, 0.77997243, 0.7853451, 0.79065704, 0.78544784, 0.7802954, 0.7853516, 0.79035455, 0.78544194, 0.7805793, 0.785357, 0.7900876, 0.785437, 0.78083074, 0.7853615, 0.7898505, 0.7854328, 0.78105474, 0.7853654, 0.7896386, 0.78542924, 0.7812554, 0.7853687, 0.78944826, 0.7854262, 0.7814361, 0.78537154, 0.78927654, 0.7854235, 0.78159946, 0.785374, 0.789121, 0.7854212, 0.78174776, 0.7853762, 0.7889794, 0.78541917, 0.78188294, 0.7853781, 0.78885025, 0.7854174, 0.78200644, 0.78537977, 0.78873205, 0.78541577, 0.7821197, 0.78538126, 0.7886235, 0.7854144, 0.78222376, 0.78538257, 0.7885237, 0.7854131, 0.7823196, 0.7853838, 0.7884316, 0.78541195, 0.7824081, 0.7853849, 0.78834647, 0.78541094, 0.78248996, 0.78538585, 0.78826773, 0.78541, 0.7825658, 0.78538674, 0.7881947, 0.78540915, 0.78263617, 0.7853876, 0.7881269, 0.7854084, 0.7827015, 0.7853883, 0.7880639, 0.78540766, 0.7827623, 0.785389, 0.78800523, 0.785407, 0.7828189, 0.7853896, 0.78795063, 0.7854064, 0.7828716, 0.7853902, 0.78789973, 0.7854059, 0.7829208, 0.78539073, 0.7878523, 0.78540534, 0.7829666, 0.7853912, 0.787808, 0.78540486, 0.78300935, 0.7853917, 0.7877667, 0.7854044, 0.7830492, 0.78539217, 0.7877282, 0.78540397, 0.7830864, 0.7853925, 0.78769225, 0.7854036, 0.7831211, 0.78539294, 0.78765875, 0.7854032, 0.7831535, 0.7853933, 0.7876275, 0.7854029, 0.7831836, 0.7853936, 0.7875985, 0.78540254, 0.7832116, 0.78539395, 0.78757143, 0.78540224, 0.78323764, 0.78539425, 0.7875464, 0.78540194, 0.7832618, 0.78539455, 0.78752315, 0.78540164, 0.7832842, 0.7853948, 0.78750163, 0.7854014, 0.7833048, 0.7853951, 0.7874818, 0.7854011, 0.7833238, 0.7853953, 0.7874636, 0.78540087, 0.7833413, 0.78539556, 0.7874469, 0.7854006, 0.7833572, 0.7853958, 0.7874317, 0.7854004, 0.7833717, 0.78539604, 0.78741795, 0.7854002, 0.78338474, 0.7853962, 0.78740555, 0.7854, 0.7833964, 0.78539646, 0.7873945, 0.78539973, 0.7834068, 0.7853967, 0.7873848, 0.78539956, 0.7834159, 0.7853969, 0.7873764, 0.7853994, 0.7834237, 0.78539705, 0.7873692, 0.78539914, 0.7834303, 0.7853973, 0.78736323, 0.78539896, 0.78343564, 0.78539747, 0.78735846, 0.7853988, 0.78343976, 0.78539765, 0.78735495, 0.7853986, 0.78344274, 0.7853978, 0.78735256, 0.7853984, 0.78344446, 0.785398, 0.7873514, 0.7853982, 0.78344506, 0.7853982, 0.7873514, 0.785398, 0.78344446, 0.7853984, 0.78735256, 0.7853978, 0.78344274, 0.7853986, 0.78735495, 0.78539765, 0.78343976, 0.7853988, 0.78735846, 0.78539747, 0.78343564, 0.78539896, 0.78736323, 0.7853973, 0.7834303, 0.78539914, 0.7873692, 0.78539705, 0.7834237, 0.7853994, 0.7873764, 0.7853969, 0.7834159, 0.78539956, 0.7873848, 0.7853967, 0.7834068, 0.78539973, 0.7873945, 0.78539646, 0.7833964, 0.7854, 0.78740555, 0.7853962, 0.78338474, 0.7854002, 0.78741795, 0.78539604, 0.7833717, 0.7854004, 0.7874317, 0.7853958, 0.7833572, 0.7854006, 0.7874469, 0.78539556, 0.7833413, 0.78540087, 0.7874636, 0.7853953, 0.7833238, 0.7854011, 0.7874818, 0.7853951, 0.7833048, 0.7854014, 0.78750163, 0.7853948, 0.7832842, 0.78540164, 0.78752315, 0.78539455, 0.7832618, 0.78540194, 0.7875464, 0.78539425, 0.78323764, 0.78540224, 0.78757143, 0.78539395, 0.7832116, 0.78540254, 0.7875985, 0.7853936, 0.7831836, 0.7854029, 0.7876275, 0.7853933, 0.7831535, 0.7854032, 0.78765875, 0.78539294, 0.7831211, 0.7854036, 0.78769225, 0.7853925, 0.7830864, 0.78540397, 0.7877282, 0.78539217, 0.7830492, 0.7854044, 0.7877667, 0.7853917, 0.78300935, 0.78540486, 0.787808, 0.7853912, 0.7829666, 0.78540534, 0.7878523, 0.78539073, 0.7829208, 0.7854059, 0.78789973, 0.7853902, 0.7828716, 0.7854064, 0.78795063, 0.7853896, 0.7828189, 0.785407, 0.78800523, 0.785389, 0.7827623, 0.78540766, 0.7880639, 0.7853883, 0.7827015, 0.7854084, 0.7881269, 0.7853876, 0.78263617, 0.78540915, 0.7881947, 0.78538674, 0.7825658, 0.78541, 0.78826773, 0.78538585, 0.78248996, 0.78541094, 0.78834647, 0.7853849, 0.7824081, 0.78541195, 0.7884316, 0.7853838, 0.7823196, 0.7854131, 0.7885237, 0.78538257, 0.78222376, 0.7854144, 0.7886235, 0.78538126, 0.7821197, 0.78541577, 0.78873205, 0.78537977, 0.78200644, 0.7854174, 0.78885025, 0.7853781, 0.78188294, 0.78541917, 0.7889794, 0.7853762, 0.78174776, 0.7854212, 0.789121, 0.785374, 0.78159946, 0.7854235, 0.78927654, 0.78537154, 0.7814361, 0.7854262, 0.78944826, 0.7853687, 0.7812554, 0.78542924, 0.7896386, 0.7853654, 0.78105474, 0.7854328, 0.7898505, 0.7853615, 0.78083074, 0.785437, 0.7900876, 0.785357, 0.7805793, 0.78544194, 0.79035455, 0.7853516, 0.7802954, 0.78544784, 0.79065704, 0.7853451, 0.77997243, 0.785455, 0.7910025, 0.78533715, 0.7796021, 0.78546375, 0.79140043, 0.78532743, 0.77917355, 0.78547466, 0.79186326, 0.78531516, 0.77867216, 0.7854885, 0.79240805, 0.78529954, 0.77807826, 0.7855063, 0.79305804, 0.7852791, 0.77736396, 0.7855299, 0.7938464, 0.7852517, 0.77648944, 0.785562, 0.7948219, 0.78521377, 0.7753946, 0.7856072, 0.79605913, 0.7851592, 0.7739855, 0.7856739, 0.79767835, 0.78507644, 0.7721057, 0.7857782, 0.7998868, 0.78494245, 0.7694745, 0.78595454, 0.8030745, 0.78470397, 0.7655336, 0.78628814, 0.8080707, 0.7842169, 0.75899297, 0.78703994, 0.8170024, 0.78296643, 0.7460646, 0.7893533, 0.83739287, 0.7779168, 0.7090678, 0.8041851, 0.9259725, 0.68538237, 1.6093909e-14, -0.68538237, -0.9259725, -0.8041851, -0.7090678, -0.7779168, -0.83739287, -0.7893533, -0.7460646, -0.78296643, -0.8170024, -0.78703994, -0.75899297, -0.7842169, -0.8080707, -0.78628814, -0.7655336, -0.78470397, -0.8030745, -0.78595454, -0.7694745, -0.78494245, -0.7998868, -0.7857782, -0.7721057, -0.78507644, -0.79767835, -0.7856739, -0.7739855, -0.7851592, -0.79605913, -0.7856072, -0.7753946, -0.78521377, -0.7948219, -0.785562, -0.77648944, -0.7852517, -0.7938464, -0.7855299, -0.77736396, -0.7852791, -0.79305804, -0.7855063, -0.77807826, -0.78529954, -0.79240805, -0.7854885, -0.77867216, -0.78531516, -0.79186326, -0.78547466, -0.77917355, -0.78532743, -0.79140043, -0.78546375, -0.7796021, -0.78533715, -0.7910025, -0.785455, -0.77997243, -0.7853451, -0.79065704, -0.78544784, -0.7802954, -0.7853516, -0.79035455, -0.78544194, -0.7805793, -0.785357, -0.7900876, -0.785437, -0.78083074, -0.7853615, -0.7898505, -0.7854328, -0.78105474, -0.7853654, -0.7896386, -0.78542924, -0.7812554, -0.7853687, -0.78944826, -0.7854262, -0.7814361, -0.78537154, -0.78927654, -0.7854235, -0.78159946, -0.785374, -0.789121, -0.7854212, -0.78174776, -0.7853762, -0.7889794, -0.78541917, -0.78188294, -0.7853781, -0.78885025, -0.7854174, -0.78200644, -0.78537977, -0.78873205, -0.78541577, -0.7821197, -0.78538126, -0.7886235, -0.7854144, -0.78222376, -0.78538257, -0.7885237, -0.7854131, -0.7823196, -0.7853838, -0.7884316, -0.78541195, -0.7824081, -0.7853849, -0.78834647, -0.78541094, -0.78248996, -0.78538585, -0.78826773, -0.78541, -0.7825658, -0.78538674, -0.7881947, -0.78540915, -0.78263617, -0.7853876, -0.7881269, -0.7854084, -0.7827015, -0.7853883, -0.7880639, -0.78540766, -0.7827623, -0.785389, -0.78800523, -0.785407, -0.7828189, -0.7853896, -0.78795063, -0.7854064, -0.7828716, -0.7853902, -0.78789973, -0.7854059, -0.7829208, -0.78539073, -0.7878523, -0.78540534, -0.7829666, -0.7853912, -0.787808, -0.78540486, -0.78300935, -0.7853917, -0.7877667, -0.7854044, -0.7830492, -0.78539217, -0.7877282, -0.78540397, -0.7830864, -0.7853925, -0.78769225, -0.7854036, -0.7831211, -0.78539294, -0.78765875, -0.7854032, -0.7831535, -0.7853933, -0.7876275, -0.7854029, -0.7831836, -0.7853936, -0.7875985, -0.78540254, -0.7832116, -0.78539395, -0.78757143, -0.78540224, -0.78323764, -0.78539425, -0.7875464, -0.78540194, -0.7832618, -0.78539455, -0.78752315, -0.78540164, -0.7832842, -0.7853948, -0.78750163, -0.7854014, -0.7833048, -0.7853951, -0.7874818, -0.7854011, -0.7833238, -0.7853953, -0.7874636, -0.78540087, -0.7833413, -0.78539556, -0.7874469, -0.7854006, -0.7833572, -0.7853958, -0.7874317, -0.7854004, -0.7833717, -0.78539604, -0.78741795, -0.7854002, -0.78338474, -0.7853962, -0.78740555, -0.7854, -0.7833964, -0.78539646, -0.7873945, -0.78539973, -0.7834068, -0.7853967, -0.7873848, -0.78539956, -0.7834159, -0.7853969, -0.7873764, -0.7853994, -0.7834237, -0.78539705, -0.7873692, -0.78539914, -0.7834303, -0.7853973, -0.78736323, -0.78539896, -0.78343564, -0.78539747, -0.78735846, -0.7853988, -0.78343976, -0.78539765, -0.78735495, -0.7853986, -0.78344274, -0.7853978, -0.78735256, -0.7853984, -0.78344446, -0.785398, -0.7873514, -0.7853982, -0.78344506, -0.7853982, -0.7873514, -0.785398, -0.78344446, -0.7853984, -0.78735256, -0.7853978, -0.78344274, -0.7853986, -0.78735495, -0.78539765, -0.78343976, -0.7853988, -0.78735846, -0.78539747, -0.78343564, -0.78539896, -0.78736323, -0.7853973, -0.7834303, -0.78539914, -0.7873692, -0.78539705, -0.7834237, -0.7853994, -0.7873764, -0.7853969, -0.7834159, -0.78539956, -0.7873848, -0.7853967, -0.7834068, -0.78539973, -0.7873945, -0.78539646, -0.7833964, -0.7854, -0.78740555, -0.7853962, -0.78338474, -0.7854002, -0.78741795, -0.78539604, -0.7833717, -0.7854004, -0.7874317, -0.7853958, -0.7833572, -0.7854006, -0.7874469, -0.78539556, -0.7833413, -0.78540087, -0.7874636, -0.7853953, -0.7833238, -0.7854011, -0.7874818, -0.7853951, -0.7833048, -0.7854014, -0.78750163, -0.7853948, -0.7832842, -0.78540164, -0.78752315, -0.78539455, -0.7832618, -0.78540194, -0.7875464, -0.78539425, -0.78323764, -0.78540224, -0.78757143, -0.78539395, -0.7832116, -0.78540254, -0.7875985, -0.7853936, -0.7831836, -0.7854029, -0.7876275, -0.7853933, -0.7831535, -0.7854032, -0.78765875, -0.78539294, -0.7831211, -0.7854036, -0.78769225, -0.7853925, -0.7830864, -0.78540397, -0.7877282, -0.78539217, -0.7830492, -0.7854044, -0.7877667, -0.7853917, -0.78300935, -0.78540486, -0.787808, -0.7853912, -0.7829666, -0.78540534, -0.7878523, -0.78539073, -0.7829208, -0.7854059, -0.78789973, -0.7853902, -0.7828716, -0.7854064, -0.78795063, -0.7853896, -0.7828189, -0.785407, -0.78800523, -0.785389, -0.7827623, -0.78540766, -0.7880639, -0.7853883, -0.7827015, -0.7854084, -0.7881269, -0.7853876, -0.78263617, -0.78540915, -0.7881947, -0.78538674, -0.7825658, -0.78541, -0.78826773, -0.78538585, -0.78248996, -0.78541094, -0.78834647, -0.7853849, -0.7824081, -0.78541195, -0.7884316, -0.7853838, -0.7823196, -0.7854131, -0.7885237, -0.78538257, -0.78222376, -0.7854144, -0.7886235, -0.78538126, -0.7821197, -0.78541577, -0.78873205, -0.78537977, -0.78200644, -0.7854174, -0.78885025, -0.7853781, -0.78188294, -0.78541917, -0.7889794, -0.7853762, -0.78174776, -0.7854212, -0.789121, -0.785374, -0.78159946, -0.7854235, -0.78927654, -0.78537154, -0.7814361, -0.7854262, -0.78944826, -0.7853687, -0.7812554, -0.78542924, -0.7896386, -0.7853654, -0.78105474, -0.7854328, -0.7898505, -0.7853615, -0.78083074, -0.785437, -0.7900876, -0.785357, -0.7805793, -0.78544194, -0.79035455, -0.7853516, -0.7802954, -0.78544784, -0.79065704, -0.7853451, -0.77997243, -0.785455, -0.7910025, -0.78533715, -0.7796021, -0.78546375, -0.79140043, -0.78532743, -0.77917355, -0.78547466, -0.79186326, -0.78531516, -0.77867216, -0.7854885, -0.79240805, -0.78529954, -0.77807826, -0.7855063, -0.79305804, -0.7852791, -0.77736396, -0.7855299, -0.7938464, -0.7852517, -0.77648944, -0.785562, -0.7948219, -0.78521377, -0.7753946, -0.7856072, -0.79605913, -0.7851592, -0.7739855, -0.7856739, -0.79767835, -0.78507644, -0.7721057, -0.7857782, -0.7998868, -0.78494245, -0.7694745, -0.78595454, -0.8030745, -0.78470397, -0.7655336, -0.78628814, -0.8080707, -0.7842169, -0.75899297, -0.78703994, -0.8170024, -0.78296643, -0.7460646, -0.7893533, -0.83739287, -0.7779168, -0.7090678, -0.8041851, -0.9259725, -0.68538237, ], [0.0, 0.3794887, 0.6853862, 0.8698863, 0.9259845, 0.88585603, 0.8041813, 0.7344808, 0.7090438, 0.73060256, 0.7779206, 0.8209662, 0.83742887, 0.82268006, 0.7893495, 0.7581925, 0.7460166, 0.7572308, 0.7829703, 0.8073973, 0.81706244, 0.80801165, 0.7870361, 0.76693785, 0.75892085, 0.7665119, 0.7842207, 0.8013024, 0.808155, 0.8016148, 0.78628427, 0.771424, 0.76543725, 0.77118516, 0.7847079, 0.7978651, 0.8031831, 0.79805344, 0.7859506, 0.7741398, 0.7693536, 0.7739876, 0.7849463, 0.7956665, 0.80002004, 0.79579204, 0.7857743, 0.77595484, 0.7719601, 0.77584964, 0.78508043, 0.79414374, 0.7978365, 0.79423314, 0.7856699, 0.7772499, 0.7738148, 0.77717304, 0.7851632, 0.7930297, 0.79624254, 0.7930964, 0.78560317, 0.7782178, 0.77519846, 0.7781593, 0.7852179, 0.79218155, 0.79503095, 0.7922331, 0.7855578, 0.77896655, 0.7762674, 0.7789207, 0.78525585, 0.7915162, 0.7940815, 0.79155713, 0.7855257, 0.7795613, 0.77711564, 0.7795245, 0.7852833, 0.7909818, 0.79331976, 0.79101497, 0.785502, 0.78004366, 0.777803, 0.78001356, 0.7853039, 0.7905446, 0.79269695, 0.7905719, 0.7854841, 0.7804414, 0.7783694, 0.7804164, 0.7853196, 0.7901815, 0.79218, 0.7902044, 0.7854702, 0.78077376, 0.77884257, 0.78075284, 0.78533196, 0.7898764, 0.7917457, 0.78989565, 0.78545916, 0.7810546, 0.7792422, 0.78103685, 0.78534186, 0.78961736, 0.79137725, 0.78963375, 0.7854502, 0.78129405, 0.7795827, 0.7812788, 0.7853499, 0.7893957, 0.79106206, 0.7894098, 0.7854429, 0.7814995, 0.7798749, 0.7814864, 0.7853566, 0.7892049, 0.7907908, 0.7892171, 0.7854368, 0.7816769, 0.78012705, 0.78166556, 0.7853622, 0.78903985, 0.79055613, 0.7890504, 0.7854317, 0.7818306, 0.7803456, 0.7818208, 0.7853669, 0.78889656, 0.79035246, 0.78890574, 0.78542733, 0.7819642, 0.78053546, 0.78195566, 0.78537095, 0.788772, 0.7901754, 0.78878, 0.7854235, 0.7820805, 0.7807007, 0.782073, 0.78537446, 0.7886635, 0.79002124, 0.7886705, 0.78542024, 0.7821816, 0.78084445, 0.7821751, 0.78537756, 0.7885692, 0.7898872, 0.78857523, 0.7854173, 0.7822695, 0.7809693, 0.7822639, 0.7853803, 0.78848743, 0.78977096, 0.7884927, 0.78541476, 0.7823455, 0.78107744, 0.78234065, 0.7853828, 0.78841686, 0.7896706, 0.7884214, 0.7854124, 0.78241086, 0.7811704, 0.78240675, 0.785385, 0.7883564, 0.78958464, 0.78836024, 0.7854103, 0.78246665, 0.7812497, 0.78246313, 0.78538704, 0.78830516, 0.78951174, 0.7883084, 0.7854083, 0.7825135, 0.78131646, 0.7825106, 0.78538895, 0.7882625, 0.78945094, 0.78826517, 0.7854065, 0.7825522, 0.78137153, 0.7825498, 0.78539073, 0.78822774, 0.7894014, 0.7882299, 0.78540474, 0.7825831, 0.78141564, 0.78258127, 0.7853924, 0.7882005, 0.78936255, 0.7882021, 0.78540313, 0.7826068, 0.78144944, 0.78260535, 0.78539395, 0.7881804, 0.78933376, 0.78818154, 0.7854016, 0.78262335, 0.7814733, 0.78262246, 0.7853955, 0.78816724, 0.7893148, 0.7881679, 0.78540003, 0.7826332, 0.78148746, 0.7826327, 0.78539705, 0.78816074, 0.7893053, 0.788161, 0.78539854, 0.7826363, 0.7814922, 0.7826363, 0.78539854, 0.788161, 0.7893053, 0.78816074, 0.78539705, 0.7826327, 0.78148746, 0.7826332, 0.78540003, 0.7881679, 0.7893148, 0.78816724, 0.7853955, 0.78262246, 0.7814733, 0.78262335, 0.7854016, 0.78818154, 0.78933376, 0.7881804, 0.78539395, 0.78260535, 0.78144944, 0.7826068, 0.78540313, 0.7882021, 0.78936255, 0.7882005, 0.7853924, 0.78258127, 0.78141564, 0.7825831, 0.78540474, 0.7882299, 0.7894014, 0.78822774, 0.78539073, 0.7825498, 0.78137153, 0.7825522, 0.7854065, 0.78826517, 0.78945094, 0.7882625, 0.78538895, 0.7825106, 0.78131646, 0.7825135, 0.7854083, 0.7883084, 0.78951174, 0.78830516, 0.78538704, 0.78246313, 0.7812497, 0.78246665, 0.7854103, 0.78836024, 0.78958464, 0.7883564, 0.785385, 0.78240675, 0.7811704, 0.78241086, 0.7854124, 0.7884214, 0.7896706, 0.78841686, 0.7853828, 0.78234065, 0.78107744, 0.7823455, 0.78541476, 0.7884927, 0.78977096, 0.78848743, 0.7853803, 0.7822639, 0.7809693, 0.7822695, 0.7854173, 0.78857523, 0.7898872, 0.7885692, 0.78537756, 0.7821751, 0.78084445, 0.7821816, 0.78542024, 0.7886705, 0.79002124, 0.7886635, 0.78537446, 0.782073, 0.7807007, 0.7820805, 0.7854235, 0.78878, 0.7901754, 0.788772, 0.78537095, 0.78195566, 0.78053546, 0.7819642, 0.78542733, 0.78890574, 0.79035246, 0.78889656, 0.7853669, 0.7818208, 0.7803456, 0.7818306, 0.7854317, 0.7890504, 0.79055613, 0.78903985, 0.7853622, 0.78166556, 0.78012705, 0.7816769, 0.7854368, 0.7892171, 0.7907908, 0.7892049, 0.7853566, 0.7814864, 0.7798749, 0.7814995, 0.7854429, 0.7894098, 0.79106206, 0.7893957, 0.7853499, 0.7812788, 0.7795827, 0.78129405, 0.7854502, 0.78963375, 0.79137725, 0.78961736, 0.78534186, 0.78103685, 0.7792422, 0.7810546, 0.78545916, 0.78989565, 0.7917457, 0.7898764, 0.78533196, 0.78075284, 0.77884257, 0.78077376, 0.7854702, 0.7902044, 0.79218, 0.7901815, 0.7853196, 0.7804164, 0.7783694, 0.7804414, 0.7854841, 0.7905719, 0.79269695, 0.7905446, 0.7853039, 0.78001356, 0.777803, 0.78004366, 0.785502, 0.79101497, 0.79331976, 0.7909818, 0.7852833, 0.7795245, 0.77711564, 0.7795613, 0.7855257, 0.79155713, 0.7940815, 0.7915162, 0.78525585, 0.7789207, 0.7762674, 0.77896655, 0.7855578, 0.7922331, 0.79503095, 0.79218155, 0.7852179, 0.7781593, 0.77519846, 0.7782178, 0.78560317, 0.7930964, 0.79624254, 0.7930297, 0.7851632, 0.77717304, 0.7738148, 0.7772499, 0.7856699, 0.79423314, 0.7978365, 0.79414374, 0.78508043, 0.77584964, 0.7719601, 0.77595484, 0.7857743, 0.79579204, 0.80002004, 0.7956665, 0.7849463, 0.7739876, 0.7693536, 0.7741398, 0.7859506, 0.79805344, 0.8031831, 0.7978651, 0.7847079, 0.77118516, 0.76543725, 0.771424, 0.78628427, 0.8016148, 0.808155, 0.8013024, 0.7842207, 0.7665119, 0.75892085, 0.76693785, 0.7870361, 0.80801165, 0.81706244, 0.8073973, 0.7829703, 0.7572308, 0.7460166, 0.7581925, 0.7893495, 0.82268006, 0.83742887, 0.8209662, 0.7779206, 0.73060256, 0.7090438, 0.7344808, 0.8041813, 0.88585603, 0.9259845, 0.8698863, 0.6853862, 0.3794887, 8.143985e-15, -0.3794887, -0.6853862, -0.8698863, -0.9259845, -0.88585603, -0.8041813, -0.7344808, -0.7090438, -0.73060256, -0.7779206, -0.8209662, -0.83742887, -0.82268006, -0.7893495, -0.7581925, -0.7460166, -0.7572308, -0.7829703, -0.8073973, -0.81706244, -0.80801165, -0.7870361, -0.76693785, -0.75892085, -0.7665119, -0.7842207, -0.8013024, -0.808155, -0.8016148, -0.78628427, -0.771424, -0.76543725, -0.77118516, -0.7847079, -0.7978651, -0.8031831, -0.79805344, -0.7859506, -0.7741398, -0.7693536, -0.7739876, -0.7849463, -0.7956665, -0.80002004, -0.79579204, -0.7857743, -0.77595484, -0.7719601, -0.77584964, -0.78508043, -0.79414374, -0.7978365, -0.79423314, -0.7856699, -0.7772499, -0.7738148, -0.77717304, -0.7851632, -0.7930297, -0.79624254, -0.7930964, -0.78560317, -0.7782178, -0.77519846, -0.7781593, -0.7852179, -0.79218155, -0.79503095, -0.7922331, -0.7855578, -0.77896655, -0.7762674, -0.7789207, -0.78525585, -0.7915162, -0.7940815, -0.79155713, -0.7855257, -0.7795613, -0.77711564, -0.7795245, -0.7852833, -0.7909818, -0.79331976, -0.79101497, -0.785502, -0.78004366, -0.777803, -0.78001356, -0.7853039, -0.7905446, -0.79269695, -0.7905719, -0.7854841, -0.7804414, -0.7783694, -0.7804164, -0.7853196, -0.7901815, -0.79218, -0.7902044, -0.7854702, -0.78077376, -0.77884257, -0.78075284, -0.78533196, -0.7898764, -0.7917457, -0.78989565, -0.78545916, -0.7810546, -0.7792422, -0.78103685, -0.78534186, -0.78961736, -0.79137725, -0.78963375, -0.7854502, -0.78129405, -0.7795827, -0.7812788, -0.7853499, -0.7893957, -0.79106206, -0.7894098, -0.7854429, -0.7814995, -0.7798749, -0.7814864, -0.7853566, -0.7892049, -0.7907908, -0.7892171, -0.7854368, -0.7816769, -0.78012705, -0.78166556, -0.7853622, -0.78903985, -0.79055613, -0.7890504, -0.7854317, -0.7818306, -0.7803456, -0.7818208, -0.7853669, -0.78889656, -0.79035246, -0.78890574, -0.78542733, -0.7819642, -0.78053546, -0.78195566, -0.78537095, -0.788772, -0.7901754, -0.78878, -0.7854235, -0.7820805, -0.7807007, -0.782073, -0.78537446, -0.7886635, -0.79002124, -0.7886705, -0.78542024, -0.7821816, -0.78084445, -0.7821751, -0.78537756, -0.7885692, -0.7898872, -0.78857523, -0.7854173, -0.7822695, -0.7809693, -0.7822639, -0.7853803, -0.78848743, -0.78977096, -0.7884927, -0.78541476, -0.7823455, -0.78107744, -0.78234065, -0.7853828, -0.78841686, -0.7896706, -0.7884214, -0.7854124, -0.78241086, -0.7811704, -0.78240675, -0.785385, -0.7883564, -0.78958464, -0.78836024, -0.7854103, -0.78246665, -0.7812497, -0.78246313, -0.78538704, -0.78830516, -0.78951174, -0.7883084, -0.7854083, -0.7825135, -0.78131646, -0.7825106, -0.78538895, -0.7882625, -0.78945094, -0.78826517, -0.7854065, -0.7825522, -0.78137153, -0.7825498, -0.78539073, -0.78822774, -0.7894014, -0.7882299, -0.78540474, -0.7825831, -0.78141564, -0.78258127, -0.7853924, -0.7882005, -0.78936255, -0.7882021, -0.78540313, -0.7826068, -0.78144944, -0.78260535, -0.78539395, -0.7881804, -0.78933376, -0.78818154, -0.7854016, -0.78262335, -0.7814733, -0.78262246, -0.7853955, -0.78816724, -0.7893148, -0.7881679, -0.78540003, -0.7826332, -0.78148746, -0.7826327, -0.78539705, -0.78816074, -0.7893053, -0.788161, -0.78539854, -0.7826363, -0.7814922, -0.7826363, -0.78539854, -0.788161, -0.7893053, -0.78816074, -0.78539705, -0.7826327, -0.78148746, -0.7826332, -0.78540003, -0.7881679, -0.7893148, -0.78816724, -0.7853955, -0.78262246, -0.7814733, -0.78262335, -0.7854016, -0.78818154, -0.78933376, -0.7881804, -0.78539395, -0.78260535, -0.78144944, -0.7826068, -0.78540313, -0.7882021, -0.78936255, -0.7882005, -0.7853924, -0.78258127, -0.78141564, -0.7825831, -0.78540474, -0.7882299, -0.7894014, -0.78822774, -0.78539073, -0.7825498, -0.78137153, -0.7825522, -0.7854065, -0.78826517, -0.78945094, -0.7882625, -0.78538895, -0.7825106, -0.78131646, -0.7825135, -0.7854083, -0.7883084, -0.78951174, -0.78830516, -0.78538704, -0.78246313, -0.7812497, -0.78246665, -0.7854103, -0.78836024, -0.78958464, -0.7883564, -0.785385, -0.78240675, -0.7811704, -0.78241086, -0.7854124, -0.7884214, -0.7896706, -0.78841686, -0.7853828, -0.78234065, -0.78107744, -0.7823455, -0.78541476, -0.7884927, -0.78977096, -0.78848743, -0.7853803, -0.7822639, -0.7809693, -0.7822695, -0.7854173, -0.78857523, -0.7898872, -0.7885692, -0.78537756, -0.7821751, -0.78084445, -0.7821816, -0.78542024, -0.7886705, -0.79002124, -0.7886635, -0.78537446, -0.782073, -0.7807007, -0.7820805, -0.7854235, -0.78878, -0.7901754, -0.788772, -0.78537095, -0.78195566, -0.78053546, -0.7819642, -0.78542733, -0.78890574, -0.79035246, -0.78889656, -0.7853669, -0.7818208, -0.7803456, -0.7818306, -0.7854317, -0.7890504, -0.79055613, -0.78903985, -0.7853622, -0.78166556, -0.78012705, -0.7816769, -0.7854368, -0.7892171, -0.7907908, -0.7892049, -0.7853566, -0.7814864, -0.7798749, -0.7814995, -0.7854429, -0.7894098, -0.79106206, -0.7893957, -0.7853499, -0.7812788, -0.7795827, -0.78129405, -0.7854502, -0.78963375, -0.79137725, -0.78961736, -0.78534186, -0.78103685, -0.7792422, -0.7810546, -0.78545916, -0.78989565, -0.7917457, -0.7898764, -0.78533196, -0.78075284, -0.77884257, -0.78077376, -0.7854702, -0.7902044, -0.79218, -0.7901815, -0.7853196, -0.7804164, -0.7783694, -0.7804414, -0.7854841, -0.7905719, -0.79269695, -0.7905446, -0.7853039, -0.78001356, -0.777803, -0.78004366, -0.785502, -0.79101497, -0.79331976, -0.7909818, -0.7852833, -0.7795245, -0.77711564, -0.7795613, -0.7855257, -0.79155713, -0.7940815, -0.7915162, -0.78525585, -0.7789207, -0.7762674, -0.77896655, -0.7855578, -0.7922331, -0.79503095, -0.79218155, -0.7852179, -0.7781593, -0.77519846, -0.7782178, -0.78560317, -0.7930964, -0.79624254, -0.7930297, -0.7851632, -0.77717304, -0.7738148, -0.7772499, -0.7856699, -0.79423314, -0.7978365, -0.79414374, -0.78508043, -0.77584964, -0.7719601, -0.77595484, -0.7857743, -0.79579204, -0.80002004, -0.7956665, -0.7849463, -0.7739876, -0.7693536, -0.7741398, -0.7859506, -0.79805344, -0.8031831, -0.7978651, -0.7847079, -0.77118516, -0.76543725, -0.771424, -0.78628427, -0.8016148, -0.808155, -0.8013024, -0.7842207, -0.7665119, -0.75892085, -0.76693785, -0.7870361, -0.80801165, -0.81706244, -0.8073973, -0.7829703, -0.7572308, -0.7460166, -0.7581925, -0.7893495, -0.82268006, -0.83742887, -0.8209662, -0.7779206, -0.73060256, -0.7090438, -0.7344808, -0.8041813, -0.88585603, -0.9259845, -0.8698863, -0.6853862, -0.3794887, ], [0.0, 0.1946755, 0.37949103, 0.5454861, 0.68540144, 0.7942972, 0.8699225, 0.9128013, 0.9260324, 0.9148335, 0.8858876, 0.84657305, 0.804166, 0.76510686, 0.7344106, 0.7152788, 0.70894784, 0.7147749, 0.730537, 0.7528921, 0.7779359, 0.80177915, 0.8210705, 0.8334051, 0.8375731, 0.83362824, 0.82277966, 0.8071343, 0.78933406, 0.77214456, 0.7580538, 0.7489362, 0.74582386, 0.748811, 0.7570969, 0.76915306, 0.78298587, 0.7964506, 0.8075706, 0.81481606, 0.8173042, 0.81489587, 0.8081802, 0.7983544, 0.7870204, 0.77593166, 0.76672953, 0.7607063, 0.7586296, 0.7606512, 0.76630837, 0.77461714, 0.78423667, 0.7936815, 0.80154616, 0.80671066, 0.80849636, 0.80675095, 0.8018537, 0.7946412, 0.78626806, 0.7780253, 0.7711442, 0.76661456, 0.76504505, 0.7665839, 0.7709104, 0.7772958, 0.78472435, 0.7920521, 0.7981815, 0.8022239, 0.80362695, 0.80224794, 0.79836476, 0.79262376, 0.78593385, 0.77932394, 0.7737862, 0.77012837, 0.76885706, 0.77010906, 0.7736391, 0.7788652, 0.7849635, 0.7909969, 0.7960583, 0.7994057, 0.8005704, 0.7994215, 0.7961786, 0.79137194, 0.78575677, 0.780195, 0.77552414, 0.7724317, 0.7713547, 0.7724186, 0.7754243, 0.7798837, 0.7850984, 0.7902684, 0.79461443, 0.7974944, 0.7984983, 0.7975054, 0.7946983, 0.79052997, 0.7856515, 0.7808107, 0.77673817, 0.77403724, 0.77309513, 0.7740279, 0.776667, 0.7805888, 0.7851822, 0.7897434, 0.79358363, 0.79613227, 0.79702187, 0.79614025, 0.7936445, 0.78993326, 0.78558356, 0.7812615, 0.77762026, 0.77520216, 0.7743577, 0.7751953, 0.77756786, 0.78109807, 0.7852381, 0.7893543, 0.7928241, 0.79512966, 0.7959353, 0.7951356, 0.7928694, 0.7894956, 0.7855369, 0.7815988, 0.7782774, 0.7760693, 0.77529734, 0.7760641, 0.77823806, 0.7814762, 0.7852776, 0.78906107, 0.7922536, 0.79437715, 0.7951198, 0.7943817, 0.7922878, 0.7891677, 0.7855031, 0.7818541, 0.7787736, 0.7767237, 0.77600646, 0.77671975, 0.7787438, 0.7817613, 0.7853068, 0.7888388, 0.79182184, 0.7938077, 0.79450274, 0.7938111, 0.7918477, 0.78891957, 0.7854775, 0.7820473, 0.77914906, 0.7772188, 0.7765431, 0.7772159, 0.7791266, 0.78197724, 0.78532946, 0.7886715, 0.7914963, 0.79337823, 0.79403734, 0.7933808, 0.79151565, 0.78873193, 0.78545725, 0.78219134, 0.7794299, 0.7775895, 0.77694476, 0.77758735, 0.77941334, 0.78213954, 0.78534776, 0.78854847, 0.7912557, 0.7930606, 0.7936932, 0.79306245, 0.7912698, 0.7885924, 0.78544056, 0.78229505, 0.77963364, 0.77785873, 0.77723646, 0.7778572, 0.7796219, 0.78225845, 0.78536314, 0.78846264, 0.791086, 0.7928361, 0.7934498, 0.7928374, 0.79109555, 0.7884925, 0.7854262, 0.78236413, 0.7797716, 0.77804154, 0.7774347, 0.7780406, 0.7797641, 0.7823407, 0.78537667, 0.78840953, 0.790978, 0.79269254, 0.79329413, 0.7926933, 0.79098356, 0.7884268, 0.7854133, 0.78240204, 0.779851, 0.77814764, 0.7775498, 0.77814716, 0.7798474, 0.7823906, 0.7853891, 0.78838634, 0.79092616, 0.7926226, 0.7932182, 0.79262286, 0.790928, 0.788392, 0.78540117, 0.78241074, 0.7798759, 0.77818227, 0.7775876, 0.77818227, 0.7798759, 0.78241074, 0.78540117, 0.788392, 0.790928, 0.79262286, 0.7932182, 0.7926226, 0.79092616, 0.78838634, 0.7853891, 0.7823906, 0.7798474, 0.77814716, 0.7775498, 0.77814764, 0.779851, 0.78240204, 0.7854133, 0.7884268, 0.79098356, 0.7926933, 0.79329413, 0.79269254, 0.790978, 0.78840953, 0.78537667, 0.7823407, 0.7797641, 0.7780406, 0.7774347, 0.77804154, 0.7797716, 0.78236413, 0.7854262, 0.7884925, 0.79109555, 0.7928374, 0.7934498, 0.7928361, 0.791086, 0.78846264, 0.78536314, 0.78225845, 0.7796219, 0.7778572, 0.77723646, 0.77785873, 0.77963364, 0.78229505, 0.78544056, 0.7885924, 0.7912698, 0.79306245, 0.7936932, 0.7930606, 0.7912557, 0.78854847, 0.78534776, 0.78213954, 0.77941334, 0.77758735, 0.77694476, 0.7775895, 0.7794299, 0.78219134, 0.78545725, 0.78873193, 0.79151565, 0.7933808, 0.79403734, 0.79337823, 0.7914963, 0.7886715, 0.78532946, 0.78197724, 0.7791266, 0.7772159, 0.7765431, 0.7772188, 0.77914906, 0.7820473, 0.7854775, 0.78891957, 0.7918477, 0.7938111, 0.79450274, 0.7938077, 0.79182184, 0.7888388, 0.7853068, 0.7817613, 0.7787438, 0.77671975, 0.77600646, 0.7767237, 0.7787736, 0.7818541, 0.7855031, 0.7891677, 0.7922878, 0.7943817, 0.7951198, 0.79437715, 0.7922536, 0.78906107, 0.7852776, 0.7814762, 0.77823806, 0.7760641, 0.77529734, 0.7760693, 0.7782774, 0.7815988, 0.7855369, 0.7894956, 0.7928694, 0.7951356, 0.7959353, 0.79512966, 0.7928241, 0.7893543, 0.7852381, 0.78109807, 0.77756786, 0.7751953, 0.7743577, 0.77520216, 0.77762026, 0.7812615, 0.78558356, 0.78993326, 0.7936445, 0.79614025, 0.79702187, 0.79613227, 0.79358363, 0.7897434, 0.7851822, 0.7805888, 0.776667, 0.7740279, 0.77309513, 0.77403724, 0.77673817, 0.7808107, 0.7856515, 0.79052997, 0.7946983, 0.7975054, 0.7984983, 0.7974944, 0.79461443, 0.7902684, 0.7850984, 0.7798837, 0.7754243, 0.7724186, 0.7713547, 0.7724317, 0.77552414, 0.780195, 0.78575677, 0.79137194, 0.7961786, 0.7994215, 0.8005704, 0.7994057, 0.7960583, 0.7909969, 0.7849635, 0.7788652, 0.7736391, 0.77010906, 0.76885706, 0.77012837, 0.7737862, 0.77932394, 0.78593385, 0.79262376, 0.79836476, 0.80224794, 0.80362695, 0.8022239, 0.7981815, 0.7920521, 0.78472435, 0.7772958, 0.7709104, 0.7665839, 0.76504505, 0.76661456, 0.7711442, 0.7780253, 0.78626806, 0.7946412, 0.8018537, 0.80675095, 0.80849636, 0.80671066, 0.80154616, 0.7936815, 0.78423667, 0.77461714, 0.76630837, 0.7606512, 0.7586296, 0.7607063, 0.76672953, 0.77593166, 0.7870204, 0.7983544, 0.8081802, 0.81489587, 0.8173042, 0.81481606, 0.8075706, 0.7964506, 0.78298587, 0.76915306, 0.7570969, 0.748811, 0.74582386, 0.7489362, 0.7580538, 0.77214456, 0.78933406, 0.8071343, 0.82277966, 0.83362824, 0.8375731, 0.8334051, 0.8210705, 0.80177915, 0.7779359, 0.7528921, 0.730537, 0.7147749, 0.70894784, 0.7152788, 0.7344106, 0.76510686, 0.804166, 0.84657305, 0.8858876, 0.9148335, 0.9260324, 0.9128013, 0.8699225, 0.7942972, 0.68540144, 0.5454861, 0.37949103, 0.1946755, 4.2221878e-15, -0.1946755, -0.37949103, -0.5454861, -0.68540144, -0.7942972, -0.8699225, -0.9128013, -0.9260324, -0.9148335, -0.8858876, -0.84657305, -0.804166, -0.76510686, -0.7344106, -0.7152788, -0.70894784, -0.7147749, -0.730537, -0.7528921, -0.7779359, -0.80177915, -0.8210705, -0.8334051, -0.8375731, -0.83362824, -0.82277966, -0.8071343, -0.78933406, -0.77214456, -0.7580538, -0.7489362, -0.74582386, -0.748811, -0.7570969, -0.76915306, -0.78298587, -0.7964506, -0.8075706, -0.81481606, -0.8173042, -0.81489587, -0.8081802, -0.7983544, -0.7870204, -0.77593166, -0.76672953, -0.7607063, -0.7586296, -0.7606512, -0.76630837, -0.77461714, -0.78423667, -0.7936815, -0.80154616, -0.80671066, -0.80849636, -0.80675095, -0.8018537, -0.7946412, -0.78626806, -0.7780253, -0.7711442, -0.76661456, -0.76504505, -0.7665839, -0.7709104, -0.7772958, -0.78472435, -0.7920521, -0.7981815, -0.8022239, -0.80362695, -0.80224794, -0.79836476, -0.79262376, -0.78593385, -0.77932394, -0.7737862, -0.77012837, -0.76885706, -0.77010906, -0.7736391, -0.7788652, -0.7849635, -0.7909969, -0.7960583, -0.7994057, -0.8005704, -0.7994215, -0.7961786, -0.79137194, -0.78575677, -0.780195, -0.77552414, -0.7724317, -0.7713547, -0.7724186, -0.7754243, -0.7798837, -0.7850984, -0.7902684, -0.79461443, -0.7974944, -0.7984983, -0.7975054, -0.7946983, -0.79052997, -0.7856515, -0.7808107, -0.77673817, -0.77403724, -0.77309513, -0.7740279, -0.776667, -0.7805888, -0.7851822, -0.7897434, -0.79358363, -0.79613227, -0.79702187, -0.79614025, -0.7936445, -0.78993326, -0.78558356, -0.7812615, -0.77762026, -0.77520216, -0.7743577, -0.7751953, -0.77756786, -0.78109807, -0.7852381, -0.7893543, -0.7928241, -0.79512966, -0.7959353, -0.7951356, -0.7928694, -0.7894956, -0.7855369, -0.7815988, -0.7782774, -0.7760693, -0.77529734, -0.7760641, -0.77823806, -0.7814762, -0.7852776, -0.78906107, -0.7922536, -0.79437715, -0.7951198, -0.7943817, -0.7922878, -0.7891677, -0.7855031, -0.7818541, -0.7787736, -0.7767237, -0.77600646, -0.77671975, -0.7787438, -0.7817613, -0.7853068, -0.7888388, -0.79182184, -0.7938077, -0.79450274, -0.7938111, -0.7918477, -0.78891957, -0.7854775, -0.7820473, -0.77914906, -0.7772188, -0.7765431, -0.7772159, -0.7791266, -0.78197724, -0.78532946, -0.7886715, -0.7914963, -0.79337823, -0.79403734, -0.7933808, -0.79151565, -0.78873193, -0.78545725, -0.78219134, -0.7794299, -0.7775895, -0.77694476, -0.77758735, -0.77941334, -0.78213954, -0.78534776, -0.78854847, -0.7912557, -0.7930606, -0.7936932, -0.79306245, -0.7912698, -0.7885924, -0.78544056, -0.78229505, -0.77963364, -0.77785873, -0.77723646, -0.7778572, -0.7796219, -0.78225845, -0.78536314, -0.78846264, -0.791086, -0.7928361, -0.7934498, -0.7928374, -0.79109555, -0.7884925, -0.7854262, -0.78236413, -0.7797716, -0.77804154, -0.7774347, -0.7780406, -0.7797641, -0.7823407, -0.78537667, -0.78840953, -0.790978, -0.79269254, -0.79329413, -0.7926933, -0.79098356, -0.7884268, -0.7854133, -0.78240204, -0.779851, -0.77814764, -0.7775498, -0.77814716, -0.7798474, -0.7823906, -0.7853891, -0.78838634, -0.79092616, -0.7926226, -0.7932182, -0.79262286, -0.790928, -0.788392, -0.78540117, -0.78241074, -0.7798759, -0.77818227, -0.7775876, -0.77818227, -0.7798759, -0.78241074, -0.78540117, -0.788392, -0.790928, -0.79262286, -0.7932182, -0.7926226, -0.79092616, -0.78838634, -0.7853891, -0.7823906, -0.7798474, -0.77814716, -0.7775498, -0.77814764, -0.779851, -0.78240204, -0.7854133, -0.7884268, -0.79098356, -0.7926933, -0.79329413, -0.79269254, -0.790978, -0.78840953, -0.78537667, -0.7823407, -0.7797641, -0.7780406, -0.7774347, -0.77804154, -0.7797716, -0.78236413, -0.7854262, -0.7884925, -0.79109555, -0.7928374, -0.7934498, -0.7928361, -0.791086, -0.78846264, -0.78536314, -0.78225845, -0.7796219, -0.7778572, -0.77723646, -0.77785873, -0.77963364, -0.78229505, -0.78544056, -0.7885924, -0.7912698, -0.79306245, -0.7936932, -0.7930606, -0.7912557, -0.78854847, -0.78534776, -0.78213954, -0.77941334, -0.77758735, -0.77694476, -0.7775895, -0.7794299, -0.78219134, -0.78545725, -0.78873193, -0.79151565, -0.7933808, -0.79403734, -0.79337823, -0.7914963, -0.7886715, -0.78532946, -0.78197724, -0.7791266, -0.7772159, -0.7765431, -0.7772188, -0.77914906, -0.7820473, -0.7854775, -0.78891957, -0.7918477, -0.7938111, -0.79450274, -0.7938077, -0.79182184, -0.7888388, -0.7853068, -0.7817613, -0.7787438, -0.77671975, -0.77600646, -0.7767237, -0.7787736, -0.7818541, -0.7855031, -0.7891677, -0.7922878, -0.7943817, -0.7951198, -0.79437715, -0.7922536, -0.78906107, -0.7852776, -0.7814762, -0.77823806, -0.7760641, -0.77529734, -0.7760693, -0.7782774, -0.7815988, -0.7855369, -0.7894956, -0.7928694, -0.7951356, -0.7959353, -0.79512966, -0.7928241, -0.7893543, -0.7852381, -0.78109807, -0.77756786, -0.7751953, -0.7743577, -0.77520216, -0.77762026, -0.7812615, -0.78558356, -0.78993326, -0.7936445, -0.79614025, -0.79702187, -0.79613227, -0.79358363, -0.7897434, -0.7851822, -0.7805888, -0.776667, -0.7740279, -0.77309513, -0.77403724, -0.77673817, -0.7808107, -0.7856515, -0.79052997, -0.7946983, -0.7975054, -0.7984983, -0.7974944, -0.79461443, -0.7902684, -0.7850984, -0.7798837, -0.7754243, -0.7724186, -0.7713547, -0.7724317, -0.77552414, -0.780195, -0.78575677, -0.79137194, -0.7961786, -0.7994215, -0.8005704, -0.7994057, -0.7960583, -0.7909969, -0.7849635, -0.7788652, -0.7736391, -0.77010906, -0.76885706, -0.77012837, -0.7737862, -0.77932394, -0.78593385, -0.79262376, -0.79836476, -0.80224794, -0.80362695, -0.8022239, -0.7981815, -0.7920521, -0.78472435, -0.7772958, -0.7709104, -0.7665839, -0.76504505, -0.76661456, -0.7711442, -0.7780253, -0.78626806, -0.7946412, -0.8018537, -0.80675095, -0.80849636, -0.80671066, -0.80154616, -0.7936815, -0.78423667, -0.77461714, -0.76630837, -0.7606512, -0.7586296, -0.7607063, -0.76672953, -0.77593166, -0.7870204, -0.7983544, -0.8081802, -0.81489587, -0.8173042, -0.81481606, -0.8075706, -0.7964506, -0.78298587, -0.76915306, -0.7570969, -0.748811, -0.74582386, -0.7489362, -0.7580538, -0.77214456, -0.78933406, -0.8071343, -0.82277966, -0.83362824, -0.8375731, -0.8334051, -0.8210705, -0.80177915, -0.7779359, -0.7528921, -0.730537, -0.7147749, -0.70894784, -0.7152788, -0.7344106, -0.76510686, -0.804166, -0.84657305, -0.8858876, -0.9148335, -0.9260324, -0.9128013, -0.8699225, -0.7942972, -0.68540144, -0.5454861, -0.37949103, -0.1946755, ], [0.0, 0.09796494, 0.19467673, 0.28891098, 0.3795003, 0.46536046, 0.54551494, 0.61911595, 0.6854625, 0.74401385, 0.7943995, 0.836424, 0.8700674, 0.8954812, 0.9129798, 0.9230279, 0.9262243, 0.92328274, 0.9150095, 0.9022801, 0.8860139, 0.8671491, 0.84661734, 0.82531977, 0.80410445, 0.7837464, 0.7649303, 0.7482362, 0.73412883, 0.72295123, 0.7149216, 0.71013385, 0.7085625, 0.71007055, 0.7144202, 0.7212867, 0.7302741, 0.7409319, 0.75277424, 0.76529735, 0.77799857, 0.79039335, 0.80203146, 0.8125113, 0.82149154, 0.8287007, 0.8339441, 0.83710676, 0.8381552, 0.83713466, 0.83416474, 0.82943213, 0.82318145, 0.8157042, 0.8073266, 0.79839647, 0.78926986, 0.78029794, 0.7718143, 0.76412326, 0.75748986, 0.75213134, 0.7482107, 0.7458324, 0.74504, 0.7458169, 0.7480882, 0.7517255, 0.75655276, 0.7623545, 0.76888454, 0.77587616, 0.78305227, 0.79013616, 0.7968617, 0.8029828, 0.8082823, 0.81257915, 0.81573427, 0.81765485, 0.8182967, 0.81766456, 0.81581134, 0.8128344, 0.8088714, 0.80409414, 0.7987014, 0.792911, 0.7869512, 0.7810519, 0.77543604, 0.7703116, 0.76586366, 0.7622483, 0.75958717, 0.7579635, 0.7574197, 0.75795686, 0.75953484, 0.7620751, 0.765464, 0.76955783, 0.7741886, 0.779171, 0.7843094, 0.7894056, 0.7942662, 0.7987099, 0.80257416, 0.80572075, 0.808041, 0.8094591, 0.80993474, 0.8094638, 0.8080782, 0.8058442, 0.802859, 0.79924715, 0.7951551, 0.7907456, 0.78619105, 0.7816672, 0.777346, 0.77338976, 0.7699445, 0.7671351, 0.7650606, 0.763791, 0.7633647, 0.7637875, 0.7650332, 0.7670442, 0.7697348, 0.7729943, 0.77669173, 0.780681, 0.78480655, 0.7889092, 0.7928327, 0.7964291, 0.7995646, 0.8021243, 0.8040166, 0.80517596, 0.80556566, 0.8051786, 0.8040372, 0.80219257, 0.799722, 0.7967259, 0.7933237, 0.78964925, 0.78584546, 0.7820589, 0.77843404, 0.77510816, 0.77220565, 0.76983386, 0.76807886, 0.7670026, 0.7666405, 0.7670006, 0.76806325, 0.7697822, 0.7720865, 0.7748835, 0.77806246, 0.78149885, 0.7850593, 0.7886067, 0.79200554, 0.79512674, 0.7978529, 0.8000825, 0.8017336, 0.8027471, 0.80308825, 0.80274856, 0.8017454, 0.8001214, 0.79794276, 0.79529613, 0.79228574, 0.789029, 0.7856521, 0.78228486, 0.7790563, 0.77608913, 0.77349555, 0.7713728, 0.7697996, 0.76883334, 0.7685078, 0.7688322, 0.7697909, 0.77134395, 0.7734291, 0.77596384, 0.778849, 0.7819725, 0.78521365, 0.7884477, 0.79155076, 0.7944045, 0.79690075, 0.79894525, 0.8004615, 0.80139333, 0.8017075, 0.8013941, 0.8004676, 0.79896563, 0.7969478, 0.79449314, 0.79169726, 0.7886685, 0.7855236, 0.7823835, 0.77936864, 0.7765941, 0.77416563, 0.7721754, 0.7706984, 0.7697901, 0.76948375, 0.76978964, 0.7706945, 0.7721624, 0.7741356, 0.7765374, 0.7792749, 0.78224224, 0.7853253, 0.78840554, 0.7913649, 0.7940899, 0.7964766, 0.7984338, 0.79988706, 0.8007814, 0.8010832, 0.80078167, 0.79988897, 0.79844016, 0.79649127, 0.7941176, 0.7914106, 0.7884745, 0.7854221, 0.78237057, 0.77943707, 0.7767342, 0.7743655, 0.77242184, 0.7709778, 0.77008855, 0.7697883, 0.77008855, 0.7709778, 0.77242184, 0.7743655, 0.7767342, 0.77943707, 0.78237057, 0.7854221, 0.7884745, 0.7914106, 0.7941176, 0.79649127, 0.79844016, 0.79988897, 0.80078167, 0.8010832, 0.8007814, 0.79988706, 0.7984338, 0.7964766, 0.7940899, 0.7913649, 0.78840554, 0.7853253, 0.78224224, 0.7792749, 0.7765374, 0.7741356, 0.7721624, 0.7706945, 0.76978964, 0.76948375, 0.7697901, 0.7706984, 0.7721754, 0.77416563, 0.7765941, 0.77936864, 0.7823835, 0.7855236, 0.7886685, 0.79169726, 0.79449314, 0.7969478, 0.79896563, 0.8004676, 0.8013941, 0.8017075, 0.80139333, 0.8004615, 0.79894525, 0.79690075, 0.7944045, 0.79155076, 0.7884477, 0.78521365, 0.7819725, 0.778849, 0.77596384, 0.7734291, 0.77134395, 0.7697909, 0.7688322, 0.7685078, 0.76883334, 0.7697996, 0.7713728, 0.77349555, 0.77608913, 0.7790563, 0.78228486, 0.7856521, 0.789029, 0.79228574, 0.79529613, 0.79794276, 0.8001214, 0.8017454, 0.80274856, 0.80308825, 0.8027471, 0.8017336, 0.8000825, 0.7978529, 0.79512674, 0.79200554, 0.7886067, 0.7850593, 0.78149885, 0.77806246, 0.7748835, 0.7720865, 0.7697822, 0.76806325, 0.7670006, 0.7666405, 0.7670026, 0.76807886, 0.76983386, 0.77220565, 0.77510816, 0.77843404, 0.7820589, 0.78584546, 0.78964925, 0.7933237, 0.7967259, 0.799722, 0.80219257, 0.8040372, 0.8051786, 0.80556566, 0.80517596, 0.8040166, 0.8021243, 0.7995646, 0.7964291, 0.7928327, 0.7889092, 0.78480655, 0.780681, 0.77669173, 0.7729943, 0.7697348, 0.7670442, 0.7650332, 0.7637875, 0.7633647, 0.763791, 0.7650606, 0.7671351, 0.7699445, 0.77338976, 0.777346, 0.7816672, 0.78619105, 0.7907456, 0.7951551, 0.79924715, 0.802859, 0.8058442, 0.8080782, 0.8094638, 0.80993474, 0.8094591, 0.808041, 0.80572075, 0.80257416, 0.7987099, 0.7942662, 0.7894056, 0.7843094, 0.779171, 0.7741886, 0.76955783, 0.765464, 0.7620751, 0.75953484, 0.75795686, 0.7574197, 0.7579635, 0.75958717, 0.7622483, 0.76586366, 0.7703116, 0.77543604, 0.7810519, 0.7869512, 0.792911, 0.7987014, 0.80409414, 0.8088714, 0.8128344, 0.81581134, 0.81766456, 0.8182967, 0.81765485, 0.81573427, 0.81257915, 0.8082823, 0.8029828, 0.7968617, 0.79013616, 0.78305227, 0.77587616, 0.76888454, 0.7623545, 0.75655276, 0.7517255, 0.7480882, 0.7458169, 0.74504, 0.7458324, 0.7482107, 0.75213134, 0.75748986, 0.76412326, 0.7718143, 0.78029794, 0.78926986, 0.79839647, 0.8073266, 0.8157042, 0.82318145, 0.82943213, 0.83416474, 0.83713466, 0.8381552, 0.83710676, 0.8339441, 0.8287007, 0.82149154, 0.8125113, 0.80203146, 0.79039335, 0.77799857, 0.76529735, 0.75277424, 0.7409319, 0.7302741, 0.7212867, 0.7144202, 0.71007055, 0.7085625, 0.71013385, 0.7149216, 0.72295123, 0.73412883, 0.7482362, 0.7649303, 0.7837464, 0.80410445, 0.82531977, 0.84661734, 0.8671491, 0.8860139, 0.9022801, 0.9150095, 0.92328274, 0.9262243, 0.9230279, 0.9129798, 0.8954812, 0.8700674, 0.836424, 0.7943995, 0.74401385, 0.6854625, 0.61911595, 0.54551494, 0.46536046, 0.3795003, 0.28891098, 0.19467673, 0.09796494, 2.1908312e-15, -0.09796494, -0.19467673, -0.28891098, -0.3795003, -0.46536046, -0.54551494, -0.61911595, -0.6854625, -0.74401385, -0.7943995, -0.836424, -0.8700674, -0.8954812, -0.9129798, -0.9230279, -0.9262243, -0.92328274, -0.9150095, -0.9022801, -0.8860139, -0.8671491, -0.84661734, -0.82531977, -0.80410445, -0.7837464, -0.7649303, -0.7482362, -0.73412883, -0.72295123, -0.7149216, -0.71013385, -0.7085625, -0.71007055, -0.7144202, -0.7212867, -0.7302741, -0.7409319, -0.75277424, -0.76529735, -0.77799857, -0.79039335, -0.80203146, -0.8125113, -0.82149154, -0.8287007, -0.8339441, -0.83710676, -0.8381552, -0.83713466, -0.83416474, -0.82943213, -0.82318145, -0.8157042, -0.8073266, -0.79839647, -0.78926986, -0.78029794, -0.7718143, -0.76412326, -0.75748986, -0.75213134, -0.7482107, -0.7458324, -0.74504, -0.7458169, -0.7480882, -0.7517255, -0.75655276, -0.7623545, -0.76888454, -0.77587616, -0.78305227, -0.79013616, -0.7968617, -0.8029828, -0.8082823, -0.81257915, -0.81573427, -0.81765485, -0.8182967, -0.81766456, -0.81581134, -0.8128344, -0.8088714, -0.80409414, -0.7987014, -0.792911, -0.7869512, -0.7810519, -0.77543604, -0.7703116, -0.76586366, -0.7622483, -0.75958717, -0.7579635, -0.7574197, -0.75795686, -0.75953484, -0.7620751, -0.765464, -0.76955783, -0.7741886, -0.779171, -0.7843094, -0.7894056, -0.7942662, -0.7987099, -0.80257416, -0.80572075, -0.808041, -0.8094591, -0.80993474, -0.8094638, -0.8080782, -0.8058442, -0.802859, -0.79924715, -0.7951551, -0.7907456, -0.78619105, -0.7816672, -0.777346, -0.77338976, -0.7699445, -0.7671351, -0.7650606, -0.763791, -0.7633647, -0.7637875, -0.7650332, -0.7670442, -0.7697348, -0.7729943, -0.77669173, -0.780681, -0.78480655, -0.7889092, -0.7928327, -0.7964291, -0.7995646, -0.8021243, -0.8040166, -0.80517596, -0.80556566, -0.8051786, -0.8040372, -0.80219257, -0.799722, -0.7967259, -0.7933237, -0.78964925, -0.78584546, -0.7820589, -0.77843404, -0.77510816, -0.77220565, -0.76983386, -0.76807886, -0.7670026, -0.7666405, -0.7670006, -0.76806325, -0.7697822, -0.7720865, -0.7748835, -0.77806246, -0.78149885, -0.7850593, -0.7886067, -0.79200554, -0.79512674, -0.7978529, -0.8000825, -0.8017336, -0.8027471, -0.80308825, -0.80274856, -0.8017454, -0.8001214, -0.79794276, -0.79529613, -0.79228574, -0.789029, -0.7856521, -0.78228486, -0.7790563, -0.77608913, -0.77349555, -0.7713728, -0.7697996, -0.76883334, -0.7685078, -0.7688322, -0.7697909, -0.77134395, -0.7734291, -0.77596384, -0.778849, -0.7819725, -0.78521365, -0.7884477, -0.79155076, -0.7944045, -0.79690075, -0.79894525, -0.8004615, -0.80139333, -0.8017075, -0.8013941, -0.8004676, -0.79896563, -0.7969478, -0.79449314, -0.79169726, -0.7886685, -0.7855236, -0.7823835, -0.77936864, -0.7765941, -0.77416563, -0.7721754, -0.7706984, -0.7697901, -0.76948375, -0.76978964, -0.7706945, -0.7721624, -0.7741356, -0.7765374, -0.7792749, -0.78224224, -0.7853253, -0.78840554, -0.7913649, -0.7940899, -0.7964766, -0.7984338, -0.79988706, -0.8007814, -0.8010832, -0.80078167, -0.79988897, -0.79844016, -0.79649127, -0.7941176, -0.7914106, -0.7884745, -0.7854221, -0.78237057, -0.77943707, -0.7767342, -0.7743655, -0.77242184, -0.7709778, -0.77008855, -0.7697883, -0.77008855, -0.7709778, -0.77242184, -0.7743655, -0.7767342, -0.77943707, -0.78237057, -0.7854221, -0.7884745, -0.7914106, -0.7941176, -0.79649127, -0.79844016, -0.79988897, -0.80078167, -0.8010832, -0.8007814, -0.79988706, -0.7984338, -0.7964766, -0.7940899, -0.7913649, -0.78840554, -0.7853253, -0.78224224, -0.7792749, -0.7765374, -0.7741356, -0.7721624, -0.7706945, -0.76978964, -0.76948375, -0.7697901, -0.7706984, -0.7721754, -0.77416563, -0.7765941, -0.77936864, -0.7823835, -0.7855236, -0.7886685, -0.79169726, -0.79449314, -0.7969478, -0.79896563, -0.8004676, -0.8013941, -0.8017075, -0.80139333, -0.8004615, -0.79894525, -0.79690075, -0.7944045, -0.79155076, -0.7884477, -0.78521365, -0.7819725, -0.778849, -0.77596384, -0.7734291, -0.77134395, -0.7697909, -0.7688322, -0.7685078, -0.76883334, -0.7697996, -0.7713728, -0.77349555, -0.77608913, -0.7790563, -0.78228486, -0.7856521, -0.789029, -0.79228574, -0.79529613, -0.79794276, -0.8001214, -0.8017454, -0.80274856, -0.80308825, -0.8027471, -0.8017336, -0.8000825, -0.7978529, -0.79512674, -0.79200554, -0.7886067, -0.7850593, -0.78149885, -0.77806246, -0.7748835, -0.7720865, -0.7697822, -0.76806325, -0.7670006, -0.7666405, -0.7670026, -0.76807886, -0.76983386, -0.77220565, -0.77510816, -0.77843404, -0.7820589, -0.78584546, -0.78964925, -0.7933237, -0.7967259, -0.799722, -0.80219257, -0.8040372, -0.8051786, -0.80556566, -0.80517596, -0.8040166, -0.8021243, -0.7995646, -0.7964291, -0.7928327, -0.7889092, -0.78480655, -0.780681, -0.77669173, -0.7729943, -0.7697348, -0.7670442, -0.7650332, -0.7637875, -0.7633647, -0.763791, -0.7650606, -0.7671351, -0.7699445, -0.77338976, -0.777346, -0.7816672, -0.78619105, -0.7907456, -0.7951551, -0.79924715, -0.802859, -0.8058442, -0.8080782, -0.8094638, -0.80993474, -0.8094591, -0.808041, -0.80572075, -0.80257416, -0.7987099, -0.7942662, -0.7894056, -0.7843094, -0.779171, -0.7741886, -0.76955783, -0.765464, -0.7620751, -0.75953484, -0.75795686, -0.7574197, -0.7579635, -0.75958717, -0.7622483, -0.76586366, -0.7703116, -0.77543604, -0.7810519, -0.7869512, -0.792911, -0.7987014, -0.80409414, -0.8088714, -0.8128344, -0.81581134, -0.81766456, -0.8182967, -0.81765485, -0.81573427, -0.81257915, -0.8082823, -0.8029828, -0.7968617, -0.79013616, -0.78305227, -0.77587616, -0.76888454, -0.7623545, -0.75655276, -0.7517255, -0.7480882, -0.7458169, -0.74504, -0.7458324, -0.7482107, -0.75213134, -0.75748986, -0.76412326, -0.7718143, -0.78029794, -0.78926986, -0.79839647, -0.8073266, -0.8157042, -0.82318145, -0.82943213, -0.83416474, -0.83713466, -0.8381552, -0.83710676, -0.8339441, -0.8287007, -0.82149154, -0.8125113, -0.80203146, -0.79039335, -0.77799857, -0.76529735, -0.75277424, -0.7409319, -0.7302741, -0.7212867, -0.7144202, -0.71007055, -0.7085625, -0.71013385, -0.7149216, -0.72295123, -0.73412883, -0.7482362, -0.7649303, -0.7837464, -0.80410445, -0.82531977, -0.84661734, -0.8671491, -0.8860139, -0.9022801, -0.9150095, -0.92328274, -0.9262243, -0.9230279, -0.9129798, -0.8954812, -0.8700674, -0.836424, -0.7943995, -0.74401385, -0.6854625, -0.61911595, -0.54551494, -0.46536046, -0.3795003, -0.28891098, -0.19467673, -0.09796494, ], [0.0, 0.049061213, 0.09796555, 0.14655708, 0.19468157, 0.24218756, 0.28892705, 0.33475646, 0.37953734, 0.42313722, 0.46543032, 0.5062982, 0.54563046, 0.5833253, 0.61929005, 0.6534416, 0.6857068, 0.7160229, 0.7443378, 0.77061, 0.794809, 0.8169154, 0.8369207, 0.8548271, 0.8706478, 0.8844062, 0.8961362, 0.90588135, 0.9136947, 0.9196382, 0.92378235, 0.9262054, 0.926993, 0.92623717, 0.92403597, 0.92049253, 0.9157145, 0.9098131, 0.9029024, 0.8950985, 0.8865189, 0.8772814, 0.8675037, 0.85730225, 0.84679186, 0.83608466, 0.8252896, 0.8145118, 0.8038518, 0.7934051, 0.7832616, 0.77350515, 0.76421297, 0.75545543, 0.7472958, 0.7397898, 0.73298544, 0.72692305, 0.72163504, 0.71714586, 0.71347225, 0.71062315, 0.70859975, 0.7073961, 0.70699894, 0.7073883, 0.70853776, 0.71041477, 0.7129813, 0.7161939, 0.72000486, 0.7243621, 0.72920996, 0.7344899, 0.74014103, 0.74610037, 0.752304, 0.7586871, 0.76518494, 0.77173316, 0.7782685, 0.78472936, 0.7910561, 0.7971916, 0.8030819, 0.8086764, 0.8139283, 0.8187949, 0.82323796, 0.82722396, 0.8307243, 0.83371526, 0.8361785, 0.83810073, 0.8394741, 0.84029585, 0.84056836, 0.8402991, 0.83950055, 0.83818966, 0.8363879, 0.8341209, 0.83141834, 0.8283132, 0.8248418, 0.8210432, 0.81695884, 0.8126323, 0.8081085, 0.80343366, 0.79865456, 0.79381835, 0.7889719, 0.7841615, 0.7794325, 0.7748287, 0.77039206, 0.76616263, 0.76217765, 0.7584718, 0.75507647, 0.7520199, 0.7493266, 0.74701744, 0.74510956, 0.7436158, 0.74254525, 0.7419027, 0.741689, 0.7419009, 0.74253136, 0.74356914, 0.74499965, 0.74680465, 0.7489626, 0.7514488, 0.754236, 0.7572941, 0.76059103, 0.76409274, 0.7677636, 0.7715668, 0.7754647, 0.779419, 0.78339154, 0.7873441, 0.7912392, 0.7950402, 0.7987118, 0.80222005, 0.8055331, 0.80862117, 0.81145674, 0.81401515, 0.81627434, 0.81821555, 0.8198229, 0.82108396, 0.82198966, 0.8225343, 0.8227158, 0.82253534, 0.8219975, 0.8211105, 0.8198853, 0.8183364, 0.8164811, 0.8143394, 0.811934, 0.8092897, 0.80643356, 0.8033945, 0.8002027, 0.7968899, 0.7934885, 0.7900317, 0.7865528, 0.78308535, 0.7796623, 0.7763162, 0.7730785, 0.76997954, 0.7670481, 0.7643112, 0.76179385, 0.7595188, 0.75750655, 0.7557748, 0.7543385, 0.7532099, 0.752398, 0.75190896, 0.7517458, 0.7519084, 0.7523936, 0.7531951, 0.7543037, 0.7557073, 0.7573912, 0.75933796, 0.76152766, 0.76393837, 0.766546, 0.76932484, 0.77224743, 0.7752853, 0.7784089, 0.7815879, 0.78479177, 0.7879897, 0.79115105, 0.7942457, 0.7972443, 0.8001184, 0.8028409, 0.8053862, 0.80773056, 0.80985206, 0.81173116, 0.81335044, 0.8146953, 0.81575346, 0.8165157, 0.81697536, 0.8171289, 0.8169756, 0.81651765, 0.81576014, 0.81471103, 0.81338096, 0.8117833, 0.80993384, 0.8078509, 0.8055548, 0.8030679, 0.80041444, 0.79762006, 0.7947118, 0.7917177, 0.7886666, 0.7855878, 0.7825109, 0.77946526, 0.7764802, 0.77358425, 0.770805, 0.7681691, 0.7657017, 0.7634263, 0.7613646, 0.7595363, 0.75795877, 0.75664705, 0.7556137, 0.75486845, 0.75441843, 0.75426793, 0.75441843, 0.75486845, 0.7556137, 0.75664705, 0.75795877, 0.7595363, 0.7613646, 0.7634263, 0.7657017, 0.7681691, 0.770805, 0.77358425, 0.7764802, 0.77946526, 0.7825109, 0.7855878, 0.7886666, 0.7917177, 0.7947118, 0.79762006, 0.80041444, 0.8030679, 0.8055548, 0.8078509, 0.80993384, 0.8117833, 0.81338096, 0.81471103, 0.81576014, 0.81651765, 0.8169756, 0.8171289, 0.81697536, 0.8165157, 0.81575346, 0.8146953, 0.81335044, 0.81173116, 0.80985206, 0.80773056, 0.8053862, 0.8028409, 0.8001184, 0.7972443, 0.7942457, 0.79115105, 0.7879897, 0.78479177, 0.7815879, 0.7784089, 0.7752853, 0.77224743, 0.76932484, 0.766546, 0.76393837, 0.76152766, 0.75933796, 0.7573912, 0.7557073, 0.7543037, 0.7531951, 0.7523936, 0.7519084, 0.7517458, 0.75190896, 0.752398, 0.7532099, 0.7543385, 0.7557748, 0.75750655, 0.7595188, 0.76179385, 0.7643112, 0.7670481, 0.76997954, 0.7730785, 0.7763162, 0.7796623, 0.78308535, 0.7865528, 0.7900317, 0.7934885, 0.7968899, 0.8002027, 0.8033945, 0.80643356, 0.8092897, 0.811934, 0.8143394, 0.8164811, 0.8183364, 0.8198853, 0.8211105, 0.8219975, 0.82253534, 0.8227158, 0.8225343, 0.82198966, 0.82108396, 0.8198229, 0.81821555, 0.81627434, 0.81401515, 0.81145674, 0.80862117, 0.8055331, 0.80222005, 0.7987118, 0.7950402, 0.7912392, 0.7873441, 0.78339154, 0.779419, 0.7754647, 0.7715668, 0.7677636, 0.76409274, 0.76059103, 0.7572941, 0.754236, 0.7514488, 0.7489626, 0.74680465, 0.74499965, 0.74356914, 0.74253136, 0.7419009, 0.741689, 0.7419027, 0.74254525, 0.7436158, 0.74510956, 0.74701744, 0.7493266, 0.7520199, 0.75507647, 0.7584718, 0.76217765, 0.76616263, 0.77039206, 0.7748287, 0.7794325, 0.7841615, 0.7889719, 0.79381835, 0.79865456, 0.80343366, 0.8081085, 0.8126323, 0.81695884, 0.8210432, 0.8248418, 0.8283132, 0.83141834, 0.8341209, 0.8363879, 0.83818966, 0.83950055, 0.8402991, 0.84056836, 0.84029585, 0.8394741, 0.83810073, 0.8361785, 0.83371526, 0.8307243, 0.82722396, 0.82323796, 0.8187949, 0.8139283, 0.8086764, 0.8030819, 0.7971916, 0.7910561, 0.78472936, 0.7782685, 0.77173316, 0.76518494, 0.7586871, 0.752304, 0.74610037, 0.74014103, 0.7344899, 0.72920996, 0.7243621, 0.72000486, 0.7161939, 0.7129813, 0.71041477, 0.70853776, 0.7073883, 0.70699894, 0.7073961, 0.70859975, 0.71062315, 0.71347225, 0.71714586, 0.72163504, 0.72692305, 0.73298544, 0.7397898, 0.7472958, 0.75545543, 0.76421297, 0.77350515, 0.7832616, 0.7934051, 0.8038518, 0.8145118, 0.8252896, 0.83608466, 0.84679186, 0.85730225, 0.8675037, 0.8772814, 0.8865189, 0.8950985, 0.9029024, 0.9098131, 0.9157145, 0.92049253, 0.92403597, 0.92623717, 0.926993, 0.9262054, 0.92378235, 0.9196382, 0.9136947, 0.90588135, 0.8961362, 0.8844062, 0.8706478, 0.8548271, 0.8369207, 0.8169154, 0.794809, 0.77061, 0.7443378, 0.7160229, 0.6857068, 0.6534416, 0.61929005, 0.5833253, 0.54563046, 0.5062982, 0.46543032, 0.42313722, 0.37953734, 0.33475646, 0.28892705, 0.24218756, 0.19468157, 0.14655708, 0.09796555, 0.049061213, 1.2662533e-15, -0.049061213, -0.09796555, -0.14655708, -0.19468157, -0.24218756, -0.28892705, -0.33475646, -0.37953734, -0.42313722, -0.46543032, -0.5062982, -0.54563046, -0.5833253, -0.61929005, -0.6534416, -0.6857068, -0.7160229, -0.7443378, -0.77061, -0.794809, -0.8169154, -0.8369207, -0.8548271, -0.8706478, -0.8844062, -0.8961362, -0.90588135, -0.9136947, -0.9196382, -0.92378235, -0.9262054, -0.926993, -0.92623717, -0.92403597, -0.92049253, -0.9157145, -0.9098131, -0.9029024, -0.8950985, -0.8865189, -0.8772814, -0.8675037, -0.85730225, -0.84679186, -0.83608466, -0.8252896, -0.8145118, -0.8038518, -0.7934051, -0.7832616, -0.77350515, -0.76421297, -0.75545543, -0.7472958, -0.7397898, -0.73298544, -0.72692305, -0.72163504, -0.71714586, -0.71347225, -0.71062315, -0.70859975, -0.7073961, -0.70699894, -0.7073883, -0.70853776, -0.71041477, -0.7129813, -0.7161939, -0.72000486, -0.7243621, -0.72920996, -0.7344899, -0.74014103, -0.74610037, -0.752304, -0.7586871, -0.76518494, -0.77173316, -0.7782685, -0.78472936, -0.7910561, -0.7971916, -0.8030819, -0.8086764, -0.8139283, -0.8187949, -0.82323796, -0.82722396, -0.8307243, -0.83371526, -0.8361785, -0.83810073, -0.8394741, -0.84029585, -0.84056836, -0.8402991, -0.83950055, -0.83818966, -0.8363879, -0.8341209, -0.83141834, -0.8283132, -0.8248418, -0.8210432, -0.81695884, -0.8126323, -0.8081085, -0.80343366, -0.79865456, -0.79381835, -0.7889719, -0.7841615, -0.7794325, -0.7748287, -0.77039206, -0.76616263, -0.76217765, -0.7584718, -0.75507647, -0.7520199, -0.7493266, -0.74701744, -0.74510956, -0.7436158, -0.74254525, -0.7419027, -0.741689, -0.7419009, -0.74253136, -0.74356914, -0.74499965, -0.74680465, -0.7489626, -0.7514488, -0.754236, -0.7572941, -0.76059103, -0.76409274, -0.7677636, -0.7715668, -0.7754647, -0.779419, -0.78339154, -0.7873441, -0.7912392, -0.7950402, -0.7987118, -0.80222005, -0.8055331, -0.80862117, -0.81145674, -0.81401515, -0.81627434, -0.81821555, -0.8198229, -0.82108396, -0.82198966, -0.8225343, -0.8227158, -0.82253534, -0.8219975, -0.8211105, -0.8198853, -0.8183364, -0.8164811, -0.8143394, -0.811934, -0.8092897, -0.80643356, -0.8033945, -0.8002027, -0.7968899, -0.7934885, -0.7900317, -0.7865528, -0.78308535, -0.7796623, -0.7763162, -0.7730785, -0.76997954, -0.7670481, -0.7643112, -0.76179385, -0.7595188, -0.75750655, -0.7557748, -0.7543385, -0.7532099, -0.752398, -0.75190896, -0.7517458, -0.7519084, -0.7523936, -0.7531951, -0.7543037, -0.7557073, -0.7573912, -0.75933796, -0.76152766, -0.76393837, -0.766546, -0.76932484, -0.77224743, -0.7752853, -0.7784089, -0.7815879, -0.78479177, -0.7879897, -0.79115105, -0.7942457, -0.7972443, -0.8001184, -0.8028409, -0.8053862, -0.80773056, -0.80985206, -0.81173116, -0.81335044, -0.8146953, -0.81575346, -0.8165157, -0.81697536, -0.8171289, -0.8169756, -0.81651765, -0.81576014, -0.81471103, -0.81338096, -0.8117833, -0.80993384, -0.8078509, -0.8055548, -0.8030679, -0.80041444, -0.79762006, -0.7947118, -0.7917177, -0.7886666, -0.7855878, -0.7825109, -0.77946526, -0.7764802, -0.77358425, -0.770805, -0.7681691, -0.7657017, -0.7634263, -0.7613646, -0.7595363, -0.75795877, -0.75664705, -0.7556137, -0.75486845, -0.75441843, -0.75426793, -0.75441843, -0.75486845, -0.7556137, -0.75664705, -0.75795877, -0.7595363, -0.7613646, -0.7634263, -0.7657017, -0.7681691, -0.770805, -0.77358425, -0.7764802, -0.77946526, -0.7825109, -0.7855878, -0.7886666, -0.7917177, -0.7947118, -0.79762006, -0.80041444, -0.8030679, -0.8055548, -0.8078509, -0.80993384, -0.8117833, -0.81338096, -0.81471103, -0.81576014, -0.81651765, -0.8169756, -0.8171289, -0.81697536, -0.8165157, -0.81575346, -0.8146953, -0.81335044, -0.81173116, -0.80985206, -0.80773056, -0.8053862, -0.8028409, -0.8001184, -0.7972443, -0.7942457, -0.79115105, -0.7879897, -0.78479177, -0.7815879, -0.7784089, -0.7752853, -0.77224743, -0.76932484, -0.766546, -0.76393837, -0.76152766, -0.75933796, -0.7573912, -0.7557073, -0.7543037, -0.7531951, -0.7523936, -0.7519084, -0.7517458, -0.75190896, -0.752398, -0.7532099, -0.7543385, -0.7557748, -0.75750655, -0.7595188, -0.76179385, -0.7643112, -0.7670481, -0.76997954, -0.7730785, -0.7763162, -0.7796623, -0.78308535, -0.7865528, -0.7900317, -0.7934885, -0.7968899, -0.8002027, -0.8033945, -0.80643356, -0.8092897, -0.811934, -0.8143394, -0.8164811, -0.8183364, -0.8198853, -0.8211105, -0.8219975, -0.82253534, -0.8227158, -0.8225343, -0.82198966, -0.82108396, -0.8198229, -0.81821555, -0.81627434, -0.81401515, -0.81145674, -0.80862117, -0.8055331, -0.80222005, -0.7987118, -0.7950402, -0.7912392, -0.7873441, -0.78339154, -0.779419, -0.7754647, -0.7715668, -0.7677636, -0.76409274, -0.76059103, -0.7572941, -0.754236, -0.7514488, -0.7489626, -0.74680465, -0.74499965, -0.74356914, -0.74253136, -0.7419009, -0.741689, -0.7419027, -0.74254525, -0.7436158, -0.74510956, -0.74701744, -0.7493266, -0.7520199, -0.75507647, -0.7584718, -0.76217765, -0.76616263, -0.77039206, -0.7748287, -0.7794325, -0.7841615, -0.7889719, -0.79381835, -0.79865456, -0.80343366, -0.8081085, -0.8126323, -0.81695884, -0.8210432, -0.8248418, -0.8283132, -0.83141834, -0.8341209, -0.8363879, -0.83818966, -0.83950055, -0.8402991, -0.84056836, -0.84029585, -0.8394741, -0.83810073, -0.8361785, -0.83371526, -0.8307243, -0.82722396, -0.82323796, -0.8187949, -0.8139283, -0.8086764, -0.8030819, -0.7971916, -0.7910561, -0.78472936, -0.7782685, -0.77173316, -0.76518494, -0.7586871, -0.752304, -0.74610037, -0.74014103, -0.7344899, -0.72920996, -0.7243621, -0.72000486, -0.7161939, -0.7129813, -0.71041477, -0.70853776, -0.7073883, -0.70699894, -0.7073961, -0.70859975, -0.71062315, -0.71347225, -0.71714586, -0.72163504, -0.72692305, -0.73298544, -0.7397898, -0.7472958, -0.75545543, -0.76421297, -0.77350515, -0.7832616, -0.7934051, -0.8038518, -0.8145118, -0.8252896, -0.83608466, -0.84679186, -0.85730225, -0.8675037, -0.8772814, -0.8865189, -0.8950985, -0.9029024, -0.9098131, -0.9157145, -0.92049253, -0.92403597, -0.92623717, -0.926993, -0.9262054, -0.92378235, -0.9196382, -0.9136947, -0.90588135, -0.8961362, -0.8844062, -0.8706478, -0.8548271, -0.8369207, -0.8169154, -0.794809, -0.77061, -0.7443378, -0.7160229, -0.6857068, -0.6534416, -0.61929005, -0.5833253, -0.54563046, -0.5062982, -0.46543032, -0.42313722, -0.37953734, -0.33475646, -0.28892705, -0.24218756, -0.19468157, -0.14655708, -0.09796555, -0.049061213, ], [0.0, 0.024540458, 0.04906152, 0.07354381, 0.09796801, 0.12231489, 0.14656532, 0.1707003, 0.19470099, 0.21854876, 0.24222516, 0.265712, 0.28899133, 0.31204554, 0.33485729, 0.35740954, 0.37968567, 0.4016694, 0.42334494, 0.4446968, 0.46571, 0.48637006, 0.5066629, 0.52657497, 0.5460933, 0.5652054, 0.58389926, 0.6021637, 0.6199877, 0.63736135, 0.6542749, 0.6707194, 0.6866865, 0.7021686, 0.7171586, 0.7316501, 0.7456374, 0.7591154, 0.7720797, 0.7845265, 0.7964529, 0.8078564, 0.81873524, 0.8290885, 0.83891577, 0.8482173, 0.8569941, 0.8652477, 0.87298054, 0.8801954, 0.8868958, 0.89308596, 0.8987707, 0.90395534, 0.9086459, 0.912849, 0.9165717, 0.9198216, 0.9226071, 0.9249368, 0.9268199, 0.9282662, 0.9292859, 0.92988956, 0.9300882, 0.92989343, 0.929317, 0.9283711, 0.9270683, 0.9254215, 0.9234438, 0.9211488, 0.91855, 0.9156615, 0.9124973, 0.9090718, 0.9053994, 0.9014947, 0.8973724, 0.8930474, 0.88853437, 0.8838483, 0.87900406, 0.8740165, 0.8689005, 0.8636707, 0.858342, 0.8529288, 0.84744567, 0.84190685, 0.8363265, 0.8307185, 0.8250966, 0.8194743, 0.8138647, 0.8082808, 0.8027353, 0.79724056, 0.7918084, 0.78645056, 0.7811784, 0.7760027, 0.7709341, 0.76598257, 0.7611579, 0.7564693, 0.7519256, 0.7475351, 0.74330574, 0.73924494, 0.7353596, 0.7316563, 0.72814083, 0.7248188, 0.7216951, 0.71877426, 0.71606016, 0.7135563, 0.71126556, 0.7091905, 0.7073329, 0.7056944, 0.70427567, 0.7030774, 0.7020993, 0.7013411, 0.70080155, 0.7004793, 0.70037246, 0.7004785, 0.70079464, 0.7013176, 0.7020438, 0.702969, 0.70408887, 0.70539844, 0.70689255, 0.7085656, 0.71041167, 0.7124245, 0.71459764, 0.71692413, 0.719397, 0.7220089, 0.7247522, 0.72761905, 0.73060155, 0.7336915, 0.73688054, 0.7401602, 0.7435219, 0.74695694, 0.7504565, 0.75401175, 0.7576138, 0.7612537, 0.7649226, 0.7686115, 0.77231157, 0.7760139, 0.7797098, 0.78339064, 0.78704774, 0.7906727, 0.7942573, 0.7977932, 0.8012726, 0.80468756, 0.8080306, 0.81129426, 0.81447154, 0.8175554, 0.82053936, 0.82341695, 0.8261821, 0.82882917, 0.83135253, 0.83374715, 0.83600813, 0.83813107, 0.8401117, 0.8419463, 0.8436314, 0.8451639, 0.8465411, 0.8477607, 0.8488206, 0.8497193, 0.8504556, 0.8510285, 0.8514376, 0.85168284, 0.8517645, 0.85168314, 0.85143983, 0.85103595, 0.85047317, 0.8497537, 0.8488798, 0.8478544, 0.8466805, 0.8453617, 0.8439016, 0.8423043, 0.8405741, 0.83871573, 0.83673406, 0.83463424, 0.8324217, 0.8301022, 0.8276815, 0.8251657, 0.82256114, 0.8198743, 0.8171118, 0.8142804, 0.811387, 0.8084387, 0.8054427, 0.80240613, 0.7993364, 0.79624075, 0.79312664, 0.7900015, 0.78687274, 0.7837478, 0.7806341, 0.77753896, 0.7744696, 0.7714334, 0.7684373, 0.7654885, 0.76259375, 0.75975984, 0.7569934, 0.75430083, 0.7516885, 0.7491624, 0.7467283, 0.744392, 0.74215883, 0.7400339, 0.73802227, 0.73612845, 0.7343568, 0.73271155, 0.73119634, 0.72981477, 0.7285699, 0.7274647, 0.72650164, 0.72568303, 0.72501063, 0.72448605, 0.7241105, 0.7238848, 0.72380954, 0.7238848, 0.7241105, 0.72448605, 0.72501063, 0.72568303, 0.72650164, 0.7274647, 0.7285699, 0.72981477, 0.73119634, 0.73271155, 0.7343568, 0.73612845, 0.73802227, 0.7400339, 0.74215883, 0.744392, 0.7467283, 0.7491624, 0.7516885, 0.75430083, 0.7569934, 0.75975984, 0.76259375, 0.7654885, 0.7684373, 0.7714334, 0.7744696, 0.77753896, 0.7806341, 0.7837478, 0.78687274, 0.7900015, 0.79312664, 0.79624075, 0.7993364, 0.80240613, 0.8054427, 0.8084387, 0.811387, 0.8142804, 0.8171118, 0.8198743, 0.82256114, 0.8251657, 0.8276815, 0.8301022, 0.8324217, 0.83463424, 0.83673406, 0.83871573, 0.8405741, 0.8423043, 0.8439016, 0.8453617, 0.8466805, 0.8478544, 0.8488798, 0.8497537, 0.85047317, 0.85103595, 0.85143983, 0.85168314, 0.8517645, 0.85168284, 0.8514376, 0.8510285, 0.8504556, 0.8497193, 0.8488206, 0.8477607, 0.8465411, 0.8451639, 0.8436314, 0.8419463, 0.8401117, 0.83813107, 0.83600813, 0.83374715, 0.83135253, 0.82882917, 0.8261821, 0.82341695, 0.82053936, 0.8175554, 0.81447154, 0.81129426, 0.8080306, 0.80468756, 0.8012726, 0.7977932, 0.7942573, 0.7906727, 0.78704774, 0.78339064, 0.7797098, 0.7760139, 0.77231157, 0.7686115, 0.7649226, 0.7612537, 0.7576138, 0.75401175, 0.7504565, 0.74695694, 0.7435219, 0.7401602, 0.73688054, 0.7336915, 0.73060155, 0.72761905, 0.7247522, 0.7220089, 0.719397, 0.71692413, 0.71459764, 0.7124245, 0.71041167, 0.7085656, 0.70689255, 0.70539844, 0.70408887, 0.702969, 0.7020438, 0.7013176, 0.70079464, 0.7004785, 0.70037246, 0.7004793, 0.70080155, 0.7013411, 0.7020993, 0.7030774, 0.70427567, 0.7056944, 0.7073329, 0.7091905, 0.71126556, 0.7135563, 0.71606016, 0.71877426, 0.7216951, 0.7248188, 0.72814083, 0.7316563, 0.7353596, 0.73924494, 0.74330574, 0.7475351, 0.7519256, 0.7564693, 0.7611579, 0.76598257, 0.7709341, 0.7760027, 0.7811784, 0.78645056, 0.7918084, 0.79724056, 0.8027353, 0.8082808, 0.8138647, 0.8194743, 0.8250966, 0.8307185, 0.8363265, 0.84190685, 0.84744567, 0.8529288, 0.858342, 0.8636707, 0.8689005, 0.8740165, 0.87900406, 0.8838483, 0.88853437, 0.8930474, 0.8973724, 0.9014947, 0.9053994, 0.9090718, 0.9124973, 0.9156615, 0.91855, 0.9211488, 0.9234438, 0.9254215, 0.9270683, 0.9283711, 0.929317, 0.92989343, 0.9300882, 0.92988956, 0.9292859, 0.9282662, 0.9268199, 0.9249368, 0.9226071, 0.9198216, 0.9165717, 0.912849, 0.9086459, 0.90395534, 0.8987707, 0.89308596, 0.8868958, 0.8801954, 0.87298054, 0.8652477, 0.8569941, 0.8482173, 0.83891577, 0.8290885, 0.81873524, 0.8078564, 0.7964529, 0.7845265, 0.7720797, 0.7591154, 0.7456374, 0.7316501, 0.7171586, 0.7021686, 0.6866865, 0.6707194, 0.6542749, 0.63736135, 0.6199877, 0.6021637, 0.58389926, 0.5652054, 0.5460933, 0.52657497, 0.5066629, 0.48637006, 0.46571, 0.4446968, 0.42334494, 0.4016694, 0.37968567, 0.35740954, 0.33485729, 0.31204554, 0.28899133, 0.265712, 0.24222516, 0.21854876, 0.19470099, 0.1707003, 0.14656532, 0.12231489, 0.09796801, 0.07354381, 0.04906152, 0.024540458, 4.8985874e-16, -0.024540458, -0.04906152, -0.07354381, -0.09796801, -0.12231489, -0.14656532, -0.1707003, -0.19470099, -0.21854876, -0.24222516, -0.265712, -0.28899133, -0.31204554, -0.33485729, -0.35740954, -0.37968567, -0.4016694, -0.42334494, -0.4446968, -0.46571, -0.48637006, -0.5066629, -0.52657497, -0.5460933, -0.5652054, -0.58389926, -0.6021637, -0.6199877, -0.63736135, -0.6542749, -0.6707194, -0.6866865, -0.7021686, -0.7171586, -0.7316501, -0.7456374, -0.7591154, -0.7720797, -0.7845265, -0.7964529, -0.8078564, -0.81873524, -0.8290885, -0.83891577, -0.8482173, -0.8569941, -0.8652477, -0.87298054, -0.8801954, -0.8868958, -0.89308596, -0.8987707, -0.90395534, -0.9086459, -0.912849, -0.9165717, -0.9198216, -0.9226071, -0.9249368, -0.9268199, -0.9282662, -0.9292859, -0.92988956, -0.9300882, -0.92989343, -0.929317, -0.9283711, -0.9270683, -0.9254215, -0.9234438, -0.9211488, -0.91855, -0.9156615, -0.9124973, -0.9090718, -0.9053994, -0.9014947, -0.8973724, -0.8930474, -0.88853437, -0.8838483, -0.87900406, -0.8740165, -0.8689005, -0.8636707, -0.858342, -0.8529288, -0.84744567, -0.84190685, -0.8363265, -0.8307185, -0.8250966, -0.8194743, -0.8138647, -0.8082808, -0.8027353, -0.79724056, -0.7918084, -0.78645056, -0.7811784, -0.7760027, -0.7709341, -0.76598257, -0.7611579, -0.7564693, -0.7519256, -0.7475351, -0.74330574, -0.73924494, -0.7353596, -0.7316563, -0.72814083, -0.7248188, -0.7216951, -0.71877426, -0.71606016, -0.7135563, -0.71126556, -0.7091905, -0.7073329, -0.7056944, -0.70427567, -0.7030774, -0.7020993, -0.7013411, -0.70080155, -0.7004793, -0.70037246, -0.7004785, -0.70079464, -0.7013176, -0.7020438, -0.702969, -0.70408887, -0.70539844, -0.70689255, -0.7085656, -0.71041167, -0.7124245, -0.71459764, -0.71692413, -0.719397, -0.7220089, -0.7247522, -0.72761905, -0.73060155, -0.7336915, -0.73688054, -0.7401602, -0.7435219, -0.74695694, -0.7504565, -0.75401175, -0.7576138, -0.7612537, -0.7649226, -0.7686115, -0.77231157, -0.7760139, -0.7797098, -0.78339064, -0.78704774, -0.7906727, -0.7942573, -0.7977932, -0.8012726, -0.80468756, -0.8080306, -0.81129426, -0.81447154, -0.8175554, -0.82053936, -0.82341695, -0.8261821, -0.82882917, -0.83135253, -0.83374715, -0.83600813, -0.83813107, -0.8401117, -0.8419463, -0.8436314, -0.8451639, -0.8465411, -0.8477607, -0.8488206, -0.8497193, -0.8504556, -0.8510285, -0.8514376, -0.85168284, -0.8517645, -0.85168314, -0.85143983, -0.85103595, -0.85047317, -0.8497537, -0.8488798, -0.8478544, -0.8466805, -0.8453617, -0.8439016, -0.8423043, -0.8405741, -0.83871573, -0.83673406, -0.83463424, -0.8324217, -0.8301022, -0.8276815, -0.8251657, -0.82256114, -0.8198743, -0.8171118, -0.8142804, -0.811387, -0.8084387, -0.8054427, -0.80240613, -0.7993364, -0.79624075, -0.79312664, -0.7900015, -0.78687274, -0.7837478, -0.7806341, -0.77753896, -0.7744696, -0.7714334, -0.7684373, -0.7654885, -0.76259375, -0.75975984, -0.7569934, -0.75430083, -0.7516885, -0.7491624, -0.7467283, -0.744392, -0.74215883, -0.7400339, -0.73802227, -0.73612845, -0.7343568, -0.73271155, -0.73119634, -0.72981477, -0.7285699, -0.7274647, -0.72650164, -0.72568303, -0.72501063, -0.72448605, -0.7241105, -0.7238848, -0.72380954, -0.7238848, -0.7241105, -0.72448605, -0.72501063, -0.72568303, -0.72650164, -0.7274647, -0.7285699, -0.72981477, -0.73119634, -0.73271155, -0.7343568, -0.73612845, -0.73802227, -0.7400339, -0.74215883, -0.744392, -0.7467283, -0.7491624, -0.7516885, -0.75430083, -0.7569934, -0.75975984, -0.76259375, -0.7654885, -0.7684373, -0.7714334, -0.7744696, -0.77753896, -0.7806341, -0.7837478, -0.78687274, -0.7900015, -0.79312664, -0.79624075, -0.7993364, -0.80240613, -0.8054427, -0.8084387, -0.811387, -0.8142804, -0.8171118, -0.8198743, -0.82256114, -0.8251657, -0.8276815, -0.8301022, -0.8324217, -0.83463424, -0.83673406, -0.83871573, -0.8405741, -0.8423043, -0.8439016, -0.8453617, -0.8466805, -0.8478544, -0.8488798, -0.8497537, -0.85047317, -0.85103595, -0.85143983, -0.85168314, -0.8517645, -0.85168284, -0.8514376, -0.8510285, -0.8504556, -0.8497193, -0.8488206, -0.8477607, -0.8465411, -0.8451639, -0.8436314, -0.8419463, -0.8401117, -0.83813107, -0.83600813, -0.83374715, -0.83135253, -0.82882917, -0.8261821, -0.82341695, -0.82053936, -0.8175554, -0.81447154, -0.81129426, -0.8080306, -0.80468756, -0.8012726, -0.7977932, -0.7942573, -0.7906727, -0.78704774, -0.78339064, -0.7797098, -0.7760139, -0.77231157, -0.7686115, -0.7649226, -0.7612537, -0.7576138, -0.75401175, -0.7504565, -0.74695694, -0.7435219, -0.7401602, -0.73688054, -0.7336915, -0.73060155, -0.72761905, -0.7247522, -0.7220089, -0.719397, -0.71692413, -0.71459764, -0.7124245, -0.71041167, -0.7085656, -0.70689255, -0.70539844, -0.70408887, -0.702969, -0.7020438, -0.7013176, -0.70079464, -0.7004785, -0.70037246, -0.7004793, -0.70080155, -0.7013411, -0.7020993, -0.7030774, -0.70427567, -0.7056944, -0.7073329, -0.7091905, -0.71126556, -0.7135563, -0.71606016, -0.71877426, -0.7216951, -0.7248188, -0.72814083, -0.7316563, -0.7353596, -0.73924494, -0.74330574, -0.7475351, -0.7519256, -0.7564693, -0.7611579, -0.76598257, -0.7709341, -0.7760027, -0.7811784, -0.78645056, -0.7918084, -0.79724056, -0.8027353, -0.8082808, -0.8138647, -0.8194743, -0.8250966, -0.8307185, -0.8363265, -0.84190685, -0.84744567, -0.8529288, -0.858342, -0.8636707, -0.8689005, -0.8740165, -0.87900406, -0.8838483, -0.88853437, -0.8930474, -0.8973724, -0.9014947, -0.9053994, -0.9090718, -0.9124973, -0.9156615, -0.91855, -0.9211488, -0.9234438, -0.9254215, -0.9270683, -0.9283711, -0.929317, -0.92989343, -0.9300882, -0.92988956, -0.9292859, -0.9282662, -0.9268199, -0.9249368, -0.9226071, -0.9198216, -0.9165717, -0.912849, -0.9086459, -0.90395534, -0.8987707, -0.89308596, -0.8868958, -0.8801954, -0.87298054, -0.8652477, -0.8569941, -0.8482173, -0.83891577, -0.8290885, -0.81873524, -0.8078564, -0.7964529, -0.7845265, -0.7720797, -0.7591154, -0.7456374, -0.7316501, -0.7171586, -0.7021686, -0.6866865, -0.6707194, -0.6542749, -0.63736135, -0.6199877, -0.6021637, -0.58389926, -0.5652054, -0.5460933, -0.52657497, -0.5066629, -0.48637006, -0.46571, -0.4446968, -0.42334494, -0.4016694, -0.37968567, -0.35740954, -0.33485729, -0.31204554, -0.28899133, -0.265712, -0.24222516, -0.21854876, -0.19470099, -0.1707003, -0.14656532, -0.12231489, -0.09796801, -0.07354381, -0.04906152, -0.024540458, ], [0.0, 0.012271461, 0.024540612, 0.036805145, 0.04906275, 0.061311122, 0.07354796, 0.08577096, 0.09797783, 0.11016628, 0.12233403, 0.13447879, 0.14659831, 0.1586903, 0.17075253, 0.18278274, 0.1947787, 0.20673817, 0.21865897, 0.23053887, 0.24237569, 0.25416726, 0.2659114, 0.27760595, 0.28924882, 0.3008379, 0.31237105, 0.3238462, 0.3352613, 0.34661427, 0.35790312, 0.3691258, 0.3802804, 0.39136487, 0.40237734, 0.4133158, 0.42417845, 0.43496332, 0.4456686, 0.4562925, 0.46683317, 0.47728884, 0.48765776, 0.4979382, 0.50812846, 0.51822686, 0.5282318, 0.53814167, 0.54795486, 0.55766976, 0.56728494, 0.5767988, 0.58621, 0.59551704, 0.6047185, 0.6138131, 0.6227994, 0.63167614, 0.6404421, 0.6490959, 0.65763646, 0.6660626, 0.6743732, 0.6825671, 0.69064325, 0.6986007, 0.70643836, 0.7141554, 0.72175074, 0.72922355, 0.73657304, 0.74379843, 0.75089884, 0.7578736, 0.76472205, 0.7714434, 0.7780372, 0.7845028, 0.7908396, 0.79704714, 0.80312496, 0.8090727, 0.8148898, 0.8205761, 0.8261311, 0.8315547, 0.8368466, 0.8420066, 0.8470346, 0.8519303, 0.85669386, 0.86132514, 0.8658241, 0.87019086, 0.8744255, 0.878528, 0.8824987, 0.88633764, 0.8900451, 0.89362144, 0.8970669, 0.9003818, 0.90356654, 0.9066216, 0.9095473, 0.91234434, 0.9150131, 0.91755414, 0.9199682, 0.92225575, 0.9244176, 0.9264545, 0.928367, 0.9301561, 0.9318225, 0.9333671, 0.9347907, 0.93609434, 0.93727887, 0.9383454, 0.9392948, 0.9401282, 0.94084674, 0.94145143, 0.94194347, 0.94232404, 0.94259435, 0.9427556, 0.94280905, 0.942756, 0.9425978, 0.94233584, 0.94197136, 0.94150585, 0.9409408, 0.9402775, 0.93951756, 0.9386624, 0.9377137, 0.9366728, 0.9355414, 0.93432105, 0.9330134, 0.93162006, 0.9301427, 0.92858297, 0.92694265, 0.92522335, 0.92342687, 0.9215549, 0.9196093, 0.91759175, 0.91550416, 0.91334826, 0.91112584, 0.90883887, 0.9064891, 0.90407836, 0.90160865, 0.8990818, 0.8964997, 0.8938643, 0.8911774, 0.8884411, 0.88565713, 0.8828276, 0.8799543, 0.8770393, 0.8740845, 0.8710919, 0.8680634, 0.86500096, 0.8619066, 0.85878223, 0.85562986, 0.8524514, 0.8492489, 0.8460242, 0.8427794, 0.83951634, 0.836237, 0.8329434, 0.82963735, 0.8263209, 0.8229959, 0.81966436, 0.8163281, 0.81298906, 0.80964917, 0.8063103, 0.8029743, 0.79964304, 0.7963184, 0.7930022, 0.7896962, 0.78640234, 0.78312236, 0.779858, 0.7766111, 0.7733833, 0.7701765, 0.7669923, 0.76383233, 0.7606984, 0.75759214, 0.7545151, 0.751469, 0.7484554, 0.7454759, 0.742532, 0.7396253, 0.73675716, 0.7339292, 0.7311429, 0.7283995, 0.7257006, 0.72304755, 0.72044164, 0.7178842, 0.71537656, 0.71292, 0.71051574, 0.708165, 0.7058689, 0.7036287, 0.7014455, 0.69932044, 0.6972544, 0.69524866, 0.693304, 0.69142157, 0.68960214, 0.6878467, 0.6861562, 0.68453133, 0.682973, 0.68148196, 0.6800589, 0.6787045, 0.67741954, 0.6762046, 0.6750603, 0.67398715, 0.6729857, 0.67205644, 0.6711998, 0.6704163, 0.6697062, 0.66906995, 0.6685078, 0.66802007, 0.66760695, 0.6672687, 0.66700536, 0.66681725, 0.6667043, 0.6666667, 0.6667043, 0.66681725, 0.66700536, 0.6672687, 0.66760695, 0.66802007, 0.6685078, 0.66906995, 0.6697062, 0.6704163, 0.6711998, 0.67205644, 0.6729857, 0.67398715, 0.6750603, 0.6762046, 0.67741954, 0.6787045, 0.6800589, 0.68148196, 0.682973, 0.68453133, 0.6861562, 0.6878467, 0.68960214, 0.69142157, 0.693304, 0.69524866, 0.6972544, 0.69932044, 0.7014455, 0.7036287, 0.7058689, 0.708165, 0.71051574, 0.71292, 0.71537656, 0.7178842, 0.72044164, 0.72304755, 0.7257006, 0.7283995, 0.7311429, 0.7339292, 0.73675716, 0.7396253, 0.742532, 0.7454759, 0.7484554, 0.751469, 0.7545151, 0.75759214, 0.7606984, 0.76383233, 0.7669923, 0.7701765, 0.7733833, 0.7766111, 0.779858, 0.78312236, 0.78640234, 0.7896962, 0.7930022, 0.7963184, 0.79964304, 0.8029743, 0.8063103, 0.80964917, 0.81298906, 0.8163281, 0.81966436, 0.8229959, 0.8263209, 0.82963735, 0.8329434, 0.836237, 0.83951634, 0.8427794, 0.8460242, 0.8492489, 0.8524514, 0.85562986, 0.85878223, 0.8619066, 0.86500096, 0.8680634, 0.8710919, 0.8740845, 0.8770393, 0.8799543, 0.8828276, 0.88565713, 0.8884411, 0.8911774, 0.8938643, 0.8964997, 0.8990818, 0.90160865, 0.90407836, 0.9064891, 0.90883887, 0.91112584, 0.91334826, 0.91550416, 0.91759175, 0.9196093, 0.9215549, 0.92342687, 0.92522335, 0.92694265, 0.92858297, 0.9301427, 0.93162006, 0.9330134, 0.93432105, 0.9355414, 0.9366728, 0.9377137, 0.9386624, 0.93951756, 0.9402775, 0.9409408, 0.94150585, 0.94197136, 0.94233584, 0.9425978, 0.942756, 0.94280905, 0.9427556, 0.94259435, 0.94232404, 0.94194347, 0.94145143, 0.94084674, 0.9401282, 0.9392948, 0.9383454, 0.93727887, 0.93609434, 0.9347907, 0.9333671, 0.9318225, 0.9301561, 0.928367, 0.9264545, 0.9244176, 0.92225575, 0.9199682, 0.91755414, 0.9150131, 0.91234434, 0.9095473, 0.9066216, 0.90356654, 0.9003818, 0.8970669, 0.89362144, 0.8900451, 0.88633764, 0.8824987, 0.878528, 0.8744255, 0.87019086, 0.8658241, 0.86132514, 0.85669386, 0.8519303, 0.8470346, 0.8420066, 0.8368466, 0.8315547, 0.8261311, 0.8205761, 0.8148898, 0.8090727, 0.80312496, 0.79704714, 0.7908396, 0.7845028, 0.7780372, 0.7714434, 0.76472205, 0.7578736, 0.75089884, 0.74379843, 0.73657304, 0.72922355, 0.72175074, 0.7141554, 0.70643836, 0.6986007, 0.69064325, 0.6825671, 0.6743732, 0.6660626, 0.65763646, 0.6490959, 0.6404421, 0.63167614, 0.6227994, 0.6138131, 0.6047185, 0.59551704, 0.58621, 0.5767988, 0.56728494, 0.55766976, 0.54795486, 0.53814167, 0.5282318, 0.51822686, 0.50812846, 0.4979382, 0.48765776, 0.47728884, 0.46683317, 0.4562925, 0.4456686, 0.43496332, 0.42417845, 0.4133158, 0.40237734, 0.39136487, 0.3802804, 0.3691258, 0.35790312, 0.34661427, 0.3352613, 0.3238462, 0.31237105, 0.3008379, 0.28924882, 0.27760595, 0.2659114, 0.25416726, 0.24237569, 0.23053887, 0.21865897, 0.20673817, 0.1947787, 0.18278274, 0.17075253, 0.1586903, 0.14659831, 0.13447879, 0.12233403, 0.11016628, 0.09797783, 0.08577096, 0.07354796, 0.061311122, 0.04906275, 0.036805145, 0.024540612, 0.012271461, 2.4492937e-16, -0.012271461, -0.024540612, -0.036805145, -0.04906275, -0.061311122, -0.07354796, -0.08577096, -0.09797783, -0.11016628, -0.12233403, -0.13447879, -0.14659831, -0.1586903, -0.17075253, -0.18278274, -0.1947787, -0.20673817, -0.21865897, -0.23053887, -0.24237569, -0.25416726, -0.2659114, -0.27760595, -0.28924882, -0.3008379, -0.31237105, -0.3238462, -0.3352613, -0.34661427, -0.35790312, -0.3691258, -0.3802804, -0.39136487, -0.40237734, -0.4133158, -0.42417845, -0.43496332, -0.4456686, -0.4562925, -0.46683317, -0.47728884, -0.48765776, -0.4979382, -0.50812846, -0.51822686, -0.5282318, -0.53814167, -0.54795486, -0.55766976, -0.56728494, -0.5767988, -0.58621, -0.59551704, -0.6047185, -0.6138131, -0.6227994, -0.63167614, -0.6404421, -0.6490959, -0.65763646, -0.6660626, -0.6743732, -0.6825671, -0.69064325, -0.6986007, -0.70643836, -0.7141554, -0.72175074, -0.72922355, -0.73657304, -0.74379843, -0.75089884, -0.7578736, -0.76472205, -0.7714434, -0.7780372, -0.7845028, -0.7908396, -0.79704714, -0.80312496, -0.8090727, -0.8148898, -0.8205761, -0.8261311, -0.8315547, -0.8368466, -0.8420066, -0.8470346, -0.8519303, -0.85669386, -0.86132514, -0.8658241, -0.87019086, -0.8744255, -0.878528, -0.8824987, -0.88633764, -0.8900451, -0.89362144, -0.8970669, -0.9003818, -0.90356654, -0.9066216, -0.9095473, -0.91234434, -0.9150131, -0.91755414, -0.9199682, -0.92225575, -0.9244176, -0.9264545, -0.928367, -0.9301561, -0.9318225, -0.9333671, -0.9347907, -0.93609434, -0.93727887, -0.9383454, -0.9392948, -0.9401282, -0.94084674, -0.94145143, -0.94194347, -0.94232404, -0.94259435, -0.9427556, -0.94280905, -0.942756, -0.9425978, -0.94233584, -0.94197136, -0.94150585, -0.9409408, -0.9402775, -0.93951756, -0.9386624, -0.9377137, -0.9366728, -0.9355414, -0.93432105, -0.9330134, -0.93162006, -0.9301427, -0.92858297, -0.92694265, -0.92522335, -0.92342687, -0.9215549, -0.9196093, -0.91759175, -0.91550416, -0.91334826, -0.91112584, -0.90883887, -0.9064891, -0.90407836, -0.90160865, -0.8990818, -0.8964997, -0.8938643, -0.8911774, -0.8884411, -0.88565713, -0.8828276, -0.8799543, -0.8770393, -0.8740845, -0.8710919, -0.8680634, -0.86500096, -0.8619066, -0.85878223, -0.85562986, -0.8524514, -0.8492489, -0.8460242, -0.8427794, -0.83951634, -0.836237, -0.8329434, -0.82963735, -0.8263209, -0.8229959, -0.81966436, -0.8163281, -0.81298906, -0.80964917, -0.8063103, -0.8029743, -0.79964304, -0.7963184, -0.7930022, -0.7896962, -0.78640234, -0.78312236, -0.779858, -0.7766111, -0.7733833, -0.7701765, -0.7669923, -0.76383233, -0.7606984, -0.75759214, -0.7545151, -0.751469, -0.7484554, -0.7454759, -0.742532, -0.7396253, -0.73675716, -0.7339292, -0.7311429, -0.7283995, -0.7257006, -0.72304755, -0.72044164, -0.7178842, -0.71537656, -0.71292, -0.71051574, -0.708165, -0.7058689, -0.7036287, -0.7014455, -0.69932044, -0.6972544, -0.69524866, -0.693304, -0.69142157, -0.68960214, -0.6878467, -0.6861562, -0.68453133, -0.682973, -0.68148196, -0.6800589, -0.6787045, -0.67741954, -0.6762046, -0.6750603, -0.67398715, -0.6729857, -0.67205644, -0.6711998, -0.6704163, -0.6697062, -0.66906995, -0.6685078, -0.66802007, -0.66760695, -0.6672687, -0.66700536, -0.66681725, -0.6667043, -0.6666667, -0.6667043, -0.66681725, -0.66700536, -0.6672687, -0.66760695, -0.66802007, -0.6685078, -0.66906995, -0.6697062, -0.6704163, -0.6711998, -0.67205644, -0.6729857, -0.67398715, -0.6750603, -0.6762046, -0.67741954, -0.6787045, -0.6800589, -0.68148196, -0.682973, -0.68453133, -0.6861562, -0.6878467, -0.68960214, -0.69142157, -0.693304, -0.69524866, -0.6972544, -0.69932044, -0.7014455, -0.7036287, -0.7058689, -0.708165, -0.71051574, -0.71292, -0.71537656, -0.7178842, -0.72044164, -0.72304755, -0.7257006, -0.7283995, -0.7311429, -0.7339292, -0.73675716, -0.7396253, -0.742532, -0.7454759, -0.7484554, -0.751469, -0.7545151, -0.75759214, -0.7606984, -0.76383233, -0.7669923, -0.7701765, -0.7733833, -0.7766111, -0.779858, -0.78312236, -0.78640234, -0.7896962, -0.7930022, -0.7963184, -0.79964304, -0.8029743, -0.8063103, -0.80964917, -0.81298906, -0.8163281, -0.81966436, -0.8229959, -0.8263209, -0.82963735, -0.8329434, -0.836237, -0.83951634, -0.8427794, -0.8460242, -0.8492489, -0.8524514, -0.85562986, -0.85878223, -0.8619066, -0.86500096, -0.8680634, -0.8710919, -0.8740845, -0.8770393, -0.8799543, -0.8828276, -0.88565713, -0.8884411, -0.8911774, -0.8938643, -0.8964997, -0.8990818, -0.90160865, -0.90407836, -0.9064891, -0.90883887, -0.91112584, -0.91334826, -0.91550416, -0.91759175, -0.9196093, -0.9215549, -0.92342687, -0.92522335, -0.92694265, -0.92858297, -0.9301427, -0.93162006, -0.9330134, -0.93432105, -0.9355414, -0.9366728, -0.9377137, -0.9386624, -0.93951756, -0.9402775, -0.9409408, -0.94150585, -0.94197136, -0.94233584, -0.9425978, -0.942756, -0.94280905, -0.9427556, -0.94259435, -0.94232404, -0.94194347, -0.94145143, -0.94084674, -0.9401282, -0.9392948, -0.9383454, -0.93727887, -0.93609434, -0.9347907, -0.9333671, -0.9318225, -0.9301561, -0.928367, -0.9264545, -0.9244176, -0.92225575, -0.9199682, -0.91755414, -0.9150131, -0.91234434, -0.9095473, -0.9066216, -0.90356654, -0.9003818, -0.8970669, -0.89362144, -0.8900451, -0.88633764, -0.8824987, -0.878528, -0.8744255, -0.87019086, -0.8658241, -0.86132514, -0.85669386, -0.8519303, -0.8470346, -0.8420066, -0.8368466, -0.8315547, -0.8261311, -0.8205761, -0.8148898, -0.8090727, -0.80312496, -0.79704714, -0.7908396, -0.7845028, -0.7780372, -0.7714434, -0.76472205, -0.7578736, -0.75089884, -0.74379843, -0.73657304, -0.72922355, -0.72175074, -0.7141554, -0.70643836, -0.6986007, -0.69064325, -0.6825671, -0.6743732, -0.6660626, -0.65763646, -0.6490959, -0.6404421, -0.63167614, -0.6227994, -0.6138131, -0.6047185, -0.59551704, -0.58621, -0.5767988, -0.56728494, -0.55766976, -0.54795486, -0.53814167, -0.5282318, -0.51822686, -0.50812846, -0.4979382, -0.48765776, -0.47728884, -0.46683317, -0.4562925, -0.4456686, -0.43496332, -0.42417845, -0.4133158, -0.40237734, -0.39136487, -0.3802804, -0.3691258, -0.35790312, -0.34661427, -0.3352613, -0.3238462, -0.31237105, -0.3008379, -0.28924882, -0.27760595, -0.2659114, -0.25416726, -0.24237569, -0.23053887, -0.21865897, -0.20673817, -0.1947787, -0.18278274, -0.17075253, -0.1586903, -0.14659831, -0.13447879, -0.12233403, -0.11016628, -0.09797783, -0.08577096, -0.07354796, -0.061311122, -0.04906275, -0.036805145, -0.024540612, -0.012271461, ], [0.0, 0.0061358847, 0.012271538, 0.01840673, 0.024541229, 0.030674804, 0.036807224, 0.04293826, 0.049067676, 0.055195246, 0.061320737, 0.06744392, 0.07356457, 0.07968244, 0.08579731, 0.091908954, 0.09801714, 0.10412163, 0.110222206, 0.11631863, 0.12241068, 0.1284981, 0.1345807, 0.14065824, 0.14673047, 0.15279719, 0.15885815, 0.16491312, 0.17096189, 0.17700422, 0.18303989, 0.18906866, 0.19509032, 0.20110464, 0.20711137, 0.21311031, 0.21910124, 0.22508392, 0.2310581, 0.2370236, 0.24298018, 0.24892761, 0.25486565, 0.2607941, 0.26671275, 0.27262136, 0.2785197, 0.28440753, 0.29028466, 0.2961509, 0.30200595, 0.30784965, 0.31368175, 0.31950203, 0.3253103, 0.3311063, 0.33688986, 0.34266073, 0.34841868, 0.35416353, 0.35989505, 0.36561298, 0.3713172, 0.37700742, 0.38268343, 0.38834503, 0.39399204, 0.3996242, 0.4052413, 0.41084316, 0.41642955, 0.42200026, 0.42755508, 0.43309382, 0.43861625, 0.44412214, 0.44961134, 0.45508358, 0.46053872, 0.4659765, 0.47139674, 0.47679922, 0.48218378, 0.48755017, 0.4928982, 0.49822766, 0.50353837, 0.50883013, 0.51410276, 0.519356, 0.52458966, 0.52980363, 0.53499764, 0.54017144, 0.545325, 0.55045795, 0.55557024, 0.56066155, 0.5657318, 0.57078075, 0.57580817, 0.58081394, 0.58579785, 0.5907597, 0.5956993, 0.60061646, 0.60551107, 0.6103828, 0.6152316, 0.6200572, 0.6248595, 0.62963825, 0.6343933, 0.63912445, 0.64383155, 0.6485144, 0.65317285, 0.6578067, 0.6624158, 0.66699994, 0.671559, 0.6760927, 0.680601, 0.6850837, 0.68954057, 0.69397146, 0.69837624, 0.70275474, 0.70710677, 0.7114322, 0.71573085, 0.72000253, 0.7242471, 0.72846437, 0.7326543, 0.7368166, 0.7409511, 0.74505776, 0.7491364, 0.7531868, 0.7572088, 0.7612024, 0.76516724, 0.76910335, 0.77301043, 0.7768885, 0.7807372, 0.78455657, 0.7883464, 0.79210657, 0.7958369, 0.79953724, 0.8032075, 0.8068476, 0.81045717, 0.8140363, 0.8175848, 0.8211025, 0.8245893, 0.82804507, 0.8314696, 0.8348629, 0.8382247, 0.841555, 0.8448536, 0.84812033, 0.8513552, 0.854558, 0.8577286, 0.86086696, 0.86397284, 0.86704624, 0.87008697, 0.873095, 0.8760701, 0.8790122, 0.8819213, 0.8847971, 0.88763964, 0.89044875, 0.8932243, 0.89596623, 0.8986745, 0.9013488, 0.9039893, 0.9065957, 0.909168, 0.91170603, 0.9142098, 0.9166791, 0.9191139, 0.92151403, 0.9238795, 0.9262102, 0.9285061, 0.93076694, 0.9329928, 0.9351835, 0.937339, 0.9394592, 0.94154406, 0.94359344, 0.9456073, 0.9475856, 0.94952816, 0.951435, 0.953306, 0.9551412, 0.95694035, 0.95870346, 0.9604305, 0.9621214, 0.96377605, 0.96539444, 0.96697646, 0.9685221, 0.97003126, 0.9715039, 0.97293997, 0.97433937, 0.9757021, 0.97702813, 0.9783174, 0.9795698, 0.98078525, 0.9819639, 0.9831055, 0.9842101, 0.98527765, 0.9863081, 0.9873014, 0.9882576, 0.9891765, 0.9900582, 0.99090266, 0.99170977, 0.99247956, 0.9932119, 0.993907, 0.9945646, 0.9951847, 0.9957674, 0.9963126, 0.9968203, 0.99729043, 0.99772304, 0.9981181, 0.99847555, 0.99879545, 0.99907774, 0.99932235, 0.9995294, 0.9996988, 0.9998306, 0.9999247, 0.99998116, 1.0, 0.99998116, 0.9999247, 0.9998306, 0.9996988, 0.9995294, 0.99932235, 0.99907774, 0.99879545, 0.99847555, 0.9981181, 0.99772304, 0.99729043, 0.9968203, 0.9963126, 0.9957674, 0.9951847, 0.9945646, 0.993907, 0.9932119, 0.99247956, 0.99170977, 0.99090266, 0.9900582, 0.9891765, 0.9882576, 0.9873014, 0.9863081, 0.98527765, 0.9842101, 0.9831055, 0.9819639, 0.98078525, 0.9795698, 0.9783174, 0.97702813, 0.9757021, 0.97433937, 0.97293997, 0.9715039, 0.97003126, 0.9685221, 0.96697646, 0.96539444, 0.96377605, 0.9621214, 0.9604305, 0.95870346, 0.95694035, 0.9551412, 0.953306, 0.951435, 0.94952816, 0.9475856, 0.9456073, 0.94359344, 0.94154406, 0.9394592, 0.937339, 0.9351835, 0.9329928, 0.93076694, 0.9285061, 0.9262102, 0.9238795, 0.92151403, 0.9191139, 0.9166791, 0.9142098, 0.91170603, 0.909168, 0.9065957, 0.9039893, 0.9013488, 0.8986745, 0.89596623, 0.8932243, 0.89044875, 0.88763964, 0.8847971, 0.8819213, 0.8790122, 0.8760701, 0.873095, 0.87008697, 0.86704624, 0.86397284, 0.86086696, 0.8577286, 0.854558, 0.8513552, 0.84812033, 0.8448536, 0.841555, 0.8382247, 0.8348629, 0.8314696, 0.82804507, 0.8245893, 0.8211025, 0.8175848, 0.8140363, 0.81045717, 0.8068476, 0.8032075, 0.79953724, 0.7958369, 0.79210657, 0.7883464, 0.78455657, 0.7807372, 0.7768885, 0.77301043, 0.76910335, 0.76516724, 0.7612024, 0.7572088, 0.7531868, 0.7491364, 0.74505776, 0.7409511, 0.7368166, 0.7326543, 0.72846437, 0.7242471, 0.72000253, 0.71573085, 0.7114322, 0.70710677, 0.70275474, 0.69837624, 0.69397146, 0.68954057, 0.6850837, 0.680601, 0.6760927, 0.671559, 0.66699994, 0.6624158, 0.6578067, 0.65317285, 0.6485144, 0.64383155, 0.63912445, 0.6343933, 0.62963825, 0.6248595, 0.6200572, 0.6152316, 0.6103828, 0.60551107, 0.60061646, 0.5956993, 0.5907597, 0.58579785, 0.58081394, 0.57580817, 0.57078075, 0.5657318, 0.56066155, 0.55557024, 0.55045795, 0.545325, 0.54017144, 0.53499764, 0.52980363, 0.52458966, 0.519356, 0.51410276, 0.50883013, 0.50353837, 0.49822766, 0.4928982, 0.48755017, 0.48218378, 0.47679922, 0.47139674, 0.4659765, 0.46053872, 0.45508358, 0.44961134, 0.44412214, 0.43861625, 0.43309382, 0.42755508, 0.42200026, 0.41642955, 0.41084316, 0.4052413, 0.3996242, 0.39399204, 0.38834503, 0.38268343, 0.37700742, 0.3713172, 0.36561298, 0.35989505, 0.35416353, 0.34841868, 0.34266073, 0.33688986, 0.3311063, 0.3253103, 0.31950203, 0.31368175, 0.30784965, 0.30200595, 0.2961509, 0.29028466, 0.28440753, 0.2785197, 0.27262136, 0.26671275, 0.2607941, 0.25486565, 0.24892761, 0.24298018, 0.2370236, 0.2310581, 0.22508392, 0.21910124, 0.21311031, 0.20711137, 0.20110464, 0.19509032, 0.18906866, 0.18303989, 0.17700422, 0.17096189, 0.16491312, 0.15885815, 0.15279719, 0.14673047, 0.14065824, 0.1345807, 0.1284981, 0.12241068, 0.11631863, 0.110222206, 0.10412163, 0.09801714, 0.091908954, 0.08579731, 0.07968244, 0.07356457, 0.06744392, 0.061320737, 0.055195246, 0.049067676, 0.04293826, 0.036807224, 0.030674804, 0.024541229, 0.01840673, 0.012271538, 0.0061358847, 1.2246469e-16, -0.0061358847, -0.012271538, -0.01840673, -0.024541229, -0.030674804, -0.036807224, -0.04293826, -0.049067676, -0.055195246, -0.061320737, -0.06744392, -0.07356457, -0.07968244, -0.08579731, -0.091908954, -0.09801714, -0.10412163, -0.110222206, -0.11631863, -0.12241068, -0.1284981, -0.1345807, -0.14065824, -0.14673047, -0.15279719, -0.15885815, -0.16491312, -0.17096189, -0.17700422, -0.18303989, -0.18906866, -0.19509032, -0.20110464, -0.20711137, -0.21311031, -0.21910124, -0.22508392, -0.2310581, -0.2370236, -0.24298018, -0.24892761, -0.25486565, -0.2607941, -0.26671275, -0.27262136, -0.2785197, -0.28440753, -0.29028466, -0.2961509, -0.30200595, -0.30784965, -0.31368175, -0.31950203, -0.3253103, -0.3311063, -0.33688986, -0.34266073, -0.34841868, -0.35416353, -0.35989505, -0.36561298, -0.3713172, -0.37700742, -0.38268343, -0.38834503, -0.39399204, -0.3996242, -0.4052413, -0.41084316, -0.41642955, -0.42200026, -0.42755508, -0.43309382, -0.43861625, -0.44412214, -0.44961134, -0.45508358, -0.46053872, -0.4659765, -0.47139674, -0.47679922, -0.48218378, -0.48755017, -0.4928982, -0.49822766, -0.50353837, -0.50883013, -0.51410276, -0.519356, -0.52458966, -0.52980363, -0.53499764, -0.54017144, -0.545325, -0.55045795, -0.55557024, -0.56066155, -0.5657318, -0.57078075, -0.57580817, -0.58081394, -0.58579785, -0.5907597, -0.5956993, -0.60061646, -0.60551107, -0.6103828, -0.6152316, -0.6200572, -0.6248595, -0.62963825, -0.6343933, -0.63912445, -0.64383155, -0.6485144, -0.65317285, -0.6578067, -0.6624158, -0.66699994, -0.671559, -0.6760927, -0.680601, -0.6850837, -0.68954057, -0.69397146, -0.69837624, -0.70275474, -0.70710677, -0.7114322, -0.71573085, -0.72000253, -0.7242471, -0.72846437, -0.7326543, -0.7368166, -0.7409511, -0.74505776, -0.7491364, -0.7531868, -0.7572088, -0.7612024, -0.76516724, -0.76910335, -0.77301043, -0.7768885, -0.7807372, -0.78455657, -0.7883464, -0.79210657, -0.7958369, -0.79953724, -0.8032075, -0.8068476, -0.81045717, -0.8140363, -0.8175848, -0.8211025, -0.8245893, -0.82804507, -0.8314696, -0.8348629, -0.8382247, -0.841555, -0.8448536, -0.84812033, -0.8513552, -0.854558, -0.8577286, -0.86086696, -0.86397284, -0.86704624, -0.87008697, -0.873095, -0.8760701, -0.8790122, -0.8819213, -0.8847971, -0.88763964, -0.89044875, -0.8932243, -0.89596623, -0.8986745, -0.9013488, -0.9039893, -0.9065957, -0.909168, -0.91170603, -0.9142098, -0.9166791, -0.9191139, -0.92151403, -0.9238795, -0.9262102, -0.9285061, -0.93076694, -0.9329928, -0.9351835, -0.937339, -0.9394592, -0.94154406, -0.94359344, -0.9456073, -0.9475856, -0.94952816, -0.951435, -0.953306, -0.9551412, -0.95694035, -0.95870346, -0.9604305, -0.9621214, -0.96377605, -0.96539444, -0.96697646, -0.9685221, -0.97003126, -0.9715039, -0.97293997, -0.97433937, -0.9757021, -0.97702813, -0.9783174, -0.9795698, -0.98078525, -0.9819639, -0.9831055, -0.9842101, -0.98527765, -0.9863081, -0.9873014, -0.9882576, -0.9891765, -0.9900582, -0.99090266, -0.99170977, -0.99247956, -0.9932119, -0.993907, -0.9945646, -0.9951847, -0.9957674, -0.9963126, -0.9968203, -0.99729043, -0.99772304, -0.9981181, -0.99847555, -0.99879545, -0.99907774, -0.99932235, -0.9995294, -0.9996988, -0.9998306, -0.9999247, -0.99998116, -1.0, -0.99998116, -0.9999247, -0.9998306, -0.9996988, -0.9995294, -0.99932235, -0.99907774, -0.99879545, -0.99847555, -0.9981181, -0.99772304, -0.99729043, -0.9968203, -0.9963126, -0.9957674, -0.9951847, -0.9945646, -0.993907, -0.9932119, -0.99247956, -0.99170977, -0.99090266, -0.9900582, -0.9891765, -0.9882576, -0.9873014, -0.9863081, -0.98527765, -0.9842101, -0.9831055, -0.9819639, -0.98078525, -0.9795698, -0.9783174, -0.97702813, -0.9757021, -0.97433937, -0.97293997, -0.9715039, -0.97003126, -0.9685221, -0.96697646, -0.96539444, -0.96377605, -0.9621214, -0.9604305, -0.95870346, -0.95694035, -0.9551412, -0.953306, -0.951435, -0.94952816, -0.9475856, -0.9456073, -0.94359344, -0.94154406, -0.9394592, -0.937339, -0.9351835, -0.9329928, -0.93076694, -0.9285061, -0.9262102, -0.9238795, -0.92151403, -0.9191139, -0.9166791, -0.9142098, -0.91170603, -0.909168, -0.9065957, -0.9039893, -0.9013488, -0.8986745, -0.89596623, -0.8932243, -0.89044875, -0.88763964, -0.8847971, -0.8819213, -0.8790122, -0.8760701, -0.873095, -0.87008697, -0.86704624, -0.86397284, -0.86086696, -0.8577286, -0.854558, -0.8513552, -0.84812033, -0.8448536, -0.841555, -0.8382247, -0.8348629, -0.8314696, -0.82804507, -0.8245893, -0.8211025, -0.8175848, -0.8140363, -0.81045717, -0.8068476, -0.8032075, -0.79953724, -0.7958369, -0.79210657, -0.7883464, -0.78455657, -0.7807372, -0.7768885, -0.77301043, -0.76910335, -0.76516724, -0.7612024, -0.7572088, -0.7531868, -0.7491364, -0.74505776, -0.7409511, -0.7368166, -0.7326543, -0.72846437, -0.7242471, -0.72000253, -0.71573085, -0.7114322, -0.70710677, -0.70275474, -0.69837624, -0.69397146, -0.68954057, -0.6850837, -0.680601, -0.6760927, -0.671559, -0.66699994, -0.6624158, -0.6578067, -0.65317285, -0.6485144, -0.64383155, -0.63912445, -0.6343933, -0.62963825, -0.6248595, -0.6200572, -0.6152316, -0.6103828, -0.60551107, -0.60061646, -0.5956993, -0.5907597, -0.58579785, -0.58081394, -0.57580817, -0.57078075, -0.5657318, -0.56066155, -0.55557024, -0.55045795, -0.545325, -0.54017144, -0.53499764, -0.52980363, -0.52458966, -0.519356, -0.51410276, -0.50883013, -0.50353837, -0.49822766, -0.4928982, -0.48755017, -0.48218378, -0.47679922, -0.47139674, -0.4659765, -0.46053872, -0.45508358, -0.44961134, -0.44412214, -0.43861625, -0.43309382, -0.42755508, -0.42200026, -0.41642955, -0.41084316, -0.4052413, -0.3996242, -0.39399204, -0.38834503, -0.38268343, -0.37700742, -0.3713172, -0.36561298, -0.35989505, -0.35416353, -0.34841868, -0.34266073, -0.33688986, -0.3311063, -0.3253103, -0.31950203, -0.31368175, -0.30784965, -0.30200595, -0.2961509, -0.29028466, -0.28440753, -0.2785197, -0.27262136, -0.26671275, -0.2607941, -0.25486565, -0.24892761, -0.24298018, -0.2370236, -0.2310581, -0.22508392, -0.21910124, -0.21311031, -0.20711137, -0.20110464, -0.19509032, -0.18906866, -0.18303989, -0.17700422, -0.17096189, -0.16491312, -0.15885815, -0.15279719, -0.14673047, -0.14065824, -0.1345807, -0.1284981, -0.12241068, -0.11631863, -0.110222206, -0.10412163, -0.09801714, -0.091908954, -0.08579731, -0.07968244, -0.07356457, -0.06744392, -0.061320737, -0.055195246, -0.049067676, -0.04293826, -0.036807224, -0.030674804, -0.024541229, -0.01840673, -0.012271538, -0.0061358847, ], [0.0, 0.0061358847, 0.012271538, 0.01840673, 0.024541229, 0.030674804, 0.036807224, 0.04293826, 0.049067676, 0.055195246, 0.061320737, 0.06744392, 0.07356457, 0.07968244, 0.08579731, 0.091908954, 0.09801714, 0.10412163, 0.110222206, 0.11631863, 0.12241068, 0.1284981, 0.1345807, 0.14065824, 0.14673047, 0.15279719, 0.15885815, 0.16491312, 0.17096189, 0.17700422, 0.18303989, 0.18906866, 0.19509032, 0.20110464, 0.20711137, 0.21311031, 0.21910124, 0.22508392, 0.2310581, 0.2370236, 0.24298018, 0.24892761, 0.25486565, 0.2607941, 0.26671275, 0.27262136, 0.2785197, 0.28440753, 0.29028466, 0.2961509, 0.30200595, 0.30784965, 0.31368175, 0.31950203, 0.3253103, 0.3311063, 0.33688986, 0.34266073, 0.34841868, 0.35416353, 0.35989505, 0.36561298, 0.3713172, 0.37700742, 0.38268343, 0.38834503, 0.39399204, 0.3996242, 0.4052413, 0.41084316, 0.41642955, 0.42200026, 0.42755508, 0.43309382, 0.43861625, 0.44412214, 0.44961134, 0.45508358, 0.46053872, 0.4659765, 0.47139674, 0.47679922, 0.48218378, 0.48755017, 0.4928982, 0.49822766, 0.50353837, 0.50883013, 0.51410276, 0.519356, 0.52458966, 0.52980363, 0.53499764, 0.54017144, 0.545325, 0.55045795, 0.55557024, 0.56066155, 0.5657318, 0.57078075, 0.57580817, 0.58081394, 0.58579785, 0.5907597, 0.5956993, 0.60061646, 0.60551107, 0.6103828, 0.6152316, 0.6200572, 0.6248595, 0.62963825, 0.6343933, 0.63912445, 0.64383155, 0.6485144, 0.65317285, 0.6578067, 0.6624158, 0.66699994, 0.671559, 0.6760927, 0.680601, 0.6850837, 0.68954057, 0.69397146, 0.69837624, 0.70275474, 0.70710677, 0.7114322, 0.71573085, 0.72000253, 0.7242471, 0.72846437, 0.7326543, 0.7368166, 0.7409511, 0.74505776, 0.7491364, 0.7531868, 0.7572088, 0.7612024, 0.76516724, 0.76910335, 0.77301043, 0.7768885, 0.7807372, 0.78455657, 0.7883464, 0.79210657, 0.7958369, 0.79953724, 0.8032075, 0.8068476, 0.81045717, 0.8140363, 0.8175848, 0.8211025, 0.8245893, 0.82804507, 0.8314696, 0.8348629, 0.8382247, 0.841555, 0.8448536, 0.84812033, 0.8513552, 0.854558, 0.8577286, 0.86086696, 0.86397284, 0.86704624, 0.87008697, 0.873095, 0.8760701, 0.8790122, 0.8819213, 0.8847971, 0.88763964, 0.89044875, 0.8932243, 0.89596623, 0.8986745, 0.9013488, 0.9039893, 0.9065957, 0.909168, 0.91170603, 0.9142098, 0.9166791, 0.9191139, 0.92151403, 0.9238795, 0.9262102, 0.9285061, 0.93076694, 0.9329928, 0.9351835, 0.937339, 0.9394592, 0.94154406, 0.94359344, 0.9456073, 0.9475856, 0.94952816, 0.951435, 0.953306, 0.9551412, 0.95694035, 0.95870346, 0.9604305, 0.9621214, 0.96377605, 0.96539444, 0.96697646, 0.9685221, 0.97003126, 0.9715039, 0.97293997, 0.97433937, 0.9757021, 0.97702813, 0.9783174, 0.9795698, 0.98078525, 0.9819639, 0.9831055, 0.9842101, 0.98527765, 0.9863081, 0.9873014, 0.9882576, 0.9891765, 0.9900582, 0.99090266, 0.99170977, 0.99247956, 0.9932119, 0.993907, 0.9945646, 0.9951847, 0.9957674, 0.9963126, 0.9968203, 0.99729043, 0.99772304, 0.9981181, 0.99847555, 0.99879545, 0.99907774, 0.99932235, 0.9995294, 0.9996988, 0.9998306, 0.9999247, 0.99998116, 1.0, 0.99998116, 0.9999247, 0.9998306, 0.9996988, 0.9995294, 0.99932235, 0.99907774, 0.99879545, 0.99847555, 0.9981181, 0.99772304, 0.99729043, 0.9968203, 0.9963126, 0.9957674, 0.9951847, 0.9945646, 0.993907, 0.9932119, 0.99247956, 0.99170977, 0.99090266, 0.9900582, 0.9891765, 0.9882576, 0.9873014, 0.9863081, 0.98527765, 0.9842101, 0.9831055, 0.9819639, 0.98078525, 0.9795698, 0.9783174, 0.97702813, 0.9757021, 0.97433937, 0.97293997, 0.9715039, 0.97003126, 0.9685221, 0.96697646, 0.96539444, 0.96377605, 0.9621214, 0.9604305, 0.95870346, 0.95694035, 0.9551412, 0.953306, 0.951435, 0.94952816, 0.9475856, 0.9456073, 0.94359344, 0.94154406, 0.9394592, 0.937339, 0.9351835, 0.9329928, 0.93076694, 0.9285061, 0.9262102, 0.9238795, 0.92151403, 0.9191139, 0.9166791, 0.9142098, 0.91170603, 0.909168, 0.9065957, 0.9039893, 0.9013488, 0.8986745, 0.89596623, 0.8932243, 0.89044875, 0.88763964, 0.8847971, 0.8819213, 0.8790122, 0.8760701, 0.873095, 0.87008697, 0.86704624, 0.86397284, 0.86086696, 0.8577286, 0.854558, 0.8513552, 0.84812033, 0.8448536, 0.841555, 0.8382247, 0.8348629, 0.8314696, 0.82804507, 0.8245893, 0.8211025, 0.8175848, 0.8140363, 0.81045717, 0.8068476, 0.8032075, 0.79953724, 0.7958369, 0.79210657, 0.7883464, 0.78455657, 0.7807372, 0.7768885, 0.77301043, 0.76910335, 0.76516724, 0.7612024, 0.7572088, 0.7531868, 0.7491364, 0.74505776, 0.7409511, 0.7368166, 0.7326543, 0.72846437, 0.7242471, 0.72000253, 0.71573085, 0.7114322, 0.70710677, 0.70275474, 0.69837624, 0.69397146, 0.68954057, 0.6850837, 0.680601, 0.6760927, 0.671559, 0.66699994, 0.6624158, 0.6578067, 0.65317285, 0.6485144, 0.64383155, 0.63912445, 0.6343933, 0.62963825, 0.6248595, 0.6200572, 0.6152316, 0.6103828, 0.60551107, 0.60061646, 0.5956993, 0.5907597, 0.58579785, 0.58081394, 0.57580817, 0.57078075, 0.5657318, 0.56066155, 0.55557024, 0.55045795, 0.545325, 0.54017144, 0.53499764, 0.52980363, 0.52458966, 0.519356, 0.51410276, 0.50883013, 0.50353837, 0.49822766, 0.4928982, 0.48755017, 0.48218378, 0.47679922, 0.47139674, 0.4659765, 0.46053872, 0.45508358, 0.44961134, 0.44412214, 0.43861625, 0.43309382, 0.42755508, 0.42200026, 0.41642955, 0.41084316, 0.4052413, 0.3996242, 0.39399204, 0.38834503, 0.38268343, 0.37700742, 0.3713172, 0.36561298, 0.35989505, 0.35416353, 0.34841868, 0.34266073, 0.33688986, 0.3311063, 0.3253103, 0.31950203, 0.31368175, 0.30784965, 0.30200595, 0.2961509, 0.29028466, 0.28440753, 0.2785197, 0.27262136, 0.26671275, 0.2607941, 0.25486565, 0.24892761, 0.24298018, 0.2370236, 0.2310581, 0.22508392, 0.21910124, 0.21311031, 0.20711137, 0.20110464, 0.19509032, 0.18906866, 0.18303989, 0.17700422, 0.17096189, 0.16491312, 0.15885815, 0.15279719, 0.14673047, 0.14065824, 0.1345807, 0.1284981, 0.12241068, 0.11631863, 0.110222206, 0.10412163, 0.09801714, 0.091908954, 0.08579731, 0.07968244, 0.07356457, 0.06744392, 0.061320737, 0.055195246, 0.049067676, 0.04293826, 0.036807224, 0.030674804, 0.024541229, 0.01840673, 0.012271538, 0.0061358847, 1.2246469e-16, -0.0061358847, -0.012271538, -0.01840673, -0.024541229, -0.030674804, -0.036807224, -0.04293826, -0.049067676, -0.055195246, -0.061320737, -0.06744392, -0.07356457, -0.07968244, -0.08579731, -0.091908954, -0.09801714, -0.10412163, -0.110222206, -0.11631863, -0.12241068, -0.1284981, -0.1345807, -0.14065824, -0.14673047, -0.15279719, -0.15885815, -0.16491312, -0.17096189, -0.17700422, -0.18303989, -0.18906866, -0.19509032, -0.20110464, -0.20711137, -0.21311031, -0.21910124, -0.22508392, -0.2310581, -0.2370236, -0.24298018, -0.24892761, -0.25486565, -0.2607941, -0.26671275, -0.27262136, -0.2785197, -0.28440753, -0.29028466, -0.2961509, -0.30200595, -0.30784965, -0.31368175, -0.31950203, -0.3253103, -0.3311063, -0.33688986, -0.34266073, -0.34841868, -0.35416353, -0.35989505, -0.36561298, -0.3713172, -0.37700742, -0.38268343, -0.38834503, -0.39399204, -0.3996242, -0.4052413, -0.41084316, -0.41642955, -0.42200026, -0.42755508, -0.43309382, -0.43861625, -0.44412214, -0.44961134, -0.45508358, -0.46053872, -0.4659765, -0.47139674, -0.47679922, -0.48218378, -0.48755017, -0.4928982, -0.49822766, -0.50353837, -0.50883013, -0.51410276, -0.519356, -0.52458966, -0.52980363, -0.53499764, -0.54017144, -0.545325, -0.55045795, -0.55557024, -0.56066155, -0.5657318, -0.57078075, -0.57580817, -0.58081394, -0.58579785, -0.5907597, -0.5956993, -0.60061646, -0.60551107, -0.6103828, -0.6152316, -0.6200572, -0.6248595, -0.62963825, -0.6343933, -0.63912445, -0.64383155, -0.6485144, -0.65317285, -0.6578067, -0.6624158, -0.66699994, -0.671559, -0.6760927, -0.680601, -0.6850837, -0.68954057, -0.69397146, -0.69837624, -0.70275474, -0.70710677, -0.7114322, -0.71573085, -0.72000253, -0.7242471, -0.72846437, -0.7326543, -0.7368166, -0.7409511, -0.74505776, -0.7491364, -0.7531868, -0.7572088, -0.7612024, -0.76516724, -0.76910335, -0.77301043, -0.7768885, -0.7807372, -0.78455657, -0.7883464, -0.79210657, -0.7958369, -0.79953724, -0.8032075, -0.8068476, -0.81045717, -0.8140363, -0.8175848, -0.8211025, -0.8245893, -0.82804507, -0.8314696, -0.8348629, -0.8382247, -0.841555, -0.8448536, -0.84812033, -0.8513552, -0.854558, -0.8577286, -0.86086696, -0.86397284, -0.86704624, -0.87008697, -0.873095, -0.8760701, -0.8790122, -0.8819213, -0.8847971, -0.88763964, -0.89044875, -0.8932243, -0.89596623, -0.8986745, -0.9013488, -0.9039893, -0.9065957, -0.909168, -0.91170603, -0.9142098, -0.9166791, -0.9191139, -0.92151403, -0.9238795, -0.9262102, -0.9285061, -0.93076694, -0.9329928, -0.9351835, -0.937339, -0.9394592, -0.94154406, -0.94359344, -0.9456073, -0.9475856, -0.94952816, -0.951435, -0.953306, -0.9551412, -0.95694035, -0.95870346, -0.9604305, -0.9621214, -0.96377605, -0.96539444, -0.96697646, -0.9685221, -0.97003126, -0.9715039, -0.97293997, -0.97433937, -0.9757021, -0.97702813, -0.9783174, -0.9795698, -0.98078525, -0.9819639, -0.9831055, -0.9842101, -0.98527765, -0.9863081, -0.9873014, -0.9882576, -0.9891765, -0.9900582, -0.99090266, -0.99170977, -0.99247956, -0.9932119, -0.993907, -0.9945646, -0.9951847, -0.9957674, -0.9963126, -0.9968203, -0.99729043, -0.99772304, -0.9981181, -0.99847555, -0.99879545, -0.99907774, -0.99932235, -0.9995294, -0.9996988, -0.9998306, -0.9999247, -0.99998116, -1.0, -0.99998116, -0.9999247, -0.9998306, -0.9996988, -0.9995294, -0.99932235, -0.99907774, -0.99879545, -0.99847555, -0.9981181, -0.99772304, -0.99729043, -0.9968203, -0.9963126, -0.9957674, -0.9951847, -0.9945646, -0.993907, -0.9932119, -0.99247956, -0.99170977, -0.99090266, -0.9900582, -0.9891765, -0.9882576, -0.9873014, -0.9863081, -0.98527765, -0.9842101, -0.9831055, -0.9819639, -0.98078525, -0.9795698, -0.9783174, -0.97702813, -0.9757021, -0.97433937, -0.97293997, -0.9715039, -0.97003126, -0.9685221, -0.96697646, -0.96539444, -0.96377605, -0.9621214, -0.9604305, -0.95870346, -0.95694035, -0.9551412, -0.953306, -0.951435, -0.94952816, -0.9475856, -0.9456073, -0.94359344, -0.94154406, -0.9394592, -0.937339, -0.9351835, -0.9329928, -0.93076694, -0.9285061, -0.9262102, -0.9238795, -0.92151403, -0.9191139, -0.9166791, -0.9142098, -0.91170603, -0.909168, -0.9065957, -0.9039893, -0.9013488, -0.8986745, -0.89596623, -0.8932243, -0.89044875, -0.88763964, -0.8847971, -0.8819213, -0.8790122, -0.8760701, -0.873095, -0.87008697, -0.86704624, -0.86397284, -0.86086696, -0.8577286, -0.854558, -0.8513552, -0.84812033, -0.8448536, -0.841555, -0.8382247, -0.8348629, -0.8314696, -0.82804507, -0.8245893, -0.8211025, -0.8175848, -0.8140363, -0.81045717, -0.8068476, -0.8032075, -0.79953724, -0.7958369, -0.79210657, -0.7883464, -0.78455657, -0.7807372, -0.7768885, -0.77301043, -0.76910335, -0.76516724, -0.7612024, -0.7572088, -0.7531868, -0.7491364, -0.74505776, -0.7409511, -0.7368166, -0.7326543, -0.72846437, -0.7242471, -0.72000253, -0.71573085, -0.7114322, -0.70710677, -0.70275474, -0.69837624, -0.69397146, -0.68954057, -0.6850837, -0.680601, -0.6760927, -0.671559, -0.66699994, -0.6624158, -0.6578067, -0.65317285, -0.6485144, -0.64383155, -0.63912445, -0.6343933, -0.62963825, -0.6248595, -0.6200572, -0.6152316, -0.6103828, -0.60551107, -0.60061646, -0.5956993, -0.5907597, -0.58579785, -0.58081394, -0.57580817, -0.57078075, -0.5657318, -0.56066155, -0.55557024, -0.55045795, -0.545325, -0.54017144, -0.53499764, -0.52980363, -0.52458966, -0.519356, -0.51410276, -0.50883013, -0.50353837, -0.49822766, -0.4928982, -0.48755017, -0.48218378, -0.47679922, -0.47139674, -0.4659765, -0.46053872, -0.45508358, -0.44961134, -0.44412214, -0.43861625, -0.43309382, -0.42755508, -0.42200026, -0.41642955, -0.41084316, -0.4052413, -0.3996242, -0.39399204, -0.38834503, -0.38268343, -0.37700742, -0.3713172, -0.36561298, -0.35989505, -0.35416353, -0.34841868, -0.34266073, -0.33688986, -0.3311063, -0.3253103, -0.31950203, -0.31368175, -0.30784965, -0.30200595, -0.2961509, -0.29028466, -0.28440753, -0.2785197, -0.27262136, -0.26671275, -0.2607941, -0.25486565, -0.24892761, -0.24298018, -0.2370236, -0.2310581, -0.22508392, -0.21910124, -0.21311031, -0.20711137, -0.20110464, -0.19509032, -0.18906866, -0.18303989, -0.17700422, -0.17096189, -0.16491312, -0.15885815, -0.15279719, -0.14673047, -0.14065824, -0.1345807, -0.1284981, -0.12241068, -0.11631863, -0.110222206, -0.10412163, -0.09801714, -0.091908954, -0.08579731, -0.07968244, -0.07356457, -0.06744392, -0.061320737, -0.055195246, -0.049067676, -0.04293826, -0.036807224, -0.030674804, -0.024541229, -0.01840673, -0.012271538, -0.0061358847, ], ];
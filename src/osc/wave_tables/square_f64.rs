@@ -0,0 +1,2 @@
+#[allow(clippy::approx_constant)]
+pub static SQUARE_F64: [[f64; 1024]; 10] = [[0.0, 0.9259695246783622, 0.7090737906657367, 0.8373838956566503, 0.7460766177804994, 0.8169874170135115, 0.7590109876894882, 0.8080497510099252, 0.7655576507868669, 0.8030474372525571, 0.7695045485729816, 0.799853792517693, 0.7721417693923097, 0.7976392678143431, 0.7740276412351961, 0.7960139829440146, 0.7754428194920946, 0.7947706808593998, 0.7765437297468094, 0.7937890580829601, 0.7774244267186139, 0.7929945102698985, 0.7781448513034905, 0.7923383220388817, 0.7787450063810049, 0.7917873367992387, 0.7792526080641987, 0.7913182061200076, 0.7796874654887902, 0.7909140135515424, 0.780064112174567, 0.7905622029396905, 0.7803934523236089, 0.7902532588710461, 0.7806838269444479, 0.7899798389002253, 0.7809417250713553, 0.7897361868667455, 0.7811722706515477, 0.7895177265297691, 0.7813795635193501, 0.7893207740209021, 0.7815669230409616, 0.7891423304708809, 0.7817370653672187, 0.7889799298909778, 0.7818922344822882, 0.7888315258645137, 0.7820343005132782, 0.7886954059686713, 0.7821648344607797, 0.7885701263198891, 0.7822851656941563, 0.7884544609307297, 0.782396426677066, 0.788347362110331, 0.7824995881138154, 0.788247929197568, 0.7825954868274388, 0.7881553836506805, 0.7826848480646228, 0.7880690490352522, 0.7827683034852583, 0.7879883348225151, 0.78284640578026, 0.7879127231776347, 0.782919640632514, 0.7878417581134769, 0.7829884365677752, 0.7877750365300774, 0.7830531731173548, 0.7877122007681117, 0.7831141876207479, 0.7876529323861129, 0.7831717809254233, 0.7875969469330936, 0.7832262221868899, 0.7875439895356081, 0.7832777529304227, 0.7874938311549999, 0.7833265905037015, 0.7874462653990149, 0.783372931024251, 0.7874011057942998, 0.7834169519059136, 0.7873581834438994, 0.7834588140328343, 0.7873173450078041, 0.7834986636370379, 0.7872784509557502, 0.783536633925691, 0.7872413740503971, 0.7835728464961405, 0.7872059980262037, 0.7836074125703122, 0.7871722164351724, 0.7836404340748219, 0.7871399316353951, 0.7836720045888318, 0.7871090539021723, 0.7837022101781618, 0.7870795006447714, 0.783731130131272, 0.7870511957144232, 0.7837588376102979, 0.7870240687914275, 0.7837854002284103, 0.7869980548409882, 0.7838108805629738, 0.7869730936289786, 0.7838353366127396, 0.7869491292900659, 0.7838588222060385, 0.7869261099417166, 0.7838813873659667, 0.7869039873384764, 0.7839030786378142, 0.7868827165617581, 0.7839239393831454, 0.7868622557409028, 0.7839440100444864, 0.7868425658019187, 0.7839633283839232, 0.7868236102407778, 0.7839819296986256, 0.7868053549184679, 0.7839998470158026, 0.7867877678754482, 0.7840171112693667, 0.7867708191633754, 0.7840337514602727, 0.7867544806922707, 0.7840497948022607, 0.7867387260914919, 0.7840652668545124, 0.7867235305830995, 0.7840801916425956, 0.7867088708663181, 0.7840945917688547, 0.7866947250120108, 0.7841084885133317, 0.7866810723661621, 0.7841219019261124, 0.7866678934614645, 0.7841348509119735, 0.7866551699362763, 0.7841473533080265, 0.786642884460187, 0.7841594259550562, 0.7866310206656454, 0.7841710847631149, 0.786619563085011, 0.7841823447719125, 0.786608497092595, 0.7841932202064745, 0.7865978088512062, 0.7842037245284839, 0.7865874852628236, 0.7842138704836991, 0.786577513922997, 0.784223670145776, 0.786567883078725, 0.7842331349568258, 0.7865585815894113, 0.7842422757649589, 0.7865495988907365, 0.7842511028590831, 0.7865409249611343, 0.7842596260011896, 0.7865325502906996, 0.7842678544563149, 0.7865244658523075, 0.7842757970203794, 0.7865166630747747, 0.7842834620460716, 0.7865091338178933, 0.7842908574669162, 0.7865018703492137, 0.7842979908196998, 0.7864948653224031, 0.784304869265334, 0.7864881117570973, 0.7843114996083321, 0.7864816030201127, 0.7843178883149305, 0.7864753328079201, 0.784324041530039, 0.7864692951302825, 0.7843299650930331, 0.7864634842950002, 0.7843356645525056, 0.7864578948936408, 0.7843411451800546, 0.786452521788218, 0.7843464119831655, 0.7864473600987419, 0.7843514697172393, 0.7864424051915894, 0.7843563228968452, 0.7864376526686191, 0.7843609758062475, 0.7864330983569863, 0.7843654325092303, 0.7864287382996559, 0.7843696968583062, 0.7864245687465025, 0.784373772503301, 0.7864205861459912, 0.7843776628993935, 0.7864167871374189, 0.7843813713146236, 0.7864131685436561, 0.7843849008369009, 0.786409727364373, 0.7843882543805606, 0.7864064607697127, 0.7843914346924565, 0.7864033660943972, 0.7843944443576587, 0.7864004408322384, 0.784397285804755, 0.78639768263102, 0.7843999613107729, 0.7863950892877529, 0.7844024730057598, 0.78639265874428, 0.78440482287702, 0.7863903890831705, 0.7844070127730378, 0.786388278523976, 0.7844090444070951, 0.7863863254197504, 0.7844109193606031, 0.7863845282538608, 0.7844126390861391, 0.7863828856370825, 0.7844142049102419, 0.7863813963049336, 0.7844156180359315, 0.7863800591152944, 0.7844168795449763, 0.7863788730462304, 0.7844179903999462, 0.7863778371940894, 0.7844189514460009, 0.7863769507718045, 0.7844197634124782, 0.7863762131074425, 0.7844204269142345, 0.786375623642925, 0.7844209424528016, 0.7863751819330372, 0.7844213104173039, 0.7863748876445711, 0.7844215310851714, 0.7863747405557391, 0.7844216046226673, 0.7863747405557386, 0.7844215310851725, 0.7863748876445726, 0.7844213104173035, 0.7863751819330356, 0.7844209424528028, 0.7863756236429253, 0.784420426914237, 0.7863762131074415, 0.7844197634124781, 0.7863769507718088, 0.7844189514460028, 0.7863778371940902, 0.7844179903999465, 0.7863788730462319, 0.7844168795449765, 0.7863800591152956, 0.7844156180359346, 0.786381396304935, 0.7844142049102439, 0.7863828856370823, 0.7844126390861387, 0.7863845282538602, 0.7844109193606023, 0.7863863254197504, 0.7844090444070957, 0.7863882785239763, 0.7844070127730391, 0.7863903890831709, 0.784404822877021, 0.786392658744284, 0.784402473005761, 0.7863950892877546, 0.7843999613107738, 0.7863976826310191, 0.7843972858047586, 0.7864004408322366, 0.7843944443576639, 0.7864033660943962, 0.7843914346924564, 0.7864064607697133, 0.7843882543805595, 0.7864097273643729, 0.7843849008369016, 0.7864131685436561, 0.7843813713146236, 0.78641678713742, 0.7843776628993937, 0.7864205861459911, 0.7843737725033008, 0.7864245687465036, 0.7843696968583078, 0.7864287382996555, 0.7843654325092314, 0.786433098356986, 0.7843609758062474, 0.7864376526686183, 0.7843563228968405, 0.7864424051915916, 0.7843514697172391, 0.7864473600987437, 0.7843464119831671, 0.7864525217882156, 0.7843411451800528, 0.7864578948936434, 0.7843356645525053, 0.7864634842950003, 0.7843299650930325, 0.7864692951302831, 0.7843240415300372, 0.7864753328079186, 0.7843178883149298, 0.7864816030201135, 0.7843114996083328, 0.7864881117570981, 0.7843048692653366, 0.7864948653224016, 0.7842979908197022, 0.7865018703492138, 0.7842908574669171, 0.7865091338178934, 0.7842834620460715, 0.7865166630747732, 0.7842757970203809, 0.7865244658523075, 0.7842678544563139, 0.786532550290697, 0.7842596260011895, 0.786540924961135, 0.7842511028590813, 0.7865495988907364, 0.7842422757649593, 0.7865585815894136, 0.7842331349568259, 0.7865678830787225, 0.7842236701457767, 0.7865775139229975, 0.784213870483698, 0.7865874852628211, 0.7842037245284826, 0.7865978088512069, 0.7841932202064772, 0.7866084970925952, 0.7841823447719115, 0.7866195630850119, 0.7841710847631159, 0.7866310206656447, 0.7841594259550566, 0.7866428844601874, 0.7841473533080249, 0.7866551699362756, 0.7841348509119751, 0.7866678934614666, 0.7841219019261115, 0.7866810723661626, 0.7841084885133319, 0.7866947250120101, 0.7840945917688558, 0.7867088708663162, 0.7840801916425955, 0.786723530583099, 0.7840652668545135, 0.7867387260914918, 0.7840497948022632, 0.7867544806922696, 0.7840337514602733, 0.7867708191633754, 0.7840171112693654, 0.7867877678754478, 0.7839998470158045, 0.7868053549184676, 0.7839819296986269, 0.7868236102407761, 0.7839633283839239, 0.7868425658019195, 0.783944010044486, 0.7868622557409003, 0.783923939383146, 0.7868827165617593, 0.7839030786378139, 0.7869039873384757, 0.7838813873659616, 0.7869261099417151, 0.78385882220604, 0.7869491292900678, 0.7838353366127384, 0.7869730936289793, 0.7838108805629727, 0.7869980548409903, 0.7837854002284101, 0.787024068791428, 0.7837588376102962, 0.787051195714425, 0.7837311301312722, 0.7870795006447739, 0.7837022101781623, 0.7871090539021738, 0.7836720045888321, 0.787139931635397, 0.7836404340748214, 0.787172216435176, 0.7836074125703119, 0.7872059980262034, 0.783572846496141, 0.7872413740503956, 0.7835366339256874, 0.7872784509557502, 0.7834986636370367, 0.7873173450078047, 0.7834588140328331, 0.7873581834438994, 0.7834169519059142, 0.787401105794299, 0.7833729310242523, 0.7874462653990125, 0.7833265905037, 0.7874938311549984, 0.783277752930422, 0.7875439895356071, 0.7832262221868896, 0.7875969469330941, 0.7831717809254231, 0.7876529323861132, 0.7831141876207466, 0.7877122007681112, 0.783053173117353, 0.787775036530074, 0.7829884365677746, 0.7878417581134769, 0.7829196406325184, 0.787912723177632, 0.7828464057802588, 0.7879883348225155, 0.7827683034852588, 0.7880690490352548, 0.782684848064624, 0.7881553836506814, 0.7825954868274368, 0.7882479291975689, 0.7824995881138133, 0.7883473621103307, 0.7823964266770671, 0.7884544609307291, 0.7822851656941547, 0.7885701263198901, 0.7821648344607801, 0.7886954059686735, 0.7820343005132772, 0.7888315258645096, 0.7818922344822866, 0.7889799298909765, 0.7817370653672188, 0.7891423304708777, 0.78156692304096, 0.789320774020903, 0.7813795635193514, 0.7895177265297707, 0.7811722706515491, 0.7897361868667446, 0.7809417250713585, 0.7899798389002248, 0.780683826944448, 0.7902532588710469, 0.7803934523236083, 0.7905622029396907, 0.7800641121745661, 0.790914013551542, 0.7796874654887879, 0.7913182061200084, 0.7792526080641979, 0.7917873367992394, 0.7787450063810043, 0.7923383220388796, 0.7781448513034912, 0.7929945102698995, 0.7774244267186141, 0.7937890580829603, 0.7765437297468043, 0.7947706808594003, 0.7754428194920953, 0.7960139829440143, 0.7740276412351977, 0.7976392678143437, 0.7721417693923097, 0.7998537925176928, 0.7695045485729802, 0.8030474372525577, 0.7655576507868673, 0.8080497510099253, 0.7590109876894876, 0.816987417013512, 0.7460766177804983, 0.8373838956566505, 0.7090737906657365, 0.9259695246783423, 3.2659351958307634e-14, -0.9259695246783614, -0.7090737906657364, -0.8373838956566495, -0.7460766177804994, -0.816987417013511, -0.7590109876894886, -0.8080497510099253, -0.765557650786867, -0.8030474372525552, -0.7695045485729799, -0.7998537925176918, -0.77214176939231, -0.7976392678143412, -0.7740276412351971, -0.7960139829440157, -0.7754428194920926, -0.7947706808594, -0.7765437297468119, -0.793789058082959, -0.7774244267186157, -0.7929945102698972, -0.778144851303492, -0.7923383220388845, -0.7787450063810051, -0.7917873367992381, -0.7792526080642008, -0.7913182061200058, -0.779687465488791, -0.7909140135515442, -0.7800641121745642, -0.7905622029396875, -0.7803934523236086, -0.7902532588710468, -0.7806838269444475, -0.7899798389002236, -0.7809417250713525, -0.7897361868667468, -0.781172270651548, -0.7895177265297705, -0.7813795635193516, -0.7893207740209028, -0.7815669230409601, -0.789142330470881, -0.7817370653672171, -0.7889799298909793, -0.7818922344822894, -0.7888315258645122, -0.7820343005132769, -0.788695405968672, -0.7821648344607786, -0.7885701263198887, -0.7822851656941564, -0.7884544609307298, -0.7823964266770684, -0.7883473621103325, -0.7824995881138141, -0.7882479291975629, -0.7825954868274383, -0.7881553836506779, -0.7826848480646242, -0.7880690490352543, -0.782768303485258, -0.7879883348225117, -0.7828464057802598, -0.7879127231776369, -0.7829196406325151, -0.7878417581134776, -0.7829884365677754, -0.7877750365300785, -0.783053173117356, -0.7877122007681101, -0.7831141876207449, -0.7876529323861111, -0.7831717809254253, -0.7875969469330943, -0.7832262221868924, -0.7875439895356074, -0.7832777529304241, -0.7874938311550019, -0.7833265905037003, -0.7874462653990136, -0.7833729310242522, -0.7874011057943, -0.7834169519059131, -0.7873581834439004, -0.7834588140328349, -0.7873173450078037, -0.783498663637038, -0.7872784509557517, -0.7835366339256896, -0.7872413740503957, -0.7835728464961423, -0.7872059980262024, -0.7836074125703119, -0.7871722164351729, -0.7836404340748213, -0.7871399316353955, -0.7836720045888342, -0.7871090539021697, -0.7837022101781623, -0.7870795006447706, -0.7837311301312689, -0.7870511957144254, -0.7837588376102994, -0.7870240687914264, -0.783785400228408, -0.7869980548409877, -0.7838108805629725, -0.786973093628975, -0.7838353366127484, -0.7869491292900672, -0.7838588222060354, -0.7869261099417159, -0.7838813873659667, -0.7869039873384678, -0.783903078637818, -0.7868827165617588, -0.7839239393831462, -0.7868622557409035, -0.7839440100444853, -0.7868425658019208, -0.7839633283839244, -0.7868236102407765, -0.7839819296986268, -0.786805354918467, -0.7839998470158029, -0.7867877678754469, -0.7840171112693666, -0.786770819163377, -0.784033751460276, -0.7867544806922677, -0.7840497948022601, -0.7867387260914925, -0.7840652668545122, -0.7867235305830986, -0.7840801916425957, -0.7867088708663179, -0.7840945917688534, -0.7866947250120102, -0.784108488513332, -0.7866810723661635, -0.7841219019261132, -0.7866678934614662, -0.7841348509119727, -0.7866551699362747, -0.7841473533080217, -0.7866428844601885, -0.7841594259550548, -0.7866310206656455, -0.7841710847631164, -0.786619563085012, -0.7841823447719134, -0.786608497092592, -0.7841932202064763, -0.7865978088512043, -0.7842037245284822, -0.7865874852628243, -0.7842138704836991, -0.7865775139229972, -0.784223670145775, -0.7865678830787267, -0.7842331349568287, -0.786558581589414, -0.7842422757649595, -0.7865495988907362, -0.7842511028590812, -0.786540924961133, -0.7842596260011886, -0.7865325502906992, -0.7842678544563129, -0.7865244658523082, -0.7842757970203791, -0.7865166630747769, -0.7842834620460679, -0.7865091338178924, -0.7842908574669144, -0.7865018703492145, -0.7842979908197004, -0.7864948653224006, -0.7843048692653353, -0.7864881117570963, -0.7843114996083327, -0.7864816030201162, -0.7843178883149265, -0.7864753328079177, -0.7843240415300397, -0.7864692951302791, -0.7843299650930289, -0.7864634842950009, -0.7843356645525046, -0.7864578948936392, -0.7843411451800529, -0.7864525217882121, -0.7843464119831667, -0.7864473600987397, -0.78435146971724, -0.7864424051915907, -0.7843563228968466, -0.7864376526686181, -0.7843609758062479, -0.7864330983569914, -0.7843654325092299, -0.786428738299654, -0.7843696968583083, -0.7864245687464992, -0.7843737725033075, -0.7864205861459886, -0.7843776628993977, -0.7864167871374182, -0.784381371314615, -0.7864131685436571, -0.7843849008369004, -0.7864097273643691, -0.7843882543805606, -0.7864064607697124, -0.7843914346924571, -0.7864033660943945, -0.7843944443576641, -0.786400440832238, -0.7843972858047578, -0.7863976826310229, -0.7843999613107735, -0.7863950892877569, -0.7844024730057599, -0.7863926587442808, -0.7844048228770214, -0.786390389083177, -0.7844070127730376, -0.786388278523972, -0.7844090444070962, -0.7863863254197537, -0.7844109193606044, -0.7863845282538628, -0.7844126390861356, -0.7863828856370831, -0.7844142049102455, -0.7863813963049373, -0.7844156180359299, -0.7863800591152956, -0.7844168795449795, -0.7863788730462286, -0.7844179903999441, -0.7863778371940909, -0.7844189514460022, -0.7863769507718062, -0.7844197634124863, -0.7863762131074404, -0.7844204269142414, -0.7863756236429239, -0.7844209424528004, -0.7863751819330369, -0.7844213104173051, -0.7863748876445795, -0.7844215310851719, -0.7863747405557376, -0.7844216046226673, -0.7863747405557353, -0.7844215310851713, -0.7863748876445711, -0.7844213104173029, -0.7863751819330353, -0.7844209424528038, -0.7863756236429231, -0.7844204269142316, -0.7863762131074402, -0.7844197634124801, -0.7863769507718088, -0.7844189514459989, -0.7863778371940887, -0.7844179903999483, -0.786378873046225, -0.7844168795449706, -0.7863800591152955, -0.7844156180359325, -0.7863813963049346, -0.7844142049102424, -0.7863828856370829, -0.7844126390861406, -0.7863845282538612, -0.7844109193606028, -0.7863863254197475, -0.7844090444070988, -0.7863882785239757, -0.7844070127730328, -0.7863903890831709, -0.7844048228770133, -0.7863926587442812, -0.7844024730057585, -0.786395089287753, -0.7843999613107755, -0.7863976826310185, -0.7843972858047573, -0.7864004408322333, -0.7843944443576593, -0.7864033660943982, -0.7843914346924502, -0.7864064607697118, -0.7843882543805606, -0.7864097273643711, -0.7843849008369013, -0.7864131685436512, -0.7843813713146243, -0.7864167871374216, -0.7843776628993946, -0.7864205861459901, -0.7843737725033001, -0.7864245687464994, -0.7843696968583086, -0.7864287382996493, -0.78436543250922, -0.786433098356985, -0.7843609758062485, -0.7864376526686176, -0.7843563228968471, -0.7864424051915911, -0.7843514697172392, -0.7864473600987434, -0.7843464119831642, -0.7864525217882173, -0.7843411451800552, -0.7864578948936324, -0.7843356645525041, -0.7864634842949986, -0.7843299650930324, -0.7864692951302851, -0.7843240415300379, -0.7864753328079185, -0.78431788831493, -0.7864816030201154, -0.7843114996083281, -0.786488111757098, -0.7843048692653415, -0.7864948653224018, -0.7842979908197008, -0.7865018703492107, -0.7842908574669157, -0.786509133817895, -0.784283462046072, -0.7865166630747757, -0.784275797020381, -0.7865244658523091, -0.7842678544563155, -0.786532550290698, -0.7842596260011881, -0.786540924961134, -0.7842511028590823, -0.78654959889073, -0.7842422757649582, -0.786558581589412, -0.7842331349568274, -0.7865678830787249, -0.7842236701457738, -0.7865775139229954, -0.7842138704836966, -0.7865874852628219, -0.7842037245284843, -0.7865978088512096, -0.7841932202064734, -0.7866084970925932, -0.7841823447719113, -0.7866195630850118, -0.7841710847631169, -0.7866310206656445, -0.7841594259550554, -0.7866428844601863, -0.7841473533080299, -0.7866551699362737, -0.7841348509119742, -0.7866678934614655, -0.7841219019261133, -0.7866810723661617, -0.784108488513329, -0.7866947250120105, -0.7840945917688517, -0.7867088708663149, -0.7840801916425933, -0.7867235305830959, -0.7840652668545112, -0.7867387260914912, -0.7840497948022592, -0.7867544806922709, -0.7840337514602733, -0.7867708191633755, -0.7840171112693683, -0.7867877678754485, -0.783999847015803, -0.7868053549184709, -0.7839819296986233, -0.786823610240781, -0.7839633283839202, -0.786842565801918, -0.7839440100444853, -0.7868622557409025, -0.783923939383145, -0.7868827165617556, -0.7839030786378124, -0.7869039873384748, -0.7838813873659657, -0.7869261099417156, -0.783858822206039, -0.7869491292900701, -0.7838353366127377, -0.786973093628982, -0.7838108805629725, -0.7869980548409898, -0.7837854002284063, -0.7870240687914276, -0.7837588376102967, -0.7870511957144181, -0.7837311301312594, -0.7870795006447705, -0.7837022101781623, -0.7871090539021685, -0.7836720045888282, -0.7871399316353991, -0.7836404340748223, -0.7871722164351594, -0.7836074125703025, -0.7872059980262037, -0.78357284649614, -0.7872413740504092, -0.7835366339256887, -0.787278450955755, -0.7834986636370379, -0.7873173450078068, -0.7834588140328329, -0.7873581834438972, -0.783416951905917, -0.7874011057942988, -0.7833729310242505, -0.7874462653990165, -0.7833265905036987, -0.7874938311549963, -0.7832777529304236, -0.7875439895356104, -0.7832262221868896, -0.787596946933099, -0.7831717809254254, -0.7876529323861089, -0.7831141876207466, -0.7877122007681082, -0.7830531731173563, -0.7877750365300769, -0.7829884365677738, -0.7878417581134792, -0.7829196406325153, -0.7879127231776343, -0.7828464057802593, -0.7879883348225192, -0.7827683034852596, -0.7880690490352558, -0.7826848480646231, -0.7881553836506799, -0.782595486827439, -0.7882479291975675, -0.7824995881138189, -0.7883473621103281, -0.7823964266770653, -0.7884544609307252, -0.7822851656941577, -0.7885701263198875, -0.7821648344607816, -0.7886954059686732, -0.7820343005132752, -0.7888315258645103, -0.7818922344822928, -0.7889799298909809, -0.7817370653672183, -0.7891423304708814, -0.7815669230409609, -0.7893207740209014, -0.7813795635193508, -0.7895177265297694, -0.7811722706515464, -0.7897361868667375, -0.7809417250713506, -0.7899798389002247, -0.7806838269444486, -0.7902532588710444, -0.7803934523236091, -0.7905622029396944, -0.7800641121745683, -0.7909140135515428, -0.7796874654887909, -0.791318206120008, -0.7792526080641969, -0.7917873367992438, -0.7787450063810033, -0.7923383220388783, -0.7781448513034896, -0.7929945102698965, -0.7774244267186136, -0.7937890580829625, -0.7765437297468082, -0.7947706808593964, -0.7754428194920946, -0.7960139829440154, -0.7740276412351965, -0.7976392678143439, -0.7721417693923099, -0.7998537925176928, -0.7695045485729819, -0.8030474372525529, -0.7655576507868663, -0.8080497510099277, -0.7590109876894863, -0.8169874170135102, -0.7460766177804954, -0.8373838956566504, -0.7090737906657483, -0.9259695246783631, ], [0.0, 0.6853823556461739, 0.9259725207603869, 0.8041851052740243, 0.7090677981068664, 0.7779167655766396, 0.837392885482137, 0.7893533396694352, 0.7460646295034071, 0.7829664646628539, 0.8170024053228064, 0.7870399582550833, 0.7589929973712555, 0.7842168679435719, 0.8080707457106329, 0.7862881141717799, 0.7655336489325606, 0.7847039984693697, 0.8030744494301058, 0.7859545219863, 0.7694745225029656, 0.7849424247313833, 0.79988683645017, 0.7857782150823736, 0.7721057032252672, 0.7850764632271888, 0.7976783609916236, 0.7856739354902947, 0.773985515866825, 0.7851591851790393, 0.7960591460912496, 0.7856072126362451, 0.7753946125694183, 0.7852137846516946, 0.7948219379649212, 0.7855619672619005, 0.776489415638042, 0.7852516973038646, 0.7938464364306779, 0.7855298839184717, 0.7773639764784814, 0.7852790882812712, 0.7930580404762715, 0.7855063126291589, 0.7780782326339367, 0.7852995187256766, 0.7924080380945693, 0.7854884885574229, 0.7786721835871613, 0.7853151618652582, 0.7918632761155352, 0.7854746842979459, 0.7791735420055099, 0.7853274047809935, 0.7914004095801938, 0.7854637756343746, 0.7796021135251499, 0.7853371664554832, 0.7910025255672956, 0.785455005416259, 0.7799724281073283, 0.7853450752597408, 0.7906570715126962, 0.7854478486195976, 0.7802953863312981, 0.7853515726276009, 0.7903545356599968, 0.7854419319160599, 0.7805793255130156, 0.7853569760189393, 0.790087579293383, 0.7854369839687112, 0.7808307309187471, 0.7853615184653004, 0.7898504500602779, 0.7854328037377388, 0.781054722646404, 0.7853653741464249, 0.7896385756120786, 0.7854292397074205, 0.7812553965935922, 0.7853686753645268, 0.7894482760631873, 0.7854261759364154, 0.7814360680960043, 0.7853715240704098, 0.789276556624205, 0.7854235224853964, 0.7815994491735644, 0.7853739998518264, 0.7891209554901154, 0.785421208719921, 0.7817477795721609, 0.785376165572647, 0.788979430538887, 0.7854191785423368, 0.7818829250660193, 0.7853780714203877, 0.7888502737607149, 0.785417386942938, 0.7820064521806486, 0.7853797578555406, 0.788732045811197, 0.7854157974691282, 0.782119685680141, 0.7853812577904437, 0.7886235253763634, 0.7854143803436199, 0.782223753284328, 0.7853825982194479, 0.7885236695817388, 0.7854131110481686, 0.7823196208063169, 0.785383801452772, 0.7884315827345264, 0.785411969245754, 0.7824081200213029, 0.7853848860604574, 0.7883464914227741, 0.785410937951798, 0.782489970961686, 0.7853858676017514, 0.7882677245134041, 0.7854100028907947, 0.7825657998963099, 0.7853867591938838, 0.7881946969611352, 0.7854091519923859, 0.7826361539373536, 0.7853875719594497, 0.7881268966080048, 0.7854083749933913, 0.7827015129897232, 0.7853883153811201, 0.7880638733490262, 0.7854076631210573, 0.7827622995897164, 0.785388997585004, 0.7880052301842515, 0.7854070088391164, 0.7828188870547224, 0.7853896255686033, 0.7879506157856156, 0.7854064056428153, 0.7828716062720836, 0.7853902053854344, 0.7878997182883613, 0.7854058478923795, 0.7829207513842479, 0.7853907422954631, 0.7878522600787328, 0.7854053306769014, 0.7829665845732535, 0.7853912408884357, 0.7878079933971204, 0.7854048497024468, 0.783009340105899, 0.7853917051855406, 0.7877666966124093, 0.7854044011995621, 0.7830492277687023, 0.7853921387236653, 0.7877281710518325, 0.7854039818464177, 0.7830864357965076, 0.7853925446255826, 0.7876922382929452, 0.7854035887046443, 0.7831211333788276, 0.7853929256587019, 0.7876587378419134, 0.7854032191654683, 0.7831534728123313, 0.7853932842844726, 0.7876275251363066, 0.7854028709043455, 0.7831835913554134, 0.7853936227001276, 0.787598469821701, 0.7854025418425176, 0.7832116128308152, 0.7853939428741062, 0.787571454260364, 0.7854022301143355, 0.7832376490142587, 0.7853942465762288, 0.787546372237485, 0.7854019340393564, 0.7832618008404985, 0.7853945354035368, 0.7875231278362819, 0.7854016520984043, 0.7832841594530099, 0.7853948108024891, 0.7875016344580834, 0.7854013829129527, 0.7833048071191214, 0.7853950740881175, 0.7874818139674162, 0.7854011252273106, 0.7833238180289199, 0.7853953264606539, 0.7874635959452789, 0.7854008778931142, 0.7833412589932875, 0.7853955690199593, 0.7874469170365338, 0.7854006398558349, 0.7833571900540608, 0.7853958027781925, 0.7874317203795008, 0.785400410142911, 0.7833716650171997, 0.785396028670916, 0.7874179551076884, 0.7854001878533281, 0.7833847319182625, 0.785396247566912, 0.7874055759151912, 0.7853999721483585, 0.7833964334279591, 0.7853964602769054, 0.7873945426785088, 0.7853997622433364, 0.7834068072044357, 0.7853966675613486, 0.7873848201287752, 0.7853995574002686, 0.7834158861978616, 0.7853968701374461, 0.787376377569177, 0.7853993569211586, 0.7834236989120451, 0.7853970686854947, 0.7873691886333182, 0.7853991601419551, 0.7834302696270108, 0.7853972638546936, 0.7873632310808626, 0.7853989664269759, 0.7834356185858845, 0.7853974562684929, 0.7873584866274771, 0.7853987751637597, 0.783439762148723, 0.7853976465295732, 0.7873549408066378, 0.7853985857582333, 0.7834427129155755, 0.7853978352245395, 0.7873525828613055, 0.7853983976301521, 0.7834444798204431, 0.7853980229283677, 0.7873514056639725, 0.7853982102087252, 0.7834450681974975, 0.7853982102087246, 0.7873514056639739, 0.7853980229283691, 0.7834444798204426, 0.7853983976301508, 0.7873525828613069, 0.7853978352245389, 0.7834427129155759, 0.7853985857582334, 0.7873549408066378, 0.7853976465295768, 0.7834397621487248, 0.7853987751637607, 0.7873584866274772, 0.7853974562684954, 0.783435618585886, 0.7853989664269765, 0.787363231080864, 0.7853972638546941, 0.783430269627014, 0.7853991601419554, 0.7873691886333178, 0.7853970686854946, 0.7834236989120437, 0.7853993569211585, 0.7873763775691768, 0.7853968701374456, 0.7834158861978628, 0.7853995574002691, 0.7873848201287758, 0.7853966675613498, 0.7834068072044365, 0.7853997622433373, 0.7873945426785101, 0.7853964602769046, 0.7833964334279595, 0.7853999721483588, 0.7874055759151931, 0.785396247566912, 0.7833847319182627, 0.7854001878533278, 0.787417955107689, 0.7853960286709163, 0.7833716650172005, 0.7854004101429103, 0.787431720379501, 0.7853958027781932, 0.7833571900540608, 0.785400639855835, 0.7874469170365331, 0.7853955690199601, 0.783341258993288, 0.7854008778931142, 0.7874635959452788, 0.7853953264606534, 0.7833238180289193, 0.7854011252273105, 0.7874818139674138, 0.7853950740881198, 0.7833048071191212, 0.7854013829129548, 0.7875016344580842, 0.785394810802488, 0.7832841594530081, 0.7854016520984048, 0.7875231278362811, 0.7853945354035379, 0.7832618008404986, 0.7854019340393569, 0.7875463722374834, 0.7853942465762279, 0.7832376490142585, 0.7854022301143356, 0.7875714542603641, 0.7853939428741059, 0.7832116128308172, 0.7854025418425178, 0.787598469821701, 0.7853936227001281, 0.7831835913554138, 0.7854028709043447, 0.7876275251363074, 0.7853932842844714, 0.7831534728123325, 0.7854032191654682, 0.7876587378419131, 0.7853929256586996, 0.7831211333788255, 0.7854035887046442, 0.7876922382929448, 0.7853925446255824, 0.7830864357965077, 0.7854039818464195, 0.7877281710518317, 0.7853921387236634, 0.7830492277687031, 0.7854044011995617, 0.7877666966124093, 0.7853917051855402, 0.7830093401058993, 0.785404849702447, 0.7878079933971219, 0.7853912408884353, 0.782966584573253, 0.7854053306769018, 0.7878522600787332, 0.7853907422954629, 0.7829207513842482, 0.7854058478923808, 0.7878997182883611, 0.7853902053854329, 0.7828716062720841, 0.785406405642816, 0.7879506157856159, 0.7853896255686041, 0.7828188870547235, 0.7854070088391165, 0.7880052301842525, 0.7853889975850034, 0.7827622995897169, 0.7854076631210566, 0.7880638733490274, 0.7853883153811209, 0.7827015129897263, 0.7854083749933903, 0.7881268966080046, 0.7853875719594505, 0.7826361539373539, 0.7854091519923848, 0.7881946969611364, 0.7853867591938828, 0.7825657998963114, 0.7854100028907938, 0.7882677245134062, 0.7853858676017511, 0.7824899709616853, 0.7854109379517968, 0.7883464914227747, 0.785384886060458, 0.7824081200213046, 0.7854119692457545, 0.7884315827345244, 0.7853838014527715, 0.782319620806316, 0.7854131110481699, 0.7885236695817383, 0.7853825982194478, 0.7822237532843274, 0.7854143803436207, 0.7886235253763637, 0.785381257790444, 0.7821196856801399, 0.7854157974691296, 0.7887320458111974, 0.7853797578555424, 0.7820064521806497, 0.7854173869429383, 0.7888502737607159, 0.7853780714203888, 0.78188292506602, 0.7854191785423391, 0.7889794305388875, 0.7853761655726471, 0.7817477795721602, 0.7854212087199197, 0.7891209554901153, 0.7853739998518283, 0.7815994491735633, 0.785423522485397, 0.7892765566242046, 0.7853715240704097, 0.7814360680960034, 0.7854261759364154, 0.7894482760631883, 0.7853686753645267, 0.7812553965935913, 0.7854292397074193, 0.78963857561208, 0.7853653741464247, 0.7810547226464033, 0.7854328037377393, 0.7898504500602758, 0.7853615184653004, 0.7808307309187463, 0.7854369839687103, 0.7900875792933814, 0.7853569760189372, 0.7805793255130143, 0.7854419319160602, 0.7903545356599997, 0.7853515726275994, 0.780295386331297, 0.7854478486195973, 0.7906570715126955, 0.7853450752597432, 0.7799724281073286, 0.7854550054162597, 0.7910025255672941, 0.7853371664554831, 0.7796021135251486, 0.7854637756343743, 0.7914004095801952, 0.7853274047809937, 0.779173542005507, 0.7854746842979461, 0.7918632761155346, 0.7853151618652604, 0.7786721835871613, 0.7854884885574197, 0.7924080380945685, 0.7852995187256767, 0.7780782326339372, 0.7855063126291567, 0.793058040476272, 0.785279088281272, 0.7773639764784825, 0.7855298839184719, 0.7938464364306789, 0.7852516973038643, 0.7764894156380426, 0.7855619672618999, 0.7948219379649214, 0.7852137846516954, 0.7753946125694181, 0.7856072126362451, 0.79605914609125, 0.7851591851790392, 0.7739855158668231, 0.7856739354902945, 0.7976783609916234, 0.7850764632271896, 0.7721057032252635, 0.7857782150823718, 0.7998868364501711, 0.7849424247313844, 0.7694745225029652, 0.7859545219863004, 0.8030744494301016, 0.7847039984693698, 0.7655336489325617, 0.7862881141717799, 0.8080707457106334, 0.7842168679435736, 0.7589929973712551, 0.7870399582550824, 0.8170024053228061, 0.7829664646628549, 0.7460646295034071, 0.7893533396694333, 0.8373928854821369, 0.7779167655766434, 0.709067798106866, 0.8041851052740175, 0.9259725207603868, 0.6853823556461944, 1.609390908849073e-14, -0.6853823556461796, -0.9259725207603872, -0.8041851052740232, -0.7090677981068675, -0.7779167655766391, -0.8373928854821374, -0.7893533396694353, -0.7460646295034069, -0.7829664646628521, -0.8170024053228071, -0.7870399582550839, -0.7589929973712554, -0.784216867943569, -0.8080707457106328, -0.7862881141717815, -0.7655336489325594, -0.7847039984693677, -0.803074449430106, -0.7859545219863004, -0.7694745225029668, -0.7849424247313814, -0.7998868364501707, -0.7857782150823761, -0.7721057032252684, -0.7850764632271883, -0.7976783609916255, -0.7856739354902922, -0.7739855158668238, -0.7851591851790394, -0.7960591460912487, -0.7856072126362437, -0.7753946125694189, -0.7852137846516944, -0.7948219379649193, -0.7855619672618995, -0.7764894156380392, -0.7852516973038655, -0.793846436430678, -0.7855298839184728, -0.7773639764784818, -0.7852790882812705, -0.7930580404762712, -0.78550631262916, -0.778078232633936, -0.7852995187256783, -0.7924080380945703, -0.7854884885574217, -0.778672183587162, -0.7853151618652574, -0.7918632761155348, -0.7854746842979449, -0.7791735420055094, -0.7853274047809936, -0.7914004095801954, -0.7854637756343752, -0.7796021135251495, -0.785337166455484, -0.791002525567294, -0.7854550054162589, -0.7799724281073281, -0.7853450752597424, -0.7906570715126962, -0.7854478486195956, -0.7802953863312975, -0.7853515726276024, -0.7903545356599977, -0.7854419319160605, -0.7805793255130162, -0.7853569760189395, -0.7900875792933839, -0.7854369839687105, -0.7808307309187441, -0.7853615184652988, -0.7898504500602789, -0.7854328037377389, -0.7810547226464065, -0.7853653741464244, -0.7896385756120811, -0.7854292397074204, -0.7812553965935921, -0.7853686753645255, -0.7894482760631873, -0.7854261759364161, -0.7814360680960036, -0.7853715240704106, -0.7892765566242053, -0.7854235224853973, -0.7815994491735642, -0.7853739998518277, -0.7891209554901145, -0.7854212087199197, -0.7817477795721627, -0.7853761655726459, -0.7889794305388863, -0.7854191785423366, -0.7818829250660201, -0.7853780714203884, -0.7888502737607173, -0.7854173869429377, -0.7820064521806506, -0.7853797578555406, -0.7887320458111954, -0.78541579746913, -0.7821196856801412, -0.7853812577904427, -0.7886235253763617, -0.7854143803436184, -0.7822237532843265, -0.7853825982194444, -0.7885236695817393, -0.7854131110481685, -0.7823196208063156, -0.7853838014527711, -0.7884315827345267, -0.7854119692457527, -0.7824081200213072, -0.7853848860604576, -0.7883464914227738, -0.785410937951799, -0.7824899709616849, -0.7853858676017513, -0.7882677245134049, -0.7854100028907935, -0.7825657998963111, -0.7853867591938823, -0.7881946969611343, -0.7854091519923851, -0.7826361539373533, -0.7853875719594503, -0.7881268966080065, -0.7854083749933891, -0.7827015129897222, -0.7853883153811214, -0.7880638733490275, -0.7854076631210564, -0.7827622995897173, -0.7853889975850046, -0.7880052301842516, -0.7854070088391168, -0.782818887054722, -0.7853896255686043, -0.7879506157856165, -0.7854064056428167, -0.7828716062720821, -0.7853902053854327, -0.7878997182883585, -0.7854058478923799, -0.7829207513842472, -0.7853907422954637, -0.7878522600787322, -0.7854053306769028, -0.7829665845732536, -0.7853912408884322, -0.7878079933971205, -0.7854048497024467, -0.7830093401058985, -0.7853917051855437, -0.7877666966124097, -0.7854044011995627, -0.7830492277687023, -0.7853921387236663, -0.7877281710518341, -0.7854039818464199, -0.7830864357965083, -0.7853925446255832, -0.7876922382929438, -0.7854035887046412, -0.7831211333788265, -0.7853929256587003, -0.7876587378419125, -0.7854032191654683, -0.783153472812331, -0.7853932842844727, -0.7876275251363032, -0.7854028709043441, -0.7831835913554133, -0.78539362270013, -0.7875984698217005, -0.7854025418425163, -0.7832116128308161, -0.7853939428741048, -0.7875714542603638, -0.7854022301143367, -0.7832376490142562, -0.785394246576229, -0.7875463722374856, -0.7854019340393548, -0.7832618008404972, -0.785394535403538, -0.7875231278362828, -0.7854016520984034, -0.7832841594530086, -0.7853948108024867, -0.7875016344580846, -0.7854013829129518, -0.783304807119121, -0.7853950740881182, -0.7874818139674168, -0.7854011252273091, -0.7833238180289197, -0.7853953264606587, -0.787463595945278, -0.7854008778931121, -0.7833412589932884, -0.7853955690199567, -0.7874469170365372, -0.7854006398558305, -0.7833571900540605, -0.7853958027781927, -0.7874317203795002, -0.7854004101429112, -0.7833716650171992, -0.7853960286709113, -0.7874179551076886, -0.7854001878533278, -0.7833847319182641, -0.7853962475669114, -0.7874055759151929, -0.7853999721483605, -0.7833964334279625, -0.7853964602769061, -0.7873945426785102, -0.7853997622433394, -0.7834068072044361, -0.7853966675613473, -0.7873848201287753, -0.7853995574002707, -0.7834158861978621, -0.7853968701374429, -0.7873763775691768, -0.7853993569211601, -0.7834236989120423, -0.7853970686854963, -0.7873691886333154, -0.7853991601419547, -0.7834302696270159, -0.7853972638546969, -0.7873632310808595, -0.7853989664269764, -0.7834356185858868, -0.7853974562684928, -0.7873584866274739, -0.7853987751637579, -0.7834397621487247, -0.7853976465295752, -0.7873549408066425, -0.7853985857582313, -0.7834427129155801, -0.7853978352245389, -0.787352582861304, -0.7853983976301524, -0.7834444798204436, -0.7853980229283757, -0.7873514056639727, -0.7853982102087246, -0.7834450681974975, -0.7853982102087226, -0.7873514056639731, -0.7853980229283678, -0.7834444798204424, -0.7853983976301508, -0.7873525828613065, -0.785397835224534, -0.7834427129155729, -0.7853985857582316, -0.787354940806637, -0.7853976465295768, -0.7834397621487217, -0.7853987751637598, -0.7873584866274763, -0.7853974562684936, -0.7834356185858857, -0.7853989664269762, -0.7873632310808631, -0.7853972638546948, -0.7834302696270115, -0.785399160141956, -0.7873691886333202, -0.7853970686854954, -0.7834236989120442, -0.7853993569211569, -0.7873763775691799, -0.785396870137447, -0.7834158861978548, -0.7853995574002676, -0.7873848201287724, -0.7853966675613474, -0.7834068072044331, -0.7853997622433369, -0.7873945426785106, -0.785396460276903, -0.7833964334279591, -0.7853999721483559, -0.7874055759151893, -0.7853962475669127, -0.783384731918262, -0.7854001878533278, -0.7874179551076896, -0.7853960286709141, -0.7833716650172026, -0.7854004101429074, -0.787431720379501, -0.7853958027781951, -0.7833571900540598, -0.7854006398558343, -0.7874469170365336, -0.7853955690199582, -0.7833412589932893, -0.7854008778931069, -0.7874635959452756, -0.7853953264606549, -0.7833238180289196, -0.7854011252273105, -0.7874818139674155, -0.7853950740881206, -0.783304807119121, -0.7854013829129531, -0.7875016344580813, -0.7853948108024862, -0.7832841594530099, -0.7854016520984004, -0.7875231278362823, -0.785394535403535, -0.7832618008404992, -0.7854019340393592, -0.7875463722374836, -0.7853942465762277, -0.7832376490142585, -0.7854022301143367, -0.7875714542603602, -0.7853939428741059, -0.7832116128308222, -0.7854025418425175, -0.7875984698217019, -0.7853936227001261, -0.7831835913554134, -0.7854028709043464, -0.7876275251363073, -0.7853932842844726, -0.7831534728123333, -0.7854032191654676, -0.7876587378419132, -0.7853929256587019, -0.7831211333788286, -0.7854035887046444, -0.787692238292945, -0.785392544625576, -0.7830864357965069, -0.7854039818464181, -0.7877281710518329, -0.785392138723665, -0.7830492277687015, -0.7854044011995612, -0.7877666966124082, -0.7853917051855409, -0.7830093401059001, -0.7854048497024502, -0.7878079933971207, -0.7853912408884317, -0.7829665845732519, -0.7854053306769019, -0.7878522600787361, -0.7853907422954625, -0.7829207513842488, -0.785405847892378, -0.7878997182883632, -0.7853902053854345, -0.7828716062720827, -0.7854064056428156, -0.7879506157856134, -0.785389625568603, -0.7828188870547208, -0.7854070088391165, -0.7880052301842495, -0.7853889975850017, -0.7827622995897177, -0.7854076631210563, -0.7880638733490253, -0.7853883153811211, -0.782701512989719, -0.7854083749933913, -0.7881268966080073, -0.7853875719594486, -0.782636153937352, -0.785409151992385, -0.7881946969611335, -0.785386759193886, -0.7825657998963094, -0.7854100028907963, -0.7882677245134015, -0.7853858676017542, -0.7824899709616858, -0.7854109379517975, -0.7883464914227755, -0.7853848860604526, -0.7824081200213, -0.7854119692457525, -0.7884315827345252, -0.7853838014527711, -0.782319620806315, -0.7854131110481691, -0.788523669581739, -0.7853825982194509, -0.7822237532843285, -0.7854143803436205, -0.7886235253763606, -0.7853812577904433, -0.7821196856801393, -0.7854157974691254, -0.7887320458111895, -0.7853797578555405, -0.7820064521806521, -0.785417386942935, -0.7888502737607134, -0.7853780714203892, -0.781882925066021, -0.7854191785423323, -0.7889794305388825, -0.7853761655726464, -0.7817477795721587, -0.7854212087199258, -0.7891209554901146, -0.7853739998518257, -0.7815994491735622, -0.7854235224853975, -0.7892765566242049, -0.7853715240704069, -0.7814360680960082, -0.7854261759364145, -0.789448276063187, -0.7853686753645263, -0.781255396593589, -0.785429239707419, -0.78963857561208, -0.7853653741464264, -0.7810547226464039, -0.7854328037377419, -0.7898504500602772, -0.7853615184652978, -0.7808307309187483, -0.7854369839687089, -0.7900875792933822, -0.7853569760189393, -0.7805793255130143, -0.7854419319160628, -0.7903545356599975, -0.7853515726276022, -0.7802953863312986, -0.7854478486195996, -0.7906570715126986, -0.7853450752597452, -0.7799724281073284, -0.7854550054162593, -0.7910025255672958, -0.7853371664554837, -0.7796021135251511, -0.7854637756343731, -0.7914004095801926, -0.7853274047809892, -0.7791735420055116, -0.7854746842979452, -0.7918632761155372, -0.7853151618652585, -0.7786721835871621, -0.7854884885574241, -0.7924080380945724, -0.7852995187256794, -0.7780782326339362, -0.785506312629158, -0.793058040476272, -0.7852790882812707, -0.7773639764784813, -0.7855298839184708, -0.7938464364306771, -0.7852516973038586, -0.7764894156380386, -0.7855619672618995, -0.7948219379649216, -0.7852137846516948, -0.7753946125694188, -0.7856072126362491, -0.7960591460912522, -0.7851591851790403, -0.773985515866825, -0.7856739354902949, -0.7976783609916239, -0.7850764632271929, -0.7721057032252674, -0.7857782150823737, -0.79988683645017, -0.7849424247313835, -0.7694745225029656, -0.7859545219863034, -0.8030744494301058, -0.7847039984693711, -0.7655336489325605, -0.7862881141717794, -0.8080707457106334, -0.7842168679435758, -0.7589929973712558, -0.787039958255082, -0.8170024053228069, -0.7829664646628516, -0.7460646295034068, -0.7893533396694322, -0.8373928854821365, -0.7779167655766381, -0.7090677981068633, -0.8041851052740133, -0.9259725207603876, -0.6853823556461737, ], [0.0, 0.37948871235857445, 0.6853861703910509, 0.869886301497878, 0.9259845053980817, 0.8858560302415087, 0.8041812885181571, 0.7344807801434036, 0.7090438225134139, 0.7306025439626949, 0.7779205863571707, 0.8209661599235243, 0.8374288646759048, 0.8226800276563995, 0.7893495128451944, 0.7581924553171372, 0.7460166277213912, 0.757230827599198, 0.7829702995579311, 0.8073972803090739, 0.817062455049765, 0.808011646535345, 0.7870361132512392, 0.7669378735412797, 0.7589208679399556, 0.7665119020607931, 0.7842207251076686, 0.8013023712011297, 0.8081549930509762, 0.8016148270592651, 0.7862842427795937, 0.7714239774670002, 0.7654372389818016, 0.7711851665794942, 0.7847078861766628, 0.7978650906854793, 0.8031830732495893, 0.798053423684682, 0.7859506158548147, 0.7741398476156484, 0.7693536269301812, 0.7739876159094966, 0.7849463514211523, 0.7956665444916386, 0.8000200683656816, 0.7957920670308379, 0.7857742656722041, 0.7759548485872164, 0.7719600635847204, 0.775849640637468, 0.7850804375509779, 0.7941437634752182, 0.7978364866301373, 0.7942331608524825, 0.7856699340253872, 0.7772498738217078, 0.7738148189590927, 0.7771730232543822, 0.7851632160501066, 0.7930296970675141, 0.7962425066557306, 0.793096423559879, 0.785603150053066, 0.7782177722773772, 0.7751984887158425, 0.7781593337727012, 0.7852178812973304, 0.7921815656164455, 0.795030932124239, 0.7922331329086489, 0.7855578341554629, 0.7789665315185876, 0.7762674366212259, 0.778920724816773, 0.7852558693211891, 0.7915161750316572, 0.7940815225544335, 0.7915571040065356, 0.7855256704845409, 0.77956128629584, 0.7771156531258724, 0.7795245244617438, 0.7852833456972077, 0.7909817997993669, 0.7933197392396439, 0.791014972818745, 0.7855020086019096, 0.7800436351350202, 0.7778030120171032, 0.7800135755066849, 0.7853038720618626, 0.7905446061364763, 0.7926969354830057, 0.7905719467645324, 0.7854840831417375, 0.780441376958011, 0.778369445804375, 0.7804164254540243, 0.7853196222087737, 0.7901815432768888, 0.7921800268643849, 0.7902043836531112, 0.7854701660954215, 0.7807737850166342, 0.7788425965096175, 0.7807528198759306, 0.7853319838618826, 0.7898763738446238, 0.7917457410904221, 0.7898956651820781, 0.7854591325619845, 0.7810546341224227, 0.7792421949523116, 0.781036843580464, 0.7853418767321999, 0.789617337645197, 0.791377242345225, 0.7896337767831431, 0.7854502246164742, 0.7812940233014027, 0.7795826915548332, 0.781278805929804, 0.7853499300138256, 0.7893957154001732, 0.79106206018967, 0.7894098240159438, 0.7854429163605451, 0.7814995180393443, 0.7798749020242116, 0.7814864192467881, 0.7853565860690813, 0.7892049125817505, 0.790790770656926, 0.7892170884981641, 0.7854368334801135, 0.7816768909207867, 0.7801270727895633, 0.7816655611908815, 0.7853621634042218, 0.7890398562790439, 0.7905561292057838, 0.789050407690222, 0.7854317035276158, 0.7818306161387936, 0.7803455914528905, 0.7818207828013001, 0.7853668962295904, 0.7888965871694817, 0.7903524848517853, 0.7889057560623095, 0.7854273242120456, 0.7819642081663224, 0.7805354728126386, 0.7819556558559371, 0.7853709600530899, 0.7887719768545332, 0.7901753747189875, 0.7887799553975144, 0.7854235426076089, 0.7820804589006328, 0.7807006988631936, 0.7820730157448882, 0.7853744886742506, 0.7886635279380213, 0.7900212375297316, 0.7886704701749016, 0.7854202411826179, 0.7821816069707386, 0.7808444613352697, 0.7821751346476984, 0.7853775857335142, 0.7885692300361375, 0.7898872074086354, 0.7885752603728031, 0.7854173282020362, 0.782269460680643, 0.7809693376924132, 0.7822638471475569, 0.785380332727778, 0.7884874544182102, 0.7897709631058515, 0.7884926738739133, 0.7854147310008583, 0.7823454886070133, 0.7810774207450171, 0.7823406427091847, 0.7853827946815581, 0.7884168758585653, 0.7896706162239714, 0.7884213667286392, 0.785412391181263, 0.7824108872005743, 0.7811704153151443, 0.7824067346282448, 0.7853850242323279, 0.7883564140090609, 0.7895846274035534, 0.7883602433787015, 0.7854102611226416, 0.7824666317450412, 0.7812497110739619, 0.7824631119737439, 0.7853870646246012, 0.7883051890211168, 0.7895117429025523, 0.7883084114337672, 0.785408301401959, 0.7825135150633331, 0.7813164378493817, 0.7825105790240872, 0.7853889519420915, 0.7882624877487785, 0.7894509463084204, 0.7882651472416583, 0.7854064788537501, 0.7825521770464411, 0.7813715078132848, 0.7825497853471343, 0.7853907168014915, 0.7882277379474648, 0.78940142167705, 0.7882298696047982, 0.78540476508423, 0.782583127183406, 0.7814156476702928, 0.7825812487543194, 0.7853923856626522, 0.7882004886294762, 0.7893625254635822, 0.7882021197607593, 0.7854031353097176, 0.7826067616466058, 0.7814494230747842, 0.782605372719287, 0.7853939818645164, 0.7881803952621225, 0.7893337653624544, 0.7881815462821874, 0.7854015674267191, 0.7826233760428973, 0.7814732568671988, 0.7826224593976486, 0.7853955264658945, 0.7881672088716726, 0.7893147847143669, 0.7881678939369597, 0.7854000412457044, 0.7826331746181041, 0.7814874422582776, 0.7826327190545671, 0.7853970389498727, 0.788160768394989, 0.7893053515364797, 0.7881609958337704, 0.7853985378372402, 0.7826362764598763, 0.781492151743322, 0.7826362764598763, 0.7853985378372406, 0.7881609958337713, 0.7893053515364793, 0.7881607683949878, 0.7853970389498737, 0.7826327190545673, 0.7814874422582784, 0.7826331746181046, 0.7854000412457045, 0.7881678939369627, 0.7893147847143684, 0.788167208871673, 0.7853955264658945, 0.7826224593976493, 0.7814732568671989, 0.7826233760428978, 0.7854015674267197, 0.7881815462821872, 0.7893337653624571, 0.7881803952621227, 0.7853939818645164, 0.7826053727192869, 0.7814494230747838, 0.7826067616466055, 0.785403135309717, 0.7882021197607586, 0.7893625254635832, 0.7882004886294767, 0.7853923856626523, 0.7825812487543202, 0.7814156476702929, 0.782583127183406, 0.7854047650842301, 0.7882298696047974, 0.7894014216770503, 0.7882277379474645, 0.7853907168014923, 0.7825497853471337, 0.7813715078132841, 0.7825521770464415, 0.7854064788537505, 0.7882651472416582, 0.7894509463084214, 0.7882624877487778, 0.7853889519420917, 0.7825105790240877, 0.7813164378493817, 0.7825135150633339, 0.7854083014019587, 0.7883084114337678, 0.7895117429025521, 0.7883051890211166, 0.7853870646246012, 0.7824631119737436, 0.7812497110739619, 0.7824666317450408, 0.7854102611226412, 0.7883602433787028, 0.7895846274035543, 0.788356414009062, 0.7853850242323291, 0.7824067346282444, 0.7811704153151435, 0.7824108872005746, 0.7854123911812632, 0.7884213667286397, 0.7896706162239719, 0.7884168758585658, 0.7853827946815576, 0.7823406427091832, 0.781077420745017, 0.782345488607013, 0.7854147310008581, 0.788492673873913, 0.7897709631058529, 0.78848745441821, 0.7853803327277783, 0.7822638471475578, 0.7809693376924132, 0.782269460680643, 0.7854173282020372, 0.788575260372802, 0.7898872074086364, 0.7885692300361381, 0.7853775857335146, 0.7821751346476967, 0.7808444613352683, 0.7821816069707389, 0.785420241182618, 0.7886704701749016, 0.7900212375297322, 0.7886635279380231, 0.7853744886742503, 0.7820730157448892, 0.7807006988631938, 0.7820804589006324, 0.7854235426076094, 0.7887799553975137, 0.7901753747189876, 0.7887719768545334, 0.7853709600530909, 0.7819556558559364, 0.7805354728126378, 0.7819642081663236, 0.785427324212046, 0.7889057560623094, 0.7903524848517857, 0.7888965871694816, 0.7853668962295899, 0.7818207828012999, 0.7803455914528907, 0.7818306161387935, 0.7854317035276156, 0.7890504076902229, 0.7905561292057838, 0.7890398562790442, 0.7853621634042234, 0.7816655611908809, 0.7801270727895637, 0.781676890920787, 0.7854368334801146, 0.7892170884981652, 0.7907907706569279, 0.7892049125817495, 0.7853565860690818, 0.781486419246788, 0.7798749020242124, 0.781499518039344, 0.7854429163605458, 0.7894098240159428, 0.7910620601896711, 0.7893957154001727, 0.7853499300138264, 0.7812788059298055, 0.7795826915548323, 0.7812940233014027, 0.7854502246164738, 0.7896337767831441, 0.7913772423452249, 0.7896173376451983, 0.7853418767321988, 0.7810368435804645, 0.7792421949523106, 0.7810546341224229, 0.7854591325619844, 0.7898956651820775, 0.7917457410904218, 0.7898763738446254, 0.7853319838618833, 0.7807528198759313, 0.778842596509617, 0.7807737850166346, 0.7854701660954206, 0.7902043836531115, 0.7921800268643854, 0.790181543276889, 0.7853196222087745, 0.780416425454023, 0.7783694458043752, 0.7804413769580111, 0.7854840831417379, 0.7905719467645317, 0.7926969354830052, 0.7905446061364768, 0.7853038720618628, 0.7800135755066864, 0.7778030120171029, 0.7800436351350213, 0.7855020086019089, 0.7910149728187458, 0.7933197392396439, 0.7909817997993673, 0.7852833456972084, 0.7795245244617438, 0.7771156531258718, 0.7795612862958398, 0.7855256704845415, 0.791557104006536, 0.7940815225544335, 0.7915161750316576, 0.7852558693211886, 0.7789207248167731, 0.7762674366212248, 0.7789665315185869, 0.7855578341554624, 0.7922331329086469, 0.795030932124238, 0.7921815656164461, 0.7852178812973318, 0.7781593337727006, 0.7751984887158426, 0.7782177722773764, 0.7856031500530652, 0.79309642355988, 0.7962425066557319, 0.7930296970675154, 0.7851632160501055, 0.7771730232543819, 0.7738148189590917, 0.7772498738217073, 0.7856699340253874, 0.7942331608524823, 0.7978364866301355, 0.7941437634752178, 0.7850804375509778, 0.7758496406374704, 0.7719600635847201, 0.7759548485872162, 0.7857742656722041, 0.7957920670308372, 0.8000200683656817, 0.7956665444916381, 0.7849463514211523, 0.7739876159094959, 0.7693536269301816, 0.7741398476156479, 0.7859506158548156, 0.7980534236846815, 0.8031830732495893, 0.7978650906854775, 0.7847078861766638, 0.7711851665794942, 0.7654372389818028, 0.7714239774670006, 0.7862842427795933, 0.8016148270592645, 0.8081549930509736, 0.8013023712011305, 0.7842207251076692, 0.7665119020607934, 0.7589208679399557, 0.7669378735412776, 0.7870361132512391, 0.8080116465353462, 0.8170624550497653, 0.8073972803090737, 0.7829702995579292, 0.7572308275991981, 0.7460166277213924, 0.7581924553171375, 0.7893495128451928, 0.8226800276563994, 0.8374288646759047, 0.8209661599235251, 0.7779205863571697, 0.7306025439626956, 0.7090438225134138, 0.7344807801434022, 0.804181288518157, 0.8858560302415057, 0.9259845053980832, 0.8698863014978834, 0.6853861703910539, 0.3794887123585895, 8.143984251901798e-15, -0.3794887123585788, -0.685386170391044, -0.8698863014978787, -0.9259845053980815, -0.8858560302415079, -0.8041812885181602, -0.7344807801434039, -0.7090438225134136, -0.7306025439626928, -0.7779205863571678, -0.8209661599235236, -0.8374288646759049, -0.822680027656399, -0.7893495128451934, -0.7581924553171379, -0.74601662772139, -0.7572308275991959, -0.7829702995579306, -0.8073972803090738, -0.8170624550497659, -0.8080116465353462, -0.78703611325124, -0.7669378735412822, -0.7589208679399566, -0.7665119020607922, -0.7842207251076703, -0.8013023712011311, -0.8081549930509758, -0.8016148270592655, -0.7862842427795929, -0.7714239774669995, -0.765437238981802, -0.7711851665794937, -0.7847078861766618, -0.7978650906854785, -0.803183073249588, -0.7980534236846835, -0.7859506158548152, -0.7741398476156485, -0.769353626930182, -0.7739876159094954, -0.7849463514211518, -0.7956665444916404, -0.8000200683656812, -0.7957920670308394, -0.7857742656722053, -0.7759548485872161, -0.7719600635847211, -0.7758496406374669, -0.7850804375509765, -0.7941437634752168, -0.7978364866301374, -0.794233160852483, -0.7856699340253874, -0.7772498738217076, -0.7738148189590922, -0.7771730232543832, -0.7851632160501054, -0.7930296970675144, -0.7962425066557315, -0.7930964235598802, -0.7856031500530664, -0.7782177722773767, -0.7751984887158415, -0.7781593337727022, -0.7852178812973314, -0.7921815656164459, -0.795030932124239, -0.7922331329086493, -0.7855578341554639, -0.7789665315185871, -0.7762674366212258, -0.7789207248167721, -0.7852558693211892, -0.7915161750316564, -0.7940815225544341, -0.7915571040065359, -0.7855256704845427, -0.7795612862958404, -0.7771156531258728, -0.7795245244617427, -0.7852833456972074, -0.7909817997993669, -0.7933197392396432, -0.791014972818745, -0.7855020086019101, -0.7800436351350204, -0.7778030120171023, -0.7800135755066858, -0.7853038720618621, -0.7905446061364754, -0.7926969354830058, -0.7905719467645316, -0.7854840831417373, -0.7804413769580099, -0.7783694458043751, -0.7804164254540247, -0.7853196222087744, -0.7901815432768893, -0.7921800268643862, -0.7902043836531116, -0.7854701660954196, -0.7807737850166352, -0.778842596509618, -0.78075281987593, -0.7853319838618821, -0.7898763738446235, -0.7917457410904215, -0.7898956651820752, -0.7854591325619849, -0.7810546341224219, -0.7792421949523114, -0.7810368435804637, -0.7853418767322006, -0.7896173376451969, -0.791377242345227, -0.7896337767831443, -0.7854502246164736, -0.7812940233014035, -0.7795826915548326, -0.7812788059298036, -0.7853499300138259, -0.7893957154001716, -0.7910620601896714, -0.7894098240159426, -0.7854429163605451, -0.7814995180393435, -0.7798749020242114, -0.7814864192467879, -0.7853565860690813, -0.7892049125817493, -0.7907907706569262, -0.7892170884981652, -0.7854368334801142, -0.7816768909207858, -0.7801270727895645, -0.7816655611908826, -0.7853621634042227, -0.7890398562790437, -0.7905561292057836, -0.7890504076902226, -0.7854317035276163, -0.7818306161387941, -0.7803455914528893, -0.7818207828013, -0.7853668962295886, -0.7888965871694814, -0.7903524848517856, -0.78890575606231, -0.7854273242120453, -0.7819642081663234, -0.780535472812639, -0.781955655855935, -0.7853709600530896, -0.7887719768545327, -0.7901753747189878, -0.7887799553975167, -0.7854235426076097, -0.7820804589006326, -0.780700698863194, -0.7820730157448896, -0.7853744886742525, -0.7886635279380241, -0.7900212375297315, -0.7886704701749021, -0.7854202411826171, -0.7821816069707371, -0.7808444613352684, -0.7821751346476977, -0.7853775857335135, -0.7885692300361375, -0.7898872074086353, -0.7885752603728032, -0.7854173282020342, -0.782269460680642, -0.7809693376924118, -0.7822638471475567, -0.785380332727777, -0.7884874544182086, -0.789770963105851, -0.788492673873913, -0.7854147310008579, -0.7823454886070145, -0.7810774207450154, -0.7823406427091851, -0.7853827946815596, -0.7884168758585651, -0.7896706162239722, -0.78842136672864, -0.7854123911812634, -0.7824108872005732, -0.7811704153151434, -0.7824067346282457, -0.7853850242323304, -0.7883564140090601, -0.7895846274035534, -0.7883602433787015, -0.7854102611226425, -0.7824666317450412, -0.7812497110739604, -0.7824631119737456, -0.7853870646245997, -0.7883051890211153, -0.7895117429025537, -0.788308411433766, -0.7854083014019605, -0.7825135150633303, -0.7813164378493821, -0.7825105790240864, -0.7853889519420896, -0.7882624877487783, -0.7894509463084209, -0.7882651472416549, -0.7854064788537514, -0.7825521770464418, -0.7813715078132855, -0.7825497853471339, -0.7853907168014943, -0.7882277379474648, -0.78940142167705, -0.7882298696047988, -0.7854047650842308, -0.7825831271834061, -0.7814156476702926, -0.7825812487543177, -0.7853923856626521, -0.7882004886294779, -0.7893625254635823, -0.788202119760756, -0.7854031353097181, -0.782606761646606, -0.7814494230747857, -0.7826053727192881, -0.7853939818645139, -0.7881803952621208, -0.7893337653624571, -0.7881815462821904, -0.7854015674267175, -0.7826233760428979, -0.7814732568672009, -0.7826224593976493, -0.7853955264658915, -0.7881672088716711, -0.789314784714369, -0.788167893936961, -0.7854000412457061, -0.7826331746181037, -0.7814874422582834, -0.7826327190545668, -0.7853970389498715, -0.7881607683949898, -0.7893053515364801, -0.7881609958337775, -0.7853985378372402, -0.7826362764598761, -0.781492151743322, -0.7826362764598752, -0.7853985378372396, -0.7881609958337702, -0.7893053515364792, -0.7881607683949877, -0.7853970389498733, -0.7826327190545648, -0.7814874422582755, -0.7826331746181031, -0.7854000412457047, -0.7881678939369617, -0.7893147847143657, -0.7881672088716726, -0.7853955264658942, -0.7826224593976503, -0.7814732568671983, -0.7826233760428972, -0.7854015674267191, -0.7881815462821871, -0.7893337653624565, -0.7881803952621228, -0.785393981864517, -0.7826053727192878, -0.781449423074786, -0.7826067616466044, -0.7854031353097201, -0.7882021197607597, -0.7893625254635774, -0.7882004886294753, -0.785392385662652, -0.7825812487543174, -0.7814156476702921, -0.7825831271834051, -0.7854047650842313, -0.7882298696047949, -0.7894014216770494, -0.7882277379474628, -0.7853907168014902, -0.7825497853471346, -0.7813715078132843, -0.7825521770464415, -0.7854064788537517, -0.788265147241655, -0.7894509463084225, -0.7882624877487777, -0.7853889519420927, -0.7825105790240886, -0.7813164378493805, -0.7825135150633327, -0.7854083014019587, -0.788308411433767, -0.789511742902555, -0.7883051890211119, -0.7853870646245974, -0.7824631119737441, -0.7812497110739622, -0.78246663174504, -0.785410261122641, -0.788360243378703, -0.7895846274035526, -0.7883564140090613, -0.7853850242323276, -0.7824067346282427, -0.7811704153151443, -0.7824108872005741, -0.7854123911812644, -0.7884213667286374, -0.7896706162239722, -0.7884168758585668, -0.7853827946815575, -0.7823406427091826, -0.7810774207450165, -0.7823454886070127, -0.7854147310008547, -0.7884926738739131, -0.7897709631058535, -0.7884874544182108, -0.7853803327277784, -0.7822638471475568, -0.7809693376924143, -0.7822694606806436, -0.7854173282020365, -0.7885752603728045, -0.7898872074086363, -0.7885692300361377, -0.7853775857335156, -0.7821751346476992, -0.7808444613352692, -0.7821816069707388, -0.7854202411826186, -0.7886704701749, -0.7900212375297311, -0.7886635279380221, -0.7853744886742522, -0.7820730157448884, -0.7807006988631926, -0.7820804589006332, -0.7854235426076084, -0.7887799553975141, -0.7901753747189889, -0.7887719768545344, -0.7853709600530897, -0.7819556558559357, -0.7805354728126386, -0.7819642081663223, -0.7854273242120484, -0.7889057560623091, -0.7903524848517858, -0.788896587169481, -0.785366896229593, -0.7818207828012994, -0.7803455914528895, -0.7818306161387932, -0.7854317035276158, -0.7890504076902207, -0.7905561292057823, -0.7890398562790448, -0.7853621634042202, -0.7816655611908817, -0.7801270727895634, -0.7816768909207863, -0.7854368334801135, -0.7892170884981654, -0.7907907706569236, -0.7892049125817504, -0.7853565860690802, -0.7814864192467874, -0.7798749020242107, -0.7814995180393437, -0.7854429163605446, -0.7894098240159458, -0.7910620601896692, -0.7893957154001752, -0.7853499300138227, -0.781278805929807, -0.7795826915548334, -0.781294023301403, -0.7854502246164748, -0.7896337767831414, -0.7913772423452229, -0.7896173376451949, -0.7853418767321997, -0.7810368435804629, -0.7792421949523095, -0.7810546341224213, -0.7854591325619847, -0.7898956651820795, -0.7917457410904226, -0.7898763738446246, -0.7853319838618801, -0.7807528198759299, -0.7788425965096173, -0.7807737850166314, -0.7854701660954166, -0.7902043836531109, -0.7921800268643875, -0.790181543276887, -0.7853196222087727, -0.780416425454025, -0.7783694458043753, -0.7804413769580105, -0.7854840831417337, -0.7905719467645327, -0.7926969354830035, -0.7905446061364773, -0.7853038720618629, -0.7800135755066849, -0.7778030120171016, -0.7800436351350222, -0.7855020086019109, -0.7910149728187432, -0.793319739239646, -0.7909817997993661, -0.7852833456972078, -0.779524524461742, -0.7771156531258718, -0.7795612862958383, -0.7855256704845419, -0.791557104006537, -0.7940815225544333, -0.7915161750316586, -0.7852558693211884, -0.7789207248167709, -0.7762674366212247, -0.7789665315185853, -0.7855578341554621, -0.7922331329086492, -0.7950309321242377, -0.7921815656164466, -0.7852178812973298, -0.7781593337727022, -0.7751984887158422, -0.7782177722773779, -0.7856031500530666, -0.7930964235598797, -0.796242506655731, -0.7930296970675147, -0.785163216050106, -0.7771730232543819, -0.7738148189590943, -0.7772498738217061, -0.7856699340253863, -0.7942331608524794, -0.7978364866301383, -0.7941437634752168, -0.7850804375509806, -0.7758496406374679, -0.7719600635847188, -0.775954848587219, -0.7857742656722058, -0.79579206703084, -0.8000200683656822, -0.7956665444916395, -0.7849463514211521, -0.7739876159094966, -0.7693536269301808, -0.7741398476156475, -0.7859506158548145, -0.7980534236846818, -0.8031830732495868, -0.797865090685481, -0.7847078861766631, -0.7711851665794942, -0.7654372389818015, -0.7714239774669996, -0.7862842427795953, -0.8016148270592652, -0.8081549930509753, -0.8013023712011293, -0.7842207251076698, -0.7665119020607903, -0.758920867939955, -0.76693787354128, -0.7870361132512382, -0.8080116465353426, -0.8170624550497653, -0.8073972803090739, -0.782970299557932, -0.7572308275991994, -0.7460166277213909, -0.7581924553171376, -0.7893495128451915, -0.8226800276563978, -0.8374288646759049, -0.820966159923526, -0.7779205863571743, -0.7306025439626936, -0.7090438225134102, -0.7344807801434011, -0.8041812885181483, -0.8858560302415092, -0.9259845053980779, -0.8698863014978864, -0.6853861703910721, -0.3794887123585752, ], [0.0, 0.19467550918029627, 0.37949102785523897, 0.54548606351051, 0.6854014299419255, 0.7942972269105032, 0.8699225171588488, 0.9128013181525445, 0.9260324489030265, 0.914833485576237, 0.8858876100249463, 0.8465730797089278, 0.8041659967776565, 0.7651068856029952, 0.7344105879160564, 0.715278787704914, 0.7089478343543333, 0.7147749344580442, 0.7305370022915862, 0.7528920581537489, 0.7779359426489165, 0.8017791553649951, 0.8210704770454609, 0.8334050622423461, 0.8375731003280672, 0.8336282510631285, 0.822779669670331, 0.8071342738823859, 0.7893340592947911, 0.7721445596570364, 0.7580537922515045, 0.748936161356553, 0.7458238389606223, 0.7488110363167383, 0.7570968741982456, 0.7691530538833359, 0.7829858835976485, 0.796450585983222, 0.8075705842780224, 0.8148160833869589, 0.8173042069557895, 0.8148958450837935, 0.8081801960914907, 0.7983544042088228, 0.7870203647852975, 0.77593168569981, 0.7667295582017535, 0.7607062827196565, 0.758629636211151, 0.7606511591450082, 0.7663083963168531, 0.7746171230667573, 0.7842366728335712, 0.7936814739816136, 0.8015461459078838, 0.8067106836962032, 0.8084963307396879, 0.8067509494301641, 0.8018537262504742, 0.7946411981827344, 0.7862680598628936, 0.778025355670823, 0.7711442153987108, 0.766614552896499, 0.7650450562882344, 0.7665839341463389, 0.7709103570489937, 0.7772958188618262, 0.7847243415244084, 0.7920520620080165, 0.798181451049143, 0.8022239160887852, 0.8036269570163219, 0.8022479164216755, 0.7983647429542882, 0.7926237691011848, 0.7859338492994847, 0.7793239365586386, 0.7737861916349712, 0.7701283406824491, 0.7688570641699336, 0.7701090793943718, 0.7736391016163932, 0.7788651953294525, 0.7849634697411876, 0.7909968980619643, 0.7960582837925376, 0.799405713997171, 0.8005704154883806, 0.7994214636758677, 0.7961785514329345, 0.791371957070687, 0.785756752984909, 0.780194994048659, 0.7755241432958483, 0.7724317002650851, 0.7713546928529669, 0.7724186269788033, 0.7754243167383361, 0.7798836996569402, 0.7850983895425213, 0.7902684312400188, 0.7946144176257081, 0.797494418120071, 0.7984982614327413, 0.7975054027590213, 0.7946982930786407, 0.7905299725034481, 0.78565149514096, 0.7808107283862189, 0.7767381818044281, 0.7740372227687381, 0.7730951100739598, 0.7740279014543974, 0.7766670085898828, 0.7805888033863915, 0.7851821924177921, 0.7897434462706251, 0.7935836288218987, 0.7961322852947168, 0.7970218386201985, 0.796140258401811, 0.7936445066669618, 0.7899332638054108, 0.7855835822216559, 0.7812614801433988, 0.777620278460409, 0.775202150514728, 0.7743576750340053, 0.7751952874325919, 0.7775678768579249, 0.7810980954395086, 0.785238098395873, 0.7893542998676505, 0.7928240728379029, 0.795129674745968, 0.7959352678196022, 0.7951356109315911, 0.7928693967791773, 0.7894956141612031, 0.7855369056788176, 0.7815988231102863, 0.7782774208881216, 0.7760692548004126, 0.7752973434175807, 0.7760641026462689, 0.7782380835694752, 0.7814761763124312, 0.78527757613937, 0.7890610525922744, 0.7922536286046692, 0.7943771687708195, 0.7951198192133668, 0.7943816497336975, 0.792287841015, 0.7891677194057704, 0.7855031128937197, 0.7818541028635172, 0.7787735884732725, 0.7767236709924434, 0.7760064794244582, 0.77671977116861, 0.7787438132989943, 0.7817612714236472, 0.7853068326568081, 0.7888388231778457, 0.7918218187588708, 0.7938076713097222, 0.7945027105915022, 0.7938110624679969, 0.7918477101183587, 0.7889195451056472, 0.785477506718786, 0.7820472564614328, 0.7791490269854239, 0.7772188472422886, 0.7765430535101973, 0.7772159060450138, 0.7791265711706564, 0.7819772461472557, 0.7853294822884249, 0.7886715154112994, 0.791496280312734, 0.7933782338642017, 0.7940373642727492, 0.7933807728607828, 0.7915156652759242, 0.7887319513500225, 0.7854572622470006, 0.7821913519212846, 0.7794299311862928, 0.7775895270176443, 0.7769447419849557, 0.7775873512873257, 0.779413319779358, 0.7821395633111091, 0.7853477661981667, 0.788548483411233, 0.791255717578449, 0.7930606216554914, 0.7936931638392887, 0.7930624658312092, 0.7912697975698842, 0.7885923797380215, 0.7854405750997004, 0.7822950696532553, 0.7796336481482274, 0.7778587054318632, 0.7772364787636619, 0.7778571671011627, 0.7796219032636081, 0.7822584534887833, 0.7853631588663085, 0.7884626654503205, 0.7910860137594269, 0.7928361209345836, 0.7934498178730846, 0.7928373740596745, 0.7910955811306333, 0.7884924928464869, 0.7854262215847783, 0.7823641173007232, 0.7797716106994166, 0.7780415432442326, 0.7774347031720358, 0.7780405590458415, 0.7797640965439319, 0.7823406911029857, 0.7853766927977212, 0.7884095059461464, 0.7909780034769681, 0.7926925687523009, 0.7932941367123691, 0.7926932964774174, 0.790983559504411, 0.7884268274335867, 0.7854133146212983, 0.7824020518308371, 0.7798510334830226, 0.7781476278064346, 0.7775498133519216, 0.7781471475323695, 0.7798473666950679, 0.7823906202572037, 0.7853891455464275, 0.788386331665331, 0.7909261845303177, 0.7926226354334391, 0.7932181687339097, 0.7926228741248532, 0.7909280068864317, 0.7883920130360363, 0.7854011573073103, 0.7824107461774815, 0.7798758813437962, 0.7781822651503477, 0.7775875684246718, 0.7781822651503475, 0.7798758813437966, 0.7824107461774813, 0.7854011573073101, 0.7883920130360355, 0.7909280068864323, 0.7926228741248533, 0.7932181687339097, 0.7926226354334395, 0.790926184530318, 0.7883863316653336, 0.7853891455464282, 0.782390620257204, 0.7798473666950682, 0.7781471475323697, 0.7775498133519215, 0.7781476278064354, 0.779851033483023, 0.7824020518308368, 0.7854133146212993, 0.788426827433587, 0.7909835595044109, 0.7926932964774173, 0.7932941367123685, 0.7926925687523002, 0.7909780034769676, 0.7884095059461463, 0.7853766927977212, 0.7823406911029863, 0.7797640965439315, 0.7780405590458418, 0.7774347031720364, 0.7780415432442327, 0.7797716106994164, 0.7823641173007224, 0.7854262215847784, 0.7884924928464863, 0.7910955811306336, 0.7928373740596741, 0.7934498178730849, 0.7928361209345839, 0.7910860137594267, 0.7884626654503203, 0.7853631588663089, 0.7822584534887829, 0.7796219032636085, 0.7778571671011627, 0.7772364787636623, 0.7778587054318642, 0.7796336481482279, 0.7822950696532558, 0.7854405750997, 0.7885923797380213, 0.7912697975698838, 0.7930624658312088, 0.7936931638392887, 0.7930606216554914, 0.7912557175784488, 0.7885484834112336, 0.785347766198167, 0.7821395633111099, 0.7794133197793587, 0.7775873512873253, 0.7769447419849548, 0.7775895270176449, 0.7794299311862927, 0.7821913519212847, 0.785457262247001, 0.7887319513500225, 0.7915156652759238, 0.7933807728607825, 0.7940373642727492, 0.7933782338642017, 0.7914962803127337, 0.7886715154112993, 0.7853294822884256, 0.7819772461472553, 0.7791265711706553, 0.7772159060450147, 0.7765430535101974, 0.7772188472422887, 0.779149026985425, 0.7820472564614319, 0.7854775067187866, 0.7889195451056467, 0.791847710118359, 0.7938110624679968, 0.7945027105915017, 0.7938076713097217, 0.7918218187588709, 0.7888388231778459, 0.7853068326568088, 0.7817612714236482, 0.7787438132989938, 0.77671977116861, 0.7760064794244586, 0.7767236709924438, 0.7787735884732723, 0.7818541028635163, 0.7855031128937195, 0.7891677194057697, 0.7922878410150005, 0.7943816497336967, 0.7951198192133662, 0.794377168770821, 0.7922536286046696, 0.7890610525922744, 0.7852775761393707, 0.7814761763124312, 0.7782380835694752, 0.7760641026462681, 0.7752973434175806, 0.7760692548004123, 0.778277420888121, 0.7815988231102863, 0.7855369056788174, 0.7894956141612034, 0.792869396779178, 0.7951356109315906, 0.7959352678196022, 0.795129674745968, 0.7928240728379036, 0.789354299867651, 0.7852380983958739, 0.7810980954395086, 0.7775678768579254, 0.7751952874325917, 0.7743576750340055, 0.7752021505147281, 0.7776202784604097, 0.7812614801433984, 0.7855835822216566, 0.7899332638054103, 0.7936445066669616, 0.7961402584018111, 0.7970218386201974, 0.7961322852947169, 0.7935836288218981, 0.7897434462706254, 0.7851821924177921, 0.7805888033863922, 0.776667008589882, 0.7740279014543975, 0.7730951100739591, 0.7740372227687381, 0.776738181804428, 0.7808107283862185, 0.7856514951409597, 0.7905299725034485, 0.7946982930786408, 0.797505402759021, 0.7984982614327405, 0.7974944181200714, 0.7946144176257077, 0.790268431240019, 0.7850983895425218, 0.7798836996569406, 0.7754243167383368, 0.772418626978803, 0.7713546928529668, 0.772431700265085, 0.7755241432958485, 0.7801949940486583, 0.7857567529849089, 0.7913719570706871, 0.7961785514329345, 0.7994214636758681, 0.8005704154883809, 0.799405713997172, 0.7960582837925376, 0.7909968980619642, 0.7849634697411872, 0.7788651953294523, 0.7736391016163933, 0.7701090793943719, 0.7688570641699335, 0.7701283406824493, 0.7737861916349721, 0.779323936558639, 0.7859338492994843, 0.7926237691011849, 0.7983647429542882, 0.8022479164216753, 0.8036269570163213, 0.8022239160887855, 0.7981814510491428, 0.792052062008016, 0.7847243415244082, 0.7772958188618263, 0.7709103570489939, 0.7665839341463387, 0.7650450562882339, 0.7666145528964984, 0.7711442153987109, 0.7780253556708239, 0.7862680598628939, 0.794641198182735, 0.8018537262504732, 0.8067509494301641, 0.8084963307396871, 0.8067106836962029, 0.8015461459078844, 0.793681473981614, 0.7842366728335705, 0.7746171230667573, 0.766308396316853, 0.760651159145009, 0.7586296362111512, 0.7607062827196561, 0.7667295582017533, 0.7759316856998086, 0.7870203647852972, 0.7983544042088218, 0.80818019609149, 0.8148958450837931, 0.8173042069557905, 0.8148160833869585, 0.8075705842780239, 0.7964505859832218, 0.782985883597649, 0.769153053883335, 0.7570968741982455, 0.7488110363167383, 0.7458238389606223, 0.7489361613565522, 0.7580537922515044, 0.7721445596570357, 0.7893340592947903, 0.8071342738823852, 0.8227796696703316, 0.8336282510631284, 0.837573100328067, 0.8334050622423462, 0.8210704770454612, 0.8017791553649956, 0.7779359426489174, 0.752892058153749, 0.7305370022915861, 0.7147749344580442, 0.7089478343543346, 0.7152787877049139, 0.7344105879160554, 0.7651068856029948, 0.8041659967776542, 0.8465730797089273, 0.8858876100249466, 0.9148334855762367, 0.9260324489030262, 0.9128013181525453, 0.8699225171588487, 0.7942972269105064, 0.6854014299419261, 0.5454860635105173, 0.37949102785524047, 0.19467550918030427, 4.222187713144881e-15, -0.1946755091802988, -0.3794910278552341, -0.5454860635105109, -0.6854014299419214, -0.794297226910503, -0.869922517158845, -0.9128013181525445, -0.9260324489030263, -0.9148334855762369, -0.8858876100249486, -0.8465730797089287, -0.8041659967776557, -0.7651068856029959, -0.7344105879160561, -0.7152787877049143, -0.7089478343543335, -0.7147749344580432, -0.7305370022915858, -0.7528920581537475, -0.7779359426489169, -0.8017791553649951, -0.8210704770454607, -0.8334050622423469, -0.8375731003280674, -0.8336282510631288, -0.8227796696703337, -0.8071342738823866, -0.7893340592947913, -0.7721445596570364, -0.758053792251505, -0.748936161356553, -0.7458238389606224, -0.7488110363167381, -0.7570968741982451, -0.7691530538833352, -0.782985883597648, -0.7964505859832223, -0.8075705842780224, -0.8148160833869583, -0.8173042069557891, -0.8148958450837933, -0.8081801960914905, -0.7983544042088239, -0.7870203647852977, -0.7759316856998111, -0.7667295582017537, -0.7607062827196555, -0.7586296362111521, -0.7606511591450079, -0.7663083963168521, -0.7746171230667561, -0.7842366728335708, -0.7936814739816141, -0.8015461459078834, -0.8067106836962028, -0.8084963307396876, -0.8067509494301649, -0.8018537262504735, -0.7946411981827353, -0.7862680598628939, -0.7780253556708238, -0.7711442153987106, -0.7666145528964989, -0.7650450562882333, -0.7665839341463399, -0.7709103570489934, -0.7772958188618269, -0.7847243415244082, -0.7920520620080168, -0.7981814510491432, -0.8022239160887851, -0.8036269570163228, -0.802247916421675, -0.7983647429542882, -0.7926237691011846, -0.7859338492994834, -0.7793239365586386, -0.7737861916349721, -0.7701283406824492, -0.7688570641699343, -0.7701090793943712, -0.773639101616393, -0.7788651953294525, -0.7849634697411871, -0.7909968980619645, -0.7960582837925381, -0.7994057139971706, -0.8005704154883801, -0.799421463675868, -0.7961785514329348, -0.7913719570706871, -0.7857567529849098, -0.780194994048658, -0.7755241432958484, -0.7724317002650846, -0.7713546928529668, -0.7724186269788034, -0.7754243167383356, -0.7798836996569405, -0.7850983895425224, -0.790268431240019, -0.7946144176257063, -0.7974944181200719, -0.7984982614327415, -0.7975054027590209, -0.7946982930786415, -0.7905299725034485, -0.7856514951409599, -0.7808107283862187, -0.7767381818044288, -0.7740372227687378, -0.7730951100739593, -0.7740279014543963, -0.7766670085898826, -0.7805888033863901, -0.7851821924177919, -0.7897434462706251, -0.7935836288218981, -0.7961322852947176, -0.7970218386201973, -0.7961402584018108, -0.793644506666962, -0.7899332638054092, -0.7855835822216566, -0.7812614801433986, -0.7776202784604095, -0.7752021505147274, -0.7743576750340052, -0.7751952874325915, -0.7775678768579254, -0.7810980954395081, -0.7852380983958718, -0.7893542998676512, -0.7928240728379041, -0.7951296747459675, -0.7959352678196014, -0.7951356109315917, -0.7928693967791782, -0.7894956141612036, -0.7855369056788181, -0.7815988231102872, -0.7782774208881216, -0.7760692548004126, -0.7752973434175799, -0.7760641026462685, -0.7782380835694741, -0.7814761763124312, -0.7852775761393697, -0.789061052592275, -0.7922536286046694, -0.7943771687708197, -0.7951198192133673, -0.7943816497336974, -0.792287841015, -0.7891677194057699, -0.7855031128937187, -0.781854102863517, -0.7787735884732729, -0.7767236709924439, -0.7760064794244581, -0.7767197711686112, -0.7787438132989948, -0.7817612714236483, -0.7853068326568096, -0.7888388231778455, -0.7918218187588694, -0.7938076713097216, -0.7945027105915013, -0.7938110624679964, -0.7918477101183592, -0.7889195451056467, -0.7854775067187865, -0.782047256461434, -0.7791490269854231, -0.7772188472422878, -0.7765430535101969, -0.777215906045014, -0.7791265711706552, -0.7819772461472547, -0.7853294822884244, -0.7886715154112988, -0.7914962803127332, -0.7933782338642029, -0.794037364272748, -0.7933807728607837, -0.7915156652759243, -0.7887319513500225, -0.7854572622470015, -0.782191351921285, -0.7794299311862914, -0.7775895270176438, -0.776944741984955, -0.7775873512873254, -0.7794133197793596, -0.7821395633111093, -0.7853477661981672, -0.7885484834112325, -0.7912557175784491, -0.7930606216554921, -0.7936931638392886, -0.7930624658312108, -0.7912697975698835, -0.7885923797380201, -0.7854405750997009, -0.7822950696532561, -0.7796336481482284, -0.7778587054318614, -0.777236478763663, -0.7778571671011628, -0.7796219032636065, -0.7822584534887844, -0.7853631588663077, -0.7884626654503187, -0.7910860137594268, -0.7928361209345839, -0.7934498178730848, -0.7928373740596737, -0.7910955811306343, -0.7884924928464877, -0.7854262215847783, -0.782364117300722, -0.7797716106994165, -0.7780415432442339, -0.7774347031720352, -0.7780405590458407, -0.7797640965439315, -0.7823406911029871, -0.7853766927977209, -0.788409505946145, -0.7909780034769691, -0.7926925687523003, -0.7932941367123691, -0.7926932964774169, -0.7909835595044102, -0.7884268274335863, -0.7854133146212994, -0.7824020518308398, -0.7798510334830212, -0.7781476278064339, -0.7775498133519221, -0.7781471475323696, -0.7798473666950653, -0.7823906202572033, -0.7853891455464282, -0.7883863316653319, -0.7909261845303179, -0.7926226354334386, -0.7932181687339135, -0.7926228741248531, -0.7909280068864306, -0.7883920130360373, -0.7854011573073105, -0.7824107461774856, -0.7798758813437968, -0.7781822651503479, -0.7775875684246718, -0.7781822651503473, -0.7798758813437958, -0.7824107461774813, -0.78540115730731, -0.7883920130360352, -0.7909280068864323, -0.7926228741248529, -0.7932181687339089, -0.7926226354334385, -0.7909261845303186, -0.7883863316653318, -0.785389145546426, -0.7823906202572033, -0.7798473666950698, -0.7781471475323709, -0.7775498133519212, -0.7781476278064361, -0.7798510334830214, -0.7824020518308366, -0.7854133146212996, -0.788426827433587, -0.7909835595044117, -0.7926932964774172, -0.7932941367123688, -0.7926925687522995, -0.7909780034769701, -0.7884095059461473, -0.7853766927977189, -0.7823406911029864, -0.7797640965439332, -0.7780405590458424, -0.7774347031720357, -0.7780415432442309, -0.7797716106994166, -0.7823641173007218, -0.785426221584777, -0.7884924928464865, -0.7910955811306316, -0.7928373740596756, -0.7934498178730836, -0.7928361209345856, -0.7910860137594283, -0.7884626654503174, -0.7853631588663104, -0.7822584534887838, -0.7796219032636085, -0.777857167101162, -0.7772364787636624, -0.7778587054318633, -0.7796336481482267, -0.7822950696532548, -0.7854405750997003, -0.7885923797380184, -0.7912697975698822, -0.7930624658312089, -0.7936931638392879, -0.7930606216554899, -0.7912557175784481, -0.7885484834112342, -0.7853477661981669, -0.7821395633111088, -0.7794133197793578, -0.7775873512873257, -0.7769447419849559, -0.7775895270176433, -0.7794299311862928, -0.7821913519212836, -0.7854572622470011, -0.7887319513500228, -0.7915156652759224, -0.7933807728607806, -0.7940373642727487, -0.7933782338642007, -0.791496280312733, -0.7886715154112992, -0.7853294822884271, -0.7819772461472562, -0.779126571170657, -0.7772159060450131, -0.7765430535101971, -0.7772188472422891, -0.7791490269854239, -0.7820472564614338, -0.7854775067187865, -0.7889195451056474, -0.7918477101183601, -0.793811062467997, -0.7945027105915023, -0.7938076713097219, -0.7918218187588699, -0.7888388231778455, -0.7853068326568081, -0.7817612714236482, -0.7787438132989953, -0.77671977116861, -0.7760064794244573, -0.7767236709924435, -0.7787735884732714, -0.781854102863518, -0.7855031128937204, -0.7891677194057716, -0.792287841015, -0.7943816497336965, -0.7951198192133664, -0.7943771687708194, -0.7922536286046716, -0.7890610525922731, -0.7852775761393691, -0.7814761763124309, -0.7782380835694782, -0.7760641026462689, -0.7752973434175798, -0.7760692548004114, -0.7782774208881216, -0.7815988231102851, -0.7855369056788183, -0.7894956141612037, -0.7928693967791751, -0.7951356109315911, -0.7959352678196032, -0.7951296747459683, -0.7928240728379041, -0.7893542998676509, -0.7852380983958719, -0.7810980954395097, -0.7775678768579243, -0.7751952874325923, -0.7743576750340055, -0.7752021505147275, -0.7776202784604102, -0.7812614801433995, -0.7855835822216554, -0.7899332638054117, -0.7936445066669607, -0.7961402584018124, -0.797021838620199, -0.7961322852947161, -0.7935836288218995, -0.7897434462706254, -0.7851821924177905, -0.78058880338639, -0.7766670085898827, -0.7740279014543968, -0.7730951100739588, -0.7740372227687374, -0.7767381818044281, -0.7808107283862196, -0.7856514951409601, -0.790529972503449, -0.7946982930786392, -0.7975054027590195, -0.7984982614327399, -0.7974944181200708, -0.7946144176257058, -0.7902684312400193, -0.7850983895425225, -0.7798836996569397, -0.7754243167383347, -0.772418626978804, -0.7713546928529667, -0.7724317002650856, -0.7755241432958481, -0.7801949940486593, -0.7857567529849087, -0.791371957070688, -0.7961785514329334, -0.7994214636758679, -0.80057041548838, -0.7994057139971732, -0.7960582837925412, -0.7909968980619646, -0.7849634697411892, -0.7788651953294524, -0.7736391016163923, -0.7701090793943699, -0.7688570641699332, -0.7701283406824477, -0.7737861916349723, -0.7793239365586393, -0.7859338492994841, -0.7926237691011848, -0.798364742954288, -0.802247916421674, -0.8036269570163214, -0.8022239160887852, -0.798181451049141, -0.7920520620080167, -0.7847243415244087, -0.7772958188618267, -0.7709103570489936, -0.7665839341463411, -0.7650450562882334, -0.766614552896498, -0.7711442153987111, -0.778025355670823, -0.7862680598628948, -0.7946411981827337, -0.8018537262504722, -0.8067509494301632, -0.8084963307396885, -0.8067106836962025, -0.8015461459078844, -0.7936814739816116, -0.7842366728335733, -0.774617123066756, -0.7663083963168557, -0.7606511591450085, -0.7586296362111531, -0.7607062827196571, -0.7667295582017558, -0.7759316856998124, -0.7870203647852976, -0.798354404208822, -0.8081801960914922, -0.8148958450837941, -0.81730420695579, -0.8148160833869589, -0.8075705842780229, -0.7964505859832228, -0.7829858835976458, -0.7691530538833381, -0.7570968741982449, -0.748811036316739, -0.745823838960623, -0.7489361613565532, -0.7580537922515045, -0.7721445596570352, -0.7893340592947887, -0.8071342738823859, -0.8227796696703314, -0.8336282510631273, -0.8375731003280671, -0.8334050622423462, -0.8210704770454615, -0.8017791553649967, -0.777935942648916, -0.7528920581537477, -0.7305370022915867, -0.7147749344580449, -0.7089478343543338, -0.7152787877049149, -0.7344105879160547, -0.7651068856029919, -0.804165996777656, -0.8465730797089271, -0.8858876100249435, -0.9148334855762371, -0.9260324489030255, -0.9128013181525464, -0.8699225171588538, -0.7942972269105018, -0.6854014299419278, -0.545486063510521, -0.3794910278552538, -0.19467550918029564, ], [0.0, 0.09796494325296816, 0.19467672237911826, 0.2889109977165885, 0.3795002902036346, 0.4653604643014772, 0.5455149389841766, 0.6191159755096, 0.6854624772888614, 0.7440138402446059, 0.7943995083844888, 0.8364240153039393, 0.8700674240719609, 0.8954812113819588, 0.9129797728693468, 0.9230278511088789, 0.9262243022310476, 0.9232827179052799, 0.9150095036454541, 0.902280079560949, 0.8860139139818782, 0.8671491226797775, 0.8466173662374051, 0.8253197557584697, 0.8041044335128643, 0.7837464319094087, 0.764930333603342, 0.7482361603328869, 0.7341288114234454, 0.722951258309855, 0.7149215826257743, 0.7101338261999532, 0.7085625054493038, 0.7100705337762668, 0.7144201969962187, 0.7212867414932866, 0.7302740652124934, 0.7409319496794258, 0.7527742383302887, 0.7652973532323895, 0.7779985488232322, 0.7903933269967522, 0.8020314815038568, 0.8125112994309849, 0.8214915211952818, 0.8287007453521075, 0.8339440575331394, 0.8371067607886962, 0.8381551841586886, 0.8371346441134253, 0.8341647263780437, 0.8294321405969467, 0.823181474642611, 0.8157042368672422, 0.8073266214512478, 0.7983964629618931, 0.7892698606017318, 0.7802979502775071, 0.7718142840034207, 0.764123242259636, 0.7574898572507446, 0.7521313654845306, 0.7482107390218248, 0.7458323687193329, 0.7450399925742838, 0.7458168807481164, 0.748088208852121, 0.7517254753685809, 0.7565527502028276, 0.7623544815748416, 0.768884539664136, 0.7758761390995917, 0.7830522595537043, 0.7901361748839046, 0.7968617064863042, 0.8029828353197602, 0.8082823384818987, 0.8125791589071122, 0.8157342689700594, 0.8176548484722527, 0.8182966623911726, 0.8176645914643379, 0.8158113366921949, 0.8128343847332322, 0.808871382610664, 0.8040941250296094, 0.7987014040656026, 0.7929110075111663, 0.7869511776204294, 0.7810518556522988, 0.775436039195747, 0.770311568920826, 0.7658636397169645, 0.7622482991361973, 0.7595871549914549, 0.7579634655114119, 0.7574197315071157, 0.7579568526142787, 0.7595348509842054, 0.7620751079652371, 0.7654640044406009, 0.769557805523686, 0.7741885870063492, 0.7791709657898022, 0.7843093706627684, 0.7894055740338035, 0.7942661999996705, 0.7987099294745283, 0.802574138664147, 0.8057207322255308, 0.808040965948017, 0.8094590943754743, 0.8099347248679256, 0.8094638094019195, 0.8080782570467394, 0.8058442015998899, 0.8028590084207603, 0.7992471502640474, 0.7951551222452687, 0.7907455995507371, 0.7861910669851123, 0.7816671660912066, 0.7773460128753398, 0.7733897369739787, 0.7699444816025637, 0.7671350833749562, 0.765060622928432, 0.7637910023731159, 0.7633646652814238, 0.7637875308012109, 0.7650331671985938, 0.7670441834562026, 0.7697347722087599, 0.7729942949607967, 0.7766917627418317, 0.7806810334682697, 0.7848065224210058, 0.7889092052634354, 0.7928326844650684, 0.7964290900918963, 0.7995645945826841, 0.8021243379400962, 0.8040165840176542, 0.8051759592989207, 0.8055656615340612, 0.8051785654273235, 0.8040371947328105, 0.8021925730183975, 0.7997220073892904, 0.796725899061249, 0.7933237103814426, 0.7896492484128877, 0.7858454494321153, 0.7820588657886693, 0.7784340659623821, 0.7751081600459221, 0.7722056562924369, 0.7698338401157241, 0.7680788456111444, 0.7670025621367054, 0.7666404858374724, 0.7670005894780357, 0.7680632449667183, 0.7697821929924533, 0.7720865147569337, 0.7748835233458966, 0.7780624582335912, 0.7814988370056842, 0.7850592946875888, 0.7886067239251392, 0.792005519279034, 0.7951267263875326, 0.7978529017652217, 0.8000825012988567, 0.8017336345627107, 0.8027470471436828, 0.8030882232584988, 0.8027485348976183, 0.8017454002414539, 0.8001214517750449, 0.7979427519529027, 0.7952961300301895, 0.792285746444443, 0.789029019688742, 0.785652073912922, 0.7822848826781279, 0.7790562947598114, 0.7760891312891213, 0.7734955397542279, 0.7713727796327958, 0.7697995971372324, 0.768833323411664, 0.7685078024263994, 0.7688322228559984, 0.769790893624976, 0.7713439668779051, 0.7734290762389764, 0.7759638237228013, 0.7788490168376245, 0.7819725294714878, 0.7852136371076037, 0.7884476596242256, 0.7915507340234269, 0.7944045352863829, 0.7969007662974974, 0.798945247283777, 0.8004614510928036, 0.8013933522576373, 0.8017074843312175, 0.8013941303956937, 0.8004676048003702, 0.7989656187940744, 0.796947757481465, 0.7944931291348233, 0.7916972790682911, 0.7886684878610861, 0.7855235966701097, 0.7823835198485585, 0.7793686164426278, 0.7765940969631723, 0.7741656399605494, 0.772175384459076, 0.7706984495717251, 0.7697901121901459, 0.7694837483173039, 0.769789614350363, 0.7706945125459511, 0.7721623512283702, 0.7741355763105527, 0.7765374176735688, 0.7792748631398759, 0.7822422453386172, 0.7853253037285357, 0.7884055662777874, 0.7913648834616102, 0.7940899417651494, 0.7964765849652128, 0.7984337790587628, 0.7998870705094218, 0.8007814069639718, 0.801083214002974, 0.8007816499023237, 0.7998889917151177, 0.7984401390459335, 0.7964912554292191, 0.794117599969837, 0.7914106326183712, 0.7884745039759801, 0.7854220638145599, 0.7823705406796343, 0.7794370573315944, 0.7767341529107024, 0.7743654823547248, 0.7724218567723663, 0.7709777754426278, 0.7700885813659366, 0.769788348549357, 0.7700885813659367, 0.7709777754426278, 0.7724218567723663, 0.7743654823547246, 0.7767341529107022, 0.7794370573315943, 0.7823705406796343, 0.7854220638145598, 0.7884745039759801, 0.7914106326183712, 0.7941175999698386, 0.7964912554292194, 0.7984401390459334, 0.799888991715118, 0.8007816499023235, 0.8010832140029738, 0.8007814069639718, 0.7998870705094219, 0.798433779058763, 0.7964765849652132, 0.7940899417651497, 0.7913648834616093, 0.7884055662777872, 0.7853253037285355, 0.782242245338617, 0.7792748631398757, 0.7765374176735688, 0.7741355763105525, 0.7721623512283706, 0.7706945125459511, 0.7697896143503634, 0.769483748317304, 0.7697901121901456, 0.7706984495717248, 0.772175384459076, 0.7741656399605495, 0.7765940969631715, 0.7793686164426279, 0.7823835198485581, 0.7855235966701101, 0.7886684878610857, 0.791697279068291, 0.7944931291348227, 0.7969477574814652, 0.7989656187940738, 0.80046760480037, 0.8013941303956938, 0.8017074843312173, 0.8013933522576377, 0.8004614510928042, 0.7989452472837771, 0.7969007662974971, 0.7944045352863828, 0.7915507340234268, 0.7884476596242254, 0.785213637107604, 0.7819725294714879, 0.7788490168376245, 0.7759638237228016, 0.7734290762389764, 0.771343966877906, 0.7697908936249762, 0.7688322228559981, 0.7685078024263988, 0.7688333234116645, 0.7697995971372324, 0.7713727796327959, 0.7734955397542279, 0.7760891312891213, 0.7790562947598116, 0.7822848826781275, 0.7856520739129222, 0.7890290196887423, 0.7922857464444424, 0.7952961300301893, 0.7979427519529031, 0.8001214517750448, 0.801745400241453, 0.8027485348976187, 0.8030882232584986, 0.8027470471436827, 0.8017336345627116, 0.8000825012988565, 0.7978529017652221, 0.7951267263875325, 0.7920055192790342, 0.7886067239251392, 0.7850592946875882, 0.7814988370056838, 0.7780624582335913, 0.7748835233458965, 0.7720865147569337, 0.7697821929924543, 0.768063244966718, 0.7670005894780353, 0.7666404858374727, 0.7670025621367057, 0.7680788456111443, 0.7698338401157236, 0.7722056562924365, 0.7751081600459215, 0.7784340659623821, 0.782058865788669, 0.7858454494321152, 0.7896492484128877, 0.7933237103814424, 0.7967258990612488, 0.7997220073892906, 0.8021925730183979, 0.8040371947328107, 0.8051785654273231, 0.8055656615340614, 0.8051759592989208, 0.8040165840176536, 0.8021243379400963, 0.7995645945826843, 0.7964290900918966, 0.7928326844650684, 0.7889092052634352, 0.7848065224210063, 0.7806810334682696, 0.7766917627418318, 0.7729942949607969, 0.7697347722087605, 0.7670441834562031, 0.7650331671985942, 0.763787530801211, 0.7633646652814237, 0.7637910023731157, 0.7650606229284324, 0.7671350833749565, 0.7699444816025636, 0.7733897369739786, 0.7773460128753397, 0.7816671660912068, 0.7861910669851123, 0.790745599550737, 0.7951551222452683, 0.7992471502640471, 0.8028590084207602, 0.8058442015998903, 0.8080782570467392, 0.8094638094019199, 0.8099347248679255, 0.8094590943754745, 0.808040965948017, 0.8057207322255311, 0.8025741386641473, 0.7987099294745291, 0.7942661999996703, 0.7894055740338031, 0.784309370662768, 0.7791709657898025, 0.7741885870063491, 0.7695578055236862, 0.765464004440601, 0.7620751079652373, 0.7595348509842061, 0.7579568526142783, 0.7574197315071152, 0.7579634655114122, 0.759587154991455, 0.7622482991361967, 0.7658636397169643, 0.770311568920826, 0.775436039195747, 0.7810518556522988, 0.7869511776204293, 0.7929110075111665, 0.7987014040656026, 0.8040941250296092, 0.8088713826106634, 0.8128343847332326, 0.815811336692195, 0.8176645914643375, 0.8182966623911728, 0.8176548484722536, 0.8157342689700599, 0.8125791589071127, 0.8082823384818989, 0.8029828353197599, 0.7968617064863042, 0.7901361748839046, 0.7830522595537035, 0.7758761390995917, 0.768884539664136, 0.7623544815748418, 0.7565527502028278, 0.7517254753685809, 0.7480882088521212, 0.7458168807481163, 0.7450399925742835, 0.745832368719332, 0.7482107390218251, 0.752131365484531, 0.7574898572507449, 0.7641232422596367, 0.7718142840034201, 0.7802979502775065, 0.789269860601731, 0.798396462961893, 0.8073266214512475, 0.8157042368672426, 0.8231814746426113, 0.829432140596946, 0.8341647263780435, 0.8371346441134258, 0.8381551841586884, 0.8371067607886964, 0.8339440575331396, 0.8287007453521075, 0.821491521195282, 0.8125112994309853, 0.8020314815038567, 0.7903933269967519, 0.7779985488232329, 0.7652973532323891, 0.7527742383302894, 0.7409319496794259, 0.7302740652124938, 0.7212867414932862, 0.7144201969962185, 0.7100705337762669, 0.7085625054493043, 0.7101338261999532, 0.7149215826257743, 0.7229512583098544, 0.7341288114234454, 0.7482361603328866, 0.7649303336033421, 0.7837464319094078, 0.8041044335128636, 0.8253197557584679, 0.8466173662374047, 0.8671491226797778, 0.8860139139818778, 0.9022800795609494, 0.915009503645454, 0.92328271790528, 0.9262243022310488, 0.9230278511088791, 0.9129797728693476, 0.8954812113819589, 0.8700674240719624, 0.8364240153039402, 0.7943995083844879, 0.7440138402446071, 0.6854624772888607, 0.6191159755096022, 0.5455149389841767, 0.46536046430148037, 0.37950029020363524, 0.28891099771659284, 0.1946767223791191, 0.09796494325297232, 2.1908312433585575e-15, -0.09796494325297009, -0.1946767223791157, -0.28891099771658857, -0.37950029020363146, -0.465360464301477, -0.5455149389841728, -0.6191159755095993, -0.6854624772888585, -0.7440138402446049, -0.7943995083844867, -0.8364240153039386, -0.870067424071961, -0.8954812113819585, -0.9129797728693467, -0.9230278511088789, -0.9262243022310478, -0.9232827179052798, -0.9150095036454535, -0.9022800795609494, -0.8860139139818789, -0.86714912267978, -0.8466173662374056, -0.8253197557584698, -0.8041044335128651, -0.7837464319094087, -0.7649303336033434, -0.7482361603328872, -0.7341288114234454, -0.722951258309855, -0.7149215826257749, -0.7101338261999527, -0.7085625054493041, -0.7100705337762668, -0.7144201969962181, -0.7212867414932863, -0.7302740652124933, -0.740931949679426, -0.752774238330288, -0.7652973532323883, -0.7779985488232316, -0.7903933269967516, -0.8020314815038563, -0.8125112994309852, -0.8214915211952817, -0.8287007453521079, -0.8339440575331396, -0.8371067607886961, -0.8381551841586893, -0.837134644113425, -0.8341647263780436, -0.829432140596946, -0.8231814746426114, -0.8157042368672422, -0.8073266214512473, -0.7983964629618933, -0.7892698606017318, -0.7802979502775073, -0.7718142840034202, -0.7641232422596367, -0.7574898572507447, -0.752131365484531, -0.7482107390218246, -0.7458323687193328, -0.7450399925742834, -0.7458168807481168, -0.7480882088521208, -0.7517254753685813, -0.7565527502028276, -0.7623544815748414, -0.7688845396641367, -0.7758761390995914, -0.7830522595537043, -0.7901361748839044, -0.7968617064863038, -0.8029828353197598, -0.8082823384818978, -0.8125791589071121, -0.8157342689700599, -0.8176548484722527, -0.8182966623911726, -0.8176645914643381, -0.8158113366921946, -0.8128343847332324, -0.8088713826106642, -0.80409412502961, -0.7987014040656029, -0.792911007511166, -0.7869511776204293, -0.7810518556522987, -0.775436039195747, -0.7703115689208258, -0.7658636397169646, -0.7622482991361963, -0.7595871549914547, -0.7579634655114111, -0.7574197315071156, -0.7579568526142787, -0.7595348509842059, -0.7620751079652375, -0.765464004440602, -0.7695578055236855, -0.7741885870063483, -0.7791709657898027, -0.7843093706627685, -0.7894055740338034, -0.7942661999996705, -0.7987099294745283, -0.802574138664147, -0.8057207322255309, -0.8080409659480166, -0.809459094375474, -0.8099347248679254, -0.8094638094019188, -0.8080782570467393, -0.8058442015998899, -0.8028590084207604, -0.7992471502640472, -0.7951551222452677, -0.7907455995507383, -0.786191066985112, -0.7816671660912067, -0.7773460128753399, -0.7733897369739775, -0.7699444816025633, -0.7671350833749565, -0.765060622928432, -0.7637910023731159, -0.7633646652814238, -0.7637875308012108, -0.7650331671985938, -0.7670441834562026, -0.7697347722087604, -0.7729942949607971, -0.7766917627418325, -0.7806810334682686, -0.7848065224210055, -0.7889092052634348, -0.7928326844650684, -0.7964290900918962, -0.7995645945826846, -0.8021243379400973, -0.8040165840176545, -0.805175959298921, -0.8055656615340608, -0.805178565427323, -0.8040371947328104, -0.8021925730183977, -0.7997220073892903, -0.7967258990612492, -0.7933237103814424, -0.7896492484128879, -0.7858454494321153, -0.7820588657886692, -0.7784340659623826, -0.7751081600459214, -0.7722056562924369, -0.7698338401157234, -0.7680788456111451, -0.7670025621367056, -0.7666404858374718, -0.7670005894780371, -0.7680632449667192, -0.769782192992454, -0.7720865147569331, -0.7748835233458969, -0.7780624582335912, -0.7814988370056846, -0.7850592946875878, -0.7886067239251384, -0.792005519279034, -0.7951267263875327, -0.797852901765222, -0.8000825012988568, -0.801733634562711, -0.8027470471436821, -0.8030882232584983, -0.802748534897619, -0.8017454002414531, -0.8001214517750443, -0.7979427519529028, -0.79529613003019, -0.7922857464444422, -0.7890290196887436, -0.7856520739129221, -0.7822848826781281, -0.779056294759812, -0.7760891312891214, -0.7734955397542284, -0.7713727796327967, -0.7697995971372322, -0.768833323411664, -0.7685078024263993, -0.7688322228559976, -0.769790893624977, -0.7713439668779053, -0.7734290762389768, -0.7759638237227999, -0.7788490168376249, -0.7819725294714883, -0.7852136371076035, -0.7884476596242259, -0.7915507340234257, -0.794404535286382, -0.7969007662974972, -0.7989452472837779, -0.8004614510928043, -0.8013933522576362, -0.8017074843312179, -0.8013941303956937, -0.8004676048003693, -0.7989656187940757, -0.7969477574814646, -0.7944931291348223, -0.7916972790682908, -0.7886684878610863, -0.7855235966701103, -0.7823835198485583, -0.7793686164426282, -0.776594096963172, -0.7741656399605497, -0.7721753844590762, -0.770698449571725, -0.7697901121901471, -0.7694837483173037, -0.7697896143503624, -0.7706945125459511, -0.7721623512283711, -0.7741355763105532, -0.7765374176735674, -0.7792748631398769, -0.7822422453386167, -0.7853253037285347, -0.7884055662777875, -0.7913648834616089, -0.7940899417651489, -0.7964765849652131, -0.7984337790587648, -0.7998870705094204, -0.8007814069639708, -0.8010832140029743, -0.8007816499023247, -0.7998889917151156, -0.7984401390459334, -0.796491255429219, -0.794117599969838, -0.7914106326183719, -0.7884745039759798, -0.7854220638145624, -0.7823705406796343, -0.7794370573315942, -0.776734152910703, -0.774365482354725, -0.7724218567723669, -0.7709777754426278, -0.7700885813659365, -0.769788348549357, -0.7700885813659364, -0.770977775442628, -0.7724218567723667, -0.7743654823547246, -0.7767341529107019, -0.7794370573315946, -0.7823705406796339, -0.785422063814558, -0.7884745039759791, -0.7914106326183717, -0.7941175999698378, -0.796491255429218, -0.798440139045933, -0.799888991715117, -0.8007816499023246, -0.801083214002974, -0.8007814069639723, -0.7998870705094207, -0.7984337790587643, -0.7964765849652146, -0.7940899417651496, -0.7913648834616099, -0.7884055662777878, -0.7853253037285354, -0.7822422453386154, -0.7792748631398779, -0.7765374176735699, -0.7741355763105512, -0.7721623512283702, -0.7706945125459528, -0.7697896143503626, -0.7694837483173032, -0.7697901121901444, -0.7706984495717256, -0.7721753844590754, -0.7741656399605482, -0.7765940969631726, -0.7793686164426261, -0.7823835198485601, -0.785523596670109, -0.7886684878610856, -0.7916972790682917, -0.7944931291348214, -0.7969477574814663, -0.7989656187940749, -0.8004676048003707, -0.8013941303956931, -0.801707484331217, -0.8013933522576376, -0.8004614510928034, -0.7989452472837775, -0.7969007662974975, -0.7944045352863822, -0.7915507340234259, -0.7884476596242248, -0.7852136371076046, -0.781972529471487, -0.7788490168376244, -0.775963823722802, -0.7734290762389764, -0.7713439668779055, -0.7697908936249768, -0.7688322228559985, -0.7685078024263997, -0.7688333234116632, -0.7697995971372329, -0.7713727796327963, -0.7734955397542285, -0.7760891312891215, -0.7790562947598105, -0.7822848826781271, -0.7856520739129219, -0.7890290196887421, -0.7922857464444418, -0.7952961300301897, -0.7979427519529023, -0.800121451775045, -0.8017454002414542, -0.8027485348976185, -0.8030882232584982, -0.8027470471436827, -0.801733634562711, -0.800082501298856, -0.7978529017652226, -0.7951267263875336, -0.7920055192790352, -0.7886067239251396, -0.7850592946875891, -0.7814988370056841, -0.778062458233591, -0.774883523345896, -0.7720865147569337, -0.7697821929924523, -0.7680632449667195, -0.7670005894780354, -0.7666404858374734, -0.7670025621367053, -0.7680788456111438, -0.7698338401157244, -0.7722056562924371, -0.7751081600459231, -0.7784340659623816, -0.7820588657886682, -0.7858454494321147, -0.7896492484128872, -0.7933237103814438, -0.7967258990612485, -0.7997220073892901, -0.8021925730183979, -0.8040371947328122, -0.8051785654273225, -0.805565661534061, -0.8051759592989204, -0.8040165840176535, -0.8021243379400964, -0.7995645945826847, -0.796429090091897, -0.7928326844650676, -0.7889092052634352, -0.7848065224210061, -0.7806810334682689, -0.7766917627418326, -0.7729942949607966, -0.76973477220876, -0.7670441834562028, -0.7650331671985935, -0.7637875308012106, -0.7633646652814248, -0.7637910023731154, -0.7650606229284329, -0.7671350833749565, -0.7699444816025639, -0.7733897369739791, -0.7773460128753393, -0.7816671660912072, -0.7861910669851123, -0.7907455995507368, -0.795155122245269, -0.7992471502640472, -0.8028590084207593, -0.8058442015998901, -0.808078257046739, -0.8094638094019195, -0.809934724867924, -0.8094590943754733, -0.8080409659480171, -0.8057207322255321, -0.8025741386641474, -0.798709929474529, -0.7942661999996696, -0.7894055740338036, -0.7843093706627681, -0.7791709657898013, -0.7741885870063483, -0.7695578055236852, -0.7654640044406017, -0.7620751079652376, -0.7595348509842051, -0.7579568526142788, -0.7574197315071147, -0.7579634655114125, -0.7595871549914558, -0.7622482991361973, -0.7658636397169647, -0.7703115689208263, -0.775436039195746, -0.7810518556522987, -0.7869511776204291, -0.7929110075111677, -0.7987014040656037, -0.8040941250296095, -0.8088713826106633, -0.8128343847332316, -0.815811336692194, -0.8176645914643375, -0.8182966623911716, -0.8176548484722518, -0.8157342689700604, -0.8125791589071121, -0.8082823384818999, -0.8029828353197611, -0.796861706486304, -0.7901361748839039, -0.7830522595537047, -0.7758761390995914, -0.7688845396641358, -0.7623544815748415, -0.7565527502028287, -0.7517254753685807, -0.7480882088521209, -0.7458168807481168, -0.7450399925742833, -0.7458323687193326, -0.7482107390218249, -0.7521313654845306, -0.757489857250745, -0.7641232422596353, -0.7718142840034191, -0.7802979502775054, -0.7892698606017327, -0.7983964629618926, -0.8073266214512477, -0.8157042368672427, -0.8231814746426124, -0.8294321405969448, -0.8341647263780437, -0.8371346441134251, -0.8381551841586896, -0.8371067607886968, -0.8339440575331408, -0.8287007453521074, -0.8214915211952826, -0.8125112994309847, -0.8020314815038564, -0.7903933269967522, -0.7779985488232326, -0.7652973532323907, -0.7527742383302883, -0.740931949679427, -0.7302740652124927, -0.7212867414932874, -0.7144201969962177, -0.7100705337762667, -0.708562505449303, -0.7101338261999536, -0.7149215826257748, -0.7229512583098547, -0.7341288114234439, -0.748236160332887, -0.7649303336033417, -0.7837464319094086, -0.8041044335128618, -0.8253197557584697, -0.8466173662374044, -0.8671491226797764, -0.8860139139818789, -0.9022800795609485, -0.9150095036454537, -0.9232827179052793, -0.9262243022310479, -0.923027851108881, -0.9129797728693476, -0.8954812113819617, -0.8700674240719595, -0.8364240153039412, -0.7943995083844921, -0.7440138402446048, -0.6854624772888621, -0.619115975509604, -0.5455149389841836, -0.46536046430147604, -0.3795002902036362, -0.28891099771659534, -0.19467672237912642, -0.09796494325296731, ], [0.0, 0.04906121103933364, 0.09796555692866045, 0.14655707065103, 0.1946815753656885, 0.24218756431611788, 0.2889270626469073, 0.334756465306212, 0.3795373453860567, 0.4231372274695771, 0.46543032081079727, 0.5062982074667427, 0.5456304808313343, 0.5833253303830638, 0.6192900688511493, 0.653441598424685, 0.6857068130730382, 0.7160229345100182, 0.7443377798155734, 0.7706099592233455, 0.7948090030865028, 0.8169154175441099, 0.8369206689219527, 0.8548270974113943, 0.8706477610736022, 0.8844062117106022, 0.8961362046253297, 0.9058813447566033, 0.91369467211825, 0.9196381898912012, 0.9237823389101407, 0.9262054226493415, 0.9269929871430499, 0.926237160571763, 0.9240359575048971, 0.9204925530108712, 0.9157145320259743, 0.9098131195124198, 0.9029023970327964, 0.8950985114222025, 0.8865188812504959, 0.8772814067354164, 0.8675036886933519, 0.857302261998981, 0.8467918488690649, 0.8360846370907223, 0.8252895880822865, 0.8145117794073651, 0.8038517860622478, 0.7934051045258541, 0.7832616232027432, 0.7735051425062625, 0.7642129474238019, 0.7554554349826795, 0.7472957985967339, 0.7397897708238544, 0.7329854256069116, 0.7269230406085656, 0.7216350197878034, 0.7171458759064319, 0.7134722722006983, 0.7106231220102145, 0.7085997447268364, 0.7073960760133451, 0.7069989298488403, 0.7073883095875859, 0.7085377648734296, 0.7104147909353283, 0.7129812665032689, 0.7161939263299587, 0.7200048640838724, 0.7243620611949947, 0.7292099370871168, 0.7344899161206521, 0.7401410064982242, 0.746100386351998, 0.7523039922367876, 0.7586871052960562, 0.7651849304483159, 0.7717331640581871, 0.7782685457082437, 0.7847293898731988, 0.7910560935152064, 0.7971916158660274, 0.8030819269362611, 0.8086764215913395, 0.8139282963558091, 0.8187948864488586, 0.8232379609120258, 0.8272239740615761, 0.8307242718799406, 0.8337152523496872, 0.8361784791264787, 0.8381007483411049, 0.8394741087117426, 0.8402958355328548, 0.8405683594835338, 0.8402991515625414, 0.8395005658069399, 0.8381896417832628, 0.8363878691520648, 0.8341209168960153, 0.8314183300662448, 0.828313197139469, 0.8248417912877536, 0.8210431890421408, 0.8169588699795516, 0.8126323011784085, 0.8081085102716353, 0.8034336509756846, 0.7986545649908898, 0.7938183441519088, 0.7889718966577569, 0.7841615211296197, 0.7794324921322932, 0.7748286606529001, 0.7703920728600113, 0.7661626102690899, 0.7621776542182493, 0.758471777313719, 0.7550764642394825, 0.7520198640426785, 0.7493265757081344, 0.7470174685245168, 0.7451095384237745, 0.7436158011476988, 0.7425452227633281, 0.7419026877155676, 0.7416890042735487, 0.7419009468998422, 0.7425313347513885, 0.7435691452106622, 0.744999661047711, 0.7468046495307893, 0.748962571537648, 0.7514488184733594, 0.7542359745757218, 0.7572941019877125, 0.7605910457995848, 0.7640927561124639, 0.7677636240517413, 0.7715668285630951, 0.7754646907571638, 0.779419032531158, 0.7833915361871097, 0.7873441017868743, 0.7912391990330527, 0.7950402105420054, 0.7987117634792609, 0.8022200466577051, 0.8055331103537136, 0.8086211462742531, 0.8114567453072715, 0.8140151309064595, 0.8162743661976698, 0.818215533145729, 0.819822882384712, 0.8210839525896143, 0.8219896585501886, 0.8225343473960232, 0.8227158227130882, 0.8225353365834295, 0.8219975498688039, 0.8211104613433428, 0.8198853065572654, 0.8183364275808548, 0.8164811150330555, 0.8143394240399316, 0.8119339659928148, 0.8092896781823705, 0.8064335735712683, 0.8033944731331398, 0.8002027233277018, 0.7968899014001534, 0.7934885112863292, 0.790031672972905, 0.7865528082037329, 0.7830853254389284, 0.7796623069625854, 0.7763162009982758, 0.7730785216291829, 0.7699795592325454, 0.7670481040269774, 0.7643111851972498, 0.7617938279056109, 0.7595188303231751, 0.7575065626210099, 0.7557747896500755, 0.7543385188141467, 0.7532098744023026, 0.7523979993997416, 0.7519089855397897, 0.7517458320983864, 0.751908433667427, 0.7523935968774725, 0.7531950857759574, 0.7543036953064272, 0.7557073520799465, 0.7573912413837983, 0.7593379591371856, 0.7615276872809226, 0.7639383908800014, 0.7665460350262506, 0.7693248194547944, 0.7722474286340617, 0.775285294956129, 0.7784088725432385, 0.7815879190983618, 0.7847917831634347, 0.7879896941088401, 0.791151052162102, 0.7942457157927557, 0.7972442838036995, 0.8001183695366858, 0.8028408646804196, 0.8053861902731283, 0.807730532616496, 0.8098520619633444, 0.8117311320059516, 0.8133504583739591, 0.8146952745487246, 0.8157534638128215, 0.8165156660773687, 0.8169753586637745, 0.8171289103582582, 0.8169756083049557, 0.8165176575542201, 0.8157601533346517, 0.8147110263681677, 0.8133809617947217, 0.811783292514917, 0.8099338679925698, 0.8078508997830584, 0.8055547852651839, 0.8030679112522263, 0.8004144393402021, 0.7976200750164167, 0.7947118226976885, 0.7917177289938959, 0.7886666165976093, 0.7855878112835774, 0.7825108645620138, 0.7794652745665386, 0.7764802077707954, 0.7735842241172963, 0.7708050081078806, 0.7681691083477625, 0.7657016879549862, 0.7634262881449424, 0.7613646071763751, 0.7595362967021987, 0.757958777406686, 0.7566470756317368, 0.7556136825005683, 0.754868436839106, 0.7544184329754343, 0.7542679542679545, 0.7544184329754343, 0.754868436839106, 0.7556136825005684, 0.7566470756317367, 0.757958777406686, 0.7595362967021986, 0.7613646071763751, 0.7634262881449424, 0.7657016879549863, 0.7681691083477624, 0.7708050081078811, 0.7735842241172964, 0.7764802077707953, 0.7794652745665387, 0.7825108645620136, 0.7855878112835775, 0.7886666165976093, 0.7917177289938959, 0.7947118226976887, 0.7976200750164169, 0.8004144393402022, 0.8030679112522255, 0.8055547852651836, 0.807850899783058, 0.8099338679925698, 0.8117832925149169, 0.8133809617947217, 0.8147110263681678, 0.8157601533346518, 0.81651765755422, 0.816975608304956, 0.8171289103582584, 0.8169753586637748, 0.8165156660773687, 0.8157534638128214, 0.8146952745487248, 0.8133504583739588, 0.8117311320059515, 0.8098520619633445, 0.8077305326164961, 0.805386190273128, 0.8028408646804195, 0.8001183695366856, 0.7972442838036997, 0.7942457157927556, 0.7911510521621021, 0.7879896941088403, 0.7847917831634346, 0.7815879190983617, 0.7784088725432389, 0.7752852949561292, 0.7722474286340613, 0.7693248194547944, 0.7665460350262507, 0.7639383908800007, 0.7615276872809227, 0.7593379591371858, 0.7573912413837984, 0.7557073520799467, 0.7543036953064273, 0.7531950857759577, 0.752393596877473, 0.7519084336674268, 0.751745832098386, 0.7519089855397897, 0.7523979993997418, 0.7532098744023026, 0.7543385188141468, 0.7557747896500755, 0.7575065626210099, 0.7595188303231752, 0.7617938279056108, 0.7643111851972498, 0.7670481040269769, 0.769979559232545, 0.7730785216291831, 0.7763162009982757, 0.7796623069625851, 0.7830853254389285, 0.7865528082037329, 0.7900316729729044, 0.7934885112863297, 0.796889901400153, 0.8002027233277019, 0.8033944731331394, 0.8064335735712682, 0.8092896781823704, 0.8119339659928144, 0.8143394240399315, 0.8164811150330555, 0.8183364275808548, 0.8198853065572653, 0.8211104613433432, 0.8219975498688037, 0.8225353365834294, 0.8227158227130883, 0.8225343473960233, 0.8219896585501887, 0.8210839525896142, 0.8198228823847116, 0.818215533145729, 0.8162743661976698, 0.8140151309064598, 0.8114567453072719, 0.8086211462742532, 0.8055331103537136, 0.802220046657705, 0.7987117634792611, 0.7950402105420055, 0.7912391990330528, 0.7873441017868743, 0.7833915361871098, 0.779419032531158, 0.7754646907571638, 0.7715668285630957, 0.7677636240517414, 0.764092756112464, 0.7605910457995847, 0.7572941019877123, 0.7542359745757219, 0.7514488184733592, 0.7489625715376481, 0.7468046495307892, 0.7449996610477116, 0.7435691452106623, 0.7425313347513887, 0.7419009468998424, 0.7416890042735484, 0.7419026877155674, 0.7425452227633281, 0.7436158011476989, 0.7451095384237745, 0.7470174685245163, 0.7493265757081345, 0.7520198640426785, 0.7550764642394825, 0.7584717773137187, 0.7621776542182492, 0.7661626102690897, 0.7703920728600114, 0.7748286606529001, 0.7794324921322933, 0.7841615211296196, 0.7889718966577568, 0.7938183441519087, 0.7986545649908897, 0.8034336509756843, 0.8081085102716354, 0.8126323011784082, 0.8169588699795512, 0.8210431890421406, 0.8248417912877535, 0.8283131971394693, 0.8314183300662442, 0.8341209168960155, 0.8363878691520646, 0.8381896417832628, 0.8395005658069404, 0.840299151562541, 0.8405683594835338, 0.8402958355328545, 0.8394741087117427, 0.838100748341105, 0.8361784791264787, 0.8337152523496876, 0.8307242718799407, 0.8272239740615761, 0.8232379609120257, 0.8187948864488591, 0.8139282963558092, 0.8086764215913393, 0.8030819269362611, 0.7971916158660274, 0.7910560935152068, 0.7847293898731986, 0.7782685457082439, 0.7717331640581876, 0.7651849304483166, 0.7586871052960565, 0.7523039922367879, 0.7461003863519977, 0.7401410064982241, 0.7344899161206521, 0.7292099370871165, 0.7243620611949946, 0.7200048640838727, 0.7161939263299592, 0.7129812665032691, 0.7104147909353282, 0.7085377648734295, 0.7073883095875861, 0.70699892984884, 0.707396076013345, 0.7085997447268363, 0.7106231220102148, 0.7134722722006985, 0.7171458759064322, 0.7216350197878032, 0.7269230406085653, 0.732985425606911, 0.7397897708238543, 0.7472957985967335, 0.7554554349826794, 0.7642129474238022, 0.7735051425062618, 0.7832616232027434, 0.7934051045258541, 0.8038517860622478, 0.814511779407365, 0.8252895880822865, 0.8360846370907217, 0.8467918488690647, 0.8573022619989802, 0.8675036886933518, 0.8772814067354164, 0.8865188812504958, 0.8950985114222021, 0.9029023970327961, 0.9098131195124203, 0.9157145320259741, 0.9204925530108707, 0.9240359575048963, 0.9262371605717629, 0.9269929871430505, 0.9262054226493416, 0.9237823389101409, 0.919638189891201, 0.9136946721182501, 0.9058813447566042, 0.8961362046253297, 0.8844062117106027, 0.8706477610736023, 0.8548270974113952, 0.8369206689219532, 0.8169154175441097, 0.7948090030865035, 0.7706099592233454, 0.7443377798155741, 0.7160229345100181, 0.6857068130730396, 0.6534415984246853, 0.6192900688511511, 0.5833253303830642, 0.5456304808313364, 0.5062982074667436, 0.4654303208107964, 0.42313722746957805, 0.3795373453860562, 0.3347564653062135, 0.2889270626469069, 0.24218756431611935, 0.19468157536568895, 0.14655707065103202, 0.09796555692866094, 0.04906121103933581, 1.2662533211045895e-15, -0.049061211039333856, -0.09796555692865898, -0.14655707065103007, -0.19468157536568706, -0.2421875643161175, -0.2889270626469051, -0.33475646530621167, -0.3795373453860544, -0.4231372274695764, -0.46543032081079516, -0.506298207466742, -0.5456304808313346, -0.5833253303830628, -0.6192900688511493, -0.653441598424684, -0.6857068130730387, -0.7160229345100165, -0.7443377798155731, -0.7706099592233442, -0.7948090030865025, -0.816915417544109, -0.8369206689219523, -0.8548270974113942, -0.8706477610736018, -0.8844062117106024, -0.8961362046253296, -0.9058813447566039, -0.9136946721182494, -0.9196381898912008, -0.9237823389101408, -0.9262054226493414, -0.9269929871430505, -0.926237160571763, -0.9240359575048963, -0.920492553010871, -0.9157145320259741, -0.9098131195124206, -0.9029023970327964, -0.8950985114222024, -0.8865188812504958, -0.8772814067354168, -0.8675036886933518, -0.857302261998981, -0.8467918488690652, -0.8360846370907223, -0.8252895880822871, -0.814511779407365, -0.8038517860622485, -0.7934051045258546, -0.7832616232027441, -0.7735051425062622, -0.7642129474238024, -0.7554554349826798, -0.7472957985967339, -0.7397897708238546, -0.7329854256069115, -0.7269230406085656, -0.7216350197878033, -0.717145875906432, -0.7134722722006984, -0.7106231220102149, -0.708599744726836, -0.7073960760133452, -0.70699892984884, -0.7073883095875866, -0.7085377648734295, -0.7104147909353284, -0.7129812665032692, -0.7161939263299586, -0.7200048640838725, -0.724362061194994, -0.7292099370871167, -0.7344899161206523, -0.7401410064982237, -0.7461003863519974, -0.7523039922367873, -0.7586871052960561, -0.7651849304483167, -0.7717331640581875, -0.778268545708244, -0.7847293898731984, -0.7910560935152062, -0.7971916158660273, -0.8030819269362612, -0.8086764215913393, -0.8139282963558092, -0.8187948864488588, -0.8232379609120256, -0.8272239740615761, -0.8307242718799402, -0.8337152523496872, -0.8361784791264784, -0.8381007483411045, -0.8394741087117424, -0.8402958355328541, -0.8405683594835335, -0.8402991515625412, -0.8395005658069407, -0.838189641783263, -0.8363878691520652, -0.8341209168960153, -0.8314183300662448, -0.8283131971394695, -0.8248417912877537, -0.8210431890421411, -0.8169588699795519, -0.8126323011784081, -0.8081085102716354, -0.8034336509756842, -0.7986545649908897, -0.793818344151909, -0.7889718966577569, -0.7841615211296196, -0.779432492132293, -0.7748286606528999, -0.7703920728600114, -0.7661626102690897, -0.7621776542182488, -0.7584717773137196, -0.7550764642394829, -0.7520198640426787, -0.7493265757081349, -0.7470174685245159, -0.7451095384237747, -0.743615801147699, -0.7425452227633278, -0.7419026877155677, -0.7416890042735487, -0.7419009468998425, -0.7425313347513883, -0.7435691452106622, -0.7449996610477114, -0.7468046495307895, -0.7489625715376482, -0.7514488184733591, -0.7542359745757219, -0.7572941019877122, -0.7605910457995849, -0.764092756112464, -0.7677636240517408, -0.7715668285630958, -0.7754646907571636, -0.7794190325311579, -0.7833915361871095, -0.7873441017868743, -0.7912391990330528, -0.7950402105420049, -0.7987117634792603, -0.8022200466577055, -0.8055331103537132, -0.808621146274253, -0.8114567453072716, -0.8140151309064596, -0.8162743661976698, -0.8182155331457285, -0.8198228823847125, -0.821083952589614, -0.8219896585501887, -0.8225343473960234, -0.8227158227130882, -0.8225353365834299, -0.821997549868804, -0.8211104613433429, -0.8198853065572648, -0.8183364275808547, -0.8164811150330562, -0.8143394240399318, -0.8119339659928145, -0.8092896781823703, -0.8064335735712687, -0.80339447313314, -0.8002027233277021, -0.7968899014001536, -0.7934885112863291, -0.7900316729729043, -0.7865528082037326, -0.7830853254389293, -0.7796623069625855, -0.7763162009982759, -0.7730785216291827, -0.769979559232546, -0.7670481040269771, -0.7643111851972506, -0.761793827905611, -0.7595188303231751, -0.7575065626210095, -0.7557747896500758, -0.7543385188141474, -0.7532098744023032, -0.7523979993997414, -0.7519089855397896, -0.7517458320983864, -0.751908433667426, -0.752393596877474, -0.7531950857759576, -0.7543036953064273, -0.7557073520799453, -0.757391241383799, -0.7593379591371862, -0.7615276872809229, -0.7639383908800016, -0.7665460350262495, -0.7693248194547939, -0.7722474286340618, -0.7752852949561295, -0.7784088725432392, -0.7815879190983607, -0.7847917831634346, -0.78798969410884, -0.7911510521621014, -0.7942457157927567, -0.7972442838036989, -0.800118369536685, -0.802840864680419, -0.8053861902731279, -0.8077305326164963, -0.8098520619633436, -0.8117311320059524, -0.8133504583739591, -0.8146952745487253, -0.8157534638128215, -0.8165156660773688, -0.8169753586637756, -0.817128910358258, -0.8169756083049557, -0.81651765755422, -0.8157601533346517, -0.8147110263681686, -0.8133809617947205, -0.8117832925149174, -0.8099338679925705, -0.8078508997830582, -0.8055547852651838, -0.8030679112522255, -0.8004144393402021, -0.7976200750164169, -0.7947118226976901, -0.7917177289938949, -0.7886666165976086, -0.7855878112835782, -0.7825108645620149, -0.7794652745665375, -0.7764802077707956, -0.7735842241172967, -0.7708050081078812, -0.7681691083477626, -0.7657016879549864, -0.7634262881449426, -0.7613646071763747, -0.759536296702199, -0.757958777406686, -0.7566470756317367, -0.7556136825005685, -0.7548684368391062, -0.7544184329754343, -0.7542679542679545, -0.754418432975434, -0.754868436839106, -0.7556136825005687, -0.7566470756317367, -0.7579587774066862, -0.7595362967021986, -0.7613646071763748, -0.7634262881449412, -0.7657016879549864, -0.7681691083477626, -0.7708050081078801, -0.7735842241172958, -0.7764802077707955, -0.7794652745665387, -0.7825108645620141, -0.7855878112835766, -0.7886666165976102, -0.791717728993895, -0.7947118226976889, -0.7976200750164173, -0.800414439340202, -0.8030679112522263, -0.8055547852651838, -0.8078508997830581, -0.8099338679925684, -0.8117832925149177, -0.8133809617947225, -0.8147110263681666, -0.8157601533346512, -0.8165176575542213, -0.8169756083049559, -0.8171289103582585, -0.8169753586637738, -0.8165156660773691, -0.8157534638128207, -0.8146952745487248, -0.81335045837396, -0.8117311320059508, -0.8098520619633456, -0.8077305326164954, -0.8053861902731282, -0.8028408646804204, -0.8001183695366849, -0.7972442838037006, -0.7942457157927562, -0.7911510521621025, -0.7879896941088405, -0.784791783163434, -0.7815879190983626, -0.778408872543238, -0.7752852949561297, -0.7722474286340616, -0.7693248194547941, -0.7665460350262497, -0.7639383908800004, -0.7615276872809233, -0.7593379591371852, -0.7573912413837983, -0.7557073520799468, -0.7543036953064268, -0.7531950857759583, -0.7523935968774736, -0.7519084336674277, -0.7517458320983857, -0.7519089855397889, -0.7523979993997418, -0.7532098744023025, -0.7543385188141473, -0.7557747896500764, -0.7575065626210093, -0.7595188303231752, -0.7617938279056108, -0.7643111851972502, -0.7670481040269762, -0.7699795592325455, -0.7730785216291824, -0.7763162009982761, -0.7796623069625854, -0.7830853254389291, -0.7865528082037324, -0.790031672972904, -0.7934885112863292, -0.796889901400153, -0.8002027233277027, -0.8033944731331396, -0.8064335735712689, -0.8092896781823704, -0.8119339659928142, -0.8143394240399316, -0.8164811150330558, -0.8183364275808542, -0.8198853065572652, -0.8211104613433423, -0.8219975498688044, -0.8225353365834296, -0.8227158227130886, -0.8225343473960224, -0.8219896585501882, -0.8210839525896149, -0.8198228823847123, -0.8182155331457299, -0.8162743661976699, -0.8140151309064588, -0.8114567453072716, -0.8086211462742531, -0.8055331103537141, -0.8022200466577053, -0.7987117634792605, -0.7950402105420057, -0.7912391990330535, -0.7873441017868754, -0.7833915361871092, -0.7794190325311582, -0.7754646907571638, -0.7715668285630959, -0.7677636240517414, -0.7640927561124642, -0.7605910457995848, -0.7572941019877123, -0.7542359745757216, -0.751448818473359, -0.7489625715376483, -0.7468046495307901, -0.7449996610477108, -0.7435691452106614, -0.7425313347513882, -0.7419009468998428, -0.7416890042735486, -0.7419026877155672, -0.7425452227633285, -0.7436158011476989, -0.7451095384237749, -0.7470174685245169, -0.7493265757081344, -0.7520198640426786, -0.7550764642394824, -0.7584717773137187, -0.7621776542182493, -0.7661626102690902, -0.7703920728600108, -0.7748286606529, -0.7794324921322929, -0.7841615211296189, -0.7889718966577559, -0.7938183441519087, -0.7986545649908897, -0.8034336509756845, -0.8081085102716351, -0.8126323011784088, -0.8169588699795509, -0.8210431890421401, -0.824841791287754, -0.8283131971394685, -0.8314183300662439, -0.8341209168960148, -0.8363878691520651, -0.8381896417832624, -0.8395005658069402, -0.8402991515625413, -0.8405683594835337, -0.8402958355328547, -0.8394741087117431, -0.8381007483411048, -0.836178479126479, -0.8337152523496879, -0.8307242718799408, -0.8272239740615749, -0.8232379609120256, -0.8187948864488599, -0.8139282963558104, -0.8086764215913392, -0.8030819269362609, -0.7971916158660277, -0.791056093515206, -0.7847293898731984, -0.7782685457082434, -0.771733164058187, -0.7651849304483168, -0.7586871052960568, -0.7523039922367883, -0.7461003863519989, -0.7401410064982241, -0.7344899161206516, -0.7292099370871168, -0.7243620611949946, -0.720004864083873, -0.7161939263299598, -0.7129812665032697, -0.7104147909353277, -0.7085377648734293, -0.7073883095875866, -0.7069989298488398, -0.7073960760133443, -0.7085997447268362, -0.7106231220102146, -0.7134722722006984, -0.717145875906432, -0.721635019787803, -0.7269230406085644, -0.7329854256069118, -0.7397897708238541, -0.7472957985967339, -0.7554554349826794, -0.7642129474238025, -0.7735051425062611, -0.7832616232027425, -0.7934051045258541, -0.8038517860622483, -0.8145117794073649, -0.8252895880822859, -0.8360846370907227, -0.8467918488690649, -0.8573022619989799, -0.8675036886933517, -0.8772814067354155, -0.8865188812504952, -0.8950985114222019, -0.9029023970327963, -0.9098131195124209, -0.9157145320259736, -0.9204925530108711, -0.9240359575048959, -0.9262371605717629, -0.9269929871430497, -0.9262054226493416, -0.9237823389101416, -0.9196381898912017, -0.9136946721182506, -0.9058813447566034, -0.89613620462533, -0.8844062117106032, -0.8706477610736031, -0.8548270974113944, -0.8369206689219535, -0.8169154175441113, -0.7948090030865025, -0.7706099592233455, -0.7443377798155747, -0.716022934510021, -0.6857068130730375, -0.6534415984246862, -0.6192900688511513, -0.5833253303830678, -0.5456304808313336, -0.5062982074667447, -0.46543032081080005, -0.42313722746957627, -0.37953734538605755, -0.33475646530621506, -0.2889270626469112, -0.24218756431611727, -0.19468157536568942, -0.14655707065103318, -0.09796555692866477, -0.049061211039333245, ], [0.0, 0.02454045862474162, 0.04906151876629648, 0.07354380895900323, 0.097968011728402, 0.12231489047728737, 0.14656531624051677, 0.17070029426517924, 0.19470099037303235, 0.21854875706249138, 0.24222515930790647, 0.2657120000143857, 0.2889913450870194, 0.31204554807402657, 0.3348572743440836, 0.3574095247588971, 0.37968565880295885, 0.4016694171333566, 0.4233449435135177, 0.44469680609582746, 0.4657100180191838, 0.4863700572887409, 0.5066628859063235, 0.526574968221294, 0.5460932884729935, 0.5652053674972745, 0.5838992785710796, 0.6021636623705098, 0.619987741019346, 0.6373613312065558, 0.6542748563529166, 0.67071935780852, 0.6866865050645852, 0.7021686049647047, 0.717158609902352, 0.7316501249932303, 0.7456374142127824, 0.7591154054909643, 0.7720796947581583, 0.7845265489378933, 0.7964529078838429, 0.8078563852603584, 0.8187352683676035, 0.8290885169141418, 0.8389157607416222, 0.8482172965079758, 0.8569940833373099, 0.8652477374464203, 0.8729805257595791, 0.880195358524954, 0.8868957809476931, 0.8930859638563617, 0.8987706934210326, 0.9039553599429164, 0.9086459457369663, 0.9128490121303952, 0.9165716856015077, 0.9198216430846743, 0.9226070964686374, 0.9249367763166676, 0.9268199148383569, 0.9282662281440469, 0.929285897814055, 0.9298895518159553, 0.9300882448042174, 0.9298934378374776, 0.9293169775496345, 0.9283710748118129, 0.9270682829230116, 0.9254214753679765, 0.9234438231814781, 0.9211487719587456, 0.9185500185523189, 0.915661487496005, 0.912497307196987, 0.9090717859374176, 0.9053993877270401, 0.9014947080485193, 0.8973724495372261, 0.8930473976372105, 0.8885343962750121, 0.8838483235927997, 0.8790040677821025, 0.8740165030590844, 0.8689004658219467, 0.863670731030589, 0.8583419888481423, 0.8529288215834064, 0.8474456809725596, 0.8419068658377934, 0.836326500159738, 0.8307185115996852, 0.8250966105067121, 0.8194742694438254, 0.8138647032662218, 0.8082808497836637, 0.8027353510378269, 0.7972405352242862, 0.7918083992875479, 0.7864505922162527, 0.7811783990643257, 0.7760027257224742, 0.7709340844630016, 0.7659825802794549, 0.761157898041117, 0.7564692904808358, 0.7519255670331173, 0.7475350835378307, 0.743305732823264, 0.7392449361806388, 0.7353596357405494, 0.73165628776013, 0.7281408568280814, 0.7248188109930078, 0.7216951178188311, 0.7187742413693563, 0.7160601401223802, 0.71355626581205, 0.7112655631964977, 0.7091904707461176, 0.7073329222461923, 0.7056943493059409, 0.7042756847644399, 0.7030773669822762, 0.7020993450062101, 0.7013410845925953, 0.7008015750737725, 0.7004793370501883, 0.7003724308895328, 0.7004784660127918, 0.7007946109457361, 0.701317604113053, 0.7020437653510455, 0.7029690081135964, 0.7040888523449101, 0.7053984379914315, 0.7068925391242469, 0.7085655786422782, 0.7104116435256023, 0.7124245006073427, 0.71459761283173, 0.7169241559651588, 0.7193970357263535, 0.722008905301103, 0.7247521832064526, 0.7276190714687105, 0.7306015740791982, 0.7336915156912731, 0.7368805605218626, 0.7401602314204911, 0.743521929068624, 0.7469569512720389, 0.7504565123089117, 0.7540117622963454, 0.7576138065381651, 0.7612537248170015, 0.7649225905939055, 0.7686114900790788, 0.7723115411376629, 0.7760139119949985, 0.7797098397062661, 0.7833906483560107, 0.787047766953686, 0.7906727469920659, 0.7942572796361338, 0.7977932125108843, 0.8012725660573581, 0.8046875494271608, 0.8080305758867197, 0.8112942777035644, 0.8144715204880213, 0.8175554169648435, 0.8205393401504919, 0.8234169359130105, 0.8261821348927061, 0.8288291637631564, 0.8313525558134124, 0.8337471608336401, 0.8360081542878477, 0.8381310457587952, 0.8401116866516266, 0.8419462771442602, 0.843631372374068, 0.8451638878519001, 0.8465411040960361, 0.847760670480191, 0.8488206082912544, 0.8497193129939984, 0.8504555557015427, 0.8510284838519226, 0.8514376210926636, 0.8516828663768017, 0.8517644922753274, 0.8516831425125571, 0.8514398287324344, 0.8510359265052595, 0.8504731705858011, 0.8497536494351957, 0.8488797990204545, 0.847854395906773, 0.846680549659202, 0.8453616945715597, 0.8439015807417302, 0.8423042645137547, 0.8405740983083099, 0.8387157198643239, 0.8367340409156017, 0.834634235327375, 0.832421726718715, 0.8301021755977096, 0.8276814660371926, 0.8251656919196818, 0.8225611427809666, 0.8198742892825129, 0.8171117683435427, 0.8142803679642407, 0.811387011772101, 0.8084387433239149, 0.8054427101963135, 0.8024061478981447, 0.7993363636382601, 0.7962407199824988, 0.7931266184338277, 0.7900014829696964, 0.7868727435706651, 0.783747819774356, 0.7806341042886523, 0.7775389466978948, 0.7744696372956039, 0.77143339107693, 0.7684373319236738, 0.7654884770142988, 0.7625937214908316, 0.759759823414015, 0.7569933890374503, 0.7543008584307737, 0.7516884914811931, 0.7491623543019071, 0.746728306075071, 0.7443919863560865, 0.7421588028650264, 0.7400339197899916, 0.7380222466261661, 0.7361284275732097, 0.7343568315125029, 0.7327115425845666, 0.7311963513857461, 0.7298147468020018, 0.7285699084963418, 0.7274647000651007, 0.7265016628769239, 0.7256830106069153, 0.7250106244770103, 0.7244860492121952, 0.7241104897207562, 0.7238848085052626, 0.7238095238095239, 0.7238848085052626, 0.7241104897207562, 0.7244860492121952, 0.7250106244770103, 0.7256830106069152, 0.7265016628769239, 0.7274647000651007, 0.7285699084963417, 0.7298147468020019, 0.731196351385746, 0.7327115425845666, 0.734356831512503, 0.7361284275732097, 0.7380222466261662, 0.7400339197899914, 0.7421588028650266, 0.7443919863560865, 0.746728306075071, 0.7491623543019071, 0.7516884914811931, 0.7543008584307735, 0.7569933890374502, 0.7597598234140149, 0.7625937214908315, 0.7654884770142989, 0.7684373319236736, 0.77143339107693, 0.774469637295604, 0.7775389466978948, 0.780634104288652, 0.783747819774356, 0.7868727435706651, 0.7900014829696964, 0.7931266184338277, 0.7962407199824987, 0.7993363636382602, 0.8024061478981441, 0.8054427101963136, 0.808438743323915, 0.8113870117721012, 0.8142803679642405, 0.8171117683435426, 0.8198742892825128, 0.8225611427809665, 0.8251656919196817, 0.8276814660371927, 0.8301021755977097, 0.8324217267187146, 0.834634235327375, 0.8367340409156019, 0.838715719864324, 0.8405740983083095, 0.8423042645137546, 0.8439015807417303, 0.8453616945715596, 0.8466805496592019, 0.847854395906773, 0.8488797990204545, 0.8497536494351957, 0.8504731705858011, 0.8510359265052596, 0.8514398287324347, 0.8516831425125571, 0.8517644922753271, 0.8516828663768018, 0.8514376210926635, 0.8510284838519225, 0.8504555557015427, 0.8497193129939986, 0.8488206082912544, 0.8477606704801912, 0.8465411040960361, 0.8451638878519001, 0.8436313723740678, 0.8419462771442602, 0.8401116866516265, 0.8381310457587954, 0.8360081542878475, 0.8337471608336402, 0.8313525558134125, 0.8288291637631562, 0.8261821348927063, 0.8234169359130106, 0.8205393401504921, 0.8175554169648435, 0.8144715204880213, 0.8112942777035642, 0.8080305758867196, 0.8046875494271607, 0.8012725660573583, 0.7977932125108844, 0.7942572796361339, 0.7906727469920662, 0.7870477669536862, 0.7833906483560108, 0.7797098397062662, 0.7760139119949983, 0.7723115411376629, 0.7686114900790788, 0.7649225905939054, 0.7612537248170017, 0.7576138065381651, 0.7540117622963457, 0.7504565123089121, 0.7469569512720391, 0.743521929068624, 0.7401602314204909, 0.7368805605218625, 0.7336915156912732, 0.7306015740791985, 0.7276190714687106, 0.7247521832064526, 0.722008905301103, 0.7193970357263537, 0.7169241559651591, 0.7145976128317302, 0.7124245006073425, 0.7104116435256023, 0.7085655786422781, 0.7068925391242471, 0.7053984379914313, 0.70408885234491, 0.7029690081135964, 0.7020437653510457, 0.7013176041130531, 0.7007946109457364, 0.7004784660127917, 0.7003724308895326, 0.7004793370501882, 0.7008015750737725, 0.7013410845925956, 0.7020993450062103, 0.7030773669822759, 0.7042756847644399, 0.7056943493059409, 0.7073329222461924, 0.7091904707461176, 0.7112655631964976, 0.7135562658120498, 0.7160601401223802, 0.7187742413693563, 0.7216951178188313, 0.7248188109930076, 0.7281408568280814, 0.7316562877601298, 0.7353596357405494, 0.7392449361806388, 0.743305732823264, 0.7475350835378304, 0.7519255670331169, 0.7564692904808359, 0.7611578980411171, 0.765982580279455, 0.7709340844630013, 0.7760027257224742, 0.7811783990643255, 0.7864505922162527, 0.7918083992875479, 0.797240535224286, 0.802735351037827, 0.8082808497836634, 0.813864703266222, 0.8194742694438254, 0.8250966105067121, 0.8307185115996852, 0.8363265001597381, 0.8419068658377928, 0.8474456809725593, 0.8529288215834063, 0.8583419888481422, 0.8636707310305889, 0.8689004658219467, 0.8740165030590844, 0.8790040677821024, 0.8838483235927997, 0.8885343962750116, 0.8930473976372109, 0.8973724495372258, 0.9014947080485192, 0.90539938772704, 0.9090717859374171, 0.9124973071969872, 0.9156614874960046, 0.9185500185523188, 0.9211487719587456, 0.9234438231814781, 0.9254214753679767, 0.9270682829230117, 0.9283710748118128, 0.9293169775496346, 0.9298934378374774, 0.9300882448042173, 0.929889551815955, 0.929285897814055, 0.928266228144047, 0.9268199148383571, 0.9249367763166678, 0.9226070964686373, 0.9198216430846744, 0.9165716856015077, 0.9128490121303953, 0.9086459457369667, 0.9039553599429162, 0.8987706934210327, 0.8930859638563617, 0.8868957809476932, 0.8801953585249543, 0.8729805257595793, 0.8652477374464209, 0.8569940833373102, 0.8482172965079762, 0.8389157607416222, 0.8290885169141425, 0.8187352683676035, 0.8078563852603583, 0.7964529078838432, 0.7845265489378932, 0.7720796947581587, 0.7591154054909646, 0.7456374142127831, 0.7316501249932305, 0.7171586099023527, 0.7021686049647049, 0.6866865050645863, 0.6707193578085203, 0.6542748563529166, 0.6373613312065561, 0.6199877410193458, 0.6021636623705106, 0.5838992785710798, 0.5652053674972752, 0.5460932884729938, 0.5265749682212949, 0.5066628859063239, 0.4863700572887407, 0.46571001801918444, 0.44469680609582746, 0.4233449435135182, 0.40166941713335635, 0.3796856588029598, 0.3574095247588974, 0.3348572743440848, 0.3120455480740267, 0.2889913450870204, 0.2657120000143862, 0.24222515930790625, 0.21854875706249208, 0.1947009903730319, 0.1707002942651798, 0.14656531624051666, 0.12231489047728844, 0.09796801172840237, 0.0735438089590041, 0.04906151876629667, 0.024540458624742793, 4.898587196589413e-16, -0.024540458624741808, -0.04906151876629569, -0.07354380895900313, -0.09796801172840142, -0.12231489047728747, -0.1465653162405157, -0.17070029426517885, -0.19470099037303099, -0.21854875706249113, -0.24222515930790567, -0.2657120000143852, -0.2889913450870192, -0.31204554807402585, -0.3348572743440836, -0.3574095247588966, -0.37968565880295924, -0.4016694171333552, -0.4233449435135173, -0.44469680609582635, -0.46571001801918355, -0.48637005728874017, -0.506662885906323, -0.5265749682212938, -0.546093288472993, -0.5652053674972747, -0.5838992785710791, -0.60216366237051, -0.6199877410193448, -0.6373613312065555, -0.654274856352916, -0.6707193578085195, -0.6866865050645857, -0.7021686049647042, -0.717158609902352, -0.7316501249932299, -0.7456374142127823, -0.7591154054909641, -0.7720796947581585, -0.7845265489378926, -0.7964529078838426, -0.8078563852603577, -0.818735268367603, -0.8290885169141421, -0.8389157607416219, -0.8482172965079757, -0.8569940833373099, -0.8652477374464203, -0.8729805257595789, -0.880195358524954, -0.8868957809476928, -0.8930859638563616, -0.8987706934210323, -0.903955359942916, -0.9086459457369666, -0.912849012130395, -0.9165716856015077, -0.9198216430846744, -0.9226070964686371, -0.9249367763166676, -0.926819914838357, -0.928266228144047, -0.9292858978140548, -0.9298895518159552, -0.9300882448042173, -0.9298934378374777, -0.9293169775496346, -0.928371074811813, -0.9270682829230119, -0.9254214753679765, -0.9234438231814782, -0.9211487719587453, -0.918550018552319, -0.9156614874960048, -0.9124973071969871, -0.9090717859374173, -0.90539938772704, -0.9014947080485193, -0.8973724495372263, -0.893047397637211, -0.8885343962750122, -0.8838483235928, -0.8790040677821023, -0.8740165030590848, -0.8689004658219469, -0.8636707310305894, -0.8583419888481424, -0.8529288215834065, -0.8474456809725595, -0.8419068658377936, -0.8363265001597383, -0.8307185115996851, -0.8250966105067125, -0.8194742694438252, -0.8138647032662225, -0.8082808497836637, -0.8027353510378269, -0.7972405352242861, -0.7918083992875481, -0.7864505922162529, -0.7811783990643262, -0.7760027257224743, -0.7709340844630018, -0.7659825802794549, -0.7611578980411168, -0.7564692904808364, -0.7519255670331172, -0.7475350835378305, -0.743305732823264, -0.7392449361806388, -0.7353596357405496, -0.7316562877601305, -0.7281408568280814, -0.7248188109930076, -0.7216951178188314, -0.7187742413693559, -0.7160601401223806, -0.71355626581205, -0.7112655631964976, -0.7091904707461179, -0.7073329222461925, -0.705694349305941, -0.7042756847644404, -0.703077366982276, -0.7020993450062105, -0.7013410845925954, -0.7008015750737722, -0.7004793370501885, -0.7003724308895328, -0.7004784660127916, -0.7007946109457364, -0.701317604113053, -0.7020437653510457, -0.7029690081135965, -0.7040888523449099, -0.7053984379914311, -0.7068925391242469, -0.7085655786422779, -0.7104116435256024, -0.7124245006073427, -0.7145976128317295, -0.7169241559651595, -0.7193970357263534, -0.722008905301103, -0.7247521832064523, -0.7276190714687109, -0.7306015740791986, -0.7336915156912729, -0.736880560521862, -0.740160231420491, -0.7435219290686242, -0.746956951272039, -0.7504565123089119, -0.754011762296345, -0.7576138065381651, -0.7612537248170013, -0.7649225905939059, -0.7686114900790786, -0.7723115411376624, -0.776013911994998, -0.779709839706266, -0.7833906483560109, -0.787047766953686, -0.7906727469920662, -0.7942572796361335, -0.7977932125108842, -0.8012725660573581, -0.8046875494271613, -0.8080305758867194, -0.8112942777035639, -0.8144715204880211, -0.8175554169648435, -0.8205393401504921, -0.8234169359130101, -0.8261821348927063, -0.828829163763156, -0.8313525558134124, -0.8337471608336402, -0.8360081542878479, -0.8381310457587954, -0.8401116866516263, -0.84194627714426, -0.8436313723740678, -0.8451638878519004, -0.8465411040960363, -0.8477606704801907, -0.848820608291254, -0.8497193129939986, -0.8504555557015432, -0.851028483851923, -0.8514376210926631, -0.8516828663768014, -0.8517644922753275, -0.8516831425125571, -0.851439828732435, -0.8510359265052592, -0.8504731705858013, -0.8497536494351952, -0.8488797990204546, -0.8478543959067734, -0.8466805496592025, -0.8453616945715603, -0.8439015807417297, -0.8423042645137542, -0.8405740983083096, -0.8387157198643245, -0.8367340409156027, -0.8346342353273742, -0.8324217267187145, -0.8301021755977098, -0.8276814660371932, -0.8251656919196823, -0.822561142780966, -0.8198742892825126, -0.8171117683435422, -0.8142803679642405, -0.8113870117721016, -0.8084387433239146, -0.8054427101963139, -0.8024061478981442, -0.7993363636382604, -0.7962407199824992, -0.7931266184338279, -0.7900014829696971, -0.7868727435706648, -0.7837478197743557, -0.7806341042886521, -0.7775389466978951, -0.7744696372956048, -0.7714333910769291, -0.7684373319236736, -0.7654884770142991, -0.762593721490832, -0.7597598234140153, -0.75699338903745, -0.7543008584307738, -0.7516884914811929, -0.7491623543019076, -0.7467283060750711, -0.7443919863560863, -0.7421588028650268, -0.7400339197899917, -0.738022246626166, -0.7361284275732101, -0.734356831512503, -0.7327115425845669, -0.731196351385746, -0.729814746802002, -0.7285699084963417, -0.7274647000651006, -0.726501662876924, -0.7256830106069152, -0.7250106244770103, -0.7244860492121953, -0.7241104897207562, -0.7238848085052626, -0.7238095238095239, -0.7238848085052625, -0.7241104897207563, -0.7244860492121953, -0.7250106244770101, -0.7256830106069154, -0.7265016628769237, -0.7274647000651007, -0.7285699084963414, -0.7298147468020016, -0.731196351385746, -0.7327115425845663, -0.734356831512503, -0.7361284275732095, -0.7380222466261662, -0.7400339197899919, -0.7421588028650258, -0.7443919863560868, -0.7467283060750707, -0.749162354301907, -0.7516884914811932, -0.7543008584307733, -0.7569933890374505, -0.7597598234140147, -0.7625937214908318, -0.7654884770142986, -0.7684373319236735, -0.7714333910769301, -0.7744696372956035, -0.7775389466978949, -0.7806341042886524, -0.7837478197743555, -0.7868727435706653, -0.7900014829696959, -0.7931266184338279, -0.7962407199824985, -0.7993363636382603, -0.8024061478981448, -0.8054427101963124, -0.8084387433239156, -0.8113870117721008, -0.8142803679642403, -0.8171117683435425, -0.8198742892825124, -0.822561142780967, -0.8251656919196817, -0.8276814660371932, -0.83010217559771, -0.8324217267187143, -0.8346342353273751, -0.8367340409156013, -0.8387157198643242, -0.8405740983083091, -0.8423042645137541, -0.8439015807417299, -0.8453616945715591, -0.8466805496592024, -0.847854395906773, -0.8488797990204546, -0.8497536494351955, -0.8504731705858003, -0.85103592650526, -0.8514398287324347, -0.8516831425125571, -0.8517644922753276, -0.8516828663768012, -0.8514376210926641, -0.8510284838519226, -0.850455555701543, -0.8497193129939986, -0.8488206082912539, -0.8477606704801913, -0.8465411040960359, -0.8451638878519006, -0.8436313723740679, -0.8419462771442601, -0.8401116866516264, -0.838131045758795, -0.8360081542878481, -0.8337471608336404, -0.8313525558134126, -0.8288291637631559, -0.8261821348927063, -0.8234169359130107, -0.8205393401504925, -0.8175554169648437, -0.8144715204880214, -0.8112942777035642, -0.8080305758867198, -0.8046875494271617, -0.8012725660573583, -0.7977932125108841, -0.7942572796361338, -0.7906727469920662, -0.7870477669536863, -0.7833906483560112, -0.7797098397062667, -0.7760139119949983, -0.7723115411376622, -0.7686114900790791, -0.7649225905939061, -0.761253724817002, -0.7576138065381652, -0.7540117622963449, -0.7504565123089121, -0.7469569512720393, -0.7435219290686248, -0.7401602314204911, -0.7368805605218618, -0.733691515691273, -0.7306015740791986, -0.7276190714687115, -0.7247521832064525, -0.7220089053011035, -0.7193970357263535, -0.7169241559651595, -0.7145976128317302, -0.7124245006073429, -0.7104116435256019, -0.7085655786422781, -0.7068925391242464, -0.7053984379914315, -0.7040888523449104, -0.7029690081135969, -0.7020437653510457, -0.7013176041130527, -0.7007946109457361, -0.7004784660127918, -0.7003724308895332, -0.7004793370501882, -0.7008015750737729, -0.7013410845925953, -0.7020993450062101, -0.7030773669822763, -0.70427568476444, -0.7056943493059407, -0.7073329222461924, -0.7091904707461175, -0.7112655631964978, -0.71355626581205, -0.7160601401223801, -0.7187742413693564, -0.7216951178188311, -0.7248188109930077, -0.728140856828081, -0.73165628776013, -0.7353596357405494, -0.7392449361806386, -0.7433057328232635, -0.7475350835378305, -0.7519255670331169, -0.7564692904808354, -0.7611578980411173, -0.7659825802794548, -0.7709340844630012, -0.7760027257224734, -0.7811783990643257, -0.7864505922162524, -0.7918083992875478, -0.7972405352242856, -0.8027353510378268, -0.8082808497836633, -0.8138647032662218, -0.8194742694438256, -0.8250966105067125, -0.830718511599685, -0.8363265001597373, -0.8419068658377927, -0.8474456809725593, -0.8529288215834065, -0.858341988848142, -0.8636707310305888, -0.868900465821946, -0.874016503059084, -0.8790040677821022, -0.8838483235927999, -0.8885343962750117, -0.8930473976372096, -0.8973724495372263, -0.9014947080485197, -0.9053993877270399, -0.909071785937417, -0.912497307196987, -0.9156614874960046, -0.9185500185523179, -0.9211487719587457, -0.9234438231814786, -0.9254214753679765, -0.9270682829230114, -0.9283710748118125, -0.9293169775496344, -0.929893437837478, -0.9300882448042174, -0.9298895518159552, -0.9292858978140545, -0.9282662281440471, -0.9268199148383575, -0.9249367763166679, -0.9226070964686373, -0.9198216430846742, -0.9165716856015076, -0.9128490121303949, -0.9086459457369669, -0.9039553599429165, -0.8987706934210328, -0.8930859638563619, -0.886895780947694, -0.8801953585249538, -0.8729805257595795, -0.8652477374464209, -0.8569940833373108, -0.8482172965079755, -0.8389157607416224, -0.8290885169141429, -0.8187352683676032, -0.8078563852603582, -0.7964529078838433, -0.7845265489378944, -0.772079694758158, -0.7591154054909648, -0.745637414212783, -0.7316501249932317, -0.7171586099023519, -0.7021686049647051, -0.6866865050645865, -0.6707193578085195, -0.6542748563529173, -0.6373613312065565, -0.6199877410193477, -0.6021636623705094, -0.5838992785710799, -0.565205367497276, -0.5460932884729953, -0.526574968221294, -0.5066628859063242, -0.4863700572887424, -0.4657100180191835, -0.44469680609582724, -0.423344943513519, -0.40166941713335846, -0.3796856588029581, -0.35740952475889787, -0.3348572743440846, -0.31204554807402907, -0.28899134508701907, -0.2657120000143871, -0.24222515930790858, -0.2185487570624905, -0.19470099037303312, -0.17070029426518027, -0.14656531624051852, -0.12231489047728704, -0.09796801172840236, -0.07354380895900534, -0.049061518766298814, -0.02454045862474189, ], [0.0, 0.012271461284422748, 0.024540612599506205, 0.0368051446890348, 0.04906274972280143, 0.0613111220090142, 0.07354795870598652, 0.08577096053287188, 0.09797783247920527, 0.11016628451301325, 0.12233403228725556, 0.13447879784436134, 0.14659831031862403, 0.1586903066362192, 0.1707525322126114, 0.1827827416471157, 0.19477869941438136, 0.2067381805525662, 0.2186589713479707, 0.2305388700159029, 0.24237568737754556, 0.2541672475325992, 0.26591138852747553, 0.2776059630188172, 0.28924883893212244, 0.30083790011525313, 0.3123710469866081, 0.323846197177744, 0.3352612861702292, 0.3466142679265177, 0.35790311551463055, 0.36912582172643715, 0.380280399689329, 0.391364883471081, 0.40237732867769815, 0.4133158130440477, 0.42417843701707875, 0.43496332433143514, 0.44566862257726825, 0.4562925037600601, 0.46683316485227, 0.47728882833662023, 0.48765774274083884, 0.497938183163681, 0.508128451792054, 0.5182268784090722, 0.5282318208928728, 0.5381416657060258, 0.5479548283753747, 0.5576697539621489, 0.5672849175221892, 0.5767988245561346, 0.5862100114494194, 0.5955170459019343, 0.6047185273472089, 0.6138130873609755, 0.6227993900589774, 0.631676132483892, 0.6404420449812367, 0.6490958915641363, 0.6576364702668265, 0.6660626134867812, 0.674373188315345, 0.6825670968567659, 0.6906432765355187, 0.6986007003918209, 0.7064383773652397, 0.7141553525663002, 0.7217507075360021, 0.7292235604931607, 0.7365730665694924, 0.7437984180323628, 0.7508988444951301, 0.7578736131150106, 0.7647220287784036, 0.7714434342736143, 0.7780372104509203, 0.784502776369927, 0.7908395894341667, 0.7970471455128977, 0.8031249790500632, 0.8090726631603773, 0.8148898097125058, 0.8205760693993178, 0.8261311317951854, 0.8315547254003155, 0.836846617672099, 0.8420066150434713, 0.8470345629282792, 0.8519303457136536, 0.856693886739395, 0.8613251482643798, 0.8658241314200005, 0.8701908761506585, 0.8744254611413326, 0.8785280037322478, 0.882498659820679, 0.8863376237499208, 0.8900451281854664, 0.8936214439784397, 0.8970668800163253, 0.900381783061055, 0.9035665375745034, 0.9066215655314546, 0.9095473262201069, 0.9123443160301848, 0.9150130682287307, 0.9175541527236568, 0.919968175815137, 0.9222557799349276, 0.9244176433737044, 0.926454479996513, 0.9283670389464305, 0.9301561043365392, 0.9318224949303203, 0.9333670638105793, 0.9347906980370124, 0.9360943182925388, 0.9372788785185135, 0.9383453655389536, 0.9392947986739, 0.9401282293420534, 0.9408467406528178, 0.9414514469878936, 0.9419434935725617, 0.9423240560368094, 0.9425943399664438, 0.9427555804443526, 0.9428090415820634, 0.9427560160417677, 0.9425978245489697, 0.9423358153959275, 0.9419713639360591, 0.9415058720694838, 0.9409407677198749, 0.9402775043028063, 0.9395175601857702, 0.9386624381400541, 0.9377136647846638, 0.9366727900224807, 0.9355413864688502, 0.9343210488727917, 0.9330133935310315, 0.9316200576950582, 0.930142698971403, 0.9285829947153471, 0.9269426414182703, 0.9252233540888417, 0.9234268656282696, 0.9215549261998213, 0.9196093025928294, 0.9175917775814018, 0.9155041492780516, 0.9133482304824712, 0.9111258480256708, 0.9088388421097057, 0.9064890656432165, 0.9040783835730115, 0.9016086722119155, 0.8990818185631196, 0.8964997196412547, 0.8938642817904296, 0.8911774199994584, 0.8884410572145148, 0.8856571236494459, 0.8828275560939821, 0.8799542972200785, 0.8770392948866219, 0.8740845014427447, 0.8710918730299795, 0.8680633688834933, 0.8650009506326413, 0.861906581601074, 0.8587822261066431, 0.8556298487613372, 0.8524514137714903, 0.8492488842385015, 0.8460242214603029, 0.8427793842338119, 0.8395163281586101, 0.8362370049420815, 0.8329433617062497, 0.8296373402965478, 0.8263208765927579, 0.8229958998223554, 0.8196643318764902, 0.816328086628838, 0.8129890692575551, 0.8096491755705668, 0.8063102913344173, 0.8029742916069129, 0.799643040073784, 0.7963183883895901, 0.7930021755230958, 0.7896962271073364, 0.7864023547945997, 0.7831223556165368, 0.7798580113496256, 0.7766110878862006, 0.7733833346112626, 0.7701764837852803, 0.7669922499331955, 0.7638323292398391, 0.7606983989519625, 0.7575921167870884, 0.7545151203493837, 0.7514690265527519, 0.748455431051341, 0.7454759076776604, 0.7425320078885007, 0.7396252602188419, 0.7367571697439363, 0.7339292175497509, 0.7311428602119476, 0.7283995292835792, 0.7257006307916757, 0.723047544742891, 0.7204416246383809, 0.7178841969980739, 0.7153765608944984, 0.7129199874963263, 0.7105157196217862, 0.7081649713020961, 0.7058689273550722, 0.7036287429690486, 0.7014455432972557, 0.6993204230627947, 0.69725444617434, 0.695248645352704, 0.6933040217683877, 0.6914215446902404, 0.6896021511453543, 0.6878467455902999, 0.6861561995938206, 0.6845313515310941, 0.6829730062896624, 0.6814819349871304, 0.6800588747007312, 0.6787045282088491, 0.6774195637445859, 0.6762046147614607, 0.6750602797113125, 0.6739871218344939, 0.6729856689624152, 0.672056413332514, 0.6711998114157114, 0.6704162837564105, 0.6697062148250952, 0.6690699528835788, 0.6685078098629456, 0.6680200612542309, 0.6676069460118732, 0.6672686664699742, 0.6670053882713918, 0.6668172403096947, 0.6667043146839933, 0.6666666666666667, 0.6667043146839933, 0.6668172403096946, 0.6670053882713918, 0.6672686664699742, 0.6676069460118732, 0.6680200612542309, 0.6685078098629456, 0.6690699528835788, 0.6697062148250952, 0.6704162837564104, 0.6711998114157114, 0.672056413332514, 0.6729856689624152, 0.6739871218344939, 0.6750602797113125, 0.6762046147614607, 0.6774195637445859, 0.678704528208849, 0.6800588747007312, 0.6814819349871303, 0.6829730062896624, 0.684531351531094, 0.6861561995938206, 0.6878467455902999, 0.6896021511453543, 0.6914215446902403, 0.6933040217683877, 0.695248645352704, 0.69725444617434, 0.6993204230627945, 0.7014455432972557, 0.7036287429690486, 0.7058689273550722, 0.7081649713020961, 0.7105157196217861, 0.7129199874963263, 0.7153765608944982, 0.717884196998074, 0.7204416246383809, 0.7230475447428909, 0.7257006307916757, 0.7283995292835791, 0.7311428602119475, 0.7339292175497508, 0.7367571697439362, 0.739625260218842, 0.7425320078885006, 0.7454759076776603, 0.748455431051341, 0.7514690265527519, 0.7545151203493836, 0.7575921167870883, 0.7606983989519623, 0.7638323292398391, 0.7669922499331954, 0.7701764837852801, 0.7733833346112626, 0.7766110878862005, 0.7798580113496256, 0.7831223556165368, 0.7864023547945996, 0.7896962271073364, 0.7930021755230958, 0.7963183883895899, 0.799643040073784, 0.8029742916069128, 0.8063102913344171, 0.8096491755705668, 0.812989069257555, 0.8163280866288379, 0.8196643318764903, 0.8229958998223551, 0.8263208765927577, 0.8296373402965475, 0.8329433617062496, 0.8362370049420815, 0.8395163281586101, 0.8427793842338118, 0.846024221460303, 0.8492488842385015, 0.8524514137714899, 0.8556298487613372, 0.8587822261066429, 0.861906581601074, 0.8650009506326409, 0.8680633688834932, 0.8710918730299795, 0.8740845014427446, 0.8770392948866218, 0.8799542972200785, 0.8828275560939821, 0.8856571236494457, 0.8884410572145149, 0.8911774199994582, 0.8938642817904296, 0.8964997196412545, 0.8990818185631193, 0.9016086722119157, 0.9040783835730113, 0.9064890656432164, 0.9088388421097058, 0.9111258480256708, 0.9133482304824712, 0.9155041492780518, 0.9175917775814016, 0.9196093025928294, 0.9215549261998213, 0.9234268656282694, 0.9252233540888417, 0.9269426414182703, 0.928582994715347, 0.930142698971403, 0.9316200576950582, 0.9330133935310314, 0.9343210488727919, 0.9355413864688501, 0.9366727900224807, 0.9377136647846639, 0.938662438140054, 0.9395175601857702, 0.9402775043028063, 0.9409407677198749, 0.9415058720694839, 0.9419713639360592, 0.9423358153959274, 0.9425978245489698, 0.9427560160417676, 0.9428090415820634, 0.9427555804443526, 0.9425943399664437, 0.9423240560368094, 0.9419434935725618, 0.9414514469878934, 0.940846740652818, 0.9401282293420534, 0.9392947986739, 0.9383453655389538, 0.9372788785185135, 0.9360943182925388, 0.9347906980370124, 0.9333670638105793, 0.9318224949303203, 0.9301561043365392, 0.9283670389464305, 0.9264544799965131, 0.9244176433737044, 0.9222557799349278, 0.9199681758151372, 0.9175541527236568, 0.9150130682287307, 0.9123443160301847, 0.909547326220107, 0.9066215655314546, 0.9035665375745034, 0.900381783061055, 0.8970668800163254, 0.8936214439784397, 0.8900451281854668, 0.886337623749921, 0.882498659820679, 0.8785280037322479, 0.8744254611413325, 0.8701908761506587, 0.8658241314200005, 0.86132514826438, 0.856693886739395, 0.8519303457136536, 0.8470345629282792, 0.8420066150434717, 0.8368466176720992, 0.8315547254003155, 0.8261311317951856, 0.8205760693993176, 0.8148898097125059, 0.8090726631603773, 0.8031249790500635, 0.7970471455128978, 0.7908395894341669, 0.784502776369927, 0.7780372104509208, 0.7714434342736144, 0.7647220287784033, 0.7578736131150107, 0.75089884449513, 0.7437984180323631, 0.7365730665694925, 0.7292235604931612, 0.7217507075360021, 0.7141553525663006, 0.7064383773652398, 0.6986007003918213, 0.6906432765355188, 0.6825670968567656, 0.6743731883153451, 0.6660626134867811, 0.6576364702668269, 0.6490958915641365, 0.6404420449812371, 0.631676132483892, 0.6227993900589777, 0.6138130873609756, 0.6047185273472095, 0.5955170459019343, 0.5862100114494193, 0.5767988245561346, 0.5672849175221891, 0.5576697539621494, 0.5479548283753749, 0.5381416657060263, 0.5282318208928731, 0.5182268784090727, 0.5081284517920541, 0.49793818316368177, 0.48765774274083895, 0.4772888283366202, 0.4668331648522701, 0.4562925037600599, 0.44566862257726875, 0.4349633243314354, 0.42417843701707925, 0.41331581304404785, 0.4023773286776986, 0.39136488347108117, 0.3802803996893299, 0.3691258217264373, 0.3579031155146305, 0.34661426792651784, 0.3352612861702291, 0.32384619717774454, 0.31237104698660845, 0.3008379001152537, 0.2892488389321227, 0.2776059630188177, 0.26591138852747576, 0.2541672475325992, 0.24237568737754572, 0.23053887001590279, 0.2186589713479708, 0.20673818055256604, 0.19477869941438197, 0.18278274164711605, 0.17075253221261194, 0.15869030663621947, 0.14659831031862447, 0.1344787978443616, 0.12233403228725553, 0.11016628451301341, 0.09797783247920516, 0.08577096053287198, 0.07354795870598636, 0.061311122009014826, 0.0490627497228018, 0.03680514468903536, 0.024540612599506514, 0.012271461284423248, 2.4492935982947064e-16, -0.012271461284422757, -0.024540612599506025, -0.036805144689034876, -0.04906274972280131, -0.06131112200901434, -0.07354795870598588, -0.08577096053287149, -0.09797783247920469, -0.11016628451301293, -0.12233403228725506, -0.1344787978443611, -0.14659831031862403, -0.158690306636219, -0.17075253221261147, -0.18278274164711558, -0.19477869941438153, -0.20673818055256557, -0.2186589713479703, -0.23053887001590234, -0.24237568737754525, -0.25416724753259873, -0.2659113885274753, -0.2776059630188172, -0.2892488389321223, -0.3008379001152532, -0.312371046986608, -0.32384619717774404, -0.3352612861702286, -0.3466142679265174, -0.35790311551463, -0.3691258217264368, -0.3802803996893294, -0.3913648834710807, -0.40237732867769815, -0.4133158130440475, -0.42417843701707875, -0.43496332433143503, -0.44566862257726836, -0.45629250376005953, -0.46683316485226967, -0.4772888283366198, -0.4876577427408385, -0.4979381831636813, -0.5081284517920538, -0.5182268784090722, -0.5282318208928727, -0.5381416657060257, -0.5479548283753745, -0.5576697539621489, -0.5672849175221888, -0.5767988245561344, -0.5862100114494189, -0.595517045901934, -0.6047185273472092, -0.6138130873609753, -0.6227993900589774, -0.6316761324838918, -0.6404420449812367, -0.649095891564136, -0.6576364702668266, -0.6660626134867808, -0.6743731883153448, -0.6825670968567654, -0.6906432765355185, -0.6986007003918211, -0.7064383773652395, -0.7141553525663002, -0.7217507075360019, -0.7292235604931607, -0.7365730665694922, -0.7437984180323627, -0.7508988444951297, -0.7578736131150103, -0.7647220287784031, -0.7714434342736141, -0.7780372104509204, -0.7845027763699268, -0.7908395894341667, -0.7970471455128975, -0.8031249790500633, -0.8090726631603771, -0.8148898097125057, -0.8205760693993174, -0.8261311317951854, -0.8315547254003153, -0.836846617672099, -0.8420066150434715, -0.847034562928279, -0.8519303457136536, -0.8566938867393947, -0.8613251482643798, -0.8658241314200003, -0.8701908761506585, -0.8744254611413323, -0.8785280037322478, -0.8824986598206788, -0.8863376237499208, -0.8900451281854665, -0.8936214439784396, -0.8970668800163253, -0.9003817830610548, -0.9035665375745034, -0.9066215655314542, -0.9095473262201068, -0.9123443160301847, -0.9150130682287307, -0.9175541527236567, -0.919968175815137, -0.9222557799349276, -0.9244176433737044, -0.9264544799965131, -0.9283670389464302, -0.9301561043365392, -0.9318224949303202, -0.9333670638105791, -0.9347906980370124, -0.9360943182925388, -0.9372788785185134, -0.9383453655389538, -0.9392947986739, -0.9401282293420534, -0.940846740652818, -0.9414514469878934, -0.9419434935725617, -0.9423240560368091, -0.9425943399664437, -0.9427555804443526, -0.9428090415820636, -0.9427560160417676, -0.9425978245489698, -0.9423358153959273, -0.9419713639360592, -0.9415058720694839, -0.9409407677198748, -0.9402775043028063, -0.93951756018577, -0.938662438140054, -0.9377136647846639, -0.936672790022481, -0.9355413864688503, -0.9343210488727917, -0.9330133935310312, -0.9316200576950584, -0.9301426989714032, -0.9285829947153473, -0.9269426414182703, -0.9252233540888416, -0.9234268656282697, -0.921554926199821, -0.9196093025928298, -0.917591777581402, -0.9155041492780516, -0.9133482304824708, -0.911125848025671, -0.9088388421097061, -0.9064890656432167, -0.9040783835730115, -0.9016086722119154, -0.8990818185631199, -0.8964997196412546, -0.8938642817904301, -0.8911774199994588, -0.8884410572145147, -0.8856571236494455, -0.8828275560939822, -0.879954297220079, -0.8770392948866221, -0.8740845014427447, -0.8710918730299794, -0.8680633688834937, -0.8650009506326412, -0.8619065816010744, -0.8587822261066433, -0.855629848761337, -0.8524514137714898, -0.8492488842385016, -0.8460242214603034, -0.8427793842338122, -0.8395163281586102, -0.8362370049420815, -0.83294336170625, -0.8296373402965476, -0.8263208765927581, -0.8229958998223555, -0.81966433187649, -0.8163280866288378, -0.8129890692575554, -0.8096491755705675, -0.8063102913344175, -0.8029742916069129, -0.799643040073784, -0.7963183883895905, -0.7930021755230957, -0.7896962271073369, -0.7864023547946, -0.7831223556165365, -0.7798580113496253, -0.7766110878862008, -0.7733833346112631, -0.7701764837852804, -0.7669922499331955, -0.7638323292398391, -0.760698398951962, -0.7575921167870883, -0.7545151203493841, -0.7514690265527523, -0.7484554310513409, -0.7454759076776601, -0.7425320078885009, -0.7396252602188423, -0.7367571697439365, -0.7339292175497509, -0.7311428602119476, -0.7283995292835789, -0.7257006307916756, -0.7230475447428913, -0.7204416246383811, -0.7178841969980738, -0.7153765608944982, -0.7129199874963266, -0.7105157196217864, -0.7081649713020961, -0.7058689273550725, -0.7036287429690486, -0.7014455432972555, -0.6993204230627945, -0.6972544461743402, -0.6952486453527043, -0.6933040217683875, -0.6914215446902403, -0.6896021511453545, -0.6878467455903001, -0.6861561995938206, -0.6845313515310942, -0.6829730062896624, -0.6814819349871302, -0.6800588747007312, -0.6787045282088491, -0.6774195637445861, -0.6762046147614607, -0.6750602797113125, -0.673987121834494, -0.6729856689624153, -0.6720564133325141, -0.6711998114157115, -0.6704162837564105, -0.6697062148250952, -0.6690699528835787, -0.6685078098629456, -0.6680200612542309, -0.6676069460118732, -0.6672686664699742, -0.6670053882713918, -0.6668172403096947, -0.6667043146839933, -0.6666666666666667, -0.6667043146839933, -0.6668172403096947, -0.6670053882713918, -0.6672686664699741, -0.6676069460118732, -0.6680200612542309, -0.6685078098629456, -0.6690699528835786, -0.6697062148250951, -0.6704162837564103, -0.6711998114157114, -0.672056413332514, -0.6729856689624152, -0.6739871218344939, -0.6750602797113125, -0.6762046147614604, -0.677419563744586, -0.6787045282088491, -0.6800588747007312, -0.6814819349871302, -0.6829730062896624, -0.684531351531094, -0.6861561995938205, -0.6878467455903, -0.6896021511453544, -0.6914215446902402, -0.6933040217683875, -0.6952486453527041, -0.6972544461743402, -0.6993204230627945, -0.7014455432972554, -0.7036287429690485, -0.7058689273550722, -0.7081649713020961, -0.7105157196217862, -0.7129199874963265, -0.7153765608944982, -0.7178841969980736, -0.720441624638381, -0.723047544742891, -0.7257006307916753, -0.7283995292835788, -0.7311428602119474, -0.7339292175497508, -0.7367571697439362, -0.7396252602188422, -0.7425320078885007, -0.7454759076776599, -0.7484554310513407, -0.751469026552752, -0.7545151203493838, -0.757592116787088, -0.7606983989519619, -0.7638323292398389, -0.7669922499331953, -0.7701764837852801, -0.773383334611263, -0.7766110878862007, -0.7798580113496252, -0.7831223556165363, -0.7864023547945997, -0.7896962271073367, -0.7930021755230955, -0.7963183883895902, -0.7996430400737837, -0.8029742916069128, -0.8063102913344171, -0.8096491755705671, -0.8129890692575552, -0.8163280866288375, -0.8196643318764898, -0.8229958998223554, -0.826320876592758, -0.8296373402965475, -0.8329433617062498, -0.8362370049420813, -0.83951632815861, -0.8427793842338119, -0.8460242214603032, -0.8492488842385014, -0.8524514137714896, -0.8556298487613369, -0.8587822261066432, -0.8619065816010741, -0.8650009506326409, -0.8680633688834934, -0.8710918730299793, -0.8740845014427446, -0.8770392948866219, -0.8799542972200788, -0.8828275560939821, -0.8856571236494454, -0.8884410572145146, -0.8911774199994585, -0.8938642817904299, -0.8964997196412544, -0.8990818185631196, -0.9016086722119153, -0.9040783835730113, -0.9064890656432166, -0.908838842109706, -0.9111258480256708, -0.9133482304824707, -0.9155041492780508, -0.9175917775814024, -0.9196093025928296, -0.9215549261998209, -0.9234268656282696, -0.9252233540888415, -0.9269426414182708, -0.9285829947153472, -0.9301426989714031, -0.9316200576950583, -0.9330133935310311, -0.9343210488727921, -0.9355413864688499, -0.9366727900224809, -0.9377136647846637, -0.9386624381400542, -0.9395175601857699, -0.9402775043028058, -0.9409407677198751, -0.941505872069484, -0.9419713639360592, -0.9423358153959273, -0.9425978245489691, -0.9427560160417682, -0.9428090415820636, -0.9427555804443525, -0.9425943399664438, -0.9423240560368091, -0.9419434935725621, -0.9414514469878938, -0.940846740652818, -0.9401282293420534, -0.9392947986739, -0.9383453655389542, -0.9372788785185132, -0.936094318292539, -0.9347906980370125, -0.9333670638105793, -0.9318224949303202, -0.930156104336539, -0.9283670389464309, -0.9264544799965131, -0.9244176433737044, -0.9222557799349277, -0.919968175815137, -0.917554152723657, -0.9150130682287309, -0.912344316030185, -0.9095473262201068, -0.9066215655314545, -0.9035665375745038, -0.9003817830610554, -0.8970668800163253, -0.8936214439784398, -0.8900451281854668, -0.8863376237499214, -0.8824986598206789, -0.878528003732248, -0.8744254611413329, -0.8701908761506585, -0.8658241314200006, -0.86132514826438, -0.8566938867393954, -0.8519303457136533, -0.8470345629282794, -0.8420066150434717, -0.8368466176720997, -0.8315547254003155, -0.8261311317951856, -0.8205760693993183, -0.8148898097125057, -0.8090726631603775, -0.8031249790500635, -0.7970471455128982, -0.7908395894341667, -0.7845027763699273, -0.7780372104509209, -0.771443434273615, -0.7647220287784037, -0.7578736131150108, -0.7508988444951306, -0.7437984180323627, -0.7365730665694926, -0.7292235604931614, -0.7217507075360028, -0.7141553525663001, -0.70643837736524, -0.6986007003918215, -0.6906432765355197, -0.6825670968567656, -0.6743731883153452, -0.666062613486782, -0.6576364702668264, -0.6490958915641366, -0.6404420449812369, -0.6316761324838926, -0.6227993900589772, -0.6138130873609757, -0.6047185273472098, -0.5955170459019341, -0.5862100114494198, -0.5767988245561348, -0.5672849175221901, -0.5576697539621487, -0.547954828375375, -0.5381416657060267, -0.5282318208928737, -0.518226878409072, -0.5081284517920543, -0.497938183163682, -0.48765774274083873, -0.47728882833661995, -0.46683316485227033, -0.45629250376006103, -0.445668622577268, -0.43496332433143564, -0.4241784370170789, -0.4133158130440485, -0.40237732867769793, -0.3913648834710814, -0.3802803996893301, -0.3691258217264371, -0.3579031155146312, -0.34661426792651806, -0.33526128617023027, -0.32384619717774377, -0.31237104698660867, -0.3008379001152544, -0.2892488389321234, -0.2776059630188169, -0.265911388527476, -0.25416724753260045, -0.24237568737754553, -0.23053887001590248, -0.21865897134797102, -0.2067381805525673, -0.1947786994143812, -0.18278274164711628, -0.1707525322126116, -0.15869030663622014, -0.14659831031862372, -0.1344787978443618, -0.12233403228725681, -0.11016628451301322, -0.09797783247920601, -0.08577096053287223, -0.07354795870598764, -0.061311122009014035, -0.04906274972280204, -0.0368051446890362, -0.0245406125995072, -0.012271461284422457, ], [0.0, 0.006135884649154475, 0.012271538285719925, 0.01840672990580482, 0.024541228522912288, 0.030674803176636626, 0.03680722294135883, 0.04293825693494082, 0.049067674327418015, 0.055195244349689934, 0.06132073630220858, 0.06744391956366405, 0.07356456359966743, 0.07968243797143013, 0.0857973123444399, 0.09190895649713272, 0.0980171403295606, 0.10412163387205459, 0.11022220729388306, 0.11631863091190475, 0.1224106751992162, 0.12849811079379317, 0.13458070850712617, 0.1406582393328492, 0.14673047445536175, 0.15279718525844344, 0.15885814333386145, 0.16491312048996992, 0.17096188876030122, 0.17700422041214875, 0.18303988795514095, 0.1890686641498062, 0.19509032201612825, 0.2011046348420919, 0.20711137619221856, 0.21311031991609136, 0.2191012401568698, 0.22508391135979283, 0.2310581082806711, 0.2370236059943672, 0.24298017990326387, 0.24892760574572015, 0.25486565960451457, 0.2607941179152755, 0.26671275747489837, 0.272621355449949, 0.27851968938505306, 0.2844075372112719, 0.29028467725446233, 0.2961508882436238, 0.3020059493192281, 0.30784964004153487, 0.3136817403988915, 0.3195020308160157, 0.3253102921622629, 0.33110630575987643, 0.33688985339222005, 0.3426607173119944, 0.34841868024943456, 0.35416352542049034, 0.3598950365349881, 0.36561299780477385, 0.37131719395183754, 0.37700741021641826, 0.3826834323650898, 0.38834504669882625, 0.3939920400610481, 0.3996241998456468, 0.40524131400498986, 0.4108431710579039, 0.41642956009763715, 0.4220002707997997, 0.4275550934302821, 0.43309381885315196, 0.43861623853852766, 0.4441221445704292, 0.44961132965460654, 0.45508358712634384, 0.46053871095824, 0.4659764957679662, 0.47139673682599764, 0.4767992300633221, 0.4821837720791227, 0.487550160148436, 0.49289819222978404, 0.49822766697278187, 0.5035383837257176, 0.508830142543107, 0.5141027441932217, 0.5193559901655896, 0.524589682678469, 0.5298036246862946, 0.5349976198870972, 0.5401714727298929, 0.5453249884220465, 0.5504579729366048, 0.5555702330196022, 0.560661576197336, 0.5657318107836131, 0.5707807458869673, 0.5758081914178453, 0.5808139580957645, 0.5857978574564389, 0.5907597018588742, 0.5956993044924334, 0.600616479383869, 0.6055110414043255, 0.6103828062763095, 0.6152315905806268, 0.6200572117632891, 0.6248594881423863, 0.629638238914927, 0.6343932841636455, 0.6391244448637757, 0.6438315428897914, 0.6485144010221124, 0.6531728429537768, 0.6578066932970786, 0.6624157775901718, 0.6669999223036375, 0.6715589548470183, 0.6760927035753159, 0.680600997795453, 0.6850836677727004, 0.6895405447370668, 0.693971460889654, 0.6983762494089729, 0.7027547444572253, 0.7071067811865475, 0.7114321957452164, 0.7157308252838186, 0.7200025079613817, 0.7242470829514669, 0.7284643904482252, 0.7326542716724128, 0.7368165688773698, 0.7409511253549591, 0.745057785441466, 0.7491363945234593, 0.7531867990436124, 0.7572088465064845, 0.7612023854842618, 0.765167265622459, 0.7691033376455796, 0.773010453362737, 0.7768884656732324, 0.7807372285720944, 0.7845565971555752, 0.7883464276266062, 0.7921065773002124, 0.7958369046088835, 0.799537269107905, 0.8032075314806448, 0.8068475535437992, 0.8104571982525948, 0.8140363297059483, 0.8175848131515837, 0.8211025149911046, 0.8245893027850253, 0.8280450452577558, 0.8314696123025452, 0.83486287498638, 0.838224705554838, 0.8415549774368983, 0.844853565249707, 0.8481203448032971, 0.8513551931052652, 0.8545579883654005, 0.8577286100002721, 0.8608669386377673, 0.8639728561215867, 0.8670462455156926, 0.8700869911087113, 0.8730949784182901, 0.8760700941954066, 0.8790122264286334, 0.8819212643483549, 0.8847970984309378, 0.8876396204028539, 0.8904487232447579, 0.8932243011955153, 0.8959662497561851, 0.8986744656939538, 0.901348847046022, 0.9039892931234433, 0.9065957045149153, 0.9091679830905223, 0.9117060320054299, 0.9142097557035307, 0.9166790599210427, 0.9191138516900578, 0.9215140393420419, 0.9238795325112867, 0.9262102421383113, 0.9285060804732155, 0.9307669610789837, 0.9329927988347388, 0.9351835099389475, 0.937339011912575, 0.9394592236021899, 0.9415440651830208, 0.9435934581619604, 0.9456073253805213, 0.9475855910177411, 0.9495281805930367, 0.9514350209690083, 0.9533060403541938, 0.9551411683057707, 0.9569403357322089, 0.9587034748958716, 0.9604305194155658, 0.9621214042690416, 0.9637760657954398, 0.9653944416976894, 0.9669764710448521, 0.9685220942744173, 0.970031253194544, 0.9715038909862518, 0.9729399522055601, 0.9743393827855759, 0.9757021300385286, 0.9770281426577544, 0.9783173707196277, 0.9795697656854405, 0.9807852804032304, 0.9819638691095552, 0.9831054874312163, 0.984210092386929, 0.9852776423889412, 0.9863080972445987, 0.9873014181578584, 0.9882575677307495, 0.989176509964781, 0.9900582102622971, 0.99090263542778, 0.9917097536690995, 0.99247953459871, 0.9932119492347945, 0.9939069700023561, 0.9945645707342554, 0.9951847266721968, 0.9957674144676598, 0.996312612182778, 0.9968202992911657, 0.9972904566786902, 0.9977230666441916, 0.9981181129001492, 0.9984755805732948, 0.9987954562051724, 0.9990777277526454, 0.9993223845883495, 0.9995294175010931, 0.9996988186962042, 0.9998305817958234, 0.9999247018391445, 0.9999811752826011, 1.0, 0.9999811752826011, 0.9999247018391445, 0.9998305817958234, 0.9996988186962042, 0.9995294175010931, 0.9993223845883495, 0.9990777277526454, 0.9987954562051724, 0.9984755805732948, 0.9981181129001492, 0.9977230666441916, 0.9972904566786902, 0.9968202992911658, 0.996312612182778, 0.9957674144676598, 0.9951847266721969, 0.9945645707342554, 0.9939069700023561, 0.9932119492347945, 0.99247953459871, 0.9917097536690995, 0.99090263542778, 0.9900582102622971, 0.989176509964781, 0.9882575677307495, 0.9873014181578584, 0.9863080972445987, 0.9852776423889412, 0.984210092386929, 0.9831054874312163, 0.9819638691095552, 0.9807852804032304, 0.9795697656854405, 0.9783173707196277, 0.9770281426577544, 0.9757021300385286, 0.9743393827855759, 0.9729399522055602, 0.9715038909862518, 0.970031253194544, 0.9685220942744174, 0.9669764710448521, 0.9653944416976894, 0.9637760657954398, 0.9621214042690416, 0.9604305194155659, 0.9587034748958716, 0.9569403357322089, 0.9551411683057707, 0.9533060403541939, 0.9514350209690083, 0.9495281805930367, 0.9475855910177412, 0.9456073253805214, 0.9435934581619604, 0.9415440651830208, 0.9394592236021899, 0.937339011912575, 0.9351835099389476, 0.9329927988347388, 0.9307669610789837, 0.9285060804732156, 0.9262102421383114, 0.9238795325112867, 0.921514039342042, 0.9191138516900578, 0.9166790599210427, 0.9142097557035307, 0.9117060320054299, 0.9091679830905225, 0.9065957045149153, 0.9039892931234434, 0.901348847046022, 0.8986744656939539, 0.8959662497561852, 0.8932243011955152, 0.890448723244758, 0.8876396204028539, 0.8847970984309379, 0.881921264348355, 0.8790122264286335, 0.8760700941954066, 0.8730949784182902, 0.8700869911087115, 0.8670462455156928, 0.8639728561215868, 0.8608669386377672, 0.8577286100002721, 0.8545579883654005, 0.8513551931052652, 0.8481203448032972, 0.8448535652497072, 0.8415549774368984, 0.8382247055548382, 0.8348628749863801, 0.8314696123025455, 0.8280450452577558, 0.8245893027850252, 0.8211025149911048, 0.8175848131515837, 0.8140363297059485, 0.8104571982525948, 0.8068475535437994, 0.8032075314806449, 0.7995372691079052, 0.7958369046088836, 0.7921065773002123, 0.7883464276266063, 0.7845565971555751, 0.7807372285720946, 0.7768884656732324, 0.7730104533627371, 0.7691033376455796, 0.7651672656224591, 0.7612023854842619, 0.7572088465064847, 0.7531867990436125, 0.7491363945234593, 0.7450577854414661, 0.740951125354959, 0.73681656887737, 0.7326542716724128, 0.7284643904482253, 0.7242470829514669, 0.7200025079613818, 0.7157308252838187, 0.7114321957452167, 0.7071067811865476, 0.7027547444572252, 0.6983762494089729, 0.693971460889654, 0.689540544737067, 0.6850836677727004, 0.6806009977954532, 0.6760927035753159, 0.6715589548470186, 0.6669999223036376, 0.662415777590172, 0.6578066932970787, 0.6531728429537766, 0.6485144010221126, 0.6438315428897914, 0.6391244448637758, 0.6343932841636455, 0.6296382389149272, 0.6248594881423863, 0.6200572117632894, 0.6152315905806269, 0.6103828062763097, 0.6055110414043257, 0.6006164793838689, 0.5956993044924335, 0.5907597018588742, 0.585797857456439, 0.5808139580957645, 0.5758081914178454, 0.5707807458869673, 0.5657318107836135, 0.5606615761973361, 0.5555702330196022, 0.5504579729366049, 0.5453249884220464, 0.540171472729893, 0.5349976198870972, 0.5298036246862948, 0.524589682678469, 0.5193559901655898, 0.5141027441932218, 0.5088301425431073, 0.5035383837257177, 0.49822766697278176, 0.49289819222978415, 0.4875501601484359, 0.4821837720791229, 0.4767992300633221, 0.47139673682599786, 0.4659764957679662, 0.4605387109582402, 0.4550835871263439, 0.4496113296546069, 0.4441221445704293, 0.43861623853852755, 0.43309381885315207, 0.42755509343028203, 0.42200027079979985, 0.41642956009763715, 0.41084317105790413, 0.4052413140049899, 0.39962419984564707, 0.39399204006104815, 0.3883450466988266, 0.3826834323650899, 0.37700741021641815, 0.3713171939518377, 0.3656129978047738, 0.35989503653498833, 0.3541635254204904, 0.3484186802494348, 0.34266071731199443, 0.33688985339222033, 0.3311063057598765, 0.32531029216226326, 0.3195020308160158, 0.3136817403988914, 0.30784964004153503, 0.30200594931922803, 0.296150888243624, 0.2902846772544624, 0.2844075372112721, 0.27851968938505317, 0.27262135544994925, 0.2667127574748985, 0.26079411791527585, 0.2548656596045147, 0.2489276057457201, 0.24298017990326407, 0.23702360599436717, 0.23105810828067133, 0.22508391135979283, 0.21910124015687005, 0.21311031991609142, 0.20711137619221884, 0.201104634842092, 0.1950903220161286, 0.18906866414980636, 0.1830398879551409, 0.17700422041214894, 0.17096188876030122, 0.16491312048997014, 0.15885814333386147, 0.15279718525844369, 0.1467304744553618, 0.14065823933284954, 0.13458070850712628, 0.12849811079379309, 0.12241067519921635, 0.11631863091190471, 0.11022220729388324, 0.10412163387205457, 0.09801714032956083, 0.09190895649713275, 0.08579731234444016, 0.0796824379714302, 0.07356456359966773, 0.06744391956366418, 0.06132073630220849, 0.055195244349690094, 0.049067674327417966, 0.04293825693494102, 0.03680722294135883, 0.030674803176636865, 0.024541228522912326, 0.0184067299058051, 0.012271538285720007, 0.006135884649154799, 1.2246467991473532e-16, -0.006135884649154554, -0.012271538285719762, -0.01840672990580486, -0.02454122852291208, -0.03067480317663662, -0.03680722294135858, -0.04293825693494078, -0.049067674327417724, -0.05519524434968985, -0.061320736302208245, -0.06744391956366393, -0.0735645635996675, -0.07968243797142995, -0.08579731234443992, -0.09190895649713252, -0.09801714032956059, -0.10412163387205432, -0.110222207293883, -0.11631863091190447, -0.1224106751992161, -0.12849811079379284, -0.13458070850712606, -0.1406582393328493, -0.14673047445536158, -0.15279718525844344, -0.15885814333386122, -0.1649131204899699, -0.17096188876030097, -0.1770042204121487, -0.18303988795514065, -0.1890686641498061, -0.19509032201612836, -0.20110463484209176, -0.2071113761922186, -0.2131103199160912, -0.2191012401568698, -0.2250839113597926, -0.23105810828067108, -0.23702360599436695, -0.24298017990326382, -0.24892760574571987, -0.25486565960451446, -0.2607941179152756, -0.26671275747489825, -0.27262135544994903, -0.2785196893850529, -0.2844075372112718, -0.2902846772544621, -0.2961508882436238, -0.3020059493192278, -0.3078496400415348, -0.3136817403988912, -0.3195020308160156, -0.325310292162263, -0.33110630575987626, -0.3368898533922201, -0.3426607173119942, -0.34841868024943456, -0.3541635254204901, -0.3598950365349881, -0.3656129978047736, -0.37131719395183743, -0.3770074102164179, -0.38268343236508967, -0.38834504669882636, -0.39399204006104793, -0.39962419984564684, -0.4052413140049897, -0.4108431710579039, -0.41642956009763693, -0.4220002707997996, -0.4275550934302818, -0.43309381885315185, -0.4386162385385273, -0.4441221445704291, -0.44961132965460665, -0.45508358712634367, -0.46053871095824006, -0.46597649576796596, -0.47139673682599764, -0.47679923006332187, -0.48218377207912266, -0.48755016014843566, -0.4928981922297839, -0.49822766697278154, -0.5035383837257175, -0.5088301425431071, -0.5141027441932216, -0.5193559901655896, -0.5245896826784687, -0.5298036246862946, -0.5349976198870969, -0.5401714727298929, -0.5453249884220461, -0.5504579729366047, -0.555570233019602, -0.5606615761973359, -0.5657318107836132, -0.5707807458869671, -0.5758081914178453, -0.5808139580957643, -0.5857978574564389, -0.5907597018588739, -0.5956993044924332, -0.6006164793838686, -0.6055110414043254, -0.6103828062763095, -0.6152315905806267, -0.6200572117632892, -0.6248594881423862, -0.629638238914927, -0.6343932841636453, -0.6391244448637757, -0.6438315428897913, -0.6485144010221123, -0.6531728429537765, -0.6578066932970785, -0.6624157775901718, -0.6669999223036374, -0.6715589548470184, -0.6760927035753158, -0.680600997795453, -0.6850836677727001, -0.6895405447370668, -0.6939714608896538, -0.6983762494089728, -0.7027547444572251, -0.7071067811865475, -0.7114321957452164, -0.7157308252838185, -0.7200025079613817, -0.7242470829514668, -0.7284643904482252, -0.7326542716724126, -0.7368165688773698, -0.7409511253549589, -0.7450577854414658, -0.749136394523459, -0.7531867990436124, -0.7572088465064842, -0.761202385484262, -0.765167265622459, -0.7691033376455795, -0.7730104533627367, -0.7768884656732326, -0.7807372285720944, -0.784556597155575, -0.7883464276266059, -0.7921065773002124, -0.7958369046088835, -0.7995372691079048, -0.803207531480645, -0.8068475535437992, -0.8104571982525947, -0.8140363297059481, -0.8175848131515838, -0.8211025149911046, -0.8245893027850251, -0.8280450452577555, -0.8314696123025452, -0.83486287498638, -0.8382247055548379, -0.8415549774368986, -0.844853565249707, -0.8481203448032971, -0.8513551931052649, -0.8545579883654006, -0.857728610000272, -0.8608669386377671, -0.8639728561215865, -0.8670462455156926, -0.8700869911087113, -0.8730949784182899, -0.8760700941954067, -0.8790122264286334, -0.8819212643483549, -0.8847970984309376, -0.887639620402854, -0.8904487232447579, -0.8932243011955152, -0.8959662497561849, -0.8986744656939538, -0.9013488470460219, -0.9039892931234431, -0.9065957045149154, -0.9091679830905224, -0.9117060320054298, -0.9142097557035305, -0.9166790599210427, -0.9191138516900577, -0.9215140393420418, -0.9238795325112865, -0.9262102421383114, -0.9285060804732155, -0.9307669610789836, -0.932992798834739, -0.9351835099389476, -0.9373390119125748, -0.9394592236021897, -0.9415440651830208, -0.9435934581619603, -0.9456073253805212, -0.9475855910177412, -0.9495281805930367, -0.9514350209690083, -0.9533060403541938, -0.9551411683057708, -0.9569403357322088, -0.9587034748958715, -0.9604305194155657, -0.9621214042690416, -0.9637760657954398, -0.9653944416976893, -0.9669764710448522, -0.9685220942744173, -0.970031253194544, -0.9715038909862517, -0.9729399522055602, -0.9743393827855759, -0.9757021300385285, -0.9770281426577543, -0.9783173707196277, -0.9795697656854405, -0.9807852804032303, -0.9819638691095554, -0.9831054874312163, -0.984210092386929, -0.9852776423889411, -0.9863080972445987, -0.9873014181578583, -0.9882575677307495, -0.9891765099647809, -0.9900582102622971, -0.99090263542778, -0.9917097536690995, -0.9924795345987101, -0.9932119492347945, -0.9939069700023561, -0.9945645707342554, -0.9951847266721969, -0.9957674144676598, -0.996312612182778, -0.9968202992911657, -0.9972904566786902, -0.9977230666441916, -0.9981181129001492, -0.9984755805732948, -0.9987954562051724, -0.9990777277526454, -0.9993223845883494, -0.9995294175010931, -0.9996988186962042, -0.9998305817958234, -0.9999247018391445, -0.9999811752826011, -1.0, -0.9999811752826011, -0.9999247018391445, -0.9998305817958234, -0.9996988186962042, -0.9995294175010931, -0.9993223845883495, -0.9990777277526454, -0.9987954562051724, -0.9984755805732948, -0.9981181129001492, -0.9977230666441916, -0.9972904566786902, -0.9968202992911657, -0.996312612182778, -0.9957674144676598, -0.9951847266721969, -0.9945645707342554, -0.9939069700023561, -0.9932119492347946, -0.9924795345987101, -0.9917097536690995, -0.99090263542778, -0.9900582102622971, -0.9891765099647809, -0.9882575677307495, -0.9873014181578584, -0.9863080972445988, -0.9852776423889412, -0.9842100923869291, -0.9831054874312164, -0.9819638691095554, -0.9807852804032304, -0.9795697656854406, -0.9783173707196278, -0.9770281426577543, -0.9757021300385286, -0.974339382785576, -0.9729399522055603, -0.9715038909862518, -0.970031253194544, -0.9685220942744174, -0.9669764710448523, -0.9653944416976894, -0.96377606579544, -0.9621214042690417, -0.9604305194155658, -0.9587034748958716, -0.9569403357322089, -0.9551411683057709, -0.9533060403541939, -0.9514350209690084, -0.9495281805930368, -0.9475855910177413, -0.9456073253805213, -0.9435934581619604, -0.9415440651830209, -0.9394592236021898, -0.937339011912575, -0.9351835099389477, -0.9329927988347391, -0.9307669610789837, -0.9285060804732156, -0.9262102421383115, -0.9238795325112866, -0.9215140393420419, -0.9191138516900579, -0.9166790599210428, -0.9142097557035306, -0.9117060320054299, -0.9091679830905225, -0.9065957045149156, -0.9039892931234433, -0.9013488470460221, -0.898674465693954, -0.895966249756185, -0.8932243011955153, -0.890448723244758, -0.8876396204028542, -0.8847970984309377, -0.881921264348355, -0.8790122264286336, -0.8760700941954069, -0.8730949784182901, -0.8700869911087115, -0.8670462455156929, -0.8639728561215866, -0.8608669386377673, -0.8577286100002722, -0.8545579883654008, -0.8513551931052651, -0.8481203448032973, -0.8448535652497072, -0.8415549774368988, -0.838224705554838, -0.8348628749863801, -0.8314696123025455, -0.8280450452577557, -0.8245893027850253, -0.8211025149911049, -0.817584813151584, -0.8140363297059483, -0.8104571982525949, -0.8068475535437994, -0.8032075314806453, -0.799537269107905, -0.7958369046088837, -0.7921065773002126, -0.7883464276266061, -0.7845565971555752, -0.7807372285720946, -0.7768884656732328, -0.7730104533627369, -0.7691033376455797, -0.7651672656224592, -0.7612023854842622, -0.7572088465064846, -0.7531867990436126, -0.7491363945234596, -0.7450577854414658, -0.7409511253549591, -0.73681656887737, -0.7326542716724131, -0.7284643904482251, -0.724247082951467, -0.7200025079613819, -0.715730825283819, -0.7114321957452164, -0.7071067811865477, -0.7027547444572256, -0.6983762494089727, -0.693971460889654, -0.6895405447370672, -0.6850836677727008, -0.680600997795453, -0.676092703575316, -0.6715589548470187, -0.666999922303638, -0.6624157775901718, -0.6578066932970789, -0.6531728429537771, -0.6485144010221123, -0.6438315428897915, -0.639124444863776, -0.6343932841636459, -0.629638238914927, -0.6248594881423865, -0.6200572117632895, -0.6152315905806274, -0.6103828062763095, -0.6055110414043257, -0.6006164793838693, -0.5956993044924332, -0.5907597018588743, -0.5857978574564391, -0.580813958095765, -0.5758081914178452, -0.5707807458869674, -0.5657318107836136, -0.5606615761973366, -0.5555702330196022, -0.550457972936605, -0.5453249884220468, -0.5401714727298927, -0.5349976198870973, -0.5298036246862949, -0.5245896826784694, -0.5193559901655895, -0.5141027441932219, -0.5088301425431074, -0.5035383837257181, -0.49822766697278187, -0.49289819222978426, -0.4875501601484364, -0.4821837720791226, -0.4767992300633222, -0.4713967368259979, -0.4659764957679667, -0.46053871095823995, -0.455083587126344, -0.449611329654607, -0.4441221445704298, -0.43861623853852766, -0.4330938188531522, -0.42755509343028253, -0.42200027079979957, -0.41642956009763726, -0.41084317105790424, -0.4052413140049904, -0.3996241998456468, -0.39399204006104827, -0.3883450466988267, -0.3826834323650904, -0.37700741021641826, -0.3713171939518378, -0.36561299780477435, -0.359895036534988, -0.3541635254204905, -0.3484186802494349, -0.34266071731199493, -0.33688985339222, -0.3311063057598766, -0.32531029216226337, -0.31950203081601547, -0.3136817403988915, -0.30784964004153514, -0.3020059493192286, -0.29615088824362373, -0.2902846772544625, -0.2844075372112722, -0.27851968938505367, -0.272621355449949, -0.2667127574748986, -0.26079411791527596, -0.2548656596045144, -0.2489276057457202, -0.24298017990326418, -0.23702360599436773, -0.231058108280671, -0.22508391135979297, -0.21910124015687016, -0.21311031991609197, -0.20711137619221853, -0.20110463484209212, -0.19509032201612872, -0.18906866414980603, -0.183039887955141, -0.17700422041214905, -0.17096188876030177, -0.1649131204899698, -0.15885814333386158, -0.1527971852584438, -0.1467304744553624, -0.1406582393328492, -0.13458070850712642, -0.12849811079379364, -0.12241067519921603, -0.11631863091190484, -0.11022220729388336, -0.10412163387205513, -0.0980171403295605, -0.09190895649713288, -0.08579731234444028, -0.07968243797143075, -0.07356456359966741, -0.06744391956366429, -0.06132073630220906, -0.055195244349689775, -0.04906767432741809, -0.04293825693494114, -0.036807222941359394, -0.030674803176636543, -0.024541228522912448, -0.018406729905805226, -0.012271538285720572, -0.006135884649154477, ], [0.0, 0.006135884649154475, 0.012271538285719925, 0.01840672990580482, 0.024541228522912288, 0.030674803176636626, 0.03680722294135883, 0.04293825693494082, 0.049067674327418015, 0.055195244349689934, 0.06132073630220858, 0.06744391956366405, 0.07356456359966743, 0.07968243797143013, 0.0857973123444399, 0.09190895649713272, 0.0980171403295606, 0.10412163387205459, 0.11022220729388306, 0.11631863091190475, 0.1224106751992162, 0.12849811079379317, 0.13458070850712617, 0.1406582393328492, 0.14673047445536175, 0.15279718525844344, 0.15885814333386145, 0.16491312048996992, 0.17096188876030122, 0.17700422041214875, 0.18303988795514095, 0.1890686641498062, 0.19509032201612825, 0.2011046348420919, 0.20711137619221856, 0.21311031991609136, 0.2191012401568698, 0.22508391135979283, 0.2310581082806711, 0.2370236059943672, 0.24298017990326387, 0.24892760574572015, 0.25486565960451457, 0.2607941179152755, 0.26671275747489837, 0.272621355449949, 0.27851968938505306, 0.2844075372112719, 0.29028467725446233, 0.2961508882436238, 0.3020059493192281, 0.30784964004153487, 0.3136817403988915, 0.3195020308160157, 0.3253102921622629, 0.33110630575987643, 0.33688985339222005, 0.3426607173119944, 0.34841868024943456, 0.35416352542049034, 0.3598950365349881, 0.36561299780477385, 0.37131719395183754, 0.37700741021641826, 0.3826834323650898, 0.38834504669882625, 0.3939920400610481, 0.3996241998456468, 0.40524131400498986, 0.4108431710579039, 0.41642956009763715, 0.4220002707997997, 0.4275550934302821, 0.43309381885315196, 0.43861623853852766, 0.4441221445704292, 0.44961132965460654, 0.45508358712634384, 0.46053871095824, 0.4659764957679662, 0.47139673682599764, 0.4767992300633221, 0.4821837720791227, 0.487550160148436, 0.49289819222978404, 0.49822766697278187, 0.5035383837257176, 0.508830142543107, 0.5141027441932217, 0.5193559901655896, 0.524589682678469, 0.5298036246862946, 0.5349976198870972, 0.5401714727298929, 0.5453249884220465, 0.5504579729366048, 0.5555702330196022, 0.560661576197336, 0.5657318107836131, 0.5707807458869673, 0.5758081914178453, 0.5808139580957645, 0.5857978574564389, 0.5907597018588742, 0.5956993044924334, 0.600616479383869, 0.6055110414043255, 0.6103828062763095, 0.6152315905806268, 0.6200572117632891, 0.6248594881423863, 0.629638238914927, 0.6343932841636455, 0.6391244448637757, 0.6438315428897914, 0.6485144010221124, 0.6531728429537768, 0.6578066932970786, 0.6624157775901718, 0.6669999223036375, 0.6715589548470183, 0.6760927035753159, 0.680600997795453, 0.6850836677727004, 0.6895405447370668, 0.693971460889654, 0.6983762494089729, 0.7027547444572253, 0.7071067811865475, 0.7114321957452164, 0.7157308252838186, 0.7200025079613817, 0.7242470829514669, 0.7284643904482252, 0.7326542716724128, 0.7368165688773698, 0.7409511253549591, 0.745057785441466, 0.7491363945234593, 0.7531867990436124, 0.7572088465064845, 0.7612023854842618, 0.765167265622459, 0.7691033376455796, 0.773010453362737, 0.7768884656732324, 0.7807372285720944, 0.7845565971555752, 0.7883464276266062, 0.7921065773002124, 0.7958369046088835, 0.799537269107905, 0.8032075314806448, 0.8068475535437992, 0.8104571982525948, 0.8140363297059483, 0.8175848131515837, 0.8211025149911046, 0.8245893027850253, 0.8280450452577558, 0.8314696123025452, 0.83486287498638, 0.838224705554838, 0.8415549774368983, 0.844853565249707, 0.8481203448032971, 0.8513551931052652, 0.8545579883654005, 0.8577286100002721, 0.8608669386377673, 0.8639728561215867, 0.8670462455156926, 0.8700869911087113, 0.8730949784182901, 0.8760700941954066, 0.8790122264286334, 0.8819212643483549, 0.8847970984309378, 0.8876396204028539, 0.8904487232447579, 0.8932243011955153, 0.8959662497561851, 0.8986744656939538, 0.901348847046022, 0.9039892931234433, 0.9065957045149153, 0.9091679830905223, 0.9117060320054299, 0.9142097557035307, 0.9166790599210427, 0.9191138516900578, 0.9215140393420419, 0.9238795325112867, 0.9262102421383113, 0.9285060804732155, 0.9307669610789837, 0.9329927988347388, 0.9351835099389475, 0.937339011912575, 0.9394592236021899, 0.9415440651830208, 0.9435934581619604, 0.9456073253805213, 0.9475855910177411, 0.9495281805930367, 0.9514350209690083, 0.9533060403541938, 0.9551411683057707, 0.9569403357322089, 0.9587034748958716, 0.9604305194155658, 0.9621214042690416, 0.9637760657954398, 0.9653944416976894, 0.9669764710448521, 0.9685220942744173, 0.970031253194544, 0.9715038909862518, 0.9729399522055601, 0.9743393827855759, 0.9757021300385286, 0.9770281426577544, 0.9783173707196277, 0.9795697656854405, 0.9807852804032304, 0.9819638691095552, 0.9831054874312163, 0.984210092386929, 0.9852776423889412, 0.9863080972445987, 0.9873014181578584, 0.9882575677307495, 0.989176509964781, 0.9900582102622971, 0.99090263542778, 0.9917097536690995, 0.99247953459871, 0.9932119492347945, 0.9939069700023561, 0.9945645707342554, 0.9951847266721968, 0.9957674144676598, 0.996312612182778, 0.9968202992911657, 0.9972904566786902, 0.9977230666441916, 0.9981181129001492, 0.9984755805732948, 0.9987954562051724, 0.9990777277526454, 0.9993223845883495, 0.9995294175010931, 0.9996988186962042, 0.9998305817958234, 0.9999247018391445, 0.9999811752826011, 1.0, 0.9999811752826011, 0.9999247018391445, 0.9998305817958234, 0.9996988186962042, 0.9995294175010931, 0.9993223845883495, 0.9990777277526454, 0.9987954562051724, 0.9984755805732948, 0.9981181129001492, 0.9977230666441916, 0.9972904566786902, 0.9968202992911658, 0.996312612182778, 0.9957674144676598, 0.9951847266721969, 0.9945645707342554, 0.9939069700023561, 0.9932119492347945, 0.99247953459871, 0.9917097536690995, 0.99090263542778, 0.9900582102622971, 0.989176509964781, 0.9882575677307495, 0.9873014181578584, 0.9863080972445987, 0.9852776423889412, 0.984210092386929, 0.9831054874312163, 0.9819638691095552, 0.9807852804032304, 0.9795697656854405, 0.9783173707196277, 0.9770281426577544, 0.9757021300385286, 0.9743393827855759, 0.9729399522055602, 0.9715038909862518, 0.970031253194544, 0.9685220942744174, 0.9669764710448521, 0.9653944416976894, 0.9637760657954398, 0.9621214042690416, 0.9604305194155659, 0.9587034748958716, 0.9569403357322089, 0.9551411683057707, 0.9533060403541939, 0.9514350209690083, 0.9495281805930367, 0.9475855910177412, 0.9456073253805214, 0.9435934581619604, 0.9415440651830208, 0.9394592236021899, 0.937339011912575, 0.9351835099389476, 0.9329927988347388, 0.9307669610789837, 0.9285060804732156, 0.9262102421383114, 0.9238795325112867, 0.921514039342042, 0.9191138516900578, 0.9166790599210427, 0.9142097557035307, 0.9117060320054299, 0.9091679830905225, 0.9065957045149153, 0.9039892931234434, 0.901348847046022, 0.8986744656939539, 0.8959662497561852, 0.8932243011955152, 0.890448723244758, 0.8876396204028539, 0.8847970984309379, 0.881921264348355, 0.8790122264286335, 0.8760700941954066, 0.8730949784182902, 0.8700869911087115, 0.8670462455156928, 0.8639728561215868, 0.8608669386377672, 0.8577286100002721, 0.8545579883654005, 0.8513551931052652, 0.8481203448032972, 0.8448535652497072, 0.8415549774368984, 0.8382247055548382, 0.8348628749863801, 0.8314696123025455, 0.8280450452577558, 0.8245893027850252, 0.8211025149911048, 0.8175848131515837, 0.8140363297059485, 0.8104571982525948, 0.8068475535437994, 0.8032075314806449, 0.7995372691079052, 0.7958369046088836, 0.7921065773002123, 0.7883464276266063, 0.7845565971555751, 0.7807372285720946, 0.7768884656732324, 0.7730104533627371, 0.7691033376455796, 0.7651672656224591, 0.7612023854842619, 0.7572088465064847, 0.7531867990436125, 0.7491363945234593, 0.7450577854414661, 0.740951125354959, 0.73681656887737, 0.7326542716724128, 0.7284643904482253, 0.7242470829514669, 0.7200025079613818, 0.7157308252838187, 0.7114321957452167, 0.7071067811865476, 0.7027547444572252, 0.6983762494089729, 0.693971460889654, 0.689540544737067, 0.6850836677727004, 0.6806009977954532, 0.6760927035753159, 0.6715589548470186, 0.6669999223036376, 0.662415777590172, 0.6578066932970787, 0.6531728429537766, 0.6485144010221126, 0.6438315428897914, 0.6391244448637758, 0.6343932841636455, 0.6296382389149272, 0.6248594881423863, 0.6200572117632894, 0.6152315905806269, 0.6103828062763097, 0.6055110414043257, 0.6006164793838689, 0.5956993044924335, 0.5907597018588742, 0.585797857456439, 0.5808139580957645, 0.5758081914178454, 0.5707807458869673, 0.5657318107836135, 0.5606615761973361, 0.5555702330196022, 0.5504579729366049, 0.5453249884220464, 0.540171472729893, 0.5349976198870972, 0.5298036246862948, 0.524589682678469, 0.5193559901655898, 0.5141027441932218, 0.5088301425431073, 0.5035383837257177, 0.49822766697278176, 0.49289819222978415, 0.4875501601484359, 0.4821837720791229, 0.4767992300633221, 0.47139673682599786, 0.4659764957679662, 0.4605387109582402, 0.4550835871263439, 0.4496113296546069, 0.4441221445704293, 0.43861623853852755, 0.43309381885315207, 0.42755509343028203, 0.42200027079979985, 0.41642956009763715, 0.41084317105790413, 0.4052413140049899, 0.39962419984564707, 0.39399204006104815, 0.3883450466988266, 0.3826834323650899, 0.37700741021641815, 0.3713171939518377, 0.3656129978047738, 0.35989503653498833, 0.3541635254204904, 0.3484186802494348, 0.34266071731199443, 0.33688985339222033, 0.3311063057598765, 0.32531029216226326, 0.3195020308160158, 0.3136817403988914, 0.30784964004153503, 0.30200594931922803, 0.296150888243624, 0.2902846772544624, 0.2844075372112721, 0.27851968938505317, 0.27262135544994925, 0.2667127574748985, 0.26079411791527585, 0.2548656596045147, 0.2489276057457201, 0.24298017990326407, 0.23702360599436717, 0.23105810828067133, 0.22508391135979283, 0.21910124015687005, 0.21311031991609142, 0.20711137619221884, 0.201104634842092, 0.1950903220161286, 0.18906866414980636, 0.1830398879551409, 0.17700422041214894, 0.17096188876030122, 0.16491312048997014, 0.15885814333386147, 0.15279718525844369, 0.1467304744553618, 0.14065823933284954, 0.13458070850712628, 0.12849811079379309, 0.12241067519921635, 0.11631863091190471, 0.11022220729388324, 0.10412163387205457, 0.09801714032956083, 0.09190895649713275, 0.08579731234444016, 0.0796824379714302, 0.07356456359966773, 0.06744391956366418, 0.06132073630220849, 0.055195244349690094, 0.049067674327417966, 0.04293825693494102, 0.03680722294135883, 0.030674803176636865, 0.024541228522912326, 0.0184067299058051, 0.012271538285720007, 0.006135884649154799, 1.2246467991473532e-16, -0.006135884649154554, -0.012271538285719762, -0.01840672990580486, -0.02454122852291208, -0.03067480317663662, -0.03680722294135858, -0.04293825693494078, -0.049067674327417724, -0.05519524434968985, -0.061320736302208245, -0.06744391956366393, -0.0735645635996675, -0.07968243797142995, -0.08579731234443992, -0.09190895649713252, -0.09801714032956059, -0.10412163387205432, -0.110222207293883, -0.11631863091190447, -0.1224106751992161, -0.12849811079379284, -0.13458070850712606, -0.1406582393328493, -0.14673047445536158, -0.15279718525844344, -0.15885814333386122, -0.1649131204899699, -0.17096188876030097, -0.1770042204121487, -0.18303988795514065, -0.1890686641498061, -0.19509032201612836, -0.20110463484209176, -0.2071113761922186, -0.2131103199160912, -0.2191012401568698, -0.2250839113597926, -0.23105810828067108, -0.23702360599436695, -0.24298017990326382, -0.24892760574571987, -0.25486565960451446, -0.2607941179152756, -0.26671275747489825, -0.27262135544994903, -0.2785196893850529, -0.2844075372112718, -0.2902846772544621, -0.2961508882436238, -0.3020059493192278, -0.3078496400415348, -0.3136817403988912, -0.3195020308160156, -0.325310292162263, -0.33110630575987626, -0.3368898533922201, -0.3426607173119942, -0.34841868024943456, -0.3541635254204901, -0.3598950365349881, -0.3656129978047736, -0.37131719395183743, -0.3770074102164179, -0.38268343236508967, -0.38834504669882636, -0.39399204006104793, -0.39962419984564684, -0.4052413140049897, -0.4108431710579039, -0.41642956009763693, -0.4220002707997996, -0.4275550934302818, -0.43309381885315185, -0.4386162385385273, -0.4441221445704291, -0.44961132965460665, -0.45508358712634367, -0.46053871095824006, -0.46597649576796596, -0.47139673682599764, -0.47679923006332187, -0.48218377207912266, -0.48755016014843566, -0.4928981922297839, -0.49822766697278154, -0.5035383837257175, -0.5088301425431071, -0.5141027441932216, -0.5193559901655896, -0.5245896826784687, -0.5298036246862946, -0.5349976198870969, -0.5401714727298929, -0.5453249884220461, -0.5504579729366047, -0.555570233019602, -0.5606615761973359, -0.5657318107836132, -0.5707807458869671, -0.5758081914178453, -0.5808139580957643, -0.5857978574564389, -0.5907597018588739, -0.5956993044924332, -0.6006164793838686, -0.6055110414043254, -0.6103828062763095, -0.6152315905806267, -0.6200572117632892, -0.6248594881423862, -0.629638238914927, -0.6343932841636453, -0.6391244448637757, -0.6438315428897913, -0.6485144010221123, -0.6531728429537765, -0.6578066932970785, -0.6624157775901718, -0.6669999223036374, -0.6715589548470184, -0.6760927035753158, -0.680600997795453, -0.6850836677727001, -0.6895405447370668, -0.6939714608896538, -0.6983762494089728, -0.7027547444572251, -0.7071067811865475, -0.7114321957452164, -0.7157308252838185, -0.7200025079613817, -0.7242470829514668, -0.7284643904482252, -0.7326542716724126, -0.7368165688773698, -0.7409511253549589, -0.7450577854414658, -0.749136394523459, -0.7531867990436124, -0.7572088465064842, -0.761202385484262, -0.765167265622459, -0.7691033376455795, -0.7730104533627367, -0.7768884656732326, -0.7807372285720944, -0.784556597155575, -0.7883464276266059, -0.7921065773002124, -0.7958369046088835, -0.7995372691079048, -0.803207531480645, -0.8068475535437992, -0.8104571982525947, -0.8140363297059481, -0.8175848131515838, -0.8211025149911046, -0.8245893027850251, -0.8280450452577555, -0.8314696123025452, -0.83486287498638, -0.8382247055548379, -0.8415549774368986, -0.844853565249707, -0.8481203448032971, -0.8513551931052649, -0.8545579883654006, -0.857728610000272, -0.8608669386377671, -0.8639728561215865, -0.8670462455156926, -0.8700869911087113, -0.8730949784182899, -0.8760700941954067, -0.8790122264286334, -0.8819212643483549, -0.8847970984309376, -0.887639620402854, -0.8904487232447579, -0.8932243011955152, -0.8959662497561849, -0.8986744656939538, -0.9013488470460219, -0.9039892931234431, -0.9065957045149154, -0.9091679830905224, -0.9117060320054298, -0.9142097557035305, -0.9166790599210427, -0.9191138516900577, -0.9215140393420418, -0.9238795325112865, -0.9262102421383114, -0.9285060804732155, -0.9307669610789836, -0.932992798834739, -0.9351835099389476, -0.9373390119125748, -0.9394592236021897, -0.9415440651830208, -0.9435934581619603, -0.9456073253805212, -0.9475855910177412, -0.9495281805930367, -0.9514350209690083, -0.9533060403541938, -0.9551411683057708, -0.9569403357322088, -0.9587034748958715, -0.9604305194155657, -0.9621214042690416, -0.9637760657954398, -0.9653944416976893, -0.9669764710448522, -0.9685220942744173, -0.970031253194544, -0.9715038909862517, -0.9729399522055602, -0.9743393827855759, -0.9757021300385285, -0.9770281426577543, -0.9783173707196277, -0.9795697656854405, -0.9807852804032303, -0.9819638691095554, -0.9831054874312163, -0.984210092386929, -0.9852776423889411, -0.9863080972445987, -0.9873014181578583, -0.9882575677307495, -0.9891765099647809, -0.9900582102622971, -0.99090263542778, -0.9917097536690995, -0.9924795345987101, -0.9932119492347945, -0.9939069700023561, -0.9945645707342554, -0.9951847266721969, -0.9957674144676598, -0.996312612182778, -0.9968202992911657, -0.9972904566786902, -0.9977230666441916, -0.9981181129001492, -0.9984755805732948, -0.9987954562051724, -0.9990777277526454, -0.9993223845883494, -0.9995294175010931, -0.9996988186962042, -0.9998305817958234, -0.9999247018391445, -0.9999811752826011, -1.0, -0.9999811752826011, -0.9999247018391445, -0.9998305817958234, -0.9996988186962042, -0.9995294175010931, -0.9993223845883495, -0.9990777277526454, -0.9987954562051724, -0.9984755805732948, -0.9981181129001492, -0.9977230666441916, -0.9972904566786902, -0.9968202992911657, -0.996312612182778, -0.9957674144676598, -0.9951847266721969, -0.9945645707342554, -0.9939069700023561, -0.9932119492347946, -0.9924795345987101, -0.9917097536690995, -0.99090263542778, -0.9900582102622971, -0.9891765099647809, -0.9882575677307495, -0.9873014181578584, -0.9863080972445988, -0.9852776423889412, -0.9842100923869291, -0.9831054874312164, -0.9819638691095554, -0.9807852804032304, -0.9795697656854406, -0.9783173707196278, -0.9770281426577543, -0.9757021300385286, -0.974339382785576, -0.9729399522055603, -0.9715038909862518, -0.970031253194544, -0.9685220942744174, -0.9669764710448523, -0.9653944416976894, -0.96377606579544, -0.9621214042690417, -0.9604305194155658, -0.9587034748958716, -0.9569403357322089, -0.9551411683057709, -0.9533060403541939, -0.9514350209690084, -0.9495281805930368, -0.9475855910177413, -0.9456073253805213, -0.9435934581619604, -0.9415440651830209, -0.9394592236021898, -0.937339011912575, -0.9351835099389477, -0.9329927988347391, -0.9307669610789837, -0.9285060804732156, -0.9262102421383115, -0.9238795325112866, -0.9215140393420419, -0.9191138516900579, -0.9166790599210428, -0.9142097557035306, -0.9117060320054299, -0.9091679830905225, -0.9065957045149156, -0.9039892931234433, -0.9013488470460221, -0.898674465693954, -0.895966249756185, -0.8932243011955153, -0.890448723244758, -0.8876396204028542, -0.8847970984309377, -0.881921264348355, -0.8790122264286336, -0.8760700941954069, -0.8730949784182901, -0.8700869911087115, -0.8670462455156929, -0.8639728561215866, -0.8608669386377673, -0.8577286100002722, -0.8545579883654008, -0.8513551931052651, -0.8481203448032973, -0.8448535652497072, -0.8415549774368988, -0.838224705554838, -0.8348628749863801, -0.8314696123025455, -0.8280450452577557, -0.8245893027850253, -0.8211025149911049, -0.817584813151584, -0.8140363297059483, -0.8104571982525949, -0.8068475535437994, -0.8032075314806453, -0.799537269107905, -0.7958369046088837, -0.7921065773002126, -0.7883464276266061, -0.7845565971555752, -0.7807372285720946, -0.7768884656732328, -0.7730104533627369, -0.7691033376455797, -0.7651672656224592, -0.7612023854842622, -0.7572088465064846, -0.7531867990436126, -0.7491363945234596, -0.7450577854414658, -0.7409511253549591, -0.73681656887737, -0.7326542716724131, -0.7284643904482251, -0.724247082951467, -0.7200025079613819, -0.715730825283819, -0.7114321957452164, -0.7071067811865477, -0.7027547444572256, -0.6983762494089727, -0.693971460889654, -0.6895405447370672, -0.6850836677727008, -0.680600997795453, -0.676092703575316, -0.6715589548470187, -0.666999922303638, -0.6624157775901718, -0.6578066932970789, -0.6531728429537771, -0.6485144010221123, -0.6438315428897915, -0.639124444863776, -0.6343932841636459, -0.629638238914927, -0.6248594881423865, -0.6200572117632895, -0.6152315905806274, -0.6103828062763095, -0.6055110414043257, -0.6006164793838693, -0.5956993044924332, -0.5907597018588743, -0.5857978574564391, -0.580813958095765, -0.5758081914178452, -0.5707807458869674, -0.5657318107836136, -0.5606615761973366, -0.5555702330196022, -0.550457972936605, -0.5453249884220468, -0.5401714727298927, -0.5349976198870973, -0.5298036246862949, -0.5245896826784694, -0.5193559901655895, -0.5141027441932219, -0.5088301425431074, -0.5035383837257181, -0.49822766697278187, -0.49289819222978426, -0.4875501601484364, -0.4821837720791226, -0.4767992300633222, -0.4713967368259979, -0.4659764957679667, -0.46053871095823995, -0.455083587126344, -0.449611329654607, -0.4441221445704298, -0.43861623853852766, -0.4330938188531522, -0.42755509343028253, -0.42200027079979957, -0.41642956009763726, -0.41084317105790424, -0.4052413140049904, -0.3996241998456468, -0.39399204006104827, -0.3883450466988267, -0.3826834323650904, -0.37700741021641826, -0.3713171939518378, -0.36561299780477435, -0.359895036534988, -0.3541635254204905, -0.3484186802494349, -0.34266071731199493, -0.33688985339222, -0.3311063057598766, -0.32531029216226337, -0.31950203081601547, -0.3136817403988915, -0.30784964004153514, -0.3020059493192286, -0.29615088824362373, -0.2902846772544625, -0.2844075372112722, -0.27851968938505367, -0.272621355449949, -0.2667127574748986, -0.26079411791527596, -0.2548656596045144, -0.2489276057457202, -0.24298017990326418, -0.23702360599436773, -0.231058108280671, -0.22508391135979297, -0.21910124015687016, -0.21311031991609197, -0.20711137619221853, -0.20110463484209212, -0.19509032201612872, -0.18906866414980603, -0.183039887955141, -0.17700422041214905, -0.17096188876030177, -0.1649131204899698, -0.15885814333386158, -0.1527971852584438, -0.1467304744553624, -0.1406582393328492, -0.13458070850712642, -0.12849811079379364, -0.12241067519921603, -0.11631863091190484, -0.11022220729388336, -0.10412163387205513, -0.0980171403295605, -0.09190895649713288, -0.08579731234444028, -0.07968243797143075, -0.07356456359966741, -0.06744391956366429, -0.06132073630220906, -0.055195244349689775, -0.04906767432741809, -0.04293825693494114, -0.036807222941359394, -0.030674803176636543, -0.024541228522912448, -0.018406729905805226, -0.012271538285720572, -0.006135884649154477, ], ];
@@ -0,0 +1 @@
+pub static SQUARE_I16: [[i16; 1024]; 10] = [[0, 30341, 23234, 27439, 24447, 26770, 24871, 26477, 25085, 26313, 25214, 26209, 25301, 26136, 25363, 26083, 25409, 26042, 25445, 26010, 25474, 25984, 25497, 25963, 25517, 25944, 25534, 25929, 25548, 25916, 25560, 25904, 25571, 25894, 25581, 25885, 25589, 25877, 25597, 25870, 25603, 25864, 25610, 25858, 25615, 25853, 25620, 25848, 25625, 25843, 25629, 25839, 25633, 25835, 25637, 25832, 25640, 25829, 25643, 25825, 25646, 25823, 25649, 25820, 25652, 25818, 25654, 25815, 25656, 25813, 25658, 25811, 25660, 25809, 25662, 25807, 25664, 25805, 25666, 25804, 25667, 25802, 25669, 25801, 25670, 25799, 25672, 25798, 25673, 25797, 25674, 25796, 25675, 25794, 25676, 25793, 25678, 25792, 25679, 25791, 25680, 25790, 25681, 25789, 25681, 25788, 25682, 25788, 25683, 25787, 25684, 25786, 25685, 25785, 25685, 25784, 25686, 25784, 25687, 25783, 25687, 25782, 25688, 25782, 25689, 25781, 25689, 25781, 25690, 25780, 25690, 25780, 25691, 25779, 25691, 25779, 25692, 25778, 25692, 25778, 25693, 25777, 25693, 25777, 25694, 25776, 25694, 25776, 25695, 25776, 25695, 25775, 25695, 25775, 25696, 25774, 25696, 25774, 25696, 25774, 25697, 25773, 25697, 25773, 25697, 25773, 25698, 25773, 25698, 25772, 25698, 25772, 25698, 25772, 25699, 25772, 25699, 25771, 25699, 25771, 25699, 25771, 25700, 25771, 25700, 25770, 25700, 25770, 25700, 25770, 25700, 25770, 25701, 25770, 25701, 25770, 25701, 25769, 25701, 25769, 25701, 25769, 25701, 25769, 25701, 25769, 25702, 25769, 25702, 25769, 25702, 25768, 25702, 25768, 25702, 25768, 25702, 25768, 25702, 25768, 25702, 25768, 25702, 25768, 25703, 25768, 25703, 25768, 25703, 25768, 25703, 25768, 25703, 25767, 25703, 25767, 25703, 25767, 25703, 25767, 25703, 25767, 25703, 25767, 25703, 25767, 25703, 25767, 25703, 25767, 25703, 25767, 25703, 25767, 25703, 25767, 25703, 25767, 25703, 25767, 25703, 25767, 25703, 25767, 25703, 25767, 25703, 25767, 25703, 25767, 25703, 25767, 25703, 25767, 25703, 25767, 25703, 25767, 25703, 25767, 25703, 25768, 25703, 25768, 25703, 25768, 25703, 25768, 25703, 25768, 25702, 25768, 25702, 25768, 25702, 25768, 25702, 25768, 25702, 25768, 25702, 25768, 25702, 25769, 25702, 25769, 25702, 25769, 25701, 25769, 25701, 25769, 25701, 25769, 25701, 25769, 25701, 25770, 25701, 25770, 25701, 25770, 25700, 25770, 25700, 25770, 25700, 25770, 25700, 25771, 25700, 25771, 25699, 25771, 25699, 25771, 25699, 25772, 25699, 25772, 25698, 25772, 25698, 25772, 25698, 25773, 25698, 25773, 25697, 25773, 25697, 25773, 25697, 25774, 25696, 25774, 25696, 25774, 25696, 25775, 25695, 25775, 25695, 25776, 25695, 25776, 25694, 25776, 25694, 25777, 25693, 25777, 25693, 25778, 25692, 25778, 25692, 25779, 25691, 25779, 25691, 25780, 25690, 25780, 25690, 25781, 25689, 25781, 25689, 25782, 25688, 25782, 25687, 25783, 25687, 25784, 25686, 25784, 25685, 25785, 25685, 25786, 25684, 25787, 25683, 25788, 25682, 25788, 25681, 25789, 25681, 25790, 25680, 25791, 25679, 25792, 25678, 25793, 25676, 25794, 25675, 25796, 25674, 25797, 25673, 25798, 25672, 25799, 25670, 25801, 25669, 25802, 25667, 25804, 25666, 25805, 25664, 25807, 25662, 25809, 25660, 25811, 25658, 25813, 25656, 25815, 25654, 25818, 25652, 25820, 25649, 25823, 25646, 25825, 25643, 25829, 25640, 25832, 25637, 25835, 25633, 25839, 25629, 25843, 25625, 25848, 25620, 25853, 25615, 25858, 25610, 25864, 25603, 25870, 25597, 25877, 25589, 25885, 25581, 25894, 25571, 25904, 25560, 25916, 25548, 25929, 25534, 25944, 25517, 25963, 25497, 25984, 25474, 26010, 25445, 26042, 25409, 26083, 25363, 26136, 25301, 26209, 25214, 26313, 25085, 26477, 24871, 26770, 24447, 27439, 23234, 30341, 0, -30341, -23234, -27439, -24447, -26770, -24871, -26477, -25085, -26313, -25214, -26209, -25301, -26136, -25363, -26083, -25409, -26042, -25445, -26010, -25474, -25984, -25497, -25963, -25517, -25944, -25534, -25929, -25548, -25916, -25560, -25904, -25571, -25894, -25581, -25885, -25589, -25877, -25597, -25870, -25603, -25864, -25610, -25858, -25615, -25853, -25620, -25848, -25625, -25843, -25629, -25839, -25633, -25835, -25637, -25832, -25640, -25829, -25643, -25825, -25646, -25823, -25649, -25820, -25652, -25818, -25654, -25815, -25656, -25813, -25658, -25811, -25660, -25809, -25662, -25807, -25664, -25805, -25666, -25804, -25667, -25802, -25669, -25801, -25670, -25799, -25672, -25798, -25673, -25797, -25674, -25796, -25675, -25794, -25676, -25793, -25678, -25792, -25679, -25791, -25680, -25790, -25681, -25789, -25681, -25788, -25682, -25788, -25683, -25787, -25684, -25786, -25685, -25785, -25685, -25784, -25686, -25784, -25687, -25783, -25687, -25782, -25688, -25782, -25689, -25781, -25689, -25781, -25690, -25780, -25690, -25780, -25691, -25779, -25691, -25779, -25692, -25778, -25692, -25778, -25693, -25777, -25693, -25777, -25694, -25776, -25694, -25776, -25695, -25776, -25695, -25775, -25695, -25775, -25696, -25774, -25696, -25774, -25696, -25774, -25697, -25773, -25697, -25773, -25697, -25773, -25698, -25773, -25698, -25772, -25698, -25772, -25698, -25772, -25699, -25772, -25699, -25771, -25699, -25771, -25699, -25771, -25700, -25771, -25700, -25770, -25700, -25770, -25700, -25770, -25700, -25770, -25701, -25770, -25701, -25770, -25701, -25769, -25701, -25769, -25701, -25769, -25701, -25769, -25701, -25769, -25702, -25769, -25702, -25769, -25702, -25768, -25702, -25768, -25702, -25768, -25702, -25768, -25702, -25768, -25702, -25768, -25702, -25768, -25703, -25768, -25703, -25768, -25703, -25768, -25703, -25768, -25703, -25767, -25703, -25767, -25703, -25767, -25703, -25767, -25703, -25767, -25703, -25767, -25703, -25767, -25703, -25767, -25703, -25767, -25703, -25767, -25703, -25767, -25703, -25767, -25703, -25767, -25703, -25767, -25703, -25767, -25703, -25767, -25703, -25767, -25703, -25767, -25703, -25767, -25703, -25767, -25703, -25767, -25703, -25767, -25703, -25767, -25703, -25767, -25703, -25768, -25703, -25768, -25703, -25768, -25703, -25768, -25703, -25768, -25702, -25768, -25702, -25768, -25702, -25768, -25702, -25768, -25702, -25768, -25702, -25768, -25702, -25769, -25702, -25769, -25702, -25769, -25701, -25769, -25701, -25769, -25701, -25769, -25701, -25769, -25701, -25770, -25701, -25770, -25701, -25770, -25700, -25770, -25700, -25770, -25700, -25770, -25700, -25771, -25700, -25771, -25699, -25771, -25699, -25771, -25699, -25772, -25699, -25772, -25698, -25772, -25698, -25772, -25698, -25773, -25698, -25773, -25697, -25773, -25697, -25773, -25697, -25774, -25696, -25774, -25696, -25774, -25696, -25775, -25695, -25775, -25695, -25776, -25695, -25776, -25694, -25776, -25694, -25777, -25693, -25777, -25693, -25778, -25692, -25778, -25692, -25779, -25691, -25779, -25691, -25780, -25690, -25780, -25690, -25781, -25689, -25781, -25689, -25782, -25688, -25782, -25687, -25783, -25687, -25784, -25686, -25784, -25685, -25785, -25685, -25786, -25684, -25787, -25683, -25788, -25682, -25788, -25681, -25789, -25681, -25790, -25680, -25791, -25679, -25792, -25678, -25793, -25676, -25794, -25675, -25796, -25674, -25797, -25673, -25798, -25672, -25799, -25670, -25801, -25669, -25802, -25667, -25804, -25666, -25805, -25664, -25807, -25662, -25809, -25660, -25811, -25658, -25813, -25656, -25815, -25654, -25818, -25652, -25820, -25649, -25823, -25646, -25825, -25643, -25829, -25640, -25832, -25637, -25835, -25633, -25839, -25629, -25843, -25625, -25848, -25620, -25853, -25615, -25858, -25610, -25864, -25603, -25870, -25597, -25877, -25589, -25885, -25581, -25894, -25571, -25904, -25560, -25916, -25548, -25929, -25534, -25944, -25517, -25963, -25497, -25984, -25474, -26010, -25445, -26042, -25409, -26083, -25363, -26136, -25301, -26209, -25214, -26313, -25085, -26477, -24871, -26770, -24447, -27439, -23234, -30341, ], [0, 22458, 30341, 26351, 23234, 25490, 27439, 25865, 24446, 25655, 26771, 25789, 24870, 25696, 26478, 25764, 25084, 25712, 26314, 25753, 25213, 25720, 26210, 25748, 25300, 25725, 26138, 25744, 25361, 25727, 26084, 25742, 25407, 25729, 26044, 25741, 25443, 25730, 26012, 25739, 25472, 25731, 25986, 25739, 25495, 25732, 25965, 25738, 25515, 25732, 25947, 25738, 25531, 25733, 25932, 25737, 25545, 25733, 25919, 25737, 25557, 25733, 25907, 25737, 25568, 25734, 25898, 25737, 25577, 25734, 25889, 25736, 25585, 25734, 25881, 25736, 25593, 25734, 25874, 25736, 25599, 25734, 25868, 25736, 25605, 25734, 25862, 25736, 25611, 25734, 25857, 25736, 25616, 25734, 25852, 25736, 25620, 25734, 25848, 25736, 25624, 25735, 25844, 25736, 25628, 25735, 25841, 25736, 25631, 25735, 25838, 25736, 25634, 25735, 25835, 25736, 25637, 25735, 25832, 25736, 25640, 25735, 25829, 25736, 25642, 25735, 25827, 25736, 25645, 25735, 25825, 25735, 25647, 25735, 25822, 25735, 25649, 25735, 25821, 25735, 25651, 25735, 25819, 25735, 25652, 25735, 25817, 25735, 25654, 25735, 25816, 25735, 25655, 25735, 25814, 25735, 25657, 25735, 25813, 25735, 25658, 25735, 25811, 25735, 25659, 25735, 25810, 25735, 25661, 25735, 25809, 25735, 25662, 25735, 25808, 25735, 25663, 25735, 25807, 25735, 25663, 25735, 25806, 25735, 25664, 25735, 25806, 25735, 25665, 25735, 25805, 25735, 25666, 25735, 25804, 25735, 25667, 25735, 25803, 25735, 25667, 25735, 25803, 25735, 25668, 25735, 25802, 25735, 25668, 25735, 25802, 25735, 25669, 25735, 25801, 25735, 25669, 25735, 25801, 25735, 25670, 25735, 25801, 25735, 25670, 25735, 25800, 25735, 25670, 25735, 25800, 25735, 25670, 25735, 25800, 25735, 25671, 25735, 25800, 25735, 25671, 25735, 25799, 25735, 25671, 25735, 25799, 25735, 25671, 25735, 25799, 25735, 25671, 25735, 25799, 25735, 25671, 25735, 25799, 25735, 25671, 25735, 25799, 25735, 25671, 25735, 25799, 25735, 25671, 25735, 25799, 25735, 25671, 25735, 25800, 25735, 25671, 25735, 25800, 25735, 25670, 25735, 25800, 25735, 25670, 25735, 25800, 25735, 25670, 25735, 25801, 25735, 25670, 25735, 25801, 25735, 25669, 25735, 25801, 25735, 25669, 25735, 25802, 25735, 25668, 25735, 25802, 25735, 25668, 25735, 25803, 25735, 25667, 25735, 25803, 25735, 25667, 25735, 25804, 25735, 25666, 25735, 25805, 25735, 25665, 25735, 25806, 25735, 25664, 25735, 25806, 25735, 25663, 25735, 25807, 25735, 25663, 25735, 25808, 25735, 25662, 25735, 25809, 25735, 25661, 25735, 25810, 25735, 25659, 25735, 25811, 25735, 25658, 25735, 25813, 25735, 25657, 25735, 25814, 25735, 25655, 25735, 25816, 25735, 25654, 25735, 25817, 25735, 25652, 25735, 25819, 25735, 25651, 25735, 25821, 25735, 25649, 25735, 25822, 25735, 25647, 25735, 25825, 25735, 25645, 25736, 25827, 25735, 25642, 25736, 25829, 25735, 25640, 25736, 25832, 25735, 25637, 25736, 25835, 25735, 25634, 25736, 25838, 25735, 25631, 25736, 25841, 25735, 25628, 25736, 25844, 25735, 25624, 25736, 25848, 25734, 25620, 25736, 25852, 25734, 25616, 25736, 25857, 25734, 25611, 25736, 25862, 25734, 25605, 25736, 25868, 25734, 25599, 25736, 25874, 25734, 25593, 25736, 25881, 25734, 25585, 25736, 25889, 25734, 25577, 25737, 25898, 25734, 25568, 25737, 25907, 25733, 25557, 25737, 25919, 25733, 25545, 25737, 25932, 25733, 25531, 25738, 25947, 25732, 25515, 25738, 25965, 25732, 25495, 25739, 25986, 25731, 25472, 25739, 26012, 25730, 25443, 25741, 26044, 25729, 25407, 25742, 26084, 25727, 25361, 25744, 26138, 25725, 25300, 25748, 26210, 25720, 25213, 25753, 26314, 25712, 25084, 25764, 26478, 25696, 24870, 25789, 26771, 25655, 24446, 25865, 27439, 25490, 23234, 26351, 30341, 22458, 0, -22458, -30341, -26351, -23234, -25490, -27439, -25865, -24446, -25655, -26771, -25789, -24870, -25696, -26478, -25764, -25084, -25712, -26314, -25753, -25213, -25720, -26210, -25748, -25300, -25725, -26138, -25744, -25361, -25727, -26084, -25742, -25407, -25729, -26044, -25741, -25443, -25730, -26012, -25739, -25472, -25731, -25986, -25739, -25495, -25732, -25965, -25738, -25515, -25732, -25947, -25738, -25531, -25733, -25932, -25737, -25545, -25733, -25919, -25737, -25557, -25733, -25907, -25737, -25568, -25734, -25898, -25737, -25577, -25734, -25889, -25736, -25585, -25734, -25881, -25736, -25593, -25734, -25874, -25736, -25599, -25734, -25868, -25736, -25605, -25734, -25862, -25736, -25611, -25734, -25857, -25736, -25616, -25734, -25852, -25736, -25620, -25734, -25848, -25736, -25624, -25735, -25844, -25736, -25628, -25735, -25841, -25736, -25631, -25735, -25838, -25736, -25634, -25735, -25835, -25736, -25637, -25735, -25832, -25736, -25640, -25735, -25829, -25736, -25642, -25735, -25827, -25736, -25645, -25735, -25825, -25735, -25647, -25735, -25822, -25735, -25649, -25735, -25821, -25735, -25651, -25735, -25819, -25735, -25652, -25735, -25817, -25735, -25654, -25735, -25816, -25735, -25655, -25735, -25814, -25735, -25657, -25735, -25813, -25735, -25658, -25735, -25811, -25735, -25659, -25735, -25810, -25735, -25661, -25735, -25809, -25735, -25662, -25735, -25808, -25735, -25663, -25735, -25807, -25735, -25663, -25735, -25806, -25735, -25664, -25735, -25806, -25735, -25665, -25735, -25805, -25735, -25666, -25735, -25804, -25735, -25667, -25735, -25803, -25735, -25667, -25735, -25803, -25735, -25668, -25735, -25802, -25735, -25668, -25735, -25802, -25735, -25669, -25735, -25801, -25735, -25669, -25735, -25801, -25735, -25670, -25735, -25801, -25735, -25670, -25735, -25800, -25735, -25670, -25735, -25800, -25735, -25670, -25735, -25800, -25735, -25671, -25735, -25800, -25735, -25671, -25735, -25799, -25735, -25671, -25735, -25799, -25735, -25671, -25735, -25799, -25735, -25671, -25735, -25799, -25735, -25671, -25735, -25799, -25735, -25671, -25735, -25799, -25735, -25671, -25735, -25799, -25735, -25671, -25735, -25799, -25735, -25671, -25735, -25800, -25735, -25671, -25735, -25800, -25735, -25670, -25735, -25800, -25735, -25670, -25735, -25800, -25735, -25670, -25735, -25801, -25735, -25670, -25735, -25801, -25735, -25669, -25735, -25801, -25735, -25669, -25735, -25802, -25735, -25668, -25735, -25802, -25735, -25668, -25735, -25803, -25735, -25667, -25735, -25803, -25735, -25667, -25735, -25804, -25735, -25666, -25735, -25805, -25735, -25665, -25735, -25806, -25735, -25664, -25735, -25806, -25735, -25663, -25735, -25807, -25735, -25663, -25735, -25808, -25735, -25662, -25735, -25809, -25735, -25661, -25735, -25810, -25735, -25659, -25735, -25811, -25735, -25658, -25735, -25813, -25735, -25657, -25735, -25814, -25735, -25655, -25735, -25816, -25735, -25654, -25735, -25817, -25735, -25652, -25735, -25819, -25735, -25651, -25735, -25821, -25735, -25649, -25735, -25822, -25735, -25647, -25735, -25825, -25735, -25645, -25736, -25827, -25735, -25642, -25736, -25829, -25735, -25640, -25736, -25832, -25735, -25637, -25736, -25835, -25735, -25634, -25736, -25838, -25735, -25631, -25736, -25841, -25735, -25628, -25736, -25844, -25735, -25624, -25736, -25848, -25734, -25620, -25736, -25852, -25734, -25616, -25736, -25857, -25734, -25611, -25736, -25862, -25734, -25605, -25736, -25868, -25734, -25599, -25736, -25874, -25734, -25593, -25736, -25881, -25734, -25585, -25736, -25889, -25734, -25577, -25737, -25898, -25734, -25568, -25737, -25907, -25733, -25557, -25737, -25919, -25733, -25545, -25737, -25932, -25733, -25531, -25738, -25947, -25732, -25515, -25738, -25965, -25732, -25495, -25739, -25986, -25731, -25472, -25739, -26012, -25730, -25443, -25741, -26044, -25729, -25407, -25742, -26084, -25727, -25361, -25744, -26138, -25725, -25300, -25748, -26210, -25720, -25213, -25753, -26314, -25712, -25084, -25764, -26478, -25696, -24870, -25789, -26771, -25655, -24446, -25865, -27439, -25490, -23234, -26351, -30341, -22458, ], [0, 12435, 22458, 28504, 30342, 29027, 26351, 24067, 23233, 23940, 25490, 26901, 27440, 26957, 25865, 24844, 24445, 24812, 25656, 26456, 26773, 26476, 25789, 25130, 24868, 25116, 25697, 26256, 26481, 26267, 25764, 25277, 25081, 25269, 25713, 26144, 26318, 26150, 25753, 25366, 25209, 25361, 25720, 26072, 26214, 26076, 25747, 25426, 25295, 25422, 25725, 26022, 26143, 26025, 25744, 25468, 25356, 25466, 25727, 25985, 26090, 25987, 25742, 25500, 25401, 25498, 25729, 25957, 26051, 25959, 25740, 25524, 25436, 25523, 25730, 25936, 26020, 25937, 25739, 25544, 25464, 25543, 25731, 25918, 25995, 25919, 25739, 25560, 25486, 25559, 25732, 25904, 25974, 25905, 25738, 25573, 25505, 25572, 25733, 25892, 25957, 25893, 25738, 25584, 25520, 25583, 25733, 25882, 25943, 25883, 25737, 25593, 25533, 25592, 25733, 25873, 25931, 25874, 25737, 25601, 25545, 25600, 25734, 25866, 25921, 25867, 25737, 25607, 25554, 25607, 25734, 25860, 25912, 25860, 25736, 25613, 25562, 25613, 25734, 25854, 25904, 25855, 25736, 25618, 25570, 25618, 25734, 25850, 25897, 25850, 25736, 25623, 25576, 25622, 25734, 25846, 25892, 25846, 25736, 25626, 25581, 25626, 25734, 25842, 25887, 25842, 25736, 25630, 25586, 25630, 25734, 25839, 25882, 25839, 25736, 25633, 25590, 25632, 25735, 25836, 25878, 25837, 25736, 25635, 25594, 25635, 25735, 25834, 25875, 25834, 25736, 25637, 25597, 25637, 25735, 25832, 25872, 25832, 25736, 25639, 25599, 25639, 25735, 25830, 25870, 25831, 25735, 25641, 25601, 25641, 25735, 25829, 25868, 25829, 25735, 25642, 25603, 25642, 25735, 25828, 25866, 25828, 25735, 25643, 25605, 25643, 25735, 25827, 25865, 25827, 25735, 25644, 25606, 25644, 25735, 25826, 25864, 25826, 25735, 25644, 25607, 25644, 25735, 25826, 25863, 25826, 25735, 25645, 25607, 25645, 25735, 25826, 25863, 25826, 25735, 25645, 25607, 25645, 25735, 25826, 25863, 25826, 25735, 25645, 25607, 25645, 25735, 25826, 25863, 25826, 25735, 25644, 25607, 25644, 25735, 25826, 25864, 25826, 25735, 25644, 25606, 25644, 25735, 25827, 25865, 25827, 25735, 25643, 25605, 25643, 25735, 25828, 25866, 25828, 25735, 25642, 25603, 25642, 25735, 25829, 25868, 25829, 25735, 25641, 25601, 25641, 25735, 25831, 25870, 25830, 25735, 25639, 25599, 25639, 25736, 25832, 25872, 25832, 25735, 25637, 25597, 25637, 25736, 25834, 25875, 25834, 25735, 25635, 25594, 25635, 25736, 25837, 25878, 25836, 25735, 25632, 25590, 25633, 25736, 25839, 25882, 25839, 25734, 25630, 25586, 25630, 25736, 25842, 25887, 25842, 25734, 25626, 25581, 25626, 25736, 25846, 25892, 25846, 25734, 25622, 25576, 25623, 25736, 25850, 25897, 25850, 25734, 25618, 25570, 25618, 25736, 25855, 25904, 25854, 25734, 25613, 25562, 25613, 25736, 25860, 25912, 25860, 25734, 25607, 25554, 25607, 25737, 25867, 25921, 25866, 25734, 25600, 25545, 25601, 25737, 25874, 25931, 25873, 25733, 25592, 25533, 25593, 25737, 25883, 25943, 25882, 25733, 25583, 25520, 25584, 25738, 25893, 25957, 25892, 25733, 25572, 25505, 25573, 25738, 25905, 25974, 25904, 25732, 25559, 25486, 25560, 25739, 25919, 25995, 25918, 25731, 25543, 25464, 25544, 25739, 25937, 26020, 25936, 25730, 25523, 25436, 25524, 25740, 25959, 26051, 25957, 25729, 25498, 25401, 25500, 25742, 25987, 26090, 25985, 25727, 25466, 25356, 25468, 25744, 26025, 26143, 26022, 25725, 25422, 25295, 25426, 25747, 26076, 26214, 26072, 25720, 25361, 25209, 25366, 25753, 26150, 26318, 26144, 25713, 25269, 25081, 25277, 25764, 26267, 26481, 26256, 25697, 25116, 24868, 25130, 25789, 26476, 26773, 26456, 25656, 24812, 24445, 24844, 25865, 26957, 27440, 26901, 25490, 23940, 23233, 24067, 26351, 29027, 30342, 28504, 22458, 12435, 0, -12435, -22458, -28504, -30342, -29027, -26351, -24067, -23233, -23940, -25490, -26901, -27440, -26957, -25865, -24844, -24445, -24812, -25656, -26456, -26773, -26476, -25789, -25130, -24868, -25116, -25697, -26256, -26481, -26267, -25764, -25277, -25081, -25269, -25713, -26144, -26318, -26150, -25753, -25366, -25209, -25361, -25720, -26072, -26214, -26076, -25747, -25426, -25295, -25422, -25725, -26022, -26143, -26025, -25744, -25468, -25356, -25466, -25727, -25985, -26090, -25987, -25742, -25500, -25401, -25498, -25729, -25957, -26051, -25959, -25740, -25524, -25436, -25523, -25730, -25936, -26020, -25937, -25739, -25544, -25464, -25543, -25731, -25918, -25995, -25919, -25739, -25560, -25486, -25559, -25732, -25904, -25974, -25905, -25738, -25573, -25505, -25572, -25733, -25892, -25957, -25893, -25738, -25584, -25520, -25583, -25733, -25882, -25943, -25883, -25737, -25593, -25533, -25592, -25733, -25873, -25931, -25874, -25737, -25601, -25545, -25600, -25734, -25866, -25921, -25867, -25737, -25607, -25554, -25607, -25734, -25860, -25912, -25860, -25736, -25613, -25562, -25613, -25734, -25854, -25904, -25855, -25736, -25618, -25570, -25618, -25734, -25850, -25897, -25850, -25736, -25623, -25576, -25622, -25734, -25846, -25892, -25846, -25736, -25626, -25581, -25626, -25734, -25842, -25887, -25842, -25736, -25630, -25586, -25630, -25734, -25839, -25882, -25839, -25736, -25633, -25590, -25632, -25735, -25836, -25878, -25837, -25736, -25635, -25594, -25635, -25735, -25834, -25875, -25834, -25736, -25637, -25597, -25637, -25735, -25832, -25872, -25832, -25736, -25639, -25599, -25639, -25735, -25830, -25870, -25831, -25735, -25641, -25601, -25641, -25735, -25829, -25868, -25829, -25735, -25642, -25603, -25642, -25735, -25828, -25866, -25828, -25735, -25643, -25605, -25643, -25735, -25827, -25865, -25827, -25735, -25644, -25606, -25644, -25735, -25826, -25864, -25826, -25735, -25644, -25607, -25644, -25735, -25826, -25863, -25826, -25735, -25645, -25607, -25645, -25735, -25826, -25863, -25826, -25735, -25645, -25607, -25645, -25735, -25826, -25863, -25826, -25735, -25645, -25607, -25645, -25735, -25826, -25863, -25826, -25735, -25644, -25607, -25644, -25735, -25826, -25864, -25826, -25735, -25644, -25606, -25644, -25735, -25827, -25865, -25827, -25735, -25643, -25605, -25643, -25735, -25828, -25866, -25828, -25735, -25642, -25603, -25642, -25735, -25829, -25868, -25829, -25735, -25641, -25601, -25641, -25735, -25831, -25870, -25830, -25735, -25639, -25599, -25639, -25736, -25832, -25872, -25832, -25735, -25637, -25597, -25637, -25736, -25834, -25875, -25834, -25735, -25635, -25594, -25635, -25736, -25837, -25878, -25836, -25735, -25632, -25590, -25633, -25736, -25839, -25882, -25839, -25734, -25630, -25586, -25630, -25736, -25842, -25887, -25842, -25734, -25626, -25581, -25626, -25736, -25846, -25892, -25846, -25734, -25622, -25576, -25623, -25736, -25850, -25897, -25850, -25734, -25618, -25570, -25618, -25736, -25855, -25904, -25854, -25734, -25613, -25562, -25613, -25736, -25860, -25912, -25860, -25734, -25607, -25554, -25607, -25737, -25867, -25921, -25866, -25734, -25600, -25545, -25601, -25737, -25874, -25931, -25873, -25733, -25592, -25533, -25593, -25737, -25883, -25943, -25882, -25733, -25583, -25520, -25584, -25738, -25893, -25957, -25892, -25733, -25572, -25505, -25573, -25738, -25905, -25974, -25904, -25732, -25559, -25486, -25560, -25739, -25919, -25995, -25918, -25731, -25543, -25464, -25544, -25739, -25937, -26020, -25936, -25730, -25523, -25436, -25524, -25740, -25959, -26051, -25957, -25729, -25498, -25401, -25500, -25742, -25987, -26090, -25985, -25727, -25466, -25356, -25468, -25744, -26025, -26143, -26022, -25725, -25422, -25295, -25426, -25747, -26076, -26214, -26072, -25720, -25361, -25209, -25366, -25753, -26150, -26318, -26144, -25713, -25269, -25081, -25277, -25764, -26267, -26481, -26256, -25697, -25116, -24868, -25130, -25789, -26476, -26773, -26456, -25656, -24812, -24445, -24844, -25865, -26957, -27440, -26901, -25490, -23940, -23233, -24067, -26351, -29027, -30342, -28504, -22458, -12435, ], [0, 6379, 12435, 17874, 22459, 26027, 28505, 29910, 30343, 29976, 29028, 27740, 26350, 25070, 24064, 23438, 23230, 23421, 23938, 24670, 25491, 26272, 26904, 27308, 27445, 27315, 26960, 26447, 25864, 25301, 24839, 24540, 24438, 24536, 24808, 25203, 25656, 26097, 26462, 26699, 26781, 26702, 26482, 26160, 25788, 25425, 25123, 24926, 24858, 24924, 25110, 25382, 25697, 26007, 26264, 26433, 26492, 26435, 26274, 26038, 25764, 25494, 25268, 25120, 25068, 25119, 25260, 25470, 25713, 25953, 26154, 26286, 26332, 26287, 26160, 25972, 25753, 25536, 25355, 25235, 25193, 25234, 25350, 25521, 25721, 25919, 26084, 26194, 26232, 26195, 26088, 25931, 25747, 25565, 25412, 25310, 25275, 25310, 25408, 25554, 25725, 25895, 26037, 26131, 26164, 26132, 26040, 25903, 25743, 25585, 25451, 25363, 25332, 25363, 25449, 25578, 25728, 25878, 26003, 26087, 26116, 26087, 26005, 25884, 25741, 25600, 25480, 25401, 25373, 25401, 25479, 25594, 25730, 25865, 25978, 26054, 26080, 26054, 25980, 25869, 25740, 25611, 25502, 25429, 25404, 25429, 25501, 25607, 25731, 25855, 25960, 26029, 26054, 26030, 25961, 25859, 25739, 25619, 25518, 25451, 25427, 25451, 25517, 25616, 25732, 25848, 25946, 26011, 26033, 26011, 25946, 25851, 25738, 25625, 25530, 25467, 25445, 25467, 25530, 25623, 25733, 25842, 25935, 25997, 26018, 25997, 25936, 25844, 25737, 25630, 25540, 25479, 25458, 25479, 25539, 25628, 25733, 25838, 25927, 25986, 26007, 25986, 25928, 25840, 25737, 25633, 25546, 25488, 25468, 25488, 25546, 25632, 25734, 25836, 25922, 25979, 25999, 25979, 25922, 25837, 25736, 25636, 25551, 25494, 25474, 25494, 25551, 25635, 25734, 25834, 25918, 25974, 25994, 25974, 25918, 25834, 25736, 25637, 25553, 25498, 25478, 25498, 25553, 25637, 25735, 25833, 25916, 25972, 25991, 25972, 25916, 25833, 25735, 25637, 25554, 25499, 25479, 25499, 25554, 25637, 25735, 25833, 25916, 25972, 25991, 25972, 25916, 25833, 25735, 25637, 25553, 25498, 25478, 25498, 25553, 25637, 25736, 25834, 25918, 25974, 25994, 25974, 25918, 25834, 25734, 25635, 25551, 25494, 25474, 25494, 25551, 25636, 25736, 25837, 25922, 25979, 25999, 25979, 25922, 25836, 25734, 25632, 25546, 25488, 25468, 25488, 25546, 25633, 25737, 25840, 25928, 25986, 26007, 25986, 25927, 25838, 25733, 25628, 25539, 25479, 25458, 25479, 25540, 25630, 25737, 25844, 25936, 25997, 26018, 25997, 25935, 25842, 25733, 25623, 25530, 25467, 25445, 25467, 25530, 25625, 25738, 25851, 25946, 26011, 26033, 26011, 25946, 25848, 25732, 25616, 25517, 25451, 25427, 25451, 25518, 25619, 25739, 25859, 25961, 26030, 26054, 26029, 25960, 25855, 25731, 25607, 25501, 25429, 25404, 25429, 25502, 25611, 25740, 25869, 25980, 26054, 26080, 26054, 25978, 25865, 25730, 25594, 25479, 25401, 25373, 25401, 25480, 25600, 25741, 25884, 26005, 26087, 26116, 26087, 26003, 25878, 25728, 25578, 25449, 25363, 25332, 25363, 25451, 25585, 25743, 25903, 26040, 26132, 26164, 26131, 26037, 25895, 25725, 25554, 25408, 25310, 25275, 25310, 25412, 25565, 25747, 25931, 26088, 26195, 26232, 26194, 26084, 25919, 25721, 25521, 25350, 25234, 25193, 25235, 25355, 25536, 25753, 25972, 26160, 26287, 26332, 26286, 26154, 25953, 25713, 25470, 25260, 25119, 25068, 25120, 25268, 25494, 25764, 26038, 26274, 26435, 26492, 26433, 26264, 26007, 25697, 25382, 25110, 24924, 24858, 24926, 25123, 25425, 25788, 26160, 26482, 26702, 26781, 26699, 26462, 26097, 25656, 25203, 24808, 24536, 24438, 24540, 24839, 25301, 25864, 26447, 26960, 27315, 27445, 27308, 26904, 26272, 25491, 24670, 23938, 23421, 23230, 23438, 24064, 25070, 26350, 27740, 29028, 29976, 30343, 29910, 28505, 26027, 22459, 17874, 12435, 6379, 0, -6379, -12435, -17874, -22459, -26027, -28505, -29910, -30343, -29976, -29028, -27740, -26350, -25070, -24064, -23438, -23230, -23421, -23938, -24670, -25491, -26272, -26904, -27308, -27445, -27315, -26960, -26447, -25864, -25301, -24839, -24540, -24438, -24536, -24808, -25203, -25656, -26097, -26462, -26699, -26781, -26702, -26482, -26160, -25788, -25425, -25123, -24926, -24858, -24924, -25110, -25382, -25697, -26007, -26264, -26433, -26492, -26435, -26274, -26038, -25764, -25494, -25268, -25120, -25068, -25119, -25260, -25470, -25713, -25953, -26154, -26286, -26332, -26287, -26160, -25972, -25753, -25536, -25355, -25235, -25193, -25234, -25350, -25521, -25721, -25919, -26084, -26194, -26232, -26195, -26088, -25931, -25747, -25565, -25412, -25310, -25275, -25310, -25408, -25554, -25725, -25895, -26037, -26131, -26164, -26132, -26040, -25903, -25743, -25585, -25451, -25363, -25332, -25363, -25449, -25578, -25728, -25878, -26003, -26087, -26116, -26087, -26005, -25884, -25741, -25600, -25480, -25401, -25373, -25401, -25479, -25594, -25730, -25865, -25978, -26054, -26080, -26054, -25980, -25869, -25740, -25611, -25502, -25429, -25404, -25429, -25501, -25607, -25731, -25855, -25960, -26029, -26054, -26030, -25961, -25859, -25739, -25619, -25518, -25451, -25427, -25451, -25517, -25616, -25732, -25848, -25946, -26011, -26033, -26011, -25946, -25851, -25738, -25625, -25530, -25467, -25445, -25467, -25530, -25623, -25733, -25842, -25935, -25997, -26018, -25997, -25936, -25844, -25737, -25630, -25540, -25479, -25458, -25479, -25539, -25628, -25733, -25838, -25927, -25986, -26007, -25986, -25928, -25840, -25737, -25633, -25546, -25488, -25468, -25488, -25546, -25632, -25734, -25836, -25922, -25979, -25999, -25979, -25922, -25837, -25736, -25636, -25551, -25494, -25474, -25494, -25551, -25635, -25734, -25834, -25918, -25974, -25994, -25974, -25918, -25834, -25736, -25637, -25553, -25498, -25478, -25498, -25553, -25637, -25735, -25833, -25916, -25972, -25991, -25972, -25916, -25833, -25735, -25637, -25554, -25499, -25479, -25499, -25554, -25637, -25735, -25833, -25916, -25972, -25991, -25972, -25916, -25833, -25735, -25637, -25553, -25498, -25478, -25498, -25553, -25637, -25736, -25834, -25918, -25974, -25994, -25974, -25918, -25834, -25734, -25635, -25551, -25494, -25474, -25494, -25551, -25636, -25736, -25837, -25922, -25979, -25999, -25979, -25922, -25836, -25734, -25632, -25546, -25488, -25468, -25488, -25546, -25633, -25737, -25840, -25928, -25986, -26007, -25986, -25927, -25838, -25733, -25628, -25539, -25479, -25458, -25479, -25540, -25630, -25737, -25844, -25936, -25997, -26018, -25997, -25935, -25842, -25733, -25623, -25530, -25467, -25445, -25467, -25530, -25625, -25738, -25851, -25946, -26011, -26033, -26011, -25946, -25848, -25732, -25616, -25517, -25451, -25427, -25451, -25518, -25619, -25739, -25859, -25961, -26030, -26054, -26029, -25960, -25855, -25731, -25607, -25501, -25429, -25404, -25429, -25502, -25611, -25740, -25869, -25980, -26054, -26080, -26054, -25978, -25865, -25730, -25594, -25479, -25401, -25373, -25401, -25480, -25600, -25741, -25884, -26005, -26087, -26116, -26087, -26003, -25878, -25728, -25578, -25449, -25363, -25332, -25363, -25451, -25585, -25743, -25903, -26040, -26132, -26164, -26131, -26037, -25895, -25725, -25554, -25408, -25310, -25275, -25310, -25412, -25565, -25747, -25931, -26088, -26195, -26232, -26194, -26084, -25919, -25721, -25521, -25350, -25234, -25193, -25235, -25355, -25536, -25753, -25972, -26160, -26287, -26332, -26286, -26154, -25953, -25713, -25470, -25260, -25119, -25068, -25120, -25268, -25494, -25764, -26038, -26274, -26435, -26492, -26433, -26264, -26007, -25697, -25382, -25110, -24924, -24858, -24926, -25123, -25425, -25788, -26160, -26482, -26702, -26781, -26699, -26462, -26097, -25656, -25203, -24808, -24536, -24438, -24540, -24839, -25301, -25864, -26447, -26960, -27315, -27445, -27308, -26904, -26272, -25491, -24670, -23938, -23421, -23230, -23438, -24064, -25070, -26350, -27740, -29028, -29976, -30343, -29910, -28505, -26027, -22459, -17874, -12435, -6379, ], [0, 3210, 6379, 9467, 12435, 15248, 17875, 20287, 22461, 24379, 26030, 27407, 28509, 29342, 29916, 30245, 30350, 30253, 29982, 29565, 29032, 28414, 27741, 27043, 26348, 25681, 25064, 24517, 24055, 23689, 23426, 23269, 23217, 23267, 23409, 23634, 23929, 24278, 24666, 25076, 25493, 25899, 26280, 26624, 26918, 27154, 27326, 27429, 27464, 27430, 27333, 27178, 26973, 26728, 26454, 26161, 25862, 25568, 25290, 25038, 24821, 24645, 24517, 24439, 24413, 24438, 24513, 24632, 24790, 24980, 25194, 25423, 25658, 25890, 26111, 26311, 26485, 26626, 26729, 26792, 26813, 26792, 26732, 26634, 26504, 26348, 26171, 25981, 25786, 25593, 25409, 25241, 25095, 24977, 24889, 24836, 24818, 24836, 24888, 24971, 25082, 25216, 25368, 25531, 25699, 25866, 26026, 26171, 26298, 26401, 26477, 26524, 26539, 26524, 26478, 26405, 26307, 26189, 26055, 25910, 25761, 25613, 25471, 25342, 25229, 25137, 25069, 25027, 25013, 25027, 25068, 25134, 25222, 25329, 25450, 25581, 25716, 25850, 25979, 26097, 26199, 26283, 26345, 26383, 26396, 26383, 26346, 26285, 26204, 26106, 25995, 25874, 25750, 25626, 25507, 25398, 25303, 25225, 25168, 25132, 25121, 25132, 25167, 25223, 25299, 25391, 25495, 25607, 25724, 25840, 25952, 26054, 26143, 26216, 26270, 26304, 26315, 26304, 26271, 26218, 26146, 26059, 25961, 25854, 25743, 25633, 25527, 25430, 25345, 25276, 25224, 25192, 25182, 25192, 25224, 25275, 25343, 25426, 25521, 25623, 25729, 25835, 25937, 26030, 26112, 26179, 26229, 26259, 26270, 26259, 26229, 26180, 26114, 26033, 25942, 25842, 25739, 25636, 25538, 25447, 25367, 25302, 25253, 25224, 25214, 25224, 25253, 25301, 25366, 25445, 25534, 25632, 25733, 25834, 25931, 26020, 26098, 26162, 26210, 26239, 26249, 26239, 26210, 26162, 26099, 26021, 25932, 25836, 25736, 25636, 25540, 25451, 25374, 25310, 25263, 25233, 25224, 25233, 25263, 25310, 25374, 25451, 25540, 25636, 25736, 25836, 25932, 26021, 26099, 26162, 26210, 26239, 26249, 26239, 26210, 26162, 26098, 26020, 25931, 25834, 25733, 25632, 25534, 25445, 25366, 25301, 25253, 25224, 25214, 25224, 25253, 25302, 25367, 25447, 25538, 25636, 25739, 25842, 25942, 26033, 26114, 26180, 26229, 26259, 26270, 26259, 26229, 26179, 26112, 26030, 25937, 25835, 25729, 25623, 25521, 25426, 25343, 25275, 25224, 25192, 25182, 25192, 25224, 25276, 25345, 25430, 25527, 25633, 25743, 25854, 25961, 26059, 26146, 26218, 26271, 26304, 26315, 26304, 26270, 26216, 26143, 26054, 25952, 25840, 25724, 25607, 25495, 25391, 25299, 25223, 25167, 25132, 25121, 25132, 25168, 25225, 25303, 25398, 25507, 25626, 25750, 25874, 25995, 26106, 26204, 26285, 26346, 26383, 26396, 26383, 26345, 26283, 26199, 26097, 25979, 25850, 25716, 25581, 25450, 25329, 25222, 25134, 25068, 25027, 25013, 25027, 25069, 25137, 25229, 25342, 25471, 25613, 25761, 25910, 26055, 26189, 26307, 26405, 26478, 26524, 26539, 26524, 26477, 26401, 26298, 26171, 26026, 25866, 25699, 25531, 25368, 25216, 25082, 24971, 24888, 24836, 24818, 24836, 24889, 24977, 25095, 25241, 25409, 25593, 25786, 25981, 26171, 26348, 26504, 26634, 26732, 26792, 26813, 26792, 26729, 26626, 26485, 26311, 26111, 25890, 25658, 25423, 25194, 24980, 24790, 24632, 24513, 24438, 24413, 24439, 24517, 24645, 24821, 25038, 25290, 25568, 25862, 26161, 26454, 26728, 26973, 27178, 27333, 27430, 27464, 27429, 27326, 27154, 26918, 26624, 26280, 25899, 25493, 25076, 24666, 24278, 23929, 23634, 23409, 23267, 23217, 23269, 23426, 23689, 24055, 24517, 25064, 25681, 26348, 27043, 27741, 28414, 29032, 29565, 29982, 30253, 30350, 30245, 29916, 29342, 28509, 27407, 26030, 24379, 22461, 20287, 17875, 15248, 12435, 9467, 6379, 3210, 0, -3210, -6379, -9467, -12435, -15248, -17875, -20287, -22461, -24379, -26030, -27407, -28509, -29342, -29916, -30245, -30350, -30253, -29982, -29565, -29032, -28414, -27741, -27043, -26348, -25681, -25064, -24517, -24055, -23689, -23426, -23269, -23217, -23267, -23409, -23634, -23929, -24278, -24666, -25076, -25493, -25899, -26280, -26624, -26918, -27154, -27326, -27429, -27464, -27430, -27333, -27178, -26973, -26728, -26454, -26161, -25862, -25568, -25290, -25038, -24821, -24645, -24517, -24439, -24413, -24438, -24513, -24632, -24790, -24980, -25194, -25423, -25658, -25890, -26111, -26311, -26485, -26626, -26729, -26792, -26813, -26792, -26732, -26634, -26504, -26348, -26171, -25981, -25786, -25593, -25409, -25241, -25095, -24977, -24889, -24836, -24818, -24836, -24888, -24971, -25082, -25216, -25368, -25531, -25699, -25866, -26026, -26171, -26298, -26401, -26477, -26524, -26539, -26524, -26478, -26405, -26307, -26189, -26055, -25910, -25761, -25613, -25471, -25342, -25229, -25137, -25069, -25027, -25013, -25027, -25068, -25134, -25222, -25329, -25450, -25581, -25716, -25850, -25979, -26097, -26199, -26283, -26345, -26383, -26396, -26383, -26346, -26285, -26204, -26106, -25995, -25874, -25750, -25626, -25507, -25398, -25303, -25225, -25168, -25132, -25121, -25132, -25167, -25223, -25299, -25391, -25495, -25607, -25724, -25840, -25952, -26054, -26143, -26216, -26270, -26304, -26315, -26304, -26271, -26218, -26146, -26059, -25961, -25854, -25743, -25633, -25527, -25430, -25345, -25276, -25224, -25192, -25182, -25192, -25224, -25275, -25343, -25426, -25521, -25623, -25729, -25835, -25937, -26030, -26112, -26179, -26229, -26259, -26270, -26259, -26229, -26180, -26114, -26033, -25942, -25842, -25739, -25636, -25538, -25447, -25367, -25302, -25253, -25224, -25214, -25224, -25253, -25301, -25366, -25445, -25534, -25632, -25733, -25834, -25931, -26020, -26098, -26162, -26210, -26239, -26249, -26239, -26210, -26162, -26099, -26021, -25932, -25836, -25736, -25636, -25540, -25451, -25374, -25310, -25263, -25233, -25224, -25233, -25263, -25310, -25374, -25451, -25540, -25636, -25736, -25836, -25932, -26021, -26099, -26162, -26210, -26239, -26249, -26239, -26210, -26162, -26098, -26020, -25931, -25834, -25733, -25632, -25534, -25445, -25366, -25301, -25253, -25224, -25214, -25224, -25253, -25302, -25367, -25447, -25538, -25636, -25739, -25842, -25942, -26033, -26114, -26180, -26229, -26259, -26270, -26259, -26229, -26179, -26112, -26030, -25937, -25835, -25729, -25623, -25521, -25426, -25343, -25275, -25224, -25192, -25182, -25192, -25224, -25276, -25345, -25430, -25527, -25633, -25743, -25854, -25961, -26059, -26146, -26218, -26271, -26304, -26315, -26304, -26270, -26216, -26143, -26054, -25952, -25840, -25724, -25607, -25495, -25391, -25299, -25223, -25167, -25132, -25121, -25132, -25168, -25225, -25303, -25398, -25507, -25626, -25750, -25874, -25995, -26106, -26204, -26285, -26346, -26383, -26396, -26383, -26345, -26283, -26199, -26097, -25979, -25850, -25716, -25581, -25450, -25329, -25222, -25134, -25068, -25027, -25013, -25027, -25069, -25137, -25229, -25342, -25471, -25613, -25761, -25910, -26055, -26189, -26307, -26405, -26478, -26524, -26539, -26524, -26477, -26401, -26298, -26171, -26026, -25866, -25699, -25531, -25368, -25216, -25082, -24971, -24888, -24836, -24818, -24836, -24889, -24977, -25095, -25241, -25409, -25593, -25786, -25981, -26171, -26348, -26504, -26634, -26732, -26792, -26813, -26792, -26729, -26626, -26485, -26311, -26111, -25890, -25658, -25423, -25194, -24980, -24790, -24632, -24513, -24438, -24413, -24439, -24517, -24645, -24821, -25038, -25290, -25568, -25862, -26161, -26454, -26728, -26973, -27178, -27333, -27430, -27464, -27429, -27326, -27154, -26918, -26624, -26280, -25899, -25493, -25076, -24666, -24278, -23929, -23634, -23409, -23267, -23217, -23269, -23426, -23689, -24055, -24517, -25064, -25681, -26348, -27043, -27741, -28414, -29032, -29565, -29982, -30253, -30350, -30245, -29916, -29342, -28509, -27407, -26030, -24379, -22461, -20287, -17875, -15248, -12435, -9467, -6379, -3210, ], [0, 1608, 3210, 4802, 6379, 7936, 9467, 10969, 12436, 13865, 15251, 16590, 17879, 19114, 20292, 21411, 22469, 23462, 24390, 25251, 26044, 26768, 27423, 28010, 28529, 28979, 29364, 29683, 29939, 30134, 30270, 30349, 30375, 30350, 30278, 30162, 30005, 29812, 29585, 29330, 29049, 28746, 28425, 28091, 27747, 27396, 27042, 26689, 26340, 25998, 25665, 25345, 25041, 24754, 24487, 24241, 24018, 23819, 23646, 23499, 23378, 23285, 23219, 23179, 23166, 23179, 23217, 23278, 23362, 23468, 23592, 23735, 23894, 24067, 24252, 24447, 24651, 24860, 25073, 25287, 25502, 25713, 25921, 26122, 26315, 26498, 26670, 26829, 26975, 27106, 27220, 27318, 27399, 27462, 27507, 27534, 27543, 27534, 27508, 27465, 27406, 27332, 27243, 27141, 27028, 26903, 26769, 26628, 26479, 26326, 26170, 26011, 25852, 25695, 25540, 25389, 25243, 25105, 24974, 24853, 24742, 24641, 24553, 24478, 24415, 24366, 24331, 24310, 24303, 24310, 24331, 24365, 24411, 24471, 24541, 24623, 24714, 24814, 24922, 25037, 25157, 25282, 25410, 25539, 25669, 25799, 25927, 26051, 26171, 26286, 26395, 26496, 26589, 26673, 26747, 26810, 26863, 26904, 26934, 26952, 26958, 26952, 26934, 26905, 26865, 26814, 26754, 26683, 26605, 26518, 26424, 26325, 26220, 26112, 26000, 25887, 25773, 25659, 25547, 25438, 25331, 25230, 25134, 25044, 24962, 24887, 24821, 24764, 24717, 24680, 24654, 24638, 24632, 24638, 24654, 24680, 24716, 24762, 24817, 24881, 24953, 25032, 25117, 25208, 25304, 25404, 25506, 25610, 25715, 25820, 25924, 26025, 26123, 26217, 26307, 26390, 26467, 26536, 26598, 26651, 26695, 26730, 26755, 26770, 26775, 26770, 26755, 26730, 26696, 26652, 26600, 26539, 26471, 26396, 26314, 26227, 26136, 26040, 25942, 25842, 25741, 25641, 25541, 25443, 25348, 25257, 25171, 25090, 25015, 24948, 24888, 24836, 24793, 24759, 24735, 24720, 24715, 24720, 24735, 24759, 24793, 24836, 24888, 24948, 25015, 25090, 25171, 25257, 25348, 25443, 25541, 25641, 25741, 25842, 25942, 26040, 26136, 26227, 26314, 26396, 26471, 26539, 26600, 26652, 26696, 26730, 26755, 26770, 26775, 26770, 26755, 26730, 26695, 26651, 26598, 26536, 26467, 26390, 26307, 26217, 26123, 26025, 25924, 25820, 25715, 25610, 25506, 25404, 25304, 25208, 25117, 25032, 24953, 24881, 24817, 24762, 24716, 24680, 24654, 24638, 24632, 24638, 24654, 24680, 24717, 24764, 24821, 24887, 24962, 25044, 25134, 25230, 25331, 25438, 25547, 25659, 25773, 25887, 26000, 26112, 26220, 26325, 26424, 26518, 26605, 26683, 26754, 26814, 26865, 26905, 26934, 26952, 26958, 26952, 26934, 26904, 26863, 26810, 26747, 26673, 26589, 26496, 26395, 26286, 26171, 26051, 25927, 25799, 25669, 25539, 25410, 25282, 25157, 25037, 24922, 24814, 24714, 24623, 24541, 24471, 24411, 24365, 24331, 24310, 24303, 24310, 24331, 24366, 24415, 24478, 24553, 24641, 24742, 24853, 24974, 25105, 25243, 25389, 25540, 25695, 25852, 26011, 26170, 26326, 26479, 26628, 26769, 26903, 27028, 27141, 27243, 27332, 27406, 27465, 27508, 27534, 27543, 27534, 27507, 27462, 27399, 27318, 27220, 27106, 26975, 26829, 26670, 26498, 26315, 26122, 25921, 25713, 25502, 25287, 25073, 24860, 24651, 24447, 24252, 24067, 23894, 23735, 23592, 23468, 23362, 23278, 23217, 23179, 23166, 23179, 23219, 23285, 23378, 23499, 23646, 23819, 24018, 24241, 24487, 24754, 25041, 25345, 25665, 25998, 26340, 26689, 27042, 27396, 27747, 28091, 28425, 28746, 29049, 29330, 29585, 29812, 30005, 30162, 30278, 30350, 30375, 30349, 30270, 30134, 29939, 29683, 29364, 28979, 28529, 28010, 27423, 26768, 26044, 25251, 24390, 23462, 22469, 21411, 20292, 19114, 17879, 16590, 15251, 13865, 12436, 10969, 9467, 7936, 6379, 4802, 3210, 1608, 0, -1608, -3210, -4802, -6379, -7936, -9467, -10969, -12436, -13865, -15251, -16590, -17879, -19114, -20292, -21411, -22469, -23462, -24390, -25251, -26044, -26768, -27423, -28010, -28529, -28979, -29364, -29683, -29939, -30134, -30270, -30349, -30375, -30350, -30278, -30162, -30005, -29812, -29585, -29330, -29049, -28746, -28425, -28091, -27747, -27396, -27042, -26689, -26340, -25998, -25665, -25345, -25041, -24754, -24487, -24241, -24018, -23819, -23646, -23499, -23378, -23285, -23219, -23179, -23166, -23179, -23217, -23278, -23362, -23468, -23592, -23735, -23894, -24067, -24252, -24447, -24651, -24860, -25073, -25287, -25502, -25713, -25921, -26122, -26315, -26498, -26670, -26829, -26975, -27106, -27220, -27318, -27399, -27462, -27507, -27534, -27543, -27534, -27508, -27465, -27406, -27332, -27243, -27141, -27028, -26903, -26769, -26628, -26479, -26326, -26170, -26011, -25852, -25695, -25540, -25389, -25243, -25105, -24974, -24853, -24742, -24641, -24553, -24478, -24415, -24366, -24331, -24310, -24303, -24310, -24331, -24365, -24411, -24471, -24541, -24623, -24714, -24814, -24922, -25037, -25157, -25282, -25410, -25539, -25669, -25799, -25927, -26051, -26171, -26286, -26395, -26496, -26589, -26673, -26747, -26810, -26863, -26904, -26934, -26952, -26958, -26952, -26934, -26905, -26865, -26814, -26754, -26683, -26605, -26518, -26424, -26325, -26220, -26112, -26000, -25887, -25773, -25659, -25547, -25438, -25331, -25230, -25134, -25044, -24962, -24887, -24821, -24764, -24717, -24680, -24654, -24638, -24632, -24638, -24654, -24680, -24716, -24762, -24817, -24881, -24953, -25032, -25117, -25208, -25304, -25404, -25506, -25610, -25715, -25820, -25924, -26025, -26123, -26217, -26307, -26390, -26467, -26536, -26598, -26651, -26695, -26730, -26755, -26770, -26775, -26770, -26755, -26730, -26696, -26652, -26600, -26539, -26471, -26396, -26314, -26227, -26136, -26040, -25942, -25842, -25741, -25641, -25541, -25443, -25348, -25257, -25171, -25090, -25015, -24948, -24888, -24836, -24793, -24759, -24735, -24720, -24715, -24720, -24735, -24759, -24793, -24836, -24888, -24948, -25015, -25090, -25171, -25257, -25348, -25443, -25541, -25641, -25741, -25842, -25942, -26040, -26136, -26227, -26314, -26396, -26471, -26539, -26600, -26652, -26696, -26730, -26755, -26770, -26775, -26770, -26755, -26730, -26695, -26651, -26598, -26536, -26467, -26390, -26307, -26217, -26123, -26025, -25924, -25820, -25715, -25610, -25506, -25404, -25304, -25208, -25117, -25032, -24953, -24881, -24817, -24762, -24716, -24680, -24654, -24638, -24632, -24638, -24654, -24680, -24717, -24764, -24821, -24887, -24962, -25044, -25134, -25230, -25331, -25438, -25547, -25659, -25773, -25887, -26000, -26112, -26220, -26325, -26424, -26518, -26605, -26683, -26754, -26814, -26865, -26905, -26934, -26952, -26958, -26952, -26934, -26904, -26863, -26810, -26747, -26673, -26589, -26496, -26395, -26286, -26171, -26051, -25927, -25799, -25669, -25539, -25410, -25282, -25157, -25037, -24922, -24814, -24714, -24623, -24541, -24471, -24411, -24365, -24331, -24310, -24303, -24310, -24331, -24366, -24415, -24478, -24553, -24641, -24742, -24853, -24974, -25105, -25243, -25389, -25540, -25695, -25852, -26011, -26170, -26326, -26479, -26628, -26769, -26903, -27028, -27141, -27243, -27332, -27406, -27465, -27508, -27534, -27543, -27534, -27507, -27462, -27399, -27318, -27220, -27106, -26975, -26829, -26670, -26498, -26315, -26122, -25921, -25713, -25502, -25287, -25073, -24860, -24651, -24447, -24252, -24067, -23894, -23735, -23592, -23468, -23362, -23278, -23217, -23179, -23166, -23179, -23219, -23285, -23378, -23499, -23646, -23819, -24018, -24241, -24487, -24754, -25041, -25345, -25665, -25998, -26340, -26689, -27042, -27396, -27747, -28091, -28425, -28746, -29049, -29330, -29585, -29812, -30005, -30162, -30278, -30350, -30375, -30349, -30270, -30134, -29939, -29683, -29364, -28979, -28529, -28010, -27423, -26768, -26044, -25251, -24390, -23462, -22469, -21411, -20292, -19114, -17879, -16590, -15251, -13865, -12436, -10969, -9467, -7936, -6379, -4802, -3210, -1608, ], [0, 804, 1608, 2410, 3210, 4008, 4803, 5593, 6380, 7161, 7937, 8707, 9469, 10225, 10972, 11711, 12441, 13162, 13872, 14571, 15260, 15937, 16602, 17254, 17894, 18520, 19133, 19731, 20315, 20884, 21439, 21977, 22501, 23008, 23499, 23974, 24432, 24874, 25299, 25707, 26097, 26471, 26827, 27167, 27489, 27794, 28081, 28352, 28605, 28841, 29061, 29264, 29450, 29620, 29774, 29911, 30033, 30140, 30231, 30307, 30369, 30416, 30450, 30470, 30476, 30470, 30451, 30420, 30377, 30323, 30258, 30183, 30098, 30003, 29900, 29788, 29667, 29539, 29404, 29262, 29115, 28961, 28802, 28639, 28471, 28300, 28125, 27948, 27768, 27587, 27404, 27220, 27036, 26852, 26668, 26485, 26303, 26123, 25945, 25770, 25597, 25427, 25261, 25099, 24941, 24787, 24638, 24494, 24356, 24223, 24096, 23974, 23859, 23750, 23648, 23552, 23463, 23381, 23306, 23238, 23177, 23123, 23077, 23038, 23006, 22981, 22963, 22953, 22949, 22953, 22963, 22980, 23004, 23034, 23071, 23114, 23163, 23218, 23278, 23344, 23415, 23491, 23572, 23658, 23748, 23842, 23940, 24041, 24145, 24253, 24363, 24476, 24590, 24707, 24825, 24944, 25064, 25185, 25306, 25428, 25549, 25669, 25789, 25908, 26025, 26141, 26255, 26367, 26477, 26584, 26688, 26789, 26887, 26981, 27072, 27158, 27241, 27319, 27393, 27463, 27528, 27588, 27643, 27693, 27739, 27779, 27813, 27843, 27867, 27886, 27899, 27907, 27910, 27907, 27899, 27886, 27867, 27844, 27815, 27782, 27743, 27700, 27652, 27600, 27543, 27482, 27417, 27348, 27276, 27200, 27121, 27038, 26953, 26865, 26774, 26682, 26587, 26490, 26392, 26292, 26192, 26090, 25988, 25886, 25783, 25681, 25579, 25478, 25377, 25278, 25179, 25083, 24988, 24895, 24804, 24716, 24631, 24548, 24468, 24391, 24318, 24249, 24183, 24121, 24063, 24009, 23959, 23914, 23873, 23837, 23805, 23778, 23756, 23739, 23727, 23720, 23717, 23720, 23727, 23739, 23756, 23778, 23805, 23837, 23873, 23914, 23959, 24009, 24063, 24121, 24183, 24249, 24318, 24391, 24468, 24548, 24631, 24716, 24804, 24895, 24988, 25083, 25179, 25278, 25377, 25478, 25579, 25681, 25783, 25886, 25988, 26090, 26192, 26292, 26392, 26490, 26587, 26682, 26774, 26865, 26953, 27038, 27121, 27200, 27276, 27348, 27417, 27482, 27543, 27600, 27652, 27700, 27743, 27782, 27815, 27844, 27867, 27886, 27899, 27907, 27910, 27907, 27899, 27886, 27867, 27843, 27813, 27779, 27739, 27693, 27643, 27588, 27528, 27463, 27393, 27319, 27241, 27158, 27072, 26981, 26887, 26789, 26688, 26584, 26477, 26367, 26255, 26141, 26025, 25908, 25789, 25669, 25549, 25428, 25306, 25185, 25064, 24944, 24825, 24707, 24590, 24476, 24363, 24253, 24145, 24041, 23940, 23842, 23748, 23658, 23572, 23491, 23415, 23344, 23278, 23218, 23163, 23114, 23071, 23034, 23004, 22980, 22963, 22953, 22949, 22953, 22963, 22981, 23006, 23038, 23077, 23123, 23177, 23238, 23306, 23381, 23463, 23552, 23648, 23750, 23859, 23974, 24096, 24223, 24356, 24494, 24638, 24787, 24941, 25099, 25261, 25427, 25597, 25770, 25945, 26123, 26303, 26485, 26668, 26852, 27036, 27220, 27404, 27587, 27768, 27948, 28125, 28300, 28471, 28639, 28802, 28961, 29115, 29262, 29404, 29539, 29667, 29788, 29900, 30003, 30098, 30183, 30258, 30323, 30377, 30420, 30451, 30470, 30476, 30470, 30450, 30416, 30369, 30307, 30231, 30140, 30033, 29911, 29774, 29620, 29450, 29264, 29061, 28841, 28605, 28352, 28081, 27794, 27489, 27167, 26827, 26471, 26097, 25707, 25299, 24874, 24432, 23974, 23499, 23008, 22501, 21977, 21439, 20884, 20315, 19731, 19133, 18520, 17894, 17254, 16602, 15937, 15260, 14571, 13872, 13162, 12441, 11711, 10972, 10225, 9469, 8707, 7937, 7161, 6380, 5593, 4803, 4008, 3210, 2410, 1608, 804, 0, -804, -1608, -2410, -3210, -4008, -4803, -5593, -6380, -7161, -7937, -8707, -9469, -10225, -10972, -11711, -12441, -13162, -13872, -14571, -15260, -15937, -16602, -17254, -17894, -18520, -19133, -19731, -20315, -20884, -21439, -21977, -22501, -23008, -23499, -23974, -24432, -24874, -25299, -25707, -26097, -26471, -26827, -27167, -27489, -27794, -28081, -28352, -28605, -28841, -29061, -29264, -29450, -29620, -29774, -29911, -30033, -30140, -30231, -30307, -30369, -30416, -30450, -30470, -30476, -30470, -30451, -30420, -30377, -30323, -30258, -30183, -30098, -30003, -29900, -29788, -29667, -29539, -29404, -29262, -29115, -28961, -28802, -28639, -28471, -28300, -28125, -27948, -27768, -27587, -27404, -27220, -27036, -26852, -26668, -26485, -26303, -26123, -25945, -25770, -25597, -25427, -25261, -25099, -24941, -24787, -24638, -24494, -24356, -24223, -24096, -23974, -23859, -23750, -23648, -23552, -23463, -23381, -23306, -23238, -23177, -23123, -23077, -23038, -23006, -22981, -22963, -22953, -22949, -22953, -22963, -22980, -23004, -23034, -23071, -23114, -23163, -23218, -23278, -23344, -23415, -23491, -23572, -23658, -23748, -23842, -23940, -24041, -24145, -24253, -24363, -24476, -24590, -24707, -24825, -24944, -25064, -25185, -25306, -25428, -25549, -25669, -25789, -25908, -26025, -26141, -26255, -26367, -26477, -26584, -26688, -26789, -26887, -26981, -27072, -27158, -27241, -27319, -27393, -27463, -27528, -27588, -27643, -27693, -27739, -27779, -27813, -27843, -27867, -27886, -27899, -27907, -27910, -27907, -27899, -27886, -27867, -27844, -27815, -27782, -27743, -27700, -27652, -27600, -27543, -27482, -27417, -27348, -27276, -27200, -27121, -27038, -26953, -26865, -26774, -26682, -26587, -26490, -26392, -26292, -26192, -26090, -25988, -25886, -25783, -25681, -25579, -25478, -25377, -25278, -25179, -25083, -24988, -24895, -24804, -24716, -24631, -24548, -24468, -24391, -24318, -24249, -24183, -24121, -24063, -24009, -23959, -23914, -23873, -23837, -23805, -23778, -23756, -23739, -23727, -23720, -23717, -23720, -23727, -23739, -23756, -23778, -23805, -23837, -23873, -23914, -23959, -24009, -24063, -24121, -24183, -24249, -24318, -24391, -24468, -24548, -24631, -24716, -24804, -24895, -24988, -25083, -25179, -25278, -25377, -25478, -25579, -25681, -25783, -25886, -25988, -26090, -26192, -26292, -26392, -26490, -26587, -26682, -26774, -26865, -26953, -27038, -27121, -27200, -27276, -27348, -27417, -27482, -27543, -27600, -27652, -27700, -27743, -27782, -27815, -27844, -27867, -27886, -27899, -27907, -27910, -27907, -27899, -27886, -27867, -27843, -27813, -27779, -27739, -27693, -27643, -27588, -27528, -27463, -27393, -27319, -27241, -27158, -27072, -26981, -26887, -26789, -26688, -26584, -26477, -26367, -26255, -26141, -26025, -25908, -25789, -25669, -25549, -25428, -25306, -25185, -25064, -24944, -24825, -24707, -24590, -24476, -24363, -24253, -24145, -24041, -23940, -23842, -23748, -23658, -23572, -23491, -23415, -23344, -23278, -23218, -23163, -23114, -23071, -23034, -23004, -22980, -22963, -22953, -22949, -22953, -22963, -22981, -23006, -23038, -23077, -23123, -23177, -23238, -23306, -23381, -23463, -23552, -23648, -23750, -23859, -23974, -24096, -24223, -24356, -24494, -24638, -24787, -24941, -25099, -25261, -25427, -25597, -25770, -25945, -26123, -26303, -26485, -26668, -26852, -27036, -27220, -27404, -27587, -27768, -27948, -28125, -28300, -28471, -28639, -28802, -28961, -29115, -29262, -29404, -29539, -29667, -29788, -29900, -30003, -30098, -30183, -30258, -30323, -30377, -30420, -30451, -30470, -30476, -30470, -30450, -30416, -30369, -30307, -30231, -30140, -30033, -29911, -29774, -29620, -29450, -29264, -29061, -28841, -28605, -28352, -28081, -27794, -27489, -27167, -26827, -26471, -26097, -25707, -25299, -24874, -24432, -23974, -23499, -23008, -22501, -21977, -21439, -20884, -20315, -19731, -19133, -18520, -17894, -17254, -16602, -15937, -15260, -14571, -13872, -13162, -12441, -11711, -10972, -10225, -9469, -8707, -7937, -7161, -6380, -5593, -4803, -4008, -3210, -2410, -1608, -804, ], [0, 402, 804, 1206, 1608, 2009, 2410, 2810, 3210, 3610, 4009, 4406, 4804, 5200, 5595, 5989, 6382, 6774, 7165, 7554, 7942, 8328, 8713, 9096, 9478, 9858, 10235, 10611, 10986, 11358, 11727, 12095, 12461, 12824, 13185, 13543, 13899, 14252, 14603, 14951, 15297, 15639, 15979, 16316, 16650, 16981, 17309, 17633, 17955, 18273, 18588, 18900, 19208, 19513, 19815, 20113, 20407, 20698, 20985, 21269, 21549, 21825, 22097, 22366, 22630, 22891, 23148, 23401, 23650, 23894, 24135, 24372, 24605, 24833, 25058, 25278, 25494, 25706, 25913, 26117, 26316, 26511, 26701, 26888, 27070, 27248, 27421, 27590, 27755, 27915, 28071, 28223, 28370, 28514, 28652, 28787, 28917, 29043, 29164, 29281, 29394, 29503, 29607, 29707, 29803, 29895, 29982, 30065, 30145, 30220, 30290, 30357, 30420, 30478, 30533, 30584, 30630, 30673, 30712, 30747, 30778, 30805, 30829, 30849, 30865, 30877, 30886, 30891, 30893, 30891, 30886, 30878, 30866, 30850, 30832, 30810, 30785, 30757, 30726, 30692, 30655, 30615, 30572, 30526, 30478, 30427, 30373, 30317, 30258, 30197, 30133, 30067, 29998, 29928, 29855, 29780, 29703, 29624, 29543, 29460, 29376, 29289, 29201, 29112, 29020, 28928, 28833, 28738, 28641, 28543, 28444, 28343, 28242, 28140, 28036, 27932, 27827, 27722, 27615, 27508, 27401, 27293, 27185, 27076, 26967, 26858, 26749, 26639, 26530, 26420, 26311, 26202, 26093, 25984, 25876, 25768, 25661, 25554, 25447, 25341, 25236, 25132, 25028, 24926, 24824, 24723, 24623, 24525, 24427, 24331, 24235, 24141, 24049, 23957, 23867, 23779, 23692, 23607, 23523, 23441, 23360, 23281, 23204, 23129, 23056, 22984, 22915, 22847, 22781, 22717, 22656, 22596, 22539, 22483, 22430, 22379, 22330, 22283, 22239, 22197, 22157, 22120, 22085, 22052, 22021, 21993, 21968, 21944, 21923, 21905, 21889, 21875, 21864, 21856, 21850, 21846, 21845, 21846, 21850, 21856, 21864, 21875, 21889, 21905, 21923, 21944, 21968, 21993, 22021, 22052, 22085, 22120, 22157, 22197, 22239, 22283, 22330, 22379, 22430, 22483, 22539, 22596, 22656, 22717, 22781, 22847, 22915, 22984, 23056, 23129, 23204, 23281, 23360, 23441, 23523, 23607, 23692, 23779, 23867, 23957, 24049, 24141, 24235, 24331, 24427, 24525, 24623, 24723, 24824, 24926, 25028, 25132, 25236, 25341, 25447, 25554, 25661, 25768, 25876, 25984, 26093, 26202, 26311, 26420, 26530, 26639, 26749, 26858, 26967, 27076, 27185, 27293, 27401, 27508, 27615, 27722, 27827, 27932, 28036, 28140, 28242, 28343, 28444, 28543, 28641, 28738, 28833, 28928, 29020, 29112, 29201, 29289, 29376, 29460, 29543, 29624, 29703, 29780, 29855, 29928, 29998, 30067, 30133, 30197, 30258, 30317, 30373, 30427, 30478, 30526, 30572, 30615, 30655, 30692, 30726, 30757, 30785, 30810, 30832, 30850, 30866, 30878, 30886, 30891, 30893, 30891, 30886, 30877, 30865, 30849, 30829, 30805, 30778, 30747, 30712, 30673, 30630, 30584, 30533, 30478, 30420, 30357, 30290, 30220, 30145, 30065, 29982, 29895, 29803, 29707, 29607, 29503, 29394, 29281, 29164, 29043, 28917, 28787, 28652, 28514, 28370, 28223, 28071, 27915, 27755, 27590, 27421, 27248, 27070, 26888, 26701, 26511, 26316, 26117, 25913, 25706, 25494, 25278, 25058, 24833, 24605, 24372, 24135, 23894, 23650, 23401, 23148, 22891, 22630, 22366, 22097, 21825, 21549, 21269, 20985, 20698, 20407, 20113, 19815, 19513, 19208, 18900, 18588, 18273, 17955, 17633, 17309, 16981, 16650, 16316, 15979, 15639, 15297, 14951, 14603, 14252, 13899, 13543, 13185, 12824, 12461, 12095, 11727, 11358, 10986, 10611, 10235, 9858, 9478, 9096, 8713, 8328, 7942, 7554, 7165, 6774, 6382, 5989, 5595, 5200, 4804, 4406, 4009, 3610, 3210, 2810, 2410, 2009, 1608, 1206, 804, 402, 0, -402, -804, -1206, -1608, -2009, -2410, -2810, -3210, -3610, -4009, -4406, -4804, -5200, -5595, -5989, -6382, -6774, -7165, -7554, -7942, -8328, -8713, -9096, -9478, -9858, -10235, -10611, -10986, -11358, -11727, -12095, -12461, -12824, -13185, -13543, -13899, -14252, -14603, -14951, -15297, -15639, -15979, -16316, -16650, -16981, -17309, -17633, -17955, -18273, -18588, -18900, -19208, -19513, -19815, -20113, -20407, -20698, -20985, -21269, -21549, -21825, -22097, -22366, -22630, -22891, -23148, -23401, -23650, -23894, -24135, -24372, -24605, -24833, -25058, -25278, -25494, -25706, -25913, -26117, -26316, -26511, -26701, -26888, -27070, -27248, -27421, -27590, -27755, -27915, -28071, -28223, -28370, -28514, -28652, -28787, -28917, -29043, -29164, -29281, -29394, -29503, -29607, -29707, -29803, -29895, -29982, -30065, -30145, -30220, -30290, -30357, -30420, -30478, -30533, -30584, -30630, -30673, -30712, -30747, -30778, -30805, -30829, -30849, -30865, -30877, -30886, -30891, -30893, -30891, -30886, -30878, -30866, -30850, -30832, -30810, -30785, -30757, -30726, -30692, -30655, -30615, -30572, -30526, -30478, -30427, -30373, -30317, -30258, -30197, -30133, -30067, -29998, -29928, -29855, -29780, -29703, -29624, -29543, -29460, -29376, -29289, -29201, -29112, -29020, -28928, -28833, -28738, -28641, -28543, -28444, -28343, -28242, -28140, -28036, -27932, -27827, -27722, -27615, -27508, -27401, -27293, -27185, -27076, -26967, -26858, -26749, -26639, -26530, -26420, -26311, -26202, -26093, -25984, -25876, -25768, -25661, -25554, -25447, -25341, -25236, -25132, -25028, -24926, -24824, -24723, -24623, -24525, -24427, -24331, -24235, -24141, -24049, -23957, -23867, -23779, -23692, -23607, -23523, -23441, -23360, -23281, -23204, -23129, -23056, -22984, -22915, -22847, -22781, -22717, -22656, -22596, -22539, -22483, -22430, -22379, -22330, -22283, -22239, -22197, -22157, -22120, -22085, -22052, -22021, -21993, -21968, -21944, -21923, -21905, -21889, -21875, -21864, -21856, -21850, -21846, -21845, -21846, -21850, -21856, -21864, -21875, -21889, -21905, -21923, -21944, -21968, -21993, -22021, -22052, -22085, -22120, -22157, -22197, -22239, -22283, -22330, -22379, -22430, -22483, -22539, -22596, -22656, -22717, -22781, -22847, -22915, -22984, -23056, -23129, -23204, -23281, -23360, -23441, -23523, -23607, -23692, -23779, -23867, -23957, -24049, -24141, -24235, -24331, -24427, -24525, -24623, -24723, -24824, -24926, -25028, -25132, -25236, -25341, -25447, -25554, -25661, -25768, -25876, -25984, -26093, -26202, -26311, -26420, -26530, -26639, -26749, -26858, -26967, -27076, -27185, -27293, -27401, -27508, -27615, -27722, -27827, -27932, -28036, -28140, -28242, -28343, -28444, -28543, -28641, -28738, -28833, -28928, -29020, -29112, -29201, -29289, -29376, -29460, -29543, -29624, -29703, -29780, -29855, -29928, -29998, -30067, -30133, -30197, -30258, -30317, -30373, -30427, -30478, -30526, -30572, -30615, -30655, -30692, -30726, -30757, -30785, -30810, -30832, -30850, -30866, -30878, -30886, -30891, -30893, -30891, -30886, -30877, -30865, -30849, -30829, -30805, -30778, -30747, -30712, -30673, -30630, -30584, -30533, -30478, -30420, -30357, -30290, -30220, -30145, -30065, -29982, -29895, -29803, -29707, -29607, -29503, -29394, -29281, -29164, -29043, -28917, -28787, -28652, -28514, -28370, -28223, -28071, -27915, -27755, -27590, -27421, -27248, -27070, -26888, -26701, -26511, -26316, -26117, -25913, -25706, -25494, -25278, -25058, -24833, -24605, -24372, -24135, -23894, -23650, -23401, -23148, -22891, -22630, -22366, -22097, -21825, -21549, -21269, -20985, -20698, -20407, -20113, -19815, -19513, -19208, -18900, -18588, -18273, -17955, -17633, -17309, -16981, -16650, -16316, -15979, -15639, -15297, -14951, -14603, -14252, -13899, -13543, -13185, -12824, -12461, -12095, -11727, -11358, -10986, -10611, -10235, -9858, -9478, -9096, -8713, -8328, -7942, -7554, -7165, -6774, -6382, -5989, -5595, -5200, -4804, -4406, -4009, -3610, -3210, -2810, -2410, -2009, -1608, -1206, -804, -402, ], [0, 201, 402, 603, 804, 1005, 1206, 1407, 1608, 1809, 2009, 2210, 2410, 2611, 2811, 3012, 3212, 3412, 3612, 3811, 4011, 4210, 4410, 4609, 4808, 5007, 5205, 5404, 5602, 5800, 5998, 6195, 6393, 6590, 6786, 6983, 7179, 7375, 7571, 7767, 7962, 8157, 8351, 8545, 8739, 8933, 9126, 9319, 9512, 9704, 9896, 10087, 10278, 10469, 10659, 10849, 11039, 11228, 11417, 11605, 11793, 11980, 12167, 12353, 12539, 12725, 12910, 13094, 13279, 13462, 13645, 13828, 14010, 14191, 14372, 14553, 14732, 14912, 15090, 15269, 15446, 15623, 15800, 15976, 16151, 16325, 16499, 16673, 16846, 17018, 17189, 17360, 17530, 17700, 17869, 18037, 18204, 18371, 18537, 18703, 18868, 19032, 19195, 19357, 19519, 19680, 19841, 20000, 20159, 20317, 20475, 20631, 20787, 20942, 21096, 21250, 21403, 21554, 21705, 21856, 22005, 22154, 22301, 22448, 22594, 22739, 22884, 23027, 23170, 23311, 23452, 23592, 23731, 23870, 24007, 24143, 24279, 24413, 24547, 24680, 24811, 24942, 25072, 25201, 25329, 25456, 25582, 25708, 25832, 25955, 26077, 26198, 26319, 26438, 26556, 26674, 26790, 26905, 27019, 27133, 27245, 27356, 27466, 27575, 27683, 27790, 27896, 28001, 28105, 28208, 28310, 28411, 28510, 28609, 28706, 28803, 28898, 28992, 29085, 29177, 29268, 29358, 29447, 29534, 29621, 29706, 29791, 29874, 29956, 30037, 30117, 30195, 30273, 30349, 30424, 30498, 30571, 30643, 30714, 30783, 30852, 30919, 30985, 31050, 31113, 31176, 31237, 31297, 31356, 31414, 31470, 31526, 31580, 31633, 31685, 31736, 31785, 31833, 31880, 31926, 31971, 32014, 32057, 32098, 32137, 32176, 32213, 32250, 32285, 32318, 32351, 32382, 32412, 32441, 32469, 32495, 32521, 32545, 32567, 32589, 32609, 32628, 32646, 32663, 32678, 32692, 32705, 32717, 32728, 32737, 32745, 32752, 32757, 32761, 32765, 32766, 32767, 32766, 32765, 32761, 32757, 32752, 32745, 32737, 32728, 32717, 32705, 32692, 32678, 32663, 32646, 32628, 32609, 32589, 32567, 32545, 32521, 32495, 32469, 32441, 32412, 32382, 32351, 32318, 32285, 32250, 32213, 32176, 32137, 32098, 32057, 32014, 31971, 31926, 31880, 31833, 31785, 31736, 31685, 31633, 31580, 31526, 31470, 31414, 31356, 31297, 31237, 31176, 31113, 31050, 30985, 30919, 30852, 30783, 30714, 30643, 30571, 30498, 30424, 30349, 30273, 30195, 30117, 30037, 29956, 29874, 29791, 29706, 29621, 29534, 29447, 29358, 29268, 29177, 29085, 28992, 28898, 28803, 28706, 28609, 28510, 28411, 28310, 28208, 28105, 28001, 27896, 27790, 27683, 27575, 27466, 27356, 27245, 27133, 27019, 26905, 26790, 26674, 26556, 26438, 26319, 26198, 26077, 25955, 25832, 25708, 25582, 25456, 25329, 25201, 25072, 24942, 24811, 24680, 24547, 24413, 24279, 24143, 24007, 23870, 23731, 23592, 23452, 23311, 23170, 23027, 22884, 22739, 22594, 22448, 22301, 22154, 22005, 21856, 21705, 21554, 21403, 21250, 21096, 20942, 20787, 20631, 20475, 20317, 20159, 20000, 19841, 19680, 19519, 19357, 19195, 19032, 18868, 18703, 18537, 18371, 18204, 18037, 17869, 17700, 17530, 17360, 17189, 17018, 16846, 16673, 16499, 16325, 16151, 15976, 15800, 15623, 15446, 15269, 15090, 14912, 14732, 14553, 14372, 14191, 14010, 13828, 13645, 13462, 13279, 13094, 12910, 12725, 12539, 12353, 12167, 11980, 11793, 11605, 11417, 11228, 11039, 10849, 10659, 10469, 10278, 10087, 9896, 9704, 9512, 9319, 9126, 8933, 8739, 8545, 8351, 8157, 7962, 7767, 7571, 7375, 7179, 6983, 6786, 6590, 6393, 6195, 5998, 5800, 5602, 5404, 5205, 5007, 4808, 4609, 4410, 4210, 4011, 3811, 3612, 3412, 3212, 3012, 2811, 2611, 2410, 2210, 2009, 1809, 1608, 1407, 1206, 1005, 804, 603, 402, 201, 0, -201, -402, -603, -804, -1005, -1206, -1407, -1608, -1809, -2009, -2210, -2410, -2611, -2811, -3012, -3212, -3412, -3612, -3811, -4011, -4210, -4410, -4609, -4808, -5007, -5205, -5404, -5602, -5800, -5998, -6195, -6393, -6590, -6786, -6983, -7179, -7375, -7571, -7767, -7962, -8157, -8351, -8545, -8739, -8933, -9126, -9319, -9512, -9704, -9896, -10087, -10278, -10469, -10659, -10849, -11039, -11228, -11417, -11605, -11793, -11980, -12167, -12353, -12539, -12725, -12910, -13094, -13279, -13462, -13645, -13828, -14010, -14191, -14372, -14553, -14732, -14912, -15090, -15269, -15446, -15623, -15800, -15976, -16151, -16325, -16499, -16673, -16846, -17018, -17189, -17360, -17530, -17700, -17869, -18037, -18204, -18371, -18537, -18703, -18868, -19032, -19195, -19357, -19519, -19680, -19841, -20000, -20159, -20317, -20475, -20631, -20787, -20942, -21096, -21250, -21403, -21554, -21705, -21856, -22005, -22154, -22301, -22448, -22594, -22739, -22884, -23027, -23170, -23311, -23452, -23592, -23731, -23870, -24007, -24143, -24279, -24413, -24547, -24680, -24811, -24942, -25072, -25201, -25329, -25456, -25582, -25708, -25832, -25955, -26077, -26198, -26319, -26438, -26556, -26674, -26790, -26905, -27019, -27133, -27245, -27356, -27466, -27575, -27683, -27790, -27896, -28001, -28105, -28208, -28310, -28411, -28510, -28609, -28706, -28803, -28898, -28992, -29085, -29177, -29268, -29358, -29447, -29534, -29621, -29706, -29791, -29874, -29956, -30037, -30117, -30195, -30273, -30349, -30424, -30498, -30571, -30643, -30714, -30783, -30852, -30919, -30985, -31050, -31113, -31176, -31237, -31297, -31356, -31414, -31470, -31526, -31580, -31633, -31685, -31736, -31785, -31833, -31880, -31926, -31971, -32014, -32057, -32098, -32137, -32176, -32213, -32250, -32285, -32318, -32351, -32382, -32412, -32441, -32469, -32495, -32521, -32545, -32567, -32589, -32609, -32628, -32646, -32663, -32678, -32692, -32705, -32717, -32728, -32737, -32745, -32752, -32757, -32761, -32765, -32766, -32767, -32766, -32765, -32761, -32757, -32752, -32745, -32737, -32728, -32717, -32705, -32692, -32678, -32663, -32646, -32628, -32609, -32589, -32567, -32545, -32521, -32495, -32469, -32441, -32412, -32382, -32351, -32318, -32285, -32250, -32213, -32176, -32137, -32098, -32057, -32014, -31971, -31926, -31880, -31833, -31785, -31736, -31685, -31633, -31580, -31526, -31470, -31414, -31356, -31297, -31237, -31176, -31113, -31050, -30985, -30919, -30852, -30783, -30714, -30643, -30571, -30498, -30424, -30349, -30273, -30195, -30117, -30037, -29956, -29874, -29791, -29706, -29621, -29534, -29447, -29358, -29268, -29177, -29085, -28992, -28898, -28803, -28706, -28609, -28510, -28411, -28310, -28208, -28105, -28001, -27896, -27790, -27683, -27575, -27466, -27356, -27245, -27133, -27019, -26905, -26790, -26674, -26556, -26438, -26319, -26198, -26077, -25955, -25832, -25708, -25582, -25456, -25329, -25201, -25072, -24942, -24811, -24680, -24547, -24413, -24279, -24143, -24007, -23870, -23731, -23592, -23452, -23311, -23170, -23027, -22884, -22739, -22594, -22448, -22301, -22154, -22005, -21856, -21705, -21554, -21403, -21250, -21096, -20942, -20787, -20631, -20475, -20317, -20159, -20000, -19841, -19680, -19519, -19357, -19195, -19032, -18868, -18703, -18537, -18371, -18204, -18037, -17869, -17700, -17530, -17360, -17189, -17018, -16846, -16673, -16499, -16325, -16151, -15976, -15800, -15623, -15446, -15269, -15090, -14912, -14732, -14553, -14372, -14191, -14010, -13828, -13645, -13462, -13279, -13094, -12910, -12725, -12539, -12353, -12167, -11980, -11793, -11605, -11417, -11228, -11039, -10849, -10659, -10469, -10278, -10087, -9896, -9704, -9512, -9319, -9126, -8933, -8739, -8545, -8351, -8157, -7962, -7767, -7571, -7375, -7179, -6983, -6786, -6590, -6393, -6195, -5998, -5800, -5602, -5404, -5205, -5007, -4808, -4609, -4410, -4210, -4011, -3811, -3612, -3412, -3212, -3012, -2811, -2611, -2410, -2210, -2009, -1809, -1608, -1407, -1206, -1005, -804, -603, -402, -201, ], [0, 201, 402, 603, 804, 1005, 1206, 1407, 1608, 1809, 2009, 2210, 2410, 2611, 2811, 3012, 3212, 3412, 3612, 3811, 4011, 4210, 4410, 4609, 4808, 5007, 5205, 5404, 5602, 5800, 5998, 6195, 6393, 6590, 6786, 6983, 7179, 7375, 7571, 7767, 7962, 8157, 8351, 8545, 8739, 8933, 9126, 9319, 9512, 9704, 9896, 10087, 10278, 10469, 10659, 10849, 11039, 11228, 11417, 11605, 11793, 11980, 12167, 12353, 12539, 12725, 12910, 13094, 13279, 13462, 13645, 13828, 14010, 14191, 14372, 14553, 14732, 14912, 15090, 15269, 15446, 15623, 15800, 15976, 16151, 16325, 16499, 16673, 16846, 17018, 17189, 17360, 17530, 17700, 17869, 18037, 18204, 18371, 18537, 18703, 18868, 19032, 19195, 19357, 19519, 19680, 19841, 20000, 20159, 20317, 20475, 20631, 20787, 20942, 21096, 21250, 21403, 21554, 21705, 21856, 22005, 22154, 22301, 22448, 22594, 22739, 22884, 23027, 23170, 23311, 23452, 23592, 23731, 23870, 24007, 24143, 24279, 24413, 24547, 24680, 24811, 24942, 25072, 25201, 25329, 25456, 25582, 25708, 25832, 25955, 26077, 26198, 26319, 26438, 26556, 26674, 26790, 26905, 27019, 27133, 27245, 27356, 27466, 27575, 27683, 27790, 27896, 28001, 28105, 28208, 28310, 28411, 28510, 28609, 28706, 28803, 28898, 28992, 29085, 29177, 29268, 29358, 29447, 29534, 29621, 29706, 29791, 29874, 29956, 30037, 30117, 30195, 30273, 30349, 30424, 30498, 30571, 30643, 30714, 30783, 30852, 30919, 30985, 31050, 31113, 31176, 31237, 31297, 31356, 31414, 31470, 31526, 31580, 31633, 31685, 31736, 31785, 31833, 31880, 31926, 31971, 32014, 32057, 32098, 32137, 32176, 32213, 32250, 32285, 32318, 32351, 32382, 32412, 32441, 32469, 32495, 32521, 32545, 32567, 32589, 32609, 32628, 32646, 32663, 32678, 32692, 32705, 32717, 32728, 32737, 32745, 32752, 32757, 32761, 32765, 32766, 32767, 32766, 32765, 32761, 32757, 32752, 32745, 32737, 32728, 32717, 32705, 32692, 32678, 32663, 32646, 32628, 32609, 32589, 32567, 32545, 32521, 32495, 32469, 32441, 32412, 32382, 32351, 32318, 32285, 32250, 32213, 32176, 32137, 32098, 32057, 32014, 31971, 31926, 31880, 31833, 31785, 31736, 31685, 31633, 31580, 31526, 31470, 31414, 31356, 31297, 31237, 31176, 31113, 31050, 30985, 30919, 30852, 30783, 30714, 30643, 30571, 30498, 30424, 30349, 30273, 30195, 30117, 30037, 29956, 29874, 29791, 29706, 29621, 29534, 29447, 29358, 29268, 29177, 29085, 28992, 28898, 28803, 28706, 28609, 28510, 28411, 28310, 28208, 28105, 28001, 27896, 27790, 27683, 27575, 27466, 27356, 27245, 27133, 27019, 26905, 26790, 26674, 26556, 26438, 26319, 26198, 26077, 25955, 25832, 25708, 25582, 25456, 25329, 25201, 25072, 24942, 24811, 24680, 24547, 24413, 24279, 24143, 24007, 23870, 23731, 23592, 23452, 23311, 23170, 23027, 22884, 22739, 22594, 22448, 22301, 22154, 22005, 21856, 21705, 21554, 21403, 21250, 21096, 20942, 20787, 20631, 20475, 20317, 20159, 20000, 19841, 19680, 19519, 19357, 19195, 19032, 18868, 18703, 18537, 18371, 18204, 18037, 17869, 17700, 17530, 17360, 17189, 17018, 16846, 16673, 16499, 16325, 16151, 15976, 15800, 15623, 15446, 15269, 15090, 14912, 14732, 14553, 14372, 14191, 14010, 13828, 13645, 13462, 13279, 13094, 12910, 12725, 12539, 12353, 12167, 11980, 11793, 11605, 11417, 11228, 11039, 10849, 10659, 10469, 10278, 10087, 9896, 9704, 9512, 9319, 9126, 8933, 8739, 8545, 8351, 8157, 7962, 7767, 7571, 7375, 7179, 6983, 6786, 6590, 6393, 6195, 5998, 5800, 5602, 5404, 5205, 5007, 4808, 4609, 4410, 4210, 4011, 3811, 3612, 3412, 3212, 3012, 2811, 2611, 2410, 2210, 2009, 1809, 1608, 1407, 1206, 1005, 804, 603, 402, 201, 0, -201, -402, -603, -804, -1005, -1206, -1407, -1608, -1809, -2009, -2210, -2410, -2611, -2811, -3012, -3212, -3412, -3612, -3811, -4011, -4210, -4410, -4609, -4808, -5007, -5205, -5404, -5602, -5800, -5998, -6195, -6393, -6590, -6786, -6983, -7179, -7375, -7571, -7767, -7962, -8157, -8351, -8545, -8739, -8933, -9126, -9319, -9512, -9704, -9896, -10087, -10278, -10469, -10659, -10849, -11039, -11228, -11417, -11605, -11793, -11980, -12167, -12353, -12539, -12725, -12910, -13094, -13279, -13462, -13645, -13828, -14010, -14191, -14372, -14553, -14732, -14912, -15090, -15269, -15446, -15623, -15800, -15976, -16151, -16325, -16499, -16673, -16846, -17018, -17189, -17360, -17530, -17700, -17869, -18037, -18204, -18371, -18537, -18703, -18868, -19032, -19195, -19357, -19519, -19680, -19841, -20000, -20159, -20317, -20475, -20631, -20787, -20942, -21096, -21250, -21403, -21554, -21705, -21856, -22005, -22154, -22301, -22448, -22594, -22739, -22884, -23027, -23170, -23311, -23452, -23592, -23731, -23870, -24007, -24143, -24279, -24413, -24547, -24680, -24811, -24942, -25072, -25201, -25329, -25456, -25582, -25708, -25832, -25955, -26077, -26198, -26319, -26438, -26556, -26674, -26790, -26905, -27019, -27133, -27245, -27356, -27466, -27575, -27683, -27790, -27896, -28001, -28105, -28208, -28310, -28411, -28510, -28609, -28706, -28803, -28898, -28992, -29085, -29177, -29268, -29358, -29447, -29534, -29621, -29706, -29791, -29874, -29956, -30037, -30117, -30195, -30273, -30349, -30424, -30498, -30571, -30643, -30714, -30783, -30852, -30919, -30985, -31050, -31113, -31176, -31237, -31297, -31356, -31414, -31470, -31526, -31580, -31633, -31685, -31736, -31785, -31833, -31880, -31926, -31971, -32014, -32057, -32098, -32137, -32176, -32213, -32250, -32285, -32318, -32351, -32382, -32412, -32441, -32469, -32495, -32521, -32545, -32567, -32589, -32609, -32628, -32646, -32663, -32678, -32692, -32705, -32717, -32728, -32737, -32745, -32752, -32757, -32761, -32765, -32766, -32767, -32766, -32765, -32761, -32757, -32752, -32745, -32737, -32728, -32717, -32705, -32692, -32678, -32663, -32646, -32628, -32609, -32589, -32567, -32545, -32521, -32495, -32469, -32441, -32412, -32382, -32351, -32318, -32285, -32250, -32213, -32176, -32137, -32098, -32057, -32014, -31971, -31926, -31880, -31833, -31785, -31736, -31685, -31633, -31580, -31526, -31470, -31414, -31356, -31297, -31237, -31176, -31113, -31050, -30985, -30919, -30852, -30783, -30714, -30643, -30571, -30498, -30424, -30349, -30273, -30195, -30117, -30037, -29956, -29874, -29791, -29706, -29621, -29534, -29447, -29358, -29268, -29177, -29085, -28992, -28898, -28803, -28706, -28609, -28510, -28411, -28310, -28208, -28105, -28001, -27896, -27790, -27683, -27575, -27466, -27356, -27245, -27133, -27019, -26905, -26790, -26674, -26556, -26438, -26319, -26198, -26077, -25955, -25832, -25708, -25582, -25456, -25329, -25201, -25072, -24942, -24811, -24680, -24547, -24413, -24279, -24143, -24007, -23870, -23731, -23592, -23452, -23311, -23170, -23027, -22884, -22739, -22594, -22448, -22301, -22154, -22005, -21856, -21705, -21554, -21403, -21250, -21096, -20942, -20787, -20631, -20475, -20317, -20159, -20000, -19841, -19680, -19519, -19357, -19195, -19032, -18868, -18703, -18537, -18371, -18204, -18037, -17869, -17700, -17530, -17360, -17189, -17018, -16846, -16673, -16499, -16325, -16151, -15976, -15800, -15623, -15446, -15269, -15090, -14912, -14732, -14553, -14372, -14191, -14010, -13828, -13645, -13462, -13279, -13094, -12910, -12725, -12539, -12353, -12167, -11980, -11793, -11605, -11417, -11228, -11039, -10849, -10659, -10469, -10278, -10087, -9896, -9704, -9512, -9319, -9126, -8933, -8739, -8545, -8351, -8157, -7962, -7767, -7571, -7375, -7179, -6983, -6786, -6590, -6393, -6195, -5998, -5800, -5602, -5404, -5205, -5007, -4808, -4609, -4410, -4210, -4011, -3811, -3612, -3412, -3212, -3012, -2811, -2611, -2410, -2210, -2009, -1809, -1608, -1407, -1206, -1005, -804, -603, -402, -201, ], ];
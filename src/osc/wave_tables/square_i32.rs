@@ -0,0 +1 @@
+pub static SQUARE_I32: [[i32; 1024]; 10] = [[0, 1988504412, 1522724370, 1798268222, 1602187336, 1754467118, 1629963684, 1735273626, 1644022536, 1724531239, 1652498434, 1717672939, 1658161823, 1712917284, 1662211702, 1709427011, 1665250774, 1706757040, 1667614961, 1704649021, 1669506243, 1702942743, 1671053343, 1701533589, 1672342166, 1700350358, 1673432233, 1699342907, 1674366082, 1698474910, 1675174925, 1697719403, 1675882177, 1697055950, 1676505752, 1696468785, 1677059584, 1695945547, 1677554677, 1695476407, 1677999835, 1695053454, 1678402186, 1694670250, 1678767564, 1694321497, 1679100787, 1694002802, 1679405872, 1693710487, 1679686191, 1693441451, 1679944601, 1693193061, 1680183532, 1692963068, 1680405069, 1692749538, 1680611010, 1692550798, 1680802912, 1692365396, 1680982131, 1692192063, 1681149855, 1692029688, 1681307125, 1691877292, 1681454863, 1691734008, 1681593884, 1691599070, 1681724912, 1691471792, 1681848592, 1691351564, 1681965504, 1691237839, 1682076165, 1691130125, 1682181043, 1691027978, 1682280559, 1690930998, 1682375093, 1690838823, 1682464991, 1690751123, 1682550568, 1690667599, 1682632108, 1690587977, 1682709874, 1690512008, 1682784104, 1690439462, 1682855017, 1690370131, 1682922814, 1690303822, 1682987680, 1690240357, 1683049786, 1690179572, 1683109287, 1690121318, 1683166330, 1690065453, 1683221048, 1690011849, 1683273567, 1689960386, 1683324002, 1689910952, 1683372461, 1689863445, 1683419042, 1689817766, 1683463840, 1689773827, 1683506942, 1689731543, 1683548428, 1689690836, 1683588374, 1689651633, 1683626851, 1689613865, 1683663925, 1689577468, 1683699660, 1689542381, 1683734113, 1689508549, 1683767339, 1689475917, 1683799389, 1689444435, 1683830314, 1689414057, 1683860157, 1689384738, 1683888962, 1689356437, 1683916769, 1689329113, 1683943618, 1689302730, 1683969544, 1689277253, 1683994581, 1689252648, 1684018762, 1689228884, 1684042116, 1689205931, 1684064674, 1689183762, 1684086463, 1689162348, 1684107507, 1689141666, 1684127833, 1689121691, 1684147462, 1689102401, 1684166419, 1689083774, 1684184722, 1689065790, 1684202392, 1689048428, 1684219449, 1689031672, 1684235909, 1689015503, 1684251791, 1688999905, 1684267110, 1688984862, 1684281881, 1688970359, 1684296120, 1688956381, 1684309839, 1688942916, 1684323053, 1688929950, 1684335774, 1688917471, 1684348013, 1688905468, 1684359783, 1688893930, 1684371093, 1688882845, 1684381955, 1688872204, 1684392377, 1688861998, 1684402369, 1688852218, 1684411940, 1688842855, 1684421097, 1688833901, 1684429850, 1688825348, 1684438204, 1688817190, 1684446168, 1688809419, 1684453748, 1688802029, 1684460949, 1688795014, 1684467779, 1688788369, 1684474242, 1688782087, 1684480344, 1688776163, 1684486090, 1688770594, 1684491483, 1688765375, 1684496530, 1688760501, 1684501233, 1688755968, 1684505595, 1688751774, 1684509622, 1688747915, 1684513315, 1688744387, 1684516678, 1688741189, 1684519712, 1688738317, 1684522421, 1688735770, 1684524807, 1688733546, 1684526871, 1688731642, 1684528614, 1688730058, 1684530039, 1688728792, 1684531146, 1688727844, 1684531936, 1688727212, 1684532410, 1688726896, 1684532568, 1688726896, 1684532410, 1688727212, 1684531936, 1688727844, 1684531146, 1688728792, 1684530039, 1688730058, 1684528614, 1688731642, 1684526871, 1688733546, 1684524807, 1688735770, 1684522421, 1688738317, 1684519712, 1688741189, 1684516678, 1688744387, 1684513315, 1688747915, 1684509622, 1688751774, 1684505595, 1688755968, 1684501233, 1688760501, 1684496530, 1688765375, 1684491483, 1688770594, 1684486090, 1688776163, 1684480344, 1688782087, 1684474242, 1688788369, 1684467779, 1688795014, 1684460949, 1688802029, 1684453748, 1688809419, 1684446168, 1688817190, 1684438204, 1688825348, 1684429850, 1688833901, 1684421097, 1688842855, 1684411940, 1688852218, 1684402369, 1688861998, 1684392377, 1688872204, 1684381955, 1688882845, 1684371093, 1688893930, 1684359783, 1688905468, 1684348013, 1688917471, 1684335774, 1688929950, 1684323053, 1688942916, 1684309839, 1688956381, 1684296120, 1688970359, 1684281881, 1688984862, 1684267110, 1688999905, 1684251791, 1689015503, 1684235909, 1689031672, 1684219449, 1689048428, 1684202392, 1689065790, 1684184722, 1689083774, 1684166419, 1689102401, 1684147462, 1689121691, 1684127833, 1689141666, 1684107507, 1689162348, 1684086463, 1689183762, 1684064674, 1689205931, 1684042116, 1689228884, 1684018762, 1689252648, 1683994581, 1689277253, 1683969544, 1689302730, 1683943618, 1689329113, 1683916769, 1689356437, 1683888962, 1689384738, 1683860157, 1689414057, 1683830314, 1689444435, 1683799389, 1689475917, 1683767339, 1689508549, 1683734113, 1689542381, 1683699660, 1689577468, 1683663925, 1689613865, 1683626851, 1689651633, 1683588374, 1689690836, 1683548428, 1689731543, 1683506942, 1689773827, 1683463840, 1689817766, 1683419042, 1689863445, 1683372461, 1689910952, 1683324002, 1689960386, 1683273567, 1690011849, 1683221048, 1690065453, 1683166330, 1690121318, 1683109287, 1690179572, 1683049786, 1690240357, 1682987680, 1690303822, 1682922814, 1690370131, 1682855017, 1690439462, 1682784104, 1690512008, 1682709874, 1690587977, 1682632108, 1690667599, 1682550568, 1690751123, 1682464991, 1690838823, 1682375093, 1690930998, 1682280559, 1691027978, 1682181043, 1691130125, 1682076165, 1691237839, 1681965504, 1691351564, 1681848592, 1691471792, 1681724912, 1691599070, 1681593884, 1691734008, 1681454863, 1691877292, 1681307125, 1692029688, 1681149855, 1692192063, 1680982131, 1692365396, 1680802912, 1692550798, 1680611010, 1692749538, 1680405069, 1692963068, 1680183532, 1693193061, 1679944601, 1693441451, 1679686191, 1693710487, 1679405872, 1694002802, 1679100787, 1694321497, 1678767564, 1694670250, 1678402186, 1695053454, 1677999835, 1695476407, 1677554677, 1695945547, 1677059584, 1696468785, 1676505752, 1697055950, 1675882177, 1697719403, 1675174925, 1698474910, 1674366082, 1699342907, 1673432233, 1700350358, 1672342166, 1701533589, 1671053343, 1702942743, 1669506243, 1704649021, 1667614961, 1706757040, 1665250774, 1709427011, 1662211702, 1712917284, 1658161823, 1717672939, 1652498434, 1724531239, 1644022536, 1735273626, 1629963684, 1754467118, 1602187336, 1798268222, 1522724370, 1988504412, 0, -1988504412, -1522724370, -1798268222, -1602187336, -1754467118, -1629963684, -1735273626, -1644022536, -1724531239, -1652498434, -1717672939, -1658161823, -1712917284, -1662211702, -1709427011, -1665250774, -1706757040, -1667614961, -1704649021, -1669506243, -1702942743, -1671053343, -1701533589, -1672342166, -1700350358, -1673432233, -1699342907, -1674366082, -1698474910, -1675174925, -1697719403, -1675882177, -1697055950, -1676505752, -1696468785, -1677059584, -1695945547, -1677554677, -1695476407, -1677999835, -1695053454, -1678402186, -1694670250, -1678767564, -1694321497, -1679100787, -1694002802, -1679405872, -1693710487, -1679686191, -1693441451, -1679944601, -1693193061, -1680183532, -1692963068, -1680405069, -1692749538, -1680611010, -1692550798, -1680802912, -1692365396, -1680982131, -1692192063, -1681149855, -1692029688, -1681307125, -1691877292, -1681454863, -1691734008, -1681593884, -1691599070, -1681724912, -1691471792, -1681848592, -1691351564, -1681965504, -1691237839, -1682076165, -1691130125, -1682181043, -1691027978, -1682280559, -1690930998, -1682375093, -1690838823, -1682464991, -1690751123, -1682550568, -1690667599, -1682632108, -1690587977, -1682709874, -1690512008, -1682784104, -1690439462, -1682855017, -1690370131, -1682922814, -1690303822, -1682987680, -1690240357, -1683049786, -1690179572, -1683109287, -1690121318, -1683166330, -1690065453, -1683221048, -1690011849, -1683273567, -1689960386, -1683324002, -1689910952, -1683372461, -1689863445, -1683419042, -1689817766, -1683463840, -1689773827, -1683506942, -1689731543, -1683548428, -1689690836, -1683588374, -1689651633, -1683626851, -1689613865, -1683663925, -1689577468, -1683699660, -1689542381, -1683734113, -1689508549, -1683767339, -1689475917, -1683799389, -1689444435, -1683830314, -1689414057, -1683860157, -1689384738, -1683888962, -1689356437, -1683916769, -1689329113, -1683943618, -1689302730, -1683969544, -1689277253, -1683994581, -1689252648, -1684018762, -1689228884, -1684042116, -1689205931, -1684064674, -1689183762, -1684086463, -1689162348, -1684107507, -1689141666, -1684127833, -1689121691, -1684147462, -1689102401, -1684166419, -1689083774, -1684184722, -1689065790, -1684202392, -1689048428, -1684219449, -1689031672, -1684235909, -1689015503, -1684251791, -1688999905, -1684267110, -1688984862, -1684281881, -1688970359, -1684296120, -1688956381, -1684309839, -1688942916, -1684323053, -1688929950, -1684335774, -1688917471, -1684348013, -1688905468, -1684359783, -1688893930, -1684371093, -1688882845, -1684381955, -1688872204, -1684392377, -1688861998, -1684402369, -1688852218, -1684411940, -1688842855, -1684421097, -1688833901, -1684429850, -1688825348, -1684438204, -1688817190, -1684446168, -1688809419, -1684453748, -1688802029, -1684460949, -1688795014, -1684467779, -1688788369, -1684474242, -1688782087, -1684480344, -1688776163, -1684486090, -1688770594, -1684491483, -1688765375, -1684496530, -1688760501, -1684501233, -1688755968, -1684505595, -1688751774, -1684509622, -1688747915, -1684513315, -1688744387, -1684516678, -1688741189, -1684519712, -1688738317, -1684522421, -1688735770, -1684524807, -1688733546, -1684526871, -1688731642, -1684528614, -1688730058, -1684530039, -1688728792, -1684531146, -1688727844, -1684531936, -1688727212, -1684532410, -1688726896, -1684532568, -1688726896, -1684532410, -1688727212, -1684531936, -1688727844, -1684531146, -1688728792, -1684530039, -1688730058, -1684528614, -1688731642, -1684526871, -1688733546, -1684524807, -1688735770, -1684522421, -1688738317, -1684519712, -1688741189, -1684516678, -1688744387, -1684513315, -1688747915, -1684509622, -1688751774, -1684505595, -1688755968, -1684501233, -1688760501, -1684496530, -1688765375, -1684491483, -1688770594, -1684486090, -1688776163, -1684480344, -1688782087, -1684474242, -1688788369, -1684467779, -1688795014, -1684460949, -1688802029, -1684453748, -1688809419, -1684446168, -1688817190, -1684438204, -1688825348, -1684429850, -1688833901, -1684421097, -1688842855, -1684411940, -1688852218, -1684402369, -1688861998, -1684392377, -1688872204, -1684381955, -1688882845, -1684371093, -1688893930, -1684359783, -1688905468, -1684348013, -1688917471, -1684335774, -1688929950, -1684323053, -1688942916, -1684309839, -1688956381, -1684296120, -1688970359, -1684281881, -1688984862, -1684267110, -1688999905, -1684251791, -1689015503, -1684235909, -1689031672, -1684219449, -1689048428, -1684202392, -1689065790, -1684184722, -1689083774, -1684166419, -1689102401, -1684147462, -1689121691, -1684127833, -1689141666, -1684107507, -1689162348, -1684086463, -1689183762, -1684064674, -1689205931, -1684042116, -1689228884, -1684018762, -1689252648, -1683994581, -1689277253, -1683969544, -1689302730, -1683943618, -1689329113, -1683916769, -1689356437, -1683888962, -1689384738, -1683860157, -1689414057, -1683830314, -1689444435, -1683799389, -1689475917, -1683767339, -1689508549, -1683734113, -1689542381, -1683699660, -1689577468, -1683663925, -1689613865, -1683626851, -1689651633, -1683588374, -1689690836, -1683548428, -1689731543, -1683506942, -1689773827, -1683463840, -1689817766, -1683419042, -1689863445, -1683372461, -1689910952, -1683324002, -1689960386, -1683273567, -1690011849, -1683221048, -1690065453, -1683166330, -1690121318, -1683109287, -1690179572, -1683049786, -1690240357, -1682987680, -1690303822, -1682922814, -1690370131, -1682855017, -1690439462, -1682784104, -1690512008, -1682709874, -1690587977, -1682632108, -1690667599, -1682550568, -1690751123, -1682464991, -1690838823, -1682375093, -1690930998, -1682280559, -1691027978, -1682181043, -1691130125, -1682076165, -1691237839, -1681965504, -1691351564, -1681848592, -1691471792, -1681724912, -1691599070, -1681593884, -1691734008, -1681454863, -1691877292, -1681307125, -1692029688, -1681149855, -1692192063, -1680982131, -1692365396, -1680802912, -1692550798, -1680611010, -1692749538, -1680405069, -1692963068, -1680183532, -1693193061, -1679944601, -1693441451, -1679686191, -1693710487, -1679405872, -1694002802, -1679100787, -1694321497, -1678767564, -1694670250, -1678402186, -1695053454, -1677999835, -1695476407, -1677554677, -1695945547, -1677059584, -1696468785, -1676505752, -1697055950, -1675882177, -1697719403, -1675174925, -1698474910, -1674366082, -1699342907, -1673432233, -1700350358, -1672342166, -1701533589, -1671053343, -1702942743, -1669506243, -1704649021, -1667614961, -1706757040, -1665250774, -1709427011, -1662211702, -1712917284, -1658161823, -1717672939, -1652498434, -1724531239, -1644022536, -1735273626, -1629963684, -1754467118, -1602187336, -1798268222, -1522724370, -1988504412, ], [0, 1471847401, 1988510846, 1726974363, 1522711501, 1670563533, 1798287528, 1695123389, 1602161591, 1681407679, 1754499305, 1690155440, 1629925050, 1684092900, 1735318712, 1688540867, 1643970992, 1685139004, 1724589247, 1687824483, 1652433954, 1685651021, 1717743901, 1687445867, 1658084371, 1685938866, 1713001236, 1687221928, 1662121238, 1686116510, 1709523998, 1687078642, 1665147250, 1686233762, 1706867114, 1686981478, 1667498322, 1686315179, 1704772240, 1686912580, 1669376427, 1686374000, 1703079173, 1686861961, 1670910281, 1686417874, 1701683304, 1686823684, 1672185781, 1686451468, 1700513436, 1686794040, 1673262440, 1686477759, 1699519438, 1686770613, 1674182790, 1686498722, 1698664988, 1686751780, 1674978034, 1686515706, 1697923131, 1686736410, 1675671582, 1686529659, 1697273441, 1686723704, 1676281337, 1686541263, 1696700156, 1686713079, 1676821226, 1686551018, 1696190925, 1686704102, 1677302244, 1686559298, 1695735928, 1686696448, 1677733188, 1686566387, 1695327263, 1686689869, 1678121177, 1686572505, 1694958498, 1686684171, 1678472036, 1686577821, 1694624347, 1686679202, 1678790573, 1686582472, 1694320425, 1686674842, 1679080795, 1686586565, 1694043063, 1686670995, 1679346068, 1686590187, 1693789170, 1686667581, 1679589235, 1686593408, 1693556124, 1686664538, 1679812718, 1686596286, 1693341686, 1686661812, 1680018592, 1686598870, 1693143931, 1686659360, 1680208643, 1686601199, 1692961199, 1686657145, 1680384417, 1686603307, 1692792048, 1686655137, 1680547258, 1686605222, 1692635222, 1686653310, 1680698342, 1686606967, 1692489622, 1686651642, 1680838700, 1686608564, 1692354281, 1686650113, 1680969238, 1686610029, 1692228346, 1686648708, 1681090759, 1686611377, 1692111062, 1686647412, 1681203972, 1686612623, 1692001761, 1686646215, 1681309510, 1686613776, 1691899845, 1686645104, 1681407937, 1686614846, 1691804783, 1686644071, 1681499753, 1686615843, 1691716099, 1686643108, 1681585411, 1686616774, 1691633366, 1686642207, 1681665315, 1686617646, 1691556201, 1686641363, 1681739828, 1686618464, 1691484259, 1686640569, 1681809276, 1686619234, 1691417230, 1686639822, 1681873955, 1686619961, 1691354834, 1686639115, 1681934131, 1686620649, 1691296819, 1686638445, 1681990043, 1686621301, 1691242956, 1686637810, 1682041909, 1686621921, 1691193039, 1686637204, 1682089923, 1686622513, 1691146882, 1686636626, 1682134264, 1686623078, 1691104318, 1686636073, 1682175090, 1686623620, 1691065195, 1686635542, 1682212544, 1686624141, 1691029377, 1686635030, 1682246755, 1686624643, 1690996743, 1686634537, 1682277840, 1686625128, 1690967182, 1686634060, 1682305901, 1686625598, 1690940598, 1686633597, 1682331030, 1686626055, 1690916904, 1686633146, 1682353307, 1686626500, 1690896025, 1686632706, 1682372804, 1686626935, 1690877895, 1686632275, 1682389582, 1686627361, 1690862457, 1686631853, 1682403693, 1686627781, 1690849663, 1686631437, 1682415179, 1686628194, 1690839474, 1686631026, 1682424078, 1686628602, 1690831860, 1686630619, 1682430414, 1686629008, 1690826796, 1686630215, 1682434209, 1686629411, 1690824268, 1686629813, 1682435472, 1686629813, 1690824268, 1686629411, 1682434209, 1686630215, 1690826796, 1686629008, 1682430414, 1686630619, 1690831860, 1686628602, 1682424078, 1686631026, 1690839474, 1686628194, 1682415179, 1686631437, 1690849663, 1686627781, 1682403693, 1686631853, 1690862457, 1686627361, 1682389582, 1686632275, 1690877895, 1686626935, 1682372804, 1686632706, 1690896025, 1686626500, 1682353307, 1686633146, 1690916904, 1686626055, 1682331030, 1686633597, 1690940598, 1686625598, 1682305901, 1686634060, 1690967182, 1686625128, 1682277840, 1686634537, 1690996743, 1686624643, 1682246755, 1686635030, 1691029377, 1686624141, 1682212544, 1686635542, 1691065195, 1686623620, 1682175090, 1686636073, 1691104318, 1686623078, 1682134264, 1686636626, 1691146882, 1686622513, 1682089923, 1686637204, 1691193039, 1686621921, 1682041909, 1686637810, 1691242956, 1686621301, 1681990043, 1686638445, 1691296819, 1686620649, 1681934131, 1686639115, 1691354834, 1686619961, 1681873955, 1686639822, 1691417230, 1686619234, 1681809276, 1686640569, 1691484259, 1686618464, 1681739828, 1686641363, 1691556201, 1686617646, 1681665315, 1686642207, 1691633366, 1686616774, 1681585411, 1686643108, 1691716099, 1686615843, 1681499753, 1686644071, 1691804783, 1686614846, 1681407937, 1686645104, 1691899845, 1686613776, 1681309510, 1686646215, 1692001761, 1686612623, 1681203972, 1686647412, 1692111062, 1686611377, 1681090759, 1686648708, 1692228346, 1686610029, 1680969238, 1686650113, 1692354281, 1686608564, 1680838700, 1686651642, 1692489622, 1686606967, 1680698342, 1686653310, 1692635222, 1686605222, 1680547258, 1686655137, 1692792048, 1686603307, 1680384417, 1686657145, 1692961199, 1686601199, 1680208643, 1686659360, 1693143931, 1686598870, 1680018592, 1686661812, 1693341686, 1686596286, 1679812718, 1686664538, 1693556124, 1686593408, 1679589235, 1686667581, 1693789170, 1686590187, 1679346068, 1686670995, 1694043063, 1686586565, 1679080795, 1686674842, 1694320425, 1686582472, 1678790573, 1686679202, 1694624347, 1686577821, 1678472036, 1686684171, 1694958498, 1686572505, 1678121177, 1686689869, 1695327263, 1686566387, 1677733188, 1686696448, 1695735928, 1686559298, 1677302244, 1686704102, 1696190925, 1686551018, 1676821226, 1686713079, 1696700156, 1686541263, 1676281337, 1686723704, 1697273441, 1686529659, 1675671582, 1686736410, 1697923131, 1686515706, 1674978034, 1686751780, 1698664988, 1686498722, 1674182790, 1686770613, 1699519438, 1686477759, 1673262440, 1686794040, 1700513436, 1686451468, 1672185781, 1686823684, 1701683304, 1686417874, 1670910281, 1686861961, 1703079173, 1686374000, 1669376427, 1686912580, 1704772240, 1686315179, 1667498322, 1686981478, 1706867114, 1686233762, 1665147250, 1687078642, 1709523998, 1686116510, 1662121238, 1687221928, 1713001236, 1685938866, 1658084371, 1687445867, 1717743901, 1685651021, 1652433954, 1687824483, 1724589247, 1685139004, 1643970992, 1688540867, 1735318712, 1684092900, 1629925050, 1690155440, 1754499305, 1681407679, 1602161591, 1695123389, 1798287528, 1670563533, 1522711501, 1726974363, 1988510846, 1471847401, 0, -1471847401, -1988510846, -1726974363, -1522711501, -1670563533, -1798287528, -1695123389, -1602161591, -1681407679, -1754499305, -1690155440, -1629925050, -1684092900, -1735318712, -1688540867, -1643970992, -1685139004, -1724589247, -1687824483, -1652433954, -1685651021, -1717743901, -1687445867, -1658084371, -1685938866, -1713001236, -1687221928, -1662121238, -1686116510, -1709523998, -1687078642, -1665147250, -1686233762, -1706867114, -1686981478, -1667498322, -1686315179, -1704772240, -1686912580, -1669376427, -1686374000, -1703079173, -1686861961, -1670910281, -1686417874, -1701683304, -1686823684, -1672185781, -1686451468, -1700513436, -1686794040, -1673262440, -1686477759, -1699519438, -1686770613, -1674182790, -1686498722, -1698664988, -1686751780, -1674978034, -1686515706, -1697923131, -1686736410, -1675671582, -1686529659, -1697273441, -1686723704, -1676281337, -1686541263, -1696700156, -1686713079, -1676821226, -1686551018, -1696190925, -1686704102, -1677302244, -1686559298, -1695735928, -1686696448, -1677733188, -1686566387, -1695327263, -1686689869, -1678121177, -1686572505, -1694958498, -1686684171, -1678472036, -1686577821, -1694624347, -1686679202, -1678790573, -1686582472, -1694320425, -1686674842, -1679080795, -1686586565, -1694043063, -1686670995, -1679346068, -1686590187, -1693789170, -1686667581, -1679589235, -1686593408, -1693556124, -1686664538, -1679812718, -1686596286, -1693341686, -1686661812, -1680018592, -1686598870, -1693143931, -1686659360, -1680208643, -1686601199, -1692961199, -1686657145, -1680384417, -1686603307, -1692792048, -1686655137, -1680547258, -1686605222, -1692635222, -1686653310, -1680698342, -1686606967, -1692489622, -1686651642, -1680838700, -1686608564, -1692354281, -1686650113, -1680969238, -1686610029, -1692228346, -1686648708, -1681090759, -1686611377, -1692111062, -1686647412, -1681203972, -1686612623, -1692001761, -1686646215, -1681309510, -1686613776, -1691899845, -1686645104, -1681407937, -1686614846, -1691804783, -1686644071, -1681499753, -1686615843, -1691716099, -1686643108, -1681585411, -1686616774, -1691633366, -1686642207, -1681665315, -1686617646, -1691556201, -1686641363, -1681739828, -1686618464, -1691484259, -1686640569, -1681809276, -1686619234, -1691417230, -1686639822, -1681873955, -1686619961, -1691354834, -1686639115, -1681934131, -1686620649, -1691296819, -1686638445, -1681990043, -1686621301, -1691242956, -1686637810, -1682041909, -1686621921, -1691193039, -1686637204, -1682089923, -1686622513, -1691146882, -1686636626, -1682134264, -1686623078, -1691104318, -1686636073, -1682175090, -1686623620, -1691065195, -1686635542, -1682212544, -1686624141, -1691029377, -1686635030, -1682246755, -1686624643, -1690996743, -1686634537, -1682277840, -1686625128, -1690967182, -1686634060, -1682305901, -1686625598, -1690940598, -1686633597, -1682331030, -1686626055, -1690916904, -1686633146, -1682353307, -1686626500, -1690896025, -1686632706, -1682372804, -1686626935, -1690877895, -1686632275, -1682389582, -1686627361, -1690862457, -1686631853, -1682403693, -1686627781, -1690849663, -1686631437, -1682415179, -1686628194, -1690839474, -1686631026, -1682424078, -1686628602, -1690831860, -1686630619, -1682430414, -1686629008, -1690826796, -1686630215, -1682434209, -1686629411, -1690824268, -1686629813, -1682435472, -1686629813, -1690824268, -1686629411, -1682434209, -1686630215, -1690826796, -1686629008, -1682430414, -1686630619, -1690831860, -1686628602, -1682424078, -1686631026, -1690839474, -1686628194, -1682415179, -1686631437, -1690849663, -1686627781, -1682403693, -1686631853, -1690862457, -1686627361, -1682389582, -1686632275, -1690877895, -1686626935, -1682372804, -1686632706, -1690896025, -1686626500, -1682353307, -1686633146, -1690916904, -1686626055, -1682331030, -1686633597, -1690940598, -1686625598, -1682305901, -1686634060, -1690967182, -1686625128, -1682277840, -1686634537, -1690996743, -1686624643, -1682246755, -1686635030, -1691029377, -1686624141, -1682212544, -1686635542, -1691065195, -1686623620, -1682175090, -1686636073, -1691104318, -1686623078, -1682134264, -1686636626, -1691146882, -1686622513, -1682089923, -1686637204, -1691193039, -1686621921, -1682041909, -1686637810, -1691242956, -1686621301, -1681990043, -1686638445, -1691296819, -1686620649, -1681934131, -1686639115, -1691354834, -1686619961, -1681873955, -1686639822, -1691417230, -1686619234, -1681809276, -1686640569, -1691484259, -1686618464, -1681739828, -1686641363, -1691556201, -1686617646, -1681665315, -1686642207, -1691633366, -1686616774, -1681585411, -1686643108, -1691716099, -1686615843, -1681499753, -1686644071, -1691804783, -1686614846, -1681407937, -1686645104, -1691899845, -1686613776, -1681309510, -1686646215, -1692001761, -1686612623, -1681203972, -1686647412, -1692111062, -1686611377, -1681090759, -1686648708, -1692228346, -1686610029, -1680969238, -1686650113, -1692354281, -1686608564, -1680838700, -1686651642, -1692489622, -1686606967, -1680698342, -1686653310, -1692635222, -1686605222, -1680547258, -1686655137, -1692792048, -1686603307, -1680384417, -1686657145, -1692961199, -1686601199, -1680208643, -1686659360, -1693143931, -1686598870, -1680018592, -1686661812, -1693341686, -1686596286, -1679812718, -1686664538, -1693556124, -1686593408, -1679589235, -1686667581, -1693789170, -1686590187, -1679346068, -1686670995, -1694043063, -1686586565, -1679080795, -1686674842, -1694320425, -1686582472, -1678790573, -1686679202, -1694624347, -1686577821, -1678472036, -1686684171, -1694958498, -1686572505, -1678121177, -1686689869, -1695327263, -1686566387, -1677733188, -1686696448, -1695735928, -1686559298, -1677302244, -1686704102, -1696190925, -1686551018, -1676821226, -1686713079, -1696700156, -1686541263, -1676281337, -1686723704, -1697273441, -1686529659, -1675671582, -1686736410, -1697923131, -1686515706, -1674978034, -1686751780, -1698664988, -1686498722, -1674182790, -1686770613, -1699519438, -1686477759, -1673262440, -1686794040, -1700513436, -1686451468, -1672185781, -1686823684, -1701683304, -1686417874, -1670910281, -1686861961, -1703079173, -1686374000, -1669376427, -1686912580, -1704772240, -1686315179, -1667498322, -1686981478, -1706867114, -1686233762, -1665147250, -1687078642, -1709523998, -1686116510, -1662121238, -1687221928, -1713001236, -1685938866, -1658084371, -1687445867, -1717743901, -1685651021, -1652433954, -1687824483, -1724589247, -1685139004, -1643970992, -1688540867, -1735318712, -1684092900, -1629925050, -1690155440, -1754499305, -1681407679, -1602161591, -1695123389, -1798287528, -1670563533, -1522711501, -1726974363, -1988510846, -1471847401, ], [0, 814945804, 1471855593, 1868066607, 1988536583, 1902361339, 1726966166, 1577285464, 1522660014, 1568957016, 1670571738, 1763011403, 1798364792, 1766691906, 1695115171, 1628205899, 1602058508, 1626140819, 1681415914, 1733872456, 1754628261, 1735191798, 1690147183, 1646986542, 1629770153, 1646071775, 1684101183, 1720783738, 1735499632, 1721454732, 1688532553, 1656620377, 1643763954, 1656107534, 1685147353, 1713402235, 1724822515, 1713806677, 1687816095, 1662452663, 1652174333, 1662125748, 1685659453, 1708680893, 1718030014, 1708950450, 1687437386, 1666350348, 1657771613, 1666124416, 1685947401, 1705410745, 1713340808, 1705602725, 1687213335, 1669131394, 1661754670, 1668966358, 1686125167, 1703018306, 1709917762, 1703161600, 1687069918, 1671209940, 1664726078, 1671084444, 1686242559, 1701196958, 1707315926, 1701307698, 1686972603, 1672817888, 1667021626, 1672719519, 1686324138, 1699768042, 1705277084, 1699855937, 1686903532, 1674095114, 1668843157, 1674016169, 1686383143, 1698620480, 1703641167, 1698691719, 1686852718, 1675130950, 1670319249, 1675066398, 1686427223, 1697681614, 1702303706, 1697740327, 1686814224, 1675985094, 1671535656, 1675931512, 1686461046, 1696901942, 1701193653, 1696950992, 1686784337, 1676698935, 1672551740, 1676653913, 1686487593, 1696246596, 1700261032, 1696288024, 1686760643, 1677302054, 1673409871, 1677263849, 1686508838, 1695690320, 1699469687, 1695725623, 1686741513, 1677816139, 1674141082, 1677783459, 1686526132, 1695214390, 1698792838, 1695244688, 1686725819, 1678257435, 1674768599, 1678229306, 1686540426, 1694804644, 1698210248, 1694830791, 1686712756, 1678638340, 1675310131, 1678614010, 1686552403, 1694450188, 1697706360, 1694472847, 1686701739, 1678968463, 1675779397, 1678947346, 1686562567, 1694142520, 1697269037, 1694162210, 1686692335, 1679255350, 1676187164, 1679236984, 1686571294, 1693874922, 1696888695, 1693892055, 1686684214, 1679504996, 1676541984, 1679489012, 1686578871, 1693642029, 1696557688, 1693656938, 1686677124, 1679722210, 1676850712, 1679708311, 1686585522, 1693439526, 1696269861, 1693452476, 1686670868, 1679910874, 1677118882, 1679898819, 1686591421, 1693263914, 1696020228, 1693275123, 1686665291, 1680074143, 1677350988, 1680063737, 1686596708, 1693112348, 1695804735, 1693121992, 1686660266, 1680214585, 1677550692, 1680205668, 1686601496, 1692982507, 1695620075, 1692990731, 1686655692, 1680334296, 1677720979, 1680326737, 1686605878, 1692872502, 1695463557, 1692879422, 1686651483, 1680434977, 1677864273, 1680428672, 1686609931, 1692780802, 1695332997, 1692786513, 1686647570, 1680518003, 1677982535, 1680512867, 1686613721, 1692706177, 1695226644, 1692710755, 1686643889, 1680584468, 1678077325, 1680580434, 1686617305, 1692647660, 1695143115, 1692651163, 1686640389, 1680635223, 1678149857, 1680632240, 1686620733, 1692604510, 1695081353, 1692606982, 1686637022, 1680670902, 1678201040, 1680668933, 1686624050, 1692576192, 1695040593, 1692577663, 1686633745, 1680691944, 1678231503, 1680690966, 1686627298, 1692562361, 1695020335, 1692562850, 1686630516, 1680698605, 1678241616, 1680698605, 1686630516, 1692562850, 1695020335, 1692562361, 1686627298, 1680690966, 1678231503, 1680691944, 1686633745, 1692577663, 1695040593, 1692576192, 1686624050, 1680668933, 1678201040, 1680670902, 1686637022, 1692606982, 1695081353, 1692604510, 1686620733, 1680632240, 1678149857, 1680635223, 1686640389, 1692651163, 1695143115, 1692647660, 1686617305, 1680580434, 1678077325, 1680584468, 1686643889, 1692710755, 1695226644, 1692706177, 1686613721, 1680512867, 1677982535, 1680518003, 1686647570, 1692786513, 1695332997, 1692780802, 1686609931, 1680428672, 1677864273, 1680434977, 1686651483, 1692879422, 1695463557, 1692872502, 1686605878, 1680326737, 1677720979, 1680334296, 1686655692, 1692990731, 1695620075, 1692982507, 1686601496, 1680205668, 1677550692, 1680214585, 1686660266, 1693121992, 1695804735, 1693112348, 1686596708, 1680063737, 1677350988, 1680074143, 1686665291, 1693275123, 1696020228, 1693263914, 1686591421, 1679898819, 1677118882, 1679910874, 1686670868, 1693452476, 1696269861, 1693439526, 1686585522, 1679708311, 1676850712, 1679722210, 1686677124, 1693656938, 1696557688, 1693642029, 1686578871, 1679489012, 1676541984, 1679504996, 1686684214, 1693892055, 1696888695, 1693874922, 1686571294, 1679236984, 1676187164, 1679255350, 1686692335, 1694162210, 1697269037, 1694142520, 1686562567, 1678947346, 1675779397, 1678968463, 1686701739, 1694472847, 1697706360, 1694450188, 1686552403, 1678614010, 1675310131, 1678638340, 1686712756, 1694830791, 1698210248, 1694804644, 1686540426, 1678229306, 1674768599, 1678257435, 1686725819, 1695244688, 1698792838, 1695214390, 1686526132, 1677783459, 1674141082, 1677816139, 1686741513, 1695725623, 1699469687, 1695690320, 1686508838, 1677263849, 1673409871, 1677302054, 1686760643, 1696288024, 1700261032, 1696246596, 1686487593, 1676653913, 1672551740, 1676698935, 1686784337, 1696950992, 1701193653, 1696901942, 1686461046, 1675931512, 1671535656, 1675985094, 1686814224, 1697740327, 1702303706, 1697681614, 1686427223, 1675066398, 1670319249, 1675130950, 1686852718, 1698691719, 1703641167, 1698620480, 1686383143, 1674016169, 1668843157, 1674095114, 1686903532, 1699855937, 1705277084, 1699768042, 1686324138, 1672719519, 1667021626, 1672817888, 1686972603, 1701307698, 1707315926, 1701196958, 1686242559, 1671084444, 1664726078, 1671209940, 1687069918, 1703161600, 1709917762, 1703018306, 1686125167, 1668966358, 1661754670, 1669131394, 1687213335, 1705602725, 1713340808, 1705410745, 1685947401, 1666124416, 1657771613, 1666350348, 1687437386, 1708950450, 1718030014, 1708680893, 1685659453, 1662125748, 1652174333, 1662452663, 1687816095, 1713806677, 1724822515, 1713402235, 1685147353, 1656107534, 1643763954, 1656620377, 1688532553, 1721454732, 1735499632, 1720783738, 1684101183, 1646071775, 1629770153, 1646986542, 1690147183, 1735191798, 1754628261, 1733872456, 1681415914, 1626140819, 1602058508, 1628205899, 1695115171, 1766691906, 1798364792, 1763011403, 1670571738, 1568957016, 1522660014, 1577285464, 1726966166, 1902361339, 1988536583, 1868066607, 1471855593, 814945804, 0, -814945804, -1471855593, -1868066607, -1988536583, -1902361339, -1726966166, -1577285464, -1522660014, -1568957016, -1670571738, -1763011403, -1798364792, -1766691906, -1695115171, -1628205899, -1602058508, -1626140819, -1681415914, -1733872456, -1754628261, -1735191798, -1690147183, -1646986542, -1629770153, -1646071775, -1684101183, -1720783738, -1735499632, -1721454732, -1688532553, -1656620377, -1643763954, -1656107534, -1685147353, -1713402235, -1724822515, -1713806677, -1687816095, -1662452663, -1652174333, -1662125748, -1685659453, -1708680893, -1718030014, -1708950450, -1687437386, -1666350348, -1657771613, -1666124416, -1685947401, -1705410745, -1713340808, -1705602725, -1687213335, -1669131394, -1661754670, -1668966358, -1686125167, -1703018306, -1709917762, -1703161600, -1687069918, -1671209940, -1664726078, -1671084444, -1686242559, -1701196958, -1707315926, -1701307698, -1686972603, -1672817888, -1667021626, -1672719519, -1686324138, -1699768042, -1705277084, -1699855937, -1686903532, -1674095114, -1668843157, -1674016169, -1686383143, -1698620480, -1703641167, -1698691719, -1686852718, -1675130950, -1670319249, -1675066398, -1686427223, -1697681614, -1702303706, -1697740327, -1686814224, -1675985094, -1671535656, -1675931512, -1686461046, -1696901942, -1701193653, -1696950992, -1686784337, -1676698935, -1672551740, -1676653913, -1686487593, -1696246596, -1700261032, -1696288024, -1686760643, -1677302054, -1673409871, -1677263849, -1686508838, -1695690320, -1699469687, -1695725623, -1686741513, -1677816139, -1674141082, -1677783459, -1686526132, -1695214390, -1698792838, -1695244688, -1686725819, -1678257435, -1674768599, -1678229306, -1686540426, -1694804644, -1698210248, -1694830791, -1686712756, -1678638340, -1675310131, -1678614010, -1686552403, -1694450188, -1697706360, -1694472847, -1686701739, -1678968463, -1675779397, -1678947346, -1686562567, -1694142520, -1697269037, -1694162210, -1686692335, -1679255350, -1676187164, -1679236984, -1686571294, -1693874922, -1696888695, -1693892055, -1686684214, -1679504996, -1676541984, -1679489012, -1686578871, -1693642029, -1696557688, -1693656938, -1686677124, -1679722210, -1676850712, -1679708311, -1686585522, -1693439526, -1696269861, -1693452476, -1686670868, -1679910874, -1677118882, -1679898819, -1686591421, -1693263914, -1696020228, -1693275123, -1686665291, -1680074143, -1677350988, -1680063737, -1686596708, -1693112348, -1695804735, -1693121992, -1686660266, -1680214585, -1677550692, -1680205668, -1686601496, -1692982507, -1695620075, -1692990731, -1686655692, -1680334296, -1677720979, -1680326737, -1686605878, -1692872502, -1695463557, -1692879422, -1686651483, -1680434977, -1677864273, -1680428672, -1686609931, -1692780802, -1695332997, -1692786513, -1686647570, -1680518003, -1677982535, -1680512867, -1686613721, -1692706177, -1695226644, -1692710755, -1686643889, -1680584468, -1678077325, -1680580434, -1686617305, -1692647660, -1695143115, -1692651163, -1686640389, -1680635223, -1678149857, -1680632240, -1686620733, -1692604510, -1695081353, -1692606982, -1686637022, -1680670902, -1678201040, -1680668933, -1686624050, -1692576192, -1695040593, -1692577663, -1686633745, -1680691944, -1678231503, -1680690966, -1686627298, -1692562361, -1695020335, -1692562850, -1686630516, -1680698605, -1678241616, -1680698605, -1686630516, -1692562850, -1695020335, -1692562361, -1686627298, -1680690966, -1678231503, -1680691944, -1686633745, -1692577663, -1695040593, -1692576192, -1686624050, -1680668933, -1678201040, -1680670902, -1686637022, -1692606982, -1695081353, -1692604510, -1686620733, -1680632240, -1678149857, -1680635223, -1686640389, -1692651163, -1695143115, -1692647660, -1686617305, -1680580434, -1678077325, -1680584468, -1686643889, -1692710755, -1695226644, -1692706177, -1686613721, -1680512867, -1677982535, -1680518003, -1686647570, -1692786513, -1695332997, -1692780802, -1686609931, -1680428672, -1677864273, -1680434977, -1686651483, -1692879422, -1695463557, -1692872502, -1686605878, -1680326737, -1677720979, -1680334296, -1686655692, -1692990731, -1695620075, -1692982507, -1686601496, -1680205668, -1677550692, -1680214585, -1686660266, -1693121992, -1695804735, -1693112348, -1686596708, -1680063737, -1677350988, -1680074143, -1686665291, -1693275123, -1696020228, -1693263914, -1686591421, -1679898819, -1677118882, -1679910874, -1686670868, -1693452476, -1696269861, -1693439526, -1686585522, -1679708311, -1676850712, -1679722210, -1686677124, -1693656938, -1696557688, -1693642029, -1686578871, -1679489012, -1676541984, -1679504996, -1686684214, -1693892055, -1696888695, -1693874922, -1686571294, -1679236984, -1676187164, -1679255350, -1686692335, -1694162210, -1697269037, -1694142520, -1686562567, -1678947346, -1675779397, -1678968463, -1686701739, -1694472847, -1697706360, -1694450188, -1686552403, -1678614010, -1675310131, -1678638340, -1686712756, -1694830791, -1698210248, -1694804644, -1686540426, -1678229306, -1674768599, -1678257435, -1686725819, -1695244688, -1698792838, -1695214390, -1686526132, -1677783459, -1674141082, -1677816139, -1686741513, -1695725623, -1699469687, -1695690320, -1686508838, -1677263849, -1673409871, -1677302054, -1686760643, -1696288024, -1700261032, -1696246596, -1686487593, -1676653913, -1672551740, -1676698935, -1686784337, -1696950992, -1701193653, -1696901942, -1686461046, -1675931512, -1671535656, -1675985094, -1686814224, -1697740327, -1702303706, -1697681614, -1686427223, -1675066398, -1670319249, -1675130950, -1686852718, -1698691719, -1703641167, -1698620480, -1686383143, -1674016169, -1668843157, -1674095114, -1686903532, -1699855937, -1705277084, -1699768042, -1686324138, -1672719519, -1667021626, -1672817888, -1686972603, -1701307698, -1707315926, -1701196958, -1686242559, -1671084444, -1664726078, -1671209940, -1687069918, -1703161600, -1709917762, -1703018306, -1686125167, -1668966358, -1661754670, -1669131394, -1687213335, -1705602725, -1713340808, -1705410745, -1685947401, -1666124416, -1657771613, -1666350348, -1687437386, -1708950450, -1718030014, -1708680893, -1685659453, -1662125748, -1652174333, -1662452663, -1687816095, -1713806677, -1724822515, -1713402235, -1685147353, -1656107534, -1643763954, -1656620377, -1688532553, -1721454732, -1735499632, -1720783738, -1684101183, -1646071775, -1629770153, -1646986542, -1690147183, -1735191798, -1754628261, -1733872456, -1681415914, -1626140819, -1602058508, -1628205899, -1695115171, -1766691906, -1798364792, -1763011403, -1670571738, -1568957016, -1522660014, -1577285464, -1726966166, -1902361339, -1988536583, -1868066607, -1471855593, -814945804, ], [0, 418062472, 814950777, 1171422401, 1471888362, 1705740306, 1868144380, 1960225904, 1988639541, 1964589950, 1902429156, 1818001845, 1726933328, 1643054525, 1577134728, 1536049500, 1522453881, 1534967483, 1568816266, 1616823383, 1670604715, 1721807625, 1763235422, 1789723742, 1798674536, 1790203037, 1766905886, 1733307654, 1695081984, 1658167815, 1627908122, 1608328159, 1601644498, 1608059455, 1625853157, 1651743605, 1681449381, 1710364609, 1734244624, 1749804214, 1755147419, 1749975501, 1735553755, 1714453028, 1690113363, 1666300606, 1646539188, 1633604302, 1629144738, 1633485925, 1645634750, 1663477604, 1684135430, 1704417986, 1721307241, 1732398001, 1736232649, 1732484471, 1721967764, 1706478978, 1688497801, 1670796728, 1656019592, 1646292216, 1642921748, 1646226463, 1655517385, 1669230060, 1685182691, 1700918851, 1714081613, 1722762741, 1725775748, 1722814281, 1714475230, 1702146582, 1687780089, 1673585409, 1661693193, 1653838018, 1651107972, 1653796654, 1661377319, 1672600270, 1685696215, 1698652903, 1709522147, 1716710698, 1719211876, 1716744520, 1709780419, 1699458337, 1687399778, 1675455991, 1665425416, 1658784445, 1656471589, 1658756370, 1665211040, 1674787492, 1685985953, 1697088533, 1706421468, 1712606221, 1714761959, 1712629811, 1706601589, 1697650188, 1687173738, 1676778271, 1668032543, 1662232278, 1660209106, 1662212261, 1667879700, 1676301690, 1686165918, 1695961136, 1704207865, 1709681064, 1711591365, 1709698186, 1704338600, 1696368766, 1687027896, 1677746253, 1669926832, 1664733941, 1662920444, 1664719203, 1669814300, 1677395387, 1686285975, 1695125451, 1702576731, 1707527974, 1709257972, 1707540722, 1702674064, 1695428921, 1686927659, 1678470691, 1671338034, 1666596034, 1664938367, 1666584969, 1671253558, 1678207309, 1686370753, 1694495707, 1701351712, 1705911979, 1707506809, 1705921602, 1701425182, 1694724772, 1686855090, 1679018900, 1672403546, 1668001382, 1666461225, 1667993007, 1672339604, 1678819546, 1686433581, 1694018473, 1700424407, 1704688993, 1706181578, 1704696275, 1700480008, 1694191822, 1686800101, 1679433694, 1673209794, 1669064765, 1667613509, 1669058448, 1673161571, 1679283348, 1686482221, 1693659182, 1699725319, 1703766783, 1705182255, 1703772236, 1699766948, 1693788967, 1686756626, 1679743137, 1673813031, 1669860793, 1668476128, 1669856121, 1673777358, 1679631922, 1686521485, 1693394973, 1699208714, 1703084716, 1704443090, 1703088676, 1699238951, 1693489240, 1686720791, 1679965869, 1674250510, 1670438850, 1669102628, 1670435546, 1674225288, 1679887237, 1686554541, 1693210680, 1698844278, 1702602604, 1703920509, 1702605296, 1698864824, 1693274734, 1686689967, 1680114148, 1674546782, 1670831491, 1669528312, 1670829377, 1674530646, 1680063841, 1686583605, 1693096521, 1698612328, 1702294328, 1703586186, 1702295891, 1698624259, 1693133719, 1686662249, 1680195612, 1674717342, 1671059306, 1669775509, 1671058274, 1674709467, 1680171063, 1686610347, 1693046755, 1698501047, 1702144148, 1703423046, 1702144660, 1698504961, 1693058955, 1686636142, 1680214283, 1674770702, 1671133689, 1669856587, 1671133689, 1674770702, 1680214283, 1686636142, 1693058955, 1698504961, 1702144660, 1703423046, 1702144148, 1698501047, 1693046755, 1686610347, 1680171063, 1674709467, 1671058274, 1669775509, 1671059306, 1674717342, 1680195612, 1686662249, 1693133719, 1698624259, 1702295891, 1703586186, 1702294328, 1698612328, 1693096521, 1686583605, 1680063841, 1674530646, 1670829377, 1669528312, 1670831491, 1674546782, 1680114148, 1686689967, 1693274734, 1698864824, 1702605296, 1703920509, 1702602604, 1698844278, 1693210680, 1686554541, 1679887237, 1674225288, 1670435546, 1669102628, 1670438850, 1674250510, 1679965869, 1686720791, 1693489240, 1699238951, 1703088676, 1704443090, 1703084716, 1699208714, 1693394973, 1686521485, 1679631922, 1673777358, 1669856121, 1668476128, 1669860793, 1673813031, 1679743137, 1686756626, 1693788967, 1699766948, 1703772236, 1705182255, 1703766783, 1699725319, 1693659182, 1686482221, 1679283348, 1673161571, 1669058448, 1667613509, 1669064765, 1673209794, 1679433694, 1686800101, 1694191822, 1700480008, 1704696275, 1706181578, 1704688993, 1700424407, 1694018473, 1686433581, 1678819546, 1672339604, 1667993007, 1666461225, 1668001382, 1672403546, 1679018900, 1686855090, 1694724772, 1701425182, 1705921602, 1707506809, 1705911979, 1701351712, 1694495707, 1686370753, 1678207309, 1671253558, 1666584969, 1664938367, 1666596034, 1671338034, 1678470691, 1686927659, 1695428921, 1702674064, 1707540722, 1709257972, 1707527974, 1702576731, 1695125451, 1686285975, 1677395387, 1669814300, 1664719203, 1662920444, 1664733941, 1669926832, 1677746253, 1687027896, 1696368766, 1704338600, 1709698186, 1711591365, 1709681064, 1704207865, 1695961136, 1686165918, 1676301690, 1667879700, 1662212261, 1660209106, 1662232278, 1668032543, 1676778271, 1687173738, 1697650188, 1706601589, 1712629811, 1714761959, 1712606221, 1706421468, 1697088533, 1685985953, 1674787492, 1665211040, 1658756370, 1656471589, 1658784445, 1665425416, 1675455991, 1687399778, 1699458337, 1709780419, 1716744520, 1719211876, 1716710698, 1709522147, 1698652903, 1685696215, 1672600270, 1661377319, 1653796654, 1651107972, 1653838018, 1661693193, 1673585409, 1687780089, 1702146582, 1714475230, 1722814281, 1725775748, 1722762741, 1714081613, 1700918851, 1685182691, 1669230060, 1655517385, 1646226463, 1642921748, 1646292216, 1656019592, 1670796728, 1688497801, 1706478978, 1721967764, 1732484471, 1736232649, 1732398001, 1721307241, 1704417986, 1684135430, 1663477604, 1645634750, 1633485925, 1629144738, 1633604302, 1646539188, 1666300606, 1690113363, 1714453028, 1735553755, 1749975501, 1755147419, 1749804214, 1734244624, 1710364609, 1681449381, 1651743605, 1625853157, 1608059455, 1601644498, 1608328159, 1627908122, 1658167815, 1695081984, 1733307654, 1766905886, 1790203037, 1798674536, 1789723742, 1763235422, 1721807625, 1670604715, 1616823383, 1568816266, 1534967483, 1522453881, 1536049500, 1577134728, 1643054525, 1726933328, 1818001845, 1902429156, 1964589950, 1988639541, 1960225904, 1868144380, 1705740306, 1471888362, 1171422401, 814950777, 418062472, 0, -418062472, -814950777, -1171422401, -1471888362, -1705740306, -1868144380, -1960225904, -1988639541, -1964589950, -1902429156, -1818001845, -1726933328, -1643054525, -1577134728, -1536049500, -1522453881, -1534967483, -1568816266, -1616823383, -1670604715, -1721807625, -1763235422, -1789723742, -1798674536, -1790203037, -1766905886, -1733307654, -1695081984, -1658167815, -1627908122, -1608328159, -1601644498, -1608059455, -1625853157, -1651743605, -1681449381, -1710364609, -1734244624, -1749804214, -1755147419, -1749975501, -1735553755, -1714453028, -1690113363, -1666300606, -1646539188, -1633604302, -1629144738, -1633485925, -1645634750, -1663477604, -1684135430, -1704417986, -1721307241, -1732398001, -1736232649, -1732484471, -1721967764, -1706478978, -1688497801, -1670796728, -1656019592, -1646292216, -1642921748, -1646226463, -1655517385, -1669230060, -1685182691, -1700918851, -1714081613, -1722762741, -1725775748, -1722814281, -1714475230, -1702146582, -1687780089, -1673585409, -1661693193, -1653838018, -1651107972, -1653796654, -1661377319, -1672600270, -1685696215, -1698652903, -1709522147, -1716710698, -1719211876, -1716744520, -1709780419, -1699458337, -1687399778, -1675455991, -1665425416, -1658784445, -1656471589, -1658756370, -1665211040, -1674787492, -1685985953, -1697088533, -1706421468, -1712606221, -1714761959, -1712629811, -1706601589, -1697650188, -1687173738, -1676778271, -1668032543, -1662232278, -1660209106, -1662212261, -1667879700, -1676301690, -1686165918, -1695961136, -1704207865, -1709681064, -1711591365, -1709698186, -1704338600, -1696368766, -1687027896, -1677746253, -1669926832, -1664733941, -1662920444, -1664719203, -1669814300, -1677395387, -1686285975, -1695125451, -1702576731, -1707527974, -1709257972, -1707540722, -1702674064, -1695428921, -1686927659, -1678470691, -1671338034, -1666596034, -1664938367, -1666584969, -1671253558, -1678207309, -1686370753, -1694495707, -1701351712, -1705911979, -1707506809, -1705921602, -1701425182, -1694724772, -1686855090, -1679018900, -1672403546, -1668001382, -1666461225, -1667993007, -1672339604, -1678819546, -1686433581, -1694018473, -1700424407, -1704688993, -1706181578, -1704696275, -1700480008, -1694191822, -1686800101, -1679433694, -1673209794, -1669064765, -1667613509, -1669058448, -1673161571, -1679283348, -1686482221, -1693659182, -1699725319, -1703766783, -1705182255, -1703772236, -1699766948, -1693788967, -1686756626, -1679743137, -1673813031, -1669860793, -1668476128, -1669856121, -1673777358, -1679631922, -1686521485, -1693394973, -1699208714, -1703084716, -1704443090, -1703088676, -1699238951, -1693489240, -1686720791, -1679965869, -1674250510, -1670438850, -1669102628, -1670435546, -1674225288, -1679887237, -1686554541, -1693210680, -1698844278, -1702602604, -1703920509, -1702605296, -1698864824, -1693274734, -1686689967, -1680114148, -1674546782, -1670831491, -1669528312, -1670829377, -1674530646, -1680063841, -1686583605, -1693096521, -1698612328, -1702294328, -1703586186, -1702295891, -1698624259, -1693133719, -1686662249, -1680195612, -1674717342, -1671059306, -1669775509, -1671058274, -1674709467, -1680171063, -1686610347, -1693046755, -1698501047, -1702144148, -1703423046, -1702144660, -1698504961, -1693058955, -1686636142, -1680214283, -1674770702, -1671133689, -1669856587, -1671133689, -1674770702, -1680214283, -1686636142, -1693058955, -1698504961, -1702144660, -1703423046, -1702144148, -1698501047, -1693046755, -1686610347, -1680171063, -1674709467, -1671058274, -1669775509, -1671059306, -1674717342, -1680195612, -1686662249, -1693133719, -1698624259, -1702295891, -1703586186, -1702294328, -1698612328, -1693096521, -1686583605, -1680063841, -1674530646, -1670829377, -1669528312, -1670831491, -1674546782, -1680114148, -1686689967, -1693274734, -1698864824, -1702605296, -1703920509, -1702602604, -1698844278, -1693210680, -1686554541, -1679887237, -1674225288, -1670435546, -1669102628, -1670438850, -1674250510, -1679965869, -1686720791, -1693489240, -1699238951, -1703088676, -1704443090, -1703084716, -1699208714, -1693394973, -1686521485, -1679631922, -1673777358, -1669856121, -1668476128, -1669860793, -1673813031, -1679743137, -1686756626, -1693788967, -1699766948, -1703772236, -1705182255, -1703766783, -1699725319, -1693659182, -1686482221, -1679283348, -1673161571, -1669058448, -1667613509, -1669064765, -1673209794, -1679433694, -1686800101, -1694191822, -1700480008, -1704696275, -1706181578, -1704688993, -1700424407, -1694018473, -1686433581, -1678819546, -1672339604, -1667993007, -1666461225, -1668001382, -1672403546, -1679018900, -1686855090, -1694724772, -1701425182, -1705921602, -1707506809, -1705911979, -1701351712, -1694495707, -1686370753, -1678207309, -1671253558, -1666584969, -1664938367, -1666596034, -1671338034, -1678470691, -1686927659, -1695428921, -1702674064, -1707540722, -1709257972, -1707527974, -1702576731, -1695125451, -1686285975, -1677395387, -1669814300, -1664719203, -1662920444, -1664733941, -1669926832, -1677746253, -1687027896, -1696368766, -1704338600, -1709698186, -1711591365, -1709681064, -1704207865, -1695961136, -1686165918, -1676301690, -1667879700, -1662212261, -1660209106, -1662232278, -1668032543, -1676778271, -1687173738, -1697650188, -1706601589, -1712629811, -1714761959, -1712606221, -1706421468, -1697088533, -1685985953, -1674787492, -1665211040, -1658756370, -1656471589, -1658784445, -1665425416, -1675455991, -1687399778, -1699458337, -1709780419, -1716744520, -1719211876, -1716710698, -1709522147, -1698652903, -1685696215, -1672600270, -1661377319, -1653796654, -1651107972, -1653838018, -1661693193, -1673585409, -1687780089, -1702146582, -1714475230, -1722814281, -1725775748, -1722762741, -1714081613, -1700918851, -1685182691, -1669230060, -1655517385, -1646226463, -1642921748, -1646292216, -1656019592, -1670796728, -1688497801, -1706478978, -1721967764, -1732484471, -1736232649, -1732398001, -1721307241, -1704417986, -1684135430, -1663477604, -1645634750, -1633485925, -1629144738, -1633604302, -1646539188, -1666300606, -1690113363, -1714453028, -1735553755, -1749975501, -1755147419, -1749804214, -1734244624, -1710364609, -1681449381, -1651743605, -1625853157, -1608059455, -1601644498, -1608328159, -1627908122, -1658167815, -1695081984, -1733307654, -1766905886, -1790203037, -1798674536, -1789723742, -1763235422, -1721807625, -1670604715, -1616823383, -1568816266, -1534967483, -1522453881, -1536049500, -1577134728, -1643054525, -1726933328, -1818001845, -1902429156, -1964589950, -1988639541, -1960225904, -1868144380, -1705740306, -1471888362, -1171422401, -814950777, -418062472, ], [0, 210378114, 418065078, 620431643, 814970667, 999353987, 1171484411, 1329541433, 1472019461, 1597757555, 1705959953, 1796206895, 1868455565, 1923031258, 1960609132, 1982187216, 1989051542, 1982734538, 1964967946, 1937631716, 1902700391, 1862188560, 1818096949, 1772360679, 1726801121, 1683082646, 1642675383, 1606824918, 1576529617, 1552526005, 1535282408, 1525000779, 1521626393, 1524864860, 1534205690, 1548951482, 1568251613, 1591139245, 1616570367, 1643463551, 1670739161, 1697356744, 1722349491, 1744854729, 1764139608, 1779621299, 1790881226, 1797673080, 1799924552, 1797732959, 1791355109, 1781191958, 1767768755, 1751711509, 1733720717, 1714543348, 1694944119, 1675677088, 1657458553, 1640942167, 1626697081, 1615189808, 1606770327, 1601662815, 1599961200, 1601629555, 1606507195, 1614318165, 1624684659, 1637143782, 1651166975, 1666181321, 1681591922, 1696804514, 1711247484, 1724392508, 1735773104, 1745000456, 1751776003, 1755900416, 1757278701, 1755921339, 1751941505, 1745548549, 1737038067, 1726778984, 1715198204, 1702763422, 1689964785, 1677296087, 1665236213, 1654231497, 1644679642, 1636915757, 1631200994, 1627714147, 1626546487, 1627699946, 1631088672, 1636543832, 1643821432, 1652612803, 1662557330, 1673256907, 1684291548, 1695235561, 1705673676, 1715216512, 1723514838, 1730272097, 1735254760, 1738300168, 1739321577, 1738310294, 1735334843, 1730537245, 1724126591, 1716370185, 1707582622, 1698113244, 1688332460, 1678617457, 1669337851, 1660841813, 1653443183, 1647410047, 1642955177, 1640228687, 1639313135, 1640221232, 1642896216, 1647214840, 1652992836, 1659992608, 1667932859, 1676499753, 1685359173, 1694169617, 1702595225, 1710318447, 1717051892, 1722548899, 1726612466, 1729102206, 1729939085, 1729107802, 1726656727, 1722695432, 1717389933, 1710955839, 1703649695, 1695758848, 1687590252, 1679458625, 1671674427, 1664532098, 1658299019, 1653205583, 1649436761, 1647125459, 1646347906, 1647121223, 1649403258, 1653094671, 1658043165, 1664049695, 1670876405, 1678255973, 1685901997, 1693520044, 1700818901, 1707521642, 1713376059, 1718164088, 1721709869, 1723886156, 1724618827, 1723889351, 1721735136, 1718247733, 1713569011, 1707885434, 1701420684, 1694426917, 1687174981, 1679943993, 1673010653, 1666638718, 1661069023, 1656510430, 1653132046, 1651056989, 1650357938, 1651054626, 1653113356, 1656448555, 1660926293, 1666369622, 1672565527, 1679273219, 1686233445, 1693178456, 1699842257, 1705970749, 1711331364, 1715721853, 1718977876, 1720979119, 1721653712, 1720980790, 1718991091, 1715765601, 1711432277, 1706161002, 1700156960, 1693652681, 1686899078, 1680155815, 1673681359, 1667723124, 1662508052, 1658234011, 1655062317, 1653111678, 1652453766, 1653110608, 1655053863, 1658206022, 1662443491, 1667601406, 1673480025, 1679852430, 1686473247, 1693088061, 1699443146, 1705295164, 1710420441, 1714623484, 1717744403, 1719664976, 1720313102, 1719665498, 1717748529, 1714637142, 1710451946, 1705354560, 1699541392, 1693236103, 1686681038, 1680127942, 1673828334, 1668023891, 1662937210, 1658763306, 1655662165, 1653752635, 1653107890, 1653752635, 1655662165, 1658763306, 1662937210, 1668023891, 1673828334, 1680127942, 1686681038, 1693236103, 1699541392, 1705354560, 1710451946, 1714637142, 1717748529, 1719665498, 1720313102, 1719664976, 1717744403, 1714623484, 1710420441, 1705295164, 1699443146, 1693088061, 1686473247, 1679852430, 1673480025, 1667601406, 1662443491, 1658206022, 1655053863, 1653110608, 1652453766, 1653111678, 1655062317, 1658234011, 1662508052, 1667723124, 1673681359, 1680155815, 1686899078, 1693652681, 1700156960, 1706161002, 1711432277, 1715765601, 1718991091, 1720980790, 1721653712, 1720979119, 1718977876, 1715721853, 1711331364, 1705970749, 1699842257, 1693178456, 1686233445, 1679273219, 1672565527, 1666369622, 1660926293, 1656448555, 1653113356, 1651054626, 1650357938, 1651056989, 1653132046, 1656510430, 1661069023, 1666638718, 1673010653, 1679943993, 1687174981, 1694426917, 1701420684, 1707885434, 1713569011, 1718247733, 1721735136, 1723889351, 1724618827, 1723886156, 1721709869, 1718164088, 1713376059, 1707521642, 1700818901, 1693520044, 1685901997, 1678255973, 1670876405, 1664049695, 1658043165, 1653094671, 1649403258, 1647121223, 1646347906, 1647125459, 1649436761, 1653205583, 1658299019, 1664532098, 1671674427, 1679458625, 1687590252, 1695758848, 1703649695, 1710955839, 1717389933, 1722695432, 1726656727, 1729107802, 1729939085, 1729102206, 1726612466, 1722548899, 1717051892, 1710318447, 1702595225, 1694169617, 1685359173, 1676499753, 1667932859, 1659992608, 1652992836, 1647214840, 1642896216, 1640221232, 1639313135, 1640228687, 1642955177, 1647410047, 1653443183, 1660841813, 1669337851, 1678617457, 1688332460, 1698113244, 1707582622, 1716370185, 1724126591, 1730537245, 1735334843, 1738310294, 1739321577, 1738300168, 1735254760, 1730272097, 1723514838, 1715216512, 1705673676, 1695235561, 1684291548, 1673256907, 1662557330, 1652612803, 1643821432, 1636543832, 1631088672, 1627699946, 1626546487, 1627714147, 1631200994, 1636915757, 1644679642, 1654231497, 1665236213, 1677296087, 1689964785, 1702763422, 1715198204, 1726778984, 1737038067, 1745548549, 1751941505, 1755921339, 1757278701, 1755900416, 1751776003, 1745000456, 1735773104, 1724392508, 1711247484, 1696804514, 1681591922, 1666181321, 1651166975, 1637143782, 1624684659, 1614318165, 1606507195, 1601629555, 1599961200, 1601662815, 1606770327, 1615189808, 1626697081, 1640942167, 1657458553, 1675677088, 1694944119, 1714543348, 1733720717, 1751711509, 1767768755, 1781191958, 1791355109, 1797732959, 1799924552, 1797673080, 1790881226, 1779621299, 1764139608, 1744854729, 1722349491, 1697356744, 1670739161, 1643463551, 1616570367, 1591139245, 1568251613, 1548951482, 1534205690, 1524864860, 1521626393, 1525000779, 1535282408, 1552526005, 1576529617, 1606824918, 1642675383, 1683082646, 1726801121, 1772360679, 1818096949, 1862188560, 1902700391, 1937631716, 1964967946, 1982734538, 1989051542, 1982187216, 1960609132, 1923031258, 1868455565, 1796206895, 1705959953, 1597757555, 1472019461, 1329541433, 1171484411, 999353987, 814970667, 620431643, 418065078, 210378114, 0, -210378114, -418065078, -620431643, -814970667, -999353987, -1171484411, -1329541433, -1472019461, -1597757555, -1705959953, -1796206895, -1868455565, -1923031258, -1960609132, -1982187216, -1989051542, -1982734538, -1964967946, -1937631716, -1902700391, -1862188560, -1818096949, -1772360679, -1726801121, -1683082646, -1642675383, -1606824918, -1576529617, -1552526005, -1535282408, -1525000779, -1521626393, -1524864860, -1534205690, -1548951482, -1568251613, -1591139245, -1616570367, -1643463551, -1670739161, -1697356744, -1722349491, -1744854729, -1764139608, -1779621299, -1790881226, -1797673080, -1799924552, -1797732959, -1791355109, -1781191958, -1767768755, -1751711509, -1733720717, -1714543348, -1694944119, -1675677088, -1657458553, -1640942167, -1626697081, -1615189808, -1606770327, -1601662815, -1599961200, -1601629555, -1606507195, -1614318165, -1624684659, -1637143782, -1651166975, -1666181321, -1681591922, -1696804514, -1711247484, -1724392508, -1735773104, -1745000456, -1751776003, -1755900416, -1757278701, -1755921339, -1751941505, -1745548549, -1737038067, -1726778984, -1715198204, -1702763422, -1689964785, -1677296087, -1665236213, -1654231497, -1644679642, -1636915757, -1631200994, -1627714147, -1626546487, -1627699946, -1631088672, -1636543832, -1643821432, -1652612803, -1662557330, -1673256907, -1684291548, -1695235561, -1705673676, -1715216512, -1723514838, -1730272097, -1735254760, -1738300168, -1739321577, -1738310294, -1735334843, -1730537245, -1724126591, -1716370185, -1707582622, -1698113244, -1688332460, -1678617457, -1669337851, -1660841813, -1653443183, -1647410047, -1642955177, -1640228687, -1639313135, -1640221232, -1642896216, -1647214840, -1652992836, -1659992608, -1667932859, -1676499753, -1685359173, -1694169617, -1702595225, -1710318447, -1717051892, -1722548899, -1726612466, -1729102206, -1729939085, -1729107802, -1726656727, -1722695432, -1717389933, -1710955839, -1703649695, -1695758848, -1687590252, -1679458625, -1671674427, -1664532098, -1658299019, -1653205583, -1649436761, -1647125459, -1646347906, -1647121223, -1649403258, -1653094671, -1658043165, -1664049695, -1670876405, -1678255973, -1685901997, -1693520044, -1700818901, -1707521642, -1713376059, -1718164088, -1721709869, -1723886156, -1724618827, -1723889351, -1721735136, -1718247733, -1713569011, -1707885434, -1701420684, -1694426917, -1687174981, -1679943993, -1673010653, -1666638718, -1661069023, -1656510430, -1653132046, -1651056989, -1650357938, -1651054626, -1653113356, -1656448555, -1660926293, -1666369622, -1672565527, -1679273219, -1686233445, -1693178456, -1699842257, -1705970749, -1711331364, -1715721853, -1718977876, -1720979119, -1721653712, -1720980790, -1718991091, -1715765601, -1711432277, -1706161002, -1700156960, -1693652681, -1686899078, -1680155815, -1673681359, -1667723124, -1662508052, -1658234011, -1655062317, -1653111678, -1652453766, -1653110608, -1655053863, -1658206022, -1662443491, -1667601406, -1673480025, -1679852430, -1686473247, -1693088061, -1699443146, -1705295164, -1710420441, -1714623484, -1717744403, -1719664976, -1720313102, -1719665498, -1717748529, -1714637142, -1710451946, -1705354560, -1699541392, -1693236103, -1686681038, -1680127942, -1673828334, -1668023891, -1662937210, -1658763306, -1655662165, -1653752635, -1653107890, -1653752635, -1655662165, -1658763306, -1662937210, -1668023891, -1673828334, -1680127942, -1686681038, -1693236103, -1699541392, -1705354560, -1710451946, -1714637142, -1717748529, -1719665498, -1720313102, -1719664976, -1717744403, -1714623484, -1710420441, -1705295164, -1699443146, -1693088061, -1686473247, -1679852430, -1673480025, -1667601406, -1662443491, -1658206022, -1655053863, -1653110608, -1652453766, -1653111678, -1655062317, -1658234011, -1662508052, -1667723124, -1673681359, -1680155815, -1686899078, -1693652681, -1700156960, -1706161002, -1711432277, -1715765601, -1718991091, -1720980790, -1721653712, -1720979119, -1718977876, -1715721853, -1711331364, -1705970749, -1699842257, -1693178456, -1686233445, -1679273219, -1672565527, -1666369622, -1660926293, -1656448555, -1653113356, -1651054626, -1650357938, -1651056989, -1653132046, -1656510430, -1661069023, -1666638718, -1673010653, -1679943993, -1687174981, -1694426917, -1701420684, -1707885434, -1713569011, -1718247733, -1721735136, -1723889351, -1724618827, -1723886156, -1721709869, -1718164088, -1713376059, -1707521642, -1700818901, -1693520044, -1685901997, -1678255973, -1670876405, -1664049695, -1658043165, -1653094671, -1649403258, -1647121223, -1646347906, -1647125459, -1649436761, -1653205583, -1658299019, -1664532098, -1671674427, -1679458625, -1687590252, -1695758848, -1703649695, -1710955839, -1717389933, -1722695432, -1726656727, -1729107802, -1729939085, -1729102206, -1726612466, -1722548899, -1717051892, -1710318447, -1702595225, -1694169617, -1685359173, -1676499753, -1667932859, -1659992608, -1652992836, -1647214840, -1642896216, -1640221232, -1639313135, -1640228687, -1642955177, -1647410047, -1653443183, -1660841813, -1669337851, -1678617457, -1688332460, -1698113244, -1707582622, -1716370185, -1724126591, -1730537245, -1735334843, -1738310294, -1739321577, -1738300168, -1735254760, -1730272097, -1723514838, -1715216512, -1705673676, -1695235561, -1684291548, -1673256907, -1662557330, -1652612803, -1643821432, -1636543832, -1631088672, -1627699946, -1626546487, -1627714147, -1631200994, -1636915757, -1644679642, -1654231497, -1665236213, -1677296087, -1689964785, -1702763422, -1715198204, -1726778984, -1737038067, -1745548549, -1751941505, -1755921339, -1757278701, -1755900416, -1751776003, -1745000456, -1735773104, -1724392508, -1711247484, -1696804514, -1681591922, -1666181321, -1651166975, -1637143782, -1624684659, -1614318165, -1606507195, -1601629555, -1599961200, -1601662815, -1606770327, -1615189808, -1626697081, -1640942167, -1657458553, -1675677088, -1694944119, -1714543348, -1733720717, -1751711509, -1767768755, -1781191958, -1791355109, -1797732959, -1799924552, -1797673080, -1790881226, -1779621299, -1764139608, -1744854729, -1722349491, -1697356744, -1670739161, -1643463551, -1616570367, -1591139245, -1568251613, -1548951482, -1534205690, -1524864860, -1521626393, -1525000779, -1535282408, -1552526005, -1576529617, -1606824918, -1642675383, -1683082646, -1726801121, -1772360679, -1818096949, -1862188560, -1902700391, -1937631716, -1964967946, -1982734538, -1989051542, -1982187216, -1960609132, -1923031258, -1868455565, -1796206895, -1705959953, -1597757555, -1472019461, -1329541433, -1171484411, -999353987, -814970667, -620431643, -418065078, -210378114, ], [0, 105358148, 210379431, 314728913, 418075499, 520093834, 620466142, 718884035, 815050243, 908680276, 999504003, 1087267121, 1171732535, 1252681608, 1329915296, 1403255147, 1472544168, 1537647543, 1598453210, 1654872286, 1706839337, 1754312500, 1797273450, 1835727213, 1869701829, 1899247877, 1924437845, 1945365374, 1962144367, 1974907974, 1983807466, 1989010999, 1990702281, 1989079156, 1984352108, 1976742705, 1966481983, 1953808796, 1938968132, 1922209416, 1903784800, 1883947475, 1862949985, 1841042588, 1818471648, 1795478086, 1772295894, 1749150727, 1726258565, 1703824487, 1682041527, 1661089644, 1641134807, 1622328193, 1604805507, 1588686435, 1574074215, 1561055342, 1549699404, 1540059041, 1532170037, 1526051534, 1521706364, 1519121505, 1518268640, 1519104827, 1521573263, 1525604146, 1531115610, 1538014745, 1546198671, 1555555681, 1565966415, 1577305084, 1589440708, 1602238379, 1615560521, 1629268152, 1643222125, 1657284350, 1671318975, 1685193532, 1698780025, 1711955959, 1724605305, 1736619391, 1747897706, 1758348629, 1767890059, 1776449957, 1783966789, 1790389871, 1795679610, 1799807652, 1802756921, 1804521565, 1805106806, 1804528687, 1802813737, 1799998549, 1796129272, 1791261029, 1785457268, 1778789045, 1771334258, 1763176822, 1754405814, 1745114578, 1735399811, 1725360627, 1715097618, 1704711913, 1694304246, 1683974043, 1673818531, 1663931878, 1654404378, 1645321676, 1636764049, 1628805738, 1621514359, 1614950360, 1609166568, 1604207798, 1600110549, 1596902773, 1594603723, 1593223890, 1592765008, 1593220151, 1594573899, 1596802580, 1599874589, 1603750772, 1608384875, 1613724049, 1619709421, 1626276700, 1633356833, 1640876699, 1648759827, 1656927147, 1665297742, 1673789627, 1682320513, 1690808583, 1699173241, 1707335851, 1715220451, 1722754431, 1729869182, 1736500688, 1742590091, 1748084182, 1752935853, 1757104477, 1760556233, 1763264361, 1765209350, 1766379060, 1766768775, 1766381184, 1765226296, 1763321288, 1760690288, 1757364096, 1753379843, 1748780596, 1743614914, 1737936350, 1731802912, 1725276493, 1718422263, 1711308032, 1704003602, 1696580098, 1689109293, 1681662931, 1674312054, 1667126347, 1660173483, 1653518512, 1647223260, 1641345771, 1635939788, 1631054268, 1626732956, 1623014002, 1619929633, 1617505888, 1615762400, 1614712250, 1614361881, 1614711065, 1615752945, 1617474130, 1619854851, 1622869181, 1626485305, 1630665850, 1635368255, 1640545202, 1646145075, 1652112469, 1658388724, 1664912493, 1671620324, 1678447275, 1685327521, 1692194982, 1698983947, 1705629686, 1712069062, 1718241114, 1724087628, 1729553673, 1734588110, 1739144060, 1743179332, 1746656809, 1749544779, 1751817224, 1753454040, 1754441223, 1754770972, 1754441759, 1753458317, 1751831589, 1749578606, 1746722314, 1743291346, 1739319737, 1734846596, 1729915728, 1724575207, 1718876919, 1712876068, 1706630643, 1700200876, 1693648662, 1687036978, 1680429285, 1673888931, 1667478548, 1661259471, 1655291150, 1649630598, 1644331853, 1639445469, 1635018043, 1631091776, 1627704080, 1624887221, 1622668027, 1621067624, 1620101248, 1619778097, 1620101248, 1621067624, 1622668027, 1624887221, 1627704080, 1631091776, 1635018043, 1639445469, 1644331853, 1649630598, 1655291150, 1661259471, 1667478548, 1673888931, 1680429285, 1687036978, 1693648662, 1700200876, 1706630643, 1712876068, 1718876919, 1724575207, 1729915728, 1734846596, 1739319737, 1743291346, 1746722314, 1749578606, 1751831589, 1753458317, 1754441759, 1754770972, 1754441223, 1753454040, 1751817224, 1749544779, 1746656809, 1743179332, 1739144060, 1734588110, 1729553673, 1724087628, 1718241114, 1712069062, 1705629686, 1698983947, 1692194982, 1685327521, 1678447275, 1671620324, 1664912493, 1658388724, 1652112469, 1646145075, 1640545202, 1635368255, 1630665850, 1626485305, 1622869181, 1619854851, 1617474130, 1615752945, 1614711065, 1614361881, 1614712250, 1615762400, 1617505888, 1619929633, 1623014002, 1626732956, 1631054268, 1635939788, 1641345771, 1647223260, 1653518512, 1660173483, 1667126347, 1674312054, 1681662931, 1689109293, 1696580098, 1704003602, 1711308032, 1718422263, 1725276493, 1731802912, 1737936350, 1743614914, 1748780596, 1753379843, 1757364096, 1760690288, 1763321288, 1765226296, 1766381184, 1766768775, 1766379060, 1765209350, 1763264361, 1760556233, 1757104477, 1752935853, 1748084182, 1742590091, 1736500688, 1729869182, 1722754431, 1715220451, 1707335851, 1699173241, 1690808583, 1682320513, 1673789627, 1665297742, 1656927147, 1648759827, 1640876699, 1633356833, 1626276700, 1619709421, 1613724049, 1608384875, 1603750772, 1599874589, 1596802580, 1594573899, 1593220151, 1592765008, 1593223890, 1594603723, 1596902773, 1600110549, 1604207798, 1609166568, 1614950360, 1621514359, 1628805738, 1636764049, 1645321676, 1654404378, 1663931878, 1673818531, 1683974043, 1694304246, 1704711913, 1715097618, 1725360627, 1735399811, 1745114578, 1754405814, 1763176822, 1771334258, 1778789045, 1785457268, 1791261029, 1796129272, 1799998549, 1802813737, 1804528687, 1805106806, 1804521565, 1802756921, 1799807652, 1795679610, 1790389871, 1783966789, 1776449957, 1767890059, 1758348629, 1747897706, 1736619391, 1724605305, 1711955959, 1698780025, 1685193532, 1671318975, 1657284350, 1643222125, 1629268152, 1615560521, 1602238379, 1589440708, 1577305084, 1565966415, 1555555681, 1546198671, 1538014745, 1531115610, 1525604146, 1521573263, 1519104827, 1518268640, 1519121505, 1521706364, 1526051534, 1532170037, 1540059041, 1549699404, 1561055342, 1574074215, 1588686435, 1604805507, 1622328193, 1641134807, 1661089644, 1682041527, 1703824487, 1726258565, 1749150727, 1772295894, 1795478086, 1818471648, 1841042588, 1862949985, 1883947475, 1903784800, 1922209416, 1938968132, 1953808796, 1966481983, 1976742705, 1984352108, 1989079156, 1990702281, 1989010999, 1983807466, 1974907974, 1962144367, 1945365374, 1924437845, 1899247877, 1869701829, 1835727213, 1797273450, 1754312500, 1706839337, 1654872286, 1598453210, 1537647543, 1472544168, 1403255147, 1329915296, 1252681608, 1171732535, 1087267121, 999504003, 908680276, 815050243, 718884035, 620466142, 520093834, 418075499, 314728913, 210379431, 105358148, 0, -105358148, -210379431, -314728913, -418075499, -520093834, -620466142, -718884035, -815050243, -908680276, -999504003, -1087267121, -1171732535, -1252681608, -1329915296, -1403255147, -1472544168, -1537647543, -1598453210, -1654872286, -1706839337, -1754312500, -1797273450, -1835727213, -1869701829, -1899247877, -1924437845, -1945365374, -1962144367, -1974907974, -1983807466, -1989010999, -1990702281, -1989079156, -1984352108, -1976742705, -1966481983, -1953808796, -1938968132, -1922209416, -1903784800, -1883947475, -1862949985, -1841042588, -1818471648, -1795478086, -1772295894, -1749150727, -1726258565, -1703824487, -1682041527, -1661089644, -1641134807, -1622328193, -1604805507, -1588686435, -1574074215, -1561055342, -1549699404, -1540059041, -1532170037, -1526051534, -1521706364, -1519121505, -1518268640, -1519104827, -1521573263, -1525604146, -1531115610, -1538014745, -1546198671, -1555555681, -1565966415, -1577305084, -1589440708, -1602238379, -1615560521, -1629268152, -1643222125, -1657284350, -1671318975, -1685193532, -1698780025, -1711955959, -1724605305, -1736619391, -1747897706, -1758348629, -1767890059, -1776449957, -1783966789, -1790389871, -1795679610, -1799807652, -1802756921, -1804521565, -1805106806, -1804528687, -1802813737, -1799998549, -1796129272, -1791261029, -1785457268, -1778789045, -1771334258, -1763176822, -1754405814, -1745114578, -1735399811, -1725360627, -1715097618, -1704711913, -1694304246, -1683974043, -1673818531, -1663931878, -1654404378, -1645321676, -1636764049, -1628805738, -1621514359, -1614950360, -1609166568, -1604207798, -1600110549, -1596902773, -1594603723, -1593223890, -1592765008, -1593220151, -1594573899, -1596802580, -1599874589, -1603750772, -1608384875, -1613724049, -1619709421, -1626276700, -1633356833, -1640876699, -1648759827, -1656927147, -1665297742, -1673789627, -1682320513, -1690808583, -1699173241, -1707335851, -1715220451, -1722754431, -1729869182, -1736500688, -1742590091, -1748084182, -1752935853, -1757104477, -1760556233, -1763264361, -1765209350, -1766379060, -1766768775, -1766381184, -1765226296, -1763321288, -1760690288, -1757364096, -1753379843, -1748780596, -1743614914, -1737936350, -1731802912, -1725276493, -1718422263, -1711308032, -1704003602, -1696580098, -1689109293, -1681662931, -1674312054, -1667126347, -1660173483, -1653518512, -1647223260, -1641345771, -1635939788, -1631054268, -1626732956, -1623014002, -1619929633, -1617505888, -1615762400, -1614712250, -1614361881, -1614711065, -1615752945, -1617474130, -1619854851, -1622869181, -1626485305, -1630665850, -1635368255, -1640545202, -1646145075, -1652112469, -1658388724, -1664912493, -1671620324, -1678447275, -1685327521, -1692194982, -1698983947, -1705629686, -1712069062, -1718241114, -1724087628, -1729553673, -1734588110, -1739144060, -1743179332, -1746656809, -1749544779, -1751817224, -1753454040, -1754441223, -1754770972, -1754441759, -1753458317, -1751831589, -1749578606, -1746722314, -1743291346, -1739319737, -1734846596, -1729915728, -1724575207, -1718876919, -1712876068, -1706630643, -1700200876, -1693648662, -1687036978, -1680429285, -1673888931, -1667478548, -1661259471, -1655291150, -1649630598, -1644331853, -1639445469, -1635018043, -1631091776, -1627704080, -1624887221, -1622668027, -1621067624, -1620101248, -1619778097, -1620101248, -1621067624, -1622668027, -1624887221, -1627704080, -1631091776, -1635018043, -1639445469, -1644331853, -1649630598, -1655291150, -1661259471, -1667478548, -1673888931, -1680429285, -1687036978, -1693648662, -1700200876, -1706630643, -1712876068, -1718876919, -1724575207, -1729915728, -1734846596, -1739319737, -1743291346, -1746722314, -1749578606, -1751831589, -1753458317, -1754441759, -1754770972, -1754441223, -1753454040, -1751817224, -1749544779, -1746656809, -1743179332, -1739144060, -1734588110, -1729553673, -1724087628, -1718241114, -1712069062, -1705629686, -1698983947, -1692194982, -1685327521, -1678447275, -1671620324, -1664912493, -1658388724, -1652112469, -1646145075, -1640545202, -1635368255, -1630665850, -1626485305, -1622869181, -1619854851, -1617474130, -1615752945, -1614711065, -1614361881, -1614712250, -1615762400, -1617505888, -1619929633, -1623014002, -1626732956, -1631054268, -1635939788, -1641345771, -1647223260, -1653518512, -1660173483, -1667126347, -1674312054, -1681662931, -1689109293, -1696580098, -1704003602, -1711308032, -1718422263, -1725276493, -1731802912, -1737936350, -1743614914, -1748780596, -1753379843, -1757364096, -1760690288, -1763321288, -1765226296, -1766381184, -1766768775, -1766379060, -1765209350, -1763264361, -1760556233, -1757104477, -1752935853, -1748084182, -1742590091, -1736500688, -1729869182, -1722754431, -1715220451, -1707335851, -1699173241, -1690808583, -1682320513, -1673789627, -1665297742, -1656927147, -1648759827, -1640876699, -1633356833, -1626276700, -1619709421, -1613724049, -1608384875, -1603750772, -1599874589, -1596802580, -1594573899, -1593220151, -1592765008, -1593223890, -1594603723, -1596902773, -1600110549, -1604207798, -1609166568, -1614950360, -1621514359, -1628805738, -1636764049, -1645321676, -1654404378, -1663931878, -1673818531, -1683974043, -1694304246, -1704711913, -1715097618, -1725360627, -1735399811, -1745114578, -1754405814, -1763176822, -1771334258, -1778789045, -1785457268, -1791261029, -1796129272, -1799998549, -1802813737, -1804528687, -1805106806, -1804521565, -1802756921, -1799807652, -1795679610, -1790389871, -1783966789, -1776449957, -1767890059, -1758348629, -1747897706, -1736619391, -1724605305, -1711955959, -1698780025, -1685193532, -1671318975, -1657284350, -1643222125, -1629268152, -1615560521, -1602238379, -1589440708, -1577305084, -1565966415, -1555555681, -1546198671, -1538014745, -1531115610, -1525604146, -1521573263, -1519104827, -1518268640, -1519121505, -1521706364, -1526051534, -1532170037, -1540059041, -1549699404, -1561055342, -1574074215, -1588686435, -1604805507, -1622328193, -1641134807, -1661089644, -1682041527, -1703824487, -1726258565, -1749150727, -1772295894, -1795478086, -1818471648, -1841042588, -1862949985, -1883947475, -1903784800, -1922209416, -1938968132, -1953808796, -1966481983, -1976742705, -1984352108, -1989079156, -1990702281, -1989010999, -1983807466, -1974907974, -1962144367, -1945365374, -1924437845, -1899247877, -1869701829, -1835727213, -1797273450, -1754312500, -1706839337, -1654872286, -1598453210, -1537647543, -1472544168, -1403255147, -1329915296, -1252681608, -1171732535, -1087267121, -999504003, -908680276, -815050243, -718884035, -620466142, -520093834, -418075499, -314728913, -210379431, -105358148, ], [0, 52700234, 105358809, 157934127, 210384703, 262669227, 314746620, 366576090, 418117193, 469329882, 520174569, 570612175, 620604188, 670112712, 719100521, 767531110, 815368743, 862578505, 909126343, 954979119, 1000104648, 1044471744, 1088050262, 1130811133, 1172726407, 1213769284, 1253914152, 1293136618, 1331413535, 1368723036, 1405044555, 1440358853, 1474648040, 1507895597, 1540086387, 1571206679, 1601244154, 1630187919, 1658028519, 1684757934, 1710369595, 1734858376, 1758220600, 1780454032, 1801557877, 1821532773, 1840380780, 1858105367, 1874711403, 1890205139, 1904594186, 1917887503, 1930095367, 1941229353, 1951302309, 1960328326, 1968322706, 1975301937, 1981283652, 1986286602, 1990330611, 1993436545, 1995626269, 1996922606, 1997349296, 1996930951, 1995693012, 1993661702, 1990863977, 1987327485, 1983080509, 1978151924, 1972571144, 1966368071, 1959573045, 1952216794, 1944330379, 1935945143, 1927092661, 1917804682, 1908113086, 1898049821, 1887646861, 1876936148, 1865949541, 1854718771, 1843275385, 1831650696, 1819875742, 1807981227, 1795997483, 1783954419, 1771881478, 1759807593, 1747761141, 1735769907, 1723861039, 1712061012, 1700395589, 1688889786, 1677567837, 1666453164, 1655568339, 1644935065, 1634574139, 1624505431, 1614747859, 1605319367, 1596236906, 1587516412, 1579172792, 1571219913, 1563670583, 1556536544, 1549828464, 1543555929, 1537727441, 1532350412, 1527431166, 1522974939, 1518985884, 1515467075, 1512420516, 1509847148, 1507746862, 1506118510, 1504959922, 1504267921, 1504038342, 1504266051, 1504944967, 1506068086, 1507627506, 1509614449, 1512019296, 1514831610, 1518040168, 1521632993, 1525597387, 1529919965, 1534586688, 1539582901, 1544893370, 1550502317, 1556393462, 1562550057, 1568954933, 1575590532, 1582438954, 1589481993, 1596701184, 1604077838, 1611593088, 1619227929, 1626963260, 1634779925, 1642658755, 1650580606, 1658526405, 1666477186, 1674414130, 1682318607, 1690172209, 1697956794, 1705654520, 1713247878, 1720719732, 1728053353, 1735232448, 1742241194, 1749064271, 1755686888, 1762094815, 1768274405, 1774212624, 1779897075, 1785316019, 1790458394, 1795313840, 1799872715, 1804126109, 1808065862, 1811684576, 1814975628, 1817933178, 1820552176, 1822828376, 1824758329, 1826339398, 1827569752, 1828448368, 1828975028, 1829150318, 1828975621, 1828453109, 1827585735, 1826377226, 1824832066, 1822955487, 1820753450, 1818232635, 1815400415, 1812264844, 1808834634, 1805119130, 1801128293, 1796872670, 1792363372, 1787612046, 1782630847, 1777432413, 1772029829, 1766436603, 1760666629, 1754734160, 1748653774, 1742440339, 1736108981, 1729675049, 1723154081, 1716561769, 1709913925, 1703226443, 1696515266, 1689796349, 1683085626, 1676398973, 1669752173, 1663160881, 1656640592, 1650206604, 1643873986, 1637657546, 1631571796, 1625630924, 1619848758, 1614238743, 1608813905, 1603586826, 1598569618, 1593773893, 1589210741, 1584890706, 1580823760, 1577019287, 1573486056, 1570232207, 1567265234, 1564591964, 1562218547, 1560150441, 1558392398, 1556948460, 1555821943, 1555015435, 1554530789, 1554369116, 1554530789, 1555015435, 1555821943, 1556948460, 1558392398, 1560150441, 1562218547, 1564591964, 1567265234, 1570232207, 1573486056, 1577019287, 1580823760, 1584890706, 1589210741, 1593773893, 1598569618, 1603586826, 1608813905, 1614238743, 1619848758, 1625630924, 1631571796, 1637657546, 1643873986, 1650206604, 1656640592, 1663160881, 1669752173, 1676398973, 1683085626, 1689796349, 1696515266, 1703226443, 1709913925, 1716561769, 1723154081, 1729675049, 1736108981, 1742440339, 1748653774, 1754734160, 1760666629, 1766436603, 1772029829, 1777432413, 1782630847, 1787612046, 1792363372, 1796872670, 1801128293, 1805119130, 1808834634, 1812264844, 1815400415, 1818232635, 1820753450, 1822955487, 1824832066, 1826377226, 1827585735, 1828453109, 1828975621, 1829150318, 1828975028, 1828448368, 1827569752, 1826339398, 1824758329, 1822828376, 1820552176, 1817933178, 1814975628, 1811684576, 1808065862, 1804126109, 1799872715, 1795313840, 1790458394, 1785316019, 1779897075, 1774212624, 1768274405, 1762094815, 1755686888, 1749064271, 1742241194, 1735232448, 1728053353, 1720719732, 1713247878, 1705654520, 1697956794, 1690172209, 1682318607, 1674414130, 1666477186, 1658526405, 1650580606, 1642658755, 1634779925, 1626963260, 1619227929, 1611593088, 1604077838, 1596701184, 1589481993, 1582438954, 1575590532, 1568954933, 1562550057, 1556393462, 1550502317, 1544893370, 1539582901, 1534586688, 1529919965, 1525597387, 1521632993, 1518040168, 1514831610, 1512019296, 1509614449, 1507627506, 1506068086, 1504944967, 1504266051, 1504038342, 1504267921, 1504959922, 1506118510, 1507746862, 1509847148, 1512420516, 1515467075, 1518985884, 1522974939, 1527431166, 1532350412, 1537727441, 1543555929, 1549828464, 1556536544, 1563670583, 1571219913, 1579172792, 1587516412, 1596236906, 1605319367, 1614747859, 1624505431, 1634574139, 1644935065, 1655568339, 1666453164, 1677567837, 1688889786, 1700395589, 1712061012, 1723861039, 1735769907, 1747761141, 1759807593, 1771881478, 1783954419, 1795997483, 1807981227, 1819875742, 1831650696, 1843275385, 1854718771, 1865949541, 1876936148, 1887646861, 1898049821, 1908113086, 1917804682, 1927092661, 1935945143, 1944330379, 1952216794, 1959573045, 1966368071, 1972571144, 1978151924, 1983080509, 1987327485, 1990863977, 1993661702, 1995693012, 1996930951, 1997349296, 1996922606, 1995626269, 1993436545, 1990330611, 1986286602, 1981283652, 1975301937, 1968322706, 1960328326, 1951302309, 1941229353, 1930095367, 1917887503, 1904594186, 1890205139, 1874711403, 1858105367, 1840380780, 1821532773, 1801557877, 1780454032, 1758220600, 1734858376, 1710369595, 1684757934, 1658028519, 1630187919, 1601244154, 1571206679, 1540086387, 1507895597, 1474648040, 1440358853, 1405044555, 1368723036, 1331413535, 1293136618, 1253914152, 1213769284, 1172726407, 1130811133, 1088050262, 1044471744, 1000104648, 954979119, 909126343, 862578505, 815368743, 767531110, 719100521, 670112712, 620604188, 570612175, 520174569, 469329882, 418117193, 366576090, 314746620, 262669227, 210384703, 157934127, 105358809, 52700234, 0, -52700234, -105358809, -157934127, -210384703, -262669227, -314746620, -366576090, -418117193, -469329882, -520174569, -570612175, -620604188, -670112712, -719100521, -767531110, -815368743, -862578505, -909126343, -954979119, -1000104648, -1044471744, -1088050262, -1130811133, -1172726407, -1213769284, -1253914152, -1293136618, -1331413535, -1368723036, -1405044555, -1440358853, -1474648040, -1507895597, -1540086387, -1571206679, -1601244154, -1630187919, -1658028519, -1684757934, -1710369595, -1734858376, -1758220600, -1780454032, -1801557877, -1821532773, -1840380780, -1858105367, -1874711403, -1890205139, -1904594186, -1917887503, -1930095367, -1941229353, -1951302309, -1960328326, -1968322706, -1975301937, -1981283652, -1986286602, -1990330611, -1993436545, -1995626269, -1996922606, -1997349296, -1996930951, -1995693012, -1993661702, -1990863977, -1987327485, -1983080509, -1978151924, -1972571144, -1966368071, -1959573045, -1952216794, -1944330379, -1935945143, -1927092661, -1917804682, -1908113086, -1898049821, -1887646861, -1876936148, -1865949541, -1854718771, -1843275385, -1831650696, -1819875742, -1807981227, -1795997483, -1783954419, -1771881478, -1759807593, -1747761141, -1735769907, -1723861039, -1712061012, -1700395589, -1688889786, -1677567837, -1666453164, -1655568339, -1644935065, -1634574139, -1624505431, -1614747859, -1605319367, -1596236906, -1587516412, -1579172792, -1571219913, -1563670583, -1556536544, -1549828464, -1543555929, -1537727441, -1532350412, -1527431166, -1522974939, -1518985884, -1515467075, -1512420516, -1509847148, -1507746862, -1506118510, -1504959922, -1504267921, -1504038342, -1504266051, -1504944967, -1506068086, -1507627506, -1509614449, -1512019296, -1514831610, -1518040168, -1521632993, -1525597387, -1529919965, -1534586688, -1539582901, -1544893370, -1550502317, -1556393462, -1562550057, -1568954933, -1575590532, -1582438954, -1589481993, -1596701184, -1604077838, -1611593088, -1619227929, -1626963260, -1634779925, -1642658755, -1650580606, -1658526405, -1666477186, -1674414130, -1682318607, -1690172209, -1697956794, -1705654520, -1713247878, -1720719732, -1728053353, -1735232448, -1742241194, -1749064271, -1755686888, -1762094815, -1768274405, -1774212624, -1779897075, -1785316019, -1790458394, -1795313840, -1799872715, -1804126109, -1808065862, -1811684576, -1814975628, -1817933178, -1820552176, -1822828376, -1824758329, -1826339398, -1827569752, -1828448368, -1828975028, -1829150318, -1828975621, -1828453109, -1827585735, -1826377226, -1824832066, -1822955487, -1820753450, -1818232635, -1815400415, -1812264844, -1808834634, -1805119130, -1801128293, -1796872670, -1792363372, -1787612046, -1782630847, -1777432413, -1772029829, -1766436603, -1760666629, -1754734160, -1748653774, -1742440339, -1736108981, -1729675049, -1723154081, -1716561769, -1709913925, -1703226443, -1696515266, -1689796349, -1683085626, -1676398973, -1669752173, -1663160881, -1656640592, -1650206604, -1643873986, -1637657546, -1631571796, -1625630924, -1619848758, -1614238743, -1608813905, -1603586826, -1598569618, -1593773893, -1589210741, -1584890706, -1580823760, -1577019287, -1573486056, -1570232207, -1567265234, -1564591964, -1562218547, -1560150441, -1558392398, -1556948460, -1555821943, -1555015435, -1554530789, -1554369116, -1554530789, -1555015435, -1555821943, -1556948460, -1558392398, -1560150441, -1562218547, -1564591964, -1567265234, -1570232207, -1573486056, -1577019287, -1580823760, -1584890706, -1589210741, -1593773893, -1598569618, -1603586826, -1608813905, -1614238743, -1619848758, -1625630924, -1631571796, -1637657546, -1643873986, -1650206604, -1656640592, -1663160881, -1669752173, -1676398973, -1683085626, -1689796349, -1696515266, -1703226443, -1709913925, -1716561769, -1723154081, -1729675049, -1736108981, -1742440339, -1748653774, -1754734160, -1760666629, -1766436603, -1772029829, -1777432413, -1782630847, -1787612046, -1792363372, -1796872670, -1801128293, -1805119130, -1808834634, -1812264844, -1815400415, -1818232635, -1820753450, -1822955487, -1824832066, -1826377226, -1827585735, -1828453109, -1828975621, -1829150318, -1828975028, -1828448368, -1827569752, -1826339398, -1824758329, -1822828376, -1820552176, -1817933178, -1814975628, -1811684576, -1808065862, -1804126109, -1799872715, -1795313840, -1790458394, -1785316019, -1779897075, -1774212624, -1768274405, -1762094815, -1755686888, -1749064271, -1742241194, -1735232448, -1728053353, -1720719732, -1713247878, -1705654520, -1697956794, -1690172209, -1682318607, -1674414130, -1666477186, -1658526405, -1650580606, -1642658755, -1634779925, -1626963260, -1619227929, -1611593088, -1604077838, -1596701184, -1589481993, -1582438954, -1575590532, -1568954933, -1562550057, -1556393462, -1550502317, -1544893370, -1539582901, -1534586688, -1529919965, -1525597387, -1521632993, -1518040168, -1514831610, -1512019296, -1509614449, -1507627506, -1506068086, -1504944967, -1504266051, -1504038342, -1504267921, -1504959922, -1506118510, -1507746862, -1509847148, -1512420516, -1515467075, -1518985884, -1522974939, -1527431166, -1532350412, -1537727441, -1543555929, -1549828464, -1556536544, -1563670583, -1571219913, -1579172792, -1587516412, -1596236906, -1605319367, -1614747859, -1624505431, -1634574139, -1644935065, -1655568339, -1666453164, -1677567837, -1688889786, -1700395589, -1712061012, -1723861039, -1735769907, -1747761141, -1759807593, -1771881478, -1783954419, -1795997483, -1807981227, -1819875742, -1831650696, -1843275385, -1854718771, -1865949541, -1876936148, -1887646861, -1898049821, -1908113086, -1917804682, -1927092661, -1935945143, -1944330379, -1952216794, -1959573045, -1966368071, -1972571144, -1978151924, -1983080509, -1987327485, -1990863977, -1993661702, -1995693012, -1996930951, -1997349296, -1996922606, -1995626269, -1993436545, -1990330611, -1986286602, -1981283652, -1975301937, -1968322706, -1960328326, -1951302309, -1941229353, -1930095367, -1917887503, -1904594186, -1890205139, -1874711403, -1858105367, -1840380780, -1821532773, -1801557877, -1780454032, -1758220600, -1734858376, -1710369595, -1684757934, -1658028519, -1630187919, -1601244154, -1571206679, -1540086387, -1507895597, -1474648040, -1440358853, -1405044555, -1368723036, -1331413535, -1293136618, -1253914152, -1213769284, -1172726407, -1130811133, -1088050262, -1044471744, -1000104648, -954979119, -909126343, -862578505, -815368743, -767531110, -719100521, -670112712, -620604188, -570612175, -520174569, -469329882, -418117193, -366576090, -314746620, -262669227, -210384703, -157934127, -105358809, -52700234, ], [0, 26352762, 52700564, 79038446, 105361453, 131664632, 157943039, 184191735, 210405793, 236580294, 262710334, 288791019, 314817474, 340784838, 366688271, 392522949, 418284072, 443966862, 469566565, 495078453, 520497825, 545820008, 571040358, 596154266, 621157152, 646044471, 670811715, 695454413, 719968130, 744348472, 768591088, 792691666, 816645940, 840449687, 864098733, 887588950, 910916257, 934076626, 957066079, 979880690, 1002516587, 1024969954, 1047237028, 1069314106, 1091197541, 1112883747, 1134369197, 1155650427, 1176724033, 1197586677, 1218235084, 1238666043, 1258876413, 1278863118, 1298623149, 1318153567, 1337451506, 1356514165, 1375338818, 1393922812, 1412263566, 1430358570, 1448205394, 1465801678, 1483145142, 1500233580, 1517064863, 1533636941, 1549947842, 1565995671, 1581778615, 1597294939, 1612542989, 1627521191, 1642228051, 1656662160, 1670822186, 1684706883, 1698315086, 1711645711, 1724697759, 1737470313, 1749962540, 1762173690, 1774103096, 1785750174, 1797114426, 1808195436, 1818992872, 1829506486, 1839736112, 1849681671, 1859343163, 1868720676, 1877814378, 1886624521, 1895151440, 1903395553, 1911357358, 1919037438, 1926436455, 1933555155, 1940394363, 1946954986, 1953238009, 1959244499, 1964975601, 1970432538, 1975616613, 1980529206, 1985171772, 1989545845, 1993653035, 1997495023, 2001073570, 2004390506, 2007447737, 2010247241, 2012791064, 2015081328, 2017120220, 2018909999, 2020452990, 2021751587, 2022808249, 2023625501, 2024205931, 2024552192, 2024666999, 2024553128, 2024213414, 2023650754, 2022868100, 2021868464, 2020654911, 2019230564, 2017598597, 2015762236, 2013724761, 2011489499, 2009059829, 2006439174, 2003631005, 2000638839, 1997466235, 1994116796, 1990594164, 1986902023, 1983044093, 1979024134, 1974845939, 1970513337, 1966030189, 1961400389, 1956627859, 1951716551, 1946670445, 1941493544, 1936189880, 1930763503, 1925218487, 1919558928, 1913788936, 1907912642, 1901934190, 1895857740, 1889687463, 1883427544, 1877082173, 1870655552, 1864151889, 1857575396, 1850930289, 1844220787, 1837451108, 1830625471, 1823748091, 1816823181, 1809854946, 1802847586, 1795805293, 1788732248, 1781632621, 1774510570, 1767370236, 1760215749, 1753051217, 1745880731, 1738708364, 1731538165, 1724374160, 1717220352, 1710080717, 1702959204, 1695859734, 1688786197, 1681742452, 1674732326, 1667759611, 1660828064, 1653941404, 1647103314, 1640317436, 1633587372, 1626916682, 1620308882, 1613767446, 1607295799, 1600897321, 1594575344, 1588333151, 1582173974, 1576100993, 1570117336, 1564226078, 1558430237, 1552732778, 1547136608, 1541644573, 1536259466, 1530984015, 1525820889, 1520772695, 1515841978, 1511031219, 1506342833, 1501779173, 1497342521, 1493035096, 1488859049, 1484816460, 1480909343, 1477139638, 1473509218, 1470019883, 1466673362, 1463471311, 1460415312, 1457506875, 1454747435, 1452138352, 1449680911, 1447376322, 1445225719, 1443230157, 1441390619, 1439708006, 1438183145, 1436816783, 1435609590, 1434562157, 1433674999, 1432948549, 1432383164, 1431979119, 1431736613, 1431655765, 1431736613, 1431979119, 1432383164, 1432948549, 1433674999, 1434562157, 1435609590, 1436816783, 1438183145, 1439708006, 1441390619, 1443230157, 1445225719, 1447376322, 1449680911, 1452138352, 1454747435, 1457506875, 1460415312, 1463471311, 1466673362, 1470019883, 1473509218, 1477139638, 1480909343, 1484816460, 1488859049, 1493035096, 1497342521, 1501779173, 1506342833, 1511031219, 1515841978, 1520772695, 1525820889, 1530984015, 1536259466, 1541644573, 1547136608, 1552732778, 1558430237, 1564226078, 1570117336, 1576100993, 1582173974, 1588333151, 1594575344, 1600897321, 1607295799, 1613767446, 1620308882, 1626916682, 1633587372, 1640317436, 1647103314, 1653941404, 1660828064, 1667759611, 1674732326, 1681742452, 1688786197, 1695859734, 1702959204, 1710080717, 1717220352, 1724374160, 1731538165, 1738708364, 1745880731, 1753051217, 1760215749, 1767370236, 1774510570, 1781632621, 1788732248, 1795805293, 1802847586, 1809854946, 1816823181, 1823748091, 1830625471, 1837451108, 1844220787, 1850930289, 1857575396, 1864151889, 1870655552, 1877082173, 1883427544, 1889687463, 1895857740, 1901934190, 1907912642, 1913788936, 1919558928, 1925218487, 1930763503, 1936189880, 1941493544, 1946670445, 1951716551, 1956627859, 1961400389, 1966030189, 1970513337, 1974845939, 1979024134, 1983044093, 1986902023, 1990594164, 1994116796, 1997466235, 2000638839, 2003631005, 2006439174, 2009059829, 2011489499, 2013724761, 2015762236, 2017598597, 2019230564, 2020654911, 2021868464, 2022868100, 2023650754, 2024213414, 2024553128, 2024666999, 2024552192, 2024205931, 2023625501, 2022808249, 2021751587, 2020452990, 2018909999, 2017120220, 2015081328, 2012791064, 2010247241, 2007447737, 2004390506, 2001073570, 1997495023, 1993653035, 1989545845, 1985171772, 1980529206, 1975616613, 1970432538, 1964975601, 1959244499, 1953238009, 1946954986, 1940394363, 1933555155, 1926436455, 1919037438, 1911357358, 1903395553, 1895151440, 1886624521, 1877814378, 1868720676, 1859343163, 1849681671, 1839736112, 1829506486, 1818992872, 1808195436, 1797114426, 1785750174, 1774103096, 1762173690, 1749962540, 1737470313, 1724697759, 1711645711, 1698315086, 1684706883, 1670822186, 1656662160, 1642228051, 1627521191, 1612542989, 1597294939, 1581778615, 1565995671, 1549947842, 1533636941, 1517064863, 1500233580, 1483145142, 1465801678, 1448205394, 1430358570, 1412263566, 1393922812, 1375338818, 1356514165, 1337451506, 1318153567, 1298623149, 1278863118, 1258876413, 1238666043, 1218235084, 1197586677, 1176724033, 1155650427, 1134369197, 1112883747, 1091197541, 1069314106, 1047237028, 1024969954, 1002516587, 979880690, 957066079, 934076626, 910916257, 887588950, 864098733, 840449687, 816645940, 792691666, 768591088, 744348472, 719968130, 695454413, 670811715, 646044471, 621157152, 596154266, 571040358, 545820008, 520497825, 495078453, 469566565, 443966862, 418284072, 392522949, 366688271, 340784838, 314817474, 288791019, 262710334, 236580294, 210405793, 184191735, 157943039, 131664632, 105361453, 79038446, 52700564, 26352762, 0, -26352762, -52700564, -79038446, -105361453, -131664632, -157943039, -184191735, -210405793, -236580294, -262710334, -288791019, -314817474, -340784838, -366688271, -392522949, -418284072, -443966862, -469566565, -495078453, -520497825, -545820008, -571040358, -596154266, -621157152, -646044471, -670811715, -695454413, -719968130, -744348472, -768591088, -792691666, -816645940, -840449687, -864098733, -887588950, -910916257, -934076626, -957066079, -979880690, -1002516587, -1024969954, -1047237028, -1069314106, -1091197541, -1112883747, -1134369197, -1155650427, -1176724033, -1197586677, -1218235084, -1238666043, -1258876413, -1278863118, -1298623149, -1318153567, -1337451506, -1356514165, -1375338818, -1393922812, -1412263566, -1430358570, -1448205394, -1465801678, -1483145142, -1500233580, -1517064863, -1533636941, -1549947842, -1565995671, -1581778615, -1597294939, -1612542989, -1627521191, -1642228051, -1656662160, -1670822186, -1684706883, -1698315086, -1711645711, -1724697759, -1737470313, -1749962540, -1762173690, -1774103096, -1785750174, -1797114426, -1808195436, -1818992872, -1829506486, -1839736112, -1849681671, -1859343163, -1868720676, -1877814378, -1886624521, -1895151440, -1903395553, -1911357358, -1919037438, -1926436455, -1933555155, -1940394363, -1946954986, -1953238009, -1959244499, -1964975601, -1970432538, -1975616613, -1980529206, -1985171772, -1989545845, -1993653035, -1997495023, -2001073570, -2004390506, -2007447737, -2010247241, -2012791064, -2015081328, -2017120220, -2018909999, -2020452990, -2021751587, -2022808249, -2023625501, -2024205931, -2024552192, -2024666999, -2024553128, -2024213414, -2023650754, -2022868100, -2021868464, -2020654911, -2019230564, -2017598597, -2015762236, -2013724761, -2011489499, -2009059829, -2006439174, -2003631005, -2000638839, -1997466235, -1994116796, -1990594164, -1986902023, -1983044093, -1979024134, -1974845939, -1970513337, -1966030189, -1961400389, -1956627859, -1951716551, -1946670445, -1941493544, -1936189880, -1930763503, -1925218487, -1919558928, -1913788936, -1907912642, -1901934190, -1895857740, -1889687463, -1883427544, -1877082173, -1870655552, -1864151889, -1857575396, -1850930289, -1844220787, -1837451108, -1830625471, -1823748091, -1816823181, -1809854946, -1802847586, -1795805293, -1788732248, -1781632621, -1774510570, -1767370236, -1760215749, -1753051217, -1745880731, -1738708364, -1731538165, -1724374160, -1717220352, -1710080717, -1702959204, -1695859734, -1688786197, -1681742452, -1674732326, -1667759611, -1660828064, -1653941404, -1647103314, -1640317436, -1633587372, -1626916682, -1620308882, -1613767446, -1607295799, -1600897321, -1594575344, -1588333151, -1582173974, -1576100993, -1570117336, -1564226078, -1558430237, -1552732778, -1547136608, -1541644573, -1536259466, -1530984015, -1525820889, -1520772695, -1515841978, -1511031219, -1506342833, -1501779173, -1497342521, -1493035096, -1488859049, -1484816460, -1480909343, -1477139638, -1473509218, -1470019883, -1466673362, -1463471311, -1460415312, -1457506875, -1454747435, -1452138352, -1449680911, -1447376322, -1445225719, -1443230157, -1441390619, -1439708006, -1438183145, -1436816783, -1435609590, -1434562157, -1433674999, -1432948549, -1432383164, -1431979119, -1431736613, -1431655765, -1431736613, -1431979119, -1432383164, -1432948549, -1433674999, -1434562157, -1435609590, -1436816783, -1438183145, -1439708006, -1441390619, -1443230157, -1445225719, -1447376322, -1449680911, -1452138352, -1454747435, -1457506875, -1460415312, -1463471311, -1466673362, -1470019883, -1473509218, -1477139638, -1480909343, -1484816460, -1488859049, -1493035096, -1497342521, -1501779173, -1506342833, -1511031219, -1515841978, -1520772695, -1525820889, -1530984015, -1536259466, -1541644573, -1547136608, -1552732778, -1558430237, -1564226078, -1570117336, -1576100993, -1582173974, -1588333151, -1594575344, -1600897321, -1607295799, -1613767446, -1620308882, -1626916682, -1633587372, -1640317436, -1647103314, -1653941404, -1660828064, -1667759611, -1674732326, -1681742452, -1688786197, -1695859734, -1702959204, -1710080717, -1717220352, -1724374160, -1731538165, -1738708364, -1745880731, -1753051217, -1760215749, -1767370236, -1774510570, -1781632621, -1788732248, -1795805293, -1802847586, -1809854946, -1816823181, -1823748091, -1830625471, -1837451108, -1844220787, -1850930289, -1857575396, -1864151889, -1870655552, -1877082173, -1883427544, -1889687463, -1895857740, -1901934190, -1907912642, -1913788936, -1919558928, -1925218487, -1930763503, -1936189880, -1941493544, -1946670445, -1951716551, -1956627859, -1961400389, -1966030189, -1970513337, -1974845939, -1979024134, -1983044093, -1986902023, -1990594164, -1994116796, -1997466235, -2000638839, -2003631005, -2006439174, -2009059829, -2011489499, -2013724761, -2015762236, -2017598597, -2019230564, -2020654911, -2021868464, -2022868100, -2023650754, -2024213414, -2024553128, -2024666999, -2024552192, -2024205931, -2023625501, -2022808249, -2021751587, -2020452990, -2018909999, -2017120220, -2015081328, -2012791064, -2010247241, -2007447737, -2004390506, -2001073570, -1997495023, -1993653035, -1989545845, -1985171772, -1980529206, -1975616613, -1970432538, -1964975601, -1959244499, -1953238009, -1946954986, -1940394363, -1933555155, -1926436455, -1919037438, -1911357358, -1903395553, -1895151440, -1886624521, -1877814378, -1868720676, -1859343163, -1849681671, -1839736112, -1829506486, -1818992872, -1808195436, -1797114426, -1785750174, -1774103096, -1762173690, -1749962540, -1737470313, -1724697759, -1711645711, -1698315086, -1684706883, -1670822186, -1656662160, -1642228051, -1627521191, -1612542989, -1597294939, -1581778615, -1565995671, -1549947842, -1533636941, -1517064863, -1500233580, -1483145142, -1465801678, -1448205394, -1430358570, -1412263566, -1393922812, -1375338818, -1356514165, -1337451506, -1318153567, -1298623149, -1278863118, -1258876413, -1238666043, -1218235084, -1197586677, -1176724033, -1155650427, -1134369197, -1112883747, -1091197541, -1069314106, -1047237028, -1024969954, -1002516587, -979880690, -957066079, -934076626, -910916257, -887588950, -864098733, -840449687, -816645940, -792691666, -768591088, -744348472, -719968130, -695454413, -670811715, -646044471, -621157152, -596154266, -571040358, -545820008, -520497825, -495078453, -469566565, -443966862, -418284072, -392522949, -366688271, -340784838, -314817474, -288791019, -262710334, -236580294, -210405793, -184191735, -157943039, -131664632, -105361453, -79038446, -52700564, -26352762, ], [0, 13176712, 26352928, 39528151, 52701887, 65873638, 79042909, 92209205, 105372028, 118530885, 131685278, 144834714, 157978697, 171116732, 184248325, 197372981, 210490206, 223599506, 236700388, 249792358, 262874923, 275947592, 289009871, 302061269, 315101294, 328129457, 341145265, 354148229, 367137860, 380113669, 393075166, 406021864, 418953276, 431868915, 444768293, 457650927, 470516330, 483364019, 496193509, 509004318, 521795963, 534567963, 547319836, 560051103, 572761285, 585449903, 598116478, 610760535, 623381597, 635979190, 648552837, 661102068, 673626408, 686125386, 698598533, 711045377, 723465451, 735858287, 748223418, 760560379, 772868706, 785147934, 797397602, 809617248, 821806413, 833964637, 846091463, 858186434, 870249095, 882278991, 894275670, 906238681, 918167571, 930061894, 941921200, 953745043, 965532978, 977284561, 988999351, 1000676905, 1012316784, 1023918549, 1035481765, 1047005996, 1058490807, 1069935767, 1081340445, 1092704410, 1104027236, 1115308496, 1126547765, 1137744620, 1148898640, 1160009404, 1171076495, 1182099495, 1193077990, 1204011566, 1214899812, 1225742318, 1236538675, 1247288477, 1257991319, 1268646799, 1279254515, 1289814068, 1300325059, 1310787095, 1321199780, 1331562722, 1341875532, 1352137822, 1362349204, 1372509294, 1382617710, 1392674071, 1402677999, 1412629117, 1422527050, 1432371426, 1442161874, 1451898025, 1461579513, 1471205973, 1480777044, 1490292364, 1499751575, 1509154322, 1518500249, 1527789006, 1537020243, 1546193612, 1555308767, 1564365366, 1573363067, 1582301533, 1591180425, 1599999410, 1608758157, 1617456334, 1626093615, 1634669675, 1643184190, 1651636840, 1660027308, 1668355276, 1676620431, 1684822463, 1692961061, 1701035921, 1709046738, 1716993211, 1724875039, 1732691927, 1740443580, 1748129706, 1755750016, 1763304223, 1770792043, 1778213194, 1785567395, 1792854372, 1800073848, 1807225552, 1814309215, 1821324571, 1828271355, 1835149305, 1841958164, 1848697673, 1855367580, 1861967633, 1868497585, 1874957188, 1881346201, 1887664382, 1893911493, 1900087300, 1906191569, 1912224072, 1918184580, 1924072870, 1929888719, 1935631909, 1941302224, 1946899450, 1952423376, 1957873795, 1963250500, 1968553291, 1973781966, 1978936330, 1984016188, 1989021349, 1993951624, 1998806828, 2003586778, 2008291295, 2012920200, 2017473320, 2021950483, 2026351521, 2030676268, 2034924561, 2039096240, 2043191149, 2047209132, 2051150040, 2055013722, 2058800035, 2062508835, 2066139982, 2069693341, 2073168776, 2076566159, 2079885359, 2083126253, 2086288719, 2089372637, 2092377891, 2095304369, 2098151959, 2100920555, 2103610053, 2106220351, 2108751351, 2111202958, 2113575079, 2115867625, 2118080510, 2120213650, 2122266966, 2124240379, 2126133816, 2127947205, 2129680479, 2131333571, 2132906419, 2134398965, 2135811152, 2137142926, 2138394239, 2139565042, 2140655292, 2141664947, 2142593970, 2143442325, 2144209981, 2144896909, 2145503082, 2146028479, 2146473079, 2146836865, 2147119824, 2147321945, 2147443221, 2147483647, 2147443221, 2147321945, 2147119824, 2146836865, 2146473079, 2146028479, 2145503082, 2144896909, 2144209981, 2143442325, 2142593970, 2141664947, 2140655292, 2139565042, 2138394239, 2137142926, 2135811152, 2134398965, 2132906419, 2131333571, 2129680479, 2127947205, 2126133816, 2124240379, 2122266966, 2120213650, 2118080510, 2115867625, 2113575079, 2111202958, 2108751351, 2106220351, 2103610053, 2100920555, 2098151959, 2095304369, 2092377891, 2089372637, 2086288719, 2083126253, 2079885359, 2076566159, 2073168776, 2069693341, 2066139982, 2062508835, 2058800035, 2055013722, 2051150040, 2047209132, 2043191149, 2039096240, 2034924561, 2030676268, 2026351521, 2021950483, 2017473320, 2012920200, 2008291295, 2003586778, 1998806828, 1993951624, 1989021349, 1984016188, 1978936330, 1973781966, 1968553291, 1963250500, 1957873795, 1952423376, 1946899450, 1941302224, 1935631909, 1929888719, 1924072870, 1918184580, 1912224072, 1906191569, 1900087300, 1893911493, 1887664382, 1881346201, 1874957188, 1868497585, 1861967633, 1855367580, 1848697673, 1841958164, 1835149305, 1828271355, 1821324571, 1814309215, 1807225552, 1800073848, 1792854372, 1785567395, 1778213194, 1770792043, 1763304223, 1755750016, 1748129706, 1740443580, 1732691927, 1724875039, 1716993211, 1709046738, 1701035921, 1692961061, 1684822463, 1676620431, 1668355276, 1660027308, 1651636840, 1643184190, 1634669675, 1626093615, 1617456334, 1608758157, 1599999410, 1591180425, 1582301533, 1573363067, 1564365366, 1555308767, 1546193612, 1537020243, 1527789006, 1518500249, 1509154322, 1499751575, 1490292364, 1480777044, 1471205973, 1461579513, 1451898025, 1442161874, 1432371426, 1422527050, 1412629117, 1402677999, 1392674071, 1382617710, 1372509294, 1362349204, 1352137822, 1341875532, 1331562722, 1321199780, 1310787095, 1300325059, 1289814068, 1279254515, 1268646799, 1257991319, 1247288477, 1236538675, 1225742318, 1214899812, 1204011566, 1193077990, 1182099495, 1171076495, 1160009404, 1148898640, 1137744620, 1126547765, 1115308496, 1104027236, 1092704410, 1081340445, 1069935767, 1058490807, 1047005996, 1035481765, 1023918549, 1012316784, 1000676905, 988999351, 977284561, 965532978, 953745043, 941921200, 930061894, 918167571, 906238681, 894275670, 882278991, 870249095, 858186434, 846091463, 833964637, 821806413, 809617248, 797397602, 785147934, 772868706, 760560379, 748223418, 735858287, 723465451, 711045377, 698598533, 686125386, 673626408, 661102068, 648552837, 635979190, 623381597, 610760535, 598116478, 585449903, 572761285, 560051103, 547319836, 534567963, 521795963, 509004318, 496193509, 483364019, 470516330, 457650927, 444768293, 431868915, 418953276, 406021864, 393075166, 380113669, 367137860, 354148229, 341145265, 328129457, 315101294, 302061269, 289009871, 275947592, 262874923, 249792358, 236700388, 223599506, 210490206, 197372981, 184248325, 171116732, 157978697, 144834714, 131685278, 118530885, 105372028, 92209205, 79042909, 65873638, 52701887, 39528151, 26352928, 13176712, 0, -13176712, -26352928, -39528151, -52701887, -65873638, -79042909, -92209205, -105372028, -118530885, -131685278, -144834714, -157978697, -171116732, -184248325, -197372981, -210490206, -223599506, -236700388, -249792358, -262874923, -275947592, -289009871, -302061269, -315101294, -328129457, -341145265, -354148229, -367137860, -380113669, -393075166, -406021864, -418953276, -431868915, -444768293, -457650927, -470516330, -483364019, -496193509, -509004318, -521795963, -534567963, -547319836, -560051103, -572761285, -585449903, -598116478, -610760535, -623381597, -635979190, -648552837, -661102068, -673626408, -686125386, -698598533, -711045377, -723465451, -735858287, -748223418, -760560379, -772868706, -785147934, -797397602, -809617248, -821806413, -833964637, -846091463, -858186434, -870249095, -882278991, -894275670, -906238681, -918167571, -930061894, -941921200, -953745043, -965532978, -977284561, -988999351, -1000676905, -1012316784, -1023918549, -1035481765, -1047005996, -1058490807, -1069935767, -1081340445, -1092704410, -1104027236, -1115308496, -1126547765, -1137744620, -1148898640, -1160009404, -1171076495, -1182099495, -1193077990, -1204011566, -1214899812, -1225742318, -1236538675, -1247288477, -1257991319, -1268646799, -1279254515, -1289814068, -1300325059, -1310787095, -1321199780, -1331562722, -1341875532, -1352137822, -1362349204, -1372509294, -1382617710, -1392674071, -1402677999, -1412629117, -1422527050, -1432371426, -1442161874, -1451898025, -1461579513, -1471205973, -1480777044, -1490292364, -1499751575, -1509154322, -1518500249, -1527789006, -1537020243, -1546193612, -1555308767, -1564365366, -1573363067, -1582301533, -1591180425, -1599999410, -1608758157, -1617456334, -1626093615, -1634669675, -1643184190, -1651636840, -1660027308, -1668355276, -1676620431, -1684822463, -1692961061, -1701035921, -1709046738, -1716993211, -1724875039, -1732691927, -1740443580, -1748129706, -1755750016, -1763304223, -1770792043, -1778213194, -1785567395, -1792854372, -1800073848, -1807225552, -1814309215, -1821324571, -1828271355, -1835149305, -1841958164, -1848697673, -1855367580, -1861967633, -1868497585, -1874957188, -1881346201, -1887664382, -1893911493, -1900087300, -1906191569, -1912224072, -1918184580, -1924072870, -1929888719, -1935631909, -1941302224, -1946899450, -1952423376, -1957873795, -1963250500, -1968553291, -1973781966, -1978936330, -1984016188, -1989021349, -1993951624, -1998806828, -2003586778, -2008291295, -2012920200, -2017473320, -2021950483, -2026351521, -2030676268, -2034924561, -2039096240, -2043191149, -2047209132, -2051150040, -2055013722, -2058800035, -2062508835, -2066139982, -2069693341, -2073168776, -2076566159, -2079885359, -2083126253, -2086288719, -2089372637, -2092377891, -2095304369, -2098151959, -2100920555, -2103610053, -2106220351, -2108751351, -2111202958, -2113575079, -2115867625, -2118080510, -2120213650, -2122266966, -2124240379, -2126133816, -2127947205, -2129680479, -2131333571, -2132906419, -2134398965, -2135811152, -2137142926, -2138394239, -2139565042, -2140655292, -2141664947, -2142593970, -2143442325, -2144209981, -2144896909, -2145503082, -2146028479, -2146473079, -2146836865, -2147119824, -2147321945, -2147443221, -2147483647, -2147443221, -2147321945, -2147119824, -2146836865, -2146473079, -2146028479, -2145503082, -2144896909, -2144209981, -2143442325, -2142593970, -2141664947, -2140655292, -2139565042, -2138394239, -2137142926, -2135811152, -2134398965, -2132906419, -2131333571, -2129680479, -2127947205, -2126133816, -2124240379, -2122266966, -2120213650, -2118080510, -2115867625, -2113575079, -2111202958, -2108751351, -2106220351, -2103610053, -2100920555, -2098151959, -2095304369, -2092377891, -2089372637, -2086288719, -2083126253, -2079885359, -2076566159, -2073168776, -2069693341, -2066139982, -2062508835, -2058800035, -2055013722, -2051150040, -2047209132, -2043191149, -2039096240, -2034924561, -2030676268, -2026351521, -2021950483, -2017473320, -2012920200, -2008291295, -2003586778, -1998806828, -1993951624, -1989021349, -1984016188, -1978936330, -1973781966, -1968553291, -1963250500, -1957873795, -1952423376, -1946899450, -1941302224, -1935631909, -1929888719, -1924072870, -1918184580, -1912224072, -1906191569, -1900087300, -1893911493, -1887664382, -1881346201, -1874957188, -1868497585, -1861967633, -1855367580, -1848697673, -1841958164, -1835149305, -1828271355, -1821324571, -1814309215, -1807225552, -1800073848, -1792854372, -1785567395, -1778213194, -1770792043, -1763304223, -1755750016, -1748129706, -1740443580, -1732691927, -1724875039, -1716993211, -1709046738, -1701035921, -1692961061, -1684822463, -1676620431, -1668355276, -1660027308, -1651636840, -1643184190, -1634669675, -1626093615, -1617456334, -1608758157, -1599999410, -1591180425, -1582301533, -1573363067, -1564365366, -1555308767, -1546193612, -1537020243, -1527789006, -1518500249, -1509154322, -1499751575, -1490292364, -1480777044, -1471205973, -1461579513, -1451898025, -1442161874, -1432371426, -1422527050, -1412629117, -1402677999, -1392674071, -1382617710, -1372509294, -1362349204, -1352137822, -1341875532, -1331562722, -1321199780, -1310787095, -1300325059, -1289814068, -1279254515, -1268646799, -1257991319, -1247288477, -1236538675, -1225742318, -1214899812, -1204011566, -1193077990, -1182099495, -1171076495, -1160009404, -1148898640, -1137744620, -1126547765, -1115308496, -1104027236, -1092704410, -1081340445, -1069935767, -1058490807, -1047005996, -1035481765, -1023918549, -1012316784, -1000676905, -988999351, -977284561, -965532978, -953745043, -941921200, -930061894, -918167571, -906238681, -894275670, -882278991, -870249095, -858186434, -846091463, -833964637, -821806413, -809617248, -797397602, -785147934, -772868706, -760560379, -748223418, -735858287, -723465451, -711045377, -698598533, -686125386, -673626408, -661102068, -648552837, -635979190, -623381597, -610760535, -598116478, -585449903, -572761285, -560051103, -547319836, -534567963, -521795963, -509004318, -496193509, -483364019, -470516330, -457650927, -444768293, -431868915, -418953276, -406021864, -393075166, -380113669, -367137860, -354148229, -341145265, -328129457, -315101294, -302061269, -289009871, -275947592, -262874923, -249792358, -236700388, -223599506, -210490206, -197372981, -184248325, -171116732, -157978697, -144834714, -131685278, -118530885, -105372028, -92209205, -79042909, -65873638, -52701887, -39528151, -26352928, -13176712, ], [0, 13176712, 26352928, 39528151, 52701887, 65873638, 79042909, 92209205, 105372028, 118530885, 131685278, 144834714, 157978697, 171116732, 184248325, 197372981, 210490206, 223599506, 236700388, 249792358, 262874923, 275947592, 289009871, 302061269, 315101294, 328129457, 341145265, 354148229, 367137860, 380113669, 393075166, 406021864, 418953276, 431868915, 444768293, 457650927, 470516330, 483364019, 496193509, 509004318, 521795963, 534567963, 547319836, 560051103, 572761285, 585449903, 598116478, 610760535, 623381597, 635979190, 648552837, 661102068, 673626408, 686125386, 698598533, 711045377, 723465451, 735858287, 748223418, 760560379, 772868706, 785147934, 797397602, 809617248, 821806413, 833964637, 846091463, 858186434, 870249095, 882278991, 894275670, 906238681, 918167571, 930061894, 941921200, 953745043, 965532978, 977284561, 988999351, 1000676905, 1012316784, 1023918549, 1035481765, 1047005996, 1058490807, 1069935767, 1081340445, 1092704410, 1104027236, 1115308496, 1126547765, 1137744620, 1148898640, 1160009404, 1171076495, 1182099495, 1193077990, 1204011566, 1214899812, 1225742318, 1236538675, 1247288477, 1257991319, 1268646799, 1279254515, 1289814068, 1300325059, 1310787095, 1321199780, 1331562722, 1341875532, 1352137822, 1362349204, 1372509294, 1382617710, 1392674071, 1402677999, 1412629117, 1422527050, 1432371426, 1442161874, 1451898025, 1461579513, 1471205973, 1480777044, 1490292364, 1499751575, 1509154322, 1518500249, 1527789006, 1537020243, 1546193612, 1555308767, 1564365366, 1573363067, 1582301533, 1591180425, 1599999410, 1608758157, 1617456334, 1626093615, 1634669675, 1643184190, 1651636840, 1660027308, 1668355276, 1676620431, 1684822463, 1692961061, 1701035921, 1709046738, 1716993211, 1724875039, 1732691927, 1740443580, 1748129706, 1755750016, 1763304223, 1770792043, 1778213194, 1785567395, 1792854372, 1800073848, 1807225552, 1814309215, 1821324571, 1828271355, 1835149305, 1841958164, 1848697673, 1855367580, 1861967633, 1868497585, 1874957188, 1881346201, 1887664382, 1893911493, 1900087300, 1906191569, 1912224072, 1918184580, 1924072870, 1929888719, 1935631909, 1941302224, 1946899450, 1952423376, 1957873795, 1963250500, 1968553291, 1973781966, 1978936330, 1984016188, 1989021349, 1993951624, 1998806828, 2003586778, 2008291295, 2012920200, 2017473320, 2021950483, 2026351521, 2030676268, 2034924561, 2039096240, 2043191149, 2047209132, 2051150040, 2055013722, 2058800035, 2062508835, 2066139982, 2069693341, 2073168776, 2076566159, 2079885359, 2083126253, 2086288719, 2089372637, 2092377891, 2095304369, 2098151959, 2100920555, 2103610053, 2106220351, 2108751351, 2111202958, 2113575079, 2115867625, 2118080510, 2120213650, 2122266966, 2124240379, 2126133816, 2127947205, 2129680479, 2131333571, 2132906419, 2134398965, 2135811152, 2137142926, 2138394239, 2139565042, 2140655292, 2141664947, 2142593970, 2143442325, 2144209981, 2144896909, 2145503082, 2146028479, 2146473079, 2146836865, 2147119824, 2147321945, 2147443221, 2147483647, 2147443221, 2147321945, 2147119824, 2146836865, 2146473079, 2146028479, 2145503082, 2144896909, 2144209981, 2143442325, 2142593970, 2141664947, 2140655292, 2139565042, 2138394239, 2137142926, 2135811152, 2134398965, 2132906419, 2131333571, 2129680479, 2127947205, 2126133816, 2124240379, 2122266966, 2120213650, 2118080510, 2115867625, 2113575079, 2111202958, 2108751351, 2106220351, 2103610053, 2100920555, 2098151959, 2095304369, 2092377891, 2089372637, 2086288719, 2083126253, 2079885359, 2076566159, 2073168776, 2069693341, 2066139982, 2062508835, 2058800035, 2055013722, 2051150040, 2047209132, 2043191149, 2039096240, 2034924561, 2030676268, 2026351521, 2021950483, 2017473320, 2012920200, 2008291295, 2003586778, 1998806828, 1993951624, 1989021349, 1984016188, 1978936330, 1973781966, 1968553291, 1963250500, 1957873795, 1952423376, 1946899450, 1941302224, 1935631909, 1929888719, 1924072870, 1918184580, 1912224072, 1906191569, 1900087300, 1893911493, 1887664382, 1881346201, 1874957188, 1868497585, 1861967633, 1855367580, 1848697673, 1841958164, 1835149305, 1828271355, 1821324571, 1814309215, 1807225552, 1800073848, 1792854372, 1785567395, 1778213194, 1770792043, 1763304223, 1755750016, 1748129706, 1740443580, 1732691927, 1724875039, 1716993211, 1709046738, 1701035921, 1692961061, 1684822463, 1676620431, 1668355276, 1660027308, 1651636840, 1643184190, 1634669675, 1626093615, 1617456334, 1608758157, 1599999410, 1591180425, 1582301533, 1573363067, 1564365366, 1555308767, 1546193612, 1537020243, 1527789006, 1518500249, 1509154322, 1499751575, 1490292364, 1480777044, 1471205973, 1461579513, 1451898025, 1442161874, 1432371426, 1422527050, 1412629117, 1402677999, 1392674071, 1382617710, 1372509294, 1362349204, 1352137822, 1341875532, 1331562722, 1321199780, 1310787095, 1300325059, 1289814068, 1279254515, 1268646799, 1257991319, 1247288477, 1236538675, 1225742318, 1214899812, 1204011566, 1193077990, 1182099495, 1171076495, 1160009404, 1148898640, 1137744620, 1126547765, 1115308496, 1104027236, 1092704410, 1081340445, 1069935767, 1058490807, 1047005996, 1035481765, 1023918549, 1012316784, 1000676905, 988999351, 977284561, 965532978, 953745043, 941921200, 930061894, 918167571, 906238681, 894275670, 882278991, 870249095, 858186434, 846091463, 833964637, 821806413, 809617248, 797397602, 785147934, 772868706, 760560379, 748223418, 735858287, 723465451, 711045377, 698598533, 686125386, 673626408, 661102068, 648552837, 635979190, 623381597, 610760535, 598116478, 585449903, 572761285, 560051103, 547319836, 534567963, 521795963, 509004318, 496193509, 483364019, 470516330, 457650927, 444768293, 431868915, 418953276, 406021864, 393075166, 380113669, 367137860, 354148229, 341145265, 328129457, 315101294, 302061269, 289009871, 275947592, 262874923, 249792358, 236700388, 223599506, 210490206, 197372981, 184248325, 171116732, 157978697, 144834714, 131685278, 118530885, 105372028, 92209205, 79042909, 65873638, 52701887, 39528151, 26352928, 13176712, 0, -13176712, -26352928, -39528151, -52701887, -65873638, -79042909, -92209205, -105372028, -118530885, -131685278, -144834714, -157978697, -171116732, -184248325, -197372981, -210490206, -223599506, -236700388, -249792358, -262874923, -275947592, -289009871, -302061269, -315101294, -328129457, -341145265, -354148229, -367137860, -380113669, -393075166, -406021864, -418953276, -431868915, -444768293, -457650927, -470516330, -483364019, -496193509, -509004318, -521795963, -534567963, -547319836, -560051103, -572761285, -585449903, -598116478, -610760535, -623381597, -635979190, -648552837, -661102068, -673626408, -686125386, -698598533, -711045377, -723465451, -735858287, -748223418, -760560379, -772868706, -785147934, -797397602, -809617248, -821806413, -833964637, -846091463, -858186434, -870249095, -882278991, -894275670, -906238681, -918167571, -930061894, -941921200, -953745043, -965532978, -977284561, -988999351, -1000676905, -1012316784, -1023918549, -1035481765, -1047005996, -1058490807, -1069935767, -1081340445, -1092704410, -1104027236, -1115308496, -1126547765, -1137744620, -1148898640, -1160009404, -1171076495, -1182099495, -1193077990, -1204011566, -1214899812, -1225742318, -1236538675, -1247288477, -1257991319, -1268646799, -1279254515, -1289814068, -1300325059, -1310787095, -1321199780, -1331562722, -1341875532, -1352137822, -1362349204, -1372509294, -1382617710, -1392674071, -1402677999, -1412629117, -1422527050, -1432371426, -1442161874, -1451898025, -1461579513, -1471205973, -1480777044, -1490292364, -1499751575, -1509154322, -1518500249, -1527789006, -1537020243, -1546193612, -1555308767, -1564365366, -1573363067, -1582301533, -1591180425, -1599999410, -1608758157, -1617456334, -1626093615, -1634669675, -1643184190, -1651636840, -1660027308, -1668355276, -1676620431, -1684822463, -1692961061, -1701035921, -1709046738, -1716993211, -1724875039, -1732691927, -1740443580, -1748129706, -1755750016, -1763304223, -1770792043, -1778213194, -1785567395, -1792854372, -1800073848, -1807225552, -1814309215, -1821324571, -1828271355, -1835149305, -1841958164, -1848697673, -1855367580, -1861967633, -1868497585, -1874957188, -1881346201, -1887664382, -1893911493, -1900087300, -1906191569, -1912224072, -1918184580, -1924072870, -1929888719, -1935631909, -1941302224, -1946899450, -1952423376, -1957873795, -1963250500, -1968553291, -1973781966, -1978936330, -1984016188, -1989021349, -1993951624, -1998806828, -2003586778, -2008291295, -2012920200, -2017473320, -2021950483, -2026351521, -2030676268, -2034924561, -2039096240, -2043191149, -2047209132, -2051150040, -2055013722, -2058800035, -2062508835, -2066139982, -2069693341, -2073168776, -2076566159, -2079885359, -2083126253, -2086288719, -2089372637, -2092377891, -2095304369, -2098151959, -2100920555, -2103610053, -2106220351, -2108751351, -2111202958, -2113575079, -2115867625, -2118080510, -2120213650, -2122266966, -2124240379, -2126133816, -2127947205, -2129680479, -2131333571, -2132906419, -2134398965, -2135811152, -2137142926, -2138394239, -2139565042, -2140655292, -2141664947, -2142593970, -2143442325, -2144209981, -2144896909, -2145503082, -2146028479, -2146473079, -2146836865, -2147119824, -2147321945, -2147443221, -2147483647, -2147443221, -2147321945, -2147119824, -2146836865, -2146473079, -2146028479, -2145503082, -2144896909, -2144209981, -2143442325, -2142593970, -2141664947, -2140655292, -2139565042, -2138394239, -2137142926, -2135811152, -2134398965, -2132906419, -2131333571, -2129680479, -2127947205, -2126133816, -2124240379, -2122266966, -2120213650, -2118080510, -2115867625, -2113575079, -2111202958, -2108751351, -2106220351, -2103610053, -2100920555, -2098151959, -2095304369, -2092377891, -2089372637, -2086288719, -2083126253, -2079885359, -2076566159, -2073168776, -2069693341, -2066139982, -2062508835, -2058800035, -2055013722, -2051150040, -2047209132, -2043191149, -2039096240, -2034924561, -2030676268, -2026351521, -2021950483, -2017473320, -2012920200, -2008291295, -2003586778, -1998806828, -1993951624, -1989021349, -1984016188, -1978936330, -1973781966, -1968553291, -1963250500, -1957873795, -1952423376, -1946899450, -1941302224, -1935631909, -1929888719, -1924072870, -1918184580, -1912224072, -1906191569, -1900087300, -1893911493, -1887664382, -1881346201, -1874957188, -1868497585, -1861967633, -1855367580, -1848697673, -1841958164, -1835149305, -1828271355, -1821324571, -1814309215, -1807225552, -1800073848, -1792854372, -1785567395, -1778213194, -1770792043, -1763304223, -1755750016, -1748129706, -1740443580, -1732691927, -1724875039, -1716993211, -1709046738, -1701035921, -1692961061, -1684822463, -1676620431, -1668355276, -1660027308, -1651636840, -1643184190, -1634669675, -1626093615, -1617456334, -1608758157, -1599999410, -1591180425, -1582301533, -1573363067, -1564365366, -1555308767, -1546193612, -1537020243, -1527789006, -1518500249, -1509154322, -1499751575, -1490292364, -1480777044, -1471205973, -1461579513, -1451898025, -1442161874, -1432371426, -1422527050, -1412629117, -1402677999, -1392674071, -1382617710, -1372509294, -1362349204, -1352137822, -1341875532, -1331562722, -1321199780, -1310787095, -1300325059, -1289814068, -1279254515, -1268646799, -1257991319, -1247288477, -1236538675, -1225742318, -1214899812, -1204011566, -1193077990, -1182099495, -1171076495, -1160009404, -1148898640, -1137744620, -1126547765, -1115308496, -1104027236, -1092704410, -1081340445, -1069935767, -1058490807, -1047005996, -1035481765, -1023918549, -1012316784, -1000676905, -988999351, -977284561, -965532978, -953745043, -941921200, -930061894, -918167571, -906238681, -894275670, -882278991, -870249095, -858186434, -846091463, -833964637, -821806413, -809617248, -797397602, -785147934, -772868706, -760560379, -748223418, -735858287, -723465451, -711045377, -698598533, -686125386, -673626408, -661102068, -648552837, -635979190, -623381597, -610760535, -598116478, -585449903, -572761285, -560051103, -547319836, -534567963, -521795963, -509004318, -496193509, -483364019, -470516330, -457650927, -444768293, -431868915, -418953276, -406021864, -393075166, -380113669, -367137860, -354148229, -341145265, -328129457, -315101294, -302061269, -289009871, -275947592, -262874923, -249792358, -236700388, -223599506, -210490206, -197372981, -184248325, -171116732, -157978697, -144834714, -131685278, -118530885, -105372028, -92209205, -79042909, -65873638, -52701887, -39528151, -26352928, -13176712, ], ];
@@ -0,0 +1,2 @@
+#[allow(clippy::approx_constant)]
+pub static TRIANGLE_F32: [[f32; 1024]; 10] = [[0.0, 0.003909345, 0.007818689, 0.011728033, 0.015637377, 0.019546723, 0.023456067, 0.027365413, 0.031274755, 0.0351841, 0.039093446, 0.04300279, 0.046912134, 0.05082148, 0.05473082, 0.058640167, 0.06254951, 0.06645886, 0.0703682, 0.07427755, 0.07818689, 0.082096234, 0.086005576, 0.089914925, 0.09382427, 0.09773362, 0.10164296, 0.1055523, 0.10946164, 0.11337099, 0.117280334, 0.12118968, 0.12509902, 0.12900837, 0.13291772, 0.13682707, 0.1407364, 0.14464575, 0.14855509, 0.15246443, 0.15637378, 0.16028313, 0.16419247, 0.16810182, 0.17201115, 0.1759205, 0.17982985, 0.1837392, 0.18764853, 0.19155788, 0.19546722, 0.19937657, 0.20328592, 0.20719527, 0.2111046, 0.21501395, 0.21892329, 0.22283264, 0.22674198, 0.23065133, 0.23456067, 0.23847002, 0.24237935, 0.2462887, 0.25019804, 0.2541074, 0.25801674, 0.26192608, 0.26583543, 0.26974478, 0.2736541, 0.27756345, 0.2814728, 0.28538215, 0.2892915, 0.29320085, 0.29711017, 0.30101952, 0.30492887, 0.30883822, 0.31274757, 0.31665692, 0.32056624, 0.3244756, 0.32838494, 0.3322943, 0.33620363, 0.34011298, 0.3440223, 0.34793165, 0.351841, 0.35575035, 0.3596597, 0.36356905, 0.36747837, 0.37138772, 0.37529707, 0.37920642, 0.38311577, 0.38702512, 0.39093444, 0.39484382, 0.39875314, 0.4026625, 0.40657184, 0.41048118, 0.4143905, 0.41829988, 0.4222092, 0.42611855, 0.4300279, 0.43393725, 0.43784657, 0.44175595, 0.44566527, 0.44957462, 0.45348397, 0.45739332, 0.46130264, 0.46521202, 0.46912134, 0.4730307, 0.47694004, 0.4808494, 0.4847587, 0.48866808, 0.4925774, 0.49648675, 0.5003961, 0.5043055, 0.5082148, 0.5121241, 0.5160335, 0.5199428, 0.52385217, 0.5277615, 0.53167087, 0.5355802, 0.5394895, 0.5433989, 0.5473082, 0.5512176, 0.5551269, 0.55903625, 0.5629456, 0.56685495, 0.5707643, 0.57467365, 0.578583, 0.58249235, 0.58640164, 0.59031105, 0.59422034, 0.59812975, 0.60203904, 0.6059484, 0.60985774, 0.6137671, 0.61767644, 0.6215858, 0.62549514, 0.6294045, 0.6333138, 0.6372232, 0.6411325, 0.6450419, 0.6489512, 0.6528606, 0.6567699, 0.6606792, 0.6645886, 0.6684979, 0.6724072, 0.6763166, 0.6802259, 0.6841353, 0.6880446, 0.691954, 0.6958633, 0.6997727, 0.703682, 0.7075914, 0.7115007, 0.71541005, 0.71931934, 0.72322875, 0.72713804, 0.73104745, 0.73495674, 0.73886615, 0.74277544, 0.74668485, 0.75059414, 0.75450355, 0.7584128, 0.76232225, 0.7662315, 0.7701409, 0.7740502, 0.7779596, 0.7818689, 0.7857783, 0.7896875, 0.793597, 0.7975062, 0.8014157, 0.8053249, 0.8092344, 0.8131436, 0.8170531, 0.82096225, 0.8248718, 0.82878095, 0.8326905, 0.83659965, 0.8405092, 0.84441835, 0.8483279, 0.852237, 0.8561466, 0.8600557, 0.8639653, 0.8678743, 0.871784, 0.875693, 0.8796027, 0.88351166, 0.88742137, 0.89133036, 0.8952401, 0.899149, 0.9030588, 0.90696764, 0.9108776, 0.9147863, 0.91869634, 0.9226049, 0.9265151, 0.9304235, 0.9343339, 0.9382421, 0.94215274, 0.94606054, 0.9499717, 0.95387894, 0.95779073, 0.96169716, 0.96561, 0.96951497, 0.97343, 0.9773318, 0.9812516, 0.98514545, 0.98908013, 0.9929411, 0.99697536, 1.0, 0.99697536, 0.9929411, 0.98908013, 0.98514545, 0.9812516, 0.9773318, 0.97343, 0.96951497, 0.96561, 0.96169716, 0.95779073, 0.95387894, 0.9499717, 0.94606054, 0.94215274, 0.9382421, 0.9343339, 0.9304235, 0.9265151, 0.9226049, 0.91869634, 0.9147863, 0.9108776, 0.90696764, 0.9030588, 0.899149, 0.8952401, 0.89133036, 0.88742137, 0.88351166, 0.8796027, 0.875693, 0.871784, 0.8678743, 0.8639653, 0.8600557, 0.8561466, 0.852237, 0.8483279, 0.84441835, 0.8405092, 0.83659965, 0.8326905, 0.82878095, 0.8248718, 0.82096225, 0.8170531, 0.8131436, 0.8092344, 0.8053249, 0.8014157, 0.7975062, 0.793597, 0.7896875, 0.7857783, 0.7818689, 0.7779596, 0.7740502, 0.7701409, 0.7662315, 0.76232225, 0.7584128, 0.75450355, 0.75059414, 0.74668485, 0.74277544, 0.73886615, 0.73495674, 0.73104745, 0.72713804, 0.72322875, 0.71931934, 0.71541005, 0.7115007, 0.7075914, 0.703682, 0.6997727, 0.6958633, 0.691954, 0.6880446, 0.6841353, 0.6802259, 0.6763166, 0.6724072, 0.6684979, 0.6645886, 0.6606792, 0.6567699, 0.6528606, 0.6489512, 0.6450419, 0.6411325, 0.6372232, 0.6333138, 0.6294045, 0.62549514, 0.6215858, 0.61767644, 0.6137671, 0.60985774, 0.6059484, 0.60203904, 0.59812975, 0.59422034, 0.59031105, 0.58640164, 0.58249235, 0.578583, 0.57467365, 0.5707643, 0.56685495, 0.5629456, 0.55903625, 0.5551269, 0.5512176, 0.5473082, 0.5433989, 0.5394895, 0.5355802, 0.53167087, 0.5277615, 0.52385217, 0.5199428, 0.5160335, 0.5121241, 0.5082148, 0.5043055, 0.5003961, 0.49648675, 0.4925774, 0.48866808, 0.4847587, 0.4808494, 0.47694004, 0.4730307, 0.46912134, 0.46521202, 0.46130264, 0.45739332, 0.45348397, 0.44957462, 0.44566527, 0.44175595, 0.43784657, 0.43393725, 0.4300279, 0.42611855, 0.4222092, 0.41829988, 0.4143905, 0.41048118, 0.40657184, 0.4026625, 0.39875314, 0.39484382, 0.39093444, 0.38702512, 0.38311577, 0.37920642, 0.37529707, 0.37138772, 0.36747837, 0.36356905, 0.3596597, 0.35575035, 0.351841, 0.34793165, 0.3440223, 0.34011298, 0.33620363, 0.3322943, 0.32838494, 0.3244756, 0.32056624, 0.31665692, 0.31274757, 0.30883822, 0.30492887, 0.30101952, 0.29711017, 0.29320085, 0.2892915, 0.28538215, 0.2814728, 0.27756345, 0.2736541, 0.26974478, 0.26583543, 0.26192608, 0.25801674, 0.2541074, 0.25019804, 0.2462887, 0.24237935, 0.23847002, 0.23456067, 0.23065133, 0.22674198, 0.22283264, 0.21892329, 0.21501395, 0.2111046, 0.20719527, 0.20328592, 0.19937657, 0.19546722, 0.19155788, 0.18764853, 0.1837392, 0.17982985, 0.1759205, 0.17201115, 0.16810182, 0.16419247, 0.16028313, 0.15637378, 0.15246443, 0.14855509, 0.14464575, 0.1407364, 0.13682707, 0.13291772, 0.12900837, 0.12509902, 0.12118968, 0.117280334, 0.11337099, 0.10946164, 0.1055523, 0.10164296, 0.09773362, 0.09382427, 0.089914925, 0.086005576, 0.082096234, 0.07818689, 0.07427755, 0.0703682, 0.06645886, 0.06254951, 0.058640167, 0.05473082, 0.05082148, 0.046912134, 0.04300279, 0.039093446, 0.0351841, 0.031274755, 0.027365413, 0.023456067, 0.019546723, 0.015637377, 0.011728033, 0.007818689, 0.003909345, 4.6958928e-18, -0.003909345, -0.007818689, -0.011728033, -0.015637377, -0.019546723, -0.023456067, -0.027365413, -0.031274755, -0.0351841, -0.039093446, -0.04300279, -0.046912134, -0.05082148, -0.05473082, -0.058640167, -0.06254951, -0.06645886, -0.0703682, -0.07427755, -0.07818689, -0.082096234, -0.086005576, -0.089914925, -0.09382427, -0.09773362, -0.10164296, -0.1055523, -0.10946164, -0.11337099, -0.117280334, -0.12118968, -0.12509902, -0.12900837, -0.13291772, -0.13682707, -0.1407364, -0.14464575, -0.14855509, -0.15246443, -0.15637378, -0.16028313, -0.16419247, -0.16810182, -0.17201115, -0.1759205, -0.17982985, -0.1837392, -0.18764853, -0.19155788, -0.19546722, -0.19937657, -0.20328592, -0.20719527, -0.2111046, -0.21501395, -0.21892329, -0.22283264, -0.22674198, -0.23065133, -0.23456067, -0.23847002, -0.24237935, -0.2462887, -0.25019804, -0.2541074, -0.25801674, -0.26192608, -0.26583543, -0.26974478, -0.2736541, -0.27756345, -0.2814728, -0.28538215, -0.2892915, -0.29320085, -0.29711017, -0.30101952, -0.30492887, -0.30883822, -0.31274757, -0.31665692, -0.32056624, -0.3244756, -0.32838494, -0.3322943, -0.33620363, -0.34011298, -0.3440223, -0.34793165, -0.351841, -0.35575035, -0.3596597, -0.36356905, -0.36747837, -0.37138772, -0.37529707, -0.37920642, -0.38311577, -0.38702512, -0.39093444, -0.39484382, -0.39875314, -0.4026625, -0.40657184, -0.41048118, -0.4143905, -0.41829988, -0.4222092, -0.42611855, -0.4300279, -0.43393725, -0.43784657, -0.44175595, -0.44566527, -0.44957462, -0.45348397, -0.45739332, -0.46130264, -0.46521202, -0.46912134, -0.4730307, -0.47694004, -0.4808494, -0.4847587, -0.48866808, -0.4925774, -0.49648675, -0.5003961, -0.5043055, -0.5082148, -0.5121241, -0.5160335, -0.5199428, -0.52385217, -0.5277615, -0.53167087, -0.5355802, -0.5394895, -0.5433989, -0.5473082, -0.5512176, -0.5551269, -0.55903625, -0.5629456, -0.56685495, -0.5707643, -0.57467365, -0.578583, -0.58249235, -0.58640164, -0.59031105, -0.59422034, -0.59812975, -0.60203904, -0.6059484, -0.60985774, -0.6137671, -0.61767644, -0.6215858, -0.62549514, -0.6294045, -0.6333138, -0.6372232, -0.6411325, -0.6450419, -0.6489512, -0.6528606, -0.6567699, -0.6606792, -0.6645886, -0.6684979, -0.6724072, -0.6763166, -0.6802259, -0.6841353, -0.6880446, -0.691954, -0.6958633, -0.6997727, -0.703682, -0.7075914, -0.7115007, -0.71541005, -0.71931934, -0.72322875, -0.72713804, -0.73104745, -0.73495674, -0.73886615, -0.74277544, -0.74668485, -0.75059414, -0.75450355, -0.7584128, -0.76232225, -0.7662315, -0.7701409, -0.7740502, -0.7779596, -0.7818689, -0.7857783, -0.7896875, -0.793597, -0.7975062, -0.8014157, -0.8053249, -0.8092344, -0.8131436, -0.8170531, -0.82096225, -0.8248718, -0.82878095, -0.8326905, -0.83659965, -0.8405092, -0.84441835, -0.8483279, -0.852237, -0.8561466, -0.8600557, -0.8639653, -0.8678743, -0.871784, -0.875693, -0.8796027, -0.88351166, -0.88742137, -0.89133036, -0.8952401, -0.899149, -0.9030588, -0.90696764, -0.9108776, -0.9147863, -0.91869634, -0.9226049, -0.9265151, -0.9304235, -0.9343339, -0.9382421, -0.94215274, -0.94606054, -0.9499717, -0.95387894, -0.95779073, -0.96169716, -0.96561, -0.96951497, -0.97343, -0.9773318, -0.9812516, -0.98514545, -0.98908013, -0.9929411, -0.99697536, -1.0, -0.99697536, -0.9929411, -0.98908013, -0.98514545, -0.9812516, -0.9773318, -0.97343, -0.96951497, -0.96561, -0.96169716, -0.95779073, -0.95387894, -0.9499717, -0.94606054, -0.94215274, -0.9382421, -0.9343339, -0.9304235, -0.9265151, -0.9226049, -0.91869634, -0.9147863, -0.9108776, -0.90696764, -0.9030588, -0.899149, -0.8952401, -0.89133036, -0.88742137, -0.88351166, -0.8796027, -0.875693, -0.871784, -0.8678743, -0.8639653, -0.8600557, -0.8561466, -0.852237, -0.8483279, -0.84441835, -0.8405092, -0.83659965, -0.8326905, -0.82878095, -0.8248718, -0.82096225, -0.8170531, -0.8131436, -0.8092344, -0.8053249, -0.8014157, -0.7975062, -0.793597, -0.7896875, -0.7857783, -0.7818689, -0.7779596, -0.7740502, -0.7701409, -0.7662315, -0.76232225, -0.7584128, -0.75450355, -0.75059414, -0.74668485, -0.74277544, -0.73886615, -0.73495674, -0.73104745, -0.72713804, -0.72322875, -0.71931934, -0.71541005, -0.7115007, -0.7075914, -0.703682, -0.6997727, -0.6958633, -0.691954, -0.6880446, -0.6841353, -0.6802259, -0.6763166, -0.6724072, -0.6684979, -0.6645886, -0.6606792, -0.6567699, -0.6528606, -0.6489512, -0.6450419, -0.6411325, -0.6372232, -0.6333138, -0.6294045, -0.62549514, -0.6215858, -0.61767644, -0.6137671, -0.60985774, -0.6059484, -0.60203904, -0.59812975, -0.59422034, -0.59031105, -0.58640164, -0.58249235, -0.578583, -0.57467365, -0.5707643, -0.56685495, -0.5629456, -0.55903625, -0.5551269, -0.5512176, -0.5473082, -0.5433989, -0.5394895, -0.5355802, -0.53167087, -0.5277615, -0.52385217, -0.5199428, -0.5160335, -0.5121241, -0.5082148, -0.5043055, -0.5003961, -0.49648675, -0.4925774, -0.48866808, -0.4847587, -0.4808494, -0.47694004, -0.4730307, -0.46912134, -0.46521202, -0.46130264, -0.45739332, -0.45348397, -0.44957462, -0.44566527, -0.44175595, -0.43784657, -0.43393725, -0.4300279, -0.42611855, -0.4222092, -0.41829988, -0.4143905, -0.41048118, -0.40657184, -0.4026625, -0.39875314, -0.39484382, -0.39093444, -0.38702512, -0.38311577, -0.37920642, -0.37529707, -0.37138772, -0.36747837, -0.36356905, -0.3596597, -0.35575035, -0.351841, -0.34793165, -0.3440223, -0.34011298, -0.33620363, -0.3322943, -0.32838494, -0.3244756, -0.32056624, -0.31665692, -0.31274757, -0.30883822, -0.30492887, -0.30101952, -0.29711017, -0.29320085, -0.2892915, -0.28538215, -0.2814728, -0.27756345, -0.2736541, -0.26974478, -0.26583543, -0.26192608, -0.25801674, -0.2541074, -0.25019804, -0.2462887, -0.24237935, -0.23847002, -0.23456067, -0.23065133, -0.22674198, -0.22283264, -0.21892329, -0.21501395, -0.2111046, -0.20719527, -0.20328592, -0.19937657, -0.19546722, -0.19155788, -0.18764853, -0.1837392, -0.17982985, -0.1759205, -0.17201115, -0.16810182, -0.16419247, -0.16028313, -0.15637378, -0.15246443, -0.14855509, -0.14464575, -0.1407364, -0.13682707, -0.13291772, -0.12900837, -0.12509902, -0.12118968, -0.117280334, -0.11337099, -0.10946164, -0.1055523, -0.10164296, -0.09773362, -0.09382427, -0.089914925, -0.086005576, -0.082096234, -0.07818689, -0.07427755, -0.0703682, -0.06645886, -0.06254951, -0.058640167, -0.05473082, -0.05082148, -0.046912134, -0.04300279, -0.039093446, -0.0351841, -0.031274755, -0.027365413, -0.023456067, -0.019546723, -0.015637377, -0.011728033, -0.007818689, -0.003909345, ], [0.0, 0.0039031557, 0.00781869, 0.011734223, 0.015637377, 0.019540532, 0.023456069, 0.027371606, 0.031274755, 0.0351779, 0.03909345, 0.043008994, 0.04691213, 0.05081527, 0.05473083, 0.058646385, 0.06254951, 0.06645264, 0.07036821, 0.07428378, 0.078186885, 0.08209, 0.08600558, 0.08992118, 0.09382426, 0.09772735, 0.101642966, 0.105558574, 0.109461635, 0.113364704, 0.11728034, 0.12119598, 0.12509902, 0.12900205, 0.13291772, 0.1368334, 0.14073639, 0.1446394, 0.1485551, 0.15247081, 0.15637377, 0.16027674, 0.16419248, 0.16810822, 0.17201115, 0.17591406, 0.17982987, 0.18374565, 0.18764852, 0.1915514, 0.19546725, 0.19938308, 0.2032859, 0.20718873, 0.21110462, 0.21502051, 0.21892327, 0.22282605, 0.226742, 0.23065795, 0.23456065, 0.23846337, 0.24237938, 0.24629539, 0.25019804, 0.25410068, 0.25801677, 0.26193282, 0.2658354, 0.269738, 0.27365413, 0.27757028, 0.28147277, 0.2853753, 0.28929153, 0.29320773, 0.29711017, 0.30101258, 0.3049289, 0.30884522, 0.31274754, 0.31664985, 0.32056627, 0.32448268, 0.3283849, 0.33228713, 0.33620366, 0.34012017, 0.34402227, 0.3479244, 0.35184103, 0.35575765, 0.35965967, 0.3635617, 0.36747843, 0.37139514, 0.37529704, 0.37919894, 0.3831158, 0.38703266, 0.3909344, 0.3948362, 0.3987532, 0.40267015, 0.40657178, 0.41047344, 0.41439056, 0.41830766, 0.42220917, 0.42611066, 0.43002796, 0.4339452, 0.43784654, 0.44174787, 0.44566533, 0.44958276, 0.4534839, 0.4573851, 0.4613027, 0.4652203, 0.46912128, 0.47302228, 0.4769401, 0.48085788, 0.48475865, 0.48865947, 0.49257746, 0.49649546, 0.500396, 0.50429666, 0.50821483, 0.51213306, 0.5160334, 0.51993376, 0.5238522, 0.52777064, 0.53167075, 0.5355709, 0.5394896, 0.5434083, 0.54730815, 0.551208, 0.555127, 0.559046, 0.56294554, 0.5668451, 0.5707644, 0.5746836, 0.5785829, 0.5824822, 0.58640176, 0.5903213, 0.5942203, 0.59811926, 0.60203916, 0.60595906, 0.6098576, 0.61375624, 0.61767656, 0.6215968, 0.625495, 0.6293932, 0.63331395, 0.6372346, 0.64113235, 0.64503014, 0.64895135, 0.65287244, 0.65676975, 0.66066706, 0.66458875, 0.6685103, 0.6724071, 0.6763039, 0.68022615, 0.68414825, 0.6880444, 0.6919407, 0.69586354, 0.69978625, 0.7036818, 0.7075774, 0.71150094, 0.71542436, 0.71931916, 0.7232141, 0.72713834, 0.7310625, 0.7349565, 0.73885065, 0.74277574, 0.7467007, 0.75059384, 0.7544871, 0.75841314, 0.7623391, 0.7662312, 0.7701234, 0.7740506, 0.7779776, 0.7818685, 0.78575957, 0.78968805, 0.7936163, 0.7975058, 0.80139554, 0.80532545, 0.8092552, 0.81314313, 0.81703126, 0.82096297, 0.82489437, 0.8287804, 0.8326667, 0.8366004, 0.8405339, 0.84441763, 0.8483017, 0.852238, 0.8561739, 0.8600548, 0.8639361, 0.8678756, 0.87181455, 0.87569195, 0.8795699, 0.8835133, 0.88745606, 0.89132893, 0.8952025, 0.89915115, 0.90309894, 0.90696573, 0.9108335, 0.91478926, 0.91874385, 0.9226021, 0.9264618, 0.9304279, 0.9343923, 0.9382378, 0.9420854, 0.94606775, 0.9500473, 0.9538714, 0.95769936, 0.96171105, 0.96571714, 0.9694987, 0.9732889, 0.97736806, 0.9814317, 0.98509, 0.9887836, 0.9931585, 0.9973807, 0.9992078, 0.9973807, 0.9931585, 0.9887836, 0.98509, 0.9814317, 0.97736806, 0.9732889, 0.9694987, 0.96571714, 0.96171105, 0.95769936, 0.9538714, 0.9500473, 0.94606775, 0.9420854, 0.9382378, 0.9343923, 0.9304279, 0.9264618, 0.9226021, 0.91874385, 0.91478926, 0.9108335, 0.90696573, 0.90309894, 0.89915115, 0.8952025, 0.89132893, 0.88745606, 0.8835133, 0.8795699, 0.87569195, 0.87181455, 0.8678756, 0.8639361, 0.8600548, 0.8561739, 0.852238, 0.8483017, 0.84441763, 0.8405339, 0.8366004, 0.8326667, 0.8287804, 0.82489437, 0.82096297, 0.81703126, 0.81314313, 0.8092552, 0.80532545, 0.80139554, 0.7975058, 0.7936163, 0.78968805, 0.78575957, 0.7818685, 0.7779776, 0.7740506, 0.7701234, 0.7662312, 0.7623391, 0.75841314, 0.7544871, 0.75059384, 0.7467007, 0.74277574, 0.73885065, 0.7349565, 0.7310625, 0.72713834, 0.7232141, 0.71931916, 0.71542436, 0.71150094, 0.7075774, 0.7036818, 0.69978625, 0.69586354, 0.6919407, 0.6880444, 0.68414825, 0.68022615, 0.6763039, 0.6724071, 0.6685103, 0.66458875, 0.66066706, 0.65676975, 0.65287244, 0.64895135, 0.64503014, 0.64113235, 0.6372346, 0.63331395, 0.6293932, 0.625495, 0.6215968, 0.61767656, 0.61375624, 0.6098576, 0.60595906, 0.60203916, 0.59811926, 0.5942203, 0.5903213, 0.58640176, 0.5824822, 0.5785829, 0.5746836, 0.5707644, 0.5668451, 0.56294554, 0.559046, 0.555127, 0.551208, 0.54730815, 0.5434083, 0.5394896, 0.5355709, 0.53167075, 0.52777064, 0.5238522, 0.51993376, 0.5160334, 0.51213306, 0.50821483, 0.50429666, 0.500396, 0.49649546, 0.49257746, 0.48865947, 0.48475865, 0.48085788, 0.4769401, 0.47302228, 0.46912128, 0.4652203, 0.4613027, 0.4573851, 0.4534839, 0.44958276, 0.44566533, 0.44174787, 0.43784654, 0.4339452, 0.43002796, 0.42611066, 0.42220917, 0.41830766, 0.41439056, 0.41047344, 0.40657178, 0.40267015, 0.3987532, 0.3948362, 0.3909344, 0.38703266, 0.3831158, 0.37919894, 0.37529704, 0.37139514, 0.36747843, 0.3635617, 0.35965967, 0.35575765, 0.35184103, 0.3479244, 0.34402227, 0.34012017, 0.33620366, 0.33228713, 0.3283849, 0.32448268, 0.32056627, 0.31664985, 0.31274754, 0.30884522, 0.3049289, 0.30101258, 0.29711017, 0.29320773, 0.28929153, 0.2853753, 0.28147277, 0.27757028, 0.27365413, 0.269738, 0.2658354, 0.26193282, 0.25801677, 0.25410068, 0.25019804, 0.24629539, 0.24237938, 0.23846337, 0.23456065, 0.23065795, 0.226742, 0.22282605, 0.21892327, 0.21502051, 0.21110462, 0.20718873, 0.2032859, 0.19938308, 0.19546725, 0.1915514, 0.18764852, 0.18374565, 0.17982987, 0.17591406, 0.17201115, 0.16810822, 0.16419248, 0.16027674, 0.15637377, 0.15247081, 0.1485551, 0.1446394, 0.14073639, 0.1368334, 0.13291772, 0.12900205, 0.12509902, 0.12119598, 0.11728034, 0.113364704, 0.109461635, 0.105558574, 0.101642966, 0.09772735, 0.09382426, 0.08992118, 0.08600558, 0.08209, 0.078186885, 0.07428378, 0.07036821, 0.06645264, 0.06254951, 0.058646385, 0.05473083, 0.05081527, 0.04691213, 0.043008994, 0.03909345, 0.0351779, 0.031274755, 0.027371606, 0.023456069, 0.019540532, 0.015637377, 0.011734223, 0.00781869, 0.0039031557, 9.334095e-19, -0.0039031557, -0.00781869, -0.011734223, -0.015637377, -0.019540532, -0.023456069, -0.027371606, -0.031274755, -0.0351779, -0.03909345, -0.043008994, -0.04691213, -0.05081527, -0.05473083, -0.058646385, -0.06254951, -0.06645264, -0.07036821, -0.07428378, -0.078186885, -0.08209, -0.08600558, -0.08992118, -0.09382426, -0.09772735, -0.101642966, -0.105558574, -0.109461635, -0.113364704, -0.11728034, -0.12119598, -0.12509902, -0.12900205, -0.13291772, -0.1368334, -0.14073639, -0.1446394, -0.1485551, -0.15247081, -0.15637377, -0.16027674, -0.16419248, -0.16810822, -0.17201115, -0.17591406, -0.17982987, -0.18374565, -0.18764852, -0.1915514, -0.19546725, -0.19938308, -0.2032859, -0.20718873, -0.21110462, -0.21502051, -0.21892327, -0.22282605, -0.226742, -0.23065795, -0.23456065, -0.23846337, -0.24237938, -0.24629539, -0.25019804, -0.25410068, -0.25801677, -0.26193282, -0.2658354, -0.269738, -0.27365413, -0.27757028, -0.28147277, -0.2853753, -0.28929153, -0.29320773, -0.29711017, -0.30101258, -0.3049289, -0.30884522, -0.31274754, -0.31664985, -0.32056627, -0.32448268, -0.3283849, -0.33228713, -0.33620366, -0.34012017, -0.34402227, -0.3479244, -0.35184103, -0.35575765, -0.35965967, -0.3635617, -0.36747843, -0.37139514, -0.37529704, -0.37919894, -0.3831158, -0.38703266, -0.3909344, -0.3948362, -0.3987532, -0.40267015, -0.40657178, -0.41047344, -0.41439056, -0.41830766, -0.42220917, -0.42611066, -0.43002796, -0.4339452, -0.43784654, -0.44174787, -0.44566533, -0.44958276, -0.4534839, -0.4573851, -0.4613027, -0.4652203, -0.46912128, -0.47302228, -0.4769401, -0.48085788, -0.48475865, -0.48865947, -0.49257746, -0.49649546, -0.500396, -0.50429666, -0.50821483, -0.51213306, -0.5160334, -0.51993376, -0.5238522, -0.52777064, -0.53167075, -0.5355709, -0.5394896, -0.5434083, -0.54730815, -0.551208, -0.555127, -0.559046, -0.56294554, -0.5668451, -0.5707644, -0.5746836, -0.5785829, -0.5824822, -0.58640176, -0.5903213, -0.5942203, -0.59811926, -0.60203916, -0.60595906, -0.6098576, -0.61375624, -0.61767656, -0.6215968, -0.625495, -0.6293932, -0.63331395, -0.6372346, -0.64113235, -0.64503014, -0.64895135, -0.65287244, -0.65676975, -0.66066706, -0.66458875, -0.6685103, -0.6724071, -0.6763039, -0.68022615, -0.68414825, -0.6880444, -0.6919407, -0.69586354, -0.69978625, -0.7036818, -0.7075774, -0.71150094, -0.71542436, -0.71931916, -0.7232141, -0.72713834, -0.7310625, -0.7349565, -0.73885065, -0.74277574, -0.7467007, -0.75059384, -0.7544871, -0.75841314, -0.7623391, -0.7662312, -0.7701234, -0.7740506, -0.7779776, -0.7818685, -0.78575957, -0.78968805, -0.7936163, -0.7975058, -0.80139554, -0.80532545, -0.8092552, -0.81314313, -0.81703126, -0.82096297, -0.82489437, -0.8287804, -0.8326667, -0.8366004, -0.8405339, -0.84441763, -0.8483017, -0.852238, -0.8561739, -0.8600548, -0.8639361, -0.8678756, -0.87181455, -0.87569195, -0.8795699, -0.8835133, -0.88745606, -0.89132893, -0.8952025, -0.89915115, -0.90309894, -0.90696573, -0.9108335, -0.91478926, -0.91874385, -0.9226021, -0.9264618, -0.9304279, -0.9343923, -0.9382378, -0.9420854, -0.94606775, -0.9500473, -0.9538714, -0.95769936, -0.96171105, -0.96571714, -0.9694987, -0.9732889, -0.97736806, -0.9814317, -0.98509, -0.9887836, -0.9931585, -0.9973807, -0.9992078, -0.9973807, -0.9931585, -0.9887836, -0.98509, -0.9814317, -0.97736806, -0.9732889, -0.9694987, -0.96571714, -0.96171105, -0.95769936, -0.9538714, -0.9500473, -0.94606775, -0.9420854, -0.9382378, -0.9343923, -0.9304279, -0.9264618, -0.9226021, -0.91874385, -0.91478926, -0.9108335, -0.90696573, -0.90309894, -0.89915115, -0.8952025, -0.89132893, -0.88745606, -0.8835133, -0.8795699, -0.87569195, -0.87181455, -0.8678756, -0.8639361, -0.8600548, -0.8561739, -0.852238, -0.8483017, -0.84441763, -0.8405339, -0.8366004, -0.8326667, -0.8287804, -0.82489437, -0.82096297, -0.81703126, -0.81314313, -0.8092552, -0.80532545, -0.80139554, -0.7975058, -0.7936163, -0.78968805, -0.78575957, -0.7818685, -0.7779776, -0.7740506, -0.7701234, -0.7662312, -0.7623391, -0.75841314, -0.7544871, -0.75059384, -0.7467007, -0.74277574, -0.73885065, -0.7349565, -0.7310625, -0.72713834, -0.7232141, -0.71931916, -0.71542436, -0.71150094, -0.7075774, -0.7036818, -0.69978625, -0.69586354, -0.6919407, -0.6880444, -0.68414825, -0.68022615, -0.6763039, -0.6724071, -0.6685103, -0.66458875, -0.66066706, -0.65676975, -0.65287244, -0.64895135, -0.64503014, -0.64113235, -0.6372346, -0.63331395, -0.6293932, -0.625495, -0.6215968, -0.61767656, -0.61375624, -0.6098576, -0.60595906, -0.60203916, -0.59811926, -0.5942203, -0.5903213, -0.58640176, -0.5824822, -0.5785829, -0.5746836, -0.5707644, -0.5668451, -0.56294554, -0.559046, -0.555127, -0.551208, -0.54730815, -0.5434083, -0.5394896, -0.5355709, -0.53167075, -0.52777064, -0.5238522, -0.51993376, -0.5160334, -0.51213306, -0.50821483, -0.50429666, -0.500396, -0.49649546, -0.49257746, -0.48865947, -0.48475865, -0.48085788, -0.4769401, -0.47302228, -0.46912128, -0.4652203, -0.4613027, -0.4573851, -0.4534839, -0.44958276, -0.44566533, -0.44174787, -0.43784654, -0.4339452, -0.43002796, -0.42611066, -0.42220917, -0.41830766, -0.41439056, -0.41047344, -0.40657178, -0.40267015, -0.3987532, -0.3948362, -0.3909344, -0.38703266, -0.3831158, -0.37919894, -0.37529704, -0.37139514, -0.36747843, -0.3635617, -0.35965967, -0.35575765, -0.35184103, -0.3479244, -0.34402227, -0.34012017, -0.33620366, -0.33228713, -0.3283849, -0.32448268, -0.32056627, -0.31664985, -0.31274754, -0.30884522, -0.3049289, -0.30101258, -0.29711017, -0.29320773, -0.28929153, -0.2853753, -0.28147277, -0.27757028, -0.27365413, -0.269738, -0.2658354, -0.26193282, -0.25801677, -0.25410068, -0.25019804, -0.24629539, -0.24237938, -0.23846337, -0.23456065, -0.23065795, -0.226742, -0.22282605, -0.21892327, -0.21502051, -0.21110462, -0.20718873, -0.2032859, -0.19938308, -0.19546725, -0.1915514, -0.18764852, -0.18374565, -0.17982987, -0.17591406, -0.17201115, -0.16810822, -0.16419248, -0.16027674, -0.15637377, -0.15247081, -0.1485551, -0.1446394, -0.14073639, -0.1368334, -0.13291772, -0.12900205, -0.12509902, -0.12119598, -0.11728034, -0.113364704, -0.109461635, -0.105558574, -0.101642966, -0.09772735, -0.09382426, -0.08992118, -0.08600558, -0.08209, -0.078186885, -0.07428378, -0.07036821, -0.06645264, -0.06254951, -0.058646385, -0.05473083, -0.05081527, -0.04691213, -0.043008994, -0.03909345, -0.0351779, -0.031274755, -0.027371606, -0.023456069, -0.019540532, -0.015637377, -0.011734223, -0.00781869, -0.0039031557, ], [0.0, 0.0038918403, 0.0077939355, 0.011710534, 0.015637388, 0.019564241, 0.023480836, 0.027382918, 0.031274736, 0.035166558, 0.039068647, 0.042985268, 0.046912163, 0.05083906, 0.054755665, 0.05865772, 0.06254947, 0.06644123, 0.0703433, 0.07425996, 0.07818694, 0.08211392, 0.08603056, 0.08993256, 0.09382421, 0.09771586, 0.10161789, 0.105534606, 0.10946172, 0.11338882, 0.11730551, 0.12120745, 0.12509894, 0.12899044, 0.13289241, 0.1368092, 0.14073649, 0.14466378, 0.14858054, 0.15248238, 0.15637368, 0.16026498, 0.16416687, 0.16808376, 0.17201127, 0.17593877, 0.17985561, 0.18375736, 0.18764842, 0.19153947, 0.19544126, 0.19935827, 0.20328605, 0.20721382, 0.21113078, 0.2150324, 0.21892315, 0.2228139, 0.22671558, 0.23063272, 0.23456083, 0.23848893, 0.24240601, 0.24630748, 0.2501979, 0.25408828, 0.25798982, 0.26190713, 0.2658356, 0.2697641, 0.27368134, 0.27758262, 0.2814726, 0.2853626, 0.28926396, 0.29318145, 0.2971104, 0.30103934, 0.30495676, 0.30885783, 0.31274733, 0.31663683, 0.320538, 0.32445574, 0.3283852, 0.33231464, 0.33623227, 0.3401331, 0.34402204, 0.347911, 0.35181195, 0.3557299, 0.35966, 0.36359003, 0.3675079, 0.37140846, 0.37529677, 0.37918505, 0.38308576, 0.38700402, 0.3909348, 0.39486554, 0.39878368, 0.4026839, 0.40657148, 0.41045904, 0.41435942, 0.41827804, 0.4222096, 0.42614114, 0.43005958, 0.43395948, 0.43784618, 0.44173288, 0.44563293, 0.44955194, 0.45348442, 0.45741686, 0.4613357, 0.46523514, 0.46912086, 0.4730066, 0.47690627, 0.48082572, 0.48475924, 0.48869273, 0.49261197, 0.49651095, 0.50039554, 0.50428015, 0.50817937, 0.5120993, 0.51603407, 0.5199688, 0.5238885, 0.52778697, 0.5316702, 0.5355535, 0.5394522, 0.54337275, 0.5473089, 0.55124503, 0.55516535, 0.55906314, 0.5629449, 0.56682664, 0.5707247, 0.574646, 0.5785838, 0.58252156, 0.58644253, 0.59033954, 0.5942195, 0.59809947, 0.60199684, 0.60591894, 0.6098587, 0.61379844, 0.6177201, 0.62161624, 0.62549406, 0.62937194, 0.6332685, 0.6371916, 0.64113367, 0.6450757, 0.6489983, 0.65289336, 0.6567686, 0.66064394, 0.66453946, 0.66846377, 0.67240864, 0.67635345, 0.6802772, 0.6841709, 0.6880431, 0.69191533, 0.69580966, 0.69973546, 0.70368373, 0.70763195, 0.71155703, 0.7154491, 0.7193175, 0.72318596, 0.72707874, 0.7310064, 0.7349589, 0.7389114, 0.74283814, 0.7467281, 0.7505917, 0.7544554, 0.7583463, 0.7622763, 0.7662343, 0.7701922, 0.77412105, 0.77800834, 0.7818657, 0.7857232, 0.78961176, 0.7935448, 0.79750997, 0.801475, 0.80540663, 0.80929023, 0.8131393, 0.8169885, 0.82087386, 0.8248113, 0.82878625, 0.832761, 0.8366963, 0.8405748, 0.84441215, 0.8482497, 0.85213065, 0.8560744, 0.86006355, 0.8640524, 0.86799306, 0.8718636, 0.87568337, 0.8795035, 0.883378, 0.8873318, 0.8913433, 0.8953544, 0.89930296, 0.9031602, 0.9069507, 0.91074175, 0.91460615, 0.9185783, 0.92262983, 0.9266805, 0.9306424, 0.93447274, 0.93820524, 0.94193894, 0.9457853, 0.94980085, 0.95394385, 0.9580847, 0.9620715, 0.9658214, 0.9693879, 0.97295934, 0.9767749, 0.9809861, 0.9855248, 0.9900434, 0.99396926, 0.9966627, 0.99762344, 0.9966627, 0.99396926, 0.9900434, 0.9855248, 0.9809861, 0.9767749, 0.97295934, 0.9693879, 0.9658214, 0.9620715, 0.9580847, 0.95394385, 0.94980085, 0.9457853, 0.94193894, 0.93820524, 0.93447274, 0.9306424, 0.9266805, 0.92262983, 0.9185783, 0.91460615, 0.91074175, 0.9069507, 0.9031602, 0.89930296, 0.8953544, 0.8913433, 0.8873318, 0.883378, 0.8795035, 0.87568337, 0.8718636, 0.86799306, 0.8640524, 0.86006355, 0.8560744, 0.85213065, 0.8482497, 0.84441215, 0.8405748, 0.8366963, 0.832761, 0.82878625, 0.8248113, 0.82087386, 0.8169885, 0.8131393, 0.80929023, 0.80540663, 0.801475, 0.79750997, 0.7935448, 0.78961176, 0.7857232, 0.7818657, 0.77800834, 0.77412105, 0.7701922, 0.7662343, 0.7622763, 0.7583463, 0.7544554, 0.7505917, 0.7467281, 0.74283814, 0.7389114, 0.7349589, 0.7310064, 0.72707874, 0.72318596, 0.7193175, 0.7154491, 0.71155703, 0.70763195, 0.70368373, 0.69973546, 0.69580966, 0.69191533, 0.6880431, 0.6841709, 0.6802772, 0.67635345, 0.67240864, 0.66846377, 0.66453946, 0.66064394, 0.6567686, 0.65289336, 0.6489983, 0.6450757, 0.64113367, 0.6371916, 0.6332685, 0.62937194, 0.62549406, 0.62161624, 0.6177201, 0.61379844, 0.6098587, 0.60591894, 0.60199684, 0.59809947, 0.5942195, 0.59033954, 0.58644253, 0.58252156, 0.5785838, 0.574646, 0.5707247, 0.56682664, 0.5629449, 0.55906314, 0.55516535, 0.55124503, 0.5473089, 0.54337275, 0.5394522, 0.5355535, 0.5316702, 0.52778697, 0.5238885, 0.5199688, 0.51603407, 0.5120993, 0.50817937, 0.50428015, 0.50039554, 0.49651095, 0.49261197, 0.48869273, 0.48475924, 0.48082572, 0.47690627, 0.4730066, 0.46912086, 0.46523514, 0.4613357, 0.45741686, 0.45348442, 0.44955194, 0.44563293, 0.44173288, 0.43784618, 0.43395948, 0.43005958, 0.42614114, 0.4222096, 0.41827804, 0.41435942, 0.41045904, 0.40657148, 0.4026839, 0.39878368, 0.39486554, 0.3909348, 0.38700402, 0.38308576, 0.37918505, 0.37529677, 0.37140846, 0.3675079, 0.36359003, 0.35966, 0.3557299, 0.35181195, 0.347911, 0.34402204, 0.3401331, 0.33623227, 0.33231464, 0.3283852, 0.32445574, 0.320538, 0.31663683, 0.31274733, 0.30885783, 0.30495676, 0.30103934, 0.2971104, 0.29318145, 0.28926396, 0.2853626, 0.2814726, 0.27758262, 0.27368134, 0.2697641, 0.2658356, 0.26190713, 0.25798982, 0.25408828, 0.2501979, 0.24630748, 0.24240601, 0.23848893, 0.23456083, 0.23063272, 0.22671558, 0.2228139, 0.21892315, 0.2150324, 0.21113078, 0.20721382, 0.20328605, 0.19935827, 0.19544126, 0.19153947, 0.18764842, 0.18375736, 0.17985561, 0.17593877, 0.17201127, 0.16808376, 0.16416687, 0.16026498, 0.15637368, 0.15248238, 0.14858054, 0.14466378, 0.14073649, 0.1368092, 0.13289241, 0.12899044, 0.12509894, 0.12120745, 0.11730551, 0.11338882, 0.10946172, 0.105534606, 0.10161789, 0.09771586, 0.09382421, 0.08993256, 0.08603056, 0.08211392, 0.07818694, 0.07425996, 0.0703433, 0.06644123, 0.06254947, 0.05865772, 0.054755665, 0.05083906, 0.046912163, 0.042985268, 0.039068647, 0.035166558, 0.031274736, 0.027382918, 0.023480836, 0.019564241, 0.015637388, 0.011710534, 0.0077939355, 0.0038918403, -4.939228e-18, -0.0038918403, -0.0077939355, -0.011710534, -0.015637388, -0.019564241, -0.023480836, -0.027382918, -0.031274736, -0.035166558, -0.039068647, -0.042985268, -0.046912163, -0.05083906, -0.054755665, -0.05865772, -0.06254947, -0.06644123, -0.0703433, -0.07425996, -0.07818694, -0.08211392, -0.08603056, -0.08993256, -0.09382421, -0.09771586, -0.10161789, -0.105534606, -0.10946172, -0.11338882, -0.11730551, -0.12120745, -0.12509894, -0.12899044, -0.13289241, -0.1368092, -0.14073649, -0.14466378, -0.14858054, -0.15248238, -0.15637368, -0.16026498, -0.16416687, -0.16808376, -0.17201127, -0.17593877, -0.17985561, -0.18375736, -0.18764842, -0.19153947, -0.19544126, -0.19935827, -0.20328605, -0.20721382, -0.21113078, -0.2150324, -0.21892315, -0.2228139, -0.22671558, -0.23063272, -0.23456083, -0.23848893, -0.24240601, -0.24630748, -0.2501979, -0.25408828, -0.25798982, -0.26190713, -0.2658356, -0.2697641, -0.27368134, -0.27758262, -0.2814726, -0.2853626, -0.28926396, -0.29318145, -0.2971104, -0.30103934, -0.30495676, -0.30885783, -0.31274733, -0.31663683, -0.320538, -0.32445574, -0.3283852, -0.33231464, -0.33623227, -0.3401331, -0.34402204, -0.347911, -0.35181195, -0.3557299, -0.35966, -0.36359003, -0.3675079, -0.37140846, -0.37529677, -0.37918505, -0.38308576, -0.38700402, -0.3909348, -0.39486554, -0.39878368, -0.4026839, -0.40657148, -0.41045904, -0.41435942, -0.41827804, -0.4222096, -0.42614114, -0.43005958, -0.43395948, -0.43784618, -0.44173288, -0.44563293, -0.44955194, -0.45348442, -0.45741686, -0.4613357, -0.46523514, -0.46912086, -0.4730066, -0.47690627, -0.48082572, -0.48475924, -0.48869273, -0.49261197, -0.49651095, -0.50039554, -0.50428015, -0.50817937, -0.5120993, -0.51603407, -0.5199688, -0.5238885, -0.52778697, -0.5316702, -0.5355535, -0.5394522, -0.54337275, -0.5473089, -0.55124503, -0.55516535, -0.55906314, -0.5629449, -0.56682664, -0.5707247, -0.574646, -0.5785838, -0.58252156, -0.58644253, -0.59033954, -0.5942195, -0.59809947, -0.60199684, -0.60591894, -0.6098587, -0.61379844, -0.6177201, -0.62161624, -0.62549406, -0.62937194, -0.6332685, -0.6371916, -0.64113367, -0.6450757, -0.6489983, -0.65289336, -0.6567686, -0.66064394, -0.66453946, -0.66846377, -0.67240864, -0.67635345, -0.6802772, -0.6841709, -0.6880431, -0.69191533, -0.69580966, -0.69973546, -0.70368373, -0.70763195, -0.71155703, -0.7154491, -0.7193175, -0.72318596, -0.72707874, -0.7310064, -0.7349589, -0.7389114, -0.74283814, -0.7467281, -0.7505917, -0.7544554, -0.7583463, -0.7622763, -0.7662343, -0.7701922, -0.77412105, -0.77800834, -0.7818657, -0.7857232, -0.78961176, -0.7935448, -0.79750997, -0.801475, -0.80540663, -0.80929023, -0.8131393, -0.8169885, -0.82087386, -0.8248113, -0.82878625, -0.832761, -0.8366963, -0.8405748, -0.84441215, -0.8482497, -0.85213065, -0.8560744, -0.86006355, -0.8640524, -0.86799306, -0.8718636, -0.87568337, -0.8795035, -0.883378, -0.8873318, -0.8913433, -0.8953544, -0.89930296, -0.9031602, -0.9069507, -0.91074175, -0.91460615, -0.9185783, -0.92262983, -0.9266805, -0.9306424, -0.93447274, -0.93820524, -0.94193894, -0.9457853, -0.94980085, -0.95394385, -0.9580847, -0.9620715, -0.9658214, -0.9693879, -0.97295934, -0.9767749, -0.9809861, -0.9855248, -0.9900434, -0.99396926, -0.9966627, -0.99762344, -0.9966627, -0.99396926, -0.9900434, -0.9855248, -0.9809861, -0.9767749, -0.97295934, -0.9693879, -0.9658214, -0.9620715, -0.9580847, -0.95394385, -0.94980085, -0.9457853, -0.94193894, -0.93820524, -0.93447274, -0.9306424, -0.9266805, -0.92262983, -0.9185783, -0.91460615, -0.91074175, -0.9069507, -0.9031602, -0.89930296, -0.8953544, -0.8913433, -0.8873318, -0.883378, -0.8795035, -0.87568337, -0.8718636, -0.86799306, -0.8640524, -0.86006355, -0.8560744, -0.85213065, -0.8482497, -0.84441215, -0.8405748, -0.8366963, -0.832761, -0.82878625, -0.8248113, -0.82087386, -0.8169885, -0.8131393, -0.80929023, -0.80540663, -0.801475, -0.79750997, -0.7935448, -0.78961176, -0.7857232, -0.7818657, -0.77800834, -0.77412105, -0.7701922, -0.7662343, -0.7622763, -0.7583463, -0.7544554, -0.7505917, -0.7467281, -0.74283814, -0.7389114, -0.7349589, -0.7310064, -0.72707874, -0.72318596, -0.7193175, -0.7154491, -0.71155703, -0.70763195, -0.70368373, -0.69973546, -0.69580966, -0.69191533, -0.6880431, -0.6841709, -0.6802772, -0.67635345, -0.67240864, -0.66846377, -0.66453946, -0.66064394, -0.6567686, -0.65289336, -0.6489983, -0.6450757, -0.64113367, -0.6371916, -0.6332685, -0.62937194, -0.62549406, -0.62161624, -0.6177201, -0.61379844, -0.6098587, -0.60591894, -0.60199684, -0.59809947, -0.5942195, -0.59033954, -0.58644253, -0.58252156, -0.5785838, -0.574646, -0.5707247, -0.56682664, -0.5629449, -0.55906314, -0.55516535, -0.55124503, -0.5473089, -0.54337275, -0.5394522, -0.5355535, -0.5316702, -0.52778697, -0.5238885, -0.5199688, -0.51603407, -0.5120993, -0.50817937, -0.50428015, -0.50039554, -0.49651095, -0.49261197, -0.48869273, -0.48475924, -0.48082572, -0.47690627, -0.4730066, -0.46912086, -0.46523514, -0.4613357, -0.45741686, -0.45348442, -0.44955194, -0.44563293, -0.44173288, -0.43784618, -0.43395948, -0.43005958, -0.42614114, -0.4222096, -0.41827804, -0.41435942, -0.41045904, -0.40657148, -0.4026839, -0.39878368, -0.39486554, -0.3909348, -0.38700402, -0.38308576, -0.37918505, -0.37529677, -0.37140846, -0.3675079, -0.36359003, -0.35966, -0.3557299, -0.35181195, -0.347911, -0.34402204, -0.3401331, -0.33623227, -0.33231464, -0.3283852, -0.32445574, -0.320538, -0.31663683, -0.31274733, -0.30885783, -0.30495676, -0.30103934, -0.2971104, -0.29318145, -0.28926396, -0.2853626, -0.2814726, -0.27758262, -0.27368134, -0.2697641, -0.2658356, -0.26190713, -0.25798982, -0.25408828, -0.2501979, -0.24630748, -0.24240601, -0.23848893, -0.23456083, -0.23063272, -0.22671558, -0.2228139, -0.21892315, -0.2150324, -0.21113078, -0.20721382, -0.20328605, -0.19935827, -0.19544126, -0.19153947, -0.18764842, -0.18375736, -0.17985561, -0.17593877, -0.17201127, -0.16808376, -0.16416687, -0.16026498, -0.15637368, -0.15248238, -0.14858054, -0.14466378, -0.14073649, -0.1368092, -0.13289241, -0.12899044, -0.12509894, -0.12120745, -0.11730551, -0.11338882, -0.10946172, -0.105534606, -0.10161789, -0.09771586, -0.09382421, -0.08993256, -0.08603056, -0.08211392, -0.07818694, -0.07425996, -0.0703433, -0.06644123, -0.06254947, -0.05865772, -0.054755665, -0.05083906, -0.046912163, -0.042985268, -0.039068647, -0.035166558, -0.031274736, -0.027382918, -0.023480836, -0.019564241, -0.015637388, -0.011710534, -0.0077939355, -0.0038918403, ], [0.0, 0.0038714588, 0.007748687, 0.011636577, 0.015538396, 0.019455295, 0.02338613, 0.027327633, 0.031274907, 0.035222184, 0.039163683, 0.043094497, 0.047011353, 0.050913095, 0.054800864, 0.05867793, 0.0625492, 0.06642048, 0.07029756, 0.074185364, 0.07808719, 0.082004204, 0.085935265, 0.089877084, 0.09382473, 0.097772375, 0.10171418, 0.10564519, 0.10956208, 0.113463655, 0.11735109, 0.12122768, 0.12509839, 0.12896912, 0.13284573, 0.13673323, 0.140635, 0.1445522, 0.14848371, 0.15242618, 0.15637457, 0.16032298, 0.16426542, 0.16819683, 0.17211382, 0.17601517, 0.17990203, 0.18377781, 0.18764757, 0.19151731, 0.19539312, 0.1992801, 0.20318171, 0.20709921, 0.21103142, 0.21497488, 0.21892446, 0.22287405, 0.22681747, 0.23074955, 0.2346667, 0.23856771, 0.24245375, 0.24632832, 0.25019667, 0.254065, 0.2579396, 0.26182583, 0.26572722, 0.26964512, 0.2735783, 0.27752316, 0.2814744, 0.28542566, 0.2893705, 0.2933035, 0.29722092, 0.30112147, 0.30500638, 0.30887932, 0.3127457, 0.3166121, 0.32048506, 0.32437018, 0.32827127, 0.33218968, 0.33612415, 0.34007096, 0.34402448, 0.34797797, 0.35192472, 0.35585895, 0.35977677, 0.3636767, 0.36756012, 0.3714308, 0.3752946, 0.37915838, 0.38302916, 0.38691285, 0.3908135, 0.3947326, 0.39866883, 0.40261817, 0.40657467, 0.41053116, 0.41448045, 0.41841632, 0.4223346, 0.4262338, 0.4301152, 0.43398297, 0.43784332, 0.44170368, 0.44557154, 0.44945332, 0.45335343, 0.45727342, 0.46121192, 0.46516466, 0.46912512, 0.47308555, 0.47703817, 0.48097622, 0.48489517, 0.48879328, 0.49267206, 0.49653596, 0.5003917, 0.50424755, 0.50811154, 0.51199085, 0.5158902, 0.51981145, 0.523753, 0.5277102, 0.53167593, 0.5356416, 0.5395987, 0.54353964, 0.5474594, 0.55135614, 0.5552314, 0.55909, 0.5629397, 0.5667894, 0.57064825, 0.57452416, 0.57842255, 0.5823454, 0.58629113, 0.5902544, 0.5942273, 0.5982002, 0.6021633, 0.6061082, 0.6100291, 0.61392385, 0.61779416, 0.6216456, 0.62548685, 0.6293281, 0.63317984, 0.6370511, 0.64094824, 0.6448735, 0.648825, 0.65279686, 0.6567798, 0.6607627, 0.6647343, 0.6686847, 0.6726071, 0.67649895, 0.6803622, 0.6842034, 0.6880325, 0.6918616, 0.69570315, 0.69956785, 0.7034632, 0.70739204, 0.7113522, 0.7153367, 0.7193345, 0.7233322, 0.72731626, 0.73127466, 0.73519915, 0.7390865, 0.74293894, 0.74676436, 0.750575, 0.75438565, 0.7582116, 0.76206636, 0.7659592, 0.76989394, 0.77386796, 0.77787256, 0.78189397, 0.7859153, 0.7899192, 0.79389024, 0.7978177, 0.8016971, 0.80553126, 0.80933017, 0.81310964, 0.81688917, 0.8206891, 0.8245273, 0.8284167, 0.8323626, 0.8363618, 0.8404029, 0.84446704, 0.84853107, 0.85257065, 0.856564, 0.86049545, 0.86435807, 0.86815536, 0.8719013, 0.87561864, 0.8793362, 0.88308436, 0.88689095, 0.8907763, 0.8947498, 0.89880776, 0.9029327, 0.9070951, 0.91125727, 0.9153781, 0.91941947, 0.92335224, 0.9271618, 0.9308518, 0.9344453, 0.937984, 0.9415232, 0.94512594, 0.9488536, 0.95275664, 0.9568648, 0.9611792, 0.96566665, 0.970257, 0.97484475, 0.97929466, 0.9834504, 0.98714656, 0.990222, 0.9925335, 0.99396855, 0.9944551, 0.99396855, 0.9925335, 0.990222, 0.98714656, 0.9834504, 0.97929466, 0.97484475, 0.970257, 0.96566665, 0.9611792, 0.9568648, 0.95275664, 0.9488536, 0.94512594, 0.9415232, 0.937984, 0.9344453, 0.9308518, 0.9271618, 0.92335224, 0.91941947, 0.9153781, 0.91125727, 0.9070951, 0.9029327, 0.89880776, 0.8947498, 0.8907763, 0.88689095, 0.88308436, 0.8793362, 0.87561864, 0.8719013, 0.86815536, 0.86435807, 0.86049545, 0.856564, 0.85257065, 0.84853107, 0.84446704, 0.8404029, 0.8363618, 0.8323626, 0.8284167, 0.8245273, 0.8206891, 0.81688917, 0.81310964, 0.80933017, 0.80553126, 0.8016971, 0.7978177, 0.79389024, 0.7899192, 0.7859153, 0.78189397, 0.77787256, 0.77386796, 0.76989394, 0.7659592, 0.76206636, 0.7582116, 0.75438565, 0.750575, 0.74676436, 0.74293894, 0.7390865, 0.73519915, 0.73127466, 0.72731626, 0.7233322, 0.7193345, 0.7153367, 0.7113522, 0.70739204, 0.7034632, 0.69956785, 0.69570315, 0.6918616, 0.6880325, 0.6842034, 0.6803622, 0.67649895, 0.6726071, 0.6686847, 0.6647343, 0.6607627, 0.6567798, 0.65279686, 0.648825, 0.6448735, 0.64094824, 0.6370511, 0.63317984, 0.6293281, 0.62548685, 0.6216456, 0.61779416, 0.61392385, 0.6100291, 0.6061082, 0.6021633, 0.5982002, 0.5942273, 0.5902544, 0.58629113, 0.5823454, 0.57842255, 0.57452416, 0.57064825, 0.5667894, 0.5629397, 0.55909, 0.5552314, 0.55135614, 0.5474594, 0.54353964, 0.5395987, 0.5356416, 0.53167593, 0.5277102, 0.523753, 0.51981145, 0.5158902, 0.51199085, 0.50811154, 0.50424755, 0.5003917, 0.49653596, 0.49267206, 0.48879328, 0.48489517, 0.48097622, 0.47703817, 0.47308555, 0.46912512, 0.46516466, 0.46121192, 0.45727342, 0.45335343, 0.44945332, 0.44557154, 0.44170368, 0.43784332, 0.43398297, 0.4301152, 0.4262338, 0.4223346, 0.41841632, 0.41448045, 0.41053116, 0.40657467, 0.40261817, 0.39866883, 0.3947326, 0.3908135, 0.38691285, 0.38302916, 0.37915838, 0.3752946, 0.3714308, 0.36756012, 0.3636767, 0.35977677, 0.35585895, 0.35192472, 0.34797797, 0.34402448, 0.34007096, 0.33612415, 0.33218968, 0.32827127, 0.32437018, 0.32048506, 0.3166121, 0.3127457, 0.30887932, 0.30500638, 0.30112147, 0.29722092, 0.2933035, 0.2893705, 0.28542566, 0.2814744, 0.27752316, 0.2735783, 0.26964512, 0.26572722, 0.26182583, 0.2579396, 0.254065, 0.25019667, 0.24632832, 0.24245375, 0.23856771, 0.2346667, 0.23074955, 0.22681747, 0.22287405, 0.21892446, 0.21497488, 0.21103142, 0.20709921, 0.20318171, 0.1992801, 0.19539312, 0.19151731, 0.18764757, 0.18377781, 0.17990203, 0.17601517, 0.17211382, 0.16819683, 0.16426542, 0.16032298, 0.15637457, 0.15242618, 0.14848371, 0.1445522, 0.140635, 0.13673323, 0.13284573, 0.12896912, 0.12509839, 0.12122768, 0.11735109, 0.113463655, 0.10956208, 0.10564519, 0.10171418, 0.097772375, 0.09382473, 0.089877084, 0.085935265, 0.082004204, 0.07808719, 0.074185364, 0.07029756, 0.06642048, 0.0625492, 0.05867793, 0.054800864, 0.050913095, 0.047011353, 0.043094497, 0.039163683, 0.035222184, 0.031274907, 0.027327633, 0.02338613, 0.019455295, 0.015538396, 0.011636577, 0.007748687, 0.0038714588, -1.6721828e-17, -0.0038714588, -0.007748687, -0.011636577, -0.015538396, -0.019455295, -0.02338613, -0.027327633, -0.031274907, -0.035222184, -0.039163683, -0.043094497, -0.047011353, -0.050913095, -0.054800864, -0.05867793, -0.0625492, -0.06642048, -0.07029756, -0.074185364, -0.07808719, -0.082004204, -0.085935265, -0.089877084, -0.09382473, -0.097772375, -0.10171418, -0.10564519, -0.10956208, -0.113463655, -0.11735109, -0.12122768, -0.12509839, -0.12896912, -0.13284573, -0.13673323, -0.140635, -0.1445522, -0.14848371, -0.15242618, -0.15637457, -0.16032298, -0.16426542, -0.16819683, -0.17211382, -0.17601517, -0.17990203, -0.18377781, -0.18764757, -0.19151731, -0.19539312, -0.1992801, -0.20318171, -0.20709921, -0.21103142, -0.21497488, -0.21892446, -0.22287405, -0.22681747, -0.23074955, -0.2346667, -0.23856771, -0.24245375, -0.24632832, -0.25019667, -0.254065, -0.2579396, -0.26182583, -0.26572722, -0.26964512, -0.2735783, -0.27752316, -0.2814744, -0.28542566, -0.2893705, -0.2933035, -0.29722092, -0.30112147, -0.30500638, -0.30887932, -0.3127457, -0.3166121, -0.32048506, -0.32437018, -0.32827127, -0.33218968, -0.33612415, -0.34007096, -0.34402448, -0.34797797, -0.35192472, -0.35585895, -0.35977677, -0.3636767, -0.36756012, -0.3714308, -0.3752946, -0.37915838, -0.38302916, -0.38691285, -0.3908135, -0.3947326, -0.39866883, -0.40261817, -0.40657467, -0.41053116, -0.41448045, -0.41841632, -0.4223346, -0.4262338, -0.4301152, -0.43398297, -0.43784332, -0.44170368, -0.44557154, -0.44945332, -0.45335343, -0.45727342, -0.46121192, -0.46516466, -0.46912512, -0.47308555, -0.47703817, -0.48097622, -0.48489517, -0.48879328, -0.49267206, -0.49653596, -0.5003917, -0.50424755, -0.50811154, -0.51199085, -0.5158902, -0.51981145, -0.523753, -0.5277102, -0.53167593, -0.5356416, -0.5395987, -0.54353964, -0.5474594, -0.55135614, -0.5552314, -0.55909, -0.5629397, -0.5667894, -0.57064825, -0.57452416, -0.57842255, -0.5823454, -0.58629113, -0.5902544, -0.5942273, -0.5982002, -0.6021633, -0.6061082, -0.6100291, -0.61392385, -0.61779416, -0.6216456, -0.62548685, -0.6293281, -0.63317984, -0.6370511, -0.64094824, -0.6448735, -0.648825, -0.65279686, -0.6567798, -0.6607627, -0.6647343, -0.6686847, -0.6726071, -0.67649895, -0.6803622, -0.6842034, -0.6880325, -0.6918616, -0.69570315, -0.69956785, -0.7034632, -0.70739204, -0.7113522, -0.7153367, -0.7193345, -0.7233322, -0.72731626, -0.73127466, -0.73519915, -0.7390865, -0.74293894, -0.74676436, -0.750575, -0.75438565, -0.7582116, -0.76206636, -0.7659592, -0.76989394, -0.77386796, -0.77787256, -0.78189397, -0.7859153, -0.7899192, -0.79389024, -0.7978177, -0.8016971, -0.80553126, -0.80933017, -0.81310964, -0.81688917, -0.8206891, -0.8245273, -0.8284167, -0.8323626, -0.8363618, -0.8404029, -0.84446704, -0.84853107, -0.85257065, -0.856564, -0.86049545, -0.86435807, -0.86815536, -0.8719013, -0.87561864, -0.8793362, -0.88308436, -0.88689095, -0.8907763, -0.8947498, -0.89880776, -0.9029327, -0.9070951, -0.91125727, -0.9153781, -0.91941947, -0.92335224, -0.9271618, -0.9308518, -0.9344453, -0.937984, -0.9415232, -0.94512594, -0.9488536, -0.95275664, -0.9568648, -0.9611792, -0.96566665, -0.970257, -0.97484475, -0.97929466, -0.9834504, -0.98714656, -0.990222, -0.9925335, -0.99396855, -0.9944551, -0.99396855, -0.9925335, -0.990222, -0.98714656, -0.9834504, -0.97929466, -0.97484475, -0.970257, -0.96566665, -0.9611792, -0.9568648, -0.95275664, -0.9488536, -0.94512594, -0.9415232, -0.937984, -0.9344453, -0.9308518, -0.9271618, -0.92335224, -0.91941947, -0.9153781, -0.91125727, -0.9070951, -0.9029327, -0.89880776, -0.8947498, -0.8907763, -0.88689095, -0.88308436, -0.8793362, -0.87561864, -0.8719013, -0.86815536, -0.86435807, -0.86049545, -0.856564, -0.85257065, -0.84853107, -0.84446704, -0.8404029, -0.8363618, -0.8323626, -0.8284167, -0.8245273, -0.8206891, -0.81688917, -0.81310964, -0.80933017, -0.80553126, -0.8016971, -0.7978177, -0.79389024, -0.7899192, -0.7859153, -0.78189397, -0.77787256, -0.77386796, -0.76989394, -0.7659592, -0.76206636, -0.7582116, -0.75438565, -0.750575, -0.74676436, -0.74293894, -0.7390865, -0.73519915, -0.73127466, -0.72731626, -0.7233322, -0.7193345, -0.7153367, -0.7113522, -0.70739204, -0.7034632, -0.69956785, -0.69570315, -0.6918616, -0.6880325, -0.6842034, -0.6803622, -0.67649895, -0.6726071, -0.6686847, -0.6647343, -0.6607627, -0.6567798, -0.65279686, -0.648825, -0.6448735, -0.64094824, -0.6370511, -0.63317984, -0.6293281, -0.62548685, -0.6216456, -0.61779416, -0.61392385, -0.6100291, -0.6061082, -0.6021633, -0.5982002, -0.5942273, -0.5902544, -0.58629113, -0.5823454, -0.57842255, -0.57452416, -0.57064825, -0.5667894, -0.5629397, -0.55909, -0.5552314, -0.55135614, -0.5474594, -0.54353964, -0.5395987, -0.5356416, -0.53167593, -0.5277102, -0.523753, -0.51981145, -0.5158902, -0.51199085, -0.50811154, -0.50424755, -0.5003917, -0.49653596, -0.49267206, -0.48879328, -0.48489517, -0.48097622, -0.47703817, -0.47308555, -0.46912512, -0.46516466, -0.46121192, -0.45727342, -0.45335343, -0.44945332, -0.44557154, -0.44170368, -0.43784332, -0.43398297, -0.4301152, -0.4262338, -0.4223346, -0.41841632, -0.41448045, -0.41053116, -0.40657467, -0.40261817, -0.39866883, -0.3947326, -0.3908135, -0.38691285, -0.38302916, -0.37915838, -0.3752946, -0.3714308, -0.36756012, -0.3636767, -0.35977677, -0.35585895, -0.35192472, -0.34797797, -0.34402448, -0.34007096, -0.33612415, -0.33218968, -0.32827127, -0.32437018, -0.32048506, -0.3166121, -0.3127457, -0.30887932, -0.30500638, -0.30112147, -0.29722092, -0.2933035, -0.2893705, -0.28542566, -0.2814744, -0.27752316, -0.2735783, -0.26964512, -0.26572722, -0.26182583, -0.2579396, -0.254065, -0.25019667, -0.24632832, -0.24245375, -0.23856771, -0.2346667, -0.23074955, -0.22681747, -0.22287405, -0.21892446, -0.21497488, -0.21103142, -0.20709921, -0.20318171, -0.1992801, -0.19539312, -0.19151731, -0.18764757, -0.18377781, -0.17990203, -0.17601517, -0.17211382, -0.16819683, -0.16426542, -0.16032298, -0.15637457, -0.15242618, -0.14848371, -0.1445522, -0.140635, -0.13673323, -0.13284573, -0.12896912, -0.12509839, -0.12122768, -0.11735109, -0.113463655, -0.10956208, -0.10564519, -0.10171418, -0.097772375, -0.09382473, -0.089877084, -0.085935265, -0.082004204, -0.07808719, -0.074185364, -0.07029756, -0.06642048, -0.0625492, -0.05867793, -0.054800864, -0.050913095, -0.047011353, -0.043094497, -0.039163683, -0.035222184, -0.031274907, -0.027327633, -0.02338613, -0.019455295, -0.015538396, -0.011636577, -0.007748687, -0.0038714588, ], [0.0, 0.003832145, 0.00766726, 0.0115082, 0.015357599, 0.019217765, 0.023090592, 0.026977492, 0.030879337, 0.03479642, 0.038728457, 0.042674586, 0.046633396, 0.050603, 0.054581087, 0.05856502, 0.06255194, 0.066538855, 0.070522785, 0.07450085, 0.07847041, 0.08242912, 0.08637506, 0.09030681, 0.094223484, 0.09812477, 0.10201095, 0.10588288, 0.109741986, 0.113590166, 0.117429756, 0.121263415, 0.12509406, 0.1289247, 0.13275836, 0.13659799, 0.14044628, 0.14430559, 0.14817789, 0.15206465, 0.15596677, 0.1598846, 0.16381782, 0.1677656, 0.1717265, 0.17569855, 0.1796794, 0.1836663, 0.1876563, 0.19164631, 0.1956332, 0.19961397, 0.20358588, 0.20754643, 0.21149363, 0.21542595, 0.21934246, 0.22324279, 0.22712724, 0.23099668, 0.23485258, 0.23869696, 0.24253225, 0.24636127, 0.25018707, 0.25401288, 0.2578419, 0.2616773, 0.2655219, 0.26937827, 0.27324855, 0.27713427, 0.28103647, 0.2849555, 0.2888911, 0.29284236, 0.29680774, 0.30078515, 0.30477205, 0.30876553, 0.31276238, 0.3167592, 0.32075265, 0.32473946, 0.32871658, 0.3326813, 0.33663145, 0.34056532, 0.34448183, 0.3483806, 0.3522619, 0.3561267, 0.35997656, 0.3638137, 0.36764076, 0.37146088, 0.37527746, 0.37909403, 0.38291422, 0.38674146, 0.390579, 0.39442968, 0.39829594, 0.40217954, 0.40608165, 0.4100027, 0.41394246, 0.41789985, 0.42187324, 0.42586026, 0.42985806, 0.43386334, 0.4378724, 0.4418815, 0.44588673, 0.44988433, 0.4538708, 0.4578431, 0.46179855, 0.46573523, 0.46965188, 0.473548, 0.47742385, 0.4812805, 0.4851198, 0.4889442, 0.49275687, 0.49656144, 0.5003618, 0.5041622, 0.5079668, 0.5117797, 0.5156049, 0.51944566, 0.5233049, 0.5271848, 0.5310868, 0.5350114, 0.53895843, 0.5429267, 0.5469142, 0.55091816, 0.5549352, 0.5589613, 0.5629919, 0.5670226, 0.5710486, 0.57506526, 0.57906824, 0.5830537, 0.5870185, 0.59095997, 0.59487665, 0.59876776, 0.6026336, 0.6064755, 0.61029553, 0.6140969, 0.61788344, 0.6216597, 0.62543064, 0.62920165, 0.632978, 0.6367651, 0.64056784, 0.6443907, 0.64823747, 0.65211105, 0.65601337, 0.6599453, 0.6639066, 0.66789573, 0.67191035, 0.6759468, 0.68000054, 0.68406636, 0.6881384, 0.69221044, 0.69627607, 0.70032907, 0.70436347, 0.7083739, 0.71235585, 0.71630573, 0.7202211, 0.7241009, 0.7279453, 0.731756, 0.7355358, 0.7392891, 0.74302125, 0.7467386, 0.7504484, 0.75415814, 0.75787586, 0.7616092, 0.7653657, 0.7691522, 0.7729744, 0.776837, 0.7807431, 0.78469443, 0.78869087, 0.7927305, 0.7968098, 0.80092335, 0.8050643, 0.80922455, 0.8133948, 0.817565, 0.8217247, 0.82586354, 0.82997125, 0.83403856, 0.83805734, 0.8420209, 0.8459245, 0.8497654, 0.8535434, 0.85726047, 0.8609214, 0.8645332, 0.86810535, 0.87164944, 0.8751789, 0.87870836, 0.88225365, 0.8858308, 0.8894559, 0.893144, 0.89690894, 0.90076244, 0.90471375, 0.9087688, 0.91293, 0.9171957, 0.92155975, 0.9260117, 0.93053615, 0.93511325, 0.93971866, 0.9443237, 0.94889605, 0.9534001, 0.95779747, 0.962048, 0.9661102, 0.96994245, 0.9735034, 0.9767533, 0.97965467, 0.9821731, 0.9842779, 0.98594314, 0.987148, 0.9878771, 0.98812115, 0.9878771, 0.987148, 0.98594314, 0.9842779, 0.9821731, 0.97965467, 0.9767533, 0.9735034, 0.96994245, 0.9661102, 0.962048, 0.95779747, 0.9534001, 0.94889605, 0.9443237, 0.93971866, 0.93511325, 0.93053615, 0.9260117, 0.92155975, 0.9171957, 0.91293, 0.9087688, 0.90471375, 0.90076244, 0.89690894, 0.893144, 0.8894559, 0.8858308, 0.88225365, 0.87870836, 0.8751789, 0.87164944, 0.86810535, 0.8645332, 0.8609214, 0.85726047, 0.8535434, 0.8497654, 0.8459245, 0.8420209, 0.83805734, 0.83403856, 0.82997125, 0.82586354, 0.8217247, 0.817565, 0.8133948, 0.80922455, 0.8050643, 0.80092335, 0.7968098, 0.7927305, 0.78869087, 0.78469443, 0.7807431, 0.776837, 0.7729744, 0.7691522, 0.7653657, 0.7616092, 0.75787586, 0.75415814, 0.7504484, 0.7467386, 0.74302125, 0.7392891, 0.7355358, 0.731756, 0.7279453, 0.7241009, 0.7202211, 0.71630573, 0.71235585, 0.7083739, 0.70436347, 0.70032907, 0.69627607, 0.69221044, 0.6881384, 0.68406636, 0.68000054, 0.6759468, 0.67191035, 0.66789573, 0.6639066, 0.6599453, 0.65601337, 0.65211105, 0.64823747, 0.6443907, 0.64056784, 0.6367651, 0.632978, 0.62920165, 0.62543064, 0.6216597, 0.61788344, 0.6140969, 0.61029553, 0.6064755, 0.6026336, 0.59876776, 0.59487665, 0.59095997, 0.5870185, 0.5830537, 0.57906824, 0.57506526, 0.5710486, 0.5670226, 0.5629919, 0.5589613, 0.5549352, 0.55091816, 0.5469142, 0.5429267, 0.53895843, 0.5350114, 0.5310868, 0.5271848, 0.5233049, 0.51944566, 0.5156049, 0.5117797, 0.5079668, 0.5041622, 0.5003618, 0.49656144, 0.49275687, 0.4889442, 0.4851198, 0.4812805, 0.47742385, 0.473548, 0.46965188, 0.46573523, 0.46179855, 0.4578431, 0.4538708, 0.44988433, 0.44588673, 0.4418815, 0.4378724, 0.43386334, 0.42985806, 0.42586026, 0.42187324, 0.41789985, 0.41394246, 0.4100027, 0.40608165, 0.40217954, 0.39829594, 0.39442968, 0.390579, 0.38674146, 0.38291422, 0.37909403, 0.37527746, 0.37146088, 0.36764076, 0.3638137, 0.35997656, 0.3561267, 0.3522619, 0.3483806, 0.34448183, 0.34056532, 0.33663145, 0.3326813, 0.32871658, 0.32473946, 0.32075265, 0.3167592, 0.31276238, 0.30876553, 0.30477205, 0.30078515, 0.29680774, 0.29284236, 0.2888911, 0.2849555, 0.28103647, 0.27713427, 0.27324855, 0.26937827, 0.2655219, 0.2616773, 0.2578419, 0.25401288, 0.25018707, 0.24636127, 0.24253225, 0.23869696, 0.23485258, 0.23099668, 0.22712724, 0.22324279, 0.21934246, 0.21542595, 0.21149363, 0.20754643, 0.20358588, 0.19961397, 0.1956332, 0.19164631, 0.1876563, 0.1836663, 0.1796794, 0.17569855, 0.1717265, 0.1677656, 0.16381782, 0.1598846, 0.15596677, 0.15206465, 0.14817789, 0.14430559, 0.14044628, 0.13659799, 0.13275836, 0.1289247, 0.12509406, 0.121263415, 0.117429756, 0.113590166, 0.109741986, 0.10588288, 0.10201095, 0.09812477, 0.094223484, 0.09030681, 0.08637506, 0.08242912, 0.07847041, 0.07450085, 0.070522785, 0.066538855, 0.06255194, 0.05856502, 0.054581087, 0.050603, 0.046633396, 0.042674586, 0.038728457, 0.03479642, 0.030879337, 0.026977492, 0.023090592, 0.019217765, 0.015357599, 0.0115082, 0.00766726, 0.003832145, -2.2135221e-17, -0.003832145, -0.00766726, -0.0115082, -0.015357599, -0.019217765, -0.023090592, -0.026977492, -0.030879337, -0.03479642, -0.038728457, -0.042674586, -0.046633396, -0.050603, -0.054581087, -0.05856502, -0.06255194, -0.066538855, -0.070522785, -0.07450085, -0.07847041, -0.08242912, -0.08637506, -0.09030681, -0.094223484, -0.09812477, -0.10201095, -0.10588288, -0.109741986, -0.113590166, -0.117429756, -0.121263415, -0.12509406, -0.1289247, -0.13275836, -0.13659799, -0.14044628, -0.14430559, -0.14817789, -0.15206465, -0.15596677, -0.1598846, -0.16381782, -0.1677656, -0.1717265, -0.17569855, -0.1796794, -0.1836663, -0.1876563, -0.19164631, -0.1956332, -0.19961397, -0.20358588, -0.20754643, -0.21149363, -0.21542595, -0.21934246, -0.22324279, -0.22712724, -0.23099668, -0.23485258, -0.23869696, -0.24253225, -0.24636127, -0.25018707, -0.25401288, -0.2578419, -0.2616773, -0.2655219, -0.26937827, -0.27324855, -0.27713427, -0.28103647, -0.2849555, -0.2888911, -0.29284236, -0.29680774, -0.30078515, -0.30477205, -0.30876553, -0.31276238, -0.3167592, -0.32075265, -0.32473946, -0.32871658, -0.3326813, -0.33663145, -0.34056532, -0.34448183, -0.3483806, -0.3522619, -0.3561267, -0.35997656, -0.3638137, -0.36764076, -0.37146088, -0.37527746, -0.37909403, -0.38291422, -0.38674146, -0.390579, -0.39442968, -0.39829594, -0.40217954, -0.40608165, -0.4100027, -0.41394246, -0.41789985, -0.42187324, -0.42586026, -0.42985806, -0.43386334, -0.4378724, -0.4418815, -0.44588673, -0.44988433, -0.4538708, -0.4578431, -0.46179855, -0.46573523, -0.46965188, -0.473548, -0.47742385, -0.4812805, -0.4851198, -0.4889442, -0.49275687, -0.49656144, -0.5003618, -0.5041622, -0.5079668, -0.5117797, -0.5156049, -0.51944566, -0.5233049, -0.5271848, -0.5310868, -0.5350114, -0.53895843, -0.5429267, -0.5469142, -0.55091816, -0.5549352, -0.5589613, -0.5629919, -0.5670226, -0.5710486, -0.57506526, -0.57906824, -0.5830537, -0.5870185, -0.59095997, -0.59487665, -0.59876776, -0.6026336, -0.6064755, -0.61029553, -0.6140969, -0.61788344, -0.6216597, -0.62543064, -0.62920165, -0.632978, -0.6367651, -0.64056784, -0.6443907, -0.64823747, -0.65211105, -0.65601337, -0.6599453, -0.6639066, -0.66789573, -0.67191035, -0.6759468, -0.68000054, -0.68406636, -0.6881384, -0.69221044, -0.69627607, -0.70032907, -0.70436347, -0.7083739, -0.71235585, -0.71630573, -0.7202211, -0.7241009, -0.7279453, -0.731756, -0.7355358, -0.7392891, -0.74302125, -0.7467386, -0.7504484, -0.75415814, -0.75787586, -0.7616092, -0.7653657, -0.7691522, -0.7729744, -0.776837, -0.7807431, -0.78469443, -0.78869087, -0.7927305, -0.7968098, -0.80092335, -0.8050643, -0.80922455, -0.8133948, -0.817565, -0.8217247, -0.82586354, -0.82997125, -0.83403856, -0.83805734, -0.8420209, -0.8459245, -0.8497654, -0.8535434, -0.85726047, -0.8609214, -0.8645332, -0.86810535, -0.87164944, -0.8751789, -0.87870836, -0.88225365, -0.8858308, -0.8894559, -0.893144, -0.89690894, -0.90076244, -0.90471375, -0.9087688, -0.91293, -0.9171957, -0.92155975, -0.9260117, -0.93053615, -0.93511325, -0.93971866, -0.9443237, -0.94889605, -0.9534001, -0.95779747, -0.962048, -0.9661102, -0.96994245, -0.9735034, -0.9767533, -0.97965467, -0.9821731, -0.9842779, -0.98594314, -0.987148, -0.9878771, -0.98812115, -0.9878771, -0.987148, -0.98594314, -0.9842779, -0.9821731, -0.97965467, -0.9767533, -0.9735034, -0.96994245, -0.9661102, -0.962048, -0.95779747, -0.9534001, -0.94889605, -0.9443237, -0.93971866, -0.93511325, -0.93053615, -0.9260117, -0.92155975, -0.9171957, -0.91293, -0.9087688, -0.90471375, -0.90076244, -0.89690894, -0.893144, -0.8894559, -0.8858308, -0.88225365, -0.87870836, -0.8751789, -0.87164944, -0.86810535, -0.8645332, -0.8609214, -0.85726047, -0.8535434, -0.8497654, -0.8459245, -0.8420209, -0.83805734, -0.83403856, -0.82997125, -0.82586354, -0.8217247, -0.817565, -0.8133948, -0.80922455, -0.8050643, -0.80092335, -0.7968098, -0.7927305, -0.78869087, -0.78469443, -0.7807431, -0.776837, -0.7729744, -0.7691522, -0.7653657, -0.7616092, -0.75787586, -0.75415814, -0.7504484, -0.7467386, -0.74302125, -0.7392891, -0.7355358, -0.731756, -0.7279453, -0.7241009, -0.7202211, -0.71630573, -0.71235585, -0.7083739, -0.70436347, -0.70032907, -0.69627607, -0.69221044, -0.6881384, -0.68406636, -0.68000054, -0.6759468, -0.67191035, -0.66789573, -0.6639066, -0.6599453, -0.65601337, -0.65211105, -0.64823747, -0.6443907, -0.64056784, -0.6367651, -0.632978, -0.62920165, -0.62543064, -0.6216597, -0.61788344, -0.6140969, -0.61029553, -0.6064755, -0.6026336, -0.59876776, -0.59487665, -0.59095997, -0.5870185, -0.5830537, -0.57906824, -0.57506526, -0.5710486, -0.5670226, -0.5629919, -0.5589613, -0.5549352, -0.55091816, -0.5469142, -0.5429267, -0.53895843, -0.5350114, -0.5310868, -0.5271848, -0.5233049, -0.51944566, -0.5156049, -0.5117797, -0.5079668, -0.5041622, -0.5003618, -0.49656144, -0.49275687, -0.4889442, -0.4851198, -0.4812805, -0.47742385, -0.473548, -0.46965188, -0.46573523, -0.46179855, -0.4578431, -0.4538708, -0.44988433, -0.44588673, -0.4418815, -0.4378724, -0.43386334, -0.42985806, -0.42586026, -0.42187324, -0.41789985, -0.41394246, -0.4100027, -0.40608165, -0.40217954, -0.39829594, -0.39442968, -0.390579, -0.38674146, -0.38291422, -0.37909403, -0.37527746, -0.37146088, -0.36764076, -0.3638137, -0.35997656, -0.3561267, -0.3522619, -0.3483806, -0.34448183, -0.34056532, -0.33663145, -0.3326813, -0.32871658, -0.32473946, -0.32075265, -0.3167592, -0.31276238, -0.30876553, -0.30477205, -0.30078515, -0.29680774, -0.29284236, -0.2888911, -0.2849555, -0.28103647, -0.27713427, -0.27324855, -0.26937827, -0.2655219, -0.2616773, -0.2578419, -0.25401288, -0.25018707, -0.24636127, -0.24253225, -0.23869696, -0.23485258, -0.23099668, -0.22712724, -0.22324279, -0.21934246, -0.21542595, -0.21149363, -0.20754643, -0.20358588, -0.19961397, -0.1956332, -0.19164631, -0.1876563, -0.1836663, -0.1796794, -0.17569855, -0.1717265, -0.1677656, -0.16381782, -0.1598846, -0.15596677, -0.15206465, -0.14817789, -0.14430559, -0.14044628, -0.13659799, -0.13275836, -0.1289247, -0.12509406, -0.121263415, -0.117429756, -0.113590166, -0.109741986, -0.10588288, -0.10201095, -0.09812477, -0.094223484, -0.09030681, -0.08637506, -0.08242912, -0.07847041, -0.07450085, -0.070522785, -0.066538855, -0.06255194, -0.05856502, -0.054581087, -0.050603, -0.046633396, -0.042674586, -0.038728457, -0.03479642, -0.030879337, -0.026977492, -0.023090592, -0.019217765, -0.015357599, -0.0115082, -0.00766726, -0.003832145, ], [0.0, 0.0037546426, 0.007510781, 0.011269896, 0.015033442, 0.01880283, 0.022579411, 0.026364477, 0.030159231, 0.03396479, 0.03778216, 0.04161224, 0.04545581, 0.049313515, 0.05318587, 0.057073243, 0.06097587, 0.06489383, 0.06882705, 0.07277533, 0.07673831, 0.0807155, 0.084706254, 0.08870982, 0.09272529, 0.09675167, 0.10078785, 0.104832605, 0.10888466, 0.11294264, 0.11700512, 0.12107063, 0.12513766, 0.12920469, 0.13327019, 0.13733265, 0.14139058, 0.14544252, 0.14948708, 0.15352292, 0.1575488, 0.16156356, 0.16556615, 0.1695556, 0.17353113, 0.17749201, 0.18143773, 0.18536787, 0.18928216, 0.1931805, 0.19706295, 0.2009297, 0.20478112, 0.20861767, 0.21244003, 0.21624897, 0.22004542, 0.2238304, 0.22760507, 0.2313707, 0.23512866, 0.23888034, 0.24262726, 0.24637099, 0.2501131, 0.2538552, 0.25759894, 0.26134592, 0.26509774, 0.26885593, 0.272622, 0.2763974, 0.2801835, 0.2839815, 0.28779262, 0.29161784, 0.2954581, 0.29931414, 0.30318657, 0.30707586, 0.3109823, 0.31490606, 0.31884706, 0.3228052, 0.32678002, 0.33077106, 0.3347776, 0.33879885, 0.3428338, 0.3468813, 0.35094014, 0.35500896, 0.35908625, 0.36317042, 0.3672599, 0.37135288, 0.3754477, 0.37954247, 0.38363546, 0.38772485, 0.3918088, 0.39588565, 0.39995363, 0.4040112, 0.40805674, 0.41208884, 0.41610616, 0.42010757, 0.424092, 0.42805853, 0.43200648, 0.43593523, 0.43984443, 0.4437339, 0.44760364, 0.4514538, 0.45528477, 0.45909715, 0.46289167, 0.46666923, 0.47043097, 0.47417814, 0.4779122, 0.48163465, 0.48534727, 0.4890518, 0.4927502, 0.49644443, 0.50013655, 0.5038287, 0.50752294, 0.51122147, 0.5149264, 0.5186398, 0.52236366, 0.52610004, 0.52985066, 0.53361744, 0.53740185, 0.5412054, 0.54502946, 0.54887503, 0.5527432, 0.55663455, 0.5605497, 0.5644889, 0.5684522, 0.57243943, 0.5764502, 0.580484, 0.5845397, 0.58861643, 0.5927128, 0.59682727, 0.6009581, 0.60510343, 0.6092611, 0.61342895, 0.6176045, 0.62178534, 0.6259689, 0.6301524, 0.63433313, 0.6385085, 0.6426756, 0.6468318, 0.6509744, 0.6551009, 0.65920866, 0.6632955, 0.6673591, 0.67139757, 0.67540896, 0.6793918, 0.68334466, 0.68726647, 0.69115645, 0.69501406, 0.6988391, 0.70263165, 0.70639205, 0.71012115, 0.71382, 0.71748984, 0.72113246, 0.72474986, 0.7283444, 0.73191845, 0.7354751, 0.7390173, 0.74254847, 0.74607205, 0.7495918, 0.7531116, 0.75663537, 0.7601671, 0.76371104, 0.76727116, 0.7708516, 0.7744564, 0.77808946, 0.78175455, 0.7854553, 0.789195, 0.79297686, 0.79680365, 0.80067796, 0.8046017, 0.8085768, 0.8126045, 0.81668556, 0.82082033, 0.8250087, 0.82924986, 0.83354264, 0.8378852, 0.84227514, 0.8467095, 0.8511846, 0.85569644, 0.8602402, 0.8648106, 0.8694017, 0.8740072, 0.8786201, 0.8832329, 0.88783777, 0.8924264, 0.8969899, 0.9015192, 0.9060048, 0.9104369, 0.9148057, 0.9191009, 0.9233121, 0.927429, 0.93144107, 0.9353379, 0.9391091, 0.94274443, 0.94623387, 0.94956744, 0.9527358, 0.95572954, 0.9585399, 0.9611586, 0.9635776, 0.9657896, 0.9677878, 0.969566, 0.97111857, 0.9724408, 0.9735283, 0.9743778, 0.9749865, 0.9753525, 0.97547466, 0.9753525, 0.9749865, 0.9743778, 0.9735283, 0.9724408, 0.97111857, 0.969566, 0.9677878, 0.9657896, 0.9635776, 0.9611586, 0.9585399, 0.95572954, 0.9527358, 0.94956744, 0.94623387, 0.94274443, 0.9391091, 0.9353379, 0.93144107, 0.927429, 0.9233121, 0.9191009, 0.9148057, 0.9104369, 0.9060048, 0.9015192, 0.8969899, 0.8924264, 0.88783777, 0.8832329, 0.8786201, 0.8740072, 0.8694017, 0.8648106, 0.8602402, 0.85569644, 0.8511846, 0.8467095, 0.84227514, 0.8378852, 0.83354264, 0.82924986, 0.8250087, 0.82082033, 0.81668556, 0.8126045, 0.8085768, 0.8046017, 0.80067796, 0.79680365, 0.79297686, 0.789195, 0.7854553, 0.78175455, 0.77808946, 0.7744564, 0.7708516, 0.76727116, 0.76371104, 0.7601671, 0.75663537, 0.7531116, 0.7495918, 0.74607205, 0.74254847, 0.7390173, 0.7354751, 0.73191845, 0.7283444, 0.72474986, 0.72113246, 0.71748984, 0.71382, 0.71012115, 0.70639205, 0.70263165, 0.6988391, 0.69501406, 0.69115645, 0.68726647, 0.68334466, 0.6793918, 0.67540896, 0.67139757, 0.6673591, 0.6632955, 0.65920866, 0.6551009, 0.6509744, 0.6468318, 0.6426756, 0.6385085, 0.63433313, 0.6301524, 0.6259689, 0.62178534, 0.6176045, 0.61342895, 0.6092611, 0.60510343, 0.6009581, 0.59682727, 0.5927128, 0.58861643, 0.5845397, 0.580484, 0.5764502, 0.57243943, 0.5684522, 0.5644889, 0.5605497, 0.55663455, 0.5527432, 0.54887503, 0.54502946, 0.5412054, 0.53740185, 0.53361744, 0.52985066, 0.52610004, 0.52236366, 0.5186398, 0.5149264, 0.51122147, 0.50752294, 0.5038287, 0.50013655, 0.49644443, 0.4927502, 0.4890518, 0.48534727, 0.48163465, 0.4779122, 0.47417814, 0.47043097, 0.46666923, 0.46289167, 0.45909715, 0.45528477, 0.4514538, 0.44760364, 0.4437339, 0.43984443, 0.43593523, 0.43200648, 0.42805853, 0.424092, 0.42010757, 0.41610616, 0.41208884, 0.40805674, 0.4040112, 0.39995363, 0.39588565, 0.3918088, 0.38772485, 0.38363546, 0.37954247, 0.3754477, 0.37135288, 0.3672599, 0.36317042, 0.35908625, 0.35500896, 0.35094014, 0.3468813, 0.3428338, 0.33879885, 0.3347776, 0.33077106, 0.32678002, 0.3228052, 0.31884706, 0.31490606, 0.3109823, 0.30707586, 0.30318657, 0.29931414, 0.2954581, 0.29161784, 0.28779262, 0.2839815, 0.2801835, 0.2763974, 0.272622, 0.26885593, 0.26509774, 0.26134592, 0.25759894, 0.2538552, 0.2501131, 0.24637099, 0.24262726, 0.23888034, 0.23512866, 0.2313707, 0.22760507, 0.2238304, 0.22004542, 0.21624897, 0.21244003, 0.20861767, 0.20478112, 0.2009297, 0.19706295, 0.1931805, 0.18928216, 0.18536787, 0.18143773, 0.17749201, 0.17353113, 0.1695556, 0.16556615, 0.16156356, 0.1575488, 0.15352292, 0.14948708, 0.14544252, 0.14139058, 0.13733265, 0.13327019, 0.12920469, 0.12513766, 0.12107063, 0.11700512, 0.11294264, 0.10888466, 0.104832605, 0.10078785, 0.09675167, 0.09272529, 0.08870982, 0.084706254, 0.0807155, 0.07673831, 0.07277533, 0.06882705, 0.06489383, 0.06097587, 0.057073243, 0.05318587, 0.049313515, 0.04545581, 0.04161224, 0.03778216, 0.03396479, 0.030159231, 0.026364477, 0.022579411, 0.01880283, 0.015033442, 0.011269896, 0.007510781, 0.0037546426, 2.1252202e-17, -0.0037546426, -0.007510781, -0.011269896, -0.015033442, -0.01880283, -0.022579411, -0.026364477, -0.030159231, -0.03396479, -0.03778216, -0.04161224, -0.04545581, -0.049313515, -0.05318587, -0.057073243, -0.06097587, -0.06489383, -0.06882705, -0.07277533, -0.07673831, -0.0807155, -0.084706254, -0.08870982, -0.09272529, -0.09675167, -0.10078785, -0.104832605, -0.10888466, -0.11294264, -0.11700512, -0.12107063, -0.12513766, -0.12920469, -0.13327019, -0.13733265, -0.14139058, -0.14544252, -0.14948708, -0.15352292, -0.1575488, -0.16156356, -0.16556615, -0.1695556, -0.17353113, -0.17749201, -0.18143773, -0.18536787, -0.18928216, -0.1931805, -0.19706295, -0.2009297, -0.20478112, -0.20861767, -0.21244003, -0.21624897, -0.22004542, -0.2238304, -0.22760507, -0.2313707, -0.23512866, -0.23888034, -0.24262726, -0.24637099, -0.2501131, -0.2538552, -0.25759894, -0.26134592, -0.26509774, -0.26885593, -0.272622, -0.2763974, -0.2801835, -0.2839815, -0.28779262, -0.29161784, -0.2954581, -0.29931414, -0.30318657, -0.30707586, -0.3109823, -0.31490606, -0.31884706, -0.3228052, -0.32678002, -0.33077106, -0.3347776, -0.33879885, -0.3428338, -0.3468813, -0.35094014, -0.35500896, -0.35908625, -0.36317042, -0.3672599, -0.37135288, -0.3754477, -0.37954247, -0.38363546, -0.38772485, -0.3918088, -0.39588565, -0.39995363, -0.4040112, -0.40805674, -0.41208884, -0.41610616, -0.42010757, -0.424092, -0.42805853, -0.43200648, -0.43593523, -0.43984443, -0.4437339, -0.44760364, -0.4514538, -0.45528477, -0.45909715, -0.46289167, -0.46666923, -0.47043097, -0.47417814, -0.4779122, -0.48163465, -0.48534727, -0.4890518, -0.4927502, -0.49644443, -0.50013655, -0.5038287, -0.50752294, -0.51122147, -0.5149264, -0.5186398, -0.52236366, -0.52610004, -0.52985066, -0.53361744, -0.53740185, -0.5412054, -0.54502946, -0.54887503, -0.5527432, -0.55663455, -0.5605497, -0.5644889, -0.5684522, -0.57243943, -0.5764502, -0.580484, -0.5845397, -0.58861643, -0.5927128, -0.59682727, -0.6009581, -0.60510343, -0.6092611, -0.61342895, -0.6176045, -0.62178534, -0.6259689, -0.6301524, -0.63433313, -0.6385085, -0.6426756, -0.6468318, -0.6509744, -0.6551009, -0.65920866, -0.6632955, -0.6673591, -0.67139757, -0.67540896, -0.6793918, -0.68334466, -0.68726647, -0.69115645, -0.69501406, -0.6988391, -0.70263165, -0.70639205, -0.71012115, -0.71382, -0.71748984, -0.72113246, -0.72474986, -0.7283444, -0.73191845, -0.7354751, -0.7390173, -0.74254847, -0.74607205, -0.7495918, -0.7531116, -0.75663537, -0.7601671, -0.76371104, -0.76727116, -0.7708516, -0.7744564, -0.77808946, -0.78175455, -0.7854553, -0.789195, -0.79297686, -0.79680365, -0.80067796, -0.8046017, -0.8085768, -0.8126045, -0.81668556, -0.82082033, -0.8250087, -0.82924986, -0.83354264, -0.8378852, -0.84227514, -0.8467095, -0.8511846, -0.85569644, -0.8602402, -0.8648106, -0.8694017, -0.8740072, -0.8786201, -0.8832329, -0.88783777, -0.8924264, -0.8969899, -0.9015192, -0.9060048, -0.9104369, -0.9148057, -0.9191009, -0.9233121, -0.927429, -0.93144107, -0.9353379, -0.9391091, -0.94274443, -0.94623387, -0.94956744, -0.9527358, -0.95572954, -0.9585399, -0.9611586, -0.9635776, -0.9657896, -0.9677878, -0.969566, -0.97111857, -0.9724408, -0.9735283, -0.9743778, -0.9749865, -0.9753525, -0.97547466, -0.9753525, -0.9749865, -0.9743778, -0.9735283, -0.9724408, -0.97111857, -0.969566, -0.9677878, -0.9657896, -0.9635776, -0.9611586, -0.9585399, -0.95572954, -0.9527358, -0.94956744, -0.94623387, -0.94274443, -0.9391091, -0.9353379, -0.93144107, -0.927429, -0.9233121, -0.9191009, -0.9148057, -0.9104369, -0.9060048, -0.9015192, -0.8969899, -0.8924264, -0.88783777, -0.8832329, -0.8786201, -0.8740072, -0.8694017, -0.8648106, -0.8602402, -0.85569644, -0.8511846, -0.8467095, -0.84227514, -0.8378852, -0.83354264, -0.82924986, -0.8250087, -0.82082033, -0.81668556, -0.8126045, -0.8085768, -0.8046017, -0.80067796, -0.79680365, -0.79297686, -0.789195, -0.7854553, -0.78175455, -0.77808946, -0.7744564, -0.7708516, -0.76727116, -0.76371104, -0.7601671, -0.75663537, -0.7531116, -0.7495918, -0.74607205, -0.74254847, -0.7390173, -0.7354751, -0.73191845, -0.7283444, -0.72474986, -0.72113246, -0.71748984, -0.71382, -0.71012115, -0.70639205, -0.70263165, -0.6988391, -0.69501406, -0.69115645, -0.68726647, -0.68334466, -0.6793918, -0.67540896, -0.67139757, -0.6673591, -0.6632955, -0.65920866, -0.6551009, -0.6509744, -0.6468318, -0.6426756, -0.6385085, -0.63433313, -0.6301524, -0.6259689, -0.62178534, -0.6176045, -0.61342895, -0.6092611, -0.60510343, -0.6009581, -0.59682727, -0.5927128, -0.58861643, -0.5845397, -0.580484, -0.5764502, -0.57243943, -0.5684522, -0.5644889, -0.5605497, -0.55663455, -0.5527432, -0.54887503, -0.54502946, -0.5412054, -0.53740185, -0.53361744, -0.52985066, -0.52610004, -0.52236366, -0.5186398, -0.5149264, -0.51122147, -0.50752294, -0.5038287, -0.50013655, -0.49644443, -0.4927502, -0.4890518, -0.48534727, -0.48163465, -0.4779122, -0.47417814, -0.47043097, -0.46666923, -0.46289167, -0.45909715, -0.45528477, -0.4514538, -0.44760364, -0.4437339, -0.43984443, -0.43593523, -0.43200648, -0.42805853, -0.424092, -0.42010757, -0.41610616, -0.41208884, -0.40805674, -0.4040112, -0.39995363, -0.39588565, -0.3918088, -0.38772485, -0.38363546, -0.37954247, -0.3754477, -0.37135288, -0.3672599, -0.36317042, -0.35908625, -0.35500896, -0.35094014, -0.3468813, -0.3428338, -0.33879885, -0.3347776, -0.33077106, -0.32678002, -0.3228052, -0.31884706, -0.31490606, -0.3109823, -0.30707586, -0.30318657, -0.29931414, -0.2954581, -0.29161784, -0.28779262, -0.2839815, -0.2801835, -0.2763974, -0.272622, -0.26885593, -0.26509774, -0.26134592, -0.25759894, -0.2538552, -0.2501131, -0.24637099, -0.24262726, -0.23888034, -0.23512866, -0.2313707, -0.22760507, -0.2238304, -0.22004542, -0.21624897, -0.21244003, -0.20861767, -0.20478112, -0.2009297, -0.19706295, -0.1931805, -0.18928216, -0.18536787, -0.18143773, -0.17749201, -0.17353113, -0.1695556, -0.16556615, -0.16156356, -0.1575488, -0.15352292, -0.14948708, -0.14544252, -0.14139058, -0.13733265, -0.13327019, -0.12920469, -0.12513766, -0.12107063, -0.11700512, -0.11294264, -0.10888466, -0.104832605, -0.10078785, -0.09675167, -0.09272529, -0.08870982, -0.084706254, -0.0807155, -0.07673831, -0.07277533, -0.06882705, -0.06489383, -0.06097587, -0.057073243, -0.05318587, -0.049313515, -0.04545581, -0.04161224, -0.03778216, -0.03396479, -0.030159231, -0.026364477, -0.022579411, -0.01880283, -0.015033442, -0.011269896, -0.007510781, -0.0037546426, ], [0.0, 0.00360291, 0.0072065694, 0.010811725, 0.014419122, 0.018029498, 0.021643585, 0.025262108, 0.028885778, 0.0325153, 0.036151357, 0.039794628, 0.043445766, 0.047105405, 0.050774172, 0.05445266, 0.058141448, 0.061841078, 0.065552086, 0.06927497, 0.0730102, 0.07675822, 0.080519445, 0.08429426, 0.088083014, 0.09188603, 0.095703594, 0.09953595, 0.103383325, 0.1072459, 0.11112382, 0.1150172, 0.1189261, 0.12285057, 0.12679061, 0.13074617, 0.13471721, 0.13870358, 0.14270516, 0.14672175, 0.15075314, 0.15479906, 0.15885924, 0.16293335, 0.16702102, 0.17112187, 0.17523545, 0.17936133, 0.18349901, 0.18764795, 0.19180766, 0.19597751, 0.20015691, 0.20434526, 0.20854189, 0.21274613, 0.21695729, 0.22117464, 0.22539748, 0.22962505, 0.23385657, 0.2380913, 0.24232844, 0.24656717, 0.25080672, 0.25504628, 0.259285, 0.26352212, 0.2677568, 0.27198818, 0.27621552, 0.28043798, 0.28465477, 0.2888651, 0.29306817, 0.29726323, 0.30144957, 0.30562636, 0.30979297, 0.31394866, 0.31809276, 0.32222462, 0.3263436, 0.3304491, 0.33454058, 0.33861744, 0.34267923, 0.3467254, 0.35075557, 0.3547693, 0.35876617, 0.36274588, 0.36670816, 0.3706527, 0.37457928, 0.37848774, 0.38237795, 0.38624978, 0.39010322, 0.3939382, 0.3977548, 0.4015531, 0.4053332, 0.40909526, 0.4128395, 0.4165662, 0.4202756, 0.4239681, 0.42764407, 0.43130395, 0.4349482, 0.43857732, 0.44219187, 0.44579247, 0.44937974, 0.45295435, 0.456517, 0.46006846, 0.46360952, 0.46714094, 0.4706636, 0.4741784, 0.4776862, 0.48118797, 0.48468462, 0.48817718, 0.49166662, 0.495154, 0.4986403, 0.5021266, 0.505614, 0.5091035, 0.5125962, 0.51609325, 0.51959574, 0.5231047, 0.52662134, 0.53014666, 0.53368175, 0.5372277, 0.5407856, 0.54435647, 0.5479414, 0.5515413, 0.55515736, 0.5587904, 0.56244135, 0.5661112, 0.56980085, 0.5735111, 0.5772429, 0.5809969, 0.58477384, 0.58857447, 0.5923994, 0.59624934, 0.6001248, 0.6040262, 0.6079541, 0.61190885, 0.6158908, 0.6199002, 0.6239373, 0.6280023, 0.6320953, 0.6362162, 0.6403651, 0.64454186, 0.64874625, 0.6529781, 0.6572371, 0.6615228, 0.66583484, 0.67017263, 0.6745355, 0.6789229, 0.68333405, 0.68776804, 0.6922241, 0.6967011, 0.70119816, 0.70571405, 0.7102476, 0.71479756, 0.7193626, 0.7239413, 0.7285322, 0.73313373, 0.7377443, 0.7423622, 0.7469857, 0.75161296, 0.7562422, 0.7608714, 0.76549864, 0.7701218, 0.77473885, 0.77934766, 0.7839459, 0.7885315, 0.793102, 0.7976552, 0.8021887, 0.80670005, 0.81118685, 0.8156465, 0.8200767, 0.8244748, 0.8288383, 0.8331645, 0.837451, 0.8416951, 0.8458942, 0.8500457, 0.85414696, 0.85819536, 0.86218834, 0.86612326, 0.8699975, 0.8738085, 0.8775537, 0.88123053, 0.8848365, 0.888369, 0.8918258, 0.89520425, 0.89850205, 0.9017168, 0.90484625, 0.9078881, 0.9108401, 0.9137001, 0.91646606, 0.91913587, 0.9217075, 0.9241791, 0.9265487, 0.92881465, 0.93097514, 0.9330286, 0.9349733, 0.9368079, 0.93853086, 0.94014096, 0.9416369, 0.94301754, 0.94428176, 0.94542855, 0.9464571, 0.9473666, 0.94815624, 0.9488255, 0.9493737, 0.9498006, 0.9501057, 0.95028895, 0.95035005, 0.95028895, 0.9501057, 0.9498006, 0.9493737, 0.9488255, 0.94815624, 0.9473666, 0.9464571, 0.94542855, 0.94428176, 0.94301754, 0.9416369, 0.94014096, 0.93853086, 0.9368079, 0.9349733, 0.9330286, 0.93097514, 0.92881465, 0.9265487, 0.9241791, 0.9217075, 0.91913587, 0.91646606, 0.9137001, 0.9108401, 0.9078881, 0.90484625, 0.9017168, 0.89850205, 0.89520425, 0.8918258, 0.888369, 0.8848365, 0.88123053, 0.8775537, 0.8738085, 0.8699975, 0.86612326, 0.86218834, 0.85819536, 0.85414696, 0.8500457, 0.8458942, 0.8416951, 0.837451, 0.8331645, 0.8288383, 0.8244748, 0.8200767, 0.8156465, 0.81118685, 0.80670005, 0.8021887, 0.7976552, 0.793102, 0.7885315, 0.7839459, 0.77934766, 0.77473885, 0.7701218, 0.76549864, 0.7608714, 0.7562422, 0.75161296, 0.7469857, 0.7423622, 0.7377443, 0.73313373, 0.7285322, 0.7239413, 0.7193626, 0.71479756, 0.7102476, 0.70571405, 0.70119816, 0.6967011, 0.6922241, 0.68776804, 0.68333405, 0.6789229, 0.6745355, 0.67017263, 0.66583484, 0.6615228, 0.6572371, 0.6529781, 0.64874625, 0.64454186, 0.6403651, 0.6362162, 0.6320953, 0.6280023, 0.6239373, 0.6199002, 0.6158908, 0.61190885, 0.6079541, 0.6040262, 0.6001248, 0.59624934, 0.5923994, 0.58857447, 0.58477384, 0.5809969, 0.5772429, 0.5735111, 0.56980085, 0.5661112, 0.56244135, 0.5587904, 0.55515736, 0.5515413, 0.5479414, 0.54435647, 0.5407856, 0.5372277, 0.53368175, 0.53014666, 0.52662134, 0.5231047, 0.51959574, 0.51609325, 0.5125962, 0.5091035, 0.505614, 0.5021266, 0.4986403, 0.495154, 0.49166662, 0.48817718, 0.48468462, 0.48118797, 0.4776862, 0.4741784, 0.4706636, 0.46714094, 0.46360952, 0.46006846, 0.456517, 0.45295435, 0.44937974, 0.44579247, 0.44219187, 0.43857732, 0.4349482, 0.43130395, 0.42764407, 0.4239681, 0.4202756, 0.4165662, 0.4128395, 0.40909526, 0.4053332, 0.4015531, 0.3977548, 0.3939382, 0.39010322, 0.38624978, 0.38237795, 0.37848774, 0.37457928, 0.3706527, 0.36670816, 0.36274588, 0.35876617, 0.3547693, 0.35075557, 0.3467254, 0.34267923, 0.33861744, 0.33454058, 0.3304491, 0.3263436, 0.32222462, 0.31809276, 0.31394866, 0.30979297, 0.30562636, 0.30144957, 0.29726323, 0.29306817, 0.2888651, 0.28465477, 0.28043798, 0.27621552, 0.27198818, 0.2677568, 0.26352212, 0.259285, 0.25504628, 0.25080672, 0.24656717, 0.24232844, 0.2380913, 0.23385657, 0.22962505, 0.22539748, 0.22117464, 0.21695729, 0.21274613, 0.20854189, 0.20434526, 0.20015691, 0.19597751, 0.19180766, 0.18764795, 0.18349901, 0.17936133, 0.17523545, 0.17112187, 0.16702102, 0.16293335, 0.15885924, 0.15479906, 0.15075314, 0.14672175, 0.14270516, 0.13870358, 0.13471721, 0.13074617, 0.12679061, 0.12285057, 0.1189261, 0.1150172, 0.11112382, 0.1072459, 0.103383325, 0.09953595, 0.095703594, 0.09188603, 0.088083014, 0.08429426, 0.080519445, 0.07675822, 0.0730102, 0.06927497, 0.065552086, 0.061841078, 0.058141448, 0.05445266, 0.050774172, 0.047105405, 0.043445766, 0.039794628, 0.036151357, 0.0325153, 0.028885778, 0.025262108, 0.021643585, 0.018029498, 0.014419122, 0.010811725, 0.0072065694, 0.00360291, 7.190669e-17, -0.00360291, -0.0072065694, -0.010811725, -0.014419122, -0.018029498, -0.021643585, -0.025262108, -0.028885778, -0.0325153, -0.036151357, -0.039794628, -0.043445766, -0.047105405, -0.050774172, -0.05445266, -0.058141448, -0.061841078, -0.065552086, -0.06927497, -0.0730102, -0.07675822, -0.080519445, -0.08429426, -0.088083014, -0.09188603, -0.095703594, -0.09953595, -0.103383325, -0.1072459, -0.11112382, -0.1150172, -0.1189261, -0.12285057, -0.12679061, -0.13074617, -0.13471721, -0.13870358, -0.14270516, -0.14672175, -0.15075314, -0.15479906, -0.15885924, -0.16293335, -0.16702102, -0.17112187, -0.17523545, -0.17936133, -0.18349901, -0.18764795, -0.19180766, -0.19597751, -0.20015691, -0.20434526, -0.20854189, -0.21274613, -0.21695729, -0.22117464, -0.22539748, -0.22962505, -0.23385657, -0.2380913, -0.24232844, -0.24656717, -0.25080672, -0.25504628, -0.259285, -0.26352212, -0.2677568, -0.27198818, -0.27621552, -0.28043798, -0.28465477, -0.2888651, -0.29306817, -0.29726323, -0.30144957, -0.30562636, -0.30979297, -0.31394866, -0.31809276, -0.32222462, -0.3263436, -0.3304491, -0.33454058, -0.33861744, -0.34267923, -0.3467254, -0.35075557, -0.3547693, -0.35876617, -0.36274588, -0.36670816, -0.3706527, -0.37457928, -0.37848774, -0.38237795, -0.38624978, -0.39010322, -0.3939382, -0.3977548, -0.4015531, -0.4053332, -0.40909526, -0.4128395, -0.4165662, -0.4202756, -0.4239681, -0.42764407, -0.43130395, -0.4349482, -0.43857732, -0.44219187, -0.44579247, -0.44937974, -0.45295435, -0.456517, -0.46006846, -0.46360952, -0.46714094, -0.4706636, -0.4741784, -0.4776862, -0.48118797, -0.48468462, -0.48817718, -0.49166662, -0.495154, -0.4986403, -0.5021266, -0.505614, -0.5091035, -0.5125962, -0.51609325, -0.51959574, -0.5231047, -0.52662134, -0.53014666, -0.53368175, -0.5372277, -0.5407856, -0.54435647, -0.5479414, -0.5515413, -0.55515736, -0.5587904, -0.56244135, -0.5661112, -0.56980085, -0.5735111, -0.5772429, -0.5809969, -0.58477384, -0.58857447, -0.5923994, -0.59624934, -0.6001248, -0.6040262, -0.6079541, -0.61190885, -0.6158908, -0.6199002, -0.6239373, -0.6280023, -0.6320953, -0.6362162, -0.6403651, -0.64454186, -0.64874625, -0.6529781, -0.6572371, -0.6615228, -0.66583484, -0.67017263, -0.6745355, -0.6789229, -0.68333405, -0.68776804, -0.6922241, -0.6967011, -0.70119816, -0.70571405, -0.7102476, -0.71479756, -0.7193626, -0.7239413, -0.7285322, -0.73313373, -0.7377443, -0.7423622, -0.7469857, -0.75161296, -0.7562422, -0.7608714, -0.76549864, -0.7701218, -0.77473885, -0.77934766, -0.7839459, -0.7885315, -0.793102, -0.7976552, -0.8021887, -0.80670005, -0.81118685, -0.8156465, -0.8200767, -0.8244748, -0.8288383, -0.8331645, -0.837451, -0.8416951, -0.8458942, -0.8500457, -0.85414696, -0.85819536, -0.86218834, -0.86612326, -0.8699975, -0.8738085, -0.8775537, -0.88123053, -0.8848365, -0.888369, -0.8918258, -0.89520425, -0.89850205, -0.9017168, -0.90484625, -0.9078881, -0.9108401, -0.9137001, -0.91646606, -0.91913587, -0.9217075, -0.9241791, -0.9265487, -0.92881465, -0.93097514, -0.9330286, -0.9349733, -0.9368079, -0.93853086, -0.94014096, -0.9416369, -0.94301754, -0.94428176, -0.94542855, -0.9464571, -0.9473666, -0.94815624, -0.9488255, -0.9493737, -0.9498006, -0.9501057, -0.95028895, -0.95035005, -0.95028895, -0.9501057, -0.9498006, -0.9493737, -0.9488255, -0.94815624, -0.9473666, -0.9464571, -0.94542855, -0.94428176, -0.94301754, -0.9416369, -0.94014096, -0.93853086, -0.9368079, -0.9349733, -0.9330286, -0.93097514, -0.92881465, -0.9265487, -0.9241791, -0.9217075, -0.91913587, -0.91646606, -0.9137001, -0.9108401, -0.9078881, -0.90484625, -0.9017168, -0.89850205, -0.89520425, -0.8918258, -0.888369, -0.8848365, -0.88123053, -0.8775537, -0.8738085, -0.8699975, -0.86612326, -0.86218834, -0.85819536, -0.85414696, -0.8500457, -0.8458942, -0.8416951, -0.837451, -0.8331645, -0.8288383, -0.8244748, -0.8200767, -0.8156465, -0.81118685, -0.80670005, -0.8021887, -0.7976552, -0.793102, -0.7885315, -0.7839459, -0.77934766, -0.77473885, -0.7701218, -0.76549864, -0.7608714, -0.7562422, -0.75161296, -0.7469857, -0.7423622, -0.7377443, -0.73313373, -0.7285322, -0.7239413, -0.7193626, -0.71479756, -0.7102476, -0.70571405, -0.70119816, -0.6967011, -0.6922241, -0.68776804, -0.68333405, -0.6789229, -0.6745355, -0.67017263, -0.66583484, -0.6615228, -0.6572371, -0.6529781, -0.64874625, -0.64454186, -0.6403651, -0.6362162, -0.6320953, -0.6280023, -0.6239373, -0.6199002, -0.6158908, -0.61190885, -0.6079541, -0.6040262, -0.6001248, -0.59624934, -0.5923994, -0.58857447, -0.58477384, -0.5809969, -0.5772429, -0.5735111, -0.56980085, -0.5661112, -0.56244135, -0.5587904, -0.55515736, -0.5515413, -0.5479414, -0.54435647, -0.5407856, -0.5372277, -0.53368175, -0.53014666, -0.52662134, -0.5231047, -0.51959574, -0.51609325, -0.5125962, -0.5091035, -0.505614, -0.5021266, -0.4986403, -0.495154, -0.49166662, -0.48817718, -0.48468462, -0.48118797, -0.4776862, -0.4741784, -0.4706636, -0.46714094, -0.46360952, -0.46006846, -0.456517, -0.45295435, -0.44937974, -0.44579247, -0.44219187, -0.43857732, -0.4349482, -0.43130395, -0.42764407, -0.4239681, -0.4202756, -0.4165662, -0.4128395, -0.40909526, -0.4053332, -0.4015531, -0.3977548, -0.3939382, -0.39010322, -0.38624978, -0.38237795, -0.37848774, -0.37457928, -0.3706527, -0.36670816, -0.36274588, -0.35876617, -0.3547693, -0.35075557, -0.3467254, -0.34267923, -0.33861744, -0.33454058, -0.3304491, -0.3263436, -0.32222462, -0.31809276, -0.31394866, -0.30979297, -0.30562636, -0.30144957, -0.29726323, -0.29306817, -0.2888651, -0.28465477, -0.28043798, -0.27621552, -0.27198818, -0.2677568, -0.26352212, -0.259285, -0.25504628, -0.25080672, -0.24656717, -0.24232844, -0.2380913, -0.23385657, -0.22962505, -0.22539748, -0.22117464, -0.21695729, -0.21274613, -0.20854189, -0.20434526, -0.20015691, -0.19597751, -0.19180766, -0.18764795, -0.18349901, -0.17936133, -0.17523545, -0.17112187, -0.16702102, -0.16293335, -0.15885924, -0.15479906, -0.15075314, -0.14672175, -0.14270516, -0.13870358, -0.13471721, -0.13074617, -0.12679061, -0.12285057, -0.1189261, -0.1150172, -0.11112382, -0.1072459, -0.103383325, -0.09953595, -0.095703594, -0.09188603, -0.088083014, -0.08429426, -0.080519445, -0.07675822, -0.0730102, -0.06927497, -0.065552086, -0.061841078, -0.058141448, -0.05445266, -0.050774172, -0.047105405, -0.043445766, -0.039794628, -0.036151357, -0.0325153, -0.028885778, -0.025262108, -0.021643585, -0.018029498, -0.014419122, -0.010811725, -0.0072065694, -0.00360291, ], [0.0, 0.0033184171, 0.006637209, 0.00995675, 0.013277415, 0.016599577, 0.019923609, 0.023249883, 0.026578771, 0.02991064, 0.03324586, 0.0365848, 0.03992782, 0.043275286, 0.046627555, 0.049984988, 0.05334794, 0.056716766, 0.060091812, 0.063473426, 0.06686196, 0.07025774, 0.07366111, 0.077072404, 0.080491945, 0.08392007, 0.08735709, 0.09080332, 0.094259076, 0.09772466, 0.10120039, 0.10468654, 0.10818342, 0.1116913, 0.11521048, 0.11874122, 0.1222838, 0.12583847, 0.1294055, 0.13298514, 0.13657762, 0.14018321, 0.1438021, 0.14743455, 0.15108074, 0.15474093, 0.15841527, 0.16210401, 0.16580729, 0.16952533, 0.17325827, 0.1770063, 0.18076956, 0.18454823, 0.18834242, 0.19215228, 0.19597791, 0.19981946, 0.20367703, 0.2075507, 0.2114406, 0.21534677, 0.2192693, 0.22320826, 0.2271637, 0.23113568, 0.23512422, 0.23912935, 0.24315108, 0.24718946, 0.25124446, 0.25531605, 0.25940424, 0.263509, 0.2676303, 0.2717681, 0.2759223, 0.28009287, 0.2842797, 0.28848276, 0.2927019, 0.29693705, 0.30118808, 0.30545485, 0.30973727, 0.31403515, 0.31834835, 0.32267672, 0.32702005, 0.33137822, 0.33575097, 0.34013814, 0.3445395, 0.34895486, 0.35338393, 0.3578265, 0.3622823, 0.3667511, 0.37123263, 0.37572658, 0.3802327, 0.38475063, 0.38928014, 0.39382085, 0.39837247, 0.40293464, 0.40750703, 0.4120893, 0.41668105, 0.42128193, 0.42589158, 0.4305096, 0.43513554, 0.4397691, 0.44440976, 0.44905713, 0.45371082, 0.45837036, 0.46303532, 0.46770522, 0.4723796, 0.47705802, 0.48173997, 0.48642498, 0.49111256, 0.4958022, 0.5004934, 0.50518566, 0.5098784, 0.5145712, 0.51926345, 0.52395463, 0.52864414, 0.5333315, 0.5380162, 0.54269755, 0.547375, 0.5520481, 0.5567161, 0.56137854, 0.5660348, 0.57068425, 0.5753263, 0.5799604, 0.5845859, 0.58920217, 0.5938086, 0.5984046, 0.6029895, 0.6075627, 0.6121236, 0.6166715, 0.6212058, 0.6257259, 0.6302311, 0.63472074, 0.63919425, 0.6436509, 0.64809006, 0.6525111, 0.6569134, 0.66129625, 0.665659, 0.670001, 0.6743216, 0.6786201, 0.6828959, 0.68714833, 0.6913767, 0.69558036, 0.6997587, 0.703911, 0.70803666, 0.712135, 0.71620536, 0.7202471, 0.7242595, 0.728242, 0.73219395, 0.7361146, 0.7400035, 0.7438598, 0.74768305, 0.7514725, 0.7552275, 0.75894755, 0.7626319, 0.76627994, 0.76989114, 0.77346486, 0.7770004, 0.78049725, 0.7839548, 0.78737247, 0.7907496, 0.7940857, 0.79738015, 0.8006323, 0.8038417, 0.8070078, 0.81012994, 0.8132076, 0.8162403, 0.81922746, 0.8221685, 0.82506305, 0.8279104, 0.83071023, 0.8334619, 0.83616495, 0.83881897, 0.8414234, 0.8439778, 0.8464817, 0.84893465, 0.85133624, 0.853686, 0.85598344, 0.85822827, 0.8604199, 0.8625582, 0.86464256, 0.86667264, 0.8686481, 0.8705686, 0.8724337, 0.87424314, 0.8759966, 0.8776936, 0.87933403, 0.88091743, 0.8824436, 0.8839122, 0.885323, 0.8866757, 0.8879701, 0.8892059, 0.8903829, 0.89150083, 0.8925595, 0.89355886, 0.8944985, 0.8953784, 0.89619833, 0.8969581, 0.8976577, 0.89829683, 0.8988755, 0.8993935, 0.89985085, 0.90024734, 0.90058297, 0.9008577, 0.90107137, 0.9012241, 0.9013157, 0.9013462, 0.9013157, 0.9012241, 0.90107137, 0.9008577, 0.90058297, 0.90024734, 0.89985085, 0.8993935, 0.8988755, 0.89829683, 0.8976577, 0.8969581, 0.89619833, 0.8953784, 0.8944985, 0.89355886, 0.8925595, 0.89150083, 0.8903829, 0.8892059, 0.8879701, 0.8866757, 0.885323, 0.8839122, 0.8824436, 0.88091743, 0.87933403, 0.8776936, 0.8759966, 0.87424314, 0.8724337, 0.8705686, 0.8686481, 0.86667264, 0.86464256, 0.8625582, 0.8604199, 0.85822827, 0.85598344, 0.853686, 0.85133624, 0.84893465, 0.8464817, 0.8439778, 0.8414234, 0.83881897, 0.83616495, 0.8334619, 0.83071023, 0.8279104, 0.82506305, 0.8221685, 0.81922746, 0.8162403, 0.8132076, 0.81012994, 0.8070078, 0.8038417, 0.8006323, 0.79738015, 0.7940857, 0.7907496, 0.78737247, 0.7839548, 0.78049725, 0.7770004, 0.77346486, 0.76989114, 0.76627994, 0.7626319, 0.75894755, 0.7552275, 0.7514725, 0.74768305, 0.7438598, 0.7400035, 0.7361146, 0.73219395, 0.728242, 0.7242595, 0.7202471, 0.71620536, 0.712135, 0.70803666, 0.703911, 0.6997587, 0.69558036, 0.6913767, 0.68714833, 0.6828959, 0.6786201, 0.6743216, 0.670001, 0.665659, 0.66129625, 0.6569134, 0.6525111, 0.64809006, 0.6436509, 0.63919425, 0.63472074, 0.6302311, 0.6257259, 0.6212058, 0.6166715, 0.6121236, 0.6075627, 0.6029895, 0.5984046, 0.5938086, 0.58920217, 0.5845859, 0.5799604, 0.5753263, 0.57068425, 0.5660348, 0.56137854, 0.5567161, 0.5520481, 0.547375, 0.54269755, 0.5380162, 0.5333315, 0.52864414, 0.52395463, 0.51926345, 0.5145712, 0.5098784, 0.50518566, 0.5004934, 0.4958022, 0.49111256, 0.48642498, 0.48173997, 0.47705802, 0.4723796, 0.46770522, 0.46303532, 0.45837036, 0.45371082, 0.44905713, 0.44440976, 0.4397691, 0.43513554, 0.4305096, 0.42589158, 0.42128193, 0.41668105, 0.4120893, 0.40750703, 0.40293464, 0.39837247, 0.39382085, 0.38928014, 0.38475063, 0.3802327, 0.37572658, 0.37123263, 0.3667511, 0.3622823, 0.3578265, 0.35338393, 0.34895486, 0.3445395, 0.34013814, 0.33575097, 0.33137822, 0.32702005, 0.32267672, 0.31834835, 0.31403515, 0.30973727, 0.30545485, 0.30118808, 0.29693705, 0.2927019, 0.28848276, 0.2842797, 0.28009287, 0.2759223, 0.2717681, 0.2676303, 0.263509, 0.25940424, 0.25531605, 0.25124446, 0.24718946, 0.24315108, 0.23912935, 0.23512422, 0.23113568, 0.2271637, 0.22320826, 0.2192693, 0.21534677, 0.2114406, 0.2075507, 0.20367703, 0.19981946, 0.19597791, 0.19215228, 0.18834242, 0.18454823, 0.18076956, 0.1770063, 0.17325827, 0.16952533, 0.16580729, 0.16210401, 0.15841527, 0.15474093, 0.15108074, 0.14743455, 0.1438021, 0.14018321, 0.13657762, 0.13298514, 0.1294055, 0.12583847, 0.1222838, 0.11874122, 0.11521048, 0.1116913, 0.10818342, 0.10468654, 0.10120039, 0.09772466, 0.094259076, 0.09080332, 0.08735709, 0.08392007, 0.080491945, 0.077072404, 0.07366111, 0.07025774, 0.06686196, 0.063473426, 0.060091812, 0.056716766, 0.05334794, 0.049984988, 0.046627555, 0.043275286, 0.03992782, 0.0365848, 0.03324586, 0.02991064, 0.026578771, 0.023249883, 0.019923609, 0.016599577, 0.013277415, 0.00995675, 0.006637209, 0.0033184171, 6.6229844e-17, -0.0033184171, -0.006637209, -0.00995675, -0.013277415, -0.016599577, -0.019923609, -0.023249883, -0.026578771, -0.02991064, -0.03324586, -0.0365848, -0.03992782, -0.043275286, -0.046627555, -0.049984988, -0.05334794, -0.056716766, -0.060091812, -0.063473426, -0.06686196, -0.07025774, -0.07366111, -0.077072404, -0.080491945, -0.08392007, -0.08735709, -0.09080332, -0.094259076, -0.09772466, -0.10120039, -0.10468654, -0.10818342, -0.1116913, -0.11521048, -0.11874122, -0.1222838, -0.12583847, -0.1294055, -0.13298514, -0.13657762, -0.14018321, -0.1438021, -0.14743455, -0.15108074, -0.15474093, -0.15841527, -0.16210401, -0.16580729, -0.16952533, -0.17325827, -0.1770063, -0.18076956, -0.18454823, -0.18834242, -0.19215228, -0.19597791, -0.19981946, -0.20367703, -0.2075507, -0.2114406, -0.21534677, -0.2192693, -0.22320826, -0.2271637, -0.23113568, -0.23512422, -0.23912935, -0.24315108, -0.24718946, -0.25124446, -0.25531605, -0.25940424, -0.263509, -0.2676303, -0.2717681, -0.2759223, -0.28009287, -0.2842797, -0.28848276, -0.2927019, -0.29693705, -0.30118808, -0.30545485, -0.30973727, -0.31403515, -0.31834835, -0.32267672, -0.32702005, -0.33137822, -0.33575097, -0.34013814, -0.3445395, -0.34895486, -0.35338393, -0.3578265, -0.3622823, -0.3667511, -0.37123263, -0.37572658, -0.3802327, -0.38475063, -0.38928014, -0.39382085, -0.39837247, -0.40293464, -0.40750703, -0.4120893, -0.41668105, -0.42128193, -0.42589158, -0.4305096, -0.43513554, -0.4397691, -0.44440976, -0.44905713, -0.45371082, -0.45837036, -0.46303532, -0.46770522, -0.4723796, -0.47705802, -0.48173997, -0.48642498, -0.49111256, -0.4958022, -0.5004934, -0.50518566, -0.5098784, -0.5145712, -0.51926345, -0.52395463, -0.52864414, -0.5333315, -0.5380162, -0.54269755, -0.547375, -0.5520481, -0.5567161, -0.56137854, -0.5660348, -0.57068425, -0.5753263, -0.5799604, -0.5845859, -0.58920217, -0.5938086, -0.5984046, -0.6029895, -0.6075627, -0.6121236, -0.6166715, -0.6212058, -0.6257259, -0.6302311, -0.63472074, -0.63919425, -0.6436509, -0.64809006, -0.6525111, -0.6569134, -0.66129625, -0.665659, -0.670001, -0.6743216, -0.6786201, -0.6828959, -0.68714833, -0.6913767, -0.69558036, -0.6997587, -0.703911, -0.70803666, -0.712135, -0.71620536, -0.7202471, -0.7242595, -0.728242, -0.73219395, -0.7361146, -0.7400035, -0.7438598, -0.74768305, -0.7514725, -0.7552275, -0.75894755, -0.7626319, -0.76627994, -0.76989114, -0.77346486, -0.7770004, -0.78049725, -0.7839548, -0.78737247, -0.7907496, -0.7940857, -0.79738015, -0.8006323, -0.8038417, -0.8070078, -0.81012994, -0.8132076, -0.8162403, -0.81922746, -0.8221685, -0.82506305, -0.8279104, -0.83071023, -0.8334619, -0.83616495, -0.83881897, -0.8414234, -0.8439778, -0.8464817, -0.84893465, -0.85133624, -0.853686, -0.85598344, -0.85822827, -0.8604199, -0.8625582, -0.86464256, -0.86667264, -0.8686481, -0.8705686, -0.8724337, -0.87424314, -0.8759966, -0.8776936, -0.87933403, -0.88091743, -0.8824436, -0.8839122, -0.885323, -0.8866757, -0.8879701, -0.8892059, -0.8903829, -0.89150083, -0.8925595, -0.89355886, -0.8944985, -0.8953784, -0.89619833, -0.8969581, -0.8976577, -0.89829683, -0.8988755, -0.8993935, -0.89985085, -0.90024734, -0.90058297, -0.9008577, -0.90107137, -0.9012241, -0.9013157, -0.9013462, -0.9013157, -0.9012241, -0.90107137, -0.9008577, -0.90058297, -0.90024734, -0.89985085, -0.8993935, -0.8988755, -0.89829683, -0.8976577, -0.8969581, -0.89619833, -0.8953784, -0.8944985, -0.89355886, -0.8925595, -0.89150083, -0.8903829, -0.8892059, -0.8879701, -0.8866757, -0.885323, -0.8839122, -0.8824436, -0.88091743, -0.87933403, -0.8776936, -0.8759966, -0.87424314, -0.8724337, -0.8705686, -0.8686481, -0.86667264, -0.86464256, -0.8625582, -0.8604199, -0.85822827, -0.85598344, -0.853686, -0.85133624, -0.84893465, -0.8464817, -0.8439778, -0.8414234, -0.83881897, -0.83616495, -0.8334619, -0.83071023, -0.8279104, -0.82506305, -0.8221685, -0.81922746, -0.8162403, -0.8132076, -0.81012994, -0.8070078, -0.8038417, -0.8006323, -0.79738015, -0.7940857, -0.7907496, -0.78737247, -0.7839548, -0.78049725, -0.7770004, -0.77346486, -0.76989114, -0.76627994, -0.7626319, -0.75894755, -0.7552275, -0.7514725, -0.74768305, -0.7438598, -0.7400035, -0.7361146, -0.73219395, -0.728242, -0.7242595, -0.7202471, -0.71620536, -0.712135, -0.70803666, -0.703911, -0.6997587, -0.69558036, -0.6913767, -0.68714833, -0.6828959, -0.6786201, -0.6743216, -0.670001, -0.665659, -0.66129625, -0.6569134, -0.6525111, -0.64809006, -0.6436509, -0.63919425, -0.63472074, -0.6302311, -0.6257259, -0.6212058, -0.6166715, -0.6121236, -0.6075627, -0.6029895, -0.5984046, -0.5938086, -0.58920217, -0.5845859, -0.5799604, -0.5753263, -0.57068425, -0.5660348, -0.56137854, -0.5567161, -0.5520481, -0.547375, -0.54269755, -0.5380162, -0.5333315, -0.52864414, -0.52395463, -0.51926345, -0.5145712, -0.5098784, -0.50518566, -0.5004934, -0.4958022, -0.49111256, -0.48642498, -0.48173997, -0.47705802, -0.4723796, -0.46770522, -0.46303532, -0.45837036, -0.45371082, -0.44905713, -0.44440976, -0.4397691, -0.43513554, -0.4305096, -0.42589158, -0.42128193, -0.41668105, -0.4120893, -0.40750703, -0.40293464, -0.39837247, -0.39382085, -0.38928014, -0.38475063, -0.3802327, -0.37572658, -0.37123263, -0.3667511, -0.3622823, -0.3578265, -0.35338393, -0.34895486, -0.3445395, -0.34013814, -0.33575097, -0.33137822, -0.32702005, -0.32267672, -0.31834835, -0.31403515, -0.30973727, -0.30545485, -0.30118808, -0.29693705, -0.2927019, -0.28848276, -0.2842797, -0.28009287, -0.2759223, -0.2717681, -0.2676303, -0.263509, -0.25940424, -0.25531605, -0.25124446, -0.24718946, -0.24315108, -0.23912935, -0.23512422, -0.23113568, -0.2271637, -0.22320826, -0.2192693, -0.21534677, -0.2114406, -0.2075507, -0.20367703, -0.19981946, -0.19597791, -0.19215228, -0.18834242, -0.18454823, -0.18076956, -0.1770063, -0.17325827, -0.16952533, -0.16580729, -0.16210401, -0.15841527, -0.15474093, -0.15108074, -0.14743455, -0.1438021, -0.14018321, -0.13657762, -0.13298514, -0.1294055, -0.12583847, -0.1222838, -0.11874122, -0.11521048, -0.1116913, -0.10818342, -0.10468654, -0.10120039, -0.09772466, -0.094259076, -0.09080332, -0.08735709, -0.08392007, -0.080491945, -0.077072404, -0.07366111, -0.07025774, -0.06686196, -0.063473426, -0.060091812, -0.056716766, -0.05334794, -0.049984988, -0.046627555, -0.043275286, -0.03992782, -0.0365848, -0.03324586, -0.02991064, -0.026578771, -0.023249883, -0.019923609, -0.016599577, -0.013277415, -0.00995675, -0.006637209, -0.0033184171, ], [0.0, 0.004977501, 0.009954814, 0.014931753, 0.01990813, 0.024883756, 0.029858446, 0.034832012, 0.039804265, 0.044775024, 0.049744092, 0.05471129, 0.059676427, 0.064639315, 0.06959978, 0.07455761, 0.07951264, 0.08446468, 0.08941353, 0.094359025, 0.09930096, 0.10423916, 0.10917343, 0.11410359, 0.11902946, 0.12395085, 0.12886757, 0.13377944, 0.13868627, 0.14358787, 0.14848408, 0.15337469, 0.15825953, 0.16313842, 0.16801114, 0.17287757, 0.17773747, 0.18259068, 0.18743701, 0.1922763, 0.19710834, 0.20193297, 0.20674998, 0.21155922, 0.21636048, 0.2211536, 0.22593841, 0.2307147, 0.23548229, 0.24024104, 0.24499074, 0.2497312, 0.25446227, 0.25918376, 0.26389548, 0.26859728, 0.27328897, 0.27797034, 0.28264126, 0.28730157, 0.29195103, 0.2965895, 0.3012168, 0.30583277, 0.31043723, 0.31503, 0.31961092, 0.3241798, 0.32873645, 0.33328074, 0.33781248, 0.34233153, 0.34683764, 0.35133073, 0.35581058, 0.36027703, 0.36472994, 0.3691691, 0.37359434, 0.37800553, 0.3824025, 0.38678506, 0.39115307, 0.39550635, 0.39984474, 0.40416807, 0.40847617, 0.4127689, 0.4170461, 0.4213076, 0.42555323, 0.42978284, 0.4339963, 0.43819335, 0.44237396, 0.44653788, 0.45068502, 0.45481518, 0.4589282, 0.46302396, 0.4671023, 0.47116303, 0.47520602, 0.47923112, 0.4832382, 0.48722705, 0.4911976, 0.4951496, 0.499083, 0.5029976, 0.5068933, 0.51076984, 0.5146272, 0.51846516, 0.5222836, 0.5260824, 0.5298614, 0.5336204, 0.53735936, 0.5410781, 0.54477644, 0.5484542, 0.55211145, 0.5557478, 0.5593633, 0.5629577, 0.56653094, 0.5700828, 0.5736132, 0.57712203, 0.58060914, 0.5840744, 0.5875176, 0.59093875, 0.59433764, 0.5977141, 0.60106814, 0.6043995, 0.60770816, 0.61099386, 0.6142566, 0.6174962, 0.6207126, 0.62390554, 0.6270751, 0.63022095, 0.6333431, 0.6364414, 0.63951576, 0.642566, 0.64559215, 0.6485939, 0.6515713, 0.6545241, 0.6574523, 0.6603557, 0.6632343, 0.66608787, 0.6689164, 0.67171973, 0.6744978, 0.67725044, 0.6799776, 0.6826792, 0.685355, 0.6880051, 0.6906292, 0.69322735, 0.6957994, 0.69834524, 0.7008648, 0.703358, 0.7058247, 0.70826477, 0.7106782, 0.7130649, 0.7154248, 0.71775764, 0.72006357, 0.7223423, 0.72459394, 0.7268182, 0.7290152, 0.73118466, 0.7333266, 0.73544097, 0.7375276, 0.73958653, 0.74161756, 0.7436207, 0.7455958, 0.74754286, 0.74946177, 0.7513525, 0.7532149, 0.75504893, 0.7568546, 0.7586317, 0.76038027, 0.7621002, 0.76379144, 0.76545393, 0.76708764, 0.76869243, 0.77026826, 0.7718151, 0.7733329, 0.7748216, 0.7762811, 0.7777114, 0.7791124, 0.780484, 0.7818263, 0.78313917, 0.7844225, 0.78567636, 0.7869006, 0.78809524, 0.78926015, 0.79039544, 0.79150087, 0.79257655, 0.7936224, 0.79463834, 0.7956244, 0.7965805, 0.7975066, 0.79840267, 0.79926866, 0.80010456, 0.80091035, 0.801686, 0.80243146, 0.8031467, 0.8038317, 0.80448645, 0.80511093, 0.8057051, 0.8062689, 0.80680233, 0.8073054, 0.80777806, 0.8082203, 0.8086322, 0.8090136, 0.8093645, 0.809685, 0.80997497, 0.8102345, 0.8104634, 0.8106619, 0.8108299, 0.81096727, 0.81107414, 0.8111505, 0.8111963, 0.8112116, 0.8111963, 0.8111505, 0.81107414, 0.81096727, 0.8108299, 0.8106619, 0.8104634, 0.8102345, 0.80997497, 0.809685, 0.8093645, 0.8090136, 0.8086322, 0.8082203, 0.80777806, 0.8073054, 0.80680233, 0.8062689, 0.8057051, 0.80511093, 0.80448645, 0.8038317, 0.8031467, 0.80243146, 0.801686, 0.80091035, 0.80010456, 0.79926866, 0.79840267, 0.7975066, 0.7965805, 0.7956244, 0.79463834, 0.7936224, 0.79257655, 0.79150087, 0.79039544, 0.78926015, 0.78809524, 0.7869006, 0.78567636, 0.7844225, 0.78313917, 0.7818263, 0.780484, 0.7791124, 0.7777114, 0.7762811, 0.7748216, 0.7733329, 0.7718151, 0.77026826, 0.76869243, 0.76708764, 0.76545393, 0.76379144, 0.7621002, 0.76038027, 0.7586317, 0.7568546, 0.75504893, 0.7532149, 0.7513525, 0.74946177, 0.74754286, 0.7455958, 0.7436207, 0.74161756, 0.73958653, 0.7375276, 0.73544097, 0.7333266, 0.73118466, 0.7290152, 0.7268182, 0.72459394, 0.7223423, 0.72006357, 0.71775764, 0.7154248, 0.7130649, 0.7106782, 0.70826477, 0.7058247, 0.703358, 0.7008648, 0.69834524, 0.6957994, 0.69322735, 0.6906292, 0.6880051, 0.685355, 0.6826792, 0.6799776, 0.67725044, 0.6744978, 0.67171973, 0.6689164, 0.66608787, 0.6632343, 0.6603557, 0.6574523, 0.6545241, 0.6515713, 0.6485939, 0.64559215, 0.642566, 0.63951576, 0.6364414, 0.6333431, 0.63022095, 0.6270751, 0.62390554, 0.6207126, 0.6174962, 0.6142566, 0.61099386, 0.60770816, 0.6043995, 0.60106814, 0.5977141, 0.59433764, 0.59093875, 0.5875176, 0.5840744, 0.58060914, 0.57712203, 0.5736132, 0.5700828, 0.56653094, 0.5629577, 0.5593633, 0.5557478, 0.55211145, 0.5484542, 0.54477644, 0.5410781, 0.53735936, 0.5336204, 0.5298614, 0.5260824, 0.5222836, 0.51846516, 0.5146272, 0.51076984, 0.5068933, 0.5029976, 0.499083, 0.4951496, 0.4911976, 0.48722705, 0.4832382, 0.47923112, 0.47520602, 0.47116303, 0.4671023, 0.46302396, 0.4589282, 0.45481518, 0.45068502, 0.44653788, 0.44237396, 0.43819335, 0.4339963, 0.42978284, 0.42555323, 0.4213076, 0.4170461, 0.4127689, 0.40847617, 0.40416807, 0.39984474, 0.39550635, 0.39115307, 0.38678506, 0.3824025, 0.37800553, 0.37359434, 0.3691691, 0.36472994, 0.36027703, 0.35581058, 0.35133073, 0.34683764, 0.34233153, 0.33781248, 0.33328074, 0.32873645, 0.3241798, 0.31961092, 0.31503, 0.31043723, 0.30583277, 0.3012168, 0.2965895, 0.29195103, 0.28730157, 0.28264126, 0.27797034, 0.27328897, 0.26859728, 0.26389548, 0.25918376, 0.25446227, 0.2497312, 0.24499074, 0.24024104, 0.23548229, 0.2307147, 0.22593841, 0.2211536, 0.21636048, 0.21155922, 0.20674998, 0.20193297, 0.19710834, 0.1922763, 0.18743701, 0.18259068, 0.17773747, 0.17287757, 0.16801114, 0.16313842, 0.15825953, 0.15337469, 0.14848408, 0.14358787, 0.13868627, 0.13377944, 0.12886757, 0.12395085, 0.11902946, 0.11410359, 0.10917343, 0.10423916, 0.09930096, 0.094359025, 0.08941353, 0.08446468, 0.07951264, 0.07455761, 0.06959978, 0.064639315, 0.059676427, 0.05471129, 0.049744092, 0.044775024, 0.039804265, 0.034832012, 0.029858446, 0.024883756, 0.01990813, 0.014931753, 0.009954814, 0.004977501, 9.934477e-17, -0.004977501, -0.009954814, -0.014931753, -0.01990813, -0.024883756, -0.029858446, -0.034832012, -0.039804265, -0.044775024, -0.049744092, -0.05471129, -0.059676427, -0.064639315, -0.06959978, -0.07455761, -0.07951264, -0.08446468, -0.08941353, -0.094359025, -0.09930096, -0.10423916, -0.10917343, -0.11410359, -0.11902946, -0.12395085, -0.12886757, -0.13377944, -0.13868627, -0.14358787, -0.14848408, -0.15337469, -0.15825953, -0.16313842, -0.16801114, -0.17287757, -0.17773747, -0.18259068, -0.18743701, -0.1922763, -0.19710834, -0.20193297, -0.20674998, -0.21155922, -0.21636048, -0.2211536, -0.22593841, -0.2307147, -0.23548229, -0.24024104, -0.24499074, -0.2497312, -0.25446227, -0.25918376, -0.26389548, -0.26859728, -0.27328897, -0.27797034, -0.28264126, -0.28730157, -0.29195103, -0.2965895, -0.3012168, -0.30583277, -0.31043723, -0.31503, -0.31961092, -0.3241798, -0.32873645, -0.33328074, -0.33781248, -0.34233153, -0.34683764, -0.35133073, -0.35581058, -0.36027703, -0.36472994, -0.3691691, -0.37359434, -0.37800553, -0.3824025, -0.38678506, -0.39115307, -0.39550635, -0.39984474, -0.40416807, -0.40847617, -0.4127689, -0.4170461, -0.4213076, -0.42555323, -0.42978284, -0.4339963, -0.43819335, -0.44237396, -0.44653788, -0.45068502, -0.45481518, -0.4589282, -0.46302396, -0.4671023, -0.47116303, -0.47520602, -0.47923112, -0.4832382, -0.48722705, -0.4911976, -0.4951496, -0.499083, -0.5029976, -0.5068933, -0.51076984, -0.5146272, -0.51846516, -0.5222836, -0.5260824, -0.5298614, -0.5336204, -0.53735936, -0.5410781, -0.54477644, -0.5484542, -0.55211145, -0.5557478, -0.5593633, -0.5629577, -0.56653094, -0.5700828, -0.5736132, -0.57712203, -0.58060914, -0.5840744, -0.5875176, -0.59093875, -0.59433764, -0.5977141, -0.60106814, -0.6043995, -0.60770816, -0.61099386, -0.6142566, -0.6174962, -0.6207126, -0.62390554, -0.6270751, -0.63022095, -0.6333431, -0.6364414, -0.63951576, -0.642566, -0.64559215, -0.6485939, -0.6515713, -0.6545241, -0.6574523, -0.6603557, -0.6632343, -0.66608787, -0.6689164, -0.67171973, -0.6744978, -0.67725044, -0.6799776, -0.6826792, -0.685355, -0.6880051, -0.6906292, -0.69322735, -0.6957994, -0.69834524, -0.7008648, -0.703358, -0.7058247, -0.70826477, -0.7106782, -0.7130649, -0.7154248, -0.71775764, -0.72006357, -0.7223423, -0.72459394, -0.7268182, -0.7290152, -0.73118466, -0.7333266, -0.73544097, -0.7375276, -0.73958653, -0.74161756, -0.7436207, -0.7455958, -0.74754286, -0.74946177, -0.7513525, -0.7532149, -0.75504893, -0.7568546, -0.7586317, -0.76038027, -0.7621002, -0.76379144, -0.76545393, -0.76708764, -0.76869243, -0.77026826, -0.7718151, -0.7733329, -0.7748216, -0.7762811, -0.7777114, -0.7791124, -0.780484, -0.7818263, -0.78313917, -0.7844225, -0.78567636, -0.7869006, -0.78809524, -0.78926015, -0.79039544, -0.79150087, -0.79257655, -0.7936224, -0.79463834, -0.7956244, -0.7965805, -0.7975066, -0.79840267, -0.79926866, -0.80010456, -0.80091035, -0.801686, -0.80243146, -0.8031467, -0.8038317, -0.80448645, -0.80511093, -0.8057051, -0.8062689, -0.80680233, -0.8073054, -0.80777806, -0.8082203, -0.8086322, -0.8090136, -0.8093645, -0.809685, -0.80997497, -0.8102345, -0.8104634, -0.8106619, -0.8108299, -0.81096727, -0.81107414, -0.8111505, -0.8111963, -0.8112116, -0.8111963, -0.8111505, -0.81107414, -0.81096727, -0.8108299, -0.8106619, -0.8104634, -0.8102345, -0.80997497, -0.809685, -0.8093645, -0.8090136, -0.8086322, -0.8082203, -0.80777806, -0.8073054, -0.80680233, -0.8062689, -0.8057051, -0.80511093, -0.80448645, -0.8038317, -0.8031467, -0.80243146, -0.801686, -0.80091035, -0.80010456, -0.79926866, -0.79840267, -0.7975066, -0.7965805, -0.7956244, -0.79463834, -0.7936224, -0.79257655, -0.79150087, -0.79039544, -0.78926015, -0.78809524, -0.7869006, -0.78567636, -0.7844225, -0.78313917, -0.7818263, -0.780484, -0.7791124, -0.7777114, -0.7762811, -0.7748216, -0.7733329, -0.7718151, -0.77026826, -0.76869243, -0.76708764, -0.76545393, -0.76379144, -0.7621002, -0.76038027, -0.7586317, -0.7568546, -0.75504893, -0.7532149, -0.7513525, -0.74946177, -0.74754286, -0.7455958, -0.7436207, -0.74161756, -0.73958653, -0.7375276, -0.73544097, -0.7333266, -0.73118466, -0.7290152, -0.7268182, -0.72459394, -0.7223423, -0.72006357, -0.71775764, -0.7154248, -0.7130649, -0.7106782, -0.70826477, -0.7058247, -0.703358, -0.7008648, -0.69834524, -0.6957994, -0.69322735, -0.6906292, -0.6880051, -0.685355, -0.6826792, -0.6799776, -0.67725044, -0.6744978, -0.67171973, -0.6689164, -0.66608787, -0.6632343, -0.6603557, -0.6574523, -0.6545241, -0.6515713, -0.6485939, -0.64559215, -0.642566, -0.63951576, -0.6364414, -0.6333431, -0.63022095, -0.6270751, -0.62390554, -0.6207126, -0.6174962, -0.6142566, -0.61099386, -0.60770816, -0.6043995, -0.60106814, -0.5977141, -0.59433764, -0.59093875, -0.5875176, -0.5840744, -0.58060914, -0.57712203, -0.5736132, -0.5700828, -0.56653094, -0.5629577, -0.5593633, -0.5557478, -0.55211145, -0.5484542, -0.54477644, -0.5410781, -0.53735936, -0.5336204, -0.5298614, -0.5260824, -0.5222836, -0.51846516, -0.5146272, -0.51076984, -0.5068933, -0.5029976, -0.499083, -0.4951496, -0.4911976, -0.48722705, -0.4832382, -0.47923112, -0.47520602, -0.47116303, -0.4671023, -0.46302396, -0.4589282, -0.45481518, -0.45068502, -0.44653788, -0.44237396, -0.43819335, -0.4339963, -0.42978284, -0.42555323, -0.4213076, -0.4170461, -0.4127689, -0.40847617, -0.40416807, -0.39984474, -0.39550635, -0.39115307, -0.38678506, -0.3824025, -0.37800553, -0.37359434, -0.3691691, -0.36472994, -0.36027703, -0.35581058, -0.35133073, -0.34683764, -0.34233153, -0.33781248, -0.33328074, -0.32873645, -0.3241798, -0.31961092, -0.31503, -0.31043723, -0.30583277, -0.3012168, -0.2965895, -0.29195103, -0.28730157, -0.28264126, -0.27797034, -0.27328897, -0.26859728, -0.26389548, -0.25918376, -0.25446227, -0.2497312, -0.24499074, -0.24024104, -0.23548229, -0.2307147, -0.22593841, -0.2211536, -0.21636048, -0.21155922, -0.20674998, -0.20193297, -0.19710834, -0.1922763, -0.18743701, -0.18259068, -0.17773747, -0.17287757, -0.16801114, -0.16313842, -0.15825953, -0.15337469, -0.14848408, -0.14358787, -0.13868627, -0.13377944, -0.12886757, -0.12395085, -0.11902946, -0.11410359, -0.10917343, -0.10423916, -0.09930096, -0.094359025, -0.08941353, -0.08446468, -0.07951264, -0.07455761, -0.06959978, -0.064639315, -0.059676427, -0.05471129, -0.049744092, -0.044775024, -0.039804265, -0.034832012, -0.029858446, -0.024883756, -0.01990813, -0.014931753, -0.009954814, -0.004977501, ], [0.0, 0.004977501, 0.009954814, 0.014931753, 0.01990813, 0.024883756, 0.029858446, 0.034832012, 0.039804265, 0.044775024, 0.049744092, 0.05471129, 0.059676427, 0.064639315, 0.06959978, 0.07455761, 0.07951264, 0.08446468, 0.08941353, 0.094359025, 0.09930096, 0.10423916, 0.10917343, 0.11410359, 0.11902946, 0.12395085, 0.12886757, 0.13377944, 0.13868627, 0.14358787, 0.14848408, 0.15337469, 0.15825953, 0.16313842, 0.16801114, 0.17287757, 0.17773747, 0.18259068, 0.18743701, 0.1922763, 0.19710834, 0.20193297, 0.20674998, 0.21155922, 0.21636048, 0.2211536, 0.22593841, 0.2307147, 0.23548229, 0.24024104, 0.24499074, 0.2497312, 0.25446227, 0.25918376, 0.26389548, 0.26859728, 0.27328897, 0.27797034, 0.28264126, 0.28730157, 0.29195103, 0.2965895, 0.3012168, 0.30583277, 0.31043723, 0.31503, 0.31961092, 0.3241798, 0.32873645, 0.33328074, 0.33781248, 0.34233153, 0.34683764, 0.35133073, 0.35581058, 0.36027703, 0.36472994, 0.3691691, 0.37359434, 0.37800553, 0.3824025, 0.38678506, 0.39115307, 0.39550635, 0.39984474, 0.40416807, 0.40847617, 0.4127689, 0.4170461, 0.4213076, 0.42555323, 0.42978284, 0.4339963, 0.43819335, 0.44237396, 0.44653788, 0.45068502, 0.45481518, 0.4589282, 0.46302396, 0.4671023, 0.47116303, 0.47520602, 0.47923112, 0.4832382, 0.48722705, 0.4911976, 0.4951496, 0.499083, 0.5029976, 0.5068933, 0.51076984, 0.5146272, 0.51846516, 0.5222836, 0.5260824, 0.5298614, 0.5336204, 0.53735936, 0.5410781, 0.54477644, 0.5484542, 0.55211145, 0.5557478, 0.5593633, 0.5629577, 0.56653094, 0.5700828, 0.5736132, 0.57712203, 0.58060914, 0.5840744, 0.5875176, 0.59093875, 0.59433764, 0.5977141, 0.60106814, 0.6043995, 0.60770816, 0.61099386, 0.6142566, 0.6174962, 0.6207126, 0.62390554, 0.6270751, 0.63022095, 0.6333431, 0.6364414, 0.63951576, 0.642566, 0.64559215, 0.6485939, 0.6515713, 0.6545241, 0.6574523, 0.6603557, 0.6632343, 0.66608787, 0.6689164, 0.67171973, 0.6744978, 0.67725044, 0.6799776, 0.6826792, 0.685355, 0.6880051, 0.6906292, 0.69322735, 0.6957994, 0.69834524, 0.7008648, 0.703358, 0.7058247, 0.70826477, 0.7106782, 0.7130649, 0.7154248, 0.71775764, 0.72006357, 0.7223423, 0.72459394, 0.7268182, 0.7290152, 0.73118466, 0.7333266, 0.73544097, 0.7375276, 0.73958653, 0.74161756, 0.7436207, 0.7455958, 0.74754286, 0.74946177, 0.7513525, 0.7532149, 0.75504893, 0.7568546, 0.7586317, 0.76038027, 0.7621002, 0.76379144, 0.76545393, 0.76708764, 0.76869243, 0.77026826, 0.7718151, 0.7733329, 0.7748216, 0.7762811, 0.7777114, 0.7791124, 0.780484, 0.7818263, 0.78313917, 0.7844225, 0.78567636, 0.7869006, 0.78809524, 0.78926015, 0.79039544, 0.79150087, 0.79257655, 0.7936224, 0.79463834, 0.7956244, 0.7965805, 0.7975066, 0.79840267, 0.79926866, 0.80010456, 0.80091035, 0.801686, 0.80243146, 0.8031467, 0.8038317, 0.80448645, 0.80511093, 0.8057051, 0.8062689, 0.80680233, 0.8073054, 0.80777806, 0.8082203, 0.8086322, 0.8090136, 0.8093645, 0.809685, 0.80997497, 0.8102345, 0.8104634, 0.8106619, 0.8108299, 0.81096727, 0.81107414, 0.8111505, 0.8111963, 0.8112116, 0.8111963, 0.8111505, 0.81107414, 0.81096727, 0.8108299, 0.8106619, 0.8104634, 0.8102345, 0.80997497, 0.809685, 0.8093645, 0.8090136, 0.8086322, 0.8082203, 0.80777806, 0.8073054, 0.80680233, 0.8062689, 0.8057051, 0.80511093, 0.80448645, 0.8038317, 0.8031467, 0.80243146, 0.801686, 0.80091035, 0.80010456, 0.79926866, 0.79840267, 0.7975066, 0.7965805, 0.7956244, 0.79463834, 0.7936224, 0.79257655, 0.79150087, 0.79039544, 0.78926015, 0.78809524, 0.7869006, 0.78567636, 0.7844225, 0.78313917, 0.7818263, 0.780484, 0.7791124, 0.7777114, 0.7762811, 0.7748216, 0.7733329, 0.7718151, 0.77026826, 0.76869243, 0.76708764, 0.76545393, 0.76379144, 0.7621002, 0.76038027, 0.7586317, 0.7568546, 0.75504893, 0.7532149, 0.7513525, 0.74946177, 0.74754286, 0.7455958, 0.7436207, 0.74161756, 0.73958653, 0.7375276, 0.73544097, 0.7333266, 0.73118466, 0.7290152, 0.7268182, 0.72459394, 0.7223423, 0.72006357, 0.71775764, 0.7154248, 0.7130649, 0.7106782, 0.70826477, 0.7058247, 0.703358, 0.7008648, 0.69834524, 0.6957994, 0.69322735, 0.6906292, 0.6880051, 0.685355, 0.6826792, 0.6799776, 0.67725044, 0.6744978, 0.67171973, 0.6689164, 0.66608787, 0.6632343, 0.6603557, 0.6574523, 0.6545241, 0.6515713, 0.6485939, 0.64559215, 0.642566, 0.63951576, 0.6364414, 0.6333431, 0.63022095, 0.6270751, 0.62390554, 0.6207126, 0.6174962, 0.6142566, 0.61099386, 0.60770816, 0.6043995, 0.60106814, 0.5977141, 0.59433764, 0.59093875, 0.5875176, 0.5840744, 0.58060914, 0.57712203, 0.5736132, 0.5700828, 0.56653094, 0.5629577, 0.5593633, 0.5557478, 0.55211145, 0.5484542, 0.54477644, 0.5410781, 0.53735936, 0.5336204, 0.5298614, 0.5260824, 0.5222836, 0.51846516, 0.5146272, 0.51076984, 0.5068933, 0.5029976, 0.499083, 0.4951496, 0.4911976, 0.48722705, 0.4832382, 0.47923112, 0.47520602, 0.47116303, 0.4671023, 0.46302396, 0.4589282, 0.45481518, 0.45068502, 0.44653788, 0.44237396, 0.43819335, 0.4339963, 0.42978284, 0.42555323, 0.4213076, 0.4170461, 0.4127689, 0.40847617, 0.40416807, 0.39984474, 0.39550635, 0.39115307, 0.38678506, 0.3824025, 0.37800553, 0.37359434, 0.3691691, 0.36472994, 0.36027703, 0.35581058, 0.35133073, 0.34683764, 0.34233153, 0.33781248, 0.33328074, 0.32873645, 0.3241798, 0.31961092, 0.31503, 0.31043723, 0.30583277, 0.3012168, 0.2965895, 0.29195103, 0.28730157, 0.28264126, 0.27797034, 0.27328897, 0.26859728, 0.26389548, 0.25918376, 0.25446227, 0.2497312, 0.24499074, 0.24024104, 0.23548229, 0.2307147, 0.22593841, 0.2211536, 0.21636048, 0.21155922, 0.20674998, 0.20193297, 0.19710834, 0.1922763, 0.18743701, 0.18259068, 0.17773747, 0.17287757, 0.16801114, 0.16313842, 0.15825953, 0.15337469, 0.14848408, 0.14358787, 0.13868627, 0.13377944, 0.12886757, 0.12395085, 0.11902946, 0.11410359, 0.10917343, 0.10423916, 0.09930096, 0.094359025, 0.08941353, 0.08446468, 0.07951264, 0.07455761, 0.06959978, 0.064639315, 0.059676427, 0.05471129, 0.049744092, 0.044775024, 0.039804265, 0.034832012, 0.029858446, 0.024883756, 0.01990813, 0.014931753, 0.009954814, 0.004977501, 9.934477e-17, -0.004977501, -0.009954814, -0.014931753, -0.01990813, -0.024883756, -0.029858446, -0.034832012, -0.039804265, -0.044775024, -0.049744092, -0.05471129, -0.059676427, -0.064639315, -0.06959978, -0.07455761, -0.07951264, -0.08446468, -0.08941353, -0.094359025, -0.09930096, -0.10423916, -0.10917343, -0.11410359, -0.11902946, -0.12395085, -0.12886757, -0.13377944, -0.13868627, -0.14358787, -0.14848408, -0.15337469, -0.15825953, -0.16313842, -0.16801114, -0.17287757, -0.17773747, -0.18259068, -0.18743701, -0.1922763, -0.19710834, -0.20193297, -0.20674998, -0.21155922, -0.21636048, -0.2211536, -0.22593841, -0.2307147, -0.23548229, -0.24024104, -0.24499074, -0.2497312, -0.25446227, -0.25918376, -0.26389548, -0.26859728, -0.27328897, -0.27797034, -0.28264126, -0.28730157, -0.29195103, -0.2965895, -0.3012168, -0.30583277, -0.31043723, -0.31503, -0.31961092, -0.3241798, -0.32873645, -0.33328074, -0.33781248, -0.34233153, -0.34683764, -0.35133073, -0.35581058, -0.36027703, -0.36472994, -0.3691691, -0.37359434, -0.37800553, -0.3824025, -0.38678506, -0.39115307, -0.39550635, -0.39984474, -0.40416807, -0.40847617, -0.4127689, -0.4170461, -0.4213076, -0.42555323, -0.42978284, -0.4339963, -0.43819335, -0.44237396, -0.44653788, -0.45068502, -0.45481518, -0.4589282, -0.46302396, -0.4671023, -0.47116303, -0.47520602, -0.47923112, -0.4832382, -0.48722705, -0.4911976, -0.4951496, -0.499083, -0.5029976, -0.5068933, -0.51076984, -0.5146272, -0.51846516, -0.5222836, -0.5260824, -0.5298614, -0.5336204, -0.53735936, -0.5410781, -0.54477644, -0.5484542, -0.55211145, -0.5557478, -0.5593633, -0.5629577, -0.56653094, -0.5700828, -0.5736132, -0.57712203, -0.58060914, -0.5840744, -0.5875176, -0.59093875, -0.59433764, -0.5977141, -0.60106814, -0.6043995, -0.60770816, -0.61099386, -0.6142566, -0.6174962, -0.6207126, -0.62390554, -0.6270751, -0.63022095, -0.6333431, -0.6364414, -0.63951576, -0.642566, -0.64559215, -0.6485939, -0.6515713, -0.6545241, -0.6574523, -0.6603557, -0.6632343, -0.66608787, -0.6689164, -0.67171973, -0.6744978, -0.67725044, -0.6799776, -0.6826792, -0.685355, -0.6880051, -0.6906292, -0.69322735, -0.6957994, -0.69834524, -0.7008648, -0.703358, -0.7058247, -0.70826477, -0.7106782, -0.7130649, -0.7154248, -0.71775764, -0.72006357, -0.7223423, -0.72459394, -0.7268182, -0.7290152, -0.73118466, -0.7333266, -0.73544097, -0.7375276, -0.73958653, -0.74161756, -0.7436207, -0.7455958, -0.74754286, -0.74946177, -0.7513525, -0.7532149, -0.75504893, -0.7568546, -0.7586317, -0.76038027, -0.7621002, -0.76379144, -0.76545393, -0.76708764, -0.76869243, -0.77026826, -0.7718151, -0.7733329, -0.7748216, -0.7762811, -0.7777114, -0.7791124, -0.780484, -0.7818263, -0.78313917, -0.7844225, -0.78567636, -0.7869006, -0.78809524, -0.78926015, -0.79039544, -0.79150087, -0.79257655, -0.7936224, -0.79463834, -0.7956244, -0.7965805, -0.7975066, -0.79840267, -0.79926866, -0.80010456, -0.80091035, -0.801686, -0.80243146, -0.8031467, -0.8038317, -0.80448645, -0.80511093, -0.8057051, -0.8062689, -0.80680233, -0.8073054, -0.80777806, -0.8082203, -0.8086322, -0.8090136, -0.8093645, -0.809685, -0.80997497, -0.8102345, -0.8104634, -0.8106619, -0.8108299, -0.81096727, -0.81107414, -0.8111505, -0.8111963, -0.8112116, -0.8111963, -0.8111505, -0.81107414, -0.81096727, -0.8108299, -0.8106619, -0.8104634, -0.8102345, -0.80997497, -0.809685, -0.8093645, -0.8090136, -0.8086322, -0.8082203, -0.80777806, -0.8073054, -0.80680233, -0.8062689, -0.8057051, -0.80511093, -0.80448645, -0.8038317, -0.8031467, -0.80243146, -0.801686, -0.80091035, -0.80010456, -0.79926866, -0.79840267, -0.7975066, -0.7965805, -0.7956244, -0.79463834, -0.7936224, -0.79257655, -0.79150087, -0.79039544, -0.78926015, -0.78809524, -0.7869006, -0.78567636, -0.7844225, -0.78313917, -0.7818263, -0.780484, -0.7791124, -0.7777114, -0.7762811, -0.7748216, -0.7733329, -0.7718151, -0.77026826, -0.76869243, -0.76708764, -0.76545393, -0.76379144, -0.7621002, -0.76038027, -0.7586317, -0.7568546, -0.75504893, -0.7532149, -0.7513525, -0.74946177, -0.74754286, -0.7455958, -0.7436207, -0.74161756, -0.73958653, -0.7375276, -0.73544097, -0.7333266, -0.73118466, -0.7290152, -0.7268182, -0.72459394, -0.7223423, -0.72006357, -0.71775764, -0.7154248, -0.7130649, -0.7106782, -0.70826477, -0.7058247, -0.703358, -0.7008648, -0.69834524, -0.6957994, -0.69322735, -0.6906292, -0.6880051, -0.685355, -0.6826792, -0.6799776, -0.67725044, -0.6744978, -0.67171973, -0.6689164, -0.66608787, -0.6632343, -0.6603557, -0.6574523, -0.6545241, -0.6515713, -0.6485939, -0.64559215, -0.642566, -0.63951576, -0.6364414, -0.6333431, -0.63022095, -0.6270751, -0.62390554, -0.6207126, -0.6174962, -0.6142566, -0.61099386, -0.60770816, -0.6043995, -0.60106814, -0.5977141, -0.59433764, -0.59093875, -0.5875176, -0.5840744, -0.58060914, -0.57712203, -0.5736132, -0.5700828, -0.56653094, -0.5629577, -0.5593633, -0.5557478, -0.55211145, -0.5484542, -0.54477644, -0.5410781, -0.53735936, -0.5336204, -0.5298614, -0.5260824, -0.5222836, -0.51846516, -0.5146272, -0.51076984, -0.5068933, -0.5029976, -0.499083, -0.4951496, -0.4911976, -0.48722705, -0.4832382, -0.47923112, -0.47520602, -0.47116303, -0.4671023, -0.46302396, -0.4589282, -0.45481518, -0.45068502, -0.44653788, -0.44237396, -0.43819335, -0.4339963, -0.42978284, -0.42555323, -0.4213076, -0.4170461, -0.4127689, -0.40847617, -0.40416807, -0.39984474, -0.39550635, -0.39115307, -0.38678506, -0.3824025, -0.37800553, -0.37359434, -0.3691691, -0.36472994, -0.36027703, -0.35581058, -0.35133073, -0.34683764, -0.34233153, -0.33781248, -0.33328074, -0.32873645, -0.3241798, -0.31961092, -0.31503, -0.31043723, -0.30583277, -0.3012168, -0.2965895, -0.29195103, -0.28730157, -0.28264126, -0.27797034, -0.27328897, -0.26859728, -0.26389548, -0.25918376, -0.25446227, -0.2497312, -0.24499074, -0.24024104, -0.23548229, -0.2307147, -0.22593841, -0.2211536, -0.21636048, -0.21155922, -0.20674998, -0.20193297, -0.19710834, -0.1922763, -0.18743701, -0.18259068, -0.17773747, -0.17287757, -0.16801114, -0.16313842, -0.15825953, -0.15337469, -0.14848408, -0.14358787, -0.13868627, -0.13377944, -0.12886757, -0.12395085, -0.11902946, -0.11410359, -0.10917343, -0.10423916, -0.09930096, -0.094359025, -0.08941353, -0.08446468, -0.07951264, -0.07455761, -0.06959978, -0.064639315, -0.059676427, -0.05471129, -0.049744092, -0.044775024, -0.039804265, -0.034832012, -0.029858446, -0.024883756, -0.01990813, -0.014931753, -0.009954814, -0.004977501, ], ];
@@ -0,0 +1,2 @@
+#[allow(clippy::approx_constant)]
+pub static TRIANGLE_F64: [[f64; 1024]; 10] = [[0.0, 0.003909344559824667, 0.007818688971301347, 0.011728033679501943, 0.015637377942546826, 0.019546722799263037, 0.023456066913680466, 0.02736541191916408, 0.031274755884646115, 0.03518410103926131, 0.039093444855387176, 0.043002790159611406, 0.04691213382584689, 0.050821479280271614, 0.05473082279596776, 0.05864016840129955, 0.06254951176569172, 0.06645885752275384, 0.07036820073495996, 0.07427754664469328, 0.07818688970371283, 0.08209623576717841, 0.08600557867188997, 0.08991492489027006, 0.09382426763942929, 0.09773361401403068, 0.10164295660626813, 0.10555230313852376, 0.10946164557234221, 0.11337099226381435, 0.11728033453758567, 0.12118968138996881, 0.12509902350193164, 0.12900837051705544, 0.1329177124653104, 0.1368270596451443, 0.14073640142765137, 0.14464574877430703, 0.14855509038888154, 0.15246443790461803, 0.15637377934892557, 0.16028312703615383, 0.16419246830770576, 0.16810181616899278, 0.17201115726514193, 0.17592050530321726, 0.17982984622115109, 0.1837391944389114, 0.18764853517564747, 0.19155788357616235, 0.19546722412854178, 0.19937657271506137, 0.20328591307974145, 0.20719526185570275, 0.21110460202914932, 0.21501395099818488, 0.2189232909766679, 0.22283264014260867, 0.2267419799221903, 0.23065132928908202, 0.23456066886560833, 0.23847001843771629, 0.24237935780680825, 0.2462887075886263, 0.25019804674567253, 0.2541073967419348, 0.2580167356820755, 0.2619260858977682, 0.26583542461588794, 0.26974477505625966, 0.2736541135469731, 0.2775634642175488, 0.2814728024751877, 0.28538215338178285, 0.2892914914003821, 0.29320084254911516, 0.2971101803223982, 0.30101953171970797, 0.3049288692410692, 0.308838220893732, 0.3127475581562208, 0.31665691007136687, 0.32056624706766784, 0.3244755992528015, 0.32838493597521584, 0.3322942884382375, 0.33620362487865957, 0.3401129776278846, 0.3440223137777809, 0.34793166682196686, 0.35184100267235074, 0.3557503560207202, 0.35965969156212535, 0.36356904522439704, 0.3674783804468471, 0.3713877344332612, 0.3752970693262422, 0.37920642364759505, 0.3831157582000201, 0.38702511286769875, 0.3909344470678714, 0.3948438020938893, 0.3987531359294683, 0.402662491326507, 0.40657182478446163, 0.4104811805659125, 0.41439051363247714, 0.41829986981249, 0.422209202473119, 0.426118559066651, 0.4300278913059615, 0.43393724832883335, 0.4378465801305515, 0.44175593759950704, 0.4456652689464044, 0.4495746268791736, 0.4534839577529987, 0.4573933161683724, 0.4613026465497795, 0.46521200546768066, 0.4691213353361441, 0.47303069477771725, 0.4769400241114541, 0.48084938409914824, 0.48475871287501565, 0.4886680734326939, 0.4925774016260851, 0.49648676277912573, 0.5003960903638592, 0.504305452139277, 0.5082147790874721, 0.512124141514049, 0.516033467795985, 0.5199428309044183, 0.5238521564883865, 0.5277615203114381, 0.5316708451635784, 0.5355802097362506, 0.5394895338203651, 0.5433988991801023, 0.5473082224574534, 0.5512175886443411, 0.5551269110734333, 0.5590362781304394, 0.5629455996667699, 0.5668549676400022, 0.5707642882357846, 0.5746736571747836, 0.5785829767786462, 0.5824923467366981, 0.5864016652933449, 0.5903110363278494, 0.594220353777681, 0.5981297259505457, 0.6020390422292347, 0.6059484156073242, 0.6098577306453419, 0.6137671053009803, 0.6176764190230667, 0.6215857950346021, 0.6254951073591604, 0.6294044848116045, 0.6333137956500307, 0.6372231746357746, 0.6411324838916858, 0.6450418645113164, 0.6489511720796926, 0.6528605544429144, 0.6567698602091011, 0.6606792444357956, 0.6645885482743835, 0.6684979344958091, 0.6724072362693488, 0.6763166246295187, 0.6802259241870386, 0.6841353148443015, 0.6880446120196169, 0.6919540051484869, 0.6958632997582311, 0.6997726955514952, 0.7036819873928457, 0.7075913860640198, 0.7115006749120578, 0.7154100766982361, 0.7193193623028565, 0.723228767468058, 0.7271380495503448, 0.7310474583894448, 0.7349567366374105, 0.7388661494807702, 0.7427754235443071, 0.7466848407632759, 0.7505941102481593, 0.7545035322616258, 0.758412796722344, 0.7623222240045886, 0.7662314829357345, 0.770140916025887, 0.7740501688517477, 0.7779596083652603, 0.7818688544271597, 0.7857783010697925, 0.7896875396106012, 0.7935969941956114, 0.7975062243406479, 0.8014156878100482, 0.8053249085433623, 0.8092343819944443, 0.8131435921291015, 0.8170530768477816, 0.8209622749883425, 0.8248717724914969, 0.8287809569861477, 0.8326904690758743, 0.8365996379547431, 0.8405091667886763, 0.8444183176834515, 0.8483278658669392, 0.8522369959048142, 0.8561465666133528, 0.8600556722751956, 0.8639652694193267, 0.8678743463472004, 0.871783974798141, 0.8756930175296825, 0.879602683433434, 0.8835116850285623, 0.887421396251847, 0.8913303477571658, 0.8952401145344597, 0.8991490041967046, 0.9030588400927573, 0.90696765217257, 0.9108775755555066, 0.9147862884829882, 0.9186963248542894, 0.9226049082573022, 0.9265150940816825, 0.9304235037934355, 0.9343338930883748, 0.9382420623296588, 0.9421527386454067, 0.9460605614709243, 0.9499716612008211, 0.9538789589663664, 0.9577907207704905, 0.9616971672282224, 0.9656100492942349, 0.9695149798847839, 0.9734299845912422, 0.9773318119558716, 0.9812516147065635, 0.9851454393807965, 0.9890801248709099, 0.9929411050917855, 0.9969753576360979, 1.0, 0.9969753576360981, 0.9929411050917855, 0.9890801248709101, 0.9851454393807965, 0.9812516147065633, 0.9773318119558715, 0.9734299845912424, 0.9695149798847841, 0.9656100492942349, 0.9616971672282224, 0.9577907207704905, 0.9538789589663665, 0.9499716612008213, 0.9460605614709244, 0.9421527386454065, 0.9382420623296591, 0.9343338930883746, 0.9304235037934357, 0.9265150940816825, 0.9226049082573022, 0.9186963248542894, 0.9147862884829882, 0.910877575555507, 0.9069676521725702, 0.9030588400927573, 0.8991490041967048, 0.8952401145344597, 0.891330347757166, 0.8874213962518472, 0.8835116850285626, 0.8796026834334338, 0.8756930175296825, 0.871783974798141, 0.8678743463472002, 0.8639652694193267, 0.8600556722751956, 0.8561465666133529, 0.8522369959048142, 0.8483278658669395, 0.8444183176834517, 0.840509166788676, 0.8365996379547435, 0.8326904690758744, 0.8287809569861477, 0.8248717724914971, 0.8209622749883427, 0.8170530768477818, 0.8131435921291015, 0.8092343819944441, 0.8053249085433623, 0.8014156878100482, 0.797506224340648, 0.7935969941956115, 0.7896875396106013, 0.7857783010697927, 0.7818688544271598, 0.7779596083652602, 0.7740501688517475, 0.770140916025887, 0.7662314829357345, 0.7623222240045887, 0.7584127967223443, 0.7545035322616256, 0.7505941102481595, 0.7466848407632759, 0.7427754235443071, 0.7388661494807703, 0.7349567366374106, 0.7310474583894448, 0.727138049550345, 0.7232287674680579, 0.7193193623028569, 0.7154100766982359, 0.7115006749120582, 0.70759138606402, 0.7036819873928458, 0.6997726955514955, 0.6958632997582311, 0.691954005148487, 0.6880446120196169, 0.6841353148443019, 0.6802259241870386, 0.6763166246295187, 0.6724072362693488, 0.6684979344958094, 0.6645885482743836, 0.6606792444357955, 0.6567698602091013, 0.652860554442914, 0.6489511720796926, 0.6450418645113167, 0.6411324838916862, 0.6372231746357747, 0.6333137956500308, 0.6294044848116047, 0.6254951073591605, 0.6215857950346022, 0.6176764190230662, 0.6137671053009806, 0.6098577306453418, 0.6059484156073242, 0.6020390422292345, 0.5981297259505461, 0.5942203537776809, 0.5903110363278501, 0.5864016652933449, 0.582492346736698, 0.5785829767786462, 0.5746736571747832, 0.5707642882357848, 0.566854967640002, 0.5629455996667699, 0.5590362781304395, 0.5551269110734337, 0.5512175886443411, 0.5473082224574536, 0.5433988991801025, 0.539489533820365, 0.5355802097362508, 0.5316708451635782, 0.5277615203114382, 0.5238521564883865, 0.5199428309044185, 0.5160334677959849, 0.5121241415140494, 0.5082147790874724, 0.5043054521392771, 0.5003960903638595, 0.49648676277912557, 0.4925774016260852, 0.4886680734326942, 0.4847587128750154, 0.48084938409914835, 0.47694002411145425, 0.47303069477771736, 0.4691213353361441, 0.46521200546768093, 0.4613026465497795, 0.4573933161683724, 0.45348395775299843, 0.4495746268791736, 0.44566526894640457, 0.44175593759950704, 0.4378465801305516, 0.4339372483288336, 0.43002789130596136, 0.4261185590666513, 0.42220920247311905, 0.41829986981249, 0.4143905136324771, 0.4104811805659124, 0.40657182478446174, 0.40266249132650705, 0.3987531359294685, 0.39484380209388936, 0.3909344470678714, 0.38702511286769853, 0.3831157582000201, 0.37920642364759516, 0.3752970693262421, 0.37138773443326134, 0.367478380446847, 0.3635690452243972, 0.3596596915621254, 0.35575035602072047, 0.3518410026723508, 0.34793166682196697, 0.34402231377778103, 0.3401129776278848, 0.33620362487865973, 0.33229428843823744, 0.32838493597521573, 0.32447559925280123, 0.3205662470676679, 0.31665691007136676, 0.31274755815622085, 0.3088382208937319, 0.3049288692410694, 0.3010195317197078, 0.2971101803223986, 0.2932008425491153, 0.289291491400382, 0.28538215338178285, 0.2814728024751876, 0.277563464217549, 0.27365411354697317, 0.2697447750562599, 0.26583542461588794, 0.26192608589776845, 0.25801673568207567, 0.25410739674193505, 0.25019804674567264, 0.24628870758862625, 0.24237935780680844, 0.2384700184377164, 0.2345606688656085, 0.23065132928908202, 0.22674197992219042, 0.22283264014260867, 0.2189232909766682, 0.21501395099818485, 0.21110460202914955, 0.20719526185570303, 0.20328591307974128, 0.19937657271506154, 0.19546722412854173, 0.19155788357616252, 0.18764853517564759, 0.18373919443891146, 0.17982984622115114, 0.17592050530321748, 0.17201115726514202, 0.16810181616899314, 0.16419246830770592, 0.1602831270361538, 0.15637377934892577, 0.15246443790461803, 0.14855509038888157, 0.14464574877430694, 0.1407364014276516, 0.13682705964514422, 0.13291771246531067, 0.12900837051705555, 0.12509902350193167, 0.12118968138996898, 0.1172803345375856, 0.11337099226381453, 0.10946164557234218, 0.10555230313852379, 0.10164295660626797, 0.09773361401403088, 0.0938242676394293, 0.08991492489027018, 0.08600557867189004, 0.0820962357671783, 0.07818688970371293, 0.07427754664469327, 0.07036820073496022, 0.06645885752275388, 0.06254951176569178, 0.05864016840129941, 0.05473082279596784, 0.050821479280271656, 0.0469121338258471, 0.043002790159611476, 0.03909344485538706, 0.0351841010392614, 0.03127475588464615, 0.027365411919164345, 0.02345606691368054, 0.019546722799263158, 0.015637377942546757, 0.011728033679502205, 0.007818688971301384, 0.0039093445598249515, 4.6958925833615415e-18, -0.0039093445598248, -0.007818688971301224, -0.011728033679502032, -0.015637377942546604, -0.01954672279926296, -0.02345606691368036, -0.02736541191916419, -0.03127475588464598, -0.035184101039261284, -0.03909344485538694, -0.043002790159611406, -0.04691213382584696, -0.05082147928027146, -0.05473082279596769, -0.05864016840129932, -0.06254951176569169, -0.06645885752275359, -0.07036820073496004, -0.07427754664469309, -0.07818688970371282, -0.08209623576717814, -0.08600557867188997, -0.08991492489027009, -0.09382426763942915, -0.09773361401403072, -0.10164295660626788, -0.10555230313852368, -0.10946164557234214, -0.11337099226381435, -0.11728033453758559, -0.12118968138996886, -0.12509902350193153, -0.12900837051705535, -0.13291771246531048, -0.13682705964514394, -0.1407364014276514, -0.14464574877430678, -0.14855509038888148, -0.1524644379046179, -0.1563737793489256, -0.16028312703615363, -0.16419246830770579, -0.16810181616899286, -0.17201115726514188, -0.17592050530321734, -0.17982984622115095, -0.18373919443891132, -0.18764853517564725, -0.1915578835761624, -0.19546722412854162, -0.19937657271506143, -0.203285913079741, -0.20719526185570297, -0.21110460202914946, -0.21501395099818457, -0.21892329097666807, -0.2228326401426085, -0.2267419799221903, -0.2306513292890818, -0.23456066886560828, -0.23847001843771626, -0.2423793578068081, -0.2462887075886261, -0.2501980467456725, -0.2541073967419349, -0.25801673568207556, -0.2619260858977684, -0.2658354246158878, -0.26974477505625954, -0.27365411354697294, -0.2775634642175489, -0.28147280247518747, -0.2853821533817828, -0.2892914914003817, -0.29320084254911505, -0.29711018032239833, -0.30101953171970774, -0.3049288692410695, -0.308838220893732, -0.3127475581562208, -0.3166569100713664, -0.32056624706766773, -0.3244755992528012, -0.32838493597521556, -0.3322942884382374, -0.33620362487865957, -0.3401129776278845, -0.344022313777781, -0.3479316668219669, -0.3518410026723507, -0.35575035602072025, -0.3596596915621251, -0.36356904522439704, -0.3674783804468468, -0.3713877344332611, -0.37529706932624207, -0.3792064236475949, -0.3831157582000201, -0.3870251128676985, -0.3909344470678716, -0.3948438020938893, -0.39875313592946854, -0.4026624913265069, -0.40657182478446147, -0.4104811805659122, -0.41439051363247714, -0.4182998698124901, -0.42220920247311855, -0.4261185590666511, -0.43002789130596136, -0.43393724832883335, -0.43784658013055144, -0.4417559375995069, -0.4456652689464045, -0.44957462687917343, -0.45348395775299843, -0.45739331616837214, -0.4613026465497795, -0.4652120054676808, -0.46912133533614436, -0.4730306947777169, -0.47694002411145414, -0.4808493840991482, -0.4847587128750152, -0.4886680734326941, -0.4925774016260851, -0.4964867627791253, -0.500396090363859, -0.504305452139277, -0.508214779087472, -0.512124141514049, -0.516033467795985, -0.5199428309044185, -0.5238521564883863, -0.527761520311438, -0.531670845163578, -0.5355802097362504, -0.5394895338203647, -0.5433988991801022, -0.5473082224574536, -0.5512175886443414, -0.5551269110734331, -0.5590362781304395, -0.5629455996667695, -0.566854967640002, -0.5707642882357846, -0.5746736571747835, -0.578582976778646, -0.5824923467366983, -0.5864016652933449, -0.5903110363278494, -0.5942203537776812, -0.5981297259505457, -0.6020390422292344, -0.6059484156073237, -0.6098577306453417, -0.6137671053009803, -0.617676419023066, -0.6215857950346013, -0.6254951073591605, -0.6294044848116045, -0.6333137956500302, -0.6372231746357746, -0.6411324838916861, -0.6450418645113165, -0.648951172079692, -0.6528605544429142, -0.6567698602091011, -0.6606792444357953, -0.6645885482743833, -0.6684979344958091, -0.6724072362693488, -0.676316624629518, -0.6802259241870389, -0.6841353148443018, -0.6880446120196169, -0.6919540051484867, -0.6958632997582311, -0.6997726955514953, -0.7036819873928459, -0.7075913860640196, -0.7115006749120578, -0.7154100766982359, -0.7193193623028565, -0.7232287674680579, -0.7271380495503452, -0.7310474583894446, -0.7349567366374097, -0.7388661494807708, -0.7427754235443069, -0.7466848407632755, -0.7505941102481591, -0.7545035322616258, -0.7584127967223441, -0.7623222240045882, -0.7662314829357346, -0.7701409160258873, -0.7740501688517478, -0.7779596083652595, -0.78186885442716, -0.7857783010697924, -0.7896875396106009, -0.7935969941956119, -0.797506224340648, -0.8014156878100482, -0.8053249085433618, -0.8092343819944443, -0.8131435921291013, -0.8170530768477815, -0.8209622749883427, -0.8248717724914972, -0.8287809569861475, -0.8326904690758741, -0.8365996379547437, -0.840509166788676, -0.8444183176834513, -0.8483278658669384, -0.8522369959048146, -0.8561465666133528, -0.8600556722751947, -0.8639652694193264, -0.8678743463472005, -0.8717839747981412, -0.8756930175296823, -0.8796026834334342, -0.8835116850285625, -0.887421396251847, -0.8913303477571657, -0.8952401145344597, -0.8991490041967042, -0.9030588400927568, -0.9069676521725691, -0.9108775755555069, -0.9147862884829882, -0.9186963248542891, -0.9226049082573023, -0.9265150940816825, -0.9304235037934354, -0.9343338930883744, -0.9382420623296589, -0.9421527386454067, -0.9460605614709234, -0.9499716612008204, -0.9538789589663665, -0.95779072077049, -0.9616971672282222, -0.9656100492942349, -0.9695149798847841, -0.9734299845912425, -0.9773318119558709, -0.9812516147065633, -0.9851454393807965, -0.9890801248709098, -0.9929411050917847, -0.9969753576360983, -1.0, -0.9969753576360985, -0.9929411050917855, -0.9890801248709098, -0.9851454393807965, -0.9812516147065636, -0.9773318119558715, -0.9734299845912424, -0.9695149798847837, -0.9656100492942354, -0.9616971672282224, -0.9577907207704909, -0.9538789589663668, -0.9499716612008208, -0.9460605614709247, -0.9421527386454067, -0.9382420623296595, -0.934333893088375, -0.9304235037934361, -0.9265150940816825, -0.9226049082573026, -0.9186963248542893, -0.9147862884829882, -0.9108775755555072, -0.9069676521725699, -0.9030588400927573, -0.8991490041967046, -0.8952401145344604, -0.891330347757166, -0.8874213962518472, -0.8835116850285621, -0.8796026834334342, -0.8756930175296825, -0.8717839747981413, -0.867874346347201, -0.8639652694193265, -0.8600556722751956, -0.8561465666133529, -0.8522369959048138, -0.8483278658669392, -0.8444183176834515, -0.8405091667886763, -0.8365996379547439, -0.8326904690758743, -0.8287809569861477, -0.8248717724914972, -0.820962274988343, -0.8170530768477816, -0.8131435921291016, -0.8092343819944444, -0.805324908543362, -0.8014156878100483, -0.7975062243406484, -0.7935969941956119, -0.7896875396106009, -0.7857783010697927, -0.7818688544271599, -0.7779596083652598, -0.7740501688517475, -0.7701409160258872, -0.7662314829357353, -0.7623222240045884, -0.7584127967223445, -0.7545035322616258, -0.7505941102481591, -0.7466848407632755, -0.7427754235443075, -0.7388661494807708, -0.7349567366374101, -0.7310474583894447, -0.7271380495503456, -0.7232287674680583, -0.7193193623028563, -0.7154100766982356, -0.7115006749120583, -0.7075913860640196, -0.7036819873928458, -0.6997726955514953, -0.695863299758231, -0.6919540051484869, -0.688044612019617, -0.6841353148443019, -0.6802259241870389, -0.6763166246295184, -0.6724072362693486, -0.6684979344958093, -0.6645885482743832, -0.6606792444357955, -0.6567698602091014, -0.652860554442914, -0.6489511720796924, -0.6450418645113166, -0.6411324838916864, -0.6372231746357752, -0.6333137956500305, -0.6294044848116046, -0.6254951073591605, -0.6215857950346022, -0.6176764190230666, -0.6137671053009806, -0.609857730645342, -0.605948415607324, -0.6020390422292344, -0.5981297259505461, -0.5942203537776813, -0.5903110363278494, -0.5864016652933449, -0.5824923467366986, -0.5785829767786462, -0.5746736571747836, -0.5707642882357847, -0.5668549676400024, -0.5629455996667698, -0.5590362781304398, -0.5551269110734335, -0.5512175886443416, -0.5473082224574537, -0.5433988991801024, -0.5394895338203654, -0.5355802097362503, -0.5316708451635785, -0.5277615203114383, -0.5238521564883867, -0.5199428309044182, -0.5160334677959851, -0.5121241415140496, -0.5082147790874725, -0.5043054521392768, -0.5003960903638595, -0.4964867627791261, -0.49257740162608465, -0.4886680734326942, -0.48475871287501565, -0.4808493840991485, -0.476940024111454, -0.4730306947777171, -0.4691213353361443, -0.46521200546768093, -0.4613026465497793, -0.45739331616837264, -0.4534839577529992, -0.44957462687917343, -0.4456652689464045, -0.44175593759950715, -0.4378465801305522, -0.43393724832883335, -0.43002789130596153, -0.4261185590666512, -0.4222092024731196, -0.41829986981248996, -0.4143905136324771, -0.41048118056591326, -0.40657182478446136, -0.4026624913265071, -0.3987531359294683, -0.3948438020938899, -0.39093444706787117, -0.3870251128676988, -0.38311575820002025, -0.37920642364759516, -0.37529706932624224, -0.3713877344332613, -0.3674783804468474, -0.3635690452243969, -0.3596596915621255, -0.35575035602072047, -0.351841002672351, -0.3479316668219668, -0.3440223137777813, -0.340112977627885, -0.3362036248786602, -0.3322942884382375, -0.328384935975216, -0.3244755992528018, -0.3205662470676678, -0.31665691007136676, -0.31274755815622096, -0.3088382208937325, -0.30492886924106916, -0.30101953171970774, -0.2971101803223987, -0.2932008425491157, -0.28929149140038224, -0.28538215338178285, -0.2814728024751882, -0.2775634642175487, -0.27365411354697317, -0.26974477505625993, -0.2658354246158882, -0.26192608589776845, -0.2580167356820757, -0.25410739674193505, -0.2501980467456734, -0.24628870758862637, -0.2423793578068088, -0.23847001843771662, -0.23456066886560822, -0.23065132928908208, -0.22674197992219042, -0.2228326401426093, -0.21892329097666782, -0.21501395099818524, -0.21110460202914985, -0.20719526185570267, -0.20328591307974114, -0.19937657271506182, -0.19546722412854195, -0.1915578835761624, -0.18764853517564745, -0.1837391944389114, -0.17982984622115167, -0.17592050530321737, -0.17201115726514205, -0.168101816168993, -0.16419246830770556, -0.16028312703615394, -0.15637377934892602, -0.15246443790461825, -0.14855509038888165, -0.1446457487743072, -0.14073640142765176, -0.13682705964514463, -0.1329177124653106, -0.12900837051705547, -0.12509902350193158, -0.12118968138996869, -0.11728033453758553, -0.11337099226381446, -0.10946164557234273, -0.1055523031385235, -0.10164295660626803, -0.09773361401403079, -0.09382426763942979, -0.08991492489027002, -0.0860055786718901, -0.08209623576717853, -0.0781868897037127, -0.07427754664469356, -0.07036820073496035, -0.06645885752275418, -0.06254951176569183, -0.05864016840129946, -0.05473082279596814, -0.05082147928027209, -0.046912133825847045, -0.04300279015961145, -0.039093444855387315, -0.035184101039261194, -0.031274755884645906, -0.027365411919164372, -0.02345606691368099, -0.019546722799263082, -0.0156373779425469, -0.011728033679501985, -0.0078186889713019, -0.003909344559824639, ], [0.0, 0.0039031556361844764, 0.007818689638830822, 0.011734223386766452, 0.015637376903806868, 0.019540530930460567, 0.023456068918280667, 0.027371606141200776, 0.031274753804034064, 0.03517790248763099, 0.039093448203108186, 0.04300899264118712, 0.046912130697075616, 0.05081527028796341, 0.05473082749694268, 0.05864638291215786, 0.06254950757927241, 0.06645263430027835, 0.07036820680347987, 0.07428377699109753, 0.07818688444688456, 0.08208999448171772, 0.08600558612651055, 0.08992117492681757, 0.09382426129606358, 0.0977273507774249, 0.10164296546994926, 0.10555857678032304, 0.10946163812282102, 0.11336470312013136, 0.11728034483786547, 0.12119598262527428, 0.12509901492299763, 0.12900205142964333, 0.13291772423451756, 0.13683339254855267, 0.14073639169222785, 0.1446393956122239, 0.1485551036643873, 0.15247080665093438, 0.15637376842590292, 0.1602767355598573, 0.16419248313222073, 0.16810822504788933, 0.17201114511912924, 0.1759140711493884, 0.1798298626430706, 0.18374564787050854, 0.18764852176668276, 0.19155140224152203, 0.19546724220234482, 0.19938307526657986, 0.2032858983629587, 0.20718872867966678, 0.2111046218158589, 0.21502050740183043, 0.2189232749019143, 0.22282605028860575, 0.2267420014898996, 0.23065794446134713, 0.23456065137700438, 0.23846336687297223, 0.2423793812312878, 0.24629538665121276, 0.25019802778111006, 0.25410067821550264, 0.25801676104746174, 0.26193283420037056, 0.26583540410645373, 0.26973798407504007, 0.2736541409465619, 0.2775702873627621, 0.28147278034450657, 0.28537528418424746, 0.2892915209375324, 0.29320774641977265, 0.29711015648587813, 0.3010125782469926, 0.30492890103024145, 0.3088452116830304, 0.3127475325201911, 0.31664986593534966, 0.3205662812356125, 0.32448268349761744, 0.32838490843593493, 0.3322871468861598, 0.3362036615657852, 0.3401201622457564, 0.3440222842202959, 0.3479244206970776, 0.35184104203429606, 0.35575764835105145, 0.35965965985895887, 0.36356168692201785, 0.3674784226562979, 0.3713951422833832, 0.375297035335873, 0.37919894506589413, 0.38311580344880924, 0.3870326445645641, 0.3909344106329763, 0.39483619457852936, 0.3987531844310164, 0.4026701557749002, 0.40657178572986863, 0.4104734348475824, 0.4143905656246297, 0.41830767656082557, 0.4222091606034241, 0.42611066519030555, 0.4300279470543065, 0.43394520764380873, 0.43784653522732525, 0.4417478848439016, 0.44566532874816356, 0.44958274983079644, 0.45348390957150475, 0.45738509295421026, 0.4613027107383891, 0.4652203040264918, 0.46912128360147043, 0.4730222885623692, 0.476940093061983, 0.48085787124785945, 0.4847586572774833, 0.4886594705890116, 0.49257747576167016, 0.49649545264135014, 0.5003960305535579, 0.504296637815487, 0.5082148588870057, 0.5121330495034203, 0.516033403376232, 0.5199337888613911, 0.5238522424957388, 0.5277706633051544, 0.5316707756830426, 0.53557092215757, 0.5394896266555199, 0.5434082957219133, 0.5473081474006274, 0.5512080359134816, 0.5551270114460106, 0.5590459486693137, 0.5629455184423553, 0.5668451280774954, 0.5707643969615623, 0.5746836243471128, 0.5785828887053175, 0.5824821962882994, 0.5864017833145948, 0.5903213252931115, 0.594220258066501, 0.5981192378149771, 0.6020391706399347, 0.60595905444988, 0.6098576263778759, 0.6137562494825816, 0.6176765591004043, 0.6215968152479557, 0.6254949934600141, 0.6293932275789247, 0.6333139488941132, 0.6372346117104944, 0.6411323590937341, 0.6450301677368223, 0.6489513402640652, 0.6528724485860905, 0.656769723009035, 0.6606670647839015, 0.6645887335109498, 0.6685103315190379, 0.6724070848702491, 0.6763039125490784, 0.6802261290104082, 0.6841482672699288, 0.6880444442559056, 0.6919407036103572, 0.6958635272366266, 0.699786264004846, 0.7036818006309837, 0.7075774289621486, 0.7115009287950769, 0.7154243316793848, 0.7193191533081198, 0.7232140775704263, 0.7271383344686838, 0.7310624825558457, 0.7349565013924267, 0.7388506357690584, 0.7427757452840812, 0.7467007319107517, 0.7505938437015263, 0.754487086426985, 0.7584131626086422, 0.7623390990196683, 0.7662311786471856, 0.770123407777993, 0.7740505882957157, 0.7779776085548972, 0.7818685040559797, 0.785759571742204, 0.7896880249075368, 0.7936162926129634, 0.7975058168902643, 0.8013955414618646, 0.8053254760671342, 0.8092551937294812, 0.8131431128007633, 0.8170312675865647, 0.8209629470321113, 0.824894369491252, 0.8287803853838669, 0.8326666825000631, 0.8366004456656753, 0.8405338998268818, 0.8444176248982113, 0.84830169102495, 0.85223798415304, 0.8561738989818127, 0.8600548159396009, 0.86393615481967, 0.8678755821956148, 0.8718145361020553, 0.8756919329845294, 0.8795698648511064, 0.8835132733355293, 0.8874560726071355, 0.8913289312428674, 0.8952024897968156, 0.8991511184689002, 0.9030989347755936, 0.9069657262128503, 0.9108334717542614, 0.9147892376275096, 0.9187438672295539, 0.9226021427189207, 0.9264617940568379, 0.9304278946910156, 0.9343922955511028, 0.93823776801764, 0.9420853933532758, 0.9460677652978202, 0.9500473229950747, 0.9538714321486328, 0.9576993757307326, 0.9617110255380362, 0.9657171458472478, 0.9694986869905114, 0.9732888883998924, 0.977368045873107, 0.9814317329995355, 0.98509001840771, 0.9887835884599333, 0.9931585114066679, 0.9973806978445345, 0.9992078082225433, 0.9973806978445348, 0.9931585114066679, 0.9887835884599333, 0.98509001840771, 0.9814317329995352, 0.9773680458731068, 0.9732888883998926, 0.9694986869905117, 0.9657171458472478, 0.9617110255380362, 0.9576993757307326, 0.9538714321486329, 0.9500473229950749, 0.9460677652978203, 0.9420853933532756, 0.9382377680176404, 0.9343922955511026, 0.9304278946910158, 0.9264617940568379, 0.9226021427189207, 0.9187438672295539, 0.9147892376275096, 0.9108334717542617, 0.9069657262128505, 0.9030989347755933, 0.8991511184689003, 0.8952024897968156, 0.8913289312428675, 0.8874560726071355, 0.8835132733355296, 0.8795698648511062, 0.8756919329845294, 0.8718145361020553, 0.8678755821956146, 0.86393615481967, 0.8600548159396009, 0.8561738989818128, 0.8522379841530401, 0.8483016910249503, 0.8444176248982115, 0.8405338998268815, 0.8366004456656756, 0.8326666825000634, 0.8287803853838669, 0.8248943694912523, 0.8209629470321115, 0.817031267586565, 0.8131431128007632, 0.809255193729481, 0.8053254760671343, 0.8013955414618646, 0.7975058168902645, 0.7936162926129634, 0.789688024907537, 0.7857595717422042, 0.7818685040559799, 0.7779776085548971, 0.7740505882957155, 0.770123407777993, 0.7662311786471856, 0.7623390990196685, 0.7584131626086424, 0.7544870864269848, 0.7505938437015264, 0.7467007319107518, 0.7427757452840812, 0.7388506357690585, 0.734956501392427, 0.7310624825558457, 0.727138334468684, 0.7232140775704262, 0.7193191533081201, 0.7154243316793846, 0.7115009287950772, 0.7075774289621488, 0.7036818006309838, 0.6997862640048461, 0.6958635272366266, 0.6919407036103573, 0.6880444442559056, 0.6841482672699293, 0.6802261290104082, 0.6763039125490784, 0.6724070848702491, 0.6685103315190384, 0.6645887335109499, 0.6606670647839014, 0.6567697230090351, 0.6528724485860902, 0.6489513402640652, 0.6450301677368226, 0.6411323590937346, 0.6372346117104947, 0.6333139488941134, 0.6293932275789248, 0.6254949934600141, 0.6215968152479558, 0.617676559100404, 0.6137562494825819, 0.6098576263778758, 0.6059590544498799, 0.6020391706399347, 0.5981192378149774, 0.594220258066501, 0.590321325293112, 0.5864017833145949, 0.5824821962882994, 0.5785828887053175, 0.5746836243471124, 0.5707643969615626, 0.5668451280774952, 0.5629455184423553, 0.5590459486693138, 0.5551270114460112, 0.5512080359134817, 0.5473081474006277, 0.5434082957219135, 0.5394896266555198, 0.53557092215757, 0.5316707756830424, 0.5277706633051545, 0.5238522424957388, 0.5199337888613913, 0.5160334033762319, 0.5121330495034208, 0.5082148588870058, 0.5042966378154872, 0.500396030553558, 0.49649545264135, 0.49257747576167027, 0.4886594705890118, 0.484758657277483, 0.48085787124785956, 0.47694009306198315, 0.47302228856236933, 0.46912128360147043, 0.46522030402649206, 0.4613027107383891, 0.45738509295421026, 0.4534839095715045, 0.44958274983079655, 0.4456653287481637, 0.4417478848439016, 0.43784653522732536, 0.433945207643809, 0.4300279470543064, 0.4261106651903058, 0.4222091606034242, 0.41830767656082557, 0.4143905656246297, 0.41047343484758236, 0.4065717857298687, 0.4026701557749002, 0.3987531844310166, 0.39483619457852936, 0.3909344106329763, 0.3870326445645639, 0.38311580344880936, 0.3791989450658943, 0.37529703533587294, 0.37139514228338333, 0.36747842265629777, 0.36356168692201807, 0.35965965985895887, 0.35575764835105167, 0.3518410420342961, 0.34792442069707763, 0.34402228422029607, 0.34012016224575675, 0.33620366156578524, 0.33228714688615973, 0.3283849084359348, 0.3244826834976172, 0.3205662812356126, 0.3166498659353496, 0.3127475325201912, 0.3088452116830304, 0.3049289010302416, 0.3010125782469925, 0.2971101564858784, 0.2932077464197728, 0.2892915209375323, 0.2853752841842475, 0.2814727803445064, 0.2775702873627623, 0.2736541409465619, 0.26973798407504024, 0.2658354041064537, 0.2619328342003708, 0.2580167610474619, 0.2541006782155029, 0.25019802778111017, 0.2462953866512127, 0.242379381231288, 0.2384633668729723, 0.23456065137700466, 0.2306579444613472, 0.22674200148989976, 0.22282605028860575, 0.2189232749019146, 0.2150205074018304, 0.21110462181585907, 0.20718872867966703, 0.20328589836295854, 0.19938307526658003, 0.1954672422023448, 0.19155140224152217, 0.18764852176668287, 0.18374564787050857, 0.17982986264307063, 0.17591407114938862, 0.17201114511912938, 0.1681082250478897, 0.16419248313222087, 0.1602767355598573, 0.15637376842590317, 0.15247080665093438, 0.14855510366438737, 0.14463939561222378, 0.14073639169222807, 0.1368333925485526, 0.13291772423451784, 0.12900205142964344, 0.1250990149229977, 0.12119598262527446, 0.11728034483786541, 0.11336470312013154, 0.109461638122821, 0.10555857678032302, 0.1016429654699491, 0.09772735077742511, 0.0938242612960636, 0.08992117492681771, 0.08600558612651062, 0.08208999448171764, 0.07818688444688467, 0.07428377699109749, 0.07036820680348012, 0.06645263430027838, 0.06254950757927243, 0.05864638291215772, 0.054730827496942774, 0.05081527028796344, 0.04691213069707585, 0.04300899264118719, 0.03909344820310808, 0.03517790248763107, 0.03127475380403411, 0.02737160614120102, 0.023456068918280726, 0.019540530930460674, 0.0156373769038068, 0.011734223386766704, 0.007818689638830862, 0.0039031556361847526, 9.33409473925949e-19, -0.003903155636184609, -0.007818689638830697, -0.01173422338676654, -0.01563737690380663, -0.0195405309304605, -0.023456068918280556, -0.02737160614120088, -0.03127475380403394, -0.03517790248763095, -0.03909344820310796, -0.04300899264118713, -0.046912130697075685, -0.05081527028796324, -0.05473082749694259, -0.05864638291215763, -0.06254950757927236, -0.0664526343002781, -0.07036820680347995, -0.0742837769910973, -0.07818688444688454, -0.0820899944817175, -0.08600558612651053, -0.0899211749268176, -0.09382426129606347, -0.09772735077742495, -0.10164296546994901, -0.10555857678032295, -0.10946163812282093, -0.11336470312013139, -0.1172803448378654, -0.12119598262527433, -0.1250990149229975, -0.12900205142964324, -0.13291772423451761, -0.13683339254855234, -0.14073639169222787, -0.14463939561222364, -0.14855510366438723, -0.15247080665093424, -0.15637376842590295, -0.16027673555985705, -0.16419248313222073, -0.16810822504788941, -0.17201114511912913, -0.1759140711493885, -0.17982986264307038, -0.18374564787050846, -0.18764852176668256, -0.1915514022415221, -0.19546724220234463, -0.19938307526657992, -0.20328589836295832, -0.20718872867966703, -0.21110462181585898, -0.21502050740183012, -0.2189232749019145, -0.22282605028860555, -0.2267420014898996, -0.2306579444613469, -0.23456065137700435, -0.2384633668729722, -0.2423793812312877, -0.24629538665121262, -0.25019802778111006, -0.2541006782155028, -0.2580167610474618, -0.26193283420037067, -0.26583540410645357, -0.2697379840750399, -0.27365414094656165, -0.27757028736276224, -0.2814727803445063, -0.28537528418424735, -0.28929152093753197, -0.29320774641977254, -0.29711015648587824, -0.30101257824699246, -0.3049289010302417, -0.30884521168303036, -0.31274753252019116, -0.3166498659353492, -0.3205662812356124, -0.32448268349761716, -0.32838490843593465, -0.3322871468861595, -0.33620366156578513, -0.3401201622457565, -0.34402228422029596, -0.34792442069707763, -0.35184104203429584, -0.3557576483510515, -0.3596596598589586, -0.3635616869220179, -0.36747842265629754, -0.37139514228338316, -0.3752970353358729, -0.379198945065894, -0.38311580344880936, -0.3870326445645638, -0.3909344106329764, -0.3948361945785293, -0.39875318443101665, -0.40267015577489995, -0.4065717857298684, -0.41047343484758214, -0.4143905656246297, -0.4183076765608257, -0.42220916060342367, -0.4261106651903056, -0.4300279470543065, -0.43394520764380873, -0.4378465352273252, -0.4417478848439014, -0.4456653287481637, -0.4495827498307963, -0.4534839095715045, -0.45738509295421, -0.4613027107383891, -0.465220304026492, -0.4691212836014707, -0.47302228856236905, -0.47694009306198304, -0.48085787124785934, -0.48475865727748285, -0.4886594705890117, -0.49257747576167016, -0.4964954526413497, -0.5003960305535576, -0.5042966378154871, -0.5082148588870055, -0.5121330495034203, -0.516033403376232, -0.5199337888613913, -0.5238522424957388, -0.5277706633051544, -0.531670775683042, -0.5355709221575699, -0.5394896266555195, -0.5434082957219131, -0.5473081474006276, -0.5512080359134818, -0.5551270114460106, -0.5590459486693138, -0.562945518442355, -0.5668451280774953, -0.5707643969615623, -0.5746836243471127, -0.5785828887053173, -0.5824821962882994, -0.5864017833145948, -0.5903213252931115, -0.5942202580665013, -0.5981192378149771, -0.6020391706399347, -0.6059590544498794, -0.6098576263778757, -0.6137562494825817, -0.6176765591004039, -0.621596815247955, -0.6254949934600141, -0.6293932275789248, -0.6333139488941129, -0.6372346117104944, -0.6411323590937344, -0.6450301677368224, -0.6489513402640646, -0.6528724485860904, -0.6567697230090348, -0.6606670647839011, -0.6645887335109496, -0.668510331519038, -0.6724070848702491, -0.6763039125490776, -0.6802261290104086, -0.6841482672699293, -0.6880444442559056, -0.691940703610357, -0.6958635272366266, -0.699786264004846, -0.703681800630984, -0.7075774289621485, -0.711500928795077, -0.7154243316793846, -0.7193191533081198, -0.7232140775704262, -0.7271383344686843, -0.7310624825558456, -0.7349565013924262, -0.738850635769059, -0.742775745284081, -0.7467007319107515, -0.7505938437015262, -0.754487086426985, -0.7584131626086422, -0.7623390990196679, -0.7662311786471856, -0.7701234077779934, -0.7740505882957157, -0.7779776085548964, -0.7818685040559801, -0.7857595717422039, -0.7896880249075365, -0.7936162926129638, -0.7975058168902645, -0.8013955414618648, -0.8053254760671339, -0.8092551937294811, -0.8131431128007632, -0.8170312675865647, -0.8209629470321115, -0.8248943694912524, -0.8287803853838668, -0.8326666825000631, -0.8366004456656758, -0.8405338998268815, -0.8444176248982113, -0.8483016910249492, -0.8522379841530403, -0.8561738989818127, -0.8600548159396, -0.8639361548196697, -0.8678755821956151, -0.8718145361020556, -0.8756919329845292, -0.8795698648511068, -0.8835132733355295, -0.8874560726071355, -0.8913289312428672, -0.8952024897968156, -0.8991511184688997, -0.9030989347755932, -0.9069657262128497, -0.9108334717542617, -0.9147892376275096, -0.9187438672295536, -0.9226021427189209, -0.9264617940568379, -0.9304278946910155, -0.9343922955511024, -0.9382377680176403, -0.9420853933532758, -0.9460677652978193, -0.9500473229950741, -0.9538714321486329, -0.9576993757307323, -0.961711025538036, -0.9657171458472478, -0.9694986869905117, -0.9732888883998927, -0.9773680458731062, -0.9814317329995352, -0.98509001840771, -0.9887835884599331, -0.9931585114066672, -0.997380697844535, -0.9992078082225433, -0.9973806978445351, -0.9931585114066679, -0.9887835884599331, -0.98509001840771, -0.9814317329995356, -0.9773680458731068, -0.9732888883998926, -0.9694986869905114, -0.9657171458472484, -0.9617110255380362, -0.9576993757307329, -0.9538714321486331, -0.9500473229950746, -0.9460677652978203, -0.9420853933532758, -0.9382377680176407, -0.9343922955511029, -0.9304278946910162, -0.9264617940568379, -0.9226021427189212, -0.9187438672295537, -0.9147892376275096, -0.910833471754262, -0.9069657262128502, -0.9030989347755933, -0.8991511184689003, -0.8952024897968162, -0.8913289312428675, -0.8874560726071358, -0.8835132733355293, -0.8795698648511068, -0.8756919329845294, -0.8718145361020556, -0.8678755821956153, -0.8639361548196699, -0.8600548159396009, -0.8561738989818128, -0.8522379841530398, -0.84830169102495, -0.8444176248982113, -0.8405338998268818, -0.8366004456656759, -0.832666682500063, -0.8287803853838669, -0.8248943694912524, -0.8209629470321116, -0.8170312675865647, -0.8131431128007636, -0.8092551937294812, -0.8053254760671341, -0.8013955414618649, -0.7975058168902649, -0.793616292612964, -0.7896880249075365, -0.7857595717422041, -0.78186850405598, -0.7779776085548966, -0.7740505882957155, -0.7701234077779932, -0.7662311786471862, -0.7623390990196681, -0.7584131626086427, -0.754487086426985, -0.7505938437015262, -0.7467007319107515, -0.7427757452840816, -0.7388506357690587, -0.7349565013924265, -0.7310624825558456, -0.7271383344686845, -0.7232140775704266, -0.7193191533081196, -0.7154243316793845, -0.7115009287950773, -0.7075774289621486, -0.7036818006309838, -0.699786264004846, -0.6958635272366265, -0.6919407036103572, -0.6880444442559058, -0.6841482672699294, -0.6802261290104086, -0.676303912549078, -0.6724070848702489, -0.6685103315190383, -0.6645887335109495, -0.6606670647839014, -0.6567697230090351, -0.6528724485860901, -0.6489513402640651, -0.6450301677368226, -0.6411323590937348, -0.637234611710495, -0.6333139488941131, -0.6293932275789247, -0.6254949934600144, -0.621596815247956, -0.6176765591004042, -0.6137562494825818, -0.609857626377876, -0.6059590544498797, -0.6020391706399347, -0.5981192378149776, -0.5942202580665014, -0.5903213252931114, -0.5864017833145949, -0.5824821962882999, -0.5785828887053175, -0.5746836243471128, -0.5707643969615623, -0.5668451280774957, -0.5629455184423552, -0.559045948669314, -0.555127011446011, -0.551208035913482, -0.5473081474006278, -0.5434082957219134, -0.5394896266555202, -0.5355709221575697, -0.5316707756830427, -0.5277706633051548, -0.5238522424957393, -0.519933788861391, -0.5160334033762322, -0.5121330495034209, -0.508214858887006, -0.5042966378154868, -0.5003960305535581, -0.49649545264135053, -0.4925774757616698, -0.4886594705890119, -0.4847586572774832, -0.4808578712478598, -0.4769400930619829, -0.4730222885623691, -0.4691212836014706, -0.46522030402649217, -0.4613027107383889, -0.45738509295421026, -0.45348390957150536, -0.4495827498307963, -0.4456653287481637, -0.44174788484390165, -0.4378465352273259, -0.43394520764380873, -0.4300279470543066, -0.4261106651903057, -0.42220916060342467, -0.41830767656082546, -0.41439056562462956, -0.41047343484758325, -0.4065717857298684, -0.40267015577490023, -0.39875318443101654, -0.3948361945785299, -0.390934410632976, -0.3870326445645641, -0.3831158034488095, -0.3791989450658943, -0.3752970353358731, -0.3713951422833834, -0.36747842265629804, -0.3635616869220178, -0.359659659858959, -0.3557576483510517, -0.3518410420342963, -0.3479244206970774, -0.34402228422029635, -0.340120162245757, -0.33620366156578574, -0.3322871468861598, -0.32838490843593515, -0.3244826834976178, -0.3205662812356125, -0.3166498659353496, -0.3127475325201913, -0.308845211683031, -0.3049289010302414, -0.30101257824699246, -0.2971101564858785, -0.2932077464197732, -0.2892915209375326, -0.28537528418424746, -0.28147278034450707, -0.277570287362762, -0.27365414094656193, -0.26973798407504035, -0.26583540410645395, -0.2619328342003707, -0.2580167610474619, -0.25410067821550286, -0.2501980277811108, -0.24629538665121284, -0.24237938123128827, -0.2384633668729726, -0.23456065137700435, -0.23065794446134721, -0.22674200148989976, -0.22282605028860641, -0.21892327490191424, -0.21502050740183076, -0.2111046218158594, -0.2071887286796667, -0.20328589836295843, -0.1993830752665803, -0.1954672422023449, -0.19155140224152203, -0.18764852176668276, -0.18374564787050857, -0.17982986264307116, -0.17591407114938853, -0.17201114511912938, -0.16810822504788958, -0.16419248313222054, -0.16027673555985741, -0.15637376842590336, -0.15247080665093463, -0.14855510366438743, -0.144639395612224, -0.14073639169222824, -0.136833392548553, -0.13291772423451767, -0.12900205142964338, -0.12509901492299766, -0.12119598262527412, -0.11728034483786533, -0.11336470312013154, -0.10946163812282152, -0.10555857678032272, -0.10164296546994919, -0.09772735077742502, -0.09382426129606403, -0.08992117492681755, -0.0860055861265107, -0.0820899944817179, -0.07818688444688439, -0.07428377699109778, -0.0703682068034802, -0.0664526343002787, -0.06254950757927254, -0.058646382912157756, -0.05473082749694307, -0.05081527028796389, -0.04691213069707578, -0.04300899264118715, -0.03909344820310834, -0.03517790248763088, -0.03127475380403389, -0.027371606141201075, -0.023456068918281173, -0.019540530930460605, -0.015637376903806923, -0.011734223386766487, -0.007818689638831377, -0.0039031556361844473, ], [0.0, 0.0038918404334922084, 0.0077939355002464225, 0.011710533555276991, 0.015637387583819, 0.019564241323350644, 0.023480835590340587, 0.02738291812762912, 0.03127473716758749, 0.03516655678643824, 0.03906864691123353, 0.04298526627494972, 0.04691216286608841, 0.05083905858670288, 0.05475566654051096, 0.058657718925450984, 0.06254947410506717, 0.06644123044948011, 0.07034329810134421, 0.0742599565526393, 0.07818693849564365, 0.0821139189760272, 0.08603055825683122, 0.08993256249958788, 0.09382421057550121, 0.097715860416348, 0.10161788779199903, 0.10553460358815844, 0.10946171471550198, 0.11338882377013967, 0.11730551239943514, 0.12120744991674565, 0.1250989463279119, 0.12899044512602967, 0.13289241392901607, 0.13680920604029573, 0.14073649178659514, 0.14466377482416218, 0.14858053143217534, 0.15248238280278073, 0.1563736810892774, 0.16026498241494605, 0.1641668736198979, 0.1680837619861122, 0.17201126999634725, 0.17593877462712088, 0.17985561869879718, 0.1837573633926284, 0.18764841455524567, 0.19153946944873598, 0.19544126304308437, 0.1993582688610673, 0.20328604966882033, 0.20721382638180127, 0.21113077852960713, 0.21503239460100385, 0.21892314637895746, 0.22281390262954043, 0.22671557732374673, 0.2306327233764283, 0.23456083117712076, 0.23848893411177305, 0.24240601638514503, 0.24630748011814815, 0.2501978761571797, 0.25408827747294915, 0.25798981036835905, 0.2619071214088635, 0.26583561495899405, 0.26976410280250707, 0.2736813390460783, 0.27758262453668, 0.2814726034126698, 0.2853625884464151, 0.28926395464713733, 0.2931814578550366, 0.2971104015368745, 0.30103933858627274, 0.30495675486221757, 0.30885783351805, 0.3127473275712661, 0.3166368287576802, 0.32053800090908435, 0.32445572644115545, 0.3283851915441839, 0.3323146489843785, 0.3362322740787196, 0.3401331140104763, 0.3440220479315662, 0.34791099007712595, 0.35181193780823256, 0.3557299194734115, 0.3596599857604446, 0.36359004322588445, 0.367507909264914, 0.3714084745350575, 0.37529676362408976, 0.37918506217123676, 0.3830857514107758, 0.38700402750944374, 0.39093478515897495, 0.39486553267007146, 0.39878367588196056, 0.40268392556375177, 0.40657147355533735, 0.4104590324144456, 0.41435942453969876, 0.4182780389224855, 0.42220959097278826, 0.4261411313720745, 0.43005959304153857, 0.4339594800232599, 0.4378461763298107, 0.4417328851316894, 0.44563293589384734, 0.4495519393171677, 0.4534844047872815, 0.4574168568496106, 0.4613356845320583, 0.46523515397446574, 0.46912087013932957, 0.473006600700988, 0.47690625884824034, 0.4808257107366131, 0.4847592286730665, 0.4886927311374778, 0.49261198022651026, 0.49651096754115964, 0.5003955526028023, 0.5042801543091826, 0.5081793597951572, 0.512099330570324, 0.5160340653802734, 0.5199687822623097, 0.5238885180457915, 0.527786946199723, 0.5316702205292672, 0.535553514195611, 0.5394521958096385, 0.5433727700242911, 0.54730891862926, 0.5512450463449442, 0.5551653467484924, 0.5590631226027533, 0.5629448695590019, 0.566826639121747, 0.570724711297732, 0.5746459919359985, 0.5785837935566192, 0.582521570663706, 0.5864425299808942, 0.5903395392114723, 0.5942194936053045, 0.5980994746395396, 0.6019968330727682, 0.6059189475842941, 0.6098586974191591, 0.6137984182308016, 0.6177201523022738, 0.6216162521861482, 0.6254940839595023, 0.6293719474386311, 0.6332684629299903, 0.637191571912872, 0.641133640741601, 0.6450756748287716, 0.6489983284051153, 0.6528933372930545, 0.6567686278054271, 0.6606439565142923, 0.6645394661039808, 0.6684637761683598, 0.6724086392587748, 0.676353460196645, 0.6802772176927693, 0.6841708991568315, 0.6880431056524637, 0.6919153588607692, 0.6958096526813791, 0.6997354361664629, 0.7036837173485014, 0.7076319465229216, 0.7115570482262886, 0.7154490862877941, 0.7193174866852813, 0.7231859452881881, 0.7270787463980295, 0.7310063728410634, 0.734958914420091, 0.7389113904741644, 0.7428381578889276, 0.7467281165520487, 0.7505917198508095, 0.7544553974051497, 0.7583463295869453, 0.762276318466816, 0.766234297563445, 0.7701921918738296, 0.774121069129352, 0.7780083225931447, 0.7818657155635479, 0.7857232061835897, 0.789611739985381, 0.7935448546355021, 0.7975099885756681, 0.801475008924827, 0.8054066341301536, 0.8092902379750415, 0.8131393048154542, 0.8169885053345632, 0.8208738621556744, 0.8248112903370804, 0.8287862275244766, 0.8327610052237846, 0.8366963417746903, 0.8405747734042514, 0.8444121372516029, 0.848249694519366, 0.8521306631195212, 0.8560744011756054, 0.860063542172666, 0.8640524438872106, 0.8679930428776891, 0.8718636129851838, 0.8756833873819688, 0.879503465182534, 0.8833780165188757, 0.8873318092159173, 0.8913432837998811, 0.8953543614393835, 0.8993029436087928, 0.9031602169233209, 0.9069507152832589, 0.9107417547485642, 0.9146061348640905, 0.9185783051031918, 0.9226298045885909, 0.92668052377208, 0.930642436207041, 0.9344727167214898, 0.9382052247107404, 0.941938953714124, 0.9457853129059955, 0.949800829952791, 0.9539438454633338, 0.9580846861324824, 0.962071458129397, 0.965821371867782, 0.969387887391924, 0.9729593156900865, 0.9767748651676454, 0.9809861174932932, 0.985524776632489, 0.99004340202738, 0.9939692358380836, 0.99666267126632, 0.9976234669706118, 0.9966626712663202, 0.9939692358380836, 0.99004340202738, 0.985524776632489, 0.9809861174932932, 0.9767748651676454, 0.9729593156900869, 0.9693878873919243, 0.965821371867782, 0.962071458129397, 0.9580846861324824, 0.953943845463334, 0.9498008299527911, 0.9457853129059958, 0.9419389537141237, 0.9382052247107407, 0.9344727167214897, 0.9306424362070412, 0.92668052377208, 0.9226298045885909, 0.9185783051031918, 0.9146061348640905, 0.9107417547485643, 0.9069507152832589, 0.9031602169233209, 0.899302943608793, 0.8953543614393835, 0.8913432837998814, 0.8873318092159173, 0.8833780165188758, 0.879503465182534, 0.8756833873819688, 0.8718636129851838, 0.8679930428776891, 0.8640524438872106, 0.860063542172666, 0.8560744011756057, 0.8521306631195212, 0.8482496945193664, 0.8444121372516031, 0.8405747734042512, 0.8366963417746907, 0.8327610052237848, 0.8287862275244766, 0.8248112903370804, 0.8208738621556746, 0.8169885053345635, 0.813139304815454, 0.8092902379750413, 0.8054066341301537, 0.801475008924827, 0.7975099885756683, 0.7935448546355021, 0.7896117399853813, 0.78572320618359, 0.7818657155635479, 0.7780083225931446, 0.7741210691293517, 0.7701921918738296, 0.7662342975634449, 0.762276318466816, 0.7583463295869456, 0.7544553974051496, 0.7505917198508096, 0.7467281165520488, 0.7428381578889276, 0.7389113904741645, 0.7349589144200912, 0.7310063728410634, 0.7270787463980298, 0.723185945288188, 0.7193174866852816, 0.7154490862877939, 0.711557048226289, 0.7076319465229217, 0.7036837173485014, 0.6997354361664632, 0.695809652681379, 0.6919153588607692, 0.6880431056524637, 0.6841708991568319, 0.6802772176927693, 0.676353460196645, 0.6724086392587748, 0.6684637761683602, 0.6645394661039811, 0.6606439565142922, 0.6567686278054273, 0.6528933372930543, 0.6489983284051153, 0.6450756748287719, 0.6411336407416013, 0.6371915719128722, 0.6332684629299905, 0.6293719474386312, 0.6254940839595026, 0.6216162521861482, 0.6177201523022736, 0.613798418230802, 0.609858697419159, 0.6059189475842941, 0.6019968330727682, 0.5980994746395399, 0.5942194936053046, 0.5903395392114728, 0.5864425299808943, 0.582521570663706, 0.5785837935566192, 0.5746459919359982, 0.5707247112977322, 0.5668266391217469, 0.5629448695590019, 0.5590631226027533, 0.5551653467484928, 0.5512450463449442, 0.5473089186292603, 0.5433727700242913, 0.5394521958096385, 0.535553514195611, 0.531670220529267, 0.5277869461997231, 0.5238885180457915, 0.5199687822623099, 0.5160340653802733, 0.5120993305703243, 0.5081793597951574, 0.504280154309183, 0.5003955526028026, 0.4965109675411594, 0.49261198022651037, 0.48869273113747796, 0.4847592286730664, 0.48082571073661323, 0.4769062588482405, 0.4730066007009881, 0.4691208701393296, 0.465235153974466, 0.4613356845320583, 0.4574168568496106, 0.45348440478728125, 0.4495519393171678, 0.4456329358938475, 0.4417328851316894, 0.4378461763298108, 0.4339594800232603, 0.4300595930415385, 0.42614113137207477, 0.4222095909727884, 0.4182780389224855, 0.41435942453969876, 0.41045903241444553, 0.40657147355533746, 0.40268392556375177, 0.3987836758819608, 0.3948655326700714, 0.39093478515897495, 0.3870040275094436, 0.38308575141077594, 0.3791850621712369, 0.37529676362408976, 0.37140847453505765, 0.3675079092649139, 0.36359004322588473, 0.35965998576044467, 0.35572991947341165, 0.3518119378082326, 0.34791099007712606, 0.3440220479315663, 0.3401331140104765, 0.3362322740787197, 0.33231464898437846, 0.3283851915441838, 0.3244557264411552, 0.3205380009090844, 0.3166368287576802, 0.3127473275712663, 0.3088578335180501, 0.3049567548622177, 0.30103933858627274, 0.29711040153687474, 0.29318145785503674, 0.28926395464713717, 0.2853625884464151, 0.28147260341266966, 0.2775826245366801, 0.27368133904607833, 0.2697641028025072, 0.265835614958994, 0.26190712140886374, 0.2579898103683593, 0.25408827747294943, 0.2501978761571798, 0.2463074801181481, 0.24240601638514517, 0.23848893411177305, 0.23456083117712104, 0.23063272337642837, 0.22671557732374695, 0.22281390262954048, 0.21892314637895774, 0.2150323946010038, 0.2111307785296073, 0.2072138263818015, 0.20328604966882013, 0.19935826886106747, 0.19544126304308435, 0.19153946944873618, 0.18764841455524578, 0.1837573633926285, 0.17985561869879718, 0.1759387746271211, 0.17201126999634742, 0.16808376198611255, 0.1641668736198981, 0.16026498241494605, 0.15637368108927763, 0.15248238280278076, 0.14858053143217545, 0.14466377482416207, 0.14073649178659536, 0.13680920604029564, 0.13289241392901635, 0.12899044512602975, 0.12509894632791205, 0.12120744991674583, 0.1173055123994351, 0.11338882377013983, 0.10946171471550199, 0.10553460358815844, 0.1016178877919989, 0.09771586041634821, 0.09382421057550118, 0.08993256249958806, 0.08603055825683129, 0.08211391897602711, 0.07818693849564376, 0.07425995655263924, 0.07034329810134445, 0.06644123044948015, 0.06254947410506716, 0.05865771892545087, 0.05475566654051106, 0.050839058586702936, 0.04691216286608866, 0.04298526627494978, 0.03906864691123343, 0.03516655678643834, 0.03127473716758754, 0.027382918127629385, 0.023480835590340643, 0.01956424132335075, 0.015637387583818937, 0.011710533555277233, 0.007793935500246458, 0.00389184043349248, -4.939228250543178e-18, -0.0038918404334923407, -0.007793935500246302, -0.011710533555277071, -0.015637387583818756, -0.019564241323350575, -0.023480835590340504, -0.027382918127629236, -0.03127473716758737, -0.03516655678643819, -0.039068646911233296, -0.0429852662749497, -0.046912162866088486, -0.05083905858670272, -0.054755666540510874, -0.05865771892545073, -0.06254947410506712, -0.06644123044947986, -0.07034329810134429, -0.07425995655263905, -0.07818693849564361, -0.08211391897602698, -0.0860305582568312, -0.08993256249958792, -0.09382421057550104, -0.09771586041634805, -0.1016178877919988, -0.10553460358815835, -0.10946171471550188, -0.11338882377013969, -0.1173055123994351, -0.1212074499167457, -0.1250989463279118, -0.12899044512602956, -0.1328924139290161, -0.13680920604029542, -0.14073649178659517, -0.14466377482416198, -0.14858053143217528, -0.15248238280278056, -0.1563736810892774, -0.16026498241494588, -0.1641668736198979, -0.16808376198611227, -0.17201126999634714, -0.175938774627121, -0.17985561869879696, -0.18375736339262833, -0.18764841455524545, -0.191539469448736, -0.19544126304308418, -0.19935826886106733, -0.20328604966881997, -0.20721382638180144, -0.21113077852960727, -0.2150323946010036, -0.21892314637895766, -0.22281390262954026, -0.22671557732374673, -0.23063272337642804, -0.23456083117712076, -0.23848893411177288, -0.2424060163851449, -0.246307480118148, -0.2501978761571797, -0.2540882774729494, -0.2579898103683591, -0.2619071214088636, -0.2658356149589939, -0.26976410280250696, -0.2736813390460782, -0.27758262453668, -0.2814726034126695, -0.285362588446415, -0.28926395464713694, -0.29318145785503646, -0.2971104015368745, -0.3010393385862726, -0.30495675486221774, -0.3088578335180499, -0.31274732757126616, -0.31663682875767984, -0.3205380009090843, -0.32445572644115517, -0.3283851915441836, -0.33231464898437824, -0.3362322740787195, -0.34013311401047636, -0.34402204793156627, -0.347910990077126, -0.3518119378082323, -0.35572991947341154, -0.3596599857604444, -0.3635900432258845, -0.3675079092649137, -0.3714084745350574, -0.37529676362408976, -0.37918506217123665, -0.3830857514107759, -0.38700402750944357, -0.3909347851589751, -0.39486553267007135, -0.3987836758819608, -0.4026839255637516, -0.4065714735553372, -0.4104590324144453, -0.41435942453969876, -0.4182780389224855, -0.42220959097278793, -0.42614113137207454, -0.4300595930415385, -0.4339594800232599, -0.43784617632981065, -0.44173288513168923, -0.4456329358938475, -0.44955193931716764, -0.45348440478728125, -0.4574168568496103, -0.4613356845320583, -0.4652351539744659, -0.46912087013932985, -0.4730066007009878, -0.4769062588482404, -0.48082571073661307, -0.4847592286730662, -0.4886927311374779, -0.49261198022651026, -0.49651096754115914, -0.5003955526028021, -0.5042801543091827, -0.508179359795157, -0.512099330570324, -0.5160340653802734, -0.5199687822623098, -0.5238885180457913, -0.527786946199723, -0.5316702205292666, -0.5355535141956108, -0.5394521958096383, -0.5433727700242909, -0.5473089186292602, -0.5512450463449445, -0.5551653467484924, -0.5590631226027533, -0.5629448695590016, -0.566826639121747, -0.570724711297732, -0.5746459919359984, -0.5785837935566188, -0.5825215706637061, -0.5864425299808942, -0.5903395392114723, -0.5942194936053047, -0.5980994746395396, -0.6019968330727682, -0.6059189475842935, -0.6098586974191589, -0.6137984182308017, -0.6177201523022734, -0.6216162521861476, -0.6254940839595025, -0.6293719474386312, -0.63326846292999, -0.637191571912872, -0.6411336407416011, -0.6450756748287717, -0.6489983284051147, -0.6528933372930544, -0.656768627805427, -0.6606439565142921, -0.6645394661039806, -0.66846377616836, -0.6724086392587748, -0.6763534601966443, -0.6802772176927695, -0.6841708991568318, -0.6880431056524637, -0.6919153588607689, -0.6958096526813791, -0.699735436166463, -0.7036837173485015, -0.7076319465229213, -0.7115570482262887, -0.7154490862877939, -0.7193174866852812, -0.723185945288188, -0.7270787463980299, -0.7310063728410633, -0.7349589144200905, -0.7389113904741649, -0.7428381578889273, -0.7467281165520484, -0.7505917198508093, -0.7544553974051497, -0.7583463295869453, -0.7622763184668155, -0.7662342975634451, -0.7701921918738298, -0.7741210691293521, -0.778008322593144, -0.781865715563548, -0.7857232061835897, -0.7896117399853808, -0.7935448546355025, -0.7975099885756682, -0.801475008924827, -0.8054066341301533, -0.8092902379750414, -0.8131393048154542, -0.8169885053345631, -0.8208738621556746, -0.8248112903370807, -0.8287862275244765, -0.8327610052237845, -0.8366963417746909, -0.8405747734042512, -0.8444121372516029, -0.8482496945193657, -0.8521306631195216, -0.8560744011756054, -0.8600635421726651, -0.8640524438872103, -0.8679930428776895, -0.8718636129851841, -0.8756833873819687, -0.8795034651825344, -0.8833780165188757, -0.8873318092159173, -0.8913432837998809, -0.8953543614393835, -0.8993029436087925, -0.9031602169233206, -0.9069507152832583, -0.9107417547485642, -0.9146061348640905, -0.9185783051031915, -0.9226298045885909, -0.92668052377208, -0.9306424362070409, -0.9344727167214893, -0.9382052247107407, -0.941938953714124, -0.9457853129059947, -0.9498008299527905, -0.953943845463334, -0.9580846861324821, -0.9620714581293968, -0.965821371867782, -0.9693878873919243, -0.9729593156900869, -0.9767748651676449, -0.9809861174932932, -0.985524776632489, -0.9900434020273798, -0.993969235838083, -0.9966626712663204, -0.9976234669706118, -0.9966626712663206, -0.9939692358380836, -0.9900434020273798, -0.985524776632489, -0.9809861174932936, -0.9767748651676452, -0.9729593156900869, -0.969387887391924, -0.9658213718677825, -0.962071458129397, -0.9580846861324827, -0.9539438454633341, -0.9498008299527908, -0.9457853129059955, -0.941938953714124, -0.9382052247107415, -0.93447271672149, -0.9306424362070416, -0.92668052377208, -0.9226298045885911, -0.9185783051031917, -0.9146061348640905, -0.9107417547485647, -0.9069507152832589, -0.9031602169233208, -0.899302943608793, -0.8953543614393841, -0.8913432837998814, -0.8873318092159175, -0.8833780165188757, -0.8795034651825342, -0.8756833873819688, -0.8718636129851841, -0.8679930428776899, -0.8640524438872105, -0.860063542172666, -0.8560744011756057, -0.852130663119521, -0.8482496945193662, -0.8444121372516029, -0.8405747734042514, -0.8366963417746912, -0.8327610052237845, -0.8287862275244766, -0.8248112903370807, -0.8208738621556746, -0.8169885053345631, -0.8131393048154542, -0.8092902379750414, -0.8054066341301535, -0.8014750089248271, -0.7975099885756686, -0.7935448546355025, -0.7896117399853808, -0.7857232061835899, -0.7818657155635481, -0.7780083225931442, -0.7741210691293517, -0.7701921918738299, -0.7662342975634456, -0.7622763184668156, -0.7583463295869458, -0.7544553974051497, -0.7505917198508093, -0.7467281165520484, -0.7428381578889278, -0.7389113904741647, -0.7349589144200909, -0.7310063728410633, -0.7270787463980302, -0.7231859452881884, -0.7193174866852811, -0.7154490862877938, -0.7115570482262891, -0.7076319465229215, -0.7036837173485014, -0.6997354361664632, -0.6958096526813791, -0.6919153588607692, -0.688043105652464, -0.684170899156832, -0.6802772176927695, -0.6763534601966446, -0.6724086392587747, -0.6684637761683603, -0.6645394661039806, -0.6606439565142923, -0.6567686278054273, -0.6528933372930542, -0.6489983284051152, -0.6450756748287718, -0.6411336407416013, -0.6371915719128726, -0.6332684629299903, -0.6293719474386311, -0.6254940839595028, -0.6216162521861482, -0.6177201523022737, -0.613798418230802, -0.6098586974191593, -0.6059189475842939, -0.6019968330727681, -0.5980994746395399, -0.594219493605305, -0.5903395392114722, -0.5864425299808944, -0.5825215706637066, -0.578583793556619, -0.5746459919359985, -0.570724711297732, -0.5668266391217474, -0.5629448695590019, -0.5590631226027536, -0.5551653467484927, -0.5512450463449446, -0.5473089186292603, -0.5433727700242912, -0.539452195809639, -0.5355535141956107, -0.5316702205292674, -0.5277869461997233, -0.5238885180457917, -0.5199687822623097, -0.5160340653802735, -0.5120993305703244, -0.5081793597951575, -0.5042801543091826, -0.5003955526028026, -0.49651096754116, -0.4926119802265099, -0.4886927311374781, -0.48475922867306664, -0.4808257107366134, -0.4769062588482402, -0.47300660070098793, -0.46912087013932985, -0.4652351539744661, -0.46133568453205814, -0.45741685684961075, -0.4534844047872821, -0.4495519393171675, -0.4456329358938475, -0.4417328851316895, -0.43784617632981115, -0.4339594800232599, -0.4300595930415387, -0.42614113137207466, -0.4222095909727888, -0.41827803892248533, -0.41435942453969876, -0.4104590324144464, -0.4065714735553372, -0.4026839255637518, -0.39878367588196073, -0.39486553267007196, -0.39093478515897473, -0.38700402750944374, -0.38308575141077605, -0.3791850621712369, -0.37529676362409, -0.3714084745350577, -0.3675079092649142, -0.3635900432258844, -0.3596599857604447, -0.3557299194734117, -0.35181193780823267, -0.34791099007712584, -0.34402204793156654, -0.3401331140104767, -0.33623227407872014, -0.3323146489843785, -0.32838519154418405, -0.3244557264411557, -0.32053800090908424, -0.31663682875768, -0.3127473275712663, -0.30885783351805063, -0.3049567548622175, -0.3010393385862727, -0.29711040153687485, -0.29318145785503713, -0.2892639546471374, -0.2853625884464151, -0.2814726034126703, -0.27758262453667987, -0.27368133904607844, -0.26976410280250734, -0.2658356149589943, -0.26190712140886363, -0.2579898103683593, -0.25408827747294943, -0.2501978761571803, -0.2463074801181482, -0.2424060163851454, -0.2384889341117734, -0.23456083117712073, -0.2306327233764284, -0.22671557732374686, -0.22281390262954115, -0.2189231463789574, -0.21503239460100423, -0.21113077852960754, -0.2072138263818012, -0.20328604966882005, -0.1993582688610677, -0.19544126304308448, -0.19153946944873604, -0.18764841455524567, -0.18375736339262846, -0.1798556186987977, -0.175938774627121, -0.17201126999634733, -0.16808376198611247, -0.16416687361989774, -0.1602649824149462, -0.1563736810892778, -0.152482382802781, -0.14858053143217542, -0.14466377482416226, -0.14073649178659559, -0.1368092060402961, -0.13289241392901618, -0.1289904451260297, -0.125098946327912, -0.12120744991674545, -0.11730551239943506, -0.1133888237701398, -0.10946171471550245, -0.10553460358815815, -0.10161788779199898, -0.09771586041634811, -0.09382421057550171, -0.08993256249958792, -0.0860305582568314, -0.08211391897602742, -0.07818693849564345, -0.07425995655263957, -0.07034329810134449, -0.06644123044948046, -0.06254947410506728, -0.05865771892545093, -0.0547556665405114, -0.05083905858670335, -0.04691216286608858, -0.042985266274949724, -0.039068646911233684, -0.03516655678643813, -0.031274737167587344, -0.027382918127629444, -0.023480835590341097, -0.019564241323350644, -0.015637387583819072, -0.011710533555277028, -0.007793935500246974, -0.0038918404334921794, ], [0.0, 0.00387145883979275, 0.007748687036619576, 0.011636576206378908, 0.015538395893735943, 0.019455295805769816, 0.023386130318697497, 0.02732763201937219, 0.03127490801858599, 0.035222183719240255, 0.0391636810871628, 0.0430944979741891, 0.04701135480295746, 0.05091309498928365, 0.054800862818108906, 0.058677931446466436, 0.0625492068578303, 0.06642048286992959, 0.07029756021627602, 0.07418536351043696, 0.07808719038531596, 0.08200420717961057, 0.08593526821933957, 0.08987708665882126, 0.09382473144058115, 0.09777237531208952, 0.10171418054181648, 0.10564518784336145, 0.10956207328425029, 0.1134636577735219, 0.11735109111778134, 0.12122768196265976, 0.12509839872626805, 0.128969116720929, 0.13284572543110856, 0.13673323145274532, 0.1406349935892951, 0.14455220684249123, 0.14848371448112666, 0.15242617767961927, 0.15637457790829762, 0.16032297656954525, 0.1642654170211219, 0.1681968321234204, 0.17211381918688431, 0.1760151639342667, 0.17990203289579831, 0.18377780383607187, 0.1876475587832379, 0.19151731565457436, 0.1953931145188918, 0.19928009707803657, 0.2031817194969741, 0.2070992189516698, 0.211031416119574, 0.21497488503674334, 0.21892446596170023, 0.22287404457993107, 0.22681748002125712, 0.23074954100588063, 0.23466670758038596, 0.23856771572732388, 0.24245376071367067, 0.24632832660283147, 0.2501966661116717, 0.2540650083419508, 0.2579396137254685, 0.26182581937966015, 0.26572722025789575, 0.2696451115541091, 0.27357827870118984, 0.27752316883623857, 0.2814744197022122, 0.28542566739236863, 0.2893705114388717, 0.29330349109129106, 0.29722092407858786, 0.30112147921532184, 0.30500639397928037, 0.308879301721457, 0.3127456924096559, 0.31661208677674935, 0.32048504790845855, 0.32437017986969546, 0.32827126592409533, 0.33218967865123405, 0.33612415373759646, 0.3400709644409719, 0.3440244729410516, 0.3479779771994212, 0.3519247263452508, 0.35585895100391773, 0.35977675157918076, 0.36367670797338314, 0.36756011564594027, 0.37143080912280907, 0.37529459663694065, 0.3791583890290258, 0.3830291532971704, 0.3869128489641756, 0.39081350934713216, 0.39473260907554997, 0.3986688167640332, 0.4026181738815762, 0.40657467624852067, 0.41053117301061864, 0.41448044878746404, 0.41841632556113395, 0.42233461637343067, 0.42623378394398437, 0.4301152009373126, 0.433982968364622, 0.43784331556039263, 0.4417036692005371, 0.44557153015280737, 0.44945332763377216, 0.4533534253149309, 0.45727343263122944, 0.46121192958575447, 0.46516465141789876, 0.46912510986073547, 0.47308556087853054, 0.4770381749528727, 0.48097623351060487, 0.4848951691360513, 0.48879328898288327, 0.49267206729221014, 0.496535957334142, 0.5003917458182444, 0.5042475428867489, 0.5081115575130467, 0.5119908426834943, 0.5158902016148168, 0.5198114240686429, 0.5237529735896447, 0.5277101792339277, 0.5316759090709245, 0.5356416289351069, 0.5395986898436724, 0.5435396505082483, 0.5474594333904625, 0.55135613538721, 0.5552313644508033, 0.5590900439848606, 0.5629397072514967, 0.5667893821772697, 0.5706482309228408, 0.5745241484386278, 0.5784225335446116, 0.5823454229762731, 0.5862911263610343, 0.5902544257743328, 0.5942273183502277, 0.5982001971865267, 0.6021632971910236, 0.6061081892395295, 0.6100290950548637, 0.6139238187660802, 0.6177941461557512, 0.6216456412991446, 0.6254868610658868, 0.6293280971846551, 0.6331798296569475, 0.6370511227043825, 0.6409482074546061, 0.6448734716094395, 0.6488250183628339, 0.6527968730347373, 0.6567798185240676, 0.660762744313829, 0.6647343130641208, 0.6686846963874289, 0.6726071157533422, 0.6764989487376364, 0.6803622221374908, 0.6842034032641015, 0.6880325059406809, 0.6918616327089914, 0.6957031635174122, 0.699567859880343, 0.7034631725719781, 0.7073920165569463, 0.7113522117765568, 0.715336691547583, 0.7193344656576264, 0.7233322097486309, 0.7273162537852916, 0.7312746757166029, 0.7351991827785205, 0.7390864869319328, 0.7429389502585401, 0.746764381411195, 0.7505749904208237, 0.7543856377272571, 0.758211624804101, 0.7620663508195895, 0.765959189771882, 0.7698939195688477, 0.7738679605252987, 0.7778725654132799, 0.781893962726352, 0.7859153096772777, 0.7899191834293052, 0.7938902480427636, 0.797817695531677, 0.8016970798659196, 0.8055312416218645, 0.8093301483717927, 0.8131096342974901, 0.8168891891671121, 0.8206890969605579, 0.8245273349061266, 0.8284166964443995, 0.8323625865718665, 0.8363618547836748, 0.8404028892148407, 0.84446701440771, 0.8485310398451702, 0.8525706255109754, 0.8565639918051662, 0.8604954266121061, 0.8643580460833725, 0.8681553507158322, 0.8719012757355808, 0.8756186448148491, 0.8793361703730213, 0.8830843689728037, 0.8868909432427157, 0.8907762935518587, 0.8947498434568819, 0.898807784985284, 0.9029326791090426, 0.9070951027739482, 0.9112572473657023, 0.9153780827613228, 0.9194194468153412, 0.9233522398233799, 0.9271618262978175, 0.9308517885791363, 0.934445339127708, 0.9379839652455021, 0.9415232211027945, 0.9451259556408059, 0.948853623413019, 0.9527566209643666, 0.9568647825316745, 0.9611792263054967, 0.9656666531019695, 0.970256968385465, 0.9748447497721874, 0.9792946547586291, 0.9834504078266264, 0.9871465774914465, 0.9902220053984311, 0.9925335254773573, 0.993968541054711, 0.9944551227706351, 0.9939685410547112, 0.9925335254773573, 0.9902220053984311, 0.9871465774914465, 0.9834504078266264, 0.9792946547586291, 0.9748447497721877, 0.9702569683854653, 0.9656666531019695, 0.9611792263054967, 0.9568647825316745, 0.9527566209643669, 0.9488536234130193, 0.945125955640806, 0.9415232211027945, 0.9379839652455024, 0.9344453391277078, 0.9308517885791365, 0.9271618262978175, 0.9233522398233799, 0.9194194468153412, 0.9153780827613228, 0.9112572473657025, 0.9070951027739482, 0.9029326791090426, 0.8988077849852841, 0.8947498434568819, 0.8907762935518587, 0.8868909432427157, 0.8830843689728038, 0.8793361703730213, 0.8756186448148491, 0.8719012757355808, 0.8681553507158322, 0.8643580460833725, 0.8604954266121061, 0.8565639918051665, 0.8525706255109754, 0.8485310398451705, 0.8444670144077101, 0.8404028892148405, 0.836361854783675, 0.8323625865718666, 0.8284166964443995, 0.8245273349061266, 0.8206890969605581, 0.8168891891671123, 0.8131096342974898, 0.8093301483717925, 0.8055312416218646, 0.8016970798659196, 0.797817695531677, 0.7938902480427636, 0.7899191834293053, 0.785915309677278, 0.7818939627263521, 0.7778725654132798, 0.7738679605252986, 0.7698939195688478, 0.7659591897718819, 0.7620663508195896, 0.7582116248041012, 0.7543856377272571, 0.7505749904208237, 0.746764381411195, 0.7429389502585401, 0.7390864869319329, 0.7351991827785206, 0.7312746757166029, 0.7273162537852919, 0.7233322097486309, 0.7193344656576268, 0.7153366915475827, 0.7113522117765573, 0.7073920165569464, 0.7034631725719781, 0.6995678598803432, 0.6957031635174121, 0.6918616327089915, 0.6880325059406809, 0.6842034032641018, 0.6803622221374908, 0.6764989487376365, 0.6726071157533422, 0.668684696387429, 0.6647343130641209, 0.6607627443138289, 0.6567798185240677, 0.6527968730347371, 0.6488250183628338, 0.6448734716094398, 0.6409482074546063, 0.6370511227043827, 0.6331798296569477, 0.6293280971846554, 0.6254868610658871, 0.6216456412991447, 0.6177941461557511, 0.6139238187660803, 0.6100290950548637, 0.6061081892395296, 0.6021632971910236, 0.5982001971865271, 0.5942273183502278, 0.5902544257743331, 0.5862911263610343, 0.5823454229762731, 0.5784225335446117, 0.5745241484386274, 0.570648230922841, 0.5667893821772695, 0.5629397072514967, 0.5590900439848606, 0.5552313644508037, 0.55135613538721, 0.5474594333904629, 0.5435396505082484, 0.5395986898436724, 0.5356416289351069, 0.5316759090709243, 0.5277101792339278, 0.5237529735896448, 0.5198114240686431, 0.5158902016148167, 0.5119908426834945, 0.5081115575130469, 0.5042475428867493, 0.5003917458182444, 0.49653595733414185, 0.4926720672922102, 0.4887932889828833, 0.4848951691360513, 0.480976233510605, 0.47703817495287276, 0.4730855608785306, 0.4691251098607355, 0.465164651417899, 0.4612119295857545, 0.45727343263122944, 0.4533534253149306, 0.44945332763377216, 0.4455715301528075, 0.4417036692005371, 0.43784331556039263, 0.4339829683646223, 0.4301152009373125, 0.42623378394398453, 0.4223346163734308, 0.4184163255611341, 0.4144804487874641, 0.41053117301061864, 0.4065746762485208, 0.4026181738815762, 0.3986688167640334, 0.3947326090755499, 0.39081350934713216, 0.38691284896417555, 0.3830291532971706, 0.37915838902902593, 0.37529459663694065, 0.3714308091228092, 0.3675601156459402, 0.3636767079733834, 0.35977675157918076, 0.35585895100391796, 0.3519247263452508, 0.34797797719942125, 0.34402447294105176, 0.3400709644409721, 0.3361241537375966, 0.33218967865123394, 0.3282712659240952, 0.32437017986969524, 0.32048504790845866, 0.31661208677674935, 0.31274569240965605, 0.30887930172145706, 0.3050063939792805, 0.3011214792153219, 0.2972209240785882, 0.29330349109129117, 0.28937051143887155, 0.28542566739236874, 0.2814744197022121, 0.2775231688362387, 0.27357827870118984, 0.26964511155410925, 0.26572722025789575, 0.2618258193796604, 0.2579396137254687, 0.254065008341951, 0.25019666611167174, 0.24632832660283147, 0.24245376071367084, 0.23856771572732383, 0.23466670758038619, 0.23074954100588072, 0.22681748002125732, 0.2228740445799311, 0.2189244659617005, 0.21497488503674345, 0.2110314161195742, 0.20709921895166997, 0.20318171949697392, 0.1992800970780367, 0.19539311451889177, 0.19151731565457453, 0.18764755878323802, 0.18377780383607195, 0.17990203289579837, 0.1760151639342669, 0.17211381918688445, 0.16819683212342074, 0.16426541702112207, 0.16032297656954528, 0.15637457790829784, 0.15242617767961927, 0.14848371448112677, 0.1445522068424911, 0.14063499358929535, 0.1367332314527453, 0.1328457254311089, 0.1289691167209291, 0.12509839872626818, 0.12122768196265991, 0.11735109111778128, 0.11346365777352209, 0.1095620732842503, 0.10564518784336145, 0.10171418054181634, 0.09777237531208971, 0.09382473144058114, 0.08987708665882145, 0.08593526821933963, 0.08200420717961047, 0.07808719038531603, 0.0741853635104369, 0.07029756021627626, 0.06642048286992962, 0.06254920685783032, 0.0586779314464663, 0.054800862818109024, 0.0509130949892837, 0.0470113548029577, 0.04309449797418914, 0.03916368108716269, 0.03522218371924035, 0.03127490801858604, 0.027327632019372453, 0.023386130318697563, 0.019455295805769917, 0.015538395893735872, 0.011636576206379153, 0.007748687036619611, 0.00387145883979302, -1.67218281086607e-17, -0.0038714588397928723, -0.007748687036619459, -0.011636576206378988, -0.0155383958937357, -0.019455295805769747, -0.0233861303186974, -0.0273276320193723, -0.03127490801858586, -0.0352221837192402, -0.03916368108716257, -0.04309449797418907, -0.047011354802957525, -0.05091309498928349, -0.054800862818108795, -0.058677931446466165, -0.06254920685783025, -0.06642048286992935, -0.0702975602162761, -0.0741853635104367, -0.0780871903853159, -0.08200420717961034, -0.08593526821933954, -0.0898770866588213, -0.09382473144058098, -0.09777237531208956, -0.1017141805418162, -0.10564518784336138, -0.10956207328425017, -0.11346365777352191, -0.11735109111778123, -0.12122768196265983, -0.12509839872626793, -0.12896911672092892, -0.13284572543110865, -0.13673323145274505, -0.14063499358929515, -0.14455220684249098, -0.14848371448112666, -0.15242617767961913, -0.15637457790829762, -0.16032297656954514, -0.1642654170211219, -0.16819683212342051, -0.1721138191868842, -0.17601516393426675, -0.1799020328957981, -0.18377780383607178, -0.18764755878323772, -0.19151731565457436, -0.1953931145188916, -0.1992800970780366, -0.2031817194969738, -0.20709921895166988, -0.21103141611957413, -0.21497488503674322, -0.21892446596170043, -0.22287404457993087, -0.22681748002125712, -0.23074954100588044, -0.23466670758038596, -0.23856771572732366, -0.24245376071367056, -0.24632832660283127, -0.25019666611167163, -0.25406500834195095, -0.2579396137254685, -0.26182581937966015, -0.2657272202578956, -0.269645111554109, -0.2735782787011897, -0.2775231688362385, -0.28147441970221193, -0.2854256673923686, -0.28937051143887127, -0.293303491091291, -0.2972209240785879, -0.3011214792153217, -0.30500639397928053, -0.3088793017214569, -0.31274569240965594, -0.316612086776749, -0.32048504790845855, -0.3243701798696952, -0.32827126592409506, -0.3321896786512337, -0.3361241537375964, -0.34007096444097196, -0.3440244729410516, -0.34797797719942125, -0.35192472634525057, -0.3558589510039178, -0.35977675157918054, -0.36367670797338325, -0.36756011564593993, -0.371430809122809, -0.37529459663694054, -0.3791583890290257, -0.3830291532971705, -0.38691284896417555, -0.3908135093471323, -0.3947326090755498, -0.3986688167640334, -0.402618173881576, -0.4065746762485206, -0.4105311730106186, -0.41448044878746393, -0.41841632556113395, -0.4223346163734303, -0.4262337839439845, -0.4301152009373124, -0.433982968364622, -0.43784331556039247, -0.44170366920053705, -0.4455715301528075, -0.4494533276337721, -0.4533534253149306, -0.4572734326312292, -0.46121192958575447, -0.46516465141789887, -0.4691251098607357, -0.4730855608785303, -0.4770381749528727, -0.4809762335106047, -0.48489516913605113, -0.4887932889828833, -0.49267206729221014, -0.4965359573341417, -0.5003917458182443, -0.504247542886749, -0.5081115575130466, -0.5119908426834943, -0.5158902016148168, -0.5198114240686429, -0.5237529735896445, -0.5277101792339277, -0.5316759090709241, -0.5356416289351067, -0.5395986898436722, -0.5435396505082483, -0.5474594333904628, -0.5513561353872102, -0.5552313644508033, -0.5590900439848606, -0.5629397072514963, -0.5667893821772696, -0.5706482309228408, -0.5745241484386276, -0.5784225335446114, -0.5823454229762731, -0.5862911263610343, -0.5902544257743327, -0.594227318350228, -0.5982001971865267, -0.6021632971910235, -0.606108189239529, -0.6100290950548636, -0.6139238187660802, -0.6177941461557509, -0.621645641299144, -0.6254868610658869, -0.6293280971846552, -0.6331798296569472, -0.6370511227043825, -0.6409482074546062, -0.6448734716094396, -0.6488250183628335, -0.6527968730347372, -0.6567798185240674, -0.6607627443138286, -0.6647343130641205, -0.6686846963874289, -0.6726071157533422, -0.6764989487376358, -0.680362222137491, -0.6842034032641018, -0.6880325059406809, -0.6918616327089909, -0.6957031635174122, -0.6995678598803431, -0.7034631725719782, -0.707392016556946, -0.7113522117765569, -0.7153366915475827, -0.719334465657626, -0.7233322097486309, -0.7273162537852919, -0.7312746757166028, -0.73519918277852, -0.7390864869319332, -0.7429389502585398, -0.7467643814111948, -0.7505749904208234, -0.7543856377272572, -0.758211624804101, -0.7620663508195892, -0.765959189771882, -0.769893919568848, -0.7738679605252986, -0.7778725654132792, -0.7818939627263521, -0.7859153096772776, -0.789919183429305, -0.7938902480427641, -0.797817695531677, -0.8016970798659196, -0.8055312416218643, -0.8093301483717926, -0.8131096342974901, -0.8168891891671117, -0.8206890969605581, -0.8245273349061268, -0.8284166964443994, -0.8323625865718665, -0.8363618547836751, -0.8404028892148405, -0.84446701440771, -0.8485310398451699, -0.8525706255109757, -0.8565639918051662, -0.8604954266121052, -0.8643580460833721, -0.8681553507158326, -0.8719012757355812, -0.8756186448148489, -0.8793361703730216, -0.8830843689728037, -0.8868909432427157, -0.8907762935518584, -0.8947498434568819, -0.8988077849852837, -0.9029326791090423, -0.9070951027739477, -0.9112572473657023, -0.9153780827613228, -0.9194194468153407, -0.9233522398233799, -0.9271618262978175, -0.9308517885791363, -0.9344453391277074, -0.9379839652455024, -0.9415232211027946, -0.9451259556408054, -0.9488536234130185, -0.9527566209643669, -0.9568647825316741, -0.9611792263054966, -0.9656666531019695, -0.9702569683854653, -0.9748447497721877, -0.9792946547586285, -0.9834504078266264, -0.9871465774914465, -0.990222005398431, -0.9925335254773571, -0.9939685410547112, -0.9944551227706351, -0.9939685410547112, -0.9925335254773573, -0.990222005398431, -0.9871465774914465, -0.9834504078266267, -0.9792946547586289, -0.9748447497721877, -0.9702569683854653, -0.9656666531019699, -0.9611792263054967, -0.9568647825316746, -0.952756620964367, -0.9488536234130188, -0.945125955640806, -0.9415232211027945, -0.9379839652455032, -0.9344453391277081, -0.9308517885791369, -0.9271618262978175, -0.92335223982338, -0.9194194468153409, -0.9153780827613228, -0.9112572473657028, -0.9070951027739482, -0.9029326791090424, -0.8988077849852841, -0.8947498434568825, -0.8907762935518589, -0.8868909432427158, -0.8830843689728037, -0.8793361703730216, -0.8756186448148489, -0.8719012757355812, -0.8681553507158329, -0.8643580460833723, -0.8604954266121061, -0.8565639918051667, -0.8525706255109754, -0.8485310398451703, -0.84446701440771, -0.8404028892148407, -0.8363618547836754, -0.8323625865718665, -0.8284166964443995, -0.8245273349061268, -0.8206890969605581, -0.8168891891671117, -0.8131096342974901, -0.8093301483717927, -0.8055312416218644, -0.8016970798659199, -0.7978176955316775, -0.7938902480427642, -0.789919183429305, -0.785915309677278, -0.7818939627263523, -0.7778725654132794, -0.7738679605252986, -0.769893919568848, -0.7659591897718826, -0.7620663508195893, -0.7582116248041013, -0.7543856377272574, -0.7505749904208234, -0.7467643814111948, -0.7429389502585404, -0.7390864869319331, -0.7351991827785203, -0.7312746757166028, -0.7273162537852922, -0.7233322097486313, -0.7193344656576264, -0.7153366915475827, -0.7113522117765573, -0.7073920165569463, -0.7034631725719781, -0.6995678598803432, -0.6957031635174122, -0.6918616327089911, -0.688032505940681, -0.6842034032641019, -0.6803622221374912, -0.6764989487376363, -0.6726071157533421, -0.6686846963874292, -0.6647343130641205, -0.660762744313829, -0.6567798185240677, -0.6527968730347372, -0.6488250183628338, -0.6448734716094396, -0.6409482074546065, -0.637051122704383, -0.6331798296569475, -0.6293280971846553, -0.6254868610658871, -0.6216456412991446, -0.6177941461557512, -0.6139238187660803, -0.610029095054864, -0.6061081892395294, -0.6021632971910235, -0.5982001971865272, -0.5942273183502282, -0.5902544257743327, -0.5862911263610344, -0.5823454229762735, -0.5784225335446115, -0.5745241484386276, -0.5706482309228408, -0.5667893821772699, -0.5629397072514966, -0.559090043984861, -0.5552313644508037, -0.5513561353872104, -0.547459433390463, -0.5435396505082483, -0.5395986898436727, -0.5356416289351067, -0.5316759090709245, -0.527710179233928, -0.523752973589645, -0.5198114240686429, -0.5158902016148169, -0.5119908426834947, -0.5081115575130469, -0.5042475428867489, -0.5003917458182445, -0.4965359573341423, -0.49267206729220975, -0.48879328898288354, -0.48489516913605146, -0.48097623351060526, -0.4770381749528727, -0.47308556087853054, -0.4691251098607357, -0.46516465141789914, -0.46121192958575424, -0.4572734326312296, -0.45335342531493145, -0.449453327633772, -0.44557153015280754, -0.4417036692005371, -0.437843315560393, -0.43398296836462186, -0.4301152009373127, -0.42623378394398465, -0.4223346163734312, -0.41841632556113384, -0.4144804487874641, -0.4105311730106195, -0.4065746762485206, -0.4026181738815762, -0.3986688167640334, -0.39473260907555036, -0.39081350934713205, -0.38691284896417566, -0.38302915329717063, -0.379158389029026, -0.3752945966369408, -0.37143080912280924, -0.36756011564594054, -0.36367670797338314, -0.3597767515791808, -0.35585895100391796, -0.351924726345251, -0.3479779771994211, -0.3440244729410518, -0.34007096444097223, -0.33612415373759713, -0.33218967865123405, -0.3282712659240955, -0.3243701798696957, -0.3204850479084585, -0.3166120867767493, -0.31274569240965605, -0.3088793017214575, -0.3050063939792803, -0.30112147921532184, -0.2972209240785882, -0.2933034910912916, -0.28937051143887177, -0.2854256673923688, -0.28147441970221276, -0.27752316883623834, -0.27357827870118995, -0.26964511155410936, -0.2657272202578961, -0.26182581937966026, -0.25793961372546875, -0.254065008341951, -0.2501966661116722, -0.24632832660283152, -0.24245376071367103, -0.2385677157273242, -0.2346667075803859, -0.23074954100588072, -0.22681748002125726, -0.22287404457993173, -0.21892446596170023, -0.21497488503674367, -0.21103141611957438, -0.2070992189516696, -0.20318171949697397, -0.1992800970780369, -0.19539311451889196, -0.19151731565457436, -0.1876475587832379, -0.18377780383607195, -0.17990203289579879, -0.1760151639342668, -0.17211381918688443, -0.16819683212342074, -0.16426541702112174, -0.1603229765695454, -0.15637457790829795, -0.15242617767961952, -0.14848371448112677, -0.1445522068424913, -0.14063499358929554, -0.13673323145274577, -0.13284572543110865, -0.12896911672092903, -0.1250983987262682, -0.12122768196265955, -0.11735109111778128, -0.11346365777352208, -0.10956207328425069, -0.10564518784336117, -0.10171418054181641, -0.09777237531208957, -0.09382473144058168, -0.08987708665882127, -0.08593526821933976, -0.08200420717961084, -0.07808719038531575, -0.07418536351043724, -0.07029756021627627, -0.06642048286992994, -0.06254920685783037, -0.058677931446466366, -0.05480086281810936, -0.050913094989284105, -0.04701135480295761, -0.0430944979741891, -0.03916368108716295, -0.035222183719240165, -0.03127490801858584, -0.027327632019372508, -0.023386130318697993, -0.019455295805769754, -0.015538395893736007, -0.011636576206378944, -0.007748687036620119, -0.0038714588397927288, ], [0.0, 0.003832144947845143, 0.007667259620427255, 0.01150819994520477, 0.015357598598731878, 0.01921776407518329, 0.023090592131472173, 0.02697749299272434, 0.0308793371023987, 0.03479642149579184, 0.03872845809077417, 0.04267458435513174, 0.04663339595748263, 0.050603000170742464, 0.05458108800535182, 0.05856502233403749, 0.06255193865798074, 0.06653885467922839, 0.07052278450454416, 0.07450085312469806, 0.0784704067974853, 0.08242911511334768, 0.0863750608338241, 0.0903068140535767, 0.09422348782914942, 0.09812477311961453, 0.10201095166936389, 0.10588288630183136, 0.10974198895313796, 0.11359016762391001, 0.11742975423355803, 0.12126341609343996, 0.12509405434560072, 0.1289246932180583, 0.13275836430645174, 0.13659799029134678, 0.1404462725326586, 0.1443055868458877, 0.14817789146394325, 0.15206465073428246, 0.1559667775107425, 0.1598845964948671, 0.16381782998915256, 0.1677656066743162, 0.17172649314654392, 0.1756985470818964, 0.17967939006666933, 0.18366629737608928, 0.18765630132824856, 0.19164630431086638, 0.1956331971958238, 0.19961397863551944, 0.20358587068486417, 0.20754642631571604, 0.2114936246827269, 0.21542595045044147, 0.21934245408459405, 0.22324279072387443, 0.2271272360554148, 0.23099667848760255, 0.23485258781477183, 0.23869696146578484, 0.24253225028838848, 0.24636126661071753, 0.2501870780100897, 0.2540128907807273, 0.25784192750428847, 0.2616773033737682, 0.2655219059922562, 0.26937828325965063, 0.2732485436759579, 0.2771342729391469, 0.28103647011492405, 0.2849555059272722, 0.2888911048891006, 0.2928423520928525, 0.296807724545144, 0.30078514599277967, 0.30477206328537615, 0.3087655414867368, 0.31276237421509157, 0.3167592050897429, 0.32075265571216816, 0.3247394553312424, 0.3287165672465756, 0.33268130709581073, 0.3366314484488044, 0.34056531158462416, 0.3444818319402088, 0.3483806054701854, 0.35226190901816634, 0.3561266947389911, 0.35997655859373884, 0.3638136839279809, 0.36764076210122376, 0.3714608930252637, 0.37527746925701666, 0.37909404794664936, 0.38291421543868526, 0.38674144964214263, 0.3905789854122033, 0.3944296881141359, 0.39829594027131676, 0.40217954574180653, 0.4060816552378475, 0.4100027162222725, 0.4139424493134636, 0.41789985233931043, 0.4218732321373931, 0.4258602631423513, 0.42985807077205646, 0.4338633366609357, 0.43787242192870146, 0.4418815039493069, 0.44588672152685327, 0.44988432301505304, 0.45387081175053096, 0.45784308321598277, 0.4617985486072866, 0.4657352399415002, 0.4696518924947247, 0.4735480011773872, 0.4774238484103256, 0.48128050212343215, 0.4851197836205926, 0.48894420619855583, 0.4927568865301461, 0.496561431881291, 0.5003618071859617, 0.5041621868160994, 0.5079667965226621, 0.5117797514629647, 0.5156048964496363, 0.519445654546921, 0.5233048898983981, 0.5271847902027883, 0.5310867735763228, 0.5350114236741206, 0.5389584559195042, 0.5429267165457916, 0.5469142149313869, 0.5509181884510671, 0.554935197821098, 0.5589612497302948, 0.5629919424687101, 0.5670226293323756, 0.571048593833487, 0.5750652302113005, 0.5790682224428152, 0.583053714908539, 0.5870184680824265, 0.5909599930821526, 0.5948766596223242, 0.5987677728360736, 0.6026336155382814, 0.6064754537577247, 0.6102955047209341, 0.6140968678782825, 0.6178834209709272, 0.6216596844930072, 0.6254306591554336, 0.6292016420572403, 0.6329780281742532, 0.636765104445812, 0.6405678441495694, 0.6443907093823271, 0.6482374693019239, 0.6521110413324404, 0.656013361804277, 0.6599452915142769, 0.6639065604811707, 0.6678957547791516, 0.6719103468057033, 0.6759467687331219, 0.6800005272645214, 0.6840663562244423, 0.6881384020210484, 0.6922104356784279, 0.6962760840061082, 0.700329071594422, 0.7043634647362276, 0.7083739081051768, 0.7123558450846681, 0.7163057130443234, 0.7202211055945383, 0.7241008948944618, 0.7279453084129431, 0.7317559561031802, 0.735535805698076, 0.7392891057050412, 0.7430212576107946, 0.7467386407296354, 0.750448394972761, 0.7541581685126996, 0.7578758388011072, 0.7616092166114085, 0.7653657436698942, 0.7691521949701957, 0.7729743970087193, 0.7768369729181565, 0.78074312481194, 0.7846944625982692, 0.7886908871058351, 0.792730533625823, 0.7968097799695415, 0.8009233209320643, 0.8050643087121165, 0.8092245564457909, 0.8133947996488877, 0.8175650081128398, 0.8217247387434274, 0.8258635180460409, 0.8299712415146747, 0.8340385761327996, 0.8380573515888691, 0.8420209256797445, 0.8459245097384129, 0.8497654407783761, 0.853543388379792, 0.8572604861191395, 0.8609213795166208, 0.8645331849811558, 0.8681053569967837, 0.8716494637313628, 0.8751788742662724, 0.8787083636470918, 0.8822536448416631, 0.885830839366794, 0.8894559007166473, 0.893144006711171, 0.8969089384093918, 0.9007624642412083, 0.9047137484594096, 0.9087688028751572, 0.9129300001077653, 0.9171956652647806, 0.9215597611014178, 0.9260116793376153, 0.9305361480014804, 0.9351132614994666, 0.9397186366785922, 0.9443236945463405, 0.9488960636577756, 0.9534000975785398, 0.9577974953974746, 0.9620480111008608, 0.9661102348311168, 0.9699424267252623, 0.973503382237892, 0.9767533066593123, 0.9796546759831682, 0.9821730613814404, 0.9842778953098837, 0.9859431586752221, 0.9871479705083771, 0.9878770641483618, 0.9881211369752133, 0.9878770641483618, 0.9871479705083771, 0.9859431586752221, 0.9842778953098837, 0.9821730613814404, 0.9796546759831682, 0.9767533066593124, 0.9735033822378922, 0.9699424267252623, 0.9661102348311168, 0.9620480111008608, 0.9577974953974748, 0.95340009757854, 0.9488960636577758, 0.9443236945463405, 0.9397186366785927, 0.9351132614994666, 0.9305361480014807, 0.9260116793376153, 0.9215597611014178, 0.9171956652647806, 0.9129300001077653, 0.9087688028751573, 0.9047137484594096, 0.9007624642412083, 0.8969089384093919, 0.893144006711171, 0.8894559007166473, 0.885830839366794, 0.8822536448416634, 0.8787083636470918, 0.8751788742662724, 0.8716494637313628, 0.8681053569967837, 0.8645331849811558, 0.8609213795166208, 0.8572604861191397, 0.853543388379792, 0.8497654407783765, 0.8459245097384132, 0.8420209256797445, 0.8380573515888692, 0.8340385761327999, 0.8299712415146747, 0.8258635180460409, 0.8217247387434276, 0.81756500811284, 0.8133947996488877, 0.8092245564457906, 0.8050643087121168, 0.8009233209320643, 0.7968097799695415, 0.792730533625823, 0.7886908871058353, 0.7846944625982695, 0.78074312481194, 0.7768369729181563, 0.7729743970087192, 0.7691521949701957, 0.7653657436698942, 0.7616092166114085, 0.7578758388011074, 0.7541581685126997, 0.7504483949727611, 0.7467386407296354, 0.7430212576107946, 0.7392891057050414, 0.7355358056980761, 0.7317559561031803, 0.7279453084129432, 0.7241008948944618, 0.7202211055945387, 0.7163057130443232, 0.7123558450846684, 0.7083739081051769, 0.7043634647362276, 0.7003290715944223, 0.6962760840061081, 0.692210435678428, 0.6881384020210484, 0.6840663562244428, 0.6800005272645214, 0.675946768733122, 0.6719103468057033, 0.6678957547791518, 0.6639065604811709, 0.6599452915142769, 0.6560133618042773, 0.6521110413324401, 0.6482374693019238, 0.6443907093823273, 0.6405678441495697, 0.6367651044458121, 0.6329780281742534, 0.6292016420572405, 0.6254306591554338, 0.6216596844930073, 0.617883420970927, 0.6140968678782827, 0.6102955047209341, 0.6064754537577248, 0.6026336155382814, 0.5987677728360739, 0.5948766596223243, 0.5909599930821529, 0.5870184680824265, 0.583053714908539, 0.5790682224428152, 0.5750652302113002, 0.5710485938334873, 0.5670226293323753, 0.5629919424687103, 0.5589612497302948, 0.5549351978210983, 0.5509181884510671, 0.546914214931387, 0.5429267165457917, 0.5389584559195042, 0.5350114236741207, 0.5310867735763226, 0.5271847902027884, 0.5233048898983982, 0.5194456545469214, 0.5156048964496363, 0.5117797514629648, 0.5079667965226622, 0.5041621868160997, 0.5003618071859617, 0.4965614318812908, 0.4927568865301462, 0.48894420619855594, 0.4851197836205926, 0.4812805021234322, 0.47742384841032565, 0.47354800117738727, 0.4696518924947249, 0.46573523994150035, 0.46179854860728664, 0.4578430832159829, 0.4538708117505308, 0.4498843230150531, 0.4458867215268535, 0.4418815039493068, 0.4378724219287015, 0.43386333666093596, 0.42985807077205634, 0.4258602631423516, 0.42187323213739314, 0.4178998523393106, 0.41394244931346363, 0.4100027162222725, 0.40608165523784756, 0.40217954574180653, 0.398295940271317, 0.3944296881141359, 0.39057898541220337, 0.38674144964214263, 0.38291421543868537, 0.3790940479466494, 0.3752774692570166, 0.37146089302526375, 0.3676407621012237, 0.36381368392798114, 0.3599765585937389, 0.3561266947389912, 0.35226190901816634, 0.34838060547018546, 0.3444818319402089, 0.3405653115846244, 0.3366314484488045, 0.3326813070958107, 0.3287165672465755, 0.32473945533124216, 0.32075265571216827, 0.3167592050897429, 0.3127623742150918, 0.3087655414867368, 0.30477206328537626, 0.30078514599277967, 0.29680772454514426, 0.29284235209285264, 0.28889110488910047, 0.2849555059272723, 0.281036470114924, 0.27713427293914694, 0.2732485436759579, 0.26937828325965074, 0.2655219059922562, 0.26167730337376843, 0.25784192750428864, 0.2540128907807276, 0.2501870780100898, 0.24636126661071744, 0.2425322502883887, 0.23869696146578473, 0.23485258781477208, 0.2309966784876026, 0.227127236055415, 0.22324279072387448, 0.21934245408459427, 0.21542595045044152, 0.21149362468272712, 0.20754642631571626, 0.20358587068486397, 0.19961397863551958, 0.19563319719582375, 0.19164630431086652, 0.18765630132824865, 0.1836662973760894, 0.17967939006666933, 0.1756985470818966, 0.17172649314654403, 0.16776560667431648, 0.1638178299891527, 0.15988459649486714, 0.15596677751074273, 0.1520646507342825, 0.14817789146394342, 0.14430558684588757, 0.14044627253265884, 0.13659799029134673, 0.13275836430645202, 0.12892469321805838, 0.12509405434560084, 0.1212634160934401, 0.11742975423355796, 0.1135901676239102, 0.10974198895313801, 0.1058828863018314, 0.1020109516693638, 0.0981247731196147, 0.09422348782914941, 0.09030681405357695, 0.08637506083382414, 0.08242911511334758, 0.07847040679748539, 0.07450085312469801, 0.07052278450454444, 0.06653885467922843, 0.0625519386579808, 0.05856502233403738, 0.05458108800535196, 0.050603000170742506, 0.04663339595748289, 0.042674584355131814, 0.03872845809077406, 0.03479642149579194, 0.03087933710239874, 0.026977492992724606, 0.02309059213147225, 0.019217764075183386, 0.015357598598731806, 0.011508199945205017, 0.007667259620427292, 0.003832144947845414, -2.2135220025256558e-17, -0.0038321449478452663, -0.0076672596204271325, -0.011508199945204857, -0.015357598598731642, -0.019217764075183226, -0.023090592131472076, -0.026977492992724443, -0.030879337102398582, -0.034796421495791784, -0.03872845809077395, -0.04267458435513166, -0.046633395957482685, -0.05060300017074231, -0.05458108800535172, -0.05856502233403722, -0.0625519386579807, -0.06653885467922818, -0.07052278450454427, -0.07450085312469781, -0.07847040679748525, -0.08242911511334744, -0.08637506083382407, -0.09030681405357677, -0.09422348782914927, -0.09812477311961455, -0.10201095166936366, -0.10588288630183132, -0.10974198895313783, -0.11359016762391003, -0.11742975423355789, -0.12126341609344002, -0.12509405434560064, -0.12892469321805822, -0.13275836430645183, -0.13659799029134656, -0.14044627253265862, -0.14430558684588743, -0.14817789146394325, -0.15206465073428233, -0.15596677751074253, -0.15988459649486697, -0.1638178299891526, -0.16776560667431628, -0.1717264931465438, -0.17569854708189647, -0.17967939006666908, -0.18366629737608917, -0.18765630132824837, -0.19164630431086638, -0.1956331971958236, -0.19961397863551944, -0.20358587068486383, -0.20754642631571613, -0.21149362468272703, -0.21542595045044138, -0.21934245408459419, -0.2232427907238742, -0.2271272360554148, -0.23099667848760239, -0.2348525878147719, -0.23869696146578456, -0.24253225028838846, -0.2463612666107173, -0.2501870780100897, -0.2540128907807275, -0.25784192750428836, -0.26167730337376827, -0.26552190599225606, -0.2693782832596505, -0.27324854367595786, -0.27713427293914683, -0.2810364701149239, -0.2849555059272722, -0.2888911048891002, -0.2928423520928524, -0.29680772454514404, -0.30078514599277945, -0.30477206328537626, -0.3087655414867366, -0.3127623742150916, -0.31675920508974253, -0.32075265571216816, -0.3247394553312421, -0.32871656724657533, -0.3326813070958105, -0.3366314484488043, -0.3405653115846242, -0.34448183194020876, -0.3483806054701855, -0.3522619090181661, -0.3561266947389911, -0.3599765585937387, -0.363813683927981, -0.3676407621012235, -0.3714608930252636, -0.37527746925701644, -0.37909404794664925, -0.38291421543868537, -0.3867414496421425, -0.3905789854122034, -0.3944296881141358, -0.3982959402713169, -0.4021795457418063, -0.4060816552378475, -0.41000271622227247, -0.41394244931346347, -0.41789985233931043, -0.4218732321373927, -0.4258602631423515, -0.42985807077205623, -0.4338633366609357, -0.43787242192870135, -0.4418815039493068, -0.4458867215268534, -0.44988432301505293, -0.45387081175053084, -0.4578430832159826, -0.4617985486072866, -0.4657352399415002, -0.46965189249472494, -0.473548001177387, -0.4774238484103256, -0.48128050212343193, -0.48511978362059244, -0.48894420619855594, -0.4927568865301461, -0.4965614318812906, -0.5003618071859616, -0.5041621868160995, -0.507966796522662, -0.5117797514629647, -0.5156048964496363, -0.5194456545469212, -0.5233048898983977, -0.5271847902027883, -0.5310867735763225, -0.5350114236741205, -0.538958455919504, -0.5429267165457915, -0.5469142149313868, -0.5509181884510673, -0.554935197821098, -0.5589612497302948, -0.5629919424687098, -0.5670226293323755, -0.571048593833487, -0.5750652302113005, -0.5790682224428149, -0.583053714908539, -0.5870184680824265, -0.5909599930821524, -0.5948766596223244, -0.5987677728360737, -0.6026336155382814, -0.6064754537577243, -0.610295504720934, -0.6140968678782825, -0.6178834209709269, -0.6216596844930068, -0.6254306591554336, -0.6292016420572403, -0.6329780281742531, -0.636765104445812, -0.6405678441495695, -0.6443907093823272, -0.6482374693019236, -0.6521110413324404, -0.6560133618042769, -0.6599452915142768, -0.6639065604811705, -0.6678957547791516, -0.6719103468057032, -0.6759467687331213, -0.6800005272645218, -0.6840663562244428, -0.6881384020210483, -0.6922104356784273, -0.6962760840061083, -0.7003290715944221, -0.7043634647362277, -0.7083739081051763, -0.7123558450846682, -0.7163057130443232, -0.720221105594538, -0.7241008948944619, -0.7279453084129434, -0.7317559561031801, -0.7355358056980755, -0.7392891057050416, -0.7430212576107944, -0.7467386407296351, -0.7504483949727608, -0.7541581685126998, -0.7578758388011072, -0.7616092166114081, -0.7653657436698943, -0.769152194970196, -0.7729743970087191, -0.7768369729181559, -0.78074312481194, -0.7846944625982691, -0.7886908871058349, -0.7927305336258235, -0.7968097799695414, -0.8009233209320643, -0.8050643087121163, -0.8092245564457908, -0.8133947996488877, -0.8175650081128395, -0.8217247387434276, -0.8258635180460409, -0.8299712415146745, -0.8340385761327996, -0.8380573515888692, -0.8420209256797445, -0.8459245097384129, -0.8497654407783758, -0.8535433883797922, -0.8572604861191393, -0.8609213795166204, -0.8645331849811555, -0.8681053569967839, -0.8716494637313631, -0.8751788742662723, -0.8787083636470923, -0.8822536448416631, -0.885830839366794, -0.8894559007166469, -0.893144006711171, -0.8969089384093915, -0.900762464241208, -0.904713748459409, -0.9087688028751573, -0.9129300001077653, -0.9171956652647802, -0.921559761101418, -0.9260116793376152, -0.9305361480014804, -0.9351132614994662, -0.9397186366785927, -0.9443236945463405, -0.9488960636577751, -0.9534000975785393, -0.9577974953974748, -0.9620480111008606, -0.9661102348311166, -0.9699424267252623, -0.9735033822378922, -0.9767533066593124, -0.9796546759831678, -0.9821730613814404, -0.9842778953098837, -0.9859431586752221, -0.9871479705083771, -0.9878770641483618, -0.9881211369752133, -0.9878770641483618, -0.9871479705083771, -0.9859431586752219, -0.9842778953098837, -0.9821730613814406, -0.979654675983168, -0.9767533066593126, -0.9735033822378922, -0.9699424267252625, -0.9661102348311168, -0.9620480111008608, -0.9577974953974749, -0.9534000975785397, -0.9488960636577758, -0.9443236945463405, -0.9397186366785933, -0.9351132614994666, -0.930536148001481, -0.9260116793376153, -0.9215597611014184, -0.9171956652647805, -0.9129300001077653, -0.9087688028751576, -0.9047137484594093, -0.9007624642412082, -0.8969089384093919, -0.8931440067111714, -0.8894559007166475, -0.8858308393667941, -0.8822536448416634, -0.8787083636470924, -0.8751788742662723, -0.8716494637313631, -0.8681053569967843, -0.8645331849811557, -0.8609213795166208, -0.8572604861191401, -0.8535433883797918, -0.8497654407783763, -0.8459245097384129, -0.8420209256797446, -0.8380573515888695, -0.8340385761327999, -0.8299712415146749, -0.8258635180460411, -0.8217247387434276, -0.8175650081128396, -0.813394799648888, -0.8092245564457912, -0.8050643087121165, -0.8009233209320645, -0.7968097799695417, -0.7927305336258236, -0.788690887105835, -0.7846944625982694, -0.7807431248119401, -0.776836972918156, -0.7729743970087192, -0.7691521949701962, -0.7653657436698947, -0.7616092166114082, -0.7578758388011074, -0.7541581685127, -0.7504483949727608, -0.7467386407296351, -0.743021257610795, -0.7392891057050416, -0.7355358056980756, -0.7317559561031802, -0.7279453084129436, -0.7241008948944624, -0.7202211055945382, -0.7163057130443232, -0.7123558450846685, -0.7083739081051765, -0.7043634647362277, -0.7003290715944221, -0.6962760840061083, -0.6922104356784277, -0.6881384020210485, -0.6840663562244428, -0.680000527264522, -0.6759467687331218, -0.6719103468057033, -0.667895754779152, -0.6639065604811705, -0.6599452915142769, -0.6560133618042772, -0.6521110413324402, -0.6482374693019237, -0.6443907093823273, -0.6405678441495697, -0.6367651044458126, -0.6329780281742531, -0.6292016420572404, -0.6254306591554339, -0.6216596844930072, -0.6178834209709272, -0.6140968678782827, -0.6102955047209344, -0.6064754537577246, -0.6026336155382814, -0.598767772836074, -0.5948766596223247, -0.5909599930821524, -0.5870184680824266, -0.5830537149085394, -0.5790682224428151, -0.5750652302113005, -0.5710485938334872, -0.5670226293323757, -0.56299194246871, -0.558961249730295, -0.5549351978210985, -0.5509181884510674, -0.5469142149313871, -0.5429267165457916, -0.5389584559195045, -0.5350114236741204, -0.531086773576323, -0.5271847902027885, -0.5233048898983983, -0.519445654546921, -0.5156048964496364, -0.5117797514629652, -0.5079667965226623, -0.5041621868160993, -0.5003618071859617, -0.49656143188129126, -0.4927568865301458, -0.4889442061985561, -0.4851197836205928, -0.4812805021234325, -0.4774238484103256, -0.4735480011773872, -0.46965189249472494, -0.4657352399415006, -0.4617985486072864, -0.45784308321598294, -0.4538708117505314, -0.4498843230150529, -0.4458867215268535, -0.4418815039493069, -0.4378724219287019, -0.4338633366609356, -0.4298580707720565, -0.42586026314235165, -0.42187323213739364, -0.4178998523393103, -0.41394244931346363, -0.41000271622227324, -0.4060816552378475, -0.4021795457418066, -0.39829594027131693, -0.39442968811413626, -0.39057898541220315, -0.3867414496421427, -0.3829142154386855, -0.3790940479466495, -0.3752774692570167, -0.37146089302526386, -0.367640762101224, -0.3638136839279809, -0.359976558593739, -0.3561266947389913, -0.3522619090181665, -0.34838060547018534, -0.344481831940209, -0.3405653115846244, -0.33663144844880494, -0.33268130709581073, -0.3287165672465757, -0.3247394553312426, -0.32075265571216816, -0.31675920508974287, -0.3127623742150918, -0.30876554148673724, -0.30477206328537604, -0.30078514599277967, -0.2968077245451443, -0.2928423520928529, -0.28889110488910064, -0.28495550592727237, -0.28103647011492455, -0.27713427293914666, -0.273248543675958, -0.26937828325965085, -0.26552190599225656, -0.2616773033737683, -0.25784192750428864, -0.2540128907807276, -0.2501870780100902, -0.24636126661071758, -0.24253225028838882, -0.23869696146578515, -0.23485258781477183, -0.2309966784876026, -0.227127236055415, -0.223242790723875, -0.219342454084594, -0.2154259504504417, -0.21149362468272728, -0.20754642631571585, -0.20358587068486397, -0.19961397863551972, -0.195633197195824, -0.19164630431086638, -0.18765630132824856, -0.1836662973760894, -0.1796793900666698, -0.1756985470818965, -0.17172649314654398, -0.16776560667431645, -0.16381782998915242, -0.15988459649486722, -0.15596677751074284, -0.15206465073428277, -0.14817789146394333, -0.14430558684588768, -0.140446272532659, -0.13659799029134725, -0.1327583643064518, -0.12892469321805836, -0.1250940543456009, -0.12126341609343978, -0.11742975423355789, -0.1135901676239102, -0.10974198895313834, -0.10588288630183111, -0.10201095166936384, -0.09812477311961455, -0.09422348782914994, -0.0903068140535767, -0.08637506083382425, -0.08242911511334794, -0.0784704067974851, -0.07450085312469837, -0.07052278450454445, -0.06653885467922874, -0.0625519386579808, -0.05856502233403746, -0.054581088005352316, -0.05060300017074291, -0.04663339595748277, -0.04267458435513175, -0.038728458090774354, -0.034796421495791735, -0.030879337102398548, -0.02697749299272465, -0.023090592131472666, -0.019217764075183233, -0.015357598598731946, -0.011508199945204809, -0.0076672596204277995, -0.0038321449478451, ], [0.0, 0.0037546426595381713, 0.007510780967137876, 0.01126989633490237, 0.015033441836389019, 0.018802828369532898, 0.02257941121477297, 0.026364477114426254, 0.030159231994545982, 0.03396478944457036, 0.037782160063073486, 0.04161224176993161, 0.04545581117629195, 0.04931351609395448, 0.05318586925524308, 0.05707324330324401, 0.060975867100529914, 0.06489382339227409, 0.06882704784710315, 0.07277532948625265, 0.07673831249869272, 0.08071549942700436, 0.08470625569602097, 0.08870981544372791, 0.09272528860174742, 0.0967516691610381, 0.10078784454731875, 0.10483260602028197, 0.10888466000099853, 0.1129426402231109, 0.11700512059555977, 0.12107062865775589, 0.1251376595023582, 0.1292046900362134, 0.13327019344658622, 0.1373326537376026, 0.14139058020086237, 0.14544252168446478, 0.14948708052622536, 0.15352292601964512, 0.1575488072851873, 0.16156356542460135, 0.16556614484235488, 0.16955560362564379, 0.1735311228828776, 0.17749201494991423, 0.18143773038355507, 0.18536786367282235, 0.18928215761022388, 0.1931805062774661, 0.19706295661278375, 0.2009297085401149, 0.20478111365363075, 0.20861767246451354, 0.21244003023025487, 0.21624897139996888, 0.22004541272219671, 0.22383039507426783, 0.22760507408438035, 0.23137070962905787, 0.23512865429941365, 0.23888034093961336, 0.24262726936997916, 0.2463709924152358, 0.25011310136537646, 0.2538552110024731, 0.25759894433139546, 0.26134591715579775, 0.26509772264284975, 0.26885591602099046, 0.2726219995544699, 0.2763974079366082, 0.28018349424055017, 0.28398151656186194, 0.2877926254816188, 0.29161785247173744, 0.2954580993562515, 0.29931412893309084, 0.3031865568507834, 0.3070758448234334, 0.3109822952554358, 0.3149060473347855, 0.3188470746406093, 0.32280518429684274, 0.32678001768987996, 0.3307710527536967, 0.3347776078114897, 0.33879884694844015, 0.34283378687590815, 0.34688130523334354, 0.35094015026057135, 0.35500895176002023, 0.35908623325600947, 0.36317042524652776, 0.36725987943213556, 0.37135288379678644, 0.375447678406614, 0.37954247178513006, 0.3836354577169205, 0.38772483232687016, 0.3918088112782331, 0.3958856469305688, 0.3999536452976803, 0.4040111826462557, 0.40805672157793915, 0.4120888264410142, 0.4161061779227664, 0.4201075866798671, 0.42409200587173573, 0.4280585424707389, 0.432006467233196, 0.43593522322641703, 0.439844432819282, 0.4437339030570986, 0.4476036293555435, 0.45145379746324465, 0.4552847836579237, 0.45909715315681754, 0.4628916567382077, 0.4666692255871839, 0.4704309643950816, 0.4741781427582349, 0.4779121849376347, 0.4816346580566187, 0.4853472588287068, 0.48905179892201944, 0.49275018908019647, 0.4964444221322966, 0.500136555035643, 0.5038286901058974, 0.5075229555976896, 0.511221485806805, 0.514926400871161, 0.5186397864525183, 0.5223636734840206, 0.5261000181701829, 0.5298506824258414, 0.5336174149388109, 0.5374018330375671, 0.5412054055402099, 0.5450294367542712, 0.5488750517886832, 0.5527431833294414, 0.556634560019277, 0.5605496965690732, 0.5644888857148935, 0.5684521921194878, 0.5724394483010665, 0.5764502526551665, 0.5804839696176755, 0.5845397319986841, 0.5886164454979752, 0.5927127953937478, 0.5968272553768291, 0.6009580984832464, 0.6051034100588534, 0.6092611026708307, 0.6134289328625161, 0.6176045196303093, 0.6217853644845031, 0.6259688729399661, 0.6301523772677874, 0.6343331603254345, 0.6385084802707925, 0.6426755959547678, 0.6468317927780556, 0.6509744087902921, 0.6551008608041979, 0.6592086702935716, 0.6632954888421153, 0.6673591229101525, 0.6713975576883219, 0.6754089798113071, 0.6793917987106063, 0.6833446663931806, 0.6872664954425628, 0.6911564750505383, 0.6950140849008108, 0.698839106740992, 0.7026316334957454, 0.7063920757918297, 0.7101211657849998, 0.7138199581990926, 0.7174898285090033, 0.7211324682214599, 0.724749877230401, 0.7283443532471192, 0.7319184783290266, 0.735475102554692, 0.7390173249165333, 0.7425484715260187, 0.7460720712492417, 0.7495918289131239, 0.7531115962440359, 0.7566353407211792, 0.7601671125464375, 0.7637110099504184, 0.7672711430709355, 0.7708515966550418, 0.7744563918488222, 0.7780894473503523, 0.7817545402103959, 0.7854552665725226, 0.789195002649225, 0.7929768662333101, 0.7968036790442367, 0.8006779302071972, 0.8046017411585427, 0.8085768322646731, 0.8126044914327786, 0.8166855449808553, 0.8208203310213361, 0.8250086755974885, 0.8292498717946396, 0.8335426620292811, 0.8378852236984357, 0.842275158349393, 0.846709484506249, 0.8511846342647514, 0.8556964537409748, 0.8602402074324836, 0.864810586523099, 0.8694017211343876, 0.8740071964987018, 0.8786200730002889, 0.8832329100028196, 0.8878377933539001, 0.892426366429934, 0.8969898645582951, 0.9015191526283667, 0.9060047656787951, 0.9104369522254713, 0.9148057200734908, 0.9191008843367998, 0.9233121173715787, 0.9274290003137713, 0.9314410758977026, 0.9353379022214738, 0.9391091071159617, 0.9427444427678154, 0.9462338402428397, 0.9495674635547743, 0.9527357629255186, 0.9557295268865583, 0.9585399328774801, 0.9611585960061443, 0.9635776156461704, 0.9657896195608366, 0.9677878052581911, 0.9695659783000494, 0.9711185873074117, 0.9724407554266112, 0.9735283080439817, 0.9743777965618691, 0.9749865180752338, 0.9753525308156625, 0.9754746652581913, 0.9753525308156625, 0.9749865180752338, 0.9743777965618691, 0.9735283080439817, 0.9724407554266112, 0.9711185873074117, 0.9695659783000494, 0.9677878052581913, 0.9657896195608366, 0.9635776156461704, 0.9611585960061443, 0.9585399328774802, 0.9557295268865585, 0.9527357629255189, 0.9495674635547743, 0.9462338402428399, 0.9427444427678154, 0.9391091071159618, 0.9353379022214738, 0.9314410758977026, 0.9274290003137713, 0.9233121173715787, 0.9191008843368, 0.9148057200734908, 0.9104369522254713, 0.9060047656787953, 0.9015191526283667, 0.8969898645582951, 0.892426366429934, 0.8878377933539001, 0.8832329100028196, 0.878620073000289, 0.8740071964987018, 0.8694017211343876, 0.864810586523099, 0.8602402074324836, 0.855696453740975, 0.8511846342647514, 0.8467094845062495, 0.8422751583493933, 0.8378852236984357, 0.8335426620292812, 0.8292498717946398, 0.8250086755974885, 0.8208203310213361, 0.8166855449808555, 0.8126044914327786, 0.8085768322646731, 0.8046017411585424, 0.8006779302071975, 0.7968036790442367, 0.7929768662333102, 0.789195002649225, 0.7854552665725228, 0.7817545402103961, 0.7780894473503525, 0.7744563918488222, 0.7708515966550417, 0.7672711430709357, 0.7637110099504184, 0.7601671125464375, 0.7566353407211793, 0.7531115962440359, 0.749591828913124, 0.7460720712492418, 0.7425484715260187, 0.7390173249165334, 0.735475102554692, 0.7319184783290267, 0.7283443532471193, 0.724749877230401, 0.7211324682214603, 0.7174898285090032, 0.7138199581990927, 0.7101211657849998, 0.7063920757918297, 0.7026316334957455, 0.6988391067409919, 0.695014084900811, 0.6911564750505383, 0.687266495442563, 0.6833446663931806, 0.6793917987106063, 0.6754089798113071, 0.6713975576883221, 0.6673591229101529, 0.6632954888421152, 0.6592086702935718, 0.6551008608041977, 0.6509744087902921, 0.6468317927780558, 0.642675595954768, 0.6385084802707927, 0.6343331603254347, 0.6301523772677877, 0.6259688729399665, 0.6217853644845032, 0.617604519630309, 0.6134289328625162, 0.6092611026708307, 0.6051034100588535, 0.6009580984832464, 0.5968272553768293, 0.5927127953937479, 0.5886164454979754, 0.5845397319986843, 0.5804839696176755, 0.5764502526551667, 0.5724394483010663, 0.5684521921194879, 0.5644888857148934, 0.5605496965690733, 0.556634560019277, 0.5527431833294416, 0.5488750517886832, 0.5450294367542714, 0.54120540554021, 0.537401833037567, 0.533617414938811, 0.5298506824258413, 0.526100018170183, 0.5223636734840207, 0.5186397864525184, 0.5149264008711609, 0.5112214858068052, 0.5075229555976897, 0.5038286901058977, 0.5001365550356431, 0.49644442213229645, 0.4927501890801966, 0.4890517989220195, 0.48534725882870683, 0.4816346580566188, 0.4779121849376349, 0.4741781427582349, 0.47043096439508164, 0.466669225587184, 0.46289165673820787, 0.45909715315681765, 0.4552847836579236, 0.45145379746324477, 0.4476036293555436, 0.4437339030570986, 0.4398444328192821, 0.4359352232264173, 0.432006467233196, 0.4280585424707392, 0.42409200587173584, 0.4201075866798673, 0.41610617792276644, 0.41208882644101413, 0.4080567215779393, 0.4040111826462557, 0.3999536452976805, 0.3958856469305688, 0.3918088112782331, 0.38772483232687016, 0.38363545771692076, 0.3795424717851301, 0.375447678406614, 0.37135288379678655, 0.36725987943213545, 0.36317042524652793, 0.3590862332560095, 0.3550089517600204, 0.35094015026057135, 0.3468813052333436, 0.3428337868759083, 0.33879884694844037, 0.3347776078114898, 0.33077105275369667, 0.32678001768987996, 0.3228051842968426, 0.3188470746406094, 0.3149060473347855, 0.310982295255436, 0.30707584482343336, 0.30318655685078355, 0.2993141289330909, 0.2954580993562518, 0.29161785247173755, 0.2877926254816187, 0.28398151656186205, 0.28018349424055017, 0.27639740793660833, 0.2726219995544699, 0.2688559160209905, 0.2650977226428497, 0.2613459171557979, 0.25759894433139563, 0.25385521100247344, 0.25011310136537657, 0.24637099241523572, 0.24262726936997933, 0.2388803409396133, 0.23512865429941388, 0.2313707096290579, 0.22760507408438052, 0.22383039507426786, 0.22004541272219694, 0.21624897139996893, 0.21244003023025512, 0.20861767246451376, 0.2047811136536306, 0.20092970854011502, 0.1970629566127837, 0.19318050627746622, 0.1892821576102239, 0.18536786367282246, 0.1814377303835551, 0.17749201494991443, 0.17353112288287773, 0.169555603625644, 0.16556614484235502, 0.16156356542460135, 0.15754880728518753, 0.15352292601964512, 0.14948708052622547, 0.14544252168446467, 0.1413905802008626, 0.13733265373760256, 0.13327019344658653, 0.12920469003621346, 0.12513765950235828, 0.12107062865775603, 0.1170051205955597, 0.1129426402231111, 0.10888466000099857, 0.10483260602028205, 0.10078784454731866, 0.0967516691610383, 0.0927252886017474, 0.08870981544372819, 0.08470625569602103, 0.08071549942700425, 0.07673831249869283, 0.07277532948625258, 0.0688270478471034, 0.06489382339227412, 0.06097586710052998, 0.057073243303243884, 0.053185869255243205, 0.04931351609395451, 0.04545581117629221, 0.04161224176993168, 0.03778216006307337, 0.03396478944457047, 0.030159231994546017, 0.02636447711442651, 0.02257941121477305, 0.018802828369532985, 0.015033441836388935, 0.011269896334902616, 0.007510780967137918, 0.003754642659538445, 2.1252203047710204e-17, -0.003754642659538295, -0.007510780967137769, -0.011269896334902463, -0.01503344183638878, -0.018802828369532832, -0.02257941121477289, -0.026364477114426372, -0.030159231994545864, -0.03396478944457031, -0.03778216006307327, -0.04161224176993153, -0.045455811176292, -0.049313516093954346, -0.05318586925524299, -0.05707324330324375, -0.06097586710052988, -0.06489382339227391, -0.06882704784710324, -0.07277532948625239, -0.07673831249869267, -0.08071549942700412, -0.08470625569602087, -0.08870981544372798, -0.09272528860174727, -0.09675166916103814, -0.1007878445473185, -0.10483260602028191, -0.10888466000099838, -0.11294264022311093, -0.1170051205955596, -0.12107062865775592, -0.12513765950235814, -0.12920469003621332, -0.13327019344658628, -0.1373326537376024, -0.14139058020086237, -0.14544252168446453, -0.14948708052622534, -0.15352292601964496, -0.15754880728518736, -0.16156356542460115, -0.16556614484235488, -0.1695556036256438, -0.17353112288287748, -0.1774920149499143, -0.18143773038355487, -0.18536786367282226, -0.18928215761022368, -0.19318050627746608, -0.19706295661278356, -0.20092970854011488, -0.20478111365363047, -0.20861767246451363, -0.212440030230255, -0.21624897139996874, -0.2200454127221968, -0.22383039507426764, -0.22760507408438035, -0.23137070962905767, -0.23512865429941368, -0.23888034093961316, -0.2426272693699791, -0.24637099241523563, -0.25011310136537646, -0.25385521100247327, -0.2575989443313954, -0.2613459171557978, -0.2650977226428496, -0.2688559160209903, -0.27262199955446986, -0.2763974079366082, -0.28018349424055006, -0.2839815165618619, -0.2877926254816185, -0.2916178524717374, -0.29545809935625156, -0.2993141289330906, -0.3031865568507835, -0.30707584482343325, -0.31098229525543586, -0.3149060473347853, -0.31884707464060924, -0.3228051842968425, -0.3267800176898798, -0.3307710527536965, -0.3347776078114896, -0.3387988469484402, -0.34283378687590815, -0.3468813052333436, -0.3509401502605711, -0.35500895176002023, -0.3590862332560094, -0.36317042524652776, -0.3672598794321353, -0.3713528837967863, -0.37544767840661386, -0.37954247178512995, -0.3836354577169206, -0.38772483232687005, -0.3918088112782331, -0.39588564693056866, -0.39995364529768046, -0.4040111826462555, -0.40805672157793915, -0.412088826441014, -0.4161061779227663, -0.4201075866798671, -0.42409200587173546, -0.42805854247073905, -0.43200646723319586, -0.43593522322641703, -0.4398444328192819, -0.44373390305709853, -0.4476036293555435, -0.4514537974632446, -0.4552847836579237, -0.4590971531568174, -0.4628916567382077, -0.46666922558718377, -0.47043096439508175, -0.4741781427582346, -0.47791218493763477, -0.48163465805661854, -0.4853472588287068, -0.4890517989220195, -0.49275018908019647, -0.49644442213229634, -0.500136555035643, -0.5038286901058975, -0.5075229555976895, -0.511221485806805, -0.5149264008711609, -0.5186397864525183, -0.5223636734840204, -0.5261000181701829, -0.5298506824258411, -0.5336174149388108, -0.5374018330375668, -0.5412054055402098, -0.545029436754271, -0.5488750517886835, -0.5527431833294415, -0.556634560019277, -0.5605496965690727, -0.5644888857148935, -0.5684521921194878, -0.5724394483010663, -0.5764502526551663, -0.5804839696176756, -0.5845397319986841, -0.588616445497975, -0.5927127953937481, -0.5968272553768292, -0.6009580984832463, -0.605103410058853, -0.6092611026708307, -0.6134289328625161, -0.6176045196303089, -0.6217853644845026, -0.6259688729399663, -0.6301523772677874, -0.6343331603254344, -0.6385084802707927, -0.6426755959547679, -0.6468317927780556, -0.6509744087902917, -0.6551008608041979, -0.6592086702935714, -0.6632954888421152, -0.6673591229101523, -0.6713975576883219, -0.675408979811307, -0.6793917987106057, -0.6833446663931808, -0.687266495442563, -0.6911564750505382, -0.6950140849008104, -0.6988391067409921, -0.7026316334957454, -0.7063920757918297, -0.7101211657849994, -0.7138199581990926, -0.7174898285090031, -0.7211324682214597, -0.7247498772304011, -0.7283443532471194, -0.7319184783290265, -0.7354751025546915, -0.7390173249165334, -0.7425484715260184, -0.7460720712492415, -0.7495918289131238, -0.753111596244036, -0.7566353407211792, -0.7601671125464372, -0.7637110099504186, -0.7672711430709357, -0.7708515966550414, -0.7744563918488218, -0.7780894473503525, -0.7817545402103958, -0.7854552665725223, -0.7891950026492255, -0.7929768662333101, -0.7968036790442367, -0.800677930207197, -0.8046017411585427, -0.8085768322646731, -0.8126044914327782, -0.8166855449808553, -0.8208203310213361, -0.8250086755974884, -0.8292498717946396, -0.8335426620292814, -0.8378852236984357, -0.842275158349393, -0.8467094845062489, -0.8511846342647517, -0.8556964537409747, -0.8602402074324831, -0.8648105865230984, -0.8694017211343879, -0.874007196498702, -0.8786200730002884, -0.8832329100028199, -0.8878377933539001, -0.892426366429934, -0.8969898645582948, -0.9015191526283667, -0.9060047656787947, -0.9104369522254708, -0.9148057200734903, -0.9191008843368, -0.9233121173715787, -0.9274290003137712, -0.9314410758977029, -0.9353379022214736, -0.9391091071159617, -0.9427444427678149, -0.9462338402428399, -0.9495674635547743, -0.9527357629255183, -0.955729526886558, -0.9585399328774802, -0.9611585960061441, -0.9635776156461702, -0.9657896195608366, -0.9677878052581913, -0.9695659783000494, -0.9711185873074113, -0.9724407554266112, -0.9735283080439817, -0.9743777965618691, -0.9749865180752338, -0.9753525308156625, -0.9754746652581913, -0.9753525308156625, -0.9749865180752338, -0.974377796561869, -0.9735283080439817, -0.9724407554266112, -0.9711185873074115, -0.9695659783000494, -0.9677878052581913, -0.9657896195608366, -0.9635776156461704, -0.9611585960061443, -0.9585399328774804, -0.9557295268865581, -0.9527357629255189, -0.9495674635547743, -0.9462338402428404, -0.9427444427678154, -0.9391091071159623, -0.9353379022214738, -0.9314410758977032, -0.9274290003137712, -0.9233121173715789, -0.9191008843368004, -0.9148057200734906, -0.910436952225471, -0.9060047656787953, -0.9015191526283672, -0.8969898645582953, -0.8924263664299342, -0.8878377933539002, -0.8832329100028201, -0.8786200730002889, -0.8740071964987022, -0.8694017211343882, -0.8648105865230987, -0.8602402074324836, -0.8556964537409754, -0.8511846342647517, -0.8467094845062493, -0.842275158349393, -0.8378852236984358, -0.8335426620292815, -0.8292498717946398, -0.8250086755974887, -0.8208203310213362, -0.8166855449808553, -0.8126044914327785, -0.8085768322646734, -0.8046017411585429, -0.8006779302071972, -0.7968036790442369, -0.7929768662333103, -0.7891950026492256, -0.7854552665725224, -0.781754540210396, -0.7780894473503525, -0.7744563918488219, -0.7708515966550417, -0.7672711430709359, -0.7637110099504189, -0.7601671125464374, -0.7566353407211794, -0.7531115962440363, -0.7495918289131238, -0.7460720712492417, -0.7425484715260189, -0.7390173249165336, -0.7354751025546917, -0.7319184783290266, -0.7283443532471197, -0.7247498772304015, -0.7211324682214598, -0.7174898285090031, -0.7138199581990929, -0.7101211657849996, -0.7063920757918298, -0.7026316334957454, -0.6988391067409923, -0.6950140849008108, -0.6911564750505383, -0.6872664954425632, -0.6833446663931813, -0.6793917987106061, -0.675408979811307, -0.6713975576883221, -0.6673591229101524, -0.6632954888421154, -0.6592086702935719, -0.6551008608041979, -0.6509744087902919, -0.6468317927780558, -0.6426755959547681, -0.6385084802707932, -0.6343331603254345, -0.6301523772677876, -0.6259688729399665, -0.621785364484503, -0.6176045196303093, -0.6134289328625162, -0.609261102670831, -0.6051034100588534, -0.6009580984832464, -0.5968272553768295, -0.5927127953937484, -0.588616445497975, -0.5845397319986844, -0.5804839696176758, -0.5764502526551665, -0.5724394483010666, -0.5684521921194878, -0.5644888857148938, -0.5605496965690729, -0.5566345600192771, -0.5527431833294417, -0.5488750517886836, -0.5450294367542713, -0.5412054055402099, -0.5374018330375674, -0.5336174149388105, -0.5298506824258415, -0.5261000181701831, -0.5223636734840208, -0.5186397864525182, -0.5149264008711611, -0.5112214858068055, -0.5075229555976899, -0.5038286901058973, -0.5001365550356431, -0.496444422132297, -0.4927501890801962, -0.4890517989220196, -0.48534725882870705, -0.481634658056619, -0.4779121849376347, -0.47417814275823483, -0.47043096439508186, -0.4666692255871842, -0.4628916567382076, -0.45909715315681776, -0.455284783657924, -0.4514537974632445, -0.4476036293555437, -0.4437339030570988, -0.43984443281928237, -0.4359352232264169, -0.43200646723319613, -0.4280585424707393, -0.4240920058717364, -0.42010758667986703, -0.41610617792276644, -0.41208882644101463, -0.40805672157793915, -0.4040111826462558, -0.3999536452976805, -0.39588564693056916, -0.39180881127823286, -0.3877248323268702, -0.3836354577169208, -0.3795424717851304, -0.37544767840661414, -0.3713528837967866, -0.36725987943213584, -0.36317042524652776, -0.3590862332560097, -0.3550089517600205, -0.3509401502605715, -0.34688130523334343, -0.3428337868759084, -0.3387988469484404, -0.33477760781149024, -0.33077105275369667, -0.3267800176898802, -0.32280518429684296, -0.31884707464060924, -0.31490604733478555, -0.31098229525543597, -0.3070758448234338, -0.30318655685078333, -0.2993141289330909, -0.2954580993562519, -0.2916178524717379, -0.2877926254816188, -0.2839815165618621, -0.28018349424055067, -0.2763974079366081, -0.27262199955447, -0.2688559160209906, -0.2650977226428501, -0.2613459171557979, -0.25759894433139563, -0.25385521100247344, -0.2501131013653769, -0.2463709924152359, -0.24262726936997947, -0.23888034093961366, -0.23512865429941354, -0.2313707096290579, -0.22760507408438052, -0.2238303950742684, -0.22004541272219666, -0.2162489713999691, -0.21244003023025523, -0.20861767246451332, -0.2047811136536306, -0.20092970854011516, -0.19706295661278395, -0.19318050627746608, -0.18928215761022388, -0.18536786367282249, -0.18143773038355554, -0.17749201494991435, -0.17353112288287764, -0.16955560362564398, -0.16556614484235477, -0.16156356542460146, -0.15754880728518764, -0.15352292601964546, -0.14948708052622536, -0.1454425216844648, -0.14139058020086281, -0.13733265373760312, -0.1332701934465863, -0.1292046900362135, -0.12513765950235842, -0.12107062865775571, -0.1170051205955596, -0.11294264022311115, -0.10888466000099893, -0.1048326060202818, -0.10078784454731875, -0.09675166916103815, -0.09272528860174795, -0.08870981544372795, -0.08470625569602111, -0.08071549942700462, -0.0767383124986925, -0.07277532948625293, -0.0688270478471034, -0.0648938233922744, -0.06097586710052994, -0.05707324330324397, -0.05318586925524359, -0.0493135160939549, -0.045455811176292084, -0.04161224176993163, -0.03778216006307366, -0.03396478944457024, -0.030159231994545812, -0.026364477114426563, -0.022579411214773457, -0.018802828369532846, -0.015033441836389097, -0.011269896334902354, -0.0075107809671384265, -0.0037546426595381774, ], [0.0, 0.0036029100160942275, 0.00720656920931344, 0.01081172503644974, 0.014419121517325108, 0.01802949752553803, 0.021643585090267217, 0.02526210771278336, 0.028885778701290048, 0.03251529952767806, 0.03615135820973272, 0.039794627722282656, 0.043445764440719924, 0.04710540662025539, 0.05077417291420166, 0.05445266093449628, 0.058141445857592525, 0.06184107907875336, 0.06555208691768571, 0.06927496937834833, 0.07301019896565641, 0.07675821956169081, 0.0805194453638988, 0.08429425988764695, 0.08808301503535666, 0.09188603023431603, 0.09570359164512383, 0.09953595144257499, 0.10338332717065121, 0.10724590117312832, 0.11112382010115644, 0.11501719449901396, 0.11892609846907311, 0.12285056941685536, 0.1267906078768884, 0.13074617741991168, 0.1347172046418091, 0.13870357923447973, 0.14270515413868742, 0.14672174577876182, 0.15075313437885232, 0.15479906436026833, 0.15885924481926986, 0.16293335008450557, 0.1670210203531278, 0.1711218624044505, 0.17523545038985247, 0.1793613266974686, 0.18349900289005128, 0.18764796071423412, 0.19180765317927134, 0.1959775057031843, 0.20015691732409868, 0.20434526197441397, 0.2085418898153159, 0.21274612862900502, 0.21695728526589125, 0.22117464714388468, 0.22539748379679028, 0.22962504846871143, 0.2338565797512582, 0.2380913032602577, 0.24232843334857407, 0.24656717485155852, 0.25080672486157035, 0.2550462745279384, 0.259285010878668, 0.26352211866013714, 0.26775678219097887, 0.27198818722630047, 0.27621552282835465, 0.28043798323974956, 0.28465476975526294, 0.2888650925883141, 0.29306817272813923, 0.2972632437837192, 0.3014495538105203, 0.30562636711612184, 0.3097929660408349, 0.31394865270944594, 0.31809275075026094, 0.3222246069776756, 0.3263435930345486, 0.33044910699072433, 0.3345405748941163, 0.33861745227084483, 0.3426792255710031, 0.3467254135567223, 0.3507555686293014, 0.3547692780922733, 0.35876616534739225, 0.3627458910206431, 0.3667081540154987, 0.3706526924907792, 0.37457928476060465, 0.3784877501140713, 0.3823779495524287, 0.3862497864416832, 0.3901032070787096, 0.39393820116911504, 0.3977548022152538, 0.40155308781296867, 0.4053331798557987, 0.40909524464556557, 0.4128394929084316, 0.4165661797156962, 0.4202756043087813, 0.4239681098280344, 0.42764408294516615, 0.4313039533993215, 0.4349481934369668, 0.4385773171559656, 0.4421918797543982, 0.4457924766848649, 0.4493797427152007, 0.4529543508967109, 0.4565170114412161, 0.460068470508386, 0.46360950890500463, 0.46714094069799966, 0.4706636117432362, 0.47417839813224116, 0.4776862045592009, 0.4811879626107318, 0.4846846289810805, 0.4881771836155745, 0.4916666277852831, 0.4951539820960022, 0.4986402844348131, 0.5021265878576004, 0.505613958421042, 0.509103472962711, 0.512596216833041, 0.5160932815830198, 0.5195957626115787, 0.5231047567767416, 0.5266213599746881, 0.530146664690964, 0.5336817575281548, 0.537227716714394, 0.5407856095971464, 0.5443564901267568, 0.5479413963342924, 0.5515413478082485, 0.5551573431747125, 0.5587903575855996, 0.5624413402195828, 0.5661112118003481, 0.569800862136789, 0.573511147689753, 0.5772428891699173, 0.5809968691713487, 0.5847738298452587, 0.5885744706184183, 0.592399445960638, 0.5962493632056602, 0.600124780429733, 0.6040262043920546, 0.6079540885411941, 0.6119088310914941, 0.6158907731733568, 0.6199001970612097, 0.6239373244828211, 0.6280023150135208, 0.6320952645587387, 0.6362162039281447, 0.6403650975045254, 0.6445418420103775, 0.6487462653750551, 0.6529781257051281, 0.6572371103604553, 0.6615228351382992, 0.6658348435676291, 0.6701726063155846, 0.6745355207078785, 0.6789229103647356, 0.683334024953767, 0.6877680400609836, 0.6922240571809586, 0.6967011038269428, 0.7011981337615355, 0.7057140273483078, 0.7102475920245749, 0.7147975628952972, 0.7193626034478905, 0.7239413063875166, 0.7285321945922127, 0.7331337221870183, 0.7377442757360443, 0.7423621755512343, 0.7469856771163508, 0.7516129726245302, 0.7562421926275448, 0.7608714077947145, 0.7654986307792175, 0.7701218181893617, 0.7747388726621858, 0.7793476450365877, 0.7839459366229863, 0.788531501566359, 0.7931020492993313, 0.7976552470818191, 0.8021887226235821, 0.8067000667858837, 0.8111868363583185, 0.8156465569067172, 0.8200767256879182, 0.824474814627065, 0.8288382733529669, 0.8331645322869625, 0.8374510057806036, 0.8416950952974053, 0.8458941926337946, 0.8500456831743387, 0.8541469491762401, 0.8581953730780353, 0.8621883408273798, 0.8661232452227537, 0.8699974892638864, 0.8738084895056724, 0.8775536794103327, 0.8812305126925633, 0.884836466652415, 0.8883690454906534, 0.8918257836013678, 0.8952042488366229, 0.8985020457379814, 0.9017168187297717, 0.9048462552690218, 0.9078880889470498, 0.9108401025377634, 0.9137001309878071, 0.9164660643437724, 0.9191358506117902, 0.9217074985449252, 0.924179080353897, 0.9265487343367806, 0.9288146674234583, 0.9309751576307267, 0.9330285564241154, 0.9349732909825982, 0.9368078663625591, 0.9385308675575099, 0.9401409614502411, 0.9416368986542469, 0.9430175152414506, 0.9442817343534322, 0.945428567693557, 0.9464571168975796, 0.9473665747805143, 0.9481562264577418, 0.9488254503385406, 0.9493737189904329, 0.9498005998729376, 0.9501057559395469, 0.9502889461069446, 0.9503500255907076, 0.9502889461069446, 0.9501057559395469, 0.9498005998729376, 0.9493737189904329, 0.9488254503385406, 0.9481562264577418, 0.9473665747805143, 0.9464571168975796, 0.945428567693557, 0.9442817343534322, 0.9430175152414506, 0.9416368986542472, 0.9401409614502413, 0.93853086755751, 0.9368078663625591, 0.9349732909825982, 0.9330285564241154, 0.9309751576307269, 0.9288146674234583, 0.9265487343367806, 0.924179080353897, 0.9217074985449252, 0.9191358506117902, 0.9164660643437724, 0.9137001309878071, 0.9108401025377636, 0.9078880889470498, 0.9048462552690218, 0.9017168187297717, 0.8985020457379814, 0.8952042488366229, 0.891825783601368, 0.8883690454906534, 0.884836466652415, 0.8812305126925633, 0.8775536794103327, 0.8738084895056726, 0.8699974892638864, 0.8661232452227541, 0.8621883408273798, 0.8581953730780353, 0.8541469491762402, 0.8500456831743389, 0.8458941926337946, 0.8416950952974053, 0.8374510057806038, 0.8331645322869625, 0.8288382733529669, 0.8244748146270647, 0.8200767256879185, 0.8156465569067172, 0.8111868363583186, 0.8067000667858837, 0.8021887226235823, 0.7976552470818193, 0.7931020492993314, 0.788531501566359, 0.7839459366229863, 0.7793476450365879, 0.7747388726621858, 0.7701218181893617, 0.7654986307792178, 0.7608714077947145, 0.7562421926275449, 0.7516129726245303, 0.7469856771163508, 0.7423621755512344, 0.7377442757360444, 0.7331337221870184, 0.7285321945922131, 0.7239413063875166, 0.7193626034478908, 0.7147975628952972, 0.7102475920245751, 0.7057140273483079, 0.7011981337615353, 0.6967011038269431, 0.6922240571809585, 0.6877680400609837, 0.6833340249537672, 0.678922910364736, 0.6745355207078785, 0.6701726063155848, 0.6658348435676292, 0.6615228351382993, 0.6572371103604555, 0.652978125705128, 0.6487462653750553, 0.6445418420103773, 0.6403650975045253, 0.6362162039281449, 0.6320952645587389, 0.6280023150135209, 0.6239373244828214, 0.6199001970612099, 0.6158907731733572, 0.6119088310914941, 0.607954088541194, 0.6040262043920547, 0.600124780429733, 0.5962493632056605, 0.592399445960638, 0.5885744706184185, 0.5847738298452588, 0.580996869171349, 0.5772428891699174, 0.5735111476897531, 0.5698008621367892, 0.5661112118003478, 0.5624413402195829, 0.5587903575855995, 0.5551573431747127, 0.5515413478082485, 0.5479413963342926, 0.5443564901267568, 0.5407856095971465, 0.5372277167143941, 0.5336817575281548, 0.5301466646909642, 0.5266213599746878, 0.5231047567767418, 0.5195957626115788, 0.5160932815830199, 0.512596216833041, 0.5091034729627112, 0.5056139584210421, 0.5021265878576008, 0.49864028443481323, 0.495153982096002, 0.49166662778528314, 0.4881771836155745, 0.48468462898108067, 0.4811879626107318, 0.47768620455920097, 0.47417839813224116, 0.4706636117432363, 0.4671409406979997, 0.46360950890500474, 0.4600684705083861, 0.456517011441216, 0.452954350896711, 0.4493797427152008, 0.44579247668486494, 0.44219187975439833, 0.4385773171559657, 0.4349481934369668, 0.43130395339932187, 0.4276440829451662, 0.42396810982803457, 0.4202756043087814, 0.41656617971569615, 0.41283949290843164, 0.40909524464556557, 0.40533317985579886, 0.40155308781296867, 0.3977548022152538, 0.39393820116911504, 0.3901032070787099, 0.38624978644168323, 0.3823779495524287, 0.3784877501140713, 0.37457928476060454, 0.37065269249077937, 0.3667081540154988, 0.3627458910206432, 0.35876616534739225, 0.3547692780922734, 0.3507555686293015, 0.34672541355672265, 0.34267922557100317, 0.3386174522708448, 0.33454057489411637, 0.33044910699072416, 0.3263435930345487, 0.3222246069776756, 0.31809275075026117, 0.3139486527094459, 0.30979296604083506, 0.3056263671161219, 0.3014495538105205, 0.29726324378371927, 0.2930681727281391, 0.28886509258831417, 0.28465476975526294, 0.28043798323974967, 0.27621552282835465, 0.2719881872263006, 0.26775678219097887, 0.2635221186601373, 0.2592850108786681, 0.25504627452793865, 0.2508067248615704, 0.24656717485155843, 0.24232843334857426, 0.23809130326025765, 0.23385657975125837, 0.22962504846871143, 0.22539748379679045, 0.22117464714388474, 0.21695728526589153, 0.21274612862900505, 0.20854188981531613, 0.20434526197441413, 0.20015691732409857, 0.19597750570318445, 0.19180765317927126, 0.18764796071423423, 0.18349900289005128, 0.1793613266974687, 0.1752354503898525, 0.1711218624044507, 0.16702102035312788, 0.1629333500845058, 0.15885924481927, 0.15479906436026827, 0.15075313437885252, 0.14672174577876182, 0.14270515413868753, 0.13870357923447962, 0.13471720464180928, 0.13074617741991168, 0.12679060787688864, 0.12285056941685543, 0.11892609846907325, 0.1150171944990141, 0.11112382010115643, 0.10724590117312854, 0.10338332717065128, 0.09953595144257509, 0.09570359164512376, 0.09188603023431621, 0.08808301503535663, 0.08429425988764722, 0.08051944536389885, 0.0767582195616907, 0.07301019896565653, 0.06927496937834828, 0.06555208691768595, 0.06184107907875341, 0.0581414458575926, 0.05445266093449617, 0.0507741729142018, 0.04710540662025539, 0.04344576444072017, 0.03979462772228273, 0.0361513582097326, 0.03251529952767819, 0.028885778701290055, 0.025262107712783595, 0.02164358509026728, 0.018029497525538108, 0.014419121517325008, 0.010811725036449933, 0.007206569209313456, 0.0036029100160944786, 7.190668994221072e-17, -0.003602910016094336, -0.007206569209313313, -0.01081172503644979, -0.01441912151732486, -0.018029497525537962, -0.021643585090267127, -0.025262107712783456, -0.02888577870128991, -0.03251529952767804, -0.03615135820973251, -0.03979462772228258, -0.043445764440719965, -0.047105406620255245, -0.0507741729142016, -0.05445266093449603, -0.0581414458575925, -0.061841079078753206, -0.0655520869176858, -0.0692749693783481, -0.07301019896565639, -0.0767582195616906, -0.08051944536389871, -0.08429425988764702, -0.08808301503535651, -0.09188603023431607, -0.09570359164512361, -0.09953595144257496, -0.10338332717065107, -0.10724590117312836, -0.11112382010115632, -0.11501719449901396, -0.1189260984690731, -0.12285056941685528, -0.12679060787688842, -0.1307461774199115, -0.13471720464180909, -0.13870357923447948, -0.1427051541386874, -0.14672174577876165, -0.15075313437885238, -0.1547990643602681, -0.15885924481926986, -0.16293335008450563, -0.16702102035312774, -0.17112186240445054, -0.1752354503898523, -0.17936132669746854, -0.18349900289005108, -0.18764796071423406, -0.19180765317927112, -0.1959775057031843, -0.2001569173240984, -0.20434526197441397, -0.208541889815316, -0.21274612862900483, -0.21695728526589136, -0.22117464714388452, -0.22539748379679028, -0.22962504846871126, -0.2338565797512582, -0.23809130326025751, -0.24232843334857398, -0.2465671748515583, -0.25080672486157035, -0.2550462745279385, -0.25928501087866795, -0.2635221186601372, -0.2677567821909787, -0.27198818722630036, -0.27621552282835454, -0.28043798323974956, -0.2846547697552627, -0.28886509258831405, -0.2930681727281389, -0.29726324378371916, -0.3014495538105203, -0.30562636711612173, -0.30979296604083495, -0.3139486527094457, -0.318092750750261, -0.32222460697767535, -0.3263435930345485, -0.3304491069907241, -0.33454057489411615, -0.3386174522708446, -0.342679225571003, -0.3467254135567224, -0.35075556862930135, -0.3547692780922734, -0.3587661653473921, -0.36274589102064303, -0.3667081540154986, -0.3706526924907792, -0.37457928476060437, -0.3784877501140712, -0.3823779495524285, -0.3862497864416831, -0.39010320707870977, -0.393938201169115, -0.39775480221525383, -0.4015530878129685, -0.4053331798557988, -0.4090952446455654, -0.41283949290843147, -0.41656617971569604, -0.42027560430878114, -0.4239681098280344, -0.42764408294516604, -0.43130395339932165, -0.4349481934369667, -0.4385773171559656, -0.4421918797543981, -0.44579247668486477, -0.4493797427152007, -0.4529543508967107, -0.4565170114412161, -0.46006847050838584, -0.46360950890500463, -0.46714094069799955, -0.47066361174323634, -0.474178398132241, -0.47768620455920097, -0.4811879626107316, -0.4846846289810805, -0.4881771836155745, -0.4916666277852831, -0.4951539820960019, -0.49864028443481306, -0.5021265878576006, -0.5056139584210418, -0.5091034729627111, -0.5125962168330409, -0.5160932815830198, -0.5195957626115785, -0.5231047567767416, -0.5266213599746878, -0.5301466646909639, -0.5336817575281547, -0.5372277167143938, -0.5407856095971462, -0.544356490126757, -0.5479413963342925, -0.5515413478082484, -0.5551573431747122, -0.5587903575855995, -0.5624413402195828, -0.5661112118003478, -0.5698008621367886, -0.5735111476897532, -0.5772428891699172, -0.5809968691713485, -0.584773829845259, -0.5885744706184184, -0.5923994459606379, -0.59624936320566, -0.600124780429733, -0.6040262043920546, -0.6079540885411938, -0.6119088310914937, -0.615890773173357, -0.6199001970612096, -0.623937324482821, -0.628002315013521, -0.6320952645587388, -0.6362162039281447, -0.6403650975045249, -0.6445418420103775, -0.6487462653750549, -0.6529781257051279, -0.6572371103604551, -0.6615228351382992, -0.6658348435676291, -0.6701726063155842, -0.6745355207078788, -0.6789229103647358, -0.683334024953767, -0.6877680400609831, -0.6922240571809586, -0.6967011038269428, -0.7011981337615353, -0.7057140273483073, -0.710247592024575, -0.7147975628952971, -0.7193626034478903, -0.7239413063875167, -0.7285321945922131, -0.7331337221870181, -0.7377442757360438, -0.7423621755512344, -0.7469856771163506, -0.75161297262453, -0.7562421926275444, -0.7608714077947146, -0.7654986307792174, -0.7701218181893612, -0.774738872662186, -0.7793476450365879, -0.783945936622986, -0.7885315015663584, -0.7931020492993313, -0.7976552470818191, -0.8021887226235818, -0.806700066785884, -0.8111868363583186, -0.8156465569067172, -0.8200767256879181, -0.824474814627065, -0.8288382733529669, -0.8331645322869621, -0.8374510057806035, -0.8416950952974053, -0.8458941926337945, -0.8500456831743387, -0.8541469491762405, -0.8581953730780353, -0.8621883408273798, -0.8661232452227535, -0.8699974892638866, -0.8738084895056724, -0.8775536794103321, -0.8812305126925627, -0.8848364666524152, -0.8883690454906535, -0.8918257836013674, -0.8952042488366231, -0.8985020457379814, -0.9017168187297717, -0.9048462552690216, -0.9078880889470498, -0.9108401025377633, -0.9137001309878071, -0.9164660643437719, -0.9191358506117902, -0.9217074985449252, -0.924179080353897, -0.9265487343367809, -0.9288146674234581, -0.9309751576307267, -0.9330285564241152, -0.9349732909825982, -0.9368078663625591, -0.9385308675575097, -0.940140961450241, -0.9416368986542472, -0.9430175152414506, -0.944281734353432, -0.945428567693557, -0.9464571168975796, -0.9473665747805143, -0.9481562264577416, -0.9488254503385406, -0.9493737189904329, -0.9498005998729376, -0.9501057559395469, -0.9502889461069446, -0.9503500255907076, -0.9502889461069446, -0.9501057559395469, -0.9498005998729374, -0.9493737189904329, -0.9488254503385406, -0.9481562264577418, -0.9473665747805143, -0.9464571168975796, -0.945428567693557, -0.9442817343534322, -0.9430175152414506, -0.9416368986542472, -0.9401409614502411, -0.93853086755751, -0.9368078663625591, -0.9349732909825985, -0.9330285564241154, -0.930975157630727, -0.9288146674234583, -0.9265487343367809, -0.924179080353897, -0.9217074985449254, -0.9191358506117905, -0.9164660643437722, -0.9137001309878071, -0.9108401025377636, -0.9078880889470501, -0.9048462552690221, -0.9017168187297718, -0.8985020457379816, -0.8952042488366232, -0.8918257836013678, -0.8883690454906538, -0.8848364666524153, -0.881230512692563, -0.8775536794103327, -0.8738084895056726, -0.8699974892638866, -0.866123245222754, -0.8621883408273798, -0.8581953730780355, -0.8541469491762407, -0.8500456831743389, -0.8458941926337948, -0.8416950952974054, -0.8374510057806036, -0.8331645322869623, -0.8288382733529671, -0.8244748146270653, -0.8200767256879182, -0.8156465569067173, -0.8111868363583189, -0.8067000667858842, -0.8021887226235819, -0.7976552470818193, -0.7931020492993316, -0.7885315015663587, -0.7839459366229863, -0.7793476450365882, -0.7747388726621863, -0.7701218181893615, -0.7654986307792178, -0.760871407794715, -0.7562421926275447, -0.7516129726245302, -0.746985677116351, -0.7423621755512345, -0.7377442757360441, -0.7331337221870183, -0.7285321945922132, -0.7239413063875171, -0.7193626034478904, -0.7147975628952973, -0.7102475920245753, -0.7057140273483076, -0.7011981337615355, -0.696701103826943, -0.6922240571809587, -0.6877680400609834, -0.6833340249537672, -0.6789229103647362, -0.674535520707879, -0.6701726063155846, -0.6658348435676291, -0.6615228351382996, -0.6572371103604552, -0.6529781257051281, -0.6487462653750553, -0.6445418420103776, -0.6403650975045251, -0.6362162039281449, -0.632095264558739, -0.6280023150135214, -0.6239373244828211, -0.6199001970612098, -0.6158907731733572, -0.6119088310914941, -0.6079540885411943, -0.6040262043920547, -0.6001247804297333, -0.5962493632056601, -0.5923994459606381, -0.5885744706184186, -0.5847738298452593, -0.5809968691713486, -0.5772428891699174, -0.5735111476897533, -0.5698008621367889, -0.5661112118003482, -0.5624413402195828, -0.5587903575855997, -0.5551573431747124, -0.5515413478082485, -0.5479413963342927, -0.5443564901267571, -0.5407856095971465, -0.5372277167143941, -0.5336817575281553, -0.5301466646909638, -0.5266213599746882, -0.523104756776742, -0.5195957626115789, -0.5160932815830196, -0.5125962168330411, -0.5091034729627113, -0.5056139584210424, -0.5021265878576002, -0.49864028443481323, -0.4951539820960024, -0.49166662778528286, -0.4881771836155746, -0.48468462898108067, -0.481187962610732, -0.4776862045592009, -0.47417839813224116, -0.47066361174323645, -0.467140940698, -0.46360950890500463, -0.4600684705083862, -0.4565170114412163, -0.4529543508967107, -0.44937974271520087, -0.44579247668486516, -0.44219187975439855, -0.43857731715596554, -0.4349481934369669, -0.43130395339932187, -0.42764408294516676, -0.4239681098280343, -0.4202756043087814, -0.4165661797156965, -0.41283949290843147, -0.4090952446455657, -0.4053331798557988, -0.40155308781296895, -0.3977548022152536, -0.3939382011691152, -0.39010320707871, -0.38624978644168345, -0.3823779495524288, -0.3784877501140715, -0.37457928476060487, -0.37065269249077915, -0.3667081540154989, -0.3627458910206434, -0.35876616534739253, -0.3547692780922733, -0.35075556862930163, -0.34672541355672265, -0.34267922557100355, -0.3386174522708448, -0.3345405748941165, -0.3304491069907246, -0.3263435930345485, -0.32222460697767563, -0.3180927507502611, -0.31394865270944633, -0.30979296604083484, -0.30562636711612196, -0.30144955381052063, -0.2972632437837196, -0.29306817272813923, -0.2888650925883143, -0.2846547697552634, -0.2804379832397495, -0.2762155228283547, -0.2719881872263007, -0.26775678219097926, -0.2635221186601372, -0.2592850108786682, -0.25504627452793877, -0.2508067248615708, -0.24656717485155857, -0.24232843334857435, -0.23809130326025807, -0.23385657975125812, -0.22962504846871148, -0.2253974837967905, -0.22117464714388518, -0.21695728526589125, -0.21274612862900516, -0.20854188981531627, -0.20434526197441372, -0.2001569173240986, -0.19597750570318456, -0.19180765317927165, -0.18764796071423406, -0.18349900289005133, -0.1793613266974687, -0.17523545038985297, -0.17112186240445054, -0.167021020353128, -0.1629333500845058, -0.15885924481926972, -0.15479906436026847, -0.15075313437885263, -0.14672174577876218, -0.14270515413868734, -0.13870357923447976, -0.1347172046418095, -0.13074617741991215, -0.12679060787688845, -0.1228505694168555, -0.11892609846907336, -0.11501719449901378, -0.11112382010115632, -0.10724590117312859, -0.1033833271706516, -0.0995359514425749, -0.09570359164512385, -0.09188603023431607, -0.08808301503535715, -0.08429425988764702, -0.08051944536389893, -0.07675821956169104, -0.0730101989656562, -0.0692749693783486, -0.06555208691768596, -0.061841079078753684, -0.0581414458575925, -0.054452660934496244, -0.05077417291420212, -0.04710540662025582, -0.04344576444072003, -0.03979462772228274, -0.03615135820973288, -0.0325152995276779, -0.028885778701289857, -0.025262107712783664, -0.021643585090267602, -0.01802949752553798, -0.01441912151732514, -0.010811725036449732, -0.007206569209313944, -0.0036029100160942934, ], [0.0, 0.003318417159320384, 0.006637209076492945, 0.009956750325945788, 0.013277415115323151, 0.016599577102254147, 0.01992360921131421, 0.023249883451243503, 0.02657877073248617, 0.029910640685114554, 0.03324586147720209, 0.03658479963370856, 0.03992781985594126, 0.04327528484165525, 0.0466275551058559, 0.04998498880236647, 0.0533479415462234, 0.05671676623696143, 0.060091812882850794, 0.06347342842614793, 0.06686195656942127, 0.07025773760301263, 0.0736611082336954, 0.07707240141458921, 0.08049194617639104, 0.0839200674599818, 0.08735708595046775, 0.09080331791271445, 0.09425907502843148, 0.09772466423486514, 0.10120038756515581, 0.10468654199041624, 0.10818341926358632, 0.11169130576511939, 0.1152104823505543, 0.1187412242000273, 0.12228380066977643, 0.12583847514569094, 0.12940550489895777, 0.13298514094385547, 0.13657762789774636, 0.14018320384331603, 0.14380210019310935, 0.1474345415564106, 0.15108074560851487, 0.15474092296243738, 0.15841527704310618, 0.1621040039640826, 0.16580729240685316, 0.16952532350273736, 0.17325827071745076, 0.17700629973836687, 0.18076956836451724, 0.18454822639936835, 0.18834241554641507, 0.19215226930762677, 0.19597791288478322, 0.19981946308373577, 0.20367702822162778, 0.20755070803710787, 0.21144059360356926, 0.2153467672454449, 0.21926930245759058, 0.2232082638277838, 0.22716370696236796, 0.23113567841506766, 0.23512421561900268, 0.23912934682192413, 0.2431510910246981, 0.24718945792305827, 0.25124444785265077, 0.25531605173738997, 0.259404251041146, 0.26350901772278207, 0.26763031419455846, 0.2717680932839186, 0.27592229819867475, 0.28009286249560267, 0.2842797100524616, 0.28848275504345, 0.29270190191810513, 0.29693704538365906, 0.30118807039085577, 0.30545485212323803, 0.3097372559899084, 0.3140351376217701, 0.3183483428712487, 0.32267670781549856, 0.32702005876309437, 0.3313782122642059, 0.3357509751242563, 0.3401381444210605, 0.34453950752543916, 0.34895484212530303, 0.3533839162532024, 0.357826488317333, 0.36228230713599047, 0.36675111197546273, 0.37123263259134914, 0.3757265892732963, 0.3802326928931329, 0.38475064495639383, 0.3892801376572149, 0.3938208539365813, 0.3983724675439133, 0.40293464310196936, 0.4075070361750454, 0.412089293340451, 0.4166810522632374, 0.42128194177415595, 0.4258915819508212, 0.43050958420205215, 0.43513555135536663, 0.43976907774759927, 0.4444097493186141, 0.44905714370808314, 0.45371083035529686, 0.45837037060197894, 0.4630353177980665, 0.46770521741042614, 0.4723796071344692, 0.4770580170086281, 0.48173996953165915, 0.48642497978273314, 0.4911125555442727, 0.49580219742749954, 0.5004933990006467, 0.5051856469197981, 0.509878421062309, 0.5145711946627665, 0.519263434451443, 0.523954600795199, 0.5286441478407884, 0.5333315236605201, 0.5380161704002251, 0.5426975244294848, 0.547375016494068, 0.5520480718705257, 0.5567161105228974, 0.5613785472614707, 0.566034791903548, 0.5706842494361621, 0.5753263201806913, 0.5799603999593165, 0.5845858802632665, 0.5892021484227965, 0.5938085877788416, 0.5984045778562914, 0.6029894945388247, 0.6075627102452503, 0.6121235941072924, 0.6166715121487628, 0.6212058274660608, 0.625725900409941, 0.6302310887684878, 0.634720747951236, 0.6391942311743781, 0.643650889646994, 0.6480900727582439, 0.6525111282654598, 0.656913402483076, 0.6612962404723319, 0.6656589862316886, 0.670000982887891, 0.6743215728876165, 0.6786200981896441, 0.6828959004574789, 0.6871483212523704, 0.6913767022266594, 0.6955803853173883, 0.6997587129401107, 0.7039110281828369, 0.708036675000048, 0.7121349984067179, 0.716205344672274, 0.7202470615144357, 0.7242594982928643, 0.728242006202559, 0.7321939384669363, 0.7361146505305278, 0.7400035002512316, 0.7438598480920521, 0.7476830573122676, 0.7514724941579585, 0.7552275280518332, 0.7589475317822914, 0.7626318816916554, 0.7662799578635143, 0.7698911443091104, 0.7734648291527138, 0.7770004048159153, 0.7804972682007829, 0.7839548208718156, 0.7873724692366365, 0.7907496247253643, 0.7940857039686018, 0.7973801289739844, 0.800632327301228, 0.8038417322356174, 0.8070077829598772, 0.8101299247243705, 0.8132076090155644, 0.8162402937227089, 0.8192274433026705, 0.8221685289428704, 0.8250630287222667, 0.8279104277703315, 0.8307102184239673, 0.8334619003823096, 0.8361649808593675, 0.8388189747344467, 0.8414234047003079, 0.8439778014090086, 0.8464817036153816, 0.8489346583181009, 0.851336220898288, 0.8536859552556118, 0.8559834339418384, 0.8582282382917823, 0.8604199585516209, 0.8625581940045224, 0.8646425530935509, 0.8666726535418048, 0.8686481224697479, 0.8705685965096958, 0.8724337219174174, 0.8742431546808147, 0.8759965606256465, 0.8776936155182553, 0.8793340051652702, 0.8809174255102458, 0.8824435827272091, 0.8839121933110821, 0.8853229841649463, 0.8866756926841263, 0.8879700668370586, 0.8892058652429217, 0.8903828572460003, 0.8915008229867584, 0.8925595534695986, 0.8935588506272846, 0.8944985273820036, 0.8953784077030515, 0.8961983266611169, 0.8969581304791522, 0.8976576765798067, 0.8982968336294131, 0.8988754815785094, 0.89939351169888, 0.899850826617111, 0.9002473403446396, 0.900582978304294, 0.9008576773533131, 0.9010713858028362, 0.9012240634338592, 0.9013156815096502, 0.9013462227846212, 0.9013156815096502, 0.9012240634338592, 0.9010713858028362, 0.9008576773533131, 0.900582978304294, 0.9002473403446396, 0.899850826617111, 0.89939351169888, 0.8988754815785094, 0.8982968336294131, 0.8976576765798067, 0.8969581304791522, 0.8961983266611171, 0.8953784077030515, 0.8944985273820036, 0.8935588506272846, 0.8925595534695986, 0.8915008229867584, 0.8903828572460003, 0.8892058652429217, 0.8879700668370586, 0.8866756926841263, 0.8853229841649463, 0.8839121933110821, 0.8824435827272091, 0.8809174255102458, 0.8793340051652702, 0.8776936155182553, 0.8759965606256465, 0.8742431546808147, 0.8724337219174174, 0.8705685965096958, 0.8686481224697479, 0.8666726535418048, 0.8646425530935509, 0.8625581940045224, 0.8604199585516209, 0.8582282382917823, 0.8559834339418384, 0.8536859552556118, 0.851336220898288, 0.848934658318101, 0.8464817036153818, 0.8439778014090086, 0.8414234047003079, 0.8388189747344468, 0.8361649808593675, 0.8334619003823096, 0.8307102184239672, 0.8279104277703317, 0.8250630287222667, 0.8221685289428704, 0.8192274433026705, 0.816240293722709, 0.8132076090155647, 0.8101299247243705, 0.8070077829598772, 0.8038417322356174, 0.8006323273012281, 0.7973801289739844, 0.7940857039686018, 0.7907496247253644, 0.7873724692366366, 0.7839548208718157, 0.780497268200783, 0.7770004048159153, 0.7734648291527138, 0.7698911443091104, 0.7662799578635143, 0.7626318816916556, 0.7589475317822912, 0.7552275280518335, 0.7514724941579585, 0.7476830573122678, 0.7438598480920523, 0.7400035002512315, 0.736114650530528, 0.7321939384669363, 0.7282420062025591, 0.7242594982928644, 0.720247061514436, 0.716205344672274, 0.7121349984067181, 0.7080366750000481, 0.703911028182837, 0.6997587129401109, 0.6955803853173882, 0.6913767022266595, 0.6871483212523704, 0.6828959004574789, 0.6786200981896443, 0.6743215728876168, 0.670000982887891, 0.6656589862316888, 0.6612962404723322, 0.6569134024830762, 0.6525111282654599, 0.6480900727582437, 0.6436508896469941, 0.6391942311743781, 0.6347207479512362, 0.6302310887684878, 0.6257259004099412, 0.6212058274660609, 0.616671512148763, 0.6121235941072924, 0.6075627102452502, 0.6029894945388249, 0.5984045778562912, 0.5938085877788417, 0.5892021484227965, 0.5845858802632666, 0.5799603999593165, 0.5753263201806915, 0.5706842494361622, 0.5660347919035483, 0.5613785472614709, 0.5567161105228973, 0.5520480718705258, 0.5473750164940678, 0.542697524429485, 0.5380161704002251, 0.5333315236605202, 0.5286441478407884, 0.5239546007951992, 0.519263434451443, 0.5145711946627668, 0.5098784210623091, 0.5051856469197978, 0.5004933990006467, 0.49580219742749954, 0.49111255554427297, 0.48642497978273314, 0.4817399695316593, 0.4770580170086281, 0.47237960713446947, 0.46770521741042625, 0.46303531779806667, 0.45837037060197905, 0.45371083035529675, 0.4490571437080832, 0.4444097493186141, 0.4397690777475994, 0.43513555135536675, 0.4305095842020524, 0.4258915819508212, 0.42128194177415634, 0.41668105226323743, 0.4120892933404512, 0.4075070361750455, 0.40293464310196925, 0.3983724675439135, 0.3938208539365813, 0.3892801376572151, 0.38475064495639383, 0.38023269289313294, 0.3757265892732963, 0.3712326325913494, 0.3667511119754628, 0.36228230713599047, 0.3578264883173331, 0.3533839162532023, 0.34895484212530314, 0.34453950752543916, 0.34013814442106066, 0.3357509751242563, 0.33137821226420594, 0.3270200587630944, 0.32267670781549884, 0.31834834287124875, 0.31403513762177, 0.3097372559899085, 0.305454852123238, 0.30118807039085593, 0.29693704538365906, 0.2927019019181053, 0.28848275504345, 0.2842797100524618, 0.2800928624956027, 0.275922298198675, 0.2717680932839187, 0.26763031419455835, 0.26350901772278224, 0.259404251041146, 0.2553160517373901, 0.25124444785265077, 0.2471894579230584, 0.2431510910246981, 0.23912934682192435, 0.23512421561900276, 0.23113567841506788, 0.22716370696236804, 0.22320826382778372, 0.21926930245759071, 0.21534676724544485, 0.2114405936035694, 0.20755070803710787, 0.20367702822162792, 0.19981946308373583, 0.19597791288478342, 0.1921522693076268, 0.18834241554641523, 0.18454822639936846, 0.18076956836451716, 0.177006299738367, 0.17325827071745073, 0.16952532350273747, 0.16580729240685316, 0.16210400396408273, 0.15841527704310623, 0.15474092296243758, 0.1510807456085149, 0.14743454155641078, 0.1438021001931095, 0.14018320384331598, 0.13657762789774652, 0.13298514094385547, 0.12940550489895786, 0.12583847514569085, 0.12228380066977657, 0.1187412242000273, 0.1152104823505545, 0.11169130576511944, 0.10818341926358646, 0.10468654199041637, 0.1012003875651558, 0.09772466423486532, 0.0942590750284315, 0.09080331791271455, 0.08735708595046769, 0.08392006745998196, 0.08049194617639102, 0.07707240141458943, 0.07366110823369545, 0.07025773760301254, 0.0668619565694214, 0.06347342842614791, 0.06009181288285096, 0.05671676623696145, 0.05334794154622348, 0.049984988802366404, 0.04662755510585604, 0.04327528484165525, 0.03992781985594147, 0.03658479963370863, 0.033245861477202, 0.02991064068511468, 0.026578770732486146, 0.023249883451243687, 0.019923609211314253, 0.016599577102254234, 0.013277415115323092, 0.00995675032594594, 0.006637209076492949, 0.0033184171593205985, 6.622984599940461e-17, -0.0033184171593204667, -0.006637209076492816, -0.009956750325945807, -0.013277415115322957, -0.016599577102254102, -0.019923609211314114, -0.02324988345124356, -0.02657877073248601, -0.029910640685114544, -0.03324586147720186, -0.0365847996337085, -0.03992781985594134, -0.04327528484165512, -0.046627555105855915, -0.04998498880236628, -0.05334794154622335, -0.056716766236961313, -0.060091812882850835, -0.06347342842614778, -0.06686195656942126, -0.0702577376030124, -0.07366110823369533, -0.0770724014145893, -0.0804919461763909, -0.08392006745998179, -0.08735708595046757, -0.0908033179127144, -0.09425907502843138, -0.09772466423486517, -0.10120038756515563, -0.10468654199041624, -0.10818341926358632, -0.11169130576511929, -0.11521048235055435, -0.11874122420002717, -0.12228380066977643, -0.12583847514569074, -0.12940550489895772, -0.13298514094385536, -0.13657762789774638, -0.14018320384331584, -0.14380210019310935, -0.14743454155641064, -0.15108074560851478, -0.15474092296243747, -0.15841527704310604, -0.16210400396408253, -0.16580729240685296, -0.16952532350273733, -0.1732582707174506, -0.17700629973836687, -0.18076956836451702, -0.18454822639936833, -0.18834241554641507, -0.19215226930762666, -0.1959779128847833, -0.19981946308373566, -0.20367702822162778, -0.2075507080371077, -0.2114405936035692, -0.21534676724544471, -0.2192693024575905, -0.22320826382778353, -0.2271637069623679, -0.2311356784150677, -0.2351242156190026, -0.2391293468219242, -0.24315109102469795, -0.24718945792305827, -0.25124444785265054, -0.25531605173738997, -0.25940425104114584, -0.26350901772278207, -0.2676303141945582, -0.2717680932839186, -0.2759222981986748, -0.2800928624956025, -0.2842797100524617, -0.28848275504344983, -0.29270190191810513, -0.29693704538365884, -0.3011880703908557, -0.30545485212323775, -0.3097372559899083, -0.3140351376217698, -0.3183483428712486, -0.3226767078154986, -0.32702005876309426, -0.3313782122642059, -0.3357509751242561, -0.3401381444210605, -0.344539507525439, -0.34895484212530303, -0.3533839162532021, -0.35782648831733294, -0.3622823071359903, -0.3667511119754626, -0.37123263259134925, -0.37572658927329616, -0.3802326928931329, -0.38475064495639366, -0.3892801376572149, -0.39382085393658106, -0.39837246754391326, -0.402934643101969, -0.40750703617504525, -0.412089293340451, -0.41668105226323726, -0.4212819417741561, -0.42589158195082116, -0.43050958420205215, -0.43513555135536647, -0.43976907774759927, -0.44440974931861404, -0.449057143708083, -0.4537108303552967, -0.45837037060197877, -0.4630353177980665, -0.46770521741042603, -0.47237960713446936, -0.47705801700862793, -0.48173996953165915, -0.48642497978273286, -0.4911125555442727, -0.4958021974274994, -0.5004933990006467, -0.5051856469197976, -0.5098784210623089, -0.5145711946627666, -0.5192634344514429, -0.5239546007951991, -0.5286441478407883, -0.5333315236605201, -0.5380161704002249, -0.5426975244294848, -0.5473750164940678, -0.5520480718705257, -0.5567161105228972, -0.5613785472614707, -0.5660347919035478, -0.5706842494361624, -0.5753263201806914, -0.5799603999593164, -0.5845858802632661, -0.5892021484227965, -0.5938085877788416, -0.5984045778562912, -0.6029894945388242, -0.6075627102452504, -0.6121235941072923, -0.6166715121487625, -0.621205827466061, -0.6257259004099411, -0.6302310887684878, -0.6347207479512357, -0.6391942311743781, -0.643650889646994, -0.6480900727582436, -0.6525111282654594, -0.6569134024830761, -0.6612962404723318, -0.6656589862316884, -0.6700009828878912, -0.6743215728876167, -0.6786200981896441, -0.6828959004574784, -0.6871483212523704, -0.6913767022266593, -0.6955803853173881, -0.6997587129401104, -0.7039110281828369, -0.708036675000048, -0.7121349984067176, -0.7162053446722741, -0.7202470615144358, -0.7242594982928643, -0.7282420062025586, -0.7321939384669363, -0.7361146505305278, -0.7400035002512315, -0.7438598480920517, -0.7476830573122677, -0.7514724941579582, -0.7552275280518329, -0.7589475317822915, -0.7626318816916556, -0.7662799578635141, -0.7698911443091101, -0.7734648291527138, -0.7770004048159151, -0.7804972682007828, -0.7839548208718153, -0.7873724692366367, -0.7907496247253641, -0.7940857039686015, -0.7973801289739846, -0.8006323273012282, -0.8038417322356173, -0.8070077829598767, -0.8101299247243705, -0.8132076090155644, -0.8162402937227087, -0.8192274433026707, -0.8221685289428704, -0.8250630287222667, -0.8279104277703315, -0.8307102184239673, -0.8334619003823096, -0.8361649808593673, -0.8388189747344464, -0.8414234047003079, -0.8439778014090086, -0.8464817036153816, -0.848934658318101, -0.851336220898288, -0.8536859552556118, -0.8559834339418382, -0.8582282382917823, -0.8604199585516209, -0.8625581940045223, -0.8646425530935508, -0.8666726535418048, -0.8686481224697479, -0.8705685965096956, -0.8724337219174175, -0.8742431546808147, -0.8759965606256465, -0.8776936155182552, -0.8793340051652702, -0.8809174255102455, -0.8824435827272091, -0.8839121933110818, -0.8853229841649463, -0.8866756926841263, -0.8879700668370586, -0.8892058652429218, -0.8903828572460003, -0.8915008229867584, -0.8925595534695986, -0.8935588506272846, -0.8944985273820036, -0.8953784077030513, -0.8961983266611169, -0.8969581304791522, -0.8976576765798067, -0.8982968336294131, -0.8988754815785094, -0.89939351169888, -0.899850826617111, -0.9002473403446394, -0.900582978304294, -0.9008576773533131, -0.9010713858028362, -0.9012240634338592, -0.9013156815096502, -0.9013462227846212, -0.9013156815096502, -0.9012240634338592, -0.9010713858028362, -0.9008576773533131, -0.900582978304294, -0.9002473403446396, -0.899850826617111, -0.89939351169888, -0.8988754815785094, -0.8982968336294131, -0.8976576765798067, -0.8969581304791522, -0.8961983266611169, -0.8953784077030515, -0.8944985273820036, -0.8935588506272848, -0.8925595534695986, -0.8915008229867584, -0.8903828572460003, -0.8892058652429218, -0.8879700668370586, -0.8866756926841263, -0.8853229841649463, -0.8839121933110821, -0.8824435827272091, -0.8809174255102458, -0.8793340051652703, -0.8776936155182553, -0.8759965606256466, -0.8742431546808149, -0.8724337219174175, -0.8705685965096958, -0.8686481224697481, -0.8666726535418049, -0.8646425530935508, -0.8625581940045224, -0.8604199585516211, -0.8582282382917825, -0.8559834339418384, -0.8536859552556118, -0.8513362208982882, -0.8489346583181012, -0.8464817036153818, -0.8439778014090087, -0.8414234047003079, -0.8388189747344467, -0.8361649808593675, -0.8334619003823098, -0.8307102184239675, -0.8279104277703317, -0.8250630287222669, -0.8221685289428705, -0.8192274433026708, -0.8162402937227089, -0.8132076090155647, -0.8101299247243706, -0.8070077829598769, -0.8038417322356174, -0.8006323273012285, -0.7973801289739848, -0.7940857039686017, -0.7907496247253644, -0.787372469236637, -0.7839548208718156, -0.7804972682007829, -0.7770004048159155, -0.7734648291527139, -0.7698911443091103, -0.7662799578635143, -0.7626318816916559, -0.7589475317822917, -0.7552275280518332, -0.7514724941579585, -0.747683057312268, -0.743859848092052, -0.7400035002512317, -0.736114650530528, -0.7321939384669364, -0.7282420062025587, -0.7242594982928645, -0.7202470615144362, -0.7162053446722745, -0.7121349984067179, -0.7080366750000481, -0.7039110281828372, -0.6997587129401106, -0.6955803853173884, -0.6913767022266596, -0.6871483212523706, -0.6828959004574787, -0.6786200981896443, -0.674321572887617, -0.6700009828878914, -0.6656589862316886, -0.661296240472332, -0.6569134024830763, -0.6525111282654598, -0.6480900727582439, -0.6436508896469942, -0.6391942311743785, -0.6347207479512359, -0.630231088768488, -0.6257259004099414, -0.6212058274660615, -0.6166715121487626, -0.6121235941072926, -0.6075627102452508, -0.6029894945388246, -0.5984045778562915, -0.5938085877788417, -0.5892021484227967, -0.5845858802632664, -0.5799603999593165, -0.5753263201806917, -0.5706842494361625, -0.5660347919035482, -0.561378547261471, -0.5567161105228977, -0.5520480718705256, -0.5473750164940681, -0.5426975244294853, -0.5380161704002254, -0.53333152366052, -0.5286441478407886, -0.5239546007951993, -0.5192634344514435, -0.5145711946627665, -0.5098784210623091, -0.5051856469197984, -0.5004933990006465, -0.4958021974274996, -0.49111255554427297, -0.4864249797827335, -0.48173996953165904, -0.4770580170086282, -0.47237960713446964, -0.4677052174104266, -0.4630353177980665, -0.45837037060197916, -0.4537108303552972, -0.449057143708083, -0.4444097493186143, -0.43976907774759955, -0.43513555135536713, -0.4305095842020521, -0.42589158195082133, -0.4212819417741564, -0.416681052263238, -0.4120892933404509, -0.4075070361750455, -0.4029346431019697, -0.39837246754391326, -0.3938208539365814, -0.3892801376572151, -0.3847506449563942, -0.3802326928931327, -0.3757265892732964, -0.3712326325913496, -0.3667511119754632, -0.3622823071359905, -0.3578264883173332, -0.3533839162532027, -0.3489548421253029, -0.34453950752543927, -0.3401381444210608, -0.33575097512425667, -0.3313782122642058, -0.32702005876309453, -0.3226767078154989, -0.31834834287124913, -0.3140351376217701, -0.3097372559899086, -0.3054548521232383, -0.3011880703908557, -0.2969370453836591, -0.29270190191810536, -0.2884827550434504, -0.2842797100524616, -0.2800928624956028, -0.2759222981986751, -0.2717680932839191, -0.2676303141945584, -0.2635090177227823, -0.2594042510411464, -0.2553160517373899, -0.2512444478526508, -0.24718945792305846, -0.24315109102469848, -0.2391293468219242, -0.23512421561900282, -0.23113567841506796, -0.22716370696236832, -0.22320826382778386, -0.21926930245759074, -0.21534676724544521, -0.21144059360356915, -0.20755070803710793, -0.20367702822162806, -0.19981946308373622, -0.19597791288478322, -0.19215226930762688, -0.18834241554641531, -0.18454822639936813, -0.18076956836451716, -0.1770062997383671, -0.1732582707174511, -0.1695253235027373, -0.1658072924068532, -0.1621040039640827, -0.15841527704310662, -0.15474092296243744, -0.15108074560851498, -0.1474345415564108, -0.1438021001931092, -0.14018320384331615, -0.13657762789774658, -0.1329851409438558, -0.12940550489895772, -0.12583847514569096, -0.12228380066977675, -0.11874122420002771, -0.11521048235055435, -0.1116913057651195, -0.10818341926358654, -0.10468654199041608, -0.1012003875651557, -0.09772466423486538, -0.0942590750284318, -0.09080331791271437, -0.08735708595046776, -0.08392006745998186, -0.08049194617639147, -0.07707240141458928, -0.07366110823369552, -0.07025773760301282, -0.06686195656942112, -0.06347342842614814, -0.06009181288285103, -0.05671676623696172, -0.05334794154622335, -0.04998498880236648, -0.04662755510585626, -0.043275284841655666, -0.039927819855941335, -0.036584799633708684, -0.03324586147720227, -0.029910640685114384, -0.026578770732486056, -0.023249883451243753, -0.019923609211314513, -0.0165995771022541, -0.01327741511532316, -0.009956750325945847, -0.006637209076493374, -0.0033184171593204645, ], [0.0, 0.004977500806761774, 0.009954814213431468, 0.014931752826972509, 0.019908129268459078, 0.02488375618013081, 0.02985844623244671, 0.034832012131138027, 0.03980426662425974, 0.0447750225092405, 0.04974409263993075, 0.054711289933648564, 0.05967642737822334, 0.06463931803903657, 0.06959977506605992, 0.07455761170088998, 0.07951264128377965, 0.0844646772606657, 0.0894135331901925, 0.09435902275073144, 0.09930095974739575, 0.1042391581190507, 0.10917343194531867, 0.11410359545357898, 0.11902946302596208, 0.12395084920633799, 0.12886756870729857, 0.13377943641713355, 0.13868626740679987, 0.14358787693688413, 0.1484840804645579, 0.15337469365052572, 0.15825953236596535, 0.16313841269946006, 0.16801115096392283, 0.17287756370351204, 0.17773746770053866, 0.18259067998236397, 0.18743701782828862, 0.19227629877643185, 0.19710834063060115, 0.20193296146715178, 0.2067499796418362, 0.2115592137966426, 0.21636048286662332, 0.22115360608671158, 0.22593840299852716, 0.23071469345717083, 0.2354822976380062, 0.2402410360434307, 0.24499072950963297, 0.24973119921333845, 0.25446226667854227, 0.2591837537832282, 0.2638954827660755, 0.268597276233151, 0.27328895716458806, 0.2779703489212515, 0.2826412752513875, 0.28730156029725973, 0.2919510286017703, 0.2965895051150654, 0.301216815201126, 0.3058327846443427, 0.31043723965607495, 0.3150300068811939, 0.31961091340460956, 0.32417978675778025, 0.32873645492520676, 0.3332807463509079, 0.33781248994487995, 0.342331515089538, 0.3468376516461395, 0.3513307299611899, 0.35581058087283024, 0.3602770357172056, 0.36472992633481566, 0.3691690850768454, 0.37359434481147724, 0.3780055389301833, 0.38240250135399817, 0.38678506653977185, 0.3911530694864021, 0.3955063457410469, 0.39984473140531557, 0.40416806314143994, 0.4084761781784238, 0.41276891431817087, 0.4170461099415918, 0.421307604014689, 0.42555323609461887, 0.42978284633573344, 0.43399627549559777, 0.43819336494098543, 0.44237395665385093, 0.44653789323727916, 0.4506850179214112, 0.4548151745693468, 0.45892820768302234, 0.46302396240906596, 0.4671022845446271, 0.4711630205431823, 0.47520601752031655, 0.47923112325947864, 0.48323818621771264, 0.4872270555313628, 0.49119758102175404, 0.49514961320084583, 0.4990830032768602, 0.5029976031598841, 0.5068932654674445, 0.5107698435300573, 0.5146271913967494, 0.5184651638405539, 0.5222836163639775, 0.5260824052044408, 0.5298613873396912, 0.5336204204931873, 0.5373593631394551, 0.5410780745094175, 0.5447764145956931, 0.5484542441578681, 0.5521114247277382, 0.5557478186145219, 0.5593632889100447, 0.5629576994938935, 0.5665309150385414, 0.5700828010144425, 0.5736132236950975, 0.5771220501620881, 0.5806091483100806, 0.5840743868518009, 0.5875176353229764, 0.5909387640872483, 0.5943376443410523, 0.5977141481184677, 0.601068148296036, 0.604399518597546, 0.6077081335987887, 0.6109938687322796, 0.6142566002919477, 0.6174962054377943, 0.6207125622005163, 0.6239055494860994, 0.6270750470803774, 0.6302209356535571, 0.6333430967647116, 0.63644141286624, 0.6395157673082921, 0.6425660443431609, 0.6455921291296397, 0.6485939077373472, 0.651571267151015, 0.6545240952747442, 0.6574522809362249, 0.6603557138909216, 0.6632342848262252, 0.6660878853655664, 0.6689164080724981, 0.671719746454739, 0.6744977949681833, 0.6772504490208747, 0.679977604976944, 0.6826791601605109, 0.6853550128595495, 0.6880050623297181, 0.6906292087981523, 0.6932273534672202, 0.6957993985182438, 0.6983452471151806, 0.7008648034082698, 0.7033579725376411, 0.7058246606368859, 0.7082647748365914, 0.7106782232678373, 0.713064915065654, 0.7154247603724444, 0.7177576703413666, 0.7200635571396786, 0.7223423339520458, 0.7245939149838094, 0.7268182154642159, 0.7290151516496096, 0.7311846408265846, 0.7333266013150997, 0.7354409524715533, 0.737527614691819, 0.7395865094142442, 0.7416175591226057, 0.7436206873490303, 0.7455958186768722, 0.7475428787435534, 0.7494617942433629, 0.7513524929302166, 0.7532149036203776, 0.7550489561951362, 0.7568545816034493, 0.758631711864541, 0.7603802800704615, 0.762100220388606, 0.7637914680641936, 0.7654539594227053, 0.7670876318722809, 0.7686924239060764, 0.7702682751045785, 0.7718151261378802, 0.7733329187679145, 0.7748215958506468, 0.7762811013382264, 0.7777113802810962, 0.7791123788300628, 0.7804840442383225, 0.7818263248634479, 0.7831391701693324, 0.784422530728092, 0.785676358221927, 0.7869006054449412, 0.7880952263049184, 0.7892601758250583, 0.7903954101456702, 0.7915008865258233, 0.7925765633449569, 0.7936224001044465, 0.7946383574291294, 0.7956243970687865, 0.7965804818995827, 0.7975065759254646, 0.7984026442795153, 0.799268653225268, 0.8001045701579752, 0.8009103636058369, 0.8016860032311851, 0.8024314598316266, 0.8031467053411414, 0.8038317128311404, 0.8044864565114789, 0.8051109117314273, 0.8057050549805996, 0.8062688638898382, 0.8068023172320563, 0.8073053949230372, 0.8077780780221901, 0.8082203487332634, 0.808632190405015, 0.8090135875318385, 0.8093645257543478, 0.8096849918599172, 0.8099749737831788, 0.8102344606064772, 0.81046344256028, 0.8106619110235465, 0.8108298585240507, 0.8109672787386644, 0.8110741664935941, 0.8111505177645763, 0.8111963296770288, 0.811211600506159, 0.8111963296770288, 0.8111505177645763, 0.8110741664935941, 0.8109672787386644, 0.8108298585240507, 0.8106619110235465, 0.81046344256028, 0.8102344606064772, 0.8099749737831788, 0.8096849918599172, 0.8093645257543478, 0.8090135875318385, 0.808632190405015, 0.8082203487332634, 0.8077780780221901, 0.8073053949230373, 0.8068023172320563, 0.8062688638898382, 0.8057050549805996, 0.8051109117314273, 0.8044864565114789, 0.8038317128311404, 0.8031467053411414, 0.8024314598316266, 0.8016860032311851, 0.8009103636058369, 0.8001045701579752, 0.799268653225268, 0.7984026442795153, 0.7975065759254646, 0.7965804818995827, 0.7956243970687865, 0.7946383574291294, 0.7936224001044465, 0.7925765633449569, 0.7915008865258233, 0.7903954101456702, 0.7892601758250584, 0.7880952263049184, 0.7869006054449412, 0.7856763582219272, 0.784422530728092, 0.7831391701693324, 0.7818263248634479, 0.7804840442383225, 0.7791123788300628, 0.7777113802810962, 0.7762811013382264, 0.7748215958506468, 0.7733329187679147, 0.7718151261378802, 0.7702682751045785, 0.7686924239060765, 0.767087631872281, 0.7654539594227053, 0.7637914680641936, 0.762100220388606, 0.7603802800704615, 0.7586317118645411, 0.7568545816034493, 0.7550489561951362, 0.7532149036203777, 0.7513524929302167, 0.7494617942433629, 0.7475428787435535, 0.7455958186768722, 0.7436206873490303, 0.7416175591226057, 0.7395865094142442, 0.7375276146918193, 0.7354409524715533, 0.7333266013150999, 0.7311846408265846, 0.7290151516496096, 0.726818215464216, 0.7245939149838093, 0.722342333952046, 0.7200635571396786, 0.7177576703413666, 0.7154247603724445, 0.7130649150656541, 0.7106782232678373, 0.7082647748365916, 0.705824660636886, 0.7033579725376412, 0.7008648034082698, 0.6983452471151805, 0.6957993985182438, 0.6932273534672202, 0.6906292087981523, 0.6880050623297181, 0.6853550128595497, 0.6826791601605109, 0.6799776049769441, 0.6772504490208747, 0.6744977949681834, 0.671719746454739, 0.6689164080724981, 0.6660878853655665, 0.6632342848262252, 0.6603557138909218, 0.6574522809362249, 0.6545240952747444, 0.6515712671510151, 0.6485939077373474, 0.6455921291296398, 0.6425660443431608, 0.6395157673082922, 0.6364414128662399, 0.6333430967647118, 0.6302209356535571, 0.6270750470803775, 0.6239055494860994, 0.6207125622005163, 0.6174962054377943, 0.614256600291948, 0.6109938687322797, 0.6077081335987887, 0.6043995185975461, 0.6010681482960359, 0.5977141481184679, 0.5943376443410523, 0.5909387640872484, 0.5875176353229764, 0.584074386851801, 0.5806091483100806, 0.5771220501620882, 0.5736132236950976, 0.5700828010144424, 0.5665309150385414, 0.5629576994938935, 0.5593632889100449, 0.5557478186145219, 0.5521114247277384, 0.5484542441578681, 0.5447764145956933, 0.5410780745094176, 0.5373593631394553, 0.5336204204931874, 0.5298613873396912, 0.5260824052044409, 0.5222836163639775, 0.518465163840554, 0.5146271913967494, 0.5107698435300574, 0.5068932654674445, 0.5029976031598844, 0.49908300327686034, 0.495149613200846, 0.49119758102175415, 0.4872270555313627, 0.4832381862177127, 0.47923112325947864, 0.47520601752031666, 0.4711630205431823, 0.4671022845446272, 0.46302396240906596, 0.45892820768302256, 0.4548151745693469, 0.4506850179214112, 0.44653789323727927, 0.4423739566538509, 0.4381933649409855, 0.43399627549559777, 0.4297828463357336, 0.42555323609461887, 0.42130760401468903, 0.41704610994159186, 0.41276891431817114, 0.4084761781784239, 0.4041680631414399, 0.3998447314053156, 0.3955063457410468, 0.39115306948640227, 0.38678506653977185, 0.38240250135399834, 0.3780055389301833, 0.3735943448114774, 0.3691690850768455, 0.36472992633481593, 0.3602770357172057, 0.3558105808728302, 0.35133072996118997, 0.34683765164613944, 0.34233151508953813, 0.33781248994487995, 0.3332807463509081, 0.3287364549252068, 0.3241797867577805, 0.3196109134046096, 0.31503000688119415, 0.310437239656075, 0.3058327846443426, 0.30121681520112614, 0.2965895051150654, 0.29195102860177047, 0.28730156029725973, 0.28264127525138766, 0.2779703489212515, 0.27328895716458834, 0.26859727623315105, 0.2638954827660758, 0.25918375378322833, 0.25446226667854216, 0.24973119921333858, 0.2449907295096329, 0.24024103604343086, 0.23548229763800624, 0.23071469345717102, 0.22593840299852724, 0.2211536060867118, 0.21636048286662343, 0.2115592137966429, 0.20674997964183628, 0.20193296146715173, 0.1971083406306013, 0.19227629877643182, 0.18743701782828878, 0.18259067998236397, 0.17773746770053886, 0.1728775637035121, 0.16801115096392305, 0.16313841269946014, 0.15825953236596563, 0.15337469365052586, 0.14848408046455785, 0.1435878769368843, 0.13868626740679987, 0.13377943641713372, 0.12886756870729857, 0.12395084920633818, 0.11902946302596212, 0.11410359545357925, 0.10917343194531876, 0.10423915811905064, 0.09930095974739588, 0.09435902275073141, 0.08941353319019266, 0.08446467726066569, 0.07951264128377983, 0.07455761170089001, 0.06959977506606013, 0.06463931803903662, 0.05967642737822359, 0.05471128993364867, 0.049744092639930675, 0.04477502250924063, 0.0398042666242597, 0.034832012131138186, 0.02985844623244671, 0.024883756180131004, 0.01990812926845911, 0.014931752826972738, 0.009954814213431534, 0.004977500806762037, 9.934476899910691e-17, -0.004977500806761838, -0.009954814213431334, -0.01493175282697254, -0.019908129268458908, -0.024883756180130803, -0.02985844623244651, -0.03483201213113799, -0.0398042666242595, -0.04477502250924043, -0.04974409263993048, -0.05471128993364846, -0.059676427378223394, -0.06463931803903643, -0.06959977506605994, -0.07455761170088981, -0.07951264128377963, -0.08446467726066549, -0.08941353319019246, -0.09435902275073121, -0.09930095974739568, -0.10423915811905043, -0.10917343194531857, -0.11410359545357905, -0.11902946302596194, -0.12395084920633799, -0.12886756870729837, -0.13377943641713352, -0.13868626740679968, -0.14358787693688407, -0.14848408046455766, -0.15337469365052567, -0.15825953236596543, -0.16313841269945992, -0.16801115096392283, -0.17287756370351193, -0.17773746770053866, -0.18259067998236378, -0.1874370178282886, -0.19227629877643163, -0.1971083406306011, -0.20193296146715156, -0.20674997964183608, -0.2115592137966427, -0.21636048286662324, -0.22115360608671164, -0.22593840299852702, -0.2307146934571708, -0.23548229763800602, -0.2402410360434307, -0.24499072950963274, -0.2497311992133384, -0.254462266678542, -0.25918375378322817, -0.26389548276607555, -0.2685972762331509, -0.2732889571645881, -0.27797034892125133, -0.2826412752513875, -0.2873015602972595, -0.2919510286017703, -0.29658950511506516, -0.3012168152011259, -0.30583278464434244, -0.31043723965607484, -0.315030006881194, -0.3196109134046094, -0.3241797867577803, -0.32873645492520664, -0.3332807463509079, -0.3378124899448798, -0.34233151508953796, -0.3468376516461393, -0.3513307299611898, -0.35581058087282996, -0.36027703571720554, -0.3647299263348157, -0.3691690850768453, -0.3735943448114773, -0.3780055389301831, -0.38240250135399817, -0.3867850665397717, -0.3911530694864021, -0.39550634574104665, -0.39984473140531546, -0.4041680631414397, -0.40847617817842374, -0.4127689143181709, -0.4170461099415917, -0.421307604014689, -0.4255532360946187, -0.42978284633573344, -0.4339962754955976, -0.43819336494098543, -0.44237395665385065, -0.44653789323727905, -0.450685017921411, -0.4548151745693467, -0.4589282076830224, -0.46302396240906585, -0.4671022845446271, -0.47116302054318215, -0.47520601752031655, -0.4792311232594785, -0.48323818621771253, -0.48722705553136253, -0.491197581021754, -0.49514961320084583, -0.4990830032768601, -0.5029976031598842, -0.5068932654674444, -0.5107698435300573, -0.5146271913967492, -0.5184651638405539, -0.5222836163639774, -0.5260824052044408, -0.5298613873396911, -0.5336204204931871, -0.5373593631394551, -0.5410780745094174, -0.5447764145956933, -0.548454244157868, -0.5521114247277382, -0.5557478186145218, -0.5593632889100447, -0.5629576994938934, -0.5665309150385414, -0.5700828010144423, -0.5736132236950975, -0.5771220501620881, -0.5806091483100805, -0.5840743868518009, -0.5875176353229763, -0.5909387640872483, -0.5943376443410521, -0.5977141481184677, -0.6010681482960358, -0.6043995185975459, -0.6077081335987886, -0.6109938687322796, -0.6142566002919476, -0.6174962054377944, -0.6207125622005163, -0.6239055494860993, -0.6270750470803772, -0.6302209356535572, -0.6333430967647116, -0.6364414128662399, -0.6395157673082918, -0.6425660443431609, -0.6455921291296397, -0.648593907737347, -0.6515712671510152, -0.6545240952747442, -0.6574522809362248, -0.6603557138909215, -0.6632342848262253, -0.6660878853655664, -0.668916408072498, -0.6717197464547386, -0.6744977949681833, -0.6772504490208747, -0.6799776049769439, -0.682679160160511, -0.6853550128595495, -0.6880050623297181, -0.690629208798152, -0.6932273534672203, -0.6957993985182437, -0.6983452471151804, -0.7008648034082696, -0.7033579725376411, -0.7058246606368859, -0.7082647748365913, -0.7106782232678374, -0.713064915065654, -0.7154247603724444, -0.7177576703413664, -0.7200635571396786, -0.7223423339520458, -0.7245939149838093, -0.7268182154642158, -0.7290151516496096, -0.7311846408265845, -0.7333266013150996, -0.7354409524715534, -0.7375276146918192, -0.7395865094142441, -0.7416175591226056, -0.7436206873490303, -0.7455958186768722, -0.7475428787435533, -0.7494617942433627, -0.7513524929302167, -0.7532149036203776, -0.7550489561951361, -0.7568545816034494, -0.7586317118645411, -0.7603802800704615, -0.7621002203886058, -0.7637914680641936, -0.7654539594227052, -0.7670876318722809, -0.7686924239060765, -0.7702682751045785, -0.7718151261378802, -0.7733329187679145, -0.7748215958506469, -0.7762811013382263, -0.7777113802810961, -0.7791123788300627, -0.7804840442383225, -0.7818263248634479, -0.7831391701693323, -0.7844225307280921, -0.785676358221927, -0.7869006054449412, -0.7880952263049182, -0.7892601758250584, -0.7903954101456702, -0.7915008865258232, -0.7925765633449567, -0.7936224001044465, -0.7946383574291294, -0.7956243970687864, -0.7965804818995829, -0.7975065759254646, -0.7984026442795153, -0.7992686532252679, -0.8001045701579752, -0.8009103636058368, -0.8016860032311851, -0.8024314598316264, -0.8031467053411414, -0.8038317128311404, -0.8044864565114789, -0.8051109117314275, -0.8057050549805996, -0.8062688638898382, -0.8068023172320563, -0.8073053949230373, -0.8077780780221901, -0.8082203487332634, -0.808632190405015, -0.8090135875318385, -0.8093645257543478, -0.8096849918599172, -0.8099749737831788, -0.8102344606064772, -0.81046344256028, -0.8106619110235463, -0.8108298585240507, -0.8109672787386644, -0.8110741664935941, -0.8111505177645763, -0.8111963296770288, -0.811211600506159, -0.8111963296770288, -0.8111505177645763, -0.8110741664935941, -0.8109672787386644, -0.8108298585240507, -0.8106619110235465, -0.81046344256028, -0.8102344606064772, -0.8099749737831788, -0.8096849918599172, -0.8093645257543478, -0.8090135875318385, -0.808632190405015, -0.8082203487332634, -0.8077780780221901, -0.8073053949230373, -0.8068023172320563, -0.8062688638898382, -0.8057050549805997, -0.8051109117314275, -0.8044864565114789, -0.8038317128311404, -0.8031467053411414, -0.8024314598316264, -0.8016860032311851, -0.8009103636058369, -0.8001045701579753, -0.799268653225268, -0.7984026442795155, -0.7975065759254647, -0.7965804818995829, -0.7956243970687865, -0.7946383574291295, -0.7936224001044465, -0.7925765633449567, -0.7915008865258233, -0.7903954101456702, -0.7892601758250585, -0.7880952263049184, -0.7869006054449412, -0.7856763582219272, -0.7844225307280922, -0.7831391701693324, -0.781826324863448, -0.7804840442383225, -0.7791123788300628, -0.7777113802810962, -0.7762811013382264, -0.774821595850647, -0.7733329187679147, -0.7718151261378803, -0.7702682751045785, -0.7686924239060765, -0.7670876318722809, -0.7654539594227053, -0.7637914680641937, -0.7621002203886059, -0.7603802800704615, -0.7586317118645413, -0.7568545816034495, -0.7550489561951362, -0.7532149036203777, -0.7513524929302168, -0.7494617942433628, -0.7475428787435534, -0.7455958186768723, -0.7436206873490304, -0.7416175591226056, -0.7395865094142442, -0.7375276146918193, -0.7354409524715535, -0.7333266013150997, -0.7311846408265847, -0.7290151516496097, -0.7268182154642159, -0.7245939149838094, -0.722342333952046, -0.7200635571396787, -0.7177576703413665, -0.7154247603724445, -0.7130649150656542, -0.7106782232678376, -0.7082647748365914, -0.705824660636886, -0.7033579725376413, -0.7008648034082697, -0.6983452471151806, -0.6957993985182439, -0.6932273534672203, -0.6906292087981521, -0.6880050623297183, -0.6853550128595497, -0.6826791601605112, -0.679977604976944, -0.6772504490208747, -0.6744977949681834, -0.6717197464547389, -0.6689164080724981, -0.6660878853655666, -0.6632342848262255, -0.6603557138909216, -0.6574522809362249, -0.6545240952747444, -0.6515712671510153, -0.6485939077373472, -0.6455921291296399, -0.642566044343161, -0.639515767308292, -0.63644141286624, -0.6333430967647118, -0.6302209356535573, -0.6270750470803774, -0.6239055494860996, -0.6207125622005164, -0.6174962054377946, -0.6142566002919478, -0.6109938687322798, -0.6077081335987891, -0.6043995185975459, -0.601068148296036, -0.5977141481184679, -0.5943376443410525, -0.5909387640872482, -0.5875176353229764, -0.5840743868518011, -0.5806091483100809, -0.5771220501620881, -0.5736132236950977, -0.5700828010144428, -0.5665309150385412, -0.5629576994938935, -0.5593632889100449, -0.5557478186145224, -0.5521114247277382, -0.5484542441578683, -0.5447764145956934, -0.5410780745094179, -0.5373593631394551, -0.5336204204931874, -0.5298613873396916, -0.5260824052044408, -0.5222836163639776, -0.5184651638405541, -0.5146271913967497, -0.5107698435300573, -0.5068932654674446, -0.5029976031598845, -0.49908300327686067, -0.49514961320084583, -0.49119758102175415, -0.4872270555313631, -0.48323818621771253, -0.4792311232594787, -0.47520601752031677, -0.47116302054318265, -0.467102284544627, -0.463023962409066, -0.45892820768302267, -0.4548151745693472, -0.4506850179214112, -0.4465378932372793, -0.4423739566538512, -0.4381933649409853, -0.4339962754955979, -0.4297828463357337, -0.42555323609461926, -0.42130760401468886, -0.41704610994159197, -0.4127689143181712, -0.40847617817842424, -0.40416806314143994, -0.39984473140531573, -0.3955063457410472, -0.39115306948640205, -0.3867850665397719, -0.3824025013539984, -0.3780055389301837, -0.3735943448114772, -0.3691690850768456, -0.364729926334816, -0.3602770357172061, -0.35581058087283024, -0.3513307299611901, -0.34683765164613983, -0.3423315150895379, -0.33781248994488006, -0.33328074635090815, -0.32873645492520726, -0.32417978675778025, -0.3196109134046097, -0.31503000688119426, -0.31043723965607545, -0.3058327846443427, -0.30121681520112625, -0.2965895051150658, -0.2919510286017702, -0.28730156029725984, -0.2826412752513877, -0.27797034892125194, -0.27328895716458806, -0.26859727623315116, -0.2638954827660759, -0.25918375378322805, -0.25446226667854227, -0.24973119921333867, -0.24499072950963335, -0.24024103604343064, -0.23548229763800635, -0.2307146934571711, -0.22593840299852766, -0.22115360608671158, -0.21636048286662352, -0.21155921379664297, -0.20674997964183606, -0.20193296146715184, -0.1971083406306014, -0.19227629877643226, -0.1874370178282885, -0.18259067998236408, -0.17773746770053897, -0.17287756370351254, -0.1680111509639228, -0.16313841269946022, -0.15825953236596574, -0.15337469365052558, -0.14848408046455797, -0.14358787693688438, -0.13868626740680032, -0.13377943641713347, -0.12886756870729868, -0.12395084920633828, -0.11902946302596261, -0.11410359545357898, -0.10917343194531887, -0.10423915811905109, -0.09930095974739563, -0.0943590227507315, -0.08941353319019275, -0.08446467726066614, -0.07951264128377956, -0.0745576117008901, -0.06959977506606023, -0.06463931803903708, -0.059676427378223325, -0.05471128993364876, -0.04974409263993114, -0.04477502250924037, -0.0398042666242598, -0.03483201213113828, -0.02985844623244717, -0.02488375618013074, -0.019908129268459206, -0.014931752826972839, -0.009954814213431992, -0.004977500806761776, ], [0.0, 0.004977500806761774, 0.009954814213431468, 0.014931752826972509, 0.019908129268459078, 0.02488375618013081, 0.02985844623244671, 0.034832012131138027, 0.03980426662425974, 0.0447750225092405, 0.04974409263993075, 0.054711289933648564, 0.05967642737822334, 0.06463931803903657, 0.06959977506605992, 0.07455761170088998, 0.07951264128377965, 0.0844646772606657, 0.0894135331901925, 0.09435902275073144, 0.09930095974739575, 0.1042391581190507, 0.10917343194531867, 0.11410359545357898, 0.11902946302596208, 0.12395084920633799, 0.12886756870729857, 0.13377943641713355, 0.13868626740679987, 0.14358787693688413, 0.1484840804645579, 0.15337469365052572, 0.15825953236596535, 0.16313841269946006, 0.16801115096392283, 0.17287756370351204, 0.17773746770053866, 0.18259067998236397, 0.18743701782828862, 0.19227629877643185, 0.19710834063060115, 0.20193296146715178, 0.2067499796418362, 0.2115592137966426, 0.21636048286662332, 0.22115360608671158, 0.22593840299852716, 0.23071469345717083, 0.2354822976380062, 0.2402410360434307, 0.24499072950963297, 0.24973119921333845, 0.25446226667854227, 0.2591837537832282, 0.2638954827660755, 0.268597276233151, 0.27328895716458806, 0.2779703489212515, 0.2826412752513875, 0.28730156029725973, 0.2919510286017703, 0.2965895051150654, 0.301216815201126, 0.3058327846443427, 0.31043723965607495, 0.3150300068811939, 0.31961091340460956, 0.32417978675778025, 0.32873645492520676, 0.3332807463509079, 0.33781248994487995, 0.342331515089538, 0.3468376516461395, 0.3513307299611899, 0.35581058087283024, 0.3602770357172056, 0.36472992633481566, 0.3691690850768454, 0.37359434481147724, 0.3780055389301833, 0.38240250135399817, 0.38678506653977185, 0.3911530694864021, 0.3955063457410469, 0.39984473140531557, 0.40416806314143994, 0.4084761781784238, 0.41276891431817087, 0.4170461099415918, 0.421307604014689, 0.42555323609461887, 0.42978284633573344, 0.43399627549559777, 0.43819336494098543, 0.44237395665385093, 0.44653789323727916, 0.4506850179214112, 0.4548151745693468, 0.45892820768302234, 0.46302396240906596, 0.4671022845446271, 0.4711630205431823, 0.47520601752031655, 0.47923112325947864, 0.48323818621771264, 0.4872270555313628, 0.49119758102175404, 0.49514961320084583, 0.4990830032768602, 0.5029976031598841, 0.5068932654674445, 0.5107698435300573, 0.5146271913967494, 0.5184651638405539, 0.5222836163639775, 0.5260824052044408, 0.5298613873396912, 0.5336204204931873, 0.5373593631394551, 0.5410780745094175, 0.5447764145956931, 0.5484542441578681, 0.5521114247277382, 0.5557478186145219, 0.5593632889100447, 0.5629576994938935, 0.5665309150385414, 0.5700828010144425, 0.5736132236950975, 0.5771220501620881, 0.5806091483100806, 0.5840743868518009, 0.5875176353229764, 0.5909387640872483, 0.5943376443410523, 0.5977141481184677, 0.601068148296036, 0.604399518597546, 0.6077081335987887, 0.6109938687322796, 0.6142566002919477, 0.6174962054377943, 0.6207125622005163, 0.6239055494860994, 0.6270750470803774, 0.6302209356535571, 0.6333430967647116, 0.63644141286624, 0.6395157673082921, 0.6425660443431609, 0.6455921291296397, 0.6485939077373472, 0.651571267151015, 0.6545240952747442, 0.6574522809362249, 0.6603557138909216, 0.6632342848262252, 0.6660878853655664, 0.6689164080724981, 0.671719746454739, 0.6744977949681833, 0.6772504490208747, 0.679977604976944, 0.6826791601605109, 0.6853550128595495, 0.6880050623297181, 0.6906292087981523, 0.6932273534672202, 0.6957993985182438, 0.6983452471151806, 0.7008648034082698, 0.7033579725376411, 0.7058246606368859, 0.7082647748365914, 0.7106782232678373, 0.713064915065654, 0.7154247603724444, 0.7177576703413666, 0.7200635571396786, 0.7223423339520458, 0.7245939149838094, 0.7268182154642159, 0.7290151516496096, 0.7311846408265846, 0.7333266013150997, 0.7354409524715533, 0.737527614691819, 0.7395865094142442, 0.7416175591226057, 0.7436206873490303, 0.7455958186768722, 0.7475428787435534, 0.7494617942433629, 0.7513524929302166, 0.7532149036203776, 0.7550489561951362, 0.7568545816034493, 0.758631711864541, 0.7603802800704615, 0.762100220388606, 0.7637914680641936, 0.7654539594227053, 0.7670876318722809, 0.7686924239060764, 0.7702682751045785, 0.7718151261378802, 0.7733329187679145, 0.7748215958506468, 0.7762811013382264, 0.7777113802810962, 0.7791123788300628, 0.7804840442383225, 0.7818263248634479, 0.7831391701693324, 0.784422530728092, 0.785676358221927, 0.7869006054449412, 0.7880952263049184, 0.7892601758250583, 0.7903954101456702, 0.7915008865258233, 0.7925765633449569, 0.7936224001044465, 0.7946383574291294, 0.7956243970687865, 0.7965804818995827, 0.7975065759254646, 0.7984026442795153, 0.799268653225268, 0.8001045701579752, 0.8009103636058369, 0.8016860032311851, 0.8024314598316266, 0.8031467053411414, 0.8038317128311404, 0.8044864565114789, 0.8051109117314273, 0.8057050549805996, 0.8062688638898382, 0.8068023172320563, 0.8073053949230372, 0.8077780780221901, 0.8082203487332634, 0.808632190405015, 0.8090135875318385, 0.8093645257543478, 0.8096849918599172, 0.8099749737831788, 0.8102344606064772, 0.81046344256028, 0.8106619110235465, 0.8108298585240507, 0.8109672787386644, 0.8110741664935941, 0.8111505177645763, 0.8111963296770288, 0.811211600506159, 0.8111963296770288, 0.8111505177645763, 0.8110741664935941, 0.8109672787386644, 0.8108298585240507, 0.8106619110235465, 0.81046344256028, 0.8102344606064772, 0.8099749737831788, 0.8096849918599172, 0.8093645257543478, 0.8090135875318385, 0.808632190405015, 0.8082203487332634, 0.8077780780221901, 0.8073053949230373, 0.8068023172320563, 0.8062688638898382, 0.8057050549805996, 0.8051109117314273, 0.8044864565114789, 0.8038317128311404, 0.8031467053411414, 0.8024314598316266, 0.8016860032311851, 0.8009103636058369, 0.8001045701579752, 0.799268653225268, 0.7984026442795153, 0.7975065759254646, 0.7965804818995827, 0.7956243970687865, 0.7946383574291294, 0.7936224001044465, 0.7925765633449569, 0.7915008865258233, 0.7903954101456702, 0.7892601758250584, 0.7880952263049184, 0.7869006054449412, 0.7856763582219272, 0.784422530728092, 0.7831391701693324, 0.7818263248634479, 0.7804840442383225, 0.7791123788300628, 0.7777113802810962, 0.7762811013382264, 0.7748215958506468, 0.7733329187679147, 0.7718151261378802, 0.7702682751045785, 0.7686924239060765, 0.767087631872281, 0.7654539594227053, 0.7637914680641936, 0.762100220388606, 0.7603802800704615, 0.7586317118645411, 0.7568545816034493, 0.7550489561951362, 0.7532149036203777, 0.7513524929302167, 0.7494617942433629, 0.7475428787435535, 0.7455958186768722, 0.7436206873490303, 0.7416175591226057, 0.7395865094142442, 0.7375276146918193, 0.7354409524715533, 0.7333266013150999, 0.7311846408265846, 0.7290151516496096, 0.726818215464216, 0.7245939149838093, 0.722342333952046, 0.7200635571396786, 0.7177576703413666, 0.7154247603724445, 0.7130649150656541, 0.7106782232678373, 0.7082647748365916, 0.705824660636886, 0.7033579725376412, 0.7008648034082698, 0.6983452471151805, 0.6957993985182438, 0.6932273534672202, 0.6906292087981523, 0.6880050623297181, 0.6853550128595497, 0.6826791601605109, 0.6799776049769441, 0.6772504490208747, 0.6744977949681834, 0.671719746454739, 0.6689164080724981, 0.6660878853655665, 0.6632342848262252, 0.6603557138909218, 0.6574522809362249, 0.6545240952747444, 0.6515712671510151, 0.6485939077373474, 0.6455921291296398, 0.6425660443431608, 0.6395157673082922, 0.6364414128662399, 0.6333430967647118, 0.6302209356535571, 0.6270750470803775, 0.6239055494860994, 0.6207125622005163, 0.6174962054377943, 0.614256600291948, 0.6109938687322797, 0.6077081335987887, 0.6043995185975461, 0.6010681482960359, 0.5977141481184679, 0.5943376443410523, 0.5909387640872484, 0.5875176353229764, 0.584074386851801, 0.5806091483100806, 0.5771220501620882, 0.5736132236950976, 0.5700828010144424, 0.5665309150385414, 0.5629576994938935, 0.5593632889100449, 0.5557478186145219, 0.5521114247277384, 0.5484542441578681, 0.5447764145956933, 0.5410780745094176, 0.5373593631394553, 0.5336204204931874, 0.5298613873396912, 0.5260824052044409, 0.5222836163639775, 0.518465163840554, 0.5146271913967494, 0.5107698435300574, 0.5068932654674445, 0.5029976031598844, 0.49908300327686034, 0.495149613200846, 0.49119758102175415, 0.4872270555313627, 0.4832381862177127, 0.47923112325947864, 0.47520601752031666, 0.4711630205431823, 0.4671022845446272, 0.46302396240906596, 0.45892820768302256, 0.4548151745693469, 0.4506850179214112, 0.44653789323727927, 0.4423739566538509, 0.4381933649409855, 0.43399627549559777, 0.4297828463357336, 0.42555323609461887, 0.42130760401468903, 0.41704610994159186, 0.41276891431817114, 0.4084761781784239, 0.4041680631414399, 0.3998447314053156, 0.3955063457410468, 0.39115306948640227, 0.38678506653977185, 0.38240250135399834, 0.3780055389301833, 0.3735943448114774, 0.3691690850768455, 0.36472992633481593, 0.3602770357172057, 0.3558105808728302, 0.35133072996118997, 0.34683765164613944, 0.34233151508953813, 0.33781248994487995, 0.3332807463509081, 0.3287364549252068, 0.3241797867577805, 0.3196109134046096, 0.31503000688119415, 0.310437239656075, 0.3058327846443426, 0.30121681520112614, 0.2965895051150654, 0.29195102860177047, 0.28730156029725973, 0.28264127525138766, 0.2779703489212515, 0.27328895716458834, 0.26859727623315105, 0.2638954827660758, 0.25918375378322833, 0.25446226667854216, 0.24973119921333858, 0.2449907295096329, 0.24024103604343086, 0.23548229763800624, 0.23071469345717102, 0.22593840299852724, 0.2211536060867118, 0.21636048286662343, 0.2115592137966429, 0.20674997964183628, 0.20193296146715173, 0.1971083406306013, 0.19227629877643182, 0.18743701782828878, 0.18259067998236397, 0.17773746770053886, 0.1728775637035121, 0.16801115096392305, 0.16313841269946014, 0.15825953236596563, 0.15337469365052586, 0.14848408046455785, 0.1435878769368843, 0.13868626740679987, 0.13377943641713372, 0.12886756870729857, 0.12395084920633818, 0.11902946302596212, 0.11410359545357925, 0.10917343194531876, 0.10423915811905064, 0.09930095974739588, 0.09435902275073141, 0.08941353319019266, 0.08446467726066569, 0.07951264128377983, 0.07455761170089001, 0.06959977506606013, 0.06463931803903662, 0.05967642737822359, 0.05471128993364867, 0.049744092639930675, 0.04477502250924063, 0.0398042666242597, 0.034832012131138186, 0.02985844623244671, 0.024883756180131004, 0.01990812926845911, 0.014931752826972738, 0.009954814213431534, 0.004977500806762037, 9.934476899910691e-17, -0.004977500806761838, -0.009954814213431334, -0.01493175282697254, -0.019908129268458908, -0.024883756180130803, -0.02985844623244651, -0.03483201213113799, -0.0398042666242595, -0.04477502250924043, -0.04974409263993048, -0.05471128993364846, -0.059676427378223394, -0.06463931803903643, -0.06959977506605994, -0.07455761170088981, -0.07951264128377963, -0.08446467726066549, -0.08941353319019246, -0.09435902275073121, -0.09930095974739568, -0.10423915811905043, -0.10917343194531857, -0.11410359545357905, -0.11902946302596194, -0.12395084920633799, -0.12886756870729837, -0.13377943641713352, -0.13868626740679968, -0.14358787693688407, -0.14848408046455766, -0.15337469365052567, -0.15825953236596543, -0.16313841269945992, -0.16801115096392283, -0.17287756370351193, -0.17773746770053866, -0.18259067998236378, -0.1874370178282886, -0.19227629877643163, -0.1971083406306011, -0.20193296146715156, -0.20674997964183608, -0.2115592137966427, -0.21636048286662324, -0.22115360608671164, -0.22593840299852702, -0.2307146934571708, -0.23548229763800602, -0.2402410360434307, -0.24499072950963274, -0.2497311992133384, -0.254462266678542, -0.25918375378322817, -0.26389548276607555, -0.2685972762331509, -0.2732889571645881, -0.27797034892125133, -0.2826412752513875, -0.2873015602972595, -0.2919510286017703, -0.29658950511506516, -0.3012168152011259, -0.30583278464434244, -0.31043723965607484, -0.315030006881194, -0.3196109134046094, -0.3241797867577803, -0.32873645492520664, -0.3332807463509079, -0.3378124899448798, -0.34233151508953796, -0.3468376516461393, -0.3513307299611898, -0.35581058087282996, -0.36027703571720554, -0.3647299263348157, -0.3691690850768453, -0.3735943448114773, -0.3780055389301831, -0.38240250135399817, -0.3867850665397717, -0.3911530694864021, -0.39550634574104665, -0.39984473140531546, -0.4041680631414397, -0.40847617817842374, -0.4127689143181709, -0.4170461099415917, -0.421307604014689, -0.4255532360946187, -0.42978284633573344, -0.4339962754955976, -0.43819336494098543, -0.44237395665385065, -0.44653789323727905, -0.450685017921411, -0.4548151745693467, -0.4589282076830224, -0.46302396240906585, -0.4671022845446271, -0.47116302054318215, -0.47520601752031655, -0.4792311232594785, -0.48323818621771253, -0.48722705553136253, -0.491197581021754, -0.49514961320084583, -0.4990830032768601, -0.5029976031598842, -0.5068932654674444, -0.5107698435300573, -0.5146271913967492, -0.5184651638405539, -0.5222836163639774, -0.5260824052044408, -0.5298613873396911, -0.5336204204931871, -0.5373593631394551, -0.5410780745094174, -0.5447764145956933, -0.548454244157868, -0.5521114247277382, -0.5557478186145218, -0.5593632889100447, -0.5629576994938934, -0.5665309150385414, -0.5700828010144423, -0.5736132236950975, -0.5771220501620881, -0.5806091483100805, -0.5840743868518009, -0.5875176353229763, -0.5909387640872483, -0.5943376443410521, -0.5977141481184677, -0.6010681482960358, -0.6043995185975459, -0.6077081335987886, -0.6109938687322796, -0.6142566002919476, -0.6174962054377944, -0.6207125622005163, -0.6239055494860993, -0.6270750470803772, -0.6302209356535572, -0.6333430967647116, -0.6364414128662399, -0.6395157673082918, -0.6425660443431609, -0.6455921291296397, -0.648593907737347, -0.6515712671510152, -0.6545240952747442, -0.6574522809362248, -0.6603557138909215, -0.6632342848262253, -0.6660878853655664, -0.668916408072498, -0.6717197464547386, -0.6744977949681833, -0.6772504490208747, -0.6799776049769439, -0.682679160160511, -0.6853550128595495, -0.6880050623297181, -0.690629208798152, -0.6932273534672203, -0.6957993985182437, -0.6983452471151804, -0.7008648034082696, -0.7033579725376411, -0.7058246606368859, -0.7082647748365913, -0.7106782232678374, -0.713064915065654, -0.7154247603724444, -0.7177576703413664, -0.7200635571396786, -0.7223423339520458, -0.7245939149838093, -0.7268182154642158, -0.7290151516496096, -0.7311846408265845, -0.7333266013150996, -0.7354409524715534, -0.7375276146918192, -0.7395865094142441, -0.7416175591226056, -0.7436206873490303, -0.7455958186768722, -0.7475428787435533, -0.7494617942433627, -0.7513524929302167, -0.7532149036203776, -0.7550489561951361, -0.7568545816034494, -0.7586317118645411, -0.7603802800704615, -0.7621002203886058, -0.7637914680641936, -0.7654539594227052, -0.7670876318722809, -0.7686924239060765, -0.7702682751045785, -0.7718151261378802, -0.7733329187679145, -0.7748215958506469, -0.7762811013382263, -0.7777113802810961, -0.7791123788300627, -0.7804840442383225, -0.7818263248634479, -0.7831391701693323, -0.7844225307280921, -0.785676358221927, -0.7869006054449412, -0.7880952263049182, -0.7892601758250584, -0.7903954101456702, -0.7915008865258232, -0.7925765633449567, -0.7936224001044465, -0.7946383574291294, -0.7956243970687864, -0.7965804818995829, -0.7975065759254646, -0.7984026442795153, -0.7992686532252679, -0.8001045701579752, -0.8009103636058368, -0.8016860032311851, -0.8024314598316264, -0.8031467053411414, -0.8038317128311404, -0.8044864565114789, -0.8051109117314275, -0.8057050549805996, -0.8062688638898382, -0.8068023172320563, -0.8073053949230373, -0.8077780780221901, -0.8082203487332634, -0.808632190405015, -0.8090135875318385, -0.8093645257543478, -0.8096849918599172, -0.8099749737831788, -0.8102344606064772, -0.81046344256028, -0.8106619110235463, -0.8108298585240507, -0.8109672787386644, -0.8110741664935941, -0.8111505177645763, -0.8111963296770288, -0.811211600506159, -0.8111963296770288, -0.8111505177645763, -0.8110741664935941, -0.8109672787386644, -0.8108298585240507, -0.8106619110235465, -0.81046344256028, -0.8102344606064772, -0.8099749737831788, -0.8096849918599172, -0.8093645257543478, -0.8090135875318385, -0.808632190405015, -0.8082203487332634, -0.8077780780221901, -0.8073053949230373, -0.8068023172320563, -0.8062688638898382, -0.8057050549805997, -0.8051109117314275, -0.8044864565114789, -0.8038317128311404, -0.8031467053411414, -0.8024314598316264, -0.8016860032311851, -0.8009103636058369, -0.8001045701579753, -0.799268653225268, -0.7984026442795155, -0.7975065759254647, -0.7965804818995829, -0.7956243970687865, -0.7946383574291295, -0.7936224001044465, -0.7925765633449567, -0.7915008865258233, -0.7903954101456702, -0.7892601758250585, -0.7880952263049184, -0.7869006054449412, -0.7856763582219272, -0.7844225307280922, -0.7831391701693324, -0.781826324863448, -0.7804840442383225, -0.7791123788300628, -0.7777113802810962, -0.7762811013382264, -0.774821595850647, -0.7733329187679147, -0.7718151261378803, -0.7702682751045785, -0.7686924239060765, -0.7670876318722809, -0.7654539594227053, -0.7637914680641937, -0.7621002203886059, -0.7603802800704615, -0.7586317118645413, -0.7568545816034495, -0.7550489561951362, -0.7532149036203777, -0.7513524929302168, -0.7494617942433628, -0.7475428787435534, -0.7455958186768723, -0.7436206873490304, -0.7416175591226056, -0.7395865094142442, -0.7375276146918193, -0.7354409524715535, -0.7333266013150997, -0.7311846408265847, -0.7290151516496097, -0.7268182154642159, -0.7245939149838094, -0.722342333952046, -0.7200635571396787, -0.7177576703413665, -0.7154247603724445, -0.7130649150656542, -0.7106782232678376, -0.7082647748365914, -0.705824660636886, -0.7033579725376413, -0.7008648034082697, -0.6983452471151806, -0.6957993985182439, -0.6932273534672203, -0.6906292087981521, -0.6880050623297183, -0.6853550128595497, -0.6826791601605112, -0.679977604976944, -0.6772504490208747, -0.6744977949681834, -0.6717197464547389, -0.6689164080724981, -0.6660878853655666, -0.6632342848262255, -0.6603557138909216, -0.6574522809362249, -0.6545240952747444, -0.6515712671510153, -0.6485939077373472, -0.6455921291296399, -0.642566044343161, -0.639515767308292, -0.63644141286624, -0.6333430967647118, -0.6302209356535573, -0.6270750470803774, -0.6239055494860996, -0.6207125622005164, -0.6174962054377946, -0.6142566002919478, -0.6109938687322798, -0.6077081335987891, -0.6043995185975459, -0.601068148296036, -0.5977141481184679, -0.5943376443410525, -0.5909387640872482, -0.5875176353229764, -0.5840743868518011, -0.5806091483100809, -0.5771220501620881, -0.5736132236950977, -0.5700828010144428, -0.5665309150385412, -0.5629576994938935, -0.5593632889100449, -0.5557478186145224, -0.5521114247277382, -0.5484542441578683, -0.5447764145956934, -0.5410780745094179, -0.5373593631394551, -0.5336204204931874, -0.5298613873396916, -0.5260824052044408, -0.5222836163639776, -0.5184651638405541, -0.5146271913967497, -0.5107698435300573, -0.5068932654674446, -0.5029976031598845, -0.49908300327686067, -0.49514961320084583, -0.49119758102175415, -0.4872270555313631, -0.48323818621771253, -0.4792311232594787, -0.47520601752031677, -0.47116302054318265, -0.467102284544627, -0.463023962409066, -0.45892820768302267, -0.4548151745693472, -0.4506850179214112, -0.4465378932372793, -0.4423739566538512, -0.4381933649409853, -0.4339962754955979, -0.4297828463357337, -0.42555323609461926, -0.42130760401468886, -0.41704610994159197, -0.4127689143181712, -0.40847617817842424, -0.40416806314143994, -0.39984473140531573, -0.3955063457410472, -0.39115306948640205, -0.3867850665397719, -0.3824025013539984, -0.3780055389301837, -0.3735943448114772, -0.3691690850768456, -0.364729926334816, -0.3602770357172061, -0.35581058087283024, -0.3513307299611901, -0.34683765164613983, -0.3423315150895379, -0.33781248994488006, -0.33328074635090815, -0.32873645492520726, -0.32417978675778025, -0.3196109134046097, -0.31503000688119426, -0.31043723965607545, -0.3058327846443427, -0.30121681520112625, -0.2965895051150658, -0.2919510286017702, -0.28730156029725984, -0.2826412752513877, -0.27797034892125194, -0.27328895716458806, -0.26859727623315116, -0.2638954827660759, -0.25918375378322805, -0.25446226667854227, -0.24973119921333867, -0.24499072950963335, -0.24024103604343064, -0.23548229763800635, -0.2307146934571711, -0.22593840299852766, -0.22115360608671158, -0.21636048286662352, -0.21155921379664297, -0.20674997964183606, -0.20193296146715184, -0.1971083406306014, -0.19227629877643226, -0.1874370178282885, -0.18259067998236408, -0.17773746770053897, -0.17287756370351254, -0.1680111509639228, -0.16313841269946022, -0.15825953236596574, -0.15337469365052558, -0.14848408046455797, -0.14358787693688438, -0.13868626740680032, -0.13377943641713347, -0.12886756870729868, -0.12395084920633828, -0.11902946302596261, -0.11410359545357898, -0.10917343194531887, -0.10423915811905109, -0.09930095974739563, -0.0943590227507315, -0.08941353319019275, -0.08446467726066614, -0.07951264128377956, -0.0745576117008901, -0.06959977506606023, -0.06463931803903708, -0.059676427378223325, -0.05471128993364876, -0.04974409263993114, -0.04477502250924037, -0.0398042666242598, -0.03483201213113828, -0.02985844623244717, -0.02488375618013074, -0.019908129268459206, -0.014931752826972839, -0.009954814213431992, -0.004977500806761776, ], ];
@@ -0,0 +1 @@
+pub static TRIANGLE_I16: [[i16; 1024]; 10] = [[0, 128, 256, 384, 512, 640, 769, 897, 1025, 1153, 1281, 1409, 1537, 1665, 1793, 1921, 2050, 2178, 2306, 2434, 2562, 2690, 2818, 2946, 3074, 3202, 3331, 3459, 3587, 3715, 3843, 3971, 4099, 4227, 4355, 4483, 4612, 4740, 4868, 4996, 5124, 5252, 5380, 5508, 5636, 5764, 5892, 6021, 6149, 6277, 6405, 6533, 6661, 6789, 6917, 7045, 7173, 7302, 7430, 7558, 7686, 7814, 7942, 8070, 8198, 8326, 8454, 8583, 8711, 8839, 8967, 9095, 9223, 9351, 9479, 9607, 9735, 9864, 9992, 10120, 10248, 10376, 10504, 10632, 10760, 10888, 11016, 11144, 11273, 11401, 11529, 11657, 11785, 11913, 12041, 12169, 12297, 12425, 12554, 12682, 12810, 12938, 13066, 13194, 13322, 13450, 13578, 13706, 13835, 13963, 14091, 14219, 14347, 14475, 14603, 14731, 14859, 14987, 15116, 15244, 15372, 15500, 15628, 15756, 15884, 16012, 16140, 16268, 16396, 16525, 16653, 16781, 16909, 17037, 17165, 17293, 17421, 17549, 17677, 17806, 17934, 18062, 18190, 18318, 18446, 18574, 18702, 18830, 18958, 19087, 19215, 19343, 19471, 19599, 19727, 19855, 19983, 20111, 20239, 20368, 20496, 20624, 20752, 20880, 21008, 21136, 21264, 21392, 21520, 21648, 21777, 21905, 22033, 22161, 22289, 22417, 22545, 22673, 22801, 22929, 23058, 23186, 23314, 23442, 23570, 23698, 23826, 23954, 24082, 24210, 24339, 24467, 24595, 24723, 24851, 24979, 25107, 25235, 25363, 25491, 25619, 25748, 25876, 26004, 26132, 26260, 26388, 26516, 26644, 26772, 26900, 27029, 27157, 27285, 27413, 27541, 27669, 27797, 27925, 28053, 28181, 28310, 28438, 28566, 28694, 28822, 28950, 29078, 29206, 29334, 29462, 29591, 29719, 29847, 29975, 30103, 30231, 30359, 30487, 30615, 30743, 30872, 31000, 31128, 31256, 31384, 31512, 31640, 31768, 31896, 32024, 32153, 32280, 32409, 32536, 32668, 32767, 32668, 32536, 32409, 32280, 32153, 32024, 31896, 31768, 31640, 31512, 31384, 31256, 31128, 31000, 30872, 30743, 30615, 30487, 30359, 30231, 30103, 29975, 29847, 29719, 29591, 29462, 29334, 29206, 29078, 28950, 28822, 28694, 28566, 28438, 28310, 28181, 28053, 27925, 27797, 27669, 27541, 27413, 27285, 27157, 27029, 26900, 26772, 26644, 26516, 26388, 26260, 26132, 26004, 25876, 25748, 25619, 25491, 25363, 25235, 25107, 24979, 24851, 24723, 24595, 24467, 24339, 24210, 24082, 23954, 23826, 23698, 23570, 23442, 23314, 23186, 23058, 22929, 22801, 22673, 22545, 22417, 22289, 22161, 22033, 21905, 21777, 21648, 21520, 21392, 21264, 21136, 21008, 20880, 20752, 20624, 20496, 20368, 20239, 20111, 19983, 19855, 19727, 19599, 19471, 19343, 19215, 19087, 18958, 18830, 18702, 18574, 18446, 18318, 18190, 18062, 17934, 17806, 17677, 17549, 17421, 17293, 17165, 17037, 16909, 16781, 16653, 16525, 16396, 16268, 16140, 16012, 15884, 15756, 15628, 15500, 15372, 15244, 15116, 14987, 14859, 14731, 14603, 14475, 14347, 14219, 14091, 13963, 13835, 13706, 13578, 13450, 13322, 13194, 13066, 12938, 12810, 12682, 12554, 12425, 12297, 12169, 12041, 11913, 11785, 11657, 11529, 11401, 11273, 11144, 11016, 10888, 10760, 10632, 10504, 10376, 10248, 10120, 9992, 9864, 9735, 9607, 9479, 9351, 9223, 9095, 8967, 8839, 8711, 8583, 8454, 8326, 8198, 8070, 7942, 7814, 7686, 7558, 7430, 7302, 7173, 7045, 6917, 6789, 6661, 6533, 6405, 6277, 6149, 6021, 5892, 5764, 5636, 5508, 5380, 5252, 5124, 4996, 4868, 4740, 4612, 4483, 4355, 4227, 4099, 3971, 3843, 3715, 3587, 3459, 3331, 3202, 3074, 2946, 2818, 2690, 2562, 2434, 2306, 2178, 2050, 1921, 1793, 1665, 1537, 1409, 1281, 1153, 1025, 897, 769, 640, 512, 384, 256, 128, 0, -128, -256, -384, -512, -640, -769, -897, -1025, -1153, -1281, -1409, -1537, -1665, -1793, -1921, -2050, -2178, -2306, -2434, -2562, -2690, -2818, -2946, -3074, -3202, -3331, -3459, -3587, -3715, -3843, -3971, -4099, -4227, -4355, -4483, -4612, -4740, -4868, -4996, -5124, -5252, -5380, -5508, -5636, -5764, -5892, -6021, -6149, -6277, -6405, -6533, -6661, -6789, -6917, -7045, -7173, -7302, -7430, -7558, -7686, -7814, -7942, -8070, -8198, -8326, -8454, -8583, -8711, -8839, -8967, -9095, -9223, -9351, -9479, -9607, -9735, -9864, -9992, -10120, -10248, -10376, -10504, -10632, -10760, -10888, -11016, -11144, -11273, -11401, -11529, -11657, -11785, -11913, -12041, -12169, -12297, -12425, -12554, -12682, -12810, -12938, -13066, -13194, -13322, -13450, -13578, -13706, -13835, -13963, -14091, -14219, -14347, -14475, -14603, -14731, -14859, -14987, -15116, -15244, -15372, -15500, -15628, -15756, -15884, -16012, -16140, -16268, -16396, -16525, -16653, -16781, -16909, -17037, -17165, -17293, -17421, -17549, -17677, -17806, -17934, -18062, -18190, -18318, -18446, -18574, -18702, -18830, -18958, -19087, -19215, -19343, -19471, -19599, -19727, -19855, -19983, -20111, -20239, -20368, -20496, -20624, -20752, -20880, -21008, -21136, -21264, -21392, -21520, -21648, -21777, -21905, -22033, -22161, -22289, -22417, -22545, -22673, -22801, -22929, -23058, -23186, -23314, -23442, -23570, -23698, -23826, -23954, -24082, -24210, -24339, -24467, -24595, -24723, -24851, -24979, -25107, -25235, -25363, -25491, -25619, -25748, -25876, -26004, -26132, -26260, -26388, -26516, -26644, -26772, -26900, -27029, -27157, -27285, -27413, -27541, -27669, -27797, -27925, -28053, -28181, -28310, -28438, -28566, -28694, -28822, -28950, -29078, -29206, -29334, -29462, -29591, -29719, -29847, -29975, -30103, -30231, -30359, -30487, -30615, -30743, -30872, -31000, -31128, -31256, -31384, -31512, -31640, -31768, -31896, -32024, -32153, -32280, -32409, -32536, -32668, -32767, -32668, -32536, -32409, -32280, -32153, -32024, -31896, -31768, -31640, -31512, -31384, -31256, -31128, -31000, -30872, -30743, -30615, -30487, -30359, -30231, -30103, -29975, -29847, -29719, -29591, -29462, -29334, -29206, -29078, -28950, -28822, -28694, -28566, -28438, -28310, -28181, -28053, -27925, -27797, -27669, -27541, -27413, -27285, -27157, -27029, -26900, -26772, -26644, -26516, -26388, -26260, -26132, -26004, -25876, -25748, -25619, -25491, -25363, -25235, -25107, -24979, -24851, -24723, -24595, -24467, -24339, -24210, -24082, -23954, -23826, -23698, -23570, -23442, -23314, -23186, -23058, -22929, -22801, -22673, -22545, -22417, -22289, -22161, -22033, -21905, -21777, -21648, -21520, -21392, -21264, -21136, -21008, -20880, -20752, -20624, -20496, -20368, -20239, -20111, -19983, -19855, -19727, -19599, -19471, -19343, -19215, -19087, -18958, -18830, -18702, -18574, -18446, -18318, -18190, -18062, -17934, -17806, -17677, -17549, -17421, -17293, -17165, -17037, -16909, -16781, -16653, -16525, -16396, -16268, -16140, -16012, -15884, -15756, -15628, -15500, -15372, -15244, -15116, -14987, -14859, -14731, -14603, -14475, -14347, -14219, -14091, -13963, -13835, -13706, -13578, -13450, -13322, -13194, -13066, -12938, -12810, -12682, -12554, -12425, -12297, -12169, -12041, -11913, -11785, -11657, -11529, -11401, -11273, -11144, -11016, -10888, -10760, -10632, -10504, -10376, -10248, -10120, -9992, -9864, -9735, -9607, -9479, -9351, -9223, -9095, -8967, -8839, -8711, -8583, -8454, -8326, -8198, -8070, -7942, -7814, -7686, -7558, -7430, -7302, -7173, -7045, -6917, -6789, -6661, -6533, -6405, -6277, -6149, -6021, -5892, -5764, -5636, -5508, -5380, -5252, -5124, -4996, -4868, -4740, -4612, -4483, -4355, -4227, -4099, -3971, -3843, -3715, -3587, -3459, -3331, -3202, -3074, -2946, -2818, -2690, -2562, -2434, -2306, -2178, -2050, -1921, -1793, -1665, -1537, -1409, -1281, -1153, -1025, -897, -769, -640, -512, -384, -256, -128, ], [0, 128, 256, 384, 512, 640, 769, 897, 1025, 1153, 1281, 1409, 1537, 1665, 1793, 1922, 2050, 2177, 2306, 2434, 2562, 2690, 2818, 2946, 3074, 3202, 3331, 3459, 3587, 3715, 3843, 3971, 4099, 4227, 4355, 4484, 4612, 4739, 4868, 4996, 5124, 5252, 5380, 5508, 5636, 5764, 5892, 6021, 6149, 6277, 6405, 6533, 6661, 6789, 6917, 7046, 7173, 7301, 7430, 7558, 7686, 7814, 7942, 8070, 8198, 8326, 8454, 8583, 8711, 8839, 8967, 9095, 9223, 9351, 9479, 9608, 9735, 9863, 9992, 10120, 10248, 10376, 10504, 10632, 10760, 10888, 11016, 11145, 11273, 11400, 11529, 11657, 11785, 11913, 12041, 12170, 12297, 12425, 12554, 12682, 12810, 12938, 13066, 13194, 13322, 13450, 13578, 13707, 13835, 13962, 14091, 14219, 14347, 14475, 14603, 14731, 14859, 14987, 15116, 15244, 15372, 15500, 15628, 15756, 15884, 16012, 16140, 16269, 16396, 16524, 16653, 16781, 16909, 17037, 17165, 17293, 17421, 17549, 17677, 17806, 17934, 18061, 18190, 18318, 18446, 18574, 18702, 18831, 18958, 19086, 19215, 19343, 19471, 19599, 19727, 19855, 19983, 20111, 20239, 20368, 20496, 20623, 20752, 20880, 21008, 21136, 21264, 21393, 21520, 21648, 21777, 21905, 22033, 22160, 22289, 22417, 22545, 22673, 22801, 22930, 23058, 23185, 23314, 23442, 23570, 23698, 23826, 23955, 24082, 24210, 24339, 24467, 24595, 24722, 24851, 24980, 25107, 25235, 25363, 25492, 25619, 25747, 25876, 26004, 26132, 26259, 26388, 26517, 26644, 26772, 26900, 27029, 27157, 27284, 27413, 27542, 27669, 27796, 27925, 28054, 28181, 28309, 28438, 28567, 28694, 28821, 28950, 29079, 29206, 29333, 29462, 29592, 29719, 29845, 29975, 30104, 30231, 30357, 30487, 30617, 30743, 30869, 31000, 31130, 31256, 31381, 31512, 31644, 31768, 31892, 32025, 32159, 32278, 32399, 32543, 32681, 32741, 32681, 32543, 32399, 32278, 32159, 32025, 31892, 31768, 31644, 31512, 31381, 31256, 31130, 31000, 30869, 30743, 30617, 30487, 30357, 30231, 30104, 29975, 29845, 29719, 29592, 29462, 29333, 29206, 29079, 28950, 28821, 28694, 28567, 28438, 28309, 28181, 28054, 27925, 27796, 27669, 27542, 27413, 27284, 27157, 27029, 26900, 26772, 26644, 26517, 26388, 26259, 26132, 26004, 25876, 25747, 25619, 25492, 25363, 25235, 25107, 24980, 24851, 24722, 24595, 24467, 24339, 24210, 24082, 23955, 23826, 23698, 23570, 23442, 23314, 23185, 23058, 22930, 22801, 22673, 22545, 22417, 22289, 22160, 22033, 21905, 21777, 21648, 21520, 21393, 21264, 21136, 21008, 20880, 20752, 20623, 20496, 20368, 20239, 20111, 19983, 19855, 19727, 19599, 19471, 19343, 19215, 19086, 18958, 18831, 18702, 18574, 18446, 18318, 18190, 18061, 17934, 17806, 17677, 17549, 17421, 17293, 17165, 17037, 16909, 16781, 16653, 16524, 16396, 16269, 16140, 16012, 15884, 15756, 15628, 15500, 15372, 15244, 15116, 14987, 14859, 14731, 14603, 14475, 14347, 14219, 14091, 13962, 13835, 13707, 13578, 13450, 13322, 13194, 13066, 12938, 12810, 12682, 12554, 12425, 12297, 12170, 12041, 11913, 11785, 11657, 11529, 11400, 11273, 11145, 11016, 10888, 10760, 10632, 10504, 10376, 10248, 10120, 9992, 9863, 9735, 9608, 9479, 9351, 9223, 9095, 8967, 8839, 8711, 8583, 8454, 8326, 8198, 8070, 7942, 7814, 7686, 7558, 7430, 7301, 7173, 7046, 6917, 6789, 6661, 6533, 6405, 6277, 6149, 6021, 5892, 5764, 5636, 5508, 5380, 5252, 5124, 4996, 4868, 4739, 4612, 4484, 4355, 4227, 4099, 3971, 3843, 3715, 3587, 3459, 3331, 3202, 3074, 2946, 2818, 2690, 2562, 2434, 2306, 2177, 2050, 1922, 1793, 1665, 1537, 1409, 1281, 1153, 1025, 897, 769, 640, 512, 384, 256, 128, 0, -128, -256, -384, -512, -640, -769, -897, -1025, -1153, -1281, -1409, -1537, -1665, -1793, -1922, -2050, -2177, -2306, -2434, -2562, -2690, -2818, -2946, -3074, -3202, -3331, -3459, -3587, -3715, -3843, -3971, -4099, -4227, -4355, -4484, -4612, -4739, -4868, -4996, -5124, -5252, -5380, -5508, -5636, -5764, -5892, -6021, -6149, -6277, -6405, -6533, -6661, -6789, -6917, -7046, -7173, -7301, -7430, -7558, -7686, -7814, -7942, -8070, -8198, -8326, -8454, -8583, -8711, -8839, -8967, -9095, -9223, -9351, -9479, -9608, -9735, -9863, -9992, -10120, -10248, -10376, -10504, -10632, -10760, -10888, -11016, -11145, -11273, -11400, -11529, -11657, -11785, -11913, -12041, -12170, -12297, -12425, -12554, -12682, -12810, -12938, -13066, -13194, -13322, -13450, -13578, -13707, -13835, -13962, -14091, -14219, -14347, -14475, -14603, -14731, -14859, -14987, -15116, -15244, -15372, -15500, -15628, -15756, -15884, -16012, -16140, -16269, -16396, -16524, -16653, -16781, -16909, -17037, -17165, -17293, -17421, -17549, -17677, -17806, -17934, -18061, -18190, -18318, -18446, -18574, -18702, -18831, -18958, -19086, -19215, -19343, -19471, -19599, -19727, -19855, -19983, -20111, -20239, -20368, -20496, -20623, -20752, -20880, -21008, -21136, -21264, -21393, -21520, -21648, -21777, -21905, -22033, -22160, -22289, -22417, -22545, -22673, -22801, -22930, -23058, -23185, -23314, -23442, -23570, -23698, -23826, -23955, -24082, -24210, -24339, -24467, -24595, -24722, -24851, -24980, -25107, -25235, -25363, -25492, -25619, -25747, -25876, -26004, -26132, -26259, -26388, -26517, -26644, -26772, -26900, -27029, -27157, -27284, -27413, -27542, -27669, -27796, -27925, -28054, -28181, -28309, -28438, -28567, -28694, -28821, -28950, -29079, -29206, -29333, -29462, -29592, -29719, -29845, -29975, -30104, -30231, -30357, -30487, -30617, -30743, -30869, -31000, -31130, -31256, -31381, -31512, -31644, -31768, -31892, -32025, -32159, -32278, -32399, -32543, -32681, -32741, -32681, -32543, -32399, -32278, -32159, -32025, -31892, -31768, -31644, -31512, -31381, -31256, -31130, -31000, -30869, -30743, -30617, -30487, -30357, -30231, -30104, -29975, -29845, -29719, -29592, -29462, -29333, -29206, -29079, -28950, -28821, -28694, -28567, -28438, -28309, -28181, -28054, -27925, -27796, -27669, -27542, -27413, -27284, -27157, -27029, -26900, -26772, -26644, -26517, -26388, -26259, -26132, -26004, -25876, -25747, -25619, -25492, -25363, -25235, -25107, -24980, -24851, -24722, -24595, -24467, -24339, -24210, -24082, -23955, -23826, -23698, -23570, -23442, -23314, -23185, -23058, -22930, -22801, -22673, -22545, -22417, -22289, -22160, -22033, -21905, -21777, -21648, -21520, -21393, -21264, -21136, -21008, -20880, -20752, -20623, -20496, -20368, -20239, -20111, -19983, -19855, -19727, -19599, -19471, -19343, -19215, -19086, -18958, -18831, -18702, -18574, -18446, -18318, -18190, -18061, -17934, -17806, -17677, -17549, -17421, -17293, -17165, -17037, -16909, -16781, -16653, -16524, -16396, -16269, -16140, -16012, -15884, -15756, -15628, -15500, -15372, -15244, -15116, -14987, -14859, -14731, -14603, -14475, -14347, -14219, -14091, -13962, -13835, -13707, -13578, -13450, -13322, -13194, -13066, -12938, -12810, -12682, -12554, -12425, -12297, -12170, -12041, -11913, -11785, -11657, -11529, -11400, -11273, -11145, -11016, -10888, -10760, -10632, -10504, -10376, -10248, -10120, -9992, -9863, -9735, -9608, -9479, -9351, -9223, -9095, -8967, -8839, -8711, -8583, -8454, -8326, -8198, -8070, -7942, -7814, -7686, -7558, -7430, -7301, -7173, -7046, -6917, -6789, -6661, -6533, -6405, -6277, -6149, -6021, -5892, -5764, -5636, -5508, -5380, -5252, -5124, -4996, -4868, -4739, -4612, -4484, -4355, -4227, -4099, -3971, -3843, -3715, -3587, -3459, -3331, -3202, -3074, -2946, -2818, -2690, -2562, -2434, -2306, -2177, -2050, -1922, -1793, -1665, -1537, -1409, -1281, -1153, -1025, -897, -769, -640, -512, -384, -256, -128, ], [0, 128, 255, 384, 512, 641, 769, 897, 1025, 1152, 1280, 1408, 1537, 1666, 1794, 1922, 2050, 2177, 2305, 2433, 2562, 2691, 2819, 2947, 3074, 3202, 3330, 3458, 3587, 3715, 3844, 3972, 4099, 4227, 4354, 4483, 4612, 4740, 4869, 4996, 5124, 5251, 5379, 5508, 5636, 5765, 5893, 6021, 6149, 6276, 6404, 6532, 6661, 6790, 6918, 7046, 7173, 7301, 7429, 7557, 7686, 7815, 7943, 8071, 8198, 8326, 8454, 8582, 8711, 8839, 8968, 9096, 9223, 9350, 9478, 9607, 9735, 9864, 9993, 10120, 10248, 10375, 10503, 10631, 10760, 10889, 11017, 11145, 11273, 11400, 11528, 11656, 11785, 11914, 12042, 12170, 12297, 12425, 12553, 12681, 12810, 12939, 13067, 13195, 13322, 13450, 13577, 13706, 13835, 13963, 14092, 14220, 14347, 14474, 14602, 14730, 14859, 14988, 15117, 15244, 15372, 15499, 15627, 15755, 15884, 16013, 16141, 16269, 16396, 16524, 16652, 16780, 16909, 17038, 17166, 17294, 17421, 17548, 17676, 17805, 17934, 18063, 18191, 18319, 18446, 18573, 18701, 18829, 18958, 19087, 19216, 19344, 19471, 19598, 19726, 19854, 19983, 20112, 20241, 20368, 20496, 20623, 20750, 20879, 21008, 21137, 21266, 21393, 21520, 21647, 21775, 21904, 22033, 22162, 22291, 22418, 22545, 22672, 22800, 22928, 23058, 23187, 23316, 23443, 23570, 23697, 23824, 23953, 24082, 24212, 24341, 24468, 24595, 24721, 24849, 24978, 25107, 25237, 25366, 25493, 25619, 25746, 25873, 26002, 26132, 26262, 26391, 26518, 26644, 26770, 26898, 27027, 27157, 27287, 27416, 27543, 27669, 27795, 27922, 28051, 28182, 28312, 28442, 28568, 28694, 28819, 28946, 29075, 29207, 29338, 29467, 29594, 29718, 29842, 29969, 30099, 30232, 30365, 30494, 30620, 30742, 30865, 30991, 31122, 31258, 31394, 31524, 31647, 31764, 31881, 32006, 32144, 32293, 32441, 32569, 32658, 32689, 32658, 32569, 32441, 32293, 32144, 32006, 31881, 31764, 31647, 31524, 31394, 31258, 31122, 30991, 30865, 30742, 30620, 30494, 30365, 30232, 30099, 29969, 29842, 29718, 29594, 29467, 29338, 29207, 29075, 28946, 28819, 28694, 28568, 28442, 28312, 28182, 28051, 27922, 27795, 27669, 27543, 27416, 27287, 27157, 27027, 26898, 26770, 26644, 26518, 26391, 26262, 26132, 26002, 25873, 25746, 25619, 25493, 25366, 25237, 25107, 24978, 24849, 24721, 24595, 24468, 24341, 24212, 24082, 23953, 23824, 23697, 23570, 23443, 23316, 23187, 23058, 22928, 22800, 22672, 22545, 22418, 22291, 22162, 22033, 21904, 21775, 21647, 21520, 21393, 21266, 21137, 21008, 20879, 20750, 20623, 20496, 20368, 20241, 20112, 19983, 19854, 19726, 19598, 19471, 19344, 19216, 19087, 18958, 18829, 18701, 18573, 18446, 18319, 18191, 18063, 17934, 17805, 17676, 17548, 17421, 17294, 17166, 17038, 16909, 16780, 16652, 16524, 16396, 16269, 16141, 16013, 15884, 15755, 15627, 15499, 15372, 15244, 15117, 14988, 14859, 14730, 14602, 14474, 14347, 14220, 14092, 13963, 13835, 13706, 13577, 13450, 13322, 13195, 13067, 12939, 12810, 12681, 12553, 12425, 12297, 12170, 12042, 11914, 11785, 11656, 11528, 11400, 11273, 11145, 11017, 10889, 10760, 10631, 10503, 10375, 10248, 10120, 9993, 9864, 9735, 9607, 9478, 9350, 9223, 9096, 8968, 8839, 8711, 8582, 8454, 8326, 8198, 8071, 7943, 7815, 7686, 7557, 7429, 7301, 7173, 7046, 6918, 6790, 6661, 6532, 6404, 6276, 6149, 6021, 5893, 5765, 5636, 5508, 5379, 5251, 5124, 4996, 4869, 4740, 4612, 4483, 4354, 4227, 4099, 3972, 3844, 3715, 3587, 3458, 3330, 3202, 3074, 2947, 2819, 2691, 2562, 2433, 2305, 2177, 2050, 1922, 1794, 1666, 1537, 1408, 1280, 1152, 1025, 897, 769, 641, 512, 384, 255, 128, 0, -128, -255, -384, -512, -641, -769, -897, -1025, -1152, -1280, -1408, -1537, -1666, -1794, -1922, -2050, -2177, -2305, -2433, -2562, -2691, -2819, -2947, -3074, -3202, -3330, -3458, -3587, -3715, -3844, -3972, -4099, -4227, -4354, -4483, -4612, -4740, -4869, -4996, -5124, -5251, -5379, -5508, -5636, -5765, -5893, -6021, -6149, -6276, -6404, -6532, -6661, -6790, -6918, -7046, -7173, -7301, -7429, -7557, -7686, -7815, -7943, -8071, -8198, -8326, -8454, -8582, -8711, -8839, -8968, -9096, -9223, -9350, -9478, -9607, -9735, -9864, -9993, -10120, -10248, -10375, -10503, -10631, -10760, -10889, -11017, -11145, -11273, -11400, -11528, -11656, -11785, -11914, -12042, -12170, -12297, -12425, -12553, -12681, -12810, -12939, -13067, -13195, -13322, -13450, -13577, -13706, -13835, -13963, -14092, -14220, -14347, -14474, -14602, -14730, -14859, -14988, -15117, -15244, -15372, -15499, -15627, -15755, -15884, -16013, -16141, -16269, -16396, -16524, -16652, -16780, -16909, -17038, -17166, -17294, -17421, -17548, -17676, -17805, -17934, -18063, -18191, -18319, -18446, -18573, -18701, -18829, -18958, -19087, -19216, -19344, -19471, -19598, -19726, -19854, -19983, -20112, -20241, -20368, -20496, -20623, -20750, -20879, -21008, -21137, -21266, -21393, -21520, -21647, -21775, -21904, -22033, -22162, -22291, -22418, -22545, -22672, -22800, -22928, -23058, -23187, -23316, -23443, -23570, -23697, -23824, -23953, -24082, -24212, -24341, -24468, -24595, -24721, -24849, -24978, -25107, -25237, -25366, -25493, -25619, -25746, -25873, -26002, -26132, -26262, -26391, -26518, -26644, -26770, -26898, -27027, -27157, -27287, -27416, -27543, -27669, -27795, -27922, -28051, -28182, -28312, -28442, -28568, -28694, -28819, -28946, -29075, -29207, -29338, -29467, -29594, -29718, -29842, -29969, -30099, -30232, -30365, -30494, -30620, -30742, -30865, -30991, -31122, -31258, -31394, -31524, -31647, -31764, -31881, -32006, -32144, -32293, -32441, -32569, -32658, -32689, -32658, -32569, -32441, -32293, -32144, -32006, -31881, -31764, -31647, -31524, -31394, -31258, -31122, -30991, -30865, -30742, -30620, -30494, -30365, -30232, -30099, -29969, -29842, -29718, -29594, -29467, -29338, -29207, -29075, -28946, -28819, -28694, -28568, -28442, -28312, -28182, -28051, -27922, -27795, -27669, -27543, -27416, -27287, -27157, -27027, -26898, -26770, -26644, -26518, -26391, -26262, -26132, -26002, -25873, -25746, -25619, -25493, -25366, -25237, -25107, -24978, -24849, -24721, -24595, -24468, -24341, -24212, -24082, -23953, -23824, -23697, -23570, -23443, -23316, -23187, -23058, -22928, -22800, -22672, -22545, -22418, -22291, -22162, -22033, -21904, -21775, -21647, -21520, -21393, -21266, -21137, -21008, -20879, -20750, -20623, -20496, -20368, -20241, -20112, -19983, -19854, -19726, -19598, -19471, -19344, -19216, -19087, -18958, -18829, -18701, -18573, -18446, -18319, -18191, -18063, -17934, -17805, -17676, -17548, -17421, -17294, -17166, -17038, -16909, -16780, -16652, -16524, -16396, -16269, -16141, -16013, -15884, -15755, -15627, -15499, -15372, -15244, -15117, -14988, -14859, -14730, -14602, -14474, -14347, -14220, -14092, -13963, -13835, -13706, -13577, -13450, -13322, -13195, -13067, -12939, -12810, -12681, -12553, -12425, -12297, -12170, -12042, -11914, -11785, -11656, -11528, -11400, -11273, -11145, -11017, -10889, -10760, -10631, -10503, -10375, -10248, -10120, -9993, -9864, -9735, -9607, -9478, -9350, -9223, -9096, -8968, -8839, -8711, -8582, -8454, -8326, -8198, -8071, -7943, -7815, -7686, -7557, -7429, -7301, -7173, -7046, -6918, -6790, -6661, -6532, -6404, -6276, -6149, -6021, -5893, -5765, -5636, -5508, -5379, -5251, -5124, -4996, -4869, -4740, -4612, -4483, -4354, -4227, -4099, -3972, -3844, -3715, -3587, -3458, -3330, -3202, -3074, -2947, -2819, -2691, -2562, -2433, -2305, -2177, -2050, -1922, -1794, -1666, -1537, -1408, -1280, -1152, -1025, -897, -769, -641, -512, -384, -255, -128, ], [0, 127, 254, 381, 509, 637, 766, 895, 1025, 1154, 1283, 1412, 1540, 1668, 1796, 1923, 2050, 2176, 2303, 2431, 2559, 2687, 2816, 2945, 3074, 3204, 3333, 3462, 3590, 3718, 3845, 3972, 4099, 4226, 4353, 4480, 4608, 4737, 4865, 4995, 5124, 5253, 5382, 5511, 5640, 5767, 5895, 6022, 6149, 6275, 6402, 6530, 6658, 6786, 6915, 7044, 7173, 7303, 7432, 7561, 7689, 7817, 7944, 8071, 8198, 8325, 8452, 8579, 8707, 8835, 8964, 9094, 9223, 9353, 9482, 9611, 9739, 9867, 9994, 10121, 10248, 10374, 10501, 10629, 10756, 10885, 11014, 11143, 11273, 11402, 11532, 11660, 11789, 11917, 12044, 12171, 12297, 12424, 12551, 12678, 12806, 12934, 13063, 13193, 13322, 13452, 13581, 13710, 13839, 13966, 14094, 14220, 14347, 14473, 14600, 14727, 14855, 14983, 15113, 15242, 15372, 15502, 15631, 15760, 15889, 16016, 16143, 16270, 16396, 16523, 16649, 16776, 16904, 17033, 17162, 17291, 17421, 17551, 17681, 17810, 17939, 18066, 18193, 18320, 18446, 18572, 18698, 18825, 18953, 19082, 19211, 19341, 19471, 19601, 19731, 19860, 19989, 20116, 20243, 20369, 20495, 20621, 20747, 20874, 21002, 21131, 21260, 21390, 21521, 21651, 21781, 21911, 22039, 22167, 22293, 22419, 22545, 22670, 22796, 22923, 23050, 23179, 23309, 23439, 23570, 23701, 23832, 23962, 24090, 24218, 24344, 24469, 24594, 24719, 24844, 24971, 25098, 25227, 25357, 25489, 25620, 25752, 25883, 26013, 26142, 26269, 26395, 26519, 26643, 26767, 26892, 27017, 27145, 27274, 27405, 27537, 27671, 27804, 27936, 28067, 28196, 28322, 28447, 28570, 28691, 28813, 28936, 29061, 29188, 29318, 29451, 29586, 29723, 29859, 29994, 30127, 30255, 30380, 30501, 30619, 30735, 30851, 30969, 31091, 31219, 31354, 31495, 31642, 31792, 31943, 32089, 32225, 32346, 32447, 32522, 32569, 32585, 32569, 32522, 32447, 32346, 32225, 32089, 31943, 31792, 31642, 31495, 31354, 31219, 31091, 30969, 30851, 30735, 30619, 30501, 30380, 30255, 30127, 29994, 29859, 29723, 29586, 29451, 29318, 29188, 29061, 28936, 28813, 28691, 28570, 28447, 28322, 28196, 28067, 27936, 27804, 27671, 27537, 27405, 27274, 27145, 27017, 26892, 26767, 26643, 26519, 26395, 26269, 26142, 26013, 25883, 25752, 25620, 25489, 25357, 25227, 25098, 24971, 24844, 24719, 24594, 24469, 24344, 24218, 24090, 23962, 23832, 23701, 23570, 23439, 23309, 23179, 23050, 22923, 22796, 22670, 22545, 22419, 22293, 22167, 22039, 21911, 21781, 21651, 21521, 21390, 21260, 21131, 21002, 20874, 20747, 20621, 20495, 20369, 20243, 20116, 19989, 19860, 19731, 19601, 19471, 19341, 19211, 19082, 18953, 18825, 18698, 18572, 18446, 18320, 18193, 18066, 17939, 17810, 17681, 17551, 17421, 17291, 17162, 17033, 16904, 16776, 16649, 16523, 16396, 16270, 16143, 16016, 15889, 15760, 15631, 15502, 15372, 15242, 15113, 14983, 14855, 14727, 14600, 14473, 14347, 14220, 14094, 13966, 13839, 13710, 13581, 13452, 13322, 13193, 13063, 12934, 12806, 12678, 12551, 12424, 12297, 12171, 12044, 11917, 11789, 11660, 11532, 11402, 11273, 11143, 11014, 10885, 10756, 10629, 10501, 10374, 10248, 10121, 9994, 9867, 9739, 9611, 9482, 9353, 9223, 9094, 8964, 8835, 8707, 8579, 8452, 8325, 8198, 8071, 7944, 7817, 7689, 7561, 7432, 7303, 7173, 7044, 6915, 6786, 6658, 6530, 6402, 6275, 6149, 6022, 5895, 5767, 5640, 5511, 5382, 5253, 5124, 4995, 4865, 4737, 4608, 4480, 4353, 4226, 4099, 3972, 3845, 3718, 3590, 3462, 3333, 3204, 3074, 2945, 2816, 2687, 2559, 2431, 2303, 2176, 2050, 1923, 1796, 1668, 1540, 1412, 1283, 1154, 1025, 895, 766, 637, 509, 381, 254, 127, 0, -127, -254, -381, -509, -637, -766, -895, -1025, -1154, -1283, -1412, -1540, -1668, -1796, -1923, -2050, -2176, -2303, -2431, -2559, -2687, -2816, -2945, -3074, -3204, -3333, -3462, -3590, -3718, -3845, -3972, -4099, -4226, -4353, -4480, -4608, -4737, -4865, -4995, -5124, -5253, -5382, -5511, -5640, -5767, -5895, -6022, -6149, -6275, -6402, -6530, -6658, -6786, -6915, -7044, -7173, -7303, -7432, -7561, -7689, -7817, -7944, -8071, -8198, -8325, -8452, -8579, -8707, -8835, -8964, -9094, -9223, -9353, -9482, -9611, -9739, -9867, -9994, -10121, -10248, -10374, -10501, -10629, -10756, -10885, -11014, -11143, -11273, -11402, -11532, -11660, -11789, -11917, -12044, -12171, -12297, -12424, -12551, -12678, -12806, -12934, -13063, -13193, -13322, -13452, -13581, -13710, -13839, -13966, -14094, -14220, -14347, -14473, -14600, -14727, -14855, -14983, -15113, -15242, -15372, -15502, -15631, -15760, -15889, -16016, -16143, -16270, -16396, -16523, -16649, -16776, -16904, -17033, -17162, -17291, -17421, -17551, -17681, -17810, -17939, -18066, -18193, -18320, -18446, -18572, -18698, -18825, -18953, -19082, -19211, -19341, -19471, -19601, -19731, -19860, -19989, -20116, -20243, -20369, -20495, -20621, -20747, -20874, -21002, -21131, -21260, -21390, -21521, -21651, -21781, -21911, -22039, -22167, -22293, -22419, -22545, -22670, -22796, -22923, -23050, -23179, -23309, -23439, -23570, -23701, -23832, -23962, -24090, -24218, -24344, -24469, -24594, -24719, -24844, -24971, -25098, -25227, -25357, -25489, -25620, -25752, -25883, -26013, -26142, -26269, -26395, -26519, -26643, -26767, -26892, -27017, -27145, -27274, -27405, -27537, -27671, -27804, -27936, -28067, -28196, -28322, -28447, -28570, -28691, -28813, -28936, -29061, -29188, -29318, -29451, -29586, -29723, -29859, -29994, -30127, -30255, -30380, -30501, -30619, -30735, -30851, -30969, -31091, -31219, -31354, -31495, -31642, -31792, -31943, -32089, -32225, -32346, -32447, -32522, -32569, -32585, -32569, -32522, -32447, -32346, -32225, -32089, -31943, -31792, -31642, -31495, -31354, -31219, -31091, -30969, -30851, -30735, -30619, -30501, -30380, -30255, -30127, -29994, -29859, -29723, -29586, -29451, -29318, -29188, -29061, -28936, -28813, -28691, -28570, -28447, -28322, -28196, -28067, -27936, -27804, -27671, -27537, -27405, -27274, -27145, -27017, -26892, -26767, -26643, -26519, -26395, -26269, -26142, -26013, -25883, -25752, -25620, -25489, -25357, -25227, -25098, -24971, -24844, -24719, -24594, -24469, -24344, -24218, -24090, -23962, -23832, -23701, -23570, -23439, -23309, -23179, -23050, -22923, -22796, -22670, -22545, -22419, -22293, -22167, -22039, -21911, -21781, -21651, -21521, -21390, -21260, -21131, -21002, -20874, -20747, -20621, -20495, -20369, -20243, -20116, -19989, -19860, -19731, -19601, -19471, -19341, -19211, -19082, -18953, -18825, -18698, -18572, -18446, -18320, -18193, -18066, -17939, -17810, -17681, -17551, -17421, -17291, -17162, -17033, -16904, -16776, -16649, -16523, -16396, -16270, -16143, -16016, -15889, -15760, -15631, -15502, -15372, -15242, -15113, -14983, -14855, -14727, -14600, -14473, -14347, -14220, -14094, -13966, -13839, -13710, -13581, -13452, -13322, -13193, -13063, -12934, -12806, -12678, -12551, -12424, -12297, -12171, -12044, -11917, -11789, -11660, -11532, -11402, -11273, -11143, -11014, -10885, -10756, -10629, -10501, -10374, -10248, -10121, -9994, -9867, -9739, -9611, -9482, -9353, -9223, -9094, -8964, -8835, -8707, -8579, -8452, -8325, -8198, -8071, -7944, -7817, -7689, -7561, -7432, -7303, -7173, -7044, -6915, -6786, -6658, -6530, -6402, -6275, -6149, -6022, -5895, -5767, -5640, -5511, -5382, -5253, -5124, -4995, -4865, -4737, -4608, -4480, -4353, -4226, -4099, -3972, -3845, -3718, -3590, -3462, -3333, -3204, -3074, -2945, -2816, -2687, -2559, -2431, -2303, -2176, -2050, -1923, -1796, -1668, -1540, -1412, -1283, -1154, -1025, -895, -766, -637, -509, -381, -254, -127, ], [0, 126, 251, 377, 503, 630, 757, 884, 1012, 1140, 1269, 1398, 1528, 1658, 1788, 1919, 2050, 2180, 2311, 2441, 2571, 2701, 2830, 2959, 3087, 3215, 3343, 3469, 3596, 3722, 3848, 3973, 4099, 4224, 4350, 4476, 4602, 4728, 4855, 4983, 5111, 5239, 5368, 5497, 5627, 5757, 5888, 6018, 6149, 6280, 6410, 6541, 6671, 6801, 6930, 7059, 7187, 7315, 7442, 7569, 7695, 7821, 7947, 8073, 8198, 8323, 8449, 8574, 8700, 8827, 8954, 9081, 9209, 9337, 9466, 9596, 9725, 9856, 9986, 10117, 10248, 10379, 10510, 10641, 10771, 10901, 11030, 11159, 11288, 11415, 11543, 11669, 11795, 11921, 12046, 12172, 12297, 12422, 12547, 12672, 12798, 12924, 13051, 13178, 13306, 13435, 13564, 13693, 13824, 13954, 14085, 14216, 14348, 14479, 14610, 14741, 14872, 15002, 15132, 15261, 15389, 15517, 15644, 15770, 15896, 16021, 16146, 16271, 16395, 16520, 16645, 16769, 16895, 17021, 17147, 17274, 17402, 17531, 17660, 17790, 17921, 18052, 18184, 18315, 18448, 18580, 18712, 18843, 18974, 19105, 19235, 19364, 19492, 19620, 19746, 19872, 19998, 20122, 20246, 20370, 20493, 20617, 20741, 20865, 20989, 21115, 21241, 21368, 21496, 21624, 21754, 21885, 22016, 22149, 22282, 22415, 22548, 22682, 22815, 22948, 23080, 23211, 23342, 23471, 23599, 23727, 23853, 23977, 24101, 24224, 24347, 24468, 24590, 24712, 24833, 24956, 25079, 25203, 25328, 25455, 25583, 25712, 25843, 25975, 26109, 26244, 26380, 26516, 26653, 26789, 26925, 27061, 27196, 27329, 27461, 27590, 27718, 27844, 27968, 28090, 28210, 28328, 28445, 28561, 28677, 28793, 28909, 29026, 29145, 29266, 29389, 29515, 29645, 29778, 29914, 30054, 30197, 30343, 30491, 30641, 30792, 30943, 31092, 31240, 31384, 31523, 31657, 31782, 31899, 32005, 32100, 32183, 32252, 32306, 32346, 32370, 32378, 32370, 32346, 32306, 32252, 32183, 32100, 32005, 31899, 31782, 31657, 31523, 31384, 31240, 31092, 30943, 30792, 30641, 30491, 30343, 30197, 30054, 29914, 29778, 29645, 29515, 29389, 29266, 29145, 29026, 28909, 28793, 28677, 28561, 28445, 28328, 28210, 28090, 27968, 27844, 27718, 27590, 27461, 27329, 27196, 27061, 26925, 26789, 26653, 26516, 26380, 26244, 26109, 25975, 25843, 25712, 25583, 25455, 25328, 25203, 25079, 24956, 24833, 24712, 24590, 24468, 24347, 24224, 24101, 23977, 23853, 23727, 23599, 23471, 23342, 23211, 23080, 22948, 22815, 22682, 22548, 22415, 22282, 22149, 22016, 21885, 21754, 21624, 21496, 21368, 21241, 21115, 20989, 20865, 20741, 20617, 20493, 20370, 20246, 20122, 19998, 19872, 19746, 19620, 19492, 19364, 19235, 19105, 18974, 18843, 18712, 18580, 18448, 18315, 18184, 18052, 17921, 17790, 17660, 17531, 17402, 17274, 17147, 17021, 16895, 16769, 16645, 16520, 16395, 16271, 16146, 16021, 15896, 15770, 15644, 15517, 15389, 15261, 15132, 15002, 14872, 14741, 14610, 14479, 14348, 14216, 14085, 13954, 13824, 13693, 13564, 13435, 13306, 13178, 13051, 12924, 12798, 12672, 12547, 12422, 12297, 12172, 12046, 11921, 11795, 11669, 11543, 11415, 11288, 11159, 11030, 10901, 10771, 10641, 10510, 10379, 10248, 10117, 9986, 9856, 9725, 9596, 9466, 9337, 9209, 9081, 8954, 8827, 8700, 8574, 8449, 8323, 8198, 8073, 7947, 7821, 7695, 7569, 7442, 7315, 7187, 7059, 6930, 6801, 6671, 6541, 6410, 6280, 6149, 6018, 5888, 5757, 5627, 5497, 5368, 5239, 5111, 4983, 4855, 4728, 4602, 4476, 4350, 4224, 4099, 3973, 3848, 3722, 3596, 3469, 3343, 3215, 3087, 2959, 2830, 2701, 2571, 2441, 2311, 2180, 2050, 1919, 1788, 1658, 1528, 1398, 1269, 1140, 1012, 884, 757, 630, 503, 377, 251, 126, 0, -126, -251, -377, -503, -630, -757, -884, -1012, -1140, -1269, -1398, -1528, -1658, -1788, -1919, -2050, -2180, -2311, -2441, -2571, -2701, -2830, -2959, -3087, -3215, -3343, -3469, -3596, -3722, -3848, -3973, -4099, -4224, -4350, -4476, -4602, -4728, -4855, -4983, -5111, -5239, -5368, -5497, -5627, -5757, -5888, -6018, -6149, -6280, -6410, -6541, -6671, -6801, -6930, -7059, -7187, -7315, -7442, -7569, -7695, -7821, -7947, -8073, -8198, -8323, -8449, -8574, -8700, -8827, -8954, -9081, -9209, -9337, -9466, -9596, -9725, -9856, -9986, -10117, -10248, -10379, -10510, -10641, -10771, -10901, -11030, -11159, -11288, -11415, -11543, -11669, -11795, -11921, -12046, -12172, -12297, -12422, -12547, -12672, -12798, -12924, -13051, -13178, -13306, -13435, -13564, -13693, -13824, -13954, -14085, -14216, -14348, -14479, -14610, -14741, -14872, -15002, -15132, -15261, -15389, -15517, -15644, -15770, -15896, -16021, -16146, -16271, -16395, -16520, -16645, -16769, -16895, -17021, -17147, -17274, -17402, -17531, -17660, -17790, -17921, -18052, -18184, -18315, -18448, -18580, -18712, -18843, -18974, -19105, -19235, -19364, -19492, -19620, -19746, -19872, -19998, -20122, -20246, -20370, -20493, -20617, -20741, -20865, -20989, -21115, -21241, -21368, -21496, -21624, -21754, -21885, -22016, -22149, -22282, -22415, -22548, -22682, -22815, -22948, -23080, -23211, -23342, -23471, -23599, -23727, -23853, -23977, -24101, -24224, -24347, -24468, -24590, -24712, -24833, -24956, -25079, -25203, -25328, -25455, -25583, -25712, -25843, -25975, -26109, -26244, -26380, -26516, -26653, -26789, -26925, -27061, -27196, -27329, -27461, -27590, -27718, -27844, -27968, -28090, -28210, -28328, -28445, -28561, -28677, -28793, -28909, -29026, -29145, -29266, -29389, -29515, -29645, -29778, -29914, -30054, -30197, -30343, -30491, -30641, -30792, -30943, -31092, -31240, -31384, -31523, -31657, -31782, -31899, -32005, -32100, -32183, -32252, -32306, -32346, -32370, -32378, -32370, -32346, -32306, -32252, -32183, -32100, -32005, -31899, -31782, -31657, -31523, -31384, -31240, -31092, -30943, -30792, -30641, -30491, -30343, -30197, -30054, -29914, -29778, -29645, -29515, -29389, -29266, -29145, -29026, -28909, -28793, -28677, -28561, -28445, -28328, -28210, -28090, -27968, -27844, -27718, -27590, -27461, -27329, -27196, -27061, -26925, -26789, -26653, -26516, -26380, -26244, -26109, -25975, -25843, -25712, -25583, -25455, -25328, -25203, -25079, -24956, -24833, -24712, -24590, -24468, -24347, -24224, -24101, -23977, -23853, -23727, -23599, -23471, -23342, -23211, -23080, -22948, -22815, -22682, -22548, -22415, -22282, -22149, -22016, -21885, -21754, -21624, -21496, -21368, -21241, -21115, -20989, -20865, -20741, -20617, -20493, -20370, -20246, -20122, -19998, -19872, -19746, -19620, -19492, -19364, -19235, -19105, -18974, -18843, -18712, -18580, -18448, -18315, -18184, -18052, -17921, -17790, -17660, -17531, -17402, -17274, -17147, -17021, -16895, -16769, -16645, -16520, -16395, -16271, -16146, -16021, -15896, -15770, -15644, -15517, -15389, -15261, -15132, -15002, -14872, -14741, -14610, -14479, -14348, -14216, -14085, -13954, -13824, -13693, -13564, -13435, -13306, -13178, -13051, -12924, -12798, -12672, -12547, -12422, -12297, -12172, -12046, -11921, -11795, -11669, -11543, -11415, -11288, -11159, -11030, -10901, -10771, -10641, -10510, -10379, -10248, -10117, -9986, -9856, -9725, -9596, -9466, -9337, -9209, -9081, -8954, -8827, -8700, -8574, -8449, -8323, -8198, -8073, -7947, -7821, -7695, -7569, -7442, -7315, -7187, -7059, -6930, -6801, -6671, -6541, -6410, -6280, -6149, -6018, -5888, -5757, -5627, -5497, -5368, -5239, -5111, -4983, -4855, -4728, -4602, -4476, -4350, -4224, -4099, -3973, -3848, -3722, -3596, -3469, -3343, -3215, -3087, -2959, -2830, -2701, -2571, -2441, -2311, -2180, -2050, -1919, -1788, -1658, -1528, -1398, -1269, -1140, -1012, -884, -757, -630, -503, -377, -251, -126, ], [0, 123, 246, 369, 493, 616, 740, 864, 988, 1113, 1238, 1364, 1489, 1616, 1743, 1870, 1998, 2126, 2255, 2385, 2514, 2645, 2776, 2907, 3038, 3170, 3303, 3435, 3568, 3701, 3834, 3967, 4100, 4234, 4367, 4500, 4633, 4766, 4898, 5030, 5162, 5294, 5425, 5556, 5686, 5816, 5945, 6074, 6202, 6330, 6457, 6584, 6710, 6836, 6961, 7086, 7210, 7334, 7458, 7581, 7704, 7827, 7950, 8073, 8195, 8318, 8441, 8564, 8686, 8810, 8933, 9057, 9181, 9305, 9430, 9555, 9681, 9808, 9935, 10062, 10190, 10319, 10448, 10577, 10708, 10838, 10970, 11101, 11234, 11366, 11499, 11633, 11766, 11900, 12034, 12168, 12302, 12436, 12571, 12705, 12838, 12972, 13105, 13238, 13371, 13503, 13635, 13766, 13896, 14026, 14156, 14284, 14412, 14540, 14667, 14793, 14918, 15043, 15168, 15291, 15415, 15537, 15660, 15782, 15903, 16025, 16146, 16267, 16388, 16509, 16630, 16751, 16873, 16994, 17116, 17239, 17362, 17485, 17609, 17734, 17859, 17985, 18112, 18239, 18368, 18497, 18626, 18757, 18889, 19021, 19154, 19287, 19421, 19556, 19692, 19827, 19964, 20100, 20237, 20374, 20511, 20648, 20785, 20922, 21059, 21195, 21330, 21466, 21600, 21734, 21867, 22000, 22131, 22262, 22391, 22520, 22647, 22774, 22899, 23023, 23146, 23269, 23390, 23510, 23629, 23748, 23866, 23983, 24099, 24215, 24331, 24447, 24562, 24677, 24793, 24908, 25025, 25141, 25258, 25377, 25496, 25616, 25737, 25860, 25983, 26109, 26236, 26364, 26495, 26627, 26760, 26896, 27033, 27172, 27313, 27455, 27599, 27744, 27891, 28039, 28187, 28337, 28488, 28639, 28790, 28941, 29092, 29242, 29392, 29540, 29687, 29832, 29975, 30116, 30254, 30389, 30521, 30648, 30772, 30891, 31005, 31114, 31218, 31316, 31408, 31494, 31574, 31646, 31712, 31770, 31821, 31864, 31900, 31927, 31947, 31959, 31963, 31959, 31947, 31927, 31900, 31864, 31821, 31770, 31712, 31646, 31574, 31494, 31408, 31316, 31218, 31114, 31005, 30891, 30772, 30648, 30521, 30389, 30254, 30116, 29975, 29832, 29687, 29540, 29392, 29242, 29092, 28941, 28790, 28639, 28488, 28337, 28187, 28039, 27891, 27744, 27599, 27455, 27313, 27172, 27033, 26896, 26760, 26627, 26495, 26364, 26236, 26109, 25983, 25860, 25737, 25616, 25496, 25377, 25258, 25141, 25025, 24908, 24793, 24677, 24562, 24447, 24331, 24215, 24099, 23983, 23866, 23748, 23629, 23510, 23390, 23269, 23146, 23023, 22899, 22774, 22647, 22520, 22391, 22262, 22131, 22000, 21867, 21734, 21600, 21466, 21330, 21195, 21059, 20922, 20785, 20648, 20511, 20374, 20237, 20100, 19964, 19827, 19692, 19556, 19421, 19287, 19154, 19021, 18889, 18757, 18626, 18497, 18368, 18239, 18112, 17985, 17859, 17734, 17609, 17485, 17362, 17239, 17116, 16994, 16873, 16751, 16630, 16509, 16388, 16267, 16146, 16025, 15903, 15782, 15660, 15537, 15415, 15291, 15168, 15043, 14918, 14793, 14667, 14540, 14412, 14284, 14156, 14026, 13896, 13766, 13635, 13503, 13371, 13238, 13105, 12972, 12838, 12705, 12571, 12436, 12302, 12168, 12034, 11900, 11766, 11633, 11499, 11366, 11234, 11101, 10970, 10838, 10708, 10577, 10448, 10319, 10190, 10062, 9935, 9808, 9681, 9555, 9430, 9305, 9181, 9057, 8933, 8810, 8686, 8564, 8441, 8318, 8195, 8073, 7950, 7827, 7704, 7581, 7458, 7334, 7210, 7086, 6961, 6836, 6710, 6584, 6457, 6330, 6202, 6074, 5945, 5816, 5686, 5556, 5425, 5294, 5162, 5030, 4898, 4766, 4633, 4500, 4367, 4234, 4100, 3967, 3834, 3701, 3568, 3435, 3303, 3170, 3038, 2907, 2776, 2645, 2514, 2385, 2255, 2126, 1998, 1870, 1743, 1616, 1489, 1364, 1238, 1113, 988, 864, 740, 616, 493, 369, 246, 123, 0, -123, -246, -369, -493, -616, -740, -864, -988, -1113, -1238, -1364, -1489, -1616, -1743, -1870, -1998, -2126, -2255, -2385, -2514, -2645, -2776, -2907, -3038, -3170, -3303, -3435, -3568, -3701, -3834, -3967, -4100, -4234, -4367, -4500, -4633, -4766, -4898, -5030, -5162, -5294, -5425, -5556, -5686, -5816, -5945, -6074, -6202, -6330, -6457, -6584, -6710, -6836, -6961, -7086, -7210, -7334, -7458, -7581, -7704, -7827, -7950, -8073, -8195, -8318, -8441, -8564, -8686, -8810, -8933, -9057, -9181, -9305, -9430, -9555, -9681, -9808, -9935, -10062, -10190, -10319, -10448, -10577, -10708, -10838, -10970, -11101, -11234, -11366, -11499, -11633, -11766, -11900, -12034, -12168, -12302, -12436, -12571, -12705, -12838, -12972, -13105, -13238, -13371, -13503, -13635, -13766, -13896, -14026, -14156, -14284, -14412, -14540, -14667, -14793, -14918, -15043, -15168, -15291, -15415, -15537, -15660, -15782, -15903, -16025, -16146, -16267, -16388, -16509, -16630, -16751, -16873, -16994, -17116, -17239, -17362, -17485, -17609, -17734, -17859, -17985, -18112, -18239, -18368, -18497, -18626, -18757, -18889, -19021, -19154, -19287, -19421, -19556, -19692, -19827, -19964, -20100, -20237, -20374, -20511, -20648, -20785, -20922, -21059, -21195, -21330, -21466, -21600, -21734, -21867, -22000, -22131, -22262, -22391, -22520, -22647, -22774, -22899, -23023, -23146, -23269, -23390, -23510, -23629, -23748, -23866, -23983, -24099, -24215, -24331, -24447, -24562, -24677, -24793, -24908, -25025, -25141, -25258, -25377, -25496, -25616, -25737, -25860, -25983, -26109, -26236, -26364, -26495, -26627, -26760, -26896, -27033, -27172, -27313, -27455, -27599, -27744, -27891, -28039, -28187, -28337, -28488, -28639, -28790, -28941, -29092, -29242, -29392, -29540, -29687, -29832, -29975, -30116, -30254, -30389, -30521, -30648, -30772, -30891, -31005, -31114, -31218, -31316, -31408, -31494, -31574, -31646, -31712, -31770, -31821, -31864, -31900, -31927, -31947, -31959, -31963, -31959, -31947, -31927, -31900, -31864, -31821, -31770, -31712, -31646, -31574, -31494, -31408, -31316, -31218, -31114, -31005, -30891, -30772, -30648, -30521, -30389, -30254, -30116, -29975, -29832, -29687, -29540, -29392, -29242, -29092, -28941, -28790, -28639, -28488, -28337, -28187, -28039, -27891, -27744, -27599, -27455, -27313, -27172, -27033, -26896, -26760, -26627, -26495, -26364, -26236, -26109, -25983, -25860, -25737, -25616, -25496, -25377, -25258, -25141, -25025, -24908, -24793, -24677, -24562, -24447, -24331, -24215, -24099, -23983, -23866, -23748, -23629, -23510, -23390, -23269, -23146, -23023, -22899, -22774, -22647, -22520, -22391, -22262, -22131, -22000, -21867, -21734, -21600, -21466, -21330, -21195, -21059, -20922, -20785, -20648, -20511, -20374, -20237, -20100, -19964, -19827, -19692, -19556, -19421, -19287, -19154, -19021, -18889, -18757, -18626, -18497, -18368, -18239, -18112, -17985, -17859, -17734, -17609, -17485, -17362, -17239, -17116, -16994, -16873, -16751, -16630, -16509, -16388, -16267, -16146, -16025, -15903, -15782, -15660, -15537, -15415, -15291, -15168, -15043, -14918, -14793, -14667, -14540, -14412, -14284, -14156, -14026, -13896, -13766, -13635, -13503, -13371, -13238, -13105, -12972, -12838, -12705, -12571, -12436, -12302, -12168, -12034, -11900, -11766, -11633, -11499, -11366, -11234, -11101, -10970, -10838, -10708, -10577, -10448, -10319, -10190, -10062, -9935, -9808, -9681, -9555, -9430, -9305, -9181, -9057, -8933, -8810, -8686, -8564, -8441, -8318, -8195, -8073, -7950, -7827, -7704, -7581, -7458, -7334, -7210, -7086, -6961, -6836, -6710, -6584, -6457, -6330, -6202, -6074, -5945, -5816, -5686, -5556, -5425, -5294, -5162, -5030, -4898, -4766, -4633, -4500, -4367, -4234, -4100, -3967, -3834, -3701, -3568, -3435, -3303, -3170, -3038, -2907, -2776, -2645, -2514, -2385, -2255, -2126, -1998, -1870, -1743, -1616, -1489, -1364, -1238, -1113, -988, -864, -740, -616, -493, -369, -246, -123, ], [0, 118, 236, 354, 472, 591, 709, 828, 947, 1065, 1185, 1304, 1424, 1544, 1664, 1784, 1905, 2026, 2148, 2270, 2392, 2515, 2638, 2762, 2886, 3011, 3136, 3261, 3388, 3514, 3641, 3769, 3897, 4025, 4155, 4284, 4414, 4545, 4676, 4808, 4940, 5072, 5205, 5339, 5473, 5607, 5742, 5877, 6013, 6149, 6285, 6422, 6559, 6696, 6833, 6971, 7109, 7247, 7386, 7524, 7663, 7802, 7940, 8079, 8218, 8357, 8496, 8635, 8774, 8912, 9051, 9189, 9327, 9465, 9603, 9740, 9878, 10014, 10151, 10287, 10423, 10558, 10693, 10828, 10962, 11095, 11229, 11361, 11493, 11625, 11756, 11886, 12016, 12145, 12274, 12402, 12529, 12656, 12783, 12908, 13033, 13158, 13282, 13405, 13528, 13650, 13771, 13892, 14013, 14133, 14252, 14371, 14489, 14607, 14725, 14842, 14959, 15075, 15191, 15307, 15422, 15537, 15652, 15767, 15882, 15996, 16110, 16225, 16339, 16453, 16567, 16682, 16796, 16911, 17026, 17141, 17256, 17371, 17487, 17603, 17720, 17837, 17954, 18072, 18191, 18310, 18430, 18550, 18671, 18792, 18915, 19038, 19161, 19286, 19411, 19537, 19664, 19792, 19921, 20050, 20181, 20312, 20445, 20578, 20712, 20847, 20983, 21120, 21257, 21396, 21536, 21676, 21817, 21960, 22103, 22246, 22391, 22536, 22682, 22829, 22976, 23124, 23273, 23422, 23571, 23721, 23872, 24023, 24174, 24325, 24476, 24628, 24780, 24931, 25083, 25235, 25386, 25537, 25688, 25838, 25988, 26137, 26285, 26433, 26580, 26726, 26871, 27016, 27159, 27300, 27441, 27580, 27717, 27853, 27988, 28120, 28251, 28380, 28507, 28632, 28755, 28875, 28993, 29109, 29222, 29333, 29441, 29547, 29649, 29749, 29845, 29939, 30030, 30117, 30202, 30283, 30360, 30434, 30505, 30573, 30636, 30696, 30753, 30806, 30855, 30900, 30941, 30979, 31013, 31042, 31068, 31090, 31108, 31122, 31132, 31138, 31140, 31138, 31132, 31122, 31108, 31090, 31068, 31042, 31013, 30979, 30941, 30900, 30855, 30806, 30753, 30696, 30636, 30573, 30505, 30434, 30360, 30283, 30202, 30117, 30030, 29939, 29845, 29749, 29649, 29547, 29441, 29333, 29222, 29109, 28993, 28875, 28755, 28632, 28507, 28380, 28251, 28120, 27988, 27853, 27717, 27580, 27441, 27300, 27159, 27016, 26871, 26726, 26580, 26433, 26285, 26137, 25988, 25838, 25688, 25537, 25386, 25235, 25083, 24931, 24780, 24628, 24476, 24325, 24174, 24023, 23872, 23721, 23571, 23422, 23273, 23124, 22976, 22829, 22682, 22536, 22391, 22246, 22103, 21960, 21817, 21676, 21536, 21396, 21257, 21120, 20983, 20847, 20712, 20578, 20445, 20312, 20181, 20050, 19921, 19792, 19664, 19537, 19411, 19286, 19161, 19038, 18915, 18792, 18671, 18550, 18430, 18310, 18191, 18072, 17954, 17837, 17720, 17603, 17487, 17371, 17256, 17141, 17026, 16911, 16796, 16682, 16567, 16453, 16339, 16225, 16110, 15996, 15882, 15767, 15652, 15537, 15422, 15307, 15191, 15075, 14959, 14842, 14725, 14607, 14489, 14371, 14252, 14133, 14013, 13892, 13771, 13650, 13528, 13405, 13282, 13158, 13033, 12908, 12783, 12656, 12529, 12402, 12274, 12145, 12016, 11886, 11756, 11625, 11493, 11361, 11229, 11095, 10962, 10828, 10693, 10558, 10423, 10287, 10151, 10014, 9878, 9740, 9603, 9465, 9327, 9189, 9051, 8912, 8774, 8635, 8496, 8357, 8218, 8079, 7940, 7802, 7663, 7524, 7386, 7247, 7109, 6971, 6833, 6696, 6559, 6422, 6285, 6149, 6013, 5877, 5742, 5607, 5473, 5339, 5205, 5072, 4940, 4808, 4676, 4545, 4414, 4284, 4155, 4025, 3897, 3769, 3641, 3514, 3388, 3261, 3136, 3011, 2886, 2762, 2638, 2515, 2392, 2270, 2148, 2026, 1905, 1784, 1664, 1544, 1424, 1304, 1185, 1065, 947, 828, 709, 591, 472, 354, 236, 118, 0, -118, -236, -354, -472, -591, -709, -828, -947, -1065, -1185, -1304, -1424, -1544, -1664, -1784, -1905, -2026, -2148, -2270, -2392, -2515, -2638, -2762, -2886, -3011, -3136, -3261, -3388, -3514, -3641, -3769, -3897, -4025, -4155, -4284, -4414, -4545, -4676, -4808, -4940, -5072, -5205, -5339, -5473, -5607, -5742, -5877, -6013, -6149, -6285, -6422, -6559, -6696, -6833, -6971, -7109, -7247, -7386, -7524, -7663, -7802, -7940, -8079, -8218, -8357, -8496, -8635, -8774, -8912, -9051, -9189, -9327, -9465, -9603, -9740, -9878, -10014, -10151, -10287, -10423, -10558, -10693, -10828, -10962, -11095, -11229, -11361, -11493, -11625, -11756, -11886, -12016, -12145, -12274, -12402, -12529, -12656, -12783, -12908, -13033, -13158, -13282, -13405, -13528, -13650, -13771, -13892, -14013, -14133, -14252, -14371, -14489, -14607, -14725, -14842, -14959, -15075, -15191, -15307, -15422, -15537, -15652, -15767, -15882, -15996, -16110, -16225, -16339, -16453, -16567, -16682, -16796, -16911, -17026, -17141, -17256, -17371, -17487, -17603, -17720, -17837, -17954, -18072, -18191, -18310, -18430, -18550, -18671, -18792, -18915, -19038, -19161, -19286, -19411, -19537, -19664, -19792, -19921, -20050, -20181, -20312, -20445, -20578, -20712, -20847, -20983, -21120, -21257, -21396, -21536, -21676, -21817, -21960, -22103, -22246, -22391, -22536, -22682, -22829, -22976, -23124, -23273, -23422, -23571, -23721, -23872, -24023, -24174, -24325, -24476, -24628, -24780, -24931, -25083, -25235, -25386, -25537, -25688, -25838, -25988, -26137, -26285, -26433, -26580, -26726, -26871, -27016, -27159, -27300, -27441, -27580, -27717, -27853, -27988, -28120, -28251, -28380, -28507, -28632, -28755, -28875, -28993, -29109, -29222, -29333, -29441, -29547, -29649, -29749, -29845, -29939, -30030, -30117, -30202, -30283, -30360, -30434, -30505, -30573, -30636, -30696, -30753, -30806, -30855, -30900, -30941, -30979, -31013, -31042, -31068, -31090, -31108, -31122, -31132, -31138, -31140, -31138, -31132, -31122, -31108, -31090, -31068, -31042, -31013, -30979, -30941, -30900, -30855, -30806, -30753, -30696, -30636, -30573, -30505, -30434, -30360, -30283, -30202, -30117, -30030, -29939, -29845, -29749, -29649, -29547, -29441, -29333, -29222, -29109, -28993, -28875, -28755, -28632, -28507, -28380, -28251, -28120, -27988, -27853, -27717, -27580, -27441, -27300, -27159, -27016, -26871, -26726, -26580, -26433, -26285, -26137, -25988, -25838, -25688, -25537, -25386, -25235, -25083, -24931, -24780, -24628, -24476, -24325, -24174, -24023, -23872, -23721, -23571, -23422, -23273, -23124, -22976, -22829, -22682, -22536, -22391, -22246, -22103, -21960, -21817, -21676, -21536, -21396, -21257, -21120, -20983, -20847, -20712, -20578, -20445, -20312, -20181, -20050, -19921, -19792, -19664, -19537, -19411, -19286, -19161, -19038, -18915, -18792, -18671, -18550, -18430, -18310, -18191, -18072, -17954, -17837, -17720, -17603, -17487, -17371, -17256, -17141, -17026, -16911, -16796, -16682, -16567, -16453, -16339, -16225, -16110, -15996, -15882, -15767, -15652, -15537, -15422, -15307, -15191, -15075, -14959, -14842, -14725, -14607, -14489, -14371, -14252, -14133, -14013, -13892, -13771, -13650, -13528, -13405, -13282, -13158, -13033, -12908, -12783, -12656, -12529, -12402, -12274, -12145, -12016, -11886, -11756, -11625, -11493, -11361, -11229, -11095, -10962, -10828, -10693, -10558, -10423, -10287, -10151, -10014, -9878, -9740, -9603, -9465, -9327, -9189, -9051, -8912, -8774, -8635, -8496, -8357, -8218, -8079, -7940, -7802, -7663, -7524, -7386, -7247, -7109, -6971, -6833, -6696, -6559, -6422, -6285, -6149, -6013, -5877, -5742, -5607, -5473, -5339, -5205, -5072, -4940, -4808, -4676, -4545, -4414, -4284, -4155, -4025, -3897, -3769, -3641, -3514, -3388, -3261, -3136, -3011, -2886, -2762, -2638, -2515, -2392, -2270, -2148, -2026, -1905, -1784, -1664, -1544, -1424, -1304, -1185, -1065, -947, -828, -709, -591, -472, -354, -236, -118, ], [0, 109, 217, 326, 435, 544, 653, 762, 871, 980, 1089, 1199, 1308, 1418, 1528, 1638, 1748, 1858, 1969, 2080, 2191, 2302, 2414, 2525, 2637, 2750, 2862, 2975, 3089, 3202, 3316, 3430, 3545, 3660, 3775, 3891, 4007, 4123, 4240, 4358, 4475, 4593, 4712, 4831, 4950, 5070, 5191, 5312, 5433, 5555, 5677, 5800, 5923, 6047, 6171, 6296, 6422, 6547, 6674, 6801, 6928, 7056, 7185, 7314, 7443, 7574, 7704, 7836, 7967, 8100, 8233, 8366, 8500, 8634, 8769, 8905, 9041, 9178, 9315, 9453, 9591, 9730, 9869, 10009, 10149, 10290, 10431, 10573, 10715, 10858, 11002, 11145, 11290, 11434, 11579, 11725, 11871, 12017, 12164, 12311, 12459, 12607, 12756, 12904, 13053, 13203, 13353, 13503, 13653, 13804, 13955, 14107, 14258, 14410, 14562, 14714, 14867, 15019, 15172, 15325, 15478, 15632, 15785, 15939, 16092, 16246, 16400, 16553, 16707, 16861, 17015, 17168, 17322, 17476, 17629, 17783, 17936, 18089, 18242, 18395, 18547, 18700, 18852, 19004, 19155, 19306, 19457, 19608, 19758, 19908, 20057, 20206, 20355, 20503, 20651, 20798, 20944, 21091, 21236, 21381, 21525, 21669, 21812, 21954, 22095, 22236, 22376, 22516, 22654, 22792, 22929, 23065, 23200, 23335, 23468, 23600, 23732, 23862, 23992, 24120, 24248, 24374, 24499, 24623, 24747, 24868, 24989, 25109, 25227, 25344, 25460, 25575, 25688, 25800, 25910, 26020, 26128, 26234, 26339, 26443, 26546, 26646, 26746, 26844, 26940, 27035, 27128, 27220, 27310, 27399, 27486, 27571, 27655, 27737, 27817, 27896, 27973, 28048, 28122, 28193, 28263, 28332, 28398, 28463, 28526, 28587, 28646, 28704, 28759, 28813, 28865, 28915, 28963, 29009, 29054, 29096, 29137, 29175, 29212, 29246, 29279, 29310, 29339, 29366, 29391, 29414, 29434, 29453, 29470, 29485, 29498, 29509, 29518, 29525, 29530, 29533, 29534, 29533, 29530, 29525, 29518, 29509, 29498, 29485, 29470, 29453, 29434, 29414, 29391, 29366, 29339, 29310, 29279, 29246, 29212, 29175, 29137, 29096, 29054, 29009, 28963, 28915, 28865, 28813, 28759, 28704, 28646, 28587, 28526, 28463, 28398, 28332, 28263, 28193, 28122, 28048, 27973, 27896, 27817, 27737, 27655, 27571, 27486, 27399, 27310, 27220, 27128, 27035, 26940, 26844, 26746, 26646, 26546, 26443, 26339, 26234, 26128, 26020, 25910, 25800, 25688, 25575, 25460, 25344, 25227, 25109, 24989, 24868, 24747, 24623, 24499, 24374, 24248, 24120, 23992, 23862, 23732, 23600, 23468, 23335, 23200, 23065, 22929, 22792, 22654, 22516, 22376, 22236, 22095, 21954, 21812, 21669, 21525, 21381, 21236, 21091, 20944, 20798, 20651, 20503, 20355, 20206, 20057, 19908, 19758, 19608, 19457, 19306, 19155, 19004, 18852, 18700, 18547, 18395, 18242, 18089, 17936, 17783, 17629, 17476, 17322, 17168, 17015, 16861, 16707, 16553, 16400, 16246, 16092, 15939, 15785, 15632, 15478, 15325, 15172, 15019, 14867, 14714, 14562, 14410, 14258, 14107, 13955, 13804, 13653, 13503, 13353, 13203, 13053, 12904, 12756, 12607, 12459, 12311, 12164, 12017, 11871, 11725, 11579, 11434, 11290, 11145, 11002, 10858, 10715, 10573, 10431, 10290, 10149, 10009, 9869, 9730, 9591, 9453, 9315, 9178, 9041, 8905, 8769, 8634, 8500, 8366, 8233, 8100, 7967, 7836, 7704, 7574, 7443, 7314, 7185, 7056, 6928, 6801, 6674, 6547, 6422, 6296, 6171, 6047, 5923, 5800, 5677, 5555, 5433, 5312, 5191, 5070, 4950, 4831, 4712, 4593, 4475, 4358, 4240, 4123, 4007, 3891, 3775, 3660, 3545, 3430, 3316, 3202, 3089, 2975, 2862, 2750, 2637, 2525, 2414, 2302, 2191, 2080, 1969, 1858, 1748, 1638, 1528, 1418, 1308, 1199, 1089, 980, 871, 762, 653, 544, 435, 326, 217, 109, 0, -109, -217, -326, -435, -544, -653, -762, -871, -980, -1089, -1199, -1308, -1418, -1528, -1638, -1748, -1858, -1969, -2080, -2191, -2302, -2414, -2525, -2637, -2750, -2862, -2975, -3089, -3202, -3316, -3430, -3545, -3660, -3775, -3891, -4007, -4123, -4240, -4358, -4475, -4593, -4712, -4831, -4950, -5070, -5191, -5312, -5433, -5555, -5677, -5800, -5923, -6047, -6171, -6296, -6422, -6547, -6674, -6801, -6928, -7056, -7185, -7314, -7443, -7574, -7704, -7836, -7967, -8100, -8233, -8366, -8500, -8634, -8769, -8905, -9041, -9178, -9315, -9453, -9591, -9730, -9869, -10009, -10149, -10290, -10431, -10573, -10715, -10858, -11002, -11145, -11290, -11434, -11579, -11725, -11871, -12017, -12164, -12311, -12459, -12607, -12756, -12904, -13053, -13203, -13353, -13503, -13653, -13804, -13955, -14107, -14258, -14410, -14562, -14714, -14867, -15019, -15172, -15325, -15478, -15632, -15785, -15939, -16092, -16246, -16400, -16553, -16707, -16861, -17015, -17168, -17322, -17476, -17629, -17783, -17936, -18089, -18242, -18395, -18547, -18700, -18852, -19004, -19155, -19306, -19457, -19608, -19758, -19908, -20057, -20206, -20355, -20503, -20651, -20798, -20944, -21091, -21236, -21381, -21525, -21669, -21812, -21954, -22095, -22236, -22376, -22516, -22654, -22792, -22929, -23065, -23200, -23335, -23468, -23600, -23732, -23862, -23992, -24120, -24248, -24374, -24499, -24623, -24747, -24868, -24989, -25109, -25227, -25344, -25460, -25575, -25688, -25800, -25910, -26020, -26128, -26234, -26339, -26443, -26546, -26646, -26746, -26844, -26940, -27035, -27128, -27220, -27310, -27399, -27486, -27571, -27655, -27737, -27817, -27896, -27973, -28048, -28122, -28193, -28263, -28332, -28398, -28463, -28526, -28587, -28646, -28704, -28759, -28813, -28865, -28915, -28963, -29009, -29054, -29096, -29137, -29175, -29212, -29246, -29279, -29310, -29339, -29366, -29391, -29414, -29434, -29453, -29470, -29485, -29498, -29509, -29518, -29525, -29530, -29533, -29534, -29533, -29530, -29525, -29518, -29509, -29498, -29485, -29470, -29453, -29434, -29414, -29391, -29366, -29339, -29310, -29279, -29246, -29212, -29175, -29137, -29096, -29054, -29009, -28963, -28915, -28865, -28813, -28759, -28704, -28646, -28587, -28526, -28463, -28398, -28332, -28263, -28193, -28122, -28048, -27973, -27896, -27817, -27737, -27655, -27571, -27486, -27399, -27310, -27220, -27128, -27035, -26940, -26844, -26746, -26646, -26546, -26443, -26339, -26234, -26128, -26020, -25910, -25800, -25688, -25575, -25460, -25344, -25227, -25109, -24989, -24868, -24747, -24623, -24499, -24374, -24248, -24120, -23992, -23862, -23732, -23600, -23468, -23335, -23200, -23065, -22929, -22792, -22654, -22516, -22376, -22236, -22095, -21954, -21812, -21669, -21525, -21381, -21236, -21091, -20944, -20798, -20651, -20503, -20355, -20206, -20057, -19908, -19758, -19608, -19457, -19306, -19155, -19004, -18852, -18700, -18547, -18395, -18242, -18089, -17936, -17783, -17629, -17476, -17322, -17168, -17015, -16861, -16707, -16553, -16400, -16246, -16092, -15939, -15785, -15632, -15478, -15325, -15172, -15019, -14867, -14714, -14562, -14410, -14258, -14107, -13955, -13804, -13653, -13503, -13353, -13203, -13053, -12904, -12756, -12607, -12459, -12311, -12164, -12017, -11871, -11725, -11579, -11434, -11290, -11145, -11002, -10858, -10715, -10573, -10431, -10290, -10149, -10009, -9869, -9730, -9591, -9453, -9315, -9178, -9041, -8905, -8769, -8634, -8500, -8366, -8233, -8100, -7967, -7836, -7704, -7574, -7443, -7314, -7185, -7056, -6928, -6801, -6674, -6547, -6422, -6296, -6171, -6047, -5923, -5800, -5677, -5555, -5433, -5312, -5191, -5070, -4950, -4831, -4712, -4593, -4475, -4358, -4240, -4123, -4007, -3891, -3775, -3660, -3545, -3430, -3316, -3202, -3089, -2975, -2862, -2750, -2637, -2525, -2414, -2302, -2191, -2080, -1969, -1858, -1748, -1638, -1528, -1418, -1308, -1199, -1089, -980, -871, -762, -653, -544, -435, -326, -217, -109, ], [0, 163, 326, 489, 652, 815, 978, 1141, 1304, 1467, 1630, 1793, 1955, 2118, 2281, 2443, 2605, 2768, 2930, 3092, 3254, 3416, 3577, 3739, 3900, 4061, 4223, 4384, 4544, 4705, 4865, 5026, 5186, 5346, 5505, 5665, 5824, 5983, 6142, 6300, 6459, 6617, 6775, 6932, 7089, 7247, 7403, 7560, 7716, 7872, 8028, 8183, 8338, 8493, 8647, 8801, 8955, 9108, 9261, 9414, 9566, 9718, 9870, 10021, 10172, 10323, 10473, 10622, 10772, 10921, 11069, 11217, 11365, 11512, 11659, 11805, 11951, 12097, 12242, 12386, 12530, 12674, 12817, 12960, 13102, 13243, 13385, 13525, 13665, 13805, 13944, 14083, 14221, 14358, 14495, 14632, 14768, 14903, 15038, 15172, 15306, 15439, 15571, 15703, 15834, 15965, 16095, 16225, 16353, 16482, 16609, 16736, 16863, 16989, 17114, 17238, 17362, 17485, 17608, 17730, 17851, 17971, 18091, 18210, 18329, 18446, 18564, 18680, 18796, 18911, 19025, 19138, 19251, 19363, 19475, 19585, 19695, 19804, 19913, 20020, 20127, 20233, 20339, 20444, 20547, 20650, 20753, 20854, 20955, 21055, 21154, 21252, 21350, 21447, 21543, 21638, 21732, 21826, 21918, 22010, 22101, 22191, 22281, 22369, 22457, 22544, 22630, 22715, 22799, 22883, 22965, 23047, 23128, 23208, 23287, 23365, 23442, 23519, 23594, 23669, 23743, 23816, 23888, 23959, 24029, 24098, 24167, 24234, 24301, 24366, 24431, 24495, 24558, 24620, 24681, 24741, 24800, 24858, 24915, 24972, 25027, 25082, 25135, 25188, 25239, 25290, 25340, 25389, 25436, 25483, 25529, 25574, 25618, 25661, 25703, 25744, 25784, 25824, 25862, 25899, 25935, 25970, 26005, 26038, 26070, 26102, 26132, 26161, 26190, 26217, 26243, 26269, 26293, 26317, 26339, 26361, 26381, 26401, 26419, 26436, 26453, 26468, 26483, 26496, 26509, 26520, 26531, 26540, 26549, 26556, 26563, 26568, 26573, 26576, 26579, 26580, 26581, 26580, 26579, 26576, 26573, 26568, 26563, 26556, 26549, 26540, 26531, 26520, 26509, 26496, 26483, 26468, 26453, 26436, 26419, 26401, 26381, 26361, 26339, 26317, 26293, 26269, 26243, 26217, 26190, 26161, 26132, 26102, 26070, 26038, 26005, 25970, 25935, 25899, 25862, 25824, 25784, 25744, 25703, 25661, 25618, 25574, 25529, 25483, 25436, 25389, 25340, 25290, 25239, 25188, 25135, 25082, 25027, 24972, 24915, 24858, 24800, 24741, 24681, 24620, 24558, 24495, 24431, 24366, 24301, 24234, 24167, 24098, 24029, 23959, 23888, 23816, 23743, 23669, 23594, 23519, 23442, 23365, 23287, 23208, 23128, 23047, 22965, 22883, 22799, 22715, 22630, 22544, 22457, 22369, 22281, 22191, 22101, 22010, 21918, 21826, 21732, 21638, 21543, 21447, 21350, 21252, 21154, 21055, 20955, 20854, 20753, 20650, 20547, 20444, 20339, 20233, 20127, 20020, 19913, 19804, 19695, 19585, 19475, 19363, 19251, 19138, 19025, 18911, 18796, 18680, 18564, 18446, 18329, 18210, 18091, 17971, 17851, 17730, 17608, 17485, 17362, 17238, 17114, 16989, 16863, 16736, 16609, 16482, 16353, 16225, 16095, 15965, 15834, 15703, 15571, 15439, 15306, 15172, 15038, 14903, 14768, 14632, 14495, 14358, 14221, 14083, 13944, 13805, 13665, 13525, 13385, 13243, 13102, 12960, 12817, 12674, 12530, 12386, 12242, 12097, 11951, 11805, 11659, 11512, 11365, 11217, 11069, 10921, 10772, 10622, 10473, 10323, 10172, 10021, 9870, 9718, 9566, 9414, 9261, 9108, 8955, 8801, 8647, 8493, 8338, 8183, 8028, 7872, 7716, 7560, 7403, 7247, 7089, 6932, 6775, 6617, 6459, 6300, 6142, 5983, 5824, 5665, 5505, 5346, 5186, 5026, 4865, 4705, 4544, 4384, 4223, 4061, 3900, 3739, 3577, 3416, 3254, 3092, 2930, 2768, 2605, 2443, 2281, 2118, 1955, 1793, 1630, 1467, 1304, 1141, 978, 815, 652, 489, 326, 163, 0, -163, -326, -489, -652, -815, -978, -1141, -1304, -1467, -1630, -1793, -1955, -2118, -2281, -2443, -2605, -2768, -2930, -3092, -3254, -3416, -3577, -3739, -3900, -4061, -4223, -4384, -4544, -4705, -4865, -5026, -5186, -5346, -5505, -5665, -5824, -5983, -6142, -6300, -6459, -6617, -6775, -6932, -7089, -7247, -7403, -7560, -7716, -7872, -8028, -8183, -8338, -8493, -8647, -8801, -8955, -9108, -9261, -9414, -9566, -9718, -9870, -10021, -10172, -10323, -10473, -10622, -10772, -10921, -11069, -11217, -11365, -11512, -11659, -11805, -11951, -12097, -12242, -12386, -12530, -12674, -12817, -12960, -13102, -13243, -13385, -13525, -13665, -13805, -13944, -14083, -14221, -14358, -14495, -14632, -14768, -14903, -15038, -15172, -15306, -15439, -15571, -15703, -15834, -15965, -16095, -16225, -16353, -16482, -16609, -16736, -16863, -16989, -17114, -17238, -17362, -17485, -17608, -17730, -17851, -17971, -18091, -18210, -18329, -18446, -18564, -18680, -18796, -18911, -19025, -19138, -19251, -19363, -19475, -19585, -19695, -19804, -19913, -20020, -20127, -20233, -20339, -20444, -20547, -20650, -20753, -20854, -20955, -21055, -21154, -21252, -21350, -21447, -21543, -21638, -21732, -21826, -21918, -22010, -22101, -22191, -22281, -22369, -22457, -22544, -22630, -22715, -22799, -22883, -22965, -23047, -23128, -23208, -23287, -23365, -23442, -23519, -23594, -23669, -23743, -23816, -23888, -23959, -24029, -24098, -24167, -24234, -24301, -24366, -24431, -24495, -24558, -24620, -24681, -24741, -24800, -24858, -24915, -24972, -25027, -25082, -25135, -25188, -25239, -25290, -25340, -25389, -25436, -25483, -25529, -25574, -25618, -25661, -25703, -25744, -25784, -25824, -25862, -25899, -25935, -25970, -26005, -26038, -26070, -26102, -26132, -26161, -26190, -26217, -26243, -26269, -26293, -26317, -26339, -26361, -26381, -26401, -26419, -26436, -26453, -26468, -26483, -26496, -26509, -26520, -26531, -26540, -26549, -26556, -26563, -26568, -26573, -26576, -26579, -26580, -26581, -26580, -26579, -26576, -26573, -26568, -26563, -26556, -26549, -26540, -26531, -26520, -26509, -26496, -26483, -26468, -26453, -26436, -26419, -26401, -26381, -26361, -26339, -26317, -26293, -26269, -26243, -26217, -26190, -26161, -26132, -26102, -26070, -26038, -26005, -25970, -25935, -25899, -25862, -25824, -25784, -25744, -25703, -25661, -25618, -25574, -25529, -25483, -25436, -25389, -25340, -25290, -25239, -25188, -25135, -25082, -25027, -24972, -24915, -24858, -24800, -24741, -24681, -24620, -24558, -24495, -24431, -24366, -24301, -24234, -24167, -24098, -24029, -23959, -23888, -23816, -23743, -23669, -23594, -23519, -23442, -23365, -23287, -23208, -23128, -23047, -22965, -22883, -22799, -22715, -22630, -22544, -22457, -22369, -22281, -22191, -22101, -22010, -21918, -21826, -21732, -21638, -21543, -21447, -21350, -21252, -21154, -21055, -20955, -20854, -20753, -20650, -20547, -20444, -20339, -20233, -20127, -20020, -19913, -19804, -19695, -19585, -19475, -19363, -19251, -19138, -19025, -18911, -18796, -18680, -18564, -18446, -18329, -18210, -18091, -17971, -17851, -17730, -17608, -17485, -17362, -17238, -17114, -16989, -16863, -16736, -16609, -16482, -16353, -16225, -16095, -15965, -15834, -15703, -15571, -15439, -15306, -15172, -15038, -14903, -14768, -14632, -14495, -14358, -14221, -14083, -13944, -13805, -13665, -13525, -13385, -13243, -13102, -12960, -12817, -12674, -12530, -12386, -12242, -12097, -11951, -11805, -11659, -11512, -11365, -11217, -11069, -10921, -10772, -10622, -10473, -10323, -10172, -10021, -9870, -9718, -9566, -9414, -9261, -9108, -8955, -8801, -8647, -8493, -8338, -8183, -8028, -7872, -7716, -7560, -7403, -7247, -7089, -6932, -6775, -6617, -6459, -6300, -6142, -5983, -5824, -5665, -5505, -5346, -5186, -5026, -4865, -4705, -4544, -4384, -4223, -4061, -3900, -3739, -3577, -3416, -3254, -3092, -2930, -2768, -2605, -2443, -2281, -2118, -1955, -1793, -1630, -1467, -1304, -1141, -978, -815, -652, -489, -326, -163, ], [0, 163, 326, 489, 652, 815, 978, 1141, 1304, 1467, 1630, 1793, 1955, 2118, 2281, 2443, 2605, 2768, 2930, 3092, 3254, 3416, 3577, 3739, 3900, 4061, 4223, 4384, 4544, 4705, 4865, 5026, 5186, 5346, 5505, 5665, 5824, 5983, 6142, 6300, 6459, 6617, 6775, 6932, 7089, 7247, 7403, 7560, 7716, 7872, 8028, 8183, 8338, 8493, 8647, 8801, 8955, 9108, 9261, 9414, 9566, 9718, 9870, 10021, 10172, 10323, 10473, 10622, 10772, 10921, 11069, 11217, 11365, 11512, 11659, 11805, 11951, 12097, 12242, 12386, 12530, 12674, 12817, 12960, 13102, 13243, 13385, 13525, 13665, 13805, 13944, 14083, 14221, 14358, 14495, 14632, 14768, 14903, 15038, 15172, 15306, 15439, 15571, 15703, 15834, 15965, 16095, 16225, 16353, 16482, 16609, 16736, 16863, 16989, 17114, 17238, 17362, 17485, 17608, 17730, 17851, 17971, 18091, 18210, 18329, 18446, 18564, 18680, 18796, 18911, 19025, 19138, 19251, 19363, 19475, 19585, 19695, 19804, 19913, 20020, 20127, 20233, 20339, 20444, 20547, 20650, 20753, 20854, 20955, 21055, 21154, 21252, 21350, 21447, 21543, 21638, 21732, 21826, 21918, 22010, 22101, 22191, 22281, 22369, 22457, 22544, 22630, 22715, 22799, 22883, 22965, 23047, 23128, 23208, 23287, 23365, 23442, 23519, 23594, 23669, 23743, 23816, 23888, 23959, 24029, 24098, 24167, 24234, 24301, 24366, 24431, 24495, 24558, 24620, 24681, 24741, 24800, 24858, 24915, 24972, 25027, 25082, 25135, 25188, 25239, 25290, 25340, 25389, 25436, 25483, 25529, 25574, 25618, 25661, 25703, 25744, 25784, 25824, 25862, 25899, 25935, 25970, 26005, 26038, 26070, 26102, 26132, 26161, 26190, 26217, 26243, 26269, 26293, 26317, 26339, 26361, 26381, 26401, 26419, 26436, 26453, 26468, 26483, 26496, 26509, 26520, 26531, 26540, 26549, 26556, 26563, 26568, 26573, 26576, 26579, 26580, 26581, 26580, 26579, 26576, 26573, 26568, 26563, 26556, 26549, 26540, 26531, 26520, 26509, 26496, 26483, 26468, 26453, 26436, 26419, 26401, 26381, 26361, 26339, 26317, 26293, 26269, 26243, 26217, 26190, 26161, 26132, 26102, 26070, 26038, 26005, 25970, 25935, 25899, 25862, 25824, 25784, 25744, 25703, 25661, 25618, 25574, 25529, 25483, 25436, 25389, 25340, 25290, 25239, 25188, 25135, 25082, 25027, 24972, 24915, 24858, 24800, 24741, 24681, 24620, 24558, 24495, 24431, 24366, 24301, 24234, 24167, 24098, 24029, 23959, 23888, 23816, 23743, 23669, 23594, 23519, 23442, 23365, 23287, 23208, 23128, 23047, 22965, 22883, 22799, 22715, 22630, 22544, 22457, 22369, 22281, 22191, 22101, 22010, 21918, 21826, 21732, 21638, 21543, 21447, 21350, 21252, 21154, 21055, 20955, 20854, 20753, 20650, 20547, 20444, 20339, 20233, 20127, 20020, 19913, 19804, 19695, 19585, 19475, 19363, 19251, 19138, 19025, 18911, 18796, 18680, 18564, 18446, 18329, 18210, 18091, 17971, 17851, 17730, 17608, 17485, 17362, 17238, 17114, 16989, 16863, 16736, 16609, 16482, 16353, 16225, 16095, 15965, 15834, 15703, 15571, 15439, 15306, 15172, 15038, 14903, 14768, 14632, 14495, 14358, 14221, 14083, 13944, 13805, 13665, 13525, 13385, 13243, 13102, 12960, 12817, 12674, 12530, 12386, 12242, 12097, 11951, 11805, 11659, 11512, 11365, 11217, 11069, 10921, 10772, 10622, 10473, 10323, 10172, 10021, 9870, 9718, 9566, 9414, 9261, 9108, 8955, 8801, 8647, 8493, 8338, 8183, 8028, 7872, 7716, 7560, 7403, 7247, 7089, 6932, 6775, 6617, 6459, 6300, 6142, 5983, 5824, 5665, 5505, 5346, 5186, 5026, 4865, 4705, 4544, 4384, 4223, 4061, 3900, 3739, 3577, 3416, 3254, 3092, 2930, 2768, 2605, 2443, 2281, 2118, 1955, 1793, 1630, 1467, 1304, 1141, 978, 815, 652, 489, 326, 163, 0, -163, -326, -489, -652, -815, -978, -1141, -1304, -1467, -1630, -1793, -1955, -2118, -2281, -2443, -2605, -2768, -2930, -3092, -3254, -3416, -3577, -3739, -3900, -4061, -4223, -4384, -4544, -4705, -4865, -5026, -5186, -5346, -5505, -5665, -5824, -5983, -6142, -6300, -6459, -6617, -6775, -6932, -7089, -7247, -7403, -7560, -7716, -7872, -8028, -8183, -8338, -8493, -8647, -8801, -8955, -9108, -9261, -9414, -9566, -9718, -9870, -10021, -10172, -10323, -10473, -10622, -10772, -10921, -11069, -11217, -11365, -11512, -11659, -11805, -11951, -12097, -12242, -12386, -12530, -12674, -12817, -12960, -13102, -13243, -13385, -13525, -13665, -13805, -13944, -14083, -14221, -14358, -14495, -14632, -14768, -14903, -15038, -15172, -15306, -15439, -15571, -15703, -15834, -15965, -16095, -16225, -16353, -16482, -16609, -16736, -16863, -16989, -17114, -17238, -17362, -17485, -17608, -17730, -17851, -17971, -18091, -18210, -18329, -18446, -18564, -18680, -18796, -18911, -19025, -19138, -19251, -19363, -19475, -19585, -19695, -19804, -19913, -20020, -20127, -20233, -20339, -20444, -20547, -20650, -20753, -20854, -20955, -21055, -21154, -21252, -21350, -21447, -21543, -21638, -21732, -21826, -21918, -22010, -22101, -22191, -22281, -22369, -22457, -22544, -22630, -22715, -22799, -22883, -22965, -23047, -23128, -23208, -23287, -23365, -23442, -23519, -23594, -23669, -23743, -23816, -23888, -23959, -24029, -24098, -24167, -24234, -24301, -24366, -24431, -24495, -24558, -24620, -24681, -24741, -24800, -24858, -24915, -24972, -25027, -25082, -25135, -25188, -25239, -25290, -25340, -25389, -25436, -25483, -25529, -25574, -25618, -25661, -25703, -25744, -25784, -25824, -25862, -25899, -25935, -25970, -26005, -26038, -26070, -26102, -26132, -26161, -26190, -26217, -26243, -26269, -26293, -26317, -26339, -26361, -26381, -26401, -26419, -26436, -26453, -26468, -26483, -26496, -26509, -26520, -26531, -26540, -26549, -26556, -26563, -26568, -26573, -26576, -26579, -26580, -26581, -26580, -26579, -26576, -26573, -26568, -26563, -26556, -26549, -26540, -26531, -26520, -26509, -26496, -26483, -26468, -26453, -26436, -26419, -26401, -26381, -26361, -26339, -26317, -26293, -26269, -26243, -26217, -26190, -26161, -26132, -26102, -26070, -26038, -26005, -25970, -25935, -25899, -25862, -25824, -25784, -25744, -25703, -25661, -25618, -25574, -25529, -25483, -25436, -25389, -25340, -25290, -25239, -25188, -25135, -25082, -25027, -24972, -24915, -24858, -24800, -24741, -24681, -24620, -24558, -24495, -24431, -24366, -24301, -24234, -24167, -24098, -24029, -23959, -23888, -23816, -23743, -23669, -23594, -23519, -23442, -23365, -23287, -23208, -23128, -23047, -22965, -22883, -22799, -22715, -22630, -22544, -22457, -22369, -22281, -22191, -22101, -22010, -21918, -21826, -21732, -21638, -21543, -21447, -21350, -21252, -21154, -21055, -20955, -20854, -20753, -20650, -20547, -20444, -20339, -20233, -20127, -20020, -19913, -19804, -19695, -19585, -19475, -19363, -19251, -19138, -19025, -18911, -18796, -18680, -18564, -18446, -18329, -18210, -18091, -17971, -17851, -17730, -17608, -17485, -17362, -17238, -17114, -16989, -16863, -16736, -16609, -16482, -16353, -16225, -16095, -15965, -15834, -15703, -15571, -15439, -15306, -15172, -15038, -14903, -14768, -14632, -14495, -14358, -14221, -14083, -13944, -13805, -13665, -13525, -13385, -13243, -13102, -12960, -12817, -12674, -12530, -12386, -12242, -12097, -11951, -11805, -11659, -11512, -11365, -11217, -11069, -10921, -10772, -10622, -10473, -10323, -10172, -10021, -9870, -9718, -9566, -9414, -9261, -9108, -8955, -8801, -8647, -8493, -8338, -8183, -8028, -7872, -7716, -7560, -7403, -7247, -7089, -6932, -6775, -6617, -6459, -6300, -6142, -5983, -5824, -5665, -5505, -5346, -5186, -5026, -4865, -4705, -4544, -4384, -4223, -4061, -3900, -3739, -3577, -3416, -3254, -3092, -2930, -2768, -2605, -2443, -2281, -2118, -1955, -1793, -1630, -1467, -1304, -1141, -978, -815, -652, -489, -326, -163, ], ];
@@ -0,0 +1 @@
+pub static TRIANGLE_I32: [[i32; 1024]; 10] = [[0, 8395254, 16790507, 25185761, 33581013, 41976268, 50371520, 58766775, 67162027, 75557282, 83952534, 92347789, 100743040, 109138296, 117533547, 125928803, 134324054, 142719310, 151114560, 159509817, 167905067, 176300324, 184695574, 193090831, 201486080, 209881338, 218276587, 226671845, 235067094, 243462352, 251857601, 260252859, 268648107, 277043366, 285438614, 293833873, 302229121, 310624380, 319019627, 327414887, 335810134, 344205394, 352600641, 360995901, 369391147, 377786408, 386181654, 394576915, 402972161, 411367422, 419762667, 428157930, 436553174, 444948437, 453343681, 461738944, 470134187, 478529451, 486924694, 495319958, 503715201, 512110465, 520505707, 528900972, 537296214, 545691479, 554086721, 562481986, 570877227, 579272493, 587667734, 596063000, 604458240, 612853508, 621248747, 629644015, 638039254, 646434522, 654829760, 663225029, 671620267, 680015536, 688410773, 696806043, 705201280, 713596550, 721991786, 730387058, 738782293, 747177565, 755572800, 763968072, 772363306, 780758579, 789153813, 797549086, 805944319, 814339594, 822734826, 831130101, 839525332, 847920608, 856315839, 864711115, 873106345, 881501623, 889896851, 898292130, 906687358, 915082637, 923477864, 931873145, 940268371, 948663652, 957058877, 965454159, 973849383, 982244667, 990639890, 999035174, 1007430396, 1015825682, 1024220902, 1032616189, 1041011409, 1049406697, 1057801915, 1066197204, 1074592421, 1082987712, 1091382927, 1099778219, 1108173433, 1116568727, 1124963940, 1133359234, 1141754446, 1150149742, 1158544952, 1166940250, 1175335458, 1183730758, 1192125964, 1200521265, 1208916469, 1217311773, 1225706975, 1234102281, 1242497481, 1250892789, 1259287987, 1267683297, 1276078492, 1284473805, 1292868998, 1301264313, 1309659504, 1318054822, 1326450009, 1334845330, 1343240514, 1351635838, 1360031020, 1368426347, 1376821525, 1385216856, 1393612030, 1402007364, 1410402535, 1418797873, 1427193039, 1435588382, 1443983544, 1452378892, 1460774048, 1469169401, 1477564553, 1485959911, 1494355057, 1502750420, 1511145561, 1519540930, 1527936064, 1536331441, 1544726568, 1553121951, 1561517071, 1569912462, 1578307573, 1586702973, 1595098075, 1603493485, 1611888577, 1620283997, 1628679079, 1637074510, 1645469579, 1653865023, 1662260080, 1670655537, 1679050579, 1687446052, 1695841078, 1704236567, 1712631575, 1721027084, 1729422072, 1737817602, 1746212567, 1754608121, 1763003060, 1771398642, 1779793552, 1788189165, 1796584042, 1804979691, 1813374528, 1821770219, 1830165012, 1838560751, 1846955492, 1855351288, 1863745966, 1872141830, 1880536435, 1888932379, 1897326896, 1905722936, 1914117346, 1922513506, 1930907783, 1939304091, 1947698201, 1956094698, 1964488595, 1972885334, 1981278953, 1989676013, 1998069259, 2006466756, 2014859486, 2023257599, 2031649585, 2040048608, 2048439466, 2056839910, 2065228940, 2073631790, 2082017565, 2090424973, 2098804084, 2107221796, 2115583721, 2124033394, 2132324786, 2140988277, 2147483647, 2140988277, 2132324786, 2124033394, 2115583721, 2107221796, 2098804084, 2090424973, 2082017565, 2073631790, 2065228940, 2056839910, 2048439466, 2040048608, 2031649585, 2023257599, 2014859486, 2006466756, 1998069259, 1989676013, 1981278953, 1972885334, 1964488595, 1956094698, 1947698201, 1939304091, 1930907783, 1922513506, 1914117346, 1905722936, 1897326896, 1888932379, 1880536435, 1872141830, 1863745966, 1855351288, 1846955492, 1838560751, 1830165012, 1821770219, 1813374528, 1804979691, 1796584042, 1788189165, 1779793552, 1771398642, 1763003060, 1754608121, 1746212567, 1737817602, 1729422072, 1721027084, 1712631575, 1704236567, 1695841078, 1687446052, 1679050579, 1670655537, 1662260080, 1653865023, 1645469579, 1637074510, 1628679079, 1620283997, 1611888577, 1603493485, 1595098075, 1586702973, 1578307573, 1569912462, 1561517071, 1553121951, 1544726568, 1536331441, 1527936064, 1519540930, 1511145561, 1502750420, 1494355057, 1485959911, 1477564553, 1469169401, 1460774048, 1452378892, 1443983544, 1435588382, 1427193039, 1418797873, 1410402535, 1402007364, 1393612030, 1385216856, 1376821525, 1368426347, 1360031020, 1351635838, 1343240514, 1334845330, 1326450009, 1318054822, 1309659504, 1301264313, 1292868998, 1284473805, 1276078492, 1267683297, 1259287987, 1250892789, 1242497481, 1234102281, 1225706975, 1217311773, 1208916469, 1200521265, 1192125964, 1183730758, 1175335458, 1166940250, 1158544952, 1150149742, 1141754446, 1133359234, 1124963940, 1116568727, 1108173433, 1099778219, 1091382927, 1082987712, 1074592421, 1066197204, 1057801915, 1049406697, 1041011409, 1032616189, 1024220902, 1015825682, 1007430396, 999035174, 990639890, 982244667, 973849383, 965454159, 957058877, 948663652, 940268371, 931873145, 923477864, 915082637, 906687358, 898292130, 889896851, 881501623, 873106345, 864711115, 856315839, 847920608, 839525332, 831130101, 822734826, 814339594, 805944319, 797549086, 789153813, 780758579, 772363306, 763968072, 755572800, 747177565, 738782293, 730387058, 721991786, 713596550, 705201280, 696806043, 688410773, 680015536, 671620267, 663225029, 654829760, 646434522, 638039254, 629644015, 621248747, 612853508, 604458240, 596063000, 587667734, 579272493, 570877227, 562481986, 554086721, 545691479, 537296214, 528900972, 520505707, 512110465, 503715201, 495319958, 486924694, 478529451, 470134187, 461738944, 453343681, 444948437, 436553174, 428157930, 419762667, 411367422, 402972161, 394576915, 386181654, 377786408, 369391147, 360995901, 352600641, 344205394, 335810134, 327414887, 319019627, 310624380, 302229121, 293833873, 285438614, 277043366, 268648107, 260252859, 251857601, 243462352, 235067094, 226671845, 218276587, 209881338, 201486080, 193090831, 184695574, 176300324, 167905067, 159509817, 151114560, 142719310, 134324054, 125928803, 117533547, 109138296, 100743040, 92347789, 83952534, 75557282, 67162027, 58766775, 50371520, 41976268, 33581013, 25185761, 16790507, 8395254, 0, -8395254, -16790507, -25185761, -33581013, -41976268, -50371520, -58766775, -67162027, -75557282, -83952534, -92347789, -100743040, -109138296, -117533547, -125928803, -134324054, -142719310, -151114560, -159509817, -167905067, -176300324, -184695574, -193090831, -201486080, -209881338, -218276587, -226671845, -235067094, -243462352, -251857601, -260252859, -268648107, -277043366, -285438614, -293833873, -302229121, -310624380, -319019627, -327414887, -335810134, -344205394, -352600641, -360995901, -369391147, -377786408, -386181654, -394576915, -402972161, -411367422, -419762667, -428157930, -436553174, -444948437, -453343681, -461738944, -470134187, -478529451, -486924694, -495319958, -503715201, -512110465, -520505707, -528900972, -537296214, -545691479, -554086721, -562481986, -570877227, -579272493, -587667734, -596063000, -604458240, -612853508, -621248747, -629644015, -638039254, -646434522, -654829760, -663225029, -671620267, -680015536, -688410773, -696806043, -705201280, -713596550, -721991786, -730387058, -738782293, -747177565, -755572800, -763968072, -772363306, -780758579, -789153813, -797549086, -805944319, -814339594, -822734826, -831130101, -839525332, -847920608, -856315839, -864711115, -873106345, -881501623, -889896851, -898292130, -906687358, -915082637, -923477864, -931873145, -940268371, -948663652, -957058877, -965454159, -973849383, -982244667, -990639890, -999035174, -1007430396, -1015825682, -1024220902, -1032616189, -1041011409, -1049406697, -1057801915, -1066197204, -1074592421, -1082987712, -1091382927, -1099778219, -1108173433, -1116568727, -1124963940, -1133359234, -1141754446, -1150149742, -1158544952, -1166940250, -1175335458, -1183730758, -1192125964, -1200521265, -1208916469, -1217311773, -1225706975, -1234102281, -1242497481, -1250892789, -1259287987, -1267683297, -1276078492, -1284473805, -1292868998, -1301264313, -1309659504, -1318054822, -1326450009, -1334845330, -1343240514, -1351635838, -1360031020, -1368426347, -1376821525, -1385216856, -1393612030, -1402007364, -1410402535, -1418797873, -1427193039, -1435588382, -1443983544, -1452378892, -1460774048, -1469169401, -1477564553, -1485959911, -1494355057, -1502750420, -1511145561, -1519540930, -1527936064, -1536331441, -1544726568, -1553121951, -1561517071, -1569912462, -1578307573, -1586702973, -1595098075, -1603493485, -1611888577, -1620283997, -1628679079, -1637074510, -1645469579, -1653865023, -1662260080, -1670655537, -1679050579, -1687446052, -1695841078, -1704236567, -1712631575, -1721027084, -1729422072, -1737817602, -1746212567, -1754608121, -1763003060, -1771398642, -1779793552, -1788189165, -1796584042, -1804979691, -1813374528, -1821770219, -1830165012, -1838560751, -1846955492, -1855351288, -1863745966, -1872141830, -1880536435, -1888932379, -1897326896, -1905722936, -1914117346, -1922513506, -1930907783, -1939304091, -1947698201, -1956094698, -1964488595, -1972885334, -1981278953, -1989676013, -1998069259, -2006466756, -2014859486, -2023257599, -2031649585, -2040048608, -2048439466, -2056839910, -2065228940, -2073631790, -2082017565, -2090424973, -2098804084, -2107221796, -2115583721, -2124033394, -2132324786, -2140988277, -2147483647, -2140988277, -2132324786, -2124033394, -2115583721, -2107221796, -2098804084, -2090424973, -2082017565, -2073631790, -2065228940, -2056839910, -2048439466, -2040048608, -2031649585, -2023257599, -2014859486, -2006466756, -1998069259, -1989676013, -1981278953, -1972885334, -1964488595, -1956094698, -1947698201, -1939304091, -1930907783, -1922513506, -1914117346, -1905722936, -1897326896, -1888932379, -1880536435, -1872141830, -1863745966, -1855351288, -1846955492, -1838560751, -1830165012, -1821770219, -1813374528, -1804979691, -1796584042, -1788189165, -1779793552, -1771398642, -1763003060, -1754608121, -1746212567, -1737817602, -1729422072, -1721027084, -1712631575, -1704236567, -1695841078, -1687446052, -1679050579, -1670655537, -1662260080, -1653865023, -1645469579, -1637074510, -1628679079, -1620283997, -1611888577, -1603493485, -1595098075, -1586702973, -1578307573, -1569912462, -1561517071, -1553121951, -1544726568, -1536331441, -1527936064, -1519540930, -1511145561, -1502750420, -1494355057, -1485959911, -1477564553, -1469169401, -1460774048, -1452378892, -1443983544, -1435588382, -1427193039, -1418797873, -1410402535, -1402007364, -1393612030, -1385216856, -1376821525, -1368426347, -1360031020, -1351635838, -1343240514, -1334845330, -1326450009, -1318054822, -1309659504, -1301264313, -1292868998, -1284473805, -1276078492, -1267683297, -1259287987, -1250892789, -1242497481, -1234102281, -1225706975, -1217311773, -1208916469, -1200521265, -1192125964, -1183730758, -1175335458, -1166940250, -1158544952, -1150149742, -1141754446, -1133359234, -1124963940, -1116568727, -1108173433, -1099778219, -1091382927, -1082987712, -1074592421, -1066197204, -1057801915, -1049406697, -1041011409, -1032616189, -1024220902, -1015825682, -1007430396, -999035174, -990639890, -982244667, -973849383, -965454159, -957058877, -948663652, -940268371, -931873145, -923477864, -915082637, -906687358, -898292130, -889896851, -881501623, -873106345, -864711115, -856315839, -847920608, -839525332, -831130101, -822734826, -814339594, -805944319, -797549086, -789153813, -780758579, -772363306, -763968072, -755572800, -747177565, -738782293, -730387058, -721991786, -713596550, -705201280, -696806043, -688410773, -680015536, -671620267, -663225029, -654829760, -646434522, -638039254, -629644015, -621248747, -612853508, -604458240, -596063000, -587667734, -579272493, -570877227, -562481986, -554086721, -545691479, -537296214, -528900972, -520505707, -512110465, -503715201, -495319958, -486924694, -478529451, -470134187, -461738944, -453343681, -444948437, -436553174, -428157930, -419762667, -411367422, -402972161, -394576915, -386181654, -377786408, -369391147, -360995901, -352600641, -344205394, -335810134, -327414887, -319019627, -310624380, -302229121, -293833873, -285438614, -277043366, -268648107, -260252859, -251857601, -243462352, -235067094, -226671845, -218276587, -209881338, -201486080, -193090831, -184695574, -176300324, -167905067, -159509817, -151114560, -142719310, -134324054, -125928803, -117533547, -109138296, -100743040, -92347789, -83952534, -75557282, -67162027, -58766775, -50371520, -41976268, -33581013, -25185761, -16790507, -8395254, ], [0, 8381963, 16790508, 25199053, 33581011, 41962971, 50371524, 58780077, 67162022, 75543970, 83952541, 92361108, 100743034, 109124962, 117533557, 125942148, 134324045, 142705945, 151114573, 159523196, 167905056, 176286921, 184695590, 193104253, 201486067, 209867888, 218276606, 226685317, 235067078, 243448846, 251857623, 260266391, 268648089, 277029796, 285438639, 293847473, 302229100, 310610737, 319019656, 327428564, 335810111, 344191669, 352600672, 361009664, 369391121, 377772591, 386181689, 394590774, 402972132, 411353504, 419762706, 428171894, 436553142, 444934407, 453343723, 461753023, 470134153, 478515299, 486924740, 495334164, 503715163, 512096181, 520505758, 528915315, 537296173, 545677051, 554086775, 562496478, 570877183, 579257910, 587667793, 596077653, 604458193, 612838756, 621248810, 629658841, 638039202, 646419589, 654829828, 663240042, 671620212, 680000409, 688410847, 696821257, 705201221, 713581214, 721991865, 730402486, 738782230, 747162004, 755572884, 763983732, 772363238, 780742777, 789153903, 797564995, 805944246, 814323533, 822734923, 831146275, 839525254, 847904271, 856315943, 864727575, 873106261, 881484989, 889896963, 898308895, 906687268, 915065685, 923477984, 931890237, 940268274, 948646359, 957059006, 965471603, 973849280, 982227008, 990640028, 999052995, 1007430285, 1015807629, 1024221050, 1032634415, 1041011289, 1049388222, 1057802074, 1066215865, 1074592293, 1082968783, 1091383099, 1099797349, 1108173295, 1116549309, 1124964124, 1133378869, 1141754296, 1150129797, 1158545151, 1166960429, 1175335296, 1183710243, 1192126179, 1200542033, 1208916295, 1217290643, 1225707209, 1234123685, 1242497292, 1250870991, 1259288240, 1267705393, 1276078287, 1284451282, 1292869274, 1301287160, 1309659280, 1318031509, 1326450310, 1334868996, 1343240270, 1351611664, 1360031349, 1368450908, 1376821257, 1385191737, 1393612391, 1402032907, 1410402240, 1418771718, 1427193437, 1435615005, 1443983219, 1452351593, 1460774488, 1469197216, 1477564192, 1485931346, 1494355545, 1502779558, 1511145160, 1519510958, 1527936609, 1536362053, 1544726119, 1553090405, 1561517682, 1569944726, 1578307068, 1586669658, 1595098766, 1603527611, 1611888005, 1620248680, 1628679864, 1637110749, 1645468926, 1653827424, 1662260980, 1670694192, 1679049827, 1687405831, 1695842120, 1704278010, 1712630700, 1720983820, 1729423290, 1737862295, 1746211537, 1754561286, 1763004504, 1771447169, 1779792325, 1788138084, 1796585776, 1805032805, 1813373041, 1821714009, 1830167134, 1838619447, 1846953653, 1855288765, 1863748620, 1872207459, 1880534106, 1888861901, 1897330306, 1905797403, 1914114304, 1922432708, 1930912323, 1939390194, 1947694065, 1955999986, 1964494928, 1972987431, 1981273014, 1989561552, 1998078689, 2006592175, 2014850264, 2023112976, 2031665055, 2040211090, 2048423302, 2056643748, 2065258700, 2073861778, 2081982576, 2090121972, 2098881896, 2107608597, 2115464705, 2123396587, 2132791662, 2141858738, 2145782428, 2141858738, 2132791662, 2123396587, 2115464705, 2107608597, 2098881896, 2090121972, 2081982576, 2073861778, 2065258700, 2056643748, 2048423302, 2040211090, 2031665055, 2023112976, 2014850264, 2006592175, 1998078689, 1989561552, 1981273014, 1972987431, 1964494928, 1955999986, 1947694065, 1939390194, 1930912323, 1922432708, 1914114304, 1905797403, 1897330306, 1888861901, 1880534106, 1872207459, 1863748620, 1855288765, 1846953653, 1838619447, 1830167134, 1821714009, 1813373041, 1805032805, 1796585776, 1788138084, 1779792325, 1771447169, 1763004504, 1754561286, 1746211537, 1737862295, 1729423290, 1720983820, 1712630700, 1704278010, 1695842120, 1687405831, 1679049827, 1670694192, 1662260980, 1653827424, 1645468926, 1637110749, 1628679864, 1620248680, 1611888005, 1603527611, 1595098766, 1586669658, 1578307068, 1569944726, 1561517682, 1553090405, 1544726119, 1536362053, 1527936609, 1519510958, 1511145160, 1502779558, 1494355545, 1485931346, 1477564192, 1469197216, 1460774488, 1452351593, 1443983219, 1435615005, 1427193437, 1418771718, 1410402240, 1402032907, 1393612391, 1385191737, 1376821257, 1368450908, 1360031349, 1351611664, 1343240270, 1334868996, 1326450310, 1318031509, 1309659280, 1301287160, 1292869274, 1284451282, 1276078287, 1267705393, 1259288240, 1250870991, 1242497292, 1234123685, 1225707209, 1217290643, 1208916295, 1200542033, 1192126179, 1183710243, 1175335296, 1166960429, 1158545151, 1150129797, 1141754296, 1133378869, 1124964124, 1116549309, 1108173295, 1099797349, 1091383099, 1082968783, 1074592293, 1066215865, 1057802074, 1049388222, 1041011289, 1032634415, 1024221050, 1015807629, 1007430285, 999052995, 990640028, 982227008, 973849280, 965471603, 957059006, 948646359, 940268274, 931890237, 923477984, 915065685, 906687268, 898308895, 889896963, 881484989, 873106261, 864727575, 856315943, 847904271, 839525254, 831146275, 822734923, 814323533, 805944246, 797564995, 789153903, 780742777, 772363238, 763983732, 755572884, 747162004, 738782230, 730402486, 721991865, 713581214, 705201221, 696821257, 688410847, 680000409, 671620212, 663240042, 654829828, 646419589, 638039202, 629658841, 621248810, 612838756, 604458193, 596077653, 587667793, 579257910, 570877183, 562496478, 554086775, 545677051, 537296173, 528915315, 520505758, 512096181, 503715163, 495334164, 486924740, 478515299, 470134153, 461753023, 453343723, 444934407, 436553142, 428171894, 419762706, 411353504, 402972132, 394590774, 386181689, 377772591, 369391121, 361009664, 352600672, 344191669, 335810111, 327428564, 319019656, 310610737, 302229100, 293847473, 285438639, 277029796, 268648089, 260266391, 251857623, 243448846, 235067078, 226685317, 218276606, 209867888, 201486067, 193104253, 184695590, 176286921, 167905056, 159523196, 151114573, 142705945, 134324045, 125942148, 117533557, 109124962, 100743034, 92361108, 83952541, 75543970, 67162022, 58780077, 50371524, 41962971, 33581011, 25199053, 16790508, 8381963, 0, -8381963, -16790508, -25199053, -33581011, -41962971, -50371524, -58780077, -67162022, -75543970, -83952541, -92361108, -100743034, -109124962, -117533557, -125942148, -134324045, -142705945, -151114573, -159523196, -167905056, -176286921, -184695590, -193104253, -201486067, -209867888, -218276606, -226685317, -235067078, -243448846, -251857623, -260266391, -268648089, -277029796, -285438639, -293847473, -302229100, -310610737, -319019656, -327428564, -335810111, -344191669, -352600672, -361009664, -369391121, -377772591, -386181689, -394590774, -402972132, -411353504, -419762706, -428171894, -436553142, -444934407, -453343723, -461753023, -470134153, -478515299, -486924740, -495334164, -503715163, -512096181, -520505758, -528915315, -537296173, -545677051, -554086775, -562496478, -570877183, -579257910, -587667793, -596077653, -604458193, -612838756, -621248810, -629658841, -638039202, -646419589, -654829828, -663240042, -671620212, -680000409, -688410847, -696821257, -705201221, -713581214, -721991865, -730402486, -738782230, -747162004, -755572884, -763983732, -772363238, -780742777, -789153903, -797564995, -805944246, -814323533, -822734923, -831146275, -839525254, -847904271, -856315943, -864727575, -873106261, -881484989, -889896963, -898308895, -906687268, -915065685, -923477984, -931890237, -940268274, -948646359, -957059006, -965471603, -973849280, -982227008, -990640028, -999052995, -1007430285, -1015807629, -1024221050, -1032634415, -1041011289, -1049388222, -1057802074, -1066215865, -1074592293, -1082968783, -1091383099, -1099797349, -1108173295, -1116549309, -1124964124, -1133378869, -1141754296, -1150129797, -1158545151, -1166960429, -1175335296, -1183710243, -1192126179, -1200542033, -1208916295, -1217290643, -1225707209, -1234123685, -1242497292, -1250870991, -1259288240, -1267705393, -1276078287, -1284451282, -1292869274, -1301287160, -1309659280, -1318031509, -1326450310, -1334868996, -1343240270, -1351611664, -1360031349, -1368450908, -1376821257, -1385191737, -1393612391, -1402032907, -1410402240, -1418771718, -1427193437, -1435615005, -1443983219, -1452351593, -1460774488, -1469197216, -1477564192, -1485931346, -1494355545, -1502779558, -1511145160, -1519510958, -1527936609, -1536362053, -1544726119, -1553090405, -1561517682, -1569944726, -1578307068, -1586669658, -1595098766, -1603527611, -1611888005, -1620248680, -1628679864, -1637110749, -1645468926, -1653827424, -1662260980, -1670694192, -1679049827, -1687405831, -1695842120, -1704278010, -1712630700, -1720983820, -1729423290, -1737862295, -1746211537, -1754561286, -1763004504, -1771447169, -1779792325, -1788138084, -1796585776, -1805032805, -1813373041, -1821714009, -1830167134, -1838619447, -1846953653, -1855288765, -1863748620, -1872207459, -1880534106, -1888861901, -1897330306, -1905797403, -1914114304, -1922432708, -1930912323, -1939390194, -1947694065, -1955999986, -1964494928, -1972987431, -1981273014, -1989561552, -1998078689, -2006592175, -2014850264, -2023112976, -2031665055, -2040211090, -2048423302, -2056643748, -2065258700, -2073861778, -2081982576, -2090121972, -2098881896, -2107608597, -2115464705, -2123396587, -2132791662, -2141858738, -2145782428, -2141858738, -2132791662, -2123396587, -2115464705, -2107608597, -2098881896, -2090121972, -2081982576, -2073861778, -2065258700, -2056643748, -2048423302, -2040211090, -2031665055, -2023112976, -2014850264, -2006592175, -1998078689, -1989561552, -1981273014, -1972987431, -1964494928, -1955999986, -1947694065, -1939390194, -1930912323, -1922432708, -1914114304, -1905797403, -1897330306, -1888861901, -1880534106, -1872207459, -1863748620, -1855288765, -1846953653, -1838619447, -1830167134, -1821714009, -1813373041, -1805032805, -1796585776, -1788138084, -1779792325, -1771447169, -1763004504, -1754561286, -1746211537, -1737862295, -1729423290, -1720983820, -1712630700, -1704278010, -1695842120, -1687405831, -1679049827, -1670694192, -1662260980, -1653827424, -1645468926, -1637110749, -1628679864, -1620248680, -1611888005, -1603527611, -1595098766, -1586669658, -1578307068, -1569944726, -1561517682, -1553090405, -1544726119, -1536362053, -1527936609, -1519510958, -1511145160, -1502779558, -1494355545, -1485931346, -1477564192, -1469197216, -1460774488, -1452351593, -1443983219, -1435615005, -1427193437, -1418771718, -1410402240, -1402032907, -1393612391, -1385191737, -1376821257, -1368450908, -1360031349, -1351611664, -1343240270, -1334868996, -1326450310, -1318031509, -1309659280, -1301287160, -1292869274, -1284451282, -1276078287, -1267705393, -1259288240, -1250870991, -1242497292, -1234123685, -1225707209, -1217290643, -1208916295, -1200542033, -1192126179, -1183710243, -1175335296, -1166960429, -1158545151, -1150129797, -1141754296, -1133378869, -1124964124, -1116549309, -1108173295, -1099797349, -1091383099, -1082968783, -1074592293, -1066215865, -1057802074, -1049388222, -1041011289, -1032634415, -1024221050, -1015807629, -1007430285, -999052995, -990640028, -982227008, -973849280, -965471603, -957059006, -948646359, -940268274, -931890237, -923477984, -915065685, -906687268, -898308895, -889896963, -881484989, -873106261, -864727575, -856315943, -847904271, -839525254, -831146275, -822734923, -814323533, -805944246, -797564995, -789153903, -780742777, -772363238, -763983732, -755572884, -747162004, -738782230, -730402486, -721991865, -713581214, -705201221, -696821257, -688410847, -680000409, -671620212, -663240042, -654829828, -646419589, -638039202, -629658841, -621248810, -612838756, -604458193, -596077653, -587667793, -579257910, -570877183, -562496478, -554086775, -545677051, -537296173, -528915315, -520505758, -512096181, -503715163, -495334164, -486924740, -478515299, -470134153, -461753023, -453343723, -444934407, -436553142, -428171894, -419762706, -411353504, -402972132, -394590774, -386181689, -377772591, -369391121, -361009664, -352600672, -344191669, -335810111, -327428564, -319019656, -310610737, -302229100, -293847473, -285438639, -277029796, -268648089, -260266391, -251857623, -243448846, -235067078, -226685317, -218276606, -209867888, -201486067, -193104253, -184695590, -176286921, -167905056, -159523196, -151114573, -142705945, -134324045, -125942148, -117533557, -109124962, -100743034, -92361108, -83952541, -75543970, -67162022, -58780077, -50371524, -41962971, -33581011, -25199053, -16790508, -8381963, ], [0, 8357664, 16737349, 25148179, 33581034, 42013888, 50424710, 58804369, 67161987, 75519606, 83899280, 92310156, 100743103, 109176047, 117586898, 125966492, 134323973, 142681456, 151061082, 159472042, 167905172, 176338298, 184749217, 193128707, 201485958, 209843212, 218222752, 226633835, 235067242, 243500645, 251911670, 260291017, 268647941, 277004872, 285384286, 293795533, 302229315, 310663091, 319074262, 327453424, 335809923, 344166429, 352545676, 360957130, 369391389, 377825641, 386237000, 394615933, 402971902, 411327878, 419706916, 428118622, 436553467, 444988304, 453399894, 461778551, 470133877, 478489212, 486867995, 495280002, 503715549, 512151086, 520562956, 528941286, 537295848, 545650421, 554028899, 562441260, 570877636, 579313999, 587726200, 596104147, 604457813, 612811492, 621189612, 629602386, 638039729, 646477057, 654889644, 663267147, 671619772, 679972412, 688350115, 696763367, 705201829, 713640274, 722053310, 730430300, 738781722, 747133162, 755510383, 763924185, 772363938, 780803672, 789217225, 797593625, 805943663, 814293720, 822670387, 831084820, 839526058, 847967274, 856381423, 864757145, 873105591, 881454060, 889830088, 898245248, 906688192, 915131111, 923545943, 931920887, 940267504, 948614147, 956989442, 965405438, 973850343, 982295220, 990710838, 999084885, 1007429397, 1015773940, 1024148392, 1032565351, 1041012516, 1049459649, 1057876172, 1066249183, 1074591266, 1082933385, 1091306865, 1099724938, 1108174717, 1116624457, 1125042025, 1133413836, 1141753104, 1150092414, 1158464769, 1166884138, 1175336953, 1183789723, 1192208504, 1200578913, 1208914902, 1217250938, 1225621984, 1234042870, 1242499235, 1250955547, 1259375743, 1267744507, 1276076645, 1284408841, 1292778355, 1301201031, 1309661580, 1318122066, 1326543925, 1334910736, 1343238317, 1351565965, 1359933668, 1368358481, 1376824009, 1385289463, 1393713297, 1402077765, 1410399888, 1418722093, 1427087636, 1435515028, 1443986557, 1452457995, 1460884200, 1469245818, 1477561318, 1485876918, 1494239851, 1502670406, 1511149276, 1519628033, 1528057125, 1536415213, 1544722540, 1553029991, 1561389718, 1569824232, 1578312250, 1586800128, 1595232796, 1603586419, 1611883444, 1620180628, 1628536342, 1636975928, 1645475624, 1653975137, 1662412337, 1670760150, 1679043838, 1687327736, 1695678299, 1704124598, 1712639659, 1721154475, 1729597576, 1737937552, 1746203360, 1754469455, 1762813195, 1771268758, 1779804870, 1788340641, 1796791711, 1805120580, 1813361256, 1821602348, 1829936664, 1838405777, 1846972392, 1855538493, 1864000865, 1872312851, 1880515754, 1888719309, 1897039845, 1905530550, 1914145126, 1922758849, 1931238365, 1939521796, 1947661830, 1955803025, 1964101718, 1972631889, 1981332418, 1990031271, 1998539413, 2006764878, 2014780378, 2022798500, 2031058493, 2039681750, 2048578808, 2057471196, 2066032724, 2074085602, 2081744636, 2089414220, 2097608050, 2106651645, 2116398342, 2126102016, 2134532680, 2140316788, 2142380081, 2140316788, 2134532680, 2126102016, 2116398342, 2106651645, 2097608050, 2089414220, 2081744636, 2074085602, 2066032724, 2057471196, 2048578808, 2039681750, 2031058493, 2022798500, 2014780378, 2006764878, 1998539413, 1990031271, 1981332418, 1972631889, 1964101718, 1955803025, 1947661830, 1939521796, 1931238365, 1922758849, 1914145126, 1905530550, 1897039845, 1888719309, 1880515754, 1872312851, 1864000865, 1855538493, 1846972392, 1838405777, 1829936664, 1821602348, 1813361256, 1805120580, 1796791711, 1788340641, 1779804870, 1771268758, 1762813195, 1754469455, 1746203360, 1737937552, 1729597576, 1721154475, 1712639659, 1704124598, 1695678299, 1687327736, 1679043838, 1670760150, 1662412337, 1653975137, 1645475624, 1636975928, 1628536342, 1620180628, 1611883444, 1603586419, 1595232796, 1586800128, 1578312250, 1569824232, 1561389718, 1553029991, 1544722540, 1536415213, 1528057125, 1519628033, 1511149276, 1502670406, 1494239851, 1485876918, 1477561318, 1469245818, 1460884200, 1452457995, 1443986557, 1435515028, 1427087636, 1418722093, 1410399888, 1402077765, 1393713297, 1385289463, 1376824009, 1368358481, 1359933668, 1351565965, 1343238317, 1334910736, 1326543925, 1318122066, 1309661580, 1301201031, 1292778355, 1284408841, 1276076645, 1267744507, 1259375743, 1250955547, 1242499235, 1234042870, 1225621984, 1217250938, 1208914902, 1200578913, 1192208504, 1183789723, 1175336953, 1166884138, 1158464769, 1150092414, 1141753104, 1133413836, 1125042025, 1116624457, 1108174717, 1099724938, 1091306865, 1082933385, 1074591266, 1066249183, 1057876172, 1049459649, 1041012516, 1032565351, 1024148392, 1015773940, 1007429397, 999084885, 990710838, 982295220, 973850343, 965405438, 956989442, 948614147, 940267504, 931920887, 923545943, 915131111, 906688192, 898245248, 889830088, 881454060, 873105591, 864757145, 856381423, 847967274, 839526058, 831084820, 822670387, 814293720, 805943663, 797593625, 789217225, 780803672, 772363938, 763924185, 755510383, 747133162, 738781722, 730430300, 722053310, 713640274, 705201829, 696763367, 688350115, 679972412, 671619772, 663267147, 654889644, 646477057, 638039729, 629602386, 621189612, 612811492, 604457813, 596104147, 587726200, 579313999, 570877636, 562441260, 554028899, 545650421, 537295848, 528941286, 520562956, 512151086, 503715549, 495280002, 486867995, 478489212, 470133877, 461778551, 453399894, 444988304, 436553467, 428118622, 419706916, 411327878, 402971902, 394615933, 386237000, 377825641, 369391389, 360957130, 352545676, 344166429, 335809923, 327453424, 319074262, 310663091, 302229315, 293795533, 285384286, 277004872, 268647941, 260291017, 251911670, 243500645, 235067242, 226633835, 218222752, 209843212, 201485958, 193128707, 184749217, 176338298, 167905172, 159472042, 151061082, 142681456, 134323973, 125966492, 117586898, 109176047, 100743103, 92310156, 83899280, 75519606, 67161987, 58804369, 50424710, 42013888, 33581034, 25148179, 16737349, 8357664, 0, -8357664, -16737349, -25148179, -33581034, -42013888, -50424710, -58804369, -67161987, -75519606, -83899280, -92310156, -100743103, -109176047, -117586898, -125966492, -134323973, -142681456, -151061082, -159472042, -167905172, -176338298, -184749217, -193128707, -201485958, -209843212, -218222752, -226633835, -235067242, -243500645, -251911670, -260291017, -268647941, -277004872, -285384286, -293795533, -302229315, -310663091, -319074262, -327453424, -335809923, -344166429, -352545676, -360957130, -369391389, -377825641, -386237000, -394615933, -402971902, -411327878, -419706916, -428118622, -436553467, -444988304, -453399894, -461778551, -470133877, -478489212, -486867995, -495280002, -503715549, -512151086, -520562956, -528941286, -537295848, -545650421, -554028899, -562441260, -570877636, -579313999, -587726200, -596104147, -604457813, -612811492, -621189612, -629602386, -638039729, -646477057, -654889644, -663267147, -671619772, -679972412, -688350115, -696763367, -705201829, -713640274, -722053310, -730430300, -738781722, -747133162, -755510383, -763924185, -772363938, -780803672, -789217225, -797593625, -805943663, -814293720, -822670387, -831084820, -839526058, -847967274, -856381423, -864757145, -873105591, -881454060, -889830088, -898245248, -906688192, -915131111, -923545943, -931920887, -940267504, -948614147, -956989442, -965405438, -973850343, -982295220, -990710838, -999084885, -1007429397, -1015773940, -1024148392, -1032565351, -1041012516, -1049459649, -1057876172, -1066249183, -1074591266, -1082933385, -1091306865, -1099724938, -1108174717, -1116624457, -1125042025, -1133413836, -1141753104, -1150092414, -1158464769, -1166884138, -1175336953, -1183789723, -1192208504, -1200578913, -1208914902, -1217250938, -1225621984, -1234042870, -1242499235, -1250955547, -1259375743, -1267744507, -1276076645, -1284408841, -1292778355, -1301201031, -1309661580, -1318122066, -1326543925, -1334910736, -1343238317, -1351565965, -1359933668, -1368358481, -1376824009, -1385289463, -1393713297, -1402077765, -1410399888, -1418722093, -1427087636, -1435515028, -1443986557, -1452457995, -1460884200, -1469245818, -1477561318, -1485876918, -1494239851, -1502670406, -1511149276, -1519628033, -1528057125, -1536415213, -1544722540, -1553029991, -1561389718, -1569824232, -1578312250, -1586800128, -1595232796, -1603586419, -1611883444, -1620180628, -1628536342, -1636975928, -1645475624, -1653975137, -1662412337, -1670760150, -1679043838, -1687327736, -1695678299, -1704124598, -1712639659, -1721154475, -1729597576, -1737937552, -1746203360, -1754469455, -1762813195, -1771268758, -1779804870, -1788340641, -1796791711, -1805120580, -1813361256, -1821602348, -1829936664, -1838405777, -1846972392, -1855538493, -1864000865, -1872312851, -1880515754, -1888719309, -1897039845, -1905530550, -1914145126, -1922758849, -1931238365, -1939521796, -1947661830, -1955803025, -1964101718, -1972631889, -1981332418, -1990031271, -1998539413, -2006764878, -2014780378, -2022798500, -2031058493, -2039681750, -2048578808, -2057471196, -2066032724, -2074085602, -2081744636, -2089414220, -2097608050, -2106651645, -2116398342, -2126102016, -2134532680, -2140316788, -2142380081, -2140316788, -2134532680, -2126102016, -2116398342, -2106651645, -2097608050, -2089414220, -2081744636, -2074085602, -2066032724, -2057471196, -2048578808, -2039681750, -2031058493, -2022798500, -2014780378, -2006764878, -1998539413, -1990031271, -1981332418, -1972631889, -1964101718, -1955803025, -1947661830, -1939521796, -1931238365, -1922758849, -1914145126, -1905530550, -1897039845, -1888719309, -1880515754, -1872312851, -1864000865, -1855538493, -1846972392, -1838405777, -1829936664, -1821602348, -1813361256, -1805120580, -1796791711, -1788340641, -1779804870, -1771268758, -1762813195, -1754469455, -1746203360, -1737937552, -1729597576, -1721154475, -1712639659, -1704124598, -1695678299, -1687327736, -1679043838, -1670760150, -1662412337, -1653975137, -1645475624, -1636975928, -1628536342, -1620180628, -1611883444, -1603586419, -1595232796, -1586800128, -1578312250, -1569824232, -1561389718, -1553029991, -1544722540, -1536415213, -1528057125, -1519628033, -1511149276, -1502670406, -1494239851, -1485876918, -1477561318, -1469245818, -1460884200, -1452457995, -1443986557, -1435515028, -1427087636, -1418722093, -1410399888, -1402077765, -1393713297, -1385289463, -1376824009, -1368358481, -1359933668, -1351565965, -1343238317, -1334910736, -1326543925, -1318122066, -1309661580, -1301201031, -1292778355, -1284408841, -1276076645, -1267744507, -1259375743, -1250955547, -1242499235, -1234042870, -1225621984, -1217250938, -1208914902, -1200578913, -1192208504, -1183789723, -1175336953, -1166884138, -1158464769, -1150092414, -1141753104, -1133413836, -1125042025, -1116624457, -1108174717, -1099724938, -1091306865, -1082933385, -1074591266, -1066249183, -1057876172, -1049459649, -1041012516, -1032565351, -1024148392, -1015773940, -1007429397, -999084885, -990710838, -982295220, -973850343, -965405438, -956989442, -948614147, -940267504, -931920887, -923545943, -915131111, -906688192, -898245248, -889830088, -881454060, -873105591, -864757145, -856381423, -847967274, -839526058, -831084820, -822670387, -814293720, -805943663, -797593625, -789217225, -780803672, -772363938, -763924185, -755510383, -747133162, -738781722, -730430300, -722053310, -713640274, -705201829, -696763367, -688350115, -679972412, -671619772, -663267147, -654889644, -646477057, -638039729, -629602386, -621189612, -612811492, -604457813, -596104147, -587726200, -579313999, -570877636, -562441260, -554028899, -545650421, -537295848, -528941286, -520562956, -512151086, -503715549, -495280002, -486867995, -478489212, -470133877, -461778551, -453399894, -444988304, -436553467, -428118622, -419706916, -411327878, -402971902, -394615933, -386237000, -377825641, -369391389, -360957130, -352545676, -344166429, -335809923, -327453424, -319074262, -310663091, -302229315, -293795533, -285384286, -277004872, -268647941, -260291017, -251911670, -243500645, -235067242, -226633835, -218222752, -209843212, -201485958, -193128707, -184749217, -176338298, -167905172, -159472042, -151061082, -142681456, -134323973, -125966492, -117586898, -109176047, -100743103, -92310156, -83899280, -75519606, -67161987, -58804369, -50424710, -42013888, -33581034, -25148179, -16737349, -8357664, ], [0, 8313895, 16640179, 24989357, 33368451, 41779930, 50221332, 58685643, 67162354, 75639064, 84103365, 92544730, 100956116, 109335039, 117683957, 126009898, 134323399, 142636901, 150962861, 159311855, 167690964, 176102694, 184544583, 193009574, 201487076, 209964577, 218429539, 226871313, 235282761, 243661350, 252009549, 260334465, 268646766, 276959069, 285284023, 293632379, 302011349, 310423500, 318866349, 327332724, 335811849, 344290970, 352757297, 361199946, 369611612, 377989686, 386336674, 394659828, 402970064, 411280303, 419603518, 427950750, 436329420, 444742186, 453186515, 461655050, 470136711, 478618366, 487086829, 495530866, 503942917, 512320268, 520665486, 528986053, 537293249, 545600451, 553921102, 562266665, 570644860, 579058468, 587504880, 595976467, 604461713, 612946953, 621418441, 629864451, 638277074, 646653452, 654996243, 663313249, 671616260, 679919279, 688236399, 696579657, 704957175, 713371903, 721821124, 730296835, 738786930, 747277016, 755752595, 764201278, 772614691, 780989783, 789329338, 797641589, 805939009, 814236440, 822548843, 830889016, 839265620, 847681823, 856134765, 864615944, 873112469, 881608981, 890089986, 898542217, 906956682, 915330081, 923665360, 931971328, 940261360, 948551406, 956857575, 965193671, 973569067, 981987219, 990445077, 998933482, 1007438502, 1015943506, 1024431680, 1032888596, 1041304446, 1049675595, 1058005208, 1066302849, 1074583091, 1082863352, 1091161261, 1099491962, 1107865772, 1116286533, 1124750946, 1133248980, 1141765320, 1150281639, 1158779362, 1167242511, 1175660181, 1184028284, 1192350275, 1200636727, 1208903816, 1217170930, 1225457744, 1233781214, 1242152932, 1250577273, 1259050606, 1267561727, 1276093449, 1284625141, 1293135834, 1301607425, 1310027506, 1318391361, 1326702826, 1334973849, 1343222806, 1351471797, 1359743330, 1368056868, 1376425794, 1384855235, 1393341117, 1401870610, 1410423920, 1418977188, 1427506067, 1435989450, 1444412782, 1452770430, 1461066746, 1469315620, 1477538555, 1485761542, 1494011167, 1502310539, 1510675659, 1519112788, 1527617242, 1536173847, 1544759002, 1553344092, 1561899761, 1570400408, 1578828222, 1587176144, 1595449246, 1603664297, 1611847518, 1620030821, 1628247065, 1636525026, 1644884834, 1653334602, 1661868790, 1670468614, 1679104499, 1687740275, 1696338529, 1704866325, 1713300454, 1721631369, 1729865169, 1738023259, 1746139643, 1754256175, 1762416415, 1770658968, 1779011309, 1787485043, 1796073406, 1804751461, 1813479104, 1822206532, 1830881476, 1839457165, 1847899857, 1856194769, 1864349419, 1872393731, 1880376721, 1888360046, 1896409241, 1904583797, 1912927524, 1921460657, 1930175020, 1939033163, 1947971899, 1956910037, 1965759464, 1974438227, 1982883835, 1991064860, 1998988994, 2006706085, 2014305227, 2021905721, 2029642534, 2037647640, 2046029263, 2054851473, 2064116670, 2073753346, 2083610973, 2093463158, 2103019257, 2111943668, 2119881132, 2126485563, 2131449515, 2134531188, 2135576114, 2134531188, 2131449515, 2126485563, 2119881132, 2111943668, 2103019257, 2093463158, 2083610973, 2073753346, 2064116670, 2054851473, 2046029263, 2037647640, 2029642534, 2021905721, 2014305227, 2006706085, 1998988994, 1991064860, 1982883835, 1974438227, 1965759464, 1956910037, 1947971899, 1939033163, 1930175020, 1921460657, 1912927524, 1904583797, 1896409241, 1888360046, 1880376721, 1872393731, 1864349419, 1856194769, 1847899857, 1839457165, 1830881476, 1822206532, 1813479104, 1804751461, 1796073406, 1787485043, 1779011309, 1770658968, 1762416415, 1754256175, 1746139643, 1738023259, 1729865169, 1721631369, 1713300454, 1704866325, 1696338529, 1687740275, 1679104499, 1670468614, 1661868790, 1653334602, 1644884834, 1636525026, 1628247065, 1620030821, 1611847518, 1603664297, 1595449246, 1587176144, 1578828222, 1570400408, 1561899761, 1553344092, 1544759002, 1536173847, 1527617242, 1519112788, 1510675659, 1502310539, 1494011167, 1485761542, 1477538555, 1469315620, 1461066746, 1452770430, 1444412782, 1435989450, 1427506067, 1418977188, 1410423920, 1401870610, 1393341117, 1384855235, 1376425794, 1368056868, 1359743330, 1351471797, 1343222806, 1334973849, 1326702826, 1318391361, 1310027506, 1301607425, 1293135834, 1284625141, 1276093449, 1267561727, 1259050606, 1250577273, 1242152932, 1233781214, 1225457744, 1217170930, 1208903816, 1200636727, 1192350275, 1184028284, 1175660181, 1167242511, 1158779362, 1150281639, 1141765320, 1133248980, 1124750946, 1116286533, 1107865772, 1099491962, 1091161261, 1082863352, 1074583091, 1066302849, 1058005208, 1049675595, 1041304446, 1032888596, 1024431680, 1015943506, 1007438502, 998933482, 990445077, 981987219, 973569067, 965193671, 956857575, 948551406, 940261360, 931971328, 923665360, 915330081, 906956682, 898542217, 890089986, 881608981, 873112469, 864615944, 856134765, 847681823, 839265620, 830889016, 822548843, 814236440, 805939009, 797641589, 789329338, 780989783, 772614691, 764201278, 755752595, 747277016, 738786930, 730296835, 721821124, 713371903, 704957175, 696579657, 688236399, 679919279, 671616260, 663313249, 654996243, 646653452, 638277074, 629864451, 621418441, 612946953, 604461713, 595976467, 587504880, 579058468, 570644860, 562266665, 553921102, 545600451, 537293249, 528986053, 520665486, 512320268, 503942917, 495530866, 487086829, 478618366, 470136711, 461655050, 453186515, 444742186, 436329420, 427950750, 419603518, 411280303, 402970064, 394659828, 386336674, 377989686, 369611612, 361199946, 352757297, 344290970, 335811849, 327332724, 318866349, 310423500, 302011349, 293632379, 285284023, 276959069, 268646766, 260334465, 252009549, 243661350, 235282761, 226871313, 218429539, 209964577, 201487076, 193009574, 184544583, 176102694, 167690964, 159311855, 150962861, 142636901, 134323399, 126009898, 117683957, 109335039, 100956116, 92544730, 84103365, 75639064, 67162354, 58685643, 50221332, 41779930, 33368451, 24989357, 16640179, 8313895, 0, -8313895, -16640179, -24989357, -33368451, -41779930, -50221332, -58685643, -67162354, -75639064, -84103365, -92544730, -100956116, -109335039, -117683957, -126009898, -134323399, -142636901, -150962861, -159311855, -167690964, -176102694, -184544583, -193009574, -201487076, -209964577, -218429539, -226871313, -235282761, -243661350, -252009549, -260334465, -268646766, -276959069, -285284023, -293632379, -302011349, -310423500, -318866349, -327332724, -335811849, -344290970, -352757297, -361199946, -369611612, -377989686, -386336674, -394659828, -402970064, -411280303, -419603518, -427950750, -436329420, -444742186, -453186515, -461655050, -470136711, -478618366, -487086829, -495530866, -503942917, -512320268, -520665486, -528986053, -537293249, -545600451, -553921102, -562266665, -570644860, -579058468, -587504880, -595976467, -604461713, -612946953, -621418441, -629864451, -638277074, -646653452, -654996243, -663313249, -671616260, -679919279, -688236399, -696579657, -704957175, -713371903, -721821124, -730296835, -738786930, -747277016, -755752595, -764201278, -772614691, -780989783, -789329338, -797641589, -805939009, -814236440, -822548843, -830889016, -839265620, -847681823, -856134765, -864615944, -873112469, -881608981, -890089986, -898542217, -906956682, -915330081, -923665360, -931971328, -940261360, -948551406, -956857575, -965193671, -973569067, -981987219, -990445077, -998933482, -1007438502, -1015943506, -1024431680, -1032888596, -1041304446, -1049675595, -1058005208, -1066302849, -1074583091, -1082863352, -1091161261, -1099491962, -1107865772, -1116286533, -1124750946, -1133248980, -1141765320, -1150281639, -1158779362, -1167242511, -1175660181, -1184028284, -1192350275, -1200636727, -1208903816, -1217170930, -1225457744, -1233781214, -1242152932, -1250577273, -1259050606, -1267561727, -1276093449, -1284625141, -1293135834, -1301607425, -1310027506, -1318391361, -1326702826, -1334973849, -1343222806, -1351471797, -1359743330, -1368056868, -1376425794, -1384855235, -1393341117, -1401870610, -1410423920, -1418977188, -1427506067, -1435989450, -1444412782, -1452770430, -1461066746, -1469315620, -1477538555, -1485761542, -1494011167, -1502310539, -1510675659, -1519112788, -1527617242, -1536173847, -1544759002, -1553344092, -1561899761, -1570400408, -1578828222, -1587176144, -1595449246, -1603664297, -1611847518, -1620030821, -1628247065, -1636525026, -1644884834, -1653334602, -1661868790, -1670468614, -1679104499, -1687740275, -1696338529, -1704866325, -1713300454, -1721631369, -1729865169, -1738023259, -1746139643, -1754256175, -1762416415, -1770658968, -1779011309, -1787485043, -1796073406, -1804751461, -1813479104, -1822206532, -1830881476, -1839457165, -1847899857, -1856194769, -1864349419, -1872393731, -1880376721, -1888360046, -1896409241, -1904583797, -1912927524, -1921460657, -1930175020, -1939033163, -1947971899, -1956910037, -1965759464, -1974438227, -1982883835, -1991064860, -1998988994, -2006706085, -2014305227, -2021905721, -2029642534, -2037647640, -2046029263, -2054851473, -2064116670, -2073753346, -2083610973, -2093463158, -2103019257, -2111943668, -2119881132, -2126485563, -2131449515, -2134531188, -2135576114, -2134531188, -2131449515, -2126485563, -2119881132, -2111943668, -2103019257, -2093463158, -2083610973, -2073753346, -2064116670, -2054851473, -2046029263, -2037647640, -2029642534, -2021905721, -2014305227, -2006706085, -1998988994, -1991064860, -1982883835, -1974438227, -1965759464, -1956910037, -1947971899, -1939033163, -1930175020, -1921460657, -1912927524, -1904583797, -1896409241, -1888360046, -1880376721, -1872393731, -1864349419, -1856194769, -1847899857, -1839457165, -1830881476, -1822206532, -1813479104, -1804751461, -1796073406, -1787485043, -1779011309, -1770658968, -1762416415, -1754256175, -1746139643, -1738023259, -1729865169, -1721631369, -1713300454, -1704866325, -1696338529, -1687740275, -1679104499, -1670468614, -1661868790, -1653334602, -1644884834, -1636525026, -1628247065, -1620030821, -1611847518, -1603664297, -1595449246, -1587176144, -1578828222, -1570400408, -1561899761, -1553344092, -1544759002, -1536173847, -1527617242, -1519112788, -1510675659, -1502310539, -1494011167, -1485761542, -1477538555, -1469315620, -1461066746, -1452770430, -1444412782, -1435989450, -1427506067, -1418977188, -1410423920, -1401870610, -1393341117, -1384855235, -1376425794, -1368056868, -1359743330, -1351471797, -1343222806, -1334973849, -1326702826, -1318391361, -1310027506, -1301607425, -1293135834, -1284625141, -1276093449, -1267561727, -1259050606, -1250577273, -1242152932, -1233781214, -1225457744, -1217170930, -1208903816, -1200636727, -1192350275, -1184028284, -1175660181, -1167242511, -1158779362, -1150281639, -1141765320, -1133248980, -1124750946, -1116286533, -1107865772, -1099491962, -1091161261, -1082863352, -1074583091, -1066302849, -1058005208, -1049675595, -1041304446, -1032888596, -1024431680, -1015943506, -1007438502, -998933482, -990445077, -981987219, -973569067, -965193671, -956857575, -948551406, -940261360, -931971328, -923665360, -915330081, -906956682, -898542217, -890089986, -881608981, -873112469, -864615944, -856134765, -847681823, -839265620, -830889016, -822548843, -814236440, -805939009, -797641589, -789329338, -780989783, -772614691, -764201278, -755752595, -747277016, -738786930, -730296835, -721821124, -713371903, -704957175, -696579657, -688236399, -679919279, -671616260, -663313249, -654996243, -646653452, -638277074, -629864451, -621418441, -612946953, -604461713, -595976467, -587504880, -579058468, -570644860, -562266665, -553921102, -545600451, -537293249, -528986053, -520665486, -512320268, -503942917, -495530866, -487086829, -478618366, -470136711, -461655050, -453186515, -444742186, -436329420, -427950750, -419603518, -411280303, -402970064, -394659828, -386336674, -377989686, -369611612, -361199946, -352757297, -344290970, -335811849, -327332724, -318866349, -310423500, -302011349, -293632379, -285284023, -276959069, -268646766, -260334465, -252009549, -243661350, -235282761, -226871313, -218429539, -209964577, -201487076, -193009574, -184544583, -176102694, -167690964, -159311855, -150962861, -142636901, -134323399, -126009898, -117683957, -109335039, -100956116, -92544730, -84103365, -75639064, -67162354, -58685643, -50221332, -41779930, -33368451, -24989357, -16640179, -8313895, ], [0, 8229469, 16465315, 24713671, 32980192, 41269834, 49586669, 57933725, 66312871, 74724746, 83168730, 91642972, 100144455, 108669115, 117211994, 125767428, 134329265, 142891102, 151446526, 159989364, 168513915, 177015177, 185489031, 193932406, 202343399, 210721346, 219066851, 227381767, 235669127, 243933027, 252178477, 260411203, 268637436, 276863670, 285096416, 293341950, 301606074, 309893888, 318209599, 326556351, 334936104, 343349556, 351796111, 360273897, 368779836, 377309757, 385858552, 394420370, 402988838, 411557305, 420119092, 428667755, 437197328, 445702557, 454179100, 462623706, 471034333, 479410242, 487752025, 496061590, 504342092, 512597821, 520834041, 529056791, 537272659, 545488529, 553711323, 561947730, 570203951, 578485458, 586796779, 595141319, 603521224, 611937289, 620388924, 628874162, 637389735, 645931182, 654493022, 663068951, 671652084, 680235213, 688811083, 697372670, 705913453, 714427667, 722910531, 731358437, 739769101, 748141653, 756476689, 764776253, 773043773, 781283937, 789502525, 797706193, 805902228, 814098269, 822302016, 830520939, 838761984, 847031305, 855334018, 863673998, 872053714, 880474128, 888934641, 897433099, 905965867, 914527951, 923113178, 931714421, 940323866, 948933304, 957534443, 966119227, 974680146, 983210534, 991704831, 1000158812, 1008569759, 1016936589, 1025259907, 1033542008, 1041786802, 1049999687, 1058187356, 1066357555, 1074518799, 1082680052, 1090850389, 1099038647, 1107253083, 1115501049, 1123788693, 1132120716, 1140500161, 1148928283, 1157404470, 1165926245, 1174489333, 1183087801, 1191714262, 1200360143, 1209015990, 1217671824, 1226317517, 1234943178, 1243539538, 1252098318, 1260612561, 1269076921, 1277487899, 1285844001, 1294145835, 1302396119, 1310599616, 1318762981, 1326894542, 1335004006, 1343102113, 1351200237, 1359309964, 1367442649, 1375608970, 1383818511, 1392079365, 1400397797, 1408777967, 1417221721, 1425728482, 1434295211, 1442916482, 1451584632, 1460290012, 1469021313, 1477765965, 1486510591, 1495241504, 1503945229, 1512609022, 1521221384, 1529772528, 1538254805, 1546663046, 1554994831, 1563250646, 1571433949, 1579551115, 1587611265, 1595626000, 1603609020, 1611575656, 1619542334, 1627525970, 1635543338, 1643610419, 1651741761, 1659949877, 1668244696, 1676633093, 1685118526, 1693700783, 1702375857, 1711135972, 1719969734, 1728862438, 1737796502, 1746752031, 1755707485, 1764640439, 1773528400, 1782349669, 1791084203, 1799714458, 1808226168, 1816609051, 1824857388, 1832970469, 1840952875, 1848814584, 1856570877, 1864242058, 1871852969, 1879432321, 1887011841, 1894625275, 1902307242, 1910092002, 1918012149, 1926097278, 1934372662, 1942857980, 1951566143, 1960502246, 1969662692, 1979034517, 1988594938, 1998311161, 2008140437, 2018030405, 2027919692, 2037738779, 2047411119, 2056854459, 2065982371, 2074705930, 2082935500, 2090582594, 2097561753, 2103792396, 2109200588, 2113720684, 2117296810, 2119884124, 2121449841, 2121973983, 2121449841, 2119884124, 2117296810, 2113720684, 2109200588, 2103792396, 2097561753, 2090582594, 2082935500, 2074705930, 2065982371, 2056854459, 2047411119, 2037738779, 2027919692, 2018030405, 2008140437, 1998311161, 1988594938, 1979034517, 1969662692, 1960502246, 1951566143, 1942857980, 1934372662, 1926097278, 1918012149, 1910092002, 1902307242, 1894625275, 1887011841, 1879432321, 1871852969, 1864242058, 1856570877, 1848814584, 1840952875, 1832970469, 1824857388, 1816609051, 1808226168, 1799714458, 1791084203, 1782349669, 1773528400, 1764640439, 1755707485, 1746752031, 1737796502, 1728862438, 1719969734, 1711135972, 1702375857, 1693700783, 1685118526, 1676633093, 1668244696, 1659949877, 1651741761, 1643610419, 1635543338, 1627525970, 1619542334, 1611575656, 1603609020, 1595626000, 1587611265, 1579551115, 1571433949, 1563250646, 1554994831, 1546663046, 1538254805, 1529772528, 1521221384, 1512609022, 1503945229, 1495241504, 1486510591, 1477765965, 1469021313, 1460290012, 1451584632, 1442916482, 1434295211, 1425728482, 1417221721, 1408777967, 1400397797, 1392079365, 1383818511, 1375608970, 1367442649, 1359309964, 1351200237, 1343102113, 1335004006, 1326894542, 1318762981, 1310599616, 1302396119, 1294145835, 1285844001, 1277487899, 1269076921, 1260612561, 1252098318, 1243539538, 1234943178, 1226317517, 1217671824, 1209015990, 1200360143, 1191714262, 1183087801, 1174489333, 1165926245, 1157404470, 1148928283, 1140500161, 1132120716, 1123788693, 1115501049, 1107253083, 1099038647, 1090850389, 1082680052, 1074518799, 1066357555, 1058187356, 1049999687, 1041786802, 1033542008, 1025259907, 1016936589, 1008569759, 1000158812, 991704831, 983210534, 974680146, 966119227, 957534443, 948933304, 940323866, 931714421, 923113178, 914527951, 905965867, 897433099, 888934641, 880474128, 872053714, 863673998, 855334018, 847031305, 838761984, 830520939, 822302016, 814098269, 805902228, 797706193, 789502525, 781283937, 773043773, 764776253, 756476689, 748141653, 739769101, 731358437, 722910531, 714427667, 705913453, 697372670, 688811083, 680235213, 671652084, 663068951, 654493022, 645931182, 637389735, 628874162, 620388924, 611937289, 603521224, 595141319, 586796779, 578485458, 570203951, 561947730, 553711323, 545488529, 537272659, 529056791, 520834041, 512597821, 504342092, 496061590, 487752025, 479410242, 471034333, 462623706, 454179100, 445702557, 437197328, 428667755, 420119092, 411557305, 402988838, 394420370, 385858552, 377309757, 368779836, 360273897, 351796111, 343349556, 334936104, 326556351, 318209599, 309893888, 301606074, 293341950, 285096416, 276863670, 268637436, 260411203, 252178477, 243933027, 235669127, 227381767, 219066851, 210721346, 202343399, 193932406, 185489031, 177015177, 168513915, 159989364, 151446526, 142891102, 134329265, 125767428, 117211994, 108669115, 100144455, 91642972, 83168730, 74724746, 66312871, 57933725, 49586669, 41269834, 32980192, 24713671, 16465315, 8229469, 0, -8229469, -16465315, -24713671, -32980192, -41269834, -49586669, -57933725, -66312871, -74724746, -83168730, -91642972, -100144455, -108669115, -117211994, -125767428, -134329265, -142891102, -151446526, -159989364, -168513915, -177015177, -185489031, -193932406, -202343399, -210721346, -219066851, -227381767, -235669127, -243933027, -252178477, -260411203, -268637436, -276863670, -285096416, -293341950, -301606074, -309893888, -318209599, -326556351, -334936104, -343349556, -351796111, -360273897, -368779836, -377309757, -385858552, -394420370, -402988838, -411557305, -420119092, -428667755, -437197328, -445702557, -454179100, -462623706, -471034333, -479410242, -487752025, -496061590, -504342092, -512597821, -520834041, -529056791, -537272659, -545488529, -553711323, -561947730, -570203951, -578485458, -586796779, -595141319, -603521224, -611937289, -620388924, -628874162, -637389735, -645931182, -654493022, -663068951, -671652084, -680235213, -688811083, -697372670, -705913453, -714427667, -722910531, -731358437, -739769101, -748141653, -756476689, -764776253, -773043773, -781283937, -789502525, -797706193, -805902228, -814098269, -822302016, -830520939, -838761984, -847031305, -855334018, -863673998, -872053714, -880474128, -888934641, -897433099, -905965867, -914527951, -923113178, -931714421, -940323866, -948933304, -957534443, -966119227, -974680146, -983210534, -991704831, -1000158812, -1008569759, -1016936589, -1025259907, -1033542008, -1041786802, -1049999687, -1058187356, -1066357555, -1074518799, -1082680052, -1090850389, -1099038647, -1107253083, -1115501049, -1123788693, -1132120716, -1140500161, -1148928283, -1157404470, -1165926245, -1174489333, -1183087801, -1191714262, -1200360143, -1209015990, -1217671824, -1226317517, -1234943178, -1243539538, -1252098318, -1260612561, -1269076921, -1277487899, -1285844001, -1294145835, -1302396119, -1310599616, -1318762981, -1326894542, -1335004006, -1343102113, -1351200237, -1359309964, -1367442649, -1375608970, -1383818511, -1392079365, -1400397797, -1408777967, -1417221721, -1425728482, -1434295211, -1442916482, -1451584632, -1460290012, -1469021313, -1477765965, -1486510591, -1495241504, -1503945229, -1512609022, -1521221384, -1529772528, -1538254805, -1546663046, -1554994831, -1563250646, -1571433949, -1579551115, -1587611265, -1595626000, -1603609020, -1611575656, -1619542334, -1627525970, -1635543338, -1643610419, -1651741761, -1659949877, -1668244696, -1676633093, -1685118526, -1693700783, -1702375857, -1711135972, -1719969734, -1728862438, -1737796502, -1746752031, -1755707485, -1764640439, -1773528400, -1782349669, -1791084203, -1799714458, -1808226168, -1816609051, -1824857388, -1832970469, -1840952875, -1848814584, -1856570877, -1864242058, -1871852969, -1879432321, -1887011841, -1894625275, -1902307242, -1910092002, -1918012149, -1926097278, -1934372662, -1942857980, -1951566143, -1960502246, -1969662692, -1979034517, -1988594938, -1998311161, -2008140437, -2018030405, -2027919692, -2037738779, -2047411119, -2056854459, -2065982371, -2074705930, -2082935500, -2090582594, -2097561753, -2103792396, -2109200588, -2113720684, -2117296810, -2119884124, -2121449841, -2121973983, -2121449841, -2119884124, -2117296810, -2113720684, -2109200588, -2103792396, -2097561753, -2090582594, -2082935500, -2074705930, -2065982371, -2056854459, -2047411119, -2037738779, -2027919692, -2018030405, -2008140437, -1998311161, -1988594938, -1979034517, -1969662692, -1960502246, -1951566143, -1942857980, -1934372662, -1926097278, -1918012149, -1910092002, -1902307242, -1894625275, -1887011841, -1879432321, -1871852969, -1864242058, -1856570877, -1848814584, -1840952875, -1832970469, -1824857388, -1816609051, -1808226168, -1799714458, -1791084203, -1782349669, -1773528400, -1764640439, -1755707485, -1746752031, -1737796502, -1728862438, -1719969734, -1711135972, -1702375857, -1693700783, -1685118526, -1676633093, -1668244696, -1659949877, -1651741761, -1643610419, -1635543338, -1627525970, -1619542334, -1611575656, -1603609020, -1595626000, -1587611265, -1579551115, -1571433949, -1563250646, -1554994831, -1546663046, -1538254805, -1529772528, -1521221384, -1512609022, -1503945229, -1495241504, -1486510591, -1477765965, -1469021313, -1460290012, -1451584632, -1442916482, -1434295211, -1425728482, -1417221721, -1408777967, -1400397797, -1392079365, -1383818511, -1375608970, -1367442649, -1359309964, -1351200237, -1343102113, -1335004006, -1326894542, -1318762981, -1310599616, -1302396119, -1294145835, -1285844001, -1277487899, -1269076921, -1260612561, -1252098318, -1243539538, -1234943178, -1226317517, -1217671824, -1209015990, -1200360143, -1191714262, -1183087801, -1174489333, -1165926245, -1157404470, -1148928283, -1140500161, -1132120716, -1123788693, -1115501049, -1107253083, -1099038647, -1090850389, -1082680052, -1074518799, -1066357555, -1058187356, -1049999687, -1041786802, -1033542008, -1025259907, -1016936589, -1008569759, -1000158812, -991704831, -983210534, -974680146, -966119227, -957534443, -948933304, -940323866, -931714421, -923113178, -914527951, -905965867, -897433099, -888934641, -880474128, -872053714, -863673998, -855334018, -847031305, -838761984, -830520939, -822302016, -814098269, -805902228, -797706193, -789502525, -781283937, -773043773, -764776253, -756476689, -748141653, -739769101, -731358437, -722910531, -714427667, -705913453, -697372670, -688811083, -680235213, -671652084, -663068951, -654493022, -645931182, -637389735, -628874162, -620388924, -611937289, -603521224, -595141319, -586796779, -578485458, -570203951, -561947730, -553711323, -545488529, -537272659, -529056791, -520834041, -512597821, -504342092, -496061590, -487752025, -479410242, -471034333, -462623706, -454179100, -445702557, -437197328, -428667755, -420119092, -411557305, -402988838, -394420370, -385858552, -377309757, -368779836, -360273897, -351796111, -343349556, -334936104, -326556351, -318209599, -309893888, -301606074, -293341950, -285096416, -276863670, -268637436, -260411203, -252178477, -243933027, -235669127, -227381767, -219066851, -210721346, -202343399, -193932406, -185489031, -177015177, -168513915, -159989364, -151446526, -142891102, -134329265, -125767428, -117211994, -108669115, -100144455, -91642972, -83168730, -74724746, -66312871, -57933725, -49586669, -41269834, -32980192, -24713671, -16465315, -8229469, ], [0, 8063034, 16129279, 24201918, 32284071, 40378766, 48488916, 56617283, 64766458, 72938830, 81136571, 89361609, 97615611, 105899969, 114215784, 122563857, 130944677, 139358425, 147804960, 156283830, 164794271, 173335215, 181905299, 190502878, 199126041, 207772627, 216440248, 225126307, 233828027, 242542473, 251266583, 259997195, 268731077, 277464959, 286195561, 294919628, 303633959, 312335437, 321021061, 329687973, 338333487, 346955115, 355550589, 364117886, 372655249, 381161200, 389634559, 398074456, 406480338, 414851978, 423189477, 431493263, 439764093, 448003040, 456211491, 464391130, 472543925, 480672113, 488778175, 496864815, 504934940, 512991626, 521038093, 529077677, 537113795, 545149914, 553189520, 561236083, 569293024, 577363683, 585451286, 593558914, 601689472, 609845663, 618029957, 626244569, 634491437, 642772197, 651088173, 659440355, 667829394, 676255587, 684718879, 693218854, 701754744, 710325427, 718929438, 727564983, 736229951, 744921930, 753638234, 762375918, 771131814, 779902549, 788684585, 797474245, 806267750, 815061252, 823850872, 832632737, 841403015, 850157953, 858893913, 867607408, 876295137, 884954016, 893581213, 902174172, 910730647, 919248720, 927726824, 936163763, 944558727, 952911300, 961221474, 969489647, 977716628, 985903629, 994052263, 1002164531, 1010242803, 1018289807, 1026308602, 1034302552, 1042275301, 1050230741, 1058172973, 1066106278, 1074035073, 1081963873, 1089897248, 1097839781, 1105796025, 1113770460, 1121767447, 1129791186, 1137845676, 1145934672, 1154061648, 1162229758, 1170441803, 1178700198, 1187006947, 1195363615, 1203771307, 1212230651, 1220741787, 1229304354, 1237917491, 1246579832, 1255289515, 1264044191, 1272841035, 1281676771, 1290547689, 1299449678, 1308378255, 1317328602, 1326295606, 1335273902, 1344257918, 1353241925, 1362220089, 1371186520, 1380135333, 1389060697, 1397956897, 1406818386, 1415639839, 1424416215, 1433142803, 1441815276, 1450429739, 1458982778, 1467471496, 1475893560, 1484247228, 1492531382, 1500745554, 1508889943, 1516965431, 1524973591, 1532916687, 1540797674, 1548620183, 1556388510, 1564107588, 1571782963, 1579420756, 1587027620, 1594610700, 1602177572, 1609736195, 1617294837, 1624862021, 1632446443, 1640056905, 1647702233, 1655391198, 1663132437, 1670934364, 1678805091, 1686752340, 1694783362, 1702904853, 1711122871, 1719442762, 1727869081, 1736405525, 1745054857, 1753818853, 1762698238, 1771692639, 1780800539, 1790019236, 1799344816, 1808772129, 1818294772, 1827905083, 1837594141, 1847351778, 1857166592, 1867025979, 1876916162, 1886822239, 1896728231, 1906617142, 1916471028, 1926271066, 1935997638, 1945630418, 1955148467, 1964530324, 1973754119, 1982797673, 1991638612, 2000254479, 2008622849, 2016721450, 2024528274, 2032021698, 2039180600, 2045984471, 2052413530, 2058448831, 2064072367, 2069267172, 2074017414, 2078308486, 2082127083, 2085461286, 2088300620, 2090636121, 2092460384, 2093767604, 2094553610, 2094815892, 2094553610, 2093767604, 2092460384, 2090636121, 2088300620, 2085461286, 2082127083, 2078308486, 2074017414, 2069267172, 2064072367, 2058448831, 2052413530, 2045984471, 2039180600, 2032021698, 2024528274, 2016721450, 2008622849, 2000254479, 1991638612, 1982797673, 1973754119, 1964530324, 1955148467, 1945630418, 1935997638, 1926271066, 1916471028, 1906617142, 1896728231, 1886822239, 1876916162, 1867025979, 1857166592, 1847351778, 1837594141, 1827905083, 1818294772, 1808772129, 1799344816, 1790019236, 1780800539, 1771692639, 1762698238, 1753818853, 1745054857, 1736405525, 1727869081, 1719442762, 1711122871, 1702904853, 1694783362, 1686752340, 1678805091, 1670934364, 1663132437, 1655391198, 1647702233, 1640056905, 1632446443, 1624862021, 1617294837, 1609736195, 1602177572, 1594610700, 1587027620, 1579420756, 1571782963, 1564107588, 1556388510, 1548620183, 1540797674, 1532916687, 1524973591, 1516965431, 1508889943, 1500745554, 1492531382, 1484247228, 1475893560, 1467471496, 1458982778, 1450429739, 1441815276, 1433142803, 1424416215, 1415639839, 1406818386, 1397956897, 1389060697, 1380135333, 1371186520, 1362220089, 1353241925, 1344257918, 1335273902, 1326295606, 1317328602, 1308378255, 1299449678, 1290547689, 1281676771, 1272841035, 1264044191, 1255289515, 1246579832, 1237917491, 1229304354, 1220741787, 1212230651, 1203771307, 1195363615, 1187006947, 1178700198, 1170441803, 1162229758, 1154061648, 1145934672, 1137845676, 1129791186, 1121767447, 1113770460, 1105796025, 1097839781, 1089897248, 1081963873, 1074035073, 1066106278, 1058172973, 1050230741, 1042275301, 1034302552, 1026308602, 1018289807, 1010242803, 1002164531, 994052263, 985903629, 977716628, 969489647, 961221474, 952911300, 944558727, 936163763, 927726824, 919248720, 910730647, 902174172, 893581213, 884954016, 876295137, 867607408, 858893913, 850157953, 841403015, 832632737, 823850872, 815061252, 806267750, 797474245, 788684585, 779902549, 771131814, 762375918, 753638234, 744921930, 736229951, 727564983, 718929438, 710325427, 701754744, 693218854, 684718879, 676255587, 667829394, 659440355, 651088173, 642772197, 634491437, 626244569, 618029957, 609845663, 601689472, 593558914, 585451286, 577363683, 569293024, 561236083, 553189520, 545149914, 537113795, 529077677, 521038093, 512991626, 504934940, 496864815, 488778175, 480672113, 472543925, 464391130, 456211491, 448003040, 439764093, 431493263, 423189477, 414851978, 406480338, 398074456, 389634559, 381161200, 372655249, 364117886, 355550589, 346955115, 338333487, 329687973, 321021061, 312335437, 303633959, 294919628, 286195561, 277464959, 268731077, 259997195, 251266583, 242542473, 233828027, 225126307, 216440248, 207772627, 199126041, 190502878, 181905299, 173335215, 164794271, 156283830, 147804960, 139358425, 130944677, 122563857, 114215784, 105899969, 97615611, 89361609, 81136571, 72938830, 64766458, 56617283, 48488916, 40378766, 32284071, 24201918, 16129279, 8063034, 0, -8063034, -16129279, -24201918, -32284071, -40378766, -48488916, -56617283, -64766458, -72938830, -81136571, -89361609, -97615611, -105899969, -114215784, -122563857, -130944677, -139358425, -147804960, -156283830, -164794271, -173335215, -181905299, -190502878, -199126041, -207772627, -216440248, -225126307, -233828027, -242542473, -251266583, -259997195, -268731077, -277464959, -286195561, -294919628, -303633959, -312335437, -321021061, -329687973, -338333487, -346955115, -355550589, -364117886, -372655249, -381161200, -389634559, -398074456, -406480338, -414851978, -423189477, -431493263, -439764093, -448003040, -456211491, -464391130, -472543925, -480672113, -488778175, -496864815, -504934940, -512991626, -521038093, -529077677, -537113795, -545149914, -553189520, -561236083, -569293024, -577363683, -585451286, -593558914, -601689472, -609845663, -618029957, -626244569, -634491437, -642772197, -651088173, -659440355, -667829394, -676255587, -684718879, -693218854, -701754744, -710325427, -718929438, -727564983, -736229951, -744921930, -753638234, -762375918, -771131814, -779902549, -788684585, -797474245, -806267750, -815061252, -823850872, -832632737, -841403015, -850157953, -858893913, -867607408, -876295137, -884954016, -893581213, -902174172, -910730647, -919248720, -927726824, -936163763, -944558727, -952911300, -961221474, -969489647, -977716628, -985903629, -994052263, -1002164531, -1010242803, -1018289807, -1026308602, -1034302552, -1042275301, -1050230741, -1058172973, -1066106278, -1074035073, -1081963873, -1089897248, -1097839781, -1105796025, -1113770460, -1121767447, -1129791186, -1137845676, -1145934672, -1154061648, -1162229758, -1170441803, -1178700198, -1187006947, -1195363615, -1203771307, -1212230651, -1220741787, -1229304354, -1237917491, -1246579832, -1255289515, -1264044191, -1272841035, -1281676771, -1290547689, -1299449678, -1308378255, -1317328602, -1326295606, -1335273902, -1344257918, -1353241925, -1362220089, -1371186520, -1380135333, -1389060697, -1397956897, -1406818386, -1415639839, -1424416215, -1433142803, -1441815276, -1450429739, -1458982778, -1467471496, -1475893560, -1484247228, -1492531382, -1500745554, -1508889943, -1516965431, -1524973591, -1532916687, -1540797674, -1548620183, -1556388510, -1564107588, -1571782963, -1579420756, -1587027620, -1594610700, -1602177572, -1609736195, -1617294837, -1624862021, -1632446443, -1640056905, -1647702233, -1655391198, -1663132437, -1670934364, -1678805091, -1686752340, -1694783362, -1702904853, -1711122871, -1719442762, -1727869081, -1736405525, -1745054857, -1753818853, -1762698238, -1771692639, -1780800539, -1790019236, -1799344816, -1808772129, -1818294772, -1827905083, -1837594141, -1847351778, -1857166592, -1867025979, -1876916162, -1886822239, -1896728231, -1906617142, -1916471028, -1926271066, -1935997638, -1945630418, -1955148467, -1964530324, -1973754119, -1982797673, -1991638612, -2000254479, -2008622849, -2016721450, -2024528274, -2032021698, -2039180600, -2045984471, -2052413530, -2058448831, -2064072367, -2069267172, -2074017414, -2078308486, -2082127083, -2085461286, -2088300620, -2090636121, -2092460384, -2093767604, -2094553610, -2094815892, -2094553610, -2093767604, -2092460384, -2090636121, -2088300620, -2085461286, -2082127083, -2078308486, -2074017414, -2069267172, -2064072367, -2058448831, -2052413530, -2045984471, -2039180600, -2032021698, -2024528274, -2016721450, -2008622849, -2000254479, -1991638612, -1982797673, -1973754119, -1964530324, -1955148467, -1945630418, -1935997638, -1926271066, -1916471028, -1906617142, -1896728231, -1886822239, -1876916162, -1867025979, -1857166592, -1847351778, -1837594141, -1827905083, -1818294772, -1808772129, -1799344816, -1790019236, -1780800539, -1771692639, -1762698238, -1753818853, -1745054857, -1736405525, -1727869081, -1719442762, -1711122871, -1702904853, -1694783362, -1686752340, -1678805091, -1670934364, -1663132437, -1655391198, -1647702233, -1640056905, -1632446443, -1624862021, -1617294837, -1609736195, -1602177572, -1594610700, -1587027620, -1579420756, -1571782963, -1564107588, -1556388510, -1548620183, -1540797674, -1532916687, -1524973591, -1516965431, -1508889943, -1500745554, -1492531382, -1484247228, -1475893560, -1467471496, -1458982778, -1450429739, -1441815276, -1433142803, -1424416215, -1415639839, -1406818386, -1397956897, -1389060697, -1380135333, -1371186520, -1362220089, -1353241925, -1344257918, -1335273902, -1326295606, -1317328602, -1308378255, -1299449678, -1290547689, -1281676771, -1272841035, -1264044191, -1255289515, -1246579832, -1237917491, -1229304354, -1220741787, -1212230651, -1203771307, -1195363615, -1187006947, -1178700198, -1170441803, -1162229758, -1154061648, -1145934672, -1137845676, -1129791186, -1121767447, -1113770460, -1105796025, -1097839781, -1089897248, -1081963873, -1074035073, -1066106278, -1058172973, -1050230741, -1042275301, -1034302552, -1026308602, -1018289807, -1010242803, -1002164531, -994052263, -985903629, -977716628, -969489647, -961221474, -952911300, -944558727, -936163763, -927726824, -919248720, -910730647, -902174172, -893581213, -884954016, -876295137, -867607408, -858893913, -850157953, -841403015, -832632737, -823850872, -815061252, -806267750, -797474245, -788684585, -779902549, -771131814, -762375918, -753638234, -744921930, -736229951, -727564983, -718929438, -710325427, -701754744, -693218854, -684718879, -676255587, -667829394, -659440355, -651088173, -642772197, -634491437, -626244569, -618029957, -609845663, -601689472, -593558914, -585451286, -577363683, -569293024, -561236083, -553189520, -545149914, -537113795, -529077677, -521038093, -512991626, -504934940, -496864815, -488778175, -480672113, -472543925, -464391130, -456211491, -448003040, -439764093, -431493263, -423189477, -414851978, -406480338, -398074456, -389634559, -381161200, -372655249, -364117886, -355550589, -346955115, -338333487, -329687973, -321021061, -312335437, -303633959, -294919628, -286195561, -277464959, -268731077, -259997195, -251266583, -242542473, -233828027, -225126307, -216440248, -207772627, -199126041, -190502878, -181905299, -173335215, -164794271, -156283830, -147804960, -139358425, -130944677, -122563857, -114215784, -105899969, -97615611, -89361609, -81136571, -72938830, -64766458, -56617283, -48488916, -40378766, -32284071, -24201918, -16129279, -8063034, ], [0, 7737190, 15475990, 23218003, 30964828, 38718051, 46479245, 54249963, 62031737, 69826074, 77634451, 85458312, 93299069, 101158090, 109036706, 116936199, 124857804, 132802706, 140772035, 148766864, 156788208, 164837021, 172914192, 181020545, 189156834, 197323747, 205521898, 213751828, 222014004, 230308819, 238636586, 246997544, 255391852, 263819589, 272280757, 280775278, 289302994, 297863668, 306456985, 315082550, 323739891, 332428459, 341147630, 349896705, 358674910, 367481401, 376315264, 385175516, 394061108, 402970927, 411903799, 420858489, 429833707, 438828108, 447840298, 456868832, 465912222, 474968938, 484037411, 493116037, 502203181, 511297180, 520396348, 529498976, 538603340, 547707704, 556810321, 565909440, 575003311, 584090184, 593168318, 602235983, 611291463, 620333063, 629359108, 638367955, 647357987, 656327625, 665275329, 674199598, 683098980, 691972074, 700817529, 709634053, 718420414, 727175441, 735898033, 744587156, 753241848, 761861223, 770444473, 778990869, 787499764, 795970596, 804402889, 812796254, 821150394, 829465100, 837740258, 845975845, 854171933, 862328689, 870446375, 878525348, 886566060, 894569059, 902534987, 910464583, 918358675, 926218187, 934044133, 941837617, 949599831, 957332054, 965035649, 972712061, 980362817, 987989517, 995593839, 1003177531, 1010742409, 1018290356, 1025823313, 1033343281, 1040852315, 1048352519, 1055846043, 1063335079, 1070821857, 1078308636, 1085797707, 1093291383, 1100791993, 1108301883, 1115823403, 1123358911, 1130910759, 1138481293, 1146072847, 1153687736, 1161328253, 1168996661, 1176695188, 1184426025, 1192191316, 1199993155, 1207833581, 1215714570, 1223638033, 1231605811, 1239619665, 1247681276, 1255792237, 1263954051, 1272168123, 1280435757, 1288758152, 1297136396, 1305571463, 1314064208, 1322615364, 1331225536, 1339895201, 1348624702, 1357414244, 1366263894, 1375173575, 1384143066, 1393171996, 1402259847, 1411405947, 1420609471, 1429869438, 1439184713, 1448554000, 1457975848, 1467448644, 1476970619, 1486539843, 1496154227, 1505811526, 1515509333, 1525245089, 1535016077, 1544819427, 1554652117, 1564510974, 1574392679, 1584293768, 1594210632, 1604139526, 1614076568, 1624017742, 1633958906, 1643895791, 1653824011, 1663739060, 1673636323, 1683511079, 1693358505, 1703173681, 1712951599, 1722687164, 1732375201, 1742010466, 1751587643, 1761101358, 1770546182, 1779916638, 1789207208, 1798412340, 1807526453, 1816543946, 1825459204, 1834266605, 1842960530, 1851535363, 1859985505, 1868305381, 1876489442, 1884532176, 1892428115, 1900171842, 1907757998, 1915181286, 1922436485, 1929518450, 1936422122, 1943142536, 1949674824, 1956014225, 1962156090, 1968095886, 1973829209, 1979351780, 1984659462, 1989748255, 1994614309, 1999253927, 2003663567, 2007839853, 2011779573, 2015479690, 2018937341, 2022149841, 2025114693, 2027829583, 2030292389, 2032501181, 2034454227, 2036149991, 2037587138, 2038764536, 2039681256, 2040336574, 2040729972, 2040861139, 2040729972, 2040336574, 2039681256, 2038764536, 2037587138, 2036149991, 2034454227, 2032501181, 2030292389, 2027829583, 2025114693, 2022149841, 2018937341, 2015479690, 2011779573, 2007839853, 2003663567, 1999253927, 1994614309, 1989748255, 1984659462, 1979351780, 1973829209, 1968095886, 1962156090, 1956014225, 1949674824, 1943142536, 1936422122, 1929518450, 1922436485, 1915181286, 1907757998, 1900171842, 1892428115, 1884532176, 1876489442, 1868305381, 1859985505, 1851535363, 1842960530, 1834266605, 1825459204, 1816543946, 1807526453, 1798412340, 1789207208, 1779916638, 1770546182, 1761101358, 1751587643, 1742010466, 1732375201, 1722687164, 1712951599, 1703173681, 1693358505, 1683511079, 1673636323, 1663739060, 1653824011, 1643895791, 1633958906, 1624017742, 1614076568, 1604139526, 1594210632, 1584293768, 1574392679, 1564510974, 1554652117, 1544819427, 1535016077, 1525245089, 1515509333, 1505811526, 1496154227, 1486539843, 1476970619, 1467448644, 1457975848, 1448554000, 1439184713, 1429869438, 1420609471, 1411405947, 1402259847, 1393171996, 1384143066, 1375173575, 1366263894, 1357414244, 1348624702, 1339895201, 1331225536, 1322615364, 1314064208, 1305571463, 1297136396, 1288758152, 1280435757, 1272168123, 1263954051, 1255792237, 1247681276, 1239619665, 1231605811, 1223638033, 1215714570, 1207833581, 1199993155, 1192191316, 1184426025, 1176695188, 1168996661, 1161328253, 1153687736, 1146072847, 1138481293, 1130910759, 1123358911, 1115823403, 1108301883, 1100791993, 1093291383, 1085797707, 1078308636, 1070821857, 1063335079, 1055846043, 1048352519, 1040852315, 1033343281, 1025823313, 1018290356, 1010742409, 1003177531, 995593839, 987989517, 980362817, 972712061, 965035649, 957332054, 949599831, 941837617, 934044133, 926218187, 918358675, 910464583, 902534987, 894569059, 886566060, 878525348, 870446375, 862328689, 854171933, 845975845, 837740258, 829465100, 821150394, 812796254, 804402889, 795970596, 787499764, 778990869, 770444473, 761861223, 753241848, 744587156, 735898033, 727175441, 718420414, 709634053, 700817529, 691972074, 683098980, 674199598, 665275329, 656327625, 647357987, 638367955, 629359108, 620333063, 611291463, 602235983, 593168318, 584090184, 575003311, 565909440, 556810321, 547707704, 538603340, 529498976, 520396348, 511297180, 502203181, 493116037, 484037411, 474968938, 465912222, 456868832, 447840298, 438828108, 429833707, 420858489, 411903799, 402970927, 394061108, 385175516, 376315264, 367481401, 358674910, 349896705, 341147630, 332428459, 323739891, 315082550, 306456985, 297863668, 289302994, 280775278, 272280757, 263819589, 255391852, 246997544, 238636586, 230308819, 222014004, 213751828, 205521898, 197323747, 189156834, 181020545, 172914192, 164837021, 156788208, 148766864, 140772035, 132802706, 124857804, 116936199, 109036706, 101158090, 93299069, 85458312, 77634451, 69826074, 62031737, 54249963, 46479245, 38718051, 30964828, 23218003, 15475990, 7737190, 0, -7737190, -15475990, -23218003, -30964828, -38718051, -46479245, -54249963, -62031737, -69826074, -77634451, -85458312, -93299069, -101158090, -109036706, -116936199, -124857804, -132802706, -140772035, -148766864, -156788208, -164837021, -172914192, -181020545, -189156834, -197323747, -205521898, -213751828, -222014004, -230308819, -238636586, -246997544, -255391852, -263819589, -272280757, -280775278, -289302994, -297863668, -306456985, -315082550, -323739891, -332428459, -341147630, -349896705, -358674910, -367481401, -376315264, -385175516, -394061108, -402970927, -411903799, -420858489, -429833707, -438828108, -447840298, -456868832, -465912222, -474968938, -484037411, -493116037, -502203181, -511297180, -520396348, -529498976, -538603340, -547707704, -556810321, -565909440, -575003311, -584090184, -593168318, -602235983, -611291463, -620333063, -629359108, -638367955, -647357987, -656327625, -665275329, -674199598, -683098980, -691972074, -700817529, -709634053, -718420414, -727175441, -735898033, -744587156, -753241848, -761861223, -770444473, -778990869, -787499764, -795970596, -804402889, -812796254, -821150394, -829465100, -837740258, -845975845, -854171933, -862328689, -870446375, -878525348, -886566060, -894569059, -902534987, -910464583, -918358675, -926218187, -934044133, -941837617, -949599831, -957332054, -965035649, -972712061, -980362817, -987989517, -995593839, -1003177531, -1010742409, -1018290356, -1025823313, -1033343281, -1040852315, -1048352519, -1055846043, -1063335079, -1070821857, -1078308636, -1085797707, -1093291383, -1100791993, -1108301883, -1115823403, -1123358911, -1130910759, -1138481293, -1146072847, -1153687736, -1161328253, -1168996661, -1176695188, -1184426025, -1192191316, -1199993155, -1207833581, -1215714570, -1223638033, -1231605811, -1239619665, -1247681276, -1255792237, -1263954051, -1272168123, -1280435757, -1288758152, -1297136396, -1305571463, -1314064208, -1322615364, -1331225536, -1339895201, -1348624702, -1357414244, -1366263894, -1375173575, -1384143066, -1393171996, -1402259847, -1411405947, -1420609471, -1429869438, -1439184713, -1448554000, -1457975848, -1467448644, -1476970619, -1486539843, -1496154227, -1505811526, -1515509333, -1525245089, -1535016077, -1544819427, -1554652117, -1564510974, -1574392679, -1584293768, -1594210632, -1604139526, -1614076568, -1624017742, -1633958906, -1643895791, -1653824011, -1663739060, -1673636323, -1683511079, -1693358505, -1703173681, -1712951599, -1722687164, -1732375201, -1742010466, -1751587643, -1761101358, -1770546182, -1779916638, -1789207208, -1798412340, -1807526453, -1816543946, -1825459204, -1834266605, -1842960530, -1851535363, -1859985505, -1868305381, -1876489442, -1884532176, -1892428115, -1900171842, -1907757998, -1915181286, -1922436485, -1929518450, -1936422122, -1943142536, -1949674824, -1956014225, -1962156090, -1968095886, -1973829209, -1979351780, -1984659462, -1989748255, -1994614309, -1999253927, -2003663567, -2007839853, -2011779573, -2015479690, -2018937341, -2022149841, -2025114693, -2027829583, -2030292389, -2032501181, -2034454227, -2036149991, -2037587138, -2038764536, -2039681256, -2040336574, -2040729972, -2040861139, -2040729972, -2040336574, -2039681256, -2038764536, -2037587138, -2036149991, -2034454227, -2032501181, -2030292389, -2027829583, -2025114693, -2022149841, -2018937341, -2015479690, -2011779573, -2007839853, -2003663567, -1999253927, -1994614309, -1989748255, -1984659462, -1979351780, -1973829209, -1968095886, -1962156090, -1956014225, -1949674824, -1943142536, -1936422122, -1929518450, -1922436485, -1915181286, -1907757998, -1900171842, -1892428115, -1884532176, -1876489442, -1868305381, -1859985505, -1851535363, -1842960530, -1834266605, -1825459204, -1816543946, -1807526453, -1798412340, -1789207208, -1779916638, -1770546182, -1761101358, -1751587643, -1742010466, -1732375201, -1722687164, -1712951599, -1703173681, -1693358505, -1683511079, -1673636323, -1663739060, -1653824011, -1643895791, -1633958906, -1624017742, -1614076568, -1604139526, -1594210632, -1584293768, -1574392679, -1564510974, -1554652117, -1544819427, -1535016077, -1525245089, -1515509333, -1505811526, -1496154227, -1486539843, -1476970619, -1467448644, -1457975848, -1448554000, -1439184713, -1429869438, -1420609471, -1411405947, -1402259847, -1393171996, -1384143066, -1375173575, -1366263894, -1357414244, -1348624702, -1339895201, -1331225536, -1322615364, -1314064208, -1305571463, -1297136396, -1288758152, -1280435757, -1272168123, -1263954051, -1255792237, -1247681276, -1239619665, -1231605811, -1223638033, -1215714570, -1207833581, -1199993155, -1192191316, -1184426025, -1176695188, -1168996661, -1161328253, -1153687736, -1146072847, -1138481293, -1130910759, -1123358911, -1115823403, -1108301883, -1100791993, -1093291383, -1085797707, -1078308636, -1070821857, -1063335079, -1055846043, -1048352519, -1040852315, -1033343281, -1025823313, -1018290356, -1010742409, -1003177531, -995593839, -987989517, -980362817, -972712061, -965035649, -957332054, -949599831, -941837617, -934044133, -926218187, -918358675, -910464583, -902534987, -894569059, -886566060, -878525348, -870446375, -862328689, -854171933, -845975845, -837740258, -829465100, -821150394, -812796254, -804402889, -795970596, -787499764, -778990869, -770444473, -761861223, -753241848, -744587156, -735898033, -727175441, -718420414, -709634053, -700817529, -691972074, -683098980, -674199598, -665275329, -656327625, -647357987, -638367955, -629359108, -620333063, -611291463, -602235983, -593168318, -584090184, -575003311, -565909440, -556810321, -547707704, -538603340, -529498976, -520396348, -511297180, -502203181, -493116037, -484037411, -474968938, -465912222, -456868832, -447840298, -438828108, -429833707, -420858489, -411903799, -402970927, -394061108, -385175516, -376315264, -367481401, -358674910, -349896705, -341147630, -332428459, -323739891, -315082550, -306456985, -297863668, -289302994, -280775278, -272280757, -263819589, -255391852, -246997544, -238636586, -230308819, -222014004, -213751828, -205521898, -197323747, -189156834, -181020545, -172914192, -164837021, -156788208, -148766864, -140772035, -132802706, -124857804, -116936199, -109036706, -101158090, -93299069, -85458312, -77634451, -69826074, -62031737, -54249963, -46479245, -38718051, -30964828, -23218003, -15475990, -7737190, ], [0, 7126247, 14253298, 21381959, 28513032, 35647320, 42785625, 49928745, 57077476, 64232612, 71394944, 78565259, 85744340, 92932967, 100131912, 107341946, 114563832, 121798328, 129046185, 136308150, 143584958, 150877343, 158186025, 165511722, 172855138, 180216973, 187597914, 194998640, 202419822, 209862118, 217326177, 224812637, 232322124, 239855253, 247412627, 254994837, 262602462, 270236068, 277896206, 285583415, 293298222, 301041138, 308812659, 316613267, 324443431, 332303602, 340194217, 348115698, 356068449, 364052860, 372069303, 380118134, 388199692, 396314298, 404462257, 412643856, 420859363, 429109029, 437393087, 445711751, 454065217, 462453661, 470877241, 479336096, 487830346, 496360090, 504925408, 513526362, 522162992, 530835319, 539543343, 548287046, 557066387, 565881306, 574731723, 583617536, 592538623, 601494842, 610486029, 619511999, 628572548, 637667449, 646796456, 655959300, 665155692, 674385323, 683647860, 692942953, 702270228, 711629292, 721019729, 730441103, 739892958, 749374817, 758886181, 768426532, 777995330, 787592015, 797216008, 806866706, 816543490, 826245718, 835972730, 845723844, 855498359, 865295557, 875114696, 884955019, 894815746, 904696081, 914595208, 924512292, 934446481, 944396903, 954362669, 964342873, 974336589, 984342875, 994360773, 1004389306, 1014427481, 1024474290, 1034528707, 1044589690, 1054656182, 1064727111, 1074801390, 1084877915, 1094955571, 1105033226, 1115109734, 1125183937, 1135254663, 1145320725, 1155380928, 1165434059, 1175478897, 1185514207, 1195538743, 1205551250, 1215550459, 1225535093, 1235503864, 1245455475, 1255388618, 1265301979, 1275194232, 1285064045, 1294910079, 1304730985, 1314525408, 1324291988, 1334029356, 1343736139, 1353410957, 1363052427, 1372659159, 1382229760, 1391762833, 1401256977, 1410710789, 1420122862, 1429491787, 1438816154, 1448094551, 1457325563, 1466507779, 1475639783, 1484720162, 1493747503, 1502720393, 1511637422, 1520497181, 1529298264, 1538039266, 1546718786, 1555335429, 1563887799, 1572374509, 1580794174, 1589145416, 1597426859, 1605637139, 1613774892, 1621838766, 1629827413, 1637739495, 1645573679, 1653328642, 1661003072, 1668595663, 1676105120, 1683530158, 1690869502, 1698121888, 1705286064, 1712360787, 1719344830, 1726236975, 1733036017, 1739740765, 1746350042, 1752862683, 1759277538, 1765593471, 1771809362, 1777924105, 1783936609, 1789845801, 1795650623, 1801350031, 1806943002, 1812428527, 1817805616, 1823073296, 1828230612, 1833276629, 1838210426, 1843031107, 1847737791, 1852329616, 1856805743, 1861165351, 1865407638, 1869531825, 1873537151, 1877422878, 1881188289, 1884832686, 1888355396, 1891755766, 1895033163, 1898186981, 1901216631, 1904121550, 1906901198, 1909555054, 1912082626, 1914483439, 1916757045, 1918903019, 1920920960, 1922810488, 1924571251, 1926202917, 1927705181, 1929077760, 1930320397, 1931432859, 1932414935, 1933266442, 1933987219, 1934577130, 1935036066, 1935363939, 1935560687, 1935626274, 1935560687, 1935363939, 1935036066, 1934577130, 1933987219, 1933266442, 1932414935, 1931432859, 1930320397, 1929077760, 1927705181, 1926202917, 1924571251, 1922810488, 1920920960, 1918903019, 1916757045, 1914483439, 1912082626, 1909555054, 1906901198, 1904121550, 1901216631, 1898186981, 1895033163, 1891755766, 1888355396, 1884832686, 1881188289, 1877422878, 1873537151, 1869531825, 1865407638, 1861165351, 1856805743, 1852329616, 1847737791, 1843031107, 1838210426, 1833276629, 1828230612, 1823073296, 1817805616, 1812428527, 1806943002, 1801350031, 1795650623, 1789845801, 1783936609, 1777924105, 1771809362, 1765593471, 1759277538, 1752862683, 1746350042, 1739740765, 1733036017, 1726236975, 1719344830, 1712360787, 1705286064, 1698121888, 1690869502, 1683530158, 1676105120, 1668595663, 1661003072, 1653328642, 1645573679, 1637739495, 1629827413, 1621838766, 1613774892, 1605637139, 1597426859, 1589145416, 1580794174, 1572374509, 1563887799, 1555335429, 1546718786, 1538039266, 1529298264, 1520497181, 1511637422, 1502720393, 1493747503, 1484720162, 1475639783, 1466507779, 1457325563, 1448094551, 1438816154, 1429491787, 1420122862, 1410710789, 1401256977, 1391762833, 1382229760, 1372659159, 1363052427, 1353410957, 1343736139, 1334029356, 1324291988, 1314525408, 1304730985, 1294910079, 1285064045, 1275194232, 1265301979, 1255388618, 1245455475, 1235503864, 1225535093, 1215550459, 1205551250, 1195538743, 1185514207, 1175478897, 1165434059, 1155380928, 1145320725, 1135254663, 1125183937, 1115109734, 1105033226, 1094955571, 1084877915, 1074801390, 1064727111, 1054656182, 1044589690, 1034528707, 1024474290, 1014427481, 1004389306, 994360773, 984342875, 974336589, 964342873, 954362669, 944396903, 934446481, 924512292, 914595208, 904696081, 894815746, 884955019, 875114696, 865295557, 855498359, 845723844, 835972730, 826245718, 816543490, 806866706, 797216008, 787592015, 777995330, 768426532, 758886181, 749374817, 739892958, 730441103, 721019729, 711629292, 702270228, 692942953, 683647860, 674385323, 665155692, 655959300, 646796456, 637667449, 628572548, 619511999, 610486029, 601494842, 592538623, 583617536, 574731723, 565881306, 557066387, 548287046, 539543343, 530835319, 522162992, 513526362, 504925408, 496360090, 487830346, 479336096, 470877241, 462453661, 454065217, 445711751, 437393087, 429109029, 420859363, 412643856, 404462257, 396314298, 388199692, 380118134, 372069303, 364052860, 356068449, 348115698, 340194217, 332303602, 324443431, 316613267, 308812659, 301041138, 293298222, 285583415, 277896206, 270236068, 262602462, 254994837, 247412627, 239855253, 232322124, 224812637, 217326177, 209862118, 202419822, 194998640, 187597914, 180216973, 172855138, 165511722, 158186025, 150877343, 143584958, 136308150, 129046185, 121798328, 114563832, 107341946, 100131912, 92932967, 85744340, 78565259, 71394944, 64232612, 57077476, 49928745, 42785625, 35647320, 28513032, 21381959, 14253298, 7126247, 0, -7126247, -14253298, -21381959, -28513032, -35647320, -42785625, -49928745, -57077476, -64232612, -71394944, -78565259, -85744340, -92932967, -100131912, -107341946, -114563832, -121798328, -129046185, -136308150, -143584958, -150877343, -158186025, -165511722, -172855138, -180216973, -187597914, -194998640, -202419822, -209862118, -217326177, -224812637, -232322124, -239855253, -247412627, -254994837, -262602462, -270236068, -277896206, -285583415, -293298222, -301041138, -308812659, -316613267, -324443431, -332303602, -340194217, -348115698, -356068449, -364052860, -372069303, -380118134, -388199692, -396314298, -404462257, -412643856, -420859363, -429109029, -437393087, -445711751, -454065217, -462453661, -470877241, -479336096, -487830346, -496360090, -504925408, -513526362, -522162992, -530835319, -539543343, -548287046, -557066387, -565881306, -574731723, -583617536, -592538623, -601494842, -610486029, -619511999, -628572548, -637667449, -646796456, -655959300, -665155692, -674385323, -683647860, -692942953, -702270228, -711629292, -721019729, -730441103, -739892958, -749374817, -758886181, -768426532, -777995330, -787592015, -797216008, -806866706, -816543490, -826245718, -835972730, -845723844, -855498359, -865295557, -875114696, -884955019, -894815746, -904696081, -914595208, -924512292, -934446481, -944396903, -954362669, -964342873, -974336589, -984342875, -994360773, -1004389306, -1014427481, -1024474290, -1034528707, -1044589690, -1054656182, -1064727111, -1074801390, -1084877915, -1094955571, -1105033226, -1115109734, -1125183937, -1135254663, -1145320725, -1155380928, -1165434059, -1175478897, -1185514207, -1195538743, -1205551250, -1215550459, -1225535093, -1235503864, -1245455475, -1255388618, -1265301979, -1275194232, -1285064045, -1294910079, -1304730985, -1314525408, -1324291988, -1334029356, -1343736139, -1353410957, -1363052427, -1372659159, -1382229760, -1391762833, -1401256977, -1410710789, -1420122862, -1429491787, -1438816154, -1448094551, -1457325563, -1466507779, -1475639783, -1484720162, -1493747503, -1502720393, -1511637422, -1520497181, -1529298264, -1538039266, -1546718786, -1555335429, -1563887799, -1572374509, -1580794174, -1589145416, -1597426859, -1605637139, -1613774892, -1621838766, -1629827413, -1637739495, -1645573679, -1653328642, -1661003072, -1668595663, -1676105120, -1683530158, -1690869502, -1698121888, -1705286064, -1712360787, -1719344830, -1726236975, -1733036017, -1739740765, -1746350042, -1752862683, -1759277538, -1765593471, -1771809362, -1777924105, -1783936609, -1789845801, -1795650623, -1801350031, -1806943002, -1812428527, -1817805616, -1823073296, -1828230612, -1833276629, -1838210426, -1843031107, -1847737791, -1852329616, -1856805743, -1861165351, -1865407638, -1869531825, -1873537151, -1877422878, -1881188289, -1884832686, -1888355396, -1891755766, -1895033163, -1898186981, -1901216631, -1904121550, -1906901198, -1909555054, -1912082626, -1914483439, -1916757045, -1918903019, -1920920960, -1922810488, -1924571251, -1926202917, -1927705181, -1929077760, -1930320397, -1931432859, -1932414935, -1933266442, -1933987219, -1934577130, -1935036066, -1935363939, -1935560687, -1935626274, -1935560687, -1935363939, -1935036066, -1934577130, -1933987219, -1933266442, -1932414935, -1931432859, -1930320397, -1929077760, -1927705181, -1926202917, -1924571251, -1922810488, -1920920960, -1918903019, -1916757045, -1914483439, -1912082626, -1909555054, -1906901198, -1904121550, -1901216631, -1898186981, -1895033163, -1891755766, -1888355396, -1884832686, -1881188289, -1877422878, -1873537151, -1869531825, -1865407638, -1861165351, -1856805743, -1852329616, -1847737791, -1843031107, -1838210426, -1833276629, -1828230612, -1823073296, -1817805616, -1812428527, -1806943002, -1801350031, -1795650623, -1789845801, -1783936609, -1777924105, -1771809362, -1765593471, -1759277538, -1752862683, -1746350042, -1739740765, -1733036017, -1726236975, -1719344830, -1712360787, -1705286064, -1698121888, -1690869502, -1683530158, -1676105120, -1668595663, -1661003072, -1653328642, -1645573679, -1637739495, -1629827413, -1621838766, -1613774892, -1605637139, -1597426859, -1589145416, -1580794174, -1572374509, -1563887799, -1555335429, -1546718786, -1538039266, -1529298264, -1520497181, -1511637422, -1502720393, -1493747503, -1484720162, -1475639783, -1466507779, -1457325563, -1448094551, -1438816154, -1429491787, -1420122862, -1410710789, -1401256977, -1391762833, -1382229760, -1372659159, -1363052427, -1353410957, -1343736139, -1334029356, -1324291988, -1314525408, -1304730985, -1294910079, -1285064045, -1275194232, -1265301979, -1255388618, -1245455475, -1235503864, -1225535093, -1215550459, -1205551250, -1195538743, -1185514207, -1175478897, -1165434059, -1155380928, -1145320725, -1135254663, -1125183937, -1115109734, -1105033226, -1094955571, -1084877915, -1074801390, -1064727111, -1054656182, -1044589690, -1034528707, -1024474290, -1014427481, -1004389306, -994360773, -984342875, -974336589, -964342873, -954362669, -944396903, -934446481, -924512292, -914595208, -904696081, -894815746, -884955019, -875114696, -865295557, -855498359, -845723844, -835972730, -826245718, -816543490, -806866706, -797216008, -787592015, -777995330, -768426532, -758886181, -749374817, -739892958, -730441103, -721019729, -711629292, -702270228, -692942953, -683647860, -674385323, -665155692, -655959300, -646796456, -637667449, -628572548, -619511999, -610486029, -601494842, -592538623, -583617536, -574731723, -565881306, -557066387, -548287046, -539543343, -530835319, -522162992, -513526362, -504925408, -496360090, -487830346, -479336096, -470877241, -462453661, -454065217, -445711751, -437393087, -429109029, -420859363, -412643856, -404462257, -396314298, -388199692, -380118134, -372069303, -364052860, -356068449, -348115698, -340194217, -332303602, -324443431, -316613267, -308812659, -301041138, -293298222, -285583415, -277896206, -270236068, -262602462, -254994837, -247412627, -239855253, -232322124, -224812637, -217326177, -209862118, -202419822, -194998640, -187597914, -180216973, -172855138, -165511722, -158186025, -150877343, -143584958, -136308150, -129046185, -121798328, -114563832, -107341946, -100131912, -92932967, -85744340, -78565259, -71394944, -64232612, -57077476, -49928745, -42785625, -35647320, -28513032, -21381959, -14253298, -7126247, ], [0, 10689102, 21377801, 32065695, 42752382, 53437459, 64120525, 74801176, 85479012, 96153629, 106824625, 117491600, 128154152, 138811878, 149464379, 160111252, 170752097, 181386513, 192014100, 202634458, 213247187, 223851887, 234448160, 245035605, 255613825, 266182422, 276740996, 287289152, 297826491, 308352618, 318867135, 329369646, 339859758, 350337073, 360801199, 371251741, 381688305, 392110499, 402517931, 412910207, 423286938, 433647733, 443992200, 454319952, 464630599, 474923753, 485199026, 495456031, 505694383, 515913696, 526113585, 536293666, 546453556, 556592873, 566711234, 576808258, 586883566, 596936779, 606967517, 616975402, 626960060, 636921112, 646858185, 656770904, 666658896, 676521788, 686359210, 696170791, 705956161, 715714953, 725446798, 735151331, 744828185, 754476997, 764097404, 773689043, 783251552, 792784573, 802287746, 811760713, 821203118, 830614605, 839994820, 849343410, 858660022, 867944306, 877195913, 886414493, 895599701, 904751190, 913868615, 922951634, 931999904, 941013085, 949990838, 958932823, 967838706, 976708150, 985540821, 994336387, 1003094518, 1011814882, 1020497152, 1029141000, 1037746103, 1046312134, 1054838773, 1063325697, 1071772588, 1080179127, 1088544998, 1096869886, 1105153478, 1113395461, 1121595525, 1129753362, 1137868664, 1145941127, 1153970445, 1161956317, 1169898442, 1177796520, 1185650256, 1193459352, 1201223516, 1208942454, 1216615876, 1224243493, 1231825018, 1239360165, 1246848651, 1254290194, 1261684514, 1269031332, 1276330372, 1283581359, 1290784019, 1297938082, 1305043279, 1312099342, 1319106004, 1326063003, 1332970077, 1339826965, 1346633409, 1353389153, 1360093943, 1366747526, 1373349652, 1379900072, 1386398540, 1392844810, 1399238641, 1405579791, 1411868022, 1418103097, 1424284781, 1430412841, 1436487048, 1442507171, 1448472985, 1454384264, 1460240787, 1466042333, 1471788683, 1477479620, 1483114932, 1488694405, 1494217830, 1499684998, 1505095704, 1510449744, 1515746916, 1520987022, 1526169863, 1531295244, 1536362974, 1541372860, 1546324714, 1551218350, 1556053583, 1560830232, 1565548117, 1570207059, 1574806884, 1579347419, 1583828492, 1588249935, 1592611581, 1596913266, 1601154828, 1605336108, 1609456947, 1613517192, 1617516688, 1621455286, 1625332837, 1629149195, 1632904217, 1636597761, 1640229687, 1643799860, 1647308145, 1650754410, 1654138525, 1657460362, 1660719797, 1663916706, 1667050971, 1670122471, 1673131093, 1676076722, 1678959247, 1681778561, 1684534557, 1687227131, 1689856182, 1692421611, 1694923321, 1697361218, 1699735210, 1702045209, 1704291126, 1706472878, 1708590382, 1710643558, 1712632330, 1714556622, 1716416362, 1718211480, 1719941909, 1721607582, 1723208438, 1724744416, 1726215458, 1727621510, 1728962517, 1730238430, 1731449200, 1732594783, 1733675134, 1734690213, 1735639982, 1736524405, 1737343449, 1738097084, 1738785279, 1739408011, 1739965254, 1740456989, 1740883197, 1741243862, 1741538969, 1741768509, 1741932472, 1742030852, 1742063646, 1742030852, 1741932472, 1741768509, 1741538969, 1741243862, 1740883197, 1740456989, 1739965254, 1739408011, 1738785279, 1738097084, 1737343449, 1736524405, 1735639982, 1734690213, 1733675134, 1732594783, 1731449200, 1730238430, 1728962517, 1727621510, 1726215458, 1724744416, 1723208438, 1721607582, 1719941909, 1718211480, 1716416362, 1714556622, 1712632330, 1710643558, 1708590382, 1706472878, 1704291126, 1702045209, 1699735210, 1697361218, 1694923321, 1692421611, 1689856182, 1687227131, 1684534557, 1681778561, 1678959247, 1676076722, 1673131093, 1670122471, 1667050971, 1663916706, 1660719797, 1657460362, 1654138525, 1650754410, 1647308145, 1643799860, 1640229687, 1636597761, 1632904217, 1629149195, 1625332837, 1621455286, 1617516688, 1613517192, 1609456947, 1605336108, 1601154828, 1596913266, 1592611581, 1588249935, 1583828492, 1579347419, 1574806884, 1570207059, 1565548117, 1560830232, 1556053583, 1551218350, 1546324714, 1541372860, 1536362974, 1531295244, 1526169863, 1520987022, 1515746916, 1510449744, 1505095704, 1499684998, 1494217830, 1488694405, 1483114932, 1477479620, 1471788683, 1466042333, 1460240787, 1454384264, 1448472985, 1442507171, 1436487048, 1430412841, 1424284781, 1418103097, 1411868022, 1405579791, 1399238641, 1392844810, 1386398540, 1379900072, 1373349652, 1366747526, 1360093943, 1353389153, 1346633409, 1339826965, 1332970077, 1326063003, 1319106004, 1312099342, 1305043279, 1297938082, 1290784019, 1283581359, 1276330372, 1269031332, 1261684514, 1254290194, 1246848651, 1239360165, 1231825018, 1224243493, 1216615876, 1208942454, 1201223516, 1193459352, 1185650256, 1177796520, 1169898442, 1161956317, 1153970445, 1145941127, 1137868664, 1129753362, 1121595525, 1113395461, 1105153478, 1096869886, 1088544998, 1080179127, 1071772588, 1063325697, 1054838773, 1046312134, 1037746103, 1029141000, 1020497152, 1011814882, 1003094518, 994336387, 985540821, 976708150, 967838706, 958932823, 949990838, 941013085, 931999904, 922951634, 913868615, 904751190, 895599701, 886414493, 877195913, 867944306, 858660022, 849343410, 839994820, 830614605, 821203118, 811760713, 802287746, 792784573, 783251552, 773689043, 764097404, 754476997, 744828185, 735151331, 725446798, 715714953, 705956161, 696170791, 686359210, 676521788, 666658896, 656770904, 646858185, 636921112, 626960060, 616975402, 606967517, 596936779, 586883566, 576808258, 566711234, 556592873, 546453556, 536293666, 526113585, 515913696, 505694383, 495456031, 485199026, 474923753, 464630599, 454319952, 443992200, 433647733, 423286938, 412910207, 402517931, 392110499, 381688305, 371251741, 360801199, 350337073, 339859758, 329369646, 318867135, 308352618, 297826491, 287289152, 276740996, 266182422, 255613825, 245035605, 234448160, 223851887, 213247187, 202634458, 192014100, 181386513, 170752097, 160111252, 149464379, 138811878, 128154152, 117491600, 106824625, 96153629, 85479012, 74801176, 64120525, 53437459, 42752382, 32065695, 21377801, 10689102, 0, -10689102, -21377801, -32065695, -42752382, -53437459, -64120525, -74801176, -85479012, -96153629, -106824625, -117491600, -128154152, -138811878, -149464379, -160111252, -170752097, -181386513, -192014100, -202634458, -213247187, -223851887, -234448160, -245035605, -255613825, -266182422, -276740996, -287289152, -297826491, -308352618, -318867135, -329369646, -339859758, -350337073, -360801199, -371251741, -381688305, -392110499, -402517931, -412910207, -423286938, -433647733, -443992200, -454319952, -464630599, -474923753, -485199026, -495456031, -505694383, -515913696, -526113585, -536293666, -546453556, -556592873, -566711234, -576808258, -586883566, -596936779, -606967517, -616975402, -626960060, -636921112, -646858185, -656770904, -666658896, -676521788, -686359210, -696170791, -705956161, -715714953, -725446798, -735151331, -744828185, -754476997, -764097404, -773689043, -783251552, -792784573, -802287746, -811760713, -821203118, -830614605, -839994820, -849343410, -858660022, -867944306, -877195913, -886414493, -895599701, -904751190, -913868615, -922951634, -931999904, -941013085, -949990838, -958932823, -967838706, -976708150, -985540821, -994336387, -1003094518, -1011814882, -1020497152, -1029141000, -1037746103, -1046312134, -1054838773, -1063325697, -1071772588, -1080179127, -1088544998, -1096869886, -1105153478, -1113395461, -1121595525, -1129753362, -1137868664, -1145941127, -1153970445, -1161956317, -1169898442, -1177796520, -1185650256, -1193459352, -1201223516, -1208942454, -1216615876, -1224243493, -1231825018, -1239360165, -1246848651, -1254290194, -1261684514, -1269031332, -1276330372, -1283581359, -1290784019, -1297938082, -1305043279, -1312099342, -1319106004, -1326063003, -1332970077, -1339826965, -1346633409, -1353389153, -1360093943, -1366747526, -1373349652, -1379900072, -1386398540, -1392844810, -1399238641, -1405579791, -1411868022, -1418103097, -1424284781, -1430412841, -1436487048, -1442507171, -1448472985, -1454384264, -1460240787, -1466042333, -1471788683, -1477479620, -1483114932, -1488694405, -1494217830, -1499684998, -1505095704, -1510449744, -1515746916, -1520987022, -1526169863, -1531295244, -1536362974, -1541372860, -1546324714, -1551218350, -1556053583, -1560830232, -1565548117, -1570207059, -1574806884, -1579347419, -1583828492, -1588249935, -1592611581, -1596913266, -1601154828, -1605336108, -1609456947, -1613517192, -1617516688, -1621455286, -1625332837, -1629149195, -1632904217, -1636597761, -1640229687, -1643799860, -1647308145, -1650754410, -1654138525, -1657460362, -1660719797, -1663916706, -1667050971, -1670122471, -1673131093, -1676076722, -1678959247, -1681778561, -1684534557, -1687227131, -1689856182, -1692421611, -1694923321, -1697361218, -1699735210, -1702045209, -1704291126, -1706472878, -1708590382, -1710643558, -1712632330, -1714556622, -1716416362, -1718211480, -1719941909, -1721607582, -1723208438, -1724744416, -1726215458, -1727621510, -1728962517, -1730238430, -1731449200, -1732594783, -1733675134, -1734690213, -1735639982, -1736524405, -1737343449, -1738097084, -1738785279, -1739408011, -1739965254, -1740456989, -1740883197, -1741243862, -1741538969, -1741768509, -1741932472, -1742030852, -1742063646, -1742030852, -1741932472, -1741768509, -1741538969, -1741243862, -1740883197, -1740456989, -1739965254, -1739408011, -1738785279, -1738097084, -1737343449, -1736524405, -1735639982, -1734690213, -1733675134, -1732594783, -1731449200, -1730238430, -1728962517, -1727621510, -1726215458, -1724744416, -1723208438, -1721607582, -1719941909, -1718211480, -1716416362, -1714556622, -1712632330, -1710643558, -1708590382, -1706472878, -1704291126, -1702045209, -1699735210, -1697361218, -1694923321, -1692421611, -1689856182, -1687227131, -1684534557, -1681778561, -1678959247, -1676076722, -1673131093, -1670122471, -1667050971, -1663916706, -1660719797, -1657460362, -1654138525, -1650754410, -1647308145, -1643799860, -1640229687, -1636597761, -1632904217, -1629149195, -1625332837, -1621455286, -1617516688, -1613517192, -1609456947, -1605336108, -1601154828, -1596913266, -1592611581, -1588249935, -1583828492, -1579347419, -1574806884, -1570207059, -1565548117, -1560830232, -1556053583, -1551218350, -1546324714, -1541372860, -1536362974, -1531295244, -1526169863, -1520987022, -1515746916, -1510449744, -1505095704, -1499684998, -1494217830, -1488694405, -1483114932, -1477479620, -1471788683, -1466042333, -1460240787, -1454384264, -1448472985, -1442507171, -1436487048, -1430412841, -1424284781, -1418103097, -1411868022, -1405579791, -1399238641, -1392844810, -1386398540, -1379900072, -1373349652, -1366747526, -1360093943, -1353389153, -1346633409, -1339826965, -1332970077, -1326063003, -1319106004, -1312099342, -1305043279, -1297938082, -1290784019, -1283581359, -1276330372, -1269031332, -1261684514, -1254290194, -1246848651, -1239360165, -1231825018, -1224243493, -1216615876, -1208942454, -1201223516, -1193459352, -1185650256, -1177796520, -1169898442, -1161956317, -1153970445, -1145941127, -1137868664, -1129753362, -1121595525, -1113395461, -1105153478, -1096869886, -1088544998, -1080179127, -1071772588, -1063325697, -1054838773, -1046312134, -1037746103, -1029141000, -1020497152, -1011814882, -1003094518, -994336387, -985540821, -976708150, -967838706, -958932823, -949990838, -941013085, -931999904, -922951634, -913868615, -904751190, -895599701, -886414493, -877195913, -867944306, -858660022, -849343410, -839994820, -830614605, -821203118, -811760713, -802287746, -792784573, -783251552, -773689043, -764097404, -754476997, -744828185, -735151331, -725446798, -715714953, -705956161, -696170791, -686359210, -676521788, -666658896, -656770904, -646858185, -636921112, -626960060, -616975402, -606967517, -596936779, -586883566, -576808258, -566711234, -556592873, -546453556, -536293666, -526113585, -515913696, -505694383, -495456031, -485199026, -474923753, -464630599, -454319952, -443992200, -433647733, -423286938, -412910207, -402517931, -392110499, -381688305, -371251741, -360801199, -350337073, -339859758, -329369646, -318867135, -308352618, -297826491, -287289152, -276740996, -266182422, -255613825, -245035605, -234448160, -223851887, -213247187, -202634458, -192014100, -181386513, -170752097, -160111252, -149464379, -138811878, -128154152, -117491600, -106824625, -96153629, -85479012, -74801176, -64120525, -53437459, -42752382, -32065695, -21377801, -10689102, ], [0, 10689102, 21377801, 32065695, 42752382, 53437459, 64120525, 74801176, 85479012, 96153629, 106824625, 117491600, 128154152, 138811878, 149464379, 160111252, 170752097, 181386513, 192014100, 202634458, 213247187, 223851887, 234448160, 245035605, 255613825, 266182422, 276740996, 287289152, 297826491, 308352618, 318867135, 329369646, 339859758, 350337073, 360801199, 371251741, 381688305, 392110499, 402517931, 412910207, 423286938, 433647733, 443992200, 454319952, 464630599, 474923753, 485199026, 495456031, 505694383, 515913696, 526113585, 536293666, 546453556, 556592873, 566711234, 576808258, 586883566, 596936779, 606967517, 616975402, 626960060, 636921112, 646858185, 656770904, 666658896, 676521788, 686359210, 696170791, 705956161, 715714953, 725446798, 735151331, 744828185, 754476997, 764097404, 773689043, 783251552, 792784573, 802287746, 811760713, 821203118, 830614605, 839994820, 849343410, 858660022, 867944306, 877195913, 886414493, 895599701, 904751190, 913868615, 922951634, 931999904, 941013085, 949990838, 958932823, 967838706, 976708150, 985540821, 994336387, 1003094518, 1011814882, 1020497152, 1029141000, 1037746103, 1046312134, 1054838773, 1063325697, 1071772588, 1080179127, 1088544998, 1096869886, 1105153478, 1113395461, 1121595525, 1129753362, 1137868664, 1145941127, 1153970445, 1161956317, 1169898442, 1177796520, 1185650256, 1193459352, 1201223516, 1208942454, 1216615876, 1224243493, 1231825018, 1239360165, 1246848651, 1254290194, 1261684514, 1269031332, 1276330372, 1283581359, 1290784019, 1297938082, 1305043279, 1312099342, 1319106004, 1326063003, 1332970077, 1339826965, 1346633409, 1353389153, 1360093943, 1366747526, 1373349652, 1379900072, 1386398540, 1392844810, 1399238641, 1405579791, 1411868022, 1418103097, 1424284781, 1430412841, 1436487048, 1442507171, 1448472985, 1454384264, 1460240787, 1466042333, 1471788683, 1477479620, 1483114932, 1488694405, 1494217830, 1499684998, 1505095704, 1510449744, 1515746916, 1520987022, 1526169863, 1531295244, 1536362974, 1541372860, 1546324714, 1551218350, 1556053583, 1560830232, 1565548117, 1570207059, 1574806884, 1579347419, 1583828492, 1588249935, 1592611581, 1596913266, 1601154828, 1605336108, 1609456947, 1613517192, 1617516688, 1621455286, 1625332837, 1629149195, 1632904217, 1636597761, 1640229687, 1643799860, 1647308145, 1650754410, 1654138525, 1657460362, 1660719797, 1663916706, 1667050971, 1670122471, 1673131093, 1676076722, 1678959247, 1681778561, 1684534557, 1687227131, 1689856182, 1692421611, 1694923321, 1697361218, 1699735210, 1702045209, 1704291126, 1706472878, 1708590382, 1710643558, 1712632330, 1714556622, 1716416362, 1718211480, 1719941909, 1721607582, 1723208438, 1724744416, 1726215458, 1727621510, 1728962517, 1730238430, 1731449200, 1732594783, 1733675134, 1734690213, 1735639982, 1736524405, 1737343449, 1738097084, 1738785279, 1739408011, 1739965254, 1740456989, 1740883197, 1741243862, 1741538969, 1741768509, 1741932472, 1742030852, 1742063646, 1742030852, 1741932472, 1741768509, 1741538969, 1741243862, 1740883197, 1740456989, 1739965254, 1739408011, 1738785279, 1738097084, 1737343449, 1736524405, 1735639982, 1734690213, 1733675134, 1732594783, 1731449200, 1730238430, 1728962517, 1727621510, 1726215458, 1724744416, 1723208438, 1721607582, 1719941909, 1718211480, 1716416362, 1714556622, 1712632330, 1710643558, 1708590382, 1706472878, 1704291126, 1702045209, 1699735210, 1697361218, 1694923321, 1692421611, 1689856182, 1687227131, 1684534557, 1681778561, 1678959247, 1676076722, 1673131093, 1670122471, 1667050971, 1663916706, 1660719797, 1657460362, 1654138525, 1650754410, 1647308145, 1643799860, 1640229687, 1636597761, 1632904217, 1629149195, 1625332837, 1621455286, 1617516688, 1613517192, 1609456947, 1605336108, 1601154828, 1596913266, 1592611581, 1588249935, 1583828492, 1579347419, 1574806884, 1570207059, 1565548117, 1560830232, 1556053583, 1551218350, 1546324714, 1541372860, 1536362974, 1531295244, 1526169863, 1520987022, 1515746916, 1510449744, 1505095704, 1499684998, 1494217830, 1488694405, 1483114932, 1477479620, 1471788683, 1466042333, 1460240787, 1454384264, 1448472985, 1442507171, 1436487048, 1430412841, 1424284781, 1418103097, 1411868022, 1405579791, 1399238641, 1392844810, 1386398540, 1379900072, 1373349652, 1366747526, 1360093943, 1353389153, 1346633409, 1339826965, 1332970077, 1326063003, 1319106004, 1312099342, 1305043279, 1297938082, 1290784019, 1283581359, 1276330372, 1269031332, 1261684514, 1254290194, 1246848651, 1239360165, 1231825018, 1224243493, 1216615876, 1208942454, 1201223516, 1193459352, 1185650256, 1177796520, 1169898442, 1161956317, 1153970445, 1145941127, 1137868664, 1129753362, 1121595525, 1113395461, 1105153478, 1096869886, 1088544998, 1080179127, 1071772588, 1063325697, 1054838773, 1046312134, 1037746103, 1029141000, 1020497152, 1011814882, 1003094518, 994336387, 985540821, 976708150, 967838706, 958932823, 949990838, 941013085, 931999904, 922951634, 913868615, 904751190, 895599701, 886414493, 877195913, 867944306, 858660022, 849343410, 839994820, 830614605, 821203118, 811760713, 802287746, 792784573, 783251552, 773689043, 764097404, 754476997, 744828185, 735151331, 725446798, 715714953, 705956161, 696170791, 686359210, 676521788, 666658896, 656770904, 646858185, 636921112, 626960060, 616975402, 606967517, 596936779, 586883566, 576808258, 566711234, 556592873, 546453556, 536293666, 526113585, 515913696, 505694383, 495456031, 485199026, 474923753, 464630599, 454319952, 443992200, 433647733, 423286938, 412910207, 402517931, 392110499, 381688305, 371251741, 360801199, 350337073, 339859758, 329369646, 318867135, 308352618, 297826491, 287289152, 276740996, 266182422, 255613825, 245035605, 234448160, 223851887, 213247187, 202634458, 192014100, 181386513, 170752097, 160111252, 149464379, 138811878, 128154152, 117491600, 106824625, 96153629, 85479012, 74801176, 64120525, 53437459, 42752382, 32065695, 21377801, 10689102, 0, -10689102, -21377801, -32065695, -42752382, -53437459, -64120525, -74801176, -85479012, -96153629, -106824625, -117491600, -128154152, -138811878, -149464379, -160111252, -170752097, -181386513, -192014100, -202634458, -213247187, -223851887, -234448160, -245035605, -255613825, -266182422, -276740996, -287289152, -297826491, -308352618, -318867135, -329369646, -339859758, -350337073, -360801199, -371251741, -381688305, -392110499, -402517931, -412910207, -423286938, -433647733, -443992200, -454319952, -464630599, -474923753, -485199026, -495456031, -505694383, -515913696, -526113585, -536293666, -546453556, -556592873, -566711234, -576808258, -586883566, -596936779, -606967517, -616975402, -626960060, -636921112, -646858185, -656770904, -666658896, -676521788, -686359210, -696170791, -705956161, -715714953, -725446798, -735151331, -744828185, -754476997, -764097404, -773689043, -783251552, -792784573, -802287746, -811760713, -821203118, -830614605, -839994820, -849343410, -858660022, -867944306, -877195913, -886414493, -895599701, -904751190, -913868615, -922951634, -931999904, -941013085, -949990838, -958932823, -967838706, -976708150, -985540821, -994336387, -1003094518, -1011814882, -1020497152, -1029141000, -1037746103, -1046312134, -1054838773, -1063325697, -1071772588, -1080179127, -1088544998, -1096869886, -1105153478, -1113395461, -1121595525, -1129753362, -1137868664, -1145941127, -1153970445, -1161956317, -1169898442, -1177796520, -1185650256, -1193459352, -1201223516, -1208942454, -1216615876, -1224243493, -1231825018, -1239360165, -1246848651, -1254290194, -1261684514, -1269031332, -1276330372, -1283581359, -1290784019, -1297938082, -1305043279, -1312099342, -1319106004, -1326063003, -1332970077, -1339826965, -1346633409, -1353389153, -1360093943, -1366747526, -1373349652, -1379900072, -1386398540, -1392844810, -1399238641, -1405579791, -1411868022, -1418103097, -1424284781, -1430412841, -1436487048, -1442507171, -1448472985, -1454384264, -1460240787, -1466042333, -1471788683, -1477479620, -1483114932, -1488694405, -1494217830, -1499684998, -1505095704, -1510449744, -1515746916, -1520987022, -1526169863, -1531295244, -1536362974, -1541372860, -1546324714, -1551218350, -1556053583, -1560830232, -1565548117, -1570207059, -1574806884, -1579347419, -1583828492, -1588249935, -1592611581, -1596913266, -1601154828, -1605336108, -1609456947, -1613517192, -1617516688, -1621455286, -1625332837, -1629149195, -1632904217, -1636597761, -1640229687, -1643799860, -1647308145, -1650754410, -1654138525, -1657460362, -1660719797, -1663916706, -1667050971, -1670122471, -1673131093, -1676076722, -1678959247, -1681778561, -1684534557, -1687227131, -1689856182, -1692421611, -1694923321, -1697361218, -1699735210, -1702045209, -1704291126, -1706472878, -1708590382, -1710643558, -1712632330, -1714556622, -1716416362, -1718211480, -1719941909, -1721607582, -1723208438, -1724744416, -1726215458, -1727621510, -1728962517, -1730238430, -1731449200, -1732594783, -1733675134, -1734690213, -1735639982, -1736524405, -1737343449, -1738097084, -1738785279, -1739408011, -1739965254, -1740456989, -1740883197, -1741243862, -1741538969, -1741768509, -1741932472, -1742030852, -1742063646, -1742030852, -1741932472, -1741768509, -1741538969, -1741243862, -1740883197, -1740456989, -1739965254, -1739408011, -1738785279, -1738097084, -1737343449, -1736524405, -1735639982, -1734690213, -1733675134, -1732594783, -1731449200, -1730238430, -1728962517, -1727621510, -1726215458, -1724744416, -1723208438, -1721607582, -1719941909, -1718211480, -1716416362, -1714556622, -1712632330, -1710643558, -1708590382, -1706472878, -1704291126, -1702045209, -1699735210, -1697361218, -1694923321, -1692421611, -1689856182, -1687227131, -1684534557, -1681778561, -1678959247, -1676076722, -1673131093, -1670122471, -1667050971, -1663916706, -1660719797, -1657460362, -1654138525, -1650754410, -1647308145, -1643799860, -1640229687, -1636597761, -1632904217, -1629149195, -1625332837, -1621455286, -1617516688, -1613517192, -1609456947, -1605336108, -1601154828, -1596913266, -1592611581, -1588249935, -1583828492, -1579347419, -1574806884, -1570207059, -1565548117, -1560830232, -1556053583, -1551218350, -1546324714, -1541372860, -1536362974, -1531295244, -1526169863, -1520987022, -1515746916, -1510449744, -1505095704, -1499684998, -1494217830, -1488694405, -1483114932, -1477479620, -1471788683, -1466042333, -1460240787, -1454384264, -1448472985, -1442507171, -1436487048, -1430412841, -1424284781, -1418103097, -1411868022, -1405579791, -1399238641, -1392844810, -1386398540, -1379900072, -1373349652, -1366747526, -1360093943, -1353389153, -1346633409, -1339826965, -1332970077, -1326063003, -1319106004, -1312099342, -1305043279, -1297938082, -1290784019, -1283581359, -1276330372, -1269031332, -1261684514, -1254290194, -1246848651, -1239360165, -1231825018, -1224243493, -1216615876, -1208942454, -1201223516, -1193459352, -1185650256, -1177796520, -1169898442, -1161956317, -1153970445, -1145941127, -1137868664, -1129753362, -1121595525, -1113395461, -1105153478, -1096869886, -1088544998, -1080179127, -1071772588, -1063325697, -1054838773, -1046312134, -1037746103, -1029141000, -1020497152, -1011814882, -1003094518, -994336387, -985540821, -976708150, -967838706, -958932823, -949990838, -941013085, -931999904, -922951634, -913868615, -904751190, -895599701, -886414493, -877195913, -867944306, -858660022, -849343410, -839994820, -830614605, -821203118, -811760713, -802287746, -792784573, -783251552, -773689043, -764097404, -754476997, -744828185, -735151331, -725446798, -715714953, -705956161, -696170791, -686359210, -676521788, -666658896, -656770904, -646858185, -636921112, -626960060, -616975402, -606967517, -596936779, -586883566, -576808258, -566711234, -556592873, -546453556, -536293666, -526113585, -515913696, -505694383, -495456031, -485199026, -474923753, -464630599, -454319952, -443992200, -433647733, -423286938, -412910207, -402517931, -392110499, -381688305, -371251741, -360801199, -350337073, -339859758, -329369646, -318867135, -308352618, -297826491, -287289152, -276740996, -266182422, -255613825, -245035605, -234448160, -223851887, -213247187, -202634458, -192014100, -181386513, -170752097, -160111252, -149464379, -138811878, -128154152, -117491600, -106824625, -96153629, -85479012, -74801176, -64120525, -53437459, -42752382, -32065695, -21377801, -10689102, ], ];
@@ -0,0 +1,160 @@
+// Offline rendering of oscillators and other sample iterators into WAV files,
+// e.g. for machines without an audio output device
+
+#[cfg(feature = "rodio")]
+use crate::wav::WavFormat;
+use crate::wav::{WavSample, WavSpec, WavWriter};
+use core::time::Duration;
+#[cfg(feature = "rodio")]
+use rodio::source::Source;
+use std::fs::File;
+use std::io::{self, BufWriter, Seek, Write};
+use std::path::Path;
+
+/// Returns the number of samples (over all channels) of `duration`
+fn samples_in(duration: Duration, spec: &WavSpec) -> usize {
+    let frames = (duration.as_nanos() * spec.sample_rate as u128) / 1_000_000_000;
+    frames as usize * spec.channels as usize
+}
+
+/// Pulls `duration` worth of samples from an iterator and writes them as WAV.
+/// Stops early if the iterator ends.
+pub fn render<I, W>(samples: I, spec: WavSpec, duration: Duration, writer: W) -> io::Result<W>
+where
+    I: IntoIterator,
+    I::Item: WavSample,
+    W: Write + Seek,
+{
+    let mut wav = WavWriter::new(writer, spec)?;
+    for sample in samples.into_iter().take(samples_in(duration, &spec)) {
+        wav.write_sample(sample)?;
+    }
+    wav.finalize()
+}
+
+/// Renders a rodio `Source`, such as `WaveTableOsc16`, taking channels and
+/// sample rate from the source
+#[cfg(feature = "rodio")]
+pub fn render_source<S, W>(
+    source: S,
+    format: WavFormat,
+    duration: Duration,
+    writer: W,
+) -> io::Result<W>
+where
+    S: Source,
+    S::Item: rodio::Sample + WavSample,
+    W: Write + Seek,
+{
+    let spec = WavSpec {
+        channels: source.channels(),
+        sample_rate: source.sample_rate(),
+        format,
+    };
+    render(source, spec, duration, writer)
+}
+
+/// Renders an iterator into a WAV file at `path`, see `render`
+pub fn render_to_file<I, P>(
+    samples: I,
+    spec: WavSpec,
+    duration: Duration,
+    path: P,
+) -> io::Result<()>
+where
+    I: IntoIterator,
+    I::Item: WavSample,
+    P: AsRef<Path>,
+{
+    let file = BufWriter::new(File::create(path)?);
+    render(samples, spec, duration, file)?;
+    Ok(())
+}
+
+/// Renders a rodio `Source` into a WAV file at `path`, taking channels and
+/// sample rate from the source
+#[cfg(feature = "rodio")]
+pub fn render_source_to_file<S, P>(
+    source: S,
+    format: WavFormat,
+    duration: Duration,
+    path: P,
+) -> io::Result<()>
+where
+    S: Source,
+    S::Item: rodio::Sample + WavSample,
+    P: AsRef<Path>,
+{
+    let spec = WavSpec {
+        channels: source.channels(),
+        sample_rate: source.sample_rate(),
+        format,
+    };
+    render_to_file(source, spec, duration, path)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::wav::WavFormat;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_render() {
+        // Rendering stops when the iterator ends
+        let spec = WavSpec {
+            channels: 2,
+            sample_rate: 8000,
+            format: WavFormat::Float32,
+        };
+        let out = render(
+            [0.5_f32; 10],
+            spec,
+            Duration::from_secs(1),
+            Cursor::new(Vec::new()),
+        )
+        .unwrap()
+        .into_inner();
+        assert_eq!(out.len(), 44 + 10 * 4);
+
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: 8000,
+            format: WavFormat::Pcm24,
+        };
+        let out = render(
+            core::iter::repeat(1000_i16),
+            spec,
+            Duration::from_millis(250),
+            Cursor::new(Vec::new()),
+        )
+        .unwrap()
+        .into_inner();
+        assert_eq!(out.len(), 44 + 2000 * 3);
+    }
+
+    #[cfg(feature = "rodio")]
+    #[test]
+    fn test_render_source() {
+        use crate::freq::Hz;
+        use crate::osc::wave_table_osc::WaveTableOsc16;
+        use crate::osc::wave_tables::SINE_I16;
+
+        let mut osc = WaveTableOsc16::new();
+        osc.set_wavetable(&SINE_I16);
+        osc.set_sample_rate(Hz(8000));
+        osc.set_freq(Hz(1000));
+        osc.start();
+
+        let out = render_source(
+            osc,
+            WavFormat::Pcm24,
+            Duration::from_millis(250),
+            Cursor::new(Vec::new()),
+        )
+        .unwrap()
+        .into_inner();
+        assert_eq!(out.len(), 44 + 2000 * 3);
+        assert_eq!(u32::from_le_bytes(out[24..28].try_into().unwrap()), 8000);
+    }
+}
@@ -0,0 +1,415 @@
+// Provides microtunings mapping MIDI notes to frequencies in millihertz,
+// either 12-TET or loaded from Scala scale (.scl) and keyboard mapping (.kbm)
+// files.
+
+use crate::midi::{note_to_mfreq, A4_MFREQ};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
+#[cfg(feature = "std")]
+use std::path::Path;
+
+/// Error returned when loading Scala files. Non-exhaustive since `Io` only
+/// exists with the `std` feature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TuningError {
+    /// The file could not be read
+    #[cfg(feature = "std")]
+    Io(io::ErrorKind),
+    /// The file ends before all values have been read
+    UnexpectedEof,
+    /// The value on the given line (one based) is invalid
+    InvalidLine(usize),
+    /// The scale has no pitches
+    EmptyScale,
+}
+
+impl fmt::Display for TuningError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "std")]
+            TuningError::Io(kind) => write!(f, "{}", io::Error::from(*kind)),
+            TuningError::UnexpectedEof => write!(f, "unexpected end of file"),
+            TuningError::InvalidLine(line) => write!(f, "invalid value on line {}", line),
+            TuningError::EmptyScale => write!(f, "scale has no pitches"),
+        }
+    }
+}
+
+impl core::error::Error for TuningError {}
+
+#[cfg(feature = "std")]
+impl From<io::Error> for TuningError {
+    fn from(e: io::Error) -> Self {
+        TuningError::Io(e.kind())
+    }
+}
+
+/// Iterates over the lines of a Scala file that are not comments, together
+/// with their one based line numbers
+fn values(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.starts_with('!'))
+        .map(|(i, line)| (i + 1, line.trim()))
+}
+
+/// Parses the first whitespace separated token of a line
+fn parse_first<T: core::str::FromStr>((line, text): (usize, &str)) -> Result<T, TuningError> {
+    text.split_whitespace()
+        .next()
+        .and_then(|token| token.parse().ok())
+        .ok_or(TuningError::InvalidLine(line))
+}
+
+/// Scale of pitches relative to the tonic. The last pitch is the period,
+/// usually the octave.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Scale {
+    description: String,
+    /// Pitches in cents, without the tonic
+    cents: Vec<f64>,
+}
+
+impl Scale {
+    /// Creates a scale dividing `period` cents into `steps` equal steps
+    pub fn equal(steps: usize, period: f64) -> Self {
+        let steps = steps.max(1);
+        Self {
+            description: format!("{} equal divisions of {} cents", steps, period),
+            cents: (1..=steps)
+                .map(|k| period * k as f64 / steps as f64)
+                .collect(),
+        }
+    }
+
+    /// Parses a Scala scale. Pitches containing a period are in cents, all
+    /// others are ratios like `3/2` or integers.
+    pub fn parse(text: &str) -> Result<Self, TuningError> {
+        let mut lines = values(text);
+        // The description may be empty, so it is taken verbatim
+        let description = lines
+            .next()
+            .ok_or(TuningError::UnexpectedEof)?
+            .1
+            .to_string();
+        let count: usize = parse_first(lines.next().ok_or(TuningError::UnexpectedEof)?)?;
+        if count == 0 {
+            return Err(TuningError::EmptyScale);
+        }
+        let cents = (0..count)
+            .map(|_| {
+                let (line, text) = lines.next().ok_or(TuningError::UnexpectedEof)?;
+                let pitch = text.split_whitespace().next().unwrap_or("");
+                Self::parse_pitch(pitch).ok_or(TuningError::InvalidLine(line))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { description, cents })
+    }
+
+    fn parse_pitch(pitch: &str) -> Option<f64> {
+        if pitch.contains('.') {
+            return pitch.parse().ok();
+        }
+        let (numerator, denominator) = pitch.split_once('/').unwrap_or((pitch, "1"));
+        let numerator: u64 = numerator.parse().ok()?;
+        let denominator: u64 = denominator.parse().ok()?;
+        if numerator == 0 || denominator == 0 {
+            return None;
+        }
+        Some(1200.0 * libm::log2(numerator as f64 / denominator as f64))
+    }
+
+    /// Loads a Scala scale from a `.scl` file
+    #[cfg(feature = "std")]
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, TuningError> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Returns the number of pitches per period
+    pub fn len(&self) -> usize {
+        self.cents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cents.is_empty()
+    }
+
+    /// Returns the pitch of a scale degree in cents relative to the tonic.
+    /// Degrees outside of the first period are transposed by periods.
+    pub fn cents(&self, degree: i32) -> f64 {
+        let len = self.cents.len() as i32;
+        let period = self.cents[self.cents.len() - 1];
+        let (periods, degree) = (degree.div_euclid(len), degree.rem_euclid(len));
+        let cents = if degree == 0 {
+            0.0
+        } else {
+            self.cents[degree as usize - 1]
+        };
+        periods as f64 * period + cents
+    }
+}
+
+/// Scala keyboard mapping of MIDI notes to scale degrees
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyboardMapping {
+    /// Notes outside of `first_note..=last_note` are not mapped
+    pub first_note: u8,
+    pub last_note: u8,
+    /// Note that plays the tonic of the scale
+    pub middle_note: u8,
+    /// Note that plays `reference_freq`. If it is unmapped, the middle note
+    /// plays it instead.
+    pub reference_note: u8,
+    /// Frequency of `reference_note` in Hz
+    pub reference_freq: f64,
+    /// Degree of the formal octave, by which the mapping repeats
+    pub octave_degree: i32,
+    /// Scale degree of each key in the repeating pattern, `None` for unmapped
+    /// keys. Empty for a linear mapping where every key plays the next degree.
+    pub keys: Vec<Option<i32>>,
+}
+
+impl KeyboardMapping {
+    /// Linear mapping with the tonic at `middle_note` and A4 at 440 Hz
+    pub fn linear(middle_note: u8) -> Self {
+        Self {
+            first_note: 0,
+            last_note: 127,
+            middle_note,
+            reference_note: 69,
+            reference_freq: A4_MFREQ as f64 / 1000.0,
+            octave_degree: 0,
+            keys: Vec::new(),
+        }
+    }
+
+    /// Parses a Scala keyboard mapping. Missing key entries at the end are
+    /// unmapped.
+    pub fn parse(text: &str) -> Result<Self, TuningError> {
+        let mut lines = values(text).filter(|(_, line)| !line.is_empty());
+        let mut next = || lines.next().ok_or(TuningError::UnexpectedEof);
+
+        let size: usize = parse_first(next()?)?;
+        let first_note = parse_first(next()?)?;
+        let last_note = parse_first(next()?)?;
+        let middle_note = parse_first(next()?)?;
+        let reference_note = parse_first(next()?)?;
+        let line = next()?;
+        let reference_freq: f64 = parse_first(line)?;
+        if reference_freq.is_nan() || reference_freq <= 0.0 {
+            return Err(TuningError::InvalidLine(line.0));
+        }
+        let octave_degree = parse_first(next()?)?;
+
+        let mut keys = Vec::with_capacity(size);
+        for line in lines.take(size) {
+            keys.push(match line.1.split_whitespace().next() {
+                Some("x") => None,
+                _ => Some(parse_first(line)?),
+            });
+        }
+        keys.resize(size, None);
+        Ok(Self {
+            first_note,
+            last_note,
+            middle_note,
+            reference_note,
+            reference_freq,
+            octave_degree,
+            keys,
+        })
+    }
+
+    /// Loads a Scala keyboard mapping from a `.kbm` file
+    #[cfg(feature = "std")]
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, TuningError> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Returns the scale degree played by a note relative to the tonic at the
+    /// middle note, or `None` if the note is unmapped
+    pub fn degree(&self, note: u8, scale: &Scale) -> Option<i32> {
+        if !(self.first_note..=self.last_note).contains(&note) {
+            return None;
+        }
+        let offset = note as i32 - self.middle_note as i32;
+        if self.keys.is_empty() {
+            return Some(offset);
+        }
+        let size = self.keys.len() as i32;
+        let octave_degree = if self.octave_degree > 0 {
+            self.octave_degree
+        } else {
+            scale.len() as i32
+        };
+        let degree = self.keys[offset.rem_euclid(size) as usize]?;
+        Some(offset.div_euclid(size) * octave_degree + degree)
+    }
+}
+
+impl Default for KeyboardMapping {
+    fn default() -> Self {
+        Self::linear(60)
+    }
+}
+
+/// Frequencies of all MIDI notes in millihertz
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Tuning {
+    /// Zero for unmapped notes
+    mfreqs: [u32; 128],
+}
+
+impl Tuning {
+    /// 12-TET with A4 at 440 Hz
+    pub fn new() -> Self {
+        Self::equal(A4_MFREQ)
+    }
+
+    /// 12-TET with A4 at `a4_mfreq` millihertz
+    pub fn equal(a4_mfreq: u32) -> Self {
+        Self {
+            mfreqs: core::array::from_fn(|note| note_to_mfreq(note as u8, a4_mfreq)),
+        }
+    }
+
+    /// Tunes the notes to a Scala scale and keyboard mapping. If the
+    /// reference note is unmapped it stays silent and the tonic at the middle
+    /// note plays the reference frequency instead.
+    pub fn from_scala(scale: &Scale, mapping: &KeyboardMapping) -> Self {
+        let reference = mapping
+            .degree(mapping.reference_note, scale)
+            .map_or(0.0, |degree| scale.cents(degree));
+        Self {
+            mfreqs: core::array::from_fn(|note| {
+                mapping.degree(note as u8, scale).map_or(0, |degree| {
+                    let cents = scale.cents(degree) - reference;
+                    let mfreq = 1000.0 * mapping.reference_freq * libm::exp2(cents / 1200.0);
+                    // Casts from float saturate
+                    libm::round(mfreq) as u32
+                })
+            }),
+        }
+    }
+
+    /// Returns the frequency of a note in millihertz, or `None` if the note
+    /// is unmapped
+    pub fn mfreq(&self, note: u8) -> Option<u32> {
+        match self.mfreqs.get(note as usize) {
+            Some(&mfreq) if mfreq > 0 => Some(mfreq),
+            _ => None,
+        }
+    }
+}
+
+impl Default for Tuning {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PENTATONIC: &str = "! pentatonic.scl
+!
+Just pentatonic
+ 5
+!
+ 9/8
+ 5/4
+ 3/2
+ 5/3
+ 2
+";
+
+    #[test]
+    fn test_scale() {
+        let scale = Scale::parse(PENTATONIC).unwrap();
+        assert_eq!(scale.description(), "Just pentatonic");
+        assert_eq!(scale.len(), 5);
+        assert!((scale.cents(3) - 701.955).abs() < 1e-3);
+        assert!((scale.cents(-2) - (701.955 - 1200.0)).abs() < 1e-3);
+        assert!((scale.cents(10) - 2400.0).abs() < 1e-9);
+
+        let cents = Scale::parse("\n2\n100.0 semitone\n1200.\n").unwrap();
+        assert_eq!(cents.description(), "");
+        assert_eq!(cents.cents(1), 100.0);
+        assert_eq!(cents.cents(-1), -1100.0);
+
+        assert_eq!(Scale::parse("x\n0\n"), Err(TuningError::EmptyScale));
+        assert_eq!(
+            Scale::parse("x\n2\n3/0\n2\n"),
+            Err(TuningError::InvalidLine(3))
+        );
+        assert_eq!(Scale::parse("x\n2\n3/2\n"), Err(TuningError::UnexpectedEof));
+    }
+
+    #[test]
+    fn test_tuning() {
+        // 12-TET matches the MIDI note table
+        let tuning = Tuning::new();
+        assert_eq!(tuning.mfreq(69), Some(440_000));
+        assert_eq!(tuning.mfreq(60), Some(261_626));
+        assert_eq!(tuning.mfreq(128), None);
+
+        // Scala 12-TET with the default mapping agrees within rounding
+        let scala = Tuning::from_scala(&Scale::equal(12, 1200.0), &KeyboardMapping::default());
+        for note in 0..128 {
+            let d = scala.mfreq(note).unwrap() as i64 - tuning.mfreq(note).unwrap() as i64;
+            assert!(d.abs() <= 1, "note {} differs by {}", note, d);
+        }
+
+        // Pentatonic on the white keys from C4, black keys unmapped, with
+        // C4 at 261.6256 Hz
+        let kbm = "! white.kbm
+12
+0
+127
+60
+60
+261.6256
+5
+0
+x
+1
+x
+2
+x
+x
+3
+x
+4
+";
+        let mapping = KeyboardMapping::parse(kbm).unwrap();
+        assert_eq!(mapping.keys.len(), 12);
+        assert_eq!(mapping.keys[11], None);
+        let scale = Scale::parse(PENTATONIC).unwrap();
+        let tuning = Tuning::from_scala(&scale, &mapping);
+        assert_eq!(tuning.mfreq(60), Some(261_626));
+        assert_eq!(tuning.mfreq(61), None);
+        assert_eq!(tuning.mfreq(67), Some(392_438));
+        assert_eq!(tuning.mfreq(72), Some(523_251));
+        assert_eq!(tuning.mfreq(48), Some(130_813));
+
+        // An unmapped reference key moves the reference frequency to the
+        // tonic
+        let kbm = kbm.replace("60\n261.6256", "61\n277.1826");
+        let mapping = KeyboardMapping::parse(&kbm).unwrap();
+        assert_eq!(mapping.reference_note, 61);
+        let tuning = Tuning::from_scala(&scale, &mapping);
+        assert_eq!(tuning.mfreq(61), None);
+        assert_eq!(tuning.mfreq(60), Some(277_183));
+        assert_eq!(tuning.mfreq(72), Some(554_365));
+    }
+}
@@ -0,0 +1,441 @@
+// Provides a synthesizer voice (oscillator and envelope) and a polyphonic
+// voice allocator with voice stealing.
+
+use crate::envelope::Envelope;
+use crate::fixed::Q15;
+use crate::freq::{Hz, MilliHz};
+use crate::midi::MidiEvent;
+use crate::osc::wave_table_osc::{Interpolation, WaveTableOsc16};
+use crate::osc::wave_tables::SINE_I16;
+use crate::tuning::Tuning;
+#[cfg(feature = "rodio")]
+use core::time::Duration;
+#[cfg(feature = "rodio")]
+use rodio::source::Source;
+
+/// Single voice, a wavetable oscillator shaped by an envelope
+pub struct Voice {
+    pub osc: WaveTableOsc16,
+    pub env: Envelope,
+
+    /// Note number while the voice is held or releasing
+    note: Option<u8>,
+    held: bool,
+    /// Velocity as gain
+    gain: Q15,
+    /// Amplitude, `Q15::MAX` is unity
+    amplitude: Q15,
+    priority: u8,
+    /// Note-on counter value of the last note on, larger is younger
+    age: u64,
+}
+
+impl Voice {
+    pub fn new() -> Self {
+        let mut osc = WaveTableOsc16::new();
+        osc.set_wavetable(&SINE_I16);
+        osc.set_interpolation(Interpolation::Linear);
+        Self {
+            osc,
+            env: Envelope::new(),
+
+            note: None,
+            held: false,
+            gain: Q15::ZERO,
+            amplitude: Q15::MAX,
+            priority: 0,
+            age: 0,
+        }
+    }
+
+    /// Starts a note. The envelope continues from its current level, so a
+    /// stolen voice does not click.
+    pub fn note_on<F: Into<MilliHz>>(&mut self, note: u8, velocity: u8, freq: F) {
+        self.note = Some(note);
+        self.held = true;
+        self.gain = Q15(((velocity.min(127) as i32) * (i16::MAX as i32) / 127) as i16);
+        self.osc.set_freq(freq);
+        if !self.osc.is_running() {
+            self.osc.reset_and_start();
+        }
+        self.env.gate_on();
+    }
+
+    /// Releases the note, the voice stays active until the release ended
+    pub fn note_off(&mut self) {
+        self.held = false;
+        self.env.gate_off();
+    }
+
+    /// Stops the voice immediately
+    pub fn reset(&mut self) {
+        self.note = None;
+        self.held = false;
+        self.env.reset();
+        self.osc.stop_and_reset();
+    }
+
+    /// Sets the sample rate in any unit, e.g. `Hz(48000)`
+    pub fn set_sample_rate<R: Into<MilliHz>>(&mut self, sample_rate: R) {
+        let sample_rate = sample_rate.into();
+        self.osc.set_sample_rate(sample_rate);
+        self.env.set_sample_rate(sample_rate);
+    }
+
+    /// Sets the amplitude in Q15, clamped to `0..=i16::MAX` where `i16::MAX`
+    /// is unity, e.g. for tremolo
+    pub fn set_amplitude(&mut self, amplitude: i32) {
+        self.amplitude = Q15(amplitude.clamp(0, i16::MAX as i32) as i16);
+    }
+
+    /// Returns the note number while the voice is held or releasing
+    pub fn note(&self) -> Option<u8> {
+        self.note
+    }
+
+    /// Returns whether the note is held, i.e. not released yet
+    pub fn is_held(&self) -> bool {
+        self.held
+    }
+
+    /// Returns whether the voice produces sound
+    pub fn is_active(&self) -> bool {
+        self.env.is_active()
+    }
+
+    /// Returns the current envelope level scaled by velocity in Q15
+    pub fn loudness(&self) -> i32 {
+        (Q15(self.env.level()) * self.gain).0 as i32
+    }
+
+    /// Renders the next sample
+    #[inline]
+    pub fn _next(&mut self) -> i16 {
+        if !self.is_active() {
+            self.note = None;
+            return 0;
+        }
+        let y = Q15(self.osc._next().unwrap_or(0)) * Q15(self.env._next()) * self.gain;
+        (y * self.amplitude).0
+    }
+}
+
+impl Default for Voice {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Strategy to pick a voice when all voices are held
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StealMode {
+    /// Steal the voice that started first
+    #[default]
+    Oldest,
+    /// Steal the voice with the lowest envelope level times velocity
+    Quietest,
+    /// Steal the voice with the lowest priority given at note on, the oldest
+    /// of these on ties
+    LowestPriority,
+}
+
+/// Polyphonic voice allocator mixing `N` voices into one Q15 output
+pub struct VoiceAllocator<const N: usize> {
+    voices: [Voice; N],
+    steal_mode: StealMode,
+    /// Output gain in Q15
+    gain: i32,
+    sample_rate: u32,
+    /// Frequencies of MIDI notes
+    tuning: Tuning,
+    counter: u64,
+}
+
+impl<const N: usize> VoiceAllocator<N> {
+    pub fn new() -> Self {
+        Self {
+            voices: core::array::from_fn(|_| Voice::new()),
+            steal_mode: StealMode::Oldest,
+            gain: (i16::MAX as i32) / (N.max(1) as i32),
+            sample_rate: 44100,
+            tuning: Tuning::new(),
+            counter: 0,
+        }
+    }
+
+    pub fn set_steal_mode(&mut self, steal_mode: StealMode) {
+        self.steal_mode = steal_mode;
+    }
+
+    /// Sets the output gain in Q15. The default of `1 / N` cannot clip,
+    /// larger gains saturate when many loud voices play.
+    pub fn set_gain(&mut self, gain: i16) {
+        self.gain = gain as i32;
+    }
+
+    /// Sets the sample rate in any unit, e.g. `Hz(48000)`
+    pub fn set_sample_rate<R: Into<MilliHz>>(&mut self, sample_rate: R) {
+        let sample_rate = sample_rate.into();
+        self.sample_rate = Hz::from(sample_rate).0;
+        for voice in self.voices.iter_mut() {
+            voice.set_sample_rate(sample_rate);
+        }
+    }
+
+    /// Sets the tuning used by `handle_event` and retunes sounding voices.
+    /// Voices playing notes that are unmapped in the new tuning keep their
+    /// frequency.
+    pub fn set_tuning(&mut self, tuning: Tuning) {
+        self.tuning = tuning;
+        for voice in self.voices.iter_mut().filter(|v| v.is_active()) {
+            if let Some(mfreq) = voice.note().and_then(|note| self.tuning.mfreq(note)) {
+                voice.osc.set_mfreq(mfreq);
+            }
+        }
+    }
+
+    pub fn tuning(&self) -> &Tuning {
+        &self.tuning
+    }
+
+    pub fn voices(&self) -> &[Voice; N] {
+        &self.voices
+    }
+
+    /// Returns the voices, e.g. to set wavetables and envelopes
+    pub fn voices_mut(&mut self) -> &mut [Voice; N] {
+        &mut self.voices
+    }
+
+    /// Returns the number of active voices
+    pub fn active_voices(&self) -> usize {
+        self.voices.iter().filter(|v| v.is_active()).count()
+    }
+
+    /// Picks the voice for a new note. A voice already playing the note is
+    /// reused, then idle voices, then voices in their release tail (the
+    /// quietest first) and finally a held voice according to the steal mode.
+    fn allocate(&self, note: u8) -> usize {
+        let voices = self.voices.iter().enumerate();
+        if let Some((i, _)) = voices
+            .clone()
+            .find(|(_, v)| v.is_active() && v.note() == Some(note))
+        {
+            return i;
+        }
+        if let Some((i, _)) = voices
+            .clone()
+            .filter(|(_, v)| !v.is_active())
+            .min_by_key(|(_, v)| v.age)
+        {
+            return i;
+        }
+        if let Some((i, _)) = voices
+            .clone()
+            .filter(|(_, v)| !v.is_held())
+            .min_by_key(|(_, v)| (v.loudness(), v.age))
+        {
+            return i;
+        }
+        let stolen = match self.steal_mode {
+            StealMode::Oldest => voices.min_by_key(|(_, v)| v.age),
+            StealMode::Quietest => voices.min_by_key(|(_, v)| (v.loudness(), v.age)),
+            StealMode::LowestPriority => voices.min_by_key(|(_, v)| (v.priority, v.age)),
+        };
+        stolen.map_or(0, |(i, _)| i)
+    }
+
+    /// Starts a note and returns the index of the voice playing it
+    pub fn note_on<F: Into<MilliHz>>(&mut self, note: u8, velocity: u8, freq: F) -> usize {
+        self.note_on_with_priority(note, velocity, freq, 0)
+    }
+
+    /// Starts a note with a priority for `StealMode::LowestPriority` and
+    /// returns the index of the voice playing it
+    pub fn note_on_with_priority<F: Into<MilliHz>>(
+        &mut self,
+        note: u8,
+        velocity: u8,
+        freq: F,
+        priority: u8,
+    ) -> usize {
+        let i = self.allocate(note);
+        self.counter += 1;
+        let voice = &mut self.voices[i];
+        voice.age = self.counter;
+        voice.priority = priority;
+        voice.note_on(note, velocity, freq);
+        i
+    }
+
+    /// Releases all held voices playing the note
+    pub fn note_off(&mut self, note: u8) {
+        for voice in self.voices.iter_mut() {
+            if voice.is_held() && voice.note() == Some(note) {
+                voice.note_off();
+            }
+        }
+    }
+
+    /// Releases all held voices
+    pub fn all_notes_off(&mut self) {
+        for voice in self.voices.iter_mut().filter(|v| v.is_held()) {
+            voice.note_off();
+        }
+    }
+
+    /// Stops all voices immediately
+    pub fn reset(&mut self) {
+        self.voices.iter_mut().for_each(Voice::reset);
+    }
+
+    /// Plays a MIDI event. Channels are ignored, all notes share the voices.
+    /// Notes that are unmapped in the tuning are ignored.
+    pub fn handle_event(&mut self, event: &MidiEvent) {
+        match *event {
+            MidiEvent::NoteOn { note, velocity, .. } => {
+                if let Some(mfreq) = self.tuning.mfreq(note) {
+                    self.note_on(note, velocity, MilliHz(mfreq));
+                }
+            }
+            MidiEvent::NoteOff { note, .. } => self.note_off(note),
+            // All sound off
+            MidiEvent::ControlChange {
+                controller: 120, ..
+            }
+            | MidiEvent::Reset => self.reset(),
+            // All notes off
+            MidiEvent::ControlChange {
+                controller: 123, ..
+            } => self.all_notes_off(),
+            _ => {}
+        }
+    }
+
+    /// Renders and mixes the next sample of all voices
+    #[inline]
+    pub fn _next(&mut self) -> i16 {
+        // N voices of at most 16 bit sum up without overflow in i64
+        let sum: i64 = self.voices.iter_mut().map(|v| v._next() as i64).sum();
+        ((sum * self.gain as i64) >> 15).clamp(i16::MIN as i64, i16::MAX as i64) as i16
+    }
+}
+
+impl<const N: usize> Default for VoiceAllocator<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Iterator for VoiceAllocator<N> {
+    type Item = i16;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self._next())
+    }
+}
+
+#[cfg(feature = "rodio")]
+impl<const N: usize> Source for VoiceAllocator<N> {
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn synth<const N: usize>() -> VoiceAllocator<N> {
+        let mut synth = VoiceAllocator::<N>::new();
+        synth.set_sample_rate(Hz(1000));
+        for voice in synth.voices_mut() {
+            voice.env.set_attack(0);
+            voice.env.set_release(10);
+        }
+        synth
+    }
+
+    #[test]
+    fn test_allocation_and_stealing() {
+        let mut synth = synth::<3>();
+        assert_eq!(synth.note_on(60, 100, MilliHz(261_626)), 0);
+        assert_eq!(synth.note_on(64, 100, MilliHz(329_628)), 1);
+        assert_eq!(synth.note_on(67, 100, MilliHz(391_995)), 2);
+        // The same note reuses its voice
+        assert_eq!(synth.note_on(64, 100, MilliHz(329_628)), 1);
+        synth.by_ref().take(5).count();
+        assert_eq!(synth.active_voices(), 3);
+
+        // A releasing voice is preferred over stealing a held one
+        synth.note_off(67);
+        synth.by_ref().take(2).count();
+        assert_eq!(synth.note_on(72, 100, MilliHz(523_251)), 2);
+
+        // All voices are held, the oldest is stolen
+        assert_eq!(synth.note_on(76, 100, MilliHz(659_255)), 0);
+
+        // Voices become idle after their release tail
+        synth.all_notes_off();
+        synth.by_ref().take(20).count();
+        assert_eq!(synth.active_voices(), 0);
+        assert_eq!(synth.voices()[0].note(), None);
+
+        synth.set_steal_mode(StealMode::LowestPriority);
+        synth.note_on_with_priority(48, 100, MilliHz(130_813), 9);
+        let low = synth.note_on_with_priority(50, 100, MilliHz(146_832), 1);
+        synth.note_on_with_priority(52, 100, MilliHz(164_814), 5);
+        assert_eq!(
+            synth.note_on_with_priority(53, 100, MilliHz(174_614), 7),
+            low
+        );
+    }
+
+    #[test]
+    fn test_retuning() {
+        let mut synth = synth::<2>();
+        synth.handle_event(&MidiEvent::NoteOn {
+            channel: 0,
+            note: 69,
+            velocity: 100,
+        });
+        let delta_phi = synth.voices()[0].osc.delta_phi();
+
+        // A sounding voice follows the new tuning
+        synth.set_tuning(Tuning::equal(220_000));
+        assert_eq!(synth.tuning().mfreq(69), Some(220_000));
+        assert_eq!(synth.voices()[0].osc.delta_phi(), delta_phi / 2);
+    }
+
+    #[test]
+    fn test_mix_headroom() {
+        let mut synth = synth::<4>();
+        for voice in synth.voices_mut() {
+            static DC: [i16; 1] = [i16::MAX];
+            voice.osc.set_wavetable(&DC);
+            voice.env.set_sustain(i16::MAX);
+        }
+        for note in 0..4 {
+            synth.note_on(note, 127, MilliHz(1000));
+        }
+        synth.by_ref().take(10).count();
+        assert!(synth._next() > i16::MAX - 16);
+
+        // Larger gains saturate instead of wrapping around
+        synth.set_gain(i16::MAX);
+        assert_eq!(synth._next(), i16::MAX);
+    }
+}
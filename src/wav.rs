@@ -0,0 +1,438 @@
+// Minimal RIFF/WAVE reader and writer for integer PCM and 32-bit float
+// samples
+
+use std::io::{self, Read, Seek, SeekFrom, Write};
+
+/// Sample encoding of a WAV file
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WavFormat {
+    #[default]
+    Pcm16,
+    Pcm24,
+    Pcm32,
+    Float32,
+}
+
+impl WavFormat {
+    pub fn bits_per_sample(&self) -> u16 {
+        match self {
+            WavFormat::Pcm16 => 16,
+            WavFormat::Pcm24 => 24,
+            WavFormat::Pcm32 | WavFormat::Float32 => 32,
+        }
+    }
+
+    /// Format tag of the fmt chunk, 1 is integer PCM and 3 is IEEE float
+    fn format_tag(&self) -> u16 {
+        match self {
+            WavFormat::Float32 => 3,
+            _ => 1,
+        }
+    }
+
+    fn from_tag(tag: u16, bits_per_sample: u16) -> Option<Self> {
+        match (tag, bits_per_sample) {
+            (1, 16) => Some(WavFormat::Pcm16),
+            (1, 24) => Some(WavFormat::Pcm24),
+            (1, 32) => Some(WavFormat::Pcm32),
+            (3, 32) => Some(WavFormat::Float32),
+            _ => None,
+        }
+    }
+}
+
+/// Layout of a WAV file
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WavSpec {
+    pub channels: u16,
+    pub sample_rate: u32,
+    pub format: WavFormat,
+}
+
+impl WavSpec {
+    /// Returns the bytes per frame, or None if they do not fit the header
+    fn block_align(&self) -> Option<u16> {
+        let bytes = self.format.bits_per_sample() / 8;
+        self.channels.checked_mul(bytes)
+    }
+}
+
+/// Samples that can be written to a WAV file
+pub trait WavSample: Copy {
+    /// Returns the sample as full scale i32, i.e. in Q31
+    fn to_q31(self) -> i32;
+    /// Returns the sample as float in [-1, 1]
+    fn to_f32(self) -> f32;
+    /// Converts a full scale i32 sample, rounding to nearest
+    fn from_q31(x: i32) -> Self;
+}
+
+impl WavSample for i16 {
+    fn to_q31(self) -> i32 {
+        (self as i32) << 16
+    }
+    fn to_f32(self) -> f32 {
+        self as f32 / 32768.0
+    }
+    fn from_q31(x: i32) -> Self {
+        ((x as i64 + (1 << 15)) >> 16).min(i16::MAX as i64) as i16
+    }
+}
+
+impl WavSample for i32 {
+    fn to_q31(self) -> i32 {
+        self
+    }
+    fn to_f32(self) -> f32 {
+        (self as f64 / 2147483648.0) as f32
+    }
+    fn from_q31(x: i32) -> Self {
+        x
+    }
+}
+
+impl WavSample for f32 {
+    fn to_q31(self) -> i32 {
+        // Casts from float saturate
+        (self as f64 * 2147483648.0) as i32
+    }
+    fn to_f32(self) -> f32 {
+        self
+    }
+    fn from_q31(x: i32) -> Self {
+        (x as f64 / 2147483648.0) as f32
+    }
+}
+
+/// Size of the RIFF chunk without the samples, i.e. of the WAVE id, the fmt
+/// chunk and the data chunk header
+const HEADER_LEN: u32 = 36;
+
+/// Writes samples into a WAV file. The chunk sizes are filled in by
+/// `finalize`, which therefore requires a seekable writer.
+pub struct WavWriter<W: Write + Seek> {
+    writer: W,
+    spec: WavSpec,
+    data_len: u32,
+}
+
+impl<W: Write + Seek> WavWriter<W> {
+    /// Writes the header and returns the writer
+    pub fn new(mut writer: W, spec: WavSpec) -> io::Result<Self> {
+        let bits = spec.format.bits_per_sample();
+        let block_align = spec
+            .block_align()
+            .ok_or_else(|| invalid_input("too many channels"))?;
+        let byte_rate = spec
+            .sample_rate
+            .checked_mul(block_align as u32)
+            .ok_or_else(|| invalid_input("sample rate too high"))?;
+        writer.write_all(b"RIFF")?;
+        writer.write_all(&0_u32.to_le_bytes())?;
+        writer.write_all(b"WAVE")?;
+        writer.write_all(b"fmt ")?;
+        writer.write_all(&16_u32.to_le_bytes())?;
+        writer.write_all(&spec.format.format_tag().to_le_bytes())?;
+        writer.write_all(&spec.channels.to_le_bytes())?;
+        writer.write_all(&spec.sample_rate.to_le_bytes())?;
+        writer.write_all(&byte_rate.to_le_bytes())?;
+        writer.write_all(&block_align.to_le_bytes())?;
+        writer.write_all(&bits.to_le_bytes())?;
+        writer.write_all(b"data")?;
+        writer.write_all(&0_u32.to_le_bytes())?;
+        Ok(Self {
+            writer,
+            spec,
+            data_len: 0,
+        })
+    }
+
+    pub fn spec(&self) -> WavSpec {
+        self.spec
+    }
+
+    /// Writes one sample. Channels are interleaved. Fails without writing if
+    /// the file would exceed the 4 GiB limit of the RIFF header.
+    pub fn write_sample<S: WavSample>(&mut self, sample: S) -> io::Result<()> {
+        let data_len = self
+            .data_len
+            .checked_add((self.spec.format.bits_per_sample() / 8) as u32)
+            .filter(|&len| len.checked_add(HEADER_LEN).is_some())
+            .ok_or_else(|| invalid_input("data chunk too long"))?;
+        match self.spec.format {
+            WavFormat::Pcm16 => {
+                let y = (sample.to_q31() >> 16) as i16;
+                self.writer.write_all(&y.to_le_bytes())?;
+            }
+            WavFormat::Pcm24 => {
+                let y = sample.to_q31() >> 8;
+                self.writer.write_all(&y.to_le_bytes()[..3])?;
+            }
+            WavFormat::Pcm32 => {
+                self.writer.write_all(&sample.to_q31().to_le_bytes())?;
+            }
+            WavFormat::Float32 => {
+                self.writer.write_all(&sample.to_f32().to_le_bytes())?;
+            }
+        }
+        self.data_len = data_len;
+        Ok(())
+    }
+
+    /// Returns the number of samples written so far
+    pub fn len(&self) -> usize {
+        (self.data_len / (self.spec.format.bits_per_sample() / 8) as u32) as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data_len == 0
+    }
+
+    /// Fills in the chunk sizes and returns the inner writer
+    pub fn finalize(mut self) -> io::Result<W> {
+        self.writer.seek(SeekFrom::Start(4))?;
+        let riff_len = HEADER_LEN
+            .checked_add(self.data_len)
+            .ok_or_else(|| invalid_input("data chunk too long"))?;
+        self.writer.write_all(&riff_len.to_le_bytes())?;
+        self.writer.seek(SeekFrom::Start(40))?;
+        self.writer.write_all(&self.data_len.to_le_bytes())?;
+        self.writer.seek(SeekFrom::End(0))?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+fn invalid_input(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Reads a whole WAV file into memory. Besides the format, the cycle length
+/// of Serum style wavetables is read from their `clm ` chunk.
+pub struct WavReader {
+    spec: WavSpec,
+    cycle_len: Option<usize>,
+    data: Vec<u8>,
+}
+
+impl WavReader {
+    pub fn new<R: Read>(mut reader: R) -> io::Result<Self> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+            return Err(invalid_data("not a WAV file"));
+        }
+
+        let mut spec = None;
+        let mut cycle_len = None;
+        let mut data = None;
+        let mut pos = 12;
+        while pos + 8 <= bytes.len() {
+            let id = &bytes[pos..pos + 4];
+            let len = u32::from_le_bytes(bytes[pos + 4..pos + 8].try_into().unwrap()) as usize;
+            let chunk = bytes
+                .get(pos + 8..pos + 8 + len)
+                .ok_or_else(|| invalid_data("truncated chunk"))?;
+            match id {
+                b"fmt " if len >= 16 => {
+                    let u16_at = |i: usize| u16::from_le_bytes([chunk[i], chunk[i + 1]]);
+                    let mut tag = u16_at(0);
+                    // WAVE_FORMAT_EXTENSIBLE stores the tag in its sub-format
+                    if tag == 0xfffe && len >= 26 {
+                        tag = u16_at(24);
+                    }
+                    let format = WavFormat::from_tag(tag, u16_at(14))
+                        .ok_or_else(|| invalid_data("unsupported sample format"))?;
+                    let channels = u16_at(2);
+                    if channels == 0 {
+                        return Err(invalid_data("no channels"));
+                    }
+                    spec = Some(WavSpec {
+                        channels,
+                        sample_rate: u32::from_le_bytes(chunk[4..8].try_into().unwrap()),
+                        format,
+                    });
+                }
+                // Serum writes e.g. "<!>2048 01000000 wavetable (www.xferrecords.com)"
+                b"clm " => {
+                    cycle_len = chunk
+                        .get(3..)
+                        .and_then(|text| {
+                            let digits = text.iter().take_while(|b| b.is_ascii_digit()).count();
+                            std::str::from_utf8(&text[..digits]).ok()?.parse().ok()
+                        })
+                        .filter(|&len| len > 0);
+                }
+                b"data" => data = Some(chunk.to_vec()),
+                _ => {}
+            }
+            // Chunks are padded to an even length
+            pos += 8 + len + (len & 1);
+        }
+
+        let spec = spec.ok_or_else(|| invalid_data("missing fmt chunk"))?;
+        let data = data.ok_or_else(|| invalid_data("missing data chunk"))?;
+        Ok(Self {
+            spec,
+            cycle_len,
+            data,
+        })
+    }
+
+    pub fn spec(&self) -> WavSpec {
+        self.spec
+    }
+
+    /// Returns the cycle length of a Serum style wavetable
+    pub fn cycle_len(&self) -> Option<usize> {
+        self.cycle_len
+    }
+
+    /// Returns the number of samples (over all channels)
+    pub fn len(&self) -> usize {
+        self.data.len() / (self.spec.format.bits_per_sample() / 8) as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the interleaved samples converted to `S`
+    pub fn samples<S: WavSample>(&self) -> impl Iterator<Item = S> + '_ {
+        let format = self.spec.format;
+        let size = (format.bits_per_sample() / 8) as usize;
+        self.data.chunks_exact(size).map(move |b| match format {
+            WavFormat::Pcm16 => S::from_q31((i16::from_le_bytes([b[0], b[1]]) as i32) << 16),
+            WavFormat::Pcm24 => S::from_q31(i32::from_le_bytes([0, b[0], b[1], b[2]])),
+            WavFormat::Pcm32 => S::from_q31(i32::from_le_bytes([b[0], b[1], b[2], b[3]])),
+            WavFormat::Float32 => {
+                let x = f32::from_le_bytes([b[0], b[1], b[2], b[3]]);
+                S::from_q31(x.to_q31())
+            }
+        })
+    }
+
+    /// Returns the samples of one channel converted to `S`
+    pub fn channel<S: WavSample>(&self, channel: u16) -> impl Iterator<Item = S> + '_ {
+        let channels = self.spec.channels as usize;
+        self.samples()
+            .skip(channel as usize)
+            .step_by(channels)
+            .take(if channel < self.spec.channels {
+                usize::MAX
+            } else {
+                0
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_wav_writer() {
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: 48000,
+            format: WavFormat::Pcm16,
+        };
+        let mut wav = WavWriter::new(Cursor::new(Vec::new()), spec).unwrap();
+        wav.write_sample(i16::MAX).unwrap();
+        wav.write_sample(-1_i16).unwrap();
+        assert_eq!(wav.len(), 2);
+        let bytes = wav.finalize().unwrap().into_inner();
+
+        assert_eq!(&bytes[0..4], b"RIFF");
+        assert_eq!(u32::from_le_bytes(bytes[4..8].try_into().unwrap()), 40);
+        assert_eq!(u16::from_le_bytes(bytes[20..22].try_into().unwrap()), 1);
+        assert_eq!(u32::from_le_bytes(bytes[28..32].try_into().unwrap()), 96000);
+        assert_eq!(u32::from_le_bytes(bytes[40..44].try_into().unwrap()), 4);
+        assert_eq!(&bytes[44..], &[0xff, 0x7f, 0xff, 0xff]);
+    }
+
+    #[test]
+    fn test_wav_limits() {
+        let spec = |channels, sample_rate| WavSpec {
+            channels,
+            sample_rate,
+            format: WavFormat::Pcm32,
+        };
+        for spec in [spec(u16::MAX, 48000), spec(2, u32::MAX)] {
+            let err = WavWriter::new(Cursor::new(Vec::new()), spec).err().unwrap();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        }
+
+        // The RIFF size of the header and samples must fit 32 bits
+        let mut wav = WavWriter::new(Cursor::new(Vec::new()), spec(1, 48000)).unwrap();
+        wav.data_len = u32::MAX - HEADER_LEN - 4;
+        wav.write_sample(0_i16).unwrap();
+        let err = wav.write_sample(0_i16).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        let bytes = wav.finalize().unwrap().into_inner();
+        assert_eq!(
+            u32::from_le_bytes(bytes[4..8].try_into().unwrap()),
+            u32::MAX
+        );
+    }
+
+    #[test]
+    fn test_wav_formats() {
+        for (format, bytes) in [
+            (WavFormat::Pcm24, vec![0x00, 0x00, 0xc0]),
+            (WavFormat::Pcm32, vec![0x00, 0x00, 0x00, 0xc0]),
+            (WavFormat::Float32, (-0.5_f32).to_le_bytes().to_vec()),
+        ] {
+            let spec = WavSpec {
+                channels: 1,
+                sample_rate: 44100,
+                format,
+            };
+            let mut wav = WavWriter::new(Cursor::new(Vec::new()), spec).unwrap();
+            wav.write_sample(-16384_i16).unwrap();
+            let out = wav.finalize().unwrap().into_inner();
+            assert_eq!(&out[44..], bytes.as_slice());
+        }
+    }
+
+    #[test]
+    fn test_wav_reader() {
+        let spec = WavSpec {
+            channels: 2,
+            sample_rate: 48000,
+            format: WavFormat::Pcm24,
+        };
+        let mut wav = WavWriter::new(Cursor::new(Vec::new()), spec).unwrap();
+        for x in [i16::MAX, -1, 1000, i16::MIN] {
+            wav.write_sample(x).unwrap();
+        }
+        let bytes = wav.finalize().unwrap().into_inner();
+
+        let wav = WavReader::new(bytes.as_slice()).unwrap();
+        assert_eq!(wav.spec(), spec);
+        assert_eq!(wav.len(), 4);
+        assert_eq!(wav.cycle_len(), None);
+        assert_eq!(wav.channel::<i16>(0).collect::<Vec<_>>(), [i16::MAX, 1000]);
+        assert_eq!(wav.channel::<i16>(1).collect::<Vec<_>>(), [-1, i16::MIN]);
+        assert_eq!(wav.channel::<f32>(1).last(), Some(-1.0));
+        assert_eq!(wav.channel::<i16>(2).count(), 0);
+
+        // Serum cycle length from a clm chunk inserted before the data chunk
+        let mut serum = bytes[..36].to_vec();
+        serum.extend_from_slice(b"clm ");
+        serum.extend_from_slice(&9_u32.to_le_bytes());
+        serum.extend_from_slice(b"<!>2048 0\0");
+        serum.extend_from_slice(&bytes[36..]);
+        assert_eq!(
+            WavReader::new(serum.as_slice()).unwrap().cycle_len(),
+            Some(2048)
+        );
+
+        assert!(WavReader::new(&b"RIFF\0\0\0\0WAVE"[..]).is_err());
+    }
+}